@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // The twotone handling only applies to some packages, but must not fail on any input either.
+    icondata_builder_fuzz::parse_svg(data, false);
+    icondata_builder_fuzz::parse_svg(data, true);
+});
@@ -0,0 +1,7 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|d: &str| {
+    icondata_builder_fuzz::path_segments(d);
+});
@@ -0,0 +1,19 @@
+//! Parsers of the builder exposed to the fuzz targets. The builder is a binary crate, so their
+//! sources are included as they are.
+
+#[allow(dead_code)]
+#[path = "../../src/icon/shape.rs"]
+mod shape;
+#[allow(dead_code)]
+#[path = "../../src/icon/svg.rs"]
+mod svg;
+
+/// Parses an svg file like the builder does for every upstream icon.
+pub fn parse_svg(data: &[u8], twotone: bool) {
+    let _ = svg::ParsedSvg::parse(data, twotone);
+}
+
+/// Parses the path data of a `d` attribute into its segments.
+pub fn path_segments(d: &str) {
+    shape::path_segments(d);
+}
@@ -0,0 +1,171 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{anyhow, Result};
+use heck::ToPascalCase;
+use tracing::{info, instrument};
+
+use crate::{dirs::write_to_file, icon, path, Packages};
+
+/// Words spelled differently by different packages while meaning the same.
+pub(crate) const SYNONYMS: &[(&str, &str)] = &[
+    ("Btn", "Button"),
+    ("Doc", "Document"),
+    ("Info", "Information"),
+    ("Pic", "Picture"),
+    ("Prev", "Previous"),
+    ("Sixteen", "Xs"),
+];
+
+/// How often a spelling of one or two words occurs in icon names across the catalog.
+#[derive(Debug, Default)]
+struct Usage<'a> {
+    count: usize,
+    packages: BTreeSet<&'a str>,
+    example: &'a str,
+}
+
+/// Two spellings of the same thing, of which the less common one should be replaced.
+#[derive(Debug)]
+struct Finding<'a> {
+    variant: &'a str,
+    canonical: &'a str,
+}
+
+/// Report inconsistent spellings across the icon names of all packages, like "Filetype" next to
+/// "FileType", and propose to normalize each to its more common spelling.
+///
+/// With `apply`, all proposals are added to [`icon::naming::NORMALIZATIONS`], renaming the
+/// affected icons with their old names kept as deprecated aliases once the libraries are generated
+/// again.
+#[instrument(level = "info")]
+pub(crate) async fn run(apply: bool) -> Result<()> {
+    let usages = usages()?;
+    let findings = findings(&usages);
+
+    for finding in &findings {
+        let variant = &usages[finding.variant];
+        let canonical = &usages[finding.canonical];
+        info!(
+            "{} ({} icons in {:?}, e.g. {}) should be spelled {} ({} icons in {:?}).",
+            finding.variant,
+            variant.count,
+            variant.packages,
+            variant.example,
+            finding.canonical,
+            canonical.count,
+            canonical.packages,
+        );
+    }
+    info!(num_findings = findings.len(), "Audited icon names.");
+
+    if apply && !findings.is_empty() {
+        add_normalizations(&findings).await?;
+        info!("Normalizations added. Run the build to rename the icons.");
+    }
+    Ok(())
+}
+
+/// Usages of all single words and pairs of adjacent words in icon names, without package prefixes.
+fn usages() -> Result<BTreeMap<String, Usage<'static>>> {
+    let mut usages = BTreeMap::<String, Usage>::new();
+    for package in Packages::get()? {
+        let prefix = package.name_prefix().to_pascal_case();
+        for icon in package.icons() {
+            let Some(name) = icon.name.strip_prefix(prefix.as_str()) else {
+                continue;
+            };
+            let words = icon::split_words(name);
+            for spelling in words.iter().map(|word| word.to_string()).chain(
+                words
+                    .windows(2)
+                    .filter(|pair| pair.iter().all(|word| is_word(word)))
+                    .map(|pair| pair.concat()),
+            ) {
+                let usage = usages.entry(spelling).or_default();
+                usage.count += 1;
+                usage.packages.insert(package.meta.short_name.as_ref());
+                if usage.example.is_empty() {
+                    usage.example = icon.name.as_str();
+                }
+            }
+        }
+    }
+    Ok(usages)
+}
+
+/// Spellings which are used next to another spelling of the same thing: words written both as one
+/// and as two words, and known synonyms.
+fn findings<'a>(usages: &'a BTreeMap<String, Usage>) -> Vec<Finding<'a>> {
+    let mut pairs = Vec::new();
+    for spelling in usages.keys() {
+        if let [first, second] = icon::split_words(spelling)[..] {
+            if is_word(first) && is_word(second) {
+                pairs.push((first.to_owned() + &second.to_lowercase(), spelling.as_str()));
+            }
+        }
+    }
+    pairs.extend(
+        SYNONYMS
+            .iter()
+            .map(|(short, long)| (short.to_string(), *long)),
+    );
+
+    pairs
+        .iter()
+        .filter_map(|(one, other)| {
+            let (one, one_usage) = usages.get_key_value(one.as_str())?;
+            let (other, other_usage) = usages.get_key_value(*other)?;
+            // On a tie, the spelling with more words is more readable.
+            Some(match one_usage.count > other_usage.count {
+                true => Finding {
+                    variant: other,
+                    canonical: one,
+                },
+                false => Finding {
+                    variant: one,
+                    canonical: other,
+                },
+            })
+        })
+        .collect()
+}
+
+/// Only words of at least two letters are considered, so that e.g. "AZ" in "SortAZ" is not taken
+/// for a spelling of "Az".
+fn is_word(word: &str) -> bool {
+    word.len() > 1 && word.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+/// Add the findings to the normalizations in the icon naming module.
+async fn add_normalizations(findings: &[Finding<'_>]) -> Result<()> {
+    let path = path::build_crate("src/icon/naming.rs");
+    let mut source = tokio::fs::read_to_string(&path).await?;
+
+    let declaration = "pub(crate) const NORMALIZATIONS: &[(&str, &str)] = &[";
+    let start = source
+        .find(declaration)
+        .ok_or_else(|| anyhow!("NORMALIZATIONS not found."))?
+        + declaration.len();
+    let end = start
+        + source[start..]
+            .find("];")
+            .ok_or_else(|| anyhow!("End of NORMALIZATIONS not found."))?;
+
+    let mut entries = source[start..end]
+        .lines()
+        .map(|line| line.trim().trim_end_matches(',').to_owned())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+    entries.extend(
+        findings
+            .iter()
+            .map(|finding| format!("(\"{}\", \"{}\")", finding.variant, finding.canonical)),
+    );
+
+    let table = entries
+        .iter()
+        .map(|entry| format!("\n    {entry},"))
+        .collect::<String>();
+    source.replace_range(start..end, &format!("{table}\n"));
+    write_to_file(&path, source).await
+}
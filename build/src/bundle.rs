@@ -0,0 +1,128 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    icon::SvgIcon,
+    package::{Downloaded, Package},
+    sem_ver::SemVer,
+    Packages,
+};
+
+/// A curated selection of icons across packages, generated as its own small crate
+/// `icondata_<name>` and re-exported by the main library behind the feature `<name>`.
+#[derive(Debug)]
+pub(crate) struct Bundle {
+    /// Name of the bundle, used as crate suffix, feature and module name, e.g. "essentials".
+    pub name: &'static str,
+    /// What the icons of the bundle have in common, shown in its documentation.
+    pub description: &'static str,
+    pub crate_version: SemVer,
+    /// Names of the icons in the bundle, e.g. "LuHome".
+    pub icons: &'static [&'static str],
+}
+
+/// All bundles generated next to the icon crates.
+pub(crate) const BUNDLES: &[Bundle] = &[Bundle {
+    name: "essentials",
+    description: "Common user interface icons, enough to start building an application.",
+    crate_version: SemVer {
+        major: 0,
+        minor: 0,
+        patch: 1,
+        prerelease: None,
+        build: None,
+    },
+    icons: &[
+        "LuAlertCircle",
+        "LuAlertTriangle",
+        "LuArrowDown",
+        "LuArrowLeft",
+        "LuArrowRight",
+        "LuArrowUp",
+        "LuBell",
+        "LuBookmark",
+        "LuCalendar",
+        "LuCheck",
+        "LuChevronDown",
+        "LuChevronLeft",
+        "LuChevronRight",
+        "LuChevronUp",
+        "LuClock",
+        "LuCopy",
+        "LuDownload",
+        "LuExternalLink",
+        "LuEye",
+        "LuEyeOff",
+        "LuFile",
+        "LuFileText",
+        "LuFilter",
+        "LuFolder",
+        "LuGlobe",
+        "LuHeart",
+        "LuHelpCircle",
+        "LuHome",
+        "LuImage",
+        "LuInfo",
+        "LuLink",
+        "LuLoader2",
+        "LuLock",
+        "LuLogIn",
+        "LuLogOut",
+        "LuMail",
+        "LuMapPin",
+        "LuMenu",
+        "LuMinus",
+        "LuMoon",
+        "LuMoreHorizontal",
+        "LuMoreVertical",
+        "LuPause",
+        "LuPencil",
+        "LuPhone",
+        "LuPlay",
+        "LuPlus",
+        "LuRefreshCw",
+        "LuSave",
+        "LuSearch",
+        "LuSend",
+        "LuSettings",
+        "LuShare2",
+        "LuShoppingCart",
+        "LuStar",
+        "LuSun",
+        "LuTrash2",
+        "LuUnlock",
+        "LuUpload",
+        "LuUser",
+        "LuUsers",
+        "LuX",
+        "SiDiscord",
+        "SiGithub",
+        "SiGitlab",
+        "SiLinkedin",
+        "SiMastodon",
+        "SiX",
+        "SiYoutube",
+    ],
+}];
+
+impl Bundle {
+    /// The icons of this bundle, in the order they are listed, with the package each is taken from.
+    pub fn icons(&self) -> Result<Vec<(&'static Package<Downloaded>, &'static SvgIcon)>> {
+        self.icons
+            .iter()
+            .map(|name| {
+                Packages::get()?
+                    .iter()
+                    .find_map(|package| {
+                        let icon = package.icons().iter().find(|icon| icon.name == *name)?;
+                        Some((package, icon))
+                    })
+                    .ok_or_else(|| anyhow!("Icon {name} of bundle {} does not exist.", self.name))
+            })
+            .collect()
+    }
+}
+
+/// Whether any bundle contains the icon with the given name.
+pub(crate) fn is_bundled(name: &str) -> bool {
+    BUNDLES.iter().any(|bundle| bundle.icons.contains(&name))
+}
@@ -0,0 +1,103 @@
+use std::{
+    io,
+    path::Path,
+    process::{Command, ExitStatus},
+};
+
+use snafu::{prelude::*, Backtrace};
+use tracing::{debug, info, instrument, warn};
+
+#[derive(Debug, Snafu)]
+pub(crate) enum Error {
+    #[snafu(display("Could not execute command {cmd}"))]
+    ExecCommand {
+        source: io::Error,
+        cmd: String,
+        backtrace: Backtrace,
+    },
+    #[snafu(display("Command exited with error. status_code: {exit_status}, std_out:{std_out}, std_err:{std_err}"))]
+    Command {
+        exit_status: ExitStatus,
+        std_out: String,
+        std_err: String,
+        backtrace: Backtrace,
+    },
+}
+
+/// Publish the crate located at `crate_dir` to crates.io.
+#[instrument(level = "info")]
+pub(crate) fn publish(crate_dir: &Path, dry_run: bool) -> Result<(), Error> {
+    let publish_output = {
+        let mut cmd = Command::new("cargo");
+        // Generated crates are published right after their versions were bumped, before anything is committed.
+        cmd.args(["publish", "--allow-dirty"]);
+        if dry_run {
+            cmd.arg("--dry-run");
+        }
+        debug!(?cmd, "Constructed command to publish crate.");
+        cmd.current_dir(crate_dir)
+            .output()
+            .context(ExecCommandSnafu {
+                cmd: format!("{cmd:?}"),
+            })
+    }?;
+
+    ensure!(publish_output.status.success(), {
+        let std_out = String::from_utf8_lossy(&publish_output.stdout).into_owned();
+        let std_err = String::from_utf8_lossy(&publish_output.stderr).into_owned();
+        warn!(
+            exit_code = publish_output.status.code(),
+            ?std_out,
+            ?std_err,
+            "Publish failed."
+        );
+        CommandSnafu {
+            exit_status: publish_output.status,
+            std_out,
+            std_err,
+        }
+    });
+
+    info!("Publish successful.");
+    Ok(())
+}
+
+/// Build the crate located at `crate_dir` as WebAssembly component for the given target, with the
+/// given environment variables, e.g. naming files it embeds.
+#[instrument(level = "info")]
+pub(crate) fn build_component(
+    crate_dir: &Path,
+    target: &str,
+    envs: &[(&str, &Path)],
+) -> Result<(), Error> {
+    let build_output = {
+        let mut cmd = Command::new("cargo");
+        cmd.args(["build", "--release", "--target", target])
+            .envs(envs.iter().copied());
+        debug!(?cmd, "Constructed command to build component.");
+        cmd.current_dir(crate_dir)
+            .output()
+            .context(ExecCommandSnafu {
+                cmd: format!("{cmd:?}"),
+            })
+    }?;
+
+    ensure!(build_output.status.success(), {
+        let std_out = String::from_utf8_lossy(&build_output.stdout).into_owned();
+        let std_err = String::from_utf8_lossy(&build_output.stderr).into_owned();
+        warn!(
+            exit_code = build_output.status.code(),
+            ?std_out,
+            ?std_err,
+            "Component build failed."
+        );
+        CommandSnafu {
+            exit_status: build_output.status,
+            std_out,
+            std_err,
+        }
+    });
+
+    info!("Component build successful.");
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use std::path::Path;
+
+use anyhow::Result;
+use icondata_core::{Catalog, CatalogIcon, CatalogPackage, CatalogSource, CATALOG_VERSION};
+use tracing::{info, instrument};
+
+use crate::{dirs::write_to_file, icon::svg::AttrValue, signing, Packages};
+
+/// Write all icons of all packages to `out` as binary catalog, see [`icondata_core::Catalog`],
+/// signed with the minisign secret key at `signing_key` if given.
+#[instrument(level = "info")]
+pub(crate) async fn export(out: &Path, signing_key: Option<&Path>) -> Result<()> {
+    let catalog = catalog()?;
+    let num_icons = catalog
+        .packages
+        .iter()
+        .map(|package| package.icons.len())
+        .sum::<usize>();
+
+    let bytes = catalog.to_bytes();
+    let num_bytes = bytes.len();
+    write_to_file(&out.to_path_buf(), bytes).await?;
+
+    info!(num_icons, num_bytes, ?out, "Exported catalog.");
+
+    if let Some(signing_key) = signing_key {
+        let trusted_comment = format!("icondata catalog version {CATALOG_VERSION}");
+        signing::sign(out, signing_key, &trusted_comment).await?;
+    }
+    Ok(())
+}
+
+/// Catalog of all icons of all downloaded packages.
+pub(crate) fn catalog() -> Result<Catalog> {
+    let packages = Packages::get()?
+        .iter()
+        .map(|package| CatalogPackage {
+            short_name: package.meta.short_name.to_string(),
+            name: package.meta.package_name.to_string(),
+            icons: package
+                .icons()
+                .iter()
+                .map(|icon| {
+                    let attributes = icon.svg.svg_attributes();
+                    let value = |value: &Option<AttrValue>| value.as_deref().map(str::to_owned);
+                    let source = package.revision().map(|revision| CatalogSource {
+                        repository: package.meta.source.url().to_owned(),
+                        revision: revision.to_owned(),
+                        path: icon.source_path.clone(),
+                    });
+                    CatalogIcon {
+                        id: icon.name.clone(),
+                        label: icon.label.clone(),
+                        source,
+                        paint: icon.paint,
+                        brand_color: icon.brand_color.clone(),
+                        variant: icon.style.clone(),
+                        brand: icon.brand,
+                        style: value(&attributes.style),
+                        x: value(&attributes.x),
+                        y: value(&attributes.y),
+                        width: value(&attributes.width),
+                        height: value(&attributes.height),
+                        view_box: value(&attributes.view_box),
+                        stroke_linecap: value(&attributes.stroke_linecap),
+                        stroke_linejoin: value(&attributes.stroke_linejoin),
+                        stroke_width: value(&attributes.stroke_width),
+                        stroke: value(&attributes.stroke),
+                        fill: value(&attributes.fill),
+                        data: icon.svg.content.clone(),
+                    }
+                })
+                .collect(),
+        })
+        .collect();
+    Ok(Catalog { packages })
+}
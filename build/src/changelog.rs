@@ -0,0 +1,298 @@
+//! The `CHANGELOG.md` of every icon crate, with an entry per generation changing its icon constants
+//! or the upstream version, newest first, e.g.
+//!
+//! ```md
+//! ## 2024-01-16
+//!
+//! Updated Font Awesome from 6.4.2 to 6.5.1.
+//!
+//! ### Added
+//!
+//! - `FaBluesky`
+//!
+//! ### Deprecated
+//!
+//! - `FaTwitter`, renamed to `FaXTwitter`
+//! ```
+//!
+//! Removed constants break code using them, so users can check the entries before upgrading.
+
+use std::{collections::BTreeSet, path::Path};
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use tracing::info;
+
+use crate::{
+    dirs::write_to_file,
+    package::{Downloaded, Package, PackageSource},
+    publish::ICON_DECLARATION,
+};
+
+const CHANGELOG: &str = "CHANGELOG.md";
+
+/// Matches the declaration of a deprecated alias, with the name of the icon it refers to.
+static DEPRECATED_ALIAS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?m)^#\[deprecated\(note = "Renamed to (\w+)\."\)\]\npub static (\w+):"#)
+        .expect("valid regex")
+});
+
+/// Icon constants of an icon crate.
+#[derive(Debug, Default)]
+struct Constants {
+    all: BTreeSet<String>,
+    /// Deprecated aliases, with the constant they were renamed to.
+    deprecated: Vec<(String, String)>,
+}
+
+impl Constants {
+    /// The constants declared by the generated sources of the crate.
+    fn declared<'a>(sources: impl IntoIterator<Item = &'a str>) -> Constants {
+        let mut constants = Constants::default();
+        for source in sources {
+            constants.all.extend(
+                ICON_DECLARATION
+                    .captures_iter(source)
+                    .map(|captures| captures[1].to_owned()),
+            );
+            constants.deprecated.extend(
+                DEPRECATED_ALIAS
+                    .captures_iter(source)
+                    .map(|captures| (captures[2].to_owned(), captures[1].to_owned())),
+            );
+        }
+        constants.deprecated.sort();
+        constants
+    }
+
+    /// The constants the crate of the package is generated with.
+    fn generated(package: &Package<Downloaded>) -> Constants {
+        let mut constants = Constants::default();
+        for icon in package.icons() {
+            constants.all.insert(icon.name.clone());
+            for alias in &icon.aliases {
+                constants.all.insert(alias.clone());
+                constants
+                    .deprecated
+                    .push((alias.clone(), icon.name.clone()));
+            }
+        }
+        constants.deprecated.sort();
+        constants
+    }
+}
+
+/// Add an entry to the changelog of the icon crate in `crate_dir` about the changes of the icon
+/// constants and of the upstream version, if there are any, before the crate of the package is
+/// generated. `previous_reference` is the tag or commit ref the crate was generated from before.
+pub(crate) async fn update(
+    crate_dir: &Path,
+    package: &Package<Downloaded>,
+    previous_reference: Option<&str>,
+) -> Result<()> {
+    let src_dir = crate_dir.join("src");
+    let mut sources = Vec::new();
+    if src_dir.exists() {
+        let mut dir_stream = tokio::fs::read_dir(&src_dir).await?;
+        while let Some(entry) = dir_stream.next_entry().await? {
+            if entry
+                .path()
+                .extension()
+                .is_some_and(|extension| extension == "rs")
+            {
+                sources.push(tokio::fs::read_to_string(entry.path()).await?);
+            }
+        }
+    }
+
+    let reference = match &package.meta.source {
+        PackageSource::Git { target, .. } => Some(target.reference()),
+        PackageSource::NpmTarball { version, .. } => Some(version.as_str()),
+        PackageSource::LocalPath { .. } => None,
+    };
+    let old =
+        (!sources.is_empty()).then(|| Constants::declared(sources.iter().map(String::as_str)));
+    let entry = entry(
+        &package.meta.package_name,
+        old.as_ref(),
+        &Constants::generated(package),
+        previous_reference,
+        reference,
+        &time::OffsetDateTime::now_utc().date().to_string(),
+    );
+    let Some(entry) = entry else {
+        return Ok(());
+    };
+
+    let changelog_path = crate_dir.join(CHANGELOG);
+    let changelog = match changelog_path.exists() {
+        true => Some(tokio::fs::read_to_string(&changelog_path).await?),
+        false => None,
+    };
+    let header = format!(
+        "# Changelog\n\nChanges of the icons of `icondata_{}`, generated from {}.\n",
+        package.meta.short_name, package.meta.package_name
+    );
+    info!(?changelog_path, "Adding changelog entry.");
+    write_to_file(
+        &changelog_path,
+        insert_entry(changelog.as_deref(), &header, &entry),
+    )
+    .await
+}
+
+/// The changelog entry about generating the constants `new`, replacing `old`, which are `None` if
+/// the crate is generated for the first time, from the `previous` and the `current` tag or commit
+/// ref of the package. `None` if nothing changed.
+fn entry(
+    package_name: &str,
+    old: Option<&Constants>,
+    new: &Constants,
+    previous: Option<&str>,
+    current: Option<&str>,
+    date: &str,
+) -> Option<String> {
+    let mut entry = format!("## {date}\n\n");
+    let Some(old) = old else {
+        let version = current.map_or(String::new(), |current| format!(" {current}"));
+        let num_icons = new.all.len() - new.deprecated.len();
+        entry.push_str(&format!(
+            "Generated from {package_name}{version} with {num_icons} icons.\n"
+        ));
+        return Some(entry);
+    };
+
+    let added = new.all.difference(&old.all).collect::<Vec<_>>();
+    let removed = old.all.difference(&new.all).collect::<Vec<_>>();
+    let deprecated = new
+        .deprecated
+        .iter()
+        .filter(|deprecated| !old.deprecated.contains(deprecated))
+        .collect::<Vec<_>>();
+    let updated = previous
+        .zip(current)
+        .filter(|(previous, current)| previous != current);
+    if added.is_empty() && removed.is_empty() && deprecated.is_empty() && updated.is_none() {
+        return None;
+    }
+
+    if let Some((previous, current)) = updated {
+        entry.push_str(&format!(
+            "Updated {package_name} from {previous} to {current}.\n\n"
+        ));
+    }
+    if added.is_empty() && removed.is_empty() && deprecated.is_empty() {
+        entry.push_str("The icons did not change.\n\n");
+    }
+    let mut section = |title: &str, items: Vec<String>| {
+        if !items.is_empty() {
+            entry.push_str(&format!("### {title}\n\n"));
+            for item in items {
+                entry.push_str(&format!("- {item}\n"));
+            }
+            entry.push('\n');
+        }
+    };
+    section(
+        "Added",
+        added.iter().map(|name| format!("`{name}`")).collect(),
+    );
+    section(
+        "Removed",
+        removed.iter().map(|name| format!("`{name}`")).collect(),
+    );
+    section(
+        "Deprecated",
+        deprecated
+            .iter()
+            .map(|(alias, name)| format!("`{alias}`, renamed to `{name}`"))
+            .collect(),
+    );
+    // Entries are separated by a single empty line.
+    entry.pop();
+    Some(entry)
+}
+
+/// The changelog with the entry added before all previous ones, or a new changelog with the given
+/// header and the entry.
+fn insert_entry(changelog: Option<&str>, header: &str, entry: &str) -> String {
+    let Some(changelog) = changelog else {
+        return format!("{header}\n{entry}");
+    };
+    match changelog.find("\n## ") {
+        Some(i) => format!("{}{entry}\n{}", &changelog[..i + 1], &changelog[i + 1..]),
+        None => format!("{}\n\n{entry}", changelog.trim_end()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{entry, insert_entry, Constants};
+
+    #[test]
+    fn lists_changed_constants() {
+        let old = Constants::declared([indoc::indoc! {r#"
+            pub static FaTwitter: &icondata_core::IconData = &icondata_core::IconData {
+            #[doc(hidden)]
+            #[deprecated(note = "Renamed to FaHouse.")]
+            pub static FaHome: &icondata_core::IconData = FaHouse;
+            pub static FaHouse: &icondata_core::IconData = &icondata_core::IconData {
+            pub static FaYahoo: &icondata_core::IconData = &icondata_core::IconData {
+        "#}]);
+        let new = Constants::declared([indoc::indoc! {r#"
+            pub static FaBluesky: &icondata_core::IconData = &icondata_core::IconData {
+            #[deprecated(note = "Renamed to FaHouse.")]
+            pub static FaHome: &icondata_core::IconData = FaHouse;
+            pub static FaHouse: &icondata_core::IconData = &icondata_core::IconData {
+            #[deprecated(note = "Renamed to FaXTwitter.")]
+            pub static FaTwitter: &icondata_core::IconData = FaXTwitter;
+            pub static FaXTwitter: &icondata_core::IconData = &icondata_core::IconData {
+        "#}]);
+
+        let unchanged = entry(
+            "Font Awesome",
+            Some(&new),
+            &new,
+            Some("6.5.1"),
+            Some("6.5.1"),
+            "",
+        );
+        assert_eq!(unchanged, None);
+
+        let entry = entry(
+            "Font Awesome",
+            Some(&old),
+            &new,
+            Some("6.4.2"),
+            Some("6.5.1"),
+            "2024-01-16",
+        )
+        .unwrap();
+        assert_eq!(
+            entry,
+            indoc::indoc! {"
+                ## 2024-01-16
+
+                Updated Font Awesome from 6.4.2 to 6.5.1.
+
+                ### Added
+
+                - `FaBluesky`
+                - `FaXTwitter`
+
+                ### Removed
+
+                - `FaYahoo`
+
+                ### Deprecated
+
+                - `FaTwitter`, renamed to `FaXTwitter`
+            "}
+        );
+
+        let changelog = insert_entry(None, "# Changelog\n", &entry);
+        let changelog = insert_entry(Some(&changelog), "# Changelog\n", "## 2024-02-01\n");
+        assert!(changelog.starts_with("# Changelog\n\n## 2024-02-01\n\n## 2024-01-16\n"));
+    }
+}
@@ -0,0 +1,74 @@
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Serialize;
+use tracing::{info, instrument};
+
+use crate::{dirs::write_to_file, icon::simplify::Complexity, Packages};
+
+/// Number of most complex icons listed per package.
+const NUM_HEAVIEST: usize = 10;
+
+#[derive(Debug, Serialize)]
+struct PackageComplexity {
+    short_name: String,
+    num_icons: usize,
+    /// Sum over all icons of the package.
+    total: Complexity,
+    mean_anchors: f64,
+    /// Icons with the most anchors, most first.
+    heaviest: Vec<IconComplexity>,
+}
+
+#[derive(Debug, Serialize)]
+struct IconComplexity {
+    id: String,
+    #[serde(flatten)]
+    complexity: Complexity,
+}
+
+/// Write the number of path commands and anchor points of all packages and their most complex
+/// icons to `out` as JSON, to find packages worth simplifying for a lite variant.
+#[instrument(level = "info")]
+pub(crate) async fn report(out: &Path) -> Result<()> {
+    let packages = Packages::get()?
+        .iter()
+        .map(|package| {
+            let mut icons = package
+                .icons()
+                .iter()
+                .map(|icon| IconComplexity {
+                    id: icon.name.clone(),
+                    complexity: icon.complexity,
+                })
+                .collect::<Vec<_>>();
+            icons.sort_by(|a, b| b.complexity.anchors.cmp(&a.complexity.anchors));
+
+            let total = icons
+                .iter()
+                .fold(Complexity::default(), |sum, icon| sum + icon.complexity);
+            let num_icons = icons.len();
+            icons.truncate(NUM_HEAVIEST);
+            PackageComplexity {
+                short_name: package.meta.short_name.to_string(),
+                num_icons,
+                total,
+                mean_anchors: total.anchors as f64 / num_icons.max(1) as f64,
+                heaviest: icons,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for package in &packages {
+        info!(
+            package = package.short_name,
+            package.total.commands,
+            package.total.anchors,
+            package.mean_anchors,
+            "Measured package complexity."
+        );
+    }
+    write_to_file(&out.to_path_buf(), serde_json::to_string_pretty(&packages)?).await?;
+    info!(?out, "Wrote complexity report.");
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use std::path::Path;
+
+use anyhow::Result;
+use miniz_oxide::deflate::compress_to_vec;
+use tracing::{info, instrument};
+
+use crate::{cargo, catalog, dirs::write_to_file, path};
+
+/// File name of the compressed catalog embedded into the component.
+const CATALOG_FILE: &str = "icondata.catalog";
+
+/// File name of the component.
+const COMPONENT_FILE: &str = "icondata.wasm";
+
+/// Target producing WebAssembly components instead of core modules.
+const TARGET: &str = "wasm32-wasip2";
+
+/// Write the component of the `icondata_component` crate, implementing the WIT interface of its
+/// `wit` directory, to `out`, built with the deflate compressed catalog of all icons embedded.
+#[instrument(level = "info")]
+pub(crate) async fn export(out: &Path) -> Result<()> {
+    let bytes = catalog::catalog()?.to_bytes();
+    let compressed = compress_to_vec(&bytes, 9);
+    // `include_bytes!` resolves relative paths against the source file embedding them.
+    let catalog_path = std::path::absolute(out.join(CATALOG_FILE))?;
+    write_to_file(&catalog_path, compressed).await?;
+    info!(
+        uncompressed = bytes.len(),
+        ?catalog_path,
+        "Exported compressed catalog."
+    );
+
+    let crate_dir = path::library_crate("icondata_component");
+    cargo::build_component(&crate_dir, TARGET, &[("ICONDATA_CATALOG", &catalog_path)])?;
+    let built = crate_dir
+        .join("target")
+        .join(TARGET)
+        .join("release")
+        .join("icondata_component.wasm");
+    let component_path = out.join(COMPONENT_FILE);
+    let num_bytes = tokio::fs::copy(&built, &component_path).await?;
+
+    info!(num_bytes, ?component_path, "Exported component.");
+    Ok(())
+}
@@ -0,0 +1,149 @@
+//! Dictionaries of substrings shared by the icons of a crate, used to generate icon crates in
+//! compressed mode, see `icondata_core::CompressedIcon`.
+//!
+//! Svg content is split into words starting at every `<` and space, and runs of up to
+//! [`MAX_WORDS`] words which occur often enough to save space are put into the dictionary. Every
+//! icon is then encoded by greedily replacing the longest runs found in the dictionary with a
+//! single reference character.
+
+use std::collections::HashMap;
+
+use icondata_core::{DICTIONARY_START, MAX_DICTIONARY_LEN};
+
+/// Longest runs of words considered for the dictionary.
+const MAX_WORDS: usize = 4;
+
+/// Bytes of a reference to a dictionary entry, a character of the private use area.
+const REFERENCE_LEN: usize = 3;
+
+/// Bytes each dictionary entry costs besides its content, a `&str` on 32-bit targets like WASM.
+const ENTRY_OVERHEAD: usize = 8;
+
+#[derive(Debug, Default)]
+pub struct Dictionary {
+    /// Entries in the order they are referred to, most saving first.
+    pub entries: Vec<String>,
+    index: HashMap<String, usize>,
+}
+
+impl Dictionary {
+    /// The dictionary saving the most bytes when encoding the given svg contents.
+    pub fn build<'a>(contents: impl IntoIterator<Item = &'a str>) -> Dictionary {
+        let mut counts = HashMap::<&str, usize>::new();
+        for content in contents.into_iter().filter(|content| is_encodable(content)) {
+            let starts = word_starts(content);
+            for (i, start) in starts.iter().enumerate() {
+                for end in starts.iter().skip(i + 1).take(MAX_WORDS) {
+                    *counts.entry(&content[*start..*end]).or_default() += 1;
+                }
+            }
+        }
+
+        let mut candidates = counts
+            .into_iter()
+            .filter_map(|(run, count)| {
+                let saved = count * run.len().saturating_sub(REFERENCE_LEN);
+                let cost = run.len() + ENTRY_OVERHEAD;
+                let saving = saved.checked_sub(cost).filter(|saving| *saving > 0)?;
+                Some((saving, run))
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|(a_saved, a), (b_saved, b)| b_saved.cmp(a_saved).then(a.cmp(b)));
+        candidates.truncate(MAX_DICTIONARY_LEN);
+
+        let entries = candidates
+            .into_iter()
+            .map(|(_, run)| run.to_owned())
+            .collect::<Vec<_>>();
+        let index = entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| (entry.clone(), i))
+            .collect();
+        Dictionary { entries, index }
+    }
+
+    /// The given svg content with runs of words found in the dictionary replaced by references.
+    /// `None` if the content contains characters of the private use area, which would be taken for
+    /// references.
+    pub fn encode(&self, content: &str) -> Option<String> {
+        if !is_encodable(content) {
+            return None;
+        }
+        let starts = word_starts(content);
+        let mut encoded = String::with_capacity(content.len());
+        let mut i = 0;
+        while i + 1 < starts.len() {
+            let reference = (1..=MAX_WORDS)
+                .rev()
+                .filter(|words| i + words < starts.len())
+                .find_map(|words| {
+                    let entry = self.index.get(&content[starts[i]..starts[i + words]])?;
+                    Some((words, *entry))
+                });
+            match reference {
+                Some((words, entry)) => {
+                    let reference = char::from_u32(DICTIONARY_START as u32 + entry as u32)
+                        .expect("dictionary is limited to the private use area");
+                    encoded.push(reference);
+                    i += words;
+                }
+                None => {
+                    encoded.push_str(&content[starts[i]..starts[i + 1]]);
+                    i += 1;
+                }
+            }
+        }
+        Some(encoded)
+    }
+}
+
+/// Byte offsets of all words of the given content, followed by its length.
+fn word_starts(content: &str) -> Vec<usize> {
+    let mut starts = content
+        .match_indices(['<', ' '])
+        .map(|(i, _)| i)
+        .filter(|i| *i > 0)
+        .collect::<Vec<_>>();
+    starts.insert(0, 0);
+    starts.push(content.len());
+    starts.dedup();
+    starts
+}
+
+fn is_encodable(content: &str) -> bool {
+    !content
+        .chars()
+        .any(|c| (DICTIONARY_START..='\u{F8FF}').contains(&c))
+}
+
+#[cfg(test)]
+mod test {
+    use super::Dictionary;
+
+    #[test]
+    fn encodes_shared_runs_of_words() {
+        let contents = [
+            r#"<path fill="none" stroke="currentColor" d="M4 4h16v16H4z"/>"#,
+            r#"<path fill="none" stroke="currentColor" d="M12 2v20"/>"#,
+            r#"<path fill="none" stroke="currentColor" d="M2 12h20"/><circle cx="12" cy="12" r="4"/>"#,
+        ];
+        let dictionary = Dictionary::build(contents);
+        assert_eq!(
+            dictionary.entries[0],
+            r#"<path fill="none" stroke="currentColor""#
+        );
+
+        let entries = dictionary
+            .entries
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        for content in contents {
+            let encoded = dictionary.encode(content).unwrap();
+            assert!(encoded.len() < content.len());
+            assert_eq!(icondata_core::decode(&encoded, &entries), content);
+        }
+        assert_eq!(dictionary.encode("<text>\u{E000}</text>"), None);
+    }
+}
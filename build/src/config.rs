@@ -0,0 +1,301 @@
+//! Configuration of the build, read from an `icondata.toml` file, e.g.
+//!
+//! ```toml
+//! output_dir = "../generated"
+//! packages = ["bs", "fa", "lu"]
+//!
+//! [package.fa]
+//! tag = "6.5.1"
+//! categories = ["solid", "regular"]
+//!
+//! [package.oc]
+//! sizes = [24]
+//!
+//! [package.ai.rename_categories]
+//! twotone = "two-tone"
+//! ```
+//!
+//! Everything left out defaults to the packages and refs compiled into this crate.
+
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use tracing::info;
+
+use crate::{
+    icon::IconSize,
+    package::{CategoryRenames, GitTarget, IconFilter, Package, Unknown},
+    path,
+    sem_ver::SemVer,
+};
+
+/// Name of the configuration file read if none is given, in the root of this crate.
+const CONFIG_FILE: &str = "icondata.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Config {
+    /// Directory the crates are generated in, relative to the configuration file. The root of
+    /// this repository by default.
+    output_dir: Option<PathBuf>,
+    /// Short names of the packages to build, e.g. "fa", or "fa5" for a pinned version. All
+    /// packages by default.
+    packages: Option<Vec<String>>,
+    /// Configuration files of local packages, relative to the configuration file, see
+    /// [`Package::local`].
+    #[serde(default)]
+    local_packages: Vec<PathBuf>,
+    /// Overrides of packages by their short name.
+    #[serde(default, rename = "package")]
+    overrides: BTreeMap<String, PackageOverride>,
+    /// Directory of the configuration file, resolving the paths it contains.
+    #[serde(skip)]
+    dir: PathBuf,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PackageOverride {
+    /// Tag to check out, e.g. "v6.5.1".
+    tag: Option<String>,
+    /// Branch to check out, together with `commit`.
+    branch: Option<String>,
+    /// Commit of `branch` to check out.
+    commit: Option<String>,
+    /// Upstream version of the ref. Read from the tag if left out, e.g. "6.5.1" for "v6.5.1". The
+    /// version of the tarball to download for `npm`.
+    version: Option<String>,
+    /// Name of the npm package whose tarball is downloaded instead of cloning the repository, at
+    /// `version`, see [`Package::with_npm_tarball`].
+    npm: Option<String>,
+    /// Sizes in pixels of the icons to generate, see [`IconFilter::sizes`].
+    sizes: Option<Vec<u32>>,
+    /// Categories of the icons to generate, see [`IconFilter::categories`].
+    categories: Option<Vec<String>>,
+    /// Globs of the icons to generate, see [`IconFilter::include`].
+    include: Option<Vec<String>>,
+    /// Globs of the icons to leave out, see [`IconFilter::exclude`].
+    #[serde(default)]
+    exclude: Vec<String>,
+    /// Directory of the svg files relative to the repository, if upstream moved them.
+    svg_dir: Option<String>,
+    /// Normalized names of categories by their upstream name, see [`CategoryRenames`]. An empty
+    /// name drops the category.
+    #[serde(default)]
+    rename_categories: BTreeMap<String, String>,
+}
+
+impl Config {
+    /// The configuration read from the given file, or from `icondata.toml` in the root of this
+    /// crate if none is given. Without that file, the defaults compiled into this crate are used.
+    pub fn read(config_path: Option<&Path>) -> Result<Config> {
+        let config_path = match config_path {
+            Some(config_path) => config_path.to_owned(),
+            None => {
+                let default_path = path::build_crate(CONFIG_FILE);
+                if !default_path.exists() {
+                    return Ok(Config {
+                        dir: path::build_crate(""),
+                        ..Config::default()
+                    });
+                }
+                default_path
+            }
+        };
+        info!(?config_path, "Reading configuration.");
+        let config = std::fs::read_to_string(&config_path)
+            .with_context(|| format!("Could not read configuration {config_path:?}"))?;
+        let config = toml::from_str::<Config>(&config)
+            .with_context(|| format!("Could not parse configuration {config_path:?}"))?;
+        Ok(Config {
+            dir: config_path.parent().unwrap_or(Path::new("")).to_owned(),
+            ..config
+        })
+    }
+
+    /// Directory the crates are generated in, if configured.
+    pub fn output_dir(&self) -> Option<PathBuf> {
+        self.output_dir.as_ref().map(|dir| self.dir.join(dir))
+    }
+
+    /// The configured packages with their overrides, followed by the local packages configured
+    /// here and by the given configuration files.
+    pub fn packages(&self, local_packages: &[PathBuf]) -> Result<Vec<Package<Unknown>>> {
+        let mut packages = Package::all();
+        for config_path in &self.local_packages {
+            packages.push(Package::local(&self.dir.join(config_path))?);
+        }
+        for config_path in local_packages {
+            packages.push(Package::local(config_path)?);
+        }
+
+        let short_names = self.packages.iter().flatten().chain(self.overrides.keys());
+        for short_name in short_names {
+            if !packages
+                .iter()
+                .any(|package| package.meta.short_name == short_name.as_str())
+            {
+                bail!("Unknown package {short_name:?} in the configuration.");
+            }
+        }
+
+        packages
+            .into_iter()
+            .filter(|package| match &self.packages {
+                Some(short_names) => short_names
+                    .iter()
+                    .any(|short_name| package.meta.short_name == short_name.as_str()),
+                None => true,
+            })
+            .map(|package| {
+                let Some(config) = self.overrides.get(package.meta.short_name.as_ref()) else {
+                    return Ok(package);
+                };
+                let short_name = package.meta.short_name.to_string();
+                config
+                    .apply(package)
+                    .with_context(|| format!("Invalid configuration of package {short_name:?}"))
+            })
+            .collect()
+    }
+}
+
+impl PackageOverride {
+    fn apply(&self, package: Package<Unknown>) -> Result<Package<Unknown>> {
+        let package = match (&self.npm, self.target()?) {
+            (None, Some(target)) => package.with_target(target)?,
+            (None, None) => package,
+            (Some(name), _) => package.with_npm_tarball(name.clone(), self.npm_version()?)?,
+        };
+        let package = match &self.svg_dir {
+            Some(svg_dir) => package.with_svg_dir(svg_dir.clone()),
+            None => package,
+        };
+        let sizes = match &self.sizes {
+            Some(sizes) => Some(
+                sizes
+                    .iter()
+                    .map(|size| IconSize::from_str(&size.to_string()))
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+            None => None,
+        };
+        let category_renames = self
+            .rename_categories
+            .iter()
+            .map(|(upstream, name)| {
+                (
+                    upstream.clone(),
+                    Some(name.clone()).filter(|name| !name.is_empty()),
+                )
+            })
+            .collect();
+        Ok(package
+            .with_filter(IconFilter {
+                sizes,
+                categories: self.categories.clone(),
+                include: self.include.clone(),
+                exclude: self.exclude.clone(),
+            })
+            .with_category_renames(CategoryRenames(category_renames)))
+    }
+
+    /// The version of the npm tarball to download, which must be set.
+    fn npm_version(&self) -> Result<String> {
+        if self.tag.is_some() || self.branch.is_some() || self.commit.is_some() {
+            bail!("Set either `npm`, or a git ref.");
+        }
+        let Some(version) = &self.version else {
+            bail!("Set the `version` of the npm tarball.");
+        };
+        SemVer::from_str(version)?;
+        Ok(version.clone())
+    }
+
+    /// The upstream ref to check out, if overridden.
+    fn target(&self) -> Result<Option<GitTarget>> {
+        let version = self.version.as_deref().map(SemVer::from_str).transpose()?;
+        let target = match (&self.tag, &self.branch, &self.commit) {
+            (None, None, None) => return Ok(None),
+            (Some(tag), None, None) => GitTarget::Tag {
+                name: Cow::Owned(tag.clone()),
+                version: match version {
+                    Some(version) => version,
+                    None => SemVer::from_str(tag.trim_start_matches('v'))
+                        .context("The tag names no version, set `version`.")?,
+                },
+            },
+            (None, Some(branch), Some(commit)) => GitTarget::Branch {
+                name: Cow::Owned(branch.clone()),
+                commit_ref: Cow::Owned(commit.clone()),
+                version_hint: version,
+            },
+            _ => bail!("Set either `tag`, or `branch` and `commit`."),
+        };
+        Ok(Some(target))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::package::GitTarget;
+
+    use super::Config;
+
+    #[test]
+    fn parses_configuration() {
+        let config: Config = toml::from_str(
+            r#"
+            packages = ["fa", "lu"]
+
+            [package.fa]
+            tag = "v6.5.1"
+            categories = ["solid"]
+            svg_dir = "svgs-full"
+
+            [package.lu]
+            branch = "main"
+            commit = "0123abc"
+            sizes = [24]
+            exclude = ["*-flag"]
+
+            [package.lu.rename_categories]
+            ic = ""
+
+            [package.hi]
+            npm = "heroicons"
+            version = "2.1.5"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.packages.as_deref().map(<[_]>::len), Some(2));
+
+        let GitTarget::Tag { version, .. } = config.overrides["fa"].target().unwrap().unwrap()
+        else {
+            panic!("Expected a tag.");
+        };
+        assert_eq!(version.to_string(), "6.5.1");
+        assert_eq!(config.overrides["fa"].svg_dir.as_deref(), Some("svgs-full"));
+        assert_eq!(config.overrides["lu"].exclude, ["*-flag"]);
+        assert_eq!(config.overrides["lu"].rename_categories["ic"], "");
+        assert!(matches!(
+            config.overrides["lu"].target().unwrap(),
+            Some(GitTarget::Branch { .. })
+        ));
+
+        assert_eq!(config.overrides["hi"].npm_version().unwrap(), "2.1.5");
+
+        let config: Config = toml::from_str("[package.fa]\ntag = \"latest\"").unwrap();
+        assert!(config.overrides["fa"].target().is_err());
+        let config: Config =
+            toml::from_str("[package.hi]\nnpm = \"heroicons\"\ntag = \"v2.1.5\"").unwrap();
+        assert!(config.overrides["hi"].npm_version().is_err());
+        assert!(toml::from_str::<Config>("pakages = []").is_err());
+    }
+}
@@ -0,0 +1,141 @@
+//! Contact sheets of the packages: pages showing all icons of a package in a grid, each with its
+//! name, written as svg and png files.
+//!
+//! Upstream bumps change thousands of lines of path data, which no reviewer reads. Comparing the
+//! sheets generated before and after a bump shows the visual changes instead.
+
+use std::{fmt::Write, path::Path};
+
+use anyhow::{anyhow, Result};
+use icondata_core::{escape_attribute, truncate_label, RenderOptions};
+use resvg::{
+    tiny_skia::{Pixmap, Transform},
+    usvg::{self, fontdb, TreeParsing, TreeTextToPath},
+};
+use tracing::{info, instrument};
+
+use crate::{
+    dirs::write_to_file,
+    fidelity,
+    package::{Downloaded, Package},
+    Packages,
+};
+
+/// Space around every icon and the page, in pixels.
+const PADDING: u32 = 12;
+/// Height of the name below every icon and of the title of the page, in pixels.
+const LINE_HEIGHT: u32 = 14;
+
+/// Layout of the pages.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Layout {
+    pub columns: usize,
+    pub rows: usize,
+    /// Width and height of every icon, in pixels.
+    pub size: u32,
+}
+
+impl Layout {
+    fn cell_width(&self) -> u32 {
+        // Names are rarely shorter than the icons are wide.
+        (self.size * 2).max(96)
+    }
+
+    fn cell_height(&self) -> u32 {
+        self.size + LINE_HEIGHT + PADDING
+    }
+
+    fn icons_per_page(&self) -> usize {
+        (self.columns * self.rows).max(1)
+    }
+}
+
+/// Write the contact sheets of all packages to `out`, in a directory per package, as
+/// `<short name>-<page>.svg` and `.png`.
+#[instrument(level = "info")]
+pub(crate) async fn export(out: &Path, layout: Layout) -> Result<()> {
+    let mut fonts = fontdb::Database::new();
+    fonts.load_system_fonts();
+
+    for package in Packages::get()? {
+        let short_name = package.meta.short_name.as_ref();
+        let dir = out.join(short_name);
+        let pages = package.icons().len().div_ceil(layout.icons_per_page());
+        for page in 0..pages {
+            let svg = sheet(package, page, layout)?;
+            let png = rasterize(&svg, &fonts)?;
+            let name = format!("{short_name}-{:03}", page + 1);
+            write_to_file(&dir.join(format!("{name}.svg")), &svg).await?;
+            write_to_file(&dir.join(format!("{name}.png")), png).await?;
+        }
+        info!(package = short_name, pages, "Wrote contact sheets.");
+    }
+    Ok(())
+}
+
+/// The svg of the given page of the contact sheet of the package.
+fn sheet(package: &'static Package<Downloaded>, page: usize, layout: Layout) -> Result<String> {
+    let icons_per_page = layout.icons_per_page();
+    let num_pages = package.icons().len().div_ceil(icons_per_page);
+    let icons = package
+        .icons()
+        .chunks(icons_per_page)
+        .nth(page)
+        .unwrap_or_default();
+
+    let (cell_width, cell_height) = (layout.cell_width(), layout.cell_height());
+    let width = 2 * PADDING + layout.columns as u32 * cell_width;
+    let height =
+        2 * PADDING + LINE_HEIGHT + icons.len().div_ceil(layout.columns) as u32 * cell_height;
+    let max_chars = (cell_width / 6) as usize;
+    let size = layout.size.to_string();
+
+    let mut svg = String::new();
+    write!(
+        svg,
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" color="#111827" font-family="sans-serif"><rect width="100%" height="100%" fill="#fff" />"##
+    )?;
+    write!(
+        svg,
+        r##"<text x="{PADDING}" y="{}" font-size="12" font-weight="bold">{} {} ({}), page {} of {num_pages}</text>"##,
+        PADDING + LINE_HEIGHT - 3,
+        escape_attribute(&package.meta.package_name),
+        escape_attribute(package.meta.source.url()),
+        package.revision().unwrap_or("unknown revision"),
+        page + 1,
+    )?;
+    for (i, icon) in icons.iter().enumerate() {
+        let x = PADDING + (i % layout.columns) as u32 * cell_width;
+        let y = PADDING + LINE_HEIGHT + (i / layout.columns) as u32 * cell_height;
+        let icon_svg = fidelity::icon_data(icon).render(&RenderOptions {
+            width: Some(&size),
+            height: Some(&size),
+            ..Default::default()
+        });
+        write!(
+            svg,
+            r#"<g transform="translate({} {y})">{icon_svg}</g>"#,
+            x + (cell_width - layout.size) / 2
+        )?;
+        write!(
+            svg,
+            r##"<text x="{}" y="{}" font-size="10" text-anchor="middle" fill="#4b5563">{}</text>"##,
+            x + cell_width / 2,
+            y + layout.size + LINE_HEIGHT - 2,
+            escape_attribute(&truncate_label(&icon.name, max_chars))
+        )?;
+    }
+    svg.push_str("</svg>");
+    Ok(svg)
+}
+
+fn rasterize(svg: &str, fonts: &fontdb::Database) -> Result<Vec<u8>> {
+    let mut tree = usvg::Tree::from_str(svg, &usvg::Options::default())?;
+    tree.convert_text(fonts);
+    let tree = resvg::Tree::from_usvg(&tree);
+    let (width, height) = (tree.size.width() as u32, tree.size.height() as u32);
+    let mut pixmap =
+        Pixmap::new(width, height).ok_or(anyhow!("Invalid sheet size {width}x{height}."))?;
+    tree.render(Transform::default(), &mut pixmap.as_mut());
+    Ok(pixmap.encode_png()?)
+}
@@ -0,0 +1,436 @@
+//! Currencies by their ISO 4217 code, generated into the `currency` module of the main library as
+//! the `Currency` enum, for prices and wallets.
+//!
+//! Few currencies have a glyph in every package, so every variant lists icons of a few packages
+//! in order of preference, like [`crate::status`], and falls back to an icon drawing its symbol as
+//! text, which is always available.
+
+use anyhow::Result;
+
+use crate::migration::{self, PreferredIcon};
+
+/// A variant of the generated `Currency` enum.
+#[derive(Debug)]
+pub(crate) struct Currency {
+    /// ISO 4217 code, e.g. "EUR".
+    pub code: &'static str,
+    /// English name, e.g. "Euro".
+    pub name: &'static str,
+    /// Symbol drawn by the fallback icon, e.g. "€".
+    pub symbol: &'static str,
+    /// Names of the icons showing the currency, in order of preference.
+    pub icons: &'static [&'static str],
+}
+
+impl Currency {
+    /// Name of the variant, e.g. "Eur".
+    pub fn variant(&self) -> String {
+        let (first, rest) = self.code.split_at(1);
+        format!("{first}{}", rest.to_ascii_lowercase())
+    }
+
+    /// Font size of the symbol in the fallback icon of 24 by 24 pixels, shrinking longer symbols
+    /// like "CHF" to fit.
+    pub fn font_size(&self) -> u32 {
+        match self.symbol.chars().count() {
+            1 => 18,
+            2 => 13,
+            _ => 10,
+        }
+    }
+}
+
+const DOLLAR: &[&str] = &[
+    "LuDollarSign",
+    "TbCurrencyDollar",
+    "BsCurrencyDollar",
+    "FaDollarSignSolid",
+    "BiDollarRegular",
+];
+
+const CURRENCIES: &[Currency] = &[
+    Currency {
+        code: "USD",
+        name: "United States dollar",
+        symbol: "$",
+        icons: DOLLAR,
+    },
+    Currency {
+        code: "EUR",
+        name: "Euro",
+        symbol: "€",
+        icons: &[
+            "LuEuro",
+            "TbCurrencyEuro",
+            "BsCurrencyEuro",
+            "FaEuroSignSolid",
+            "BiEuroRegular",
+        ],
+    },
+    Currency {
+        code: "GBP",
+        name: "Pound sterling",
+        symbol: "£",
+        icons: &[
+            "LuPoundSterling",
+            "TbCurrencyPound",
+            "BsCurrencyPound",
+            "BiPoundRegular",
+        ],
+    },
+    Currency {
+        code: "JPY",
+        name: "Japanese yen",
+        symbol: "¥",
+        icons: &[
+            "LuJapaneseYen",
+            "TbCurrencyYen",
+            "BsCurrencyYen",
+            "FaYenSignSolid",
+            "BiYenRegular",
+        ],
+    },
+    Currency {
+        code: "CNY",
+        name: "Renminbi",
+        symbol: "¥",
+        icons: &["TbCurrencyYuan", "TbCurrencyRenminbi"],
+    },
+    Currency {
+        code: "INR",
+        name: "Indian rupee",
+        symbol: "₹",
+        icons: &[
+            "LuIndianRupee",
+            "TbCurrencyRupee",
+            "BsCurrencyRupee",
+            "FaIndianRupeeSignSolid",
+            "BiRupeeRegular",
+        ],
+    },
+    Currency {
+        code: "RUB",
+        name: "Russian ruble",
+        symbol: "₽",
+        icons: &[
+            "LuRussianRuble",
+            "TbCurrencyRubel",
+            "FaRubleSignSolid",
+            "BiRubleRegular",
+        ],
+    },
+    Currency {
+        code: "CHF",
+        name: "Swiss franc",
+        symbol: "CHF",
+        icons: &["LuSwissFranc", "TbCurrencyFrank", "FaFrancSignSolid"],
+    },
+    Currency {
+        code: "KRW",
+        name: "South Korean won",
+        symbol: "₩",
+        icons: &["TbCurrencyWon", "FaWonSignSolid", "BiWonRegular"],
+    },
+    Currency {
+        code: "TRY",
+        name: "Turkish lira",
+        symbol: "₺",
+        icons: &["TbCurrencyLira", "FaTurkishLiraSignSolid", "BiLiraRegular"],
+    },
+    Currency {
+        code: "ILS",
+        name: "Israeli new shekel",
+        symbol: "₪",
+        icons: &["TbCurrencyShekel", "FaShekelSignSolid", "BiShekelRegular"],
+    },
+    Currency {
+        code: "UAH",
+        name: "Ukrainian hryvnia",
+        symbol: "₴",
+        icons: &["TbCurrencyHryvnia", "FaHryvniaSignSolid"],
+    },
+    Currency {
+        code: "NGN",
+        name: "Nigerian naira",
+        symbol: "₦",
+        icons: &["TbCurrencyNaira", "FaNairaSignSolid"],
+    },
+    Currency {
+        code: "BRL",
+        name: "Brazilian real",
+        symbol: "R$",
+        icons: &["TbCurrencyReal", "FaBrazilianRealSignSolid"],
+    },
+    Currency {
+        code: "THB",
+        name: "Thai baht",
+        symbol: "฿",
+        icons: &["TbCurrencyBaht", "FaBahtSignSolid"],
+    },
+    Currency {
+        code: "VND",
+        name: "Vietnamese đồng",
+        symbol: "₫",
+        icons: &["TbCurrencyDong", "FaDongSignSolid"],
+    },
+    Currency {
+        code: "KZT",
+        name: "Kazakhstani tenge",
+        symbol: "₸",
+        icons: &["TbCurrencyTenge", "FaTengeSignSolid"],
+    },
+    Currency {
+        code: "PHP",
+        name: "Philippine peso",
+        symbol: "₱",
+        icons: &["TbCurrencyPeso", "FaPesoSignSolid"],
+    },
+    Currency {
+        code: "MXN",
+        name: "Mexican peso",
+        symbol: "$",
+        icons: DOLLAR,
+    },
+    Currency {
+        code: "AUD",
+        name: "Australian dollar",
+        symbol: "A$",
+        icons: &["TbCurrencyDollarAustralian"],
+    },
+    Currency {
+        code: "CAD",
+        name: "Canadian dollar",
+        symbol: "CA$",
+        icons: &["TbCurrencyDollarCanadian"],
+    },
+    Currency {
+        code: "SGD",
+        name: "Singapore dollar",
+        symbol: "S$",
+        icons: &["TbCurrencyDollarSingapore"],
+    },
+    Currency {
+        code: "BND",
+        name: "Brunei dollar",
+        symbol: "B$",
+        icons: &["TbCurrencyDollarBrunei"],
+    },
+    Currency {
+        code: "GYD",
+        name: "Guyanese dollar",
+        symbol: "G$",
+        icons: &["TbCurrencyDollarGuyanese"],
+    },
+    Currency {
+        code: "HKD",
+        name: "Hong Kong dollar",
+        symbol: "HK$",
+        icons: &[],
+    },
+    Currency {
+        code: "NZD",
+        name: "New Zealand dollar",
+        symbol: "NZ$",
+        icons: &[],
+    },
+    Currency {
+        code: "CZK",
+        name: "Czech koruna",
+        symbol: "Kč",
+        icons: &["TbCurrencyKroneCzech"],
+    },
+    Currency {
+        code: "DKK",
+        name: "Danish krone",
+        symbol: "kr",
+        icons: &["TbCurrencyKroneDanish"],
+    },
+    Currency {
+        code: "SEK",
+        name: "Swedish krona",
+        symbol: "kr",
+        icons: &["TbCurrencyKroneSwedish"],
+    },
+    Currency {
+        code: "NOK",
+        name: "Norwegian krone",
+        symbol: "kr",
+        icons: &[],
+    },
+    Currency {
+        code: "PLN",
+        name: "Polish złoty",
+        symbol: "zł",
+        icons: &["TbCurrencyZloty"],
+    },
+    Currency {
+        code: "HUF",
+        name: "Hungarian forint",
+        symbol: "Ft",
+        icons: &["TbCurrencyForint"],
+    },
+    Currency {
+        code: "RON",
+        name: "Romanian leu",
+        symbol: "lei",
+        icons: &["TbCurrencyLeu"],
+    },
+    Currency {
+        code: "GEL",
+        name: "Georgian lari",
+        symbol: "₾",
+        icons: &["TbCurrencyLari"],
+    },
+    Currency {
+        code: "AMD",
+        name: "Armenian dram",
+        symbol: "֏",
+        icons: &["TbCurrencyDram"],
+    },
+    Currency {
+        code: "AZN",
+        name: "Azerbaijani manat",
+        symbol: "₼",
+        icons: &["TbCurrencyManat"],
+    },
+    Currency {
+        code: "IRR",
+        name: "Iranian rial",
+        symbol: "﷼",
+        icons: &["TbCurrencyIranianRial"],
+    },
+    Currency {
+        code: "SAR",
+        name: "Saudi riyal",
+        symbol: "﷼",
+        icons: &["TbCurrencyRiyal"],
+    },
+    Currency {
+        code: "AED",
+        name: "United Arab Emirates dirham",
+        symbol: "AED",
+        icons: &["TbCurrencyDirham"],
+    },
+    Currency {
+        code: "BHD",
+        name: "Bahraini dinar",
+        symbol: "BD",
+        icons: &["TbCurrencyBahraini"],
+    },
+    Currency {
+        code: "LYD",
+        name: "Libyan dinar",
+        symbol: "LD",
+        icons: &["TbCurrencyLyd"],
+    },
+    Currency {
+        code: "AFN",
+        name: "Afghan afghani",
+        symbol: "؋",
+        icons: &["TbCurrencyAfghani"],
+    },
+    Currency {
+        code: "BDT",
+        name: "Bangladeshi taka",
+        symbol: "৳",
+        icons: &["TbCurrencyTaka"],
+    },
+    Currency {
+        code: "NPR",
+        name: "Nepalese rupee",
+        symbol: "रू",
+        icons: &["TbCurrencyRupeeNepalese"],
+    },
+    Currency {
+        code: "LAK",
+        name: "Lao kip",
+        symbol: "₭",
+        icons: &["TbCurrencyKip"],
+    },
+    Currency {
+        code: "MNT",
+        name: "Mongolian tögrög",
+        symbol: "₮",
+        icons: &["TbCurrencyTugrik"],
+    },
+    Currency {
+        code: "KGS",
+        name: "Kyrgyzstani som",
+        symbol: "с",
+        icons: &["TbCurrencySom"],
+    },
+    Currency {
+        code: "MVR",
+        name: "Maldivian rufiyaa",
+        symbol: "Rf",
+        icons: &["TbCurrencyRufiyaa"],
+    },
+    Currency {
+        code: "TOP",
+        name: "Tongan paʻanga",
+        symbol: "T$",
+        icons: &["TbCurrencyPaanga"],
+    },
+    Currency {
+        code: "PYG",
+        name: "Paraguayan guaraní",
+        symbol: "₲",
+        icons: &["TbCurrencyGuarani"],
+    },
+    Currency {
+        code: "GTQ",
+        name: "Guatemalan quetzal",
+        symbol: "Q",
+        icons: &["TbCurrencyQuetzal"],
+    },
+    Currency {
+        code: "AWG",
+        name: "Aruban florin",
+        symbol: "ƒ",
+        icons: &["TbCurrencyFlorin"],
+    },
+    Currency {
+        code: "IDR",
+        name: "Indonesian rupiah",
+        symbol: "Rp",
+        icons: &[],
+    },
+    Currency {
+        code: "ZAR",
+        name: "South African rand",
+        symbol: "R",
+        icons: &[],
+    },
+];
+
+/// All currencies, each with the icons of the current packages showing it.
+pub(crate) fn currencies() -> Result<Vec<(&'static Currency, Vec<PreferredIcon>)>> {
+    CURRENCIES
+        .iter()
+        .map(|currency| Ok((currency, migration::preferred_icons(currency.icons)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::CURRENCIES;
+
+    #[test]
+    fn currencies_are_unambiguous() {
+        let codes = CURRENCIES
+            .iter()
+            .map(|currency| currency.code)
+            .collect::<HashSet<_>>();
+        assert_eq!(codes.len(), CURRENCIES.len());
+        assert!(codes
+            .iter()
+            .all(|code| code.len() == 3 && code.chars().all(|c| c.is_ascii_uppercase())));
+
+        // Symbols are written into svg markup unescaped.
+        assert!(CURRENCIES
+            .iter()
+            .all(|currency| !currency.symbol.contains(['<', '>', '&', '"'])));
+    }
+}
@@ -0,0 +1,206 @@
+//! Images of the icons a generation would draw differently, for reviewing changes of the icon data
+//! in pull requests.
+//!
+//! Every icon is rendered as declared before and after the generation, side by side in a png file
+//! per icon, and `index.html` lists all of them by package, most changed first.
+
+use std::{fmt::Write, path::Path};
+
+use anyhow::Result;
+use icondata_core::{escape_attribute, IconData, IconPaint, RenderOptions};
+use resvg::tiny_skia::{Color, Pixmap, PixmapPaint, Transform};
+
+use super::DeclaredIcon;
+use crate::{dirs::write_to_file, fidelity};
+
+/// Width and height of both renders of an icon, in pixels.
+const SIZE: u32 = 96;
+/// Space around and between both renders, in pixels.
+const PADDING: u32 = 12;
+
+/// An icon drawn differently, see [`write`].
+#[derive(Debug)]
+pub(super) struct Comparison {
+    short_name: String,
+    /// Name before the generation.
+    from: String,
+    /// Name after the generation, which the image is named after.
+    to: String,
+    /// Difference of both renders, from 0 for identical renders to 1.
+    diff: f64,
+}
+
+impl Comparison {
+    fn image_path(&self) -> String {
+        format!("{}/{}.png", self.short_name, self.to)
+    }
+}
+
+/// Write an image of every icon of the package, given by its name and declaration before and
+/// after the generation, to `<short name>/<name after>.png` below `dir`.
+pub(super) async fn write<'b, 'a: 'b>(
+    dir: &Path,
+    short_name: &str,
+    icons: impl IntoIterator<Item = (&'b str, &'b DeclaredIcon<'a>, &'b str, &'b DeclaredIcon<'a>)>,
+) -> Result<Vec<Comparison>> {
+    let mut comparisons = Vec::new();
+    for (from, before, to, after) in icons {
+        let before = fidelity::rasterize(&render(from, before), SIZE)?;
+        let after = fidelity::rasterize(&render(to, after), SIZE)?;
+
+        let mut pixmap =
+            Pixmap::new(2 * SIZE + 3 * PADDING, SIZE + 2 * PADDING).expect("size is not zero");
+        pixmap.fill(Color::WHITE);
+        for (i, render) in [&before, &after].into_iter().enumerate() {
+            pixmap.draw_pixmap(
+                (PADDING + i as u32 * (SIZE + PADDING)) as i32,
+                PADDING as i32,
+                render.as_ref(),
+                &PixmapPaint::default(),
+                Transform::identity(),
+                None,
+            );
+        }
+
+        let comparison = Comparison {
+            short_name: short_name.to_owned(),
+            from: from.to_owned(),
+            to: to.to_owned(),
+            diff: fidelity::diff(&before, &after),
+        };
+        write_to_file(&dir.join(comparison.image_path()), pixmap.encode_png()?).await?;
+        comparisons.push(comparison);
+    }
+    Ok(comparisons)
+}
+
+/// Write `index.html` to `dir`, showing the images of all `comparisons` by package.
+pub(super) async fn write_index(dir: &Path, comparisons: &[Comparison]) -> Result<()> {
+    let mut comparisons = comparisons.iter().collect::<Vec<_>>();
+    comparisons.sort_by(|a, b| {
+        a.short_name
+            .cmp(&b.short_name)
+            .then(b.diff.total_cmp(&a.diff))
+    });
+
+    let mut html = String::new();
+    html.push_str(concat!(
+        r#"<!DOCTYPE html><html lang="en"><head><meta charset="utf-8"><title>Redrawn icons</title>"#,
+        "<style>body { font-family: sans-serif; } td { padding: 4px 12px; } ",
+        "td:last-child { text-align: right; }</style></head><body><h1>Redrawn icons</h1>"
+    ));
+    if comparisons.is_empty() {
+        html.push_str("<p>No icon is drawn differently.</p>");
+    }
+    let mut short_name = None;
+    for comparison in comparisons {
+        if short_name != Some(&comparison.short_name) {
+            if short_name.is_some() {
+                html.push_str("</table>");
+            }
+            short_name = Some(&comparison.short_name);
+            write!(
+                html,
+                "<h2>icondata_{}</h2><table><tr><th>Icon</th><th>Before and after</th><th>Difference</th></tr>",
+                escape_attribute(&comparison.short_name)
+            )?;
+        }
+        let name = match comparison.from == comparison.to {
+            true => format!("<code>{}</code>", comparison.to),
+            false => format!(
+                "<code>{}</code> renamed to <code>{}</code>",
+                comparison.from, comparison.to
+            ),
+        };
+        write!(
+            html,
+            r#"<tr><td>{name}</td><td><img src="{}" alt="{}" width="{}" height="{}"></td><td>{:.4}</td></tr>"#,
+            escape_attribute(&comparison.image_path()),
+            comparison.to,
+            2 * SIZE + 3 * PADDING,
+            SIZE + 2 * PADDING,
+            comparison.diff
+        )?;
+    }
+    if short_name.is_some() {
+        html.push_str("</table>");
+    }
+    html.push_str("</body></html>\n");
+    write_to_file(&dir.join("index.html"), html).await
+}
+
+/// The svg of the icon as declared.
+fn render(name: &str, icon: &DeclaredIcon<'_>) -> String {
+    let leak = |value: String| -> &'static str { Box::leak(value.into_boxed_str()) };
+    let attribute = |key| icon.attributes.get(key).map(|value| leak(unescape(value)));
+    let icon_data = IconData {
+        id: leak(name.to_owned()),
+        label: leak(name.to_owned()),
+        source: None,
+        paint: match icon.attributes.get("paint").copied() {
+            Some("Stroke") => IconPaint::Stroke,
+            Some("FillAndStroke") => IconPaint::FillAndStroke,
+            _ => IconPaint::Fill,
+        },
+        brand_color: attribute("brand_color"),
+        style: attribute("style"),
+        x: attribute("x"),
+        y: attribute("y"),
+        width: attribute("width"),
+        height: attribute("height"),
+        view_box: attribute("view_box"),
+        stroke_linecap: attribute("stroke_linecap"),
+        stroke_linejoin: attribute("stroke_linejoin"),
+        stroke_width: attribute("stroke_width"),
+        stroke: attribute("stroke"),
+        fill: attribute("fill"),
+        data: leak(icon.data.to_string()),
+    };
+    icon_data.render(&RenderOptions {
+        width: Some("1em"),
+        height: Some("1em"),
+        ..Default::default()
+    })
+}
+
+/// The string escaped by `Debug`, as which the generated crates declare attribute values.
+fn unescape(escaped: &str) -> String {
+    let mut unescaped = String::with_capacity(escaped.len());
+    let mut chars = escaped.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some('0') => unescaped.push('\0'),
+            Some('u') => {
+                let hex = chars
+                    .by_ref()
+                    .skip(1)
+                    .take_while(|c| *c != '}')
+                    .collect::<String>();
+                unescaped.extend(u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32));
+            }
+            Some(c) => unescaped.push(c),
+            None => {}
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::unescape;
+
+    #[test]
+    fn unescapes_debug_strings() {
+        for value in ["0 0 24 24", "fill: \"red\"; \\ \n", "\u{7f}\u{E000}"] {
+            let escaped = format!("{value:?}");
+            assert_eq!(unescape(&escaped[1..escaped.len() - 1]), value);
+        }
+    }
+}
@@ -0,0 +1,365 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+    path::Path,
+};
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use tracing::{info, instrument};
+
+use crate::{
+    dirs::{write_to_file, Library},
+    icon::geometry,
+};
+
+mod images;
+
+/// An icon as declared by the generated sources of an icon crate: its id, followed by the path of
+/// its upstream file, missing in crates generated before sources were recorded, its attributes
+/// and its data.
+static DECLARED_ICON: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(concat!(
+        r#"(?s)\bid: "(\w+)",\s*label: "(?:[^"\\]|\\.)*",\s*"#,
+        r#"(?:source: Some\([^)]*?\bpath: "([^"]*)",)?"#,
+        r####"(.*?)\bdata: r###"(.*?)"###"####,
+    ))
+    .expect("valid regex")
+});
+
+/// An attribute of a declared icon which is set, with its value as escaped by `Debug`, or its
+/// paint.
+static ATTRIBUTE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\b(\w+): (?:Some\("((?:[^"\\]|\\.)*)"\)|icondata_core::IconPaint::(\w+))"#)
+        .expect("valid regex")
+});
+
+/// The dictionary of a crate generated in compressed mode.
+static DICTIONARY: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?s)static DICTIONARY: &\[&str\] = &\[(.*?)\n\];"#).expect("valid regex")
+});
+
+static DICTIONARY_ENTRY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r####"(?s)r###"(.*?)"###,"####).expect("valid regex"));
+
+/// Log every file of the libraries which a generation would add, change or remove, without
+/// writing anything, followed by the icons added, removed and renamed per icon library. The icon
+/// changes are written to `out` as Markdown too, e.g. for release notes, and images of the icons
+/// drawn differently before and after to `images_dir`.
+#[instrument(level = "info", skip_all)]
+pub(crate) async fn run(
+    libraries: &[Library<'_>],
+    out: Option<&Path>,
+    images_dir: Option<&Path>,
+) -> Result<()> {
+    let mut num_files = 0;
+    let mut notes = String::new();
+    let mut comparisons = Vec::new();
+
+    for lib in libraries {
+        let files = lib.files()?;
+        let mut existing = Vec::new();
+        for (path, expected) in &files {
+            match tokio::fs::read_to_string(path).await {
+                Ok(actual) if actual == *expected => {
+                    existing.push(actual);
+                    continue;
+                }
+                Ok(actual) => {
+                    let (added, removed) = line_changes(&actual, expected);
+                    info!(?path, added, removed, "Changed.");
+                    existing.push(actual);
+                }
+                Err(_) => info!(?path, added = expected.lines().count(), "Added."),
+            }
+            num_files += 1;
+        }
+
+        if let Some(src_dir) = lib.src_dir() {
+            for chunk in src_dir.existing_chunks().await? {
+                if !files.iter().any(|(path, _)| *path == chunk) {
+                    info!(path = ?chunk, "Removed.");
+                    existing.push(tokio::fs::read_to_string(&chunk).await?);
+                    num_files += 1;
+                }
+            }
+        }
+
+        if let Some(short_name) = lib.short_name() {
+            let old = declared_icons(existing.iter().map(String::as_str));
+            let new = declared_icons(files.iter().map(|(_, contents)| contents.as_str()));
+            let changes = icon_changes(&old, &new);
+            for icon in &changes.added {
+                info!(package = short_name, icon, "Icon added.");
+            }
+            for icon in &changes.removed {
+                info!(package = short_name, icon, "Icon removed.");
+            }
+            for (from, to) in &changes.renamed {
+                info!(package = short_name, from, to, "Icon renamed.");
+            }
+            info!(
+                package = short_name,
+                changed = changes.changed.len(),
+                reformatted = changes.reformatted.len(),
+                "Icons drawn differently or only formatted differently."
+            );
+            changes.write_notes(&mut notes, short_name)?;
+
+            if let Some(images_dir) = images_dir {
+                let redrawn = changes
+                    .changed
+                    .iter()
+                    .map(|name| (*name, *name))
+                    .chain(changes.renamed.iter().copied())
+                    .filter(|(from, to)| !old[from].draws_like(&new[to]))
+                    .map(|(from, to)| (from, &old[from], to, &new[to]));
+                comparisons.extend(images::write(images_dir, short_name, redrawn).await?);
+            }
+        }
+    }
+
+    info!(num_files, "Compared the libraries with a fresh generation.");
+    if let Some(out) = out {
+        write_to_file(&out.to_path_buf(), notes).await?;
+        info!(?out, "Wrote icon changes.");
+    }
+    if let Some(images_dir) = images_dir {
+        images::write_index(images_dir, &comparisons).await?;
+        info!(
+            ?images_dir,
+            images = comparisons.len(),
+            "Wrote images of redrawn icons."
+        );
+    }
+    Ok(())
+}
+
+/// Number of lines added to and removed from `old` to get `new`, regardless of their order.
+fn line_changes(old: &str, new: &str) -> (usize, usize) {
+    let mut counts = HashMap::<&str, isize>::new();
+    for line in old.lines() {
+        *counts.entry(line).or_default() -= 1;
+    }
+    for line in new.lines() {
+        *counts.entry(line).or_default() += 1;
+    }
+    counts
+        .values()
+        .fold((0, 0), |(added, removed), &count| match count > 0 {
+            true => (added + count.unsigned_abs(), removed),
+            false => (added, removed + count.unsigned_abs()),
+        })
+}
+
+/// An icon declared by generated sources, see [`declared_icons`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DeclaredIcon<'a> {
+    /// Path of the upstream file, relative to the repository of the package, empty if unknown.
+    path: &'a str,
+    /// Attributes which are set, like `view_box` or `paint`, with their values as escaped by
+    /// `Debug`.
+    attributes: BTreeMap<&'a str, &'a str>,
+    /// Svg content, decoded if the crate was generated in compressed mode.
+    data: Cow<'a, str>,
+}
+
+impl DeclaredIcon<'_> {
+    /// Whether both icons draw the same shapes, even if their data is formatted differently, see
+    /// [`geometry::same_geometry`].
+    fn draws_like(&self, other: &DeclaredIcon<'_>) -> bool {
+        self.attributes == other.attributes
+            && (self.data == other.data
+                || geometry::same_geometry(
+                    &self.data,
+                    &other.data,
+                    self.attributes.get("view_box").copied(),
+                ))
+    }
+}
+
+/// The icons declared by the given generated sources, by name.
+fn declared_icons<'a>(
+    sources: impl IntoIterator<Item = &'a str>,
+) -> BTreeMap<&'a str, DeclaredIcon<'a>> {
+    let sources = sources.into_iter().collect::<Vec<_>>();
+    let dictionary = sources
+        .iter()
+        .find_map(|source| DICTIONARY.captures(source))
+        .map(|captures| {
+            DICTIONARY_ENTRY
+                .captures_iter(captures.get(1).map_or("", |m| m.as_str()))
+                .filter_map(|entry| entry.get(1).map(|m| m.as_str()))
+                .collect::<Vec<_>>()
+        });
+
+    sources
+        .into_iter()
+        .flat_map(|source| DECLARED_ICON.captures_iter(source))
+        .map(|captures| {
+            let capture = |i| captures.get(i).map_or("", |m| m.as_str());
+            let attributes = ATTRIBUTE
+                .captures_iter(capture(3))
+                .filter_map(|attribute| {
+                    let value = attribute.get(2).or(attribute.get(3))?;
+                    Some((attribute.get(1)?.as_str(), value.as_str()))
+                })
+                .collect();
+            let data = match &dictionary {
+                Some(dictionary) => Cow::Owned(icondata_core::decode(capture(4), dictionary)),
+                None => Cow::Borrowed(capture(4)),
+            };
+            let icon = DeclaredIcon {
+                path: capture(2),
+                attributes,
+                data,
+            };
+            (capture(1), icon)
+        })
+        .collect()
+}
+
+/// Icons added, removed, renamed and drawn differently between two generations of an icon crate,
+/// each sorted by name.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct IconChanges<'a> {
+    added: Vec<&'a str>,
+    removed: Vec<&'a str>,
+    /// Old and new names of icons declared under another name, read from the same upstream file or
+    /// drawing the same shapes.
+    renamed: Vec<(&'a str, &'a str)>,
+    /// Icons keeping their name which are drawn differently.
+    changed: Vec<&'a str>,
+    /// Icons keeping their name and shapes whose data is formatted differently, e.g. after changes
+    /// of the optimizer.
+    reformatted: Vec<&'a str>,
+}
+
+fn icon_changes<'a>(
+    old: &BTreeMap<&'a str, DeclaredIcon<'a>>,
+    new: &BTreeMap<&'a str, DeclaredIcon<'a>>,
+) -> IconChanges<'a> {
+    let mut added = new
+        .keys()
+        .filter(|name| !old.contains_key(*name))
+        .copied()
+        .collect::<Vec<_>>();
+    let mut changes = IconChanges::default();
+    for (name, icon) in old {
+        if let Some(new_icon) = new.get(name) {
+            if !icon.draws_like(new_icon) {
+                changes.changed.push(name);
+            } else if icon.data != new_icon.data {
+                changes.reformatted.push(name);
+            }
+            continue;
+        }
+        let renamed_to = added
+            .iter()
+            .position(|added| !icon.path.is_empty() && new[added].path == icon.path)
+            .or_else(|| added.iter().position(|added| new[added].draws_like(icon)));
+        match renamed_to {
+            Some(i) => changes.renamed.push((*name, added.remove(i))),
+            None => changes.removed.push(name),
+        }
+    }
+    changes.added = added;
+    changes
+}
+
+impl IconChanges<'_> {
+    /// Whether no icons were added, removed or renamed, regardless of icons drawn differently.
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.renamed.is_empty()
+    }
+
+    /// Append the changes of the names to the Markdown `notes`, in a section of the package, if
+    /// there are any.
+    fn write_notes(&self, notes: &mut String, short_name: &str) -> std::fmt::Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+        writeln!(notes, "## icondata_{short_name}\n")?;
+        let names = |names: &[&str]| {
+            names
+                .iter()
+                .map(|name| format!("`{name}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        if !self.added.is_empty() {
+            writeln!(notes, "- Added: {}", names(&self.added))?;
+        }
+        if !self.removed.is_empty() {
+            writeln!(notes, "- Removed: {}", names(&self.removed))?;
+        }
+        for (from, to) in &self.renamed {
+            writeln!(notes, "- Renamed `{from}` to `{to}`")?;
+        }
+        writeln!(notes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{declared_icons, icon_changes, line_changes, IconChanges};
+
+    #[test]
+    fn counts_changed_lines() {
+        assert_eq!(line_changes("a\nb\nc\n", "a\nb\nc\n"), (0, 0));
+        assert_eq!(line_changes("a\nb\n", "b\na\nd\nd\n"), (2, 0));
+        assert_eq!(line_changes("a\nb\nb\n", "c\n"), (1, 3));
+    }
+
+    fn icon(name: &str, path: &str, data: &str) -> String {
+        format!(
+            "pub static {name}: &icondata_core::IconData = &icondata_core::IconData {{\n    id: \"{name}\",\n    label: \"{name}\",\n    source: Some(icondata_core::IconSource {{\n        repository: SOURCE_REPOSITORY,\n        revision: SOURCE_REVISION,\n        path: \"{path}\",\n    }}),\n    paint: icondata_core::IconPaint::Stroke,\n    style: None,\n    view_box: Some(\"0 0 24 24\"),\n    data: r###\"{data}\"###\n}};\n"
+        )
+    }
+
+    #[test]
+    fn finds_icon_changes() {
+        let old = [
+            icon("LuHome", "icons/home.svg", "<path d=\"M1 1h1\" />"),
+            icon("LuEdit", "icons/edit.svg", "<path d=\"M2 2h1\" />"),
+            icon("LuTrash", "icons/trash.svg", "<path d=\"M3 3h1\" />"),
+            icon("LuZap", "icons/zap.svg", "<path d=\"M4 4h1\" />"),
+            icon("LuSun", "icons/sun.svg", "<path d=\"M1 1h2\" />"),
+        ]
+        .concat();
+        let new = [
+            icon("LuHouse", "icons/house.svg", "<path d=\"M1 1h1\" />"),
+            icon("LuPencil", "icons/edit.svg", "<path d=\"M5 5h1\" />"),
+            icon("LuTrash", "icons/trash.svg", "<path d=\"M6 6h1\" />"),
+            icon("LuAnchor", "icons/anchor.svg", "<path d=\"M7 7h1\" />"),
+            icon("LuSun", "icons/sun.svg", "<path d=\"M1,1 L3,1\"/>"),
+        ]
+        .concat();
+
+        let old = declared_icons([old.as_str()]);
+        let new = declared_icons([new.as_str()]);
+        assert_eq!(old.len(), 5);
+        assert_eq!(
+            icon_changes(&old, &new),
+            IconChanges {
+                added: vec!["LuAnchor"],
+                removed: vec!["LuZap"],
+                renamed: vec![("LuEdit", "LuPencil"), ("LuHome", "LuHouse")],
+                changed: vec!["LuTrash"],
+                reformatted: vec!["LuSun"],
+            }
+        );
+        assert_eq!(new["LuTrash"].attributes["view_box"], "0 0 24 24");
+        assert_eq!(new["LuTrash"].attributes["paint"], "Stroke");
+    }
+
+    #[test]
+    fn decodes_compressed_icons() {
+        let lib_rs = "static DICTIONARY: &[&str] = &[\n    r###\"<path d=\"###,\n    r###\"\" />\"###,\n];\n";
+        let icons = icon("LuHome", "icons/home.svg", "\u{E000}M1\u{E001}");
+        let icons = declared_icons([lib_rs, icons.as_str()]);
+        assert_eq!(icons["LuHome"].data, "<path d=\"M1\" />");
+    }
+}
@@ -1,19 +1,53 @@
-use std::path::PathBuf;
+use std::{
+    fmt,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
 
 use anyhow::Result;
 use tokio::io::AsyncWriteExt;
 use tracing::{error, instrument, trace};
 
 use crate::{
-    fs::{cargo_toml::CargoToml, lib_rs::LibRs, readme_md::Readme},
+    bundle::Bundle,
+    compress::Dictionary,
+    fs::{
+        cargo_toml::CargoToml, currency_rs::CurrencyRs, file_types_rs::FileTypesRs,
+        keys_rs::KeysRs, lib_rs::LibRs, migrate_rs::MigrateRs, readme_md::Readme,
+        social_rs::SocialRs, spinners_rs::SpinnersRs, src_dir::SrcDir, status_rs::StatusRs,
+        user_agent_rs::UserAgentRs, weather_rs::WeatherRs,
+    },
     package::{Downloaded, Package},
 };
 
 #[derive(Debug)]
 pub struct Library<'a> {
+    /// Directory of the generated crate.
+    dir: PathBuf,
     cargo_toml: Option<CargoToml>,
     lib_rs: Option<LibRs>,
+    /// Only generated for the main library.
+    migrate_rs: Option<MigrateRs>,
+    /// Only generated for the main library.
+    spinners_rs: Option<SpinnersRs>,
+    /// Only generated for the main library.
+    keys_rs: Option<KeysRs>,
+    /// Only generated for the main library.
+    social_rs: Option<SocialRs>,
+    /// Only generated for the main library.
+    user_agent_rs: Option<UserAgentRs>,
+    /// Only generated for the main library.
+    file_types_rs: Option<FileTypesRs>,
+    /// Only generated for the main library.
+    status_rs: Option<StatusRs>,
+    /// Only generated for the main library.
+    weather_rs: Option<WeatherRs>,
+    /// Only generated for the main library.
+    currency_rs: Option<CurrencyRs>,
     readme: Option<Readme>,
+    src_dir: Option<SrcDir>,
+    /// Whether icon libraries are generated in compressed mode, see [`Library::with_compression`].
+    compressed: bool,
     ty: LibType<'a>,
 }
 
@@ -22,61 +56,288 @@ impl<'a> Library<'a> {
         let cargo_path = path.join("Cargo.toml");
         let lib_rs_path = path.join("src/lib.rs");
         let readme_path = path.join("README.md");
+        let src_path = path.join("src");
+        let dir = path.clone();
         match &ty {
             LibType::IconLib(_) => Library {
+                dir,
                 cargo_toml: Some(CargoToml { path: cargo_path }),
                 lib_rs: Some(LibRs { path: lib_rs_path }),
+                migrate_rs: None,
+                spinners_rs: None,
+                keys_rs: None,
+                social_rs: None,
+                user_agent_rs: None,
+                file_types_rs: None,
+                status_rs: None,
+                weather_rs: None,
+                currency_rs: None,
                 readme: Some(Readme { path: readme_path }),
+                src_dir: Some(SrcDir {
+                    path: src_path,
+                    chunks: 1,
+                    dictionary: None,
+                }),
+                compressed: false,
                 ty,
             },
 
             LibType::MainLib => {
+                let migrate_rs_path = path.join("src/migrate.rs");
+                let spinners_rs_path = path.join("src/spinners.rs");
+                let keys_rs_path = path.join("src/keys.rs");
+                let social_rs_path = path.join("src/social.rs");
+                let user_agent_rs_path = path.join("src/user_agent.rs");
+                let file_types_rs_path = path.join("src/file_types.rs");
+                let status_rs_path = path.join("src/status.rs");
+                let weather_rs_path = path.join("src/weather.rs");
+                let currency_rs_path = path.join("src/currency.rs");
                 path.pop();
                 let readme_path = path.join("README.md");
 
                 Library {
+                    dir,
                     cargo_toml: Some(CargoToml { path: cargo_path }),
                     lib_rs: Some(LibRs { path: lib_rs_path }),
+                    migrate_rs: Some(MigrateRs {
+                        path: migrate_rs_path,
+                    }),
+                    spinners_rs: Some(SpinnersRs {
+                        path: spinners_rs_path,
+                    }),
+                    keys_rs: Some(KeysRs { path: keys_rs_path }),
+                    social_rs: Some(SocialRs {
+                        path: social_rs_path,
+                    }),
+                    user_agent_rs: Some(UserAgentRs {
+                        path: user_agent_rs_path,
+                    }),
+                    file_types_rs: Some(FileTypesRs {
+                        path: file_types_rs_path,
+                    }),
+                    status_rs: Some(StatusRs {
+                        path: status_rs_path,
+                    }),
+                    weather_rs: Some(WeatherRs {
+                        path: weather_rs_path,
+                    }),
+                    currency_rs: Some(CurrencyRs {
+                        path: currency_rs_path,
+                    }),
                     readme: Some(Readme { path: readme_path }),
+                    src_dir: None,
+                    compressed: false,
                     ty,
                 }
             }
 
+            LibType::Bundle(_) => Library {
+                dir,
+                cargo_toml: Some(CargoToml { path: cargo_path }),
+                lib_rs: Some(LibRs { path: lib_rs_path }),
+                migrate_rs: None,
+                spinners_rs: None,
+                keys_rs: None,
+                social_rs: None,
+                user_agent_rs: None,
+                file_types_rs: None,
+                status_rs: None,
+                weather_rs: None,
+                currency_rs: None,
+                readme: Some(Readme { path: readme_path }),
+                src_dir: None,
+                compressed: false,
+                ty,
+            },
+
             LibType::IconIndex => Library {
+                dir,
                 cargo_toml: None,
                 lib_rs: Some(LibRs { path: lib_rs_path }),
+                migrate_rs: None,
+                spinners_rs: None,
+                keys_rs: None,
+                social_rs: None,
+                user_agent_rs: None,
+                file_types_rs: None,
+                status_rs: None,
+                weather_rs: None,
+                currency_rs: None,
                 ty,
                 readme: None,
+                src_dir: None,
+                compressed: false,
             },
         }
     }
 
-    pub async fn generate(&self) -> Result<()> {
+    /// Sets the number of source files icons are split into. Only affects icon libraries.
+    pub fn with_chunks(mut self, chunks: usize) -> Self {
+        if let Some(src_dir) = &mut self.src_dir {
+            src_dir.chunks = chunks;
+        }
+        self
+    }
+
+    /// Generates icons as references into a dictionary of substrings shared by the icons of each
+    /// icon library, decoded on first use, see [`Dictionary`]. Aliases referring to icons, like
+    /// those of the main library, are generated to match.
+    pub fn with_compression(mut self, compressed: bool) -> Self {
+        self.compressed = compressed;
+        if let (true, LibType::IconLib(pkg), Some(src_dir)) =
+            (compressed, &self.ty, &mut self.src_dir)
+        {
+            src_dir.dictionary = Some(Dictionary::build(
+                pkg.icons().iter().map(|icon| icon.svg.content.as_str()),
+            ));
+        }
+        self
+    }
+
+    /// Directory of the generated crate.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Package of an icon library.
+    pub fn package(&self) -> Option<&'a Package<Downloaded>> {
+        match self.ty {
+            LibType::IconLib(package) => Some(package),
+            _ => None,
+        }
+    }
+
+    /// Short name of the package of an icon library.
+    pub fn short_name(&self) -> Option<&str> {
+        match &self.ty {
+            LibType::IconLib(package) => Some(package.meta.short_name.as_ref()),
+            _ => None,
+        }
+    }
+
+    pub fn src_dir(&self) -> Option<&SrcDir> {
+        self.src_dir.as_ref()
+    }
+
+    /// Renders all files of this library, returning their full paths and contents.
+    pub fn files(&self) -> Result<Vec<(PathBuf, String)>> {
+        let mut files = self
+            .renderers()
+            .into_iter()
+            .map(|(path, render)| {
+                let mut contents = String::new();
+                render(&mut contents)?;
+                Ok((path, contents))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if let Some(src_dir) = &self.src_dir {
+            files.extend(src_dir.contents(&self.ty)?);
+        };
+        Ok(files)
+    }
+
+    /// All files of this library except for the source chunks, with a function rendering the
+    /// contents of each.
+    fn renderers(&self) -> Vec<(PathBuf, Render<'_>)> {
+        let compressed = self.compressed;
+        let mut files = Vec::<(PathBuf, Render<'_>)>::new();
         if let Some(cargo_toml) = &self.cargo_toml {
-            let contents = CargoToml::contents(&self.ty)?;
-            write_to_file(&cargo_toml.path, contents).await?;
+            let render = move |out: &mut dyn fmt::Write| {
+                write_str(out, CargoToml::contents(&self.ty, compressed)?)
+            };
+            files.push((cargo_toml.path.clone(), Box::new(render)));
         };
         if let Some(lib_rs) = &self.lib_rs {
-            let contents = LibRs::contents(&self.ty)?;
-            write_to_file(&lib_rs.path, contents).await?;
+            // The largest file of bundles, the icon index and compressed icon libraries, rendered
+            // right into the file.
+            let render =
+                |out: &mut dyn fmt::Write| LibRs::write(&self.ty, self.src_dir.as_ref(), out);
+            files.push((lib_rs.path.clone(), Box::new(render)));
+        };
+        if let Some(migrate_rs) = &self.migrate_rs {
+            let render =
+                move |out: &mut dyn fmt::Write| write_str(out, MigrateRs::contents(compressed)?);
+            files.push((migrate_rs.path.clone(), Box::new(render)));
+        };
+        if let Some(spinners_rs) = &self.spinners_rs {
+            let render =
+                move |out: &mut dyn fmt::Write| write_str(out, SpinnersRs::contents(compressed)?);
+            files.push((spinners_rs.path.clone(), Box::new(render)));
+        };
+        if let Some(keys_rs) = &self.keys_rs {
+            let render =
+                move |out: &mut dyn fmt::Write| write_str(out, KeysRs::contents(compressed)?);
+            files.push((keys_rs.path.clone(), Box::new(render)));
+        };
+        if let Some(social_rs) = &self.social_rs {
+            let render =
+                move |out: &mut dyn fmt::Write| write_str(out, SocialRs::contents(compressed)?);
+            files.push((social_rs.path.clone(), Box::new(render)));
+        };
+        if let Some(user_agent_rs) = &self.user_agent_rs {
+            let render =
+                move |out: &mut dyn fmt::Write| write_str(out, UserAgentRs::contents(compressed)?);
+            files.push((user_agent_rs.path.clone(), Box::new(render)));
+        };
+        if let Some(file_types_rs) = &self.file_types_rs {
+            let render =
+                move |out: &mut dyn fmt::Write| write_str(out, FileTypesRs::contents(compressed)?);
+            files.push((file_types_rs.path.clone(), Box::new(render)));
+        };
+        if let Some(status_rs) = &self.status_rs {
+            let render =
+                move |out: &mut dyn fmt::Write| write_str(out, StatusRs::contents(compressed)?);
+            files.push((status_rs.path.clone(), Box::new(render)));
+        };
+        if let Some(weather_rs) = &self.weather_rs {
+            let render =
+                move |out: &mut dyn fmt::Write| write_str(out, WeatherRs::contents(compressed)?);
+            files.push((weather_rs.path.clone(), Box::new(render)));
+        };
+        if let Some(currency_rs) = &self.currency_rs {
+            let render =
+                move |out: &mut dyn fmt::Write| write_str(out, CurrencyRs::contents(compressed)?);
+            files.push((currency_rs.path.clone(), Box::new(render)));
         };
         if let Some(readme) = &self.readme {
-            let contents = Readme::contents(&self.ty)?;
-            write_to_file(&readme.path, contents).await?;
+            let render = |out: &mut dyn fmt::Write| write_str(out, Readme::contents(&self.ty)?);
+            files.push((readme.path.clone(), Box::new(render)));
+        };
+        files
+    }
+
+    /// Writes all files of this library, each one while it is rendered, so that neither the
+    /// contents of all files nor those of the largest ones are held in memory at once.
+    pub async fn generate(&self) -> Result<()> {
+        if let Some(src_dir) = &self.src_dir {
+            src_dir.remove_chunks().await?;
+            src_dir.generate(&self.ty).await?;
         };
+        for (path, render) in self.renderers() {
+            write_rendered(&path, render).await?;
+        }
         Ok(())
     }
 }
 
+/// Renders the contents of a file into the given writer.
+type Render<'a> = Box<dyn Fn(&mut dyn fmt::Write) -> Result<()> + 'a>;
+
+fn write_str(out: &mut dyn fmt::Write, contents: String) -> Result<()> {
+    Ok(out.write_str(&contents)?)
+}
+
 #[derive(Debug)]
 pub enum LibType<'a> {
     IconLib(&'a Package<Downloaded>),
     MainLib,
     IconIndex,
+    /// A curated bundle of icons across packages, see [`Bundle`].
+    Bundle(&'a Bundle),
 }
 
 #[instrument(level = "info", skip(contents))]
-pub async fn write_to_file(path: &PathBuf, contents: String) -> Result<()> {
+pub async fn write_to_file(path: &PathBuf, contents: impl AsRef<[u8]>) -> Result<()> {
     trace!(?path, "Making sure full path exists.");
     tokio::fs::create_dir_all(path.parent().unwrap()).await?;
 
@@ -92,10 +353,58 @@ pub async fn write_to_file(path: &PathBuf, contents: String) -> Result<()> {
         .await?;
 
     trace!(?path, "Writing contents to file.");
-    file.write_all(contents.as_bytes()).await?;
+    file.write_all(contents.as_ref()).await?;
     file.flush().await.map_err(|err| {
         error!(?err, "Could not flush file.");
         err
     })?;
     Ok(())
 }
+
+/// Writes rendered text to a file through a buffer, keeping the error of the file, which
+/// `fmt::Write` cannot return.
+struct FileWriter {
+    file: BufWriter<std::fs::File>,
+    error: Option<io::Error>,
+}
+
+impl fmt::Write for FileWriter {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        self.file.write_all(text.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
+
+/// Like [`write_to_file`], but writes the contents while `render` renders them, instead of
+/// rendering all of them into memory first.
+#[instrument(level = "info", skip(render))]
+pub async fn write_rendered(
+    path: &PathBuf,
+    render: impl FnOnce(&mut dyn fmt::Write) -> Result<()>,
+) -> Result<()> {
+    trace!(?path, "Making sure full path exists.");
+    tokio::fs::create_dir_all(path.parent().unwrap()).await?;
+
+    if path.exists() {
+        trace!("Removing existing file.");
+        tokio::fs::remove_file(&path).await?;
+    }
+
+    let mut writer = FileWriter {
+        file: BufWriter::new(std::fs::File::create(path)?),
+        error: None,
+    };
+
+    trace!(?path, "Writing rendered contents to file.");
+    if let Err(err) = render(&mut writer) {
+        // The error of the file is more telling than the formatting error it caused.
+        return Err(writer.error.map_or(err, Into::into));
+    }
+    writer.file.flush().map_err(|err| {
+        error!(?err, "Could not flush file.");
+        err
+    })?;
+    Ok(())
+}
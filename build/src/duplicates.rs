@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+use heck::ToPascalCase;
+use tracing::warn;
+
+/// How an icon is handled whose name is already declared by another package. The main library
+/// re-exports all icon crates, so equally named icons would be ambiguous.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub(crate) enum DuplicatePolicy {
+    /// Fail the build, listing all duplicates of the package.
+    #[default]
+    Error,
+    /// Append the short name of its package to the name of the duplicate, e.g. `FaHouseFa5`.
+    Suffix,
+    /// Leave out the duplicate.
+    Skip,
+}
+
+/// What becomes of an icon of a package, see [`DuplicateNames::resolve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Resolution {
+    Keep,
+    Rename(String),
+    Skip,
+}
+
+/// Icon names declared by the packages resolved so far, with the short name of their package.
+#[derive(Debug, Default)]
+pub(crate) struct DuplicateNames {
+    declared: HashMap<String, String>,
+}
+
+impl DuplicateNames {
+    /// Resolve the icon names of the package `short_name` which were declared by a previous package
+    /// according to `policy`, and declare the resulting names and the `aliases` of the package.
+    /// Returns the resolution of every name, in order.
+    pub(crate) fn resolve<'a>(
+        &mut self,
+        short_name: &str,
+        names: &[&str],
+        aliases: impl IntoIterator<Item = &'a str>,
+        policy: DuplicatePolicy,
+    ) -> Result<Vec<Resolution>> {
+        let duplicates = names
+            .iter()
+            .filter_map(|name| Some((*name, self.declared.get(*name)?)))
+            .map(|(name, owner)| format!("{name} (declared by {owner})"))
+            .collect::<Vec<_>>();
+        if policy == DuplicatePolicy::Error && !duplicates.is_empty() {
+            bail!(
+                "Icons of package {short_name} are already declared by other packages: {}. Use `--duplicates suffix` or `--duplicates skip` to resolve them.",
+                duplicates.join(", ")
+            );
+        }
+
+        let mut resolutions = Vec::with_capacity(names.len());
+        for name in names {
+            let Some(owner) = self.declared.get(*name).cloned() else {
+                resolutions.push(Resolution::Keep);
+                continue;
+            };
+            let resolution = match policy {
+                DuplicatePolicy::Error | DuplicatePolicy::Skip => Resolution::Skip,
+                DuplicatePolicy::Suffix => {
+                    let suffixed = format!("{name}{}", short_name.to_pascal_case());
+                    let renamed = (1..)
+                        .map(|i| match i {
+                            1 => suffixed.clone(),
+                            i => format!("{suffixed}{i}"),
+                        })
+                        .find(|candidate| {
+                            !self.declared.contains_key(candidate)
+                                && !names.contains(&candidate.as_str())
+                        })
+                        .expect("unbounded candidates");
+                    Resolution::Rename(renamed)
+                }
+            };
+            warn!(
+                name,
+                package = short_name,
+                owner,
+                ?resolution,
+                "Icon name is already declared by another package."
+            );
+            resolutions.push(resolution);
+        }
+
+        for (name, resolution) in names.iter().zip(&resolutions) {
+            let declared = match resolution {
+                Resolution::Keep => name.to_string(),
+                Resolution::Rename(renamed) => renamed.clone(),
+                Resolution::Skip => continue,
+            };
+            self.declared.insert(declared, short_name.to_owned());
+        }
+        for alias in aliases {
+            self.declared
+                .entry(alias.to_owned())
+                .or_insert_with(|| short_name.to_owned());
+        }
+        Ok(resolutions)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DuplicateNames, DuplicatePolicy, Resolution};
+
+    #[test]
+    fn resolves_names_declared_by_previous_packages() {
+        let resolve = |policy| {
+            let mut names = DuplicateNames::default();
+            names
+                .resolve("fa", &["FaHouse", "FaUser"], ["FaHome"], policy)
+                .unwrap();
+            names.resolve("fa5", &["FaHouse", "FaHome", "Fa5User"], [], policy)
+        };
+
+        assert!(resolve(DuplicatePolicy::Error).is_err());
+        assert_eq!(
+            resolve(DuplicatePolicy::Suffix).unwrap(),
+            [
+                Resolution::Rename("FaHouseFa5".to_owned()),
+                Resolution::Rename("FaHomeFa5".to_owned()),
+                Resolution::Keep
+            ]
+        );
+        assert_eq!(
+            resolve(DuplicatePolicy::Skip).unwrap(),
+            [Resolution::Skip, Resolution::Skip, Resolution::Keep]
+        );
+    }
+}
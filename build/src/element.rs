@@ -0,0 +1,33 @@
+use std::path::Path;
+
+use anyhow::Result;
+use miniz_oxide::deflate::compress_to_vec;
+use tracing::{info, instrument};
+
+use crate::{catalog, dirs::write_to_file, path, wasm_pack};
+
+/// File name of the compressed catalog next to the WASM package.
+const CATALOG_FILE: &str = "icondata.catalog";
+
+/// Write the `<icon-data>` web component of the `icondata_element` crate to `out`, together with
+/// the deflate compressed catalog of all icons it renders from.
+#[instrument(level = "info")]
+pub(crate) async fn export(out: &Path) -> Result<()> {
+    let bytes = catalog::catalog()?.to_bytes();
+    let compressed = compress_to_vec(&bytes, 9);
+    let num_bytes = compressed.len();
+    write_to_file(&out.join(CATALOG_FILE), compressed).await?;
+    info!(
+        uncompressed = bytes.len(),
+        num_bytes,
+        ?out,
+        "Exported compressed catalog."
+    );
+
+    // wasm-pack resolves a relative output directory against the crate.
+    let out_dir = std::path::absolute(out.join("pkg"))?;
+    wasm_pack::build(&path::library_crate("icondata_element"), &out_dir)?;
+
+    info!(?out, "Exported web component.");
+    Ok(())
+}
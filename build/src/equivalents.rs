@@ -0,0 +1,198 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use heck::ToPascalCase;
+use serde::Serialize;
+use tracing::{info, instrument, warn};
+
+use crate::{audit, dirs::write_to_file, icon, Packages};
+
+/// Words naming the same glyph in different packages, besides the synonyms of [`audit`].
+const SYNONYMS: &[(&str, &str)] = &[
+    ("Cog", "Gear"),
+    ("House", "Home"),
+    ("Magnifier", "Search"),
+    ("Pen", "Pencil"),
+];
+
+/// Icons of a project standing for the same thing while taken from different packages.
+#[derive(Debug, Serialize)]
+struct Mixed {
+    /// Name of the icons without package prefix and style, e.g. "Home" for `BsHouse`.
+    concept: String,
+    used: Vec<Usage>,
+    /// Equivalent of the package the project uses most, if it has one.
+    suggestion: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Usage {
+    icon: String,
+    package: String,
+    /// First use of the icon, e.g. "src/nav.rs:12".
+    location: String,
+}
+
+/// Report icons of the project at `project` which are cross-package equivalents, like `BsHouse`
+/// next to `LuHome`, and write the report to `out` as JSON, encouraging a consistent look.
+///
+/// Icons are found by their names in all Rust files of the project. For every concept used from
+/// more than one package, the equivalent from the package the project uses most is suggested.
+#[instrument(level = "info")]
+pub(crate) async fn report(project: &Path, out: &Path) -> Result<()> {
+    // Package and concept of every icon.
+    let mut icons = HashMap::new();
+    // Icons of every concept by package.
+    let mut equivalents = HashMap::<String, BTreeMap<&str, &str>>::new();
+    for package in Packages::get()? {
+        let short_name = package.meta.short_name.as_ref();
+        for icon in package.icons() {
+            let Some(concept) = concept(short_name, &icon.name, icon.style.as_deref()) else {
+                continue;
+            };
+            equivalents
+                .entry(concept.clone())
+                .or_default()
+                .entry(short_name)
+                .or_insert(icon.name.as_str());
+            icons.insert(icon.name.as_str(), (short_name, concept));
+        }
+    }
+
+    // First use of every icon of the project, in the order they are found.
+    let mut used = Vec::<(&str, String)>::new();
+    for path in rust_files(project).await? {
+        let source = tokio::fs::read_to_string(&path).await?;
+        let path = path.strip_prefix(project).unwrap_or(&path).display();
+        for (i, line) in source.lines().enumerate() {
+            for word in line.split(|c: char| !c.is_ascii_alphanumeric() && c != '_') {
+                let Some((name, _)) = icons.get_key_value(word) else {
+                    continue;
+                };
+                if !used.iter().any(|(used, _)| used == name) {
+                    used.push((*name, format!("{path}:{}", i + 1)));
+                }
+            }
+        }
+    }
+
+    let mut uses_per_package = HashMap::<&str, usize>::new();
+    let mut concepts = BTreeMap::<&str, Vec<Usage>>::new();
+    for (name, location) in &used {
+        let (package, concept) = &icons[name];
+        *uses_per_package.entry(*package).or_default() += 1;
+        concepts.entry(concept.as_str()).or_default().push(Usage {
+            icon: name.to_string(),
+            package: package.to_string(),
+            location: location.clone(),
+        });
+    }
+
+    let mixed = concepts
+        .into_iter()
+        .filter(|(_, used)| used.iter().any(|usage| usage.package != used[0].package))
+        .map(|(concept, used)| {
+            let preferred = used
+                .iter()
+                .map(|usage| usage.package.as_str())
+                .max_by_key(|package| uses_per_package[package]);
+            let suggestion = preferred
+                .and_then(|package| equivalents[concept].get(package))
+                .map(|icon| icon.to_string());
+            Mixed {
+                concept: concept.to_owned(),
+                used,
+                suggestion,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for mixed in &mixed {
+        let used = mixed
+            .used
+            .iter()
+            .map(|usage| format!("{} ({})", usage.icon, usage.location))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match &mixed.suggestion {
+            Some(suggestion) => warn!(
+                "{} is drawn by icons of several packages: {used}. Consider using {suggestion} throughout.",
+                mixed.concept
+            ),
+            None => warn!(
+                "{} is drawn by icons of several packages: {used}.",
+                mixed.concept
+            ),
+        }
+    }
+    info!(
+        num_icons = used.len(),
+        num_mixed = mixed.len(),
+        "Checked the icons of the project for cross-package equivalents."
+    );
+
+    write_to_file(&out.to_path_buf(), serde_json::to_string_pretty(&mixed)?).await
+}
+
+/// What an icon shows, shared by its equivalents in other packages: its name without package
+/// prefix, style and size, with synonyms replaced, e.g. "Home" for `BsHouse` and `FaHouseSolid`.
+fn concept(short_name: &str, name: &str, style: Option<&str>) -> Option<String> {
+    let name = name.strip_prefix(short_name.to_pascal_case().as_str())?;
+    let style = style.map(|style| style.to_pascal_case());
+    let words = icon::split_words(name)
+        .into_iter()
+        .filter(|word| Some(*word) != style.as_deref())
+        .filter(|word| !word.chars().all(|c| c.is_ascii_digit()))
+        .map(|word| {
+            SYNONYMS
+                .iter()
+                .chain(audit::SYNONYMS)
+                .find(|(synonym, _)| *synonym == word)
+                .map_or(word, |(_, canonical)| *canonical)
+        })
+        .collect::<String>();
+    (!words.is_empty()).then_some(words)
+}
+
+/// All Rust files below `dir`, skipping hidden directories and build output.
+async fn rust_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let mut dir_stream = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = dir_stream.next_entry().await? {
+            let path = entry.path();
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+            if entry.file_type().await?.is_dir() {
+                if !file_name.starts_with('.') && file_name != "target" {
+                    dirs.push(path);
+                }
+            } else if file_name.ends_with(".rs") {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod test {
+    use super::concept;
+
+    #[test]
+    fn equivalents_share_their_concept() {
+        assert_eq!(concept("bs", "BsHouse", None).as_deref(), Some("Home"));
+        assert_eq!(concept("lu", "LuHome", None).as_deref(), Some("Home"));
+        assert_eq!(
+            concept("fa", "FaHouseSolid", Some("solid")).as_deref(),
+            Some("Home")
+        );
+        assert_eq!(concept("oc", "OcGear16", None).as_deref(), Some("Gear"));
+        assert_eq!(concept("bs", "LuHome", None), None);
+    }
+}
@@ -0,0 +1,64 @@
+//! Icons which could not be read with `--keep-going`, which leaves them out instead of aborting
+//! the build. They are reported once everything else was generated, and the build still fails.
+
+use anyhow::{bail, Result};
+use serde::Serialize;
+use tracing::{error, info, instrument};
+
+use crate::{path, Packages};
+
+/// File the failures are written to, relative to the root of this crate.
+const FAILURES_FILE: &str = "errors.json";
+
+/// An icon which could not be read.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct ParseFailure {
+    /// Short name of the package of the icon.
+    pub package: String,
+    /// Path of the icon relative to the download directory of its package, e.g.
+    /// "svgs/solid/house.svg".
+    pub path: String,
+    pub error: String,
+}
+
+impl ParseFailure {
+    pub fn new(package: &str, path: String, error: &anyhow::Error) -> ParseFailure {
+        ParseFailure {
+            package: package.to_owned(),
+            path,
+            error: format!("{error:#}"),
+        }
+    }
+}
+
+/// Log the icons of all packages which could not be read and write them to `errors.json`, failing
+/// if there are any. Removes the file of a previous build otherwise.
+#[instrument(level = "info")]
+pub(crate) async fn report() -> Result<()> {
+    let failures = Packages::get()?
+        .iter()
+        .flat_map(|package| package.parse_failures())
+        .collect::<Vec<_>>();
+    let failures_path = path::build_crate(FAILURES_FILE);
+    if failures.is_empty() {
+        if failures_path.exists() {
+            tokio::fs::remove_file(&failures_path).await?;
+        }
+        info!("All icons were read.");
+        return Ok(());
+    }
+
+    for failure in &failures {
+        error!(
+            package = failure.package,
+            path = failure.path,
+            error = failure.error,
+            "Could not read icon."
+        );
+    }
+    tokio::fs::write(&failures_path, serde_json::to_string_pretty(&failures)?).await?;
+    bail!(
+        "{} icons could not be read and were left out, see {failures_path:?}.",
+        failures.len()
+    )
+}
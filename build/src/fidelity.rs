@@ -0,0 +1,211 @@
+//! How closely the generated icons match their upstream svg files when rendered.
+//!
+//! Every icon is rendered twice with resvg, once from its upstream file and once as rendered by
+//! `icondata_core`, and the two images are compared pixel by pixel. Systematic parser regressions,
+//! like dropped clip paths or lost stroke attributes, show up as a raised mean difference of the
+//! affected package.
+
+use std::{cmp::Ordering, path::Path};
+
+use anyhow::{anyhow, Result};
+use icondata_core::{IconData, RenderOptions};
+use resvg::{
+    tiny_skia::{Pixmap, Transform},
+    usvg::{self, TreeParsing},
+};
+use serde::Serialize;
+use tracing::{info, instrument, warn};
+
+use crate::{
+    dirs::write_to_file,
+    icon::SvgIcon,
+    package::{Downloaded, Package},
+    Packages,
+};
+
+/// Number of worst matching icons listed per package.
+const NUM_WORST: usize = 10;
+
+#[derive(Debug, Serialize)]
+struct Report {
+    /// Width and height in pixels icons were rendered with.
+    size: u32,
+    packages: Vec<PackageFidelity>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct PackageFidelity {
+    short_name: String,
+    /// Number of icons rendered from both sources.
+    pub num_compared: usize,
+    /// Icons whose upstream file could not be read or which could not be rendered.
+    pub skipped: Vec<String>,
+    /// Mean difference of all compared icons, from 0 for identical renders to 1.
+    pub mean_diff: f64,
+    /// Icons with the largest difference, largest first.
+    worst: Vec<IconFidelity>,
+}
+
+#[derive(Debug, Serialize)]
+struct IconFidelity {
+    id: String,
+    /// Path of the upstream svg file, relative to the package's repository.
+    source_path: String,
+    diff: f64,
+}
+
+/// Compare all icons with their upstream svg files, rendered at `size` pixels, and write the mean
+/// difference per package together with the worst matching icons to `out` as JSON.
+///
+/// Upstream files are read from the download directory of each package, so vendored packages
+/// report all of their icons as skipped.
+#[instrument(level = "info")]
+pub(crate) async fn report(out: &Path, size: u32) -> Result<()> {
+    let mut packages = Vec::new();
+    for package in Packages::get()? {
+        let fidelity = package_fidelity(package, size).await?;
+        info!(
+            package = fidelity.short_name,
+            fidelity.num_compared,
+            num_skipped = fidelity.skipped.len(),
+            fidelity.mean_diff,
+            "Compared package with upstream."
+        );
+        packages.push(fidelity);
+    }
+
+    let report = Report { size, packages };
+    write_to_file(&out.to_path_buf(), serde_json::to_string_pretty(&report)?).await?;
+    info!(?out, "Wrote fidelity report.");
+    Ok(())
+}
+
+/// Compare all icons of the package with their upstream svg files, rendered at `size` pixels.
+pub(crate) async fn package_fidelity(
+    package: &'static Package<Downloaded>,
+    size: u32,
+) -> Result<PackageFidelity> {
+    let download_path = package.download_path();
+    let mut compared = Vec::new();
+    let mut skipped = Vec::new();
+
+    for icon in package.icons() {
+        match icon_diff(&download_path, icon, size).await {
+            Ok(diff) => compared.push(IconFidelity {
+                id: icon.name.clone(),
+                source_path: icon.source_path.clone(),
+                diff,
+            }),
+            Err(err) => {
+                warn!(
+                    icon = icon.name,
+                    ?err,
+                    "Could not compare icon with upstream."
+                );
+                skipped.push(icon.name.clone());
+            }
+        }
+    }
+
+    let mean_diff = match compared.is_empty() {
+        true => 0.0,
+        false => compared.iter().map(|icon| icon.diff).sum::<f64>() / compared.len() as f64,
+    };
+    compared.sort_by(|a, b| b.diff.partial_cmp(&a.diff).unwrap_or(Ordering::Equal));
+    let num_compared = compared.len();
+    compared.truncate(NUM_WORST);
+
+    Ok(PackageFidelity {
+        short_name: package.meta.short_name.to_string(),
+        num_compared,
+        skipped,
+        mean_diff,
+        worst: compared,
+    })
+}
+
+async fn icon_diff(download_path: &Path, icon: &'static SvgIcon, size: u32) -> Result<f64> {
+    let upstream = tokio::fs::read_to_string(download_path.join(&icon.source_path)).await?;
+    let generated = icon_data(icon).render(&RenderOptions {
+        width: Some("1em"),
+        height: Some("1em"),
+        ..Default::default()
+    });
+    Ok(diff(
+        &rasterize(&upstream, size)?,
+        &rasterize(&generated, size)?,
+    ))
+}
+
+/// The icon as the generated crates define it.
+pub(crate) fn icon_data(icon: &'static SvgIcon) -> IconData {
+    let attributes = icon.svg.svg_attributes();
+    IconData {
+        id: &icon.name,
+        label: &icon.label,
+        source: None,
+        paint: icon.paint,
+        brand_color: icon.brand_color.as_deref(),
+        style: attributes.style.as_deref(),
+        x: attributes.x.as_deref(),
+        y: attributes.y.as_deref(),
+        width: attributes.width.as_deref(),
+        height: attributes.height.as_deref(),
+        view_box: attributes.view_box.as_deref(),
+        stroke_linecap: attributes.stroke_linecap.as_deref(),
+        stroke_linejoin: attributes.stroke_linejoin.as_deref(),
+        stroke_width: attributes.stroke_width.as_deref(),
+        stroke: attributes.stroke.as_deref(),
+        fill: attributes.fill.as_deref(),
+        data: &icon.svg.content,
+    }
+}
+
+/// Renders the svg scaled to fit a square of `size` pixels.
+pub(crate) fn rasterize(svg: &str, size: u32) -> Result<Pixmap> {
+    let tree = resvg::Tree::from_usvg(&usvg::Tree::from_str(svg, &usvg::Options::default())?);
+    let mut pixmap = Pixmap::new(size, size).ok_or(anyhow!("Invalid render size {size}."))?;
+    let scale = size as f32 / tree.size.width().max(tree.size.height());
+    tree.render(Transform::from_scale(scale, scale), &mut pixmap.as_mut());
+    Ok(pixmap)
+}
+
+/// Mean absolute difference of the luminance of both images composited over white, from 0 for
+/// identical images to 1.
+///
+/// Colors are compared by luminance only, as icons are mostly drawn in `currentColor` and small
+/// color shifts matter less than missing or extra shapes.
+pub(crate) fn diff(a: &Pixmap, b: &Pixmap) -> f64 {
+    let luminance = |pixel: &[u8]| {
+        // Pixels are premultiplied, so compositing over white adds the uncovered part.
+        let white = 255.0 - pixel[3] as f64;
+        (0.2126 * (pixel[0] as f64 + white)
+            + 0.7152 * (pixel[1] as f64 + white)
+            + 0.0722 * (pixel[2] as f64 + white))
+            / 255.0
+    };
+    let total = a
+        .data()
+        .chunks_exact(4)
+        .zip(b.data().chunks_exact(4))
+        .map(|(a, b)| (luminance(a) - luminance(b)).abs())
+        .sum::<f64>();
+    total / (a.width() * a.height()) as f64
+}
+
+#[cfg(test)]
+mod test {
+    use super::{diff, rasterize};
+
+    #[test]
+    fn diff_ranges_from_identical_to_inverted() {
+        let square = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 8 8"><rect width="8" height="8"/></svg>"#;
+        let empty = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 8 8"></svg>"#;
+        let half = r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 8 8"><rect width="4" height="8"/></svg>"#;
+
+        let square = rasterize(square, 8).unwrap();
+        assert_eq!(diff(&square, &square), 0.0);
+        assert!((diff(&square, &rasterize(empty, 8).unwrap()) - 1.0).abs() < 1e-9);
+        assert!((diff(&square, &rasterize(half, 8).unwrap()) - 0.5).abs() < 1e-9);
+    }
+}
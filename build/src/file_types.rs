@@ -0,0 +1,270 @@
+//! Types of files by MIME type and extension, generated into the `file_types` module of the main
+//! library as the `FileType` enum, for file managers and upload forms.
+//!
+//! The icons of a file type are found by their semantic group and label: icons of the
+//! [`Group::Files`] group whose label names nothing but a file and the words of the type, like
+//! "File PDF" or "Filetype PDF". Every type falls back to a more generic one, like PDFs to
+//! documents and documents to plain files, if no package preferred for it is enabled.
+
+use anyhow::Result;
+
+use crate::{
+    icon::{group::Group, SvgIcon},
+    migration::{self, PreferredIcon},
+    Packages,
+};
+
+/// Short names of the packages searched for icons of file types, in order of preference.
+const PACKAGES: &[&str] = &["lu", "tb", "bs", "fa", "ph", "ri", "hi"];
+
+/// Words of labels naming a file rather than its type, like "Earmark" in "File Earmark PDF". "File
+/// Type" counts as "Filetype", while "Type" on its own names a type of letters.
+const FILE_WORDS: &[&str] = &["file", "filetype", "document", "earmark"];
+
+/// A variant of the generated `FileType` enum.
+#[derive(Debug)]
+pub(crate) struct FileType {
+    pub variant: &'static str,
+    /// Exact MIME types of the type, without parameters.
+    pub mime_types: &'static [&'static str],
+    /// Top-level MIME type of which every MIME type not listed exactly belongs to the type, e.g.
+    /// "image" for "image/avif".
+    pub top_level: Option<&'static str>,
+    /// Extensions of files of the type, lowercase and without dot.
+    pub extensions: &'static [&'static str],
+    /// Words left of the labels of icons showing the type after removing [`FILE_WORDS`], in
+    /// order of preference. The empty list matches icons of plain files.
+    pub words: &'static [&'static [&'static str]],
+    /// Variant whose icon is used if none of the type is enabled.
+    pub fallback: Option<&'static str>,
+}
+
+const FILE_TYPES: &[FileType] = &[
+    FileType {
+        variant: "File",
+        mime_types: &["application/octet-stream"],
+        top_level: None,
+        extensions: &["bin"],
+        words: &[&[]],
+        fallback: None,
+    },
+    FileType {
+        variant: "Document",
+        mime_types: &["text/plain", "text/markdown", "application/rtf"],
+        top_level: Some("text"),
+        extensions: &["txt", "md", "rtf", "log"],
+        words: &[&["text"], &["lines"], &["description"]],
+        fallback: Some("File"),
+    },
+    FileType {
+        variant: "Pdf",
+        mime_types: &["application/pdf"],
+        top_level: None,
+        extensions: &["pdf"],
+        words: &[&["pdf"]],
+        fallback: Some("Document"),
+    },
+    FileType {
+        variant: "WordProcessing",
+        mime_types: &[
+            "application/msword",
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+            "application/vnd.oasis.opendocument.text",
+        ],
+        top_level: None,
+        extensions: &["doc", "docx", "odt"],
+        words: &[&["word"], &["doc"]],
+        fallback: Some("Document"),
+    },
+    FileType {
+        variant: "Spreadsheet",
+        mime_types: &[
+            "text/csv",
+            "application/vnd.ms-excel",
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+            "application/vnd.oasis.opendocument.spreadsheet",
+        ],
+        top_level: None,
+        extensions: &["csv", "xls", "xlsx", "ods"],
+        words: &[&["spreadsheet"], &["excel"], &["xls"]],
+        fallback: Some("Document"),
+    },
+    FileType {
+        variant: "Presentation",
+        mime_types: &[
+            "application/vnd.ms-powerpoint",
+            "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+            "application/vnd.oasis.opendocument.presentation",
+        ],
+        top_level: None,
+        extensions: &["ppt", "pptx", "odp", "key"],
+        words: &[&["ppt"], &["powerpoint"], &["slides"]],
+        fallback: Some("Document"),
+    },
+    FileType {
+        variant: "Code",
+        mime_types: &[
+            "application/json",
+            "application/javascript",
+            "application/xml",
+            "application/x-sh",
+            "text/html",
+            "text/css",
+            "text/javascript",
+            "text/xml",
+        ],
+        top_level: None,
+        extensions: &[
+            "json", "js", "ts", "jsx", "tsx", "html", "css", "xml", "rs", "py", "rb", "go", "java",
+            "c", "cpp", "h", "sh", "toml", "yaml", "yml",
+        ],
+        words: &[&["code"]],
+        fallback: Some("Document"),
+    },
+    FileType {
+        variant: "Image",
+        mime_types: &[],
+        top_level: Some("image"),
+        extensions: &[
+            "png", "jpg", "jpeg", "gif", "webp", "avif", "svg", "bmp", "tif", "tiff", "heic", "ico",
+        ],
+        words: &[&["image"], &["photo"]],
+        fallback: Some("File"),
+    },
+    FileType {
+        variant: "Audio",
+        mime_types: &[],
+        top_level: Some("audio"),
+        extensions: &["mp3", "wav", "ogg", "oga", "flac", "aac", "m4a", "opus"],
+        words: &[&["audio"], &["music"]],
+        fallback: Some("File"),
+    },
+    FileType {
+        variant: "Video",
+        mime_types: &[],
+        top_level: Some("video"),
+        extensions: &["mp4", "webm", "mov", "mkv", "avi", "m4v", "ogv"],
+        words: &[&["video"], &["play"]],
+        fallback: Some("File"),
+    },
+    FileType {
+        variant: "Archive",
+        mime_types: &[
+            "application/zip",
+            "application/gzip",
+            "application/x-tar",
+            "application/x-7z-compressed",
+            "application/x-bzip2",
+            "application/x-xz",
+            "application/vnd.rar",
+        ],
+        top_level: None,
+        extensions: &["zip", "gz", "tgz", "tar", "7z", "bz2", "xz", "rar"],
+        words: &[&["archive"], &["zip"], &["zipper"]],
+        fallback: Some("File"),
+    },
+];
+
+/// All file types, with the icons of the current packages showing them.
+pub(crate) fn file_types() -> Result<Vec<(&'static FileType, Vec<PreferredIcon>)>> {
+    FILE_TYPES
+        .iter()
+        .map(|file_type| {
+            let names = icon_names(file_type)?;
+            let names = names.iter().map(String::as_str).collect::<Vec<_>>();
+            Ok((file_type, migration::preferred_icons(&names)?))
+        })
+        .collect()
+}
+
+/// Names of the icons showing the file type, at most one per package of [`PACKAGES`].
+fn icon_names(file_type: &FileType) -> Result<Vec<String>> {
+    let packages = Packages::get()?;
+    let names = PACKAGES
+        .iter()
+        .filter_map(|short_name| {
+            let package = packages
+                .iter()
+                .find(|package| package.meta.short_name == *short_name && !package.is_pinned())?;
+            let files = package
+                .icons()
+                .iter()
+                .filter(|icon| !icon.brand && icon.groups.contains(&Group::Files))
+                .collect::<Vec<_>>();
+            file_type.words.iter().find_map(|words| {
+                files
+                    .iter()
+                    .filter(|icon| type_words(icon).is_some_and(|found| found == *words))
+                    // Icons without style are always enabled.
+                    .min_by_key(|icon| (icon.style.is_some(), icon.name.len()))
+                    .map(|icon| icon.name.clone())
+            })
+        })
+        .collect();
+    Ok(names)
+}
+
+/// Lowercase words of the label of the icon without [`FILE_WORDS`] and its style, `None` if the
+/// label names no file.
+fn type_words(icon: &SvgIcon) -> Option<Vec<String>> {
+    let label = icon
+        .label
+        .to_ascii_lowercase()
+        .replace("file type", "filetype");
+    let words = label
+        .split(' ')
+        .filter(|word| icon.style.as_deref() != Some(*word))
+        .collect::<Vec<_>>();
+    let is_file = words.iter().any(|word| FILE_WORDS.contains(word));
+    is_file.then(|| {
+        words
+            .into_iter()
+            .filter(|word| !FILE_WORDS.contains(word))
+            .map(str::to_owned)
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::FILE_TYPES;
+
+    #[test]
+    fn file_types_are_unambiguous() {
+        let variants = FILE_TYPES
+            .iter()
+            .map(|file_type| file_type.variant)
+            .collect::<HashSet<_>>();
+        let mime_types = FILE_TYPES
+            .iter()
+            .flat_map(|file_type| file_type.mime_types)
+            .collect::<HashSet<_>>();
+        let extensions = FILE_TYPES
+            .iter()
+            .flat_map(|file_type| file_type.extensions)
+            .collect::<HashSet<_>>();
+        assert_eq!(variants.len(), FILE_TYPES.len());
+        assert_eq!(
+            mime_types.len(),
+            FILE_TYPES
+                .iter()
+                .map(|it| it.mime_types.len())
+                .sum::<usize>()
+        );
+        assert_eq!(
+            extensions.len(),
+            FILE_TYPES
+                .iter()
+                .map(|it| it.extensions.len())
+                .sum::<usize>()
+        );
+        for file_type in FILE_TYPES {
+            assert!(file_type
+                .fallback
+                .iter()
+                .all(|fallback| variants.contains(fallback)));
+        }
+    }
+}
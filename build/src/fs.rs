@@ -1,4 +1,13 @@
 pub(crate) mod cargo_toml;
+pub(crate) mod currency_rs;
+pub(crate) mod file_types_rs;
+pub(crate) mod keys_rs;
 pub(crate) mod lib_rs;
+pub(crate) mod migrate_rs;
 pub(crate) mod readme_md;
+pub(crate) mod social_rs;
+pub(crate) mod spinners_rs;
 pub(crate) mod src_dir;
+pub(crate) mod status_rs;
+pub(crate) mod user_agent_rs;
+pub(crate) mod weather_rs;
@@ -2,7 +2,7 @@ use anyhow::Result;
 use askama::Template;
 use std::path::PathBuf;
 
-use crate::dirs::LibType;
+use crate::{bundle::BUNDLES, dirs::LibType, package::PackageMetadata};
 
 #[derive(Debug)]
 pub struct CargoToml {
@@ -11,41 +11,90 @@ pub struct CargoToml {
 }
 
 impl CargoToml {
-    pub fn contents(lib_type: &LibType) -> Result<String> {
+    pub fn contents(lib_type: &LibType, compressed: bool) -> Result<String> {
         match lib_type {
-            LibType::IconLib(pkg) => {
+            LibType::IconLib(pkg) => Self::icon_lib_contents(&pkg.meta, &pkg.styles(), pkg.has_brands(), compressed),
+
+            LibType::MainLib => {
                 #[derive(Template)]
-                #[template(path = "icon_lib/Cargo.toml", escape = "none")]
+                #[template(path = "main_lib/Cargo.toml", escape = "none")]
                 struct Template<'a> {
-                    short_name: &'a str,
-                    crate_version: String,
-                    icon_package_name: &'a str,
+                    crates: Vec<(&'a str, String, Vec<&'a str>, bool)>,
+                    brand_crates: Vec<(&'a str, bool)>,
+                    bundles: Vec<(&'a str, String)>,
                 }
 
+                let crates: Vec<_> = crate::Packages::get()?.iter().map(|package| {
+
+                    (&*package.meta.short_name, package.meta.crate_version.to_string(), package.styles(), package.meta.opt_in)
+                }).collect();
+
+                let brand_crates = crate::Packages::get()?
+                    .iter()
+                    .filter(|package| package.has_brands())
+                    .map(|package| (package.meta.short_name.as_ref(), package.meta.opt_in))
+                    .collect();
+
+                let bundles = BUNDLES
+                    .iter()
+                    .map(|bundle| (bundle.name, bundle.crate_version.to_string()))
+                    .collect();
+
                 Ok(Template {
-                    crate_version: pkg.meta.crate_version.to_string(),
-                    short_name: &pkg.meta.short_name,
-                    icon_package_name: &pkg.meta.package_name,
+                    crates,
+                    brand_crates,
+                    bundles,
                 }
                 .render()?)
             }
 
-            LibType::MainLib => {
+            LibType::Bundle(bundle) => {
                 #[derive(Template)]
-                #[template(path = "main_lib/Cargo.toml", escape = "none")]
+                #[template(path = "bundle_lib/Cargo.toml", escape = "none")]
                 struct Template<'a> {
-                    sn_version: Vec<(&'a str, String)>,
+                    name: &'a str,
+                    crate_version: String,
                 }
 
-                let sn_version: Vec<_> = crate::Packages::get()?.iter().map(|package| {
-
-                    (&*package.meta.short_name, package.meta.crate_version.to_string())
-                }).collect();
-
-                Ok(Template { sn_version }.render()?)
+                Ok(Template {
+                    name: bundle.name,
+                    crate_version: bundle.crate_version.to_string(),
+                }
+                .render()?)
             }
 
             LibType::IconIndex => unimplemented!("IconIndex does not generate a Cargo.toml file.")
         }
     }
+
+    /// Contents of the Cargo.toml file of the icon library generated from the given package, with
+    /// one feature for each of the given icon styles and one for brand icons, if there are any.
+    /// Compressed icon libraries depend on the `compressed` feature of `icondata_core`.
+    pub fn icon_lib_contents(
+        meta: &PackageMetadata,
+        styles: &[&str],
+        has_brands: bool,
+        compressed: bool,
+    ) -> Result<String> {
+        #[derive(Template)]
+        #[template(path = "icon_lib/Cargo.toml", escape = "none")]
+        struct Template<'a> {
+            short_name: &'a str,
+            crate_version: String,
+            icon_package_name: &'a str,
+            styles: &'a [&'a str],
+            has_brands: bool,
+            compressed: bool,
+        }
+
+        Ok(Template {
+            crate_version: meta.crate_version.to_string(),
+            short_name: &meta.short_name,
+            icon_package_name: &meta.package_name,
+            styles,
+            has_brands,
+            compressed,
+        }
+        .render()?)
+    }
 }
@@ -0,0 +1,33 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use askama::Template;
+
+use crate::{
+    currency::{self, Currency},
+    migration::PreferredIcon,
+};
+
+/// The `currency` module of the main library, see [`crate::currency`].
+#[derive(Debug)]
+pub struct CurrencyRs {
+    pub path: PathBuf,
+}
+
+impl CurrencyRs {
+    /// Contents of the module, reading `CompressedIcon`s if the icon libraries are `compressed`.
+    pub fn contents(compressed: bool) -> Result<String> {
+        #[derive(Template)]
+        #[template(path = "main_lib/currency.rs", escape = "none")]
+        struct Template {
+            currencies: Vec<(&'static Currency, Vec<PreferredIcon>)>,
+            compressed: bool,
+        }
+
+        Ok(Template {
+            currencies: currency::currencies()?,
+            compressed,
+        }
+        .render()?)
+    }
+}
@@ -0,0 +1,33 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use askama::Template;
+
+use crate::{
+    file_types::{self, FileType},
+    migration::PreferredIcon,
+};
+
+/// The `file_types` module of the main library, see [`crate::file_types`].
+#[derive(Debug)]
+pub struct FileTypesRs {
+    pub path: PathBuf,
+}
+
+impl FileTypesRs {
+    /// Contents of the module, reading `CompressedIcon`s if the icon libraries are `compressed`.
+    pub fn contents(compressed: bool) -> Result<String> {
+        #[derive(Template)]
+        #[template(path = "main_lib/file_types.rs", escape = "none")]
+        struct Template {
+            file_types: Vec<(&'static FileType, Vec<PreferredIcon>)>,
+            compressed: bool,
+        }
+
+        Ok(Template {
+            file_types: file_types::file_types()?,
+            compressed,
+        }
+        .render()?)
+    }
+}
@@ -0,0 +1,30 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use askama::Template;
+
+use crate::keys::{self, Key};
+
+/// The `keys` module of the main library, see [`crate::keys`].
+#[derive(Debug)]
+pub struct KeysRs {
+    pub path: PathBuf,
+}
+
+impl KeysRs {
+    /// Contents of the module, reading `CompressedIcon`s if the icon libraries are `compressed`.
+    pub fn contents(compressed: bool) -> Result<String> {
+        #[derive(Template)]
+        #[template(path = "main_lib/keys.rs", escape = "none")]
+        struct Template {
+            keys: Vec<Key>,
+            compressed: bool,
+        }
+
+        Ok(Template {
+            keys: keys::keys()?,
+            compressed,
+        }
+        .render()?)
+    }
+}
@@ -1,9 +1,24 @@
-use std::path::PathBuf;
+use std::{collections::BTreeMap, fmt, path::PathBuf};
 
 use anyhow::Result;
 use askama::Template;
+use heck::ToPascalCase;
 
-use crate::{dirs::LibType, icon::svg::ParsedSvg, package::PackageSource, Packages};
+use crate::{
+    bundle::BUNDLES,
+    compress::Dictionary,
+    dirs::LibType,
+    fs::src_dir::{filters, SrcDir},
+    icon::{
+        direction::Directional,
+        family::{Family, FamilyKind},
+        group::Group,
+        state_pair::StatePair,
+        SvgIcon,
+    },
+    package::PackageMetadata,
+    Packages,
+};
 
 #[derive(Debug)]
 pub struct LibRs {
@@ -11,69 +26,248 @@ pub struct LibRs {
 }
 
 impl LibRs {
-    pub fn contents(lib_type: &LibType) -> Result<String> {
+    /// Renders the lib.rs file of the given library into `out` while it is written, the largest
+    /// file of bundles and the icon index, which are never held in memory as a whole.
+    pub fn write(
+        lib_type: &LibType,
+        src_dir: Option<&SrcDir>,
+        out: &mut dyn fmt::Write,
+    ) -> Result<()> {
         match lib_type {
             LibType::IconLib(pkg) => {
+                let chunks = src_dir
+                    .map(|src_dir| src_dir.chunk_file_names(lib_type))
+                    .unwrap_or_default();
+                let dictionary = src_dir.and_then(|src_dir| src_dir.dictionary.as_ref());
+                let families = crate::icon::family::families(pkg.icons());
+                let directionals = crate::icon::direction::directionals(pkg.icons());
+                let state_pairs = crate::icon::state_pair::state_pairs(pkg.icons());
+                Self::write_icon_lib(
+                    &pkg.meta,
+                    pkg.revision(),
+                    chunks,
+                    dictionary,
+                    &families,
+                    &directionals,
+                    &state_pairs,
+                    out,
+                )
+            }
+            LibType::MainLib => {
                 #[derive(Template)]
-                #[template(path = "icon_lib/lib.rs", escape = "none")]
+                #[template(path = "main_lib/lib.rs", escape = "none")]
                 struct Template<'a> {
-                    name_svg: Vec<(&'a str, &'a ParsedSvg)>,
-                    url: String,
-                    long_name: String,
+                    short_names: Vec<(&'a str, bool)>,
+                    bundles: Vec<&'a str>,
                 }
 
-                let icons = pkg.icons();
-                let name_svg = icons
+                let short_names = Packages::get()?
                     .iter()
-                    .map(|icon| (icon.name.as_ref(), &icon.svg))
+                    .map(|package| (package.meta.short_name.as_ref(), package.meta.opt_in))
                     .collect::<Vec<_>>();
-                let long_name = pkg.meta.package_name.to_string();
-                let url = match &pkg.meta.source {
-                    PackageSource::Git { url, .. } => url.to_string(),
-                };
+                let bundles = BUNDLES.iter().map(|bundle| bundle.name).collect();
 
                 Ok(Template {
-                    name_svg,
-                    url,
-                    long_name,
+                    short_names,
+                    bundles,
                 }
-                .render()?)
+                .render_into(out)?)
             }
-            LibType::MainLib => {
+            LibType::Bundle(bundle) => {
                 #[derive(Template)]
-                #[template(path = "main_lib/lib.rs", escape = "none")]
+                #[template(path = "bundle_lib/lib.rs", escape = "none")]
                 struct Template<'a> {
-                    short_names: Vec<&'a str>,
+                    name: &'a str,
+                    description: &'a str,
+                    icons: Vec<(&'a SvgIcon, String, Option<&'a str>)>,
                 }
 
-                let short_names = Packages::get()?
-                    .iter()
-                    .map(|package| package.meta.short_name.as_ref())
-                    .collect::<Vec<_>>();
+                let icons = bundle
+                    .icons()?
+                    .into_iter()
+                    .map(|(package, icon)| {
+                        (
+                            icon,
+                            package.meta.source.url().to_owned(),
+                            package.revision(),
+                        )
+                    })
+                    .collect();
 
-                Ok(Template { short_names }.render()?)
+                Ok(Template {
+                    name: bundle.name,
+                    description: bundle.description,
+                    icons,
+                }
+                .render_into(out)?)
             }
             LibType::IconIndex => {
                 #[derive(Template)]
                 #[template(path = "icon_index/lib.rs", escape = "none")]
                 struct Template<'a> {
-                    icons: Vec<&'a str>,
+                    icons: Vec<(&'a str, u64)>,
+                    groups: Vec<(&'a str, Vec<usize>)>,
+                    packages: Vec<(&'a str, String, usize, usize)>,
+                    styles: BTreeMap<&'a str, Vec<usize>>,
                 }
 
-                let icons = Packages::get()?
+                let all_icons = Packages::get_icons()?.collect::<Vec<_>>();
+                let icons = all_icons
+                    .iter()
+                    .map(|icon| (icon.name.as_ref(), icon.shape_hash))
+                    .collect();
+                let groups = Group::ALL
+                    .into_iter()
+                    .map(|group| {
+                        let indices = all_icons
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, icon)| icon.groups.contains(&group))
+                            .map(|(i, _)| i)
+                            .collect();
+                        (group.as_str(), indices)
+                    })
+                    .collect();
+                // Icons are listed package by package.
+                let mut start = 0;
+                let packages = Packages::get()?
                     .iter()
-                    .flat_map(|package| package.icons().iter().map(|icon| icon.name.as_ref()))
+                    .map(|package| {
+                        let range = (start, start + package.icons().len());
+                        start = range.1;
+                        (
+                            package.meta.short_name.as_ref(),
+                            package.name_prefix().to_pascal_case(),
+                            range.0,
+                            range.1,
+                        )
+                    })
                     .collect();
+                let mut styles = BTreeMap::<_, Vec<_>>::new();
+                for (i, icon) in all_icons.iter().enumerate() {
+                    if let Some(style) = &icon.style {
+                        styles.entry(style.as_str()).or_default().push(i);
+                    }
+                }
 
-                Ok(Template { icons }.render()?)
+                Ok(Template {
+                    icons,
+                    groups,
+                    packages,
+                    styles,
+                }
+                .render_into(out)?)
             }
         }
     }
+
+    /// Contents of the lib.rs file of the icon library generated from the given package, including
+    /// the given source chunks, whose icons link to the upstream `revision` if there is one.
+    /// The `dictionary` of compressed icons is embedded if given. An accessor is generated for each
+    /// of the `families` of numbered icons, each set of `directionals` and each of the
+    /// `state_pairs`.
+    pub fn icon_lib_contents(
+        meta: &PackageMetadata,
+        revision: Option<&str>,
+        chunks: Vec<String>,
+        dictionary: Option<&Dictionary>,
+        families: &[Family],
+        directionals: &[Directional],
+        state_pairs: &[StatePair],
+    ) -> Result<String> {
+        let mut contents = String::new();
+        Self::write_icon_lib(
+            meta,
+            revision,
+            chunks,
+            dictionary,
+            families,
+            directionals,
+            state_pairs,
+            &mut contents,
+        )?;
+        Ok(contents)
+    }
+
+    /// Renders the lib.rs file of an icon library into `out`, see [`LibRs::icon_lib_contents`].
+    #[allow(clippy::too_many_arguments)]
+    fn write_icon_lib(
+        meta: &PackageMetadata,
+        revision: Option<&str>,
+        chunks: Vec<String>,
+        dictionary: Option<&Dictionary>,
+        families: &[Family],
+        directionals: &[Directional],
+        state_pairs: &[StatePair],
+        out: &mut dyn fmt::Write,
+    ) -> Result<()> {
+        #[derive(Template)]
+        #[template(path = "icon_lib/lib.rs", escape = "none")]
+        struct Template<'a> {
+            revision: Option<&'a str>,
+            chunks: Vec<String>,
+            examples: Vec<String>,
+            url: String,
+            long_name: String,
+            dictionary: Option<&'a Dictionary>,
+            compressed: bool,
+            families: &'a [Family],
+            directionals: &'a [Directional],
+            state_pairs: &'a [StatePair],
+        }
+
+        let long_name = meta.package_name.to_string();
+        let url = meta.source.url().to_owned();
+
+        Ok(Template {
+            revision,
+            chunks,
+            examples: examples_macro_parts()?,
+            url,
+            long_name,
+            dictionary,
+            compressed: dictionary.is_some(),
+            families,
+            directionals,
+            state_pairs,
+        }
+        .render_into(out)?)
+    }
 }
 
-mod filters {
-    use xml::attribute::OwnedAttribute;
-    pub fn attribute_value(opt: &Option<OwnedAttribute>) -> ::askama::Result<String> {
-        Ok(format!("{:?}", opt.as_ref().map(|attr| &attr.value)))
+/// The arguments of the `concat!` call building the documentation of each icon.
+///
+/// The examples template is rendered once with placeholders, which are then replaced by the
+/// arguments of the `examples!` macro, keeping the generated sources small.
+fn examples_macro_parts() -> Result<Vec<String>> {
+    #[derive(Template)]
+    #[template(path = "icon_lib/examples.md", escape = "none")]
+    struct Template<'a> {
+        icon: &'a str,
+        label: &'a str,
+    }
+
+    const ICON: &str = "$icon";
+    const LABEL: &str = "$label";
+    let rendered = Template {
+        icon: ICON,
+        label: LABEL,
+    }
+    .render()?;
+
+    let mut parts = Vec::new();
+    for (i, with_icons) in rendered.split(LABEL).enumerate() {
+        if i > 0 {
+            parts.push(LABEL.to_owned());
+        }
+        for (j, text) in with_icons.split(ICON).enumerate() {
+            if j > 0 {
+                parts.push(format!("stringify!({ICON})"));
+            }
+            if !text.is_empty() {
+                parts.push(format!("{text:?}"));
+            }
+        }
     }
+    Ok(parts)
 }
@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use askama::Template;
+
+use crate::migration::{self, Migration};
+
+/// The `migrate` module of the main library, see [`crate::migration`].
+#[derive(Debug)]
+pub struct MigrateRs {
+    pub path: PathBuf,
+}
+
+impl MigrateRs {
+    /// Contents of the module, referring to `CompressedIcon`s if the icon libraries are
+    /// `compressed`.
+    pub fn contents(compressed: bool) -> Result<String> {
+        #[derive(Template)]
+        #[template(path = "main_lib/migrate.rs", escape = "none")]
+        struct Template {
+            migrations: Vec<Migration>,
+            compressed: bool,
+        }
+
+        Ok(Template {
+            migrations: migration::migrations()?,
+            compressed,
+        }
+        .render()?)
+    }
+}
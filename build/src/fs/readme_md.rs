@@ -4,7 +4,7 @@ use std::path::PathBuf;
 
 use crate::{
     dirs::LibType,
-    package::{GitTarget, PackageSource},
+    package::{GitTarget, PackageMetadata, PackageSource},
     Packages,
 };
 
@@ -16,20 +16,7 @@ pub struct Readme {
 impl Readme {
     pub fn contents(ty: &LibType) -> Result<String> {
         match ty {
-            LibType::IconLib(pkg) => {
-                #[derive(Template)]
-                #[template(path = "icon_lib/README.md")]
-                struct ReadmeTemplate<'a> {
-                    short_name: &'a str,
-                    package_name: &'a str,
-                }
-
-                Ok(ReadmeTemplate {
-                    short_name: &pkg.meta.short_name,
-                    package_name: &pkg.meta.package_name,
-                }
-                .render()?)
-            }
+            LibType::IconLib(pkg) => Self::icon_lib_contents(&pkg.meta),
 
             LibType::MainLib => {
                 #[derive(Template)]
@@ -49,6 +36,8 @@ impl Readme {
                                     .unwrap_or("unknown".to_owned()),
                                 GitTarget::Tag { version, .. } => version.to_string(),
                             },
+                            PackageSource::NpmTarball { version, .. } => version.clone(),
+                            PackageSource::LocalPath { .. } => "unknown".to_owned(),
                         };
                         let source = match &pkg.meta.source {
                             PackageSource::Git { url, target } => match &target {
@@ -61,6 +50,16 @@ impl Readme {
                                     format!("Git: <{url}> - Tag: {name}")
                                 }
                             },
+                            PackageSource::NpmTarball {
+                                name,
+                                version,
+                                repository,
+                            } => format!("npm: {name}@{version} - Repository: <{repository}>"),
+                            // The path is specific to the machine building the crates.
+                            PackageSource::LocalPath { repository, .. } => match repository {
+                                Some(url) => format!("Local: <{url}>"),
+                                None => "Local".to_owned(),
+                            },
                         };
                         let license = pkg.meta.licenses.iter().fold(String::new(), |mut acc, s| {
                             acc.push_str(s);
@@ -80,7 +79,45 @@ impl Readme {
 
                 Ok(ReadmeTemplate { packages }.render()?)
             }
+            LibType::Bundle(bundle) => {
+                #[derive(Template)]
+                #[template(path = "bundle_lib/README.md")]
+                struct ReadmeTemplate<'a> {
+                    name: &'a str,
+                    description: &'a str,
+                    icons: Vec<(&'a str, &'a str)>,
+                }
+
+                let icons = bundle
+                    .icons()?
+                    .into_iter()
+                    .map(|(package, icon)| (icon.name.as_str(), package.meta.short_name.as_ref()))
+                    .collect();
+
+                Ok(ReadmeTemplate {
+                    name: bundle.name,
+                    description: bundle.description,
+                    icons,
+                }
+                .render()?)
+            }
             LibType::IconIndex => unreachable!("IconIndex does not have a README.md file"),
         }
     }
+
+    /// Contents of the README.md file of the icon library generated from the given package.
+    pub fn icon_lib_contents(meta: &PackageMetadata) -> Result<String> {
+        #[derive(Template)]
+        #[template(path = "icon_lib/README.md")]
+        struct ReadmeTemplate<'a> {
+            short_name: &'a str,
+            package_name: &'a str,
+        }
+
+        Ok(ReadmeTemplate {
+            short_name: &meta.short_name,
+            package_name: &meta.package_name,
+        }
+        .render()?)
+    }
 }
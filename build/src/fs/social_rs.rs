@@ -0,0 +1,33 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use askama::Template;
+
+use crate::{
+    migration::PreferredIcon,
+    social::{self, Platform},
+};
+
+/// The `social` module of the main library, see [`crate::social`].
+#[derive(Debug)]
+pub struct SocialRs {
+    pub path: PathBuf,
+}
+
+impl SocialRs {
+    /// Contents of the module, reading `CompressedIcon`s if the icon libraries are `compressed`.
+    pub fn contents(compressed: bool) -> Result<String> {
+        #[derive(Template)]
+        #[template(path = "main_lib/social.rs", escape = "none")]
+        struct Template {
+            platforms: Vec<(&'static Platform, Vec<PreferredIcon>)>,
+            compressed: bool,
+        }
+
+        Ok(Template {
+            platforms: social::platforms()?,
+            compressed,
+        }
+        .render()?)
+    }
+}
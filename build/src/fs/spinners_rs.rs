@@ -0,0 +1,63 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use anyhow::Result;
+use askama::Template;
+
+use crate::{icon::SvgIcon, migration, Packages};
+
+/// The `spinners` module of the main library, listing the loading indicators of all packages, see
+/// [`crate::icon::spinner`].
+#[derive(Debug)]
+pub struct SpinnersRs {
+    pub path: PathBuf,
+}
+
+struct Spinner {
+    icon: &'static SvgIcon,
+    /// Feature of the main library enabling the icon, e.g. "fa-solid".
+    feature: Option<String>,
+    origin: &'static str,
+}
+
+impl SpinnersRs {
+    /// Contents of the module, reading `CompressedIcon`s if the icon libraries are `compressed`.
+    pub fn contents(compressed: bool) -> Result<String> {
+        #[derive(Template)]
+        #[template(path = "main_lib/spinners.rs", escape = "none")]
+        struct Template {
+            spinners: Vec<Spinner>,
+            compressed: bool,
+        }
+
+        let mut spinners = Vec::new();
+        for package in Packages::get()? {
+            // Variants like aligned icons share the source of the original icon, whose name is the
+            // shortest.
+            let mut originals = BTreeMap::<&str, &SvgIcon>::new();
+            for icon in package.icons() {
+                if icon.spinner_origin.is_none() {
+                    continue;
+                }
+                let original = originals.entry(icon.source_path.as_str()).or_insert(icon);
+                if icon.name.len() < original.name.len() {
+                    *original = icon;
+                }
+            }
+            let mut icons = originals.into_values().collect::<Vec<_>>();
+            icons.sort_by(|a, b| a.name.cmp(&b.name));
+            spinners.extend(icons.into_iter().filter_map(|icon| {
+                Some(Spinner {
+                    icon,
+                    feature: migration::feature(package, icon),
+                    origin: icon.spinner_origin.as_deref()?,
+                })
+            }));
+        }
+
+        Ok(Template {
+            spinners,
+            compressed,
+        }
+        .render()?)
+    }
+}
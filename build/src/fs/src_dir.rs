@@ -0,0 +1,156 @@
+use std::{borrow::Cow, path::PathBuf};
+
+use anyhow::Result;
+use askama::Template;
+use tracing::trace;
+
+use crate::{
+    compress::Dictionary,
+    dirs::{write_rendered, LibType},
+    icon::SvgIcon,
+};
+
+/// Prefix of every generated source chunk inside a library's `src` directory.
+const CHUNK_PREFIX: &str = "icons_";
+
+/// Number of icons per chunk above which a library is split into more chunks than requested, so
+/// that huge packages don't end up in a few huge source files.
+const MAX_CHUNK_ICONS: usize = 500;
+
+#[derive(Template)]
+#[template(path = "icon_lib/icons.rs", escape = "none")]
+struct IconsTemplate<'a> {
+    /// Icons with their content, encoded in compressed mode, and the end of their definition.
+    icons: Vec<(&'a SvgIcon, Cow<'a, str>, &'static str)>,
+    compressed: bool,
+    /// Whether the lib.rs file defines the upstream repository and revision the icons link to.
+    sourced: bool,
+}
+
+#[derive(Debug)]
+pub struct SrcDir {
+    /// Path to the library's src directory.
+    pub path: PathBuf,
+    /// Maximum number of source files the icons of a library are split into, unless that leaves
+    /// more than [`MAX_CHUNK_ICONS`] icons in a chunk.
+    pub chunks: usize,
+    /// Dictionary the icons are encoded with in compressed mode, see [`Dictionary`].
+    pub dictionary: Option<Dictionary>,
+}
+
+impl SrcDir {
+    /// File names of all chunks generated for the given library, relative to the src directory.
+    pub fn chunk_file_names(&self, lib_type: &LibType) -> Vec<String> {
+        match lib_type {
+            LibType::IconLib(pkg) => (0..self.chunk_sizes(pkg.icons().len()).count())
+                .map(|i| format!("{CHUNK_PREFIX}{i}.rs"))
+                .collect(),
+            LibType::MainLib | LibType::IconIndex | LibType::Bundle(_) => Vec::new(),
+        }
+    }
+
+    /// Renders every chunk of the given library, returning the full path and contents of each file.
+    pub fn contents(&self, lib_type: &LibType) -> Result<Vec<(PathBuf, String)>> {
+        self.chunks(lib_type)
+            .map(|(path, template)| Ok((path, template.render()?)))
+            .collect()
+    }
+
+    /// Writes the chunks of the given library one after another, each one while it is rendered,
+    /// so that no rendered chunk is held in memory as a whole.
+    pub async fn generate(&self, lib_type: &LibType<'_>) -> Result<()> {
+        for (path, template) in self.chunks(lib_type) {
+            write_rendered(&path, |out| Ok(template.render_into(out)?)).await?;
+        }
+        Ok(())
+    }
+
+    /// Lazily prepares the template of each chunk of the given library, with its full path.
+    fn chunks<'a>(
+        &'a self,
+        lib_type: &'a LibType,
+    ) -> impl Iterator<Item = (PathBuf, IconsTemplate<'a>)> + 'a {
+        let (icons, sourced) = match lib_type {
+            LibType::IconLib(pkg) => (pkg.icons(), pkg.revision().is_some()),
+            LibType::MainLib | LibType::IconIndex | LibType::Bundle(_) => (&[][..], false),
+        };
+        let mut start = 0;
+        self.chunk_sizes(icons.len())
+            .zip(self.chunk_file_names(lib_type))
+            .map(move |(size, file_name)| {
+                let chunk = &icons[start..start + size];
+                start += size;
+                let icons = chunk
+                    .iter()
+                    .map(|icon| {
+                        let content = icon.svg.content.as_str();
+                        match &self.dictionary {
+                            Some(dictionary) => match dictionary.encode(content) {
+                                Some(encoded) => (icon, Cow::Owned(encoded), "}, DICTIONARY);"),
+                                // Stored as it is, with no dictionary to decode it with.
+                                None => (icon, Cow::Borrowed(content), "}, &[]);"),
+                            },
+                            None => (icon, Cow::Borrowed(content), "};"),
+                        }
+                    })
+                    .collect();
+                let template = IconsTemplate {
+                    icons,
+                    compressed: self.dictionary.is_some(),
+                    sourced,
+                };
+                (self.path.join(file_name), template)
+            })
+    }
+
+    /// Paths of all chunks currently present in the src directory.
+    pub async fn existing_chunks(&self) -> Result<Vec<PathBuf>> {
+        let mut chunks = Vec::new();
+        if !self.path.exists() {
+            return Ok(chunks);
+        }
+        let mut dir_stream = tokio::fs::read_dir(&self.path).await?;
+        while let Some(entry) = dir_stream.next_entry().await? {
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+            if file_name.starts_with(CHUNK_PREFIX) && file_name.ends_with(".rs") {
+                chunks.push(entry.path());
+            }
+        }
+        Ok(chunks)
+    }
+
+    /// Removes chunks left over from a previous run, which may have used a higher chunk count.
+    pub async fn remove_chunks(&self) -> Result<()> {
+        for path in self.existing_chunks().await? {
+            trace!(?path, "Removing old chunk.");
+            tokio::fs::remove_file(path).await?;
+        }
+        Ok(())
+    }
+
+    /// Sizes of all non-empty chunks `num_icons` icons are distributed over.
+    fn chunk_sizes(&self, num_icons: usize) -> impl Iterator<Item = usize> {
+        let chunk_size = num_icons
+            .div_ceil(self.chunks.max(1))
+            .clamp(1, MAX_CHUNK_ICONS);
+        (0..num_icons)
+            .step_by(chunk_size)
+            .map(move |start| chunk_size.min(num_icons - start))
+    }
+}
+
+pub(super) mod filters {
+    use crate::icon::svg::AttrValue;
+    pub fn attribute_value(opt: &Option<AttrValue>) -> ::askama::Result<String> {
+        Ok(format!("{:?}", opt.as_deref()))
+    }
+
+    pub fn optional_str(opt: &Option<String>) -> ::askama::Result<String> {
+        Ok(format!("{:?}", opt.as_deref()))
+    }
+
+    pub fn paint_variant(paint: &icondata_core::IconPaint) -> ::askama::Result<String> {
+        Ok(format!("{paint:?}"))
+    }
+}
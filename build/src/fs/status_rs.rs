@@ -0,0 +1,33 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use askama::Template;
+
+use crate::status::{self, HttpStatuses, LogLevels};
+
+/// The `status` module of the main library, see [`crate::status`].
+#[derive(Debug)]
+pub struct StatusRs {
+    pub path: PathBuf,
+}
+
+impl StatusRs {
+    /// Contents of the module, reading `CompressedIcon`s if the icon libraries are `compressed`.
+    pub fn contents(compressed: bool) -> Result<String> {
+        #[derive(Template)]
+        #[template(path = "main_lib/status.rs", escape = "none")]
+        struct Template {
+            http_statuses: HttpStatuses,
+            log_levels: LogLevels,
+            compressed: bool,
+        }
+
+        let (http_statuses, log_levels) = status::statuses()?;
+        Ok(Template {
+            http_statuses,
+            log_levels,
+            compressed,
+        }
+        .render()?)
+    }
+}
@@ -0,0 +1,30 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use askama::Template;
+
+use crate::user_agent::{self, Enum, Variants};
+
+/// The `user_agent` module of the main library, see [`crate::user_agent`].
+#[derive(Debug)]
+pub struct UserAgentRs {
+    pub path: PathBuf,
+}
+
+impl UserAgentRs {
+    /// Contents of the module, reading `CompressedIcon`s if the icon libraries are `compressed`.
+    pub fn contents(compressed: bool) -> Result<String> {
+        #[derive(Template)]
+        #[template(path = "main_lib/user_agent.rs", escape = "none")]
+        struct Template {
+            enums: Vec<(&'static Enum, Variants)>,
+            compressed: bool,
+        }
+
+        Ok(Template {
+            enums: user_agent::enums()?,
+            compressed,
+        }
+        .render()?)
+    }
+}
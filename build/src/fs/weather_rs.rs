@@ -0,0 +1,30 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use askama::Template;
+
+use crate::weather::{self, Condition, ConditionIcons};
+
+/// The `weather` module of the main library, see [`crate::weather`].
+#[derive(Debug)]
+pub struct WeatherRs {
+    pub path: PathBuf,
+}
+
+impl WeatherRs {
+    /// Contents of the module, reading `CompressedIcon`s if the icon libraries are `compressed`.
+    pub fn contents(compressed: bool) -> Result<String> {
+        #[derive(Template)]
+        #[template(path = "main_lib/weather.rs", escape = "none")]
+        struct Template {
+            conditions: Vec<(&'static Condition, ConditionIcons)>,
+            compressed: bool,
+        }
+
+        Ok(Template {
+            conditions: weather::conditions()?,
+            compressed,
+        }
+        .render()?)
+    }
+}
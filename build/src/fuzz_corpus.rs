@@ -0,0 +1,52 @@
+use std::{collections::BTreeSet, path::Path};
+
+use anyhow::Result;
+use tracing::{info, instrument, warn};
+
+use crate::{dirs::write_to_file, icon::shape, Packages};
+
+/// Seed the corpora of the fuzz targets in `fuzz/` below `out`: the upstream svg file of every
+/// icon for `parse_svg` and every distinct path data of the generated icons for `path_segments`.
+///
+/// Upstream files are read from the download directory of each package, so vendored packages only
+/// contribute their path data.
+#[instrument(level = "info")]
+pub(crate) async fn export(out: &Path) -> Result<()> {
+    let mut num_svgs = 0;
+    let mut paths = BTreeSet::new();
+    for package in Packages::get()? {
+        let download_path = package.download_path();
+        for icon in package.icons() {
+            match tokio::fs::read(download_path.join(&icon.source_path)).await {
+                Ok(svg) => {
+                    let path = out.join("parse_svg").join(format!("{}.svg", icon.name));
+                    write_to_file(&path, svg).await?;
+                    num_svgs += 1;
+                }
+                Err(err) => warn!(
+                    icon = icon.name,
+                    %err,
+                    "Could not read the upstream file of the icon, skipping it."
+                ),
+            }
+            for (_, attributes) in shape::elements(&icon.svg.content) {
+                paths.extend(
+                    attributes
+                        .into_iter()
+                        .filter(|(name, _)| *name == "d")
+                        .map(|(_, d)| d.to_owned()),
+                );
+            }
+        }
+    }
+
+    for (i, d) in paths.iter().enumerate() {
+        write_to_file(&out.join("path_segments").join(i.to_string()), d).await?;
+    }
+    info!(
+        num_svgs,
+        num_paths = paths.len(),
+        "Exported the corpora of the fuzz targets."
+    );
+    Ok(())
+}
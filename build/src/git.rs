@@ -1,6 +1,6 @@
 use std::{
     io,
-    path::PathBuf,
+    path::{Path, PathBuf},
     process::{Command, ExitStatus},
 };
 
@@ -29,14 +29,24 @@ pub(crate) enum Error {
     },
 }
 
+/// A git command, routed through the given HTTP(S) proxy if any.
+fn git_command(proxy: Option<&str>) -> Command {
+    let mut cmd = Command::new("git");
+    if let Some(proxy) = proxy {
+        cmd.arg("-c").arg(format!("http.proxy={proxy}"));
+    }
+    cmd
+}
+
 /// Clone the given repository at a specific commit ref or tag.
-#[instrument(level = "info")]
+#[instrument(level = "info", skip(proxy))]
 pub(crate) fn clone(
     git_url: &str,
     git_target: &GitTarget,
     target_dir: &PathBuf,
+    proxy: Option<&str>,
 ) -> Result<(), Error> {
-    let mut cmd = Command::new("git");
+    let mut cmd = git_command(proxy);
     cmd.args(["clone", "--depth", "1", "--branch"]);
 
     match git_target {
@@ -96,10 +106,14 @@ pub(crate) fn clone(
 /// Clone the given repository without checking out any specific commit or tag.
 /// This might be used in conjunction with `git::checkout()` if a simple `git::clone()` fails.
 /// You have to call `git::checkout()` after this was successful.
-#[instrument(level = "info")]
-pub(crate) fn clone_without_checkout(git_url: &str, target_dir: &PathBuf) -> Result<(), Error> {
+#[instrument(level = "info", skip(proxy))]
+pub(crate) fn clone_without_checkout(
+    git_url: &str,
+    target_dir: &PathBuf,
+    proxy: Option<&str>,
+) -> Result<(), Error> {
     let clone_output = {
-        let mut cmd = Command::new("git");
+        let mut cmd = git_command(proxy);
         cmd.args(["clone", "--no-checkout", "--single-branch", git_url]);
         cmd.arg(target_dir);
         debug!(
@@ -133,6 +147,52 @@ pub(crate) fn clone_without_checkout(git_url: &str, target_dir: &PathBuf) -> Res
     Ok(())
 }
 
+/// Fetch a specific commit ref or tag into the repository at `target_dir`, e.g. after the ref of
+/// its package changed, without checking it out.
+#[instrument(level = "info", skip(proxy))]
+pub(crate) fn fetch(
+    git_target: &GitTarget,
+    target_dir: &PathBuf,
+    proxy: Option<&str>,
+) -> Result<(), Error> {
+    let fetch_output = {
+        let mut cmd = git_command(proxy);
+        cmd.args(["fetch", "--depth", "1", "origin"]);
+        match git_target {
+            package::GitTarget::Branch { commit_ref, .. } => cmd.arg(commit_ref.as_ref()),
+            // Fetching the tag into a tag of the same name, so that it can be checked out.
+            package::GitTarget::Tag { name, .. } => {
+                cmd.arg(format!("refs/tags/{name}:refs/tags/{name}"))
+            }
+        };
+        debug!(?cmd, "Constructed command to fetch specific ref.");
+        cmd.current_dir(target_dir)
+            .output()
+            .context(ExecCommandSnafu {
+                cmd: format!("{cmd:?}"),
+            })
+    }?;
+
+    ensure!(fetch_output.status.success(), {
+        let std_out = String::from_utf8_lossy(&fetch_output.stdout).into_owned();
+        let std_err = String::from_utf8_lossy(&fetch_output.stderr).into_owned();
+        warn!(
+            exit_code = fetch_output.status.code(),
+            ?std_out,
+            ?std_err,
+            "Fetch failed."
+        );
+        CommandSnafu {
+            exit_status: fetch_output.status,
+            std_out,
+            std_err,
+        }
+    });
+
+    info!("Fetch successful.");
+    Ok(())
+}
+
 /// Checkout a specific tag or commit. `target_dir` must point to a directory containing a cloned git repository.v
 #[instrument(level = "info")]
 pub(crate) fn checkout(git_target: &GitTarget, target_dir: &PathBuf) -> Result<(), Error> {
@@ -177,3 +237,189 @@ pub(crate) fn checkout(git_target: &GitTarget, target_dir: &PathBuf) -> Result<(
     info!("Checkout successful.");
     Ok(())
 }
+
+/// The commit currently checked out in the repository at `dir`.
+#[instrument(level = "debug")]
+pub(crate) fn head_commit(dir: &Path) -> Result<String, Error> {
+    let rev_parse_output = {
+        let mut cmd = Command::new("git");
+        cmd.args(["rev-parse", "HEAD"]);
+        debug!(
+            ?cmd,
+            "Constructed command to resolve the checked out commit."
+        );
+        cmd.current_dir(dir).output().context(ExecCommandSnafu {
+            cmd: format!("{cmd:?}"),
+        })
+    }?;
+
+    ensure!(
+        rev_parse_output.status.success(),
+        CommandSnafu {
+            exit_status: rev_parse_output.status,
+            std_out: String::from_utf8_lossy(&rev_parse_output.stdout).into_owned(),
+            std_err: String::from_utf8_lossy(&rev_parse_output.stderr).into_owned(),
+        }
+    );
+
+    Ok(String::from_utf8_lossy(&rev_parse_output.stdout)
+        .trim()
+        .to_owned())
+}
+
+/// List all lines matching `pattern` in files below `dir`, as they were at the given revision.
+#[instrument(level = "debug")]
+pub(crate) fn grep(revision: &str, pattern: &str, dir: &Path) -> Result<Vec<String>, Error> {
+    let grep_output = {
+        let mut cmd = Command::new("git");
+        cmd.args(["grep", "--no-color", "-h", "-E", pattern, revision, "--"]);
+        cmd.arg(dir);
+        debug!(?cmd, "Constructed command to grep GIT revision.");
+        cmd.current_dir(path::library_crate(""))
+            .output()
+            .context(ExecCommandSnafu {
+                cmd: format!("{cmd:?}"),
+            })
+    }?;
+
+    // git-grep exits with 1 when nothing was found.
+    ensure!(
+        grep_output.status.success() || grep_output.status.code() == Some(1),
+        CommandSnafu {
+            exit_status: grep_output.status,
+            std_out: String::from_utf8_lossy(&grep_output.stdout).into_owned(),
+            std_err: String::from_utf8_lossy(&grep_output.stderr).into_owned(),
+        }
+    );
+
+    Ok(String::from_utf8_lossy(&grep_output.stdout)
+        .lines()
+        .map(str::to_owned)
+        .collect())
+}
+
+/// Test whether `dir` contains changes compared to the given revision, including untracked files.
+#[instrument(level = "debug")]
+pub(crate) fn has_changes(revision: &str, dir: &Path) -> Result<bool, Error> {
+    let diff_output = {
+        let mut cmd = Command::new("git");
+        cmd.args(["diff", "--quiet", revision, "--"]);
+        cmd.arg(dir);
+        debug!(?cmd, "Constructed command to diff against GIT revision.");
+        cmd.current_dir(path::library_crate(""))
+            .output()
+            .context(ExecCommandSnafu {
+                cmd: format!("{cmd:?}"),
+            })
+    }?;
+
+    // git-diff --quiet exits with 1 when there were differences.
+    match diff_output.status.code() {
+        Some(0) => {}
+        Some(1) => return Ok(true),
+        _ => {
+            return CommandSnafu {
+                exit_status: diff_output.status,
+                std_out: String::from_utf8_lossy(&diff_output.stdout).into_owned(),
+                std_err: String::from_utf8_lossy(&diff_output.stderr).into_owned(),
+            }
+            .fail()
+        }
+    }
+
+    let untracked_output = {
+        let mut cmd = Command::new("git");
+        cmd.args(["ls-files", "--others", "--exclude-standard", "--"]);
+        cmd.arg(dir);
+        debug!(?cmd, "Constructed command to list untracked files.");
+        cmd.current_dir(path::library_crate(""))
+            .output()
+            .context(ExecCommandSnafu {
+                cmd: format!("{cmd:?}"),
+            })
+    }?;
+
+    ensure!(
+        untracked_output.status.success(),
+        CommandSnafu {
+            exit_status: untracked_output.status,
+            std_out: String::from_utf8_lossy(&untracked_output.stdout).into_owned(),
+            std_err: String::from_utf8_lossy(&untracked_output.stderr).into_owned(),
+        }
+    );
+
+    Ok(!untracked_output.stdout.is_empty())
+}
+
+/// The refs of the remote repository at `git_url` matching any of `patterns`, e.g.
+/// `refs/tags/*`, as pairs of commit and ref name.
+#[instrument(level = "debug", skip(proxy))]
+pub(crate) fn ls_remote(
+    git_url: &str,
+    patterns: &[&str],
+    proxy: Option<&str>,
+) -> Result<Vec<(String, String)>, Error> {
+    let ls_remote_output = {
+        let mut cmd = git_command(proxy);
+        cmd.args(["ls-remote", "--refs", git_url]);
+        cmd.args(patterns);
+        debug!(?cmd, "Constructed command to list remote refs.");
+        cmd.current_dir(path::build_crate(""))
+            .output()
+            .context(ExecCommandSnafu {
+                cmd: format!("{cmd:?}"),
+            })
+    }?;
+
+    ensure!(
+        ls_remote_output.status.success(),
+        CommandSnafu {
+            exit_status: ls_remote_output.status,
+            std_out: String::from_utf8_lossy(&ls_remote_output.stdout).into_owned(),
+            std_err: String::from_utf8_lossy(&ls_remote_output.stderr).into_owned(),
+        }
+    );
+
+    Ok(String::from_utf8_lossy(&ls_remote_output.stdout)
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .map(|(commit, name)| (commit.to_owned(), name.to_owned()))
+        .collect())
+}
+
+/// The last `limit` commits changing files below `dir`, newest first, as pairs of commit and
+/// committer date, e.g. "2024-01-16".
+#[instrument(level = "debug")]
+pub(crate) fn log(dir: &Path, limit: usize) -> Result<Vec<(String, String)>, Error> {
+    let log_output = {
+        let mut cmd = Command::new("git");
+        cmd.args(["log", "--format=%H %cs"]);
+        cmd.arg(format!("--max-count={limit}"));
+        cmd.arg("--");
+        cmd.arg(dir);
+        debug!(
+            ?cmd,
+            "Constructed command to list the commits changing a directory."
+        );
+        cmd.current_dir(path::library_crate(""))
+            .output()
+            .context(ExecCommandSnafu {
+                cmd: format!("{cmd:?}"),
+            })
+    }?;
+
+    ensure!(
+        log_output.status.success(),
+        CommandSnafu {
+            exit_status: log_output.status,
+            std_out: String::from_utf8_lossy(&log_output.stdout).into_owned(),
+            std_err: String::from_utf8_lossy(&log_output.stderr).into_owned(),
+        }
+    );
+
+    Ok(String::from_utf8_lossy(&log_output.stdout)
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .map(|(commit, date)| (commit.to_owned(), date.to_owned()))
+        .collect())
+}
@@ -0,0 +1,149 @@
+use std::path::Path;
+
+use anyhow::{anyhow, ensure, Result};
+use serde_json::json;
+use tracing::{info, instrument};
+
+use crate::{
+    dirs::write_to_file,
+    icon::mesh::{self, Mesh},
+    Packages,
+};
+
+/// Component types and buffer targets of the glTF specification.
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+/// Write the extruded mesh of each of the given icons to `out/<icon>.glb`, `size` meters wide and
+/// `depth` meters deep.
+#[instrument(level = "info")]
+pub(crate) async fn export(icons: &[String], out: &Path, size: f64, depth: f64) -> Result<()> {
+    for name in icons {
+        let icon = Packages::get_icons()?
+            .find(|icon| icon.name == *name)
+            .ok_or_else(|| anyhow!("There is no icon named {name}."))?;
+        let mesh = mesh::extrude(&icon.svg, size, depth)?;
+        ensure!(!mesh.indices.is_empty(), "Icon {name} has no filled area.");
+        write_to_file(&out.join(format!("{name}.glb")), glb(name, &mesh)?).await?;
+    }
+
+    info!(num_icons = icons.len(), ?out, "Exported extruded icons.");
+    Ok(())
+}
+
+/// Binary glTF of a single node drawing the mesh with a dark, matte material.
+fn glb(name: &str, mesh: &Mesh) -> Result<Vec<u8>> {
+    let mut buffer = Vec::with_capacity((mesh.positions.len() * 3 + mesh.indices.len()) * 4);
+    buffer.extend(
+        mesh.positions
+            .iter()
+            .flatten()
+            .flat_map(|c| c.to_le_bytes()),
+    );
+    let positions_len = buffer.len();
+    buffer.extend(mesh.indices.iter().flat_map(|i| i.to_le_bytes()));
+
+    let (min, max) = mesh.bounds();
+    let gltf = json!({
+        "asset": { "version": "2.0", "generator": "icondata-builder" },
+        "scene": 0,
+        "scenes": [{ "nodes": [0] }],
+        "nodes": [{ "name": name, "mesh": 0 }],
+        "meshes": [{
+            "name": name,
+            "primitives": [{ "attributes": { "POSITION": 0 }, "indices": 1, "material": 0 }],
+        }],
+        "materials": [{
+            "pbrMetallicRoughness": {
+                "baseColorFactor": [0.1, 0.1, 0.1, 1.0],
+                "metallicFactor": 0.0,
+                "roughnessFactor": 0.6,
+            },
+        }],
+        "buffers": [{ "byteLength": buffer.len() }],
+        "bufferViews": [
+            {
+                "buffer": 0,
+                "byteOffset": 0,
+                "byteLength": positions_len,
+                "target": ARRAY_BUFFER,
+            },
+            {
+                "buffer": 0,
+                "byteOffset": positions_len,
+                "byteLength": buffer.len() - positions_len,
+                "target": ELEMENT_ARRAY_BUFFER,
+            },
+        ],
+        "accessors": [
+            {
+                "bufferView": 0,
+                "componentType": FLOAT,
+                "count": mesh.positions.len(),
+                "type": "VEC3",
+                "min": min,
+                "max": max,
+            },
+            {
+                "bufferView": 1,
+                "componentType": UNSIGNED_INT,
+                "count": mesh.indices.len(),
+                "type": "SCALAR",
+            },
+        ],
+    });
+
+    // Chunks are aligned to four bytes, the JSON chunk is padded with spaces. The binary chunk
+    // holds four byte numbers only.
+    let mut json = serde_json::to_vec(&gltf)?;
+    json.resize(json.len().next_multiple_of(4), b' ');
+
+    let len = 12 + 8 + json.len() + 8 + buffer.len();
+    let mut glb = Vec::with_capacity(len);
+    glb.extend(b"glTF");
+    glb.extend(2u32.to_le_bytes());
+    glb.extend((len as u32).to_le_bytes());
+    glb.extend((json.len() as u32).to_le_bytes());
+    glb.extend(b"JSON");
+    glb.extend(json);
+    glb.extend((buffer.len() as u32).to_le_bytes());
+    glb.extend(b"BIN\0");
+    glb.extend(buffer);
+    Ok(glb)
+}
+
+#[cfg(test)]
+mod test {
+    use super::glb;
+    use crate::icon::mesh::Mesh;
+
+    #[test]
+    fn writes_aligned_binary_gltf() {
+        let mesh = Mesh {
+            positions: vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.5]],
+            indices: vec![0, 1, 2],
+        };
+        let glb = glb("BsTriangle", &mesh).unwrap();
+
+        let u32_at = |i: usize| u32::from_le_bytes(glb[i..i + 4].try_into().unwrap()) as usize;
+        assert_eq!(&glb[..4], b"glTF");
+        assert_eq!(u32_at(8), glb.len());
+        let json_len = u32_at(12);
+        assert_eq!(json_len % 4, 0);
+        assert_eq!(&glb[16..20], b"JSON");
+
+        let gltf: serde_json::Value = serde_json::from_slice(&glb[20..20 + json_len]).unwrap();
+        assert_eq!(
+            gltf["accessors"][0]["max"],
+            serde_json::json!([1.0, 1.0, 0.5])
+        );
+        assert_eq!(gltf["nodes"][0]["name"], "BsTriangle");
+
+        let bin = 20 + json_len;
+        assert_eq!(u32_at(bin), 3 * 12 + 3 * 4);
+        assert_eq!(&glb[bin + 4..bin + 8], b"BIN\0");
+        assert_eq!(bin + 8 + u32_at(bin), glb.len());
+    }
+}
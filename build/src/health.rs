@@ -0,0 +1,302 @@
+//! A health summary of every package for a status dashboard, so maintainers can see which packages
+//! need attention before a release: packages behind their upstream, packages logging warnings
+//! while their icons are read, packages rendering differently than upstream, and packages whose
+//! number of icons changes unexpectedly.
+
+use std::{collections::BTreeMap, fmt::Debug, path::Path, sync::Mutex};
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use tracing::{
+    field::{Field, Visit},
+    info, instrument,
+    span::{Attributes, Id},
+    warn, Event, Level, Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+use crate::{
+    dirs::write_to_file,
+    fidelity, git,
+    lockfile::Lockfile,
+    package::{Downloaded, GitTarget, Package, PackageSource, SourceConfig},
+    path,
+    sem_ver::SemVer,
+    Packages,
+};
+
+/// Width and height in pixels icons are rendered with to compare them with upstream.
+const FIDELITY_SIZE: u32 = 64;
+
+/// Warnings logged while reading each package, keyed by short name, see [`WarningCounter`].
+static WARNINGS: Lazy<Mutex<BTreeMap<String, usize>>> = Lazy::new(Default::default);
+
+#[derive(Debug, Serialize)]
+struct Report {
+    /// Day the report was written, e.g. "2024-01-16".
+    date: String,
+    packages: Vec<PackageHealth>,
+}
+
+#[derive(Debug, Serialize)]
+struct PackageHealth {
+    short_name: String,
+    package_name: String,
+    /// How the generated crate compares to upstream, `None` for local packages, offline or if
+    /// upstream could not be reached.
+    freshness: Option<Freshness>,
+    /// Number of warnings logged while reading the icons of the package, e.g. about unexpected
+    /// svg attributes.
+    num_warnings: usize,
+    /// Mean difference of the icons rendered from upstream and as generated, from 0 for identical
+    /// renders to 1.
+    fidelity_mean_diff: f64,
+    /// Number of icons which could not be compared with upstream.
+    num_fidelity_skipped: usize,
+    /// Number of icons the crate is generated with now.
+    num_icons: usize,
+    /// Number of icons of the committed crate after each of the last commits changing it, newest
+    /// first.
+    icon_counts: Vec<IconCount>,
+}
+
+#[derive(Debug, Serialize)]
+struct Freshness {
+    /// Tag or commit ref of the package recorded in the lockfile, `None` if it was never generated.
+    locked: Option<String>,
+    /// Newest release tag upstream, or the commit the branch of the package points to.
+    latest: String,
+    /// Whether upstream has a newer release or commit than recorded in the lockfile.
+    outdated: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct IconCount {
+    commit: String,
+    date: String,
+    num_icons: usize,
+}
+
+/// Write the health of all packages to `out` as JSON, with the number of icons of each crate over
+/// the last `history` commits changing it.
+#[instrument(level = "info", skip(sources, lockfile))]
+pub(crate) async fn report(
+    out: &Path,
+    history: usize,
+    sources: &SourceConfig,
+    lockfile: &Lockfile,
+) -> Result<()> {
+    let mut packages = Vec::new();
+    for package in Packages::get()? {
+        let health = package_health(package, history, sources, lockfile).await?;
+        info!(
+            package = health.short_name,
+            outdated = health
+                .freshness
+                .as_ref()
+                .map(|freshness| freshness.outdated),
+            health.num_warnings,
+            health.fidelity_mean_diff,
+            health.num_icons,
+            "Checked package health."
+        );
+        packages.push(health);
+    }
+
+    let report = Report {
+        date: time::OffsetDateTime::now_utc().date().to_string(),
+        packages,
+    };
+    write_to_file(&out.to_path_buf(), serde_json::to_string_pretty(&report)?).await?;
+    info!(?out, "Wrote health report.");
+    Ok(())
+}
+
+async fn package_health(
+    package: &'static Package<Downloaded>,
+    history: usize,
+    sources: &SourceConfig,
+    lockfile: &Lockfile,
+) -> Result<PackageHealth> {
+    let short_name = package.meta.short_name.as_ref();
+    let freshness = match sources.offline {
+        true => None,
+        false => freshness(package, sources, lockfile).unwrap_or_else(|err| {
+            warn!(package = short_name, ?err, "Could not check upstream.");
+            None
+        }),
+    };
+    let fidelity = fidelity::package_fidelity(package, FIDELITY_SIZE).await?;
+
+    let crate_path = path::library_crate(format!("icondata_{short_name}"));
+    let mut icon_counts = Vec::new();
+    for (commit, date) in git::log(&crate_path, history)? {
+        // Every icon declares its id once, aliases refer to icons instead.
+        let num_icons = git::grep(&commit, r#"^[[:space:]]+id: ""#, &crate_path.join("src"))?.len();
+        icon_counts.push(IconCount {
+            commit,
+            date,
+            num_icons,
+        });
+    }
+
+    Ok(PackageHealth {
+        short_name: short_name.to_owned(),
+        package_name: package.meta.package_name.to_string(),
+        freshness,
+        num_warnings: num_warnings(short_name),
+        fidelity_mean_diff: fidelity.mean_diff,
+        num_fidelity_skipped: fidelity.skipped.len(),
+        num_icons: package.icons().len(),
+        icon_counts,
+    })
+}
+
+/// Compare the ref of the package recorded in the lockfile with upstream: packages checking out a
+/// tag with the newest release tag, packages checking out a branch with its newest commit.
+/// Packages downloaded from npm are not compared.
+fn freshness(
+    package: &Package<Downloaded>,
+    sources: &SourceConfig,
+    lockfile: &Lockfile,
+) -> Result<Option<Freshness>> {
+    let PackageSource::Git { url, target } = &package.meta.source else {
+        return Ok(None);
+    };
+    let short_name = package.meta.short_name.as_ref();
+    let url = sources
+        .mirrors
+        .get(short_name)
+        .map(String::as_str)
+        .unwrap_or(url.as_ref());
+    let proxy = sources.proxy.as_deref();
+    let locked = lockfile.get(short_name);
+
+    Ok(match target {
+        GitTarget::Tag { .. } => {
+            let tags = git::ls_remote(url, &["refs/tags/*"], proxy)?;
+            let tags = tags
+                .iter()
+                .filter_map(|(_, name)| name.strip_prefix("refs/tags/"));
+            latest_release(tags).map(|(latest, version)| Freshness {
+                locked: locked.map(|locked| locked.reference().to_owned()),
+                latest: latest.to_owned(),
+                outdated: locked.map_or(true, |locked| {
+                    tag_version(locked.reference()).map_or(locked.reference() != latest, |locked| {
+                        locked.is_lower_than(&version)
+                    })
+                }),
+            })
+        }
+        GitTarget::Branch { name, .. } => {
+            git::ls_remote(url, &[format!("refs/heads/{name}").as_str()], proxy)?
+                .into_iter()
+                .next()
+                .map(|(latest, _)| Freshness {
+                    locked: locked.map(|locked| locked.revision().to_owned()),
+                    outdated: locked.map_or(true, |locked| locked.revision() != latest),
+                    latest,
+                })
+        }
+    })
+}
+
+/// The tag naming the newest version, skipping prereleases and tags naming no version.
+fn latest_release<'a>(tags: impl IntoIterator<Item = &'a str>) -> Option<(&'a str, SemVer)> {
+    tags.into_iter()
+        .filter_map(|tag| Some((tag, tag_version(tag)?)))
+        .filter(|(_, version)| version.prerelease.is_none())
+        .reduce(|latest, tag| match latest.1.is_lower_than(&tag.1) {
+            true => tag,
+            false => latest,
+        })
+}
+
+/// The version a tag names, e.g. "6.5.1" for "v6.5.1".
+fn tag_version(tag: &str) -> Option<SemVer> {
+    tag.trim_start_matches('v').parse().ok()
+}
+
+fn num_warnings(short_name: &str) -> usize {
+    WARNINGS
+        .lock()
+        .expect("no panic while counting")
+        .get(short_name)
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Counts the warnings logged within the span of a package, the outermost span with a `package`
+/// field holding its short name, like the one of [`Package::download`].
+pub(crate) struct WarningCounter;
+
+/// Short name of the package of a span, see [`WarningCounter`].
+struct SpanPackage(String);
+
+/// Reads the `package` field of a span, if recorded as string.
+#[derive(Default)]
+struct PackageVisitor(Option<String>);
+
+impl Visit for PackageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "package" {
+            self.0 = Some(value.to_owned());
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn Debug) {}
+}
+
+impl<S> Layer<S> for WarningCounter
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = PackageVisitor::default();
+        attrs.record(&mut visitor);
+        if let (Some(package), Some(span)) = (visitor.0, ctx.span(id)) {
+            span.extensions_mut().insert(SpanPackage(package));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        if *event.metadata().level() != Level::WARN {
+            return;
+        }
+        let package = ctx.event_scope(event).and_then(|scope| {
+            scope.from_root().find_map(|span| {
+                span.extensions()
+                    .get::<SpanPackage>()
+                    .map(|package| package.0.clone())
+            })
+        });
+        if let Some(package) = package {
+            *WARNINGS
+                .lock()
+                .expect("no panic while counting")
+                .entry(package)
+                .or_default() += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::latest_release;
+
+    #[test]
+    fn finds_latest_release() {
+        let tags = [
+            "v6.4.2",
+            "6.10.0",
+            "6.11.0-beta1",
+            "release-candidate",
+            "v6.9.9",
+        ];
+        let (latest, version) = latest_release(tags).unwrap();
+        assert_eq!(latest, "6.10.0");
+        assert_eq!(version.to_string(), "6.10.0");
+        assert_eq!(latest_release(["main", "next"]), None);
+    }
+}
@@ -0,0 +1,190 @@
+//! Snapping icon outlines to the pixel grid of a small rendering size.
+//!
+//! Icons designed on a 24 unit grid and rendered at 16 pixels place most edges between two pixels,
+//! which anti-aliasing turns into blurry lines. Moving every anchor point to the nearest pixel
+//! boundary keeps such edges crisp. Control points of curves move along with their anchors, so
+//! curves keep their shape. Transforms are not taken into account.
+
+use std::fmt::Write;
+
+use super::{
+    shape::{self, Cursor, Segment},
+    simplify::number,
+};
+
+/// Category of the aligned variant of an icon.
+pub const CATEGORY: &str = "aligned";
+
+/// Snaps all coordinates of the given svg content to the pixel grid of the icon rendered with
+/// a size of `pixels`.
+pub fn align(content: &str, view_box: Option<&str>, pixels: u32) -> String {
+    let [min_x, min_y, width, height] = view_box
+        .and_then(shape::parse_view_box)
+        .unwrap_or(shape::DEFAULT_VIEW_BOX);
+    let grid = Grid {
+        min: (min_x, min_y),
+        step: width.max(height) / pixels as f64,
+    };
+
+    let elements = shape::elements(content);
+    let mut replacements = Vec::new();
+    for (tag, attributes) in &elements {
+        let attr = |name: &str| {
+            attributes
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| *value)
+        };
+        let num = |name: &str| attr(name).and_then(|value| Cursor::new(value).number());
+        let snap = |name: &str, min: f64| {
+            let value = attr(name)?;
+            Some((value, number(grid.snap(num(name)?, min))))
+        };
+
+        match *tag {
+            "path" => {
+                if let Some(d) = attr("d") {
+                    replacements.push((d, align_path(&shape::path_segments(d), grid)));
+                }
+            }
+            "rect" => {
+                let (x, y) = (num("x").unwrap_or(0.0), num("y").unwrap_or(0.0));
+                let (w, h) = (num("width").unwrap_or(0.0), num("height").unwrap_or(0.0));
+                let (left, top) = (grid.snap(x, grid.min.0), grid.snap(y, grid.min.1));
+                let (right, bottom) = (grid.snap(x + w, grid.min.0), grid.snap(y + h, grid.min.1));
+                for (name, value) in [
+                    ("x", left),
+                    ("y", top),
+                    ("width", right - left),
+                    ("height", bottom - top),
+                ] {
+                    if let Some(original) = attr(name) {
+                        replacements.push((original, number(value)));
+                    }
+                }
+            }
+            "line" => replacements.extend(
+                [
+                    snap("x1", grid.min.0),
+                    snap("y1", grid.min.1),
+                    snap("x2", grid.min.0),
+                    snap("y2", grid.min.1),
+                ]
+                .into_iter()
+                .flatten(),
+            ),
+            "circle" | "ellipse" => replacements.extend(
+                [snap("cx", grid.min.0), snap("cy", grid.min.1)]
+                    .into_iter()
+                    .flatten(),
+            ),
+            "polygon" | "polyline" => {
+                if let Some(points) = attr("points") {
+                    let mut cursor = Cursor::new(points);
+                    let mut aligned = Vec::new();
+                    while let (Some(x), Some(y)) = (cursor.number(), cursor.number()) {
+                        let (x, y) = grid.snap_point((x, y));
+                        aligned.push(format!("{} {}", number(x), number(y)));
+                    }
+                    replacements.push((points, aligned.join(" ")));
+                }
+            }
+            _ => {}
+        }
+    }
+    // Attributes of an element are replaced in the order they were written, not looked up.
+    replacements.sort_by_key(|(value, _)| value.as_ptr() as usize);
+    shape::replace_values(content, &replacements)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Grid {
+    min: (f64, f64),
+    /// Size of a pixel in view box units.
+    step: f64,
+}
+
+impl Grid {
+    fn snap(self, value: f64, min: f64) -> f64 {
+        min + ((value - min) / self.step).round() * self.step
+    }
+
+    fn snap_point(self, (x, y): (f64, f64)) -> (f64, f64) {
+        (self.snap(x, self.min.0), self.snap(y, self.min.1))
+    }
+}
+
+/// Absolute path data of the given segments with all anchors snapped to the grid.
+fn align_path(segments: &[Segment], grid: Grid) -> String {
+    let mut d = String::new();
+    let point = |(x, y): (f64, f64)| format!("{} {}", number(x), number(y));
+    let moved = |(x, y): (f64, f64), (dx, dy): (f64, f64)| (x + dx, y + dy);
+    // How far the current point was moved by snapping it.
+    let mut offset = (0.0, 0.0);
+
+    for segment in segments {
+        let end = segment.end();
+        let snapped = grid.snap_point(end);
+        let end_offset = (snapped.0 - end.0, snapped.1 - end.1);
+        match segment {
+            Segment::Move(_) => write!(d, "M{}", point(snapped)),
+            Segment::Line { close: true, .. } => write!(d, "Z"),
+            Segment::Line { .. } => write!(d, "L{}", point(snapped)),
+            Segment::Curve(control) if control.len() == 4 => write!(
+                d,
+                "C{} {} {}",
+                point(moved(control[1], offset)),
+                point(moved(control[2], end_offset)),
+                point(snapped)
+            ),
+            Segment::Curve(control) => {
+                let average = (
+                    (offset.0 + end_offset.0) / 2.0,
+                    (offset.1 + end_offset.1) / 2.0,
+                );
+                write!(
+                    d,
+                    "Q{} {}",
+                    point(moved(control[1], average)),
+                    point(snapped)
+                )
+            }
+            Segment::Arc {
+                radii,
+                rotation,
+                large_arc,
+                sweep,
+                ..
+            } => write!(
+                d,
+                "A{} {} {} {} {}",
+                point(*radii),
+                number(*rotation),
+                u8::from(*large_arc),
+                u8::from(*sweep),
+                point(snapped)
+            ),
+        }
+        .expect("writing to a string");
+        offset = end_offset;
+    }
+    d
+}
+
+#[cfg(test)]
+mod test {
+    use super::align;
+
+    #[test]
+    fn snaps_to_pixel_grid() {
+        // At 16 pixels, a pixel of a 24 unit view box is 1.5 units wide.
+        assert_eq!(
+            align(
+                r#"<path d="M2 2h20v10.2C22 16 18 20 12 20z"/><rect x="4" y="4" width="5" height="5"/><circle cx="12.6" cy="12" r="2"/>"#,
+                Some("0 0 24 24"),
+                16
+            ),
+            r#"<path d="M1.5 1.5L22.5 1.5L22.5 12C22.5 15.8 18 19.5 12 19.5Z"/><rect x="4.5" y="4.5" width="4.5" height="4.5"/><circle cx="12" cy="12" r="2"/>"#
+        );
+    }
+}
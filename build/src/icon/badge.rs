@@ -0,0 +1,99 @@
+//! Composition of icons onto a filled rounded square, with the glyph knocked out of it.
+//!
+//! The glyph is drawn in black into a mask over a white square, so that it cuts through the
+//! background wherever it is painted, whether filled or stroked. The background is painted with
+//! `currentColor` and the page shows through the glyph.
+
+use super::{shape, simplify::number};
+
+/// Category of the badge variant of an icon.
+pub const CATEGORY: &str = "badge";
+
+/// Size of the glyph relative to the badge.
+const GLYPH_SCALE: f64 = 0.625;
+
+/// Corner radius of the badge relative to its size.
+const CORNER_RADIUS: f64 = 0.2;
+
+/// The given svg content knocked out of a rounded square filling the view box. `fill` and `stroke`
+/// are the paint of the root svg element, `id` identifies the mask and must be unique per icon.
+pub fn badge(
+    content: &str,
+    view_box: Option<&str>,
+    fill: Option<&str>,
+    stroke: Option<&str>,
+    id: &str,
+) -> String {
+    let [min_x, min_y, width, height] = view_box
+        .and_then(shape::parse_view_box)
+        .unwrap_or(shape::DEFAULT_VIEW_BOX);
+    let n = number;
+    let square = format!(
+        r#"x="{}" y="{}" width="{}" height="{}" rx="{}""#,
+        n(min_x),
+        n(min_y),
+        n(width),
+        n(height),
+        n(width.min(height) * CORNER_RADIUS)
+    );
+    // Scale the glyph about the center of the view box.
+    let (dx, dy) = (
+        (min_x + width / 2.0) * (1.0 - GLYPH_SCALE),
+        (min_y + height / 2.0) * (1.0 - GLYPH_SCALE),
+    );
+
+    let mut glyph = format!(
+        r#"<g transform="translate({} {}) scale({GLYPH_SCALE})""#,
+        n(dx),
+        n(dy)
+    );
+    for (property, value) in [("fill", fill), ("stroke", stroke)] {
+        if value.is_some_and(is_paint) {
+            glyph.push_str(&format!(r#" {property}="black""#));
+        }
+    }
+    glyph.push('>');
+    glyph.push_str(&knockout(content));
+    glyph.push_str("</g>");
+
+    format!(
+        r#"<defs><mask id="{id}"><rect {square} fill="white"/>{glyph}</mask></defs><rect {square} fill="currentColor" stroke="none" mask="url(#{id})"/>"#
+    )
+}
+
+/// The given svg content with every paint replaced by black.
+fn knockout(content: &str) -> String {
+    let mut replacements = Vec::new();
+    for (_, attributes) in shape::elements(content) {
+        for (key, value) in attributes {
+            if matches!(key, "fill" | "stroke") && is_paint(value) {
+                replacements.push((value, "black".to_owned()));
+            }
+        }
+    }
+    shape::replace_values(content, &replacements)
+}
+
+fn is_paint(value: &str) -> bool {
+    !matches!(value.trim(), "none" | "transparent")
+}
+
+#[cfg(test)]
+mod test {
+    use super::badge;
+
+    #[test]
+    fn knocks_glyph_out_of_rounded_square() {
+        let badge = badge(
+            r##"<path d="M4 4h16v16H4z" stroke="#333"/><circle cx="12" cy="12" r="2" fill="none"/>"##,
+            Some("0 0 24 24"),
+            Some("none"),
+            Some("currentColor"),
+            "icondata-badge-bs-square",
+        );
+        assert_eq!(
+            badge,
+            r#"<defs><mask id="icondata-badge-bs-square"><rect x="0" y="0" width="24" height="24" rx="4.8" fill="white"/><g transform="translate(4.5 4.5) scale(0.625)" stroke="black"><path d="M4 4h16v16H4z" stroke="black"/><circle cx="12" cy="12" r="2" fill="none"/></g></mask></defs><rect x="0" y="0" width="24" height="24" rx="4.8" fill="currentColor" stroke="none" mask="url(#icondata-badge-bs-square)"/>"#
+        );
+    }
+}
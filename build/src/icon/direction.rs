@@ -0,0 +1,130 @@
+//! Sets of icons pointing left, right, up and down, like `TbArrowLeft` to `TbArrowDown`, for which
+//! the icon crates provide an `icondata_core::Directional`, e.g. `TbArrowDirectional()`.
+//!
+//! Icons are paired by their names, which must differ only in the direction word. Only arrows,
+//! chevrons and carets are paired, as other icons named after a direction, like text alignments,
+//! don't form a set which is flipped in right-to-left locales.
+
+use std::collections::{BTreeMap, HashSet};
+
+use super::{split_words, SvgIcon};
+
+/// Words of the names of the icons which are paired.
+const POINTERS: &[&str] = &["Arrow", "Chevron", "Caret"];
+
+/// Direction words, in the order of [`Directional::icons`].
+const DIRECTIONS: [&str; 4] = ["Left", "Right", "Up", "Down"];
+
+/// Icons of a package differing only in the direction they point to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Directional {
+    /// Name of the generated function, e.g. "TbArrowDirectional".
+    pub name: String,
+    /// Style feature shared by all icons, see [`SvgIcon::style`].
+    pub style: Option<String>,
+    pub brand: bool,
+    /// Names of the icons pointing left, right, up and down.
+    pub icons: [String; 4],
+}
+
+impl Directional {
+    /// The fields of an `icondata_core::Directional` with the names of their icons.
+    pub fn fields(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        ["left", "right", "up", "down"]
+            .into_iter()
+            .zip(self.icons.iter().map(String::as_str))
+    }
+}
+
+/// All sets of directional icons among the given icons, ordered by name.
+pub fn directionals(icons: &[SvgIcon]) -> Vec<Directional> {
+    detect(
+        icons
+            .iter()
+            .map(|icon| (icon.name.as_str(), icon.style.as_deref(), icon.brand)),
+    )
+}
+
+/// Sets among the icons given by name, style and whether they are brands.
+fn detect<'a>(
+    icons: impl IntoIterator<Item = (&'a str, Option<&'a str>, bool)>,
+) -> Vec<Directional> {
+    let icons = icons.into_iter().collect::<Vec<_>>();
+    let names = icons.iter().map(|(name, ..)| *name).collect::<HashSet<_>>();
+
+    let mut sets = BTreeMap::<(String, Option<&str>, bool), [Option<&str>; 4]>::new();
+    for (name, style, brand) in icons {
+        let mut words = split_words(name);
+        if !words.iter().any(|word| POINTERS.contains(word)) {
+            continue;
+        }
+        // Diagonal arrows like `ArrowUpRight` are not part of a set.
+        let mut directions = words
+            .iter()
+            .enumerate()
+            .filter_map(|(i, word)| Some((i, DIRECTIONS.iter().position(|d| d == word)?)));
+        let (Some((i, direction)), None) = (directions.next(), directions.next()) else {
+            continue;
+        };
+        words.remove(i);
+        sets.entry((words.concat(), style, brand)).or_default()[direction] = Some(name);
+    }
+
+    sets.into_iter()
+        .filter_map(|((stem, style, brand), icons)| {
+            let name = format!("{stem}Directional");
+            if names.contains(name.as_str()) {
+                return None;
+            }
+            Some(Directional {
+                name,
+                style: style.map(str::to_owned),
+                brand,
+                icons: [
+                    icons[0]?.to_owned(),
+                    icons[1]?.to_owned(),
+                    icons[2]?.to_owned(),
+                    icons[3]?.to_owned(),
+                ],
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::detect;
+
+    #[test]
+    fn pairs_arrows_by_direction() {
+        let directionals = detect([
+            ("TbArrowLeft", None, false),
+            ("TbArrowRight", None, false),
+            ("TbArrowUp", None, false),
+            ("TbArrowDown", None, false),
+            ("TbArrowUpRight", None, false),
+            ("TbChevronLeftCircle", Some("filled"), false),
+            ("TbChevronRightCircle", Some("filled"), false),
+            ("TbChevronUpCircle", Some("filled"), false),
+            ("TbChevronDownCircle", Some("filled"), false),
+            ("TbCaretLeft", None, false),
+            ("TbCaretRight", None, false),
+            ("TbAlignLeft", None, false),
+        ]);
+
+        let names = directionals
+            .iter()
+            .map(|directional| (directional.name.as_str(), &directional.icons[1]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                ("TbArrowDirectional", &"TbArrowRight".to_owned()),
+                (
+                    "TbChevronCircleDirectional",
+                    &"TbChevronRightCircle".to_owned()
+                ),
+            ]
+        );
+    }
+}
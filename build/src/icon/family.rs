@@ -0,0 +1,185 @@
+//! Families of icons parameterized by a number at the end of their name, like `TbBattery1` to
+//! `TbBattery4` or `LuClock1` to `LuClock12`, for which the icon crates provide an accessor
+//! returning the variant nearest to a typed value, e.g. `TbBatteryLevel(60)`.
+//!
+//! Names ending in one of the known stems of levels and hours form such families. Icons merely
+//! numbered, like dice faces or keycaps, form numbered families instead, whose accessor returns the
+//! icon showing exactly the given number, e.g. `TbDiceNumbered::n(6)`.
+
+use std::collections::{BTreeMap, HashSet};
+
+use super::SvgIcon;
+
+/// Stems of families showing a level, e.g. the charge of a battery.
+const LEVEL_STEMS: &[&str] = &["Battery", "Brightness", "Signal", "Volume", "Wifi"];
+
+/// Stems of families showing an hour on a clock.
+const HOUR_STEMS: &[&str] = &["Clock", "ClockHour"];
+
+/// Fewest numbered variants forming a family.
+const MIN_VARIANTS: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FamilyKind {
+    /// Variants for levels in percent, taking a `u8`.
+    Level,
+    /// Variants for the hours of a 12 hour dial, taking an `icondata_core::Hour`.
+    Hour,
+    /// Variants showing a number, looked up exactly by a `u8`.
+    Numbered,
+}
+
+/// Icons of a package differing only in the number ending their names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Family {
+    /// Name of the generated accessor, e.g. "TbBatteryLevel" or "LuClockAt", or of the type
+    /// providing it for numbered families, e.g. "TbDiceNumbered".
+    pub name: String,
+    pub kind: FamilyKind,
+    /// Style feature shared by all variants, see [`SvgIcon::style`].
+    pub style: Option<String>,
+    pub brand: bool,
+    /// Names of the variants by their percentage, hour or number, in ascending order.
+    pub variants: Vec<(u32, String)>,
+}
+
+impl Family {
+    /// Parameter of the generated accessor.
+    pub fn parameter(&self) -> &'static str {
+        match self.kind {
+            FamilyKind::Level => "level: u8",
+            FamilyKind::Hour => "hour: icondata_core::Hour",
+            FamilyKind::Numbered => "n: u8",
+        }
+    }
+
+    /// The parameter of the generated accessor as `u32`, compared with the values of the variants.
+    pub fn value(&self) -> &'static str {
+        match self.kind {
+            FamilyKind::Level => "u32::from(level)",
+            FamilyKind::Hour => "u32::from(hour.get())",
+            FamilyKind::Numbered => "u32::from(n)",
+        }
+    }
+
+    /// Documentation of the generated accessor.
+    pub fn doc(&self) -> &'static str {
+        match self.kind {
+            FamilyKind::Level => {
+                "The icon of this family showing the level nearest to `level`, in percent."
+            }
+            FamilyKind::Hour => "The icon of this family showing the hour nearest to `hour`.",
+            FamilyKind::Numbered => "The icon of this family showing the number `n`, if any.",
+        }
+    }
+}
+
+/// All families among the given icons, ordered by name.
+pub fn families(icons: &[SvgIcon]) -> Vec<Family> {
+    detect(
+        icons
+            .iter()
+            .map(|icon| (icon.name.as_str(), icon.style.as_deref(), icon.brand)),
+    )
+}
+
+/// Families among the icons given by name, style and whether they are brands.
+fn detect<'a>(icons: impl IntoIterator<Item = (&'a str, Option<&'a str>, bool)>) -> Vec<Family> {
+    let icons = icons.into_iter().collect::<Vec<_>>();
+    let names = icons.iter().map(|(name, ..)| *name).collect::<HashSet<_>>();
+
+    let mut numbered = BTreeMap::<(&str, Option<&str>, bool), Vec<(u32, &str)>>::new();
+    for (name, style, brand) in icons {
+        let stem = name.trim_end_matches(|c: char| c.is_ascii_digit());
+        let Ok(number) = name[stem.len()..].parse::<u32>() else {
+            continue;
+        };
+        numbered
+            .entry((stem, style, brand))
+            .or_default()
+            .push((number, name));
+    }
+
+    numbered
+        .into_iter()
+        .filter_map(|((stem, style, brand), mut variants)| {
+            let (kind, name) = if LEVEL_STEMS.iter().any(|level| stem.ends_with(level)) {
+                (FamilyKind::Level, format!("{stem}Level"))
+            } else if HOUR_STEMS.iter().any(|hour| stem.ends_with(hour)) {
+                variants.retain(|(hour, _)| (1..=12).contains(hour));
+                let stem = stem.strip_suffix("Hour").unwrap_or(stem);
+                (FamilyKind::Hour, format!("{stem}At"))
+            } else {
+                variants.retain(|(number, _)| *number <= u32::from(u8::MAX));
+                (FamilyKind::Numbered, format!("{stem}Numbered"))
+            };
+            if variants.len() < MIN_VARIANTS || names.contains(name.as_str()) {
+                return None;
+            }
+
+            // Levels up to 10 are steps, like the bars of a signal, rather than percentages.
+            let max = variants.iter().map(|(number, _)| *number).max()?;
+            let mut variants = variants
+                .into_iter()
+                .map(|(number, name)| match kind {
+                    FamilyKind::Level if max <= 10 => (number * 100 / max, name.to_owned()),
+                    _ => (number, name.to_owned()),
+                })
+                .collect::<Vec<_>>();
+            variants.sort();
+            Some(Family {
+                name,
+                kind,
+                style: style.map(str::to_owned),
+                brand,
+                variants,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{detect, FamilyKind};
+
+    #[test]
+    fn detects_numbered_families() {
+        let families = detect([
+            ("TbBattery1", None, false),
+            ("TbBattery2", None, false),
+            ("TbBattery3", None, false),
+            ("TbBattery4", None, false),
+            ("TbBattery", None, false),
+            ("TbClockHour1", None, false),
+            ("TbClockHour12", None, false),
+            ("TbClockHour6", None, false),
+            ("TbClock24", None, false),
+            ("TbDice1", None, false),
+            ("TbDice2", None, false),
+            ("TbDice3", None, false),
+            ("TbWifi1", Some("filled"), false),
+            ("TbWifi2", None, false),
+            ("TbWifi3", None, false),
+        ]);
+
+        let summary = families
+            .iter()
+            .map(|family| {
+                let values = family.variants.iter().map(|(value, _)| *value);
+                (
+                    family.name.as_str(),
+                    family.kind,
+                    values.collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                ("TbBatteryLevel", FamilyKind::Level, vec![25, 50, 75, 100]),
+                ("TbClockAt", FamilyKind::Hour, vec![1, 6, 12]),
+                ("TbDiceNumbered", FamilyKind::Numbered, vec![1, 2, 3]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! Comparing svg content by the shapes it draws rather than by its text.
+//!
+//! Changes of the optimizer rewrite the content of every icon without changing a single shape:
+//! numbers are rounded to another precision, relative commands become absolute ones, lines become
+//! horizontal or vertical commands and implicitly repeated commands are spelled out, or the other
+//! way around. Path data is compared as segments in absolute coordinates instead, and numbers with
+//! a tolerance relative to the view box, so only actual changes of the shapes remain.
+
+use super::shape::{self, Cursor, Segment};
+
+/// Largest difference of two numbers considered equal, relative to the larger side of the view box.
+const TOLERANCE: f64 = 0.002;
+
+/// Whether both svg contents draw the same elements with the same shapes and attributes, apart from
+/// the order of the attributes and differences of their numbers smaller than the tolerance.
+pub fn same_geometry(a: &str, b: &str, view_box: Option<&str>) -> bool {
+    let [_, _, width, height] = view_box
+        .and_then(shape::parse_view_box)
+        .unwrap_or(shape::DEFAULT_VIEW_BOX);
+    let tolerance = TOLERANCE * width.max(height);
+
+    let (a, b) = (shape::elements(a), shape::elements(b));
+    a.len() == b.len()
+        && a.iter()
+            .zip(&b)
+            .all(|((tag_a, attributes_a), (tag_b, attributes_b))| {
+                tag_a == tag_b
+                    && attributes_a.len() == attributes_b.len()
+                    && attributes_a.iter().all(|(key, value_a)| {
+                        attributes_b
+                            .iter()
+                            .find(|(other_key, _)| other_key == key)
+                            .is_some_and(|(_, value_b)| {
+                                same_value(key, value_a, value_b, tolerance)
+                            })
+                    })
+            })
+}
+
+fn same_value(key: &str, a: &str, b: &str, tolerance: f64) -> bool {
+    if a == b {
+        return true;
+    }
+    if key == "d" {
+        let (a, b) = (shape::path_segments(a), shape::path_segments(b));
+        return a.len() == b.len() && a.iter().zip(&b).all(|(a, b)| same_segment(a, b, tolerance));
+    }
+    match (numbers(a), numbers(b)) {
+        (Some(a), Some(b)) => {
+            a.len() == b.len() && a.iter().zip(&b).all(|(a, b)| (a - b).abs() <= tolerance)
+        }
+        _ => false,
+    }
+}
+
+fn same_segment(a: &Segment, b: &Segment, tolerance: f64) -> bool {
+    let same_point = |a: &(f64, f64), b: &(f64, f64)| {
+        (a.0 - b.0).abs() <= tolerance && (a.1 - b.1).abs() <= tolerance
+    };
+    match (a, b) {
+        (Segment::Move(a), Segment::Move(b)) => same_point(a, b),
+        (
+            Segment::Line {
+                to: to_a,
+                close: close_a,
+                ..
+            },
+            Segment::Line {
+                to: to_b,
+                close: close_b,
+                ..
+            },
+        ) => close_a == close_b && same_point(to_a, to_b),
+        (Segment::Curve(a), Segment::Curve(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| same_point(a, b))
+        }
+        (
+            Segment::Arc {
+                radii: radii_a,
+                rotation: rotation_a,
+                large_arc: large_arc_a,
+                sweep: sweep_a,
+                to: to_a,
+                ..
+            },
+            Segment::Arc {
+                radii: radii_b,
+                rotation: rotation_b,
+                large_arc: large_arc_b,
+                sweep: sweep_b,
+                to: to_b,
+                ..
+            },
+        ) => {
+            large_arc_a == large_arc_b
+                && sweep_a == sweep_b
+                && same_point(radii_a, radii_b)
+                && (rotation_a - rotation_b).abs() <= tolerance
+                && same_point(to_a, to_b)
+        }
+        _ => false,
+    }
+}
+
+/// The numbers of an attribute value consisting of nothing else, like a coordinate or the points
+/// of a polygon.
+fn numbers(value: &str) -> Option<Vec<f64>> {
+    let mut cursor = Cursor::new(value);
+    let mut numbers = Vec::new();
+    while let Some(number) = cursor.number() {
+        numbers.push(number);
+    }
+    cursor.is_empty().then_some(numbers)
+}
+
+#[cfg(test)]
+mod test {
+    use super::same_geometry;
+
+    #[test]
+    fn ignores_reformatting() {
+        for (a, b) in [
+            (
+                r#"<path d="M2 2h20v20H2z" />"#,
+                r#"<path d="M2,2 L22,2 L22,22 L2,22 Z"/>"#,
+            ),
+            (
+                r#"<path d="M1.0004 2l3 4 1 1" />"#,
+                r#"<path d="M1 2L4.001 6L5 7" />"#,
+            ),
+            (
+                r#"<path d="M4 4c1 0 2 1 2 2s1 2 2 2" />"#,
+                r#"<path d="M4 4C5 4 6 5 6 6C6 7 7 8 8 8" />"#,
+            ),
+            (
+                r#"<circle cx="12" cy="12" r="10" fill="none" />"#,
+                r#"<circle r="10.01" cy="12.0" cx="12" fill="none"></circle>"#,
+            ),
+        ] {
+            assert!(same_geometry(a, b, None), "{a} and {b}");
+        }
+    }
+
+    #[test]
+    fn detects_changed_shapes() {
+        for (a, b) in [
+            (r#"<path d="M2 2h20" />"#, r#"<path d="M2 2h21" />"#),
+            (r#"<path d="M2 2h20" />"#, r#"<path d="M2 2h20z" />"#),
+            (
+                r#"<path d="M2 2h20" />"#,
+                r#"<path d="M2 2h20" /><path d="M2 4h20" />"#,
+            ),
+            (r#"<circle r="10" />"#, r#"<circle r="10" fill="red" />"#),
+            (r#"<circle r="10" />"#, r#"<ellipse r="10" />"#),
+            (
+                r#"<path d="M2 2h20" fill="none" />"#,
+                r#"<path d="M2 2h20" fill="red" />"#,
+            ),
+        ] {
+            assert!(!same_geometry(a, b, None), "{a} and {b}");
+        }
+        // The tolerance depends on the view box.
+        let (a, b) = (r#"<path d="M2 2h20" />"#, r#"<path d="M2 2h20.5" />"#);
+        assert!(same_geometry(a, b, Some("0 0 512 512")));
+    }
+}
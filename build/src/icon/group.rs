@@ -0,0 +1,160 @@
+//! Rule based classification of icons into semantic groups, like the sections of an icon picker.
+//!
+//! An icon belongs to every group one of whose keywords is a word of its label. Plural words count
+//! as their singular. Icons matching no keyword belong to no group.
+
+/// Semantic group of icons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Group {
+    /// Arrows, menus and maps, moving around an application or the world.
+    Navigation,
+    /// Playback controls, images, audio and video.
+    Media,
+    /// Shopping, payment and money.
+    Commerce,
+    /// Files, folders and documents, and moving them around.
+    Files,
+    /// Mail, messages, calls and notifications.
+    Communication,
+}
+
+impl Group {
+    pub const ALL: [Group; 5] = [
+        Group::Navigation,
+        Group::Media,
+        Group::Commerce,
+        Group::Files,
+        Group::Communication,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Group::Navigation => "navigation",
+            Group::Media => "media",
+            Group::Commerce => "commerce",
+            Group::Files => "files",
+            Group::Communication => "communication",
+        }
+    }
+
+    /// Lowercase, singular words of labels placing an icon into this group.
+    fn keywords(self) -> &'static [&'static str] {
+        match self {
+            Group::Navigation => &[
+                "arrow",
+                "breadcrumb",
+                "caret",
+                "chevron",
+                "compass",
+                "direction",
+                "hamburger",
+                "home",
+                "house",
+                "map",
+                "menu",
+                "navigation",
+                "sidebar",
+                "signpost",
+            ],
+            Group::Media => &[
+                "camera",
+                "film",
+                "gallery",
+                "headphone",
+                "image",
+                "microphone",
+                "movie",
+                "music",
+                "pause",
+                "photo",
+                "picture",
+                "play",
+                "playlist",
+                "record",
+                "rewind",
+                "shuffle",
+                "speaker",
+                "tv",
+                "video",
+                "volume",
+            ],
+            Group::Commerce => &[
+                "bank", "barcode", "basket", "cart", "cash", "coin", "credit", "currency",
+                "discount", "dollar", "euro", "invoice", "money", "payment", "receipt", "shop",
+                "shopping", "store", "wallet",
+            ],
+            Group::Files => &[
+                "archive",
+                "attachment",
+                "clipboard",
+                "document",
+                "download",
+                "file",
+                "floppy",
+                "folder",
+                "paperclip",
+                "pdf",
+                "save",
+                "upload",
+                "zip",
+            ],
+            Group::Communication => &[
+                "at",
+                "bell",
+                "call",
+                "chat",
+                "chatbox",
+                "chatbubble",
+                "comment",
+                "contact",
+                "conversation",
+                "email",
+                "envelope",
+                "forum",
+                "inbox",
+                "mail",
+                "megaphone",
+                "message",
+                "notification",
+                "phone",
+                "reply",
+                "send",
+                "sms",
+                "voicemail",
+            ],
+        }
+    }
+}
+
+/// The groups an icon with the given label belongs to, in the order of [`Group::ALL`].
+pub fn groups(label: &str) -> Vec<Group> {
+    let words = label
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<_>>();
+    Group::ALL
+        .into_iter()
+        .filter(|group| {
+            words.iter().any(|word| {
+                let singular = word.strip_suffix('s').unwrap_or(word);
+                group.keywords().contains(&word.as_str()) || group.keywords().contains(&singular)
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{groups, Group};
+
+    #[test]
+    fn groups_icons_by_label_words() {
+        assert_eq!(groups("Arrow Right Circle"), [Group::Navigation]);
+        assert_eq!(groups("Shopping Cart Outlined"), [Group::Commerce]);
+        assert_eq!(groups("Chatbubbles Outline"), [Group::Communication]);
+        assert_eq!(groups("File Music"), [Group::Media, Group::Files]);
+        // Keywords match whole words only.
+        assert_eq!(groups("Atom"), []);
+    }
+}
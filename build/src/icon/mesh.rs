@@ -0,0 +1,204 @@
+//! Extrusion of icons into 3D meshes, for AR scenes and 3D dashboards.
+//!
+//! Strokes are expanded into filled outlines first. Every filled shape is then tessellated by lyon
+//! and extruded into a prism: a front face, a back face and side walls along the boundary of its
+//! triangles. Shapes are not merged, so overlapping shapes give overlapping volumes. Transforms,
+//! clip paths and masks are ignored.
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use lyon::{
+    math::Point,
+    tessellation::{
+        BuffersBuilder, FillOptions, FillRule, FillTessellator, FillVertex, VertexBuffers,
+    },
+};
+
+use super::{outline, polyline, shape, svg::ParsedSvg};
+
+/// Shapes which can be filled.
+const SHAPES: &[&str] = &["path", "circle", "ellipse", "rect", "polyline", "polygon"];
+
+/// Elements whose content is not drawn directly.
+const NOT_RENDERED: &[&str] = &["defs", "clipPath", "mask", "symbol", "pattern", "marker"];
+
+/// Triangle mesh with the y axis pointing up and the front face towards positive z.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Mesh {
+    pub positions: Vec<[f32; 3]>,
+    /// Triangles wound counter-clockwise when seen from outside.
+    pub indices: Vec<u32>,
+}
+
+impl Mesh {
+    /// Smallest and largest coordinates of all positions.
+    pub fn bounds(&self) -> ([f32; 3], [f32; 3]) {
+        self.positions.iter().fold(
+            ([f32::INFINITY; 3], [f32::NEG_INFINITY; 3]),
+            |(min, max), position| {
+                (
+                    [0, 1, 2].map(|i| min[i].min(position[i])),
+                    [0, 1, 2].map(|i| max[i].max(position[i])),
+                )
+            },
+        )
+    }
+
+    /// Add the prism of the given triangles, from z = 0 to z = `depth`.
+    fn add_prism(&mut self, vertices: &[(f32, f32)], triangles: &[[u32; 3]], depth: f32) {
+        let front = self.positions.len() as u32;
+        let back = front + vertices.len() as u32;
+        self.positions
+            .extend(vertices.iter().map(|&(x, y)| [x, y, depth]));
+        self.positions
+            .extend(vertices.iter().map(|&(x, y)| [x, y, 0.0]));
+
+        // Edges used by a single triangle form the boundary of the shape, keyed by their vertices
+        // in ascending order and keeping the direction the triangle runs along them.
+        let mut edges = BTreeMap::new();
+        for &[a, b, c] in triangles {
+            self.indices.extend([a, b, c].map(|i| front + i));
+            self.indices.extend([a, c, b].map(|i| back + i));
+            for (from, to) in [(a, b), (b, c), (c, a)] {
+                edges
+                    .entry((from.min(to), from.max(to)))
+                    .or_insert((from, to, 0))
+                    .2 += 1;
+            }
+        }
+        // The inside of a counter-clockwise triangle is left of its edges, so the walls face right.
+        for (from, to, _) in edges.into_values().filter(|(_, _, uses)| *uses == 1) {
+            self.indices.extend([back + from, back + to, front + to]);
+            self.indices.extend([back + from, front + to, front + from]);
+        }
+    }
+}
+
+/// Extrude all filled areas of the icon by `depth`, scaled so that the larger side of its view box
+/// is `size` long.
+pub fn extrude(svg: &ParsedSvg, size: f64, depth: f64) -> Result<Mesh> {
+    let [min_x, min_y, width, height] = polyline::view_box(svg);
+    let scale = size / width.max(height);
+    // Flatten curves to a hundredth of a unit of a 24 unit icon.
+    let tolerance = width.max(height) / 2400.0;
+    let content = outline::expand_strokes(svg)?;
+
+    // Open elements with their name, fill and fill rule.
+    let mut open = vec![("svg", svg.svg_attributes().fill.as_deref(), None)];
+    let mut mesh = Mesh::default();
+
+    for tag in content.split('<').skip(1) {
+        let Some(tag) = tag.split('>').next() else {
+            continue;
+        };
+        if let Some(name) = tag.strip_prefix('/') {
+            if let Some(i) = open.iter().rposition(|(open, _, _)| *open == name.trim()) {
+                open.truncate(i.max(1));
+            }
+            continue;
+        }
+        if tag.starts_with(['!', '?']) {
+            continue;
+        }
+
+        let (name, attributes) = shape::element(tag);
+        let attr = |key: &str| {
+            attributes
+                .iter()
+                .find(|(attribute, _)| *attribute == key)
+                .map(|(_, value)| *value)
+        };
+        let fill = attr("fill").or_else(|| open.iter().rev().find_map(|(_, fill, _)| *fill));
+        let fill_rule =
+            attr("fill-rule").or_else(|| open.iter().rev().find_map(|(_, _, rule)| *rule));
+        let rendered = !open.iter().any(|(open, _, _)| NOT_RENDERED.contains(open));
+        if !tag.ends_with('/') {
+            open.push((name, attr("fill"), attr("fill-rule")));
+        }
+        let filled = fill.is_none_or(|fill| !matches!(fill.trim(), "none" | "transparent"));
+        if !rendered || !filled || !SHAPES.contains(&name) {
+            continue;
+        }
+
+        let options = FillOptions::default()
+            .with_tolerance(tolerance as f32)
+            .with_fill_rule(match fill_rule.map(str::trim) {
+                Some("evenodd") => FillRule::EvenOdd,
+                _ => FillRule::NonZero,
+            });
+        let mut geometry: VertexBuffers<Point, u32> = VertexBuffers::new();
+        FillTessellator::new()
+            .tessellate_path(
+                &outline::lyon_path(name, &attr),
+                &options,
+                &mut BuffersBuilder::new(&mut geometry, |vertex: FillVertex| vertex.position()),
+            )
+            .map_err(|err| anyhow!("Could not tessellate fill: {err:?}"))?;
+
+        let vertices = geometry
+            .vertices
+            .iter()
+            .map(|p| {
+                (
+                    ((p.x as f64 - min_x) * scale) as f32,
+                    ((min_y + height - p.y as f64) * scale) as f32,
+                )
+            })
+            .collect::<Vec<_>>();
+        let triangles = geometry
+            .indices
+            .chunks_exact(3)
+            .filter_map(|triangle| {
+                let [a, b, c] = [triangle[0], triangle[1], triangle[2]];
+                let [pa, pb, pc] = [a, b, c].map(|i| vertices[i as usize]);
+                let cross = (pb.0 - pa.0) * (pc.1 - pa.1) - (pb.1 - pa.1) * (pc.0 - pa.0);
+                match cross {
+                    cross if cross.abs() < f32::EPSILON => None,
+                    cross if cross < 0.0 => Some([a, c, b]),
+                    _ => Some([a, b, c]),
+                }
+            })
+            .collect::<Vec<_>>();
+        mesh.add_prism(&vertices, &triangles, depth as f32);
+    }
+    Ok(mesh)
+}
+
+#[cfg(test)]
+mod test {
+    use super::extrude;
+    use crate::icon::svg::{ColorMode, ParsedSvg};
+
+    #[test]
+    fn extrudes_filled_shapes_into_prisms() {
+        let svg = ParsedSvg::parse(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><rect x="0" y="0" width="24" height="12"/><rect x="0" y="12" width="24" height="12" fill="none"/></svg>"#.as_bytes(),
+            ColorMode::Monochrome,
+        )
+        .unwrap();
+        let mesh = extrude(&svg, 1.0, 0.25).unwrap();
+
+        // Two triangles on the front, two on the back and two on each of the four sides.
+        assert_eq!(mesh.positions.len(), 8);
+        assert_eq!(mesh.indices.len(), 12 * 3);
+        assert_eq!(mesh.bounds(), ([0.0, 0.5, 0.0], [1.0, 1.0, 0.25]));
+
+        // Every triangle faces away from the center of the box.
+        let center = [0.5, 0.75, 0.125];
+        for triangle in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| mesh.positions[triangle[i] as usize]);
+            let (u, v) = (
+                [0, 1, 2].map(|i| b[i] - a[i]),
+                [0, 1, 2].map(|i| c[i] - a[i]),
+            );
+            let normal = [
+                u[1] * v[2] - u[2] * v[1],
+                u[2] * v[0] - u[0] * v[2],
+                u[0] * v[1] - u[1] * v[0],
+            ];
+            let outwards = (0..3).map(|i| normal[i] * (a[i] - center[i])).sum::<f32>();
+            assert!(outwards > 0.0, "{triangle:?}");
+        }
+    }
+}
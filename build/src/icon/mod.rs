@@ -1,61 +1,246 @@
-use std::{fmt::Display, path::Path, str::FromStr};
+use std::{collections::HashMap, fmt::Display, path::Path, str::FromStr};
 
 use anyhow::{Context, Result};
-use heck::ToPascalCase;
+use heck::{ToKebabCase, ToPascalCase};
+use icondata_core::IconPaint;
 
 use crate::package::{Package, PackageType, Unknown};
 
-use self::svg::ParsedSvg;
+use self::svg::{ColorMode, ParsedSvg};
 
+pub mod align;
+pub mod badge;
+pub mod direction;
+pub mod family;
+pub mod geometry;
+pub mod group;
+#[cfg(feature = "mesh")]
+pub mod mesh;
+pub mod naming;
+pub mod outline;
+pub mod paint;
+pub mod palette;
+pub mod polyline;
+pub mod shape;
+pub mod simplify;
+pub mod spinner;
+pub mod state_pair;
 pub mod svg;
 
 #[derive(Debug, Clone)]
 pub struct SvgIcon {
     pub svg: svg::ParsedSvg,
     pub name: String,
+    /// Human readable name of the icon, e.g. "Arrow Right Circle".
+    pub label: String,
+    /// Variant family of the icon, e.g. "fill" or "outlined". Used as a feature of the icon crate.
+    pub style: Option<String>,
+    /// Whether the icon is a third-party brand or logo.
+    pub brand: bool,
+    /// Official color of the brand shown by the icon, e.g. "#1DB954", see
+    /// [`PackageType::brand_colors_file`].
+    pub brand_color: Option<String>,
+    /// Previous names of the icon, kept as deprecated aliases after its name was normalized.
+    pub aliases: Vec<String>,
+    /// Coarse fingerprint of the icon's outline, see [`shape::shape_hash`].
+    pub shape_hash: u64,
+    /// Path of the svg file, relative to the root of the package's repository. For icons of Iconify
+    /// collections, the path of the JSON file followed by the icon's name, e.g.
+    /// "json/mdi.json#home".
+    pub source_path: String,
+    /// Whether the icon is filled or stroked, see [`paint::paint`].
+    pub paint: IconPaint,
+    /// Number of path commands and anchor points, see [`simplify::complexity`].
+    pub complexity: simplify::Complexity,
+    /// Dominant colors and the pairs of them confused with color vision deficiencies, see
+    /// [`palette::palette`].
+    pub palette: palette::Palette,
+    /// Semantic groups of the icon, like "navigation", see [`group::groups`].
+    pub groups: Vec<group::Group>,
+    /// Center of rotation of loading indicators, see [`spinner::origin`].
+    pub spinner_origin: Option<String>,
 }
 
 impl SvgIcon {
-    pub async fn new(
+    /// Icon of the package read from the svg file at `path`. Reads the file blocking, as icons are
+    /// parsed in batches on the blocking thread pool, see [`crate::package::reader`].
+    pub fn new(
         package: &Package<Unknown>,
+        download_path: &Path,
         path: &Path,
         size: Option<IconSize>,
-        mut categories: Vec<Category>,
+        categories: Vec<Category>,
     ) -> Result<Self> {
         let file_stem = path.file_stem().unwrap().to_string_lossy(); // TODO: Error handling\
 
-        let (raw_name, size_from_name, cats_from_name) =
-            parse_raw_icon_name(package.ty, &file_stem);
+        let svg = std::fs::read_to_string(path)?;
+        let mode = if package.ty.is_colored() {
+            ColorMode::Colored
+        } else if categories
+            .iter()
+            .any(|category| TWOTONE.contains(&category.0.as_str()))
+        {
+            ColorMode::Twotone
+        } else {
+            ColorMode::Monochrome
+        };
+        let source_path = path
+            .strip_prefix(download_path)
+            .unwrap_or(path)
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        Self::from_svg(
+            package,
+            &file_stem,
+            &svg,
+            mode,
+            source_path,
+            size,
+            categories,
+        )
+    }
+
+    /// Icon of the package from the markup of its svg, named after the given file stem. Used for
+    /// icons not stored in files of their own, like those of Iconify collections.
+    pub fn from_svg(
+        package: &Package<Unknown>,
+        file_stem: &str,
+        svg: &str,
+        mode: ColorMode,
+        source_path: String,
+        size: Option<IconSize>,
+        mut categories: Vec<Category>,
+    ) -> Result<Self> {
+        let (raw_name, size_from_name, cats_from_name) = parse_raw_icon_name(package.ty, file_stem);
 
         if let Some(mut cats_from_name) = cats_from_name {
             categories.append(&mut cats_from_name);
         }
+        let categories = package.category_renames().apply(categories);
 
-        let name = feature_name(
+        // Styles given as name prefix follow the short name, like `MsRoundedHome`.
+        let prefix_style = package.ty.prefix_style(&categories);
+        let name = match prefix_style {
+            Some(prefix_style) => feature_name(
+                raw_name,
+                size_from_name.or(size),
+                &categories
+                    .iter()
+                    .filter(|category| *category != prefix_style)
+                    .cloned()
+                    .collect::<Vec<_>>(),
+                &format!("{} {}", package.name_prefix(), prefix_style.0),
+            ),
+            None => feature_name(
                 raw_name,
                 size_from_name.or(size),
                 &categories,
-                &package.meta.short_name,
-            );
+                package.name_prefix(),
+            ),
+        };
+        let label = label(raw_name, &categories);
+        let groups = group::groups(&label);
+        let style = match prefix_style {
+            Some(prefix_style) => Some(prefix_style.0.clone()),
+            None => style(&categories),
+        };
+        let brand = package.ty.is_brand(file_stem, &categories);
 
-        let svg = tokio::fs::read_to_string(path).await?;
+        let svg = ParsedSvg::parse(svg.as_bytes(), mode).with_context(|| {
+            format!(
+                "Error parsing icon: {} from package: {}, with path: {:?}",
+                &name, package.meta.short_name, source_path
+            )
+        })?;
+        let shape_hash = shape::shape_hash(&svg);
+        let paint = paint::paint(&svg);
+        let complexity = simplify::complexity(&svg.content);
+        let palette = palette::palette(&svg);
+        let spinner_origin = spinner::origin(&name, &svg);
 
         Ok(SvgIcon {
-            svg: ParsedSvg::parse(
-                svg.as_bytes(),
-                categories.contains(&Category("twotone".to_string())),
-            )
-            .with_context(|| {
-                format!(
-                    "Error parsing icon: {} from package: {}, with path: {:?}",
-                    &name,
-                    package.meta.short_name,
-                    path.to_str()
-                )
-            })?,
+            svg,
             name,
+            label,
+            style,
+            brand,
+            brand_color: None,
+            aliases: Vec::new(),
+            shape_hash,
+            source_path,
+            paint,
+            complexity,
+            palette,
+            groups,
+            spinner_origin,
         })
     }
+
+    /// Variant of the icon in the [`align::CATEGORY`] category with its outline snapped to the
+    /// pixel grid at a size of `pixels`, see [`align::align`]. `None` if the icon is designed for
+    /// this size or smaller already.
+    pub fn aligned(&self, pixels: u32) -> Option<SvgIcon> {
+        let view_box = self.svg.svg_attributes().view_box.as_deref();
+        let [_, _, width, height] = view_box
+            .and_then(shape::parse_view_box)
+            .unwrap_or(shape::DEFAULT_VIEW_BOX);
+        if width.max(height) <= pixels as f64 {
+            return None;
+        }
+
+        let mut aligned = self.clone();
+        aligned.svg.content = align::align(&self.svg.content, view_box, pixels);
+        aligned.name = with_category(&self.name, align::CATEGORY);
+        aligned.label = format!("{} {}", self.label, label(align::CATEGORY, &[]));
+        aligned.aliases = Vec::new();
+        aligned.complexity = simplify::complexity(&aligned.svg.content);
+        Some(aligned)
+    }
+
+    /// Variant of the icon in the [`badge::CATEGORY`] category, knocked out of a filled rounded
+    /// square, see [`badge::badge`]. `None` for multi-colored icons, which would lose their colors.
+    pub fn badge(&self) -> Option<SvgIcon> {
+        if self.palette.is_multi_color() {
+            return None;
+        }
+
+        let attributes = self.svg.svg_attributes();
+        let mut badge = self.clone();
+        badge.name = with_category(&self.name, badge::CATEGORY);
+        badge.svg.content = badge::badge(
+            &self.svg.content,
+            attributes.view_box.as_deref(),
+            attributes.fill.as_deref(),
+            attributes.stroke.as_deref(),
+            &format!("icondata-{}", badge.name.to_kebab_case()),
+        );
+        badge.label = format!("{} {}", self.label, label(badge::CATEGORY, &[]));
+        badge.aliases = Vec::new();
+        badge.paint = paint::paint(&badge.svg);
+        badge.complexity = simplify::complexity(&badge.svg.content);
+        badge.palette = palette::palette(&badge.svg);
+        Some(badge)
+    }
+
+    /// Replace all strokes of the icon by filled outlines, see [`outline::expand_strokes`].
+    pub fn expand_strokes(&mut self) -> Result<()> {
+        self.svg.content = outline::expand_strokes(&self.svg)
+            .with_context(|| format!("Error expanding strokes of icon: {}", self.name))?;
+        self.paint = paint::paint(&self.svg);
+        self.complexity = simplify::complexity(&self.svg.content);
+        self.palette = palette::palette(&self.svg);
+        Ok(())
+    }
+
+    /// Simplify all paths of the icon at the given tolerance, see [`simplify::simplify`].
+    pub fn simplify(&mut self, tolerance: f64) {
+        let view_box = self.svg.svg_attributes().view_box.as_deref();
+        self.svg.content = simplify::simplify(&self.svg.content, view_box, tolerance);
+        self.complexity = simplify::complexity(&self.svg.content);
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
@@ -67,6 +252,8 @@ pub enum IconSize {
     Sm,
     Md,
     Lg,
+    /// 32px, between the sizes named before it was added.
+    LgPlus,
     Xl,
     Xxl,
 }
@@ -78,6 +265,7 @@ impl IconSize {
             IconSize::Sm => "sm",
             IconSize::Md => "md",
             IconSize::Lg => "lg",
+            IconSize::LgPlus => "lgplus",
             IconSize::Xl => "xl",
             IconSize::Xxl => "xxl",
         }
@@ -99,6 +287,7 @@ impl FromStr for IconSize {
             "16" => Ok(IconSize::Sm),
             "20" => Ok(IconSize::Md),
             "24" => Ok(IconSize::Lg),
+            "32" => Ok(IconSize::LgPlus),
             "48" => Ok(IconSize::Xl),
             "96" => Ok(IconSize::Xxl),
             other => Err(anyhow::anyhow!(
@@ -108,6 +297,8 @@ impl FromStr for IconSize {
     }
 }
 
+/// Name of the constant of an icon, the PascalCase of the package prefix, raw name, categories
+/// and size, sanitized into a valid Rust identifier, see [`sanitize_identifier`].
 pub(crate) fn feature_name(
     raw_name: &str,
     size: Option<IconSize>,
@@ -138,7 +329,180 @@ pub(crate) fn feature_name(
         name.push(' ');
     };
 
-    name.to_pascal_case()
+    sanitize_identifier(name.to_pascal_case())
+}
+
+/// Make a PascalCase name a valid Rust identifier, which it is not if it is empty, e.g. for a
+/// package without prefix, starts with a digit, is the keyword `Self`, or contains characters
+/// which are alphanumeric but no identifier characters, like "²". Non-ASCII characters are dropped
+/// altogether, as even valid ones are easily confused with ASCII ones.
+fn sanitize_identifier(mut name: String) -> String {
+    name.retain(|c| c.is_ascii_alphanumeric());
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert_str(0, "Icon");
+    }
+    if name == "Self" {
+        name.push_str("Icon");
+    }
+    name
+}
+
+/// Words which should be written in uppercase when part of a label.
+const ACRONYMS: &[&str] = &[
+    "2d", "3d", "4k", "ai", "api", "ar", "cpu", "css", "csv", "gif", "gps", "hd", "html", "id",
+    "jpg", "js", "json", "nfc", "pdf", "png", "qr", "rss", "sd", "sim", "sms", "sql", "svg", "tv",
+    "ui", "url", "usb", "vr", "xml",
+];
+
+/// Build a human readable label from the parts of an icon name, e.g. "Arrow Right Circle".
+///
+/// Unlike the feature name, this keeps acronyms in uppercase ("TV") and separates trailing numbers
+/// ("Wifi 1"). The package prefix and icon size are not part of the label.
+pub(crate) fn label(raw_name: &str, categories: &[Category]) -> String {
+    raw_name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .chain(categories.iter().map(|category| category.0.as_str()))
+        .flat_map(split_words)
+        .map(|word| {
+            let word = word.to_ascii_lowercase();
+            if ACRONYMS.contains(&word.as_str()) {
+                word.to_ascii_uppercase()
+            } else {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Split a word at camel case humps and between letters and digits, dropping empty parts.
+pub(crate) fn split_words(word: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    let chars = word.char_indices().collect::<Vec<_>>();
+    for window in chars.windows(2) {
+        let [(_, prev), (i, next)] = [window[0], window[1]];
+        let hump = prev.is_ascii_lowercase() && next.is_ascii_uppercase();
+        let digit_boundary = prev.is_ascii_digit() != next.is_ascii_digit();
+        if hump || digit_boundary {
+            words.push(&word[start..i]);
+            start = i;
+        }
+    }
+    words.push(&word[start..]);
+    words.retain(|word| !word.is_empty());
+    words
+}
+
+/// Categories and name suffixes which denote a style variant rather than a distinct icon.
+const STYLES: &[&str] = &[
+    "fill", "filled", "line", "outline", "outlined", "regular", "sharp", "solid", "twotone",
+];
+
+/// Categories which denote a style variant, but as name suffixes also name distinct icons, like
+/// "type-bold" next to "type". The weights of Phosphor and the styles of Material Symbols are
+/// given by their directories.
+const CATEGORY_STYLES: &[&str] = &["thin", "light", "bold", "duotone", "rounded"];
+
+/// Categories of icons drawing their secondary shapes in a lighter color, see [`ParsedSvg::parse`].
+const TWOTONE: &[&str] = &["twotone", "duotone"];
+
+/// Suffixes which `feature_name` appends for icon sizes.
+const SIZES: &[&str] = &["xs", "sm", "md", "lg", "lgplus", "xl", "xxl"];
+
+/// The style variant of an icon given by its categories, e.g. the "outlined" directory.
+pub(crate) fn style(categories: &[Category]) -> Option<String> {
+    categories
+        .iter()
+        .rev()
+        .map(|category| category.0.to_lowercase())
+        .find(|category| {
+            STYLES.contains(&category.as_str()) || CATEGORY_STYLES.contains(&category.as_str())
+        })
+}
+
+/// Assign styles to icons which only carry their style in their name, like the "-fill" variants of
+/// Bootstrap Icons.
+pub(crate) fn assign_name_styles(icons: &mut [SvgIcon]) {
+    let styles = name_styles(
+        &icons
+            .iter()
+            .map(|icon| (icon.name.as_str(), icon.style.is_some()))
+            .collect::<Vec<_>>(),
+    );
+    for (icon, style) in icons.iter_mut().zip(styles) {
+        if style.is_some() {
+            icon.style = style;
+        }
+    }
+}
+
+/// Styles given by the names of icons which do not have a style yet.
+///
+/// A name suffix only counts as a style if another variant of the same icon exists, so that icons
+/// like "underline" or "dash-line" do not form a style of their own.
+fn name_styles(names: &[(&str, bool)]) -> Vec<Option<String>> {
+    let candidates = names
+        .iter()
+        .map(|(name, has_style)| match has_style {
+            true => None,
+            false => name_style(name),
+        })
+        .collect::<Vec<_>>();
+
+    let mut variants = HashMap::<&str, usize>::new();
+    for ((name, _), candidate) in names.iter().zip(&candidates) {
+        let base = match candidate {
+            Some((base, _)) => base.as_str(),
+            None => name,
+        };
+        *variants.entry(base).or_default() += 1;
+    }
+
+    candidates
+        .iter()
+        .map(|candidate| match candidate {
+            Some((base, style)) if variants[base.as_str()] > 1 => Some(style.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The icon name with the given category added, placed before the size like [`feature_name`]
+/// does.
+fn with_category(name: &str, category: &str) -> String {
+    let mut words = split_words(name);
+    let size = words
+        .last()
+        .copied()
+        .filter(|word| SIZES.contains(&word.to_lowercase().as_str()));
+    if size.is_some() {
+        words.pop();
+    }
+    words.concat() + &category.to_pascal_case() + size.unwrap_or_default()
+}
+
+/// Split an icon name into the name without its trailing style word and that style, if any.
+fn name_style(name: &str) -> Option<(String, String)> {
+    let mut words = split_words(name);
+    let size = words
+        .last()
+        .copied()
+        .filter(|word| SIZES.contains(&word.to_lowercase().as_str()));
+    if size.is_some() {
+        words.pop();
+    }
+    let style = words.pop()?.to_lowercase();
+    // The package prefix alone does not name an icon. Outside of categories, "line" usually
+    // describes the icon itself, as in "bar-chart-line" next to "bar-chart".
+    if !STYLES.contains(&style.as_str()) || style == "line" || words.len() < 2 {
+        return None;
+    }
+    Some((words.concat() + size.unwrap_or_default(), style))
 }
 
 pub(crate) fn parse_raw_icon_name(
@@ -170,7 +534,12 @@ pub(crate) fn parse_raw_icon_name(
         // Icomoon icons: numbered '001-xxxxxx'
         PackageType::IcoMoonFree => {
             let name = file_stem.trim_start_matches(char::is_numeric);
-            (name, None, None)
+            // Icons named by their number only keep it, instead of all being named like the
+            // package.
+            match name.trim_start_matches('-').is_empty() {
+                true => (file_stem, None, None),
+                false => (name, None, None),
+            }
         }
         PackageType::RemixIcon => {
             let mut name = file_stem;
@@ -184,6 +553,204 @@ pub(crate) fn parse_raw_icon_name(
             }
             (name, None, if cats.is_empty() { None } else { Some(cats) })
         }
+        // Phosphor icons: weights other than regular are suffixed, e.g. 'house-bold' in the 'bold'
+        // directory, which already gives their category.
+        PackageType::Phosphor => {
+            let name = ["-thin", "-light", "-bold", "-fill", "-duotone"]
+                .iter()
+                .find_map(|weight| file_stem.strip_suffix(weight))
+                .unwrap_or(file_stem);
+            (name, None, None)
+        }
+        // Iconoir icons: solid icons may repeat their directory as suffix, e.g. 'heart-solid'.
+        PackageType::Iconoir => (file_stem.trim_end_matches("-solid"), None, None),
+        // Material Symbols: variant and optical size suffixes, e.g. 'home_wght300fill1_24px'.
+        PackageType::MaterialSymbols => match material_symbols_variant(file_stem) {
+            Some((name, variant, _)) => {
+                let mut cats = vec![];
+                if let Some(weight) = variant.weight.filter(|weight| *weight != 400) {
+                    cats.push(Category(format!("weight{weight}")));
+                }
+                if variant.fill {
+                    cats.push(Category("fill".to_string()));
+                }
+                (name, None, if cats.is_empty() { None } else { Some(cats) })
+            }
+            None => (file_stem, None, None),
+        },
         _ => (file_stem, None, None),
     }
 }
+
+/// Variant of a Material Symbols icon given by the suffix of its file stem.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct MaterialSymbolsVariant {
+    /// Weight from 100 to 700, 400 if not given.
+    pub weight: Option<u32>,
+    /// Grade like -25 or 200, 0 if not given.
+    pub grade: Option<i32>,
+    pub fill: bool,
+}
+
+/// Name, variant and optical size of a Material Symbols icon given its file stem, e.g.
+/// `("home", MaterialSymbolsVariant { weight: Some(300), grade: None, fill: true }, 24)` for
+/// "home_wght300fill1_24px".
+pub(crate) fn material_symbols_variant(
+    file_stem: &str,
+) -> Option<(&str, MaterialSymbolsVariant, u32)> {
+    let (rest, size) = file_stem.strip_suffix("px")?.rsplit_once('_')?;
+    let size = size.parse().ok()?;
+
+    let Some((name, mut suffix)) = rest.rsplit_once('_') else {
+        return Some((rest, MaterialSymbolsVariant::default(), size));
+    };
+    let mut variant = MaterialSymbolsVariant::default();
+    /// Number at the start of the suffix, with "N" as minus sign, and the rest of the suffix.
+    fn number(suffix: &str) -> Option<(i32, &str)> {
+        let (negative, digits) = match suffix.strip_prefix('N') {
+            Some(digits) => (true, digits),
+            None => (false, suffix),
+        };
+        let len = digits
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(digits.len());
+        let value = digits[..len].parse::<i32>().ok()?;
+        Some((if negative { -value } else { value }, &digits[len..]))
+    }
+    if let Some((weight, rest)) = suffix.strip_prefix("wght").and_then(number) {
+        variant.weight = Some(weight.unsigned_abs());
+        suffix = rest;
+    }
+    if let Some((grade, rest)) = suffix.strip_prefix("grad").and_then(number) {
+        variant.grade = Some(grade);
+        suffix = rest;
+    }
+    if let Some(rest) = suffix.strip_prefix("fill1") {
+        variant.fill = true;
+        suffix = rest;
+    }
+    match suffix.is_empty() {
+        true => Some((name, variant, size)),
+        // Part of the name, like "arrow_back".
+        false => Some((rest, MaterialSymbolsVariant::default(), size)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        feature_name, label, material_symbols_variant, name_styles, parse_raw_icon_name, style,
+        with_category, Category, IconSize, MaterialSymbolsVariant,
+    };
+    use crate::package::PackageType;
+
+    /// Sample file stems of each package, together with the package's short name and the icon name
+    /// expected to be generated from them.
+    const FIXTURES: &[(PackageType, &str, &str, &str)] = &[
+        (PackageType::GithubOcticons, "oc", "alert-24", "OcAlertLg"),
+        (PackageType::WeatherIcons, "wi", "wi-day-sunny", "WiDaySunny"),
+        (PackageType::BoxIcons, "bi", "bxs-home", "BiHome"),
+        (PackageType::IcoMoonFree, "im", "001-home", "ImHome"),
+        (PackageType::IcoMoonFree, "im", "404", "Im404"),
+        (PackageType::RemixIcon, "ri", "add-fill", "RiAddFill"),
+        (PackageType::Phosphor, "ph", "house-duotone", "PhHouse"),
+        (PackageType::CarbonIcons, "cb", "arrow--down", "CbArrowDown"),
+        (PackageType::Iconoir, "ir", "heart-solid", "IrHeart"),
+        (PackageType::FlagIcons, "fg", "gb-eng", "FgGbEng"),
+        (PackageType::GameIcons, "gi", "crossed-swords", "GiCrossedSwords"),
+        (PackageType::MaterialSymbols, "ms", "home_wght300fill1_24px", "MsHomeWeight300Fill"),
+    ];
+
+    #[test]
+    fn feature_names() {
+        for (package, short_name, file_stem, expected) in FIXTURES {
+            let (raw_name, size, categories) = parse_raw_icon_name(*package, file_stem);
+            let name = feature_name(
+                raw_name,
+                size,
+                &categories.unwrap_or_default(),
+                short_name,
+            );
+            assert_eq!(&name, expected, "file stem: {file_stem}");
+        }
+    }
+
+    #[test]
+    fn sanitizes_identifiers() {
+        assert_eq!(feature_name("self", None, &[], ""), "SelfIcon");
+        assert_eq!(feature_name("404", None, &[], ""), "Icon404");
+        assert_eq!(feature_name("--", None, &[], ""), "Icon");
+        assert_eq!(feature_name("café²", None, &[], "fa"), "FaCaf");
+        assert_eq!(feature_name("self", None, &[], "fa"), "FaSelf");
+    }
+
+    #[test]
+    fn material_symbols_variants() {
+        assert_eq!(
+            material_symbols_variant("arrow_back_24px"),
+            Some(("arrow_back", MaterialSymbolsVariant::default(), 24))
+        );
+        assert_eq!(
+            material_symbols_variant("home_wght200gradN25fill1_48px"),
+            Some((
+                "home",
+                MaterialSymbolsVariant {
+                    weight: Some(200),
+                    grade: Some(-25),
+                    fill: true,
+                },
+                48
+            ))
+        );
+        assert_eq!(material_symbols_variant("home"), None);
+    }
+
+    #[test]
+    fn category_names() {
+        let categories = [Category("aligned".to_owned())];
+        assert_eq!(
+            with_category("OcAlertLg", "aligned"),
+            feature_name("alert", Some(IconSize::Lg), &categories, "oc")
+        );
+        assert_eq!(with_category("BsHouse", "aligned"), "BsHouseAligned");
+        assert_eq!(
+            with_category("CbAddLgplus", "aligned"),
+            feature_name("add", Some(IconSize::LgPlus), &categories, "cb")
+        );
+    }
+
+    #[test]
+    fn labels() {
+        assert_eq!(label("house", &[Category("fill".to_owned())]), "House Fill");
+        assert_eq!(label("arrow-right-circle", &[]), "Arrow Right Circle");
+        assert_eq!(label("tv", &[]), "TV");
+        assert_eq!(label("wifi1", &[]), "Wifi 1");
+        assert_eq!(label("accountBook", &[]), "Account Book");
+    }
+
+    #[test]
+    fn styles() {
+        let category = |name: &str| Category(name.to_owned());
+        assert_eq!(style(&[category("Outlined")]).as_deref(), Some("outlined"));
+        assert_eq!(style(&[category("Editor")]), None);
+        assert_eq!(style(&[category("duotone")]).as_deref(), Some("duotone"));
+
+        let names = [
+            ("BsHouse", false),
+            ("BsHouseFill", false),
+            ("BsUnderline", false),
+            ("OcAlertLg", false),
+            ("OcAlertFillLg", false),
+            ("AiHomeFilled", true),
+            ("AiHome", false),
+            ("BsBarChart", false),
+            ("BsBarChartLine", false),
+        ];
+        let styles = name_styles(&names);
+        let styles = styles.iter().map(Option::as_deref).collect::<Vec<_>>();
+        assert_eq!(
+            styles,
+            [None, Some("fill"), None, None, Some("fill"), None, None, None, None]
+        );
+    }
+}
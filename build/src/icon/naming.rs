@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+use tracing::warn;
+
+use super::{split_words, SvgIcon};
+
+/// Spellings replaced in icon names, as `(from, to)` pairs of word sequences. Renamed icons keep
+/// their previous name as a deprecated alias.
+///
+/// Entries are proposed and added by `cargo run -- audit --apply`.
+pub(crate) const NORMALIZATIONS: &[(&str, &str)] = &[];
+
+/// Rename the icons of a package according to [`NORMALIZATIONS`], given the prefix all icon names
+/// of the package start with. An icon is not renamed if its new name is already taken.
+pub(crate) fn normalize_names(icons: &mut [SvgIcon], prefix: &str) {
+    let mut names = icons
+        .iter()
+        .map(|icon| icon.name.clone())
+        .collect::<HashSet<_>>();
+    for icon in icons.iter_mut() {
+        let Some(name) = icon.name.strip_prefix(prefix) else {
+            continue;
+        };
+        let normalized = format!("{prefix}{}", normalize(name, NORMALIZATIONS));
+        if normalized == icon.name {
+            continue;
+        }
+        if !names.insert(normalized.clone()) {
+            warn!(
+                name = icon.name,
+                normalized, "Not normalizing icon name, as the normalized name is already taken."
+            );
+            continue;
+        }
+        let previous = std::mem::replace(&mut icon.name, normalized);
+        icon.aliases.push(previous);
+    }
+}
+
+/// Apply the given normalizations to a PascalCase name. Spellings are only replaced as a whole,
+/// so that "FileType" is not found in "ProfileType".
+pub(crate) fn normalize(name: &str, normalizations: &[(&str, &str)]) -> String {
+    let mut words = split_words(name);
+    for (from, to) in normalizations {
+        let from = split_words(from);
+        let to = split_words(to);
+        let mut i = 0;
+        while i + from.len() <= words.len() {
+            if words[i..i + from.len()] == from[..] {
+                words.splice(i..i + from.len(), to.iter().copied());
+                i += to.len();
+            } else {
+                i += 1;
+            }
+        }
+    }
+    words.concat()
+}
+
+#[cfg(test)]
+mod test {
+    use super::normalize;
+
+    #[test]
+    fn normalizes_whole_words() {
+        let normalizations = [("FileType", "Filetype"), ("Btn", "Button")];
+        assert_eq!(normalize("FileTypeDoc", &normalizations), "FiletypeDoc");
+        assert_eq!(normalize("ProfileType", &normalizations), "ProfileType");
+        assert_eq!(normalize("RadioBtn2", &normalizations), "RadioButton2");
+    }
+}
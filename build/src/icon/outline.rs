@@ -0,0 +1,381 @@
+//! Expansion of strokes into filled outlines, for consumers which cannot draw strokes, like icon
+//! fonts, some embedded renderers and laser cutters.
+//!
+//! Every stroked shape gets its stroke removed and is followed by a filled path covering the area
+//! the stroke painted, tessellated by lyon. The outline consists of one triangle per subpath, all
+//! wound the same way, so that overlapping triangles are filled once with the nonzero fill rule.
+//! Dashed strokes and strokes set in `style` attributes are kept as they are.
+
+use std::fmt::Write;
+
+use anyhow::{anyhow, Result};
+use lyon::{
+    math::{point, vector, Angle, Point},
+    path::{builder::SvgPathBuilder, ArcFlags, Path},
+    tessellation::{
+        BuffersBuilder, LineCap, LineJoin, StrokeOptions, StrokeTessellator, StrokeVertex,
+        VertexBuffers,
+    },
+};
+
+use super::{
+    shape::{self, Cursor, Segment},
+    simplify::number,
+    svg::ParsedSvg,
+};
+
+/// Presentation attributes which are inherited and affect how a shape is stroked.
+const STROKE_PROPERTIES: &[&str] = &[
+    "stroke",
+    "stroke-width",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-dasharray",
+    "stroke-opacity",
+];
+
+/// Shapes which can be stroked.
+const SHAPES: &[&str] = &[
+    "path", "circle", "ellipse", "rect", "line", "polyline", "polygon",
+];
+
+/// Elements whose content is not drawn directly.
+const NOT_RENDERED: &[&str] = &["defs", "clipPath", "mask", "symbol", "pattern", "marker"];
+
+/// The svg content of the icon with all strokes expanded into filled outlines.
+pub fn expand_strokes(svg: &ParsedSvg) -> Result<String> {
+    let attributes = svg.svg_attributes();
+    let root = [
+        ("fill", attributes.fill.as_deref()),
+        ("stroke", attributes.stroke.as_deref()),
+        ("stroke-width", attributes.stroke_width.as_deref()),
+        ("stroke-linecap", attributes.stroke_linecap.as_deref()),
+        ("stroke-linejoin", attributes.stroke_linejoin.as_deref()),
+    ];
+    let [_, _, width, height] = attributes
+        .view_box
+        .as_deref()
+        .and_then(shape::parse_view_box)
+        .unwrap_or(shape::DEFAULT_VIEW_BOX);
+    // Flatten curves to a hundredth of a unit of a 24 unit icon.
+    let tolerance = width.max(height) / 2400.0;
+
+    // Open elements with their name, their own stroke properties and the outline to write after
+    // their end tag.
+    let mut open = vec![("svg", root.to_vec(), None::<String>)];
+    let mut expanded = String::with_capacity(svg.content.len() * 2);
+    let mut rest = svg.content.as_str();
+
+    while let Some(start) = rest.find('<') {
+        expanded.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start + 1..start + len];
+        rest = &rest[start + len + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            expanded.push('<');
+            expanded.push_str(tag);
+            expanded.push('>');
+            if let Some(i) = open.iter().rposition(|(open, _, _)| *open == name.trim()) {
+                for (_, _, outline) in open.drain(i.max(1)..).rev() {
+                    expanded.extend(outline);
+                }
+            }
+            continue;
+        }
+        if tag.starts_with(['!', '?']) {
+            write!(expanded, "<{tag}>")?;
+            continue;
+        }
+
+        let (name, attributes) = shape::element(tag);
+        let own = |key: &str| {
+            attributes
+                .iter()
+                .find(|(attribute, _)| *attribute == key)
+                .map(|(_, value)| *value)
+        };
+        let inherited = |key: &str| {
+            own(key).or_else(|| {
+                open.iter().rev().find_map(|(_, properties, _)| {
+                    properties
+                        .iter()
+                        .find(|(property, _)| *property == key)
+                        .and_then(|(_, value)| *value)
+                })
+            })
+        };
+
+        let rendered = !open.iter().any(|(open, _, _)| NOT_RENDERED.contains(open));
+        let stroke =
+            inherited("stroke").filter(|stroke| !matches!(stroke.trim(), "none" | "transparent"));
+        let outline = match (rendered && SHAPES.contains(&name), stroke) {
+            (true, Some(stroke))
+                if inherited("stroke-dasharray").is_none_or(|dash| dash.trim() == "none") =>
+            {
+                let options = stroke_options(&inherited, tolerance);
+                let triangles = outline(name, &own, &options)?;
+                let mut outline =
+                    format!(r#"<path fill="{stroke}" fill-rule="nonzero" stroke="none""#);
+                if let Some(opacity) = inherited("stroke-opacity") {
+                    write!(outline, r#" fill-opacity="{opacity}""#)?;
+                }
+                write!(outline, r#" d="{triangles}"/>"#)?;
+                Some(outline)
+            }
+            _ => None,
+        };
+
+        let self_closing = tag.ends_with('/');
+        let filled = name != "line"
+            && inherited("fill").is_none_or(|fill| !matches!(fill.trim(), "none" | "transparent"));
+        match outline {
+            // Nothing is left of a shape which is not filled.
+            Some(outline) if self_closing && !filled => expanded.push_str(&outline),
+            Some(outline) => {
+                // Keep the shape for its fill, without its stroke.
+                write!(expanded, "<{name}")?;
+                for (key, value) in &attributes {
+                    if !STROKE_PROPERTIES.contains(key) {
+                        write!(expanded, r#" {key}="{}""#, value.replace('"', "&quot;"))?;
+                    }
+                }
+                expanded.push_str(r#" stroke="none""#);
+                match self_closing {
+                    true => write!(expanded, "/>{outline}")?,
+                    false => {
+                        expanded.push('>');
+                        open.push((name, Vec::new(), Some(outline)));
+                    }
+                }
+            }
+            None => {
+                write!(expanded, "<{tag}>")?;
+                if !self_closing {
+                    let properties = ["fill"]
+                        .iter()
+                        .chain(STROKE_PROPERTIES)
+                        .map(|property| (*property, own(property)))
+                        .collect();
+                    open.push((name, properties, None));
+                }
+            }
+        }
+    }
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+fn stroke_options<'a>(
+    inherited: &impl Fn(&str) -> Option<&'a str>,
+    tolerance: f64,
+) -> StrokeOptions {
+    let number = |key: &str| inherited(key).and_then(|value| Cursor::new(value).number());
+    let line_cap = match inherited("stroke-linecap").map(str::trim) {
+        Some("round") => LineCap::Round,
+        Some("square") => LineCap::Square,
+        _ => LineCap::Butt,
+    };
+    let line_join = match inherited("stroke-linejoin").map(str::trim) {
+        Some("round") => LineJoin::Round,
+        Some("bevel") => LineJoin::Bevel,
+        _ => LineJoin::Miter,
+    };
+    StrokeOptions::default()
+        .with_line_width(number("stroke-width").unwrap_or(1.0) as f32)
+        .with_line_cap(line_cap)
+        .with_line_join(line_join)
+        .with_miter_limit(number("stroke-miterlimit").unwrap_or(4.0).max(1.0) as f32)
+        .with_tolerance(tolerance as f32)
+}
+
+/// Path data of the triangles covering the stroke of the given shape.
+fn outline<'a>(
+    name: &str,
+    attr: &impl Fn(&str) -> Option<&'a str>,
+    options: &StrokeOptions,
+) -> Result<String> {
+    let mut geometry: VertexBuffers<Point, u32> = VertexBuffers::new();
+    StrokeTessellator::new()
+        .tessellate_path(
+            &lyon_path(name, attr),
+            options,
+            &mut BuffersBuilder::new(&mut geometry, |vertex: StrokeVertex| vertex.position()),
+        )
+        .map_err(|err| anyhow!("Could not tessellate stroke: {err:?}"))?;
+
+    let mut triangles = String::new();
+    for triangle in geometry.indices.chunks_exact(3) {
+        let [a, mut b, mut c] = [0, 1, 2].map(|i| geometry.vertices[triangle[i] as usize]);
+        let cross = (b - a).cross(c - a);
+        if cross.abs() < f32::EPSILON {
+            continue;
+        }
+        if cross < 0.0 {
+            std::mem::swap(&mut b, &mut c);
+        }
+        let [a, b, c] = [a, b, c].map(|p| format!("{} {}", number(p.x as f64), number(p.y as f64)));
+        write!(triangles, "M{a}L{b} {c}Z")?;
+    }
+    Ok(triangles)
+}
+
+/// The given shape as lyon path.
+pub(super) fn lyon_path<'a>(name: &str, attr: &impl Fn(&str) -> Option<&'a str>) -> Path {
+    let d = match name {
+        "path" => attr("d").unwrap_or_default().to_owned(),
+        _ => shape_path(name, attr),
+    };
+
+    let mut builder = Path::svg_builder();
+    let to_point = |(x, y): (f64, f64)| point(x as f32, y as f32);
+    for segment in shape::path_segments(&d) {
+        match segment {
+            Segment::Move(to) => {
+                builder.move_to(to_point(to));
+            }
+            Segment::Line { close: true, .. } => {
+                builder.close();
+            }
+            Segment::Line { to, .. } => {
+                builder.line_to(to_point(to));
+            }
+            Segment::Curve(control) if control.len() == 4 => {
+                builder.cubic_bezier_to(
+                    to_point(control[1]),
+                    to_point(control[2]),
+                    to_point(control[3]),
+                );
+            }
+            Segment::Curve(control) => {
+                builder.quadratic_bezier_to(to_point(control[1]), to_point(control[2]));
+            }
+            Segment::Arc {
+                radii,
+                rotation,
+                large_arc,
+                sweep,
+                to,
+                ..
+            } => {
+                builder.arc_to(
+                    vector(radii.0 as f32, radii.1 as f32),
+                    Angle::degrees(rotation as f32),
+                    ArcFlags { large_arc, sweep },
+                    to_point(to),
+                );
+            }
+        }
+    }
+    builder.build()
+}
+
+/// Path data drawing the given basic shape.
+pub(super) fn shape_path<'a>(name: &str, attr: &impl Fn(&str) -> Option<&'a str>) -> String {
+    let num = |key: &str| {
+        attr(key)
+            .and_then(|value| Cursor::new(value).number())
+            .unwrap_or(0.0)
+    };
+    let n = number;
+    match name {
+        "circle" | "ellipse" => {
+            let (rx, ry) = match name {
+                "circle" => (num("r"), num("r")),
+                _ => (num("rx"), num("ry")),
+            };
+            let (cx, cy) = (num("cx"), num("cy"));
+            format!(
+                "M{} {}a{} {} 0 1 0 {} 0a{} {} 0 1 0 {} 0Z",
+                n(cx - rx),
+                n(cy),
+                n(rx),
+                n(ry),
+                n(2.0 * rx),
+                n(rx),
+                n(ry),
+                n(-2.0 * rx)
+            )
+        }
+        "rect" => {
+            let (x, y, w, h) = (num("x"), num("y"), num("width"), num("height"));
+            let (rx, ry) = match (attr("rx"), attr("ry")) {
+                (None, None) => (0.0, 0.0),
+                (Some(_), None) => (num("rx"), num("rx")),
+                (None, Some(_)) => (num("ry"), num("ry")),
+                (Some(_), Some(_)) => (num("rx"), num("ry")),
+            };
+            let (rx, ry) = (rx.min(w / 2.0), ry.min(h / 2.0));
+            let corner = |dx: f64, dy: f64| match rx > 0.0 && ry > 0.0 {
+                true => format!("a{} {} 0 0 1 {} {}", n(rx), n(ry), n(dx), n(dy)),
+                false => String::new(),
+            };
+            format!(
+                "M{} {}h{}{}v{}{}h{}{}v{}{}Z",
+                n(x + rx),
+                n(y),
+                n(w - 2.0 * rx),
+                corner(rx, ry),
+                n(h - 2.0 * ry),
+                corner(-rx, ry),
+                n(-(w - 2.0 * rx)),
+                corner(-rx, -ry),
+                n(-(h - 2.0 * ry)),
+                corner(rx, -ry)
+            )
+        }
+        "line" => format!(
+            "M{} {}L{} {}",
+            n(num("x1")),
+            n(num("y1")),
+            n(num("x2")),
+            n(num("y2"))
+        ),
+        _ => {
+            let points = attr("points").unwrap_or_default();
+            let close = if name == "polygon" { "Z" } else { "" };
+            format!("M{points}{close}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::expand_strokes;
+    use crate::icon::svg::{ColorMode, ParsedSvg};
+
+    #[test]
+    fn expands_strokes_into_filled_triangles() {
+        let svg = ParsedSvg::parse(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M0 12h24"/><circle cx="12" cy="12" r="4" fill="red" stroke="none"/></svg>"#.as_bytes(),
+            ColorMode::Monochrome,
+        )
+        .unwrap();
+        let expanded = expand_strokes(&svg).unwrap();
+
+        let (outline, circle) = expanded.split_once("<circle").unwrap();
+        assert!(outline
+            .starts_with(r#"<path fill="currentColor" fill-rule="nonzero" stroke="none" d="M"#));
+        assert_eq!(
+            circle,
+            r#" cx="12" cy="12" r="4" fill="red" stroke="none" />"#
+        );
+
+        // The stroke of a horizontal line covers a band as wide as the stroke.
+        let d = outline.split(r#" d=""#).nth(1).unwrap();
+        let coordinates = d
+            .trim_end_matches(r#""/>"#)
+            .split(['M', 'L', 'Z', ' '])
+            .filter(|number| !number.is_empty())
+            .map(|number| number.parse::<f64>().unwrap())
+            .collect::<Vec<_>>();
+        assert!(coordinates.len() >= 12);
+        for point in coordinates.chunks(2) {
+            assert!((0.0..=24.0).contains(&point[0]), "{point:?}");
+            assert!((11.0..=13.0).contains(&point[1]), "{point:?}");
+        }
+    }
+}
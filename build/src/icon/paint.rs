@@ -0,0 +1,122 @@
+//! Whether an icon is drawn by filling or by stroking its shapes, independent of whether its
+//! package encodes this in icon names.
+//!
+//! Shapes inherit `fill` and `stroke` from the root svg element and enclosing elements. Shapes in
+//! definitions, clip paths and masks are not drawn themselves and therefore ignored.
+
+use icondata_core::IconPaint;
+
+use super::{shape, svg::ParsedSvg};
+
+/// Shapes which are drawn with the inherited paint.
+const SHAPES: &[&str] = &[
+    "path", "circle", "ellipse", "rect", "line", "polyline", "polygon", "text",
+];
+
+/// Elements whose content is not drawn directly.
+const NOT_RENDERED: &[&str] = &[
+    "defs",
+    "clipPath",
+    "mask",
+    "symbol",
+    "pattern",
+    "marker",
+    "linearGradient",
+    "radialGradient",
+];
+
+/// Classifies the given icon as painted by fill, stroke or both.
+pub fn paint(svg: &ParsedSvg) -> IconPaint {
+    let attributes = svg.svg_attributes();
+    classify(
+        &svg.content,
+        attributes.fill.as_deref(),
+        attributes.stroke.as_deref(),
+    )
+}
+
+fn classify(content: &str, fill: Option<&str>, stroke: Option<&str>) -> IconPaint {
+    // Open elements with their own fill and stroke, starting with the root svg element.
+    let mut open = vec![("svg", fill, stroke)];
+    let (mut filled, mut stroked) = (false, false);
+
+    for tag in content.split('<').skip(1) {
+        let Some(tag) = tag.split('>').next() else {
+            continue;
+        };
+        if let Some(name) = tag.strip_prefix('/') {
+            if let Some(i) = open.iter().rposition(|(open, _, _)| *open == name.trim()) {
+                open.truncate(i.max(1));
+            }
+            continue;
+        }
+        if tag.starts_with(['!', '?']) {
+            continue;
+        }
+
+        let (name, attributes) = shape::element(tag);
+        let attr = |name: &str| {
+            attributes
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| *value)
+        };
+        let fill = attr("fill").or_else(|| open.iter().rev().find_map(|(_, fill, _)| *fill));
+        let stroke =
+            attr("stroke").or_else(|| open.iter().rev().find_map(|(_, _, stroke)| *stroke));
+
+        let rendered = !open.iter().any(|(open, _, _)| NOT_RENDERED.contains(open));
+        if rendered && SHAPES.contains(&name) {
+            // Unlike stroke, fill defaults to black. Lines have no area to fill.
+            filled |= name != "line" && fill.is_none_or(is_paint);
+            stroked |= stroke.is_some_and(is_paint);
+        }
+
+        if !tag.ends_with('/') {
+            open.push((name, attr("fill"), attr("stroke")));
+        }
+    }
+
+    match (filled, stroked) {
+        (true, true) => IconPaint::FillAndStroke,
+        (false, true) => IconPaint::Stroke,
+        _ => IconPaint::Fill,
+    }
+}
+
+fn is_paint(value: &str) -> bool {
+    !matches!(value.trim(), "none" | "transparent")
+}
+
+#[cfg(test)]
+mod test {
+    use icondata_core::IconPaint;
+
+    use super::classify;
+
+    #[test]
+    fn classifies_by_inherited_paint() {
+        let path = r#"<path d="M2 2h20v20H2z"/>"#;
+        assert_eq!(classify(path, None, None), IconPaint::Fill);
+        assert_eq!(
+            classify(path, Some("none"), Some("currentColor")),
+            IconPaint::Stroke
+        );
+        assert_eq!(
+            classify(
+                r#"<g fill="none" stroke="currentColor"><path d="M2 2h20"/></g><circle r="2"/>"#,
+                None,
+                None
+            ),
+            IconPaint::FillAndStroke
+        );
+        assert_eq!(
+            classify(
+                r#"<defs><clipPath id="a"><rect width="24" height="24"/></clipPath></defs><path d="M2 2h20" stroke="black"/>"#,
+                Some("none"),
+                None
+            ),
+            IconPaint::Stroke
+        );
+    }
+}
@@ -0,0 +1,342 @@
+//! Dominant colors of multi-colored icons, like flags, brand logos and emoji, and the pairs of them
+//! which are hard to tell apart with common color vision deficiencies.
+//!
+//! Colors are taken from the `fill` and `stroke` of drawn shapes, inherited as for
+//! [`super::paint`], and weighted by the number of shapes painted with them. `currentColor`,
+//! gradients and colors set in `style` attributes are not taken into account.
+
+use super::{shape, svg::ParsedSvg};
+
+/// Shapes which are drawn with the inherited paint.
+const SHAPES: &[&str] = &[
+    "path", "circle", "ellipse", "rect", "line", "polyline", "polygon", "text",
+];
+
+/// Elements whose content is not drawn directly.
+const NOT_RENDERED: &[&str] = &[
+    "defs",
+    "clipPath",
+    "mask",
+    "symbol",
+    "pattern",
+    "marker",
+    "linearGradient",
+    "radialGradient",
+];
+
+/// Number of colors kept per icon.
+const MAX_COLORS: usize = 6;
+
+/// CIE76 color difference from which two colors are told apart at icon sizes.
+const DISTINGUISHABLE: f64 = 15.0;
+
+/// Named colors, besides hexadecimal and `rgb()` notation.
+const NAMED_COLORS: &[(&str, [u8; 3])] = &[
+    ("black", [0, 0, 0]),
+    ("silver", [192, 192, 192]),
+    ("gray", [128, 128, 128]),
+    ("grey", [128, 128, 128]),
+    ("white", [255, 255, 255]),
+    ("maroon", [128, 0, 0]),
+    ("red", [255, 0, 0]),
+    ("purple", [128, 0, 128]),
+    ("fuchsia", [255, 0, 255]),
+    ("green", [0, 128, 0]),
+    ("lime", [0, 255, 0]),
+    ("olive", [128, 128, 0]),
+    ("yellow", [255, 255, 0]),
+    ("navy", [0, 0, 128]),
+    ("blue", [0, 0, 255]),
+    ("teal", [0, 128, 128]),
+    ("aqua", [0, 255, 255]),
+    ("orange", [255, 165, 0]),
+];
+
+/// Dominant colors of an icon and the pairs of them which collapse under a color vision deficiency.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Palette {
+    /// Colors by descending share.
+    pub colors: Vec<PaletteColor>,
+    pub conflicts: Vec<Conflict>,
+}
+
+impl Palette {
+    pub fn is_multi_color(&self) -> bool {
+        self.colors.len() > 1
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteColor {
+    /// Color in `#rrggbb` notation.
+    pub hex: String,
+    /// Share of the drawn shapes painted with this color.
+    pub share: f64,
+}
+
+/// Two colors of an icon which are told apart with normal color vision, but not with `deficiency`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+    pub colors: [String; 2],
+    pub deficiency: Deficiency,
+}
+
+/// Common kinds of color blindness, simulated after Machado et al. (2009) at full severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Deficiency {
+    /// Missing red cones.
+    Protanopia,
+    /// Missing green cones.
+    Deuteranopia,
+    /// Missing blue cones.
+    Tritanopia,
+}
+
+impl Deficiency {
+    const ALL: [Deficiency; 3] = [
+        Deficiency::Protanopia,
+        Deficiency::Deuteranopia,
+        Deficiency::Tritanopia,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Deficiency::Protanopia => "protanopia",
+            Deficiency::Deuteranopia => "deuteranopia",
+            Deficiency::Tritanopia => "tritanopia",
+        }
+    }
+
+    /// Transformation of linear RGB as perceived with this deficiency.
+    fn matrix(self) -> [[f64; 3]; 3] {
+        match self {
+            Deficiency::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            Deficiency::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            Deficiency::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+}
+
+/// The dominant colors of the given icon and their conflicts.
+pub fn palette(svg: &ParsedSvg) -> Palette {
+    let attributes = svg.svg_attributes();
+    let mut counts = Vec::<([u8; 3], usize)>::new();
+    for color in paints(
+        &svg.content,
+        attributes.fill.as_deref(),
+        attributes.stroke.as_deref(),
+    )
+    .filter_map(parse_color)
+    {
+        match counts.iter_mut().find(|(counted, _)| *counted == color) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((color, 1)),
+        }
+    }
+    let total = counts.iter().map(|(_, count)| count).sum::<usize>();
+    // Sorting is stable, so colors used equally often keep the order they appear in.
+    counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+    counts.truncate(MAX_COLORS);
+
+    let mut conflicts = Vec::new();
+    for (i, (a, _)) in counts.iter().enumerate() {
+        for (b, _) in &counts[i + 1..] {
+            if delta_e(lab(linear(*a)), lab(linear(*b))) < DISTINGUISHABLE {
+                continue;
+            }
+            for deficiency in Deficiency::ALL {
+                let [a_seen, b_seen] = [a, b].map(|color| simulate(linear(*color), deficiency));
+                if delta_e(lab(a_seen), lab(b_seen)) < DISTINGUISHABLE {
+                    conflicts.push(Conflict {
+                        colors: [hex(*a), hex(*b)],
+                        deficiency,
+                    });
+                }
+            }
+        }
+    }
+
+    Palette {
+        colors: counts
+            .into_iter()
+            .map(|(color, count)| PaletteColor {
+                hex: hex(color),
+                share: count as f64 / total as f64,
+            })
+            .collect(),
+        conflicts,
+    }
+}
+
+/// The fill and stroke of every drawn shape.
+fn paints<'a>(
+    content: &'a str,
+    fill: Option<&'a str>,
+    stroke: Option<&'a str>,
+) -> impl Iterator<Item = &'a str> {
+    // Open elements with their own fill and stroke, starting with the root svg element.
+    let mut open = vec![("svg", fill, stroke)];
+    let mut paints = Vec::new();
+
+    for tag in content.split('<').skip(1) {
+        let Some(tag) = tag.split('>').next() else {
+            continue;
+        };
+        if let Some(name) = tag.strip_prefix('/') {
+            if let Some(i) = open.iter().rposition(|(open, _, _)| *open == name.trim()) {
+                open.truncate(i.max(1));
+            }
+            continue;
+        }
+        if tag.starts_with(['!', '?']) {
+            continue;
+        }
+
+        let (name, attributes) = shape::element(tag);
+        let attr = |name: &str| {
+            attributes
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| *value)
+        };
+        let fill = attr("fill").or_else(|| open.iter().rev().find_map(|(_, fill, _)| *fill));
+        let stroke =
+            attr("stroke").or_else(|| open.iter().rev().find_map(|(_, _, stroke)| *stroke));
+
+        let rendered = !open.iter().any(|(open, _, _)| NOT_RENDERED.contains(open));
+        if rendered && SHAPES.contains(&name) {
+            // Fill defaults to black. Lines have no area to fill.
+            if name != "line" {
+                paints.push(fill.unwrap_or("black"));
+            }
+            paints.extend(stroke);
+        }
+
+        if !tag.ends_with('/') {
+            open.push((name, attr("fill"), attr("stroke")));
+        }
+    }
+    paints.into_iter()
+}
+
+/// Parses colors in `#rgb`, `#rrggbb`, `rgb()` or named notation, ignoring transparency.
+fn parse_color(value: &str) -> Option<[u8; 3]> {
+    let value = value.trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        let digit = |i: usize| u8::from_str_radix(hex.get(i..i + 1)?, 16).ok();
+        return match hex.len() {
+            3 | 4 => Some([digit(0)? * 17, digit(1)? * 17, digit(2)? * 17]),
+            6 | 8 => Some([
+                digit(0)? * 16 + digit(1)?,
+                digit(2)? * 16 + digit(3)?,
+                digit(4)? * 16 + digit(5)?,
+            ]),
+            _ => None,
+        };
+    }
+    if let Some(channels) = value
+        .strip_prefix("rgb(")
+        .or_else(|| value.strip_prefix("rgba("))
+    {
+        let mut channels = channels
+            .trim_end_matches(')')
+            .split([',', ' ', '/'])
+            .filter(|channel| !channel.is_empty())
+            .map(|channel| match channel.strip_suffix('%') {
+                Some(percent) => percent.parse::<f64>().ok().map(|p| p * 2.55),
+                None => channel.parse::<f64>().ok(),
+            })
+            .map(|channel| channel.map(|c| c.round().clamp(0.0, 255.0) as u8));
+        return Some([channels.next()??, channels.next()??, channels.next()??]);
+    }
+    NAMED_COLORS
+        .iter()
+        .find(|(name, _)| value.eq_ignore_ascii_case(name))
+        .map(|(_, color)| *color)
+}
+
+fn hex([r, g, b]: [u8; 3]) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+/// Linear RGB of an sRGB color.
+fn linear(color: [u8; 3]) -> [f64; 3] {
+    color.map(|c| {
+        let c = c as f64 / 255.0;
+        match c <= 0.04045 {
+            true => c / 12.92,
+            false => ((c + 0.055) / 1.055).powf(2.4),
+        }
+    })
+}
+
+fn simulate(rgb: [f64; 3], deficiency: Deficiency) -> [f64; 3] {
+    deficiency
+        .matrix()
+        .map(|row| (0..3).map(|i| row[i] * rgb[i]).sum::<f64>().clamp(0.0, 1.0))
+}
+
+/// CIELAB coordinates of a linear RGB color, relative to the D65 white point.
+fn lab([r, g, b]: [f64; 3]) -> [f64; 3] {
+    let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.95047;
+    let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.08883;
+    let f = |t: f64| match t > 0.008856 {
+        true => t.cbrt(),
+        false => 7.787 * t + 16.0 / 116.0,
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+fn delta_e(a: [f64; 3], b: [f64; 3]) -> f64 {
+    (0..3).map(|i| (a[i] - b[i]).powi(2)).sum::<f64>().sqrt()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{palette, Conflict, Deficiency};
+    use crate::icon::svg::{ColorMode, ParsedSvg};
+
+    #[test]
+    fn finds_colors_confused_with_deficiencies() {
+        let svg = ParsedSvg::parse(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="red"><rect width="24" height="8"/><rect y="8" width="24" height="8" fill="rgb(0, 128, 0)"/><rect y="16" width="12" height="8" fill="#F00"/><path d="M12 16h12v8H12z" fill="none" stroke="navy"/><defs><path id="a" fill="yellow" d="M0 0h1v1z"/></defs></svg>"##.as_bytes(),
+            ColorMode::Monochrome,
+        )
+        .unwrap();
+        let palette = palette(&svg);
+
+        let colors = palette
+            .colors
+            .iter()
+            .map(|color| (color.hex.as_str(), color.share))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            colors,
+            [("#ff0000", 0.5), ("#008000", 0.25), ("#000080", 0.25)]
+        );
+        assert!(palette.is_multi_color());
+
+        // Without red cones, red appears as dark as green. Navy stays apart from both.
+        assert_eq!(
+            palette.conflicts,
+            [Conflict {
+                colors: ["#ff0000".to_owned(), "#008000".to_owned()],
+                deficiency: Deficiency::Protanopia,
+            }]
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Flattening of icon outlines into polylines, for cutting, engraving and plotting.
+//!
+//! Every drawn shape contributes the polylines of its subpaths, sampled along curves and arcs.
+//! Fills and strokes are not distinguished: a filled shape is traced along its outline, a stroked
+//! one along its center line. Transforms are ignored.
+
+use super::{
+    outline,
+    shape::{self, Segment},
+    svg::ParsedSvg,
+};
+
+/// Shapes which are drawn.
+const SHAPES: &[&str] = &[
+    "path", "circle", "ellipse", "rect", "line", "polyline", "polygon",
+];
+
+/// Elements whose content is not drawn directly.
+const NOT_RENDERED: &[&str] = &["defs", "clipPath", "mask", "symbol", "pattern", "marker"];
+
+/// A connected line through points in view box coordinates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline {
+    pub points: Vec<(f64, f64)>,
+    /// Whether the last point connects back to the first one.
+    pub closed: bool,
+}
+
+/// View box of the icon, or the default one of 24 by 24 units.
+pub fn view_box(svg: &ParsedSvg) -> [f64; 4] {
+    svg.svg_attributes()
+        .view_box
+        .as_deref()
+        .and_then(shape::parse_view_box)
+        .unwrap_or(shape::DEFAULT_VIEW_BOX)
+}
+
+/// The polylines of all shapes drawn by the given svg content.
+pub fn polylines(content: &str) -> Vec<Polyline> {
+    let mut open = Vec::new();
+    let mut polylines = Vec::new();
+
+    for tag in content.split('<').skip(1) {
+        let Some(tag) = tag.split('>').next() else {
+            continue;
+        };
+        if let Some(name) = tag.strip_prefix('/') {
+            if let Some(i) = open.iter().rposition(|open| *open == name.trim()) {
+                open.truncate(i);
+            }
+            continue;
+        }
+        if tag.starts_with(['!', '?']) {
+            continue;
+        }
+
+        let (name, attributes) = shape::element(tag);
+        if !tag.ends_with('/') {
+            open.push(name);
+        }
+        let rendered = !open.iter().any(|open| NOT_RENDERED.contains(open));
+        if !rendered || !SHAPES.contains(&name) {
+            continue;
+        }
+
+        let attr = |key: &str| {
+            attributes
+                .iter()
+                .find(|(attribute, _)| *attribute == key)
+                .map(|(_, value)| *value)
+        };
+        let d = match name {
+            "path" => attr("d").unwrap_or_default().to_owned(),
+            _ => outline::shape_path(name, &attr),
+        };
+        path_polylines(&shape::path_segments(&d), &mut polylines);
+    }
+    polylines
+}
+
+fn path_polylines(segments: &[Segment], polylines: &mut Vec<Polyline>) {
+    let mut points = Vec::new();
+    let mut finish = |points: &mut Vec<(f64, f64)>, closed: bool| {
+        if points.len() > 1 {
+            polylines.push(Polyline {
+                points: std::mem::take(points),
+                closed,
+            });
+        }
+        points.clear();
+    };
+
+    for segment in segments {
+        match segment {
+            Segment::Move(_) => finish(&mut points, false),
+            Segment::Line { close: true, .. } => {
+                finish(&mut points, true);
+                // A new subpath starts where a closed one started, unless moved.
+                points.push(segment.end());
+                continue;
+            }
+            _ => {}
+        }
+        segment.sample(&mut points);
+    }
+    finish(&mut points, false);
+}
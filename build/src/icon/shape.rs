@@ -0,0 +1,536 @@
+//! A coarse fingerprint of the geometry of an icon, used to find visually similar icons.
+//!
+//! The outline of every shape is sampled and the samples are placed on an 8x8 grid spanning the
+//! view box. Each occupied cell sets one bit of the resulting hash, so the hamming distance of two
+//! hashes tells how differently the two icons cover their canvas. Transforms, strokes and fill
+//! rules are ignored, which is good enough to detect near-duplicate glyphs.
+
+use super::svg::ParsedSvg;
+
+const GRID: f64 = 8.0;
+
+/// Number of samples taken along each straight or curved segment.
+const SAMPLES: usize = 16;
+
+/// View box assumed for icons which do not declare one.
+pub(super) const DEFAULT_VIEW_BOX: [f64; 4] = [0.0, 0.0, 24.0, 24.0];
+
+/// Computes the shape hash of the given icon.
+pub fn shape_hash(svg: &ParsedSvg) -> u64 {
+    let view_box = svg
+        .svg_attributes()
+        .view_box
+        .as_deref()
+        .and_then(parse_view_box)
+        .unwrap_or(DEFAULT_VIEW_BOX);
+    hash_points(&outline(&svg.content), view_box)
+}
+
+pub(super) fn parse_view_box(view_box: &str) -> Option<[f64; 4]> {
+    let mut cursor = Cursor::new(view_box);
+    let view_box = [
+        cursor.number()?,
+        cursor.number()?,
+        cursor.number()?,
+        cursor.number()?,
+    ];
+    (view_box[2] > 0.0 && view_box[3] > 0.0).then_some(view_box)
+}
+
+fn hash_points(points: &[(f64, f64)], [min_x, min_y, width, height]: [f64; 4]) -> u64 {
+    points.iter().fold(0, |hash, (x, y)| {
+        let cell = |value: f64, min: f64, size: f64| {
+            (((value - min) / size * GRID).floor()).clamp(0.0, GRID - 1.0) as u64
+        };
+        let (col, row) = (cell(*x, min_x, width), cell(*y, min_y, height));
+        hash | 1 << (row * GRID as u64 + col)
+    })
+}
+
+/// Samples the outlines of all basic shapes and paths found in the svg content.
+pub(super) fn outline(content: &str) -> Vec<(f64, f64)> {
+    let mut points = Vec::new();
+    for (tag, attributes) in elements(content) {
+        let attr = |name: &str| {
+            attributes
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| *value)
+        };
+        let num = |name: &str| attr(name).and_then(|value| Cursor::new(value).number());
+
+        match tag {
+            "path" => {
+                if let Some(d) = attr("d") {
+                    sample_path(d, &mut points);
+                }
+            }
+            "circle" | "ellipse" => {
+                let (cx, cy) = (num("cx").unwrap_or(0.0), num("cy").unwrap_or(0.0));
+                let (rx, ry) = match tag {
+                    "circle" => (num("r").unwrap_or(0.0), num("r").unwrap_or(0.0)),
+                    _ => (num("rx").unwrap_or(0.0), num("ry").unwrap_or(0.0)),
+                };
+                let steps = SAMPLES * 2;
+                points.extend((0..steps).map(|i| {
+                    let angle = i as f64 / steps as f64 * std::f64::consts::TAU;
+                    (cx + rx * angle.cos(), cy + ry * angle.sin())
+                }));
+            }
+            "rect" => {
+                let (x, y) = (num("x").unwrap_or(0.0), num("y").unwrap_or(0.0));
+                let (w, h) = (num("width").unwrap_or(0.0), num("height").unwrap_or(0.0));
+                let corners = [(x, y), (x + w, y), (x + w, y + h), (x, y + h), (x, y)];
+                for pair in corners.windows(2) {
+                    sample_line(pair[0], pair[1], &mut points);
+                }
+            }
+            "line" => sample_line(
+                (num("x1").unwrap_or(0.0), num("y1").unwrap_or(0.0)),
+                (num("x2").unwrap_or(0.0), num("y2").unwrap_or(0.0)),
+                &mut points,
+            ),
+            "polygon" | "polyline" => {
+                let mut cursor = Cursor::new(attr("points").unwrap_or_default());
+                let mut vertices = Vec::new();
+                while let (Some(x), Some(y)) = (cursor.number(), cursor.number()) {
+                    vertices.push((x, y));
+                }
+                if tag == "polygon" {
+                    vertices.extend(vertices.first().copied());
+                }
+                for pair in vertices.windows(2) {
+                    sample_line(pair[0], pair[1], &mut points);
+                }
+            }
+            _ => {}
+        }
+    }
+    points
+}
+
+/// Start tags in the content, with their name and attributes. Only as much xml as icons use.
+pub(crate) fn elements(content: &str) -> Vec<(&str, Vec<(&str, &str)>)> {
+    let mut elements = Vec::new();
+    for tag in content.split('<').skip(1) {
+        let Some(tag) = tag.split('>').next() else {
+            continue;
+        };
+        if tag.starts_with(['/', '!', '?']) {
+            continue;
+        }
+        elements.push(element(tag));
+    }
+    elements
+}
+
+/// The content with attribute values returned by [`elements`] replaced, in order of appearance.
+pub(super) fn replace_values(content: &str, replacements: &[(&str, String)]) -> String {
+    let mut replaced = String::with_capacity(content.len());
+    let mut copied = 0;
+    for (value, replacement) in replacements {
+        // Attribute values are slices of the content.
+        let start = value.as_ptr() as usize - content.as_ptr() as usize;
+        replaced.push_str(&content[copied..start]);
+        replaced.push_str(replacement);
+        copied = start + value.len();
+    }
+    replaced.push_str(&content[copied..]);
+    replaced
+}
+
+/// Name and attributes of a start tag, given without its angle brackets.
+pub(super) fn element(tag: &str) -> (&str, Vec<(&str, &str)>) {
+    let name_end = tag
+        .find(|c: char| c.is_whitespace() || c == '/')
+        .unwrap_or(tag.len());
+    let mut attributes = Vec::new();
+    let mut rest = &tag[name_end..];
+    while let Some(eq) = rest.find('=') {
+        let key = rest[..eq].trim();
+        let value = rest[eq + 1..].trim_start();
+        let Some(quote) = value.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+            break;
+        };
+        let Some(end) = value[1..].find(quote) else {
+            break;
+        };
+        attributes.push((key, &value[1..end + 1]));
+        rest = &value[end + 2..];
+    }
+    (&tag[..name_end], attributes)
+}
+
+fn sample_line(from: (f64, f64), to: (f64, f64), points: &mut Vec<(f64, f64)>) {
+    sample_curve(&[from, to], points);
+}
+
+/// Samples the bezier curve with the given control points, including its end point.
+fn sample_curve(control: &[(f64, f64)], points: &mut Vec<(f64, f64)>) {
+    for i in 0..=SAMPLES {
+        let t = i as f64 / SAMPLES as f64;
+        // De Casteljau's algorithm.
+        let mut reduced = control.to_vec();
+        while reduced.len() > 1 {
+            reduced = reduced
+                .windows(2)
+                .map(|pair| {
+                    (
+                        pair[0].0 + (pair[1].0 - pair[0].0) * t,
+                        pair[0].1 + (pair[1].1 - pair[0].1) * t,
+                    )
+                })
+                .collect();
+        }
+        points.extend(reduced);
+    }
+}
+
+/// Samples an elliptical arc, converting it to center parameterization as described in the svg
+/// specification (F.6.5 and F.6.6).
+fn sample_arc(
+    from: (f64, f64),
+    (rx, ry): (f64, f64),
+    rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+    to: (f64, f64),
+    points: &mut Vec<(f64, f64)>,
+) {
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+    if rx == 0.0 || ry == 0.0 || from == to {
+        return sample_line(from, to, points);
+    }
+    let (sin, cos) = rotation.to_radians().sin_cos();
+    let (dx, dy) = ((from.0 - to.0) / 2.0, (from.1 - to.1) / 2.0);
+    let (x1, y1) = (cos * dx + sin * dy, -sin * dx + cos * dy);
+
+    // Scale up radii which are too small to connect both points.
+    let scale = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if scale > 1.0 {
+        rx *= scale.sqrt();
+        ry *= scale.sqrt();
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let mut factor = (numerator / denominator).max(0.0).sqrt();
+    if large_arc == sweep {
+        factor = -factor;
+    }
+    let (cx1, cy1) = (factor * rx * y1 / ry, -factor * ry * x1 / rx);
+    let center = (
+        cos * cx1 - sin * cy1 + (from.0 + to.0) / 2.0,
+        sin * cx1 + cos * cy1 + (from.1 + to.1) / 2.0,
+    );
+
+    let angle = |x: f64, y: f64| y.atan2(x);
+    let start = angle((x1 - cx1) / rx, (y1 - cy1) / ry);
+    let mut delta = angle((-x1 - cx1) / rx, (-y1 - cy1) / ry) - start;
+    if sweep && delta < 0.0 {
+        delta += std::f64::consts::TAU;
+    } else if !sweep && delta > 0.0 {
+        delta -= std::f64::consts::TAU;
+    }
+
+    let steps = SAMPLES * 2;
+    points.extend((0..=steps).map(|i| {
+        let theta = start + delta * i as f64 / steps as f64;
+        let (x, y) = (rx * theta.cos(), ry * theta.sin());
+        (cos * x - sin * y + center.0, sin * x + cos * y + center.1)
+    }));
+}
+
+/// Samples all segments of the given path data.
+fn sample_path(d: &str, points: &mut Vec<(f64, f64)>) {
+    for segment in path_segments(d) {
+        match segment {
+            Segment::Move(point) => points.push(point),
+            Segment::Line { from, to, .. } => sample_line(from, to, points),
+            Segment::Curve(control) => sample_curve(&control, points),
+            Segment::Arc {
+                from,
+                radii,
+                rotation,
+                large_arc,
+                sweep,
+                to,
+            } => sample_arc(from, radii, rotation, large_arc, sweep, to, points),
+        }
+    }
+}
+
+/// A command of path data, in absolute coordinates.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Segment {
+    /// Start of a subpath.
+    Move((f64, f64)),
+    /// Straight line from a `L`, `H` or `V` command, or from a `Z` command closing the subpath.
+    Line {
+        from: (f64, f64),
+        to: (f64, f64),
+        close: bool,
+    },
+    /// Bezier curve given by its control points, starting at the current point.
+    Curve(Vec<(f64, f64)>),
+    Arc {
+        from: (f64, f64),
+        radii: (f64, f64),
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        to: (f64, f64),
+    },
+}
+
+impl Segment {
+    /// End point of the segment, which is where the next one starts.
+    pub(super) fn end(&self) -> (f64, f64) {
+        match self {
+            Segment::Move(point) => *point,
+            Segment::Line { to, .. } | Segment::Arc { to, .. } => *to,
+            Segment::Curve(control) => control[control.len() - 1],
+        }
+    }
+
+    /// Samples the segment, excluding its start point.
+    pub(super) fn sample(&self, points: &mut Vec<(f64, f64)>) {
+        let start = points.len();
+        match self {
+            Segment::Move(point) => return points.push(*point),
+            Segment::Line { to, .. } => return points.push(*to),
+            Segment::Curve(control) => sample_curve(control, points),
+            Segment::Arc {
+                from,
+                radii,
+                rotation,
+                large_arc,
+                sweep,
+                to,
+            } => sample_arc(*from, *radii, *rotation, *large_arc, *sweep, *to, points),
+        }
+        if points.len() > start {
+            points.remove(start);
+        }
+    }
+}
+
+/// Parses the given path data into its segments, stopping at the first error like browsers do.
+/// Commands repeated implicitly are returned as separate segments.
+pub(super) fn path_segments(d: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut cursor = Cursor::new(d);
+    let mut command = None;
+    let mut current = (0.0, 0.0);
+    let mut start = (0.0, 0.0);
+    // Last control point of a curve, used to reflect the first control point of S and T commands.
+    let mut last_control: Option<(f64, f64)> = None;
+
+    while let Some(next) = cursor.command().or(command) {
+        let relative = next.is_ascii_lowercase();
+        let offset = |(x, y): (f64, f64), current: (f64, f64)| match relative {
+            true => (current.0 + x, current.1 + y),
+            false => (x, y),
+        };
+        let reflected = |current: (f64, f64), control: Option<(f64, f64)>| {
+            control.map_or(current, |(x, y)| (2.0 * current.0 - x, 2.0 * current.1 - y))
+        };
+        let line = |to| Segment::Line {
+            from: current,
+            to,
+            close: false,
+        };
+
+        let parsed = (|| {
+            let mut control = None;
+            let segment = match next.to_ascii_uppercase() {
+                'M' => {
+                    let end = offset(cursor.point()?, current);
+                    start = end;
+                    // Coordinates following a move are implicit line commands.
+                    command = Some(if relative { 'l' } else { 'L' });
+                    return Some(Segment::Move(end));
+                }
+                'L' => line(offset(cursor.point()?, current)),
+                'H' => {
+                    let x = cursor.number()?;
+                    line((if relative { current.0 + x } else { x }, current.1))
+                }
+                'V' => {
+                    let y = cursor.number()?;
+                    line((current.0, if relative { current.1 + y } else { y }))
+                }
+                'C' => {
+                    let c1 = offset(cursor.point()?, current);
+                    let c2 = offset(cursor.point()?, current);
+                    let end = offset(cursor.point()?, current);
+                    control = Some(c2);
+                    Segment::Curve(vec![current, c1, c2, end])
+                }
+                'S' => {
+                    let c1 = reflected(current, last_control);
+                    let c2 = offset(cursor.point()?, current);
+                    let end = offset(cursor.point()?, current);
+                    control = Some(c2);
+                    Segment::Curve(vec![current, c1, c2, end])
+                }
+                'Q' => {
+                    let c = offset(cursor.point()?, current);
+                    let end = offset(cursor.point()?, current);
+                    control = Some(c);
+                    Segment::Curve(vec![current, c, end])
+                }
+                'T' => {
+                    let c = reflected(current, last_control);
+                    let end = offset(cursor.point()?, current);
+                    control = Some(c);
+                    Segment::Curve(vec![current, c, end])
+                }
+                'A' => {
+                    let radii = cursor.point()?;
+                    let rotation = cursor.number()?;
+                    let (large_arc, sweep) = (cursor.flag()?, cursor.flag()?);
+                    let to = offset(cursor.point()?, current);
+                    Segment::Arc {
+                        from: current,
+                        radii,
+                        rotation,
+                        large_arc,
+                        sweep,
+                        to,
+                    }
+                }
+                'Z' => {
+                    command = None;
+                    last_control = None;
+                    return Some(Segment::Line {
+                        from: current,
+                        to: start,
+                        close: true,
+                    });
+                }
+                _ => return None,
+            };
+            command = Some(next);
+            last_control = control;
+            Some(segment)
+        })();
+
+        match parsed {
+            Some(segment) => {
+                current = segment.end();
+                segments.push(segment);
+            }
+            None => break,
+        }
+    }
+    segments
+}
+
+/// Reads numbers, flags and commands from svg attribute values.
+pub(super) struct Cursor<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    pub(super) fn new(value: &'a str) -> Self {
+        Self { rest: value }
+    }
+
+    fn skip_separators(&mut self) {
+        self.rest = self
+            .rest
+            .trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+    }
+
+    /// Whether nothing but separators is left.
+    pub(super) fn is_empty(&mut self) -> bool {
+        self.skip_separators();
+        self.rest.is_empty()
+    }
+
+    fn command(&mut self) -> Option<char> {
+        self.skip_separators();
+        let command = self.rest.chars().next().filter(char::is_ascii_alphabetic)?;
+        self.rest = &self.rest[1..];
+        Some(command)
+    }
+
+    fn flag(&mut self) -> Option<bool> {
+        self.skip_separators();
+        let flag = match self.rest.chars().next()? {
+            '0' => false,
+            '1' => true,
+            _ => return None,
+        };
+        self.rest = &self.rest[1..];
+        Some(flag)
+    }
+
+    fn point(&mut self) -> Option<(f64, f64)> {
+        Some((self.number()?, self.number()?))
+    }
+
+    pub(super) fn number(&mut self) -> Option<f64> {
+        self.skip_separators();
+        let bytes = self.rest.as_bytes();
+        let mut end = 0;
+        if matches!(bytes.first(), Some(b'-' | b'+')) {
+            end += 1;
+        }
+        let digits = |end: &mut usize| {
+            while bytes.get(*end).is_some_and(u8::is_ascii_digit) {
+                *end += 1;
+            }
+        };
+        digits(&mut end);
+        if bytes.get(end) == Some(&b'.') {
+            end += 1;
+            digits(&mut end);
+        }
+        if matches!(bytes.get(end), Some(b'e' | b'E'))
+            && bytes
+                .get(end + 1)
+                .is_some_and(|b| b.is_ascii_digit() || *b == b'-' || *b == b'+')
+        {
+            end += 2;
+            digits(&mut end);
+        }
+        let number = self.rest[..end].parse().ok()?;
+        self.rest = &self.rest[end..];
+        Some(number)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{hash_points, outline, DEFAULT_VIEW_BOX};
+
+    fn distance(a: u64, b: u64) -> u32 {
+        (a ^ b).count_ones()
+    }
+
+    fn hash(content: &str) -> u64 {
+        hash_points(&outline(content), DEFAULT_VIEW_BOX)
+    }
+
+    #[test]
+    fn equal_shapes_have_equal_hashes() {
+        let rect = hash(r#"<rect x="2" y="2" width="20" height="20"/>"#);
+        let path = hash(r#"<path d="M2 2h20v20H2z"/>"#);
+        let relative = hash(r#"<path d="m2,2 20,0 0,20 -20,0Z"/>"#);
+        assert_eq!(rect, path);
+        assert_eq!(path, relative);
+        assert_eq!(rect.count_ones(), 28);
+    }
+
+    #[test]
+    fn similar_shapes_are_closer() {
+        let circle = hash(r#"<circle cx="12" cy="12" r="10"/>"#);
+        let arcs = hash(r#"<path d="M2 12a10 10 0 1020 0a10 10 0 10-20 0"/>"#);
+        let ellipse = hash(r#"<ellipse cx="12" cy="12" rx="10" ry="9.5"/>"#);
+        let line = hash(r#"<line x1="0" y1="0" x2="24" y2="24"/>"#);
+        assert!(distance(circle, arcs) <= 2);
+        assert!(distance(circle, ellipse) < distance(circle, line));
+    }
+}
@@ -0,0 +1,228 @@
+//! Complexity of icon outlines and their simplification for lite variants of heavy packages.
+//!
+//! Traced icons often consist of hundreds of tiny segments, which are expensive to parse and
+//! render on embedded and low-power devices. Simplification flattens such paths to polylines and
+//! removes every point closer to the line between its neighbours than the tolerance, following the
+//! Ramer–Douglas–Peucker algorithm.
+
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use super::shape::{self, Segment};
+
+/// How costly an icon is to parse and render.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Complexity {
+    /// Number of path commands, counting implicitly repeated commands. Basic shapes count like the
+    /// path drawing them.
+    pub commands: usize,
+    /// Number of points segments start or end at.
+    pub anchors: usize,
+}
+
+impl std::ops::Add for Complexity {
+    type Output = Complexity;
+
+    fn add(self, other: Complexity) -> Complexity {
+        Complexity {
+            commands: self.commands + other.commands,
+            anchors: self.anchors + other.anchors,
+        }
+    }
+}
+
+/// Computes the complexity of the given svg content.
+pub fn complexity(content: &str) -> Complexity {
+    shape::elements(content)
+        .into_iter()
+        .map(|(tag, attributes)| {
+            let attr = |name: &str| {
+                attributes
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| *value)
+            };
+            match tag {
+                "path" => path_complexity(&shape::path_segments(attr("d").unwrap_or_default())),
+                "polygon" | "polyline" => {
+                    let mut cursor = shape::Cursor::new(attr("points").unwrap_or_default());
+                    let mut vertices = 0;
+                    while let (Some(_), Some(_)) = (cursor.number(), cursor.number()) {
+                        vertices += 1;
+                    }
+                    let close = usize::from(tag == "polygon");
+                    Complexity {
+                        commands: vertices + close,
+                        anchors: vertices,
+                    }
+                }
+                // Move, three lines and close.
+                "rect" => Complexity {
+                    commands: 5,
+                    anchors: 4,
+                },
+                "line" => Complexity {
+                    commands: 2,
+                    anchors: 2,
+                },
+                // Move, two arcs and close.
+                "circle" | "ellipse" => Complexity {
+                    commands: 4,
+                    anchors: 3,
+                },
+                _ => Complexity::default(),
+            }
+        })
+        .fold(Complexity::default(), |sum, complexity| sum + complexity)
+}
+
+fn path_complexity(segments: &[Segment]) -> Complexity {
+    Complexity {
+        commands: segments.len(),
+        anchors: segments
+            .iter()
+            .filter(|segment| !matches!(segment, Segment::Line { close: true, .. }))
+            .count(),
+    }
+}
+
+/// Simplifies every path in the given svg content, removing points deviating less than
+/// `tolerance` from the simplified outline. The tolerance is relative to the larger side of the
+/// view box, e.g. `0.005` for half a percent.
+///
+/// A path is only replaced if its simplified form has fewer anchors, so paths made of few curves
+/// are kept as they are.
+pub fn simplify(content: &str, view_box: Option<&str>, tolerance: f64) -> String {
+    let [_, _, width, height] = view_box
+        .and_then(shape::parse_view_box)
+        .unwrap_or(shape::DEFAULT_VIEW_BOX);
+    let tolerance = tolerance * width.max(height);
+
+    let elements = shape::elements(content);
+    let replacements = elements
+        .iter()
+        .filter(|(tag, _)| *tag == "path")
+        .filter_map(|(_, attributes)| attributes.iter().find(|(key, _)| *key == "d"))
+        .filter_map(|(_, d)| {
+            let segments = shape::path_segments(d);
+            let (simplified, anchors) = simplify_path(&segments, tolerance);
+            (anchors < path_complexity(&segments).anchors).then_some((*d, simplified))
+        })
+        .collect::<Vec<_>>();
+    shape::replace_values(content, &replacements)
+}
+
+/// Path data of the simplified polylines of the given segments, and their number of anchors.
+fn simplify_path(segments: &[Segment], tolerance: f64) -> (String, usize) {
+    let mut d = String::new();
+    let mut anchors = 0;
+    let mut write_subpath = |points: &[(f64, f64)], closed: bool| {
+        let mut points = ramer_douglas_peucker(points, tolerance);
+        if closed && points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        for (i, (x, y)) in points.iter().enumerate() {
+            let command = match i {
+                0 => "M",
+                1 => "L",
+                _ => " ",
+            };
+            write!(d, "{command}{} {}", number(*x), number(*y)).expect("writing to a string");
+        }
+        if closed {
+            d.push('Z');
+        }
+        anchors += points.len();
+    };
+
+    let mut points: Vec<(f64, f64)> = Vec::new();
+    for segment in segments {
+        if let Segment::Move(_) = segment {
+            if points.len() > 1 {
+                write_subpath(&points, false);
+            }
+            points.clear();
+        }
+        segment.sample(&mut points);
+        if let Segment::Line { close: true, .. } = segment {
+            write_subpath(&points, true);
+            // A new subpath starts where a closed one started, unless moved.
+            points = vec![segment.end()];
+        }
+    }
+    if points.len() > 1 {
+        write_subpath(&points, false);
+    }
+    (d, anchors)
+}
+
+/// The given polyline without points closer than `tolerance` to the simplified polyline.
+fn ramer_douglas_peucker(points: &[(f64, f64)], tolerance: f64) -> Vec<(f64, f64)> {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return Vec::new();
+    };
+    let farthest = points[1..points.len().saturating_sub(1).max(1)]
+        .iter()
+        .enumerate()
+        .map(|(i, point)| (i + 1, distance_to_segment(*point, *first, *last)))
+        .max_by(|a, b| a.1.total_cmp(&b.1));
+
+    match farthest {
+        Some((i, distance)) if distance > tolerance => {
+            let mut simplified = ramer_douglas_peucker(&points[..=i], tolerance);
+            simplified.pop();
+            simplified.extend(ramer_douglas_peucker(&points[i..], tolerance));
+            simplified
+        }
+        _ if points.len() == 1 => vec![*first],
+        _ => vec![*first, *last],
+    }
+}
+
+fn distance_to_segment((x, y): (f64, f64), (x1, y1): (f64, f64), (x2, y2): (f64, f64)) -> f64 {
+    let (dx, dy) = (x2 - x1, y2 - y1);
+    let length_squared = dx * dx + dy * dy;
+    let t = match length_squared > 0.0 {
+        true => (((x - x1) * dx + (y - y1) * dy) / length_squared).clamp(0.0, 1.0),
+        false => 0.0,
+    };
+    (x - x1 - t * dx).hypot(y - y1 - t * dy)
+}
+
+/// Formats a coordinate with at most three decimals.
+pub(super) fn number(value: f64) -> String {
+    let formatted = format!("{value:.3}");
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+    match formatted {
+        "-0" => "0".to_owned(),
+        formatted => formatted.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{complexity, simplify, Complexity};
+
+    #[test]
+    fn simplifies_heavy_paths_only() {
+        // A square traced with many tiny steps along its top edge.
+        let steps = (0..40).map(|_| "h.5v.01v-.01").collect::<String>();
+        let traced = format!(r#"<path d="M2 2{steps}v20H2z"/><circle cx="12" cy="12" r="4"/>"#);
+        assert_eq!(
+            complexity(&traced),
+            Complexity {
+                commands: 4 + 120 + 4,
+                anchors: 3 + 120 + 3
+            }
+        );
+
+        assert_eq!(
+            simplify(&traced, Some("0 0 24 24"), 0.005),
+            r#"<path d="M2 2L22 2 22 22 2 22Z"/><circle cx="12" cy="12" r="4"/>"#
+        );
+
+        let curves = r#"<path d="M2 12a10 10 0 1020 0a10 10 0 10-20 0"/>"#;
+        assert_eq!(simplify(curves, None, 0.005), curves);
+    }
+}
@@ -0,0 +1,124 @@
+//! Loading indicators, listed by the `spinners()` function of the main library with the center they
+//! spin around.
+//!
+//! Spinners are found by their names, which start with a word like "Loader" or "Spinner". The
+//! center of rotation is the center of the circle best fitting the sampled outline of the glyph,
+//! ignoring strokes, which is where a spinner drawn as an arc or a ring of dots has to turn to not
+//! wobble. The bounding box would be off for arcs shorter than a full circle.
+
+use super::{shape, split_words, svg::ParsedSvg};
+
+/// First words of the names of loading indicators, after the package prefix.
+const SPINNER_WORDS: &[&str] = &["Loader", "Loading", "Spinner"];
+
+/// Loading indicators whose names don't tell.
+const CURATED: &[&str] = &["FaCircleNotchSolid"];
+
+/// Center of rotation of the icon with the given name as a CSS `transform-origin`, e.g.
+/// "50% 37.5%". `None` if the icon is no spinner.
+pub fn origin(name: &str, svg: &ParsedSvg) -> Option<String> {
+    if !is_spinner(name) {
+        return None;
+    }
+    let view_box = svg
+        .svg_attributes()
+        .view_box
+        .as_deref()
+        .and_then(shape::parse_view_box)
+        .unwrap_or(shape::DEFAULT_VIEW_BOX);
+    Some(center(&shape::outline(&svg.content), view_box))
+}
+
+fn is_spinner(name: &str) -> bool {
+    CURATED.contains(&name)
+        || split_words(name)
+            .get(1)
+            .is_some_and(|word| SPINNER_WORDS.contains(word))
+}
+
+/// Center of the circle fitted to the points, in percent of the view box. The center of the view
+/// box if the points fit no circle inside of it.
+fn center(points: &[(f64, f64)], [min_x, min_y, width, height]: [f64; 4]) -> String {
+    let inside = |&(x, y): &(f64, f64)| {
+        (min_x..=min_x + width).contains(&x) && (min_y..=min_y + height).contains(&y)
+    };
+    let (x, y) = fit_circle(points)
+        .filter(inside)
+        .unwrap_or((min_x + width / 2.0, min_y + height / 2.0));
+    let percent = |value: f64, min: f64, size: f64| {
+        let percent = (value - min) / size * 100.0;
+        // One decimal is precise to a tenth of a pixel at 100px.
+        format!("{}%", (percent * 10.0).round() / 10.0)
+    };
+    format!("{} {}", percent(x, min_x, width), percent(y, min_y, height))
+}
+
+/// Center of the circle fitting the points best in the least squares sense. `None` for fewer than
+/// three points or points on a line.
+fn fit_circle(points: &[(f64, f64)]) -> Option<(f64, f64)> {
+    if points.len() < 3 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    // Solved relative to the mean, where the center (u, v) follows from two linear equations:
+    // suu * u + suv * v = (suuu + suvv) / 2 and suv * u + svv * v = (svvv + svuu) / 2.
+    let [mut suu, mut suv, mut svv, mut suuu, mut svvv, mut suvv, mut svuu] = [0.0; 7];
+    for (x, y) in points {
+        let (u, v) = (x - mean_x, y - mean_y);
+        suu += u * u;
+        suv += u * v;
+        svv += v * v;
+        suuu += u * u * u;
+        svvv += v * v * v;
+        suvv += u * v * v;
+        svuu += v * u * u;
+    }
+    let det = suu * svv - suv * suv;
+    if det <= 1e-9 * (suu + svv).powi(2) {
+        return None;
+    }
+    let (a, b) = ((suuu + suvv) / 2.0, (svvv + svuu) / 2.0);
+    let u = (a * svv - b * suv) / det;
+    let v = (b * suu - a * suv) / det;
+    Some((mean_x + u, mean_y + v))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{center, is_spinner};
+
+    #[test]
+    fn finds_spinners_by_name() {
+        assert!(is_spinner("TbLoader2"));
+        assert!(is_spinner("AiLoading3QuartersOutlined"));
+        assert!(is_spinner("CgSpinnerTwoAlt"));
+        assert!(is_spinner("FaCircleNotchSolid"));
+        assert!(!is_spinner("TbTruckLoading"));
+        assert!(!is_spinner("TbFidgetSpinner"));
+    }
+
+    #[test]
+    fn centers_fitted_circle() {
+        // A quarter of a circle around (12, 12), whose bounding box is centered at (16.5, 7.5).
+        let arc = (0..=8)
+            .map(|i| f64::from(i) * std::f64::consts::FRAC_PI_2 / 8.0)
+            .map(|angle| (12.0 + 9.0 * angle.sin(), 12.0 - 9.0 * angle.cos()))
+            .collect::<Vec<_>>();
+        assert_eq!(center(&arc, [0.0, 0.0, 24.0, 24.0]), "50% 50%");
+        let lower = arc.iter().map(|(x, y)| (*x, y - 3.0)).collect::<Vec<_>>();
+        assert_eq!(center(&lower, [0.0, 0.0, 24.0, 24.0]), "50% 37.5%");
+
+        // Without a circle, the view box is centered.
+        assert_eq!(
+            center(
+                &[(2.0, 2.0), (10.0, 10.0), (18.0, 18.0)],
+                [-2.0, -2.0, 24.0, 24.0]
+            ),
+            "50% 50%"
+        );
+        assert_eq!(center(&[], [0.0, 0.0, 24.0, 24.0]), "50% 50%");
+    }
+}
@@ -0,0 +1,113 @@
+//! Pairs of icons showing a state which is on and off, like `LuEye` and `LuEyeOff`, for which the
+//! icon crates provide an `icondata_core::StatePair`, e.g. `LuEyeToggle()`.
+//!
+//! Icons are paired by their names: the icon showing the state off carries an additional word like
+//! "Off" or "Slash", or an antonym like "Unlock" in place of "Lock".
+
+use std::collections::{BTreeMap, HashSet};
+
+use super::{split_words, SvgIcon};
+
+/// Words added to the name of an icon to show its state off, e.g. "eye-off" or "bell-slash".
+const OFF_WORDS: &[&str] = &["Off", "Slash", "Mute", "Muted"];
+
+/// Words naming a state on, with the antonym replacing them to show the state off.
+const ANTONYMS: &[(&str, &str)] = &[
+    ("Lock", "Unlock"),
+    ("Locked", "Unlocked"),
+    ("Visible", "Invisible"),
+];
+
+/// Icons of a package showing a state on and off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatePair {
+    /// Name of the generated function, e.g. "LuEyeToggle".
+    pub name: String,
+    /// Style feature shared by both icons, see [`SvgIcon::style`].
+    pub style: Option<String>,
+    pub brand: bool,
+    pub on: String,
+    pub off: String,
+}
+
+/// All state pairs among the given icons, ordered by name.
+pub fn state_pairs(icons: &[SvgIcon]) -> Vec<StatePair> {
+    detect(
+        icons
+            .iter()
+            .map(|icon| (icon.name.as_str(), icon.style.as_deref(), icon.brand)),
+    )
+}
+
+/// Pairs among the icons given by name, style and whether they are brands.
+fn detect<'a>(icons: impl IntoIterator<Item = (&'a str, Option<&'a str>, bool)>) -> Vec<StatePair> {
+    let icons = icons.into_iter().collect::<Vec<_>>();
+    let keys = icons.iter().copied().collect::<HashSet<_>>();
+    let names = icons.iter().map(|(name, ..)| *name).collect::<HashSet<_>>();
+
+    // The first icon showing the state off, in the order of the icons, is paired.
+    let mut pairs = BTreeMap::<String, StatePair>::new();
+    for (off, style, brand) in icons {
+        let words = split_words(off);
+        // The package prefix is no state word.
+        for (i, word) in words.iter().enumerate().skip(1) {
+            let mut on = words.clone();
+            if OFF_WORDS.contains(word) {
+                on.remove(i);
+            } else if let Some((antonym, _)) = ANTONYMS.iter().find(|(_, off)| off == word) {
+                on[i] = *antonym;
+            } else {
+                continue;
+            }
+            let on = on.concat();
+            let name = format!("{on}Toggle");
+            if !keys.contains(&(on.as_str(), style, brand)) || names.contains(name.as_str()) {
+                continue;
+            }
+            pairs.entry(name.clone()).or_insert_with(|| StatePair {
+                name,
+                style: style.map(str::to_owned),
+                brand,
+                on,
+                off: off.to_owned(),
+            });
+        }
+    }
+    pairs.into_values().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::detect;
+
+    #[test]
+    fn pairs_states_by_name() {
+        let pairs = detect([
+            ("LuEye", None, false),
+            ("LuEyeOff", None, false),
+            ("FaBellRegular", Some("regular"), false),
+            ("FaBellSlashRegular", Some("regular"), false),
+            ("FaBellSlashSolid", Some("solid"), false),
+            ("TbLock", None, false),
+            ("TbUnlock", None, false),
+            ("TbVolume", None, false),
+            ("TbVolumeMute", None, false),
+            ("TbVolumeOff", None, false),
+            ("TbWifiOff", None, false),
+        ]);
+
+        let pairs = pairs
+            .iter()
+            .map(|pair| (pair.name.as_str(), pair.on.as_str(), pair.off.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pairs,
+            [
+                ("FaBellRegularToggle", "FaBellRegular", "FaBellSlashRegular"),
+                ("LuEyeToggle", "LuEye", "LuEyeOff"),
+                ("TbLockToggle", "TbLock", "TbUnlock"),
+                ("TbVolumeToggle", "TbVolume", "TbVolumeMute"),
+            ]
+        );
+    }
+}
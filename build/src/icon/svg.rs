@@ -1,12 +1,71 @@
 use anyhow::{anyhow, Result};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::ops::Deref;
 use std::str::from_utf8;
+use std::sync::Arc;
 use std::{borrow::Cow, collections::HashMap};
 use tracing::warn;
 use xml::attribute::Attribute;
 use xml::common::XmlVersion;
 use xml::name::Name;
 use xml::namespace::Namespace;
-use xml::{attribute::OwnedAttribute, EmitterConfig, ParserConfig};
+use xml::{EmitterConfig, ParserConfig};
+
+thread_local! {
+    /// Attribute values seen so far by [`with_interner`] on this thread. Most icons of a package
+    /// share values like their viewBox, so we only keep one allocation per distinct value around.
+    static INTERNER: RefCell<Option<HashSet<Arc<str>>>> = const { RefCell::new(None) };
+}
+
+/// Run `parse`, sharing one allocation between equal attribute values of all svgs it parses on
+/// this thread. Values are not interned outside of `parse`, and the interner is dropped once it
+/// returned, so that it holds no values of other packages.
+pub(crate) fn with_interner<T>(parse: impl FnOnce() -> T) -> T {
+    INTERNER.with(|interner| *interner.borrow_mut() = Some(HashSet::new()));
+    let parsed = parse();
+    INTERNER.with(|interner| interner.borrow_mut().take());
+    parsed
+}
+
+/// An attribute value, interned within [`with_interner`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AttrValue(Arc<str>);
+
+impl AttrValue {
+    pub fn intern(value: &str) -> AttrValue {
+        INTERNER.with(|interner| match interner.borrow_mut().as_mut() {
+            Some(interner) => {
+                if let Some(interned) = interner.get(value) {
+                    return AttrValue(Arc::clone(interned));
+                }
+                let interned: Arc<str> = Arc::from(value);
+                interner.insert(Arc::clone(&interned));
+                AttrValue(interned)
+            }
+            None => AttrValue(Arc::from(value)),
+        })
+    }
+
+    /// Number of bytes held by the distinct allocations of these values, each counted once no
+    /// matter how many values share it.
+    pub fn allocated_bytes<'a>(values: impl IntoIterator<Item = &'a AttrValue>) -> usize {
+        let mut seen = HashSet::new();
+        values
+            .into_iter()
+            .filter(|value| seen.insert(Arc::as_ptr(&value.0) as *const u8))
+            .map(|value| value.len())
+            .sum()
+    }
+}
+
+impl Deref for AttrValue {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct ParsedSvg {
@@ -20,6 +79,45 @@ impl ParsedSvg {
     pub fn svg_attributes(&self) -> &SvgAttributes {
         &self.svg_attributes
     }
+
+    /// Drop the content of this svg, e.g. once it was written to its library, keeping only its
+    /// attributes.
+    pub fn release_content(&mut self) {
+        self.content = String::new();
+    }
+
+    /// Approximate number of heap bytes owned by this svg, not counting interned attribute values.
+    pub fn heap_size(&self) -> usize {
+        self.content.capacity()
+            + self
+                .svg_attributes
+                .unknown_attributes
+                .iter()
+                .map(|(name, _)| name.len() + std::mem::size_of::<(Box<str>, AttrValue)>())
+                .sum::<usize>()
+    }
+
+    /// Number of bytes the attribute values of this svg would occupy if they were not interned.
+    pub fn attribute_bytes(&self) -> usize {
+        self.svg_attributes.values().map(|value| value.len()).sum()
+    }
+
+    /// Attribute values of this svg, see [`AttrValue::allocated_bytes`].
+    pub fn attribute_values(&self) -> impl Iterator<Item = &AttrValue> {
+        self.svg_attributes.values()
+    }
+}
+
+/// How the colors of the shapes of an icon are treated when parsing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    /// Dark fills are dropped, so that shapes are drawn in the color of the icon.
+    Monochrome,
+    /// Like [`ColorMode::Monochrome`], with light or translucent shapes marked as secondary.
+    Twotone,
+    /// All fills are kept, for icons drawn in their own colors like flags. Shapes without fill are
+    /// drawn black, as in the original file, rather than in the color of the icon.
+    Colored,
 }
 
 /// Parsed attributes of the xml root element.
@@ -28,39 +126,62 @@ pub struct XmlAttributes {
     #[allow(unused)]
     pub version: XmlVersion,
     #[allow(unused)]
-    pub encoding: String,
+    pub encoding: AttrValue,
 }
 
 /// Parsed attributes of the svg element.
 #[derive(Debug, Clone)]
 pub struct SvgAttributes {
     #[allow(unused)]
-    pub namespace: Namespace,
-    #[allow(unused)]
-    pub version: Option<OwnedAttribute>,
+    pub version: Option<AttrValue>,
     #[allow(unused)]
-    pub class: Option<OwnedAttribute>,
-    pub x: Option<OwnedAttribute>,
-    pub y: Option<OwnedAttribute>,
+    pub class: Option<AttrValue>,
+    pub x: Option<AttrValue>,
+    pub y: Option<AttrValue>,
     #[allow(unused)]
-    pub width: Option<OwnedAttribute>,
+    pub width: Option<AttrValue>,
     #[allow(unused)]
-    pub height: Option<OwnedAttribute>,
-    pub view_box: Option<OwnedAttribute>,
-    pub stroke_linecap: Option<OwnedAttribute>,
-    pub stroke_linejoin: Option<OwnedAttribute>,
-    pub stroke_width: Option<OwnedAttribute>,
-    pub stroke: Option<OwnedAttribute>,
-    pub fill: Option<OwnedAttribute>,
-    pub style: Option<OwnedAttribute>,
+    pub height: Option<AttrValue>,
+    pub view_box: Option<AttrValue>,
+    pub stroke_linecap: Option<AttrValue>,
+    pub stroke_linejoin: Option<AttrValue>,
+    pub stroke_width: Option<AttrValue>,
+    pub stroke: Option<AttrValue>,
+    pub fill: Option<AttrValue>,
+    pub style: Option<AttrValue>,
     #[allow(unused)]
-    pub role: Option<OwnedAttribute>,
+    pub role: Option<AttrValue>,
+    /// Local name and value of every attribute not explicitly handled.
     #[allow(unused)]
-    pub unknown_attributes: Vec<OwnedAttribute>,
+    pub unknown_attributes: Box<[(Box<str>, AttrValue)]>,
+}
+
+impl SvgAttributes {
+    fn values(&self) -> impl Iterator<Item = &AttrValue> {
+        [
+            &self.version,
+            &self.class,
+            &self.x,
+            &self.y,
+            &self.width,
+            &self.height,
+            &self.view_box,
+            &self.stroke_linecap,
+            &self.stroke_linejoin,
+            &self.stroke_width,
+            &self.stroke,
+            &self.fill,
+            &self.style,
+            &self.role,
+        ]
+        .into_iter()
+        .flatten()
+        .chain(self.unknown_attributes.iter().map(|(_, value)| value))
+    }
 }
 
 impl ParsedSvg {
-    pub(crate) fn parse<R: std::io::Read>(icon_content: R, twotone: bool) -> Result<ParsedSvg> {
+    pub(crate) fn parse<R: std::io::Read>(icon_content: R, mode: ColorMode) -> Result<ParsedSvg> {
         let parser_config = ParserConfig {
             trim_whitespace: true,
             whitespace_to_characters: false,
@@ -104,8 +225,7 @@ impl ParsedSvg {
         let mut svg_fill = None;
         let mut svg_style = None;
         let mut svg_role = None;
-        let mut svg_namespace = None;
-        let mut unknown_svg_attributes: Vec<OwnedAttribute> = Vec::new();
+        let mut unknown_svg_attributes: Vec<(Box<str>, AttrValue)> = Vec::new();
 
         let mut is_title = false;
         let mut in_content = false;
@@ -118,7 +238,10 @@ impl ParsedSvg {
                     encoding,
                     standalone: _,
                 } => {
-                    xml_attributes = Some(XmlAttributes { version, encoding });
+                    xml_attributes = Some(XmlAttributes {
+                        version,
+                        encoding: AttrValue::intern(&encoding),
+                    });
                 }
                 xml::reader::XmlEvent::EndDocument => {}
                 xml::reader::XmlEvent::StartElement { name, .. } if name.local_name == "title" => {
@@ -127,12 +250,11 @@ impl ParsedSvg {
                 xml::reader::XmlEvent::StartElement {
                     name,
                     attributes,
-                    namespace,
+                    namespace: _,
                 } if name.local_name == "svg" => {
-                    svg_namespace = Some(namespace);
                     for attr in attributes {
                         match attr.name.local_name.as_ref() {
-                            "version" => svg_version = Some(attr),
+                            "version" => svg_version = Some(AttrValue::intern(&attr.value)),
                             // We explicitly ignore any id attribute, as ids must be unique and we cannot ensure that. Users should provide an id if required.
                             "id" => {}
                             // As to https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xml:space, we explicitly ignore any "space" attribute.
@@ -143,23 +265,32 @@ impl ParsedSvg {
                             "t" => {}
                             // This is an unrecognizable attribute which we can safely ignore.
                             "p-id" => {}
-                            "class" => svg_class = Some(attr),
+                            "class" => svg_class = Some(AttrValue::intern(&attr.value)),
                             // TODO; As to https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/x, the default for x and y on svg elements is 0. We could safely ignore them then. Use regex?
-                            "x" => svg_x = Some(attr),
-                            "y" => svg_y = Some(attr),
-                            "width" => svg_width = Some(attr),
-                            "height" => svg_height = Some(attr),
-                            "viewBox" => svg_view_box = Some(attr),
-                            "stroke-linecap" => svg_stroke_linecap = Some(attr),
-                            "stroke-linejoin" => svg_stroke_linejoin = Some(attr),
-                            "stroke-width" => svg_stroke_width = Some(attr),
-                            "stroke" => svg_stroke = Some(attr),
-                            "fill" => svg_fill = Some(attr),
-                            "style" => svg_style = Some(attr),
-                            "role" => svg_role = Some(attr),
+                            "x" => svg_x = Some(AttrValue::intern(&attr.value)),
+                            "y" => svg_y = Some(AttrValue::intern(&attr.value)),
+                            "width" => svg_width = Some(AttrValue::intern(&attr.value)),
+                            "height" => svg_height = Some(AttrValue::intern(&attr.value)),
+                            "viewBox" => svg_view_box = Some(AttrValue::intern(&attr.value)),
+                            "stroke-linecap" => {
+                                svg_stroke_linecap = Some(AttrValue::intern(&attr.value))
+                            }
+                            "stroke-linejoin" => {
+                                svg_stroke_linejoin = Some(AttrValue::intern(&attr.value))
+                            }
+                            "stroke-width" => {
+                                svg_stroke_width = Some(AttrValue::intern(&attr.value))
+                            }
+                            "stroke" => svg_stroke = Some(AttrValue::intern(&attr.value)),
+                            "fill" => svg_fill = Some(AttrValue::intern(&attr.value)),
+                            "style" => svg_style = Some(AttrValue::intern(&attr.value)),
+                            "role" => svg_role = Some(AttrValue::intern(&attr.value)),
                             _ => {
                                 warn!(?attr, "Encountered an unexpected svg attribute");
-                                unknown_svg_attributes.push(attr)
+                                unknown_svg_attributes.push((
+                                    attr.name.local_name.into_boxed_str(),
+                                    AttrValue::intern(&attr.value),
+                                ))
                             }
                         };
                     }
@@ -187,7 +318,7 @@ impl ParsedSvg {
                             let mut attributes = attributes
                                 .iter()
                                 .filter_map(|attr| {
-                                    if attr.name.local_name != "fill" {
+                                    if attr.name.local_name != "fill" || mode == ColorMode::Colored {
                                         return Some(Ok(*attr));
                                     }
                                     match is_light(attr.value) {
@@ -195,7 +326,7 @@ impl ParsedSvg {
                                             FillContents::Dark => None,
                                             FillContents::Unknown => Some(Ok(*attr)),
                                             FillContents::Light => {
-                                                if twotone {
+                                                if mode == ColorMode::Twotone {
                                                     is_twotone_light_element = true;
                                                 } else {
                                                     warn!(?attr, "Encountered a light fill attribute on a non twotone icon.");
@@ -208,6 +339,15 @@ impl ParsedSvg {
                                 })
                                 .collect::<Result<Vec<_>>>()?;
 
+                            // Duotone icons like the ones of Phosphor draw their secondary shapes
+                            // translucent rather than in a light color.
+                            if mode == ColorMode::Twotone && attributes.iter().any(|attr| {
+                                attr.name.local_name == "opacity"
+                                    && matches!(attr.value.parse::<f64>(), Ok(opacity) if opacity < 1.0)
+                            }) {
+                                is_twotone_light_element = true;
+                            }
+
                             if is_twotone_light_element {
                                 attributes.push(Attribute {
                                     name: Name::local("class"),
@@ -245,7 +385,6 @@ impl ParsedSvg {
                 .replace("&#xA;", "\n"),
             xml_attributes: xml_attributes.expect("present"),
             svg_attributes: SvgAttributes {
-                namespace: svg_namespace.expect("present"),
                 version: svg_version,
                 class: svg_class,
                 x: svg_x,
@@ -257,10 +396,13 @@ impl ParsedSvg {
                 stroke_linejoin: svg_stroke_linejoin,
                 stroke_width: svg_stroke_width,
                 stroke: svg_stroke,
-                fill: svg_fill,
+                fill: match mode {
+                    ColorMode::Colored => svg_fill.or_else(|| Some(AttrValue::intern("#000"))),
+                    _ => svg_fill,
+                },
                 style: svg_style,
                 role: svg_role,
-                unknown_attributes: unknown_svg_attributes,
+                unknown_attributes: unknown_svg_attributes.into_boxed_slice(),
             },
         })
     }
@@ -298,7 +440,21 @@ fn is_light(color: &str) -> Result<FillContents> {
 
 #[cfg(test)]
 mod test {
-    use super::ParsedSvg;
+    use super::{with_interner, AttrValue, ColorMode, ParsedSvg};
+
+    #[test]
+    fn interns_values_within_scope() {
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><path d="M0 0h24"/></svg>"#;
+        let parse = || ParsedSvg::parse(svg.as_bytes(), ColorMode::Monochrome).unwrap();
+
+        let interned = with_interner(|| [parse(), parse()]);
+        let values = interned.iter().flat_map(ParsedSvg::attribute_values);
+        assert_eq!(AttrValue::allocated_bytes(values), "0 0 24 24".len());
+
+        let separate = [parse(), parse()];
+        let values = separate.iter().flat_map(ParsedSvg::attribute_values);
+        assert_eq!(AttrValue::allocated_bytes(values), 2 * "0 0 24 24".len());
+    }
 
     #[test]
     fn parse_content() {
@@ -326,7 +482,7 @@ mod test {
         "# }.replace("\n", "\r\n");
 
         // When parsing such a file.
-        let out = ParsedSvg::parse(original.as_bytes(), false).expect("no errors");
+        let out = ParsedSvg::parse(original.as_bytes(), ColorMode::Monochrome).expect("no errors");
 
         // We expect all conversions to be made to just "&#xA;" (representing \n) instead of "&#xD;&#xA;" (representing \r\n).
         pretty_assertions::assert_eq!(
@@ -357,7 +513,7 @@ mod test {
                 </svg>
             "### };
 
-        let out = ParsedSvg::parse(original.as_bytes(), true).expect("no errors");
+        let out = ParsedSvg::parse(original.as_bytes(), ColorMode::Twotone).expect("no errors");
 
         // We expect all conversions to be made to just "&#xA;" (representing \n) instead of "&#xD;&#xA;" (representing \r\n).
         pretty_assertions::assert_eq!(
@@ -368,4 +524,47 @@ mod test {
             out.content,
         )
     }
+
+    #[test]
+    fn parse_duotone() {
+        let original = indoc::indoc! { r#"
+                <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 256 256" fill="currentColor">
+                  <path d="M216,120v96H40V120L128,40Z" opacity="0.2"/>
+                  <path d="M219.31,108.68l-80-80a16,16,0,0,0-22.62,0l-80,80A15.87,15.87,0,0,0,32,120v96a8,8,0,0,0,8,8H216a8,8,0,0,0,8-8V120A15.87,15.87,0,0,0,219.31,108.68Z"/>
+                </svg>
+            "# };
+
+        let out = ParsedSvg::parse(original.as_bytes(), ColorMode::Twotone).expect("no errors");
+
+        // Translucent shapes are secondary, like light ones of twotone icons.
+        pretty_assertions::assert_eq!(
+            indoc::indoc! { r#"
+                  <path d="M216,120v96H40V120L128,40Z" opacity="0.2" class="twotone-icon-secondary-color" />
+                  <path d="M219.31,108.68l-80-80a16,16,0,0,0-22.62,0l-80,80A15.87,15.87,0,0,0,32,120v96a8,8,0,0,0,8,8H216a8,8,0,0,0,8-8V120A15.87,15.87,0,0,0,219.31,108.68Z" />"# },
+            out.content,
+        )
+    }
+
+    #[test]
+    fn parse_colored() {
+        let original = indoc::indoc! { r##"
+                <svg xmlns="http://www.w3.org/2000/svg" id="flag-icons-de" viewBox="0 0 640 480">
+                  <path fill="#fc0" d="M0 320h640v160H0z"/>
+                  <path fill="#000001" d="M0 0h640v160H0z"/>
+                  <path fill="red" d="M0 160h640v160H0z"/>
+                </svg>
+            "## };
+
+        let out = ParsedSvg::parse(original.as_bytes(), ColorMode::Colored).expect("no errors");
+
+        // Dark fills are kept, and shapes without fill stay black.
+        pretty_assertions::assert_eq!(
+            indoc::indoc! { r##"
+                  <path fill="#fc0" d="M0 320h640v160H0z" />
+                  <path fill="#000001" d="M0 0h640v160H0z" />
+                  <path fill="red" d="M0 160h640v160H0z" />"## },
+            out.content,
+        );
+        assert_eq!(out.svg_attributes().fill.as_deref(), Some("#000"));
+    }
 }
@@ -0,0 +1,100 @@
+//! Keyboard keys and modifiers, generated into the `keys` module of the main library as the `Key`
+//! enum, for rendering shortcut hints like "⌘ ⇧ P" with icons.
+//!
+//! Every key lists glyphs of a few packages in order of preference. The generated `Key::icon`
+//! returns the first of them whose feature is enabled, so that all keys of a hint come from the
+//! same package as far as possible.
+
+use anyhow::Result;
+
+use crate::migration::{self, PreferredIcon};
+
+/// Variant of the `Key` enum, its symbol and label, and the names of the icons showing it.
+const KEYS: &[(&str, &str, &str, &[&str])] = &[
+    (
+        "Cmd",
+        "⌘",
+        "Command",
+        &["LuCommand", "TbCommand", "BsCommand"],
+    ),
+    (
+        "Shift",
+        "⇧",
+        "Shift",
+        &["LuArrowBigUp", "TbArrowBigUp", "BsShift"],
+    ),
+    ("Ctrl", "⌃", "Control", &["LuChevronUp", "TbChevronUp"]),
+    ("Option", "⌥", "Option", &["LuOption", "BsOption"]),
+    ("Alt", "⎇", "Alt", &["TbAlt", "BsAlt"]),
+    (
+        "Enter",
+        "↵",
+        "Enter",
+        &["LuCornerDownLeft", "TbCornerDownLeft", "BsArrowReturnLeft"],
+    ),
+    (
+        "Backspace",
+        "⌫",
+        "Backspace",
+        &["LuDelete", "TbBackspace", "BsBackspace"],
+    ),
+    (
+        "Tab",
+        "⇥",
+        "Tab",
+        &["LuArrowRightToLine", "TbArrowBarToRight"],
+    ),
+    ("Escape", "⎋", "Escape", &["BsEscape"]),
+    (
+        "CapsLock",
+        "⇪",
+        "Caps Lock",
+        &["LuArrowBigUpDash", "TbArrowBigUpLine", "BsCapslock"],
+    ),
+    ("Space", "␣", "Space", &["LuSpace", "TbSpace"]),
+    ("Up", "↑", "Up", &["LuArrowUp", "TbArrowUp"]),
+    ("Down", "↓", "Down", &["LuArrowDown", "TbArrowDown"]),
+    ("Left", "←", "Left", &["LuArrowLeft", "TbArrowLeft"]),
+    ("Right", "→", "Right", &["LuArrowRight", "TbArrowRight"]),
+];
+
+/// A key of the generated `Key` enum.
+#[derive(Debug)]
+pub(crate) struct Key {
+    pub variant: &'static str,
+    /// Unicode symbol of the key, e.g. "⌘".
+    pub symbol: &'static str,
+    pub label: &'static str,
+    /// Icons showing the key, in order of preference.
+    pub icons: Vec<PreferredIcon>,
+}
+
+/// All keys, with the icons of the current packages showing them.
+pub(crate) fn keys() -> Result<Vec<Key>> {
+    KEYS.iter()
+        .map(|(variant, symbol, label, names)| {
+            Ok(Key {
+                variant,
+                symbol,
+                label,
+                icons: migration::preferred_icons(names)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::KEYS;
+
+    #[test]
+    fn keys_are_unique() {
+        let variants = KEYS.iter().map(|key| key.0).collect::<HashSet<_>>();
+        let symbols = KEYS.iter().map(|key| key.1).collect::<HashSet<_>>();
+        assert_eq!(variants.len(), KEYS.len());
+        assert_eq!(symbols.len(), KEYS.len());
+        assert!(KEYS.iter().all(|key| !key.3.is_empty()));
+    }
+}
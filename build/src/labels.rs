@@ -0,0 +1,60 @@
+use std::{fmt::Write, path::Path};
+
+use anyhow::Result;
+use clap::ValueEnum;
+use tracing::{info, instrument};
+
+use crate::{dirs::write_to_file, icon::SvgIcon, Packages};
+
+/// File format of an exported translation template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Format {
+    /// Fluent resource (`.ftl`), using icon ids as message ids.
+    Fluent,
+    /// Gettext template (`.pot`), using icon ids as message contexts.
+    Gettext,
+}
+
+/// Write the english labels of all icons to `out` as a translation template.
+///
+/// Translated files can be turned into `icondata_core::LabelTable`s keyed by icon id.
+#[instrument(level = "info")]
+pub(crate) async fn export(format: Format, out: &Path) -> Result<()> {
+    let mut icons = Packages::get_icons()?.collect::<Vec<_>>();
+    icons.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let contents = match format {
+        Format::Fluent => fluent(&icons),
+        Format::Gettext => gettext(&icons),
+    };
+    write_to_file(&out.to_path_buf(), contents).await?;
+
+    info!(num_labels = icons.len(), ?out, "Exported labels.");
+    Ok(())
+}
+
+fn fluent(icons: &[&SvgIcon]) -> String {
+    let mut contents = String::from("# Labels of all icons, keyed by icon id.\n\n");
+    for icon in icons {
+        writeln!(contents, "{} = {}", icon.name, icon.label).expect("writing to a string");
+    }
+    contents
+}
+
+fn gettext(icons: &[&SvgIcon]) -> String {
+    let mut contents = String::from(
+        "# Labels of all icons, using the icon id as context.\n\
+         msgid \"\"\n\
+         msgstr \"\"\n\
+         \"Content-Type: text/plain; charset=UTF-8\\n\"\n",
+    );
+    for icon in icons {
+        write!(
+            contents,
+            "\nmsgctxt \"{}\"\nmsgid \"{}\"\nmsgstr \"\"\n",
+            icon.name, icon.label
+        )
+        .expect("writing to a string");
+    }
+    contents
+}
@@ -0,0 +1,229 @@
+//! The lockfile `icondata.lock`, written next to the generated crates, e.g.
+//!
+//! ```toml
+//! [package.fa]
+//! source = "https://github.com/FortAwesome/Font-Awesome"
+//! reference = "6.5.1"
+//! revision = "d006795ede82361e1bac1ee76f215cf1dc51e4ca"
+//! options = "5f0c…"
+//! ```
+//!
+//! It records what every icon crate was generated from, so that `--incremental` builds can skip
+//! packages which did not change, and `--locked` builds can fail if a package would be generated
+//! from anything else.
+
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::info;
+
+use crate::{
+    dirs, git,
+    overrides::NameOverrides,
+    package::{Package, PackageSource, SourceConfig},
+    path,
+};
+
+/// Name of the lockfile, in the directory the crates are generated in.
+const LOCKFILE: &str = "icondata.lock";
+
+/// Prefix of revisions which are content hashes rather than commits, see [`content_hash`].
+const CONTENT_HASH_PREFIX: &str = "sha256:";
+
+const HEADER: &str = "# Generated by the icondata builder, do not edit.\n\n";
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Lockfile {
+    #[serde(default, rename = "package")]
+    packages: BTreeMap<String, LockedPackage>,
+}
+
+/// What the icon crate of a package was generated from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct LockedPackage {
+    /// Url of the upstream repository, or the directory of a local package.
+    source: String,
+    /// Tag or commit ref checked out, empty for local packages.
+    reference: String,
+    /// Commit checked out, or the sha256 of the files of a local package or of a vendored package
+    /// which is no git repository.
+    revision: String,
+    /// Sha256 of the version of this crate, the build options and the configuration of the
+    /// package, which change the generated crate as well.
+    options: String,
+}
+
+impl Lockfile {
+    /// The lockfile of the generated crates, empty if there is none yet.
+    pub fn read() -> Result<Lockfile> {
+        let lockfile_path = path::generated_crate(LOCKFILE);
+        if !lockfile_path.exists() {
+            return Ok(Lockfile::default());
+        }
+        let lockfile = std::fs::read_to_string(&lockfile_path)
+            .with_context(|| format!("Could not read lockfile {lockfile_path:?}"))?;
+        toml::from_str(&lockfile)
+            .with_context(|| format!("Could not parse lockfile {lockfile_path:?}"))
+    }
+
+    pub async fn write(&self) -> Result<()> {
+        let lockfile_path = path::generated_crate(LOCKFILE);
+        info!(?lockfile_path, "Writing lockfile.");
+        let contents = format!("{HEADER}{}", toml::to_string(self)?);
+        dirs::write_to_file(&lockfile_path, contents).await
+    }
+
+    pub fn get(&self, short_name: &str) -> Option<&LockedPackage> {
+        self.packages.get(short_name)
+    }
+
+    pub fn insert(&mut self, short_name: &str, locked: LockedPackage) {
+        self.packages.insert(short_name.to_owned(), locked);
+    }
+
+    /// Commits the packages were generated from, keyed by short name, leaving out packages
+    /// recorded by the hash of their files.
+    pub fn revisions(&self) -> BTreeMap<String, String> {
+        self.packages
+            .iter()
+            .filter(|(_, locked)| !locked.revision.starts_with(CONTENT_HASH_PREFIX))
+            .map(|(short_name, locked)| (short_name.clone(), locked.revision.clone()))
+            .collect()
+    }
+
+    /// Sha256 of the files the packages recorded by the hash of their files were generated from,
+    /// keyed by short name.
+    pub fn checksums(&self) -> BTreeMap<String, String> {
+        self.packages
+            .iter()
+            .filter(|(_, locked)| locked.revision.starts_with(CONTENT_HASH_PREFIX))
+            .map(|(short_name, locked)| (short_name.clone(), locked.revision.clone()))
+            .collect()
+    }
+
+    /// Forget all packages but the given ones, e.g. packages no longer generated.
+    pub fn retain(&mut self, short_names: &[&str]) {
+        self.packages
+            .retain(|short_name, _| short_names.contains(&short_name.as_str()));
+    }
+}
+
+impl LockedPackage {
+    /// Tag or commit ref checked out, empty for local packages.
+    pub fn reference(&self) -> &str {
+        &self.reference
+    }
+
+    /// Commit checked out, or the sha256 of the files of the package.
+    pub fn revision(&self) -> &str {
+        &self.revision
+    }
+
+    /// What the icon crate of the package would be generated from, as it is on this machine.
+    /// `None` if the package was not downloaded yet. `options` are the build options changing the
+    /// generated crates.
+    ///
+    /// Upstream is not asked: like a generation, which checks out the ref of a package without
+    /// fetching it if it was downloaded before, this sees new upstream commits only once they were
+    /// fetched, e.g. by the `update` command.
+    pub async fn current<S>(
+        package: &Package<S>,
+        sources: &SourceConfig,
+        options: &str,
+    ) -> Result<Option<LockedPackage>> {
+        let source_path = package.source_path(sources);
+        if !source_path.exists() {
+            return Ok(None);
+        }
+        let (reference, revision) = match &package.meta.source {
+            PackageSource::Git { target, .. } => {
+                let revision = match source_path.join(".git").exists() {
+                    true => git::head_commit(&source_path)?,
+                    false => content_hash(&source_path).await?,
+                };
+                (target.reference().to_owned(), revision)
+            }
+            PackageSource::NpmTarball { version, .. } => {
+                (version.clone(), content_hash(&source_path).await?)
+            }
+            PackageSource::LocalPath { .. } => (String::new(), content_hash(&source_path).await?),
+        };
+        let source = match &package.meta.source {
+            PackageSource::Git { url, .. } => url.to_string(),
+            PackageSource::NpmTarball { .. } => {
+                package.meta.source.tarball_url().unwrap_or_default()
+            }
+            PackageSource::LocalPath { path, .. } => path.display().to_string(),
+        };
+        let options = format!(
+            "{} {options} {:?} {:?} {:?} {:?} {:?}",
+            env!("CARGO_PKG_VERSION"),
+            package.meta.source,
+            package.meta.svg_dir,
+            package.filter(),
+            package.category_renames(),
+            NameOverrides::read(&package.meta.short_name)?
+        );
+        Ok(Some(LockedPackage {
+            source,
+            reference,
+            revision,
+            options: format!("{:x}", Sha256::digest(options)),
+        }))
+    }
+}
+
+/// Sha256 of the paths and contents of all files below `dir`, skipping hidden ones like `.git`.
+pub(crate) async fn content_hash(dir: &Path) -> Result<String> {
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let mut dir_stream = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = dir_stream.next_entry().await? {
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            match entry.file_type().await?.is_dir() {
+                true => dirs.push(entry.path()),
+                false => files.push(entry.path()),
+            }
+        }
+    }
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for file in &files {
+        let relative_path = file.strip_prefix(dir).unwrap_or(file);
+        hasher.update(relative_path.to_string_lossy().as_bytes());
+        hasher.update(b"\0");
+        hasher.update(tokio::fs::read(file).await?);
+    }
+    Ok(format!("{CONTENT_HASH_PREFIX}{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LockedPackage, Lockfile, HEADER};
+
+    #[test]
+    fn round_trips() {
+        let mut lockfile = Lockfile::default();
+        let locked = LockedPackage {
+            source: "https://github.com/FortAwesome/Font-Awesome".to_owned(),
+            reference: "6.5.1".to_owned(),
+            revision: "d006795ede82361e1bac1ee76f215cf1dc51e4ca".to_owned(),
+            options: "0".repeat(64),
+        };
+        lockfile.insert("fa", locked.clone());
+        lockfile.insert("bs", locked);
+        lockfile.retain(&["fa"]);
+
+        let contents = format!("{HEADER}{}", toml::to_string(&lockfile).unwrap());
+        assert!(contents.contains("[package.fa]"));
+        assert_eq!(toml::from_str::<Lockfile>(&contents).unwrap(), lockfile);
+    }
+}
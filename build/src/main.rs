@@ -1,34 +1,511 @@
-use anyhow::{anyhow, Result};
-use clap::{command, Parser};
+use anyhow::{anyhow, ensure, Result};
+use clap::{command, Parser, Subcommand};
 use icon::SvgIcon;
-use package::Downloaded;
+use package::{Downloaded, PackageSource, SourceConfig, Unknown};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::sync::Mutex;
-use tracing::{error, info};
+use tokio::sync::{Mutex, Semaphore};
+use tokio::task::JoinSet;
+use tracing::{error, info, warn};
 use tracing_subscriber::filter::Targets;
 use tracing_subscriber::fmt::format::{Format, Pretty};
 use tracing_subscriber::prelude::__tracing_subscriber_SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{Layer, Registry};
 
+use crate::config::Config;
 use crate::dirs::{LibType, Library};
+use crate::duplicates::{DuplicateNames, DuplicatePolicy};
+use crate::lockfile::{LockedPackage, Lockfile};
 use crate::package::Package;
 use once_cell::sync::OnceCell;
 
+mod audit;
+mod bundle;
+mod cargo;
+mod catalog;
+mod changelog;
+mod complexity;
+mod component;
+mod compress;
+mod config;
+mod contact_sheet;
+mod currency;
+mod diff;
 mod dirs;
+mod duplicates;
+mod element;
+mod equivalents;
+mod failures;
+mod fidelity;
+mod file_types;
 mod fs;
+mod fuzz_corpus;
 mod git;
+#[cfg(feature = "mesh")]
+mod gltf;
+mod health;
 mod icon;
+mod keys;
+mod labels;
+mod lockfile;
+mod memory;
+mod migration;
+mod overrides;
 mod package;
 mod path;
+mod plotter;
+mod progress;
+mod publish;
+mod scaffold;
 mod sem_ver;
+mod signing;
+mod social;
+mod sqlite;
+mod status;
+mod user_agent;
+mod verify;
+mod wasm_pack;
+mod weather;
 
 #[derive(Debug, Parser)]
 #[command(author, version, about, long_about = None)]
 struct BuildArgs {
+    /// Run a different task than generating all libraries.
+    #[command(subcommand)]
+    command: Option<BuildCommand>,
+
     /// Clear downloads and re-download.
     #[arg(long, default_value_t = false)]
     clean: bool,
+
+    /// Only process the packages with these short names, e.g. "fa", given comma-separated like
+    /// `--packages fa,lu` or by repeating the flag. Only the icon crates of these packages are
+    /// generated, leaving the main library, the bundles and the icon index untouched.
+    #[arg(long = "package", visible_alias = "packages", value_delimiter = ',')]
+    selected_packages: Vec<String>,
+
+    /// Maximum number of packages downloaded and read at the same time. All at once by default.
+    /// The svg files of all packages are parsed on as many threads as there are cores.
+    #[arg(long)]
+    jobs: Option<usize>,
+
+    /// Never access the network: read the packages downloaded before, failing with all packages
+    /// not downloaded yet, or not at their ref.
+    #[arg(long, default_value_t = false)]
+    offline: bool,
+
+    /// Directory packages are downloaded to and read from, `downloads` in the root of this crate
+    /// by default. Keep it between CI runs to build `--offline`.
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+
+    /// Leave out icons whose svg files cannot be parsed instead of failing right away, and fail
+    /// once everything else was generated, listing them in `errors.json`.
+    #[arg(long, default_value_t = false)]
+    keep_going: bool,
+
+    /// Draw no progress bars, only write logs, e.g. when the output is read by a program.
+    #[arg(long, default_value_t = false)]
+    quiet: bool,
+
+    /// Leave out brand and logo icons of packages mixing them with other icons.
+    #[arg(long, default_value_t = false)]
+    exclude_brands: bool,
+
+    /// Simplify the paths of all icons, removing points deviating less than this tolerance from
+    /// the simplified outline, to generate a lite variant for embedded and low-power renderers.
+    /// The tolerance is relative to the size of each icon, e.g. `0.005` for half a percent.
+    #[arg(long)]
+    simplify: Option<f64>,
+
+    /// Add a variant of every icon designed for a larger size with its outline snapped to the
+    /// pixel grid at this size in pixels, e.g. 16 for crisp 16px icons from 24px designs. Variants
+    /// are named with the "aligned" category, like `BsHouseAligned`.
+    #[arg(long)]
+    pixel_grid: Option<u32>,
+
+    /// Add a badge variant of every single-colored icon, with the icon knocked out of a filled
+    /// rounded square. Variants are named with the "badge" category, like `BsHouseBadge`.
+    #[arg(long, default_value_t = false)]
+    badges: bool,
+
+    /// Download and generate the icon crates one after another, releasing the svg data of each
+    /// package once its crate was written. Reduces peak memory usage at the cost of build time.
+    #[arg(long, default_value_t = false)]
+    low_memory: bool,
+
+    /// HTTP(S) proxy used to download packages, e.g. "http://proxy.example.com:8080".
+    #[arg(long)]
+    proxy: Option<String>,
+
+    /// Download a package from a mirror, falling back to its upstream repository if the mirror
+    /// fails, given as `<short name>=<url>`, e.g.
+    /// `fa=https://git.example.com/mirrors/font-awesome.git`. Can be repeated.
+    #[arg(long = "mirror", value_parser = parse_mirror)]
+    mirrors: Vec<(String, String)>,
+
+    /// Read all packages from this directory, containing each package in its download directory,
+    /// instead of downloading them.
+    #[arg(long)]
+    vendor_dir: Option<PathBuf>,
+
+    /// Generate an additional icon crate from a directory of your own svg files, configured by
+    /// the JSON file at this path. Can be repeated. See the README for the configuration.
+    #[arg(long = "local-package")]
+    local_packages: Vec<PathBuf>,
+
+    /// Configuration file selecting and overriding packages, see the README. Defaults to
+    /// `icondata.toml` in the root of this crate, if it exists.
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Number of source files the icons of each icon crate are split into. Crates with more than
+    /// 500 icons per file are split into more files.
+    #[arg(long, default_value_t = 8)]
+    chunks: usize,
+
+    /// Store the icons of each icon crate as references into a dictionary of shared substrings,
+    /// decoded on first use, to cut the size of the crates. The icon index is not generated, as
+    /// it requires icons available as constants.
+    #[arg(long, default_value_t = false)]
+    compress: bool,
+
+    /// How icons are handled whose name is already declared by another package, which would make
+    /// them ambiguous in the main library. Packages are resolved in the order they are defined in.
+    #[arg(long, value_enum, default_value_t = DuplicatePolicy::Error)]
+    duplicates: DuplicatePolicy,
+
+    /// Skip downloading, reading and generating packages whose download did not change since their
+    /// icon crate was generated, according to `icondata.lock`, and skip the whole generation if no
+    /// package changed. As with `--package`, only the icon crates are generated then, leaving the
+    /// main library, the bundles and the icon index untouched, unless the main library is missing.
+    /// Upstream is not asked, so new commits of a branch are only detected after the `update`
+    /// command fetched them. Changes to this crate itself are not detected either.
+    #[arg(long, default_value_t = false)]
+    incremental: bool,
+
+    /// Check out the commits recorded in `icondata.lock` instead of the ones the refs of the
+    /// packages point to now, and fail instead of updating it if a package would be generated
+    /// from another ref or configuration than recorded, for reproducible CI runs.
+    #[arg(long, default_value_t = false)]
+    locked: bool,
+}
+
+/// How each package is downloaded and prepared after reading its icons, see [`BuildArgs`].
+#[derive(Debug, Clone, Copy)]
+struct PackageOptions {
+    clean: bool,
+    exclude_brands: bool,
+    simplify: Option<f64>,
+    pixel_grid: Option<u32>,
+    badges: bool,
+    keep_going: bool,
+}
+
+impl BuildArgs {
+    fn package_options(&self) -> PackageOptions {
+        PackageOptions {
+            clean: self.clean,
+            exclude_brands: self.exclude_brands,
+            simplify: self.simplify,
+            pixel_grid: self.pixel_grid,
+            badges: self.badges,
+            keep_going: self.keep_going,
+        }
+    }
+
+    fn sources(&self) -> Result<SourceConfig> {
+        let lockfile = match self.locked {
+            true => Lockfile::read()?,
+            false => Lockfile::default(),
+        };
+        Ok(SourceConfig {
+            proxy: self.proxy.clone(),
+            mirrors: self.mirrors.iter().cloned().collect(),
+            vendor_dir: self.vendor_dir.clone(),
+            offline: self.offline,
+            locked_revisions: lockfile.revisions(),
+            locked_checksums: lockfile.checksums(),
+        })
+    }
+
+    /// The configuration of the build, see [`BuildArgs::config`].
+    fn config(&self) -> Result<Config> {
+        Config::read(self.config.as_deref())
+    }
+
+    /// All packages to generate: the ones selected by the configuration and the local ones
+    /// configured by [`BuildArgs::local_packages`].
+    fn packages(&self) -> Result<Vec<Package<Unknown>>> {
+        let mut packages = self.config()?.packages(&self.local_packages)?;
+        for short_name in &self.selected_packages {
+            ensure!(
+                packages
+                    .iter()
+                    .any(|package| package.meta.short_name == short_name.as_str()),
+                "Unknown package {short_name:?}."
+            );
+        }
+        if self.is_partial() {
+            packages.retain(|package| {
+                self.selected_packages
+                    .iter()
+                    .any(|short_name| package.meta.short_name == short_name.as_str())
+            });
+        }
+        Ok(packages)
+    }
+
+    /// The options changing the generated icon crates, recorded in the lockfile, see
+    /// [`LockedPackage::current`].
+    fn generation_options(&self) -> String {
+        format!(
+            "{:?}",
+            (
+                self.exclude_brands,
+                self.simplify,
+                self.pixel_grid,
+                self.badges,
+                self.chunks,
+                self.compress,
+                self.duplicates,
+            )
+        )
+    }
+
+    /// Limits the packages processed at the same time, see [`BuildArgs::jobs`].
+    fn job_slots(&self, num_packages: usize) -> Arc<Semaphore> {
+        Arc::new(Semaphore::new(self.jobs.unwrap_or(num_packages).max(1)))
+    }
+
+    /// Whether only some packages were selected, see [`BuildArgs::selected_packages`].
+    fn is_partial(&self) -> bool {
+        !self.selected_packages.is_empty()
+    }
+}
+
+fn parse_mirror(value: &str) -> Result<(String, String)> {
+    let (short_name, url) = value
+        .split_once('=')
+        .ok_or_else(|| anyhow!("Expected `<short name>=<url>`, got {value:?}."))?;
+    Ok((short_name.to_owned(), url.to_owned()))
+}
+
+#[derive(Debug, Subcommand)]
+enum BuildCommand {
+    /// Generate all libraries, the default without any command.
+    Generate,
+    /// Download the packages, or fetch and check out their ref if downloaded before, without
+    /// generating anything.
+    Update,
+    /// Report the files of the libraries a generation would add, change or remove, and the icons
+    /// it would add, remove or rename, without writing them.
+    Diff {
+        /// Also write the added, removed and renamed icons to this file as Markdown, e.g. for
+        /// release notes.
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// Also write images of the icons drawn differently, before and after side by side, with
+        /// an `index.html` listing them, to this directory, e.g. for reviewing pull requests.
+        #[arg(long)]
+        images: Option<PathBuf>,
+    },
+    /// Remove the downloaded packages.
+    Clean,
+    /// List the packages with their source and whether they were downloaded.
+    ListPackages,
+    /// Bump the versions of all changed crates and publish them to crates.io.
+    Publish {
+        /// Git revision the generated crates are compared against to detect changes.
+        #[arg(long, default_value = "HEAD")]
+        since: String,
+
+        /// Only report the planned releases and run `cargo publish --dry-run`.
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+    },
+    /// Check that the committed libraries match a fresh generation and that every icon is reachable.
+    Verify,
+    /// Export the labels of all icons as a translation template.
+    ExportLabels {
+        /// Format of the translation template.
+        #[arg(long, value_enum, default_value_t = labels::Format::Fluent)]
+        format: labels::Format,
+
+        /// File the translation template is written to.
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Export all icons as binary catalog, which tools can load without parsing the generated crates.
+    ExportCatalog {
+        /// File the catalog is written to.
+        #[arg(long)]
+        out: PathBuf,
+
+        /// Minisign secret key the catalog is signed with, writing the signature to
+        /// `<out>.minisig`.
+        #[arg(long)]
+        signing_key: Option<PathBuf>,
+    },
+    /// Export every icon as single line drawing for laser cutters, engravers and pen plotters.
+    ExportPlotter {
+        /// File format of the drawings.
+        #[arg(long, value_enum, default_value_t = plotter::Format::Dxf)]
+        format: plotter::Format,
+
+        /// Directory the drawings are written to, one subdirectory per package.
+        #[arg(long)]
+        out: PathBuf,
+
+        /// Width and height of each drawing in millimeters.
+        #[arg(long, default_value_t = 24.0)]
+        size: f64,
+    },
+    /// Export the given icons as extruded 3D meshes in binary glTF, for AR scenes and 3D
+    /// dashboards. Experimental.
+    #[cfg(feature = "mesh")]
+    ExportGltf {
+        /// Names of the icons to export, e.g. `BsHouse`.
+        #[arg(long = "icon", required = true)]
+        icons: Vec<String>,
+
+        /// Directory the meshes are written to, one `<icon>.glb` file per icon.
+        #[arg(long)]
+        out: PathBuf,
+
+        /// Width and height of each mesh in meters.
+        #[arg(long, default_value_t = 0.1)]
+        size: f64,
+
+        /// Depth the filled areas are extruded by, in meters.
+        #[arg(long, default_value_t = 0.01)]
+        depth: f64,
+    },
+    /// Export all icons as SQLite database with a full text index over their names and tags.
+    ExportSqlite {
+        /// File the database is written to. An existing file is replaced.
+        #[arg(long)]
+        out: PathBuf,
+
+        /// Minisign secret key the database is signed with, writing the signature to
+        /// `<out>.minisig`.
+        #[arg(long)]
+        signing_key: Option<PathBuf>,
+    },
+    /// Export the `<icon-data>` web component, built with wasm-pack, and the compressed catalog it
+    /// renders icons from, for frontends not written in Rust.
+    ExportElement {
+        /// Directory the compressed catalog and the WASM package are written to.
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Export a WebAssembly component embedding the compressed catalog, implementing the WIT
+    /// interface of the `icondata_component` crate, for hosts and plugin systems not written in
+    /// Rust.
+    ExportComponent {
+        /// Directory the compressed catalog and the component are written to.
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Report the number of path commands and anchor points of every package and its most complex
+    /// icons.
+    Complexity {
+        /// File the JSON report is written to.
+        #[arg(long, default_value = "complexity.json")]
+        out: PathBuf,
+    },
+    /// Write contact sheets of every package, pages showing all of its icons with their names as
+    /// svg and png files, to review the visual changes of an upstream bump.
+    ContactSheets {
+        /// Directory the sheets are written to, in a directory per package.
+        #[arg(long, default_value = "contact-sheets")]
+        out: PathBuf,
+
+        /// Number of icons per row.
+        #[arg(long, default_value_t = 12)]
+        columns: usize,
+
+        /// Number of rows per page.
+        #[arg(long, default_value_t = 20)]
+        rows: usize,
+
+        /// Width and height in pixels icons are rendered with.
+        #[arg(long, default_value_t = 48)]
+        size: u32,
+    },
+    /// Render every icon from its upstream svg file and as generated, and report how much they
+    /// differ per package.
+    Fidelity {
+        /// File the JSON report is written to.
+        #[arg(long, default_value = "report.json")]
+        out: PathBuf,
+
+        /// Width and height in pixels icons are rendered with.
+        #[arg(long, default_value_t = 64)]
+        size: u32,
+    },
+    /// Report the health of every package for a status dashboard: whether upstream has a newer
+    /// release than recorded in the lockfile, the number of warnings reading its icons, how closely
+    /// its icons match upstream and how its number of icons developed.
+    Health {
+        /// File the JSON report is written to.
+        #[arg(long, default_value = "health.json")]
+        out: PathBuf,
+
+        /// Number of the last commits changing each crate its number of icons is reported for.
+        #[arg(long, default_value_t = 10)]
+        history: usize,
+    },
+    /// Report inconsistently spelled words across the icon names of all packages.
+    Audit {
+        /// Normalize the reported spellings, keeping the previous icon names as deprecated aliases.
+        #[arg(long, default_value_t = false)]
+        apply: bool,
+    },
+    /// Report overrides of icon names which no longer rename an icon, e.g. after upstream fixed
+    /// the name itself.
+    Overrides,
+    /// Report icons of a project which are equivalents from different packages, like `BsHouse`
+    /// next to `LuHome`, suggesting to use one package throughout.
+    Equivalents {
+        /// Directory of the project whose Rust files are checked.
+        #[arg(long)]
+        project: PathBuf,
+
+        /// File the JSON report is written to.
+        #[arg(long, default_value = "equivalents.json")]
+        out: PathBuf,
+    },
+    /// Seed the corpora of the fuzz targets with the upstream svg files and path data of all icons.
+    ExportFuzzCorpus {
+        /// Directory the corpora are written to, one subdirectory per fuzz target.
+        #[arg(long, default_value = "fuzz/corpus")]
+        out: PathBuf,
+    },
+    /// Add a new icon package, including its crate skeleton.
+    NewPackage {
+        /// Full human readable name of the icon package, e.g. "Solar".
+        #[arg(long)]
+        name: String,
+
+        /// Short identifier used as crate suffix and icon name prefix, e.g. "so".
+        #[arg(long)]
+        prefix: String,
+
+        /// Source of the icon package in the form `git:<url>@<tag>`.
+        #[arg(long)]
+        source: String,
+
+        /// Directory relative to the downloaded package under which raw SVG files can be found.
+        #[arg(long, default_value = "icons")]
+        svg_dir: String,
+
+        /// License of the icon package.
+        #[arg(long, default_value = "MIT")]
+        license: String,
+    },
 }
 
 static PACKAGES: OnceCell<Packages> = OnceCell::new();
@@ -64,53 +541,246 @@ async fn main() -> Result<()> {
 
     let args: BuildArgs = BuildArgs::parse();
     info!(?args, "Parsed program arguments.");
+    progress::init(args.quiet);
+    if let Some(output_dir) = args.config()?.output_dir() {
+        info!(?output_dir, "Generating crates in configured directory.");
+        path::set_output_dir(output_dir)?;
+    }
+    if let Some(cache_dir) = &args.cache_dir {
+        info!(?cache_dir, "Downloading packages to cache directory.");
+        path::set_download_dir(cache_dir.clone())?;
+    }
+    ensure!(
+        !(args.clean && args.offline),
+        "`--clean` removes the downloaded packages, which are required `--offline`."
+    );
 
     let start = time::OffsetDateTime::now_utc();
 
+    match &args.command {
+        Some(BuildCommand::Publish { since, dry_run }) => {
+            return publish::run(since, *dry_run).await;
+        }
+        Some(BuildCommand::Update) => {
+            return update_packages(&args).await;
+        }
+        Some(BuildCommand::Diff { out, images }) => {
+            download_packages(&args).await?;
+            return diff::run(
+                &libraries(&args, args.is_partial())?,
+                out.as_deref(),
+                images.as_deref(),
+            )
+            .await;
+        }
+        Some(BuildCommand::Clean) => {
+            for package in args.packages()? {
+                package.remove().await?;
+            }
+            return Ok(());
+        }
+        Some(BuildCommand::ListPackages) => {
+            list_packages(&args)?;
+            return Ok(());
+        }
+        Some(BuildCommand::Verify) => {
+            ensure!(
+                !args.is_partial(),
+                "Verification covers all packages, `--package` is not supported."
+            );
+            download_packages(&args).await?;
+            return verify::run(&libraries(&args, false)?).await;
+        }
+        Some(BuildCommand::ExportLabels { format, out }) => {
+            download_packages(&args).await?;
+            return labels::export(*format, out).await;
+        }
+        Some(BuildCommand::ExportCatalog { out, signing_key }) => {
+            download_packages(&args).await?;
+            return catalog::export(out, signing_key.as_deref()).await;
+        }
+        Some(BuildCommand::ExportPlotter { format, out, size }) => {
+            download_packages(&args).await?;
+            return plotter::export(*format, out, *size).await;
+        }
+        #[cfg(feature = "mesh")]
+        Some(BuildCommand::ExportGltf {
+            icons,
+            out,
+            size,
+            depth,
+        }) => {
+            download_packages(&args).await?;
+            return gltf::export(icons, out, *size, *depth).await;
+        }
+        Some(BuildCommand::ExportSqlite { out, signing_key }) => {
+            download_packages(&args).await?;
+            return sqlite::export(out, signing_key.as_deref()).await;
+        }
+        Some(BuildCommand::ExportElement { out }) => {
+            download_packages(&args).await?;
+            return element::export(out).await;
+        }
+        Some(BuildCommand::ExportComponent { out }) => {
+            download_packages(&args).await?;
+            return component::export(out).await;
+        }
+        Some(BuildCommand::Complexity { out }) => {
+            download_packages(&args).await?;
+            return complexity::report(out).await;
+        }
+        Some(BuildCommand::ContactSheets {
+            out,
+            columns,
+            rows,
+            size,
+        }) => {
+            download_packages(&args).await?;
+            let layout = contact_sheet::Layout {
+                columns: *columns,
+                rows: *rows,
+                size: *size,
+            };
+            return contact_sheet::export(out, layout).await;
+        }
+        Some(BuildCommand::Fidelity { out, size }) => {
+            download_packages(&args).await?;
+            return fidelity::report(out, *size).await;
+        }
+        Some(BuildCommand::Health { out, history }) => {
+            download_packages(&args).await?;
+            return health::report(out, *history, &args.sources()?, &Lockfile::read()?).await;
+        }
+        Some(BuildCommand::Audit { apply }) => {
+            download_packages(&args).await?;
+            return audit::run(*apply).await;
+        }
+        Some(BuildCommand::Overrides) => {
+            download_packages(&args).await?;
+            return overrides::report().await;
+        }
+        Some(BuildCommand::Equivalents { project, out }) => {
+            download_packages(&args).await?;
+            return equivalents::report(project, out).await;
+        }
+        Some(BuildCommand::ExportFuzzCorpus { out }) => {
+            download_packages(&args).await?;
+            return fuzz_corpus::export(out).await;
+        }
+        Some(BuildCommand::NewPackage {
+            name,
+            prefix,
+            source,
+            svg_dir,
+            license,
+        }) => {
+            return scaffold::run(&scaffold::NewPackage {
+                name: name.clone(),
+                short_name: prefix.clone(),
+                source: source.clone(),
+                svg_dir: svg_dir.clone(),
+                license: license.clone(),
+            })
+            .await;
+        }
+        Some(BuildCommand::Generate) | None => {}
+    }
+
+    let mut lockfile = Lockfile::read()?;
+    let mut to_generate = args.packages()?;
+    let mut partial = args.is_partial();
+    if args.incremental {
+        let mut unchanged = unchanged_packages(&args, &lockfile).await?;
+        let main_lib_exists = path::generated_crate("icondata/Cargo.toml").exists();
+        if unchanged.len() == to_generate.len() && (partial || main_lib_exists) {
+            info!(
+                num_packages = to_generate.len(),
+                "No package changed, skipping the generation."
+            );
+            return Ok(());
+        }
+        // The main library is generated from the icons of all packages.
+        if !partial && !main_lib_exists {
+            unchanged.clear();
+        }
+        info!(
+            num_unchanged = unchanged.len(),
+            "Skipping unchanged packages."
+        );
+        for short_name in &unchanged {
+            progress::generated(short_name, true);
+        }
+        to_generate.retain(|package| !unchanged.contains(package.meta.short_name.as_ref()));
+        partial |= !unchanged.is_empty();
+    }
+
+    match args.low_memory {
+        true => generate_sequentially(&args, to_generate, partial, &mut lockfile).await?,
+        false => generate_all(&args, to_generate, partial, &mut lockfile).await?,
+    }
+
+    if !partial {
+        let short_names = Packages::get()?
+            .iter()
+            .map(|package| package.meta.short_name.as_ref())
+            .collect::<Vec<_>>();
+        lockfile.retain(&short_names);
+    }
+    if args.locked {
+        ensure!(
+            lockfile == Lockfile::read()?,
+            "The lockfile is out of date, which `--locked` forbids."
+        );
+    }
+    lockfile.write().await?;
+    memory::report().await?;
+
+    if args.keep_going {
+        failures::report().await?;
+    }
+
+    let num_libs = Packages::get()?.len();
+    let end = time::OffsetDateTime::now_utc();
+    info!(
+        took = format!("{}ms", (end - start).whole_milliseconds()),
+        num_libs, "Build successful!"
+    );
+
+    Ok(())
+}
+
+/// Download all packages, read their icons and make them available through [`Packages`].
+async fn download_packages(args: &BuildArgs) -> Result<()> {
+    download_package_list(args, args.packages()?).await
+}
+
+/// Download the given packages, read their icons and make them available through [`Packages`].
+async fn download_package_list(args: &BuildArgs, to_download: Vec<Package<Unknown>>) -> Result<()> {
+    let options = args.package_options();
+    let sources = Arc::new(args.sources()?);
     let packages: Arc<Mutex<Vec<Package<Downloaded>>>> = Arc::new(Mutex::new(Vec::new()));
 
     info!("Downloading all packages.");
-    let handles = Package::all()
-        .into_iter()
-        .map(|package| {
-            let packages = Arc::clone(&packages);
-
-            tokio::spawn(async move {
-                if args.clean {
-                    package.remove().await?;
-                }
-
-                // Download the package.
-                let package_type = package.ty;
-                let package = package.download().await.map_err(|err| {
-                    error!(
-                        ?package_type,
-                        ?err,
-                        "Downloading the package failed unexpectedly."
-                    );
-                    err
-                })?;
-
-                info!("Generating icon crate.");
-                let lib = Library::new(
-                    path::library_crate(format!("icondata_{}", package.meta.short_name)),
-                    LibType::IconLib(&package),
-                );
-                lib.generate().await?;
-
-                packages.lock().await.push(package);
-
-                Ok::<(), anyhow::Error>(())
-            })
-        })
-        .collect::<Vec<_>>();
-    for handle in handles {
-        match handle.await.unwrap() {
-            Ok(()) => (),
-            Err(err) => {
-                error!(?err, "Could not process package successfully.");
-                return Err(err);
-            }
+    ensure_downloaded(&to_download, &sources)?;
+    let job_slots = args.job_slots(to_download.len());
+    let mut downloads = JoinSet::new();
+    for package in to_download {
+        let packages = Arc::clone(&packages);
+        let sources = Arc::clone(&sources);
+        let job_slots = Arc::clone(&job_slots);
+
+        downloads.spawn(async move {
+            let _job_slot = job_slots.acquire().await?;
+            let package = download_package(package, options, &sources).await?;
+            packages.lock().await.push(package);
+
+            Ok::<(), anyhow::Error>(())
+        });
+    }
+    while let Some(download) = downloads.join_next().await {
+        if let Err(err) = download? {
+            error!(?err, "Could not process package successfully.");
+            return Err(err);
         }
     }
 
@@ -118,35 +788,351 @@ async fn main() -> Result<()> {
     let mut packages = Arc::try_unwrap(packages)
         .or(Err(anyhow!("More than one hold of packages ARC.")))?
         .into_inner();
+
+    // Resolve duplicates in a fixed order, the one packages are generated in with `low_memory`.
+    packages.sort_by(|a, b| (a.ty, &a.meta.short_name).cmp(&(b.ty, &b.meta.short_name)));
+    let mut names = DuplicateNames::default();
+    for package in &mut packages {
+        package.resolve_duplicates(&mut names, args.duplicates)?;
+    }
     packages.sort_by(|a, b| a.meta.short_name.cmp(&b.meta.short_name));
-    Packages::set(packages)?;
+    Packages::set(packages)
+}
 
-    info!("Generating main library.");
-    let main_lib = Library::new(path::library_crate("icondata"), LibType::MainLib);
-    main_lib.generate().await?;
+/// Download the packages, or fetch and check out their ref, without reading their icons.
+async fn update_packages(args: &BuildArgs) -> Result<()> {
+    let sources = Arc::new(args.sources()?);
+    let clean = args.clean;
 
-    info!("Generating icon index.");
-    let icon_index = Library::new(path::library_crate("icon_index"), LibType::IconIndex);
-    icon_index.generate().await?;
+    info!("Updating all packages.");
+    let to_update = args.packages()?;
+    let job_slots = args.job_slots(to_update.len());
+    let mut updates = JoinSet::new();
+    for package in to_update {
+        let sources = Arc::clone(&sources);
+        let job_slots = Arc::clone(&job_slots);
 
-    let num_libs = Packages::get()?.len();
-    let end = time::OffsetDateTime::now_utc();
-    info!(
-        took = format!("{}ms", (end - start).whole_milliseconds()),
-        num_libs, "Build successful!"
+        updates.spawn(async move {
+            let _job_slot = job_slots.acquire().await?;
+            if clean {
+                package.remove().await?;
+            }
+            package.update(&sources)
+        });
+    }
+    while let Some(update) = updates.join_next().await {
+        if let Err(err) = update? {
+            error!(?err, "Could not update package successfully.");
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+/// Short names of the packages which did not change since their icon crate was generated,
+/// according to the lockfile, see [`BuildArgs::incremental`].
+async fn unchanged_packages(args: &BuildArgs, lockfile: &Lockfile) -> Result<HashSet<String>> {
+    let sources = args.sources()?;
+    let options = args.generation_options();
+    let mut unchanged = HashSet::new();
+    for package in args.packages()? {
+        let short_name = package.meta.short_name.as_ref();
+        let crate_path = path::generated_crate(format!("icondata_{short_name}"));
+        if !crate_path.join("Cargo.toml").exists() {
+            continue;
+        }
+        let current = LockedPackage::current(&package, &sources, &options).await?;
+        if current.is_some() && current.as_ref() == lockfile.get(short_name) {
+            unchanged.insert(short_name.to_owned());
+        }
+    }
+    Ok(unchanged)
+}
+
+/// Record what the icon crate of the downloaded package is generated from in the lockfile,
+/// failing with [`BuildArgs::locked`] if anything else was recorded before.
+async fn lock_package(
+    args: &BuildArgs,
+    lockfile: &mut Lockfile,
+    package: &Package<Downloaded>,
+) -> Result<()> {
+    let short_name = package.meta.short_name.as_ref();
+    let locked = LockedPackage::current(package, &args.sources()?, &args.generation_options())
+        .await?
+        .ok_or_else(|| anyhow!("Package {short_name:?} was not downloaded."))?;
+    if args.locked {
+        ensure!(
+            lockfile.get(short_name) == Some(&locked),
+            "Package {short_name:?} would be generated from another revision or configuration than recorded in the lockfile, which `--locked` forbids."
+        );
+    }
+    lockfile.insert(short_name, locked);
+    Ok(())
+}
+
+/// Log every package with its source and whether it was downloaded.
+fn list_packages(args: &BuildArgs) -> Result<()> {
+    for package in args.packages()? {
+        let reference = match &package.meta.source {
+            PackageSource::Git { target, .. } => target.reference().to_owned(),
+            PackageSource::NpmTarball { name, version, .. } => format!("{name}@{version}"),
+            PackageSource::LocalPath { path, .. } => path.display().to_string(),
+        };
+        info!(
+            short_name = package.meta.short_name.as_ref(),
+            name = package.meta.package_name.as_ref(),
+            source = package.meta.source.url(),
+            reference,
+            opt_in = package.meta.opt_in,
+            pinned = package.is_pinned(),
+            downloaded = package.is_downloaded(),
+            "Package."
+        );
+    }
+    Ok(())
+}
+
+/// Fail offline with all packages not downloaded yet, rather than with the first one found.
+fn ensure_downloaded(packages: &[Package<Unknown>], sources: &SourceConfig) -> Result<()> {
+    if !sources.offline {
+        return Ok(());
+    }
+    let missing = packages
+        .iter()
+        .filter(|package| !package.meta.source.is_local())
+        .filter(|package| !package.source_path(sources).exists())
+        .map(|package| package.meta.short_name.as_ref())
+        .collect::<Vec<_>>();
+    ensure!(
+        missing.is_empty(),
+        "Packages {} were not downloaded to {:?} yet, which is required offline. Run `cargo run -- update` once without `--offline`.",
+        missing.join(", "),
+        sources
+            .vendor_dir
+            .clone()
+            .unwrap_or_else(path::download_dir)
     );
+    Ok(())
+}
 
+/// Download a single package and read its icons.
+async fn download_package(
+    package: Package<Unknown>,
+    options: PackageOptions,
+    sources: &SourceConfig,
+) -> Result<Package<Downloaded>> {
+    if options.clean {
+        package.remove().await?;
+    }
+
+    // Download the package.
+    let package_type = package.ty;
+    let mut package = package
+        .download(sources, options.keep_going)
+        .await
+        .map_err(|err| {
+            error!(
+                ?package_type,
+                ?err,
+                "Downloading the package failed unexpectedly."
+            );
+            err
+        })?;
+    if options.exclude_brands {
+        package.remove_brands();
+    }
+    if package.meta.expand_strokes {
+        package.expand_strokes()?;
+    }
+    if let Some(tolerance) = options.simplify {
+        package.simplify(tolerance);
+    }
+    if let Some(pixels) = options.pixel_grid {
+        package.add_aligned_variants(pixels);
+    }
+    if options.badges {
+        package.add_badge_variants();
+    }
+    Ok(package)
+}
+
+/// Download the given packages, then generate their icon libraries and, unless `partial`, the
+/// bundles, the main library and the icon index.
+async fn generate_all(
+    args: &BuildArgs,
+    to_download: Vec<Package<Unknown>>,
+    partial: bool,
+    lockfile: &mut Lockfile,
+) -> Result<()> {
+    download_package_list(args, to_download).await?;
+    let mut previous_references = HashMap::new();
+    for package in Packages::get()? {
+        let short_name = package.meta.short_name.as_ref();
+        if let Some(locked) = lockfile.get(short_name) {
+            previous_references.insert(short_name, locked.reference().to_owned());
+        }
+        lock_package(args, lockfile, package).await?;
+    }
+
+    info!("Generating all libraries.");
+    for lib in libraries(args, partial)? {
+        let previous_reference = lib
+            .short_name()
+            .and_then(|short_name| previous_references.get(short_name));
+        generate_library(&lib, previous_reference.map(String::as_str)).await?;
+    }
     Ok(())
 }
 
+/// Generate the library. The changelog of an icon library gets an entry about the changes since it
+/// was generated from `previous_reference` before the library is overwritten, see
+/// [`changelog::update`], and its version is bumped afterwards.
+async fn generate_library(lib: &Library<'_>, previous_reference: Option<&str>) -> Result<()> {
+    let Some(package) = lib.package() else {
+        return lib.generate().await;
+    };
+    let short_name = package.meta.short_name.as_ref();
+    progress::generating(short_name);
+    changelog::update(lib.dir(), package, previous_reference).await?;
+    lib.generate().await?;
+    bump_version(lib.dir()).await;
+    progress::generated(short_name, false);
+    Ok(())
+}
+
+/// Generate the libraries while holding the svg data of only one package at a time: each icon
+/// library is written right after its package was downloaded, the main library and the icon index
+/// once all packages were processed unless `partial`.
+async fn generate_sequentially(
+    args: &BuildArgs,
+    to_download: Vec<Package<Unknown>>,
+    partial: bool,
+    lockfile: &mut Lockfile,
+) -> Result<()> {
+    let sources = args.sources()?;
+    let mut packages = Vec::new();
+    let mut names = DuplicateNames::default();
+    ensure_downloaded(&to_download, &sources)?;
+    for package in to_download {
+        let mut package = download_package(package, args.package_options(), &sources).await?;
+        package.resolve_duplicates(&mut names, args.duplicates)?;
+        let short_name = package.meta.short_name.as_ref();
+        let previous_reference = lockfile
+            .get(short_name)
+            .map(|locked| locked.reference().to_owned());
+        lock_package(args, lockfile, &package).await?;
+
+        info!(
+            package = package.meta.package_name.as_ref(),
+            "Generating library."
+        );
+        let crate_dir = path::generated_crate(format!("icondata_{short_name}"));
+        let lib = Library::new(crate_dir, LibType::IconLib(&package))
+            .with_chunks(args.chunks)
+            .with_compression(args.compress);
+        generate_library(&lib, previous_reference.as_deref()).await?;
+
+        package.release_svgs();
+        packages.push(package);
+    }
+
+    packages.sort_by(|a, b| a.meta.short_name.cmp(&b.meta.short_name));
+    Packages::set(packages)?;
+    if partial {
+        return Ok(());
+    }
+
+    info!("Generating the bundles, the main library and the icon index.");
+    for bundle in bundle::BUNDLES {
+        Library::new(
+            path::generated_crate(format!("icondata_{}", bundle.name)),
+            LibType::Bundle(bundle),
+        )
+        .generate()
+        .await?;
+    }
+    Library::new(path::generated_crate("icondata"), LibType::MainLib)
+        .with_compression(args.compress)
+        .generate()
+        .await?;
+    if args.compress {
+        return Ok(());
+    }
+    Library::new(path::generated_crate("icon_index"), LibType::IconIndex)
+        .generate()
+        .await
+}
+
+/// Bump the version of the icon crate generated in `crate_dir` by the changes of its icon constants
+/// since the last commit, see [`sem_ver::Change`]: a patch release if only icon data changed, a
+/// minor release if constants were added or deprecated, and a major release if constants were
+/// removed or renamed without an alias. Crates generated outside of this repository have no
+/// history to compare with.
+async fn bump_version(crate_dir: &Path) {
+    if !path::is_in_repository(crate_dir) {
+        return;
+    }
+    match publish::bump_generated("HEAD", crate_dir).await {
+        Ok(Some(bump)) => info!(
+            ?crate_dir,
+            change = ?bump.change,
+            previous = %bump.previous,
+            next = %bump.next,
+            "Bumped crate version."
+        ),
+        Ok(None) => {}
+        Err(err) => warn!(?crate_dir, ?err, "Could not bump the crate version."),
+    }
+}
+
+/// All libraries generated from the downloaded packages: one per icon package, one per bundle, the
+/// main library and the icon index. The icon index is left out when `compress`ing icons, see
+/// [`BuildArgs::compress`], and only the icon libraries are generated if `partial`, e.g. as only
+/// some packages were selected.
+fn libraries(args: &BuildArgs, partial: bool) -> Result<Vec<Library<'static>>> {
+    let compress = args.compress;
+    let mut libraries = Packages::get()?
+        .iter()
+        .map(|package| {
+            Library::new(
+                path::generated_crate(format!("icondata_{}", package.meta.short_name)),
+                LibType::IconLib(package),
+            )
+            .with_chunks(args.chunks)
+            .with_compression(compress)
+        })
+        .collect::<Vec<_>>();
+    if partial {
+        return Ok(libraries);
+    }
+    libraries.extend(bundle::BUNDLES.iter().map(|bundle| {
+        Library::new(
+            path::generated_crate(format!("icondata_{}", bundle.name)),
+            LibType::Bundle(bundle),
+        )
+    }));
+    libraries.push(
+        Library::new(path::generated_crate("icondata"), LibType::MainLib)
+            .with_compression(compress),
+    );
+    if !compress {
+        libraries.push(Library::new(
+            path::generated_crate("icon_index"),
+            LibType::IconIndex,
+        ));
+    }
+    Ok(libraries)
+}
+
 fn init_tracing(level: tracing::level_filters::LevelFilter) {
     fn build_log_filter(default_log_level: tracing::level_filters::LevelFilter) -> Targets {
         Targets::new().with_default(default_log_level)
     }
 
     fn build_tracing_subscriber_fmt_layer(
-    ) -> tracing_subscriber::fmt::Layer<Registry, Pretty, Format<Pretty>> {
+    ) -> tracing_subscriber::fmt::Layer<Registry, Pretty, Format<Pretty>, progress::LogWriter> {
         tracing_subscriber::fmt::layer()
+            .with_writer(progress::LogWriter)
             .pretty()
             .with_file(true)
             .with_line_number(true)
@@ -158,7 +1144,10 @@ fn init_tracing(level: tracing::level_filters::LevelFilter) {
     let fmt_layer_filtered =
         build_tracing_subscriber_fmt_layer().with_filter(build_log_filter(level));
 
-    Registry::default().with(fmt_layer_filtered).init();
+    Registry::default()
+        .with(fmt_layer_filtered)
+        .with(health::WarningCounter)
+        .init();
 }
 
 /// Simply tests that from the assumed repository root, both the "build" and "icondata" directories are visible.
@@ -178,3 +1167,59 @@ fn assert_paths() {
         icondata_crate_root.file_name().and_then(|it| it.to_str())
     );
 }
+
+#[cfg(test)]
+mod test {
+    use clap::Parser;
+
+    use crate::{download_package, generate_library, BuildArgs, LibType, Library};
+
+    /// Generating an icon library adds an entry to its changelog before overwriting it.
+    #[tokio::test]
+    async fn generation_updates_changelog() {
+        let dir = std::env::temp_dir().join(format!("icondata-generate-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("svg")).unwrap();
+        std::fs::write(
+            dir.join("svg/home.svg"),
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><path d="M3 12l9-9 9 9"/></svg>"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("local.json"),
+            r#"{ "short_name": "custom", "package_name": "Acme Icons", "prefix": "Acme", "path": "svg" }"#,
+        )
+        .unwrap();
+        std::fs::write(dir.join("icondata.toml"), "").unwrap();
+        // The crate as generated before, with another icon.
+        let crate_dir = dir.join("icondata_custom");
+        std::fs::create_dir_all(crate_dir.join("src")).unwrap();
+        std::fs::write(
+            crate_dir.join("src/lib.rs"),
+            "pub static AcmeHouse: &icondata_core::IconData = &icondata_core::IconData {\n",
+        )
+        .unwrap();
+
+        let config = dir.join("icondata.toml");
+        let local_package = dir.join("local.json");
+        let args = BuildArgs::parse_from([
+            "icondata-builder".as_ref(),
+            "--config".as_ref(),
+            config.as_os_str(),
+            "--local-package".as_ref(),
+            local_package.as_os_str(),
+            "--package".as_ref(),
+            "custom".as_ref(),
+        ]);
+        let package = args.packages().unwrap().remove(0);
+        let package = download_package(package, args.package_options(), &args.sources().unwrap())
+            .await
+            .unwrap();
+        let lib = Library::new(crate_dir.clone(), LibType::IconLib(&package));
+        generate_library(&lib, None).await.unwrap();
+
+        let changelog = std::fs::read_to_string(crate_dir.join("CHANGELOG.md")).unwrap();
+        assert!(changelog.contains("### Added\n\n- `AcmeHome`\n"));
+        assert!(changelog.contains("### Removed\n\n- `AcmeHouse`\n"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,77 @@
+//! Memory held by the parsed icons of every package, written to `memory.json` after each build.
+//! Compares the bytes of the attribute values of all icons with those actually allocated for
+//! them, which is less as equal values are interned, see [`with_interner`](crate::icon::svg::with_interner).
+
+use anyhow::Result;
+use serde::Serialize;
+use tracing::{info, instrument};
+
+use crate::{icon::svg::AttrValue, path, Packages};
+
+/// File the memory usage is written to, relative to the root of this crate.
+const MEMORY_FILE: &str = "memory.json";
+
+/// Approximate heap bytes held by the parsed icons of a package.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+struct MemoryUsage {
+    /// Short name of the package, or "total" for the sum of all packages.
+    package: String,
+    /// Bytes of the svg content of the icons. Content released with `--low-memory` is not counted.
+    svg_bytes: usize,
+    /// Bytes the attribute values of the icons would occupy without interning.
+    attribute_bytes: usize,
+    /// Bytes allocated for the attribute values of the icons.
+    interned_attribute_bytes: usize,
+}
+
+/// Log the memory held by the parsed icons of all packages and write it to `memory.json`, one entry
+/// per package followed by the total.
+#[instrument(level = "info")]
+pub(crate) async fn report() -> Result<()> {
+    let mut usages = Packages::get()?
+        .iter()
+        .map(|package| MemoryUsage {
+            package: package.meta.short_name.to_string(),
+            svg_bytes: package
+                .icons()
+                .iter()
+                .map(|icon| icon.svg.heap_size())
+                .sum(),
+            attribute_bytes: package
+                .icons()
+                .iter()
+                .map(|icon| icon.svg.attribute_bytes())
+                .sum(),
+            interned_attribute_bytes: AttrValue::allocated_bytes(
+                package
+                    .icons()
+                    .iter()
+                    .flat_map(|icon| icon.svg.attribute_values()),
+            ),
+        })
+        .collect::<Vec<_>>();
+    let total = usages.iter().fold(
+        MemoryUsage {
+            package: "total".to_owned(),
+            ..MemoryUsage::default()
+        },
+        |total, usage| MemoryUsage {
+            svg_bytes: total.svg_bytes + usage.svg_bytes,
+            attribute_bytes: total.attribute_bytes + usage.attribute_bytes,
+            interned_attribute_bytes: total.interned_attribute_bytes
+                + usage.interned_attribute_bytes,
+            ..total
+        },
+    );
+    info!(
+        svg_bytes = total.svg_bytes,
+        attribute_bytes_without_interning = total.attribute_bytes,
+        attribute_bytes_interned = total.interned_attribute_bytes,
+        "Memory used by parsed icons."
+    );
+    usages.push(total);
+
+    let memory_path = path::build_crate(MEMORY_FILE);
+    tokio::fs::write(&memory_path, serde_json::to_string_pretty(&usages)?).await?;
+    Ok(())
+}
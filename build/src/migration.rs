@@ -0,0 +1,395 @@
+//! Aliases easing the migration from other icon crates, generated into the `migrate` module of the
+//! main library.
+//!
+//! Every migration maps the names used by another crate to the icons of this catalog. Aliases
+//! whose glyph differs from the one they replace are deprecated with a note on the difference.
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use heck::ToPascalCase;
+use tracing::warn;
+
+use crate::{
+    icon::SvgIcon,
+    package::{Downloaded, Package, PackageType},
+    Packages,
+};
+
+/// Names of another crate, mapped to the icons of this catalog.
+#[derive(Debug)]
+pub(crate) struct Migration {
+    /// Name of the module the aliases are generated into, e.g. "iconify".
+    pub module: &'static str,
+    /// Which names are mapped, shown in the documentation of the module.
+    pub description: &'static str,
+    /// Aliases by name.
+    pub aliases: Vec<Alias>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Alias {
+    pub name: String,
+    pub icon: &'static SvgIcon,
+    /// Feature of the main library enabling the icon, e.g. "fa-solid".
+    pub feature: Option<String>,
+    /// How the glyph differs from the one the alias replaces. Deprecates the alias if set.
+    pub note: Option<&'static str>,
+}
+
+/// Font Awesome 4 names, as used by older crates and the `fa-` classes, mapped to their
+/// replacements in the current version and a note if the replacement is drawn differently.
+const FONT_AWESOME_4: &[(&str, &str, Option<&str>)] = &[
+    ("bars", "FaBarsSolid", None),
+    ("bell", "FaBellSolid", None),
+    ("bell-o", "FaBellRegular", None),
+    (
+        "calendar",
+        "FaCalendarDaysSolid",
+        Some("The calendar shows a grid of days."),
+    ),
+    ("calendar-o", "FaCalendarRegular", None),
+    ("check", "FaCheckSolid", None),
+    ("check-square-o", "FaSquareCheckRegular", None),
+    ("clock-o", "FaClockRegular", None),
+    ("cog", "FaGearSolid", None),
+    ("comment", "FaCommentSolid", None),
+    ("comment-o", "FaCommentRegular", None),
+    ("download", "FaDownloadSolid", None),
+    ("envelope", "FaEnvelopeSolid", None),
+    ("envelope-o", "FaEnvelopeRegular", None),
+    ("exclamation-triangle", "FaTriangleExclamationSolid", None),
+    (
+        "external-link",
+        "FaArrowUpRightFromSquareSolid",
+        Some("The arrow points out of a closed square."),
+    ),
+    ("eye", "FaEyeSolid", None),
+    ("eye-slash", "FaEyeSlashSolid", None),
+    ("file-o", "FaFileRegular", None),
+    ("files-o", "FaCopyRegular", None),
+    ("filter", "FaFilterSolid", None),
+    ("floppy-o", "FaFloppyDiskRegular", None),
+    ("folder", "FaFolderSolid", None),
+    ("folder-o", "FaFolderRegular", None),
+    ("folder-open", "FaFolderOpenSolid", None),
+    ("github", "FaGithubBrands", None),
+    ("heart", "FaHeartSolid", None),
+    ("heart-o", "FaHeartRegular", None),
+    ("home", "FaHouseSolid", None),
+    ("info-circle", "FaCircleInfoSolid", None),
+    ("link", "FaLinkSolid", None),
+    ("lock", "FaLockSolid", None),
+    ("minus", "FaMinusSolid", None),
+    ("pencil", "FaPencilSolid", None),
+    (
+        "pencil-square-o",
+        "FaPenToSquareRegular",
+        Some("A pen is drawn instead of a pencil."),
+    ),
+    ("picture-o", "FaImageRegular", None),
+    ("plus", "FaPlusSolid", None),
+    ("question-circle-o", "FaCircleQuestionRegular", None),
+    ("repeat", "FaArrowRotateRightSolid", None),
+    ("search", "FaMagnifyingGlassSolid", None),
+    ("share", "FaShareSolid", None),
+    ("sign-in", "FaRightToBracketSolid", None),
+    ("sign-out", "FaRightFromBracketSolid", None),
+    ("star", "FaStarSolid", None),
+    ("star-o", "FaStarRegular", None),
+    ("times", "FaXmarkSolid", None),
+    ("trash", "FaTrashSolid", None),
+    (
+        "trash-o",
+        "FaTrashCanRegular",
+        Some("The can has a lid and no longer tapers."),
+    ),
+    ("twitter", "FaTwitterBrands", None),
+    ("upload", "FaUploadSolid", None),
+    ("user", "FaUserSolid", None),
+    ("user-o", "FaUserRegular", None),
+];
+
+/// All migrations generated into the main library.
+pub(crate) fn migrations() -> Result<Vec<Migration>> {
+    Ok(vec![iconify()?, font_awesome_4()?])
+}
+
+/// Aliases named after the Iconify identifiers of the icons, e.g. `LucideHome` for "lucide:home".
+fn iconify() -> Result<Migration> {
+    let mut aliases = BTreeMap::<String, Alias>::new();
+    for package in Packages::get()? {
+        // Iconify only carries the current version of each set.
+        if package.is_pinned() {
+            continue;
+        }
+        for icon in package.icons() {
+            let Some(id) = iconify_id(package.ty, &icon.source_path) else {
+                continue;
+            };
+            let name = id.replace(':', "-").to_pascal_case();
+            match aliases.get(&name) {
+                // Variants like aligned or badge icons share the source of the original icon, whose
+                // name is the shortest.
+                Some(alias) if alias.icon.source_path == icon.source_path => {
+                    if alias.icon.name.len() <= icon.name.len() {
+                        continue;
+                    }
+                }
+                Some(alias) => {
+                    warn!(
+                        name,
+                        icon = icon.name,
+                        other = alias.icon.name,
+                        "Iconify identifiers of two icons map to the same alias, skipping."
+                    );
+                    continue;
+                }
+                None => {}
+            }
+            let alias = Alias {
+                name: name.clone(),
+                icon,
+                feature: feature(package, icon),
+                note: None,
+            };
+            aliases.insert(name, alias);
+        }
+    }
+
+    Ok(Migration {
+        module: "iconify",
+        description: "Icons by their Iconify identifier, e.g. `LucideHome` for `lucide:home`.",
+        aliases: aliases.into_values().collect(),
+    })
+}
+
+/// Aliases named after the icons of Font Awesome 4, e.g. `FaPencilSquareO` for "pencil-square-o".
+fn font_awesome_4() -> Result<Migration> {
+    let package = Packages::get()?
+        .iter()
+        .find(|package| package.ty == PackageType::FontAwesome && !package.is_pinned())
+        .ok_or_else(|| anyhow!("Font Awesome is required to migrate from Font Awesome 4."))?;
+
+    let aliases = FONT_AWESOME_4
+        .iter()
+        .map(|(old_name, name, note)| {
+            let icon = package
+                .icons()
+                .iter()
+                .find(|icon| icon.name == *name)
+                .ok_or_else(|| anyhow!("Icon {name} replacing {old_name} does not exist."))?;
+            Ok(Alias {
+                name: format!("fa-{old_name}").to_pascal_case(),
+                icon,
+                feature: feature(package, icon),
+                note: *note,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Migration {
+        module: "font_awesome_4",
+        description:
+            "Icons by their Font Awesome 4 name, e.g. `FaPencilSquareO` for `fa-pencil-square-o`.",
+        aliases,
+    })
+}
+
+/// An icon of the current packages, with the feature of the main library enabling it.
+#[derive(Debug)]
+pub(crate) struct PreferredIcon {
+    pub icon: &'static SvgIcon,
+    /// Feature of the main library enabling the icon, e.g. "bs-fill", see [`feature`].
+    pub feature: Option<String>,
+}
+
+/// The icons of the current packages with the given names, in order of preference, up to the
+/// first one which is always available, as the ones after it would never be used. Icons which no
+/// longer exist are left out.
+pub(crate) fn preferred_icons(names: &[&str]) -> Result<Vec<PreferredIcon>> {
+    let packages = Packages::get()?
+        .iter()
+        .filter(|package| !package.is_pinned())
+        .collect::<Vec<_>>();
+
+    let mut icons = Vec::new();
+    for name in names {
+        let found = packages.iter().find_map(|package| {
+            let icon = package.icons().iter().find(|icon| icon.name == *name)?;
+            Some(PreferredIcon {
+                icon,
+                feature: feature(package, icon),
+            })
+        });
+        match found {
+            Some(icon) => icons.push(icon),
+            None => warn!(name, "Preferred icon does not exist, skipping."),
+        }
+        if icons
+            .last()
+            .is_some_and(|icon| icon.feature.is_none() && !icon.icon.brand)
+        {
+            break;
+        }
+    }
+    Ok(icons)
+}
+
+/// Feature of the main library enabling the given icon, besides "brands" for brand icons. Icons
+/// without style of opt-in packages are enabled by the feature of their package, see
+/// [`PackageMetadata::opt_in`](crate::package::PackageMetadata::opt_in).
+pub(crate) fn feature(package: &Package<Downloaded>, icon: &SvgIcon) -> Option<String> {
+    match &icon.style {
+        Some(style) => Some(format!("{}-{style}", package.meta.short_name)),
+        None if package.meta.opt_in => Some(package.meta.short_name.to_string()),
+        None => None,
+    }
+}
+
+/// The Iconify identifier of an icon of the given package, e.g. "fa6-solid:house", given its path
+/// in the upstream repository. `None` if the path is not an svg file.
+pub(crate) fn iconify_id(package: PackageType, source_path: &str) -> Option<String> {
+    // Icons of Iconify collections are named by their JSON file, e.g. "json/mdi.json#home".
+    if package == PackageType::Iconify {
+        let (file, name) = source_path.split_once('#')?;
+        let prefix = file.rsplit('/').next()?.strip_suffix(".json")?;
+        return Some(format!("{prefix}:{name}"));
+    }
+    let mut dirs = source_path.rsplit('/');
+    let stem = dirs.next()?.strip_suffix(".svg")?;
+    let dir = dirs.next().unwrap_or_default();
+    let id = match package {
+        PackageType::AntDesignIcons => format!("ant-design:{stem}-{dir}"),
+        PackageType::FontAwesome => format!("fa6-{dir}:{stem}"),
+        PackageType::WeatherIcons => format!("wi:{}", stem.trim_start_matches("wi-")),
+        PackageType::Feather => format!("feather:{stem}"),
+        PackageType::VSCodeIcons => format!("codicon:{stem}"),
+        PackageType::BootstrapIcons => format!("bi:{stem}"),
+        PackageType::BoxIcons => {
+            let (prefix, name) = stem.split_once('-')?;
+            format!("{prefix}:{name}")
+        }
+        PackageType::IcoMoonFree => format!(
+            "icomoon-free:{}",
+            stem.trim_start_matches(char::is_numeric)
+                .trim_start_matches('-')
+        ),
+        PackageType::Ionicons => format!("ion:{stem}"),
+        PackageType::RemixIcon => format!("ri:{stem}"),
+        PackageType::SimpleIcons => format!("simple-icons:{stem}"),
+        PackageType::Typicons => format!("typcn:{stem}"),
+        // Outline icons at 24px carry no suffix, e.g. "src/20/solid/home.svg" is "home-20-solid".
+        PackageType::Heroicons => match (dirs.next().unwrap_or_default(), dir) {
+            ("24", "outline") => format!("heroicons:{stem}"),
+            ("24", style) => format!("heroicons:{stem}-{style}"),
+            (size, style) => format!("heroicons:{stem}-{size}-{style}"),
+        },
+        PackageType::CssGg => format!("gg:{stem}"),
+        PackageType::TablerIcons => format!("tabler:{stem}"),
+        PackageType::GithubOcticons => format!("octicon:{stem}"),
+        PackageType::Lucide => format!("lucide:{stem}"),
+        PackageType::Charm => format!("charm:{stem}"),
+        PackageType::Phosphor => format!("ph:{stem}"),
+        // Iconify derives its Material Symbols from another source, mapping fill and styles
+        // differently.
+        PackageType::MaterialSymbols => return None,
+        PackageType::FlagIcons => format!("flag:{stem}-{dir}"),
+        PackageType::GameIcons => format!("game-icons:{stem}"),
+        PackageType::Iconify => return None,
+        PackageType::Local => return None,
+        PackageType::Iconoir => match dir {
+            "solid" => format!("iconoir:{}-solid", stem.trim_end_matches("-solid")),
+            _ => format!("iconoir:{stem}"),
+        },
+        // Iconify carries the icons designed at 32px, with single dashes and in lowercase, e.g.
+        // "packages/icons/src/svg/32/arrow--down.svg" is "carbon:arrow-down".
+        PackageType::CarbonIcons => {
+            let size = source_path
+                .strip_prefix("packages/icons/src/svg/")?
+                .split('/')
+                .next()?;
+            if size != "32" {
+                return None;
+            }
+            format!("carbon:{}", stem.replace("--", "-").to_lowercase())
+        }
+    };
+    Some(id)
+}
+
+#[cfg(test)]
+mod test {
+    use super::iconify_id;
+    use crate::package::PackageType;
+
+    #[test]
+    fn derives_iconify_ids_from_source_paths() {
+        for (package, path, id) in [
+            (
+                PackageType::FontAwesome,
+                "svgs/regular/circle-user.svg",
+                "fa6-regular:circle-user",
+            ),
+            (
+                PackageType::AntDesignIcons,
+                "packages/icons-svg/svg/twotone/home.svg",
+                "ant-design:home-twotone",
+            ),
+            (
+                PackageType::Phosphor,
+                "assets/duotone/house-duotone.svg",
+                "ph:house-duotone",
+            ),
+            (
+                PackageType::Iconoir,
+                "icons/solid/heart.svg",
+                "iconoir:heart-solid",
+            ),
+            (
+                PackageType::GameIcons,
+                "lorc/crossed-swords.svg",
+                "game-icons:crossed-swords",
+            ),
+            (PackageType::Iconify, "json/mdi.json#home", "mdi:home"),
+            (
+                PackageType::FlagIcons,
+                "flags/4x3/gb-eng.svg",
+                "flag:gb-eng-4x3",
+            ),
+            (
+                PackageType::CarbonIcons,
+                "packages/icons/src/svg/32/watson-health/3D-cursor.svg",
+                "carbon:3d-cursor",
+            ),
+            (
+                PackageType::BoxIcons,
+                "svg/logos/bxl-github.svg",
+                "bxl:github",
+            ),
+            (
+                PackageType::IcoMoonFree,
+                "SVG/001-home.svg",
+                "icomoon-free:home",
+            ),
+            (
+                PackageType::Heroicons,
+                "src/24/outline/bell.svg",
+                "heroicons:bell",
+            ),
+            (
+                PackageType::Heroicons,
+                "src/20/solid/bell.svg",
+                "heroicons:bell-20-solid",
+            ),
+            (
+                PackageType::WeatherIcons,
+                "svg/wi-day-fog.svg",
+                "wi:day-fog",
+            ),
+        ] {
+            assert_eq!(iconify_id(package, path).as_deref(), Some(id));
+        }
+        assert_eq!(iconify_id(PackageType::Lucide, "icons/home.json"), None);
+    }
+}
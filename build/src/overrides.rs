@@ -0,0 +1,163 @@
+//! Icon names fixed by hand, like typos of upstream, in `overrides/<short name>.toml`, e.g.
+//!
+//! ```toml
+//! [names]
+//! FaHouze = "FaHouse"
+//! ```
+//!
+//! The icon generated as `FaHouze` is named `FaHouse` instead, keeping its upstream name as a
+//! deprecated alias. Once upstream fixes the name itself, the override no longer renames anything
+//! but still keeps the alias, and `cargo run -- overrides` lists it to be removed eventually.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt,
+};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use tracing::{info, instrument, warn};
+
+use crate::{icon::SvgIcon, path, Packages};
+
+/// Directory of the overrides files, relative to the root of this crate.
+const OVERRIDES_DIR: &str = "overrides";
+
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct NameOverrides {
+    /// Fixed names of icons by the names generated from upstream, both with the prefix of the
+    /// package.
+    #[serde(default)]
+    names: BTreeMap<String, String>,
+}
+
+/// An override which no longer renames an icon, see [`NameOverrides::apply`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UnnecessaryOverride {
+    pub from: String,
+    pub to: String,
+    pub reason: Reason,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Reason {
+    /// Upstream names the icon as fixed by now. The previous name is still kept as an alias.
+    FixedUpstream,
+    /// Another icon of the package is named as fixed already.
+    Taken,
+    /// No icon of the package is named like this, e.g. after upstream removed it.
+    Missing,
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Reason::FixedUpstream => "fixed upstream",
+            Reason::Taken => "name taken by another icon",
+            Reason::Missing => "no such icon",
+        })
+    }
+}
+
+impl NameOverrides {
+    /// The overrides of the package with the given short name, none if it has no overrides file.
+    pub fn read(short_name: &str) -> Result<NameOverrides> {
+        let overrides_path = path::build_crate(OVERRIDES_DIR).join(format!("{short_name}.toml"));
+        if !overrides_path.exists() {
+            return Ok(NameOverrides::default());
+        }
+        let overrides = std::fs::read_to_string(&overrides_path)
+            .with_context(|| format!("Could not read overrides {overrides_path:?}"))?;
+        toml::from_str(&overrides)
+            .with_context(|| format!("Could not parse overrides {overrides_path:?}"))
+    }
+
+    /// Rename the icons of a package as overridden, keeping their previous names as deprecated
+    /// aliases, and return the overrides which did not rename an icon.
+    pub fn apply(&self, icons: &mut [SvgIcon]) -> Vec<UnnecessaryOverride> {
+        let mut names = icons
+            .iter()
+            .map(|icon| icon.name.clone())
+            .collect::<HashSet<_>>();
+        let mut unnecessary = Vec::new();
+        for (from, to) in &self.names {
+            let reason = match reason(names.contains(from), names.contains(to)) {
+                Some(reason) => reason,
+                None => {
+                    let icon = icons.iter_mut().find(|icon| &icon.name == from);
+                    let icon = icon.expect("an icon of this name");
+                    icon.name = to.clone();
+                    icon.aliases.push(from.clone());
+                    names.remove(from);
+                    names.insert(to.clone());
+                    continue;
+                }
+            };
+            if reason == Reason::FixedUpstream {
+                // Code using the previous name keeps compiling.
+                let icon = icons.iter_mut().find(|icon| &icon.name == to);
+                let icon = icon.expect("an icon of this name");
+                if !icon.aliases.contains(from) {
+                    icon.aliases.push(from.clone());
+                }
+            }
+            warn!(from, to, %reason, "Name override does not rename an icon.");
+            unnecessary.push(UnnecessaryOverride {
+                from: from.clone(),
+                to: to.clone(),
+                reason,
+            });
+        }
+        unnecessary
+    }
+}
+
+/// Why an override of an icon name does not rename an icon, given whether icons named like before
+/// and after the override exist. `None` if it renames one.
+fn reason(from_exists: bool, to_exists: bool) -> Option<Reason> {
+    match (from_exists, to_exists) {
+        (true, false) => None,
+        (true, true) => Some(Reason::Taken),
+        (false, true) => Some(Reason::FixedUpstream),
+        (false, false) => Some(Reason::Missing),
+    }
+}
+
+/// Report the overrides of all packages which no longer rename an icon, to be removed from their
+/// overrides files.
+#[instrument(level = "info")]
+pub(crate) async fn report() -> Result<()> {
+    let mut num_unnecessary = 0;
+    for package in Packages::get()? {
+        for unnecessary in package.unnecessary_overrides() {
+            info!(
+                package = package.meta.short_name.as_ref(),
+                from = unnecessary.from,
+                to = unnecessary.to,
+                "Unnecessary name override: {}.",
+                unnecessary.reason
+            );
+            num_unnecessary += 1;
+        }
+    }
+    info!(num_unnecessary, "Checked name overrides.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{reason, NameOverrides, Reason};
+
+    #[test]
+    fn parses_overrides() {
+        let overrides: NameOverrides = toml::from_str("[names]\nFaHouze = \"FaHouse\"").unwrap();
+        assert_eq!(overrides.names["FaHouze"], "FaHouse");
+        assert!(toml::from_str::<NameOverrides>("[name]").is_err());
+
+        assert_eq!(reason(true, false), None);
+        assert_eq!(reason(false, true), Some(Reason::FixedUpstream));
+        assert_eq!(reason(true, true), Some(Reason::Taken));
+        assert_eq!(reason(false, false), Some(Reason::Missing));
+    }
+}
@@ -0,0 +1,107 @@
+//! Official colors of brand logos, published by some packages in a data file next to their icons.
+//!
+//! Icons are matched with the entries of the data file by the `<title>` of their svg file, which
+//! Simple Icons keeps equal to the title of the brand.
+
+use std::{collections::HashMap, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use tracing::{debug, warn};
+
+use crate::icon::SvgIcon;
+
+#[derive(Debug, Deserialize)]
+struct Data {
+    icons: Vec<Entry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Entry {
+    title: String,
+    /// Color as six hex digits, without leading `#`.
+    hex: String,
+}
+
+/// Sets the brand color of every icon found in the data file at `data_path`, relative to the
+/// `download_path` of the package.
+pub(crate) async fn assign(
+    icons: &mut [SvgIcon],
+    download_path: &Path,
+    data_path: &str,
+) -> Result<()> {
+    let data_path = download_path.join(data_path);
+    let data = tokio::fs::read_to_string(&data_path)
+        .await
+        .with_context(|| format!("Could not read brand colors from {data_path:?}"))?;
+    let colors = colors(&data).with_context(|| format!("Invalid brand colors in {data_path:?}"))?;
+
+    for icon in icons {
+        let svg = tokio::fs::read_to_string(download_path.join(&icon.source_path)).await?;
+        let Some(title) = title(&svg) else {
+            warn!(%icon.name, "Icon has no title to look up its brand color.");
+            continue;
+        };
+        icon.brand_color = colors.get(&title).cloned();
+        if icon.brand_color.is_none() {
+            debug!(%icon.name, %title, "No brand color found for icon.");
+        }
+    }
+    Ok(())
+}
+
+/// Brand colors by title, as `#` followed by six uppercase hex digits. Entries with invalid colors
+/// are left out.
+fn colors(data: &str) -> Result<HashMap<String, String>> {
+    let data = serde_json::from_str::<Data>(data)?;
+    Ok(data
+        .icons
+        .into_iter()
+        .filter_map(|entry| {
+            if entry.hex.len() != 6 || !entry.hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                warn!(%entry.title, %entry.hex, "Ignoring invalid brand color.");
+                return None;
+            }
+            Some((entry.title, format!("#{}", entry.hex.to_ascii_uppercase())))
+        })
+        .collect())
+}
+
+/// The unescaped content of the `<title>` element of an svg file.
+fn title(svg: &str) -> Option<String> {
+    let start = svg.find("<title>")? + "<title>".len();
+    let len = svg[start..].find("</title>")?;
+    Some(
+        svg[start..start + len]
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&apos;", "'")
+            .replace("&amp;", "&"),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::{colors, title};
+
+    #[test]
+    fn looks_up_colors_by_title() {
+        let colors = colors(
+            r##"{"icons": [
+                {"title": "AT&T", "hex": "009fdb", "source": "https://www.att.com"},
+                {"title": "Spotify", "hex": "1DB954", "source": "https://www.spotify.com"},
+                {"title": "Broken", "hex": "#12345", "source": "https://example.com"}
+            ]}"##,
+        )
+        .unwrap();
+        let svg = r#"<svg role="img" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg"><title>AT&amp;T</title><path d="M0 0h24v24H0z"/></svg>"#;
+
+        assert_eq!(
+            title(svg).and_then(|title| colors.get(&title)),
+            Some(&"#009FDB".to_owned())
+        );
+        assert_eq!(colors.get("Spotify"), Some(&"#1DB954".to_owned()));
+        assert_eq!(colors.get("Broken"), None);
+    }
+}
@@ -0,0 +1,169 @@
+//! Downloading the repositories of packages over flaky networks.
+//!
+//! A single failing clone would abort the whole build, so git commands failing for reasons which
+//! may not happen again, like a timeout or a server error, are retried with a growing delay.
+//! Packages with a mirror are cloned from it, falling back to their upstream repository if the
+//! mirror keeps failing. Vendored packages which are no git repositories are verified against the
+//! sha256 of their files recorded in the lockfile before their icons are read.
+
+use std::{path::PathBuf, thread, time::Duration};
+
+use snafu::prelude::*;
+use tracing::{info, warn};
+
+use super::{
+    Error, GitCheckoutSnafu, GitCloneSnafu, GitCloneWithoutCheckoutSnafu, GitFetchSnafu, GitTarget,
+    VendoredChecksumSnafu,
+};
+use crate::{git, lockfile};
+
+/// Number of times a git command failing transiently is run before giving up.
+const ATTEMPTS: u32 = 4;
+
+/// Delay before the first retry, doubled before every further one.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(2);
+
+/// Parts of the error messages of git about failures of the network or the server, lowercase.
+const TRANSIENT_ERRORS: &[&str] = &[
+    "could not resolve host",
+    "connection timed out",
+    "connection reset",
+    "connection refused",
+    "operation timed out",
+    "early eof",
+    "rpc failed",
+    "the remote end hung up unexpectedly",
+    "tls connection was non-properly terminated",
+    "returned error: 429",
+    "returned error: 5",
+];
+
+/// Clone the repository of a package at the given ref into `path`, from each of the given urls in
+/// turn until one succeeds, e.g. from its mirror first and from upstream second.
+pub(super) fn clone(
+    urls: &[&str],
+    target: &GitTarget,
+    path: &PathBuf,
+    proxy: Option<&str>,
+) -> Result<(), Error> {
+    let (last, others) = urls.split_last().expect("a url to clone from");
+    for url in others {
+        match clone_from(url, target, path, proxy) {
+            Ok(()) => return Ok(()),
+            Err(err) => warn!(url, ?err, "Could not clone, trying the next repository."),
+        }
+    }
+    clone_from(last, target, path, proxy)
+}
+
+fn clone_from(
+    url: &str,
+    target: &GitTarget,
+    path: &PathBuf,
+    proxy: Option<&str>,
+) -> Result<(), Error> {
+    with_retries(|| git::clone(url, target, path, proxy))
+        .with_context(|_| GitCloneSnafu {
+            url,
+            target: target.clone(),
+            path: path.clone(),
+        })
+        .or_else(|_err| {
+            info!("Direct clone unsuccessful. Trying clone with checkout...");
+            with_retries(|| git::clone_without_checkout(url, path, proxy)).with_context(|_| {
+                GitCloneWithoutCheckoutSnafu {
+                    url,
+                    path: path.clone(),
+                }
+            })?;
+            git::checkout(target, path).with_context(|_| GitCheckoutSnafu {
+                target: target.clone(),
+                path: path.clone(),
+            })
+        })
+}
+
+/// Fetch the given ref into the repository at `path`, see [`git::fetch`].
+pub(super) fn fetch(target: &GitTarget, path: &PathBuf, proxy: Option<&str>) -> Result<(), Error> {
+    with_retries(|| git::fetch(target, path, proxy)).with_context(|_| GitFetchSnafu {
+        target: target.clone(),
+        path: path.clone(),
+    })
+}
+
+/// Fail if the sha256 of the files of the vendored package at `path` is not the recorded one.
+pub(super) async fn verify_checksum(
+    short_name: &str,
+    path: &PathBuf,
+    expected: &str,
+) -> anyhow::Result<()> {
+    let actual = lockfile::content_hash(path).await?;
+    if actual != expected {
+        return Err(VendoredChecksumSnafu {
+            short_name,
+            path: path.clone(),
+            expected,
+            actual,
+        }
+        .build()
+        .into());
+    }
+    info!(?path, "Verified checksum of vendored package.");
+    Ok(())
+}
+
+/// Run the git command `run`, running it again after a growing delay as long as it fails
+/// transiently, up to [`ATTEMPTS`] times.
+fn with_retries<T>(mut run: impl FnMut() -> Result<T, git::Error>) -> Result<T, git::Error> {
+    let mut backoff = INITIAL_BACKOFF;
+    let mut attempt = 1;
+    loop {
+        match run() {
+            Err(err) if attempt < ATTEMPTS && is_transient(&err) => {
+                warn!(attempt, ?backoff, ?err, "Git failed transiently, retrying.");
+                thread::sleep(backoff);
+                backoff *= 2;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+fn is_transient(err: &git::Error) -> bool {
+    match err {
+        git::Error::Command { std_err, .. } => is_transient_message(std_err),
+        git::Error::ExecCommand { .. } => false,
+    }
+}
+
+/// Whether git failed with this message for a reason which may not happen again.
+fn is_transient_message(std_err: &str) -> bool {
+    let std_err = std_err.to_lowercase();
+    TRANSIENT_ERRORS
+        .iter()
+        .any(|message| std_err.contains(message))
+}
+
+#[cfg(test)]
+mod test {
+    use super::is_transient_message;
+
+    #[test]
+    fn detects_transient_failures() {
+        for std_err in [
+            "fatal: unable to access 'https://github.com/tabler/tabler-icons/': Could not resolve host: github.com",
+            "error: RPC failed; curl 56 GnuTLS recv error (-9): Error decoding the received TLS packet.\nfatal: early EOF",
+            "fatal: unable to access 'https://github.com/twbs/icons/': The requested URL returned error: 502",
+        ] {
+            assert!(is_transient_message(std_err), "{std_err}");
+        }
+        for std_err in [
+            "warning: Could not find remote branch 0123abc to clone.\nfatal: Remote branch 0123abc not found in upstream origin",
+            "fatal: repository 'https://github.com/twbs/iconz/' not found",
+            "fatal: destination path 'downloads/bootstrap' already exists and is not an empty directory.",
+        ] {
+            assert!(!is_transient_message(std_err), "{std_err}");
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! Collections of the Iconify icon sets, each generated as a package of its own.
+//!
+//! The iconify/icon-sets repository holds every collection as one JSON file like `json/mdi.json`,
+//! storing the markup of each icon without its `<svg>` element, next to the view box shared by the
+//! collection. The icons are read from that file instead of walking a directory of svg files.
+//! Registering another collection only takes an entry in [`COLLECTIONS`].
+
+use std::{borrow::Cow, collections::BTreeMap, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use tracing::{instrument, trace, warn};
+
+use crate::{
+    failures::ParseFailure,
+    icon::{
+        self,
+        svg::{self, ColorMode},
+        SvgIcon,
+    },
+    package::{Package, PackageMetadata, PackageType, Unknown},
+    sem_ver::SemVer,
+};
+
+/// A collection of the iconify/icon-sets repository generated as a package.
+#[derive(Debug)]
+pub(crate) struct Collection {
+    /// Prefix of the collection in Iconify, naming its JSON file, e.g. "mdi" for `json/mdi.json`.
+    pub prefix: &'static str,
+    pub short_name: &'static str,
+    pub package_name: &'static str,
+    pub licenses: &'static [Cow<'static, str>],
+    pub crate_version: SemVer,
+    /// See [`PackageMetadata::opt_in`].
+    pub opt_in: bool,
+}
+
+pub(crate) const COLLECTIONS: &[Collection] = &[
+    Collection {
+        prefix: "mdi",
+        short_name: "md",
+        package_name: "Material Design Icons",
+        licenses: &[Cow::Borrowed("Apache-2.0")],
+        crate_version: SemVer {
+            major: 0,
+            minor: 0,
+            patch: 1,
+            prerelease: None,
+            build: None,
+        },
+        // Over 7000 icons.
+        opt_in: true,
+    },
+    Collection {
+        prefix: "mingcute",
+        short_name: "mg",
+        package_name: "MingCute",
+        licenses: &[Cow::Borrowed("Apache-2.0")],
+        crate_version: SemVer {
+            major: 0,
+            minor: 0,
+            patch: 1,
+            prerelease: None,
+            build: None,
+        },
+        opt_in: true,
+    },
+];
+
+impl Collection {
+    /// Metadata of the package of this collection, based on the one of [`PackageType::Iconify`].
+    pub fn metadata(&self) -> PackageMetadata {
+        let iconify = PackageType::Iconify.metadata();
+        PackageMetadata {
+            short_name: Cow::Borrowed(self.short_name),
+            package_name: Cow::Borrowed(self.package_name),
+            licenses: self.licenses,
+            // Packages are downloaded concurrently, so every collection checks out the repository
+            // on its own.
+            download_dir: Cow::Owned(format!("{}-{}", iconify.download_dir, self.prefix)),
+            svg_dir: Cow::Owned(format!("{}/{}.json", iconify.svg_dir, self.prefix)),
+            crate_version: self.crate_version.clone(),
+            opt_in: self.opt_in,
+            ..iconify
+        }
+    }
+}
+
+/// Contents of the JSON file of a collection, see
+/// <https://iconify.design/docs/types/iconify-json.html>.
+#[derive(Debug, Deserialize)]
+struct IconSet {
+    #[serde(default)]
+    info: IconSetInfo,
+    icons: BTreeMap<String, IconifyIcon>,
+    /// Other names of icons, possibly flipped or rotated.
+    #[serde(default)]
+    aliases: BTreeMap<String, Alias>,
+    #[serde(flatten)]
+    dimensions: Dimensions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct IconSetInfo {
+    /// Whether the icons are drawn in their own colors rather than in the color of the text.
+    #[serde(default)]
+    palette: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct IconifyIcon {
+    /// Markup of the icon without its `<svg>` element.
+    body: String,
+    /// Deprecated icons, kept by Iconify for backwards compatibility.
+    #[serde(default)]
+    hidden: bool,
+    #[serde(flatten)]
+    dimensions: Dimensions,
+}
+
+#[derive(Debug, Deserialize)]
+struct Alias {
+    parent: String,
+    /// Transformations and dimensions differing from the parent, if any.
+    #[serde(flatten)]
+    overrides: BTreeMap<String, serde_json::Value>,
+}
+
+/// View box of an icon, or the default of all icons of the collection.
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+struct Dimensions {
+    left: Option<f64>,
+    top: Option<f64>,
+    width: Option<f64>,
+    height: Option<f64>,
+}
+
+impl IconSet {
+    /// Markup of the given icon as a standalone svg.
+    fn svg(&self, icon: &IconifyIcon) -> String {
+        let dimension = |of: fn(&Dimensions) -> Option<f64>, default: f64| {
+            of(&icon.dimensions)
+                .or(of(&self.dimensions))
+                .unwrap_or(default)
+        };
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">{}</svg>"#,
+            dimension(|it| it.left, 0.0),
+            dimension(|it| it.top, 0.0),
+            dimension(|it| it.width, 16.0),
+            dimension(|it| it.height, 16.0),
+            icon.body
+        )
+    }
+
+    /// Names of the aliases of every icon showing it unchanged, following aliases of aliases.
+    fn aliases(&self) -> BTreeMap<&str, Vec<&str>> {
+        let mut aliases = BTreeMap::<_, Vec<_>>::new();
+        for name in self.aliases.keys() {
+            let mut alias = &self.aliases[name];
+            // Bounded, in case aliases refer to each other.
+            for _ in 0..self.aliases.len() {
+                if !alias.overrides.is_empty() {
+                    break;
+                }
+                if self.icons.contains_key(&alias.parent) {
+                    aliases
+                        .entry(alias.parent.as_str())
+                        .or_default()
+                        .push(name.as_str());
+                    break;
+                }
+                match self.aliases.get(&alias.parent) {
+                    Some(parent) => alias = parent,
+                    None => break,
+                }
+            }
+        }
+        aliases
+    }
+}
+
+/// Read the icons of the collection of the given package from its JSON file, skipping hidden
+/// icons. Aliases showing an icon unchanged become deprecated aliases of the icon. Icons which
+/// cannot be read are skipped as well and returned as failures.
+#[instrument(level = "info", skip(package), fields(package = package.meta.short_name.as_ref()))]
+pub(crate) async fn read_icons(
+    package: &Package<Unknown>,
+    download_path: &Path,
+) -> Result<(Vec<SvgIcon>, Vec<ParseFailure>)> {
+    trace!("Reading icon data...");
+    let json_path = download_path.join(package.meta.svg_dir.as_ref());
+    let json = tokio::fs::read_to_string(&json_path)
+        .await
+        .with_context(|| format!("Could not read Iconify collection {json_path:?}"))?;
+    let icon_set: IconSet = serde_json::from_str(&json)
+        .with_context(|| format!("Could not parse Iconify collection {json_path:?}"))?;
+
+    let mode = match icon_set.info.palette {
+        true => ColorMode::Colored,
+        false => ColorMode::Monochrome,
+    };
+    let aliases = icon_set.aliases();
+    svg::with_interner(|| {
+        let mut icons = Vec::new();
+        let mut failures = Vec::new();
+        for (name, icon) in icon_set.icons.iter().filter(|(_, icon)| !icon.hidden) {
+            let source_path = format!("{}#{name}", package.meta.svg_dir);
+            match SvgIcon::from_svg(
+                package,
+                name,
+                &icon_set.svg(icon),
+                mode,
+                source_path.clone(),
+                None,
+                Vec::new(),
+            ) {
+                Ok(mut icon) => {
+                    icon.aliases = aliases
+                        .get(name.as_str())
+                        .into_iter()
+                        .flatten()
+                        .map(|alias| icon::feature_name(alias, None, &[], package.name_prefix()))
+                        .collect();
+                    icons.push(icon);
+                }
+                Err(err) => {
+                    warn!(name, ?err, "Could not read icon, skipping it.");
+                    failures.push(ParseFailure::new(
+                        &package.meta.short_name,
+                        source_path,
+                        &err,
+                    ));
+                }
+            }
+        }
+        Ok((icons, failures))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::IconSet;
+
+    const ICON_SET: &str = r#"{
+        "prefix": "mdi",
+        "info": { "name": "Material Design Icons" },
+        "icons": {
+            "home": { "body": "<path d=\"M10 20v-6h4v6\"/>" },
+            "wide": { "body": "<path d=\"M0 0h32v24\"/>", "width": 32 }
+        },
+        "aliases": {
+            "house": { "parent": "home" },
+            "building": { "parent": "house" },
+            "home-flipped": { "parent": "home", "hFlip": true }
+        },
+        "width": 24,
+        "height": 24
+    }"#;
+
+    #[test]
+    fn reads_icon_sets() {
+        let icon_set: IconSet = serde_json::from_str(ICON_SET).unwrap();
+        assert_eq!(
+            icon_set.svg(&icon_set.icons["home"]),
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><path d="M10 20v-6h4v6"/></svg>"#
+        );
+        assert!(icon_set
+            .svg(&icon_set.icons["wide"])
+            .contains(r#"viewBox="0 0 32 24""#));
+        assert_eq!(
+            icon_set.aliases().get("home"),
+            Some(&vec!["building", "house"])
+        );
+        assert!(!icon_set.info.palette);
+    }
+}
@@ -0,0 +1,162 @@
+//! Finding the svg files of a package after its upstream repository was reorganized.
+//!
+//! A package expects its svg files below its `svg_dir`. Reading a directory which no longer exists
+//! or no longer holds svg files would generate a crate without icons, so the directory is checked
+//! first: directories known from older upstream versions are tried next, and if none of them holds
+//! svg files either, reading fails with the directories which do, to update the configuration
+//! with.
+
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use tracing::{info, warn};
+
+use super::{Error, GitTarget, LayoutChangedSnafu, Package, PackageSource, PackageType, Unknown};
+use crate::sem_ver::SemVer;
+
+/// Number of directories holding svg files listed when the layout changed.
+const NUM_SUGGESTIONS: usize = 5;
+
+/// Directory upstream kept the svg files of a package in up to some version.
+#[derive(Debug)]
+struct FormerLayout {
+    /// Last upstream version with this layout.
+    until: SemVer,
+    /// Directory of the svg files, relative to the repository.
+    svg_dir: &'static str,
+}
+
+impl PackageType {
+    /// Former layouts of the upstream repository, newest first.
+    fn former_layouts(&self) -> &'static [FormerLayout] {
+        match self {
+            PackageType::FontAwesome => &[FormerLayout {
+                until: SemVer {
+                    major: 5,
+                    minor: 0,
+                    patch: 13,
+                    prerelease: None,
+                    build: None,
+                },
+                svg_dir: "advanced-options/raw-svg",
+            }],
+            _ => &[],
+        }
+    }
+}
+
+/// The directory the svg files of the package are read from: its `svg_dir`, or the directory of a
+/// former layout matching the upstream version, if only that one holds svg files.
+pub(super) fn svg_dir(package: &Package<Unknown>, download_path: &Path) -> Result<PathBuf, Error> {
+    let svg_dir = download_path.join(package.meta.svg_dir.as_ref());
+    if has_svg_files(&svg_dir) {
+        return Ok(svg_dir);
+    }
+
+    let version = match &package.meta.source {
+        PackageSource::Git {
+            target: GitTarget::Tag { version, .. },
+            ..
+        } => Some(version.clone()),
+        PackageSource::Git {
+            target: GitTarget::Branch { version_hint, .. },
+            ..
+        } => version_hint.clone(),
+        PackageSource::NpmTarball { version, .. } => SemVer::from_str(version).ok(),
+        PackageSource::LocalPath { .. } => None,
+    };
+    // Layouts matching the version come first. Newer versions may still use a former layout if
+    // upstream moved the files after the version known to this crate, so they are tried as well.
+    let mut layouts = package.ty.former_layouts().iter().collect::<Vec<_>>();
+    layouts.sort_by_key(|layout| {
+        version
+            .as_ref()
+            .is_some_and(|version| layout.until.is_lower_than(version))
+    });
+    for layout in layouts {
+        let former_dir = download_path.join(layout.svg_dir);
+        if has_svg_files(&former_dir) {
+            info!(
+                package = package.meta.short_name.as_ref(),
+                svg_dir = layout.svg_dir,
+                "Reading svg files from the directory of a former upstream layout."
+            );
+            return Ok(former_dir);
+        }
+    }
+
+    let suggestions = svg_dirs(download_path)
+        .iter()
+        .take(NUM_SUGGESTIONS)
+        .map(|dir| format!("{:?}", dir.strip_prefix(download_path).unwrap_or(dir)))
+        .collect::<Vec<_>>();
+    warn!(
+        package = package.meta.short_name.as_ref(),
+        svg_dir = package.meta.svg_dir.as_ref(),
+        ?suggestions,
+        "Upstream layout changed."
+    );
+    LayoutChangedSnafu {
+        short_name: package.meta.short_name.to_string(),
+        svg_dir: package.meta.svg_dir.to_string(),
+        suggestions: match suggestions.is_empty() {
+            true => "no directory holds svg files".to_owned(),
+            false => format!("svg files were found in {}", suggestions.join(", ")),
+        },
+    }
+    .fail()
+}
+
+/// Whether `dir` or any directory below it holds an svg file.
+fn has_svg_files(dir: &Path) -> bool {
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if is_svg(&path) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// The directories below `dir` directly holding svg files, those holding most first, skipping
+/// hidden directories like `.git`.
+fn svg_dirs(dir: &Path) -> Vec<PathBuf> {
+    let mut svg_dirs = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        let mut num_svgs = 0;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            if path.is_dir() {
+                dirs.push(path);
+            } else if is_svg(&path) {
+                num_svgs += 1;
+            }
+        }
+        if num_svgs > 0 {
+            svg_dirs.push((num_svgs, dir));
+        }
+    }
+    svg_dirs.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    svg_dirs.into_iter().map(|(_, dir)| dir).collect()
+}
+
+fn is_svg(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension == "svg")
+}
@@ -0,0 +1,152 @@
+//! Packages of icons of your own, read from a directory on this machine rather than downloaded.
+//!
+//! Every local package is configured by a JSON file passed with `--local-package`, e.g.
+//!
+//! ```json
+//! {
+//!     "short_name": "custom",
+//!     "package_name": "Acme Icons",
+//!     "prefix": "Acme",
+//!     "path": "../acme-icons/svg",
+//!     "licenses": ["Proprietary"],
+//!     "categories": { "filled": "fill", "deprecated": null }
+//! }
+//! ```
+//!
+//! generating the `icondata_custom` crate with icons like `AcmeHome`.
+
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{ensure, Context, Result};
+use serde::Deserialize;
+
+use crate::{
+    package::{
+        CategoryRenames, IconFilter, Package, PackageMetadata, PackageSource, PackageType, Unknown,
+    },
+    sem_ver::SemVer,
+};
+
+/// Configuration of a local package.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LocalPackage {
+    /// Suffix of the crate, e.g. "custom" for `icondata_custom`.
+    short_name: String,
+    /// Full human readable name of the icons.
+    package_name: String,
+    /// Prefix of the icon names, e.g. "Acme" for `AcmeHome`. Defaults to the short name.
+    #[serde(default)]
+    prefix: Option<String>,
+    /// Directory of the svg files, relative to the configuration file.
+    path: PathBuf,
+    #[serde(default)]
+    licenses: Vec<String>,
+    /// Url of the repository holding the directory, if any, linked as source of the icons.
+    #[serde(default)]
+    repository: Option<String>,
+    /// Categories of the icons in subdirectories, by the name of the subdirectory. `null` ignores
+    /// the directory as category. Other directories are categories named like them.
+    #[serde(default)]
+    categories: BTreeMap<String, Option<String>>,
+    /// Version of the generated crate, "0.0.1" by default.
+    #[serde(default)]
+    crate_version: Option<String>,
+    /// See [`PackageMetadata::opt_in`].
+    #[serde(default)]
+    opt_in: bool,
+}
+
+impl Package<Unknown> {
+    /// The local package configured by the JSON file at `config_path`.
+    pub fn local(config_path: &Path) -> Result<Package<Unknown>> {
+        let config = std::fs::read_to_string(config_path)
+            .with_context(|| format!("Could not read local package {config_path:?}"))?;
+        let config: LocalPackage = serde_json::from_str(&config)
+            .with_context(|| format!("Could not parse local package {config_path:?}"))?;
+        ensure!(
+            !config.short_name.is_empty()
+                && config
+                    .short_name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()),
+            "The short name of local package {config_path:?} must only consist of lowercase ascii letters and digits."
+        );
+        let crate_version = match &config.crate_version {
+            Some(version) => version
+                .parse()
+                .with_context(|| format!("Invalid crate version {version:?}"))?,
+            None => SemVer {
+                major: 0,
+                minor: 0,
+                patch: 1,
+                prerelease: None,
+                build: None,
+            },
+        };
+        let path = config_path
+            .parent()
+            .unwrap_or(Path::new(""))
+            .join(&config.path);
+
+        let local = PackageType::Local.metadata();
+        Ok(Package {
+            ty: PackageType::Local,
+            meta: PackageMetadata {
+                short_name: Cow::Owned(config.short_name),
+                package_name: Cow::Owned(config.package_name),
+                // Leaked, as the metadata of packages lives for the whole build.
+                licenses: Vec::leak(config.licenses.into_iter().map(Cow::Owned).collect()),
+                source: PackageSource::LocalPath {
+                    path,
+                    repository: config.repository,
+                    prefix: config.prefix,
+                    categories: config.categories,
+                },
+                crate_version,
+                opt_in: config.opt_in,
+                ..local
+            },
+            icons: Vec::new(),
+            revision: None,
+            filter: IconFilter::default(),
+            category_renames: CategoryRenames::default(),
+            unnecessary_overrides: Vec::new(),
+            parse_failures: Vec::new(),
+            phantom_data: PhantomData {},
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LocalPackage;
+
+    #[test]
+    fn parses_configuration() {
+        let config: LocalPackage = serde_json::from_str(
+            r#"{
+                "short_name": "custom",
+                "package_name": "Acme Icons",
+                "prefix": "Acme",
+                "path": "svg",
+                "categories": { "filled": "fill", "deprecated": null }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(config.prefix.as_deref(), Some("Acme"));
+        assert_eq!(config.categories["filled"].as_deref(), Some("fill"));
+        assert_eq!(config.categories["deprecated"], None);
+        assert!(config.licenses.is_empty() && !config.opt_in);
+
+        assert!(serde_json::from_str::<LocalPackage>(
+            r#"{ "short_name": "custom", "package_name": "Acme Icons", "path": "svg", "prefx": "Acme" }"#
+        )
+        .is_err());
+    }
+}
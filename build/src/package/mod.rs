@@ -1,16 +1,34 @@
-use anyhow::Result;
+use anyhow::{bail, ensure, Result};
+use heck::ToPascalCase;
 use snafu::{prelude::*, Backtrace};
-use std::{borrow::Cow, marker::PhantomData, path::PathBuf};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    marker::PhantomData,
+    path::PathBuf,
+};
 use strum::{EnumIter, IntoEnumIterator};
 use tracing::{info, instrument};
 
-use crate::{git, icon::SvgIcon, path, sem_ver::SemVer};
+use crate::{
+    bundle,
+    duplicates::{DuplicateNames, DuplicatePolicy, Resolution},
+    failures::ParseFailure,
+    git,
+    icon::{self, Category, IconSize, SvgIcon},
+    overrides::{NameOverrides, UnnecessaryOverride},
+    path, progress,
+    sem_ver::SemVer,
+};
 
+mod brand_colors;
+mod download;
+pub(crate) mod iconify;
+mod layout;
+mod local;
+mod npm;
 mod reader;
 
-/// Name of the directory, relative to the root of this crate, to which all icon packages should be downloaded.
-const DOWNLOAD_DIR: &str = "downloads";
-
 #[derive(Debug, Snafu)]
 pub(crate) enum Error {
     #[snafu(display("Could not perform 'git checkout' {path:?}"))]
@@ -28,6 +46,13 @@ pub(crate) enum Error {
         path: PathBuf,
         backtrace: Backtrace,
     },
+    #[snafu(display("Could not perform 'git fetch' {path:?}"))]
+    GitFetch {
+        source: git::Error,
+        target: GitTarget,
+        path: PathBuf,
+        backtrace: Backtrace,
+    },
     #[snafu(display("Could not perform 'git clone --no-checkout' {path:?}"))]
     GitCloneWithoutCheckout {
         source: git::Error,
@@ -35,6 +60,26 @@ pub(crate) enum Error {
         path: PathBuf,
         backtrace: Backtrace,
     },
+    #[snafu(display(
+        "Upstream layout of package {short_name:?} changed: no svg files below {svg_dir:?}, {suggestions}. Set `svg_dir` of [package.{short_name}] in icondata.toml, or update the metadata of the package."
+    ))]
+    LayoutChanged {
+        short_name: String,
+        svg_dir: String,
+        /// Directories holding svg files instead.
+        suggestions: String,
+        backtrace: Backtrace,
+    },
+    #[snafu(display(
+        "Vendored package {short_name:?} at {path:?} does not match the lockfile: expected {expected}, found {actual}."
+    ))]
+    VendoredChecksum {
+        short_name: String,
+        path: PathBuf,
+        expected: String,
+        actual: String,
+        backtrace: Backtrace,
+    },
     #[snafu(display("Could not read icon data from {path:?}"))]
     IconRead {
         source: anyhow::Error,
@@ -48,6 +93,16 @@ pub struct Package<S> {
     pub ty: PackageType,
     pub meta: PackageMetadata,
     icons: Vec<SvgIcon>,
+    /// Commit the icons were read from. Only known once downloaded.
+    revision: Option<String>,
+    /// Icons read from the package, see [`Package::with_filter`].
+    filter: IconFilter,
+    /// Categories of the icons renamed, see [`Package::with_category_renames`].
+    category_renames: CategoryRenames,
+    /// Overrides of icon names which did not rename an icon. Only known once downloaded.
+    unnecessary_overrides: Vec<UnnecessaryOverride>,
+    /// Icons which could not be read and were left out. Only known once downloaded.
+    parse_failures: Vec<ParseFailure>,
     phantom_data: PhantomData<S>,
 }
 
@@ -61,7 +116,66 @@ pub struct Downloaded {}
 
 impl<S> Package<S> {
     pub fn download_path(&self) -> PathBuf {
-        path::build_crate(DOWNLOAD_DIR).join(self.meta.download_dir.as_ref())
+        match &self.meta.source {
+            PackageSource::Git { .. } | PackageSource::NpmTarball { .. } => {
+                path::download_dir().join(self.meta.download_dir.as_ref())
+            }
+            PackageSource::LocalPath { path, .. } => path.clone(),
+        }
+    }
+
+    /// Directory the package is read from: its download directory, or its directory in the
+    /// vendor directory if one is configured, see [`SourceConfig::vendor_dir`].
+    pub fn source_path(&self, sources: &SourceConfig) -> PathBuf {
+        match &sources.vendor_dir {
+            Some(vendor_dir) if !self.meta.source.is_local() => {
+                vendor_dir.join(self.meta.download_dir.as_ref())
+            }
+            _ => self.download_path(),
+        }
+    }
+
+    /// Icons read from the package, see [`Package::with_filter`].
+    pub fn filter(&self) -> &IconFilter {
+        &self.filter
+    }
+
+    /// Categories of the icons renamed, see [`Package::with_category_renames`].
+    pub fn category_renames(&self) -> &CategoryRenames {
+        &self.category_renames
+    }
+
+    /// Whether this is an older version of its package, see [`PackageMetadata::pinned_to`].
+    pub fn is_pinned(&self) -> bool {
+        match self.ty {
+            // Iconify collections and local packages are named after themselves, not after their
+            // package type.
+            PackageType::Iconify | PackageType::Local => false,
+            ty => self.meta.short_name != ty.metadata().short_name,
+        }
+    }
+
+    /// Prefix of the names of the icons of this package, e.g. "Fa" for `FaHouse`: the short name
+    /// unless a local package configures its own.
+    pub fn name_prefix(&self) -> &str {
+        match &self.meta.source {
+            PackageSource::LocalPath {
+                prefix: Some(prefix),
+                ..
+            } => prefix.as_str(),
+            _ => self.meta.short_name.as_ref(),
+        }
+    }
+
+    /// The category of this package a directory represents, if any. Local packages map
+    /// directories as configured, see [`PackageType::category`] for all others.
+    pub fn category(&self, dir_name: &str) -> Option<Category> {
+        if let PackageSource::LocalPath { categories, .. } = &self.meta.source {
+            if let Some(category) = categories.get(dir_name) {
+                return category.clone().map(Category);
+            }
+        }
+        self.ty.category(dir_name)
     }
 }
 
@@ -70,19 +184,103 @@ impl Package<Unknown> {
         Package::<Unknown>::of_types(PackageType::iter())
     }
 
+    /// Packages of the given types, each followed by its pinned older versions.
+    /// [`PackageType::Iconify`] stands for one package per collection in [`iconify::COLLECTIONS`],
+    /// local packages are configured separately, see [`Package::local`].
     fn of_types<I: Iterator<Item = PackageType>>(types: I) -> Vec<Package<Unknown>> {
         types
-            .map(|ty| Package::<Unknown> {
-                ty,
-                meta: ty.metadata(),
-                icons: Vec::new(),
-                phantom_data: PhantomData {},
+            .flat_map(|ty| {
+                let metas = match ty {
+                    PackageType::Iconify => iconify::COLLECTIONS
+                        .iter()
+                        .map(iconify::Collection::metadata)
+                        .collect::<Vec<_>>(),
+                    PackageType::Local => Vec::new(),
+                    _ => {
+                        let meta = ty.metadata();
+                        let pinned = meta
+                            .pinned
+                            .iter()
+                            .map(|pin| meta.pinned_to(pin))
+                            .collect::<Vec<_>>();
+                        std::iter::once(meta).chain(pinned).collect()
+                    }
+                };
+                metas.into_iter().map(move |meta| Package::<Unknown> {
+                    ty,
+                    meta,
+                    icons: Vec::new(),
+                    revision: None,
+                    filter: IconFilter::default(),
+                    category_renames: CategoryRenames::default(),
+                    unnecessary_overrides: Vec::new(),
+                    parse_failures: Vec::new(),
+                    phantom_data: PhantomData {},
+                })
             })
             .collect()
     }
 
+    /// Check out the given upstream ref instead of the one this package is defined with.
+    pub fn with_target(mut self, target: GitTarget) -> Result<Self> {
+        match &mut self.meta.source {
+            PackageSource::Git {
+                target: current, ..
+            } => *current = target,
+            PackageSource::NpmTarball { name, .. } => {
+                bail!("Package downloaded from npm as {name:?} has no git ref to override.")
+            }
+            PackageSource::LocalPath { path, .. } => {
+                bail!("Local package {path:?} has no upstream ref to override.")
+            }
+        }
+        Ok(self)
+    }
+
+    /// Download the tarball of the given version of an npm package instead of cloning the
+    /// repository of this package, e.g. as it is much smaller.
+    pub fn with_npm_tarball(mut self, name: String, version: String) -> Result<Self> {
+        let repository = match &self.meta.source {
+            PackageSource::Git { url, .. } => url.clone(),
+            PackageSource::NpmTarball { repository, .. } => repository.clone(),
+            PackageSource::LocalPath { path, .. } => {
+                bail!("Local package {path:?} cannot be downloaded from npm.")
+            }
+        };
+        self.meta.source = PackageSource::NpmTarball {
+            name,
+            version,
+            repository,
+        };
+        Ok(self)
+    }
+
+    /// Read the svg files of this package from the given directory, relative to its repository,
+    /// e.g. after upstream moved them.
+    pub fn with_svg_dir(mut self, svg_dir: String) -> Self {
+        self.meta.svg_dir = Cow::Owned(svg_dir);
+        self
+    }
+
+    /// Only read the icons of this package included by the given filter.
+    pub fn with_filter(mut self, filter: IconFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Rename the categories of the icons of this package before they are named, e.g. to name the
+    /// same style alike across packages.
+    pub fn with_category_renames(mut self, category_renames: CategoryRenames) -> Self {
+        self.category_renames = category_renames;
+        self
+    }
+
     #[instrument(level = "info", fields(package = self.meta.package_name.as_ref()))]
     pub async fn remove(&self) -> Result<()> {
+        // The directory of a local package holds the icons themselves.
+        if let PackageSource::LocalPath { .. } = self.meta.source {
+            return Ok(());
+        }
         let download_path = self.download_path();
         if download_path.exists() {
             info!(?download_path, "Removing...");
@@ -91,59 +289,200 @@ impl Package<Unknown> {
         Ok(())
     }
 
-    #[instrument(level = "info", skip_all)]
-    pub(crate) async fn download(self) -> Result<Package<Downloaded>> {
+    /// Download the package and read its icons. With `keep_going`, svg files which cannot be parsed
+    /// are left out instead of failing, see [`Package::parse_failures`].
+    #[instrument(level = "info", skip_all, fields(package = self.meta.short_name.as_ref()))]
+    pub(crate) async fn download(
+        self,
+        sources: &SourceConfig,
+        keep_going: bool,
+    ) -> Result<Package<Downloaded>> {
+        let download_path = match &sources.vendor_dir {
+            Some(_) if !self.meta.source.is_local() => {
+                let vendored_path = self.source_path(sources);
+                info!(?vendored_path, "Reading vendored package.");
+                ensure!(
+                    vendored_path.exists(),
+                    "Vendored package {vendored_path:?} does not exist."
+                );
+                let checksum = sources.locked_checksums.get(self.meta.short_name.as_ref());
+                if let Some(checksum) = checksum {
+                    if !vendored_path.join(".git").exists() {
+                        download::verify_checksum(&self.meta.short_name, &vendored_path, checksum)
+                            .await?;
+                    }
+                }
+                vendored_path
+            }
+            _ => {
+                self.fetch(sources)?;
+                self.download_path()
+            }
+        };
+        progress::reading(&self.meta.short_name);
+
+        // Only commits can be linked to, so icons read from anything else have no source.
+        let revision = match &self.meta.source {
+            // Vendored packages are not necessarily git repositories.
+            PackageSource::Git { .. } => match download_path.join(".git").exists() {
+                true => Some(git::head_commit(&download_path)?),
+                false => None,
+            },
+            PackageSource::NpmTarball { .. } => None,
+            // Local directories may be part of a repository, but need not be, and can only be
+            // linked to if the url of their repository is configured.
+            PackageSource::LocalPath { repository, .. } => repository
+                .as_ref()
+                .and_then(|_| git::head_commit(&download_path).ok()),
+        };
+
+        let (mut icons, parse_failures) = match self.ty {
+            PackageType::Iconify => iconify::read_icons(&self, &download_path).await?,
+            _ => {
+                let icons_path = layout::svg_dir(&self, &download_path)?;
+                reader::read_icons(&self, &download_path, icons_path, keep_going).await?
+            }
+        };
+        if let Some(data_path) = self.ty.brand_colors_file() {
+            brand_colors::assign(&mut icons, &download_path, data_path).await?;
+        }
+        let unnecessary_overrides = NameOverrides::read(&self.meta.short_name)?.apply(&mut icons);
+        icon::naming::normalize_names(&mut icons, &self.name_prefix().to_pascal_case());
+        icons.sort_by(|a, b| a.name.cmp(&b.name));
+        icon::assign_name_styles(&mut icons);
+        progress::read(&self.meta.short_name, icons.len());
+
+        Ok(Package::<Downloaded> {
+            ty: self.ty,
+            meta: self.meta,
+            icons,
+            revision,
+            filter: self.filter,
+            category_renames: self.category_renames,
+            unnecessary_overrides,
+            parse_failures,
+            phantom_data: PhantomData {},
+        })
+    }
+
+    /// Whether the package is available without downloading it.
+    pub fn is_downloaded(&self) -> bool {
+        self.download_path().exists()
+    }
+
+    /// Fetch the ref of this package into its download directory and check it out, cloning the
+    /// repository if it was not downloaded yet. Unlike [`Package::download`], refs not fetched
+    /// before are checked out as well, and no icons are read.
+    #[instrument(level = "info", skip_all, fields(package = self.meta.package_name.as_ref()))]
+    pub(crate) fn update(&self, sources: &SourceConfig) -> Result<()> {
+        ensure!(!sources.offline, "Packages cannot be updated offline.");
+        let download_path = self.download_path();
+        if let PackageSource::Git { target, .. } = &self.meta.source {
+            if download_path.exists() {
+                info!(?download_path, "Fetching...");
+                download::fetch(target, &download_path, sources.proxy.as_deref())?;
+            }
+        }
+        self.fetch(sources)
+    }
+
+    /// Clone or update the git repository of this package in its download directory, or unpack its
+    /// npm tarball there.
+    fn fetch(&self, sources: &SourceConfig) -> Result<()> {
         let download_path = self.download_path();
         info!(?download_path, "Downloading...");
+        let _progress = progress::downloading(&self.meta.short_name, &download_path);
 
         match &self.meta.source {
             PackageSource::Git { url, target } => {
+                // The mirror first, falling back to upstream.
+                let urls = sources
+                    .mirrors
+                    .get(self.meta.short_name.as_ref())
+                    .map(String::as_str)
+                    .into_iter()
+                    .chain([url.as_ref()])
+                    .collect::<Vec<_>>();
+                let proxy = sources.proxy.as_deref();
                 if download_path.exists() {
                     info!(?download_path, "Directory exists. Assuming git repository.");
-                    git::checkout(target, &download_path).with_context(|_| GitCheckoutSnafu {
+                    let checkout = git::checkout(target, &download_path);
+                    ensure!(
+                        checkout.is_ok() || !sources.offline,
+                        "Package {:?} was downloaded to {download_path:?}, but not at {:?}, which cannot be fetched offline. Run `cargo run -- --package {} update` once without `--offline`.",
+                        self.meta.short_name,
+                        target.reference(),
+                        self.meta.short_name
+                    );
+                    checkout.with_context(|_| GitCheckoutSnafu {
                         target: target.clone(),
                         path: download_path.clone(),
                     })?;
                 } else {
+                    ensure!(
+                        !sources.offline,
+                        "Package {:?} was not downloaded yet, which is required offline.",
+                        self.meta.short_name
+                    );
                     info!(
                         ?download_path,
                         "Directory does not exist. Cloning the repository."
                     );
-                    git::clone(url, target, &download_path)
-                        .with_context(|_| GitCloneSnafu {
-                            url: url.clone(),
-                            target: target.clone(),
-                            path: download_path.clone(),
-                        })
-                        .or_else(|_err| {
-                            info!("Direct clone unsuccessful. Trying clone with checkout...");
-                            git::clone_without_checkout(url, &download_path).with_context(
-                                |_| GitCloneWithoutCheckoutSnafu {
-                                    url: url.clone(),
-                                    path: download_path.clone(),
-                                },
-                            )?;
-                            git::checkout(target, &download_path).with_context(|_| {
-                                GitCheckoutSnafu {
-                                    target: target.clone(),
-                                    path: download_path.clone(),
-                                }
-                            })
-                        })?;
+                    download::clone(&urls, target, &download_path, proxy)?;
+                }
+                let locked_revision = sources.locked_revisions.get(self.meta.short_name.as_ref());
+                if let Some(revision) = locked_revision {
+                    self.check_out_locked(revision, sources)?;
+                }
+            }
+            PackageSource::NpmTarball { name, version, .. } => {
+                if npm::is_unpacked(&download_path, version) {
+                    info!(?download_path, "Tarball was unpacked before.");
+                } else {
+                    ensure!(
+                        !sources.offline,
+                        "Package {:?} was not downloaded from npm at {version:?} yet, which is required offline. Run `cargo run -- --package {} update` once without `--offline`.",
+                        self.meta.short_name,
+                        self.meta.short_name
+                    );
+                    npm::download(name, version, &download_path, sources.proxy.as_deref())?;
                 }
             }
+            PackageSource::LocalPath { path, .. } => {
+                ensure!(path.is_dir(), "Local package {path:?} is no directory.");
+            }
         };
 
-        let icons_path = self.download_path().join(self.meta.svg_dir.as_ref());
-        let mut icons = reader::read_icons(&self, icons_path.clone()).await?;
-        icons.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(())
+    }
 
-        Ok(Package::<Downloaded> {
-            ty: self.ty,
-            meta: self.meta,
-            icons,
-            phantom_data: PhantomData {},
-        })
+    /// Check out the commit recorded in the lockfile instead of the one the ref of this package
+    /// points to now, fetching it if needed, see [`SourceConfig::locked_revisions`].
+    fn check_out_locked(&self, revision: &str, sources: &SourceConfig) -> Result<()> {
+        let download_path = self.download_path();
+        if git::head_commit(&download_path)? == revision {
+            return Ok(());
+        }
+        info!(revision, "Checking out the locked commit.");
+        let locked = GitTarget::Branch {
+            name: Cow::Borrowed("locked"),
+            commit_ref: Cow::Owned(revision.to_owned()),
+            version_hint: None,
+        };
+        if git::checkout(&locked, &download_path).is_ok() {
+            return Ok(());
+        }
+        ensure!(
+            !sources.offline,
+            "Locked commit {revision} of package {:?} was not downloaded yet, which is required offline.",
+            self.meta.short_name
+        );
+        download::fetch(&locked, &download_path, sources.proxy.as_deref())?;
+        git::checkout(&locked, &download_path).with_context(|_| GitCheckoutSnafu {
+            target: locked.clone(),
+            path: download_path.clone(),
+        })?;
+        Ok(())
     }
 }
 
@@ -151,6 +490,126 @@ impl Package<Downloaded> {
     pub fn icons(&self) -> &[SvgIcon] {
         &self.icons
     }
+
+    /// Commit of the upstream repository the icons were read from. `None` if they were not read
+    /// from a git checkout, like the icons of npm packages, which then have no source to link to.
+    pub fn revision(&self) -> Option<&str> {
+        self.revision.as_deref()
+    }
+
+    /// Overrides of icon names which did not rename an icon, see [`NameOverrides::apply`].
+    pub fn unnecessary_overrides(&self) -> &[UnnecessaryOverride] {
+        &self.unnecessary_overrides
+    }
+
+    /// Icons which could not be read and were left out, see [`Package::download`].
+    pub fn parse_failures(&self) -> &[ParseFailure] {
+        &self.parse_failures
+    }
+
+    /// Drop the svg content of all icons, keeping everything needed to generate the main library
+    /// and the icon index. Icons of bundles are kept, as bundles are generated last.
+    pub fn release_svgs(&mut self) {
+        for icon in &mut self.icons {
+            if !bundle::is_bundled(&icon.name) {
+                icon.svg.release_content();
+            }
+        }
+    }
+
+    /// Whether this package contains brand or logo icons.
+    pub fn has_brands(&self) -> bool {
+        self.icons.iter().any(|icon| icon.brand)
+    }
+
+    /// Simplify the paths of all icons in this package, see [`SvgIcon::simplify`].
+    pub fn simplify(&mut self, tolerance: f64) {
+        for icon in &mut self.icons {
+            icon.simplify(tolerance);
+        }
+    }
+
+    /// Add a variant snapped to the pixel grid at a size of `pixels` to every icon designed for a
+    /// larger size, see [`SvgIcon::aligned`].
+    pub fn add_aligned_variants(&mut self, pixels: u32) {
+        let aligned = self
+            .icons
+            .iter()
+            .filter_map(|icon| icon.aligned(pixels))
+            .collect::<Vec<_>>();
+        self.icons.extend(aligned);
+        self.icons.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    /// Add a badge variant, knocked out of a filled rounded square, to every single-colored icon,
+    /// see [`SvgIcon::badge`]. Variants whose name is taken by an upstream icon are left out.
+    pub fn add_badge_variants(&mut self) {
+        let badges = self
+            .icons
+            .iter()
+            .filter_map(SvgIcon::badge)
+            .filter(|badge| !self.icons.iter().any(|icon| icon.name == badge.name))
+            .collect::<Vec<_>>();
+        self.icons.extend(badges);
+        self.icons.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    /// Expand the strokes of all icons into filled outlines, see [`SvgIcon::expand_strokes`].
+    pub fn expand_strokes(&mut self) -> Result<()> {
+        for icon in &mut self.icons {
+            icon.expand_strokes()?;
+        }
+        Ok(())
+    }
+
+    /// Resolve the names of icons already declared by previously resolved packages according to
+    /// `policy`, see [`DuplicateNames::resolve`].
+    pub fn resolve_duplicates(
+        &mut self,
+        names: &mut DuplicateNames,
+        policy: DuplicatePolicy,
+    ) -> Result<()> {
+        let resolutions = names.resolve(
+            &self.meta.short_name,
+            &self
+                .icons
+                .iter()
+                .map(|icon| icon.name.as_str())
+                .collect::<Vec<_>>(),
+            self.icons
+                .iter()
+                .flat_map(|icon| icon.aliases.iter().map(String::as_str)),
+            policy,
+        )?;
+        let mut resolutions = resolutions.into_iter();
+        self.icons.retain_mut(
+            |icon| match resolutions.next().unwrap_or(Resolution::Keep) {
+                Resolution::Keep => true,
+                Resolution::Rename(name) => {
+                    icon.name = name;
+                    true
+                }
+                Resolution::Skip => false,
+            },
+        );
+        self.icons.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(())
+    }
+
+    /// Drop all brand and logo icons from this package.
+    pub fn remove_brands(&mut self) {
+        self.icons.retain(|icon| !icon.brand);
+    }
+
+    /// All distinct styles of the icons in this package, sorted.
+    pub fn styles(&self) -> Vec<&str> {
+        self.icons
+            .iter()
+            .filter_map(|icon| icon.style.as_deref())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, EnumIter, Clone, Copy)]
@@ -159,6 +618,7 @@ pub enum PackageType {
     FontAwesome,
     WeatherIcons,
     Feather,
+    /// Codicons, the icons of VS Code, from microsoft/vscode-codicons.
     VSCodeIcons,
     BootstrapIcons,
     BoxIcons,
@@ -173,8 +633,24 @@ pub enum PackageType {
     GithubOcticons,
     Lucide,
     Charm,
+    Phosphor,
+    MaterialSymbols,
+    CarbonIcons,
+    Iconoir,
+    /// Country flags from lipis/flag-icons, drawn in their own colors.
+    FlagIcons,
+    /// The icons of game-icons.net, too many to be part of the main library by default.
+    GameIcons,
+    /// Collections of the Iconify icon sets, see [`iconify::COLLECTIONS`].
+    Iconify,
+    /// Icons of your own, read from a directory on this machine, see [`Package::local`].
+    Local,
 }
 
+/// Weights of Material Symbols generated, out of 100 to 700. Every weight but the default 400
+/// adds a variant of every icon named like `MsRoundedHomeWeight300`.
+const MATERIAL_SYMBOLS_WEIGHTS: &[u32] = &[400];
+
 #[derive(Debug, Clone)]
 pub struct PackageMetadata {
     /// Two-character identifier like "fa" for "Font Awesome".
@@ -187,9 +663,87 @@ pub struct PackageMetadata {
     pub source: PackageSource,
     /// Directory to which the source should be downloaded.
     pub download_dir: Cow<'static, str>,
-    /// Directory relative to download_dir under which raw SVG files can be found.
+    /// Directory relative to download_dir under which raw SVG files can be found. For Iconify
+    /// collections, the path of their JSON file instead.
     pub svg_dir: Cow<'static, str>, // TODO: PathBuf?
     pub crate_version: SemVer,
+    /// Older upstream versions generated side-by-side with this one.
+    pub pinned: &'static [PinnedVersion],
+    /// Expand the strokes of all icons into filled outlines, for consumers which cannot draw
+    /// strokes. Off by default, as outlines of complex strokes are larger and not always exact.
+    pub expand_strokes: bool,
+    /// Leave the package out of the main library unless its feature, named like its short name, is
+    /// enabled. For huge packages, which would slow down the compilation of every dependent of the
+    /// main library.
+    pub opt_in: bool,
+}
+
+impl PackageMetadata {
+    /// Metadata of the given older version of this package. Its crate, icon names and download
+    /// directory are distinguished by the version's suffix, e.g. "icondata_fa5" and "Fa5House".
+    pub fn pinned_to(&self, pin: &PinnedVersion) -> PackageMetadata {
+        let source = match &self.source {
+            PackageSource::Git { url, .. } => PackageSource::Git {
+                url: url.clone(),
+                target: pin.target.clone(),
+            },
+            // Older versions are still cloned, as they are configured by their git ref.
+            PackageSource::NpmTarball { repository, .. } => PackageSource::Git {
+                url: repository.clone(),
+                target: pin.target.clone(),
+            },
+            // The directory of a local package holds a single version.
+            PackageSource::LocalPath { .. } => self.source.clone(),
+        };
+        PackageMetadata {
+            short_name: Cow::Owned(format!("{}{}", self.short_name, pin.suffix)),
+            package_name: Cow::Owned(format!("{} {}", self.package_name, pin.suffix)),
+            licenses: pin.licenses.unwrap_or(self.licenses),
+            source,
+            download_dir: Cow::Owned(format!("{}-{}", self.download_dir, pin.suffix)),
+            svg_dir: pin.svg_dir.clone().unwrap_or_else(|| self.svg_dir.clone()),
+            crate_version: pin.crate_version.clone(),
+            pinned: &[],
+            expand_strokes: self.expand_strokes,
+            opt_in: self.opt_in,
+        }
+    }
+}
+
+/// An older upstream version of a package, e.g. Font Awesome 5 next to Font Awesome 6, allowing
+/// projects to upgrade incrementally.
+#[derive(Debug, Clone)]
+pub struct PinnedVersion {
+    /// Appended to the short name of the package, e.g. "5" for "fa5".
+    pub suffix: Cow<'static, str>,
+    pub target: GitTarget,
+    /// Licenses, if they differ from the current version.
+    pub licenses: Option<&'static [Cow<'static, str>]>,
+    /// Directory of the raw SVG files, if it differs from the current version.
+    pub svg_dir: Option<Cow<'static, str>>,
+    pub crate_version: SemVer,
+}
+
+/// Overrides of where packages are downloaded from, e.g. for networks blocking their upstream
+/// repositories.
+#[derive(Debug, Clone, Default)]
+pub struct SourceConfig {
+    /// HTTP(S) proxy used by git, e.g. "http://proxy.example.com:8080".
+    pub proxy: Option<String>,
+    /// Repository urls used instead of the upstream ones, keyed by package short name.
+    pub mirrors: BTreeMap<String, String>,
+    /// Directory already containing every package in its download directory, e.g. "font-awesome",
+    /// which is read instead of downloading anything.
+    pub vendor_dir: Option<PathBuf>,
+    /// Whether to only use packages downloaded before, never accessing the network.
+    pub offline: bool,
+    /// Commits checked out instead of the ones the refs of the packages point to, keyed by
+    /// package short name, as recorded in the lockfile for `--locked` builds.
+    pub locked_revisions: BTreeMap<String, String>,
+    /// Sha256 of the files of vendored packages which are no git repositories, keyed by package
+    /// short name, as recorded in the lockfile for `--locked` builds. Verified before their icons
+    /// are read.
+    pub locked_checksums: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone)]
@@ -198,6 +752,164 @@ pub enum PackageSource {
         url: Cow<'static, str>, // TODO use url type?
         target: GitTarget,
     },
+    /// The tarball of a version of an npm package, see [`Package::with_npm_tarball`].
+    NpmTarball {
+        /// Name of the package in the npm registry, e.g. "heroicons".
+        name: String,
+        /// Version of the package, e.g. "2.1.5".
+        version: String,
+        /// Url of the repository of the package, which is still linked in the documentation.
+        repository: Cow<'static, str>,
+    },
+    /// A directory of svg files on this machine, with the rules reading it, see [`Package::local`].
+    LocalPath {
+        path: PathBuf,
+        /// Url of the repository holding the directory, if any.
+        repository: Option<String>,
+        /// Prefix of the icon names, see [`Package::name_prefix`].
+        prefix: Option<String>,
+        /// Categories of subdirectories by their name, see [`Package::category`]. `None` ignores
+        /// the directory as category.
+        categories: BTreeMap<String, Option<String>>,
+    },
+}
+
+impl PackageSource {
+    /// Url of the repository the icons are taken from. Empty for local packages without one.
+    pub fn url(&self) -> &str {
+        match self {
+            PackageSource::Git { url, .. } => url,
+            PackageSource::NpmTarball { repository, .. } => repository,
+            PackageSource::LocalPath { repository, .. } => {
+                repository.as_deref().unwrap_or_default()
+            }
+        }
+    }
+
+    /// Whether the package is a directory on this machine, which is never downloaded.
+    pub fn is_local(&self) -> bool {
+        matches!(self, PackageSource::LocalPath { .. })
+    }
+
+    /// Url of the tarball of a package downloaded from npm.
+    pub fn tarball_url(&self) -> Option<String> {
+        match self {
+            PackageSource::NpmTarball { name, version, .. } => {
+                Some(npm::tarball_url(name, version))
+            }
+            PackageSource::Git { .. } | PackageSource::LocalPath { .. } => None,
+        }
+    }
+}
+
+/// Icons of a package to read, by the directories they were found in. Icons of Iconify
+/// collections are never filtered, as they are not stored in directories.
+#[derive(Debug, Clone, Default)]
+pub struct IconFilter {
+    /// Sizes of the icons to read. Icons of directories not naming a size are always read.
+    pub sizes: Option<Vec<IconSize>>,
+    /// Categories of the icons to read. Icons of directories not naming a category are always
+    /// read.
+    pub categories: Option<Vec<String>>,
+    /// Globs of the icons to read, see [`IconFilter::matches_globs`]. All icons by default.
+    pub include: Option<Vec<String>>,
+    /// Globs of the icons not to read, see [`IconFilter::matches_globs`].
+    pub exclude: Vec<String>,
+}
+
+impl IconFilter {
+    /// Whether to read the icon of the svg file at the given path, see
+    /// [`IconFilter::matches_globs`], of the given size and categories.
+    pub fn includes(
+        &self,
+        relative_path: &str,
+        size: Option<IconSize>,
+        categories: &[Category],
+    ) -> bool {
+        let size_included = match (&self.sizes, size) {
+            (Some(sizes), Some(size)) => sizes.contains(&size),
+            _ => true,
+        };
+        let categories_included = match &self.categories {
+            Some(included) if !categories.is_empty() => categories
+                .iter()
+                .any(|category| included.contains(&category.0)),
+            _ => true,
+        };
+        size_included && categories_included && self.matches_globs(relative_path)
+    }
+
+    /// Whether to read the icon of the svg file at the given path, relative to the svg directory of
+    /// the package and without extension, like "ui/home". It has to match one of the globs to
+    /// include, if any, and none of the globs to exclude. Globs containing a slash are matched
+    /// against the whole path, all others against the file name only. `*` matches any characters
+    /// but slashes, `**` any characters and `?` any single character but a slash.
+    fn matches_globs(&self, relative_path: &str) -> bool {
+        let file_name = relative_path.rsplit('/').next().unwrap_or(relative_path);
+        let matches = |glob: &String| match glob.contains('/') {
+            true => glob_matches(glob, relative_path),
+            false => glob_matches(glob, file_name),
+        };
+        let included = match &self.include {
+            Some(include) => include.iter().any(matches),
+            None => true,
+        };
+        included && !self.exclude.iter().any(matches)
+    }
+}
+
+/// Normalized names of the categories of a package by their upstream name, e.g. "two-tone" for
+/// "twotone". `None` drops a category, like the "ic" of Material Design icons, which every icon
+/// has. Icons are still filtered by their upstream categories, see [`IconFilter::categories`].
+#[derive(Debug, Clone, Default)]
+pub struct CategoryRenames(pub BTreeMap<String, Option<String>>);
+
+impl CategoryRenames {
+    /// The given categories of an icon renamed, dropping categories renamed to one it has already.
+    pub fn apply(&self, categories: Vec<Category>) -> Vec<Category> {
+        let mut renamed = Vec::with_capacity(categories.len());
+        for category in categories {
+            let category = match self.0.get(&category.0) {
+                Some(Some(name)) => Category(name.clone()),
+                Some(None) => continue,
+                None => category,
+            };
+            if !renamed.contains(&category) {
+                renamed.push(category);
+            }
+        }
+        renamed
+    }
+}
+
+fn glob_matches(glob: &str, text: &str) -> bool {
+    if let Some(rest) = glob.strip_prefix("**") {
+        // `**/` matches no directory as well.
+        if rest
+            .strip_prefix('/')
+            .is_some_and(|rest| glob_matches(rest, text))
+        {
+            return true;
+        }
+        return (0..=text.len())
+            .filter(|i| text.is_char_boundary(*i))
+            .any(|i| glob_matches(rest, &text[i..]));
+    }
+    let mut chars = glob.chars();
+    match chars.next() {
+        None => text.is_empty(),
+        Some('*') => (0..=text.len())
+            .filter(|i| text.is_char_boundary(*i))
+            .take_while(|i| !text[..*i].contains('/'))
+            .any(|i| glob_matches(chars.as_str(), &text[i..])),
+        Some('?') => text
+            .chars()
+            .next()
+            .is_some_and(|c| c != '/' && glob_matches(chars.as_str(), &text[c.len_utf8()..])),
+        Some(c) => text
+            .strip_prefix(c)
+            .is_some_and(|text| glob_matches(chars.as_str(), text)),
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -213,21 +925,100 @@ pub enum GitTarget {
     },
 }
 
+impl GitTarget {
+    /// The commit ref or tag name to check out.
+    pub fn reference(&self) -> &str {
+        match self {
+            GitTarget::Branch { commit_ref, .. } => commit_ref.as_ref(),
+            GitTarget::Tag { name, .. } => name.as_ref(),
+        }
+    }
+}
+
 impl PackageType {
-    /// Test whether a particular string represents a category of this icon package.
-    pub fn is_category(&self, str: &str) -> bool {
+    /// The category of this icon package a directory represents, if any.
+    pub fn category(&self, str: &str) -> Option<Category> {
         // We avoid using all-numeric directories as categories,
         // as they most likely state the size of the icons contained and not an actual category.
         if str.chars().all(char::is_numeric) {
-            return false;
+            return None;
         }
         match self {
             // SVG's located in the "logos" directory are distinct from files in the "regular" and "solid" directories. We may not use that as a category.
-            PackageType::BoxIcons => str != "logos",
+            PackageType::BoxIcons if str == "logos" => None,
+            // Icons designed at 32px are grouped into directories like "watson-health", which are
+            // not styles.
+            PackageType::CarbonIcons => None,
+            // Icons are grouped into one directory per author.
+            PackageType::GameIcons => None,
+            // Only the "regular" and "solid" directories hold icons, each carrying its style.
+            PackageType::Iconoir => ["regular", "solid"]
+                .contains(&str)
+                .then(|| Category(str.to_owned())),
+            // Every icon has a directory named like the icon, containing one directory per style,
+            // e.g. "home/materialsymbolsrounded".
+            PackageType::MaterialSymbols => str
+                .strip_prefix("materialsymbols")
+                .map(|style| Category(style.to_owned())),
+            _ => Some(Category(str.to_owned())),
+        }
+    }
+
+    /// Test whether an svg file of this package, given its file stem, is an icon to generate.
+    pub fn is_included(&self, file_stem: &str) -> bool {
+        match self {
+            // Every icon comes in all combinations of weight, grade, fill and optical size. Only
+            // the default grade at 24px and the weights in `MATERIAL_SYMBOLS_WEIGHTS` are kept.
+            PackageType::MaterialSymbols => {
+                let Some((_, variant, size)) = icon::material_symbols_variant(file_stem) else {
+                    return false;
+                };
+                size == 24
+                    && variant.grade.is_none()
+                    && MATERIAL_SYMBOLS_WEIGHTS.contains(&variant.weight.unwrap_or(400))
+            }
             _ => true,
         }
     }
 
+    /// The style given as name prefix of the icons of this package rather than appended to their
+    /// names, among the given categories of an icon.
+    pub fn prefix_style<'a>(&self, categories: &'a [Category]) -> Option<&'a Category> {
+        match self {
+            // Every icon comes in all three styles, named like `MsRoundedHome`.
+            PackageType::MaterialSymbols => categories
+                .iter()
+                .find(|category| ["outlined", "rounded", "sharp"].contains(&category.0.as_str())),
+            _ => None,
+        }
+    }
+
+    /// Test whether an icon of this package is a brand or logo, given its file stem and categories.
+    pub fn is_brand(&self, file_stem: &str, categories: &[Category]) -> bool {
+        match self {
+            PackageType::FontAwesome => categories.iter().any(|category| category.0 == "brands"),
+            // Logos are prefixed with 'bxl-', see `parse_raw_icon_name`.
+            PackageType::BoxIcons => file_stem.starts_with("bxl-"),
+            _ => false,
+        }
+    }
+
+    /// Whether the icons of this package are drawn in their own colors, like flags, rather than in
+    /// the color of the surrounding text, see
+    /// [`ColorMode::Colored`](crate::icon::svg::ColorMode::Colored).
+    pub fn is_colored(&self) -> bool {
+        matches!(self, PackageType::FlagIcons)
+    }
+
+    /// Path of the file listing the official colors of the brands of this package, relative to its
+    /// download directory, see [`brand_colors::assign`].
+    pub fn brand_colors_file(&self) -> Option<&'static str> {
+        match self {
+            PackageType::SimpleIcons => Some("_data/simple-icons.json"),
+            _ => None,
+        }
+    }
+
     fn metadata(&self) -> PackageMetadata {
         match self {
             PackageType::AntDesignIcons => PackageMetadata {
@@ -257,6 +1048,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::FontAwesome => PackageMetadata {
                 short_name: Cow::Borrowed("fa"),
@@ -284,6 +1078,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::WeatherIcons => PackageMetadata {
                 short_name: Cow::Borrowed("wi"),
@@ -311,6 +1108,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::Feather => PackageMetadata {
                 short_name: Cow::Borrowed("fi"),
@@ -338,6 +1138,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::VSCodeIcons => PackageMetadata {
                 short_name: Cow::Borrowed("vs"),
@@ -365,6 +1168,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::BootstrapIcons => PackageMetadata {
                 short_name: Cow::Borrowed("bs"),
@@ -392,6 +1198,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::BoxIcons => PackageMetadata {
                 short_name: Cow::Borrowed("bi"),
@@ -420,6 +1229,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::IcoMoonFree => PackageMetadata {
                 short_name: Cow::Borrowed("im"),
@@ -442,6 +1254,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::Ionicons => PackageMetadata {
                 short_name: Cow::Borrowed("io"),
@@ -469,6 +1284,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::RemixIcon => PackageMetadata {
                 short_name: Cow::Borrowed("ri"),
@@ -496,6 +1314,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::SimpleIcons => PackageMetadata {
                 short_name: Cow::Borrowed("si"),
@@ -523,6 +1344,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::Typicons => PackageMetadata {
                 short_name: Cow::Borrowed("ti"),
@@ -550,6 +1374,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::Heroicons => PackageMetadata {
                 short_name: Cow::Borrowed("hi"),
@@ -577,6 +1404,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::CssGg => PackageMetadata {
                 short_name: Cow::Borrowed("cg"),
@@ -604,6 +1434,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::TablerIcons => PackageMetadata {
                 short_name: Cow::Borrowed("tb"),
@@ -631,6 +1464,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::GithubOcticons => PackageMetadata {
                 short_name: Cow::Borrowed("oc"),
@@ -658,6 +1494,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::Lucide => PackageMetadata {
                 short_name: Cow::Borrowed("lu"),
@@ -685,6 +1524,9 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
             PackageType::Charm => PackageMetadata {
                 short_name: Cow::Borrowed("ch"),
@@ -712,7 +1554,291 @@ impl PackageType {
                     prerelease: None,
                     build: None,
                 },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
             },
+            PackageType::Phosphor => PackageMetadata {
+                short_name: Cow::Borrowed("ph"),
+                package_name: Cow::Borrowed("Phosphor"),
+                licenses: &[Cow::Borrowed("MIT")],
+                source: PackageSource::Git {
+                    url: Cow::Borrowed("https://github.com/phosphor-icons/core"),
+                    target: GitTarget::Tag {
+                        name: Cow::Borrowed("v2.1.1"),
+                        version: SemVer {
+                            major: 2,
+                            minor: 1,
+                            patch: 1,
+                            prerelease: None,
+                            build: None,
+                        },
+                    },
+                },
+                download_dir: Cow::Borrowed("phosphor"),
+                // One directory per weight: thin, light, regular, bold, fill and duotone.
+                svg_dir: Cow::Borrowed("assets"),
+                crate_version: SemVer {
+                    major: 0,
+                    minor: 0,
+                    patch: 1,
+                    prerelease: None,
+                    build: None,
+                },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
+            },
+            PackageType::MaterialSymbols => PackageMetadata {
+                short_name: Cow::Borrowed("ms"),
+                package_name: Cow::Borrowed("Material Symbols"),
+                licenses: &[Cow::Borrowed("Apache-2.0")],
+                source: PackageSource::Git {
+                    url: Cow::Borrowed("https://github.com/google/material-design-icons"),
+                    // Material Symbols are not part of a tagged release yet.
+                    target: GitTarget::Branch {
+                        name: Cow::Borrowed("master"),
+                        commit_ref: Cow::Borrowed("master"),
+                        version_hint: None,
+                    },
+                },
+                download_dir: Cow::Borrowed("material-design-icons"),
+                svg_dir: Cow::Borrowed("symbols/web"),
+                crate_version: SemVer {
+                    major: 0,
+                    minor: 0,
+                    patch: 1,
+                    prerelease: None,
+                    build: None,
+                },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
+            },
+            PackageType::CarbonIcons => PackageMetadata {
+                short_name: Cow::Borrowed("cb"),
+                package_name: Cow::Borrowed("Carbon Icons"),
+                licenses: &[Cow::Borrowed("Apache-2.0")],
+                source: PackageSource::Git {
+                    url: Cow::Borrowed("https://github.com/carbon-design-system/carbon"),
+                    target: GitTarget::Tag {
+                        name: Cow::Borrowed("v11.40.0"),
+                        version: SemVer {
+                            major: 11,
+                            minor: 40,
+                            patch: 0,
+                            prerelease: None,
+                            build: None,
+                        },
+                    },
+                },
+                download_dir: Cow::Borrowed("carbon"),
+                // One directory per size: 16, 20, 24 and 32, named like `CbAddSm` to `CbAddLgplus`.
+                svg_dir: Cow::Borrowed("packages/icons/src/svg"),
+                crate_version: SemVer {
+                    major: 0,
+                    minor: 0,
+                    patch: 1,
+                    prerelease: None,
+                    build: None,
+                },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
+            },
+            PackageType::Iconoir => PackageMetadata {
+                short_name: Cow::Borrowed("ir"),
+                package_name: Cow::Borrowed("Iconoir"),
+                licenses: &[Cow::Borrowed("MIT")],
+                source: PackageSource::Git {
+                    url: Cow::Borrowed("https://github.com/iconoir-icons/iconoir"),
+                    target: GitTarget::Tag {
+                        name: Cow::Borrowed("v7.0.0"),
+                        version: SemVer {
+                            major: 7,
+                            minor: 0,
+                            patch: 0,
+                            prerelease: None,
+                            build: None,
+                        },
+                    },
+                },
+                download_dir: Cow::Borrowed("iconoir"),
+                // One directory per style: regular and solid.
+                svg_dir: Cow::Borrowed("icons"),
+                crate_version: SemVer {
+                    major: 0,
+                    minor: 0,
+                    patch: 1,
+                    prerelease: None,
+                    build: None,
+                },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
+            },
+            PackageType::FlagIcons => PackageMetadata {
+                short_name: Cow::Borrowed("fg"),
+                package_name: Cow::Borrowed("Flag Icons"),
+                licenses: &[Cow::Borrowed("MIT")],
+                source: PackageSource::Git {
+                    url: Cow::Borrowed("https://github.com/lipis/flag-icons"),
+                    target: GitTarget::Tag {
+                        name: Cow::Borrowed("v7.2.3"),
+                        version: SemVer {
+                            major: 7,
+                            minor: 2,
+                            patch: 3,
+                            prerelease: None,
+                            build: None,
+                        },
+                    },
+                },
+                download_dir: Cow::Borrowed("flag-icons"),
+                // One directory per aspect ratio: 4x3 and 1x1.
+                svg_dir: Cow::Borrowed("flags"),
+                crate_version: SemVer {
+                    major: 0,
+                    minor: 0,
+                    patch: 1,
+                    prerelease: None,
+                    build: None,
+                },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
+            },
+            PackageType::GameIcons => PackageMetadata {
+                short_name: Cow::Borrowed("gi"),
+                package_name: Cow::Borrowed("Game Icons"),
+                licenses: &[Cow::Borrowed("CC-BY-3.0")],
+                source: PackageSource::Git {
+                    url: Cow::Borrowed("https://github.com/game-icons/icons"),
+                    // The icons are not released in tagged versions.
+                    target: GitTarget::Branch {
+                        name: Cow::Borrowed("master"),
+                        commit_ref: Cow::Borrowed("master"),
+                        version_hint: None,
+                    },
+                },
+                download_dir: Cow::Borrowed("game-icons"),
+                // One directory per author at the root of the repository.
+                svg_dir: Cow::Borrowed(""),
+                crate_version: SemVer {
+                    major: 0,
+                    minor: 0,
+                    patch: 1,
+                    prerelease: None,
+                    build: None,
+                },
+                pinned: &[],
+                expand_strokes: false,
+                // Over 4000 icons.
+                opt_in: true,
+            },
+            // Shared by all Iconify collections, which override everything naming the package, see
+            // `iconify::Collection::metadata`.
+            PackageType::Iconify => PackageMetadata {
+                short_name: Cow::Borrowed("iconify"),
+                package_name: Cow::Borrowed("Iconify"),
+                licenses: &[],
+                source: PackageSource::Git {
+                    url: Cow::Borrowed("https://github.com/iconify/icon-sets"),
+                    // Updated continuously without tagged versions.
+                    target: GitTarget::Branch {
+                        name: Cow::Borrowed("master"),
+                        commit_ref: Cow::Borrowed("master"),
+                        version_hint: None,
+                    },
+                },
+                download_dir: Cow::Borrowed("iconify"),
+                svg_dir: Cow::Borrowed("json"),
+                crate_version: SemVer {
+                    major: 0,
+                    minor: 0,
+                    patch: 1,
+                    prerelease: None,
+                    build: None,
+                },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
+            },
+            // Defaults of all local packages, which configure everything naming the package, see
+            // `Package::local`.
+            PackageType::Local => PackageMetadata {
+                short_name: Cow::Borrowed("custom"),
+                package_name: Cow::Borrowed("Custom Icons"),
+                licenses: &[],
+                source: PackageSource::LocalPath {
+                    path: PathBuf::new(),
+                    repository: None,
+                    prefix: None,
+                    categories: BTreeMap::new(),
+                },
+                download_dir: Cow::Borrowed(""),
+                svg_dir: Cow::Borrowed(""),
+                crate_version: SemVer {
+                    major: 0,
+                    minor: 0,
+                    patch: 1,
+                    prerelease: None,
+                    build: None,
+                },
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Category, CategoryRenames, IconFilter};
+
+    #[test]
+    fn filters_files_by_globs() {
+        let filter = IconFilter {
+            include: Some(vec!["ui/**".to_owned(), "logo-?".to_owned()]),
+            exclude: vec!["*-flag".to_owned(), "ui/legacy/*".to_owned()],
+            ..Default::default()
+        };
+        for (relative_path, included) in [
+            ("ui/home", true),
+            ("ui/arrows/up", true),
+            ("ui/germany-flag", false),
+            ("ui/legacy/home", false),
+            ("ui/legacy/old/home", true),
+            ("brands/logo-a", true),
+            ("brands/logo-ab", false),
+            ("home", false),
+        ] {
+            assert_eq!(
+                filter.matches_globs(relative_path),
+                included,
+                "{relative_path}"
+            );
         }
+        assert!(IconFilter::default().matches_globs("any/icon"));
+    }
+
+    #[test]
+    fn renames_categories() {
+        let renames = CategoryRenames(
+            [
+                ("twotone".to_owned(), Some("two-tone".to_owned())),
+                ("two_tone".to_owned(), Some("two-tone".to_owned())),
+                ("ic".to_owned(), None),
+            ]
+            .into(),
+        );
+        let categories = ["ic", "twotone", "two_tone", "24px"]
+            .map(|category| Category(category.to_owned()))
+            .to_vec();
+        assert_eq!(
+            renames.apply(categories),
+            [Category("two-tone".to_owned()), Category("24px".to_owned())]
+        );
     }
 }
@@ -0,0 +1,101 @@
+//! Downloading packages as tarballs from the npm registry, which are much smaller than the git
+//! repositories of some packages, like Heroicons.
+//!
+//! The files of a tarball are unpacked into the download directory of the package, next to a file
+//! recording their version. Unpacking another version replaces them.
+
+use std::{fs, path::Path, process::Command};
+
+use anyhow::{ensure, Context, Result};
+use tracing::{debug, info, instrument};
+
+use crate::path;
+
+const REGISTRY: &str = "https://registry.npmjs.org";
+
+/// File in the download directory recording the version unpacked into it. Hidden, so it is no part
+/// of the checksum of the package, see [`crate::lockfile::content_hash`].
+const VERSION_FILE: &str = ".npm-version";
+
+/// Url of the tarball of the given version of an npm package.
+pub(crate) fn tarball_url(name: &str, version: &str) -> String {
+    // Tarballs of scoped packages, like "@scope/icons", are named without the scope.
+    let basename = name.rsplit('/').next().unwrap_or(name);
+    format!("{REGISTRY}/{name}/-/{basename}-{version}.tgz")
+}
+
+/// Whether the given version of the package was unpacked into `path` before.
+pub(super) fn is_unpacked(path: &Path, version: &str) -> bool {
+    fs::read_to_string(path.join(VERSION_FILE)).is_ok_and(|unpacked| unpacked.trim() == version)
+}
+
+/// Download the tarball of the given version of an npm package and unpack it into `path`,
+/// replacing any other version unpacked there.
+#[instrument(level = "info", skip(proxy))]
+pub(super) fn download(name: &str, version: &str, path: &Path, proxy: Option<&str>) -> Result<()> {
+    if path.exists() {
+        info!(?path, "Removing the previously unpacked version.");
+        fs::remove_dir_all(path)?;
+    }
+    fs::create_dir_all(path)?;
+    // Within the download directory, so it is part of the bytes downloaded into it.
+    let tarball = path.join(".npm-tarball.tgz");
+
+    let mut curl = Command::new("curl");
+    // Transient failures, like timeouts or server errors, are retried by curl itself.
+    curl.args(["--fail", "--silent", "--show-error", "--location"])
+        .args(["--retry", "3", "--output"])
+        .arg(&tarball);
+    if let Some(proxy) = proxy {
+        curl.arg("--proxy").arg(proxy);
+    }
+    curl.arg(tarball_url(name, version));
+    run(curl)?;
+
+    // npm packs all files of a package into a `package` directory.
+    let mut tar = Command::new("tar");
+    tar.arg("-xzf")
+        .arg(&tarball)
+        .arg("--strip-components=1")
+        .arg("-C")
+        .arg(path);
+    let unpacked = run(tar);
+    fs::remove_file(&tarball)?;
+    unpacked?;
+
+    fs::write(path.join(VERSION_FILE), version)?;
+    info!("Unpacked tarball.");
+    Ok(())
+}
+
+fn run(mut cmd: Command) -> Result<()> {
+    debug!(?cmd, "Running command.");
+    let output = cmd
+        .current_dir(path::build_crate(""))
+        .output()
+        .with_context(|| format!("Could not execute command {cmd:?}"))?;
+    ensure!(
+        output.status.success(),
+        "Command {cmd:?} exited with {}: {}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr).trim()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::tarball_url;
+
+    #[test]
+    fn builds_tarball_urls() {
+        assert_eq!(
+            tarball_url("heroicons", "2.1.5"),
+            "https://registry.npmjs.org/heroicons/-/heroicons-2.1.5.tgz"
+        );
+        assert_eq!(
+            tarball_url("@tabler/icons", "3.2.0"),
+            "https://registry.npmjs.org/@tabler/icons/-/icons-3.2.0.tgz"
+        );
+    }
+}
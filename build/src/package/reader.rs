@@ -1,14 +1,31 @@
 use anyhow::Result;
-use std::{path::PathBuf, str::FromStr};
-use tracing::{debug, instrument, trace, warn};
+use once_cell::sync::Lazy;
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+};
+use tokio::{sync::Semaphore, task::JoinSet};
+use tracing::{debug, instrument, trace, warn, Span};
 
 use crate::{
-    icon::{Category, IconSize, SvgIcon},
+    failures::ParseFailure,
+    icon::{svg, Category, IconSize, SvgIcon},
     package::Package,
+    progress,
 };
 
 use super::Unknown;
 
+/// Number of svg files parsed by one blocking task.
+const BATCH_SIZE: usize = 64;
+
+/// Limits the batches parsed at the same time across all packages to the number of cores.
+static PARSERS: Lazy<Arc<Semaphore>> = Lazy::new(|| {
+    let cores = std::thread::available_parallelism().map_or(1, |cores| cores.get());
+    Arc::new(Semaphore::new(cores))
+});
+
 /// A directory to be searched, combined with:
 ///     - a list of categories valid for the contents of that directory and
 ///     - an icon size valid for icons inside the directory
@@ -19,18 +36,30 @@ struct SearchDir {
     icon_size: Option<IconSize>,
 }
 
+/// An svg file to be parsed, with the icon size and categories of its directory.
+#[derive(Debug)]
+struct SvgFile {
+    path: PathBuf,
+    categories: Vec<Category>,
+    icon_size: Option<IconSize>,
+}
+
+/// Read the icons of the svg files below `icons_path`, with the files which could not be parsed
+/// with `keep_going`, see [`parse`].
 #[instrument(level = "info", skip(package), fields(package = ?package.ty))]
 pub(crate) async fn read_icons(
     package: &Package<Unknown>,
+    download_path: &Path,
     icons_path: PathBuf,
-) -> Result<Vec<SvgIcon>> {
+    keep_going: bool,
+) -> Result<(Vec<SvgIcon>, Vec<ParseFailure>)> {
     trace!("Reading icon data...");
-    let mut icons = Vec::new();
+    let mut files = Vec::new();
 
     let mut search_dirs = Vec::<SearchDir>::new();
 
     search_dirs.push(SearchDir {
-        path: icons_path,
+        path: icons_path.clone(),
         categories: Vec::new(),
         icon_size: None,
     });
@@ -61,8 +90,8 @@ pub(crate) async fn read_icons(
                 // The new directory needs all categories from the current directory.
                 // We may consider the dir name as being a "category" for all items contained in it.
                 let mut entry_cats = categories.clone();
-                if package.ty.is_category(&file_name) {
-                    entry_cats.push(Category(file_name));
+                if let Some(category) = package.category(&file_name) {
+                    entry_cats.push(category);
                 }
 
                 search_dirs.push(SearchDir {
@@ -75,13 +104,31 @@ pub(crate) async fn read_icons(
             };
 
             // We found a file and can check its extension.
+            let file_stem = entry_path.file_stem().unwrap_or_default().to_string_lossy();
             match entry_path.extension() {
                 Some(file_extension) => match file_extension.to_str() {
                     Some(file_extension) => match file_extension {
-                        "svg" => icons.push(
-                            SvgIcon::new(package, &entry_path, icon_size, categories.clone())
-                                .await?,
+                        "svg" if !package.ty.is_included(&file_stem) => trace!(
+                            ?entry_path,
+                            "Found svg file of a variant which is not generated. Ignoring it."
                         ),
+                        "svg"
+                            if !package.filter.includes(
+                                &relative_path(&icons_path, &entry_path),
+                                icon_size,
+                                &categories,
+                            ) =>
+                        {
+                            trace!(
+                                ?entry_path,
+                                "Found svg file filtered out by the configuration. Ignoring it."
+                            )
+                        }
+                        "svg" => files.push(SvgFile {
+                            path: entry_path,
+                            categories: categories.clone(),
+                            icon_size,
+                        }),
                         _ => trace!(
                             ?entry_path,
                             file_extension,
@@ -101,6 +148,89 @@ pub(crate) async fn read_icons(
         }
     }
 
-    trace!(num_icons = icons.len(), "Finished retrieving icon names.");
-    Ok(icons)
+    trace!(num_files = files.len(), "Finished retrieving svg files.");
+    let (icons, failures) = parse(package, download_path, files, keep_going).await?;
+    trace!(num_icons = icons.len(), "Finished parsing icons.");
+    Ok((icons, failures))
+}
+
+/// Path of the svg file relative to the svg directory, without extension, e.g. "ui/home".
+fn relative_path(icons_path: &Path, path: &Path) -> String {
+    path.strip_prefix(icons_path)
+        .unwrap_or(path)
+        .with_extension("")
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Parse the svg files in batches on the blocking thread pool, as many at the same time as there
+/// are cores, keeping the order of the files. Attribute values are interned within each batch, see
+/// [`svg::with_interner`]. With `keep_going`, files which cannot be parsed are
+/// left out and returned as failures instead of failing.
+async fn parse(
+    package: &Package<Unknown>,
+    download_path: &Path,
+    files: Vec<SvgFile>,
+    keep_going: bool,
+) -> Result<(Vec<SvgIcon>, Vec<ParseFailure>)> {
+    let progress = progress::parsing(&package.meta.short_name, files.len());
+    let package = Arc::new(package.clone());
+    let download_path = Arc::new(download_path.to_owned());
+    let mut parsers = JoinSet::new();
+    let mut files = files.into_iter().peekable();
+    let mut index = 0;
+    while files.peek().is_some() {
+        let batch = files.by_ref().take(BATCH_SIZE).collect::<Vec<_>>();
+        let parser_slot = Arc::clone(&PARSERS).acquire_owned().await?;
+        let package = Arc::clone(&package);
+        let download_path = Arc::clone(&download_path);
+        let progress = progress.clone();
+        // Warnings of the parser are logged within the span of the package.
+        let span = Span::current();
+        parsers.spawn_blocking(move || {
+            let _parser_slot = parser_slot;
+            let _span = span.enter();
+            svg::with_interner(|| {
+                let mut icons = Vec::new();
+                let mut failures = Vec::new();
+                for file in batch {
+                    let icon = SvgIcon::new(
+                        &package,
+                        &download_path,
+                        &file.path,
+                        file.icon_size,
+                        file.categories,
+                    );
+                    progress.inc(1);
+                    match icon {
+                        Ok(icon) => icons.push(icon),
+                        Err(err) if keep_going => {
+                            let path = file.path.strip_prefix(download_path.as_path());
+                            let path = path.unwrap_or(&file.path).display().to_string();
+                            warn!(path, ?err, "Could not read icon, leaving it out.");
+                            failures.push(ParseFailure::new(&package.meta.short_name, path, &err));
+                        }
+                        Err(err) => return Err(err),
+                    }
+                }
+                Ok::<_, anyhow::Error>((index, icons, failures))
+            })
+        });
+        index += 1;
+    }
+
+    let mut batches = Vec::new();
+    while let Some(batch) = parsers.join_next().await {
+        batches.push(batch??);
+    }
+    batches.sort_by_key(|(index, _, _)| *index);
+    let mut icons = Vec::new();
+    let mut failures = Vec::new();
+    for (_, batch_icons, batch_failures) in batches {
+        icons.extend(batch_icons);
+        failures.extend(batch_failures);
+    }
+    Ok((icons, failures))
 }
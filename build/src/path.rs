@@ -1,5 +1,18 @@
 use std::path::{Path, PathBuf};
 
+use anyhow::{anyhow, Result};
+use once_cell::sync::OnceCell;
+
+/// Directory the crates are generated in, see [`set_output_dir`].
+static OUTPUT_DIR: OnceCell<PathBuf> = OnceCell::new();
+
+/// Directory packages are downloaded to, see [`set_download_dir`].
+static DOWNLOAD_DIR: OnceCell<PathBuf> = OnceCell::new();
+
+/// Name of the directory, relative to the root of this crate, to which all icon packages are
+/// downloaded unless configured otherwise.
+const DEFAULT_DOWNLOAD_DIR: &str = "downloads";
+
 pub(crate) fn build_crate<P: AsRef<Path>>(relative_path: P) -> PathBuf {
     Path::new(env!("CARGO_MANIFEST_DIR")).join(relative_path)
 }
@@ -11,3 +24,41 @@ pub(crate) fn library_crate<P: AsRef<Path>>(
     current.pop();
     current.join(name)
 }
+
+/// Generate crates in the given directory instead of the root of this repository.
+pub(crate) fn set_output_dir(dir: PathBuf) -> Result<()> {
+    OUTPUT_DIR
+        .set(dir)
+        .or(Err(anyhow!("The output directory was already set.")))
+}
+
+/// Download packages to the given directory instead of the `downloads` directory of this crate,
+/// e.g. to a directory cached between CI runs.
+pub(crate) fn set_download_dir(dir: PathBuf) -> Result<()> {
+    DOWNLOAD_DIR
+        .set(dir)
+        .or(Err(anyhow!("The download directory was already set.")))
+}
+
+/// Directory packages are downloaded to, see [`set_download_dir`].
+pub(crate) fn download_dir() -> PathBuf {
+    match DOWNLOAD_DIR.get() {
+        Some(dir) => dir.clone(),
+        None => build_crate(DEFAULT_DOWNLOAD_DIR),
+    }
+}
+
+/// Whether `dir` is a directory of this repository, as are the generated crates unless configured
+/// otherwise, see [`set_output_dir`].
+pub(crate) fn is_in_repository(dir: &Path) -> bool {
+    dir.starts_with(library_crate(""))
+}
+
+/// Path of a generated crate, in the root of this repository unless configured otherwise, see
+/// [`set_output_dir`].
+pub(crate) fn generated_crate<P: AsRef<Path>>(name: P) -> PathBuf {
+    match OUTPUT_DIR.get() {
+        Some(dir) => dir.join(name),
+        None => library_crate(name),
+    }
+}
@@ -0,0 +1,204 @@
+use std::{fmt::Write, path::Path};
+
+use anyhow::Result;
+use clap::ValueEnum;
+use tracing::{info, instrument};
+
+use crate::{
+    dirs::write_to_file,
+    icon::polyline::{self, Polyline},
+    Packages,
+};
+
+/// File format of icons exported for cutters and plotters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Format {
+    /// AutoCAD DXF (R12) with one polyline entity per outline, read by laser cutter software.
+    Dxf,
+    /// Svg with unfilled paths only, ordered to minimize pen travel.
+    Svg,
+}
+
+impl Format {
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Dxf => "dxf",
+            Format::Svg => "svg",
+        }
+    }
+}
+
+/// Write every icon of every package as single line drawing to `out/<package>/<icon>.<format>`,
+/// scaled to `size` millimeters.
+#[instrument(level = "info")]
+pub(crate) async fn export(format: Format, out: &Path, size: f64) -> Result<()> {
+    let mut num_icons = 0;
+    for package in Packages::get()? {
+        let package_dir = out.join(package.meta.short_name.as_ref());
+        for icon in package.icons() {
+            let drawing = Drawing::new(
+                polyline::view_box(&icon.svg),
+                size,
+                travel_order(polyline::polylines(&icon.svg.content)),
+            );
+            let contents = match format {
+                Format::Dxf => drawing.dxf(),
+                Format::Svg => drawing.svg(),
+            };
+            let file = package_dir.join(format!("{}.{}", icon.name, format.extension()));
+            write_to_file(&file, contents).await?;
+            num_icons += 1;
+        }
+    }
+
+    info!(num_icons, ?out, "Exported icons for cutters and plotters.");
+    Ok(())
+}
+
+/// The polylines of an icon, mapped from its view box to a square of `size` millimeters.
+struct Drawing {
+    view_box: [f64; 4],
+    size: f64,
+    polylines: Vec<Polyline>,
+}
+
+impl Drawing {
+    fn new(view_box: [f64; 4], size: f64, polylines: Vec<Polyline>) -> Drawing {
+        Drawing {
+            view_box,
+            size,
+            polylines,
+        }
+    }
+
+    fn scale(&self) -> f64 {
+        self.size / self.view_box[2].max(self.view_box[3])
+    }
+
+    /// Position in millimeters, with the y axis pointing up as in CAD software.
+    fn cad_point(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        let [min_x, min_y, _, height] = self.view_box;
+        let scale = self.scale();
+        ((x - min_x) * scale, (min_y + height - y) * scale)
+    }
+
+    fn dxf(&self) -> String {
+        let mut dxf = String::from("0\nSECTION\n2\nHEADER\n9\n$INSUNITS\n70\n4\n0\nENDSEC\n");
+        dxf.push_str("0\nSECTION\n2\nENTITIES\n");
+        for polyline in &self.polylines {
+            let flags = u8::from(polyline.closed);
+            write!(dxf, "0\nPOLYLINE\n8\n0\n66\n1\n70\n{flags}\n").expect("writing to a string");
+            for point in &polyline.points {
+                let (x, y) = self.cad_point(*point);
+                write!(dxf, "0\nVERTEX\n8\n0\n10\n{x:.4}\n20\n{y:.4}\n")
+                    .expect("writing to a string");
+            }
+            dxf.push_str("0\nSEQEND\n8\n0\n");
+        }
+        dxf.push_str("0\nENDSEC\n0\nEOF\n");
+        dxf
+    }
+
+    fn svg(&self) -> String {
+        let [min_x, min_y, width, height] = self.view_box;
+        let scale = self.scale();
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.4}mm" height="{:.4}mm" viewBox="{min_x} {min_y} {width} {height}" fill="none" stroke="black" stroke-width="{:.4}" stroke-linecap="round" stroke-linejoin="round">"#,
+            width * scale,
+            height * scale,
+            // A pen line of 0.3mm.
+            0.3 / scale,
+        );
+        for polyline in &self.polylines {
+            svg.push_str(r#"<path d=""#);
+            for (i, (x, y)) in polyline.points.iter().enumerate() {
+                let command = if i == 0 { 'M' } else { 'L' };
+                write!(svg, "{command}{x:.3} {y:.3}").expect("writing to a string");
+            }
+            if polyline.closed {
+                svg.push('Z');
+            }
+            svg.push_str(r#""/>"#);
+        }
+        svg.push_str("</svg>");
+        svg
+    }
+}
+
+/// Orders the polylines so that each one starts close to where the previous one ended, reversing
+/// open polylines where their end is closer. Greedy, but removes most of the pen travel of the
+/// order in which the shapes were written.
+fn travel_order(mut polylines: Vec<Polyline>) -> Vec<Polyline> {
+    let distance = |(x1, y1): (f64, f64), (x2, y2): (f64, f64)| (x2 - x1).hypot(y2 - y1);
+    let mut ordered = Vec::with_capacity(polylines.len());
+    let mut position = (0.0, 0.0);
+
+    while !polylines.is_empty() {
+        let (i, reversed, _) = polylines
+            .iter()
+            .enumerate()
+            .flat_map(|(i, polyline)| {
+                let start = (i, false, distance(position, polyline.points[0]));
+                let end = (!polyline.closed).then(|| {
+                    let last = polyline.points[polyline.points.len() - 1];
+                    (i, true, distance(position, last))
+                });
+                [Some(start), end].into_iter().flatten()
+            })
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+            .expect("polylines are not empty");
+
+        let mut polyline = polylines.swap_remove(i);
+        if reversed {
+            polyline.points.reverse();
+        }
+        position = match polyline.closed {
+            true => polyline.points[0],
+            false => polyline.points[polyline.points.len() - 1],
+        };
+        ordered.push(polyline);
+    }
+    ordered
+}
+
+#[cfg(test)]
+mod test {
+    use super::{travel_order, Drawing, Polyline};
+
+    fn line(points: &[(f64, f64)]) -> Polyline {
+        Polyline {
+            points: points.to_vec(),
+            closed: false,
+        }
+    }
+
+    #[test]
+    fn orders_for_short_travel() {
+        let ordered = travel_order(vec![
+            line(&[(20.0, 20.0), (24.0, 24.0)]),
+            line(&[(10.0, 10.0), (2.0, 2.0)]),
+            line(&[(0.0, 0.0), (1.0, 1.0)]),
+        ]);
+        assert_eq!(
+            ordered,
+            [
+                line(&[(0.0, 0.0), (1.0, 1.0)]),
+                line(&[(2.0, 2.0), (10.0, 10.0)]),
+                line(&[(20.0, 20.0), (24.0, 24.0)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_dxf_in_millimeters() {
+        let square = Polyline {
+            points: vec![(0.0, 0.0), (12.0, 0.0), (12.0, 12.0)],
+            closed: true,
+        };
+        let dxf = Drawing::new([0.0, 0.0, 24.0, 24.0], 48.0, vec![square]).dxf();
+        assert!(dxf.contains("0\nPOLYLINE\n8\n0\n66\n1\n70\n1\n"));
+        assert!(dxf.contains("0\nVERTEX\n8\n0\n10\n24.0000\n20\n48.0000\n"));
+        assert!(dxf.contains("0\nVERTEX\n8\n0\n10\n24.0000\n20\n24.0000\n"));
+        assert!(dxf.ends_with("0\nSEQEND\n8\n0\n0\nENDSEC\n0\nEOF\n"));
+    }
+}
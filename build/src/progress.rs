@@ -0,0 +1,193 @@
+//! Progress bars of the packages while they are downloaded, read and generated, one per package.
+//!
+//! Without them, long builds print nothing but the occasional log for minutes. Logs are written
+//! while the bars are hidden, so that they do not garble each other. With `--quiet`, no bars are
+//! drawn and only the logs are written.
+
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use once_cell::sync::{Lazy, OnceCell};
+use tracing_subscriber::fmt::MakeWriter;
+
+/// All bars, unset with `--quiet`.
+static PROGRESS: OnceCell<MultiProgress> = OnceCell::new();
+
+/// The bar of each package by its short name.
+static BARS: Lazy<Mutex<HashMap<String, ProgressBar>>> = Lazy::new(Default::default);
+
+/// Interval at which spinners turn.
+const TICK: Duration = Duration::from_millis(100);
+
+/// Interval at which the size of downloads is measured, walking the whole download directory.
+const MEASURE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Draw progress bars from now on, unless `quiet`.
+pub(crate) fn init(quiet: bool) {
+    if !quiet {
+        PROGRESS.get_or_init(MultiProgress::new);
+    }
+}
+
+/// The bar of the package with the given short name, added below the others on first use. Hidden
+/// with `--quiet`.
+fn bar(short_name: &str) -> ProgressBar {
+    let Some(progress) = PROGRESS.get() else {
+        return ProgressBar::hidden();
+    };
+    let mut bars = BARS.lock().expect("no panic while holding the bars");
+    let bar = bars.entry(short_name.to_owned()).or_insert_with(|| {
+        let bar = progress.add(ProgressBar::new_spinner());
+        bar.set_prefix(short_name.to_owned());
+        bar
+    });
+    bar.clone()
+}
+
+/// Show the bar of the package as a spinner with the given message, e.g. after it was finished.
+fn spin(short_name: &str, template: &str, message: &'static str) -> ProgressBar {
+    let bar = bar(short_name);
+    bar.reset();
+    bar.set_style(ProgressStyle::with_template(template).expect("a valid template"));
+    bar.set_message(message);
+    bar.enable_steady_tick(TICK);
+    bar
+}
+
+/// Downloading a package, showing the number of bytes written to its download directory since,
+/// until dropped.
+pub(crate) struct Downloading {
+    done: Arc<AtomicBool>,
+    measure: Option<JoinHandle<()>>,
+}
+
+/// Show the bytes downloaded into `path` for the package with the given short name, until the
+/// returned guard is dropped.
+pub(crate) fn downloading(short_name: &str, path: &Path) -> Downloading {
+    let done = Arc::new(AtomicBool::new(false));
+    if PROGRESS.get().is_none() {
+        return Downloading {
+            done,
+            measure: None,
+        };
+    }
+    let bar = spin(
+        short_name,
+        "{prefix:>10} {spinner} {msg} {bytes} ({bytes_per_sec})",
+        "downloading",
+    );
+    let path = path.to_owned();
+    let initial_size = dir_size(&path);
+    let measure = thread::spawn({
+        let done = Arc::clone(&done);
+        move || {
+            while !done.load(Ordering::Relaxed) {
+                bar.set_position(dir_size(&path).saturating_sub(initial_size));
+                thread::sleep(MEASURE_INTERVAL);
+            }
+        }
+    });
+    Downloading {
+        done,
+        measure: Some(measure),
+    }
+}
+
+impl Drop for Downloading {
+    fn drop(&mut self) {
+        self.done.store(true, Ordering::Relaxed);
+        if let Some(measure) = self.measure.take() {
+            let _ = measure.join();
+        }
+    }
+}
+
+/// Bytes of all files below `path`, zero if it does not exist.
+fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() => dir_size(&entry.path()),
+            _ => entry.metadata().map_or(0, |metadata| metadata.len()),
+        })
+        .sum()
+}
+
+/// Show that the icons of the package are being read, before their number is known.
+pub(crate) fn reading(short_name: &str) {
+    spin(short_name, "{prefix:>10} {spinner} {msg}", "reading icons");
+}
+
+/// The bar counting the given number of svg files of the package as they are parsed.
+pub(crate) fn parsing(short_name: &str, num_files: usize) -> ProgressBar {
+    let bar = bar(short_name);
+    bar.set_style(
+        ProgressStyle::with_template("{prefix:>10} [{bar:30}] {pos}/{len} {msg}")
+            .expect("a valid template")
+            .progress_chars("=> "),
+    );
+    bar.set_message("icons parsed");
+    bar.set_length(num_files as u64);
+    bar.set_position(0);
+    bar
+}
+
+/// Show that the given number of icons of the package were read.
+pub(crate) fn read(short_name: &str, num_icons: usize) {
+    let bar = bar(short_name);
+    bar.set_style(ProgressStyle::with_template("{prefix:>10} {msg}").expect("a valid template"));
+    bar.finish_with_message(format!("{num_icons} icons read"));
+}
+
+/// Show that the icon crate of the package is being generated.
+pub(crate) fn generating(short_name: &str) {
+    spin(short_name, "{prefix:>10} {spinner} {msg}", "generating");
+}
+
+/// Show that the icon crate of the package was generated, or left as it is if `unchanged`.
+pub(crate) fn generated(short_name: &str, unchanged: bool) {
+    let bar = bar(short_name);
+    bar.set_style(ProgressStyle::with_template("{prefix:>10} {msg}").expect("a valid template"));
+    bar.finish_with_message(match unchanged {
+        true => "unchanged",
+        false => "generated",
+    });
+}
+
+/// Writes logs to stdout while the progress bars are hidden.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct LogWriter;
+
+impl<'a> MakeWriter<'a> for LogWriter {
+    type Writer = LogWriter;
+
+    fn make_writer(&'a self) -> LogWriter {
+        *self
+    }
+}
+
+impl Write for LogWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match PROGRESS.get() {
+            Some(progress) => progress.suspend(|| io::stdout().write(buf)),
+            None => io::stdout().write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stdout().flush()
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use tracing::{info, instrument, warn};
+
+use crate::{
+    bundle::BUNDLES,
+    cargo,
+    dirs::write_to_file,
+    git,
+    package::Package,
+    path,
+    sem_ver::{Change, SemVer},
+};
+
+/// Matches the declaration of an icon constant in a generated source file.
+pub(crate) static ICON_DECLARATION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^pub static (\w+):").expect("valid regex"));
+
+/// Matches the version of the package declared in a Cargo.toml file.
+static PACKAGE_VERSION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?m)^version = "([^"]*)""#).expect("valid regex"));
+
+/// Kind of change of a generated crate since a revision, with the version it had then and the
+/// version it is released under.
+#[derive(Debug)]
+pub(crate) struct VersionBump {
+    pub change: Change,
+    pub previous: SemVer,
+    pub next: SemVer,
+}
+
+/// A crate which changed and has to be published under a new version.
+#[derive(Debug)]
+struct Release {
+    name: String,
+    path: PathBuf,
+    change: Change,
+    current: SemVer,
+    next: SemVer,
+}
+
+/// Determine which generated crates changed since `revision`, bump their versions and publish them
+/// in dependency order, finishing with the `icondata` facade crate.
+#[instrument(level = "info")]
+pub(crate) async fn run(revision: &str, dry_run: bool) -> Result<()> {
+    let mut releases = Vec::new();
+    let crates = Package::all()
+        .into_iter()
+        .map(|package| package.meta.short_name.into_owned())
+        .chain(BUNDLES.iter().map(|bundle| bundle.name.to_owned()));
+    for short_name in crates {
+        let name = format!("icondata_{short_name}");
+        let crate_path = path::library_crate(&name);
+        if let Some(bump) = next_version(revision, &crate_path).await? {
+            releases.push(Release {
+                name,
+                path: crate_path,
+                change: bump.change,
+                current: bump.previous,
+                next: bump.next,
+            });
+        }
+    }
+
+    let Some(facade_change) = releases.iter().map(|release| release.change).max() else {
+        info!(revision, "No generated crate changed. Nothing to publish.");
+        return Ok(());
+    };
+    let facade_path = path::library_crate("icondata");
+    let current = read_version(&facade_path).await?;
+    let next = current.bump(facade_change);
+    releases.push(Release {
+        name: "icondata".to_owned(),
+        path: facade_path,
+        change: facade_change,
+        current,
+        next,
+    });
+
+    for release in &releases {
+        info!(
+            name = release.name,
+            change = ?release.change,
+            from = %release.current,
+            to = %release.next,
+            "Planned release."
+        );
+    }
+
+    if !dry_run {
+        for release in &releases {
+            write_version(&release.path, &release.next).await?;
+        }
+        write_facade_dependencies(&releases).await?;
+        warn!("Versions were only bumped in the generated crates. Update the version of the main_lib Cargo.toml template accordingly.");
+    }
+
+    // The facade crate comes last, so that all of its dependencies are available when it is published.
+    for release in &releases {
+        cargo::publish(&release.path, dry_run)
+            .with_context(|| format!("Could not publish {}", release.name))?;
+    }
+    Ok(())
+}
+
+/// Bump the version of the freshly generated crate at `crate_path` according to its changes since
+/// `revision`, see [`next_version`]. Crates generated with an older version than at `revision`,
+/// like the one in the metadata of their package, get the version at `revision` back first, so
+/// generating a crate again never bumps it twice. `None` if the crate did not change or did not
+/// exist at `revision`.
+pub(crate) async fn bump_generated(
+    revision: &str,
+    crate_path: &Path,
+) -> Result<Option<VersionBump>> {
+    let Some(previous) = version_at(revision, crate_path)? else {
+        return Ok(None);
+    };
+    if read_version(crate_path).await?.is_lower_than(&previous) {
+        write_version(crate_path, &previous).await?;
+    }
+    let bump = next_version(revision, crate_path).await?;
+    if let Some(bump) = &bump {
+        write_version(crate_path, &bump.next).await?;
+    }
+    Ok(bump)
+}
+
+/// The change of the generated crate at `crate_path` since `revision`, with its version at
+/// `revision` bumped accordingly, unless its current version is higher already, e.g. as bumped by
+/// the generation. Crates which did not exist at `revision` are bumped from their current version.
+/// `None` if the crate did not change.
+async fn next_version(revision: &str, crate_path: &Path) -> Result<Option<VersionBump>> {
+    let Some(change) = detect_change(revision, crate_path).await? else {
+        return Ok(None);
+    };
+    let current = read_version(crate_path).await?;
+    let previous = version_at(revision, crate_path)?.unwrap_or_else(|| current.clone());
+    let bumped = previous.bump(change);
+    let next = match current.is_lower_than(&bumped) {
+        true => bumped,
+        false => current,
+    };
+    Ok(Some(VersionBump {
+        change,
+        previous,
+        next,
+    }))
+}
+
+/// Classify the changes made to the generated crate at `crate_path` since `revision`.
+async fn detect_change(revision: &str, crate_path: &Path) -> Result<Option<Change>> {
+    if !git::has_changes(revision, crate_path)? {
+        return Ok(None);
+    }
+
+    let previous = git::grep(revision, "^pub static ", &crate_path.join("src"))?
+        .iter()
+        .flat_map(|line| icon_names(line))
+        .collect::<BTreeSet<_>>();
+
+    let mut current = BTreeSet::new();
+    let mut dir_stream = tokio::fs::read_dir(crate_path.join("src")).await?;
+    while let Some(entry) = dir_stream.next_entry().await? {
+        if entry.path().extension().is_some_and(|ext| ext == "rs") {
+            let contents = tokio::fs::read_to_string(entry.path()).await?;
+            current.extend(icon_names(&contents));
+        }
+    }
+
+    Ok(Some(if !previous.is_subset(&current) {
+        Change::Breaking
+    } else if previous.len() < current.len() {
+        Change::Addition
+    } else {
+        Change::Patch
+    }))
+}
+
+fn icon_names(contents: &str) -> impl Iterator<Item = String> + '_ {
+    ICON_DECLARATION
+        .captures_iter(contents)
+        .map(|captures| captures[1].to_owned())
+}
+
+async fn read_version(crate_path: &Path) -> Result<SemVer> {
+    let cargo_toml = tokio::fs::read_to_string(crate_path.join("Cargo.toml")).await?;
+    PACKAGE_VERSION
+        .captures(&cargo_toml)
+        .ok_or_else(|| anyhow!("No package version found in {crate_path:?}"))?[1]
+        .parse()
+}
+
+/// Version of the crate at `crate_path` as it was at the given revision, `None` if the crate did
+/// not exist yet.
+fn version_at(revision: &str, crate_path: &Path) -> Result<Option<SemVer>> {
+    git::grep(revision, r#"^version = ""#, &crate_path.join("Cargo.toml"))?
+        .first()
+        .and_then(|line| PACKAGE_VERSION.captures(line))
+        .map(|captures| captures[1].parse())
+        .transpose()
+}
+
+async fn write_version(crate_path: &Path, version: &SemVer) -> Result<()> {
+    let path = crate_path.join("Cargo.toml");
+    let cargo_toml = tokio::fs::read_to_string(&path).await?;
+    let cargo_toml = PACKAGE_VERSION.replace(&cargo_toml, format!(r#"version = "{version}""#));
+    write_to_file(&path, cargo_toml.into_owned()).await
+}
+
+/// Point the facade crate's dependencies to the new versions of all released crates.
+async fn write_facade_dependencies(releases: &[Release]) -> Result<()> {
+    let path = path::library_crate("icondata").join("Cargo.toml");
+    let mut cargo_toml = tokio::fs::read_to_string(&path).await?;
+    for release in releases {
+        let dependency = Regex::new(&format!(
+            r#"(?m)^({} = \{{ path = "[^"]*", version = ")[^"]*(")"#,
+            release.name
+        ))?;
+        cargo_toml = dependency
+            .replace(&cargo_toml, format!("${{1}}{}${{2}}", release.next))
+            .into_owned();
+    }
+    write_to_file(&path, cargo_toml).await
+}
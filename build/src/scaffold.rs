@@ -0,0 +1,299 @@
+use std::borrow::Cow;
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use heck::{ToKebabCase, ToPascalCase};
+use tracing::{info, instrument};
+
+use crate::{
+    dirs::write_to_file,
+    fs::{cargo_toml::CargoToml, lib_rs::LibRs, readme_md::Readme},
+    package::{GitTarget, PackageMetadata, PackageSource},
+    path,
+    sem_ver::SemVer,
+};
+
+/// Everything needed to add a new icon package.
+#[derive(Debug)]
+pub(crate) struct NewPackage {
+    /// Full human readable name of the icon package.
+    pub name: String,
+    /// Short identifier used as the crate suffix and icon name prefix.
+    pub short_name: String,
+    /// Source in the form `git:<url>@<tag>`.
+    pub source: String,
+    /// Directory relative to the downloaded package under which raw SVG files can be found.
+    pub svg_dir: String,
+    /// License of the icon package.
+    pub license: String,
+}
+
+/// Add a new package: register it in the package list, add a fixture for its icon names,
+/// create the skeleton of its crate and wire that crate into the workspace and the main library.
+#[instrument(level = "info")]
+pub(crate) async fn run(new_package: &NewPackage) -> Result<()> {
+    ensure!(
+        !new_package.short_name.is_empty()
+            && new_package
+                .short_name
+                .chars()
+                .all(|c| c.is_ascii_lowercase()),
+        "The short name must only consist of lowercase ascii letters."
+    );
+    let crate_name = format!("icondata_{}", new_package.short_name);
+    let crate_path = path::library_crate(&crate_name);
+    ensure!(!crate_path.exists(), "{crate_path:?} already exists.");
+
+    let (url, tag) = new_package
+        .source
+        .strip_prefix("git:")
+        .and_then(|source| source.rsplit_once('@'))
+        .ok_or_else(|| anyhow!("The source must be given as 'git:<url>@<tag>'."))?;
+    let version: SemVer = tag
+        .trim_start_matches('v')
+        .parse()
+        .with_context(|| format!("The tag '{tag}' does not name a version."))?;
+    let variant = new_package.name.to_pascal_case();
+
+    info!(variant, "Registering package.");
+    register_package(new_package, &variant, url, tag, &version).await?;
+
+    info!("Adding fixture.");
+    add_fixture(new_package, &variant).await?;
+
+    info!(?crate_path, "Creating crate skeleton.");
+    let meta = PackageMetadata {
+        short_name: Cow::Owned(new_package.short_name.clone()),
+        package_name: Cow::Owned(new_package.name.clone()),
+        licenses: Vec::leak(vec![Cow::Owned(new_package.license.clone())]),
+        source: PackageSource::Git {
+            url: Cow::Owned(url.to_owned()),
+            target: GitTarget::Tag {
+                name: Cow::Owned(tag.to_owned()),
+                version,
+            },
+        },
+        download_dir: Cow::Owned(new_package.name.to_kebab_case()),
+        svg_dir: Cow::Owned(new_package.svg_dir.clone()),
+        crate_version: initial_crate_version(),
+        pinned: &[],
+        expand_strokes: false,
+        opt_in: false,
+    };
+    write_to_file(
+        &crate_path.join("Cargo.toml"),
+        CargoToml::icon_lib_contents(&meta, &[], false, false)?,
+    )
+    .await?;
+    write_to_file(
+        &crate_path.join("README.md"),
+        Readme::icon_lib_contents(&meta)?,
+    )
+    .await?;
+    write_to_file(
+        &crate_path.join("src/lib.rs"),
+        LibRs::icon_lib_contents(&meta, None, Vec::new(), None, &[], &[], &[])?,
+    )
+    .await?;
+
+    info!("Wiring crate into the workspace.");
+    wire_crate(&crate_name).await?;
+
+    info!(
+        crate_name,
+        "Package added. Run the build to download the package and generate its icons."
+    );
+    Ok(())
+}
+
+fn initial_crate_version() -> SemVer {
+    SemVer {
+        major: 0,
+        minor: 0,
+        patch: 1,
+        prerelease: None,
+        build: None,
+    }
+}
+
+/// Add a `PackageType` variant and its metadata to the package module.
+async fn register_package(
+    new_package: &NewPackage,
+    variant: &str,
+    url: &str,
+    tag: &str,
+    version: &SemVer,
+) -> Result<()> {
+    let path = path::build_crate("src/package/mod.rs");
+    let mut source = tokio::fs::read_to_string(&path).await?;
+
+    let enum_start = source
+        .find("pub enum PackageType {")
+        .ok_or_else(|| anyhow!("PackageType enum not found."))?;
+    let enum_end = enum_start
+        + source[enum_start..]
+            .find("\n}")
+            .ok_or_else(|| anyhow!("End of PackageType enum not found."))?;
+    if source[enum_start..enum_end].contains(&format!("    {variant},")) {
+        bail!("A package named {variant} already exists.");
+    }
+    source.insert_str(enum_end + 1, &format!("    {variant},\n"));
+
+    let metadata_start = source
+        .find("fn metadata(&self) -> PackageMetadata {")
+        .ok_or_else(|| anyhow!("PackageType::metadata not found."))?;
+    let match_end = metadata_start
+        + source[metadata_start..]
+            .find("\n        }\n    }\n")
+            .ok_or_else(|| anyhow!("End of PackageType::metadata not found."))?;
+    let crate_version = initial_crate_version();
+    source.insert_str(
+        match_end + 1,
+        &format!(
+            r#"            PackageType::{variant} => PackageMetadata {{
+                short_name: Cow::Borrowed("{short_name}"),
+                package_name: Cow::Borrowed("{name}"),
+                licenses: &[Cow::Borrowed("{license}")],
+                source: PackageSource::Git {{
+                    url: Cow::Borrowed("{url}"),
+                    target: GitTarget::Tag {{
+                        name: Cow::Borrowed("{tag}"),
+                        version: {version},
+                    }},
+                }},
+                download_dir: Cow::Borrowed("{download_dir}"),
+                svg_dir: Cow::Borrowed("{svg_dir}"),
+                crate_version: {crate_version},
+                pinned: &[],
+                expand_strokes: false,
+                opt_in: false,
+            }},
+"#,
+            short_name = new_package.short_name,
+            name = new_package.name,
+            license = new_package.license,
+            version = sem_ver_literal(version, 24),
+            download_dir = new_package.name.to_kebab_case(),
+            svg_dir = new_package.svg_dir,
+            crate_version = sem_ver_literal(&crate_version, 16),
+        ),
+    );
+
+    write_to_file(&path, source).await
+}
+
+/// Rust source constructing the given version, indented to fit at the given level.
+fn sem_ver_literal(version: &SemVer, indent: usize) -> String {
+    let field_indent = " ".repeat(indent + 4);
+    let indent = " ".repeat(indent);
+    format!(
+        "SemVer {{\n\
+         {field_indent}major: {},\n\
+         {field_indent}minor: {},\n\
+         {field_indent}patch: {},\n\
+         {field_indent}prerelease: None,\n\
+         {field_indent}build: None,\n\
+         {indent}}}",
+        version.major, version.minor, version.patch
+    )
+}
+
+/// Add an icon name fixture for the new package, which must be adjusted to an actual icon once the
+/// package was downloaded.
+async fn add_fixture(new_package: &NewPackage, variant: &str) -> Result<()> {
+    let path = path::build_crate("src/icon/mod.rs");
+    let mut source = tokio::fs::read_to_string(&path).await?;
+
+    let fixtures_start = source
+        .find("const FIXTURES")
+        .ok_or_else(|| anyhow!("Icon name fixtures not found."))?;
+    let fixtures_end = fixtures_start
+        + source[fixtures_start..]
+            .find("\n    ];")
+            .ok_or_else(|| anyhow!("End of icon name fixtures not found."))?;
+    source.insert_str(
+        fixtures_end + 1,
+        &format!(
+            "        (PackageType::{variant}, \"{short_name}\", \"example\", \"{prefix}Example\"),\n",
+            short_name = new_package.short_name,
+            prefix = new_package.short_name.to_pascal_case(),
+        ),
+    );
+
+    write_to_file(&path, source).await
+}
+
+/// Add the crate to the workspace and make the main library depend on and re-export it.
+async fn wire_crate(crate_name: &str) -> Result<()> {
+    let workspace_path = path::library_crate("Cargo.toml");
+    let workspace = tokio::fs::read_to_string(&workspace_path).await?;
+    let workspace = insert_sorted_line(
+        &workspace,
+        "    \"icondata_",
+        format!("    \"{crate_name}\","),
+    );
+    write_to_file(&workspace_path, workspace).await?;
+
+    let main_lib = path::library_crate("icondata");
+    let cargo_toml = tokio::fs::read_to_string(main_lib.join("Cargo.toml")).await?;
+    let cargo_toml = insert_sorted_line(
+        &cargo_toml,
+        "icondata_",
+        format!(
+            r#"{crate_name} = {{ path = "../{crate_name}", version = "{}", default-features = false }}"#,
+            initial_crate_version()
+        ),
+    );
+    write_to_file(&main_lib.join("Cargo.toml"), cargo_toml).await?;
+
+    let lib_rs = tokio::fs::read_to_string(main_lib.join("src/lib.rs")).await?;
+    let lib_rs = insert_sorted_line(
+        &lib_rs,
+        "pub use icondata_",
+        format!("pub use {crate_name}::*;"),
+    );
+    write_to_file(&main_lib.join("src/lib.rs"), lib_rs).await
+}
+
+/// Insert `line` among the lines starting with `prefix`, keeping them sorted.
+fn insert_sorted_line(contents: &str, prefix: &str, line: String) -> String {
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    let matching = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, existing)| existing.starts_with(prefix) && !existing.contains("_core"))
+        .map(|(i, existing)| (i, *existing))
+        .collect::<Vec<_>>();
+    let index = match matching
+        .iter()
+        .find(|(_, existing)| *existing > line.as_str())
+    {
+        Some((i, _)) => *i,
+        None => matching.last().map(|(i, _)| i + 1).unwrap_or(lines.len()),
+    };
+    lines.insert(index, &line);
+
+    let mut contents_out = lines.join("\n");
+    if contents.ends_with('\n') {
+        contents_out.push('\n');
+    }
+    contents_out
+}
+
+#[cfg(test)]
+mod test {
+    use super::insert_sorted_line;
+
+    #[test]
+    fn insert_sorted() {
+        let contents =
+            "pub use icondata_ai::*;\npub use icondata_fa::*;\n\npub use icondata_core::Icon;\n";
+        pretty_assertions::assert_eq!(
+            insert_sorted_line(contents, "pub use icondata_", "pub use icondata_bs::*;".to_owned()),
+            "pub use icondata_ai::*;\npub use icondata_bs::*;\npub use icondata_fa::*;\n\npub use icondata_core::Icon;\n"
+        );
+        pretty_assertions::assert_eq!(
+            insert_sorted_line(contents, "pub use icondata_", "pub use icondata_wi::*;".to_owned()),
+            "pub use icondata_ai::*;\npub use icondata_fa::*;\npub use icondata_wi::*;\n\npub use icondata_core::Icon;\n"
+        );
+    }
+}
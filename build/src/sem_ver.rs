@@ -1,4 +1,6 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::anyhow;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct SemVer {
@@ -9,6 +11,44 @@ pub struct SemVer {
     pub build: Option<String>,
 }
 
+/// Kind of change made to a crate, ordered by severity.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Change {
+    /// Existing items changed without affecting the public API.
+    Patch,
+    /// New items were added.
+    Addition,
+    /// Items were removed or renamed.
+    Breaking,
+}
+
+impl SemVer {
+    /// The next version after applying a change of the given kind, following cargo's semver rules
+    /// where the first non-zero component is the "major" one.
+    pub fn bump(&self, change: Change) -> SemVer {
+        let (major, minor, patch) = match (change, self.major, self.minor) {
+            (Change::Breaking, 0, 0) => (0, 0, self.patch + 1),
+            (Change::Breaking, 0, minor) => (0, minor + 1, 0),
+            (Change::Breaking, major, _) => (major + 1, 0, 0),
+            (Change::Addition, 0, minor) => (0, minor, self.patch + 1),
+            (Change::Addition, major, minor) => (major, minor + 1, 0),
+            (Change::Patch, major, minor) => (major, minor, self.patch + 1),
+        };
+        SemVer {
+            major,
+            minor,
+            patch,
+            prerelease: None,
+            build: None,
+        }
+    }
+
+    /// Whether this version is lower than `other`, comparing major, minor and patch only.
+    pub fn is_lower_than(&self, other: &SemVer) -> bool {
+        (self.major, self.minor, self.patch) < (other.major, other.minor, other.patch)
+    }
+}
+
 impl Display for SemVer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let major = self.major;
@@ -24,3 +64,53 @@ impl Display for SemVer {
         }
     }
 }
+
+impl FromStr for SemVer {
+    type Err = anyhow::Error;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let (rest, build) = match str.split_once('+') {
+            Some((rest, build)) => (rest, Some(build.to_owned())),
+            None => (str, None),
+        };
+        let (rest, prerelease) = match rest.split_once('-') {
+            Some((rest, pre)) => (rest, Some(pre.to_owned())),
+            None => (rest, None),
+        };
+        let mut parts = rest.split('.').map(u32::from_str);
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch)), None) => Ok(SemVer {
+                major,
+                minor,
+                patch,
+                prerelease,
+                build,
+            }),
+            _ => Err(anyhow!("Version '{str}' is not a valid semantic version!")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Change, SemVer};
+
+    #[test]
+    fn parse_and_display() {
+        let version: SemVer = "1.2.3-beta.1+abc".parse().expect("valid");
+        assert_eq!(version.to_string(), "1.2.3-beta.1+abc");
+        assert!("1.2".parse::<SemVer>().is_err());
+    }
+
+    #[test]
+    fn bump() {
+        let version = |str: &str| str.parse::<SemVer>().unwrap();
+        assert_eq!(version("0.0.10").bump(Change::Breaking), version("0.0.11"));
+        assert_eq!(version("0.3.0").bump(Change::Breaking), version("0.4.0"));
+        assert_eq!(version("0.3.0").bump(Change::Addition), version("0.3.1"));
+        assert_eq!(version("1.2.3").bump(Change::Addition), version("1.3.0"));
+        assert_eq!(version("1.2.3").bump(Change::Patch), version("1.2.4"));
+        assert!(version("0.9.9").is_lower_than(&version("0.10.0")));
+        assert!(!version("1.2.3-beta").is_lower_than(&version("1.2.3")));
+    }
+}
@@ -0,0 +1,38 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use minisign::SecretKey;
+use tracing::{info, instrument};
+
+use crate::dirs::write_to_file;
+
+/// Environment variable holding the password of the signing key. Without it, the password is
+/// prompted for, unless the key is not encrypted.
+const PASSWORD_VAR: &str = "ICONDATA_SIGNING_PASSWORD";
+
+/// Sign an exported artifact with the minisign secret key at `key`, writing the signature to
+/// `<artifact>.minisig`, where consumers like `icondata_core::Catalog::from_signed_bytes` expect
+/// it.
+#[instrument(level = "info")]
+pub(crate) async fn sign(artifact: &Path, key: &Path, trusted_comment: &str) -> Result<()> {
+    let secret_key = SecretKey::from_file(key, std::env::var(PASSWORD_VAR).ok())?;
+    let contents = tokio::fs::read(artifact).await?;
+    let signature = minisign::sign(
+        None,
+        &secret_key,
+        contents.as_slice(),
+        Some(trusted_comment),
+        None,
+    )?;
+
+    let signature_path = signature_path(artifact);
+    write_to_file(&signature_path, signature.into_string()).await?;
+    info!(?signature_path, "Signed artifact.");
+    Ok(())
+}
+
+fn signature_path(artifact: &Path) -> PathBuf {
+    let mut path = artifact.as_os_str().to_owned();
+    path.push(".minisig");
+    PathBuf::from(path)
+}
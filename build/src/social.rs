@@ -0,0 +1,192 @@
+//! Social platforms content is shared to, generated into the `social` module of the main library
+//! as the `SocialPlatform` enum, for share-button bars.
+//!
+//! Every platform lists the logos of a few packages in order of preference, official logos of
+//! Simple Icons first. The generated `SocialPlatform::icon` returns the first of them whose feature
+//! is enabled.
+
+use anyhow::Result;
+
+use crate::migration::{self, PreferredIcon};
+
+/// A platform of the generated `SocialPlatform` enum.
+#[derive(Debug)]
+pub(crate) struct Platform {
+    pub variant: &'static str,
+    pub name: &'static str,
+    /// Official brand color, used if the chosen icon carries none.
+    pub color: &'static str,
+    /// Start of the link sharing a page, to which the percent-encoded url of the page is appended.
+    /// `None` for platforms without a central share link, like the instances of Mastodon.
+    pub share_url: Option<&'static str>,
+    /// Names of the icons showing the logo of the platform, in order of preference.
+    pub icons: &'static [&'static str],
+}
+
+const PLATFORMS: &[Platform] = &[
+    Platform {
+        variant: "X",
+        name: "X",
+        color: "#000000",
+        share_url: Some("https://x.com/intent/tweet?url="),
+        icons: &["SiX", "FaXTwitterBrands", "BsTwitterX", "TbBrandX"],
+    },
+    Platform {
+        variant: "Facebook",
+        name: "Facebook",
+        color: "#0866FF",
+        share_url: Some("https://www.facebook.com/sharer/sharer.php?u="),
+        icons: &[
+            "SiFacebook",
+            "FaFacebookBrands",
+            "BsFacebook",
+            "TbBrandFacebook",
+        ],
+    },
+    Platform {
+        variant: "LinkedIn",
+        name: "LinkedIn",
+        color: "#0A66C2",
+        share_url: Some("https://www.linkedin.com/sharing/share-offsite/?url="),
+        icons: &[
+            "SiLinkedin",
+            "FaLinkedinBrands",
+            "BsLinkedin",
+            "TbBrandLinkedin",
+        ],
+    },
+    Platform {
+        variant: "Mastodon",
+        name: "Mastodon",
+        color: "#6364FF",
+        share_url: None,
+        icons: &[
+            "SiMastodon",
+            "FaMastodonBrands",
+            "BsMastodon",
+            "TbBrandMastodon",
+        ],
+    },
+    Platform {
+        variant: "Reddit",
+        name: "Reddit",
+        color: "#FF4500",
+        share_url: Some("https://www.reddit.com/submit?url="),
+        icons: &["SiReddit", "FaRedditBrands", "BsReddit", "TbBrandReddit"],
+    },
+    Platform {
+        variant: "WhatsApp",
+        name: "WhatsApp",
+        color: "#25D366",
+        share_url: Some("https://wa.me/?text="),
+        icons: &[
+            "SiWhatsapp",
+            "FaWhatsappBrands",
+            "BsWhatsapp",
+            "TbBrandWhatsapp",
+        ],
+    },
+    Platform {
+        variant: "Telegram",
+        name: "Telegram",
+        color: "#26A5E4",
+        share_url: Some("https://t.me/share/url?url="),
+        icons: &[
+            "SiTelegram",
+            "FaTelegramBrands",
+            "BsTelegram",
+            "TbBrandTelegram",
+        ],
+    },
+    Platform {
+        variant: "Threads",
+        name: "Threads",
+        color: "#000000",
+        share_url: Some("https://www.threads.net/intent/post?text="),
+        icons: &[
+            "SiThreads",
+            "FaThreadsBrands",
+            "BsThreads",
+            "TbBrandThreads",
+        ],
+    },
+    Platform {
+        variant: "Pinterest",
+        name: "Pinterest",
+        color: "#BD081C",
+        share_url: Some("https://pinterest.com/pin/create/button/?url="),
+        icons: &[
+            "SiPinterest",
+            "FaPinterestBrands",
+            "BsPinterest",
+            "TbBrandPinterest",
+        ],
+    },
+    Platform {
+        variant: "Tumblr",
+        name: "Tumblr",
+        color: "#36465D",
+        share_url: Some("https://www.tumblr.com/widgets/share/tool?canonicalUrl="),
+        icons: &["SiTumblr", "FaTumblrBrands", "TbBrandTumblr"],
+    },
+    Platform {
+        variant: "HackerNews",
+        name: "Hacker News",
+        color: "#F0652F",
+        share_url: Some("https://news.ycombinator.com/submitlink?u="),
+        icons: &["SiYcombinator", "TbBrandYcombinator"],
+    },
+    Platform {
+        variant: "Line",
+        name: "LINE",
+        color: "#00C300",
+        share_url: Some("https://social-plugins.line.me/lineit/share?url="),
+        icons: &["SiLine", "FaLineBrands", "BsLine", "TbBrandLine"],
+    },
+    Platform {
+        variant: "Vk",
+        name: "VK",
+        color: "#0077FF",
+        share_url: Some("https://vk.com/share.php?url="),
+        icons: &["SiVk", "FaVkBrands", "TbBrandVk"],
+    },
+    Platform {
+        variant: "Weibo",
+        name: "Weibo",
+        color: "#E6162D",
+        share_url: Some("https://service.weibo.com/share/share.php?url="),
+        icons: &["FaWeiboBrands", "TbBrandWeibo"],
+    },
+    Platform {
+        variant: "Xing",
+        name: "XING",
+        color: "#006567",
+        share_url: Some("https://www.xing.com/spi/shares/new?url="),
+        icons: &["SiXing", "FaXingBrands", "TbBrandXing"],
+    },
+];
+
+/// All platforms, with the icons of the current packages showing them.
+pub(crate) fn platforms() -> Result<Vec<(&'static Platform, Vec<PreferredIcon>)>> {
+    PLATFORMS
+        .iter()
+        .map(|platform| Ok((platform, migration::preferred_icons(platform.icons)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::PLATFORMS;
+
+    #[test]
+    fn colors_are_hex() {
+        for platform in PLATFORMS {
+            let digits = platform.color.strip_prefix('#').unwrap_or_default();
+            assert!(
+                digits.len() == 6 && digits.chars().all(|c| c.is_ascii_hexdigit()),
+                "{}",
+                platform.name
+            );
+        }
+    }
+}
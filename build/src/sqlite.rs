@@ -0,0 +1,201 @@
+use std::path::Path;
+
+use anyhow::Result;
+use rusqlite::{params, Connection};
+use tracing::{info, instrument};
+
+use crate::{
+    icon::SvgIcon,
+    package::{Downloaded, GitTarget, Package, PackageSource},
+    signing, Packages,
+};
+
+/// Tables of the exported database. `icons_search` is a full text index over the names, labels and
+/// tags of the icons, sharing the rowids of `icons`. `icon_colors` holds the dominant colors of the
+/// icons and `icon_color_conflicts` the pairs of them confused with a color vision deficiency.
+/// `icon_groups` holds the semantic groups of the icons, like "navigation" or "commerce".
+const SCHEMA: &str = "
+CREATE TABLE packages (
+    short_name TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    licenses TEXT NOT NULL,
+    upstream_version TEXT,
+    crate_version TEXT NOT NULL
+);
+CREATE TABLE icons (
+    id TEXT PRIMARY KEY,
+    package TEXT NOT NULL REFERENCES packages (short_name),
+    label TEXT NOT NULL,
+    variant TEXT,
+    brand INTEGER NOT NULL,
+    style TEXT,
+    x TEXT,
+    y TEXT,
+    width TEXT,
+    height TEXT,
+    view_box TEXT,
+    stroke_linecap TEXT,
+    stroke_linejoin TEXT,
+    stroke_width TEXT,
+    stroke TEXT,
+    fill TEXT,
+    data BLOB NOT NULL
+);
+CREATE INDEX icons_package ON icons (package);
+CREATE TABLE icon_colors (
+    icon TEXT NOT NULL REFERENCES icons (id),
+    color TEXT NOT NULL,
+    share REAL NOT NULL,
+    PRIMARY KEY (icon, color)
+);
+CREATE TABLE icon_color_conflicts (
+    icon TEXT NOT NULL REFERENCES icons (id),
+    color TEXT NOT NULL,
+    other_color TEXT NOT NULL,
+    deficiency TEXT NOT NULL
+);
+CREATE TABLE icon_groups (
+    icon TEXT NOT NULL REFERENCES icons (id),
+    name TEXT NOT NULL,
+    PRIMARY KEY (icon, name)
+);
+CREATE INDEX icon_groups_name ON icon_groups (name);
+CREATE VIRTUAL TABLE icons_search USING fts5 (id, label, tags);
+";
+
+/// Write all icons of all packages to `out` as SQLite database, e.g. to be queried with
+/// `SELECT id FROM icons_search WHERE icons_search MATCH 'arrow'`. The database is signed with the
+/// minisign secret key at `signing_key` if given.
+#[instrument(level = "info")]
+pub(crate) async fn export(out: &Path, signing_key: Option<&Path>) -> Result<()> {
+    let path = out.to_path_buf();
+    let num_icons = tokio::task::spawn_blocking(move || write_database(&path)).await??;
+
+    info!(num_icons, ?out, "Exported SQLite database.");
+
+    if let Some(signing_key) = signing_key {
+        signing::sign(out, signing_key, "icondata sqlite database").await?;
+    }
+    Ok(())
+}
+
+fn write_database(path: &Path) -> Result<usize> {
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+    let mut connection = Connection::open(path)?;
+    connection.execute_batch(SCHEMA)?;
+
+    let transaction = connection.transaction()?;
+    let mut num_icons = 0;
+    {
+        let mut insert_package = transaction.prepare(
+            "INSERT INTO packages (short_name, name, licenses, upstream_version, crate_version)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+        )?;
+        let mut insert_icon = transaction.prepare(
+            "INSERT INTO icons (id, package, label, variant, brand, style, x, y, width, height,
+                view_box, stroke_linecap, stroke_linejoin, stroke_width, stroke, fill, data)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)",
+        )?;
+        let mut insert_search = transaction
+            .prepare("INSERT INTO icons_search (rowid, id, label, tags) VALUES (?1, ?2, ?3, ?4)")?;
+        let mut insert_color = transaction
+            .prepare("INSERT INTO icon_colors (icon, color, share) VALUES (?1, ?2, ?3)")?;
+        let mut insert_conflict = transaction.prepare(
+            "INSERT INTO icon_color_conflicts (icon, color, other_color, deficiency)
+             VALUES (?1, ?2, ?3, ?4)",
+        )?;
+        let mut insert_group =
+            transaction.prepare("INSERT INTO icon_groups (icon, name) VALUES (?1, ?2)")?;
+
+        for package in Packages::get()? {
+            insert_package.execute(params![
+                package.meta.short_name.as_ref(),
+                package.meta.package_name.as_ref(),
+                package.meta.licenses.join(", "),
+                upstream_version(package),
+                package.meta.crate_version.to_string(),
+            ])?;
+
+            for icon in package.icons() {
+                let attributes = icon.svg.svg_attributes();
+                let rowid = insert_icon.insert(params![
+                    icon.name,
+                    package.meta.short_name.as_ref(),
+                    icon.label,
+                    icon.style,
+                    icon.brand,
+                    attributes.style.as_deref(),
+                    attributes.x.as_deref(),
+                    attributes.y.as_deref(),
+                    attributes.width.as_deref(),
+                    attributes.height.as_deref(),
+                    attributes.view_box.as_deref(),
+                    attributes.stroke_linecap.as_deref(),
+                    attributes.stroke_linejoin.as_deref(),
+                    attributes.stroke_width.as_deref(),
+                    attributes.stroke.as_deref(),
+                    attributes.fill.as_deref(),
+                    icon.svg.content.as_bytes(),
+                ])?;
+                insert_search.execute(params![
+                    rowid,
+                    icon.name,
+                    icon.label,
+                    tags(package, icon)
+                ])?;
+                for color in &icon.palette.colors {
+                    insert_color.execute(params![icon.name, color.hex, color.share])?;
+                }
+                for conflict in &icon.palette.conflicts {
+                    let [color, other_color] = &conflict.colors;
+                    insert_conflict.execute(params![
+                        icon.name,
+                        color,
+                        other_color,
+                        conflict.deficiency.as_str()
+                    ])?;
+                }
+                for group in &icon.groups {
+                    insert_group.execute(params![icon.name, group.as_str()])?;
+                }
+                num_icons += 1;
+            }
+        }
+    }
+    transaction.commit()?;
+    Ok(num_icons)
+}
+
+fn upstream_version(package: &Package<Downloaded>) -> Option<String> {
+    match &package.meta.source {
+        PackageSource::Git { target, .. } => match target {
+            GitTarget::Branch { version_hint, .. } => {
+                version_hint.as_ref().map(|version| version.to_string())
+            }
+            GitTarget::Tag { version, .. } => Some(version.to_string()),
+        },
+        PackageSource::NpmTarball { version, .. } => Some(version.clone()),
+        PackageSource::LocalPath { .. } => None,
+    }
+}
+
+/// Additional terms an icon can be found by: its package, variant, semantic groups, previous names
+/// and whether it is a brand or multi-colored icon.
+fn tags(package: &Package<Downloaded>, icon: &SvgIcon) -> String {
+    let mut tags = vec![
+        package.meta.short_name.as_ref(),
+        package.meta.package_name.as_ref(),
+    ];
+    tags.extend(icon.style.as_deref());
+    tags.extend(icon.groups.iter().map(|group| group.as_str()));
+    if icon.brand {
+        tags.push("brand");
+    }
+    if icon.palette.is_multi_color() {
+        tags.push("multicolor");
+    }
+    tags.extend(icon.aliases.iter().map(String::as_str));
+    tags.join(" ")
+}
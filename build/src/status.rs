@@ -0,0 +1,171 @@
+//! Classes of HTTP status codes and log levels, generated into the `status` module of the main
+//! library as the `HttpStatus` and `LogLevel` enums, for observability dashboards.
+//!
+//! Every variant has a severity, colored by the `Theme` of the generated module, and lists icons
+//! of a few packages in order of preference, like [`crate::user_agent`].
+
+use anyhow::Result;
+
+use crate::migration::{self, PreferredIcon};
+
+/// Severity of a status, a variant of the generated `Severity` enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Severity {
+    Neutral,
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+/// A class of HTTP status codes, like 4xx.
+#[derive(Debug)]
+pub(crate) struct HttpStatus {
+    pub variant: &'static str,
+    /// First digit of the status codes of the class.
+    pub class: u16,
+    /// What a status code of the class tells, completing its doc comment.
+    pub doc: &'static str,
+    pub severity: Severity,
+    /// Names of the icons showing the class, in order of preference.
+    pub icons: &'static [&'static str],
+}
+
+/// A level of log records.
+#[derive(Debug)]
+pub(crate) struct LogLevel {
+    pub variant: &'static str,
+    /// Lowercase names the level is parsed from, the first one being its canonical name.
+    pub names: &'static [&'static str],
+    pub severity: Severity,
+    /// Names of the icons showing the level, in order of preference.
+    pub icons: &'static [&'static str],
+}
+
+const INFO: &[&str] = &["LuInfo", "TbInfoCircle", "BsInfoCircle"];
+const WARNING: &[&str] = &[
+    "LuTriangleAlert",
+    "TbAlertTriangle",
+    "BsExclamationTriangle",
+];
+const ERROR: &[&str] = &["LuCircleX", "TbCircleX", "BsXCircle"];
+
+const HTTP_STATUSES: &[HttpStatus] = &[
+    HttpStatus {
+        variant: "Informational",
+        class: 1,
+        doc: "the request was received and is being processed",
+        severity: Severity::Info,
+        icons: INFO,
+    },
+    HttpStatus {
+        variant: "Success",
+        class: 2,
+        doc: "the request succeeded",
+        severity: Severity::Success,
+        icons: &["LuCircleCheck", "TbCircleCheck", "BsCheckCircle"],
+    },
+    HttpStatus {
+        variant: "Redirection",
+        class: 3,
+        doc: "the resource is found elsewhere",
+        severity: Severity::Info,
+        icons: &["LuCornerUpRight", "TbCornerUpRight", "BsArrowReturnRight"],
+    },
+    HttpStatus {
+        variant: "ClientError",
+        class: 4,
+        doc: "the request was invalid",
+        severity: Severity::Warning,
+        icons: WARNING,
+    },
+    HttpStatus {
+        variant: "ServerError",
+        class: 5,
+        doc: "the server failed to handle a valid request",
+        severity: Severity::Error,
+        icons: &["LuServerCrash", "TbServerOff", "BsXOctagon"],
+    },
+];
+
+const LOG_LEVELS: &[LogLevel] = &[
+    LogLevel {
+        variant: "Trace",
+        names: &["trace"],
+        severity: Severity::Neutral,
+        icons: &["LuActivity", "TbActivity", "BsActivity"],
+    },
+    LogLevel {
+        variant: "Debug",
+        names: &["debug"],
+        severity: Severity::Neutral,
+        icons: &["LuBug", "TbBug", "BsBug"],
+    },
+    LogLevel {
+        variant: "Info",
+        names: &["info", "information"],
+        severity: Severity::Info,
+        icons: INFO,
+    },
+    LogLevel {
+        variant: "Warn",
+        names: &["warn", "warning"],
+        severity: Severity::Warning,
+        icons: WARNING,
+    },
+    LogLevel {
+        variant: "Error",
+        names: &["error", "err", "fatal", "critical"],
+        severity: Severity::Error,
+        icons: ERROR,
+    },
+];
+
+/// The classes of HTTP status codes, each with the icons of the current packages showing it.
+pub(crate) type HttpStatuses = Vec<(&'static HttpStatus, Vec<PreferredIcon>)>;
+
+/// The log levels, each with the icons of the current packages showing it.
+pub(crate) type LogLevels = Vec<(&'static LogLevel, Vec<PreferredIcon>)>;
+
+/// All classes of HTTP status codes and log levels, with the icons of the current packages
+/// showing them.
+pub(crate) fn statuses() -> Result<(HttpStatuses, LogLevels)> {
+    let http_statuses = HTTP_STATUSES
+        .iter()
+        .map(|status| Ok((status, migration::preferred_icons(status.icons)?)))
+        .collect::<Result<_>>()?;
+    let log_levels = LOG_LEVELS
+        .iter()
+        .map(|level| Ok((level, migration::preferred_icons(level.icons)?)))
+        .collect::<Result<_>>()?;
+    Ok((http_statuses, log_levels))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::{HTTP_STATUSES, LOG_LEVELS};
+
+    #[test]
+    fn statuses_are_unambiguous() {
+        let classes = HTTP_STATUSES
+            .iter()
+            .map(|status| status.class)
+            .collect::<HashSet<_>>();
+        assert_eq!(classes.len(), HTTP_STATUSES.len());
+        assert!(classes.iter().all(|class| (1..=5).contains(class)));
+
+        let names = LOG_LEVELS
+            .iter()
+            .flat_map(|level| level.names)
+            .collect::<HashSet<_>>();
+        assert_eq!(
+            names.len(),
+            LOG_LEVELS.iter().map(|level| level.names.len()).sum::<usize>()
+        );
+        assert!(names
+            .iter()
+            .all(|name| name.chars().all(|c| c.is_ascii_lowercase())));
+    }
+}
@@ -0,0 +1,232 @@
+//! Operating systems, browsers and kinds of devices as detected from user agents, generated into
+//! the `user_agent` module of the main library as the `Os`, `Browser` and `Device` enums, for
+//! analytics dashboards and session lists.
+//!
+//! Every variant lists icons of a few packages in order of preference, official logos of Simple
+//! Icons first. The generated `icon` methods return the first of them whose feature is enabled.
+
+use anyhow::Result;
+
+use crate::migration::{self, PreferredIcon};
+
+/// An enum of the generated module.
+#[derive(Debug)]
+pub(crate) struct Enum {
+    pub name: &'static str,
+    /// Summary of the doc comment of the enum, e.g. "A browser".
+    pub doc: &'static str,
+    /// What a variant is, e.g. "browser", completing the doc comments of the methods.
+    pub noun: &'static str,
+    pub variants: &'static [Variant],
+}
+
+#[derive(Debug)]
+pub(crate) struct Variant {
+    pub variant: &'static str,
+    /// Name as shown to users, e.g. "macOS".
+    pub name: &'static str,
+    /// Names of the icons showing the variant, in order of preference.
+    pub icons: &'static [&'static str],
+}
+
+const ENUMS: &[Enum] = &[
+    Enum {
+        name: "Os",
+        doc: "An operating system",
+        noun: "operating system",
+        variants: &[
+            Variant {
+                variant: "Windows",
+                name: "Windows",
+                icons: &[
+                    "SiWindows",
+                    "FaWindowsBrands",
+                    "BsWindows",
+                    "TbBrandWindows",
+                ],
+            },
+            Variant {
+                variant: "MacOs",
+                name: "macOS",
+                icons: &["SiMacos", "FaAppleBrands", "BsApple", "TbBrandApple"],
+            },
+            Variant {
+                variant: "Ios",
+                name: "iOS",
+                icons: &["SiIos", "FaAppleBrands", "BsApple", "TbBrandApple"],
+            },
+            Variant {
+                variant: "Android",
+                name: "Android",
+                icons: &[
+                    "SiAndroid",
+                    "FaAndroidBrands",
+                    "BsAndroid2",
+                    "TbBrandAndroid",
+                ],
+            },
+            Variant {
+                variant: "Linux",
+                name: "Linux",
+                icons: &["SiLinux", "FaLinuxBrands"],
+            },
+            Variant {
+                variant: "Ubuntu",
+                name: "Ubuntu",
+                icons: &["SiUbuntu", "FaUbuntuBrands", "BsUbuntu", "TbBrandUbuntu"],
+            },
+            Variant {
+                variant: "FreeBsd",
+                name: "FreeBSD",
+                icons: &["SiFreebsd", "FaFreebsdBrands"],
+            },
+        ],
+    },
+    Enum {
+        name: "Browser",
+        doc: "A browser",
+        noun: "browser",
+        variants: &[
+            Variant {
+                variant: "Chrome",
+                name: "Chrome",
+                icons: &[
+                    "SiGooglechrome",
+                    "FaChromeBrands",
+                    "BsBrowserChrome",
+                    "TbBrandChrome",
+                ],
+            },
+            Variant {
+                variant: "Firefox",
+                name: "Firefox",
+                icons: &[
+                    "SiFirefoxbrowser",
+                    "FaFirefoxBrowserBrands",
+                    "BsBrowserFirefox",
+                    "TbBrandFirefox",
+                ],
+            },
+            Variant {
+                variant: "Safari",
+                name: "Safari",
+                icons: &[
+                    "SiSafari",
+                    "FaSafariBrands",
+                    "BsBrowserSafari",
+                    "TbBrandSafari",
+                ],
+            },
+            Variant {
+                variant: "Edge",
+                name: "Edge",
+                icons: &[
+                    "SiMicrosoftedge",
+                    "FaEdgeBrands",
+                    "BsBrowserEdge",
+                    "TbBrandEdge",
+                ],
+            },
+            Variant {
+                variant: "Opera",
+                name: "Opera",
+                icons: &["SiOpera", "FaOperaBrands", "TbBrandOpera"],
+            },
+            Variant {
+                variant: "Brave",
+                name: "Brave",
+                icons: &["SiBrave"],
+            },
+            Variant {
+                variant: "Vivaldi",
+                name: "Vivaldi",
+                icons: &["SiVivaldi", "TbBrandVivaldi"],
+            },
+        ],
+    },
+    Enum {
+        name: "Device",
+        doc: "A kind of device",
+        noun: "kind of device",
+        variants: &[
+            Variant {
+                variant: "Desktop",
+                name: "Desktop",
+                icons: &["LuMonitor", "TbDeviceDesktop", "BsDisplay"],
+            },
+            Variant {
+                variant: "Laptop",
+                name: "Laptop",
+                icons: &["LuLaptop", "TbDeviceLaptop", "BsLaptop"],
+            },
+            Variant {
+                variant: "Mobile",
+                name: "Mobile",
+                icons: &["LuSmartphone", "TbDeviceMobile", "BsPhone"],
+            },
+            Variant {
+                variant: "Tablet",
+                name: "Tablet",
+                icons: &["LuTablet", "TbDeviceTablet", "BsTablet"],
+            },
+            Variant {
+                variant: "Tv",
+                name: "TV",
+                icons: &["LuTv", "TbDeviceTv", "BsTv"],
+            },
+            Variant {
+                variant: "Watch",
+                name: "Watch",
+                icons: &["LuWatch", "TbDeviceWatch", "BsSmartwatch"],
+            },
+            Variant {
+                variant: "Console",
+                name: "Console",
+                icons: &["LuGamepad2", "TbDeviceGamepad2", "BsController"],
+            },
+            Variant {
+                variant: "Bot",
+                name: "Bot",
+                icons: &["LuBot", "TbRobot", "BsRobot"],
+            },
+        ],
+    },
+];
+
+/// The variants of an enum, each with the icons of the current packages showing it.
+pub(crate) type Variants = Vec<(&'static Variant, Vec<PreferredIcon>)>;
+
+/// All enums, with the icons of the current packages showing their variants.
+pub(crate) fn enums() -> Result<Vec<(&'static Enum, Variants)>> {
+    ENUMS
+        .iter()
+        .map(|ty| {
+            let variants = ty
+                .variants
+                .iter()
+                .map(|variant| Ok((variant, migration::preferred_icons(variant.icons)?)))
+                .collect::<Result<_>>()?;
+            Ok((ty, variants))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::ENUMS;
+
+    #[test]
+    fn variants_are_unique() {
+        for ty in ENUMS {
+            let variants = ty
+                .variants
+                .iter()
+                .map(|variant| variant.variant)
+                .collect::<HashSet<_>>();
+            assert_eq!(variants.len(), ty.variants.len(), "{}", ty.name);
+            assert!(ty.variants.iter().all(|variant| !variant.icons.is_empty()));
+        }
+    }
+}
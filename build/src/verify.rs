@@ -0,0 +1,255 @@
+use std::collections::{BTreeMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use icondata_core::{IconData, RenderOptions};
+use regex::Regex;
+use tracing::{error, info, instrument};
+
+use crate::{
+    bundle::BUNDLES,
+    dirs::Library,
+    fidelity,
+    icon::svg::{ColorMode, ParsedSvg},
+    path, Packages,
+};
+
+/// Check that the libraries on disk are exactly what the generator would produce right now,
+/// that the `icondata` crate depends on every icon crate in its current version, that every
+/// icon can be reached through the `icondata` crate and that every icon parses back to the same
+/// data once rendered.
+#[instrument(level = "info", skip_all)]
+pub(crate) async fn run(libraries: &[Library<'_>]) -> Result<()> {
+    let mut problems = Vec::new();
+
+    for lib in libraries {
+        verify_generated(lib, &mut problems).await?;
+    }
+    verify_main_lib(&mut problems).await?;
+    verify_reachability(&mut problems)?;
+    verify_round_trip(&mut problems)?;
+
+    if problems.is_empty() {
+        info!("Verification successful!");
+        return Ok(());
+    }
+    for problem in &problems {
+        error!(problem, "Verification problem.");
+    }
+    Err(anyhow!(
+        "Verification failed with {} problem(s).",
+        problems.len()
+    ))
+}
+
+/// Compare each file of the library with a fresh generation, catching manual edits.
+async fn verify_generated(lib: &Library<'_>, problems: &mut Vec<String>) -> Result<()> {
+    let files = lib.files()?;
+
+    for (path, expected) in &files {
+        match tokio::fs::read_to_string(path).await {
+            Ok(actual) if actual == *expected => {}
+            Ok(_) => problems.push(format!("{path:?} differs from the generated output.")),
+            Err(_) => problems.push(format!("{path:?} is missing.")),
+        }
+    }
+
+    if let Some(src_dir) = lib.src_dir() {
+        let generated = files
+            .iter()
+            .map(|(path, _)| path.clone())
+            .collect::<HashSet<_>>();
+        for chunk in src_dir.existing_chunks().await? {
+            if !generated.contains(&chunk) {
+                problems.push(format!("{chunk:?} is not part of the generated output."));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check that the main library depends on and re-exports exactly the generated icon crates and
+/// bundles, using the versions currently declared by those crates.
+async fn verify_main_lib(problems: &mut Vec<String>) -> Result<()> {
+    let main_lib = path::generated_crate("icondata");
+    let cargo_toml = tokio::fs::read_to_string(main_lib.join("Cargo.toml")).await?;
+    let lib_rs = tokio::fs::read_to_string(main_lib.join("src/lib.rs")).await?;
+
+    let dependency =
+        Regex::new(r#"(?m)^icondata_(\w+) = \{ path = "[^"]*", version = "([^"]*)"[^}]*\}"#)?;
+    let mut dependencies = dependency
+        .captures_iter(&cargo_toml)
+        .map(|captures| (captures[1].to_owned(), captures[2].to_owned()))
+        .collect::<BTreeMap<_, _>>();
+
+    let crate_version = Regex::new(r#"(?m)^version = "([^"]*)""#)?;
+    let crates = Packages::get()?
+        .iter()
+        .map(|package| (package.meta.short_name.as_ref(), false))
+        .chain(BUNDLES.iter().map(|bundle| (bundle.name, true)));
+    for (short_name, is_bundle) in crates {
+        let crate_name = format!("icondata_{short_name}");
+
+        let icon_cargo_toml =
+            tokio::fs::read_to_string(path::generated_crate(&crate_name).join("Cargo.toml"))
+                .await?;
+        let version = crate_version
+            .captures(&icon_cargo_toml)
+            .map(|captures| captures[1].to_owned());
+
+        match dependencies.remove(short_name) {
+            None => problems.push(format!("icondata does not depend on {crate_name}.")),
+            Some(dependency_version) if Some(&dependency_version) != version.as_ref() => problems
+                .push(format!(
+                    "icondata depends on {crate_name} {dependency_version}, but the crate is at {version:?}."
+                )),
+            Some(_) => {}
+        }
+
+        let re_export = match is_bundle {
+            true => format!("pub use {crate_name} as {short_name};"),
+            false => format!("pub use {crate_name}::*;"),
+        };
+        if !lib_rs.contains(&re_export) {
+            problems.push(format!("icondata does not re-export {crate_name}."));
+        }
+    }
+
+    for short_name in dependencies.keys() {
+        problems.push(format!(
+            "icondata depends on icondata_{short_name}, which is not generated from any package or bundle."
+        ));
+    }
+    Ok(())
+}
+
+/// Glob re-exports of equally named icons from different crates are ambiguous, making all of them
+/// unreachable through the main library. Every icon name must therefore be declared exactly once.
+fn verify_reachability(problems: &mut Vec<String>) -> Result<()> {
+    let mut declarations = BTreeMap::<&str, Vec<&str>>::new();
+    for package in Packages::get()? {
+        for icon in package.icons() {
+            declarations
+                .entry(icon.name.as_str())
+                .or_default()
+                .push(package.meta.short_name.as_ref());
+        }
+    }
+
+    for (name, short_names) in declarations {
+        if short_names.len() > 1 {
+            problems.push(format!(
+                "Icon {name} is declared {} times (in {short_names:?}) and therefore not reachable.",
+                short_names.len()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Render every icon as defined by the generated crates and parse the markup again, catching
+/// asymmetries between the parser of the builder and the renderer of `icondata_core`.
+fn verify_round_trip(problems: &mut Vec<String>) -> Result<()> {
+    for icon in Packages::get_icons()? {
+        match round_trip(&fidelity::icon_data(icon)) {
+            Ok(differences) if differences.is_empty() => {}
+            Ok(differences) => problems.push(format!(
+                "Icon {} differs in {} once rendered and parsed again.",
+                icon.name,
+                differences.join(", ")
+            )),
+            Err(err) => problems.push(format!(
+                "Icon {} cannot be parsed once rendered: {err}",
+                icon.name
+            )),
+        }
+    }
+    Ok(())
+}
+
+/// Names of the fields of `icon` which differ once it is rendered and parsed again.
+fn round_trip(icon: &IconData) -> Result<Vec<&'static str>> {
+    let parsed = ParsedSvg::parse(
+        icon.render(&RenderOptions::default()).as_bytes(),
+        ColorMode::Monochrome,
+    )?;
+    let attributes = parsed.svg_attributes();
+    let fields = [
+        ("style", icon.style, attributes.style.as_deref()),
+        ("x", icon.x, attributes.x.as_deref()),
+        ("y", icon.y, attributes.y.as_deref()),
+        ("view_box", icon.view_box, attributes.view_box.as_deref()),
+        (
+            "stroke_linecap",
+            icon.stroke_linecap,
+            attributes.stroke_linecap.as_deref(),
+        ),
+        (
+            "stroke_linejoin",
+            icon.stroke_linejoin,
+            attributes.stroke_linejoin.as_deref(),
+        ),
+        (
+            "stroke_width",
+            icon.stroke_width,
+            attributes.stroke_width.as_deref(),
+        ),
+        ("stroke", icon.stroke, attributes.stroke.as_deref()),
+        // Icons without fill are rendered in the color of the surrounding text.
+        (
+            "fill",
+            icon.fill.or(Some("currentColor")),
+            attributes.fill.as_deref(),
+        ),
+        ("data", Some(icon.data), Some(parsed.content.as_str())),
+    ];
+    Ok(fields
+        .into_iter()
+        .filter(|(_, expected, actual)| expected != actual)
+        .map(|(name, _, _)| name)
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use icondata_core::{IconData, IconPaint};
+
+    use super::round_trip;
+    use crate::icon::svg::{ColorMode, ParsedSvg};
+
+    #[test]
+    fn rendered_icons_parse_to_equal_data() {
+        let svg = ParsedSvg::parse(
+            r##"<svg viewBox="0 0 24 24" stroke="#000" stroke-width="2"><g><path d="M4 4h16v16H4z"/></g></svg>"##
+                .as_bytes(),
+            ColorMode::Monochrome,
+        )
+        .unwrap();
+        let icon = IconData {
+            id: "TestSquare",
+            label: "Square",
+            source: None,
+            paint: IconPaint::Stroke,
+            brand_color: None,
+            style: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            view_box: Some("0 0 24 24"),
+            stroke_linecap: None,
+            stroke_linejoin: None,
+            stroke_width: Some("2"),
+            stroke: Some("#000"),
+            fill: None,
+            data: Box::leak(svg.content.into_boxed_str()),
+        };
+        assert!(round_trip(&icon).unwrap().is_empty());
+
+        // Content not written by the parser is normalized when parsing it again.
+        let icon = IconData {
+            data: r#"<path d="M4 4h16v16H4z"/>"#,
+            ..icon
+        };
+        assert_eq!(round_trip(&icon).unwrap(), ["data"]);
+    }
+}
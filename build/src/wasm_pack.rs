@@ -0,0 +1,60 @@
+use std::{
+    io,
+    path::Path,
+    process::{Command, ExitStatus},
+};
+
+use snafu::{prelude::*, Backtrace};
+use tracing::{debug, info, instrument, warn};
+
+#[derive(Debug, Snafu)]
+pub(crate) enum Error {
+    #[snafu(display("Could not execute command {cmd}"))]
+    ExecCommand {
+        source: io::Error,
+        cmd: String,
+        backtrace: Backtrace,
+    },
+    #[snafu(display("Command exited with error. status_code: {exit_status}, std_out:{std_out}, std_err:{std_err}"))]
+    Command {
+        exit_status: ExitStatus,
+        std_out: String,
+        std_err: String,
+        backtrace: Backtrace,
+    },
+}
+
+/// Build the crate located at `crate_dir` as WASM package for browsers, written to `out_dir`.
+#[instrument(level = "info")]
+pub(crate) fn build(crate_dir: &Path, out_dir: &Path) -> Result<(), Error> {
+    let build_output = {
+        let mut cmd = Command::new("wasm-pack");
+        cmd.args(["build", "--target", "web", "--release", "--out-dir"])
+            .arg(out_dir);
+        debug!(?cmd, "Constructed command to build WASM package.");
+        cmd.current_dir(crate_dir)
+            .output()
+            .context(ExecCommandSnafu {
+                cmd: format!("{cmd:?}"),
+            })
+    }?;
+
+    ensure!(build_output.status.success(), {
+        let std_out = String::from_utf8_lossy(&build_output.stdout).into_owned();
+        let std_err = String::from_utf8_lossy(&build_output.stderr).into_owned();
+        warn!(
+            exit_code = build_output.status.code(),
+            ?std_out,
+            ?std_err,
+            "WASM build failed."
+        );
+        CommandSnafu {
+            exit_status: build_output.status,
+            std_out,
+            std_err,
+        }
+    });
+
+    info!("WASM build successful.");
+    Ok(())
+}
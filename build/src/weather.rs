@@ -0,0 +1,230 @@
+//! Weather conditions as reported by weather APIs, generated into the `weather` module of the main
+//! library as the `Weather` enum, resolving condition codes of OpenWeatherMap and WMO weather
+//! codes to icons of Weather Icons.
+//!
+//! Weather Icons draws most conditions in a neutral variant, a "Day" variant with a sun and a
+//! "Night" variant with a moon, like `WiRain`, `WiDayRain` and `WiNightAltRain`. The generated
+//! `icon` method picks the variant of the time of day, falling back to the neutral one.
+
+use anyhow::Result;
+
+use crate::migration::{self, PreferredIcon};
+
+/// A variant of the generated `Weather` enum.
+#[derive(Debug)]
+pub(crate) struct Condition {
+    pub variant: &'static str,
+    /// Inclusive ranges of the condition codes of OpenWeatherMap, see
+    /// <https://openweathermap.org/weather-conditions>.
+    pub owm: &'static [(u16, u16)],
+    /// WMO weather interpretation codes, as reported by Open-Meteo, see
+    /// <https://open-meteo.com/en/docs>.
+    pub wmo: &'static [u8],
+    /// Icon of the condition regardless of the time of day.
+    pub icon: &'static str,
+    /// Icon of the condition during the day, if differing.
+    pub day: Option<&'static str>,
+    /// Icon of the condition at night, if differing.
+    pub night: Option<&'static str>,
+}
+
+const CONDITIONS: &[Condition] = &[
+    Condition {
+        variant: "Clear",
+        owm: &[(800, 800)],
+        wmo: &[0],
+        icon: "WiDaySunny",
+        day: None,
+        night: Some("WiNightClear"),
+    },
+    Condition {
+        variant: "PartlyCloudy",
+        owm: &[(801, 802)],
+        wmo: &[1, 2],
+        icon: "WiCloud",
+        day: Some("WiDayCloudy"),
+        night: Some("WiNightAltCloudy"),
+    },
+    Condition {
+        variant: "Overcast",
+        owm: &[(803, 804)],
+        wmo: &[3],
+        icon: "WiCloudy",
+        day: None,
+        night: None,
+    },
+    Condition {
+        variant: "Fog",
+        owm: &[(701, 701), (741, 741)],
+        wmo: &[45, 48],
+        icon: "WiFog",
+        day: Some("WiDayFog"),
+        night: Some("WiNightFog"),
+    },
+    Condition {
+        variant: "Haze",
+        owm: &[(721, 721)],
+        wmo: &[],
+        icon: "WiDayHaze",
+        day: None,
+        night: Some("WiNightFog"),
+    },
+    Condition {
+        variant: "Smoke",
+        owm: &[(711, 711)],
+        wmo: &[],
+        icon: "WiSmoke",
+        day: None,
+        night: None,
+    },
+    Condition {
+        variant: "Dust",
+        owm: &[(731, 731), (751, 761)],
+        wmo: &[],
+        icon: "WiDust",
+        day: None,
+        night: None,
+    },
+    Condition {
+        variant: "VolcanicAsh",
+        owm: &[(762, 762)],
+        wmo: &[],
+        icon: "WiVolcano",
+        day: None,
+        night: None,
+    },
+    Condition {
+        variant: "Squalls",
+        owm: &[(771, 771)],
+        wmo: &[],
+        icon: "WiStrongWind",
+        day: None,
+        night: None,
+    },
+    Condition {
+        variant: "Tornado",
+        owm: &[(781, 781)],
+        wmo: &[],
+        icon: "WiTornado",
+        day: None,
+        night: None,
+    },
+    Condition {
+        variant: "Drizzle",
+        owm: &[(300, 321)],
+        wmo: &[51, 53, 55],
+        icon: "WiSprinkle",
+        day: Some("WiDaySprinkle"),
+        night: Some("WiNightAltSprinkle"),
+    },
+    Condition {
+        variant: "FreezingRain",
+        owm: &[(511, 511)],
+        wmo: &[56, 57, 66, 67],
+        icon: "WiRainMix",
+        day: Some("WiDayRainMix"),
+        night: Some("WiNightAltRainMix"),
+    },
+    Condition {
+        variant: "Rain",
+        owm: &[(500, 504)],
+        wmo: &[61, 63, 65],
+        icon: "WiRain",
+        day: Some("WiDayRain"),
+        night: Some("WiNightAltRain"),
+    },
+    Condition {
+        variant: "Showers",
+        owm: &[(520, 531)],
+        wmo: &[80, 81, 82],
+        icon: "WiShowers",
+        day: Some("WiDayShowers"),
+        night: Some("WiNightAltShowers"),
+    },
+    Condition {
+        variant: "Sleet",
+        owm: &[(611, 616)],
+        wmo: &[],
+        icon: "WiSleet",
+        day: Some("WiDaySleet"),
+        night: Some("WiNightAltSleet"),
+    },
+    Condition {
+        variant: "Snow",
+        owm: &[(600, 602), (620, 622)],
+        wmo: &[71, 73, 75, 77, 85, 86],
+        icon: "WiSnow",
+        day: Some("WiDaySnow"),
+        night: Some("WiNightAltSnow"),
+    },
+    Condition {
+        variant: "Thunderstorm",
+        owm: &[(200, 232)],
+        wmo: &[95],
+        icon: "WiThunderstorm",
+        day: Some("WiDayThunderstorm"),
+        night: Some("WiNightAltThunderstorm"),
+    },
+    Condition {
+        variant: "Hail",
+        owm: &[],
+        wmo: &[96, 99],
+        icon: "WiHail",
+        day: Some("WiDayHail"),
+        night: Some("WiNightAltHail"),
+    },
+];
+
+/// Icons of the current packages showing a condition, each falling back to the neutral one.
+#[derive(Debug)]
+pub(crate) struct ConditionIcons {
+    pub icon: Vec<PreferredIcon>,
+    pub day: Vec<PreferredIcon>,
+    pub night: Vec<PreferredIcon>,
+}
+
+/// All weather conditions, with the icons of the current packages showing them.
+pub(crate) fn conditions() -> Result<Vec<(&'static Condition, ConditionIcons)>> {
+    CONDITIONS
+        .iter()
+        .map(|condition| {
+            let variant = |name: Option<&'static str>| {
+                let names = name.into_iter().chain([condition.icon]).collect::<Vec<_>>();
+                migration::preferred_icons(&names)
+            };
+            let icons = ConditionIcons {
+                icon: variant(None)?,
+                day: variant(condition.day)?,
+                night: variant(condition.night)?,
+            };
+            Ok((condition, icons))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::CONDITIONS;
+
+    #[test]
+    fn codes_are_unambiguous() {
+        let mut owm = CONDITIONS
+            .iter()
+            .flat_map(|condition| condition.owm)
+            .collect::<Vec<_>>();
+        owm.sort();
+        assert!(owm.iter().all(|(start, end)| start <= end));
+        assert!(owm.windows(2).all(|ranges| ranges[0].1 < ranges[1].0));
+
+        let wmo = CONDITIONS
+            .iter()
+            .flat_map(|condition| condition.wmo)
+            .collect::<HashSet<_>>();
+        assert_eq!(
+            wmo.len(),
+            CONDITIONS.iter().map(|condition| condition.wmo.len()).sum::<usize>()
+        );
+    }
+}
@@ -0,0 +1,38 @@
+//! {{ description }}
+//!
+//! This crate is a curated bundle of {{ icons.len() }} icons from the icondata_* crates, for
+//! applications which only need a few common icons. The same icons are available through the
+//! `icondata` crate as `icondata::{{ name }}`, behind its `{{ name }}` feature.
+{% for (icon, repository, revision) in icons %}
+#[allow(non_upper_case_globals)]
+#[doc = "{{ icon.label }}"]
+pub static {{ icon.name }}: &icondata_core::IconData = &icondata_core::IconData {
+    id: "{{ icon.name }}",
+    label: "{{ icon.label }}",
+    {% match revision -%}
+    {% when Some with (revision) -%}
+    source: Some(icondata_core::IconSource {
+        repository: "{{ repository }}",
+        revision: "{{ revision }}",
+        path: "{{ icon.source_path }}",
+    }),
+    {% when None -%}
+    source: None,
+    {% endmatch -%}
+    paint: icondata_core::IconPaint::{{ icon.paint|paint_variant }},
+    brand_color: {{ icon.brand_color|optional_str }},
+    {% let attributes = icon.svg.svg_attributes() -%}
+    style: {{ attributes.style|attribute_value }},
+    x: {{ attributes.x|attribute_value }},
+    y: {{ attributes.y|attribute_value }},
+    width: {{ attributes.width|attribute_value }},
+    height: {{ attributes.height|attribute_value }},
+    view_box: {{ attributes.view_box|attribute_value }},
+    stroke_linecap: {{ attributes.stroke_linecap|attribute_value }},
+    stroke_linejoin: {{ attributes.stroke_linejoin|attribute_value }},
+    stroke_width: {{ attributes.stroke_width|attribute_value }},
+    stroke: {{ attributes.stroke|attribute_value }},
+    fill: {{ attributes.fill|attribute_value }},
+    data: r###"{{ icon.svg.content.as_str() }}"###
+};
+{%- endfor %}
@@ -1,7 +1,170 @@
 use icondata::*;
 
-pub static ICONS: &[(&str, &str, icondata::Icon)] = &[
-    {%- for icon in icons %}
-    ("{{ icon }}", "{{ icon|lowercase }}", {{ icon }}),
+/// All icons with their name, lowercase name for searching and shape hash.
+pub static ICONS: &[(&str, &str, icondata::Icon, u64)] = &[
+    {%- for (icon, shape_hash) in icons %}
+    ("{{ icon }}", "{{ icon|lowercase }}", {{ icon }}, {{ "{:#018x}"|format(shape_hash) }}),
 {%- endfor ~%}
 ];
+
+/// Indices into [`ICONS`] of the icons of each semantic group, like "navigation" or "commerce".
+/// Icons are classified by the words of their labels and may belong to several groups or none.
+pub static GROUPS: &[(&str, &[usize])] = &[
+    {%- for (group, indices) in groups %}
+    ("{{ group }}", &[{% for i in indices %}{{ i }},{% endfor %}]),
+{%- endfor ~%}
+];
+
+/// Short name, icon name prefix and indices into [`ICONS`] of the icons of each package.
+pub static PACKAGES: &[(&str, &str, std::ops::Range<usize>)] = &[
+    {%- for (short_name, prefix, start, end) in packages %}
+    ("{{ short_name }}", "{{ prefix }}", {{ start }}..{{ end }}),
+{%- endfor ~%}
+];
+
+/// Indices into [`ICONS`] of the icons of each style, like "outline" or "solid", of packages
+/// drawing their icons in several styles.
+pub static STYLES: &[(&str, &[usize])] = &[
+    {%- for (style, indices) in styles %}
+    ("{{ style }}", &[{% for i in indices %}{{ i }},{% endfor %}]),
+{%- endfor ~%}
+];
+
+/// An icon found by [`search`].
+#[derive(Debug, Clone, Copy)]
+pub struct SearchResult {
+    pub name: &'static str,
+    pub icon: icondata::Icon,
+    /// Short name of the package of the icon, e.g. "fa".
+    pub package: &'static str,
+    /// Style of the icon, e.g. "outline", if its package draws several.
+    pub style: Option<&'static str>,
+}
+
+/// How [`search`] ranks the icons found.
+#[derive(Debug, Clone, Copy)]
+pub struct Ranking<'a> {
+    /// Short names of the packages whose icons come first, the most preferred first.
+    pub preferred_packages: &'a [&'a str],
+    /// Whether icons named exactly like the query, with or without the prefix of their package,
+    /// come before all others. On by default.
+    pub exact_name_first: bool,
+    /// Style whose icons come before those of other styles, e.g. "outline". Icons without a style
+    /// come in between.
+    pub style: Option<&'a str>,
+}
+
+impl Default for Ranking<'_> {
+    fn default() -> Self {
+        Ranking {
+            preferred_packages: &[],
+            exact_name_first: true,
+            style: None,
+        }
+    }
+}
+
+/// The icons of all packages whose names contain `query`, ignoring case, spaces, hyphens and
+/// underscores, e.g. "arrow-left" finds `LuArrowLeft`.
+///
+/// Icons named exactly like the query come first, followed by the icons of preferred packages and
+/// of the preferred style. Among equally ranked icons, names starting with the query after the
+/// prefix of their package come first, then shorter names.
+pub fn search(query: &str, ranking: &Ranking) -> Vec<SearchResult> {
+    let query = query
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .collect::<String>()
+        .to_lowercase();
+    let mut found = Vec::new();
+    for &(package, prefix, ref indices) in PACKAGES {
+        let preference = ranking
+            .preferred_packages
+            .iter()
+            .position(|preferred| *preferred == package)
+            .unwrap_or(ranking.preferred_packages.len());
+        for i in indices.clone() {
+            let (name, lower_name, icon, _) = ICONS[i];
+            if !lower_name.contains(&query) {
+                continue;
+            }
+            let unprefixed = &lower_name[prefix.len()..];
+            let style = style_of(i);
+            let rank = (
+                !(ranking.exact_name_first && (unprefixed == query || lower_name == query)),
+                preference,
+                ranking.style.map_or(0, |preferred| match style {
+                    Some(style) if style == preferred => 0,
+                    None => 1,
+                    Some(_) => 2,
+                }),
+                !unprefixed.starts_with(&query),
+                name.len(),
+                name,
+            );
+            found.push((
+                rank,
+                SearchResult {
+                    name,
+                    icon,
+                    package,
+                    style,
+                },
+            ));
+        }
+    }
+    found.sort_unstable_by_key(|(rank, _)| *rank);
+    found.into_iter().map(|(_, result)| result).collect()
+}
+
+/// The style of the icon at index `i` into [`ICONS`], if any.
+fn style_of(i: usize) -> Option<&'static str> {
+    STYLES
+        .iter()
+        .find(|(_, indices)| indices.binary_search(&i).is_ok())
+        .map(|(style, _)| *style)
+}
+
+/// The icons of the given semantic group with their names, e.g. `in_group("media")` for the
+/// section of a grouped icon picker. Empty for unknown groups.
+pub fn in_group(group: &str) -> Vec<(&'static str, icondata::Icon)> {
+    GROUPS
+        .iter()
+        .find(|(name, _)| *name == group)
+        .map(|(_, indices)| {
+            indices
+                .iter()
+                .map(|i| {
+                    let (name, _, icon, _) = ICONS[*i];
+                    (name, icon)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The `n` icons looking most similar to `icon`, most similar first.
+///
+/// Similarity is judged by comparing shape hashes, which roughly describe which parts of the canvas
+/// an icon covers. Near-duplicate glyphs of different packages therefore come first.
+pub fn similar_to(icon: icondata::Icon, n: usize) -> Vec<(&'static str, icondata::Icon)> {
+    let Some((_, _, _, hash)) = ICONS
+        .iter()
+        .find(|(_, _, candidate, _)| std::ptr::eq(*candidate, icon))
+    else {
+        return Vec::new();
+    };
+    let mut similar = ICONS
+        .iter()
+        .filter(|(_, _, candidate, _)| !std::ptr::eq(*candidate, icon))
+        .map(|(name, _, candidate, candidate_hash)| {
+            ((hash ^ candidate_hash).count_ones(), *name, *candidate)
+        })
+        .collect::<Vec<_>>();
+    similar.sort_by_key(|(distance, _, _)| *distance);
+    similar
+        .into_iter()
+        .take(n)
+        .map(|(_, name, icon)| (name, icon))
+        .collect()
+}
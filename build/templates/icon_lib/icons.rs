@@ -0,0 +1,63 @@
+{% for (icon, data, end) in icons.iter() -%}
+#[allow(non_upper_case_globals)]
+{% match icon.style -%}
+{% when Some with (style) -%}
+#[cfg(feature = "{{ style }}")]
+{% when None -%}
+{% endmatch -%}
+{% if icon.brand -%}
+#[cfg(feature = "brands")]
+{% endif -%}
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!({{ icon.name }}, "{{ icon.label }}"))]
+{% if compressed -%}
+pub static {{ icon.name }}: icondata_core::CompressedIcon = icondata_core::CompressedIcon::new(icondata_core::IconData {
+{% else -%}
+pub static {{ icon.name }}: &icondata_core::IconData = &icondata_core::IconData {
+{% endif -%}
+    id: "{{ icon.name }}",
+    label: "{{ icon.label }}",
+    {% if sourced -%}
+    source: Some(icondata_core::IconSource {
+        repository: SOURCE_REPOSITORY,
+        revision: SOURCE_REVISION,
+        path: "{{ icon.source_path }}",
+    }),
+    {% else -%}
+    source: None,
+    {% endif -%}
+    paint: icondata_core::IconPaint::{{ icon.paint|paint_variant }},
+    brand_color: {{ icon.brand_color|optional_str }},
+    {% let attributes = icon.svg.svg_attributes() -%}
+    style: {{ attributes.style|attribute_value }},
+    x: {{ attributes.x|attribute_value }},
+    y: {{ attributes.y|attribute_value }},
+    width: {{ attributes.width|attribute_value }},
+    height: {{ attributes.height|attribute_value }},
+    view_box: {{ attributes.view_box|attribute_value }},
+    stroke_linecap: {{ attributes.stroke_linecap|attribute_value }},
+    stroke_linejoin: {{ attributes.stroke_linejoin|attribute_value }},
+    stroke_width: {{ attributes.stroke_width|attribute_value }},
+    stroke: {{ attributes.stroke|attribute_value }},
+    fill: {{ attributes.fill|attribute_value }},
+    data: r###"{{ data }}"###
+{{ end }}
+{% for alias in icon.aliases -%}
+#[allow(non_upper_case_globals)]
+{% match icon.style -%}
+{% when Some with (style) -%}
+#[cfg(feature = "{{ style }}")]
+{% when None -%}
+{% endmatch -%}
+{% if icon.brand -%}
+#[cfg(feature = "brands")]
+{% endif -%}
+#[doc(hidden)]
+#[deprecated(note = "Renamed to {{ icon.name }}.")]
+{% if compressed -%}
+pub static {{ alias }}: &icondata_core::CompressedIcon = &{{ icon.name }};
+{% else -%}
+pub static {{ alias }}: &icondata_core::IconData = {{ icon.name }};
+{% endif -%}
+{% endfor -%}
+{% endfor %}
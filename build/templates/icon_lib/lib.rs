@@ -1,22 +1,107 @@
+{% macro features_cfg(item) -%}
+{% match item.style -%}
+{% when Some with (style) -%}
+#[cfg(feature = "{{ style }}")]
+{% when None -%}
+{% endmatch -%}
+{% if item.brand -%}
+#[cfg(feature = "brands")]
+{% endif -%}
+{% endmacro -%}
 //! This crate provides a collection of icons in the form of SVG data
+{% if url.is_empty() -%}
+//! from the __{{ long_name }}__ icon set.
+{% else -%}
 //! from the [__{{ long_name }}__]({{ url }}) icon set.
+{% endif %}
+/// Documentation of every icon, showing its usage with different frameworks.
+#[cfg(feature = "docs")]
+macro_rules! examples {
+    ($icon:ident, $label:literal) => {
+        concat!(
+            {%- for part in examples %}
+            {{ part }},
+            {%- endfor %}
+        )
+    };
+}
 
-{% for (name, svg) in name_svg.iter() -%}
-#[allow(non_upper_case_globals)]
-#[doc(hidden)]
-pub static {{ name }}: &icondata_core::IconData = &icondata_core::IconData {
-    {% let attributes = svg.svg_attributes() -%}
-    style: {{ attributes.style|attribute_value }},
-    x: {{ attributes.x|attribute_value }},
-    y: {{ attributes.y|attribute_value }},
-    width: {{ attributes.width|attribute_value }},
-    height: {{ attributes.height|attribute_value }},
-    view_box: {{ attributes.view_box|attribute_value }},
-    stroke_linecap: {{ attributes.stroke_linecap|attribute_value }},
-    stroke_linejoin: {{ attributes.stroke_linejoin|attribute_value }},
-    stroke_width: {{ attributes.stroke_width|attribute_value }},
-    stroke: {{ attributes.stroke|attribute_value }},
-    fill: {{ attributes.fill|attribute_value }},
-    data: r###"{{ svg.content.as_str() }}"###
-};
-{% endfor %}
+{% match revision -%}
+{% when Some with (revision) -%}
+/// Upstream repository the icons were generated from.
+const SOURCE_REPOSITORY: &str = "{{ url }}";
+/// Commit of the upstream repository the icons were generated from.
+const SOURCE_REVISION: &str = "{{ revision }}";
+
+{% when None -%}
+{% endmatch -%}
+{% match dictionary -%}
+{% when Some with (dictionary) -%}
+/// Substrings shared by the icons of this crate, referred to by their compressed content.
+static DICTIONARY: &[&str] = &[
+{%- for entry in dictionary.entries %}
+    r###"{{ entry }}"###,
+{%- endfor %}
+];
+
+{% when None -%}
+{% endmatch -%}
+{% for chunk in chunks.iter() -%}
+include!("{{ chunk }}");
+{% endfor %}{% for family in families %}
+{% match family.kind -%}
+{% when FamilyKind::Numbered -%}
+/// Icons of this family by the number they show, see [`{{ family.name }}::n`].
+{% call features_cfg(family) -%}
+pub enum {{ family.name }} {}
+
+{% call features_cfg(family) -%}
+impl {{ family.name }} {
+    /// {{ family.doc() }}
+    pub fn n({{ family.parameter() }}) -> Option<icondata_core::Icon> {
+        match {{ family.value() }} {
+        {%- for (value, icon) in family.variants %}
+            {{ value }} => Some({{ icon }}{% if compressed %}.get(){% endif %}),
+        {%- endfor %}
+            _ => None,
+        }
+    }
+}
+{%- else -%}
+/// {{ family.doc() }}
+#[allow(non_snake_case)]
+{% call features_cfg(family) -%}
+pub fn {{ family.name }}({{ family.parameter() }}) -> icondata_core::Icon {
+    icondata_core::nearest_variant(
+        {{ family.value() }},
+        &[
+        {%- for (value, icon) in family.variants %}
+            ({{ value }}, {% if compressed %}&{% endif %}{{ icon }}),
+        {%- endfor %}
+        ],
+    ){% if compressed %}.get(){% endif %}
+}
+{%- endmatch %}
+{%- endfor %}{% for directional in directionals %}
+/// The icons of this set pointing left, right, up and down, to pick the one pointing to the start or
+/// end of the text in both left-to-right and right-to-left locales.
+#[allow(non_snake_case)]
+{% call features_cfg(directional) -%}
+pub fn {{ directional.name }}() -> icondata_core::Directional {
+    icondata_core::Directional {
+    {%- for (field, icon) in directional.fields() %}
+        {{ field }}: {{ icon }}{% if compressed %}.get(){% endif %},
+    {%- endfor %}
+    }
+}
+{%- endfor %}{% for pair in state_pairs %}
+/// The icons showing the state of this pair on and off, for toggle buttons.
+#[allow(non_snake_case)]
+{% call features_cfg(pair) -%}
+pub fn {{ pair.name }}() -> icondata_core::StatePair {
+    icondata_core::StatePair {
+        on: {{ pair.on }}{% if compressed %}.get(){% endif %},
+        off: {{ pair.off }}{% if compressed %}.get(){% endif %},
+    }
+}
+{%- endfor %}
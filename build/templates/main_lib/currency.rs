@@ -0,0 +1,128 @@
+//! Currencies by their ISO 4217 code, for prices and wallets.
+
+use icondata_core::{IconData, IconPaint};
+
+use crate::Icon;
+
+/// A currency, like `Currency::from_code("EUR")`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Currency {
+{%- for (currency, _) in currencies %}
+    /// {{ currency.name }}, {{ currency.symbol }}.
+    {{ currency.variant() }},
+{%- endfor %}
+}
+
+impl Currency {
+    /// All currencies.
+    pub const ALL: [Currency; {{ currencies.len() }}] = [
+{%- for (currency, _) in currencies %}
+        Currency::{{ currency.variant() }},
+{%- endfor %}
+    ];
+
+    /// Currency of the given ISO 4217 code, e.g. "EUR", ignoring its case. `None` for unknown
+    /// codes.
+    pub fn from_code(code: &str) -> Option<Currency> {
+        match code.trim().to_ascii_uppercase().as_str() {
+{%- for (currency, _) in currencies %}
+            "{{ currency.code }}" => Some(Currency::{{ currency.variant() }}),
+{%- endfor %}
+            _ => None,
+        }
+    }
+
+    /// ISO 4217 code of the currency, e.g. "EUR".
+    pub const fn code(self) -> &'static str {
+        match self {
+{%- for (currency, _) in currencies %}
+            Currency::{{ currency.variant() }} => "{{ currency.code }}",
+{%- endfor %}
+        }
+    }
+
+    /// English name of the currency, e.g. "Euro".
+    pub const fn name(self) -> &'static str {
+        match self {
+{%- for (currency, _) in currencies %}
+            Currency::{{ currency.variant() }} => "{{ currency.name }}",
+{%- endfor %}
+        }
+    }
+
+    /// Symbol of the currency, e.g. "€".
+    pub const fn symbol(self) -> &'static str {
+        match self {
+{%- for (currency, _) in currencies %}
+            Currency::{{ currency.variant() }} => "{{ currency.symbol }}",
+{%- endfor %}
+        }
+    }
+
+    /// Icon of the currency, from the first package preferred for it whose feature is enabled,
+    /// falling back to [`Currency::symbol_icon`].
+    pub fn icon(self) -> Icon {
+        self.glyph().unwrap_or_else(|| self.symbol_icon())
+    }
+
+    /// Icon of the currency, from the first package preferred for it whose feature is enabled.
+    /// `None` if no such package is enabled.
+    #[allow(unreachable_code)]
+    pub fn glyph(self) -> Option<Icon> {
+        match self {
+{%- for (currency, icons) in currencies %}
+            Currency::{{ currency.variant() }} => {
+{%- for preferred in icons %}
+{%- match preferred.feature %}
+{%- when Some with (feature) %}
+                #[cfg(feature = "{{ feature }}")]
+{%- when None %}
+{%- endmatch %}
+                return Some(crate::{{ preferred.icon.name }}{% if compressed %}.get(){% endif %});
+{%- endfor %}
+                None
+            }
+{%- endfor %}
+        }
+    }
+
+    /// Icon drawing the symbol of the currency as text in the color of the surrounding text, like
+    /// any other icon. Always available, but drawn in a font of the platform, so it may not match
+    /// the style of the other icons.
+    pub fn symbol_icon(self) -> Icon {
+        &SYMBOLS[self as usize]
+    }
+}
+
+/// Icons drawing the symbols of the currencies, in the order of [`Currency::ALL`].
+static SYMBOLS: [IconData; {{ currencies.len() }}] = [
+{%- for (currency, _) in currencies %}
+    symbol(
+        "CurrencySymbol{{ currency.variant() }}",
+        "{{ currency.name }}",
+        r#"<text x="12" y="12" font-size="{{ currency.font_size() }}" font-family="sans-serif" text-anchor="middle" dominant-baseline="central">{{ currency.symbol }}</text>"#,
+    ),
+{%- endfor %}
+];
+
+const fn symbol(id: &'static str, label: &'static str, data: &'static str) -> IconData {
+    IconData {
+        id,
+        label,
+        source: None,
+        paint: IconPaint::Fill,
+        brand_color: None,
+        style: None,
+        x: None,
+        y: None,
+        width: Some("24"),
+        height: Some("24"),
+        view_box: Some("0 0 24 24"),
+        stroke_linecap: None,
+        stroke_linejoin: None,
+        stroke_width: None,
+        stroke: None,
+        fill: Some("currentColor"),
+        data,
+    }
+}
@@ -0,0 +1,112 @@
+//! Types of files by MIME type and extension, for file managers and upload forms.
+
+use crate::Icon;
+
+/// A type of file, like `FileType::Pdf`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileType {
+{%- for (file_type, _) in file_types %}
+    {{ file_type.variant }},
+{%- endfor %}
+}
+
+impl FileType {
+    /// All file types.
+    pub const ALL: [FileType; {{ file_types.len() }}] = [
+{%- for (file_type, _) in file_types %}
+        FileType::{{ file_type.variant }},
+{%- endfor %}
+    ];
+
+    /// Type of files of the given MIME type, e.g. "image/png; charset=binary", ignoring its
+    /// parameters and case. Unknown types are [`FileType::File`].
+    pub fn from_mime(mime: &str) -> FileType {
+        let mime = mime
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        match mime.as_str() {
+{%- for (file_type, _) in file_types %}
+{%- for mime_type in file_type.mime_types %}
+            "{{ mime_type }}" => FileType::{{ file_type.variant }},
+{%- endfor %}
+{%- endfor %}
+            _ => match mime.split('/').next().unwrap_or_default() {
+{%- for (file_type, _) in file_types %}
+{%- match file_type.top_level %}
+{%- when Some with (top_level) %}
+                "{{ top_level }}" => FileType::{{ file_type.variant }},
+{%- when None %}
+{%- endmatch %}
+{%- endfor %}
+                _ => FileType::File,
+            },
+        }
+    }
+
+    /// Type of files with the given extension, e.g. "pdf" or ".PDF". Unknown extensions are
+    /// [`FileType::File`].
+    pub fn from_extension(extension: &str) -> FileType {
+        match extension.trim_start_matches('.').to_ascii_lowercase().as_str() {
+{%- for (file_type, _) in file_types %}
+{%- for extension in file_type.extensions %}
+            "{{ extension }}" => FileType::{{ file_type.variant }},
+{%- endfor %}
+{%- endfor %}
+            _ => FileType::File,
+        }
+    }
+
+    /// Type of the file at the given path or url, by its extension, see
+    /// [`FileType::from_extension`].
+    pub fn from_path(path: &str) -> FileType {
+        let name = path.rsplit(['/', '\\']).next().unwrap_or_default();
+        match name.rsplit_once('.') {
+            Some((_, extension)) => FileType::from_extension(extension),
+            None => FileType::File,
+        }
+    }
+
+    /// More generic type whose icon is shown if none of this type is enabled, like
+    /// [`FileType::Document`] for PDFs.
+    pub const fn fallback(self) -> Option<FileType> {
+        match self {
+{%- for (file_type, _) in file_types %}
+{%- match file_type.fallback %}
+{%- when Some with (fallback) %}
+            FileType::{{ file_type.variant }} => Some(FileType::{{ fallback }}),
+{%- when None %}
+            FileType::{{ file_type.variant }} => None,
+{%- endmatch %}
+{%- endfor %}
+        }
+    }
+
+    /// Icon of the file type from the first package preferred for it whose feature is enabled,
+    /// else the icon of its [`FileType::fallback`]. `None` if no package with icons of files is
+    /// enabled.
+    pub fn icon(self) -> Option<Icon> {
+        self.own_icon().or_else(|| self.fallback()?.icon())
+    }
+
+    #[allow(unreachable_code)]
+    fn own_icon(self) -> Option<Icon> {
+        match self {
+{%- for (file_type, icons) in file_types %}
+            FileType::{{ file_type.variant }} => {
+{%- for preferred in icons %}
+{%- match preferred.feature %}
+{%- when Some with (feature) %}
+                #[cfg(feature = "{{ feature }}")]
+{%- when None %}
+{%- endmatch %}
+                return Some(crate::{{ preferred.icon.name }}{% if compressed %}.get(){% endif %});
+{%- endfor %}
+                None
+            }
+{%- endfor %}
+        }
+    }
+}
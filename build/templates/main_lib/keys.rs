@@ -0,0 +1,83 @@
+//! Keyboard keys and modifiers for rendering shortcut hints.
+
+use crate::Icon;
+
+/// A key or modifier shown in a keyboard shortcut hint, like `Key::Cmd` for "⌘".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+{%- for key in keys %}
+    /// {{ key.label }} ({{ key.symbol }})
+    {{ key.variant }},
+{%- endfor %}
+}
+
+/// Platform a shortcut hint is shown on, see [`Key::on`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    /// macOS and iOS, which name their modifiers Command and Option.
+    Apple,
+    #[default]
+    Other,
+}
+
+impl Key {
+    /// All keys.
+    pub const ALL: [Key; {{ keys.len() }}] = [
+{%- for key in keys %}
+        Key::{{ key.variant }},
+{%- endfor %}
+    ];
+
+    /// The icon showing the key, from the first package preferred for it whose feature is enabled.
+    /// `None` if no such package is enabled, in which case hints can fall back to
+    /// [`Key::symbol`].
+    #[allow(unreachable_code)]
+    pub fn icon(self) -> Option<Icon> {
+        match self {
+{%- for key in keys %}
+            Key::{{ key.variant }} => {
+{%- for key_icon in key.icons %}
+{%- match key_icon.feature %}
+{%- when Some with (feature) %}
+                #[cfg(feature = "{{ feature }}")]
+{%- when None %}
+{%- endmatch %}
+{%- if key_icon.icon.brand %}
+                #[cfg(feature = "brands")]
+{%- endif %}
+                return Some(crate::{{ key_icon.icon.name }}{% if compressed %}.get(){% endif %});
+{%- endfor %}
+                None
+            }
+{%- endfor %}
+        }
+    }
+
+    /// Unicode symbol of the key, e.g. "⌘".
+    pub const fn symbol(self) -> &'static str {
+        match self {
+{%- for key in keys %}
+            Key::{{ key.variant }} => "{{ key.symbol }}",
+{%- endfor %}
+        }
+    }
+
+    /// Human readable name of the key, e.g. "Command".
+    pub const fn label(self) -> &'static str {
+        match self {
+{%- for key in keys %}
+            Key::{{ key.variant }} => "{{ key.label }}",
+{%- endfor %}
+        }
+    }
+
+    /// The key as shown on the given platform: `Ctrl` is shown as `Cmd` and `Alt` as `Option` on
+    /// Apple devices, so that one shortcut can be declared for all platforms.
+    pub const fn on(self, platform: Platform) -> Key {
+        match (self, platform) {
+            (Key::Ctrl, Platform::Apple) => Key::Cmd,
+            (Key::Alt, Platform::Apple) => Key::Option,
+            (key, _) => key,
+        }
+    }
+}
@@ -25,12 +25,71 @@
 //! __Note:__ importing `icondata::*` will import all icons, which can heavily slow down rust-analyzer.
 //! This can be avoided by importing only the icons you need: `use icondata::{..., ...};`, or by
 //! using the qualified path as above.
-{% for short_name in short_names %}
+{% for (short_name, opt_in) in short_names %}
+{%- if opt_in %}
+#[cfg(feature = "{{short_name}}")]
+{%- endif %}
 pub use icondata_{{short_name}}::*;
 {%- endfor %}
+{% for name in bundles %}
+/// Curated bundle of icons, see the `icondata_{{name}}` crate.
+#[cfg(feature = "{{name}}")]
+pub use icondata_{{name}} as {{name}};
+{%- endfor %}
+
+#[cfg(feature = "migrate")]
+pub mod migrate;
+
+mod spinners;
+pub use spinners::spinners;
+
+mod keys;
+/// Keys and modifiers for keyboard shortcut hints, like `Key::Cmd.icon()`.
+pub use keys::{Key, Platform};
+
+mod social;
+/// Social platforms with their logos, colors and share links, like `SocialPlatform::Mastodon`.
+pub use social::SocialPlatform;
+
+mod user_agent;
+/// Operating systems, browsers and kinds of devices with their icons, like `Browser::Firefox`.
+pub use user_agent::{Browser, Device, Os};
+
+mod file_types;
+/// Types of files by MIME type and extension with their icons, like `FileType::Pdf`.
+pub use file_types::FileType;
+
+mod status;
+/// Classes of HTTP status codes and log levels with their icons and severity colors, like
+/// `LogLevel::Warn.color(&Theme::DARK)`.
+pub use status::{HttpStatus, LogLevel, Severity, Theme};
+
+mod weather;
+/// Weather conditions resolved from the codes of OpenWeatherMap and WMO with their Weather Icons,
+/// like `Weather::from_owm(500)`.
+pub use weather::{TimeOfDay, Weather};
+
+mod currency;
+/// Currencies by their ISO 4217 code with their icons, falling back to their symbol drawn as text,
+/// like `Currency::from_code("EUR")`.
+pub use currency::Currency;
 
 /// An Icon from any of the icondata_* crates.
 ///
 /// The underlying type of this type alias implements many useful traits such as `Eq`, `Hash`,
 /// `Serialize`, `Deserialize`, etc. See the [`IconData`](icondata_core::IconData) struct for more information.
 pub use icondata_core::Icon;
+
+/// Hour taken by the `ClockAt` accessors of packages with a clock icon per hour, like
+/// `LuClockAt`.
+pub use icondata_core::Hour;
+
+/// Arrows and chevrons pointing to the start or end of the text in both left-to-right and
+/// right-to-left locales, like `TbArrowDirectional()`.
+pub use icondata_core::{Direction, Directional, TextDirection};
+
+/// Icons showing a state on and off for toggle buttons, like `LuEyeToggle()`.
+pub use icondata_core::StatePair;
+
+/// Loading indicators with the center they spin around, see [`spinners()`].
+pub use icondata_core::Spinner;
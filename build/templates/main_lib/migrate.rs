@@ -0,0 +1,31 @@
+//! Aliases easing the migration from other icon crates to the icons of this crate.
+//!
+//! Aliases are available as long as the feature of the icon they refer to is enabled. Aliases
+//! whose icon is drawn differently from the one they replace are deprecated, with a note on how
+//! the glyphs differ.
+{% for migration in migrations %}
+/// {{ migration.description }}
+pub mod {{ migration.module }} {
+{%- for alias in migration.aliases %}
+    #[allow(non_upper_case_globals)]
+{%- match alias.feature %}
+{%- when Some with (feature) %}
+    #[cfg(feature = "{{ feature }}")]
+{%- when None %}
+{%- endmatch %}
+{%- if alias.icon.brand %}
+    #[cfg(feature = "brands")]
+{%- endif %}
+{%- match alias.note %}
+{%- when Some with (note) %}
+    #[deprecated(note = "Drawn differently: {{ note }} Use {{ alias.icon.name }} instead.")]
+{%- when None %}
+{%- endmatch %}
+{%- if compressed %}
+    pub static {{ alias.name }}: &icondata_core::CompressedIcon = &crate::{{ alias.icon.name }};
+{%- else %}
+    pub static {{ alias.name }}: crate::Icon = crate::{{ alias.icon.name }};
+{%- endif %}
+{%- endfor %}
+}
+{% endfor -%}
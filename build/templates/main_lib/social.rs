@@ -0,0 +1,97 @@
+//! Social platforms content is shared to, for share-button bars.
+
+use crate::Icon;
+
+/// A social platform content is shared to, like `SocialPlatform::Mastodon`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SocialPlatform {
+{%- for (platform, _) in platforms %}
+    {{ platform.variant }},
+{%- endfor %}
+}
+
+impl SocialPlatform {
+    /// All platforms.
+    pub const ALL: [SocialPlatform; {{ platforms.len() }}] = [
+{%- for (platform, _) in platforms %}
+        SocialPlatform::{{ platform.variant }},
+{%- endfor %}
+    ];
+
+    /// Logo of the platform, from the first package preferred for it whose feature is enabled,
+    /// official logos of Simple Icons first. `None` if no such package is enabled, in which case
+    /// share buttons can fall back to [`SocialPlatform::name`].
+    #[allow(unreachable_code)]
+    pub fn icon(self) -> Option<Icon> {
+        match self {
+{%- for (platform, icons) in platforms %}
+            SocialPlatform::{{ platform.variant }} => {
+{%- for preferred in icons %}
+{%- match preferred.feature %}
+{%- when Some with (feature) %}
+                #[cfg(feature = "{{ feature }}")]
+{%- when None %}
+{%- endmatch %}
+{%- if preferred.icon.brand %}
+                #[cfg(feature = "brands")]
+{%- endif %}
+                return Some(crate::{{ preferred.icon.name }}{% if compressed %}.get(){% endif %});
+{%- endfor %}
+                None
+            }
+{%- endfor %}
+        }
+    }
+
+    /// Name of the platform, e.g. "LinkedIn".
+    pub const fn name(self) -> &'static str {
+        match self {
+{%- for (platform, _) in platforms %}
+            SocialPlatform::{{ platform.variant }} => "{{ platform.name }}",
+{%- endfor %}
+        }
+    }
+
+    /// Official color of the platform as hex color, e.g. "#0A66C2": the brand color of its
+    /// [`SocialPlatform::icon`] if known, else the color published by the platform.
+    pub fn color(self) -> &'static str {
+        let color = match self {
+{%- for (platform, _) in platforms %}
+            SocialPlatform::{{ platform.variant }} => "{{ platform.color }}",
+{%- endfor %}
+        };
+        self.icon()
+            .and_then(|icon| icon.brand_color)
+            .unwrap_or(color)
+    }
+
+    /// Link sharing the page at `url` on the platform. `None` for platforms without a central
+    /// share link, like Mastodon, whose links depend on the instance of the user.
+    pub fn share_url(self, url: &str) -> Option<String> {
+        let start = match self {
+{%- for (platform, _) in platforms %}
+{%- match platform.share_url %}
+{%- when Some with (start) %}
+            SocialPlatform::{{ platform.variant }} => "{{ start }}",
+{%- when None %}
+            SocialPlatform::{{ platform.variant }} => return None,
+{%- endmatch %}
+{%- endfor %}
+        };
+        Some(format!("{start}{}", percent_encode(url)))
+    }
+}
+
+/// `value` with every byte but the unreserved characters of URLs percent-encoded.
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(char::from(byte))
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,28 @@
+//! Loading indicators of all packages, with the center they spin around.
+
+use icondata_core::Spinner;
+
+/// The loading indicators of all packages whose feature is enabled, like `LuLoader` or
+/// `FaSpinnerSolid`, ordered by package and name.
+///
+/// Rendering them with [`Spinner::render`] rotates them around the center of their glyph, which
+/// is off the center of the view box for many spinners.
+pub fn spinners() -> Vec<Spinner> {
+    #[allow(unused_mut)]
+    let mut spinners = Vec::new();
+{%- for spinner in spinners %}
+{%- match spinner.feature %}
+{%- when Some with (feature) %}
+    #[cfg(feature = "{{ feature }}")]
+{%- when None %}
+{%- endmatch %}
+{%- if spinner.icon.brand %}
+    #[cfg(feature = "brands")]
+{%- endif %}
+    spinners.push(Spinner {
+        icon: crate::{{ spinner.icon.name }}{% if compressed %}.get(){% endif %},
+        origin: "{{ spinner.origin }}",
+    });
+{%- endfor %}
+    spinners
+}
@@ -0,0 +1,197 @@
+//! Classes of HTTP status codes and log levels with their icons and severity colors, for
+//! observability dashboards.
+
+use crate::Icon;
+
+/// Severity of a status, colored by a [`Theme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Neutral,
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// Color of the severity in the given theme, as a hex color like "#dc2626".
+    pub const fn color(self, theme: &Theme) -> &'static str {
+        match self {
+            Severity::Neutral => theme.neutral,
+            Severity::Info => theme.info,
+            Severity::Success => theme.success,
+            Severity::Warning => theme.warning,
+            Severity::Error => theme.error,
+        }
+    }
+}
+
+/// Colors of the severities, as hex colors. Build your own to match the palette of your
+/// dashboard, or use [`Theme::LIGHT`] or [`Theme::DARK`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Theme {
+    pub neutral: &'static str,
+    pub info: &'static str,
+    pub success: &'static str,
+    pub warning: &'static str,
+    pub error: &'static str,
+}
+
+impl Theme {
+    /// Colors readable on light backgrounds.
+    pub const LIGHT: Theme = Theme {
+        neutral: "#6b7280",
+        info: "#2563eb",
+        success: "#16a34a",
+        warning: "#d97706",
+        error: "#dc2626",
+    };
+
+    /// Colors readable on dark backgrounds.
+    pub const DARK: Theme = Theme {
+        neutral: "#9ca3af",
+        info: "#60a5fa",
+        success: "#4ade80",
+        warning: "#fbbf24",
+        error: "#f87171",
+    };
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::LIGHT
+    }
+}
+
+/// A class of HTTP status codes, like `HttpStatus::from_code(404)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpStatus {
+{%- for (status, _) in http_statuses %}
+    /// {{ status.class }}xx, {{ status.doc }}.
+    {{ status.variant }},
+{%- endfor %}
+}
+
+impl HttpStatus {
+    /// All classes.
+    pub const ALL: [HttpStatus; {{ http_statuses.len() }}] = [
+{%- for (status, _) in http_statuses %}
+        HttpStatus::{{ status.variant }},
+{%- endfor %}
+    ];
+
+    /// Class of the given status code, `None` for codes outside of 100 to 599.
+    pub const fn from_code(code: u16) -> Option<HttpStatus> {
+        match code / 100 {
+{%- for (status, _) in http_statuses %}
+            {{ status.class }} => Some(HttpStatus::{{ status.variant }}),
+{%- endfor %}
+            _ => None,
+        }
+    }
+
+    pub const fn severity(self) -> Severity {
+        match self {
+{%- for (status, _) in http_statuses %}
+            HttpStatus::{{ status.variant }} => Severity::{{ "{:?}"|format(status.severity) }},
+{%- endfor %}
+        }
+    }
+
+    /// Color of the [`HttpStatus::severity`] in the given theme.
+    pub const fn color(self, theme: &Theme) -> &'static str {
+        self.severity().color(theme)
+    }
+
+    /// Icon of the class, from the first package preferred for it whose feature is enabled.
+    /// `None` if no such package is enabled.
+    #[allow(unreachable_code)]
+    pub fn icon(self) -> Option<Icon> {
+        match self {
+{%- for (status, icons) in http_statuses %}
+            HttpStatus::{{ status.variant }} => {
+{%- for preferred in icons %}
+{%- match preferred.feature %}
+{%- when Some with (feature) %}
+                #[cfg(feature = "{{ feature }}")]
+{%- when None %}
+{%- endmatch %}
+                return Some(crate::{{ preferred.icon.name }}{% if compressed %}.get(){% endif %});
+{%- endfor %}
+                None
+            }
+{%- endfor %}
+        }
+    }
+}
+
+/// A level of log records, like `LogLevel::parse("WARN")`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+{%- for (level, _) in log_levels %}
+    {{ level.variant }},
+{%- endfor %}
+}
+
+impl LogLevel {
+    /// All levels, from the least to the most severe.
+    pub const ALL: [LogLevel; {{ log_levels.len() }}] = [
+{%- for (level, _) in log_levels %}
+        LogLevel::{{ level.variant }},
+{%- endfor %}
+    ];
+
+    /// Level of the given name, ignoring case, e.g. "WARN" or "warning". `None` for unknown
+    /// names.
+    pub fn parse(name: &str) -> Option<LogLevel> {
+        match name.trim().to_ascii_lowercase().as_str() {
+{%- for (level, _) in log_levels %}
+            {% for name in level.names %}{% if !loop.first %} | {% endif %}"{{ name }}"{% endfor %} => Some(LogLevel::{{ level.variant }}),
+{%- endfor %}
+            _ => None,
+        }
+    }
+
+    /// Lowercase name of the level, e.g. "warn".
+    pub const fn name(self) -> &'static str {
+        match self {
+{%- for (level, _) in log_levels %}
+            LogLevel::{{ level.variant }} => "{{ level.names[0] }}",
+{%- endfor %}
+        }
+    }
+
+    pub const fn severity(self) -> Severity {
+        match self {
+{%- for (level, _) in log_levels %}
+            LogLevel::{{ level.variant }} => Severity::{{ "{:?}"|format(level.severity) }},
+{%- endfor %}
+        }
+    }
+
+    /// Color of the [`LogLevel::severity`] in the given theme.
+    pub const fn color(self, theme: &Theme) -> &'static str {
+        self.severity().color(theme)
+    }
+
+    /// Icon of the level, from the first package preferred for it whose feature is enabled.
+    /// `None` if no such package is enabled.
+    #[allow(unreachable_code)]
+    pub fn icon(self) -> Option<Icon> {
+        match self {
+{%- for (level, icons) in log_levels %}
+            LogLevel::{{ level.variant }} => {
+{%- for preferred in icons %}
+{%- match preferred.feature %}
+{%- when Some with (feature) %}
+                #[cfg(feature = "{{ feature }}")]
+{%- when None %}
+{%- endmatch %}
+                return Some(crate::{{ preferred.icon.name }}{% if compressed %}.get(){% endif %});
+{%- endfor %}
+                None
+            }
+{%- endfor %}
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Operating systems, browsers and kinds of devices as detected from user agents, for analytics
+//! dashboards and session lists.
+
+use crate::Icon;
+{% for (ty, variants) in enums %}
+/// {{ ty.doc }}, like `{{ ty.name }}::{{ ty.variants[0].variant }}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum {{ ty.name }} {
+{%- for (variant, _) in variants %}
+    {{ variant.variant }},
+{%- endfor %}
+}
+
+impl {{ ty.name }} {
+    /// All variants.
+    pub const ALL: [{{ ty.name }}; {{ variants.len() }}] = [
+{%- for (variant, _) in variants %}
+        {{ ty.name }}::{{ variant.variant }},
+{%- endfor %}
+    ];
+
+    /// Icon of the {{ ty.noun }}, from the first package preferred for it whose feature is enabled.
+    /// `None` if no such package is enabled, in which case lists can fall back to
+    /// [`{{ ty.name }}::name`].
+    #[allow(unreachable_code)]
+    pub fn icon(self) -> Option<Icon> {
+        match self {
+{%- for (variant, icons) in variants %}
+            {{ ty.name }}::{{ variant.variant }} => {
+{%- for preferred in icons %}
+{%- match preferred.feature %}
+{%- when Some with (feature) %}
+                #[cfg(feature = "{{ feature }}")]
+{%- when None %}
+{%- endmatch %}
+{%- if preferred.icon.brand %}
+                #[cfg(feature = "brands")]
+{%- endif %}
+                return Some(crate::{{ preferred.icon.name }}{% if compressed %}.get(){% endif %});
+{%- endfor %}
+                None
+            }
+{%- endfor %}
+        }
+    }
+
+    /// Name of the {{ ty.noun }}, e.g. "{{ ty.variants[0].name }}".
+    pub const fn name(self) -> &'static str {
+        match self {
+{%- for (variant, _) in variants %}
+            {{ ty.name }}::{{ variant.variant }} => "{{ variant.name }}",
+{%- endfor %}
+        }
+    }
+}
+{% endfor -%}
@@ -0,0 +1,122 @@
+{% macro returns(icons) -%}
+{%- for preferred in icons.iter() %}
+{%- match preferred.feature %}
+{%- when Some with (feature) %}
+                #[cfg(feature = "{{ feature }}")]
+{%- when None %}
+{%- endmatch %}
+                return Some(crate::{{ preferred.icon.name }}{% if compressed %}.get(){% endif %});
+{%- endfor %}
+{%- endmacro -%}
+//! Weather conditions resolved from the condition codes of weather APIs, for weather apps.
+
+use crate::Icon;
+
+/// Time of day picking the variant of a weather icon, like the moon instead of the sun.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeOfDay {
+    Day,
+    Night,
+}
+
+impl TimeOfDay {
+    /// Time of day of an OpenWeatherMap icon code, e.g. "10n" for night. `None` for other
+    /// codes.
+    pub fn from_owm_icon(icon: &str) -> Option<TimeOfDay> {
+        match icon.chars().last() {
+            Some('d') => Some(TimeOfDay::Day),
+            Some('n') => Some(TimeOfDay::Night),
+            _ => None,
+        }
+    }
+}
+
+/// A weather condition, like `Weather::from_owm(500)` for light rain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weather {
+{%- for (condition, _) in conditions %}
+    {{ condition.variant }},
+{%- endfor %}
+}
+
+impl Weather {
+    /// All conditions.
+    pub const ALL: [Weather; {{ conditions.len() }}] = [
+{%- for (condition, _) in conditions %}
+        Weather::{{ condition.variant }},
+{%- endfor %}
+    ];
+
+    /// Condition of an OpenWeatherMap condition code, e.g. 500 for light rain. `None` for unknown
+    /// codes.
+    pub const fn from_owm(id: u16) -> Option<Weather> {
+        match id {
+{%- for (condition, _) in conditions %}
+{%- if !condition.owm.is_empty() %}
+            {% for (start, end) in condition.owm %}{% if !loop.first %} | {% endif %}{% if start == end %}{{ start }}{% else %}{{ start }}..={{ end }}{% endif %}{% endfor %} => Some(Weather::{{ condition.variant }}),
+{%- endif %}
+{%- endfor %}
+            _ => None,
+        }
+    }
+
+    /// Condition of a WMO weather interpretation code, as reported by Open-Meteo, e.g. 61 for
+    /// slight rain. `None` for unknown codes.
+    pub const fn from_wmo(code: u8) -> Option<Weather> {
+        match code {
+{%- for (condition, _) in conditions %}
+{%- if !condition.wmo.is_empty() %}
+            {% for code in condition.wmo %}{% if !loop.first %} | {% endif %}{{ code }}{% endfor %} => Some(Weather::{{ condition.variant }}),
+{%- endif %}
+{%- endfor %}
+            _ => None,
+        }
+    }
+
+    /// Icon of the condition at the given time of day, falling back to the variant regardless of
+    /// the time of day, e.g. `WiNightAltRain` at night and `WiRain` for a whole day. `None` if
+    /// Weather Icons is not enabled, in which case apps can fall back to `WiNa`.
+    pub fn icon(self, time: Option<TimeOfDay>) -> Option<Icon> {
+        match time {
+            None => self.any_time_icon(),
+            Some(TimeOfDay::Day) => self.day_icon(),
+            Some(TimeOfDay::Night) => self.night_icon(),
+        }
+    }
+
+    #[allow(unreachable_code)]
+    fn any_time_icon(self) -> Option<Icon> {
+        match self {
+{%- for (condition, icons) in conditions %}
+            Weather::{{ condition.variant }} => {
+{%- call returns(icons.icon) %}
+                None
+            }
+{%- endfor %}
+        }
+    }
+
+    #[allow(unreachable_code)]
+    fn day_icon(self) -> Option<Icon> {
+        match self {
+{%- for (condition, icons) in conditions %}
+            Weather::{{ condition.variant }} => {
+{%- call returns(icons.day) %}
+                None
+            }
+{%- endfor %}
+        }
+    }
+
+    #[allow(unreachable_code)]
+    fn night_icon(self) -> Option<Icon> {
+        match self {
+{%- for (condition, icons) in conditions %}
+            Weather::{{ condition.variant }} => {
+{%- call returns(icons.night) %}
+                None
+            }
+{%- endfor %}
+        }
+    }
+}
@@ -39,7 +39,7 @@ pub fn Icons() -> impl IntoView {
         log::debug!("Filtering icons");
         ICONS
             .into_iter()
-            .filter_map(|(name, lower_name, icon)| {
+            .filter_map(|(name, lower_name, icon, _)| {
                 lower_name
                     .contains(&search_content_memo.get())
                     .then(|| (*name, *icon))
@@ -157,6 +157,17 @@ pub fn IconItem(icon: icondata::Icon, name: &'static str, top: u32, left: u32) -
             group-hover:dark:text-emphasis-dark transition-colors delay-75 duration-200
             ease-in-out" />
             <p class={"line-clamp-1 break-all px-1 ".to_owned() + text_size}>{move || name}</p>
+            {icon.source().map(|source| view! {
+                <a
+                    href=source.url()
+                    target="_blank"
+                    rel="noopener noreferrer"
+                    class="text-[0.5rem] underline hidden group-hover:block"
+                    on:click=|ev: MouseEvent| ev.stop_propagation()
+                    >
+                    "View upstream"
+                </a>
+            })}
         </div>
     }
 }
@@ -1,9 +1,33 @@
 //! This crate provides a collection of icons in the form of SVG data
 //! from the [__Ant Design Icons__](https://github.com/ant-design/ant-design-icons) icon set.
 
+/// Documentation of every icon, showing its usage with different frameworks.
+#[cfg(feature = "docs")]
+macro_rules! examples {
+    ($icon:ident, $label:literal) => {
+        concat!(
+            $label,
+            "\n\n# Examples\n\nWith [Leptos](https://leptos.dev) and [`leptos_icons`](https://docs.rs/leptos_icons):\n```rust,ignore\nuse leptos::*;\nuse leptos_icons::Icon;\n\nview! { <Icon icon=icondata::",
+            stringify!($icon),
+            " /> }\n```\n\nWith [Yew](https://yew.rs):\n```rust,ignore\nuse yew::prelude::*;\n\nlet svg = icondata::",
+            stringify!($icon),
+            ".render(&Default::default());\nhtml! { { Html::from_html_unchecked(AttrValue::from(svg)) } }\n```\n\nWith [Dioxus](https://dioxuslabs.com):\n```rust,ignore\nuse dioxus::prelude::*;\n\nlet svg = icondata::",
+            stringify!($icon),
+            ".render(&Default::default());\nrsx! { span { dangerous_inner_html: \"{svg}\" } }\n```",
+        )
+    };
+}
+
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAccountBookFilled, "Account Book Filled"))]
 pub static AiAccountBookFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAccountBookFilled",
+    label: "Account Book Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18,8 +42,15 @@ pub static AiAccountBookFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M880 184H712v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H384v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H144c-17.7 0-32 14.3-32 32v664c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V216c0-17.7-14.3-32-32-32zM648.3 426.8l-87.7 161.1h45.7c5.5 0 10 4.5 10 10v21.3c0 5.5-4.5 10-10 10h-63.4v29.7h63.4c5.5 0 10 4.5 10 10v21.3c0 5.5-4.5 10-10 10h-63.4V752c0 5.5-4.5 10-10 10h-41.3c-5.5 0-10-4.5-10-10v-51.8h-63.1c-5.5 0-10-4.5-10-10v-21.3c0-5.5 4.5-10 10-10h63.1v-29.7h-63.1c-5.5 0-10-4.5-10-10v-21.3c0-5.5 4.5-10 10-10h45.2l-88-161.1c-2.6-4.8-.9-10.9 4-13.6 1.5-.8 3.1-1.2 4.8-1.2h46c3.8 0 7.2 2.1 8.9 5.5l72.9 144.3 73.2-144.3a10 10 0 0 1 8.9-5.5h45c5.5 0 10 4.5 10 10 .1 1.7-.3 3.3-1.1 4.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAccountBookOutlined, "Account Book Outlined"))]
 pub static AiAccountBookOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAccountBookOutlined",
+    label: "Account Book Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -34,8 +65,15 @@ pub static AiAccountBookOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M880 184H712v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H384v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H144c-17.7 0-32 14.3-32 32v664c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V216c0-17.7-14.3-32-32-32zm-40 656H184V256h128v48c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-48h256v48c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-48h128v584zM639.5 414h-45c-3 0-5.8 1.7-7.1 4.4L514 563.8h-2.8l-73.4-145.4a8 8 0 0 0-7.1-4.4h-46c-1.3 0-2.7.3-3.8 1-3.9 2.1-5.3 7-3.2 10.9l89.3 164h-48.6c-4.4 0-8 3.6-8 8v21.3c0 4.4 3.6 8 8 8h65.1v33.7h-65.1c-4.4 0-8 3.6-8 8v21.3c0 4.4 3.6 8 8 8h65.1V752c0 4.4 3.6 8 8 8h41.3c4.4 0 8-3.6 8-8v-53.8h65.4c4.4 0 8-3.6 8-8v-21.3c0-4.4-3.6-8-8-8h-65.4v-33.7h65.4c4.4 0 8-3.6 8-8v-21.3c0-4.4-3.6-8-8-8h-49.1l89.3-164.1c.6-1.2 1-2.5 1-3.8.1-4.4-3.4-8-7.9-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAccountBookTwotone, "Account Book Twotone"))]
 pub static AiAccountBookTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAccountBookTwotone",
+    label: "Account Book Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -52,8 +90,15 @@ pub static AiAccountBookTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M880 184H712v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H384v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H144c-17.7 0-32 14.3-32 32v664c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V216c0-17.7-14.3-32-32-32zm-40 656H184V256h128v48c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-48h256v48c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-48h128v584z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAimOutlined, "Aim Outlined"))]
 pub static AiAimOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAimOutlined",
+    label: "Aim Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -72,8 +117,15 @@ pub static AiAimOutlined: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M512 392c-32.1 0-62.1 12.4-84.8 35.2-22.7 22.7-35.2 52.7-35.2 84.8s12.5 62.1 35.2 84.8C449.9 619.4 480 632 512 632s62.1-12.5 84.8-35.2C619.4 574.1 632 544 632 512s-12.5-62.1-35.2-84.8C574.1 404.4 544.1 392 512 392z" p-id="13307" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAlertFilled, "Alert Filled"))]
 pub static AiAlertFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAlertFilled",
+    label: "Alert Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -88,8 +140,15 @@ pub static AiAlertFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M512 244c176.18 0 319 142.82 319 319v233a32 32 0 0 1-32 32H225a32 32 0 0 1-32-32V563c0-176.18 142.82-319 319-319zM484 68h56a8 8 0 0 1 8 8v96a8 8 0 0 1-8 8h-56a8 8 0 0 1-8-8V76a8 8 0 0 1 8-8zM177.25 191.66a8 8 0 0 1 11.32 0l67.88 67.88a8 8 0 0 1 0 11.31l-39.6 39.6a8 8 0 0 1-11.31 0l-67.88-67.88a8 8 0 0 1 0-11.31l39.6-39.6zm669.6 0l39.6 39.6a8 8 0 0 1 0 11.3l-67.88 67.9a8 8 0 0 1-11.32 0l-39.6-39.6a8 8 0 0 1 0-11.32l67.89-67.88a8 8 0 0 1 11.31 0zM192 892h640a32 32 0 0 1 32 32v24a8 8 0 0 1-8 8H168a8 8 0 0 1-8-8v-24a32 32 0 0 1 32-32zm148-317v253h64V575h-64z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAlertOutlined, "Alert Outlined"))]
 pub static AiAlertOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAlertOutlined",
+    label: "Alert Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -104,8 +163,15 @@ pub static AiAlertOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M193 796c0 17.7 14.3 32 32 32h574c17.7 0 32-14.3 32-32V563c0-176.2-142.8-319-319-319S193 386.8 193 563v233zm72-233c0-136.4 110.6-247 247-247s247 110.6 247 247v193H404V585c0-5.5-4.5-10-10-10h-44c-5.5 0-10 4.5-10 10v171h-75V563zm-48.1-252.5l39.6-39.6c3.1-3.1 3.1-8.2 0-11.3l-67.9-67.9a8.03 8.03 0 0 0-11.3 0l-39.6 39.6a8.03 8.03 0 0 0 0 11.3l67.9 67.9c3.1 3.1 8.1 3.1 11.3 0zm669.6-79.2l-39.6-39.6a8.03 8.03 0 0 0-11.3 0l-67.9 67.9a8.03 8.03 0 0 0 0 11.3l39.6 39.6c3.1 3.1 8.2 3.1 11.3 0l67.9-67.9c3.1-3.2 3.1-8.2 0-11.3zM832 892H192c-17.7 0-32 14.3-32 32v24c0 4.4 3.6 8 8 8h688c4.4 0 8-3.6 8-8v-24c0-17.7-14.3-32-32-32zM484 180h56c4.4 0 8-3.6 8-8V76c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v96c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAlertTwotone, "Alert Twotone"))]
 pub static AiAlertTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAlertTwotone",
+    label: "Alert Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -121,8 +187,15 @@ pub static AiAlertTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M216.9 310.5l39.6-39.6c3.1-3.1 3.1-8.2 0-11.3l-67.9-67.9a8.03 8.03 0 0 0-11.3 0l-39.6 39.6a8.03 8.03 0 0 0 0 11.3l67.9 67.9c3.1 3.1 8.1 3.1 11.3 0zm669.6-79.2l-39.6-39.6a8.03 8.03 0 0 0-11.3 0l-67.9 67.9a8.03 8.03 0 0 0 0 11.3l39.6 39.6c3.1 3.1 8.2 3.1 11.3 0l67.9-67.9c3.1-3.2 3.1-8.2 0-11.3zM484 180h56c4.4 0 8-3.6 8-8V76c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v96c0 4.4 3.6 8 8 8zm348 712H192c-17.7 0-32 14.3-32 32v24c0 4.4 3.6 8 8 8h688c4.4 0 8-3.6 8-8v-24c0-17.7-14.3-32-32-32zm-639-96c0 17.7 14.3 32 32 32h574c17.7 0 32-14.3 32-32V563c0-176.2-142.8-319-319-319S193 386.8 193 563v233zm72-233c0-136.4 110.6-247 247-247s247 110.6 247 247v193H404V585c0-5.5-4.5-10-10-10h-44c-5.5 0-10 4.5-10 10v171h-75V563z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAlibabaOutlined, "Alibaba Outlined"))]
 pub static AiAlibabaOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAlibabaOutlined",
+    label: "Alibaba Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -137,8 +210,15 @@ pub static AiAlibabaOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M602.9 669.8c-37.2 2.6-33.6-17.3-11.5-46.2 50.4-67.2 143.7-158.5 147.9-225.2 5.8-86.6-81.3-113.4-171-113.4-62.4 1.6-127 18.9-171 34.6-151.6 53.5-246.6 137.5-306.9 232-62.4 93.4-43 183.2 91.8 185.8 101.8-4.2 170.5-32.5 239.7-68.2.5 0-192.5 55.1-263.9 14.7-7.9-4.2-15.7-10-17.8-26.2 0-33.1 54.6-67.7 86.6-78.7v-56.7c64.5 22.6 140.6 16.3 205.7-32 2.1 5.8 4.2 13.1 3.7 21h11c2.6-22.6-12.6-44.6-37.8-46.2 7.3 5.8 12.6 10.5 15.2 14.7l-1 1-.5.5c-83.9 58.8-165.3 31.5-173.1 29.9l46.7-45.7-13.1-33.1c92.9-32.5 169.5-56.2 296.9-78.7l-28.5-23 14.7-8.9c75.5 21 126.4 36.7 123.8 76.6-1 6.8-3.7 14.7-7.9 23.1C660.1 466.1 594 538 567.2 569c-17.3 20.5-34.6 39.4-46.7 58.3-13.6 19.4-20.5 37.3-21 53.5 2.6 131.8 391.4-61.9 468-112.9-111.7 47.8-232.9 93.5-364.6 101.9zm85-302.9c2.8 5.2 4.1 11.6 4.1 19.1-.1-6.8-1.4-13.3-4.1-19.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAlignCenterOutlined, "Align Center Outlined"))]
 pub static AiAlignCenterOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAlignCenterOutlined",
+    label: "Align Center Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -153,8 +233,15 @@ pub static AiAlignCenterOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M264 230h496c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H264c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm496 424c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H264c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h496zm144 140H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-424H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAlignLeftOutlined, "Align Left Outlined"))]
 pub static AiAlignLeftOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAlignLeftOutlined",
+    label: "Align Left Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -169,8 +256,15 @@ pub static AiAlignLeftOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M120 230h496c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm0 424h496c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm784 140H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-424H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAlignRightOutlined, "Align Right Outlined"))]
 pub static AiAlignRightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAlignRightOutlined",
+    label: "Align Right Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -185,8 +279,15 @@ pub static AiAlignRightOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M904 158H408c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h496c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 424H408c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h496c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 212H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-424H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAlipayCircleFilled, "Alipay Circle Filled"))]
 pub static AiAlipayCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAlipayCircleFilled",
+    label: "Alipay Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -201,8 +302,15 @@ pub static AiAlipayCircleFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M308.6 545.7c-19.8 2-57.1 10.7-77.4 28.6-61 53-24.5 150 99 150 71.8 0 143.5-45.7 199.8-119-80.2-38.9-148.1-66.8-221.4-59.6zm460.5 67c100.1 33.4 154.7 43 166.7 44.8A445.9 445.9 0 0 0 960 512c0-247.4-200.6-448-448-448S64 264.6 64 512s200.6 448 448 448c155.9 0 293.2-79.7 373.5-200.5-75.6-29.8-213.6-85-286.8-120.1-69.9 85.7-160.1 137.8-253.7 137.8-158.4 0-212.1-138.1-137.2-229 16.3-19.8 44.2-38.7 87.3-49.4 67.5-16.5 175 10.3 275.7 43.4 18.1-33.3 33.4-69.9 44.7-108.9H305.1V402h160v-56.2H271.3v-31.3h193.8v-80.1s0-13.5 13.7-13.5H557v93.6h191.7v31.3H557.1V402h156.4c-15 61.1-37.7 117.4-66.2 166.8 47.5 17.1 90.1 33.3 121.8 43.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAlipayCircleOutlined, "Alipay Circle Outlined"))]
 pub static AiAlipayCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAlipayCircleOutlined",
+    label: "Alipay Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -217,8 +325,15 @@ pub static AiAlipayCircleOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M308.6 545.7c-19.8 2-57.1 10.7-77.4 28.6-61 53-24.5 150 99 150 71.8 0 143.5-45.7 199.8-119-80.2-38.9-148.1-66.8-221.4-59.6zm460.5 67c100.1 33.4 154.7 43 166.7 44.8A445.9 445.9 0 0 0 960 512c0-247.4-200.6-448-448-448S64 264.6 64 512s200.6 448 448 448c155.9 0 293.2-79.7 373.5-200.5-75.6-29.8-213.6-85-286.8-120.1-69.9 85.7-160.1 137.8-253.7 137.8-158.4 0-212.1-138.1-137.2-229 16.3-19.8 44.2-38.7 87.3-49.4 67.5-16.5 175 10.3 275.7 43.4 18.1-33.3 33.4-69.9 44.7-108.9H305.1V402h160v-56.2H271.3v-31.3h193.8v-80.1s0-13.5 13.7-13.5H557v93.6h191.7v31.3H557.1V402h156.4c-15 61.1-37.7 117.4-66.2 166.8 47.5 17.1 90.1 33.3 121.8 43.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAlipayOutlined, "Alipay Outlined"))]
 pub static AiAlipayOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAlipayOutlined",
+    label: "Alipay Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -233,8 +348,15 @@ pub static AiAlipayOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M789 610.3c-38.7-12.9-90.7-32.7-148.5-53.6 34.8-60.3 62.5-129 80.7-203.6H530.5v-68.6h233.6v-38.3H530.5V132h-95.4c-16.7 0-16.7 16.5-16.7 16.5v97.8H182.2v38.3h236.3v68.6H223.4v38.3h378.4a667.18 667.18 0 0 1-54.5 132.9c-122.8-40.4-253.8-73.2-336.1-53-52.6 13-86.5 36.1-106.5 60.3-91.4 111-25.9 279.6 167.2 279.6C386 811.2 496 747.6 581.2 643 708.3 704 960 808.7 960 808.7V659.4s-31.6-2.5-171-49.1zM253.9 746.6c-150.5 0-195-118.3-120.6-183.1 24.8-21.9 70.2-32.6 94.4-35 89.4-8.8 172.2 25.2 269.9 72.8-68.8 89.5-156.3 145.3-243.7 145.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAlipaySquareFilled, "Alipay Square Filled"))]
 pub static AiAlipaySquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAlipaySquareFilled",
+    label: "Alipay Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -249,8 +371,15 @@ pub static AiAlipaySquareFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M308.6 545.7c-19.8 2-57.1 10.7-77.4 28.6-61 53-24.5 150 99 150 71.8 0 143.5-45.7 199.8-119-80.2-38.9-148.1-66.8-221.4-59.6zM880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm29.4 663.2S703 689.4 598.7 639.5C528.8 725.2 438.6 777.3 345 777.3c-158.4 0-212.1-138.1-137.2-229 16.3-19.8 44.2-38.7 87.3-49.4 67.5-16.5 175 10.3 275.7 43.4 18.1-33.3 33.4-69.9 44.7-108.9H305.1V402h160v-56.2H271.3v-31.3h193.8v-80.1s0-13.5 13.7-13.5H557v93.6h191.7v31.3H557.1V402h156.4c-15 61.1-37.7 117.4-66.2 166.8 47.5 17.1 90.1 33.3 121.8 43.9 114.3 38.2 140.2 40.2 140.2 40.2v122.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAliwangwangFilled, "Aliwangwang Filled"))]
 pub static AiAliwangwangFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAliwangwangFilled",
+    label: "Aliwangwang Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -265,8 +394,15 @@ pub static AiAliwangwangFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M868.2 377.4c-18.9-45.1-46.3-85.6-81.2-120.6a377.26 377.26 0 0 0-120.5-81.2A375.65 375.65 0 0 0 519 145.8c-41.9 0-82.9 6.7-121.9 20C306 123.3 200.8 120 170.6 120c-2.2 0-7.4 0-9.4.2-11.9.4-22.8 6.5-29.2 16.4-6.5 9.9-7.7 22.4-3.4 33.5l64.3 161.6a378.59 378.59 0 0 0-52.8 193.2c0 51.4 10 101 29.8 147.6 18.9 45 46.2 85.6 81.2 120.5 34.7 34.8 75.4 62.1 120.5 81.2C418.3 894 467.9 904 519 904c51.3 0 100.9-10 147.7-29.8 44.9-18.9 85.5-46.3 120.4-81.2 34.7-34.8 62.1-75.4 81.2-120.6a376.5 376.5 0 0 0 29.8-147.6c-.2-51.2-10.1-100.8-29.9-147.4zm-325.2 79c0 20.4-16.6 37.1-37.1 37.1-20.4 0-37.1-16.7-37.1-37.1v-55.1c0-20.4 16.6-37.1 37.1-37.1 20.4 0 37.1 16.6 37.1 37.1v55.1zm175.2 0c0 20.4-16.6 37.1-37.1 37.1S644 476.8 644 456.4v-55.1c0-20.4 16.7-37.1 37.1-37.1 20.4 0 37.1 16.6 37.1 37.1v55.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAliwangwangOutlined, "Aliwangwang Outlined"))]
 pub static AiAliwangwangOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAliwangwangOutlined",
+    label: "Aliwangwang Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -281,8 +417,15 @@ pub static AiAliwangwangOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M868.2 377.4c-18.9-45.1-46.3-85.6-81.2-120.6a377.26 377.26 0 0 0-120.5-81.2A375.65 375.65 0 0 0 519 145.8c-41.9 0-82.9 6.7-121.9 20C306 123.3 200.8 120 170.6 120c-2.2 0-7.4 0-9.4.2-11.9.4-22.8 6.5-29.2 16.4-6.5 9.9-7.7 22.4-3.4 33.5l64.3 161.6a378.59 378.59 0 0 0-52.8 193.2c0 51.4 10 101 29.8 147.6 18.9 45 46.2 85.6 81.2 120.5 34.7 34.8 75.4 62.1 120.5 81.2C418.3 894 467.9 904 519 904c51.3 0 100.9-10.1 147.7-29.8 44.9-18.9 85.5-46.3 120.4-81.2 34.7-34.8 62.1-75.4 81.2-120.6a376.5 376.5 0 0 0 29.8-147.6c-.2-51.2-10.1-100.8-29.9-147.4zm-66.4 266.5a307.08 307.08 0 0 1-65.9 98c-28.4 28.5-61.3 50.7-97.7 65.9h-.1c-38 16-78.3 24.2-119.9 24.2a306.51 306.51 0 0 1-217.5-90.2c-28.4-28.5-50.6-61.4-65.8-97.8v-.1c-16-37.8-24.1-78.2-24.1-119.9 0-55.4 14.8-109.7 42.8-157l13.2-22.1-9.5-23.9L206 192c14.9.6 35.9 2.1 59.7 5.6 43.8 6.5 82.5 17.5 114.9 32.6l19 8.9 19.9-6.8c31.5-10.8 64.8-16.2 98.9-16.2a306.51 306.51 0 0 1 217.5 90.2c28.4 28.5 50.6 61.4 65.8 97.8l.1.1.1.1c16 37.6 24.1 78 24.2 119.8-.1 41.7-8.3 82-24.3 119.8zM681.1 364.2c-20.4 0-37.1 16.7-37.1 37.1v55.1c0 20.4 16.6 37.1 37.1 37.1s37.1-16.7 37.1-37.1v-55.1c0-20.5-16.7-37.1-37.1-37.1zm-175.2 0c-20.5 0-37.1 16.7-37.1 37.1v55.1c0 20.4 16.7 37.1 37.1 37.1 20.5 0 37.1-16.7 37.1-37.1v-55.1c0-20.5-16.7-37.1-37.1-37.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAliyunOutlined, "Aliyun Outlined"))]
 pub static AiAliyunOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAliyunOutlined",
+    label: "Aliyun Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -297,8 +440,15 @@ pub static AiAliyunOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M959.2 383.9c-.3-82.1-66.9-148.6-149.1-148.6H575.9l21.6 85.2 201 43.7a42.58 42.58 0 0 1 32.9 39.7c.1.5.1 216.1 0 216.6a42.58 42.58 0 0 1-32.9 39.7l-201 43.7-21.6 85.3h234.2c82.1 0 148.8-66.5 149.1-148.6V383.9zM225.5 660.4a42.58 42.58 0 0 1-32.9-39.7c-.1-.6-.1-216.1 0-216.6.8-19.4 14.6-35.5 32.9-39.7l201-43.7 21.6-85.2H213.8c-82.1 0-148.8 66.4-149.1 148.6V641c.3 82.1 67 148.6 149.1 148.6H448l-21.6-85.3-200.9-43.9zm200.9-158.8h171v21.3h-171z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAmazonCircleFilled, "Amazon Circle Filled"))]
 pub static AiAmazonCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAmazonCircleFilled",
+    label: "Amazon Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -313,8 +463,15 @@ pub static AiAmazonCircleFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M485 467.5c-11.6 4.9-20.9 12.2-27.8 22-6.9 9.8-10.4 21.6-10.4 35.5 0 17.8 7.5 31.5 22.4 41.2 14.1 9.1 28.9 11.4 44.4 6.8 17.9-5.2 30-17.9 36.4-38.1 3-9.3 4.5-19.7 4.5-31.3v-50.2c-12.6.4-24.4 1.6-35.5 3.7-11.1 2.1-22.4 5.6-34 10.4zM512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm35.8 262.7c-7.2-10.9-20.1-16.4-38.7-16.4-1.3 0-3 .1-5.3.3-2.2.2-6.6 1.5-12.9 3.7a79.4 79.4 0 0 0-17.9 9.1c-5.5 3.8-11.5 10-18 18.4-6.4 8.5-11.5 18.4-15.3 29.8l-94-8.4c0-12.4 2.4-24.7 7-36.9 4.7-12.2 11.8-23.9 21.4-35 9.6-11.2 21.1-21 34.5-29.4 13.4-8.5 29.6-15.2 48.4-20.3 18.9-5.1 39.1-7.6 60.9-7.6 21.3 0 40.6 2.6 57.8 7.7 17.2 5.2 31.1 11.5 41.4 19.1a117 117 0 0 1 25.9 25.7c6.9 9.6 11.7 18.5 14.4 26.7 2.7 8.2 4 15.7 4 22.8v182.5c0 6.4 1.4 13 4.3 19.8 2.9 6.8 6.3 12.8 10.2 18 3.9 5.2 7.9 9.9 12 14.3 4.1 4.3 7.6 7.7 10.6 9.9l4.1 3.4-72.5 69.4c-8.5-7.7-16.9-15.4-25.2-23.4-8.3-8-14.5-14-18.5-18.1l-6.1-6.2c-2.4-2.3-5-5.7-8-10.2-8.1 12.2-18.5 22.8-31.1 31.8-12.7 9-26.3 15.6-40.7 19.7-14.5 4.1-29.4 6.5-44.7 7.1-15.3.6-30-1.5-43.9-6.5-13.9-5-26.5-11.7-37.6-20.3-11.1-8.6-19.9-20.2-26.5-35-6.6-14.8-9.9-31.5-9.9-50.4 0-17.4 3-33.3 8.9-47.7 6-14.5 13.6-26.5 23-36.1 9.4-9.6 20.7-18.2 34-25.7s26.4-13.4 39.2-17.7c12.8-4.2 26.6-7.8 41.5-10.7 14.9-2.9 27.6-4.8 38.2-5.7 10.6-.9 21.2-1.6 31.8-2v-39.4c0-13.5-2.3-23.5-6.7-30.1zm180.5 379.6c-2.8 3.3-7.5 7.8-14.1 13.5s-16.8 12.7-30.5 21.1c-13.7 8.4-28.8 16-45 22.9-16.3 6.9-36.3 12.9-60.1 18-23.7 5.1-48.2 7.6-73.3 7.6-25.4 0-50.7-3.2-76.1-9.6-25.4-6.4-47.6-14.3-66.8-23.7-19.1-9.4-37.6-20.2-55.1-32.2-17.6-12.1-31.7-22.9-42.4-32.5-10.6-9.6-19.6-18.7-26.8-27.1-1.7-1.9-2.8-3.6-3.2-5.1-.4-1.5-.3-2.8.3-3.7.6-.9 1.5-1.6 2.6-2.2a7.42 7.42 0 0 1 7.4.8c40.9 24.2 72.9 41.3 95.9 51.4 82.9 36.4 168 45.7 255.3 27.9 40.5-8.3 82.1-22.2 124.9-41.8 3.2-1.2 6-1.5 8.3-.9 2.3.6 3.5 2.4 3.5 5.4 0 2.8-1.6 6.3-4.8 10.2zm59.9-29c-1.8 11.1-4.9 21.6-9.1 31.8-7.2 17.1-16.3 30-27.1 38.4-3.6 2.9-6.4 3.8-8.3 2.8-1.9-1-1.9-3.5 0-7.4 4.5-9.3 9.2-21.8 14.2-37.7 5-15.8 5.7-26 2.1-30.5-1.1-1.5-2.7-2.6-5-3.6-2.2-.9-5.1-1.5-8.6-1.9s-6.7-.6-9.4-.8c-2.8-.2-6.5-.2-11.2 0-4.7.2-8 .4-10.1.6a874.4 874.4 0 0 1-17.1 1.5c-1.3.2-2.7.4-4.1.5-1.5.1-2.7.2-3.5.3l-2.7.3c-1 .1-1.7.2-2.2.2h-3.2l-1-.2-.6-.5-.5-.9c-1.3-3.3 3.7-7.4 15-12.4s22.3-8.1 32.9-9.3c9.8-1.5 21.3-1.5 34.5-.3s21.3 3.7 24.3 7.4c2.3 3.5 2.5 10.7.7 21.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAmazonOutlined, "Amazon Outlined"))]
 pub static AiAmazonOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAmazonOutlined",
+    label: "Amazon Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -329,8 +486,15 @@ pub static AiAmazonOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M825 768.9c-3.3-.9-7.3-.4-11.9 1.3-61.6 28.2-121.5 48.3-179.7 60.2C507.7 856 385.2 842.6 266 790.3c-33.1-14.6-79.1-39.2-138-74a9.36 9.36 0 0 0-5.3-2c-2-.1-3.7.1-5.3.9-1.6.8-2.8 1.8-3.7 3.1-.9 1.3-1.1 3.1-.4 5.4.6 2.2 2.1 4.7 4.6 7.4 10.4 12.2 23.3 25.2 38.6 39s35.6 29.4 60.9 46.8c25.3 17.4 51.8 32.9 79.3 46.4 27.6 13.5 59.6 24.9 96.1 34.1s73 13.8 109.4 13.8c36.2 0 71.4-3.7 105.5-10.9 34.2-7.3 63-15.9 86.5-25.9 23.4-9.9 45-21 64.8-33 19.8-12 34.4-22.2 43.9-30.3 9.5-8.2 16.3-14.6 20.2-19.4 4.6-5.7 6.9-10.6 6.9-14.9.1-4.5-1.7-7.1-5-7.9zM527.4 348.1c-15.2 1.3-33.5 4.1-55 8.3-21.5 4.1-41.4 9.3-59.8 15.4s-37.2 14.6-56.3 25.4c-19.2 10.8-35.5 23.2-49 37s-24.5 31.1-33.1 52c-8.6 20.8-12.9 43.7-12.9 68.7 0 27.1 4.7 51.2 14.3 72.5 9.5 21.3 22.2 38 38.2 50.4 15.9 12.4 34 22.1 54 29.2 20 7.1 41.2 10.3 63.2 9.4 22-.9 43.5-4.3 64.4-10.3 20.8-5.9 40.4-15.4 58.6-28.3 18.2-12.9 33.1-28.2 44.8-45.7 4.3 6.6 8.1 11.5 11.5 14.7l8.7 8.9c5.8 5.9 14.7 14.6 26.7 26.1 11.9 11.5 24.1 22.7 36.3 33.7l104.4-99.9-6-4.9c-4.3-3.3-9.4-8-15.2-14.3-5.8-6.2-11.6-13.1-17.2-20.5-5.7-7.4-10.6-16.1-14.7-25.9-4.1-9.8-6.2-19.3-6.2-28.5V258.7c0-10.1-1.9-21-5.7-32.8-3.9-11.7-10.7-24.5-20.7-38.3-10-13.8-22.4-26.2-37.2-37-14.9-10.8-34.7-20-59.6-27.4-24.8-7.4-52.6-11.1-83.2-11.1-31.3 0-60.4 3.7-87.6 10.9-27.1 7.3-50.3 17-69.7 29.2-19.3 12.2-35.9 26.3-49.7 42.4-13.8 16.1-24.1 32.9-30.8 50.4-6.7 17.5-10.1 35.2-10.1 53.1L408 310c5.5-16.4 12.9-30.6 22-42.8 9.2-12.2 17.9-21 25.8-26.5 8-5.5 16.6-9.9 25.7-13.2 9.2-3.3 15.4-5 18.6-5.4 3.2-.3 5.7-.4 7.6-.4 26.7 0 45.2 7.9 55.6 23.6 6.5 9.5 9.7 23.9 9.7 43.3v56.6c-15.2.6-30.4 1.6-45.6 2.9zM573.1 500c0 16.6-2.2 31.7-6.5 45-9.2 29.1-26.7 47.4-52.4 54.8-22.4 6.6-43.7 3.3-63.9-9.8-21.5-14-32.2-33.8-32.2-59.3 0-19.9 5-36.9 15-51.1 10-14.1 23.3-24.7 40-31.7s33-12 49-14.9c15.9-3 33-4.8 51-5.4V500zm335.2 218.9c-4.3-5.4-15.9-8.9-34.9-10.7-19-1.8-35.5-1.7-49.7.4-15.3 1.8-31.1 6.2-47.3 13.4-16.3 7.1-23.4 13.1-21.6 17.8l.7 1.3.9.7 1.4.2h4.6c.8 0 1.8-.1 3.2-.2 1.4-.1 2.7-.3 3.9-.4 1.2-.1 2.9-.3 5.1-.4 2.1-.1 4.1-.4 6-.7.3 0 3.7-.3 10.3-.9 6.6-.6 11.4-1 14.3-1.3 2.9-.3 7.8-.6 14.5-.9 6.7-.3 12.1-.3 16.1 0 4 .3 8.5.7 13.6 1.1 5.1.4 9.2 1.3 12.4 2.7 3.2 1.3 5.6 3 7.1 5.1 5.2 6.6 4.2 21.2-3 43.9s-14 40.8-20.4 54.2c-2.8 5.7-2.8 9.2 0 10.7s6.7.1 11.9-4c15.6-12.2 28.6-30.6 39.1-55.3 6.1-14.6 10.5-29.8 13.1-45.7 2.4-15.9 2-26.2-1.3-31z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAmazonSquareFilled, "Amazon Square Filled"))]
 pub static AiAmazonSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAmazonSquareFilled",
+    label: "Amazon Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -345,8 +509,15 @@ pub static AiAmazonSquareFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM547.8 326.7c-7.2-10.9-20.1-16.4-38.7-16.4-1.3 0-3 .1-5.3.3-2.2.2-6.6 1.5-12.9 3.7a79.4 79.4 0 0 0-17.9 9.1c-5.5 3.8-11.5 10-18 18.4-6.4 8.5-11.5 18.4-15.3 29.8l-94-8.4c0-12.4 2.4-24.7 7-36.9s11.8-23.9 21.4-35c9.6-11.2 21.1-21 34.5-29.4 13.4-8.5 29.6-15.2 48.4-20.3 18.9-5.1 39.1-7.6 60.9-7.6 21.3 0 40.6 2.6 57.8 7.7 17.2 5.2 31.1 11.5 41.4 19.1a117 117 0 0 1 25.9 25.7c6.9 9.6 11.7 18.5 14.4 26.7 2.7 8.2 4 15.7 4 22.8v182.5c0 6.4 1.4 13 4.3 19.8 2.9 6.8 6.3 12.8 10.2 18 3.9 5.2 7.9 9.9 12 14.3 4.1 4.3 7.6 7.7 10.6 9.9l4.1 3.4-72.5 69.4c-8.5-7.7-16.9-15.4-25.2-23.4-8.3-8-14.5-14-18.5-18.1l-6.1-6.2c-2.4-2.3-5-5.7-8-10.2-8.1 12.2-18.5 22.8-31.1 31.8-12.7 9-26.3 15.6-40.7 19.7-14.5 4.1-29.4 6.5-44.7 7.1-15.3.6-30-1.5-43.9-6.5-13.9-5-26.5-11.7-37.6-20.3-11.1-8.6-19.9-20.2-26.5-35-6.6-14.8-9.9-31.5-9.9-50.4 0-17.4 3-33.3 8.9-47.7 6-14.5 13.6-26.5 23-36.1 9.4-9.6 20.7-18.2 34-25.7s26.4-13.4 39.2-17.7c12.8-4.2 26.6-7.8 41.5-10.7 14.9-2.9 27.6-4.8 38.2-5.7 10.6-.9 21.2-1.6 31.8-2v-39.4c0-13.5-2.3-23.5-6.7-30.1zm180.5 379.6c-2.8 3.3-7.5 7.8-14.1 13.5s-16.8 12.7-30.5 21.1c-13.7 8.4-28.8 16-45 22.9-16.3 6.9-36.3 12.9-60.1 18-23.7 5.1-48.2 7.6-73.3 7.6-25.4 0-50.7-3.2-76.1-9.6-25.4-6.4-47.6-14.3-66.8-23.7-19.1-9.4-37.6-20.2-55.1-32.2-17.6-12.1-31.7-22.9-42.4-32.5-10.6-9.6-19.6-18.7-26.8-27.1-1.7-1.9-2.8-3.6-3.2-5.1-.4-1.5-.3-2.8.3-3.7.6-.9 1.5-1.6 2.6-2.2a7.42 7.42 0 0 1 7.4.8c40.9 24.2 72.9 41.3 95.9 51.4 82.9 36.4 168 45.7 255.3 27.9 40.5-8.3 82.1-22.2 124.9-41.8 3.2-1.2 6-1.5 8.3-.9 2.3.6 3.5 2.4 3.5 5.4 0 2.8-1.6 6.3-4.8 10.2zm59.9-29c-1.8 11.1-4.9 21.6-9.1 31.8-7.2 17.1-16.3 30-27.1 38.4-3.6 2.9-6.4 3.8-8.3 2.8-1.9-1-1.9-3.5 0-7.4 4.5-9.3 9.2-21.8 14.2-37.7 5-15.8 5.7-26 2.1-30.5-1.1-1.5-2.7-2.6-5-3.6-2.2-.9-5.1-1.5-8.6-1.9s-6.7-.6-9.4-.8c-2.8-.2-6.5-.2-11.2 0-4.7.2-8 .4-10.1.6a874.4 874.4 0 0 1-17.1 1.5c-1.3.2-2.7.4-4.1.5-1.5.1-2.7.2-3.5.3l-2.7.3c-1 .1-1.7.2-2.2.2h-3.2l-1-.2-.6-.5-.5-.9c-1.3-3.3 3.7-7.4 15-12.4s22.3-8.1 32.9-9.3c9.8-1.5 21.3-1.5 34.5-.3s21.3 3.7 24.3 7.4c2.3 3.5 2.5 10.7.7 21.7zM485 467.5c-11.6 4.9-20.9 12.2-27.8 22-6.9 9.8-10.4 21.6-10.4 35.5 0 17.8 7.5 31.5 22.4 41.2 14.1 9.1 28.9 11.4 44.4 6.8 17.9-5.2 30-17.9 36.4-38.1 3-9.3 4.5-19.7 4.5-31.3v-50.2c-12.6.4-24.4 1.6-35.5 3.7-11.1 2.1-22.4 5.6-34 10.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAndroidFilled, "Android Filled"))]
 pub static AiAndroidFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAndroidFilled",
+    label: "Android Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -361,8 +532,15 @@ pub static AiAndroidFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M270.1 741.7c0 23.4 19.1 42.5 42.6 42.5h48.7v120.4c0 30.5 24.5 55.4 54.6 55.4 30.2 0 54.6-24.8 54.6-55.4V784.1h85v120.4c0 30.5 24.5 55.4 54.6 55.4 30.2 0 54.6-24.8 54.6-55.4V784.1h48.7c23.5 0 42.6-19.1 42.6-42.5V346.4h-486v395.3zm357.1-600.1l44.9-65c2.6-3.8 2-8.9-1.5-11.4-3.5-2.4-8.5-1.2-11.1 2.6l-46.6 67.6c-30.7-12.1-64.9-18.8-100.8-18.8-35.9 0-70.1 6.7-100.8 18.8l-46.6-67.5c-2.6-3.8-7.6-5.1-11.1-2.6-3.5 2.4-4.1 7.4-1.5 11.4l44.9 65c-71.4 33.2-121.4 96.1-127.8 169.6h486c-6.6-73.6-56.7-136.5-128-169.7zM409.5 244.1a26.9 26.9 0 1 1 26.9-26.9 26.97 26.97 0 0 1-26.9 26.9zm208.4 0a26.9 26.9 0 1 1 26.9-26.9 26.97 26.97 0 0 1-26.9 26.9zm223.4 100.7c-30.2 0-54.6 24.8-54.6 55.4v216.4c0 30.5 24.5 55.4 54.6 55.4 30.2 0 54.6-24.8 54.6-55.4V400.1c.1-30.6-24.3-55.3-54.6-55.3zm-658.6 0c-30.2 0-54.6 24.8-54.6 55.4v216.4c0 30.5 24.5 55.4 54.6 55.4 30.2 0 54.6-24.8 54.6-55.4V400.1c0-30.6-24.5-55.3-54.6-55.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAndroidOutlined, "Android Outlined"))]
 pub static AiAndroidOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAndroidOutlined",
+    label: "Android Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -377,8 +555,15 @@ pub static AiAndroidOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M448.3 225.2c-18.6 0-32 13.4-32 31.9s13.5 31.9 32 31.9c18.6 0 32-13.4 32-31.9.1-18.4-13.4-31.9-32-31.9zm393.9 96.4c-13.8-13.8-32.7-21.5-53.2-21.5-3.9 0-7.4.4-10.7 1v-1h-3.6c-5.5-30.6-18.6-60.5-38.1-87.4-18.7-25.7-43-47.9-70.8-64.9l25.1-35.8v-3.3c0-.8.4-2.3.7-3.8.6-2.4 1.4-5.5 1.4-8.9 0-18.5-13.5-31.9-32-31.9-9.8 0-19.5 5.7-25.9 15.4l-29.3 42.1c-30-9.8-62.4-15-93.8-15-31.3 0-63.7 5.2-93.8 15L389 79.4c-6.6-9.6-16.1-15.4-26-15.4-18.6 0-32 13.4-32 31.9 0 6.2 2.5 12.8 6.7 17.4l22.6 32.3c-28.7 17-53.5 39.4-72.2 65.1-19.4 26.9-32 56.8-36.7 87.4h-5.5v1c-3.2-.6-6.7-1-10.7-1-20.3 0-39.2 7.5-53.1 21.3-13.8 13.8-21.5 32.6-21.5 53v235c0 20.3 7.5 39.1 21.4 52.9 13.8 13.8 32.8 21.5 53.2 21.5 3.9 0 7.4-.4 10.7-1v93.5c0 29.2 23.9 53.1 53.2 53.1H331v58.3c0 20.3 7.5 39.1 21.4 52.9 13.8 13.8 32.8 21.5 53.2 21.5 20.3 0 39.2-7.5 53.1-21.3 13.8-13.8 21.5-32.6 21.5-53v-58.2H544v58.1c0 20.3 7.5 39.1 21.4 52.9 13.8 13.8 32.8 21.5 53.2 21.5 20.4 0 39.2-7.5 53.1-21.6 13.8-13.8 21.5-32.6 21.5-53v-58.2h31.9c29.3 0 53.2-23.8 53.2-53.1v-91.4c3.2.6 6.7 1 10.7 1 20.3 0 39.2-7.5 53.1-21.3 13.8-13.8 21.5-32.6 21.5-53v-235c-.1-20.3-7.6-39-21.4-52.9zM246 609.6c0 6.8-3.9 10.6-10.7 10.6-6.8 0-10.7-3.8-10.7-10.6V374.5c0-6.8 3.9-10.6 10.7-10.6 6.8 0 10.7 3.8 10.7 10.6v235.1zm131.1-396.8c37.5-27.3 85.3-42.3 135-42.3s97.5 15.1 135 42.5c32.4 23.7 54.2 54.2 62.7 87.5H314.4c8.5-33.4 30.5-64 62.7-87.7zm39.3 674.7c-.6 5.6-4.4 8.7-10.5 8.7-6.8 0-10.7-3.8-10.7-10.6v-58.2h21.2v60.1zm202.3 8.7c-6.8 0-10.7-3.8-10.7-10.6v-58.2h21.2v60.1c-.6 5.6-4.3 8.7-10.5 8.7zm95.8-132.6H309.9V364h404.6v399.6zm85.2-154c0 6.8-3.9 10.6-10.7 10.6-6.8 0-10.7-3.8-10.7-10.6V374.5c0-6.8 3.9-10.6 10.7-10.6 6.8 0 10.7 3.8 10.7 10.6v235.1zM576.1 225.2c-18.6 0-32 13.4-32 31.9s13.5 31.9 32 31.9c18.6 0 32.1-13.4 32.1-32-.1-18.6-13.4-31.8-32.1-31.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAntCloudOutlined, "Ant Cloud Outlined"))]
 pub static AiAntCloudOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAntCloudOutlined",
+    label: "Ant Cloud Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -393,8 +578,15 @@ pub static AiAntCloudOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M378.9 738c-3.1 0-6.1-.5-8.8-1.5l4.4 30.7h26.3l-15.5-29.9c-2.1.5-4.2.7-6.4.7zm421-291.2c-12.6 0-24.8 1.5-36.5 4.2-21.4-38.4-62.3-64.3-109.3-64.3-6.9 0-13.6.6-20.2 1.6-35.4-77.4-113.4-131.1-203.9-131.1-112.3 0-205.3 82.6-221.6 190.4C127.3 455.5 64 523.8 64 607c0 88.4 71.6 160.1 160 160.2h50l13.2-27.6c-26.2-8.3-43.3-29-39.1-48.8 4.6-21.6 32.8-33.9 63.1-27.5 22.9 4.9 40.4 19.1 45.5 35.1a26.1 26.1 0 0 1 22.1-12.4h.2c-.8-3.2-1.2-6.5-1.2-9.9 0-20.1 14.8-36.7 34.1-39.6v-25.4c0-4.4 3.6-8 8-8s8 3.6 8 8v26.3c4.6 1.2 8.8 3.2 12.6 5.8l19.5-21.4c3-3.3 8-3.5 11.3-.5 3.3 3 3.5 8 .5 11.3l-20 22-.2.2a40 40 0 0 1-46.9 59.2c-.4 5.6-2.6 10.7-6 14.8l20 38.4H804v-.1c86.5-2.2 156-73 156-160.1 0-88.5-71.7-160.2-160.1-160.2zM338.2 737.2l-4.3 30h24.4l-5.9-41.5c-3.5 4.6-8.3 8.5-14.2 11.5zM797.5 305a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm-65.7 61.3a24 24 0 1 0 48 0 24 24 0 1 0-48 0zM303.4 742.9l-11.6 24.3h26l3.5-24.7c-5.7.8-11.7 1-17.9.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAntDesignOutlined, "Ant Design Outlined"))]
 pub static AiAntDesignOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAntDesignOutlined",
+    label: "Ant Design Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -409,8 +601,15 @@ pub static AiAntDesignOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M716.3 313.8c19-18.9 19-49.7 0-68.6l-69.9-69.9.1.1c-18.5-18.5-50.3-50.3-95.3-95.2-21.2-20.7-55.5-20.5-76.5.5L80.9 474.2a53.84 53.84 0 0 0 0 76.4L474.6 944a54.14 54.14 0 0 0 76.5 0l165.1-165c19-18.9 19-49.7 0-68.6a48.7 48.7 0 0 0-68.7 0l-125 125.2c-5.2 5.2-13.3 5.2-18.5 0L189.5 521.4c-5.2-5.2-5.2-13.3 0-18.5l314.4-314.2c.4-.4.9-.7 1.3-1.1 5.2-4.1 12.4-3.7 17.2 1.1l125.2 125.1c19 19 49.8 19 68.7 0zM408.6 514.4a106.3 106.2 0 1 0 212.6 0 106.3 106.2 0 1 0-212.6 0zm536.2-38.6L821.9 353.5c-19-18.9-49.8-18.9-68.7.1a48.4 48.4 0 0 0 0 68.6l83 82.9c5.2 5.2 5.2 13.3 0 18.5l-81.8 81.7a48.4 48.4 0 0 0 0 68.6 48.7 48.7 0 0 0 68.7 0l121.8-121.7a53.93 53.93 0 0 0-.1-76.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiApartmentOutlined, "Apartment Outlined"))]
 pub static AiApartmentOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiApartmentOutlined",
+    label: "Apartment Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -425,8 +624,15 @@ pub static AiApartmentOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M908 640H804V488c0-4.4-3.6-8-8-8H548v-96h108c8.8 0 16-7.2 16-16V80c0-8.8-7.2-16-16-16H368c-8.8 0-16 7.2-16 16v288c0 8.8 7.2 16 16 16h108v96H228c-4.4 0-8 3.6-8 8v152H116c-8.8 0-16 7.2-16 16v288c0 8.8 7.2 16 16 16h288c8.8 0 16-7.2 16-16V656c0-8.8-7.2-16-16-16H292v-88h440v88H620c-8.8 0-16 7.2-16 16v288c0 8.8 7.2 16 16 16h288c8.8 0 16-7.2 16-16V656c0-8.8-7.2-16-16-16zm-564 76v168H176V716h168zm84-408V140h168v168H428zm420 576H680V716h168v168z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiApiFilled, "API Filled"))]
 pub static AiApiFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiApiFilled",
+    label: "API Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -441,8 +647,15 @@ pub static AiApiFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M917.7 148.8l-42.4-42.4c-1.6-1.6-3.6-2.3-5.7-2.3s-4.1.8-5.7 2.3l-76.1 76.1a199.27 199.27 0 0 0-112.1-34.3c-51.2 0-102.4 19.5-141.5 58.6L432.3 308.7a8.03 8.03 0 0 0 0 11.3L704 591.7c1.6 1.6 3.6 2.3 5.7 2.3 2 0 4.1-.8 5.7-2.3l101.9-101.9c68.9-69 77-175.7 24.3-253.5l76.1-76.1c3.1-3.2 3.1-8.3 0-11.4zM578.9 546.7a8.03 8.03 0 0 0-11.3 0L501 613.3 410.7 523l66.7-66.7c3.1-3.1 3.1-8.2 0-11.3L441 408.6a8.03 8.03 0 0 0-11.3 0L363 475.3l-43-43a7.85 7.85 0 0 0-5.7-2.3c-2 0-4.1.8-5.7 2.3L206.8 534.2c-68.9 68.9-77 175.7-24.3 253.5l-76.1 76.1a8.03 8.03 0 0 0 0 11.3l42.4 42.4c1.6 1.6 3.6 2.3 5.7 2.3s4.1-.8 5.7-2.3l76.1-76.1c33.7 22.9 72.9 34.3 112.1 34.3 51.2 0 102.4-19.5 141.5-58.6l101.9-101.9c3.1-3.1 3.1-8.2 0-11.3l-43-43 66.7-66.7c3.1-3.1 3.1-8.2 0-11.3l-36.6-36.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiApiOutlined, "API Outlined"))]
 pub static AiApiOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiApiOutlined",
+    label: "API Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -457,8 +670,15 @@ pub static AiApiOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M917.7 148.8l-42.4-42.4c-1.6-1.6-3.6-2.3-5.7-2.3s-4.1.8-5.7 2.3l-76.1 76.1a199.27 199.27 0 0 0-112.1-34.3c-51.2 0-102.4 19.5-141.5 58.6L432.3 308.7a8.03 8.03 0 0 0 0 11.3L704 591.7c1.6 1.6 3.6 2.3 5.7 2.3 2 0 4.1-.8 5.7-2.3l101.9-101.9c68.9-69 77-175.7 24.3-253.5l76.1-76.1c3.1-3.2 3.1-8.3 0-11.4zM769.1 441.7l-59.4 59.4-186.8-186.8 59.4-59.4c24.9-24.9 58.1-38.7 93.4-38.7 35.3 0 68.4 13.7 93.4 38.7 24.9 24.9 38.7 58.1 38.7 93.4 0 35.3-13.8 68.4-38.7 93.4zm-190.2 105a8.03 8.03 0 0 0-11.3 0L501 613.3 410.7 523l66.7-66.7c3.1-3.1 3.1-8.2 0-11.3L441 408.6a8.03 8.03 0 0 0-11.3 0L363 475.3l-43-43a7.85 7.85 0 0 0-5.7-2.3c-2 0-4.1.8-5.7 2.3L206.8 534.2c-68.9 69-77 175.7-24.3 253.5l-76.1 76.1a8.03 8.03 0 0 0 0 11.3l42.4 42.4c1.6 1.6 3.6 2.3 5.7 2.3s4.1-.8 5.7-2.3l76.1-76.1c33.7 22.9 72.9 34.3 112.1 34.3 51.2 0 102.4-19.5 141.5-58.6l101.9-101.9c3.1-3.1 3.1-8.2 0-11.3l-43-43 66.7-66.7c3.1-3.1 3.1-8.2 0-11.3l-36.6-36.2zM441.7 769.1a131.32 131.32 0 0 1-93.4 38.7c-35.3 0-68.4-13.7-93.4-38.7a131.32 131.32 0 0 1-38.7-93.4c0-35.3 13.7-68.4 38.7-93.4l59.4-59.4 186.8 186.8-59.4 59.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiApiTwotone, "API Twotone"))]
 pub static AiApiTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiApiTwotone",
+    label: "API Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -474,8 +694,15 @@ pub static AiApiTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M578.9 546.7a8.03 8.03 0 0 0-11.3 0L501 613.3 410.7 523l66.7-66.7c3.1-3.1 3.1-8.2 0-11.3L441 408.6a8.03 8.03 0 0 0-11.3 0L363 475.3l-43-43a7.85 7.85 0 0 0-5.7-2.3c-2 0-4.1.8-5.7 2.3L206.8 534.2a199.45 199.45 0 0 0-58.6 140.4c-.2 39.5 11.2 79.1 34.3 113.1l-76.1 76.1a8.03 8.03 0 0 0 0 11.3l42.4 42.4c1.6 1.6 3.6 2.3 5.7 2.3s4.1-.8 5.7-2.3l76.1-76.1c33.7 22.9 72.9 34.3 112.1 34.3 51.2 0 102.4-19.5 141.5-58.6l101.9-101.9c3.1-3.1 3.1-8.2 0-11.3l-43-43 66.7-66.7c3.1-3.1 3.1-8.2 0-11.3l-36.6-36.2zM441.7 769.1a131.32 131.32 0 0 1-93.4 38.7c-35.3 0-68.4-13.7-93.4-38.7-24.9-24.9-38.7-58.1-38.7-93.4s13.7-68.4 38.7-93.4l59.4-59.4 186.8 186.8-59.4 59.4zm476-620.3l-42.4-42.4c-1.6-1.6-3.6-2.3-5.7-2.3s-4.1.8-5.7 2.3l-76.1 76.1a199.27 199.27 0 0 0-112.1-34.3c-51.2 0-102.4 19.5-141.5 58.6L432.3 308.7a8.03 8.03 0 0 0 0 11.3L704 591.7c1.6 1.6 3.6 2.3 5.7 2.3 2 0 4.1-.8 5.7-2.3l101.9-101.9c68.9-69 77-175.7 24.3-253.5l76.1-76.1c3.1-3.2 3.1-8.3 0-11.4zM769.1 441.7l-59.4 59.4-186.8-186.8 59.4-59.4c24.9-24.9 58.1-38.7 93.4-38.7s68.4 13.7 93.4 38.7c24.9 24.9 38.7 58.1 38.7 93.4s-13.8 68.4-38.7 93.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAppleFilled, "Apple Filled"))]
 pub static AiAppleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAppleFilled",
+    label: "Apple Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -490,8 +717,15 @@ pub static AiAppleFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M747.4 535.7c-.4-68.2 30.5-119.6 92.9-157.5-34.9-50-87.7-77.5-157.3-82.8-65.9-5.2-138 38.4-164.4 38.4-27.9 0-91.7-36.6-141.9-36.6C273.1 298.8 163 379.8 163 544.6c0 48.7 8.9 99 26.7 150.8 23.8 68.2 109.6 235.3 199.1 232.6 46.8-1.1 79.9-33.2 140.8-33.2 59.1 0 89.7 33.2 141.9 33.2 90.3-1.3 167.9-153.2 190.5-221.6-121.1-57.1-114.6-167.2-114.6-170.7zm-105.1-305c50.7-60.2 46.1-115 44.6-134.7-44.8 2.6-96.6 30.5-126.1 64.8-32.5 36.8-51.6 82.3-47.5 133.6 48.4 3.7 92.6-21.2 129-63.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAppleOutlined, "Apple Outlined"))]
 pub static AiAppleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAppleOutlined",
+    label: "Apple Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -506,8 +740,15 @@ pub static AiAppleOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M747.4 535.7c-.4-68.2 30.5-119.6 92.9-157.5-34.9-50-87.7-77.5-157.3-82.8-65.9-5.2-138 38.4-164.4 38.4-27.9 0-91.7-36.6-141.9-36.6C273.1 298.8 163 379.8 163 544.6c0 48.7 8.9 99 26.7 150.8 23.8 68.2 109.6 235.3 199.1 232.6 46.8-1.1 79.9-33.2 140.8-33.2 59.1 0 89.7 33.2 141.9 33.2 90.3-1.3 167.9-153.2 190.5-221.6-121.1-57.1-114.6-167.2-114.6-170.7zm-10.6 267c-14.3 19.9-28.7 35.6-41.9 45.7-10.5 8-18.6 11.4-24 11.6-9-.1-17.7-2.3-34.7-8.8-1.2-.5-2.5-1-4.2-1.6l-4.4-1.7c-17.4-6.7-27.8-10.3-41.1-13.8-18.6-4.8-37.1-7.4-56.9-7.4-20.2 0-39.2 2.5-58.1 7.2-13.9 3.5-25.6 7.4-42.7 13.8-.7.3-8.1 3.1-10.2 3.9-3.5 1.3-6.2 2.3-8.7 3.2-10.4 3.6-17 5.1-22.9 5.2-.7 0-1.3-.1-1.8-.2-1.1-.2-2.5-.6-4.1-1.3-4.5-1.8-9.9-5.1-16-9.8-14-10.9-29.4-28-45.1-49.9-27.5-38.6-53.5-89.8-66-125.7-15.4-44.8-23-87.7-23-128.6 0-60.2 17.8-106 48.4-137.1 26.3-26.6 61.7-41.5 97.8-42.3 5.9.1 14.5 1.5 25.4 4.5 8.6 2.3 18 5.4 30.7 9.9 3.8 1.4 16.9 6.1 18.5 6.7 7.7 2.8 13.5 4.8 19.2 6.6 18.2 5.8 32.3 9 47.6 9 15.5 0 28.8-3.3 47.7-9.8 7.1-2.4 32.9-12 37.5-13.6 25.6-9.1 44.5-14 60.8-15.2 4.8-.4 9.1-.4 13.2-.1 22.7 1.8 42.1 6.3 58.6 13.8-37.6 43.4-57 96.5-56.9 158.4-.3 14.7.9 31.7 5.1 51.8 6.4 30.5 18.6 60.7 37.9 89 14.7 21.5 32.9 40.9 54.7 57.8-11.5 23.7-25.6 48.2-40.4 68.8zm-94.5-572c50.7-60.2 46.1-115 44.6-134.7-44.8 2.6-96.6 30.5-126.1 64.8-32.5 36.8-51.6 82.3-47.5 133.6 48.4 3.7 92.6-21.2 129-63.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAppstoreAddOutlined, "Appstore Add Outlined"))]
 pub static AiAppstoreAddOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAppstoreAddOutlined",
+    label: "Appstore Add Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -525,8 +766,15 @@ pub static AiAppstoreAddOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M464 144H160c-8.8 0-16 7.2-16 16v304c0 8.8 7.2 16 16 16h304c8.8 0 16-7.2 16-16V160c0-8.8-7.2-16-16-16z m-52 268H212V212h200v200zM864 144H560c-8.8 0-16 7.2-16 16v304c0 8.8 7.2 16 16 16h304c8.8 0 16-7.2 16-16V160c0-8.8-7.2-16-16-16z m-52 268H612V212h200v200zM864 544H560c-8.8 0-16 7.2-16 16v304c0 8.8 7.2 16 16 16h304c8.8 0 16-7.2 16-16V560c0-8.8-7.2-16-16-16z m-52 268H612V612h200v200zM424 712H296V584c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v128H104c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h128v128c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V776h128c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8z" p-id="15092" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAppstoreFilled, "Appstore Filled"))]
 pub static AiAppstoreFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAppstoreFilled",
+    label: "Appstore Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -541,8 +789,15 @@ pub static AiAppstoreFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M864 144H560c-8.8 0-16 7.2-16 16v304c0 8.8 7.2 16 16 16h304c8.8 0 16-7.2 16-16V160c0-8.8-7.2-16-16-16zm0 400H560c-8.8 0-16 7.2-16 16v304c0 8.8 7.2 16 16 16h304c8.8 0 16-7.2 16-16V560c0-8.8-7.2-16-16-16zM464 144H160c-8.8 0-16 7.2-16 16v304c0 8.8 7.2 16 16 16h304c8.8 0 16-7.2 16-16V160c0-8.8-7.2-16-16-16zm0 400H160c-8.8 0-16 7.2-16 16v304c0 8.8 7.2 16 16 16h304c8.8 0 16-7.2 16-16V560c0-8.8-7.2-16-16-16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAppstoreOutlined, "Appstore Outlined"))]
 pub static AiAppstoreOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAppstoreOutlined",
+    label: "Appstore Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -557,8 +812,15 @@ pub static AiAppstoreOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M464 144H160c-8.8 0-16 7.2-16 16v304c0 8.8 7.2 16 16 16h304c8.8 0 16-7.2 16-16V160c0-8.8-7.2-16-16-16zm-52 268H212V212h200v200zm452-268H560c-8.8 0-16 7.2-16 16v304c0 8.8 7.2 16 16 16h304c8.8 0 16-7.2 16-16V160c0-8.8-7.2-16-16-16zm-52 268H612V212h200v200zM464 544H160c-8.8 0-16 7.2-16 16v304c0 8.8 7.2 16 16 16h304c8.8 0 16-7.2 16-16V560c0-8.8-7.2-16-16-16zm-52 268H212V612h200v200zm452-268H560c-8.8 0-16 7.2-16 16v304c0 8.8 7.2 16 16 16h304c8.8 0 16-7.2 16-16V560c0-8.8-7.2-16-16-16zm-52 268H612V612h200v200z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAppstoreTwotone, "Appstore Twotone"))]
 pub static AiAppstoreTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAppstoreTwotone",
+    label: "Appstore Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -574,8 +836,15 @@ pub static AiAppstoreTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path fill="#E6E6E6" d="M212 212h200v200H212zm400 0h200v200H612zM212 612h200v200H212zm400 0h200v200H612z" class="twotone-icon-secondary-color" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAreaChartOutlined, "Area Chart Outlined"))]
 pub static AiAreaChartOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAreaChartOutlined",
+    label: "Area Chart Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -590,8 +859,15 @@ pub static AiAreaChartOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M888 792H200V168c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v688c0 4.4 3.6 8 8 8h752c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-616-64h536c4.4 0 8-3.6 8-8V284c0-7.2-8.7-10.7-13.7-5.7L592 488.6l-125.4-124a8.03 8.03 0 0 0-11.3 0l-189 189.6a7.87 7.87 0 0 0-2.3 5.6V720c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiArrowDownOutlined, "Arrow Down Outlined"))]
 pub static AiArrowDownOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiArrowDownOutlined",
+    label: "Arrow Down Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -606,8 +882,15 @@ pub static AiArrowDownOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M862 465.3h-81c-4.6 0-9 2-12.1 5.5L550 723.1V160c0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8v563.1L255.1 470.8c-3-3.5-7.4-5.5-12.1-5.5h-81c-6.8 0-10.5 8.1-6 13.2L487.9 861a31.96 31.96 0 0 0 48.3 0L868 478.5c4.5-5.2.8-13.2-6-13.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiArrowLeftOutlined, "Arrow Left Outlined"))]
 pub static AiArrowLeftOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiArrowLeftOutlined",
+    label: "Arrow Left Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -622,8 +905,15 @@ pub static AiArrowLeftOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M872 474H286.9l350.2-304c5.6-4.9 2.2-14-5.2-14h-88.5c-3.9 0-7.6 1.4-10.5 3.9L155 487.8a31.96 31.96 0 0 0 0 48.3L535.1 866c1.5 1.3 3.3 2 5.2 2h91.5c7.4 0 10.8-9.2 5.2-14L286.9 550H872c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiArrowRightOutlined, "Arrow Right Outlined"))]
 pub static AiArrowRightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiArrowRightOutlined",
+    label: "Arrow Right Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -638,8 +928,15 @@ pub static AiArrowRightOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M869 487.8L491.2 159.9c-2.9-2.5-6.6-3.9-10.5-3.9h-88.5c-7.4 0-10.8 9.2-5.2 14l350.2 304H152c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h585.1L386.9 854c-5.6 4.9-2.2 14 5.2 14h91.5c1.9 0 3.8-.7 5.2-2L869 536.2a32.07 32.07 0 0 0 0-48.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiArrowUpOutlined, "Arrow Up Outlined"))]
 pub static AiArrowUpOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiArrowUpOutlined",
+    label: "Arrow Up Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -654,8 +951,15 @@ pub static AiArrowUpOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M868 545.5L536.1 163a31.96 31.96 0 0 0-48.3 0L156 545.5a7.97 7.97 0 0 0 6 13.2h81c4.6 0 9-2 12.1-5.5L474 300.9V864c0 4.4 3.6 8 8 8h60c4.4 0 8-3.6 8-8V300.9l218.9 252.3c3 3.5 7.4 5.5 12.1 5.5h81c6.8 0 10.5-8 6-13.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiArrowsAltOutlined, "Arrows Alt Outlined"))]
 pub static AiArrowsAltOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiArrowsAltOutlined",
+    label: "Arrows Alt Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -670,8 +974,15 @@ pub static AiArrowsAltOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M855 160.1l-189.2 23.5c-6.6.8-9.3 8.8-4.7 13.5l54.7 54.7-153.5 153.5a8.03 8.03 0 0 0 0 11.3l45.1 45.1c3.1 3.1 8.2 3.1 11.3 0l153.6-153.6 54.7 54.7a7.94 7.94 0 0 0 13.5-4.7L863.9 169a7.9 7.9 0 0 0-8.9-8.9zM416.6 562.3a8.03 8.03 0 0 0-11.3 0L251.8 715.9l-54.7-54.7a7.94 7.94 0 0 0-13.5 4.7L160.1 855c-.6 5.2 3.7 9.5 8.9 8.9l189.2-23.5c6.6-.8 9.3-8.8 4.7-13.5l-54.7-54.7 153.6-153.6c3.1-3.1 3.1-8.2 0-11.3l-45.2-45z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAudioFilled, "Audio Filled"))]
 pub static AiAudioFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAudioFilled",
+    label: "Audio Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -686,8 +997,15 @@ pub static AiAudioFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M512 624c93.9 0 170-75.2 170-168V232c0-92.8-76.1-168-170-168s-170 75.2-170 168v224c0 92.8 76.1 168 170 168zm330-170c0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8 0 140.3-113.7 254-254 254S258 594.3 258 454c0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8 0 168.7 126.6 307.9 290 327.6V884H326.7c-13.7 0-24.7 14.3-24.7 32v36c0 4.4 2.8 8 6.2 8h407.6c3.4 0 6.2-3.6 6.2-8v-36c0-17.7-11-32-24.7-32H548V782.1c165.3-18 294-158 294-328.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAudioMutedOutlined, "Audio Muted Outlined"))]
 pub static AiAudioMutedOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAudioMutedOutlined",
+    label: "Audio Muted Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -706,8 +1024,15 @@ pub static AiAudioMutedOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M833 446h-60c-4.4 0-8 3.6-8 8 0 140.3-113.7 254-254 254-63 0-120.7-23-165-61l-54 54c48.9 43.2 110.8 72.3 179 81v102H326c-13.9 0-24.9 14.3-25 32v36c0.1 4.4 2.9 8 6 8h408c3.2 0 6-3.6 6-8v-36c0-17.7-11-32-25-32H547V782c165.3-17.9 294-157.9 294-328 0-4.4-3.6-8-8-8zM846.1 68.3l-43.5-41.9c-3.1-3-8.1-3-11.2 0.1l-129 129C634.3 101.2 577 64 511 64c-93.9 0-170 75.3-170 168v224c0 6.7 0.4 13.3 1.2 19.8l-68 68c-10.5-27.9-16.3-58.2-16.2-89.8-0.2-4.4-3.8-8-8-8h-60c-4.4 0-8 3.6-8 8 0 53 12.5 103 34.6 147.4l-137 137c-3.1 3.1-3.1 8.2 0 11.3l42.7 42.7c3.1 3.1 8.2 3.1 11.3 0L846.2 79.8l0.1-0.1c3.1-3.2 3-8.3-0.2-11.4zM417 401V232c0-50.6 41.9-92 94-92 46 0 84.1 32.3 92.3 74.7L417 401z" p-id="8068" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAudioOutlined, "Audio Outlined"))]
 pub static AiAudioOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAudioOutlined",
+    label: "Audio Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -722,8 +1047,15 @@ pub static AiAudioOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M842 454c0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8 0 140.3-113.7 254-254 254S258 594.3 258 454c0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8 0 168.7 126.6 307.9 290 327.6V884H326.7c-13.7 0-24.7 14.3-24.7 32v36c0 4.4 2.8 8 6.2 8h407.6c3.4 0 6.2-3.6 6.2-8v-36c0-17.7-11-32-24.7-32H548V782.1c165.3-18 294-158 294-328.1zM512 624c93.9 0 170-75.2 170-168V232c0-92.8-76.1-168-170-168s-170 75.2-170 168v224c0 92.8 76.1 168 170 168zm-94-392c0-50.6 41.9-92 94-92s94 41.4 94 92v224c0 50.6-41.9 92-94 92s-94-41.4-94-92V232z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAudioTwotone, "Audio Twotone"))]
 pub static AiAudioTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAudioTwotone",
+    label: "Audio Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -740,8 +1072,15 @@ pub static AiAudioTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M512 624c93.9 0 170-75.2 170-168V232c0-92.8-76.1-168-170-168s-170 75.2-170 168v224c0 92.8 76.1 168 170 168zm-98-392c0-52.8 43.7-96 98-96s98 43.2 98 96v224c0 52.8-43.7 96-98 96s-98-43.2-98-96V232z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiAuditOutlined, "Audit Outlined"))]
 pub static AiAuditOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiAuditOutlined",
+    label: "Audit Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -756,8 +1095,15 @@ pub static AiAuditOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M296 250c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h384c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H296zm184 144H296c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zm-48 458H208V148h560v320c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V108c0-17.7-14.3-32-32-32H168c-17.7 0-32 14.3-32 32v784c0 17.7 14.3 32 32 32h264c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm440-88H728v-36.6c46.3-13.8 80-56.6 80-107.4 0-61.9-50.1-112-112-112s-112 50.1-112 112c0 50.7 33.7 93.6 80 107.4V764H520c-8.8 0-16 7.2-16 16v152c0 8.8 7.2 16 16 16h352c8.8 0 16-7.2 16-16V780c0-8.8-7.2-16-16-16zM646 620c0-27.6 22.4-50 50-50s50 22.4 50 50-22.4 50-50 50-50-22.4-50-50zm180 266H566v-60h260v60z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBackwardFilled, "Backward Filled"))]
 pub static AiBackwardFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBackwardFilled",
+    label: "Backward Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -772,8 +1118,15 @@ pub static AiBackwardFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M485.6 249.9L198.2 498c-8.3 7.1-8.3 20.8 0 27.9l287.4 248.2c10.7 9.2 26.4.9 26.4-14V263.8c0-14.8-15.7-23.2-26.4-13.9zm320 0L518.2 498a18.6 18.6 0 0 0-6.2 14c0 5.2 2.1 10.4 6.2 14l287.4 248.2c10.7 9.2 26.4.9 26.4-14V263.8c0-14.8-15.7-23.2-26.4-13.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBackwardOutlined, "Backward Outlined"))]
 pub static AiBackwardOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBackwardOutlined",
+    label: "Backward Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -788,8 +1141,15 @@ pub static AiBackwardOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M485.6 249.9L198.2 498c-8.3 7.1-8.3 20.8 0 27.9l287.4 248.2c10.7 9.2 26.4.9 26.4-14V263.8c0-14.8-15.7-23.2-26.4-13.9zm320 0L518.2 498a18.6 18.6 0 0 0-6.2 14c0 5.2 2.1 10.4 6.2 14l287.4 248.2c10.7 9.2 26.4.9 26.4-14V263.8c0-14.8-15.7-23.2-26.4-13.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBankFilled, "Bank Filled"))]
 pub static AiBankFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBankFilled",
+    label: "Bank Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -804,8 +1164,15 @@ pub static AiBankFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M894 462c30.9 0 43.8-39.7 18.7-58L530.8 126.2a31.81 31.81 0 0 0-37.6 0L111.3 404c-25.1 18.2-12.2 58 18.8 58H192v374h-72c-4.4 0-8 3.6-8 8v52c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-52c0-4.4-3.6-8-8-8h-72V462h62zM381 836H264V462h117v374zm189 0H453V462h117v374zm190 0H642V462h118v374z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBankOutlined, "Bank Outlined"))]
 pub static AiBankOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBankOutlined",
+    label: "Bank Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -820,8 +1187,15 @@ pub static AiBankOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M894 462c30.9 0 43.8-39.7 18.7-58L530.8 126.2a31.81 31.81 0 0 0-37.6 0L111.3 404c-25.1 18.2-12.2 58 18.8 58H192v374h-72c-4.4 0-8 3.6-8 8v52c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-52c0-4.4-3.6-8-8-8h-72V462h62zM512 196.7l271.1 197.2H240.9L512 196.7zM264 462h117v374H264V462zm189 0h117v374H453V462zm307 374H642V462h118v374z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBankTwotone, "Bank Twotone"))]
 pub static AiBankTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBankTwotone",
+    label: "Bank Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -837,8 +1211,15 @@ pub static AiBankTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M894 462c30.9 0 43.8-39.7 18.7-58L530.8 126.2a31.81 31.81 0 0 0-37.6 0L111.3 404c-25.1 18.2-12.2 58 18.8 58H192v374h-72c-4.4 0-8 3.6-8 8v52c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-52c0-4.4-3.6-8-8-8h-72V462h62zM381 836H264V462h117v374zm189 0H453V462h117v374zm190 0H642V462h118v374zM240.9 393.9L512 196.7l271.1 197.2H240.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBarChartOutlined, "Bar Chart Outlined"))]
 pub static AiBarChartOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBarChartOutlined",
+    label: "Bar Chart Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -853,8 +1234,15 @@ pub static AiBarChartOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M888 792H200V168c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v688c0 4.4 3.6 8 8 8h752c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-600-80h56c4.4 0 8-3.6 8-8V560c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v144c0 4.4 3.6 8 8 8zm152 0h56c4.4 0 8-3.6 8-8V384c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v320c0 4.4 3.6 8 8 8zm152 0h56c4.4 0 8-3.6 8-8V462c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v242c0 4.4 3.6 8 8 8zm152 0h56c4.4 0 8-3.6 8-8V304c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v400c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBarcodeOutlined, "Barcode Outlined"))]
 pub static AiBarcodeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBarcodeOutlined",
+    label: "Barcode Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -869,8 +1257,15 @@ pub static AiBarcodeOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M120 160H72c-4.4 0-8 3.6-8 8v688c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V168c0-4.4-3.6-8-8-8zm833 0h-48c-4.4 0-8 3.6-8 8v688c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V168c0-4.4-3.6-8-8-8zM200 736h112c4.4 0 8-3.6 8-8V168c0-4.4-3.6-8-8-8H200c-4.4 0-8 3.6-8 8v560c0 4.4 3.6 8 8 8zm321 0h48c4.4 0 8-3.6 8-8V168c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v560c0 4.4 3.6 8 8 8zm126 0h178c4.4 0 8-3.6 8-8V168c0-4.4-3.6-8-8-8H647c-4.4 0-8 3.6-8 8v560c0 4.4 3.6 8 8 8zm-255 0h48c4.4 0 8-3.6 8-8V168c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v560c0 4.4 3.6 8 8 8zm-79 64H201c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h112c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zm257 0h-48c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zm256 0H648c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h178c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zm-385 0h-48c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBarsOutlined, "Bars Outlined"))]
 pub static AiBarsOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBarsOutlined",
+    label: "Bars Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -885,8 +1280,15 @@ pub static AiBarsOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M912 192H328c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h584c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 284H328c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h584c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 284H328c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h584c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM104 228a56 56 0 1 0 112 0 56 56 0 1 0-112 0zm0 284a56 56 0 1 0 112 0 56 56 0 1 0-112 0zm0 284a56 56 0 1 0 112 0 56 56 0 1 0-112 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBehanceCircleFilled, "Behance Circle Filled"))]
 pub static AiBehanceCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBehanceCircleFilled",
+    label: "Behance Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -901,8 +1303,15 @@ pub static AiBehanceCircleFilled: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M420.3 470.3c8.7-6.3 12.9-16.7 12.9-31 .3-6.8-1.1-13.5-4.1-19.6-2.7-4.9-6.7-9-11.6-11.9a44.8 44.8 0 0 0-16.6-6c-6.4-1.2-12.9-1.8-19.3-1.7h-70.3v79.7h76.1c13.1.1 24.2-3.1 32.9-9.5zm11.8 72c-9.8-7.5-22.9-11.2-39.2-11.2h-81.8v94h80.2c7.5 0 14.4-.7 21.1-2.1a50.5 50.5 0 0 0 17.8-7.2c5.1-3.3 9.2-7.8 12.3-13.6 3-5.8 4.5-13.2 4.5-22.1 0-17.7-5-30.2-14.9-37.8zM512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm86.5 286.9h138.4v33.7H598.5v-33.7zM512 628.8a89.52 89.52 0 0 1-27 31c-11.8 8.2-24.9 14.2-38.8 17.7a167.4 167.4 0 0 1-44.6 5.7H236V342.1h161c16.3 0 31.1 1.5 44.6 4.3 13.4 2.8 24.8 7.6 34.4 14.1 9.5 6.5 17 15.2 22.3 26 5.2 10.7 7.9 24.1 7.9 40 0 17.2-3.9 31.4-11.7 42.9-7.9 11.5-19.3 20.8-34.8 28.1 21.1 6 36.6 16.7 46.8 31.7 10.4 15.2 15.5 33.4 15.5 54.8 0 17.4-3.3 32.3-10 44.8zM790.8 576H612.4c0 19.4 6.7 38 16.8 48 10.2 9.9 24.8 14.9 43.9 14.9 13.8 0 25.5-3.5 35.5-10.4 9.9-6.9 15.9-14.2 18.1-21.8h59.8c-9.6 29.7-24.2 50.9-44 63.7-19.6 12.8-43.6 19.2-71.5 19.2-19.5 0-37-3.2-52.7-9.3-15.1-5.9-28.7-14.9-39.9-26.5a121.2 121.2 0 0 1-25.1-41.2c-6.1-16.9-9.1-34.7-8.9-52.6 0-18.5 3.1-35.7 9.1-51.7 11.5-31.1 35.4-56 65.9-68.9 16.3-6.8 33.8-10.2 51.5-10 21 0 39.2 4 55 12.2a111.6 111.6 0 0 1 38.6 32.8c10.1 13.7 17.2 29.3 21.7 46.9 4.3 17.3 5.8 35.5 4.6 54.7zm-122-95.6c-10.8 0-19.9 1.9-26.9 5.6-7 3.7-12.8 8.3-17.2 13.6a48.4 48.4 0 0 0-9.1 17.4c-1.6 5.3-2.7 10.7-3.1 16.2H723c-1.6-17.3-7.6-30.1-15.6-39.1-8.4-8.9-21.9-13.7-38.6-13.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBehanceOutlined, "Behance Outlined"))]
 pub static AiBehanceOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBehanceOutlined",
+    label: "Behance Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -917,8 +1326,15 @@ pub static AiBehanceOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M634 294.3h199.5v48.4H634zM434.1 485.8c44.1-21.1 67.2-53.2 67.2-102.8 0-98.1-73-121.9-157.3-121.9H112v492.4h238.5c89.4 0 173.3-43 173.3-143 0-61.8-29.2-107.5-89.7-124.7zM220.2 345.1h101.5c39.1 0 74.2 10.9 74.2 56.3 0 41.8-27.3 58.6-66 58.6H220.2V345.1zm115.5 324.8H220.1V534.3H338c47.6 0 77.7 19.9 77.7 70.3 0 49.6-35.9 65.3-80 65.3zm575.8-89.5c0-105.5-61.7-193.4-173.3-193.4-108.5 0-182.3 81.7-182.3 188.8 0 111 69.9 187.2 182.3 187.2 85.1 0 140.2-38.3 166.7-120h-86.3c-9.4 30.5-47.6 46.5-77.3 46.5-57.4 0-87.4-33.6-87.4-90.7h256.9c.3-5.9.7-12.1.7-18.4zM653.9 537c3.1-46.9 34.4-76.2 81.2-76.2 49.2 0 73.8 28.9 78.1 76.2H653.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBehanceSquareFilled, "Behance Square Filled"))]
 pub static AiBehanceSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBehanceSquareFilled",
+    label: "Behance Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -933,8 +1349,15 @@ pub static AiBehanceSquareFilled: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM598.5 350.9h138.4v33.7H598.5v-33.7zM512 628.8a89.52 89.52 0 0 1-27 31c-11.8 8.2-24.9 14.2-38.8 17.7a167.4 167.4 0 0 1-44.6 5.7H236V342.1h161c16.3 0 31.1 1.5 44.6 4.3 13.4 2.8 24.8 7.6 34.4 14.1 9.5 6.5 17 15.2 22.3 26 5.2 10.7 7.9 24.1 7.9 40 0 17.2-3.9 31.4-11.7 42.9-7.9 11.5-19.3 20.8-34.8 28.1 21.1 6 36.6 16.7 46.8 31.7 10.4 15.2 15.5 33.4 15.5 54.8 0 17.4-3.3 32.3-10 44.8zM790.8 576H612.4c0 19.4 6.7 38 16.8 48 10.2 9.9 24.8 14.9 43.9 14.9 13.8 0 25.5-3.5 35.5-10.4 9.9-6.9 15.9-14.2 18.1-21.8h59.8c-9.6 29.7-24.2 50.9-44 63.7-19.6 12.8-43.6 19.2-71.5 19.2-19.5 0-37-3.2-52.7-9.3-15.1-5.9-28.7-14.9-39.9-26.5a121.2 121.2 0 0 1-25.1-41.2c-6.1-16.9-9.1-34.7-8.9-52.6 0-18.5 3.1-35.7 9.1-51.7 11.5-31.1 35.4-56 65.9-68.9 16.3-6.8 33.8-10.2 51.5-10 21 0 39.2 4 55 12.2a111.6 111.6 0 0 1 38.6 32.8c10.1 13.7 17.2 29.3 21.7 46.9 4.3 17.3 5.8 35.5 4.6 54.7zm-122-95.6c-10.8 0-19.9 1.9-26.9 5.6-7 3.7-12.8 8.3-17.2 13.6a48.4 48.4 0 0 0-9.1 17.4c-1.6 5.3-2.7 10.7-3.1 16.2H723c-1.6-17.3-7.6-30.1-15.6-39.1-8.4-8.9-21.9-13.7-38.6-13.7zm-248.5-10.1c8.7-6.3 12.9-16.7 12.9-31 .3-6.8-1.1-13.5-4.1-19.6-2.7-4.9-6.7-9-11.6-11.9a44.8 44.8 0 0 0-16.6-6c-6.4-1.2-12.9-1.8-19.3-1.7h-70.3v79.7h76.1c13.1.1 24.2-3.1 32.9-9.5zm11.8 72c-9.8-7.5-22.9-11.2-39.2-11.2h-81.8v94h80.2c7.5 0 14.4-.7 21.1-2.1s12.7-3.8 17.8-7.2c5.1-3.3 9.2-7.8 12.3-13.6 3-5.8 4.5-13.2 4.5-22.1 0-17.7-5-30.2-14.9-37.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBehanceSquareOutlined, "Behance Square Outlined"))]
 pub static AiBehanceSquareOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBehanceSquareOutlined",
+    label: "Behance Square Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -949,8 +1372,15 @@ pub static AiBehanceSquareOutlined: &icondata_core::IconData = &icondata_core::I
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM598.5 350.9h138.4v33.7H598.5v-33.7zM512 628.8a89.52 89.52 0 0 1-27 31c-11.8 8.2-24.9 14.2-38.8 17.7a167.4 167.4 0 0 1-44.6 5.7H236V342.1h161c16.3 0 31.1 1.5 44.6 4.3 13.4 2.8 24.8 7.6 34.4 14.1 9.5 6.5 17 15.2 22.3 26 5.2 10.7 7.9 24.1 7.9 40 0 17.2-3.9 31.4-11.7 42.9-7.9 11.5-19.3 20.8-34.8 28.1 21.1 6 36.6 16.7 46.8 31.7 10.4 15.2 15.5 33.4 15.5 54.8 0 17.4-3.3 32.3-10 44.8zM790.8 576H612.4c0 19.4 6.7 38 16.8 48 10.2 9.9 24.8 14.9 43.9 14.9 13.8 0 25.5-3.5 35.5-10.4 9.9-6.9 15.9-14.2 18.1-21.8h59.8c-9.6 29.7-24.2 50.9-44 63.7-19.6 12.8-43.6 19.2-71.5 19.2-19.5 0-37-3.2-52.7-9.3-15.1-5.9-28.7-14.9-39.9-26.5a121.2 121.2 0 0 1-25.1-41.2c-6.1-16.9-9.1-34.7-8.9-52.6 0-18.5 3.1-35.7 9.1-51.7 11.5-31.1 35.4-56 65.9-68.9 16.3-6.8 33.8-10.2 51.5-10 21 0 39.2 4 55 12.2a111.6 111.6 0 0 1 38.6 32.8c10.1 13.7 17.2 29.3 21.7 46.9 4.3 17.3 5.8 35.5 4.6 54.7zm-122-95.6c-10.8 0-19.9 1.9-26.9 5.6-7 3.7-12.8 8.3-17.2 13.6a48.4 48.4 0 0 0-9.1 17.4c-1.6 5.3-2.7 10.7-3.1 16.2H723c-1.6-17.3-7.6-30.1-15.6-39.1-8.4-8.9-21.9-13.7-38.6-13.7zm-248.5-10.1c8.7-6.3 12.9-16.7 12.9-31 .3-6.8-1.1-13.5-4.1-19.6-2.7-4.9-6.7-9-11.6-11.9a44.8 44.8 0 0 0-16.6-6c-6.4-1.2-12.9-1.8-19.3-1.7h-70.3v79.7h76.1c13.1.1 24.2-3.1 32.9-9.5zm11.8 72c-9.8-7.5-22.9-11.2-39.2-11.2h-81.8v94h80.2c7.5 0 14.4-.7 21.1-2.1s12.7-3.8 17.8-7.2c5.1-3.3 9.2-7.8 12.3-13.6 3-5.8 4.5-13.2 4.5-22.1 0-17.7-5-30.2-14.9-37.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBellFilled, "Bell Filled"))]
 pub static AiBellFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBellFilled",
+    label: "Bell Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -965,8 +1395,15 @@ pub static AiBellFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M816 768h-24V428c0-141.1-104.3-257.8-240-277.2V112c0-22.1-17.9-40-40-40s-40 17.9-40 40v38.8C336.3 170.2 232 286.9 232 428v340h-24c-17.7 0-32 14.3-32 32v32c0 4.4 3.6 8 8 8h216c0 61.8 50.2 112 112 112s112-50.2 112-112h216c4.4 0 8-3.6 8-8v-32c0-17.7-14.3-32-32-32zM512 888c-26.5 0-48-21.5-48-48h96c0 26.5-21.5 48-48 48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBellOutlined, "Bell Outlined"))]
 pub static AiBellOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBellOutlined",
+    label: "Bell Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -981,8 +1418,15 @@ pub static AiBellOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M816 768h-24V428c0-141.1-104.3-257.7-240-277.1V112c0-22.1-17.9-40-40-40s-40 17.9-40 40v38.9c-135.7 19.4-240 136-240 277.1v340h-24c-17.7 0-32 14.3-32 32v32c0 4.4 3.6 8 8 8h216c0 61.8 50.2 112 112 112s112-50.2 112-112h216c4.4 0 8-3.6 8-8v-32c0-17.7-14.3-32-32-32zM512 888c-26.5 0-48-21.5-48-48h96c0 26.5-21.5 48-48 48zM304 768V428c0-55.6 21.6-107.8 60.9-147.1S456.4 220 512 220c55.6 0 107.8 21.6 147.1 60.9S720 372.4 720 428v340H304z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBellTwotone, "Bell Twotone"))]
 pub static AiBellTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBellTwotone",
+    label: "Bell Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -998,8 +1442,15 @@ pub static AiBellTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M816 768h-24V428c0-141.1-104.3-257.7-240-277.1V112c0-22.1-17.9-40-40-40s-40 17.9-40 40v38.9c-135.7 19.4-240 136-240 277.1v340h-24c-17.7 0-32 14.3-32 32v32c0 4.4 3.6 8 8 8h216c0 61.8 50.2 112 112 112s112-50.2 112-112h216c4.4 0 8-3.6 8-8v-32c0-17.7-14.3-32-32-32zM512 888c-26.5 0-48-21.5-48-48h96c0 26.5-21.5 48-48 48zm208-120H304V428c0-55.6 21.6-107.8 60.9-147.1S456.4 220 512 220c55.6 0 107.8 21.6 147.1 60.9S720 372.4 720 428v340z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBgColorsOutlined, "Bg Colors Outlined"))]
 pub static AiBgColorsOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBgColorsOutlined",
+    label: "Bg Colors Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1014,8 +1465,15 @@ pub static AiBgColorsOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M766.4 744.3c43.7 0 79.4-36.2 79.4-80.5 0-53.5-79.4-140.8-79.4-140.8S687 610.3 687 663.8c0 44.3 35.7 80.5 79.4 80.5zm-377.1-44.1c7.1 7.1 18.6 7.1 25.6 0l256.1-256c7.1-7.1 7.1-18.6 0-25.6l-256-256c-.6-.6-1.3-1.2-2-1.7l-78.2-78.2a9.11 9.11 0 0 0-12.8 0l-48 48a9.11 9.11 0 0 0 0 12.8l67.2 67.2-207.8 207.9c-7.1 7.1-7.1 18.6 0 25.6l255.9 256zm12.9-448.6l178.9 178.9H223.4l178.8-178.9zM904 816H120c-4.4 0-8 3.6-8 8v80c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-80c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBlockOutlined, "Block Outlined"))]
 pub static AiBlockOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBlockOutlined",
+    label: "Block Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1030,8 +1488,15 @@ pub static AiBlockOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M856 376H648V168c0-8.8-7.2-16-16-16H168c-8.8 0-16 7.2-16 16v464c0 8.8 7.2 16 16 16h208v208c0 8.8 7.2 16 16 16h464c8.8 0 16-7.2 16-16V392c0-8.8-7.2-16-16-16zm-480 16v188H220V220h360v156H392c-8.8 0-16 7.2-16 16zm204 52v136H444V444h136zm224 360H444V648h188c8.8 0 16-7.2 16-16V444h156v360z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBoldOutlined, "Bold Outlined"))]
 pub static AiBoldOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBoldOutlined",
+    label: "Bold Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1046,8 +1511,15 @@ pub static AiBoldOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M697.8 481.4c33.6-35 54.2-82.3 54.2-134.3v-10.2C752 229.3 663.9 142 555.3 142H259.4c-15.1 0-27.4 12.3-27.4 27.4v679.1c0 16.3 13.2 29.5 29.5 29.5h318.7c117 0 211.8-94.2 211.8-210.5v-11c0-73-37.4-137.3-94.2-175.1zM328 238h224.7c57.1 0 103.3 44.4 103.3 99.3v9.5c0 54.8-46.3 99.3-103.3 99.3H328V238zm366.6 429.4c0 62.9-51.7 113.9-115.5 113.9H328V542.7h251.1c63.8 0 115.5 51 115.5 113.9v10.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBookFilled, "Book Filled"))]
 pub static AiBookFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBookFilled",
+    label: "Book Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1062,8 +1534,15 @@ pub static AiBookFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M832 64H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V96c0-17.7-14.3-32-32-32zM668 345.9L621.5 312 572 347.4V124h96v221.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBookOutlined, "Book Outlined"))]
 pub static AiBookOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBookOutlined",
+    label: "Book Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1078,8 +1557,15 @@ pub static AiBookOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M832 64H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V96c0-17.7-14.3-32-32-32zm-260 72h96v209.9L621.5 312 572 347.4V136zm220 752H232V136h280v296.9c0 3.3 1 6.6 3 9.3a15.9 15.9 0 0 0 22.3 3.7l83.8-59.9 81.4 59.4c2.7 2 6 3.1 9.4 3.1 8.8 0 16-7.2 16-16V136h64v752z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBookTwotone, "Book Twotone"))]
 pub static AiBookTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBookTwotone",
+    label: "Book Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1096,8 +1582,15 @@ pub static AiBookTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path fill="#E6E6E6" d="M727.9 136v296.5c0 8.8-7.2 16-16 16-3.4 0-6.7-1.1-9.4-3.1L621.1 386l-83.8 59.9a15.9 15.9 0 0 1-22.3-3.7c-2-2.7-3-6-3-9.3V136H232v752h559.9V136h-64z" class="twotone-icon-secondary-color" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBorderBottomOutlined, "Border Bottom Outlined"))]
 pub static AiBorderBottomOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBorderBottomOutlined",
+    label: "Border Bottom Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1112,8 +1605,15 @@ pub static AiBorderBottomOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M872 808H152c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h720c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-720-94h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm0-498h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm0 332h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm0-166h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm166 166h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm0-332h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm332 0h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm0 332h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm222-72h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-388 72h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm388-404h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-388 72h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm388 426h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-388 72h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm388-404h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-388 72h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBorderHorizontalOutlined, "Border Horizontal Outlined"))]
 pub static AiBorderHorizontalOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBorderHorizontalOutlined",
+    label: "Border Horizontal Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1128,8 +1628,15 @@ pub static AiBorderHorizontalOutlined: &icondata_core::IconData = &icondata_core
     data: r###"<path d="M540 144h-56c-4.4 0-8 3.6-8 8v720c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V152c0-4.4-3.6-8-8-8zm-166 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm498 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-664 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm498 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM208 310h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm664 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-664 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 166h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm664 332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM374 808h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm332 332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBorderInnerOutlined, "Border Inner Outlined"))]
 pub static AiBorderInnerOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBorderInnerOutlined",
+    label: "Border Inner Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1144,8 +1651,15 @@ pub static AiBorderInnerOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M872 476H548V144h-72v332H152c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h324v332h72V548h324c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-166h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 498h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-664h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 498h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM650 216h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm56 592h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-56-592h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm-166 0h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm56 592h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-56-426h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm56 260h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBorderLeftOutlined, "Border Left Outlined"))]
 pub static AiBorderLeftOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBorderLeftOutlined",
+    label: "Border Left Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1160,8 +1674,15 @@ pub static AiBorderLeftOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M208 144h-56c-4.4 0-8 3.6-8 8v720c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V152c0-4.4-3.6-8-8-8zm166 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm498 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm166 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM540 310h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 166h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm332 332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM374 808h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm332 332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBorderOuterOutlined, "Border Outer Outlined"))]
 pub static AiBorderOuterOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBorderOuterOutlined",
+    label: "Border Outer Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1176,8 +1697,15 @@ pub static AiBorderOuterOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656zM484 366h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zM302 548h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm364 0h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm-182 0h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm0 182h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBorderOutlined, "Border Outlined"))]
 pub static AiBorderOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBorderOutlined",
+    label: "Border Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1192,8 +1720,15 @@ pub static AiBorderOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBorderRightOutlined, "Border Right Outlined"))]
 pub static AiBorderRightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBorderRightOutlined",
+    label: "Border Right Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1208,8 +1743,15 @@ pub static AiBorderRightOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M872 144h-56c-4.4 0-8 3.6-8 8v720c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V152c0-4.4-3.6-8-8-8zm-166 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-498 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-166 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm166 166h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 166h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM208 808h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm498 332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM374 808h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBorderTopOutlined, "Border Top Outlined"))]
 pub static AiBorderTopOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBorderTopOutlined",
+    label: "Border Top Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1224,8 +1766,15 @@ pub static AiBorderTopOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M872 144H152c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h720c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM208 310h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 498h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 166h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm166-166h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm166 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm332 332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm332-498h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm332 332h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBorderVerticleOutlined, "Border Verticle Outlined"))]
 pub static AiBorderVerticleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBorderVerticleOutlined",
+    label: "Border Verticle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1240,8 +1789,15 @@ pub static AiBorderVerticleOutlined: &icondata_core::IconData = &icondata_core::
     data: r###"<path d="M872 476H152c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h720c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-166h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 498h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-664h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 498h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM650 216h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm56 592h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-56-592h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm-166 0h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm332 0h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zM208 808h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM152 382h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm332 0h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zM208 642h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm332 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBorderlessTableOutlined, "Borderless Table Outlined"))]
 pub static AiBorderlessTableOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBorderlessTableOutlined",
+    label: "Borderless Table Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1259,8 +1815,15 @@ pub static AiBorderlessTableOutlined: &icondata_core::IconData = &icondata_core:
 <path d="M117 368h231v64H117zM676 368h241v64H676zM412 368h200v64H412zM412 592h200v64H412zM676 592h241v64H676zM117 592h231v64H117zM412 432V179h-64v666h64V592zM676 368V179h-64v666h64V432z" p-id="10938" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBoxPlotFilled, "Box Plot Filled"))]
 pub static AiBoxPlotFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBoxPlotFilled",
+    label: "Box Plot Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1275,8 +1838,15 @@ pub static AiBoxPlotFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M952 224h-52c-4.4 0-8 3.6-8 8v248h-92V304c0-4.4-3.6-8-8-8H448v432h344c4.4 0 8-3.6 8-8V548h92v244c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8V232c0-4.4-3.6-8-8-8zm-728 80v176h-92V232c0-4.4-3.6-8-8-8H72c-4.4 0-8 3.6-8 8v560c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8V548h92v172c0 4.4 3.6 8 8 8h152V296H232c-4.4 0-8 3.6-8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBoxPlotOutlined, "Box Plot Outlined"))]
 pub static AiBoxPlotOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBoxPlotOutlined",
+    label: "Box Plot Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1291,8 +1861,15 @@ pub static AiBoxPlotOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M952 224h-52c-4.4 0-8 3.6-8 8v248h-92V304c0-4.4-3.6-8-8-8H232c-4.4 0-8 3.6-8 8v176h-92V232c0-4.4-3.6-8-8-8H72c-4.4 0-8 3.6-8 8v560c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8V548h92v172c0 4.4 3.6 8 8 8h560c4.4 0 8-3.6 8-8V548h92v244c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8V232c0-4.4-3.6-8-8-8zM296 368h88v288h-88V368zm432 288H448V368h280v288z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBoxPlotTwotone, "Box Plot Twotone"))]
 pub static AiBoxPlotTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBoxPlotTwotone",
+    label: "Box Plot Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1308,8 +1885,15 @@ pub static AiBoxPlotTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M952 224h-52c-4.4 0-8 3.6-8 8v248h-92V304c0-4.4-3.6-8-8-8H232c-4.4 0-8 3.6-8 8v176h-92V232c0-4.4-3.6-8-8-8H72c-4.4 0-8 3.6-8 8v560c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8V548h92v172c0 4.4 3.6 8 8 8h560c4.4 0 8-3.6 8-8V548h92v244c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8V232c0-4.4-3.6-8-8-8zM384 656h-88V368h88v288zm344 0H448V368h280v288z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBranchesOutlined, "Branches Outlined"))]
 pub static AiBranchesOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBranchesOutlined",
+    label: "Branches Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1324,8 +1908,15 @@ pub static AiBranchesOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M740 161c-61.8 0-112 50.2-112 112 0 50.1 33.1 92.6 78.5 106.9v95.9L320 602.4V318.1c44.2-15 76-56.9 76-106.1 0-61.8-50.2-112-112-112s-112 50.2-112 112c0 49.2 31.8 91 76 106.1V706c-44.2 15-76 56.9-76 106.1 0 61.8 50.2 112 112 112s112-50.2 112-112c0-49.2-31.8-91-76-106.1v-27.8l423.5-138.7a50.52 50.52 0 0 0 34.9-48.2V378.2c42.9-15.8 73.6-57 73.6-105.2 0-61.8-50.2-112-112-112zm-504 51a48.01 48.01 0 0 1 96 0 48.01 48.01 0 0 1-96 0zm96 600a48.01 48.01 0 0 1-96 0 48.01 48.01 0 0 1 96 0zm408-491a48.01 48.01 0 0 1 0-96 48.01 48.01 0 0 1 0 96z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBugFilled, "Bug Filled"))]
 pub static AiBugFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBugFilled",
+    label: "Bug Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1341,8 +1932,15 @@ pub static AiBugFilled: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M940 512H792V412c76.8 0 139-62.2 139-139 0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8 0 34.8-28.2 63-63 63H232c-34.8 0-63-28.2-63-63 0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8 0 76.8 62.2 139 139 139v100H84c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h148v96c0 6.5.2 13 .7 19.3C164.1 728.6 116 796.7 116 876c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8 0-44.2 23.9-82.9 59.6-103.7 6 17.2 13.6 33.6 22.7 49 24.3 41.5 59 76.2 100.5 100.5 28.9 16.9 61 28.8 95.3 34.5 4.4 0 8-3.6 8-8V484c0-4.4 3.6-8 8-8h60c4.4 0 8 3.6 8 8v464.2c0 4.4 3.6 8 8 8 34.3-5.7 66.4-17.6 95.3-34.5 41.5-24.3 76.2-59 100.5-100.5 9.1-15.5 16.7-31.9 22.7-49C812.1 793.1 836 831.8 836 876c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8 0-79.3-48.1-147.4-116.7-176.7.4-6.4.7-12.8.7-19.3v-96h148c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBugOutlined, "Bug Outlined"))]
 pub static AiBugOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBugOutlined",
+    label: "Bug Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1358,8 +1956,15 @@ pub static AiBugOutlined: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M940 512H792V412c76.8 0 139-62.2 139-139 0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8 0 34.8-28.2 63-63 63H232c-34.8 0-63-28.2-63-63 0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8 0 76.8 62.2 139 139 139v100H84c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h148v96c0 6.5.2 13 .7 19.3C164.1 728.6 116 796.7 116 876c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8 0-44.2 23.9-82.9 59.6-103.7 6 17.2 13.6 33.6 22.7 49 24.3 41.5 59 76.2 100.5 100.5S460.5 960 512 960s99.8-13.9 141.3-38.2c41.5-24.3 76.2-59 100.5-100.5 9.1-15.5 16.7-31.9 22.7-49C812.1 793.1 836 831.8 836 876c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8 0-79.3-48.1-147.4-116.7-176.7.4-6.4.7-12.8.7-19.3v-96h148c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM716 680c0 36.8-9.7 72-27.8 102.9-17.7 30.3-43 55.6-73.3 73.3C584 874.3 548.8 884 512 884s-72-9.7-102.9-27.8c-30.3-17.7-55.6-43-73.3-73.3C317.7 752 308 716.8 308 680V412h408v268z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBugTwotone, "Bug Twotone"))]
 pub static AiBugTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBugTwotone",
+    label: "Bug Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1375,8 +1980,15 @@ pub static AiBugTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M308 412v268c0 36.779 9.678 71.962 27.805 102.907a205.39 205.39 0 0 0 73.288 73.288C440.038 874.322 475.22 884 512 884c36.779 0 71.962-9.678 102.907-27.805a205.39 205.39 0 0 0 73.288-73.288C706.322 751.962 716 716.78 716 680V412H308z" fill="#E6E6E6" class="twotone-icon-secondary-color" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBuildFilled, "Build Filled"))]
 pub static AiBuildFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBuildFilled",
+    label: "Build Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1391,8 +2003,15 @@ pub static AiBuildFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M916 210H376c-17.7 0-32 14.3-32 32v236H108c-17.7 0-32 14.3-32 32v272c0 17.7 14.3 32 32 32h540c17.7 0 32-14.3 32-32V546h236c17.7 0 32-14.3 32-32V242c0-17.7-14.3-32-32-32zM612 746H412V546h200v200zm268-268H680V278h200v200z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBuildOutlined, "Build Outlined"))]
 pub static AiBuildOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBuildOutlined",
+    label: "Build Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1407,8 +2026,15 @@ pub static AiBuildOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M916 210H376c-17.7 0-32 14.3-32 32v236H108c-17.7 0-32 14.3-32 32v272c0 17.7 14.3 32 32 32h540c17.7 0 32-14.3 32-32V546h236c17.7 0 32-14.3 32-32V242c0-17.7-14.3-32-32-32zm-504 68h200v200H412V278zm-68 468H144V546h200v200zm268 0H412V546h200v200zm268-268H680V278h200v200z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBuildTwotone, "Build Twotone"))]
 pub static AiBuildTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBuildTwotone",
+    label: "Build Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1424,8 +2050,15 @@ pub static AiBuildTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M916 210H376c-17.7 0-32 14.3-32 32v236H108c-17.7 0-32 14.3-32 32v272c0 17.7 14.3 32 32 32h540c17.7 0 32-14.3 32-32V546h236c17.7 0 32-14.3 32-32V242c0-17.7-14.3-32-32-32zM344 746H144V546h200v200zm268 0H412V546h200v200zm0-268H412V278h200v200zm268 0H680V278h200v200z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBulbFilled, "Bulb Filled"))]
 pub static AiBulbFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBulbFilled",
+    label: "Bulb Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1440,8 +2073,15 @@ pub static AiBulbFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M348 676.1C250 619.4 184 513.4 184 392c0-181.1 146.9-328 328-328s328 146.9 328 328c0 121.4-66 227.4-164 284.1V792c0 17.7-14.3 32-32 32H380c-17.7 0-32-14.3-32-32V676.1zM392 888h240c4.4 0 8 3.6 8 8v32c0 17.7-14.3 32-32 32H416c-17.7 0-32-14.3-32-32v-32c0-4.4 3.6-8 8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBulbOutlined, "Bulb Outlined"))]
 pub static AiBulbOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBulbOutlined",
+    label: "Bulb Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1456,8 +2096,15 @@ pub static AiBulbOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M632 888H392c-4.4 0-8 3.6-8 8v32c0 17.7 14.3 32 32 32h192c17.7 0 32-14.3 32-32v-32c0-4.4-3.6-8-8-8zM512 64c-181.1 0-328 146.9-328 328 0 121.4 66 227.4 164 284.1V792c0 17.7 14.3 32 32 32h264c17.7 0 32-14.3 32-32V676.1c98-56.7 164-162.7 164-284.1 0-181.1-146.9-328-328-328zm127.9 549.8L604 634.6V752H420V634.6l-35.9-20.8C305.4 568.3 256 484.5 256 392c0-141.4 114.6-256 256-256s256 114.6 256 256c0 92.5-49.4 176.3-128.1 221.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiBulbTwotone, "Bulb Twotone"))]
 pub static AiBulbTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiBulbTwotone",
+    label: "Bulb Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1473,8 +2120,15 @@ pub static AiBulbTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M632 888H392c-4.4 0-8 3.6-8 8v32c0 17.7 14.3 32 32 32h192c17.7 0 32-14.3 32-32v-32c0-4.4-3.6-8-8-8zM512 64c-181.1 0-328 146.9-328 328 0 121.4 66 227.4 164 284.1V792c0 17.7 14.3 32 32 32h264c17.7 0 32-14.3 32-32V676.1c98-56.7 164-162.7 164-284.1 0-181.1-146.9-328-328-328zm127.9 549.8L604 634.6V752H420V634.6l-35.9-20.8C305.4 568.3 256 484.5 256 392c0-141.4 114.6-256 256-256s256 114.6 256 256c0 92.5-49.4 176.3-128.1 221.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCalculatorFilled, "Calculator Filled"))]
 pub static AiCalculatorFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCalculatorFilled",
+    label: "Calculator Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1489,8 +2143,15 @@ pub static AiCalculatorFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM440.2 765h-50.8c-2.2 0-4.5-1.1-5.9-2.9L348 718.6l-35.5 43.5a7.38 7.38 0 0 1-5.9 2.9h-50.8c-6.6 0-10.2-7.9-5.8-13.1l62.7-76.8-61.2-74.9c-4.3-5.2-.7-13.1 5.9-13.1h50.9c2.2 0 4.5 1.1 5.9 2.9l34 41.6 34-41.6c1.5-1.9 3.6-2.9 5.9-2.9h50.8c6.6 0 10.2 7.9 5.9 13.1L383.5 675l62.7 76.8c4.2 5.3.6 13.2-6 13.2zm7.8-382c0 2.2-1.4 4-3.2 4H376v68.7c0 1.9-1.8 3.3-4 3.3h-48c-2.2 0-4-1.4-4-3.2V387h-68.8c-1.8 0-3.2-1.8-3.2-4v-48c0-2.2 1.4-4 3.2-4H320v-68.8c0-1.8 1.8-3.2 4-3.2h48c2.2 0 4 1.4 4 3.2V331h68.7c1.9 0 3.3 1.8 3.3 4v48zm328 369c0 2.2-1.4 4-3.2 4H579.2c-1.8 0-3.2-1.8-3.2-4v-48c0-2.2 1.4-4 3.2-4h193.5c1.9 0 3.3 1.8 3.3 4v48zm0-104c0 2.2-1.4 4-3.2 4H579.2c-1.8 0-3.2-1.8-3.2-4v-48c0-2.2 1.4-4 3.2-4h193.5c1.9 0 3.3 1.8 3.3 4v48zm0-265c0 2.2-1.4 4-3.2 4H579.2c-1.8 0-3.2-1.8-3.2-4v-48c0-2.2 1.4-4 3.2-4h193.5c1.9 0 3.3 1.8 3.3 4v48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCalculatorOutlined, "Calculator Outlined"))]
 pub static AiCalculatorOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCalculatorOutlined",
+    label: "Calculator Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1505,8 +2166,15 @@ pub static AiCalculatorOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M251.2 387H320v68.8c0 1.8 1.8 3.2 4 3.2h48c2.2 0 4-1.4 4-3.3V387h68.8c1.8 0 3.2-1.8 3.2-4v-48c0-2.2-1.4-4-3.3-4H376v-68.8c0-1.8-1.8-3.2-4-3.2h-48c-2.2 0-4 1.4-4 3.2V331h-68.8c-1.8 0-3.2 1.8-3.2 4v48c0 2.2 1.4 4 3.2 4zm328 0h193.6c1.8 0 3.2-1.8 3.2-4v-48c0-2.2-1.4-4-3.3-4H579.2c-1.8 0-3.2 1.8-3.2 4v48c0 2.2 1.4 4 3.2 4zm0 265h193.6c1.8 0 3.2-1.8 3.2-4v-48c0-2.2-1.4-4-3.3-4H579.2c-1.8 0-3.2 1.8-3.2 4v48c0 2.2 1.4 4 3.2 4zm0 104h193.6c1.8 0 3.2-1.8 3.2-4v-48c0-2.2-1.4-4-3.3-4H579.2c-1.8 0-3.2 1.8-3.2 4v48c0 2.2 1.4 4 3.2 4zm-195.7-81l61.2-74.9c4.3-5.2.7-13.1-5.9-13.1H388c-2.3 0-4.5 1-5.9 2.9l-34 41.6-34-41.6a7.85 7.85 0 0 0-5.9-2.9h-50.9c-6.6 0-10.2 7.9-5.9 13.1l61.2 74.9-62.7 76.8c-4.4 5.2-.8 13.1 5.8 13.1h50.8c2.3 0 4.5-1 5.9-2.9l35.5-43.5 35.5 43.5c1.5 1.8 3.7 2.9 5.9 2.9h50.8c6.6 0 10.2-7.9 5.9-13.1L383.5 675zM880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-36 732H180V180h664v664z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCalculatorTwotone, "Calculator Twotone"))]
 pub static AiCalculatorTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCalculatorTwotone",
+    label: "Calculator Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1523,8 +2191,15 @@ pub static AiCalculatorTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M383.5 675l61.3-74.8c4.3-5.2.7-13.1-5.9-13.1h-50.8c-2.3 0-4.4 1-5.9 2.9l-34 41.6-34-41.6a7.69 7.69 0 0 0-5.9-2.9h-50.9c-6.6 0-10.2 7.9-5.9 13.1l61.2 74.9-62.7 76.8c-4.4 5.2-.8 13.1 5.8 13.1h50.8c2.3 0 4.4-1 5.9-2.9l35.5-43.5 35.5 43.5c1.4 1.8 3.7 2.9 5.9 2.9h50.8c6.6 0 10.2-7.9 6-13.2L383.5 675zM251.2 387H320v68.8c0 1.8 1.8 3.2 4 3.2h48c2.2 0 4-1.4 4-3.3V387h68.8c1.8 0 3.2-1.8 3.2-4v-48c0-2.2-1.4-4-3.3-4H376v-68.8c0-1.8-1.8-3.2-4-3.2h-48c-2.2 0-4 1.4-4 3.2V331h-68.8c-1.8 0-3.2 1.8-3.2 4v48c0 2.2 1.4 4 3.2 4zm328 369h193.6c1.8 0 3.2-1.8 3.2-4v-48c0-2.2-1.4-4-3.3-4H579.2c-1.8 0-3.2 1.8-3.2 4v48c0 2.2 1.4 4 3.2 4zm0-104h193.6c1.8 0 3.2-1.8 3.2-4v-48c0-2.2-1.4-4-3.3-4H579.2c-1.8 0-3.2 1.8-3.2 4v48c0 2.2 1.4 4 3.2 4zm0-265h193.6c1.8 0 3.2-1.8 3.2-4v-48c0-2.2-1.4-4-3.3-4H579.2c-1.8 0-3.2 1.8-3.2 4v48c0 2.2 1.4 4 3.2 4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCalendarFilled, "Calendar Filled"))]
 pub static AiCalendarFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCalendarFilled",
+    label: "Calendar Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1539,8 +2214,15 @@ pub static AiCalendarFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M112 880c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V460H112v420zm768-696H712v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H384v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H144c-17.7 0-32 14.3-32 32v176h800V216c0-17.7-14.3-32-32-32z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCalendarOutlined, "Calendar Outlined"))]
 pub static AiCalendarOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCalendarOutlined",
+    label: "Calendar Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1555,8 +2237,15 @@ pub static AiCalendarOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M880 184H712v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H384v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H144c-17.7 0-32 14.3-32 32v664c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V216c0-17.7-14.3-32-32-32zm-40 656H184V460h656v380zM184 392V256h128v48c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-48h256v48c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-48h128v136H184z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCalendarTwotone, "Calendar Twotone"))]
 pub static AiCalendarTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCalendarTwotone",
+    label: "Calendar Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1572,8 +2261,15 @@ pub static AiCalendarTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M880 184H712v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H384v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H144c-17.7 0-32 14.3-32 32v664c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V216c0-17.7-14.3-32-32-32zm-40 656H184V460h656v380zm0-448H184V256h128v48c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-48h256v48c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-48h128v136z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCameraFilled, "Camera Filled"))]
 pub static AiCameraFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCameraFilled",
+    label: "Camera Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1588,8 +2284,15 @@ pub static AiCameraFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M864 260H728l-32.4-90.8a32.07 32.07 0 0 0-30.2-21.2H358.6c-13.5 0-25.6 8.5-30.1 21.2L296 260H160c-44.2 0-80 35.8-80 80v456c0 44.2 35.8 80 80 80h704c44.2 0 80-35.8 80-80V340c0-44.2-35.8-80-80-80zM512 716c-88.4 0-160-71.6-160-160s71.6-160 160-160 160 71.6 160 160-71.6 160-160 160zm-96-160a96 96 0 1 0 192 0 96 96 0 1 0-192 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCameraOutlined, "Camera Outlined"))]
 pub static AiCameraOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCameraOutlined",
+    label: "Camera Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1604,8 +2307,15 @@ pub static AiCameraOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M864 248H728l-32.4-90.8a32.07 32.07 0 0 0-30.2-21.2H358.6c-13.5 0-25.6 8.5-30.1 21.2L296 248H160c-44.2 0-80 35.8-80 80v456c0 44.2 35.8 80 80 80h704c44.2 0 80-35.8 80-80V328c0-44.2-35.8-80-80-80zm8 536c0 4.4-3.6 8-8 8H160c-4.4 0-8-3.6-8-8V328c0-4.4 3.6-8 8-8h186.7l17.1-47.8 22.9-64.2h250.5l22.9 64.2 17.1 47.8H864c4.4 0 8 3.6 8 8v456zM512 384c-88.4 0-160 71.6-160 160s71.6 160 160 160 160-71.6 160-160-71.6-160-160-160zm0 256c-53 0-96-43-96-96s43-96 96-96 96 43 96 96-43 96-96 96z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCameraTwotone, "Camera Twotone"))]
 pub static AiCameraTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCameraTwotone",
+    label: "Camera Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1622,8 +2332,15 @@ pub static AiCameraTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M864 248H728l-32.4-90.8a32.07 32.07 0 0 0-30.2-21.2H358.6c-13.5 0-25.6 8.5-30.1 21.2L296 248H160c-44.2 0-80 35.8-80 80v456c0 44.2 35.8 80 80 80h704c44.2 0 80-35.8 80-80V328c0-44.2-35.8-80-80-80zm8 536c0 4.4-3.6 8-8 8H160c-4.4 0-8-3.6-8-8V328c0-4.4 3.6-8 8-8h186.7l17.1-47.8 22.9-64.2h250.5l22.9 64.2 17.1 47.8H864c4.4 0 8 3.6 8 8v456z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCarFilled, "Car Filled"))]
 pub static AiCarFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCarFilled",
+    label: "Car Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1638,8 +2355,15 @@ pub static AiCarFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M959 413.4L935.3 372a8 8 0 0 0-10.9-2.9l-50.7 29.6-78.3-216.2a63.9 63.9 0 0 0-60.9-44.4H301.2c-34.7 0-65.5 22.4-76.2 55.5l-74.6 205.2-50.8-29.6a8 8 0 0 0-10.9 2.9L65 413.4c-2.2 3.8-.9 8.6 2.9 10.8l60.4 35.2-14.5 40c-1.2 3.2-1.8 6.6-1.8 10v348.2c0 15.7 11.8 28.4 26.3 28.4h67.6c12.3 0 23-9.3 25.6-22.3l7.7-37.7h545.6l7.7 37.7c2.7 13 13.3 22.3 25.6 22.3h67.6c14.5 0 26.3-12.7 26.3-28.4V509.4c0-3.4-.6-6.8-1.8-10l-14.5-40 60.3-35.2a8 8 0 0 0 3-10.8zM264 621c-22.1 0-40-17.9-40-40s17.9-40 40-40 40 17.9 40 40-17.9 40-40 40zm388 75c0 4.4-3.6 8-8 8H380c-4.4 0-8-3.6-8-8v-84c0-4.4 3.6-8 8-8h40c4.4 0 8 3.6 8 8v36h168v-36c0-4.4 3.6-8 8-8h40c4.4 0 8 3.6 8 8v84zm108-75c-22.1 0-40-17.9-40-40s17.9-40 40-40 40 17.9 40 40-17.9 40-40 40zM220 418l72.7-199.9.5-1.3.4-1.3c1.1-3.3 4.1-5.5 7.6-5.5h427.6l75.4 208H220z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCarOutlined, "Car Outlined"))]
 pub static AiCarOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCarOutlined",
+    label: "Car Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1654,8 +2378,15 @@ pub static AiCarOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M380 704h264c4.4 0 8-3.6 8-8v-84c0-4.4-3.6-8-8-8h-40c-4.4 0-8 3.6-8 8v36H428v-36c0-4.4-3.6-8-8-8h-40c-4.4 0-8 3.6-8 8v84c0 4.4 3.6 8 8 8zm340-123a40 40 0 1 0 80 0 40 40 0 1 0-80 0zm239-167.6L935.3 372a8 8 0 0 0-10.9-2.9l-50.7 29.6-78.3-216.2a63.9 63.9 0 0 0-60.9-44.4H301.2c-34.7 0-65.5 22.4-76.2 55.5l-74.6 205.2-50.8-29.6a8 8 0 0 0-10.9 2.9L65 413.4c-2.2 3.8-.9 8.6 2.9 10.8l60.4 35.2-14.5 40c-1.2 3.2-1.8 6.6-1.8 10v348.2c0 15.7 11.8 28.4 26.3 28.4h67.6c12.3 0 23-9.3 25.6-22.3l7.7-37.7h545.6l7.7 37.7c2.7 13 13.3 22.3 25.6 22.3h67.6c14.5 0 26.3-12.7 26.3-28.4V509.4c0-3.4-.6-6.8-1.8-10l-14.5-40 60.3-35.2a8 8 0 0 0 3-10.8zM840 517v237H184V517l15.6-43h624.8l15.6 43zM292.7 218.1l.5-1.3.4-1.3c1.1-3.3 4.1-5.5 7.6-5.5h427.6l75.4 208H220l72.7-199.9zM224 581a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCarTwotone, "Car Twotone"))]
 pub static AiCarTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCarTwotone",
+    label: "Car Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1673,8 +2404,15 @@ pub static AiCarTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M224 581a40 40 0 1 0 80 0 40 40 0 1 0-80 0zm420 23h-40c-4.4 0-8 3.6-8 8v36H428v-36c0-4.4-3.6-8-8-8h-40c-4.4 0-8 3.6-8 8v84c0 4.4 3.6 8 8 8h264c4.4 0 8-3.6 8-8v-84c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCaretDownFilled, "Caret Down Filled"))]
 pub static AiCaretDownFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCaretDownFilled",
+    label: "Caret Down Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1689,8 +2427,15 @@ pub static AiCaretDownFilled: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M840.4 300H183.6c-19.7 0-30.7 20.8-18.5 35l328.4 380.8c9.4 10.9 27.5 10.9 37 0L858.9 335c12.2-14.2 1.2-35-18.5-35z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCaretDownOutlined, "Caret Down Outlined"))]
 pub static AiCaretDownOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCaretDownOutlined",
+    label: "Caret Down Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1705,8 +2450,15 @@ pub static AiCaretDownOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M840.4 300H183.6c-19.7 0-30.7 20.8-18.5 35l328.4 380.8c9.4 10.9 27.5 10.9 37 0L858.9 335c12.2-14.2 1.2-35-18.5-35z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCaretLeftFilled, "Caret Left Filled"))]
 pub static AiCaretLeftFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCaretLeftFilled",
+    label: "Caret Left Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1721,8 +2473,15 @@ pub static AiCaretLeftFilled: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M689 165.1L308.2 493.5c-10.9 9.4-10.9 27.5 0 37L689 858.9c14.2 12.2 35 1.2 35-18.5V183.6c0-19.7-20.8-30.7-35-18.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCaretLeftOutlined, "Caret Left Outlined"))]
 pub static AiCaretLeftOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCaretLeftOutlined",
+    label: "Caret Left Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1737,8 +2496,15 @@ pub static AiCaretLeftOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M689 165.1L308.2 493.5c-10.9 9.4-10.9 27.5 0 37L689 858.9c14.2 12.2 35 1.2 35-18.5V183.6c0-19.7-20.8-30.7-35-18.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCaretRightFilled, "Caret Right Filled"))]
 pub static AiCaretRightFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCaretRightFilled",
+    label: "Caret Right Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1753,8 +2519,15 @@ pub static AiCaretRightFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M715.8 493.5L335 165.1c-14.2-12.2-35-1.2-35 18.5v656.8c0 19.7 20.8 30.7 35 18.5l380.8-328.4c10.9-9.4 10.9-27.6 0-37z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCaretRightOutlined, "Caret Right Outlined"))]
 pub static AiCaretRightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCaretRightOutlined",
+    label: "Caret Right Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1769,8 +2542,15 @@ pub static AiCaretRightOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M715.8 493.5L335 165.1c-14.2-12.2-35-1.2-35 18.5v656.8c0 19.7 20.8 30.7 35 18.5l380.8-328.4c10.9-9.4 10.9-27.6 0-37z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCaretUpFilled, "Caret Up Filled"))]
 pub static AiCaretUpFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCaretUpFilled",
+    label: "Caret Up Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1785,8 +2565,15 @@ pub static AiCaretUpFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M858.9 689L530.5 308.2c-9.4-10.9-27.5-10.9-37 0L165.1 689c-12.2 14.2-1.2 35 18.5 35h656.8c19.7 0 30.7-20.8 18.5-35z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCaretUpOutlined, "Caret Up Outlined"))]
 pub static AiCaretUpOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCaretUpOutlined",
+    label: "Caret Up Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1801,8 +2588,15 @@ pub static AiCaretUpOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M858.9 689L530.5 308.2c-9.4-10.9-27.5-10.9-37 0L165.1 689c-12.2 14.2-1.2 35 18.5 35h656.8c19.7 0 30.7-20.8 18.5-35z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCarryOutFilled, "Carry Out Filled"))]
 pub static AiCarryOutFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCarryOutFilled",
+    label: "Carry Out Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1817,8 +2611,15 @@ pub static AiCarryOutFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 184H712v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H384v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H144c-17.7 0-32 14.3-32 32v664c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V216c0-17.7-14.3-32-32-32zM694.5 432.7L481.9 725.4a16.1 16.1 0 0 1-26 0l-126.4-174c-3.8-5.3 0-12.7 6.5-12.7h55.2c5.1 0 10 2.5 13 6.6l64.7 89 150.9-207.8c3-4.1 7.8-6.6 13-6.6H688c6.5.1 10.3 7.5 6.5 12.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCarryOutOutlined, "Carry Out Outlined"))]
 pub static AiCarryOutOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCarryOutOutlined",
+    label: "Carry Out Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1833,8 +2634,15 @@ pub static AiCarryOutOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M880 184H712v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H384v-64c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H144c-17.7 0-32 14.3-32 32v664c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V216c0-17.7-14.3-32-32-32zm-40 656H184V256h128v48c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-48h256v48c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-48h128v584zM688 420h-55.2c-5.1 0-10 2.5-13 6.6L468.9 634.4l-64.7-89c-3-4.1-7.8-6.6-13-6.6H336c-6.5 0-10.3 7.4-6.5 12.7l126.4 174a16.1 16.1 0 0 0 26 0l212.6-292.7c3.8-5.4 0-12.8-6.5-12.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCarryOutTwotone, "Carry Out Twotone"))]
 pub static AiCarryOutTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCarryOutTwotone",
+    label: "Carry Out Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1851,8 +2659,15 @@ pub static AiCarryOutTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M688 420h-55.2c-5.1 0-10 2.5-13 6.6L468.9 634.4l-64.7-89c-3-4.1-7.8-6.6-13-6.6H336c-6.5 0-10.3 7.4-6.5 12.7l126.4 174a16.1 16.1 0 0 0 26 0l212.6-292.7c3.8-5.4 0-12.8-6.5-12.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCheckCircleFilled, "Check Circle Filled"))]
 pub static AiCheckCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCheckCircleFilled",
+    label: "Check Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1867,8 +2682,15 @@ pub static AiCheckCircleFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm193.5 301.7l-210.6 292a31.8 31.8 0 0 1-51.7 0L318.5 484.9c-3.8-5.3 0-12.7 6.5-12.7h46.9c10.2 0 19.9 4.9 25.9 13.3l71.2 98.8 157.2-218c6-8.3 15.6-13.3 25.9-13.3H699c6.5 0 10.3 7.4 6.5 12.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCheckCircleOutlined, "Check Circle Outlined"))]
 pub static AiCheckCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCheckCircleOutlined",
+    label: "Check Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1884,8 +2706,15 @@ pub static AiCheckCircleOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCheckCircleTwotone, "Check Circle Twotone"))]
 pub static AiCheckCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCheckCircleTwotone",
+    label: "Check Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1902,8 +2731,15 @@ pub static AiCheckCircleTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M699 353h-46.9c-10.2 0-19.9 4.9-25.9 13.3L469 584.3l-71.2-98.8c-6-8.3-15.6-13.3-25.9-13.3H325c-6.5 0-10.3 7.4-6.5 12.7l124.6 172.8a31.8 31.8 0 0 0 51.7 0l210.6-292c3.9-5.3.1-12.7-6.4-12.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCheckOutlined, "Check Outlined"))]
 pub static AiCheckOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCheckOutlined",
+    label: "Check Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1918,8 +2754,15 @@ pub static AiCheckOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M912 190h-69.9c-9.8 0-19.1 4.5-25.1 12.2L404.7 724.5 207 474a32 32 0 0 0-25.1-12.2H112c-6.7 0-10.4 7.7-6.3 12.9l273.9 347c12.8 16.2 37.4 16.2 50.3 0l488.4-618.9c4.1-5.1.4-12.8-6.3-12.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCheckSquareFilled, "Check Square Filled"))]
 pub static AiCheckSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCheckSquareFilled",
+    label: "Check Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1934,8 +2777,15 @@ pub static AiCheckSquareFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM695.5 365.7l-210.6 292a31.8 31.8 0 0 1-51.7 0L308.5 484.9c-3.8-5.3 0-12.7 6.5-12.7h46.9c10.2 0 19.9 4.9 25.9 13.3l71.2 98.8 157.2-218c6-8.3 15.6-13.3 25.9-13.3H689c6.5 0 10.3 7.4 6.5 12.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCheckSquareOutlined, "Check Square Outlined"))]
 pub static AiCheckSquareOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCheckSquareOutlined",
+    label: "Check Square Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1951,8 +2801,15 @@ pub static AiCheckSquareOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCheckSquareTwotone, "Check Square Twotone"))]
 pub static AiCheckSquareTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCheckSquareTwotone",
+    label: "Check Square Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1969,8 +2826,15 @@ pub static AiCheckSquareTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M432.2 657.7a31.8 31.8 0 0 0 51.7 0l210.6-292c3.8-5.3 0-12.7-6.5-12.7h-46.9c-10.3 0-19.9 5-25.9 13.3L458 584.3l-71.2-98.8c-6-8.4-15.7-13.3-25.9-13.3H314c-6.5 0-10.3 7.4-6.5 12.7l124.7 172.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiChromeFilled, "Chrome Filled"))]
 pub static AiChromeFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiChromeFilled",
+    label: "Chrome Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1985,8 +2849,15 @@ pub static AiChromeFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M371.8 512c0 77.5 62.7 140.2 140.2 140.2S652.2 589.5 652.2 512 589.5 371.8 512 371.8 371.8 434.4 371.8 512zM900 362.4l-234.3 12.1c63.6 74.3 64.6 181.5 11.1 263.7l-188 289.2c78 4.2 158.4-12.9 231.2-55.2 180-104 253-322.1 180-509.8zM320.3 591.9L163.8 284.1A415.35 415.35 0 0 0 96 512c0 208 152.3 380.3 351.4 410.8l106.9-209.4c-96.6 18.2-189.9-34.8-234-121.5zm218.5-285.5l344.4 18.1C848 254.7 792.6 194 719.8 151.7 653.9 113.6 581.5 95.5 510.5 96c-122.5.5-242.2 55.2-322.1 154.5l128.2 196.9c32-91.9 124.8-146.7 222.2-141z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiChromeOutlined, "Chrome Outlined"))]
 pub static AiChromeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiChromeOutlined",
+    label: "Chrome Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2001,8 +2872,15 @@ pub static AiChromeOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M928 512.3v-.3c0-229.8-186.2-416-416-416S96 282.2 96 512v.4c0 229.8 186.2 416 416 416s416-186.2 416-416v-.3.2zm-6.7-74.6l.6 3.3-.6-3.3zM676.7 638.2c53.5-82.2 52.5-189.4-11.1-263.7l162.4-8.4c20.5 44.4 32 93.8 32 145.9 0 185.2-144.6 336.6-327.1 347.4l143.8-221.2zM512 652.3c-77.5 0-140.2-62.7-140.2-140.2 0-77.7 62.7-140.2 140.2-140.2S652.2 434.5 652.2 512 589.5 652.3 512 652.3zm369.2-331.7l-3-5.7 3 5.7zM512 164c121.3 0 228.2 62.1 290.4 156.2l-263.6-13.9c-97.5-5.7-190.2 49.2-222.3 141.1L227.8 311c63.1-88.9 166.9-147 284.2-147zM102.5 585.8c26 145 127.1 264 261.6 315.1C229.6 850 128.5 731 102.5 585.8zM164 512c0-55.9 13.2-108.7 36.6-155.5l119.7 235.4c44.1 86.7 137.4 139.7 234 121.6l-74 145.1C302.9 842.5 164 693.5 164 512zm324.7 415.4c4 .2 8 .4 12 .5-4-.2-8-.3-12-.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCiCircleFilled, "Ci Circle Filled"))]
 pub static AiCiCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCiCircleFilled",
+    label: "Ci Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2017,8 +2895,15 @@ pub static AiCiCircleFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm-63.6 656c-103 0-162.4-68.6-162.4-182.6v-49C286 373.5 345.4 304 448.3 304c88.3 0 152.3 56.9 152.3 138.1 0 2.4-2 4.4-4.4 4.4h-52.6c-4.2 0-7.6-3.2-8-7.4-4-46.1-37.6-77.6-87-77.6-61.1 0-95.6 45.4-95.6 126.9v49.3c0 80.3 34.5 125.1 95.6 125.1 49.3 0 82.8-29.5 87-72.4.4-4.1 3.8-7.3 8-7.3h52.7c2.4 0 4.4 2 4.4 4.4 0 77.4-64.3 132.5-152.3 132.5zM738 704.1c0 4.4-3.6 8-8 8h-50.4c-4.4 0-8-3.6-8-8V319.9c0-4.4 3.6-8 8-8H730c4.4 0 8 3.6 8 8v384.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCiCircleOutlined, "Ci Circle Outlined"))]
 pub static AiCiCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCiCircleOutlined",
+    label: "Ci Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2033,8 +2918,15 @@ pub static AiCiCircleOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm218-572.1h-50.4c-4.4 0-8 3.6-8 8v384.2c0 4.4 3.6 8 8 8H730c4.4 0 8-3.6 8-8V319.9c0-4.4-3.6-8-8-8zm-281.4 49.6c49.5 0 83.1 31.5 87 77.6.4 4.2 3.8 7.4 8 7.4h52.6c2.4 0 4.4-2 4.4-4.4 0-81.2-64-138.1-152.3-138.1C345.4 304 286 373.5 286 488.4v49c0 114 59.4 182.6 162.3 182.6 88 0 152.3-55.1 152.3-132.5 0-2.4-2-4.4-4.4-4.4h-52.7c-4.2 0-7.6 3.2-8 7.3-4.2 43-37.7 72.4-87 72.4-61.1 0-95.6-44.9-95.6-125.2v-49.3c.1-81.4 34.6-126.8 95.7-126.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCiCircleTwotone, "Ci Circle Twotone"))]
 pub static AiCiCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCiCircleTwotone",
+    label: "Ci Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2051,8 +2943,15 @@ pub static AiCiCircleTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M730 311.9h-50.4c-4.4 0-8 3.6-8 8v384.2c0 4.4 3.6 8 8 8H730c4.4 0 8-3.6 8-8V319.9c0-4.4-3.6-8-8-8zm-281.4 49.6c49.5 0 83.1 31.5 87 77.6.4 4.2 3.8 7.4 8 7.4h52.6c2.4 0 4.4-2 4.4-4.4 0-81.2-64-138.1-152.3-138.1C345.4 304 286 373.5 286 488.4v49c0 114 59.4 182.6 162.3 182.6 88 0 152.3-55.1 152.3-132.5 0-2.4-2-4.4-4.4-4.4h-52.7c-4.2 0-7.6 3.2-8 7.3-4.2 43-37.7 72.4-87 72.4-61.1 0-95.6-44.9-95.6-125.2v-49.3c.1-81.4 34.6-126.8 95.7-126.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCiOutlined, "Ci Outlined"))]
 pub static AiCiOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCiOutlined",
+    label: "Ci Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2067,8 +2966,15 @@ pub static AiCiOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm218-572.1h-50.4c-4.4 0-8 3.6-8 8v384.2c0 4.4 3.6 8 8 8H730c4.4 0 8-3.6 8-8V319.9c0-4.4-3.6-8-8-8zm-281.4 49.6c49.5 0 83.1 31.5 87 77.6.4 4.2 3.8 7.4 8 7.4h52.6c2.4 0 4.4-2 4.4-4.4 0-81.2-64-138.1-152.3-138.1C345.4 304 286 373.5 286 488.4v49c0 114 59.4 182.6 162.3 182.6 88 0 152.3-55.1 152.3-132.5 0-2.4-2-4.4-4.4-4.4h-52.7c-4.2 0-7.6 3.2-8 7.3-4.2 43-37.7 72.4-87 72.4-61.1 0-95.6-44.9-95.6-125.2v-49.3c.1-81.4 34.6-126.8 95.7-126.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCiTwotone, "Ci Twotone"))]
 pub static AiCiTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCiTwotone",
+    label: "Ci Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2085,8 +2991,15 @@ pub static AiCiTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M730 311.9h-50.4c-4.4 0-8 3.6-8 8v384.2c0 4.4 3.6 8 8 8H730c4.4 0 8-3.6 8-8V319.9c0-4.4-3.6-8-8-8zm-281.4 49.6c49.5 0 83.1 31.5 87 77.6.4 4.2 3.8 7.4 8 7.4h52.6c2.4 0 4.4-2 4.4-4.4 0-81.2-64-138.1-152.3-138.1C345.4 304 286 373.5 286 488.4v49c0 114 59.4 182.6 162.3 182.6 88 0 152.3-55.1 152.3-132.5 0-2.4-2-4.4-4.4-4.4h-52.7c-4.2 0-7.6 3.2-8 7.3-4.2 43-37.7 72.4-87 72.4-61.1 0-95.6-44.9-95.6-125.2v-49.3c.1-81.4 34.6-126.8 95.7-126.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiClearOutlined, "Clear Outlined"))]
 pub static AiClearOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiClearOutlined",
+    label: "Clear Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2104,8 +3017,15 @@ pub static AiClearOutlined: &icondata_core::IconData = &icondata_core::IconData
 <path d="M899.1 869.6l-53-305.6H864c14.4 0 26-11.6 26-26V346c0-14.4-11.6-26-26-26H618V138c0-14.4-11.6-26-26-26H432c-14.4 0-26 11.6-26 26v182H160c-14.4 0-26 11.6-26 26v192c0 14.4 11.6 26 26 26h17.9l-53 305.6c-0.3 1.5-0.4 3-0.4 4.4 0 14.4 11.6 26 26 26h723c1.5 0 3-0.1 4.4-0.4 14.2-2.4 23.7-15.9 21.2-30zM204 390h272V182h72v208h272v104H204V390z m468 440V674c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v156H416V674c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v156H202.8l45.1-260H776l45.1 260H672z" p-id="9724" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiClockCircleFilled, "Clock Circle Filled"))]
 pub static AiClockCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiClockCircleFilled",
+    label: "Clock Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2120,8 +3040,15 @@ pub static AiClockCircleFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm176.5 585.7l-28.6 39a7.99 7.99 0 0 1-11.2 1.7L483.3 569.8a7.92 7.92 0 0 1-3.3-6.5V288c0-4.4 3.6-8 8-8h48.1c4.4 0 8 3.6 8 8v247.5l142.6 103.1c3.6 2.5 4.4 7.5 1.8 11.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiClockCircleOutlined, "Clock Circle Outlined"))]
 pub static AiClockCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiClockCircleOutlined",
+    label: "Clock Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2137,8 +3064,15 @@ pub static AiClockCircleOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M686.7 638.6L544.1 535.5V288c0-4.4-3.6-8-8-8H488c-4.4 0-8 3.6-8 8v275.4c0 2.6 1.2 5 3.3 6.5l165.4 120.6c3.6 2.6 8.6 1.8 11.2-1.7l28.6-39c2.6-3.7 1.8-8.7-1.8-11.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiClockCircleTwotone, "Clock Circle Twotone"))]
 pub static AiClockCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiClockCircleTwotone",
+    label: "Clock Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2155,8 +3089,15 @@ pub static AiClockCircleTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M686.7 638.6L544.1 535.5V288c0-4.4-3.6-8-8-8H488c-4.4 0-8 3.6-8 8v275.3c0 2.6 1.2 5 3.3 6.5l165.4 120.6c3.6 2.6 8.6 1.9 11.2-1.7l28.6-39c2.6-3.6 1.8-8.6-1.8-11.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCloseCircleFilled, "Close Circle Filled"))]
 pub static AiCloseCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCloseCircleFilled",
+    label: "Close Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2171,8 +3112,15 @@ pub static AiCloseCircleFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 64c247.4 0 448 200.6 448 448S759.4 960 512 960 64 759.4 64 512 264.6 64 512 64Zm127.978 274.82-.034.006c-.023.007-.042.018-.083.059L512 466.745l-127.86-127.86c-.042-.041-.06-.052-.084-.059a.118.118 0 0 0-.07 0c-.022.007-.041.018-.082.059l-45.02 45.019c-.04.04-.05.06-.058.083a.118.118 0 0 0 0 .07l.01.022a.268.268 0 0 0 .049.06L466.745 512l-127.86 127.862c-.041.04-.052.06-.059.083a.118.118 0 0 0 0 .07c.007.022.018.041.059.082l45.019 45.02c.04.04.06.05.083.058a.118.118 0 0 0 .07 0c.022-.007.041-.018.082-.059L512 557.254l127.862 127.861c.04.041.06.052.083.059a.118.118 0 0 0 .07 0c.022-.007.041-.018.082-.059l45.02-45.019c.04-.04.05-.06.058-.083a.118.118 0 0 0 0-.07l-.01-.022a.268.268 0 0 0-.049-.06L557.254 512l127.861-127.86c.041-.042.052-.06.059-.084a.118.118 0 0 0 0-.07c-.007-.022-.018-.041-.059-.082l-45.019-45.02c-.04-.04-.06-.05-.083-.058a.118.118 0 0 0-.07 0Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCloseCircleOutlined, "Close Circle Outlined"))]
 pub static AiCloseCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCloseCircleOutlined",
+    label: "Close Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2187,8 +3135,15 @@ pub static AiCloseCircleOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M512 64c247.4 0 448 200.6 448 448S759.4 960 512 960 64 759.4 64 512 264.6 64 512 64Zm0 76c-205.4 0-372 166.6-372 372s166.6 372 372 372 372-166.6 372-372-166.6-372-372-372Zm128.013 198.826c.023.007.042.018.083.059l45.02 45.019c.04.04.05.06.058.083a.118.118 0 0 1 0 .07c-.007.022-.018.041-.059.082L557.254 512l127.861 127.862a.268.268 0 0 1 .05.06l.009.023a.118.118 0 0 1 0 .07c-.007.022-.018.041-.059.082l-45.019 45.02c-.04.04-.06.05-.083.058a.118.118 0 0 1-.07 0c-.022-.007-.041-.018-.082-.059L512 557.254 384.14 685.115c-.042.041-.06.052-.084.059a.118.118 0 0 1-.07 0c-.022-.007-.041-.018-.082-.059l-45.02-45.019c-.04-.04-.05-.06-.058-.083a.118.118 0 0 1 0-.07c.007-.022.018-.041.059-.082L466.745 512l-127.86-127.86a.268.268 0 0 1-.05-.061l-.009-.023a.118.118 0 0 1 0-.07c.007-.022.018-.041.059-.082l45.019-45.02c.04-.04.06-.05.083-.058a.118.118 0 0 1 .07 0c.022.007.041.018.082.059L512 466.745l127.862-127.86c.04-.041.06-.052.083-.059a.118.118 0 0 1 .07 0Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCloseCircleTwotone, "Close Circle Twotone"))]
 pub static AiCloseCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCloseCircleTwotone",
+    label: "Close Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2205,8 +3160,15 @@ pub static AiCloseCircleTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M685.8 352c0-4.4-3.6-8-8-8l-66 .3-99.4 118.5-99.3-118.4-66.1-.3c-4.4 0-8 3.5-8 8 0 1.9.7 3.7 1.9 5.2l130.1 155-130.1 154.9a8.32 8.32 0 0 0-1.9 5.2c0 4.4 3.6 8 8 8l66.1-.3 99.3-118.5L611.7 680l66 .3c4.4 0 8-3.5 8-8 0-1.9-.7-3.7-1.9-5.2L553.9 512.2l130.1-155c1.2-1.4 1.8-3.3 1.8-5.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCloseOutlined, "Close Outlined"))]
 pub static AiCloseOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCloseOutlined",
+    label: "Close Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2221,8 +3183,15 @@ pub static AiCloseOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M799.855 166.312c.023.007.043.018.084.059l57.69 57.69c.041.041.052.06.059.084a.118.118 0 0 1 0 .069c-.007.023-.018.042-.059.083L569.926 512l287.703 287.703c.041.04.052.06.059.083a.118.118 0 0 1 0 .07c-.007.022-.018.042-.059.083l-57.69 57.69c-.041.041-.06.052-.084.059a.118.118 0 0 1-.069 0c-.023-.007-.042-.018-.083-.059L512 569.926 224.297 857.629c-.04.041-.06.052-.083.059a.118.118 0 0 1-.07 0c-.022-.007-.042-.018-.083-.059l-57.69-57.69c-.041-.041-.052-.06-.059-.084a.118.118 0 0 1 0-.069c.007-.023.018-.042.059-.083L454.073 512 166.371 224.297c-.041-.04-.052-.06-.059-.083a.118.118 0 0 1 0-.07c.007-.022.018-.042.059-.083l57.69-57.69c.041-.041.06-.052.084-.059a.118.118 0 0 1 .069 0c.023.007.042.018.083.059L512 454.073l287.703-287.702c.04-.041.06-.052.083-.059a.118.118 0 0 1 .07 0Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCloseSquareFilled, "Close Square Filled"))]
 pub static AiCloseSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCloseSquareFilled",
+    label: "Close Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2237,8 +3206,15 @@ pub static AiCloseSquareFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M880 112c17.7 0 32 14.3 32 32v736c0 17.7-14.3 32-32 32H144c-17.7 0-32-14.3-32-32V144c0-17.7 14.3-32 32-32ZM639.978 338.82l-.034.006c-.023.007-.042.018-.083.059L512 466.745l-127.86-127.86c-.042-.041-.06-.052-.084-.059a.118.118 0 0 0-.07 0c-.022.007-.041.018-.082.059l-45.02 45.019c-.04.04-.05.06-.058.083a.118.118 0 0 0 0 .07l.01.022a.268.268 0 0 0 .049.06L466.745 512l-127.86 127.862c-.041.04-.052.06-.059.083a.118.118 0 0 0 0 .07c.007.022.018.041.059.082l45.019 45.02c.04.04.06.05.083.058a.118.118 0 0 0 .07 0c.022-.007.041-.018.082-.059L512 557.254l127.862 127.861c.04.041.06.052.083.059a.118.118 0 0 0 .07 0c.022-.007.041-.018.082-.059l45.02-45.019c.04-.04.05-.06.058-.083a.118.118 0 0 0 0-.07l-.01-.022a.268.268 0 0 0-.049-.06L557.254 512l127.861-127.86c.041-.042.052-.06.059-.084a.118.118 0 0 0 0-.07c-.007-.022-.018-.041-.059-.082l-45.019-45.02c-.04-.04-.06-.05-.083-.058a.118.118 0 0 0-.07 0Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCloseSquareOutlined, "Close Square Outlined"))]
 pub static AiCloseSquareOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCloseSquareOutlined",
+    label: "Close Square Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2253,8 +3229,15 @@ pub static AiCloseSquareOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M880 112c17.7 0 32 14.3 32 32v736c0 17.7-14.3 32-32 32H144c-17.7 0-32-14.3-32-32V144c0-17.7 14.3-32 32-32Zm-40 72H184v656h656V184ZM640.013 338.826c.023.007.042.018.083.059l45.02 45.019c.04.04.05.06.058.083a.118.118 0 0 1 0 .07c-.007.022-.018.041-.059.082L557.254 512l127.861 127.862a.268.268 0 0 1 .05.06l.009.023a.118.118 0 0 1 0 .07c-.007.022-.018.041-.059.082l-45.019 45.02c-.04.04-.06.05-.083.058a.118.118 0 0 1-.07 0c-.022-.007-.041-.018-.082-.059L512 557.254 384.14 685.115c-.042.041-.06.052-.084.059a.118.118 0 0 1-.07 0c-.022-.007-.041-.018-.082-.059l-45.02-45.019c-.04-.04-.05-.06-.058-.083a.118.118 0 0 1 0-.07c.007-.022.018-.041.059-.082L466.745 512l-127.86-127.86a.268.268 0 0 1-.05-.061l-.009-.023a.118.118 0 0 1 0-.07c.007-.022.018-.041.059-.082l45.019-45.02c.04-.04.06-.05.083-.058a.118.118 0 0 1 .07 0c.022.007.041.018.082.059L512 466.745l127.862-127.86c.04-.041.06-.052.083-.059a.118.118 0 0 1 .07 0Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCloseSquareTwotone, "Close Square Twotone"))]
 pub static AiCloseSquareTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCloseSquareTwotone",
+    label: "Close Square Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2271,8 +3254,15 @@ pub static AiCloseSquareTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M354 671h58.9c4.8 0 9.3-2.1 12.3-5.7L512 561.8l86.8 103.5c3.1 3.6 7.6 5.7 12.3 5.7H670c6.8 0 10.5-7.9 6.1-13.1L553.8 512l122.3-145.9c4.4-5.2.7-13.1-6.1-13.1h-58.9c-4.8 0-9.3 2.1-12.3 5.7L512 462.2l-86.8-103.5c-3.1-3.6-7.6-5.7-12.3-5.7H354c-6.8 0-10.5 7.9-6.1 13.1L470.2 512 347.9 657.9A7.95 7.95 0 0 0 354 671z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCloudDownloadOutlined, "Cloud Download Outlined"))]
 pub static AiCloudDownloadOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCloudDownloadOutlined",
+    label: "Cloud Download Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2288,8 +3278,15 @@ pub static AiCloudDownloadOutlined: &icondata_core::IconData = &icondata_core::I
 <path d="M811.4 366.7C765.6 245.9 648.9 160 512.2 160S258.8 245.8 213 366.6C127.3 389.1 64 467.2 64 560c0 110.5 89.5 200 199.9 200H304c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8h-40.1c-33.7 0-65.4-13.4-89-37.7-23.5-24.2-36-56.8-34.9-90.6.9-26.4 9.9-51.2 26.2-72.1 16.7-21.3 40.1-36.8 66.1-43.7l37.9-9.9 13.9-36.6c8.6-22.8 20.6-44.1 35.7-63.4a245.6 245.6 0 0 1 52.4-49.9c41.1-28.9 89.5-44.2 140-44.2s98.9 15.3 140 44.2c19.9 14 37.5 30.8 52.4 49.9 15.1 19.3 27.1 40.7 35.7 63.4l13.8 36.5 37.8 10C846.1 454.5 884 503.8 884 560c0 33.1-12.9 64.3-36.3 87.7a123.07 123.07 0 0 1-87.6 36.3H720c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h40.1C870.5 760 960 670.5 960 560c0-92.7-63.1-170.7-148.6-193.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCloudFilled, "Cloud Filled"))]
 pub static AiCloudFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCloudFilled",
+    label: "Cloud Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2304,8 +3301,15 @@ pub static AiCloudFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M811.4 418.7C765.6 297.9 648.9 212 512.2 212S258.8 297.8 213 418.6C127.3 441.1 64 519.1 64 612c0 110.5 89.5 200 199.9 200h496.2C870.5 812 960 722.5 960 612c0-92.7-63.1-170.7-148.6-193.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCloudOutlined, "Cloud Outlined"))]
 pub static AiCloudOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCloudOutlined",
+    label: "Cloud Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2320,8 +3324,15 @@ pub static AiCloudOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M811.4 418.7C765.6 297.9 648.9 212 512.2 212S258.8 297.8 213 418.6C127.3 441.1 64 519.1 64 612c0 110.5 89.5 200 199.9 200h496.2C870.5 812 960 722.5 960 612c0-92.7-63.1-170.7-148.6-193.3zm36.3 281a123.07 123.07 0 0 1-87.6 36.3H263.9c-33.1 0-64.2-12.9-87.6-36.3A123.3 123.3 0 0 1 140 612c0-28 9.1-54.3 26.2-76.3a125.7 125.7 0 0 1 66.1-43.7l37.9-9.9 13.9-36.6c8.6-22.8 20.6-44.1 35.7-63.4a245.6 245.6 0 0 1 52.4-49.9c41.1-28.9 89.5-44.2 140-44.2s98.9 15.3 140 44.2c19.9 14 37.5 30.8 52.4 49.9 15.1 19.3 27.1 40.7 35.7 63.4l13.8 36.5 37.8 10c54.3 14.5 92.1 63.8 92.1 120 0 33.1-12.9 64.3-36.3 87.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCloudServerOutlined, "Cloud Server Outlined"))]
 pub static AiCloudServerOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCloudServerOutlined",
+    label: "Cloud Server Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2338,8 +3349,15 @@ pub static AiCloudServerOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M811.4 368.9C765.6 248 648.9 162 512.2 162S258.8 247.9 213 368.8C126.9 391.5 63.5 470.2 64 563.6 64.6 668 145.6 752.9 247.6 762c4.7.4 8.7-3.3 8.7-8v-60.4c0-4-3-7.4-7-7.9-27-3.4-52.5-15.2-72.1-34.5-24-23.5-37.2-55.1-37.2-88.6 0-28 9.1-54.4 26.2-76.4 16.7-21.4 40.2-36.9 66.1-43.7l37.9-10 13.9-36.7c8.6-22.8 20.6-44.2 35.7-63.5 14.9-19.2 32.6-36 52.4-50 41.1-28.9 89.5-44.2 140-44.2s98.9 15.3 140 44.3c19.9 14 37.5 30.8 52.4 50 15.1 19.3 27.1 40.7 35.7 63.5l13.8 36.6 37.8 10c54.2 14.4 92.1 63.7 92.1 120 0 33.6-13.2 65.1-37.2 88.6-19.5 19.2-44.9 31.1-71.9 34.5-4 .5-6.9 3.9-6.9 7.9V754c0 4.7 4.1 8.4 8.8 8 101.7-9.2 182.5-94 183.2-198.2.6-93.4-62.7-172.1-148.6-194.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCloudSyncOutlined, "Cloud Sync Outlined"))]
 pub static AiCloudSyncOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCloudSyncOutlined",
+    label: "Cloud Sync Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2355,8 +3373,15 @@ pub static AiCloudSyncOutlined: &icondata_core::IconData = &icondata_core::IconD
 <path d="M376.9 656.4c1.8-33.5 15.7-64.7 39.5-88.6 25.4-25.5 60-39.8 96-39.8 36.2 0 70.3 14.1 96 39.8 1.4 1.4 2.7 2.8 4.1 4.3l-25 19.6a8 8 0 0 0 3 14.1l98.2 24c5 1.2 9.9-2.6 9.9-7.7l.5-101.3c0-6.7-7.6-10.5-12.9-6.3L663 532.7c-36.6-42-90.4-68.6-150.5-68.6-107.4 0-195 85.1-199.4 191.7-.2 4.5 3.4 8.3 8 8.3H369c4.2-.1 7.7-3.4 7.9-7.7zM703 664h-47.9c-4.2 0-7.7 3.3-8 7.6-1.8 33.5-15.7 64.7-39.5 88.6-25.4 25.5-60 39.8-96 39.8-36.2 0-70.3-14.1-96-39.8-1.4-1.4-2.7-2.8-4.1-4.3l25-19.6a8 8 0 0 0-3-14.1l-98.2-24c-5-1.2-9.9 2.6-9.9 7.7l-.4 101.4c0 6.7 7.6 10.5 12.9 6.3l23.2-18.2c36.6 42 90.4 68.6 150.5 68.6 107.4 0 195-85.1 199.4-191.7.2-4.5-3.4-8.3-8-8.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCloudTwotone, "Cloud Twotone"))]
 pub static AiCloudTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCloudTwotone",
+    label: "Cloud Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2372,8 +3397,15 @@ pub static AiCloudTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M811.4 418.7C765.6 297.9 648.9 212 512.2 212S258.8 297.8 213 418.6C127.3 441.1 64 519.1 64 612c0 110.5 89.5 200 199.9 200h496.2C870.5 812 960 722.5 960 612c0-92.7-63.1-170.7-148.6-193.3zm36.3 281a123.07 123.07 0 0 1-87.6 36.3H263.9c-33.1 0-64.2-12.9-87.6-36.3A123.3 123.3 0 0 1 140 612c0-28 9.1-54.3 26.2-76.3a125.7 125.7 0 0 1 66.1-43.7l37.9-9.9 13.9-36.6c8.6-22.8 20.6-44.1 35.7-63.4a245.6 245.6 0 0 1 52.4-49.9c41.1-28.9 89.5-44.2 140-44.2s98.9 15.3 140 44.2c19.9 14 37.5 30.8 52.4 49.9 15.1 19.3 27.1 40.7 35.7 63.4l13.8 36.5 37.8 10c54.3 14.5 92.1 63.8 92.1 120 0 33.1-12.9 64.3-36.3 87.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCloudUploadOutlined, "Cloud Upload Outlined"))]
 pub static AiCloudUploadOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCloudUploadOutlined",
+    label: "Cloud Upload Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2389,8 +3421,15 @@ pub static AiCloudUploadOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M811.4 366.7C765.6 245.9 648.9 160 512.2 160S258.8 245.8 213 366.6C127.3 389.1 64 467.2 64 560c0 110.5 89.5 200 199.9 200H304c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8h-40.1c-33.7 0-65.4-13.4-89-37.7-23.5-24.2-36-56.8-34.9-90.6.9-26.4 9.9-51.2 26.2-72.1 16.7-21.3 40.1-36.8 66.1-43.7l37.9-9.9 13.9-36.6c8.6-22.8 20.6-44.1 35.7-63.4a245.6 245.6 0 0 1 52.4-49.9c41.1-28.9 89.5-44.2 140-44.2s98.9 15.3 140 44.2c19.9 14 37.5 30.8 52.4 49.9 15.1 19.3 27.1 40.7 35.7 63.4l13.8 36.5 37.8 10C846.1 454.5 884 503.8 884 560c0 33.1-12.9 64.3-36.3 87.7a123.07 123.07 0 0 1-87.6 36.3H720c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h40.1C870.5 760 960 670.5 960 560c0-92.7-63.1-170.7-148.6-193.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiClusterOutlined, "Cluster Outlined"))]
 pub static AiClusterOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiClusterOutlined",
+    label: "Cluster Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2405,8 +3444,15 @@ pub static AiClusterOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M888 680h-54V540H546v-92h238c8.8 0 16-7.2 16-16V168c0-8.8-7.2-16-16-16H240c-8.8 0-16 7.2-16 16v264c0 8.8 7.2 16 16 16h238v92H190v140h-54c-4.4 0-8 3.6-8 8v176c0 4.4 3.6 8 8 8h176c4.4 0 8-3.6 8-8V688c0-4.4-3.6-8-8-8h-54v-72h220v72h-54c-4.4 0-8 3.6-8 8v176c0 4.4 3.6 8 8 8h176c4.4 0 8-3.6 8-8V688c0-4.4-3.6-8-8-8h-54v-72h220v72h-54c-4.4 0-8 3.6-8 8v176c0 4.4 3.6 8 8 8h176c4.4 0 8-3.6 8-8V688c0-4.4-3.6-8-8-8zM256 805.3c0 1.5-1.2 2.7-2.7 2.7h-58.7c-1.5 0-2.7-1.2-2.7-2.7v-58.7c0-1.5 1.2-2.7 2.7-2.7h58.7c1.5 0 2.7 1.2 2.7 2.7v58.7zm288 0c0 1.5-1.2 2.7-2.7 2.7h-58.7c-1.5 0-2.7-1.2-2.7-2.7v-58.7c0-1.5 1.2-2.7 2.7-2.7h58.7c1.5 0 2.7 1.2 2.7 2.7v58.7zM288 384V216h448v168H288zm544 421.3c0 1.5-1.2 2.7-2.7 2.7h-58.7c-1.5 0-2.7-1.2-2.7-2.7v-58.7c0-1.5 1.2-2.7 2.7-2.7h58.7c1.5 0 2.7 1.2 2.7 2.7v58.7zM360 300a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCodeFilled, "Code Filled"))]
 pub static AiCodeFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCodeFilled",
+    label: "Code Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2421,8 +3467,15 @@ pub static AiCodeFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM513.1 518.1l-192 161c-5.2 4.4-13.1.7-13.1-6.1v-62.7c0-2.3 1.1-4.6 2.9-6.1L420.7 512l-109.8-92.2a7.63 7.63 0 0 1-2.9-6.1V351c0-6.8 7.9-10.5 13.1-6.1l192 160.9c3.9 3.2 3.9 9.1 0 12.3zM716 673c0 4.4-3.4 8-7.5 8h-185c-4.1 0-7.5-3.6-7.5-8v-48c0-4.4 3.4-8 7.5-8h185c4.1 0 7.5 3.6 7.5 8v48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCodeOutlined, "Code Outlined"))]
 pub static AiCodeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCodeOutlined",
+    label: "Code Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2437,8 +3490,15 @@ pub static AiCodeOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M516 673c0 4.4 3.4 8 7.5 8h185c4.1 0 7.5-3.6 7.5-8v-48c0-4.4-3.4-8-7.5-8h-185c-4.1 0-7.5 3.6-7.5 8v48zm-194.9 6.1l192-161c3.8-3.2 3.8-9.1 0-12.3l-192-160.9A7.95 7.95 0 0 0 308 351v62.7c0 2.4 1 4.6 2.9 6.1L420.7 512l-109.8 92.2a8.1 8.1 0 0 0-2.9 6.1V673c0 6.8 7.9 10.5 13.1 6.1zM880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCodeSandboxCircleFilled, "Code Sandbox Circle Filled"))]
 pub static AiCodeSandboxCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCodeSandboxCircleFilled",
+    label: "Code Sandbox Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2453,8 +3513,15 @@ pub static AiCodeSandboxCircleFilled: &icondata_core::IconData = &icondata_core:
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm243.7 589.2L512 794 268.3 653.2V371.8l110-63.6-.4-.2h.2L512 231l134 77h-.2l-.3.2 110.1 63.6v281.4zM307.9 536.7l87.6 49.9V681l96.7 55.9V524.8L307.9 418.4zm203.9-151.8L418 331l-91.1 52.6 185.2 107 185.2-106.9-91.4-52.8zm20 352l97.3-56.2v-94.1l87-49.5V418.5L531.8 525z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCodeSandboxOutlined, "Code Sandbox Outlined"))]
 pub static AiCodeSandboxOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCodeSandboxOutlined",
+    label: "Code Sandbox Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2469,8 +3536,15 @@ pub static AiCodeSandboxOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M709.6 210l.4-.2h.2L512 96 313.9 209.8h-.2l.7.3L151.5 304v416L512 928l360.5-208V304l-162.9-94zM482.7 843.6L339.6 761V621.4L210 547.8V372.9l272.7 157.3v313.4zM238.2 321.5l134.7-77.8 138.9 79.7 139.1-79.9 135.2 78-273.9 158-274-158zM814 548.3l-128.8 73.1v139.1l-143.9 83V530.4L814 373.1v175.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCodeSandboxSquareFilled, "Code Sandbox Square Filled"))]
 pub static AiCodeSandboxSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCodeSandboxSquareFilled",
+    label: "Code Sandbox Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2485,8 +3559,15 @@ pub static AiCodeSandboxSquareFilled: &icondata_core::IconData = &icondata_core:
     data: r###"<path d="M307.9 536.7l87.6 49.9V681l96.7 55.9V524.8L307.9 418.4zM880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM755.7 653.2L512 794 268.3 653.2V371.8l110-63.6-.4-.2h.2L512 231l134 77h-.2l-.3.2 110.1 63.6v281.4zm-223.9 83.7l97.3-56.2v-94.1l87-49.5V418.5L531.8 525zm-20-352L418 331l-91.1 52.6 185.2 107 185.2-106.9-91.4-52.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCodeTwotone, "Code Twotone"))]
 pub static AiCodeTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCodeTwotone",
+    label: "Code Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2503,8 +3584,15 @@ pub static AiCodeTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M321.1 679.1l192-161c3.9-3.2 3.9-9.1 0-12.3l-192-160.9A7.95 7.95 0 0 0 308 351v62.7c0 2.4 1 4.6 2.9 6.1L420.7 512l-109.8 92.2a8.1 8.1 0 0 0-2.9 6.1V673c0 6.8 7.9 10.5 13.1 6.1zM516 673c0 4.4 3.4 8 7.5 8h185c4.1 0 7.5-3.6 7.5-8v-48c0-4.4-3.4-8-7.5-8h-185c-4.1 0-7.5 3.6-7.5 8v48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCodepenCircleFilled, "Codepen Circle Filled"))]
 pub static AiCodepenCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCodepenCircleFilled",
+    label: "Codepen Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2519,8 +3607,15 @@ pub static AiCodepenCircleFilled: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M488.1 414.7V303.4L300.9 428l83.6 55.8zm254.1 137.7v-79.8l-59.8 39.9zM512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm278 533c0 1.1-.1 2.1-.2 3.1 0 .4-.1.7-.2 1a14.16 14.16 0 0 1-.8 3.2c-.2.6-.4 1.2-.6 1.7-.2.4-.4.8-.5 1.2-.3.5-.5 1.1-.8 1.6-.2.4-.4.7-.7 1.1-.3.5-.7 1-1 1.5-.3.4-.5.7-.8 1-.4.4-.8.9-1.2 1.3-.3.3-.6.6-1 .9-.4.4-.9.8-1.4 1.1-.4.3-.7.6-1.1.8-.1.1-.3.2-.4.3L525.2 786c-4 2.7-8.6 4-13.2 4-4.7 0-9.3-1.4-13.3-4L244.6 616.9c-.1-.1-.3-.2-.4-.3l-1.1-.8c-.5-.4-.9-.7-1.3-1.1-.3-.3-.6-.6-1-.9-.4-.4-.8-.8-1.2-1.3a7 7 0 0 1-.8-1c-.4-.5-.7-1-1-1.5-.2-.4-.5-.7-.7-1.1-.3-.5-.6-1.1-.8-1.6-.2-.4-.4-.8-.5-1.2-.2-.6-.4-1.2-.6-1.7-.1-.4-.3-.8-.4-1.2-.2-.7-.3-1.3-.4-2-.1-.3-.1-.7-.2-1-.1-1-.2-2.1-.2-3.1V427.9c0-1 .1-2.1.2-3.1.1-.3.1-.7.2-1a14.16 14.16 0 0 1 .8-3.2c.2-.6.4-1.2.6-1.7.2-.4.4-.8.5-1.2.2-.5.5-1.1.8-1.6.2-.4.4-.7.7-1.1.6-.9 1.2-1.7 1.8-2.5.4-.4.8-.9 1.2-1.3.3-.3.6-.6 1-.9.4-.4.9-.8 1.3-1.1.4-.3.7-.6 1.1-.8.1-.1.3-.2.4-.3L498.7 239c8-5.3 18.5-5.3 26.5 0l254.1 169.1c.1.1.3.2.4.3l1.1.8 1.4 1.1c.3.3.6.6 1 .9.4.4.8.8 1.2 1.3.7.8 1.3 1.6 1.8 2.5.2.4.5.7.7 1.1.3.5.6 1 .8 1.6.2.4.4.8.5 1.2.2.6.4 1.2.6 1.7.1.4.3.8.4 1.2.2.7.3 1.3.4 2 .1.3.1.7.2 1 .1 1 .2 2.1.2 3.1V597zm-254.1 13.3v111.3L723.1 597l-83.6-55.8zM281.8 472.6v79.8l59.8-39.9zM512 456.1l-84.5 56.4 84.5 56.4 84.5-56.4zM723.1 428L535.9 303.4v111.3l103.6 69.1zM384.5 541.2L300.9 597l187.2 124.6V610.3l-103.6-69.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCodepenCircleOutlined, "Codepen Circle Outlined"))]
 pub static AiCodepenCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCodepenCircleOutlined",
+    label: "Codepen Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2535,8 +3630,15 @@ pub static AiCodepenCircleOutlined: &icondata_core::IconData = &icondata_core::I
     data: r###"<path d="M488.1 414.7V303.4L300.9 428l83.6 55.8zm254.1 137.7v-79.8l-59.8 39.9zM512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm278 533c0 1.1-.1 2.1-.2 3.1 0 .4-.1.7-.2 1a14.16 14.16 0 0 1-.8 3.2c-.2.6-.4 1.2-.6 1.7-.2.4-.4.8-.5 1.2-.3.5-.5 1.1-.8 1.6-.2.4-.4.7-.7 1.1-.3.5-.7 1-1 1.5-.3.4-.5.7-.8 1-.4.4-.8.9-1.2 1.3-.3.3-.6.6-1 .9-.4.4-.9.8-1.4 1.1-.4.3-.7.6-1.1.8-.1.1-.3.2-.4.3L525.2 786c-4 2.7-8.6 4-13.2 4-4.7 0-9.3-1.4-13.3-4L244.6 616.9c-.1-.1-.3-.2-.4-.3l-1.1-.8c-.5-.4-.9-.7-1.3-1.1-.3-.3-.6-.6-1-.9-.4-.4-.8-.8-1.2-1.3a7 7 0 0 1-.8-1c-.4-.5-.7-1-1-1.5-.2-.4-.5-.7-.7-1.1-.3-.5-.6-1.1-.8-1.6-.2-.4-.4-.8-.5-1.2-.2-.6-.4-1.2-.6-1.7-.1-.4-.3-.8-.4-1.2-.2-.7-.3-1.3-.4-2-.1-.3-.1-.7-.2-1-.1-1-.2-2.1-.2-3.1V427.9c0-1 .1-2.1.2-3.1.1-.3.1-.7.2-1a14.16 14.16 0 0 1 .8-3.2c.2-.6.4-1.2.6-1.7.2-.4.4-.8.5-1.2.2-.5.5-1.1.8-1.6.2-.4.4-.7.7-1.1.6-.9 1.2-1.7 1.8-2.5.4-.4.8-.9 1.2-1.3.3-.3.6-.6 1-.9.4-.4.9-.8 1.3-1.1.4-.3.7-.6 1.1-.8.1-.1.3-.2.4-.3L498.7 239c8-5.3 18.5-5.3 26.5 0l254.1 169.1c.1.1.3.2.4.3l1.1.8 1.4 1.1c.3.3.6.6 1 .9.4.4.8.8 1.2 1.3.7.8 1.3 1.6 1.8 2.5.2.4.5.7.7 1.1.3.5.6 1 .8 1.6.2.4.4.8.5 1.2.2.6.4 1.2.6 1.7.1.4.3.8.4 1.2.2.7.3 1.3.4 2 .1.3.1.7.2 1 .1 1 .2 2.1.2 3.1V597zm-254.1 13.3v111.3L723.1 597l-83.6-55.8zM281.8 472.6v79.8l59.8-39.9zM512 456.1l-84.5 56.4 84.5 56.4 84.5-56.4zM723.1 428L535.9 303.4v111.3l103.6 69.1zM384.5 541.2L300.9 597l187.2 124.6V610.3l-103.6-69.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCodepenOutlined, "Codepen Outlined"))]
 pub static AiCodepenOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCodepenOutlined",
+    label: "Codepen Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2551,8 +3653,15 @@ pub static AiCodepenOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M911.7 385.3l-.3-1.5c-.2-1-.3-1.9-.6-2.9-.2-.6-.4-1.1-.5-1.7-.3-.8-.5-1.7-.9-2.5-.2-.6-.5-1.1-.8-1.7-.4-.8-.8-1.5-1.2-2.3-.3-.5-.6-1.1-1-1.6-.8-1.2-1.7-2.4-2.6-3.6-.5-.6-1.1-1.3-1.7-1.9-.4-.5-.9-.9-1.4-1.3-.6-.6-1.3-1.1-1.9-1.6-.5-.4-1-.8-1.6-1.2-.2-.1-.4-.3-.6-.4L531.1 117.8a34.3 34.3 0 0 0-38.1 0L127.3 361.3c-.2.1-.4.3-.6.4-.5.4-1 .8-1.6 1.2-.7.5-1.3 1.1-1.9 1.6-.5.4-.9.9-1.4 1.3-.6.6-1.2 1.2-1.7 1.9-1 1.1-1.8 2.3-2.6 3.6-.3.5-.7 1-1 1.6-.4.7-.8 1.5-1.2 2.3-.3.5-.5 1.1-.8 1.7-.3.8-.6 1.7-.9 2.5-.2.6-.4 1.1-.5 1.7-.2.9-.4 1.9-.6 2.9l-.3 1.5c-.2 1.5-.3 3-.3 4.5v243.5c0 1.5.1 3 .3 4.5l.3 1.5.6 2.9c.2.6.3 1.1.5 1.7.3.9.6 1.7.9 2.5.2.6.5 1.1.8 1.7.4.8.7 1.5 1.2 2.3.3.5.6 1.1 1 1.6.5.7.9 1.4 1.5 2.1l1.2 1.5c.5.6 1.1 1.3 1.7 1.9.4.5.9.9 1.4 1.3.6.6 1.3 1.1 1.9 1.6.5.4 1 .8 1.6 1.2.2.1.4.3.6.4L493 905.7c5.6 3.8 12.3 5.8 19.1 5.8 6.6 0 13.3-1.9 19.1-5.8l365.6-243.5c.2-.1.4-.3.6-.4.5-.4 1-.8 1.6-1.2.7-.5 1.3-1.1 1.9-1.6.5-.4.9-.9 1.4-1.3.6-.6 1.2-1.2 1.7-1.9l1.2-1.5 1.5-2.1c.3-.5.7-1 1-1.6.4-.8.8-1.5 1.2-2.3.3-.5.5-1.1.8-1.7.3-.8.6-1.7.9-2.5.2-.5.4-1.1.5-1.7.3-.9.4-1.9.6-2.9l.3-1.5c.2-1.5.3-3 .3-4.5V389.8c-.3-1.5-.4-3-.6-4.5zM546.4 210.5l269.4 179.4-120.3 80.4-149-99.6V210.5zm-68.8 0v160.2l-149 99.6-120.3-80.4 269.3-179.4zM180.7 454.1l86 57.5-86 57.5v-115zm296.9 358.5L208.3 633.2l120.3-80.4 149 99.6v160.2zM512 592.8l-121.6-81.2L512 430.3l121.6 81.2L512 592.8zm34.4 219.8V652.4l149-99.6 120.3 80.4-269.3 179.4zM843.3 569l-86-57.5 86-57.5v115z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCodepenSquareFilled, "Codepen Square Filled"))]
 pub static AiCodepenSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCodepenSquareFilled",
+    label: "Codepen Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2567,8 +3676,15 @@ pub static AiCodepenSquareFilled: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M723.1 428L535.9 303.4v111.3l103.6 69.1zM512 456.1l-84.5 56.4 84.5 56.4 84.5-56.4zm23.9 154.2v111.3L723.1 597l-83.6-55.8zm-151.4-69.1L300.9 597l187.2 124.6V610.3l-103.6-69.1zM880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-90 485c0 1.1-.1 2.1-.2 3.1 0 .4-.1.7-.2 1a14.16 14.16 0 0 1-.8 3.2c-.2.6-.4 1.2-.6 1.7-.2.4-.4.8-.5 1.2-.3.5-.5 1.1-.8 1.6-.2.4-.4.7-.7 1.1-.3.5-.7 1-1 1.5-.3.4-.5.7-.8 1-.4.4-.8.9-1.2 1.3-.3.3-.6.6-1 .9-.4.4-.9.8-1.4 1.1-.4.3-.7.6-1.1.8-.1.1-.3.2-.4.3L525.2 786c-4 2.7-8.6 4-13.2 4-4.7 0-9.3-1.4-13.3-4L244.6 616.9c-.1-.1-.3-.2-.4-.3l-1.1-.8c-.5-.4-.9-.7-1.3-1.1-.3-.3-.6-.6-1-.9-.4-.4-.8-.8-1.2-1.3a7 7 0 0 1-.8-1c-.4-.5-.7-1-1-1.5-.2-.4-.5-.7-.7-1.1-.3-.5-.6-1.1-.8-1.6-.2-.4-.4-.8-.5-1.2-.2-.6-.4-1.2-.6-1.7-.1-.4-.3-.8-.4-1.2-.2-.7-.3-1.3-.4-2-.1-.3-.1-.7-.2-1-.1-1-.2-2.1-.2-3.1V427.9c0-1 .1-2.1.2-3.1.1-.3.1-.7.2-1a14.16 14.16 0 0 1 .8-3.2c.2-.6.4-1.2.6-1.7.2-.4.4-.8.5-1.2.2-.5.5-1.1.8-1.6.2-.4.4-.7.7-1.1.6-.9 1.2-1.7 1.8-2.5.4-.4.8-.9 1.2-1.3.3-.3.6-.6 1-.9.4-.4.9-.8 1.3-1.1.4-.3.7-.6 1.1-.8.1-.1.3-.2.4-.3L498.7 239c8-5.3 18.5-5.3 26.5 0l254.1 169.1c.1.1.3.2.4.3l1.1.8 1.4 1.1c.3.3.6.6 1 .9.4.4.8.8 1.2 1.3.7.8 1.3 1.6 1.8 2.5.2.4.5.7.7 1.1.3.5.6 1 .8 1.6.2.4.4.8.5 1.2.2.6.4 1.2.6 1.7.1.4.3.8.4 1.2.2.7.3 1.3.4 2 .1.3.1.7.2 1 .1 1 .2 2.1.2 3.1V597zm-47.8-44.6v-79.8l-59.8 39.9zm-460.4-79.8v79.8l59.8-39.9zm206.3-57.9V303.4L300.9 428l83.6 55.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCoffeeOutlined, "Coffee Outlined"))]
 pub static AiCoffeeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCoffeeOutlined",
+    label: "Coffee Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2583,8 +3699,15 @@ pub static AiCoffeeOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M275 281c19.9 0 36-16.1 36-36V36c0-19.9-16.1-36-36-36s-36 16.1-36 36v209c0 19.9 16.1 36 36 36zm613 144H768c0-39.8-32.2-72-72-72H200c-39.8 0-72 32.2-72 72v248c0 3.4.2 6.7.7 9.9-.5 7-.7 14-.7 21.1 0 176.7 143.3 320 320 320 160.1 0 292.7-117.5 316.3-271H888c39.8 0 72-32.2 72-72V497c0-39.8-32.2-72-72-72zM696 681h-1.1c.7 7.6 1.1 15.2 1.1 23 0 137-111 248-248 248S200 841 200 704c0-7.8.4-15.4 1.1-23H200V425h496v256zm192-8H776V497h112v176zM613 281c19.9 0 36-16.1 36-36V36c0-19.9-16.1-36-36-36s-36 16.1-36 36v209c0 19.9 16.1 36 36 36zm-170 0c19.9 0 36-16.1 36-36V36c0-19.9-16.1-36-36-36s-36 16.1-36 36v209c0 19.9 16.1 36 36 36z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiColumnHeightOutlined, "Column Height Outlined"))]
 pub static AiColumnHeightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiColumnHeightOutlined",
+    label: "Column Height Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2599,8 +3722,15 @@ pub static AiColumnHeightOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M840 836H184c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h656c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8zm0-724H184c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h656c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8zM610.8 378c6 0 9.4-7 5.7-11.7L515.7 238.7a7.14 7.14 0 0 0-11.3 0L403.6 366.3a7.23 7.23 0 0 0 5.7 11.7H476v268h-62.8c-6 0-9.4 7-5.7 11.7l100.8 127.5c2.9 3.7 8.5 3.7 11.3 0l100.8-127.5c3.7-4.7.4-11.7-5.7-11.7H548V378h62.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiColumnWidthOutlined, "Column Width Outlined"))]
 pub static AiColumnWidthOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiColumnWidthOutlined",
+    label: "Column Width Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2615,8 +3745,15 @@ pub static AiColumnWidthOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M180 176h-60c-4.4 0-8 3.6-8 8v656c0 4.4 3.6 8 8 8h60c4.4 0 8-3.6 8-8V184c0-4.4-3.6-8-8-8zm724 0h-60c-4.4 0-8 3.6-8 8v656c0 4.4 3.6 8 8 8h60c4.4 0 8-3.6 8-8V184c0-4.4-3.6-8-8-8zM785.3 504.3L657.7 403.6a7.23 7.23 0 0 0-11.7 5.7V476H378v-62.8c0-6-7-9.4-11.7-5.7L238.7 508.3a7.14 7.14 0 0 0 0 11.3l127.5 100.8c4.7 3.7 11.7.4 11.7-5.7V548h268v62.8c0 6 7 9.4 11.7 5.7l127.5-100.8c3.8-2.9 3.8-8.5.2-11.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCommentOutlined, "Comment Outlined"))]
 pub static AiCommentOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCommentOutlined",
+    label: "Comment Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2636,8 +3773,15 @@ pub static AiCommentOutlined: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M433 421c-23.1 0-41 17.9-41 40s17.9 40 41 40c21.1 0 39-17.9 39-40s-17.9-40-39-40z" p-id="8188" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCompassFilled, "Compass Filled"))]
 pub static AiCompassFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCompassFilled",
+    label: "Compass Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2652,8 +3796,15 @@ pub static AiCompassFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zM327.3 702.4c-2 .9-4.4 0-5.3-2.1-.4-1-.4-2.2 0-3.2l98.7-225.5 132.1 132.1-225.5 98.7zm375.1-375.1l-98.7 225.5-132.1-132.1L697.1 322c2-.9 4.4 0 5.3 2.1.4 1 .4 2.1 0 3.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCompassOutlined, "Compass Outlined"))]
 pub static AiCompassOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCompassOutlined",
+    label: "Compass Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2668,8 +3819,15 @@ pub static AiCompassOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm198.4-588.1a32 32 0 0 0-24.5.5L414.9 415 296.4 686c-3.6 8.2-3.6 17.5 0 25.7 3.4 7.8 9.7 13.9 17.7 17 3.8 1.5 7.7 2.2 11.7 2.2 4.4 0 8.7-.9 12.8-2.7l271-118.6 118.5-271a32.06 32.06 0 0 0-17.7-42.7zM576.8 534.4l26.2 26.2-42.4 42.4-26.2-26.2L380 644.4 447.5 490 422 464.4l42.4-42.4 25.5 25.5L644.4 380l-67.6 154.4zM464.4 422L422 464.4l25.5 25.6 86.9 86.8 26.2 26.2 42.4-42.4-26.2-26.2-86.8-86.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCompassTwotone, "Compass Twotone"))]
 pub static AiCompassTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCompassTwotone",
+    label: "Compass Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2686,8 +3844,15 @@ pub static AiCompassTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCompressOutlined, "Compress Outlined"))]
 pub static AiCompressOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCompressOutlined",
+    label: "Compress Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2705,8 +3870,15 @@ pub static AiCompressOutlined: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M326 664H104c-8.8 0-16 7.2-16 16v48c0 8.8 7.2 16 16 16h174v176c0 8.8 7.2 16 16 16h48c8.8 0 16-7.2 16-16V696c0-17.7-14.3-32-32-32zM342 88h-48c-8.8 0-16 7.2-16 16v176H104c-8.8 0-16 7.2-16 16v48c0 8.8 7.2 16 16 16h222c17.7 0 32-14.3 32-32V104c0-8.8-7.2-16-16-16zM920 664H698c-17.7 0-32 14.3-32 32v224c0 8.8 7.2 16 16 16h48c8.8 0 16-7.2 16-16V744h174c8.8 0 16-7.2 16-16v-48c0-8.8-7.2-16-16-16zM920 280H746V104c0-8.8-7.2-16-16-16h-48c-8.8 0-16 7.2-16 16v224c0 17.7 14.3 32 32 32h222c8.8 0 16-7.2 16-16v-48c0-8.8-7.2-16-16-16z" p-id="13425" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiConsoleSqlOutlined, "Console SQL Outlined"))]
 pub static AiConsoleSqlOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiConsoleSqlOutlined",
+    label: "Console SQL Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2726,8 +3898,15 @@ pub static AiConsoleSqlOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M828.5 486.7h-95.8V308.5h-57.4V534h153.2zM529.9 540.1c14.1 0 27.2-2 39.1-5.8l13.3 20.3h53.3L607.9 511c21.1-20 33-51.1 33-89.8 0-73.3-43.3-118.8-110.9-118.8s-111.2 45.3-111.2 118.8c-0.1 73.7 43 118.9 111.1 118.9z m0-190c31.6 0 52.7 27.7 52.7 71.1 0 16.7-3.6 30.6-10 40.5l-5.2-6.9h-48.8L542 491c-3.9 0.9-8 1.4-12.2 1.4-31.7 0-52.8-27.5-52.8-71.2 0.1-43.6 21.2-71.1 52.9-71.1z" p-id="13068" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiContactsFilled, "Contacts Filled"))]
 pub static AiContactsFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiContactsFilled",
+    label: "Contacts Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2742,8 +3921,15 @@ pub static AiContactsFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M928 224H768v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H548v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H328v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H96c-17.7 0-32 14.3-32 32v576c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V256c0-17.7-14.3-32-32-32zM661 736h-43.9c-4.2 0-7.6-3.3-7.9-7.5-3.8-50.6-46-90.5-97.2-90.5s-93.4 40-97.2 90.5c-.3 4.2-3.7 7.5-7.9 7.5H363a8 8 0 0 1-8-8.4c2.8-53.3 32-99.7 74.6-126.1a111.8 111.8 0 0 1-29.1-75.5c0-61.9 49.9-112 111.4-112 61.5 0 111.4 50.1 111.4 112 0 29.1-11 55.5-29.1 75.5 42.7 26.5 71.8 72.8 74.6 126.1.4 4.6-3.2 8.4-7.8 8.4zM512 474c-28.5 0-51.7 23.3-51.7 52s23.2 52 51.7 52c28.5 0 51.7-23.3 51.7-52s-23.2-52-51.7-52z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiContactsOutlined, "Contacts Outlined"))]
 pub static AiContactsOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiContactsOutlined",
+    label: "Contacts Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2758,8 +3944,15 @@ pub static AiContactsOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M594.3 601.5a111.8 111.8 0 0 0 29.1-75.5c0-61.9-49.9-112-111.4-112s-111.4 50.1-111.4 112c0 29.1 11 55.5 29.1 75.5a158.09 158.09 0 0 0-74.6 126.1 8 8 0 0 0 8 8.4H407c4.2 0 7.6-3.3 7.9-7.5 3.8-50.6 46-90.5 97.2-90.5s93.4 40 97.2 90.5c.3 4.2 3.7 7.5 7.9 7.5H661a8 8 0 0 0 8-8.4c-2.8-53.3-32-99.7-74.7-126.1zM512 578c-28.5 0-51.7-23.3-51.7-52s23.2-52 51.7-52 51.7 23.3 51.7 52-23.2 52-51.7 52zm416-354H768v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H548v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H328v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H96c-17.7 0-32 14.3-32 32v576c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V256c0-17.7-14.3-32-32-32zm-40 568H136V296h120v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56h148v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56h148v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56h120v496z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiContactsTwotone, "Contacts Twotone"))]
 pub static AiContactsTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiContactsTwotone",
+    label: "Contacts Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2777,8 +3970,15 @@ pub static AiContactsTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M928 224H768v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H548v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H328v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H96c-17.7 0-32 14.3-32 32v576c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V256c0-17.7-14.3-32-32-32zm-40 568H136V296h120v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56h148v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56h148v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56h120v496z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiContainerFilled, "Container Filled"))]
 pub static AiContainerFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiContainerFilled",
+    label: "Container Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2793,8 +3993,15 @@ pub static AiContainerFilled: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M832 64H192c-17.7 0-32 14.3-32 32v529c0-.6.4-1 1-1h219.3l5.2 24.7C397.6 708.5 450.8 752 512 752s114.4-43.5 126.4-103.3l5.2-24.7H863c.6 0 1 .4 1 1V96c0-17.7-14.3-32-32-32zM712 493c0 4.4-3.6 8-8 8H320c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h384c4.4 0 8 3.6 8 8v48zm0-160c0 4.4-3.6 8-8 8H320c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h384c4.4 0 8 3.6 8 8v48zm151 354H694.1c-11.6 32.8-32 62.3-59.1 84.7-34.5 28.6-78.2 44.3-123 44.3s-88.5-15.8-123-44.3a194.02 194.02 0 0 1-59.1-84.7H161c-.6 0-1-.4-1-1v242c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V686c0 .6-.4 1-1 1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiContainerOutlined, "Container Outlined"))]
 pub static AiContainerOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiContainerOutlined",
+    label: "Container Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2809,8 +4016,15 @@ pub static AiContainerOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M832 64H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V96c0-17.7-14.3-32-32-32zm-40 824H232V687h97.9c11.6 32.8 32 62.3 59.1 84.7 34.5 28.5 78.2 44.3 123 44.3s88.5-15.7 123-44.3c27.1-22.4 47.5-51.9 59.1-84.7H792v-63H643.6l-5.2 24.7C626.4 708.5 573.2 752 512 752s-114.4-43.5-126.5-103.3l-5.2-24.7H232V136h560v752zM320 341h384c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H320c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8zm0 160h384c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H320c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiContainerTwotone, "Container Twotone"))]
 pub static AiContainerTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiContainerTwotone",
+    label: "Container Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2828,8 +4042,15 @@ pub static AiContainerTwotone: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M320 341h384c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H320c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiControlFilled, "Control Filled"))]
 pub static AiControlFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiControlFilled",
+    label: "Control Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2844,8 +4065,15 @@ pub static AiControlFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM404 683v77c0 4.4-3.6 8-8 8h-48c-4.4 0-8-3.6-8-8v-77c-41.7-13.6-72-52.8-72-99s30.3-85.5 72-99V264c0-4.4 3.6-8 8-8h48c4.4 0 8 3.6 8 8v221c41.7 13.6 72 52.8 72 99s-30.3 85.5-72 99zm279.6-143.9c.2 0 .3-.1.4-.1v221c0 4.4-3.6 8-8 8h-48c-4.4 0-8-3.6-8-8V539c.2 0 .3.1.4.1-42-13.4-72.4-52.7-72.4-99.1 0-46.4 30.4-85.7 72.4-99.1-.2 0-.3.1-.4.1v-77c0-4.4 3.6-8 8-8h48c4.4 0 8 3.6 8 8v77c-.2 0-.3-.1-.4-.1 42 13.4 72.4 52.7 72.4 99.1 0 46.4-30.4 85.7-72.4 99.1zM616 440a36 36 0 1 0 72 0 36 36 0 1 0-72 0zM403.4 566.5l-1.5-2.4c0-.1-.1-.1-.1-.2l-.9-1.2c-.1-.1-.2-.2-.2-.3-1-1.3-2-2.5-3.2-3.6l-.2-.2c-.4-.4-.8-.8-1.2-1.1-.8-.8-1.7-1.5-2.6-2.1h-.1l-1.2-.9c-.1-.1-.3-.2-.4-.3-1.2-.8-2.5-1.6-3.9-2.2-.2-.1-.5-.2-.7-.4-.4-.2-.7-.3-1.1-.5-.3-.1-.7-.3-1-.4-.5-.2-1-.4-1.5-.5-.4-.1-.9-.3-1.3-.4l-.9-.3-1.4-.3c-.2-.1-.5-.1-.7-.2-.7-.1-1.4-.3-2.1-.4-.2 0-.4 0-.6-.1-.6-.1-1.1-.1-1.7-.2-.2 0-.4 0-.7-.1-.8 0-1.5-.1-2.3-.1s-1.5 0-2.3.1c-.2 0-.4 0-.7.1-.6 0-1.2.1-1.7.2-.2 0-.4 0-.6.1-.7.1-1.4.2-2.1.4-.2.1-.5.1-.7.2l-1.4.3-.9.3c-.4.1-.9.3-1.3.4-.5.2-1 .4-1.5.5-.3.1-.7.3-1 .4-.4.2-.7.3-1.1.5-.2.1-.5.2-.7.4-1.3.7-2.6 1.4-3.9 2.2-.1.1-.3.2-.4.3l-1.2.9h-.1c-.9.7-1.8 1.4-2.6 2.1-.4.4-.8.7-1.2 1.1l-.2.2a54.8 54.8 0 0 0-3.2 3.6c-.1.1-.2.2-.2.3l-.9 1.2c0 .1-.1.1-.1.2l-1.5 2.4c-.1.2-.2.3-.3.5-2.7 5.1-4.3 10.9-4.3 17s1.6 12 4.3 17c.1.2.2.3.3.5l1.5 2.4c0 .1.1.1.1.2l.9 1.2c.1.1.2.2.2.3 1 1.3 2 2.5 3.2 3.6l.2.2c.4.4.8.8 1.2 1.1.8.8 1.7 1.5 2.6 2.1h.1l1.2.9c.1.1.3.2.4.3 1.2.8 2.5 1.6 3.9 2.2.2.1.5.2.7.4.4.2.7.3 1.1.5.3.1.7.3 1 .4.5.2 1 .4 1.5.5.4.1.9.3 1.3.4l.9.3 1.4.3c.2.1.5.1.7.2.7.1 1.4.3 2.1.4.2 0 .4 0 .6.1.6.1 1.1.1 1.7.2.2 0 .4 0 .7.1.8 0 1.5.1 2.3.1s1.5 0 2.3-.1c.2 0 .4 0 .7-.1.6 0 1.2-.1 1.7-.2.2 0 .4 0 .6-.1.7-.1 1.4-.2 2.1-.4.2-.1.5-.1.7-.2l1.4-.3.9-.3c.4-.1.9-.3 1.3-.4.5-.2 1-.4 1.5-.5.3-.1.7-.3 1-.4.4-.2.7-.3 1.1-.5.2-.1.5-.2.7-.4 1.3-.7 2.6-1.4 3.9-2.2.1-.1.3-.2.4-.3l1.2-.9h.1c.9-.7 1.8-1.4 2.6-2.1.4-.4.8-.7 1.2-1.1l.2-.2c1.1-1.1 2.2-2.4 3.2-3.6.1-.1.2-.2.2-.3l.9-1.2c0-.1.1-.1.1-.2l1.5-2.4c.1-.2.2-.3.3-.5 2.7-5.1 4.3-10.9 4.3-17s-1.6-12-4.3-17c-.1-.2-.2-.4-.3-.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiControlOutlined, "Control Outlined"))]
 pub static AiControlOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiControlOutlined",
+    label: "Control Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2860,8 +4088,15 @@ pub static AiControlOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656zM340 683v77c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8v-77c-10.1 3.3-20.8 5-32 5s-21.9-1.8-32-5zm64-198V264c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v221c10.1-3.3 20.8-5 32-5s21.9 1.8 32 5zm-64 198c10.1 3.3 20.8 5 32 5s21.9-1.8 32-5c41.8-13.5 72-52.7 72-99s-30.2-85.5-72-99c-10.1-3.3-20.8-5-32-5s-21.9 1.8-32 5c-41.8 13.5-72 52.7-72 99s30.2 85.5 72 99zm.1-115.7c.3-.6.7-1.2 1-1.8v-.1l1.2-1.8c.1-.2.2-.3.3-.5.3-.5.7-.9 1-1.4.1-.1.2-.3.3-.4.5-.6.9-1.1 1.4-1.6l.3-.3 1.2-1.2.4-.4c.5-.5 1-.9 1.6-1.4.6-.5 1.1-.9 1.7-1.3.2-.1.3-.2.5-.3.5-.3.9-.7 1.4-1 .1-.1.3-.2.4-.3.6-.4 1.2-.7 1.9-1.1.1-.1.3-.1.4-.2.5-.3 1-.5 1.6-.8l.6-.3c.7-.3 1.3-.6 2-.8.7-.3 1.4-.5 2.1-.7.2-.1.4-.1.6-.2.6-.2 1.1-.3 1.7-.4.2 0 .3-.1.5-.1.7-.2 1.5-.3 2.2-.4.2 0 .3 0 .5-.1.6-.1 1.2-.1 1.8-.2h.6c.8 0 1.5-.1 2.3-.1s1.5 0 2.3.1h.6c.6 0 1.2.1 1.8.2.2 0 .3 0 .5.1.7.1 1.5.2 2.2.4.2 0 .3.1.5.1.6.1 1.2.3 1.7.4.2.1.4.1.6.2.7.2 1.4.4 2.1.7.7.2 1.3.5 2 .8l.6.3c.5.2 1.1.5 1.6.8.1.1.3.1.4.2.6.3 1.3.7 1.9 1.1.1.1.3.2.4.3.5.3 1 .6 1.4 1 .2.1.3.2.5.3.6.4 1.2.9 1.7 1.3s1.1.9 1.6 1.4l.4.4 1.2 1.2.3.3c.5.5 1 1.1 1.4 1.6.1.1.2.3.3.4.4.4.7.9 1 1.4.1.2.2.3.3.5l1.2 1.8s0 .1.1.1a36.18 36.18 0 0 1 5.1 18.5c0 6-1.5 11.7-4.1 16.7-.3.6-.7 1.2-1 1.8 0 0 0 .1-.1.1l-1.2 1.8c-.1.2-.2.3-.3.5-.3.5-.7.9-1 1.4-.1.1-.2.3-.3.4-.5.6-.9 1.1-1.4 1.6l-.3.3-1.2 1.2-.4.4c-.5.5-1 .9-1.6 1.4-.6.5-1.1.9-1.7 1.3-.2.1-.3.2-.5.3-.5.3-.9.7-1.4 1-.1.1-.3.2-.4.3-.6.4-1.2.7-1.9 1.1-.1.1-.3.1-.4.2-.5.3-1 .5-1.6.8l-.6.3c-.7.3-1.3.6-2 .8-.7.3-1.4.5-2.1.7-.2.1-.4.1-.6.2-.6.2-1.1.3-1.7.4-.2 0-.3.1-.5.1-.7.2-1.5.3-2.2.4-.2 0-.3 0-.5.1-.6.1-1.2.1-1.8.2h-.6c-.8 0-1.5.1-2.3.1s-1.5 0-2.3-.1h-.6c-.6 0-1.2-.1-1.8-.2-.2 0-.3 0-.5-.1-.7-.1-1.5-.2-2.2-.4-.2 0-.3-.1-.5-.1-.6-.1-1.2-.3-1.7-.4-.2-.1-.4-.1-.6-.2-.7-.2-1.4-.4-2.1-.7-.7-.2-1.3-.5-2-.8l-.6-.3c-.5-.2-1.1-.5-1.6-.8-.1-.1-.3-.1-.4-.2-.6-.3-1.3-.7-1.9-1.1-.1-.1-.3-.2-.4-.3-.5-.3-1-.6-1.4-1-.2-.1-.3-.2-.5-.3-.6-.4-1.2-.9-1.7-1.3s-1.1-.9-1.6-1.4l-.4-.4-1.2-1.2-.3-.3c-.5-.5-1-1.1-1.4-1.6-.1-.1-.2-.3-.3-.4-.4-.4-.7-.9-1-1.4-.1-.2-.2-.3-.3-.5l-1.2-1.8v-.1c-.4-.6-.7-1.2-1-1.8-2.6-5-4.1-10.7-4.1-16.7s1.5-11.7 4.1-16.7zM620 539v221c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V539c-10.1 3.3-20.8 5-32 5s-21.9-1.8-32-5zm64-198v-77c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v77c10.1-3.3 20.8-5 32-5s21.9 1.8 32 5zm-64 198c10.1 3.3 20.8 5 32 5s21.9-1.8 32-5c41.8-13.5 72-52.7 72-99s-30.2-85.5-72-99c-10.1-3.3-20.8-5-32-5s-21.9 1.8-32 5c-41.8 13.5-72 52.7-72 99s30.2 85.5 72 99zm.1-115.7c.3-.6.7-1.2 1-1.8v-.1l1.2-1.8c.1-.2.2-.3.3-.5.3-.5.7-.9 1-1.4.1-.1.2-.3.3-.4.5-.6.9-1.1 1.4-1.6l.3-.3 1.2-1.2.4-.4c.5-.5 1-.9 1.6-1.4.6-.5 1.1-.9 1.7-1.3.2-.1.3-.2.5-.3.5-.3.9-.7 1.4-1 .1-.1.3-.2.4-.3.6-.4 1.2-.7 1.9-1.1.1-.1.3-.1.4-.2.5-.3 1-.5 1.6-.8l.6-.3c.7-.3 1.3-.6 2-.8.7-.3 1.4-.5 2.1-.7.2-.1.4-.1.6-.2.6-.2 1.1-.3 1.7-.4.2 0 .3-.1.5-.1.7-.2 1.5-.3 2.2-.4.2 0 .3 0 .5-.1.6-.1 1.2-.1 1.8-.2h.6c.8 0 1.5-.1 2.3-.1s1.5 0 2.3.1h.6c.6 0 1.2.1 1.8.2.2 0 .3 0 .5.1.7.1 1.5.2 2.2.4.2 0 .3.1.5.1.6.1 1.2.3 1.7.4.2.1.4.1.6.2.7.2 1.4.4 2.1.7.7.2 1.3.5 2 .8l.6.3c.5.2 1.1.5 1.6.8.1.1.3.1.4.2.6.3 1.3.7 1.9 1.1.1.1.3.2.4.3.5.3 1 .6 1.4 1 .2.1.3.2.5.3.6.4 1.2.9 1.7 1.3s1.1.9 1.6 1.4l.4.4 1.2 1.2.3.3c.5.5 1 1.1 1.4 1.6.1.1.2.3.3.4.4.4.7.9 1 1.4.1.2.2.3.3.5l1.2 1.8v.1a36.18 36.18 0 0 1 5.1 18.5c0 6-1.5 11.7-4.1 16.7-.3.6-.7 1.2-1 1.8v.1l-1.2 1.8c-.1.2-.2.3-.3.5-.3.5-.7.9-1 1.4-.1.1-.2.3-.3.4-.5.6-.9 1.1-1.4 1.6l-.3.3-1.2 1.2-.4.4c-.5.5-1 .9-1.6 1.4-.6.5-1.1.9-1.7 1.3-.2.1-.3.2-.5.3-.5.3-.9.7-1.4 1-.1.1-.3.2-.4.3-.6.4-1.2.7-1.9 1.1-.1.1-.3.1-.4.2-.5.3-1 .5-1.6.8l-.6.3c-.7.3-1.3.6-2 .8-.7.3-1.4.5-2.1.7-.2.1-.4.1-.6.2-.6.2-1.1.3-1.7.4-.2 0-.3.1-.5.1-.7.2-1.5.3-2.2.4-.2 0-.3 0-.5.1-.6.1-1.2.1-1.8.2h-.6c-.8 0-1.5.1-2.3.1s-1.5 0-2.3-.1h-.6c-.6 0-1.2-.1-1.8-.2-.2 0-.3 0-.5-.1-.7-.1-1.5-.2-2.2-.4-.2 0-.3-.1-.5-.1-.6-.1-1.2-.3-1.7-.4-.2-.1-.4-.1-.6-.2-.7-.2-1.4-.4-2.1-.7-.7-.2-1.3-.5-2-.8l-.6-.3c-.5-.2-1.1-.5-1.6-.8-.1-.1-.3-.1-.4-.2-.6-.3-1.3-.7-1.9-1.1-.1-.1-.3-.2-.4-.3-.5-.3-1-.6-1.4-1-.2-.1-.3-.2-.5-.3-.6-.4-1.2-.9-1.7-1.3s-1.1-.9-1.6-1.4l-.4-.4-1.2-1.2-.3-.3c-.5-.5-1-1.1-1.4-1.6-.1-.1-.2-.3-.3-.4-.4-.4-.7-.9-1-1.4-.1-.2-.2-.3-.3-.5l-1.2-1.8v-.1c-.4-.6-.7-1.2-1-1.8-2.6-5-4.1-10.7-4.1-16.7s1.5-11.7 4.1-16.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiControlTwotone, "Control Twotone"))]
 pub static AiControlTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiControlTwotone",
+    label: "Control Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2879,8 +4114,15 @@ pub static AiControlTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M340 683v77c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8v-77c41.7-13.5 72-52.8 72-99s-30.3-85.4-72-99V264c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v221c-41.7 13.5-72 52.8-72 99s30.3 85.4 72 99zm.1-116c.1-.2.2-.3.3-.5l1.5-2.4c0-.1.1-.1.1-.2l.9-1.2c0-.1.1-.2.2-.3 1-1.2 2.1-2.5 3.2-3.6l.2-.2c.4-.4.8-.7 1.2-1.1.8-.7 1.7-1.4 2.6-2.1h.1l1.2-.9c.1-.1.3-.2.4-.3 1.3-.8 2.6-1.5 3.9-2.2.2-.2.5-.3.7-.4.4-.2.7-.3 1.1-.5.3-.1.7-.3 1-.4.5-.1 1-.3 1.5-.5.4-.1.9-.3 1.3-.4l.9-.3 1.4-.3c.2-.1.5-.1.7-.2.7-.2 1.4-.3 2.1-.4.2-.1.4-.1.6-.1.5-.1 1.1-.2 1.7-.2.3-.1.5-.1.7-.1.8-.1 1.5-.1 2.3-.1s1.5.1 2.3.1c.3.1.5.1.7.1.6.1 1.1.1 1.7.2.2.1.4.1.6.1.7.1 1.4.3 2.1.4.2.1.5.1.7.2l1.4.3.9.3c.4.1.9.3 1.3.4.5.1 1 .3 1.5.5.3.1.7.3 1 .4.4.2.7.3 1.1.5.2.2.5.3.7.4 1.4.6 2.7 1.4 3.9 2.2.1.1.3.2.4.3l1.2.9h.1c.9.6 1.8 1.3 2.6 2.1.4.3.8.7 1.2 1.1l.2.2c1.2 1.1 2.2 2.3 3.2 3.6 0 .1.1.2.2.3l.9 1.2c0 .1.1.1.1.2l1.5 2.4A36.03 36.03 0 0 1 408 584c0 6.1-1.6 11.9-4.3 17-.1.2-.2.3-.3.5l-1.5 2.4c0 .1-.1.1-.1.2l-.9 1.2c0 .1-.1.2-.2.3-1 1.2-2.1 2.5-3.2 3.6l-.2.2c-.4.4-.8.7-1.2 1.1-.8.7-1.7 1.4-2.6 2.1h-.1l-1.2.9c-.1.1-.3.2-.4.3-1.3.8-2.6 1.5-3.9 2.2-.2.2-.5.3-.7.4-.4.2-.7.3-1.1.5-.3.1-.7.3-1 .4-.5.1-1 .3-1.5.5-.4.1-.9.3-1.3.4l-.9.3-1.4.3c-.2.1-.5.1-.7.2-.7.2-1.4.3-2.1.4-.2.1-.4.1-.6.1-.5.1-1.1.2-1.7.2-.3.1-.5.1-.7.1-.8.1-1.5.1-2.3.1s-1.5-.1-2.3-.1c-.3-.1-.5-.1-.7-.1-.6-.1-1.1-.1-1.7-.2-.2-.1-.4-.1-.6-.1-.7-.1-1.4-.3-2.1-.4-.2-.1-.5-.1-.7-.2l-1.4-.3-.9-.3c-.4-.1-.9-.3-1.3-.4-.5-.1-1-.3-1.5-.5-.3-.1-.7-.3-1-.4-.4-.2-.7-.3-1.1-.5-.2-.2-.5-.3-.7-.4-1.4-.6-2.7-1.4-3.9-2.2-.1-.1-.3-.2-.4-.3l-1.2-.9h-.1c-.9-.6-1.8-1.3-2.6-2.1-.4-.3-.8-.7-1.2-1.1l-.2-.2c-1.2-1.1-2.2-2.3-3.2-3.6 0-.1-.1-.2-.2-.3l-.9-1.2c0-.1-.1-.1-.1-.2l-1.5-2.4c-.1-.2-.2-.3-.3-.5-2.7-5-4.3-10.9-4.3-17s1.6-11.9 4.3-17zm280.3-27.9c-.1 0-.2-.1-.4-.1v221c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V539c-.1 0-.2.1-.4.1 42-13.4 72.4-52.7 72.4-99.1 0-46.4-30.4-85.7-72.4-99.1.1 0 .2.1.4.1v-77c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v77c.1 0 .2-.1.4-.1-42 13.4-72.4 52.7-72.4 99.1 0 46.4 30.4 85.7 72.4 99.1zM652 404c19.9 0 36 16.1 36 36s-16.1 36-36 36-36-16.1-36-36 16.1-36 36-36z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCopyFilled, "Copy Filled"))]
 pub static AiCopyFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCopyFilled",
+    label: "Copy Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2895,8 +4137,15 @@ pub static AiCopyFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M832 64H296c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h496v688c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V96c0-17.7-14.3-32-32-32zM704 192H192c-17.7 0-32 14.3-32 32v530.7c0 8.5 3.4 16.6 9.4 22.6l173.3 173.3c2.2 2.2 4.7 4 7.4 5.5v1.9h4.2c3.5 1.3 7.2 2 11 2H704c17.7 0 32-14.3 32-32V224c0-17.7-14.3-32-32-32zM382 896h-.2L232 746.2v-.2h150v150z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCopyOutlined, "Copy Outlined"))]
 pub static AiCopyOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCopyOutlined",
+    label: "Copy Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2911,8 +4160,15 @@ pub static AiCopyOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M832 64H296c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h496v688c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V96c0-17.7-14.3-32-32-32zM704 192H192c-17.7 0-32 14.3-32 32v530.7c0 8.5 3.4 16.6 9.4 22.6l173.3 173.3c2.2 2.2 4.7 4 7.4 5.5v1.9h4.2c3.5 1.3 7.2 2 11 2H704c17.7 0 32-14.3 32-32V224c0-17.7-14.3-32-32-32zM350 856.2L263.9 770H350v86.2zM664 888H414V746c0-22.1-17.9-40-40-40H232V264h432v624z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCopyTwotone, "Copy Twotone"))]
 pub static AiCopyTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCopyTwotone",
+    label: "Copy Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2929,8 +4185,15 @@ pub static AiCopyTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M704 192H192c-17.7 0-32 14.3-32 32v530.7c0 8.5 3.4 16.6 9.4 22.6l173.3 173.3c2.2 2.2 4.7 4 7.4 5.5v1.9h4.2c3.5 1.3 7.2 2 11 2H704c17.7 0 32-14.3 32-32V224c0-17.7-14.3-32-32-32zM350 856.2L263.9 770H350v86.2zM664 888H414V746c0-22.1-17.9-40-40-40H232V264h432v624z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCopyrightCircleFilled, "Copyright Circle Filled"))]
 pub static AiCopyrightCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCopyrightCircleFilled",
+    label: "Copyright Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2945,8 +4208,15 @@ pub static AiCopyrightCircleFilled: &icondata_core::IconData = &icondata_core::I
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm5.4 670c-110 0-173.4-73.2-173.4-194.9v-52.3C344 364.2 407.4 290 517.3 290c94.3 0 162.7 60.7 162.7 147.4 0 2.6-2.1 4.7-4.7 4.7h-56.7c-4.2 0-7.6-3.2-8-7.4-4-49.5-40-83.4-93-83.4-65.3 0-102.1 48.5-102.1 135.5v52.6c0 85.7 36.9 133.6 102.1 133.6 52.8 0 88.7-31.7 93-77.8.4-4.1 3.8-7.3 8-7.3h56.8c2.6 0 4.7 2.1 4.7 4.7 0 82.6-68.7 141.4-162.7 141.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCopyrightCircleOutlined, "Copyright Circle Outlined"))]
 pub static AiCopyrightCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCopyrightCircleOutlined",
+    label: "Copyright Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2961,8 +4231,15 @@ pub static AiCopyrightCircleOutlined: &icondata_core::IconData = &icondata_core:
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm5.6-532.7c53 0 89 33.8 93 83.4.3 4.2 3.8 7.4 8 7.4h56.7c2.6 0 4.7-2.1 4.7-4.7 0-86.7-68.4-147.4-162.7-147.4C407.4 290 344 364.2 344 486.8v52.3C344 660.8 407.4 734 517.3 734c94 0 162.7-58.8 162.7-141.4 0-2.6-2.1-4.7-4.7-4.7h-56.8c-4.2 0-7.6 3.2-8 7.3-4.2 46.1-40.1 77.8-93 77.8-65.3 0-102.1-47.9-102.1-133.6v-52.6c.1-87 37-135.5 102.2-135.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCopyrightCircleTwotone, "Copyright Circle Twotone"))]
 pub static AiCopyrightCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCopyrightCircleTwotone",
+    label: "Copyright Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2979,8 +4256,15 @@ pub static AiCopyrightCircleTwotone: &icondata_core::IconData = &icondata_core::
 <path d="M517.6 351.3c53 0 89 33.8 93 83.4.3 4.2 3.8 7.4 8 7.4h56.7c2.6 0 4.7-2.1 4.7-4.7 0-86.7-68.4-147.4-162.7-147.4C407.4 290 344 364.2 344 486.8v52.3C344 660.8 407.4 734 517.3 734c94 0 162.7-58.8 162.7-141.4 0-2.6-2.1-4.7-4.7-4.7h-56.8c-4.2 0-7.6 3.2-8 7.3-4.2 46.1-40.1 77.8-93 77.8-65.3 0-102.1-47.9-102.1-133.6v-52.6c.1-87 37-135.5 102.2-135.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCopyrightOutlined, "Copyright Outlined"))]
 pub static AiCopyrightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCopyrightOutlined",
+    label: "Copyright Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2995,8 +4279,15 @@ pub static AiCopyrightOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm5.6-532.7c53 0 89 33.8 93 83.4.3 4.2 3.8 7.4 8 7.4h56.7c2.6 0 4.7-2.1 4.7-4.7 0-86.7-68.4-147.4-162.7-147.4C407.4 290 344 364.2 344 486.8v52.3C344 660.8 407.4 734 517.3 734c94 0 162.7-58.8 162.7-141.4 0-2.6-2.1-4.7-4.7-4.7h-56.8c-4.2 0-7.6 3.2-8 7.3-4.2 46.1-40.1 77.8-93 77.8-65.3 0-102.1-47.9-102.1-133.6v-52.6c.1-87 37-135.5 102.2-135.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCopyrightTwotone, "Copyright Twotone"))]
 pub static AiCopyrightTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCopyrightTwotone",
+    label: "Copyright Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3013,8 +4304,15 @@ pub static AiCopyrightTwotone: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M517.6 351.3c53 0 89 33.8 93 83.4.3 4.2 3.8 7.4 8 7.4h56.7c2.6 0 4.7-2.1 4.7-4.7 0-86.7-68.4-147.4-162.7-147.4C407.4 290 344 364.2 344 486.8v52.3C344 660.8 407.4 734 517.3 734c94 0 162.7-58.8 162.7-141.4 0-2.6-2.1-4.7-4.7-4.7h-56.8c-4.2 0-7.6 3.2-8 7.3-4.2 46.1-40.1 77.8-93 77.8-65.3 0-102.1-47.9-102.1-133.6v-52.6c.1-87 37-135.5 102.2-135.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCreditCardFilled, "Credit Card Filled"))]
 pub static AiCreditCardFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCreditCardFilled",
+    label: "Credit Card Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3029,8 +4327,15 @@ pub static AiCreditCardFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M928 160H96c-17.7 0-32 14.3-32 32v160h896V192c0-17.7-14.3-32-32-32zM64 832c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V440H64v392zm579-184c0-4.4 3.6-8 8-8h165c4.4 0 8 3.6 8 8v72c0 4.4-3.6 8-8 8H651c-4.4 0-8-3.6-8-8v-72z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCreditCardOutlined, "Credit Card Outlined"))]
 pub static AiCreditCardOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCreditCardOutlined",
+    label: "Credit Card Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3045,8 +4350,15 @@ pub static AiCreditCardOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M928 160H96c-17.7 0-32 14.3-32 32v640c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V192c0-17.7-14.3-32-32-32zm-792 72h752v120H136V232zm752 560H136V440h752v352zm-237-64h165c4.4 0 8-3.6 8-8v-72c0-4.4-3.6-8-8-8H651c-4.4 0-8 3.6-8 8v72c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCreditCardTwotone, "Credit Card Twotone"))]
 pub static AiCreditCardTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCreditCardTwotone",
+    label: "Credit Card Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3063,8 +4375,15 @@ pub static AiCreditCardTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M928 160H96c-17.7 0-32 14.3-32 32v640c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V192c0-17.7-14.3-32-32-32zm-40 632H136V440h752v352zm0-440H136V232h752v120z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCrownFilled, "Crown Filled"))]
 pub static AiCrownFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCrownFilled",
+    label: "Crown Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3079,8 +4398,15 @@ pub static AiCrownFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M899.6 276.5L705 396.4 518.4 147.5a8.06 8.06 0 0 0-12.9 0L319 396.4 124.3 276.5c-5.7-3.5-13.1 1.2-12.2 7.9L188.5 865c1.1 7.9 7.9 14 16 14h615.1c8 0 14.9-6 15.9-14l76.4-580.6c.8-6.7-6.5-11.4-12.3-7.9zM512 734.2c-62.1 0-112.6-50.5-112.6-112.6S449.9 509 512 509s112.6 50.5 112.6 112.6S574.1 734.2 512 734.2zm0-160.9c-26.6 0-48.2 21.6-48.2 48.3 0 26.6 21.6 48.3 48.2 48.3s48.2-21.6 48.2-48.3c0-26.6-21.6-48.3-48.2-48.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCrownOutlined, "Crown Outlined"))]
 pub static AiCrownOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCrownOutlined",
+    label: "Crown Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3095,8 +4421,15 @@ pub static AiCrownOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M899.6 276.5L705 396.4 518.4 147.5a8.06 8.06 0 0 0-12.9 0L319 396.4 124.3 276.5c-5.7-3.5-13.1 1.2-12.2 7.9L188.5 865c1.1 7.9 7.9 14 16 14h615.1c8 0 14.9-6 15.9-14l76.4-580.6c.8-6.7-6.5-11.4-12.3-7.9zm-126 534.1H250.3l-53.8-409.4 139.8 86.1L512 252.9l175.7 234.4 139.8-86.1-53.9 409.4zM512 509c-62.1 0-112.6 50.5-112.6 112.6S449.9 734.2 512 734.2s112.6-50.5 112.6-112.6S574.1 509 512 509zm0 160.9c-26.6 0-48.2-21.6-48.2-48.3 0-26.6 21.6-48.3 48.2-48.3s48.2 21.6 48.2 48.3c0 26.6-21.6 48.3-48.2 48.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCrownTwotone, "Crown Twotone"))]
 pub static AiCrownTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCrownTwotone",
+    label: "Crown Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3114,8 +4447,15 @@ pub static AiCrownTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M188.5 865c1.1 7.9 7.9 14 16 14h615.1c8 0 14.9-6 15.9-14l76.4-580.6v-.5c.3-6.4-6.7-10.8-12.3-7.4L705 396.4 518.4 147.5a8.06 8.06 0 0 0-12.9 0L319 396.4 124.3 276.5c-5.5-3.4-12.6.9-12.2 7.3v.6L188.5 865zm147.8-377.7L512 252.9l175.7 234.4 139.8-86.1-53.9 409.4H250.3l-53.8-409.4 139.8 86.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCustomerServiceFilled, "Customer Service Filled"))]
 pub static AiCustomerServiceFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCustomerServiceFilled",
+    label: "Customer Service Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3130,8 +4470,15 @@ pub static AiCustomerServiceFilled: &icondata_core::IconData = &icondata_core::I
     data: r###"<path d="M512 128c-212.1 0-384 171.9-384 384v360c0 13.3 10.7 24 24 24h184c35.3 0 64-28.7 64-64V624c0-35.3-28.7-64-64-64H200v-48c0-172.3 139.7-312 312-312s312 139.7 312 312v48H688c-35.3 0-64 28.7-64 64v208c0 35.3 28.7 64 64 64h184c13.3 0 24-10.7 24-24V512c0-212.1-171.9-384-384-384z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCustomerServiceOutlined, "Customer Service Outlined"))]
 pub static AiCustomerServiceOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCustomerServiceOutlined",
+    label: "Customer Service Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3146,8 +4493,15 @@ pub static AiCustomerServiceOutlined: &icondata_core::IconData = &icondata_core:
     data: r###"<path d="M512 128c-212.1 0-384 171.9-384 384v360c0 13.3 10.7 24 24 24h184c35.3 0 64-28.7 64-64V624c0-35.3-28.7-64-64-64H200v-48c0-172.3 139.7-312 312-312s312 139.7 312 312v48H688c-35.3 0-64 28.7-64 64v208c0 35.3 28.7 64 64 64h184c13.3 0 24-10.7 24-24V512c0-212.1-171.9-384-384-384zM328 632v192H200V632h128zm496 192H696V632h128v192z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiCustomerServiceTwotone, "Customer Service Twotone"))]
 pub static AiCustomerServiceTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiCustomerServiceTwotone",
+    label: "Customer Service Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3163,8 +4517,15 @@ pub static AiCustomerServiceTwotone: &icondata_core::IconData = &icondata_core::
 <path d="M512 128c-212.1 0-384 171.9-384 384v360c0 13.3 10.7 24 24 24h184c35.3 0 64-28.7 64-64V624c0-35.3-28.7-64-64-64H200v-48c0-172.3 139.7-312 312-312s312 139.7 312 312v48H688c-35.3 0-64 28.7-64 64v208c0 35.3 28.7 64 64 64h184c13.3 0 24-10.7 24-24V512c0-212.1-171.9-384-384-384zM328 632v192H200V632h128zm496 192H696V632h128v192z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDashOutlined, "Dash Outlined"))]
 pub static AiDashOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDashOutlined",
+    label: "Dash Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3179,8 +4540,15 @@ pub static AiDashOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M112 476h160v72H112zm320 0h160v72H432zm320 0h160v72H752z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDashboardFilled, "Dashboard Filled"))]
 pub static AiDashboardFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDashboardFilled",
+    label: "Dashboard Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3195,8 +4563,15 @@ pub static AiDashboardFilled: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M924.8 385.6a446.7 446.7 0 0 0-96-142.4 446.7 446.7 0 0 0-142.4-96C631.1 123.8 572.5 112 512 112s-119.1 11.8-174.4 35.2a446.7 446.7 0 0 0-142.4 96 446.7 446.7 0 0 0-96 142.4C75.8 440.9 64 499.5 64 560c0 132.7 58.3 257.7 159.9 343.1l1.7 1.4c5.8 4.8 13.1 7.5 20.6 7.5h531.7c7.5 0 14.8-2.7 20.6-7.5l1.7-1.4C901.7 817.7 960 692.7 960 560c0-60.5-11.9-119.1-35.2-174.4zM482 232c0-4.4 3.6-8 8-8h44c4.4 0 8 3.6 8 8v80c0 4.4-3.6 8-8 8h-44c-4.4 0-8-3.6-8-8v-80zM270 582c0 4.4-3.6 8-8 8h-80c-4.4 0-8-3.6-8-8v-44c0-4.4 3.6-8 8-8h80c4.4 0 8 3.6 8 8v44zm90.7-204.5l-31.1 31.1a8.03 8.03 0 0 1-11.3 0L261.7 352a8.03 8.03 0 0 1 0-11.3l31.1-31.1c3.1-3.1 8.2-3.1 11.3 0l56.6 56.6c3.1 3.1 3.1 8.2 0 11.3zm291.1 83.6l-84.5 84.5c5 18.7.2 39.4-14.5 54.1a55.95 55.95 0 0 1-79.2 0 55.95 55.95 0 0 1 0-79.2 55.87 55.87 0 0 1 54.1-14.5l84.5-84.5c3.1-3.1 8.2-3.1 11.3 0l28.3 28.3c3.1 3.1 3.1 8.1 0 11.3zm43-52.4l-31.1-31.1a8.03 8.03 0 0 1 0-11.3l56.6-56.6c3.1-3.1 8.2-3.1 11.3 0l31.1 31.1c3.1 3.1 3.1 8.2 0 11.3l-56.6 56.6a8.03 8.03 0 0 1-11.3 0zM846 582c0 4.4-3.6 8-8 8h-80c-4.4 0-8-3.6-8-8v-44c0-4.4 3.6-8 8-8h80c4.4 0 8 3.6 8 8v44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDashboardOutlined, "Dashboard Outlined"))]
 pub static AiDashboardOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDashboardOutlined",
+    label: "Dashboard Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3211,8 +4586,15 @@ pub static AiDashboardOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M924.8 385.6a446.7 446.7 0 0 0-96-142.4 446.7 446.7 0 0 0-142.4-96C631.1 123.8 572.5 112 512 112s-119.1 11.8-174.4 35.2a446.7 446.7 0 0 0-142.4 96 446.7 446.7 0 0 0-96 142.4C75.8 440.9 64 499.5 64 560c0 132.7 58.3 257.7 159.9 343.1l1.7 1.4c5.8 4.8 13.1 7.5 20.6 7.5h531.7c7.5 0 14.8-2.7 20.6-7.5l1.7-1.4C901.7 817.7 960 692.7 960 560c0-60.5-11.9-119.1-35.2-174.4zM761.4 836H262.6A371.12 371.12 0 0 1 140 560c0-99.4 38.7-192.8 109-263 70.3-70.3 163.7-109 263-109 99.4 0 192.8 38.7 263 109 70.3 70.3 109 163.7 109 263 0 105.6-44.5 205.5-122.6 276zM623.5 421.5a8.03 8.03 0 0 0-11.3 0L527.7 506c-18.7-5-39.4-.2-54.1 14.5a55.95 55.95 0 0 0 0 79.2 55.95 55.95 0 0 0 79.2 0 55.87 55.87 0 0 0 14.5-54.1l84.5-84.5c3.1-3.1 3.1-8.2 0-11.3l-28.3-28.3zM490 320h44c4.4 0 8-3.6 8-8v-80c0-4.4-3.6-8-8-8h-44c-4.4 0-8 3.6-8 8v80c0 4.4 3.6 8 8 8zm260 218v44c0 4.4 3.6 8 8 8h80c4.4 0 8-3.6 8-8v-44c0-4.4-3.6-8-8-8h-80c-4.4 0-8 3.6-8 8zm12.7-197.2l-31.1-31.1a8.03 8.03 0 0 0-11.3 0l-56.6 56.6a8.03 8.03 0 0 0 0 11.3l31.1 31.1c3.1 3.1 8.2 3.1 11.3 0l56.6-56.6c3.1-3.1 3.1-8.2 0-11.3zm-458.6-31.1a8.03 8.03 0 0 0-11.3 0l-31.1 31.1a8.03 8.03 0 0 0 0 11.3l56.6 56.6c3.1 3.1 8.2 3.1 11.3 0l31.1-31.1c3.1-3.1 3.1-8.2 0-11.3l-56.6-56.6zM262 530h-80c-4.4 0-8 3.6-8 8v44c0 4.4 3.6 8 8 8h80c4.4 0 8-3.6 8-8v-44c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDashboardTwotone, "Dashboard Twotone"))]
 pub static AiDashboardTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDashboardTwotone",
+    label: "Dashboard Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3230,8 +4612,15 @@ pub static AiDashboardTwotone: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M762.7 340.8l-31.1-31.1a8.03 8.03 0 0 0-11.3 0l-56.6 56.6a8.03 8.03 0 0 0 0 11.3l31.1 31.1c3.1 3.1 8.2 3.1 11.3 0l56.6-56.6c3.1-3.1 3.1-8.2 0-11.3zM750 538v44c0 4.4 3.6 8 8 8h80c4.4 0 8-3.6 8-8v-44c0-4.4-3.6-8-8-8h-80c-4.4 0-8 3.6-8 8zM304.1 309.7a8.03 8.03 0 0 0-11.3 0l-31.1 31.1a8.03 8.03 0 0 0 0 11.3l56.6 56.6c3.1 3.1 8.2 3.1 11.3 0l31.1-31.1c3.1-3.1 3.1-8.2 0-11.3l-56.6-56.6zM262 530h-80c-4.4 0-8 3.6-8 8v44c0 4.4 3.6 8 8 8h80c4.4 0 8-3.6 8-8v-44c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDatabaseFilled, "Database Filled"))]
 pub static AiDatabaseFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDatabaseFilled",
+    label: "Database Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3246,8 +4635,15 @@ pub static AiDatabaseFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M832 64H192c-17.7 0-32 14.3-32 32v224h704V96c0-17.7-14.3-32-32-32zM288 232c-22.1 0-40-17.9-40-40s17.9-40 40-40 40 17.9 40 40-17.9 40-40 40zM160 928c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V704H160v224zm128-136c22.1 0 40 17.9 40 40s-17.9 40-40 40-40-17.9-40-40 17.9-40 40-40zM160 640h704V384H160v256zm128-168c22.1 0 40 17.9 40 40s-17.9 40-40 40-40-17.9-40-40 17.9-40 40-40z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDatabaseOutlined, "Database Outlined"))]
 pub static AiDatabaseOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDatabaseOutlined",
+    label: "Database Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3262,8 +4658,15 @@ pub static AiDatabaseOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M832 64H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V96c0-17.7-14.3-32-32-32zm-600 72h560v208H232V136zm560 480H232V408h560v208zm0 272H232V680h560v208zM304 240a40 40 0 1 0 80 0 40 40 0 1 0-80 0zm0 272a40 40 0 1 0 80 0 40 40 0 1 0-80 0zm0 272a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDatabaseTwotone, "Database Twotone"))]
 pub static AiDatabaseTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDatabaseTwotone",
+    label: "Database Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3280,8 +4683,15 @@ pub static AiDatabaseTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M832 64H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V96c0-17.7-14.3-32-32-32zm-40 824H232V680h560v208zm0-272H232V408h560v208zm0-272H232V136h560v208z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDeleteColumnOutlined, "Delete Column Outlined"))]
 pub static AiDeleteColumnOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDeleteColumnOutlined",
+    label: "Delete Column Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3299,8 +4709,15 @@ pub static AiDeleteColumnOutlined: &icondata_core::IconData = &icondata_core::Ic
 <path d="M651.1 641.9c-1.4-1.2-3.2-1.9-5.1-1.9h-54.7c-2.4 0-4.6 1.1-6.1 2.9L512 730.7l-73.1-87.8c-1.5-1.8-3.8-2.9-6.1-2.9H378c-1.9 0-3.7 0.7-5.1 1.9-3.4 2.8-3.9 7.9-1 11.3L474.2 776 371.8 898.9c-2.8 3.4-2.4 8.4 1 11.3 1.4 1.2 3.2 1.9 5.1 1.9h54.7c2.4 0 4.6-1.1 6.1-2.9l73.1-87.8 73.1 87.8c1.5 1.8 3.8 2.9 6.1 2.9h55c1.9 0 3.7-0.7 5.1-1.9 3.4-2.8 3.9-7.9 1-11.3L549.8 776l102.4-122.9c2.8-3.4 2.3-8.4-1.1-11.2zM472 544h80c4.4 0 8-3.6 8-8V120c0-4.4-3.6-8-8-8h-80c-4.4 0-8 3.6-8 8v416c0 4.4 3.6 8 8 8zM350 386H184V136c0-3.3-2.7-6-6-6h-60c-3.3 0-6 2.7-6 6v292c0 16.6 13.4 30 30 30h208c3.3 0 6-2.7 6-6v-60c0-3.3-2.7-6-6-6zM906 130h-60c-3.3 0-6 2.7-6 6v250H674c-3.3 0-6 2.7-6 6v60c0 3.3 2.7 6 6 6h208c16.6 0 30-13.4 30-30V136c0-3.3-2.7-6-6-6z" p-id="10078" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDeleteFilled, "Delete Filled"))]
 pub static AiDeleteFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDeleteFilled",
+    label: "Delete Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3315,8 +4732,15 @@ pub static AiDeleteFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M864 256H736v-80c0-35.3-28.7-64-64-64H352c-35.3 0-64 28.7-64 64v80H160c-17.7 0-32 14.3-32 32v32c0 4.4 3.6 8 8 8h60.4l24.7 523c1.6 34.1 29.8 61 63.9 61h454c34.2 0 62.3-26.8 63.9-61l24.7-523H888c4.4 0 8-3.6 8-8v-32c0-17.7-14.3-32-32-32zm-200 0H360v-72h304v72z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDeleteOutlined, "Delete Outlined"))]
 pub static AiDeleteOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDeleteOutlined",
+    label: "Delete Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3331,8 +4755,15 @@ pub static AiDeleteOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M360 184h-8c4.4 0 8-3.6 8-8v8h304v-8c0 4.4 3.6 8 8 8h-8v72h72v-80c0-35.3-28.7-64-64-64H352c-35.3 0-64 28.7-64 64v80h72v-72zm504 72H160c-17.7 0-32 14.3-32 32v32c0 4.4 3.6 8 8 8h60.4l24.7 523c1.6 34.1 29.8 61 63.9 61h454c34.2 0 62.3-26.8 63.9-61l24.7-523H888c4.4 0 8-3.6 8-8v-32c0-17.7-14.3-32-32-32zM731.3 840H292.7l-24.2-512h487l-24.2 512z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDeleteRowOutlined, "Delete Row Outlined"))]
 pub static AiDeleteRowOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDeleteRowOutlined",
+    label: "Delete Row Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3350,8 +4781,15 @@ pub static AiDeleteRowOutlined: &icondata_core::IconData = &icondata_core::IconD
 <path d="M819.8 512l102.4-122.9c2.8-3.4 2.4-8.4-1-11.3-1.4-1.2-3.2-1.9-5.1-1.9h-54.7c-2.4 0-4.6 1.1-6.1 2.9L782 466.7l-73.1-87.8c-1.5-1.8-3.8-2.9-6.1-2.9H648c-1.9 0-3.7 0.7-5.1 1.9-3.4 2.8-3.9 7.9-1 11.3L744.2 512 641.8 634.9c-2.8 3.4-2.4 8.4 1 11.3 1.4 1.2 3.2 1.9 5.1 1.9h54.7c2.4 0 4.6-1.1 6.1-2.9l73.1-87.8 73.1 87.8c1.5 1.8 3.8 2.9 6.1 2.9h55c1.9 0 3.7-0.7 5.1-1.9 3.4-2.8 3.9-7.9 1-11.3L819.8 512zM536 464H120c-4.4 0-8 3.6-8 8v80c0 4.4 3.6 8 8 8h416c4.4 0 8-3.6 8-8v-80c0-4.4-3.6-8-8-8zM452 668h-60c-3.3 0-6 2.7-6 6v166H136c-3.3 0-6 2.7-6 6v60c0 3.3 2.7 6 6 6h292c16.6 0 30-13.4 30-30V674c0-3.3-2.7-6-6-6zM136 184h250v166c0 3.3 2.7 6 6 6h60c3.3 0 6-2.7 6-6V142c0-16.6-13.4-30-30-30H136c-3.3 0-6 2.7-6 6v60c0 3.3 2.7 6 6 6z" p-id="11765" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDeleteTwotone, "Delete Twotone"))]
 pub static AiDeleteTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDeleteTwotone",
+    label: "Delete Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3367,8 +4805,15 @@ pub static AiDeleteTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M864 256H736v-80c0-35.3-28.7-64-64-64H352c-35.3 0-64 28.7-64 64v80H160c-17.7 0-32 14.3-32 32v32c0 4.4 3.6 8 8 8h60.4l24.7 523c1.6 34.1 29.8 61 63.9 61h454c34.2 0 62.3-26.8 63.9-61l24.7-523H888c4.4 0 8-3.6 8-8v-32c0-17.7-14.3-32-32-32zm-504-72h304v72H360v-72zm371.3 656H292.7l-24.2-512h487l-24.2 512z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDeliveredProcedureOutlined, "Delivered Procedure Outlined"))]
 pub static AiDeliveredProcedureOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDeliveredProcedureOutlined",
+    label: "Delivered Procedure Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3386,8 +4831,15 @@ pub static AiDeliveredProcedureOutlined: &icondata_core::IconData = &icondata_co
 <path d="M632 698.3l141.9-112c4.1-3.2 4.1-9.4 0-12.6L632 461.7c-5.3-4.2-13-0.4-13 6.3v76H295c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h324v76c0 6.7 7.8 10.4 13 6.3zM893.3 293.3L730.7 130.7c-7.5-7.5-16.7-13-26.7-16V112H144c-17.7 0-32 14.3-32 32v278c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V184h136v136c0 17.7 14.3 32 32 32h320c17.7 0 32-14.3 32-32V205.8l136 136V422c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-83.5c0-17-6.7-33.2-18.7-45.2zM640 288H384V184h256v104zM904 724h-56c-4.4 0-8 3.6-8 8v108H184V732c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v148c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V732c0-4.4-3.6-8-8-8z" p-id="12830" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDeploymentUnitOutlined, "Deployment Unit Outlined"))]
 pub static AiDeploymentUnitOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDeploymentUnitOutlined",
+    label: "Deployment Unit Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3402,8 +4854,15 @@ pub static AiDeploymentUnitOutlined: &icondata_core::IconData = &icondata_core::
     data: r###"<path d="M888.3 693.2c-42.5-24.6-94.3-18-129.2 12.8l-53-30.7V523.6c0-15.7-8.4-30.3-22-38.1l-136-78.3v-67.1c44.2-15 76-56.8 76-106.1 0-61.9-50.1-112-112-112s-112 50.1-112 112c0 49.3 31.8 91.1 76 106.1v67.1l-136 78.3c-13.6 7.8-22 22.4-22 38.1v151.6l-53 30.7c-34.9-30.8-86.8-37.4-129.2-12.8-53.5 31-71.7 99.4-41 152.9 30.8 53.5 98.9 71.9 152.2 41 42.5-24.6 62.7-73 53.6-118.8l48.7-28.3 140.6 81c6.8 3.9 14.4 5.9 22 5.9s15.2-2 22-5.9L674.5 740l48.7 28.3c-9.1 45.7 11.2 94.2 53.6 118.8 53.3 30.9 121.5 12.6 152.2-41 30.8-53.6 12.6-122-40.7-152.9zm-673 138.4a47.6 47.6 0 0 1-65.2-17.6c-13.2-22.9-5.4-52.3 17.5-65.5a47.6 47.6 0 0 1 65.2 17.6c13.2 22.9 5.4 52.3-17.5 65.5zM522 463.8zM464 234a48.01 48.01 0 0 1 96 0 48.01 48.01 0 0 1-96 0zm170 446.2l-122 70.3-122-70.3V539.8l122-70.3 122 70.3v140.4zm239.9 133.9c-13.2 22.9-42.4 30.8-65.2 17.6-22.8-13.2-30.7-42.6-17.5-65.5s42.4-30.8 65.2-17.6c22.9 13.2 30.7 42.5 17.5 65.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDesktopOutlined, "Desktop Outlined"))]
 pub static AiDesktopOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDesktopOutlined",
+    label: "Desktop Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3418,8 +4877,15 @@ pub static AiDesktopOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M928 140H96c-17.7 0-32 14.3-32 32v496c0 17.7 14.3 32 32 32h380v112H304c-8.8 0-16 7.2-16 16v48c0 4.4 3.6 8 8 8h432c4.4 0 8-3.6 8-8v-48c0-8.8-7.2-16-16-16H548V700h380c17.7 0 32-14.3 32-32V172c0-17.7-14.3-32-32-32zm-40 488H136V212h752v416z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDiffFilled, "Diff Filled"))]
 pub static AiDiffFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDiffFilled",
+    label: "Diff Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3434,8 +4900,15 @@ pub static AiDiffFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M854.2 306.6L611.3 72.9c-6-5.7-13.9-8.9-22.2-8.9H296c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h277l219 210.6V824c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V329.6c0-8.7-3.5-17-9.8-23zM553.4 201.4c-6-6-14.1-9.4-22.6-9.4H192c-17.7 0-32 14.3-32 32v704c0 17.7 14.3 32 32 32h512c17.7 0 32-14.3 32-32V397.3c0-8.5-3.4-16.6-9.4-22.6L553.4 201.4zM568 753c0 3.8-3.4 7-7.5 7h-225c-4.1 0-7.5-3.2-7.5-7v-42c0-3.8 3.4-7 7.5-7h225c4.1 0 7.5 3.2 7.5 7v42zm0-220c0 3.8-3.4 7-7.5 7H476v84.9c0 3.9-3.1 7.1-7 7.1h-42c-3.8 0-7-3.2-7-7.1V540h-84.5c-4.1 0-7.5-3.2-7.5-7v-42c0-3.9 3.4-7 7.5-7H420v-84.9c0-3.9 3.2-7.1 7-7.1h42c3.9 0 7 3.2 7 7.1V484h84.5c4.1 0 7.5 3.1 7.5 7v42z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDiffOutlined, "Diff Outlined"))]
 pub static AiDiffOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDiffOutlined",
+    label: "Diff Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3450,8 +4923,15 @@ pub static AiDiffOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M476 399.1c0-3.9-3.1-7.1-7-7.1h-42c-3.8 0-7 3.2-7 7.1V484h-84.5c-4.1 0-7.5 3.1-7.5 7v42c0 3.8 3.4 7 7.5 7H420v84.9c0 3.9 3.2 7.1 7 7.1h42c3.9 0 7-3.2 7-7.1V540h84.5c4.1 0 7.5-3.2 7.5-7v-42c0-3.9-3.4-7-7.5-7H476v-84.9zM560.5 704h-225c-4.1 0-7.5 3.2-7.5 7v42c0 3.8 3.4 7 7.5 7h225c4.1 0 7.5-3.2 7.5-7v-42c0-3.8-3.4-7-7.5-7zm-7.1-502.6c-6-6-14.1-9.4-22.6-9.4H192c-17.7 0-32 14.3-32 32v704c0 17.7 14.3 32 32 32h512c17.7 0 32-14.3 32-32V397.3c0-8.5-3.4-16.6-9.4-22.6L553.4 201.4zM664 888H232V264h282.2L664 413.8V888zm190.2-581.4L611.3 72.9c-6-5.7-13.9-8.9-22.2-8.9H296c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h277l219 210.6V824c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V329.6c0-8.7-3.5-17-9.8-23z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDiffTwotone, "Diff Twotone"))]
 pub static AiDiffTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDiffTwotone",
+    label: "Diff Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3469,8 +4949,15 @@ pub static AiDiffTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M476 399.1c0-3.9-3.1-7.1-7-7.1h-42c-3.8 0-7 3.2-7 7.1V484h-84.5c-4.1 0-7.5 3.1-7.5 7v42c0 3.8 3.4 7 7.5 7H420v84.9c0 3.9 3.2 7.1 7 7.1h42c3.9 0 7-3.2 7-7.1V540h84.5c4.1 0 7.5-3.2 7.5-7v-42c0-3.9-3.4-7-7.5-7H476v-84.9zM560.5 704h-225c-4.1 0-7.5 3.2-7.5 7v42c0 3.8 3.4 7 7.5 7h225c4.1 0 7.5-3.2 7.5-7v-42c0-3.8-3.4-7-7.5-7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDingdingOutlined, "Dingding Outlined"))]
 pub static AiDingdingOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDingdingOutlined",
+    label: "Dingding Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3485,8 +4972,15 @@ pub static AiDingdingOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M573.7 252.5C422.5 197.4 201.3 96.7 201.3 96.7c-15.7-4.1-17.9 11.1-17.9 11.1-5 61.1 33.6 160.5 53.6 182.8 19.9 22.3 319.1 113.7 319.1 113.7S326 357.9 270.5 341.9c-55.6-16-37.9 17.8-37.9 17.8 11.4 61.7 64.9 131.8 107.2 138.4 42.2 6.6 220.1 4 220.1 4s-35.5 4.1-93.2 11.9c-42.7 5.8-97 12.5-111.1 17.8-33.1 12.5 24 62.6 24 62.6 84.7 76.8 129.7 50.5 129.7 50.5 33.3-10.7 61.4-18.5 85.2-24.2L565 743.1h84.6L603 928l205.3-271.9H700.8l22.3-38.7c.3.5.4.8.4.8S799.8 496.1 829 433.8l.6-1h-.1c5-10.8 8.6-19.7 10-25.8 17-71.3-114.5-99.4-265.8-154.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDingtalkCircleFilled, "Dingtalk Circle Filled"))]
 pub static AiDingtalkCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDingtalkCircleFilled",
+    label: "Dingtalk Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3501,8 +4995,15 @@ pub static AiDingtalkCircleFilled: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm227 385.3c-1 4.2-3.5 10.4-7 17.8h.1l-.4.7c-20.3 43.1-73.1 127.7-73.1 127.7s-.1-.2-.3-.5l-15.5 26.8h74.5L575.1 810l32.3-128h-58.6l20.4-84.7c-16.5 3.9-35.9 9.4-59 16.8 0 0-31.2 18.2-89.9-35 0 0-39.6-34.7-16.6-43.4 9.8-3.7 47.4-8.4 77-12.3 40-5.4 64.6-8.2 64.6-8.2S422 517 392.7 512.5c-29.3-4.6-66.4-53.1-74.3-95.8 0 0-12.2-23.4 26.3-12.3 38.5 11.1 197.9 43.2 197.9 43.2s-207.4-63.3-221.2-78.7c-13.8-15.4-40.6-84.2-37.1-126.5 0 0 1.5-10.5 12.4-7.7 0 0 153.3 69.7 258.1 107.9 104.8 37.9 195.9 57.3 184.2 106.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDingtalkOutlined, "Dingtalk Outlined"))]
 pub static AiDingtalkOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDingtalkOutlined",
+    label: "Dingtalk Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3517,8 +5018,15 @@ pub static AiDingtalkOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M573.7 252.5C422.5 197.4 201.3 96.7 201.3 96.7c-15.7-4.1-17.9 11.1-17.9 11.1-5 61.1 33.6 160.5 53.6 182.8 19.9 22.3 319.1 113.7 319.1 113.7S326 357.9 270.5 341.9c-55.6-16-37.9 17.8-37.9 17.8 11.4 61.7 64.9 131.8 107.2 138.4 42.2 6.6 220.1 4 220.1 4s-35.5 4.1-93.2 11.9c-42.7 5.8-97 12.5-111.1 17.8-33.1 12.5 24 62.6 24 62.6 84.7 76.8 129.7 50.5 129.7 50.5 33.3-10.7 61.4-18.5 85.2-24.2L565 743.1h84.6L603 928l205.3-271.9H700.8l22.3-38.7c.3.5.4.8.4.8S799.8 496.1 829 433.8l.6-1h-.1c5-10.8 8.6-19.7 10-25.8 17-71.3-114.5-99.4-265.8-154.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDingtalkSquareFilled, "Dingtalk Square Filled"))]
 pub static AiDingtalkSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDingtalkSquareFilled",
+    label: "Dingtalk Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3533,8 +5041,15 @@ pub static AiDingtalkSquareFilled: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM739 449.3c-1 4.2-3.5 10.4-7 17.8h.1l-.4.7c-20.3 43.1-73.1 127.7-73.1 127.7s-.1-.2-.3-.5l-15.5 26.8h74.5L575.1 810l32.3-128h-58.6l20.4-84.7c-16.5 3.9-35.9 9.4-59 16.8 0 0-31.2 18.2-89.9-35 0 0-39.6-34.7-16.6-43.4 9.8-3.7 47.4-8.4 77-12.3 40-5.4 64.6-8.2 64.6-8.2S422 517 392.7 512.5c-29.3-4.6-66.4-53.1-74.3-95.8 0 0-12.2-23.4 26.3-12.3 38.5 11.1 197.9 43.2 197.9 43.2s-207.4-63.3-221.2-78.7c-13.8-15.4-40.6-84.2-37.1-126.5 0 0 1.5-10.5 12.4-7.7 0 0 153.3 69.7 258.1 107.9 104.8 37.9 195.9 57.3 184.2 106.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDisconnectOutlined, "Disconnect Outlined"))]
 pub static AiDisconnectOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDisconnectOutlined",
+    label: "Disconnect Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3549,8 +5064,15 @@ pub static AiDisconnectOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M832.6 191.4c-84.6-84.6-221.5-84.6-306 0l-96.9 96.9 51 51 96.9-96.9c53.8-53.8 144.6-59.5 204 0 59.5 59.5 53.8 150.2 0 204l-96.9 96.9 51.1 51.1 96.9-96.9c84.4-84.6 84.4-221.5-.1-306.1zM446.5 781.6c-53.8 53.8-144.6 59.5-204 0-59.5-59.5-53.8-150.2 0-204l96.9-96.9-51.1-51.1-96.9 96.9c-84.6 84.6-84.6 221.5 0 306s221.5 84.6 306 0l96.9-96.9-51-51-96.8 97zM260.3 209.4a8.03 8.03 0 0 0-11.3 0L209.4 249a8.03 8.03 0 0 0 0 11.3l554.4 554.4c3.1 3.1 8.2 3.1 11.3 0l39.6-39.6c3.1-3.1 3.1-8.2 0-11.3L260.3 209.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDislikeFilled, "Dislike Filled"))]
 pub static AiDislikeFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDislikeFilled",
+    label: "Dislike Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3565,8 +5087,15 @@ pub static AiDislikeFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M885.9 490.3c3.6-12 5.4-24.4 5.4-37 0-28.3-9.3-55.5-26.1-77.7 3.6-12 5.4-24.4 5.4-37 0-28.3-9.3-55.5-26.1-77.7 3.6-12 5.4-24.4 5.4-37 0-51.6-30.7-98.1-78.3-118.4a66.1 66.1 0 0 0-26.5-5.4H273v428h.3l85.8 310.8C372.9 889 418.9 924 470.9 924c29.7 0 57.4-11.8 77.9-33.4 20.5-21.5 31-49.7 29.5-79.4l-6-122.9h239.9c12.1 0 23.9-3.2 34.3-9.3 40.4-23.5 65.5-66.1 65.5-111 0-28.3-9.3-55.5-26.1-77.7zM112 132v364c0 17.7 14.3 32 32 32h65V100h-65c-17.7 0-32 14.3-32 32z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDislikeOutlined, "Dislike Outlined"))]
 pub static AiDislikeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDislikeOutlined",
+    label: "Dislike Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3581,8 +5110,15 @@ pub static AiDislikeOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M885.9 490.3c3.6-12 5.4-24.4 5.4-37 0-28.3-9.3-55.5-26.1-77.7 3.6-12 5.4-24.4 5.4-37 0-28.3-9.3-55.5-26.1-77.7 3.6-12 5.4-24.4 5.4-37 0-51.6-30.7-98.1-78.3-118.4a66.1 66.1 0 0 0-26.5-5.4H144c-17.7 0-32 14.3-32 32v364c0 17.7 14.3 32 32 32h129.3l85.8 310.8C372.9 889 418.9 924 470.9 924c29.7 0 57.4-11.8 77.9-33.4 20.5-21.5 31-49.7 29.5-79.4l-6-122.9h239.9c12.1 0 23.9-3.2 34.3-9.3 40.4-23.5 65.5-66.1 65.5-111 0-28.3-9.3-55.5-26.1-77.7zM184 456V172h81v284h-81zm627.2 160.4H496.8l9.6 198.4c.6 11.9-4.7 23.1-14.6 30.5-6.1 4.5-13.6 6.8-21.1 6.7a44.28 44.28 0 0 1-42.2-32.3L329 459.2V172h415.4a56.85 56.85 0 0 1 33.6 51.8c0 9.7-2.3 18.9-6.9 27.3l-13.9 25.4 21.9 19a56.76 56.76 0 0 1 19.6 43c0 9.7-2.3 18.9-6.9 27.3l-13.9 25.4 21.9 19a56.76 56.76 0 0 1 19.6 43c0 9.7-2.3 18.9-6.9 27.3l-14 25.5 21.9 19a56.76 56.76 0 0 1 19.6 43c0 19.1-11 37.5-28.8 48.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDislikeTwotone, "Dislike Twotone"))]
 pub static AiDislikeTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDislikeTwotone",
+    label: "Dislike Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3598,8 +5134,15 @@ pub static AiDislikeTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M112 132v364c0 17.7 14.3 32 32 32h65V100h-65c-17.7 0-32 14.3-32 32zm773.9 358.3c3.6-12 5.4-24.4 5.4-37 0-28.3-9.3-55.5-26.1-77.7 3.6-12 5.4-24.4 5.4-37 0-28.3-9.3-55.5-26.1-77.7 3.6-12 5.4-24.4 5.4-37 0-51.6-30.7-98.1-78.3-118.4a66.1 66.1 0 0 0-26.5-5.4H273l.3 428 85.8 310.8C372.9 889 418.9 924 470.9 924c29.7 0 57.4-11.8 77.9-33.4 20.5-21.5 31-49.7 29.5-79.4l-6-122.9h239.9c12.1 0 23.9-3.2 34.3-9.3 40.4-23.5 65.5-66.1 65.5-111 0-28.3-9.3-55.5-26.1-77.7zm-74.7 126.1H496.8l9.6 198.4c.6 11.9-4.7 23.1-14.6 30.5-6.1 4.5-13.6 6.8-21.1 6.7a44.28 44.28 0 0 1-42.2-32.3c-37.1-134.4-64.9-235.2-83.5-302.5V172h399.4a56.85 56.85 0 0 1 33.6 51.8c0 9.7-2.3 18.9-6.9 27.3l-13.9 25.4 21.9 19a56.76 56.76 0 0 1 19.6 43c0 9.7-2.3 18.9-6.9 27.3l-13.9 25.4 21.9 19a56.76 56.76 0 0 1 19.6 43c0 9.7-2.3 18.9-6.9 27.3l-14 25.5 21.9 19a56.76 56.76 0 0 1 19.6 43c0 19.1-11 37.5-28.8 48.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDollarCircleFilled, "Dollar Circle Filled"))]
 pub static AiDollarCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDollarCircleFilled",
+    label: "Dollar Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3614,8 +5157,15 @@ pub static AiDollarCircleFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm22.3 665.2l.2 31.7c0 4.4-3.6 8.1-8 8.1h-28.4c-4.4 0-8-3.6-8-8v-31.4C401.3 723 359.5 672.4 355 617.4c-.4-4.7 3.3-8.7 8-8.7h46.2c3.9 0 7.3 2.8 7.9 6.6 5.1 31.7 29.8 55.4 74.1 61.3V533.9l-24.7-6.3c-52.3-12.5-102.1-45.1-102.1-112.7 0-72.9 55.4-112.1 126.2-119v-33c0-4.4 3.6-8 8-8h28.1c4.4 0 8 3.6 8 8v32.7c68.5 6.9 119.9 46.9 125.9 109.2.5 4.7-3.2 8.8-8 8.8h-44.9c-4 0-7.4-3-7.9-6.9-4-29.2-27.4-53-65.5-58.2v134.3l25.4 5.9c64.8 16 108.9 47 108.9 116.4 0 75.3-56 117.3-134.3 124.1zM426.6 410.3c0 25.4 15.7 45.1 49.5 57.3 4.7 1.9 9.4 3.4 15 5v-124c-36.9 4.7-64.5 25.4-64.5 61.7zm116.5 135.2c-2.8-.6-5.6-1.3-8.8-2.2V677c42.6-3.8 72-27.2 72-66.4 0-30.7-15.9-50.7-63.2-65.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDollarCircleOutlined, "Dollar Circle Outlined"))]
 pub static AiDollarCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDollarCircleOutlined",
+    label: "Dollar Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3630,8 +5180,15 @@ pub static AiDollarCircleOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm47.7-395.2l-25.4-5.9V348.6c38 5.2 61.5 29 65.5 58.2.5 4 3.9 6.9 7.9 6.9h44.9c4.7 0 8.4-4.1 8-8.8-6.1-62.3-57.4-102.3-125.9-109.2V263c0-4.4-3.6-8-8-8h-28.1c-4.4 0-8 3.6-8 8v33c-70.8 6.9-126.2 46-126.2 119 0 67.6 49.8 100.2 102.1 112.7l24.7 6.3v142.7c-44.2-5.9-69-29.5-74.1-61.3-.6-3.8-4-6.6-7.9-6.6H363c-4.7 0-8.4 4-8 8.7 4.5 55 46.2 105.6 135.2 112.1V761c0 4.4 3.6 8 8 8h28.4c4.4 0 8-3.6 8-8.1l-.2-31.7c78.3-6.9 134.3-48.8 134.3-124-.1-69.4-44.2-100.4-109-116.4zm-68.6-16.2c-5.6-1.6-10.3-3.1-15-5-33.8-12.2-49.5-31.9-49.5-57.3 0-36.3 27.5-57 64.5-61.7v124zM534.3 677V543.3c3.1.9 5.9 1.6 8.8 2.2 47.3 14.4 63.2 34.4 63.2 65.1 0 39.1-29.4 62.6-72 66.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDollarCircleTwotone, "Dollar Circle Twotone"))]
 pub static AiDollarCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDollarCircleTwotone",
+    label: "Dollar Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3649,8 +5206,15 @@ pub static AiDollarCircleTwotone: &icondata_core::IconData = &icondata_core::Ico
 <path d="M559.7 488.8l-25.4-5.9V348.6c38 5.2 61.5 29 65.5 58.2.5 4 3.9 6.9 7.9 6.9h44.9c4.7 0 8.4-4.1 8-8.8-6.1-62.3-57.4-102.3-125.9-109.2V263c0-4.4-3.6-8-8-8h-28.1c-4.4 0-8 3.6-8 8v33c-70.8 6.9-126.2 46-126.2 119 0 67.6 49.8 100.2 102.1 112.7l24.7 6.3v142.7c-44.2-5.9-69-29.5-74.1-61.3-.6-3.8-4-6.6-7.9-6.6H363c-4.7 0-8.4 4-8 8.7 4.5 55 46.2 105.6 135.2 112.1V761c0 4.4 3.6 8 8 8h28.4c4.4 0 8-3.6 8-8.1l-.2-31.7c78.3-6.9 134.3-48.8 134.3-124-.1-69.4-44.2-100.4-109-116.4zm-68.6-16.2c-5.6-1.6-10.3-3.1-15-5-33.8-12.2-49.5-31.9-49.5-57.3 0-36.3 27.5-57 64.5-61.7v124zM534.3 677V543.3c3.1.9 5.9 1.6 8.8 2.2 47.3 14.4 63.2 34.4 63.2 65.1 0 39.1-29.4 62.6-72 66.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDollarOutlined, "Dollar Outlined"))]
 pub static AiDollarOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDollarOutlined",
+    label: "Dollar Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3665,8 +5229,15 @@ pub static AiDollarOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm47.7-395.2l-25.4-5.9V348.6c38 5.2 61.5 29 65.5 58.2.5 4 3.9 6.9 7.9 6.9h44.9c4.7 0 8.4-4.1 8-8.8-6.1-62.3-57.4-102.3-125.9-109.2V263c0-4.4-3.6-8-8-8h-28.1c-4.4 0-8 3.6-8 8v33c-70.8 6.9-126.2 46-126.2 119 0 67.6 49.8 100.2 102.1 112.7l24.7 6.3v142.7c-44.2-5.9-69-29.5-74.1-61.3-.6-3.8-4-6.6-7.9-6.6H363c-4.7 0-8.4 4-8 8.7 4.5 55 46.2 105.6 135.2 112.1V761c0 4.4 3.6 8 8 8h28.4c4.4 0 8-3.6 8-8.1l-.2-31.7c78.3-6.9 134.3-48.8 134.3-124-.1-69.4-44.2-100.4-109-116.4zm-68.6-16.2c-5.6-1.6-10.3-3.1-15-5-33.8-12.2-49.5-31.9-49.5-57.3 0-36.3 27.5-57 64.5-61.7v124zM534.3 677V543.3c3.1.9 5.9 1.6 8.8 2.2 47.3 14.4 63.2 34.4 63.2 65.1 0 39.1-29.4 62.6-72 66.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDollarTwotone, "Dollar Twotone"))]
 pub static AiDollarTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDollarTwotone",
+    label: "Dollar Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3684,8 +5255,15 @@ pub static AiDollarTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M559.7 488.8l-25.4-5.9V348.6c38 5.2 61.5 29 65.5 58.2.5 4 3.9 6.9 7.9 6.9h44.9c4.7 0 8.4-4.1 8-8.8-6.1-62.3-57.4-102.3-125.9-109.2V263c0-4.4-3.6-8-8-8h-28.1c-4.4 0-8 3.6-8 8v33c-70.8 6.9-126.2 46-126.2 119 0 67.6 49.8 100.2 102.1 112.7l24.7 6.3v142.7c-44.2-5.9-69-29.5-74.1-61.3-.6-3.8-4-6.6-7.9-6.6H363c-4.7 0-8.4 4-8 8.7 4.5 55 46.2 105.6 135.2 112.1V761c0 4.4 3.6 8 8 8h28.4c4.4 0 8-3.6 8-8.1l-.2-31.7c78.3-6.9 134.3-48.8 134.3-124-.1-69.4-44.2-100.4-109-116.4zm-68.6-16.2c-5.6-1.6-10.3-3.1-15-5-33.8-12.2-49.5-31.9-49.5-57.3 0-36.3 27.5-57 64.5-61.7v124zM534.3 677V543.3c3.1.9 5.9 1.6 8.8 2.2 47.3 14.4 63.2 34.4 63.2 65.1 0 39.1-29.4 62.6-72 66.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDotChartOutlined, "Dot Chart Outlined"))]
 pub static AiDotChartOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDotChartOutlined",
+    label: "Dot Chart Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3700,8 +5278,15 @@ pub static AiDotChartOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M888 792H200V168c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v688c0 4.4 3.6 8 8 8h752c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM288 604a64 64 0 1 0 128 0 64 64 0 1 0-128 0zm118-224a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm158 228a96 96 0 1 0 192 0 96 96 0 1 0-192 0zm148-314a56 56 0 1 0 112 0 56 56 0 1 0-112 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDoubleLeftOutlined, "Double Left Outlined"))]
 pub static AiDoubleLeftOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDoubleLeftOutlined",
+    label: "Double Left Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3716,8 +5301,15 @@ pub static AiDoubleLeftOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M272.9 512l265.4-339.1c4.1-5.2.4-12.9-6.3-12.9h-77.3c-4.9 0-9.6 2.3-12.6 6.1L186.8 492.3a31.99 31.99 0 0 0 0 39.5l255.3 326.1c3 3.9 7.7 6.1 12.6 6.1H532c6.7 0 10.4-7.7 6.3-12.9L272.9 512zm304 0l265.4-339.1c4.1-5.2.4-12.9-6.3-12.9h-77.3c-4.9 0-9.6 2.3-12.6 6.1L490.8 492.3a31.99 31.99 0 0 0 0 39.5l255.3 326.1c3 3.9 7.7 6.1 12.6 6.1H836c6.7 0 10.4-7.7 6.3-12.9L576.9 512z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDoubleRightOutlined, "Double Right Outlined"))]
 pub static AiDoubleRightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDoubleRightOutlined",
+    label: "Double Right Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3732,8 +5324,15 @@ pub static AiDoubleRightOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M533.2 492.3L277.9 166.1c-3-3.9-7.7-6.1-12.6-6.1H188c-6.7 0-10.4 7.7-6.3 12.9L447.1 512 181.7 851.1A7.98 7.98 0 0 0 188 864h77.3c4.9 0 9.6-2.3 12.6-6.1l255.3-326.1c9.1-11.7 9.1-27.9 0-39.5zm304 0L581.9 166.1c-3-3.9-7.7-6.1-12.6-6.1H492c-6.7 0-10.4 7.7-6.3 12.9L751.1 512 485.7 851.1A7.98 7.98 0 0 0 492 864h77.3c4.9 0 9.6-2.3 12.6-6.1l255.3-326.1c9.1-11.7 9.1-27.9 0-39.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDownCircleFilled, "Down Circle Filled"))]
 pub static AiDownCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDownCircleFilled",
+    label: "Down Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3748,8 +5347,15 @@ pub static AiDownCircleFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm184.5 353.7l-178 246a7.95 7.95 0 0 1-12.9 0l-178-246c-3.8-5.3 0-12.7 6.5-12.7H381c10.2 0 19.9 4.9 25.9 13.2L512 563.6l105.2-145.4c6-8.3 15.6-13.2 25.9-13.2H690c6.5 0 10.3 7.4 6.5 12.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDownCircleOutlined, "Down Circle Outlined"))]
 pub static AiDownCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDownCircleOutlined",
+    label: "Down Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3765,8 +5371,15 @@ pub static AiDownCircleOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDownCircleTwotone, "Down Circle Twotone"))]
 pub static AiDownCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDownCircleTwotone",
+    label: "Down Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3783,8 +5396,15 @@ pub static AiDownCircleTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M690 405h-46.9c-10.2 0-19.9 4.9-25.9 13.2L512 563.6 406.8 418.2c-6-8.3-15.6-13.2-25.9-13.2H334c-6.5 0-10.3 7.4-6.5 12.7l178 246c3.2 4.4 9.7 4.4 12.9 0l178-246c3.9-5.3.1-12.7-6.4-12.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDownOutlined, "Down Outlined"))]
 pub static AiDownOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDownOutlined",
+    label: "Down Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3799,8 +5419,15 @@ pub static AiDownOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M884 256h-75c-5.1 0-9.9 2.5-12.9 6.6L512 654.2 227.9 262.6c-3-4.1-7.8-6.6-12.9-6.6h-75c-6.5 0-10.3 7.4-6.5 12.7l352.6 486.1c12.8 17.6 39 17.6 51.7 0l352.6-486.1c3.9-5.3.1-12.7-6.4-12.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDownSquareFilled, "Down Square Filled"))]
 pub static AiDownSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDownSquareFilled",
+    label: "Down Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3815,8 +5442,15 @@ pub static AiDownSquareFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM696.5 412.7l-178 246a7.95 7.95 0 0 1-12.9 0l-178-246c-3.8-5.3 0-12.7 6.5-12.7H381c10.2 0 19.9 4.9 25.9 13.2L512 558.6l105.2-145.4c6-8.3 15.6-13.2 25.9-13.2H690c6.5 0 10.3 7.4 6.5 12.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDownSquareOutlined, "Down Square Outlined"))]
 pub static AiDownSquareOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDownSquareOutlined",
+    label: "Down Square Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3832,8 +5466,15 @@ pub static AiDownSquareOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDownSquareTwotone, "Down Square Twotone"))]
 pub static AiDownSquareTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDownSquareTwotone",
+    label: "Down Square Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3850,8 +5491,15 @@ pub static AiDownSquareTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M505.5 658.7c3.2 4.4 9.7 4.4 12.9 0l178-246c3.9-5.3.1-12.7-6.4-12.7h-46.9c-10.2 0-19.9 4.9-25.9 13.2L512 558.6 406.8 413.2c-6-8.3-15.6-13.2-25.9-13.2H334c-6.5 0-10.3 7.4-6.5 12.7l178 246z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDownloadOutlined, "Download Outlined"))]
 pub static AiDownloadOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDownloadOutlined",
+    label: "Download Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3866,8 +5514,15 @@ pub static AiDownloadOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M505.7 661a8 8 0 0 0 12.6 0l112-141.7c4.1-5.2.4-12.9-6.3-12.9h-74.1V168c0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8v338.3H400c-6.7 0-10.4 7.7-6.3 12.9l112 141.8zM878 626h-60c-4.4 0-8 3.6-8 8v154H214V634c0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8v198c0 17.7 14.3 32 32 32h684c17.7 0 32-14.3 32-32V634c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDragOutlined, "Drag Outlined"))]
 pub static AiDragOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDragOutlined",
+    label: "Drag Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3882,8 +5537,15 @@ pub static AiDragOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M909.3 506.3L781.7 405.6a7.23 7.23 0 0 0-11.7 5.7V476H548V254h64.8c6 0 9.4-7 5.7-11.7L517.7 114.7a7.14 7.14 0 0 0-11.3 0L405.6 242.3a7.23 7.23 0 0 0 5.7 11.7H476v222H254v-64.8c0-6-7-9.4-11.7-5.7L114.7 506.3a7.14 7.14 0 0 0 0 11.3l127.5 100.8c4.7 3.7 11.7.4 11.7-5.7V548h222v222h-64.8c-6 0-9.4 7-5.7 11.7l100.8 127.5c2.9 3.7 8.5 3.7 11.3 0l100.8-127.5c3.7-4.7.4-11.7-5.7-11.7H548V548h222v64.8c0 6 7 9.4 11.7 5.7l127.5-100.8a7.3 7.3 0 0 0 .1-11.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDribbbleCircleFilled, "Dribbble Circle Filled"))]
 pub static AiDribbbleCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDribbbleCircleFilled",
+    label: "Dribbble Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3898,8 +5560,15 @@ pub static AiDribbbleCircleFilled: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M675.1 328.3a245.2 245.2 0 0 0-220.8-55.1c6.8 9.1 51.5 69.9 91.8 144 87.5-32.8 124.5-82.6 129-88.9zM554 552.8c-138.7 48.3-188.6 144.6-193 153.6 41.7 32.5 94.1 51.9 151 51.9 34.1 0 66.6-6.9 96.1-19.5-3.7-21.6-17.9-96.8-52.5-186.6l-1.6.6zm47.7-11.9c32.2 88.4 45.3 160.4 47.8 175.4 55.2-37.3 94.5-96.4 105.4-164.9-8.4-2.6-76.1-22.8-153.2-10.5zM512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 736c-158.8 0-288-129.2-288-288s129.2-288 288-288 288 129.2 288 288-129.2 288-288 288zm53.1-346.2c5.7 11.7 11.2 23.6 16.3 35.6 1.8 4.2 3.6 8.4 5.3 12.7 81.8-10.3 163.2 6.2 171.3 7.9-.5-58.1-21.3-111.4-55.5-153.3-5.3 7.1-46.5 60-137.4 97.1zM498.6 432c-40.8-72.5-84.7-133.4-91.2-142.3-68.8 32.5-120.3 95.9-136.2 172.2 11 .2 112.4.7 227.4-29.9zm30.6 82.5c3.2-1 6.4-2 9.7-2.9-6.2-14-12.9-28-19.9-41.7-122.8 36.8-242.1 35.2-252.8 35-.1 2.5-.1 5-.1 7.5 0 63.2 23.9 120.9 63.2 164.5 5.5-9.6 73-121.4 199.9-162.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDribbbleOutlined, "Dribbble Outlined"))]
 pub static AiDribbbleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDribbbleOutlined",
+    label: "Dribbble Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3914,8 +5583,15 @@ pub static AiDribbbleOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M512 96C282.6 96 96 282.6 96 512s186.6 416 416 416 416-186.6 416-416S741.4 96 512 96zm275.1 191.8c49.5 60.5 79.5 137.5 80.2 221.4-11.7-2.5-129.2-26.3-247.4-11.4-2.5-6.1-5-12.2-7.6-18.3-7.4-17.3-15.3-34.6-23.6-51.5C720 374.3 779.6 298 787.1 287.8zM512 157.2c90.3 0 172.8 33.9 235.5 89.5-6.4 9.1-59.9 81-186.2 128.4-58.2-107-122.7-194.8-132.6-208 27.3-6.6 55.2-9.9 83.3-9.9zM360.9 191c9.4 12.8 72.9 100.9 131.7 205.5C326.4 440.6 180 440 164.1 439.8c23.1-110.3 97.4-201.9 196.8-248.8zM156.7 512.5c0-3.6.1-7.3.2-10.9 15.5.3 187.7 2.5 365.2-50.6 10.2 19.9 19.9 40.1 28.8 60.3-4.7 1.3-9.4 2.7-14 4.2C353.6 574.9 256.1 736.4 248 750.1c-56.7-63-91.3-146.3-91.3-237.6zM512 867.8c-82.2 0-157.9-28-218.1-75 6.4-13.1 78.3-152 278.7-221.9l2.3-.8c49.9 129.6 70.5 238.3 75.8 269.5A350.46 350.46 0 0 1 512 867.8zm198.5-60.7c-3.6-21.6-22.5-125.6-69-253.3C752.9 536 850.7 565.2 862.8 569c-15.8 98.8-72.5 184.2-152.3 238.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDribbbleSquareFilled, "Dribbble Square Filled"))]
 pub static AiDribbbleSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDribbbleSquareFilled",
+    label: "Dribbble Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3930,8 +5606,15 @@ pub static AiDribbbleSquareFilled: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M498.6 432c-40.8-72.5-84.7-133.4-91.2-142.3-68.8 32.5-120.3 95.9-136.2 172.2 11 .2 112.4.7 227.4-29.9zm66.5 21.8c5.7 11.7 11.2 23.6 16.3 35.6 1.8 4.2 3.6 8.4 5.3 12.7 81.8-10.3 163.2 6.2 171.3 7.9-.5-58.1-21.3-111.4-55.5-153.3-5.3 7.1-46.5 60-137.4 97.1zM880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM512 800c-158.8 0-288-129.2-288-288s129.2-288 288-288 288 129.2 288 288-129.2 288-288 288zm89.7-259.1c32.2 88.4 45.3 160.4 47.8 175.4 55.2-37.3 94.5-96.4 105.4-164.9-8.4-2.6-76.1-22.8-153.2-10.5zm-72.5-26.4c3.2-1 6.4-2 9.7-2.9-6.2-14-12.9-28-19.9-41.7-122.8 36.8-242.1 35.2-252.8 35-.1 2.5-.1 5-.1 7.5 0 63.2 23.9 120.9 63.2 164.5 5.5-9.6 73-121.4 199.9-162.4zm145.9-186.2a245.2 245.2 0 0 0-220.8-55.1c6.8 9.1 51.5 69.9 91.8 144 87.5-32.8 124.5-82.6 129-88.9zM554 552.8c-138.7 48.3-188.6 144.6-193 153.6 41.7 32.5 94.1 51.9 151 51.9 34.1 0 66.6-6.9 96.1-19.5-3.7-21.6-17.9-96.8-52.5-186.6l-1.6.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDribbbleSquareOutlined, "Dribbble Square Outlined"))]
 pub static AiDribbbleSquareOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDribbbleSquareOutlined",
+    label: "Dribbble Square Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3946,8 +5629,15 @@ pub static AiDribbbleSquareOutlined: &icondata_core::IconData = &icondata_core::
     data: r###"<path d="M498.6 432c-40.8-72.5-84.7-133.4-91.2-142.3-68.8 32.5-120.3 95.9-136.2 172.2 11 .2 112.4.7 227.4-29.9zm66.5 21.8c5.7 11.7 11.2 23.6 16.3 35.6 1.8 4.2 3.6 8.4 5.3 12.7 81.8-10.3 163.2 6.2 171.3 7.9-.5-58.1-21.3-111.4-55.5-153.3-5.3 7.1-46.5 60-137.4 97.1zM880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM512 800c-158.8 0-288-129.2-288-288s129.2-288 288-288 288 129.2 288 288-129.2 288-288 288zm89.7-259.1c32.2 88.4 45.3 160.4 47.8 175.4 55.2-37.3 94.5-96.4 105.4-164.9-8.4-2.6-76.1-22.8-153.2-10.5zm-72.5-26.4c3.2-1 6.4-2 9.7-2.9-6.2-14-12.9-28-19.9-41.7-122.8 36.8-242.1 35.2-252.8 35-.1 2.5-.1 5-.1 7.5 0 63.2 23.9 120.9 63.2 164.5 5.5-9.6 73-121.4 199.9-162.4zm145.9-186.2a245.2 245.2 0 0 0-220.8-55.1c6.8 9.1 51.5 69.9 91.8 144 87.5-32.8 124.5-82.6 129-88.9zM554 552.8c-138.7 48.3-188.6 144.6-193 153.6 41.7 32.5 94.1 51.9 151 51.9 34.1 0 66.6-6.9 96.1-19.5-3.7-21.6-17.9-96.8-52.5-186.6l-1.6.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDropboxCircleFilled, "Dropbox Circle Filled"))]
 pub static AiDropboxCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDropboxCircleFilled",
+    label: "Dropbox Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3962,8 +5652,15 @@ pub static AiDropboxCircleFilled: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M663.8 455.5zm-151.5-93.8l-151.8 93.8 151.8 93.9 151.5-93.9zM512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm151.2 595.5L512.6 750l-151-90.5v-33.1l45.4 29.4 105.6-87.7 105.6 87.7 45.1-29.4v33.1zm-45.6-22.4l-105.3-87.7L407 637.1l-151-99.2 104.5-82.4L256 371.2 407 274l105.3 87.7L617.6 274 768 372.1l-104.2 83.5L768 539l-150.4 98.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDropboxOutlined, "Dropbox Outlined"))]
 pub static AiDropboxOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDropboxOutlined",
+    label: "Dropbox Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3978,8 +5675,15 @@ pub static AiDropboxOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M64 556.9l264.2 173.5L512.5 577 246.8 412.7zm896-290.3zm0 0L696.8 95 512.5 248.5l265.2 164.2L512.5 577l184.3 153.4L960 558.8 777.7 412.7zM513 609.8L328.2 763.3l-79.4-51.5v57.8L513 928l263.7-158.4v-57.8l-78.9 51.5zM328.2 95L64 265.1l182.8 147.6 265.7-164.2zM64 556.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiDropboxSquareFilled, "Dropbox Square Filled"))]
 pub static AiDropboxSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiDropboxSquareFilled",
+    label: "Dropbox Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3994,8 +5698,15 @@ pub static AiDropboxSquareFilled: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM663.2 659.5L512.6 750l-151-90.5v-33.1l45.4 29.4 105.6-87.7 105.6 87.7 45.1-29.4v33.1zm-45.6-22.4l-105.3-87.7L407 637.1l-151-99.2 104.5-82.4L256 371.2 407 274l105.3 87.7L617.6 274 768 372.1l-104.2 83.5L768 539l-150.4 98.1zM512.3 361.7l-151.8 93.8 151.8 93.9 151.5-93.9zm151.5 93.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEditFilled, "Edit Filled"))]
 pub static AiEditFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEditFilled",
+    label: "Edit Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4010,8 +5721,15 @@ pub static AiEditFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M880 836H144c-17.7 0-32 14.3-32 32v36c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-36c0-17.7-14.3-32-32-32zm-622.3-84c2 0 4-.2 6-.5L431.9 722c2-.4 3.9-1.3 5.3-2.8l423.9-423.9a9.96 9.96 0 0 0 0-14.1L694.9 114.9c-1.9-1.9-4.4-2.9-7.1-2.9s-5.2 1-7.1 2.9L256.8 538.8c-1.5 1.5-2.4 3.3-2.8 5.3l-29.5 168.2a33.5 33.5 0 0 0 9.4 29.8c6.6 6.4 14.9 9.9 23.8 9.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEditOutlined, "Edit Outlined"))]
 pub static AiEditOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEditOutlined",
+    label: "Edit Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4026,8 +5744,15 @@ pub static AiEditOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M257.7 752c2 0 4-.2 6-.5L431.9 722c2-.4 3.9-1.3 5.3-2.8l423.9-423.9a9.96 9.96 0 0 0 0-14.1L694.9 114.9c-1.9-1.9-4.4-2.9-7.1-2.9s-5.2 1-7.1 2.9L256.8 538.8c-1.5 1.5-2.4 3.3-2.8 5.3l-29.5 168.2a33.5 33.5 0 0 0 9.4 29.8c6.6 6.4 14.9 9.9 23.8 9.9zm67.4-174.4L687.8 215l73.3 73.3-362.7 362.6-88.9 15.7 15.6-89zM880 836H144c-17.7 0-32 14.3-32 32v36c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-36c0-17.7-14.3-32-32-32z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEditTwotone, "Edit Twotone"))]
 pub static AiEditTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEditTwotone",
+    label: "Edit Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4043,8 +5768,15 @@ pub static AiEditTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M880 836H144c-17.7 0-32 14.3-32 32v36c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-36c0-17.7-14.3-32-32-32zm-622.3-84c2 0 4-.2 6-.5L431.9 722c2-.4 3.9-1.3 5.3-2.8l423.9-423.9a9.96 9.96 0 0 0 0-14.1L694.9 114.9c-1.9-1.9-4.4-2.9-7.1-2.9s-5.2 1-7.1 2.9L256.8 538.8c-1.5 1.5-2.4 3.3-2.8 5.3l-29.5 168.2a33.5 33.5 0 0 0 9.4 29.8c6.6 6.4 14.9 9.9 23.8 9.9zm67.4-174.4L687.8 215l73.3 73.3-362.7 362.6-88.9 15.7 15.6-89z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEllipsisOutlined, "Ellipsis Outlined"))]
 pub static AiEllipsisOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEllipsisOutlined",
+    label: "Ellipsis Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4059,8 +5791,15 @@ pub static AiEllipsisOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M176 511a56 56 0 1 0 112 0 56 56 0 1 0-112 0zm280 0a56 56 0 1 0 112 0 56 56 0 1 0-112 0zm280 0a56 56 0 1 0 112 0 56 56 0 1 0-112 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEnterOutlined, "Enter Outlined"))]
 pub static AiEnterOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEnterOutlined",
+    label: "Enter Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4075,8 +5814,15 @@ pub static AiEnterOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M864 170h-60c-4.4 0-8 3.6-8 8v518H310v-73c0-6.7-7.8-10.5-13-6.3l-141.9 112a8 8 0 0 0 0 12.6l141.9 112c5.3 4.2 13 .4 13-6.3v-75h498c35.3 0 64-28.7 64-64V178c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEnvironmentFilled, "Environment Filled"))]
 pub static AiEnvironmentFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEnvironmentFilled",
+    label: "Environment Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4091,8 +5837,15 @@ pub static AiEnvironmentFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 327c-29.9 0-58 11.6-79.2 32.8A111.6 111.6 0 0 0 400 439c0 29.9 11.7 58 32.8 79.2A111.6 111.6 0 0 0 512 551c29.9 0 58-11.7 79.2-32.8C612.4 497 624 468.9 624 439c0-29.9-11.6-58-32.8-79.2S541.9 327 512 327zm342.6-37.9a362.49 362.49 0 0 0-79.9-115.7 370.83 370.83 0 0 0-118.2-77.8C610.7 76.6 562.1 67 512 67c-50.1 0-98.7 9.6-144.5 28.5-44.3 18.3-84 44.5-118.2 77.8A363.6 363.6 0 0 0 169.4 289c-19.5 45-29.4 92.8-29.4 142 0 70.6 16.9 140.9 50.1 208.7 26.7 54.5 64 107.6 111 158.1 80.3 86.2 164.5 138.9 188.4 153a43.9 43.9 0 0 0 22.4 6.1c7.8 0 15.5-2 22.4-6.1 23.9-14.1 108.1-66.8 188.4-153 47-50.4 84.3-103.6 111-158.1C867.1 572 884 501.8 884 431.1c0-49.2-9.9-97-29.4-142zM512 615c-97.2 0-176-78.8-176-176s78.8-176 176-176 176 78.8 176 176-78.8 176-176 176z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEnvironmentOutlined, "Environment Outlined"))]
 pub static AiEnvironmentOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEnvironmentOutlined",
+    label: "Environment Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4107,8 +5860,15 @@ pub static AiEnvironmentOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M854.6 289.1a362.49 362.49 0 0 0-79.9-115.7 370.83 370.83 0 0 0-118.2-77.8C610.7 76.6 562.1 67 512 67c-50.1 0-98.7 9.6-144.5 28.5-44.3 18.3-84 44.5-118.2 77.8A363.6 363.6 0 0 0 169.4 289c-19.5 45-29.4 92.8-29.4 142 0 70.6 16.9 140.9 50.1 208.7 26.7 54.5 64 107.6 111 158.1 80.3 86.2 164.5 138.9 188.4 153a43.9 43.9 0 0 0 22.4 6.1c7.8 0 15.5-2 22.4-6.1 23.9-14.1 108.1-66.8 188.4-153 47-50.4 84.3-103.6 111-158.1C867.1 572 884 501.8 884 431.1c0-49.2-9.9-97-29.4-142zM512 880.2c-65.9-41.9-300-207.8-300-449.1 0-77.9 31.1-151.1 87.6-206.3C356.3 169.5 431.7 139 512 139s155.7 30.5 212.4 85.9C780.9 280 812 353.2 812 431.1c0 241.3-234.1 407.2-300 449.1zm0-617.2c-97.2 0-176 78.8-176 176s78.8 176 176 176 176-78.8 176-176-78.8-176-176-176zm79.2 255.2A111.6 111.6 0 0 1 512 551c-29.9 0-58-11.7-79.2-32.8A111.6 111.6 0 0 1 400 439c0-29.9 11.7-58 32.8-79.2C454 338.6 482.1 327 512 327c29.9 0 58 11.6 79.2 32.8C612.4 381 624 409.1 624 439c0 29.9-11.6 58-32.8 79.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEnvironmentTwotone, "Environment Twotone"))]
 pub static AiEnvironmentTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEnvironmentTwotone",
+    label: "Environment Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4125,8 +5885,15 @@ pub static AiEnvironmentTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M854.6 289.1a362.49 362.49 0 0 0-79.9-115.7 370.83 370.83 0 0 0-118.2-77.8C610.7 76.6 562.1 67 512 67c-50.1 0-98.7 9.6-144.5 28.5-44.3 18.3-84 44.5-118.2 77.8A363.6 363.6 0 0 0 169.4 289c-19.5 45-29.4 92.8-29.4 142 0 70.6 16.9 140.9 50.1 208.7 26.7 54.5 64 107.6 111 158.1 80.3 86.2 164.5 138.9 188.4 153a43.9 43.9 0 0 0 22.4 6.1c7.8 0 15.5-2 22.4-6.1 23.9-14.1 108.1-66.8 188.4-153 47-50.4 84.3-103.6 111-158.1C867.1 572 884 501.8 884 431.1c0-49.2-9.9-97-29.4-142zM512 880.2c-65.9-41.9-300-207.8-300-449.1 0-77.9 31.1-151.1 87.6-206.3C356.3 169.5 431.7 139 512 139s155.7 30.5 212.4 85.9C780.9 280 812 353.2 812 431.1c0 241.3-234.1 407.2-300 449.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEuroCircleFilled, "Euro Circle Filled"))]
 pub static AiEuroCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEuroCircleFilled",
+    label: "Euro Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4141,8 +5908,15 @@ pub static AiEuroCircleFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm63.5 375.8c4.4 0 8 3.6 8 8V475c0 4.4-3.6 8-8 8h-136c-.3 4.4-.3 9.1-.3 13.8v36h136.2c4.4 0 8 3.6 8 8V568c0 4.4-3.6 8-8 8H444.9c15.3 62 61.3 98.6 129.8 98.6 19.9 0 37.1-1.2 51.8-4.1 4.9-1 9.5 2.8 9.5 7.8v42.8c0 3.8-2.7 7-6.4 7.8-15.9 3.4-34.3 5.1-55.3 5.1-109.8 0-183-58.8-200.2-158H344c-4.4 0-8-3.6-8-8v-27.2c0-4.4 3.6-8 8-8h26.1v-36.9c0-4.4 0-8.8.3-12.8H344c-4.4 0-8-3.6-8-8v-27.2c0-4.4 3.6-8 8-8h31.7c19.7-94.2 92-149.9 198.6-149.9 20.9 0 39.4 1.9 55.3 5.4 3.7.8 6.3 4 6.3 7.8V346h.1c0 5.1-4.6 8.8-9.6 7.8-14.7-2.9-31.8-4.4-51.7-4.4-65.4 0-110.4 33.5-127.6 90.4h128.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEuroCircleOutlined, "Euro Circle Outlined"))]
 pub static AiEuroCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEuroCircleOutlined",
+    label: "Euro Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4157,8 +5931,15 @@ pub static AiEuroCircleOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm117.7-588.6c-15.9-3.5-34.4-5.4-55.3-5.4-106.7 0-178.9 55.7-198.6 149.9H344c-4.4 0-8 3.6-8 8v27.2c0 4.4 3.6 8 8 8h26.4c-.3 4.1-.3 8.4-.3 12.8v36.9H344c-4.4 0-8 3.6-8 8V568c0 4.4 3.6 8 8 8h30.2c17.2 99.2 90.4 158 200.2 158 20.9 0 39.4-1.7 55.3-5.1 3.7-.8 6.4-4 6.4-7.8v-42.8c0-5-4.6-8.8-9.5-7.8-14.7 2.8-31.9 4.1-51.8 4.1-68.5 0-114.5-36.6-129.8-98.6h130.6c4.4 0 8-3.6 8-8v-27.2c0-4.4-3.6-8-8-8H439.2v-36c0-4.7 0-9.4.3-13.8h135.9c4.4 0 8-3.6 8-8v-27.2c0-4.4-3.6-8-8-8H447.1c17.2-56.9 62.3-90.4 127.6-90.4 19.9 0 37.1 1.5 51.7 4.4a8 8 0 0 0 9.6-7.8v-42.8c0-3.8-2.6-7-6.3-7.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEuroCircleTwotone, "Euro Circle Twotone"))]
 pub static AiEuroCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEuroCircleTwotone",
+    label: "Euro Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4175,8 +5956,15 @@ pub static AiEuroCircleTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M619.6 670.5c-14.7 2.8-31.9 4.1-51.8 4.1-68.5 0-114.5-36.6-129.8-98.6h130.6c4.4 0 8-3.6 8-8v-27.2c0-4.4-3.6-8-8-8H432.2v-36c0-4.7 0-9.4.3-13.8h135.9c4.4 0 8-3.6 8-8v-27.2c0-4.4-3.6-8-8-8H440.1c17.2-56.9 62.3-90.4 127.6-90.4 19.9 0 37.1 1.5 51.7 4.4a8 8 0 0 0 9.6-7.8v-42.8c0-3.8-2.6-7-6.3-7.8-15.9-3.5-34.4-5.4-55.3-5.4-106.7 0-178.9 55.7-198.6 149.9H337c-4.4 0-8 3.6-8 8v27.2c0 4.4 3.6 8 8 8h26.4c-.3 4.1-.3 8.4-.3 12.8v36.9H337c-4.4 0-8 3.6-8 8V568c0 4.4 3.6 8 8 8h30.2c17.2 99.2 90.4 158 200.2 158 20.9 0 39.4-1.7 55.3-5.1 3.7-.8 6.4-4 6.4-7.8v-42.8c0-5-4.6-8.8-9.5-7.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEuroOutlined, "Euro Outlined"))]
 pub static AiEuroOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEuroOutlined",
+    label: "Euro Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4191,8 +5979,15 @@ pub static AiEuroOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm117.7-588.6c-15.9-3.5-34.4-5.4-55.3-5.4-106.7 0-178.9 55.7-198.6 149.9H344c-4.4 0-8 3.6-8 8v27.2c0 4.4 3.6 8 8 8h26.4c-.3 4.1-.3 8.4-.3 12.8v36.9H344c-4.4 0-8 3.6-8 8V568c0 4.4 3.6 8 8 8h30.2c17.2 99.2 90.4 158 200.2 158 20.9 0 39.4-1.7 55.3-5.1 3.7-.8 6.4-4 6.4-7.8v-42.8c0-5-4.6-8.8-9.5-7.8-14.7 2.8-31.9 4.1-51.8 4.1-68.5 0-114.5-36.6-129.8-98.6h130.6c4.4 0 8-3.6 8-8v-27.2c0-4.4-3.6-8-8-8H439.2v-36c0-4.7 0-9.4.3-13.8h135.9c4.4 0 8-3.6 8-8v-27.2c0-4.4-3.6-8-8-8H447.1c17.2-56.9 62.3-90.4 127.6-90.4 19.9 0 37.1 1.5 51.7 4.4a8 8 0 0 0 9.6-7.8v-42.8c0-3.8-2.6-7-6.3-7.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEuroTwotone, "Euro Twotone"))]
 pub static AiEuroTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEuroTwotone",
+    label: "Euro Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4209,8 +6004,15 @@ pub static AiEuroTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M619.6 670.5c-14.7 2.8-31.9 4.1-51.8 4.1-68.5 0-114.5-36.6-129.8-98.6h130.6c4.4 0 8-3.6 8-8v-27.2c0-4.4-3.6-8-8-8H432.2v-36c0-4.7 0-9.4.3-13.8h135.9c4.4 0 8-3.6 8-8v-27.2c0-4.4-3.6-8-8-8H440.1c17.2-56.9 62.3-90.4 127.6-90.4 19.9 0 37.1 1.5 51.7 4.4a8 8 0 0 0 9.6-7.8v-42.8c0-3.8-2.6-7-6.3-7.8-15.9-3.5-34.4-5.4-55.3-5.4-106.7 0-178.9 55.7-198.6 149.9H337c-4.4 0-8 3.6-8 8v27.2c0 4.4 3.6 8 8 8h26.4c-.3 4.1-.3 8.4-.3 12.8v36.9H337c-4.4 0-8 3.6-8 8V568c0 4.4 3.6 8 8 8h30.2c17.2 99.2 90.4 158 200.2 158 20.9 0 39.4-1.7 55.3-5.1 3.7-.8 6.4-4 6.4-7.8v-42.8c0-5-4.6-8.8-9.5-7.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiExceptionOutlined, "Exception Outlined"))]
 pub static AiExceptionOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiExceptionOutlined",
+    label: "Exception Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4225,8 +6027,15 @@ pub static AiExceptionOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M688 312v-48c0-4.4-3.6-8-8-8H296c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h384c4.4 0 8-3.6 8-8zm-392 88c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H296zm376 116c-119.3 0-216 96.7-216 216s96.7 216 216 216 216-96.7 216-216-96.7-216-216-216zm107.5 323.5C750.8 868.2 712.6 884 672 884s-78.8-15.8-107.5-44.5C535.8 810.8 520 772.6 520 732s15.8-78.8 44.5-107.5C593.2 595.8 631.4 580 672 580s78.8 15.8 107.5 44.5C808.2 653.2 824 691.4 824 732s-15.8 78.8-44.5 107.5zM640 812a32 32 0 1 0 64 0 32 32 0 1 0-64 0zm12-64h40c4.4 0 8-3.6 8-8V628c0-4.4-3.6-8-8-8h-40c-4.4 0-8 3.6-8 8v112c0 4.4 3.6 8 8 8zM440 852H208V148h560v344c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V108c0-17.7-14.3-32-32-32H168c-17.7 0-32 14.3-32 32v784c0 17.7 14.3 32 32 32h272c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiExclamationCircleFilled, "Exclamation Circle Filled"))]
 pub static AiExclamationCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiExclamationCircleFilled",
+    label: "Exclamation Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4241,8 +6050,15 @@ pub static AiExclamationCircleFilled: &icondata_core::IconData = &icondata_core:
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm-32 232c0-4.4 3.6-8 8-8h48c4.4 0 8 3.6 8 8v272c0 4.4-3.6 8-8 8h-48c-4.4 0-8-3.6-8-8V296zm32 440a48.01 48.01 0 0 1 0-96 48.01 48.01 0 0 1 0 96z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiExclamationCircleOutlined, "Exclamation Circle Outlined"))]
 pub static AiExclamationCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiExclamationCircleOutlined",
+    label: "Exclamation Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4258,8 +6074,15 @@ pub static AiExclamationCircleOutlined: &icondata_core::IconData = &icondata_cor
 <path d="M464 688a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm24-112h48c4.4 0 8-3.6 8-8V296c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v272c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiExclamationCircleTwotone, "Exclamation Circle Twotone"))]
 pub static AiExclamationCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiExclamationCircleTwotone",
+    label: "Exclamation Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4276,8 +6099,15 @@ pub static AiExclamationCircleTwotone: &icondata_core::IconData = &icondata_core
 <path d="M488 576h48c4.4 0 8-3.6 8-8V296c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v272c0 4.4 3.6 8 8 8zm-24 112a48 48 0 1 0 96 0 48 48 0 1 0-96 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiExclamationOutlined, "Exclamation Outlined"))]
 pub static AiExclamationOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiExclamationOutlined",
+    label: "Exclamation Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4292,8 +6122,15 @@ pub static AiExclamationOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M448 804a64 64 0 1 0 128 0 64 64 0 1 0-128 0zm32-168h64c4.4 0 8-3.6 8-8V164c0-4.4-3.6-8-8-8h-64c-4.4 0-8 3.6-8 8v464c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiExpandAltOutlined, "Expand Alt Outlined"))]
 pub static AiExpandAltOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiExpandAltOutlined",
+    label: "Expand Alt Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4308,8 +6145,15 @@ pub static AiExpandAltOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M855 160.1l-189.2 23.5c-6.6.8-9.3 8.8-4.7 13.5l54.7 54.7-153.5 153.5a8.03 8.03 0 0 0 0 11.3l45.1 45.1c3.1 3.1 8.2 3.1 11.3 0l153.6-153.6 54.7 54.7a7.94 7.94 0 0 0 13.5-4.7L863.9 169a7.9 7.9 0 0 0-8.9-8.9zM416.6 562.3a8.03 8.03 0 0 0-11.3 0L251.8 715.9l-54.7-54.7a7.94 7.94 0 0 0-13.5 4.7L160.1 855c-.6 5.2 3.7 9.5 8.9 8.9l189.2-23.5c6.6-.8 9.3-8.8 4.7-13.5l-54.7-54.7 153.6-153.6c3.1-3.1 3.1-8.2 0-11.3l-45.2-45z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiExpandOutlined, "Expand Outlined"))]
 pub static AiExpandOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiExpandOutlined",
+    label: "Expand Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4327,8 +6171,15 @@ pub static AiExpandOutlined: &icondata_core::IconData = &icondata_core::IconData
 <path d="M342 88H120c-17.7 0-32 14.3-32 32v224c0 8.8 7.2 16 16 16h48c8.8 0 16-7.2 16-16V168h174c8.8 0 16-7.2 16-16v-48c0-8.8-7.2-16-16-16zM920 664h-48c-8.8 0-16 7.2-16 16v176H682c-8.8 0-16 7.2-16 16v48c0 8.8 7.2 16 16 16h222c17.7 0 32-14.3 32-32V680c0-8.8-7.2-16-16-16zM342 856H168V680c0-8.8-7.2-16-16-16h-48c-8.8 0-16 7.2-16 16v224c0 17.7 14.3 32 32 32h222c8.8 0 16-7.2 16-16v-48c0-8.8-7.2-16-16-16zM904 88H682c-8.8 0-16 7.2-16 16v48c0 8.8 7.2 16 16 16h174v176c0 8.8 7.2 16 16 16h48c8.8 0 16-7.2 16-16V120c0-17.7-14.3-32-32-32z" p-id="13543" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiExperimentFilled, "Experiment Filled"))]
 pub static AiExperimentFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiExperimentFilled",
+    label: "Experiment Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4343,8 +6194,15 @@ pub static AiExperimentFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M218.9 636.3l42.6 26.6c.1.1.3.2.4.3l12.7 8 .3.3a186.9 186.9 0 0 0 94.1 25.1c44.9 0 87.2-15.7 121-43.8a256.27 256.27 0 0 1 164.9-59.9c52.3 0 102.2 15.7 144.6 44.5l7.9 5-111.6-289V179.8h63.5c4.4 0 8-3.6 8-8V120c0-4.4-3.6-8-8-8H264.7c-4.4 0-8 3.6-8 8v51.9c0 4.4 3.6 8 8 8h63.5v173.6L218.9 636.3zm333-203.1c22 0 39.9 17.9 39.9 39.9S573.9 513 551.9 513 512 495.1 512 473.1s17.9-39.9 39.9-39.9zM878 825.1l-29.9-77.4-85.7-53.5-.1.1c-.7-.5-1.5-1-2.2-1.5l-8.1-5-.3-.3c-29-17.5-62.3-26.8-97-26.8-44.9 0-87.2 15.7-121 43.8a256.27 256.27 0 0 1-164.9 59.9c-53 0-103.5-16.1-146.2-45.6l-28.9-18.1L146 825.1c-2.8 7.4-4.3 15.2-4.3 23 0 35.2 28.6 63.8 63.8 63.8h612.9c7.9 0 15.7-1.5 23-4.3a63.6 63.6 0 0 0 36.6-82.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiExperimentOutlined, "Experiment Outlined"))]
 pub static AiExperimentOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiExperimentOutlined",
+    label: "Experiment Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4359,8 +6217,15 @@ pub static AiExperimentOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M512 472a40 40 0 1 0 80 0 40 40 0 1 0-80 0zm367 352.9L696.3 352V178H768v-68H256v68h71.7v174L145 824.9c-2.8 7.4-4.3 15.2-4.3 23.1 0 35.3 28.7 64 64 64h614.6c7.9 0 15.7-1.5 23.1-4.3 33-12.7 49.4-49.8 36.6-82.8zM395.7 364.7V180h232.6v184.7L719.2 600c-20.7-5.3-42.1-8-63.9-8-61.2 0-119.2 21.5-165.3 60a188.78 188.78 0 0 1-121.3 43.9c-32.7 0-64.1-8.3-91.8-23.7l118.8-307.5zM210.5 844l41.7-107.8c35.7 18.1 75.4 27.8 116.6 27.8 61.2 0 119.2-21.5 165.3-60 33.9-28.2 76.3-43.9 121.3-43.9 35 0 68.4 9.5 97.6 27.1L813.5 844h-603z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiExperimentTwotone, "Experiment Twotone"))]
 pub static AiExperimentTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiExperimentTwotone",
+    label: "Experiment Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4377,8 +6242,15 @@ pub static AiExperimentTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M552 512c19.3 0 35.4-13.6 39.2-31.8.6-2.7.8-5.4.8-8.2 0-22.1-17.9-40-40-40s-40 17.9-40 40v.6a40 40 0 0 0 40 39.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiExportOutlined, "Export Outlined"))]
 pub static AiExportOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiExportOutlined",
+    label: "Export Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4393,8 +6265,15 @@ pub static AiExportOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h360c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H184V184h656v320c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V144c0-17.7-14.3-32-32-32ZM770.87 824.869l-52.2 52.2c-4.7 4.7-1.9 12.8 4.7 13.6l179.4 21c5.1.6 9.5-3.7 8.9-8.9l-21-179.4c-.8-6.6-8.9-9.4-13.6-4.7l-52.4 52.4-256.2-256.2c-3.1-3.1-8.2-3.1-11.3 0l-42.4 42.4c-3.1 3.1-3.1 8.2 0 11.3l256.1 256.3Z" transform="matrix(1 0 0 -1 0 1024)" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEyeFilled, "Eye Filled"))]
 pub static AiEyeFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEyeFilled",
+    label: "Eye Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4409,8 +6288,15 @@ pub static AiEyeFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M396 512a112 112 0 1 0 224 0 112 112 0 1 0-224 0zm546.2-25.8C847.4 286.5 704.1 186 512 186c-192.2 0-335.4 100.5-430.2 300.3a60.3 60.3 0 0 0 0 51.5C176.6 737.5 319.9 838 512 838c192.2 0 335.4-100.5 430.2-300.3 7.7-16.2 7.7-35 0-51.5zM508 688c-97.2 0-176-78.8-176-176s78.8-176 176-176 176 78.8 176 176-78.8 176-176 176z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEyeInvisibleFilled, "Eye Invisible Filled"))]
 pub static AiEyeInvisibleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEyeInvisibleFilled",
+    label: "Eye Invisible Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4431,8 +6317,15 @@ pub static AiEyeInvisibleFilled: &icondata_core::IconData = &icondata_core::Icon
 <path d="M942.2 486.2Q889.4 375 816.51 304.85L672.37 449A176.08 176.08 0 0 1 445 676.37L322.74 798.63Q407.82 838 512 838q288.3 0 430.2-300.3a60.29 60.29 0 0 0 0-51.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEyeInvisibleOutlined, "Eye Invisible Outlined"))]
 pub static AiEyeInvisibleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEyeInvisibleOutlined",
+    label: "Eye Invisible Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4448,8 +6341,15 @@ pub static AiEyeInvisibleOutlined: &icondata_core::IconData = &icondata_core::Ic
 <path d="M508 624c-3.46 0-6.87-.16-10.25-.47l-52.82 52.82a176.09 176.09 0 0 0 227.42-227.42l-52.82 52.82c.31 3.38.47 6.79.47 10.25a111.94 111.94 0 0 1-112 112z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEyeInvisibleTwotone, "Eye Invisible Twotone"))]
 pub static AiEyeInvisibleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEyeInvisibleTwotone",
+    label: "Eye Invisible Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4468,8 +6368,15 @@ pub static AiEyeInvisibleTwotone: &icondata_core::IconData = &icondata_core::Ico
 <path d="M508,624c-3.46,0-6.87-.16-10.25-.47l-52.82,52.82A176.09,176.09,0,0,0,672.35,448.93l-52.82,52.82c.31,3.38.47,6.79.47,10.25A111.94,111.94,0,0,1,508,624Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEyeOutlined, "Eye Outlined"))]
 pub static AiEyeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEyeOutlined",
+    label: "Eye Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4484,8 +6391,15 @@ pub static AiEyeOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M942.2 486.2C847.4 286.5 704.1 186 512 186c-192.2 0-335.4 100.5-430.2 300.3a60.3 60.3 0 0 0 0 51.5C176.6 737.5 319.9 838 512 838c192.2 0 335.4-100.5 430.2-300.3 7.7-16.2 7.7-35 0-51.5zM512 766c-161.3 0-279.4-81.8-362.7-254C232.6 339.8 350.7 258 512 258c161.3 0 279.4 81.8 362.7 254C791.5 684.2 673.4 766 512 766zm-4-430c-97.2 0-176 78.8-176 176s78.8 176 176 176 176-78.8 176-176-78.8-176-176-176zm0 288c-61.9 0-112-50.1-112-112s50.1-112 112-112 112 50.1 112 112-50.1 112-112 112z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiEyeTwotone, "Eye Twotone"))]
 pub static AiEyeTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiEyeTwotone",
+    label: "Eye Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4503,8 +6417,15 @@ pub static AiEyeTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M508 336c-97.2 0-176 78.8-176 176s78.8 176 176 176 176-78.8 176-176-78.8-176-176-176zm0 288c-61.9 0-112-50.1-112-112s50.1-112 112-112 112 50.1 112 112-50.1 112-112 112z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFacebookFilled, "Facebook Filled"))]
 pub static AiFacebookFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFacebookFilled",
+    label: "Facebook Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4519,8 +6440,15 @@ pub static AiFacebookFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-92.4 233.5h-63.9c-50.1 0-59.8 23.8-59.8 58.8v77.1h119.6l-15.6 120.7h-104V912H539.2V602.2H434.9V481.4h104.3v-89c0-103.3 63.1-159.6 155.3-159.6 44.2 0 82.1 3.3 93.2 4.8v107.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFacebookOutlined, "Facebook Outlined"))]
 pub static AiFacebookOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFacebookOutlined",
+    label: "Facebook Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4535,8 +6463,15 @@ pub static AiFacebookOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-32 736H663.9V602.2h104l15.6-120.7H663.9v-77.1c0-35 9.7-58.8 59.8-58.8h63.9v-108c-11.1-1.5-49-4.8-93.2-4.8-92.2 0-155.3 56.3-155.3 159.6v89H434.9v120.7h104.3V848H176V176h672v672z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFallOutlined, "Fall Outlined"))]
 pub static AiFallOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFallOutlined",
+    label: "Fall Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4551,8 +6486,15 @@ pub static AiFallOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M925.9 804l-24-199.2c-.8-6.6-8.9-9.4-13.6-4.7L829 659.5 557.7 388.3c-6.3-6.2-16.4-6.2-22.6 0L433.3 490 156.6 213.3a8.03 8.03 0 0 0-11.3 0l-45 45.2a8.03 8.03 0 0 0 0 11.3L422 591.7c6.2 6.3 16.4 6.3 22.6 0L546.4 490l226.1 226-59.3 59.3a8.01 8.01 0 0 0 4.7 13.6l199.2 24c5.1.7 9.5-3.7 8.8-8.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFastBackwardFilled, "Fast Backward Filled"))]
 pub static AiFastBackwardFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFastBackwardFilled",
+    label: "Fast Backward Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4567,8 +6509,15 @@ pub static AiFastBackwardFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M517.6 273.5L230.2 499.3a16.14 16.14 0 0 0 0 25.4l287.4 225.8c10.7 8.4 26.4.8 26.4-12.7V286.2c0-13.5-15.7-21.1-26.4-12.7zm320 0L550.2 499.3a16.14 16.14 0 0 0 0 25.4l287.4 225.8c10.7 8.4 26.4.8 26.4-12.7V286.2c0-13.5-15.7-21.1-26.4-12.7zm-620-25.5h-51.2c-3.5 0-6.4 2.7-6.4 6v516c0 3.3 2.9 6 6.4 6h51.2c3.5 0 6.4-2.7 6.4-6V254c0-3.3-2.9-6-6.4-6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFastBackwardOutlined, "Fast Backward Outlined"))]
 pub static AiFastBackwardOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFastBackwardOutlined",
+    label: "Fast Backward Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4583,8 +6532,15 @@ pub static AiFastBackwardOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M517.6 273.5L230.2 499.3a16.14 16.14 0 0 0 0 25.4l287.4 225.8c10.7 8.4 26.4.8 26.4-12.7V286.2c0-13.5-15.7-21.1-26.4-12.7zm320 0L550.2 499.3a16.14 16.14 0 0 0 0 25.4l287.4 225.8c10.7 8.4 26.4.8 26.4-12.7V286.2c0-13.5-15.7-21.1-26.4-12.7zm-620-25.5h-51.2c-3.5 0-6.4 2.7-6.4 6v516c0 3.3 2.9 6 6.4 6h51.2c3.5 0 6.4-2.7 6.4-6V254c0-3.3-2.9-6-6.4-6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFastForwardFilled, "Fast Forward Filled"))]
 pub static AiFastForwardFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFastForwardFilled",
+    label: "Fast Forward Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4599,8 +6555,15 @@ pub static AiFastForwardFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M793.8 499.3L506.4 273.5c-10.7-8.4-26.4-.8-26.4 12.7v451.6c0 13.5 15.7 21.1 26.4 12.7l287.4-225.8a16.14 16.14 0 0 0 0-25.4zm-320 0L186.4 273.5c-10.7-8.4-26.4-.8-26.4 12.7v451.5c0 13.5 15.7 21.1 26.4 12.7l287.4-225.8c4.1-3.2 6.2-8 6.2-12.7 0-4.6-2.1-9.4-6.2-12.6zM857.6 248h-51.2c-3.5 0-6.4 2.7-6.4 6v516c0 3.3 2.9 6 6.4 6h51.2c3.5 0 6.4-2.7 6.4-6V254c0-3.3-2.9-6-6.4-6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFastForwardOutlined, "Fast Forward Outlined"))]
 pub static AiFastForwardOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFastForwardOutlined",
+    label: "Fast Forward Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4615,8 +6578,15 @@ pub static AiFastForwardOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M793.8 499.3L506.4 273.5c-10.7-8.4-26.4-.8-26.4 12.7v451.6c0 13.5 15.7 21.1 26.4 12.7l287.4-225.8a16.14 16.14 0 0 0 0-25.4zm-320 0L186.4 273.5c-10.7-8.4-26.4-.8-26.4 12.7v451.5c0 13.5 15.7 21.1 26.4 12.7l287.4-225.8c4.1-3.2 6.2-8 6.2-12.7 0-4.6-2.1-9.4-6.2-12.6zM857.6 248h-51.2c-3.5 0-6.4 2.7-6.4 6v516c0 3.3 2.9 6 6.4 6h51.2c3.5 0 6.4-2.7 6.4-6V254c0-3.3-2.9-6-6.4-6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFieldBinaryOutlined, "Field Binary Outlined"))]
 pub static AiFieldBinaryOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFieldBinaryOutlined",
+    label: "Field Binary Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4634,8 +6604,15 @@ pub static AiFieldBinaryOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M600 395.4h91V649h79V267c0-4.4-3.6-8-8-8h-48.2c-3.7 0-7 2.6-7.7 6.3-2.6 12.1-6.9 22.3-12.9 30.9-7.2 10.1-15.9 18.2-26.3 24.4-10.3 6.2-22 10.5-35 12.9-10.4 1.9-21 3-32 3.1-4.4 0.1-7.9 3.6-7.9 8v42.8c0 4.4 3.6 8 8 8zM871 702H567c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h304c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zM443.9 312.7c-16.1-19-34.4-32.4-55.2-40.4-21.3-8.2-44.1-12.3-68.4-12.3-23.9 0-46.4 4.1-67.7 12.3-20.8 8-39 21.4-54.8 40.3-15.9 19.1-28.7 44.7-38.3 77-9.6 32.5-14.5 73-14.5 121.5 0 49.9 4.9 91.4 14.5 124.4 9.6 32.8 22.4 58.7 38.3 77.7 15.8 18.9 34 32.3 54.8 40.3 21.3 8.2 43.8 12.3 67.7 12.3 24.4 0 47.2-4.1 68.4-12.3 20.8-8 39.2-21.4 55.2-40.4 16.1-19 29-44.9 38.6-77.7 9.6-33 14.5-74.5 14.5-124.4 0-48.4-4.9-88.9-14.5-121.5-9.5-32.1-22.4-57.7-38.6-76.8z m-29.5 251.7c-1 21.4-4.2 42-9.5 61.9-5.5 20.7-14.5 38.5-27 53.4-13.6 16.3-33.2 24.3-57.6 24.3-24 0-43.2-8.1-56.7-24.4-12.2-14.8-21.1-32.6-26.6-53.3-5.3-19.9-8.5-40.6-9.5-61.9-1-20.8-1.5-38.5-1.5-53.2 0-8.8 0.1-19.4 0.4-31.8 0.2-12.7 1.1-25.8 2.6-39.2 1.5-13.6 4-27.1 7.6-40.5 3.7-13.8 8.8-26.3 15.4-37.4 6.9-11.6 15.8-21.1 26.7-28.3 11.4-7.6 25.3-11.3 41.5-11.3 16.1 0 30.1 3.7 41.7 11.2 11.1 7.2 20.3 16.6 27.4 28.2 6.9 11.2 12.1 23.8 15.6 37.7 3.3 13.2 5.8 26.6 7.5 40.1 1.8 13.5 2.8 26.6 3 39.4 0.2 12.4 0.4 23 0.4 31.8 0.1 14.8-0.4 32.5-1.4 53.3z" p-id="12948" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFieldNumberOutlined, "Field Number Outlined"))]
 pub static AiFieldNumberOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFieldNumberOutlined",
+    label: "Field Number Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4653,8 +6630,15 @@ pub static AiFieldNumberOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M508 280h-63.3c-3.3 0-6 2.7-6 6v340.2H433L197.4 282.6c-1.1-1.6-3-2.6-4.9-2.6H126c-3.3 0-6 2.7-6 6v464c0 3.3 2.7 6 6 6h62.7c3.3 0 6-2.7 6-6V405.1h5.7l238.2 348.3c1.1 1.6 3 2.6 5 2.6H508c3.3 0 6-2.7 6-6V286c0-3.3-2.7-6-6-6zM886 693H582c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h304c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zM733.8 630c52.9 0 95.2-17.2 126.2-51.7 29.4-32.9 44-75.8 44-128.8 0-53.1-14.6-96.5-44-129.3-30.9-34.8-73.2-52.2-126.2-52.2-53.7 0-95.9 17.5-126.3 52.8-29.2 33.1-43.4 75.9-43.4 128.7 0 52.4 14.3 95.2 43.5 128.3 30.6 34.7 73 52.2 126.2 52.2z m-71.5-263.7c16.9-20.6 40.3-30.9 71.4-30.9 31.5 0 54.8 9.6 71 29.1 16.4 20.3 24.9 48.6 24.9 84.9 0 36.3-8.4 64.1-24.8 83.9-16.5 19.4-40 29.2-71.1 29.2-31.2 0-55-10.3-71.4-30.4-16.3-20.1-24.5-47.3-24.5-82.6 0.1-35.8 8.2-63 24.5-83.2z" p-id="12001" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFieldStringOutlined, "Field String Outlined"))]
 pub static AiFieldStringOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFieldStringOutlined",
+    label: "Field String Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4674,8 +6658,15 @@ pub static AiFieldStringOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M653 457.8h-51.4V396c0-4.4-3.6-8-8-8h-54.7c-4.4 0-8 3.6-8 8v61.8H495c-4.4 0-8 3.6-8 8v42.3c0 4.4 3.6 8 8 8h35.9v147.5c0 56.2 27.4 79.4 93.1 79.4 11.7 0 23.6-1.2 33.8-3.1 1.9-0.3 3.2-2 3.2-3.9v-49.3c0-2.2-1.8-4-4-4h-0.4c-4.9 0.5-6.2 0.6-8.3 0.8-4.1 0.3-7.8 0.5-12.6 0.5-24.1 0-34.1-10.3-34.1-35.6V516.1H653c4.4 0 8-3.6 8-8v-42.3c0-4.4-3.6-8-8-8z" p-id="12121" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFieldTimeOutlined, "Field Time Outlined"))]
 pub static AiFieldTimeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFieldTimeOutlined",
+    label: "Field Time Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4694,8 +6685,15 @@ pub static AiFieldTimeOutlined: &icondata_core::IconData = &icondata_core::IconD
 <path d="M804.8 673.9H747c-5.6 0-10.9 2.9-13.9 7.7-12.7 20.1-27.5 38.7-44.5 55.7-29.3 29.3-63.4 52.3-101.3 68.3-39.3 16.6-81 25-124 25-43.1 0-84.8-8.4-124-25-37.9-16-72-39-101.3-68.3s-52.3-63.4-68.3-101.3c-16.6-39.2-25-80.9-25-124 0-43.1 8.4-84.7 25-124 16-37.9 39-72 68.3-101.3 29.3-29.3 63.4-52.3 101.3-68.3 39.2-16.6 81-25 124-25 43.1 0 84.8 8.4 124 25 37.9 16 72 39 101.3 68.3 17 17 31.8 35.6 44.5 55.7 3 4.8 8.3 7.7 13.9 7.7h57.8c6.9 0 11.3-7.2 8.2-13.3-65.2-129.7-197.4-214-345-215.7-216.1-2.7-395.6 174.2-396 390.1C71.6 727.5 246.9 903 463.2 903c149.5 0 283.9-84.6 349.8-215.8 3.1-6.1-1.4-13.3-8.2-13.3z" p-id="12358" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileAddFilled, "File Add Filled"))]
 pub static AiFileAddFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileAddFilled",
+    label: "File Add Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4710,8 +6708,15 @@ pub static AiFileAddFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M480 580H372a8 8 0 0 0-8 8v48a8 8 0 0 0 8 8h108v108a8 8 0 0 0 8 8h48a8 8 0 0 0 8-8V644h108a8 8 0 0 0 8-8v-48a8 8 0 0 0-8-8H544V472a8 8 0 0 0-8-8h-48a8 8 0 0 0-8 8v108zm374.6-291.3c6 6 9.4 14.1 9.4 22.6V928c0 17.7-14.3 32-32 32H192c-17.7 0-32-14.3-32-32V96c0-17.7 14.3-32 32-32h424.7c8.5 0 16.7 3.4 22.7 9.4l215.2 215.3zM790.2 326L602 137.8V326h188.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileAddOutlined, "File Add Outlined"))]
 pub static AiFileAddOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileAddOutlined",
+    label: "File Add Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4726,8 +6731,15 @@ pub static AiFileAddOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M854.6 288.6L639.4 73.4c-6-6-14.1-9.4-22.6-9.4H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V311.3c0-8.5-3.4-16.7-9.4-22.7zM790.2 326H602V137.8L790.2 326zm1.8 562H232V136h302v216a42 42 0 0 0 42 42h216v494zM544 472c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v108H372c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h108v108c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V644h108c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H544V472z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileAddTwotone, "File Add Twotone"))]
 pub static AiFileAddTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileAddTwotone",
+    label: "File Add Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4744,8 +6756,15 @@ pub static AiFileAddTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M544 472c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v108H372c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h108v108c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V644h108c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H544V472z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileDoneOutlined, "File Done Outlined"))]
 pub static AiFileDoneOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileDoneOutlined",
+    label: "File Done Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4760,8 +6779,15 @@ pub static AiFileDoneOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M688 312v-48c0-4.4-3.6-8-8-8H296c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h384c4.4 0 8-3.6 8-8zm-392 88c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H296zm376 116c-119.3 0-216 96.7-216 216s96.7 216 216 216 216-96.7 216-216-96.7-216-216-216zm107.5 323.5C750.8 868.2 712.6 884 672 884s-78.8-15.8-107.5-44.5C535.8 810.8 520 772.6 520 732s15.8-78.8 44.5-107.5C593.2 595.8 631.4 580 672 580s78.8 15.8 107.5 44.5C808.2 653.2 824 691.4 824 732s-15.8 78.8-44.5 107.5zM761 656h-44.3c-2.6 0-5 1.2-6.5 3.3l-63.5 87.8-23.1-31.9a7.92 7.92 0 0 0-6.5-3.3H573c-6.5 0-10.3 7.4-6.5 12.7l73.8 102.1c3.2 4.4 9.7 4.4 12.9 0l114.2-158c3.9-5.3.1-12.7-6.4-12.7zM440 852H208V148h560v344c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V108c0-17.7-14.3-32-32-32H168c-17.7 0-32 14.3-32 32v784c0 17.7 14.3 32 32 32h272c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileExcelFilled, "File Excel Filled"))]
 pub static AiFileExcelFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileExcelFilled",
+    label: "File Excel Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4776,8 +6802,15 @@ pub static AiFileExcelFilled: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M854.6 288.7c6 6 9.4 14.1 9.4 22.6V928c0 17.7-14.3 32-32 32H192c-17.7 0-32-14.3-32-32V96c0-17.7 14.3-32 32-32h424.7c8.5 0 16.7 3.4 22.7 9.4l215.2 215.3zM790.2 326L602 137.8V326h188.2zM575.34 477.84l-61.22 102.3L452.3 477.8a12 12 0 0 0-10.27-5.79h-38.44a12 12 0 0 0-6.4 1.85 12 12 0 0 0-3.75 16.56l82.34 130.42-83.45 132.78a12 12 0 0 0-1.84 6.39 12 12 0 0 0 12 12h34.46a12 12 0 0 0 10.21-5.7l62.7-101.47 62.3 101.45a12 12 0 0 0 10.23 5.72h37.48a12 12 0 0 0 6.48-1.9 12 12 0 0 0 3.62-16.58l-83.83-130.55 85.3-132.47a12 12 0 0 0 1.9-6.5 12 12 0 0 0-12-12h-35.7a12 12 0 0 0-10.29 5.84z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileExcelOutlined, "File Excel Outlined"))]
 pub static AiFileExcelOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileExcelOutlined",
+    label: "File Excel Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4792,8 +6825,15 @@ pub static AiFileExcelOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M854.6 288.6L639.4 73.4c-6-6-14.1-9.4-22.6-9.4H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V311.3c0-8.5-3.4-16.7-9.4-22.7zM790.2 326H602V137.8L790.2 326zm1.8 562H232V136h302v216a42 42 0 0 0 42 42h216v494zM514.1 580.1l-61.8-102.4c-2.2-3.6-6.1-5.8-10.3-5.8h-38.4c-2.3 0-4.5.6-6.4 1.9-5.6 3.5-7.3 10.9-3.7 16.6l82.3 130.4-83.4 132.8a12.04 12.04 0 0 0 10.2 18.4h34.5c4.2 0 8-2.2 10.2-5.7L510 664.8l62.3 101.4c2.2 3.6 6.1 5.7 10.2 5.7H620c2.3 0 4.5-.7 6.5-1.9 5.6-3.6 7.2-11 3.6-16.6l-84-130.4 85.3-132.5a12.04 12.04 0 0 0-10.1-18.5h-35.7c-4.2 0-8.1 2.2-10.3 5.8l-61.2 102.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileExcelTwotone, "File Excel Twotone"))]
 pub static AiFileExcelTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileExcelTwotone",
+    label: "File Excel Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4810,8 +6850,15 @@ pub static AiFileExcelTwotone: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M514.1 580.1l-61.8-102.4c-2.2-3.6-6.1-5.8-10.3-5.8h-38.4c-2.3 0-4.5.6-6.4 1.9-5.6 3.5-7.3 10.9-3.7 16.6l82.3 130.4-83.4 132.8a12.04 12.04 0 0 0 10.2 18.4h34.5c4.2 0 8-2.2 10.2-5.7L510 664.8l62.3 101.4c2.2 3.6 6.1 5.7 10.2 5.7H620c2.3 0 4.5-.7 6.5-1.9 5.6-3.6 7.2-11 3.6-16.6l-84-130.4 85.3-132.5a12.04 12.04 0 0 0-10.1-18.5h-35.7c-4.2 0-8.1 2.2-10.3 5.8l-61.2 102.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileExclamationFilled, "File Exclamation Filled"))]
 pub static AiFileExclamationFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileExclamationFilled",
+    label: "File Exclamation Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4826,8 +6873,15 @@ pub static AiFileExclamationFilled: &icondata_core::IconData = &icondata_core::I
     data: r###"<path d="M854.6 288.7c6 6 9.4 14.1 9.4 22.6V928c0 17.7-14.3 32-32 32H192c-17.7 0-32-14.3-32-32V96c0-17.7 14.3-32 32-32h424.7c8.5 0 16.7 3.4 22.7 9.4l215.2 215.3zM790.2 326L602 137.8V326h188.2zM512 784a40 40 0 1 0 0-80 40 40 0 0 0 0 80zm32-152V448a8 8 0 0 0-8-8h-48a8 8 0 0 0-8 8v184a8 8 0 0 0 8 8h48a8 8 0 0 0 8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileExclamationOutlined, "File Exclamation Outlined"))]
 pub static AiFileExclamationOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileExclamationOutlined",
+    label: "File Exclamation Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4842,8 +6896,15 @@ pub static AiFileExclamationOutlined: &icondata_core::IconData = &icondata_core:
     data: r###"<path d="M854.6 288.6L639.4 73.4c-6-6-14.1-9.4-22.6-9.4H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V311.3c0-8.5-3.4-16.7-9.4-22.7zM790.2 326H602V137.8L790.2 326zm1.8 562H232V136h302v216a42 42 0 0 0 42 42h216v494zM472 744a40 40 0 1 0 80 0 40 40 0 1 0-80 0zm16-104h48c4.4 0 8-3.6 8-8V448c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v184c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileExclamationTwotone, "File Exclamation Twotone"))]
 pub static AiFileExclamationTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileExclamationTwotone",
+    label: "File Exclamation Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4860,8 +6921,15 @@ pub static AiFileExclamationTwotone: &icondata_core::IconData = &icondata_core::
 <path d="M488 640h48c4.4 0 8-3.6 8-8V448c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v184c0 4.4 3.6 8 8 8zm-16 104a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileFilled, "File Filled"))]
 pub static AiFileFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileFilled",
+    label: "File Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4876,8 +6944,15 @@ pub static AiFileFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M854.6 288.7c6 6 9.4 14.1 9.4 22.6V928c0 17.7-14.3 32-32 32H192c-17.7 0-32-14.3-32-32V96c0-17.7 14.3-32 32-32h424.7c8.5 0 16.7 3.4 22.7 9.4l215.2 215.3zM790.2 326L602 137.8V326h188.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileGifOutlined, "File GIF Outlined"))]
 pub static AiFileGifOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileGifOutlined",
+    label: "File GIF Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4897,8 +6972,15 @@ pub static AiFileGifOutlined: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M608.2 727.8h32.3c4.6 0 8.4-3.7 8.4-8.4v-84.8h87.8c4.6 0 8.4-3.7 8.4-8.4v-25.5c0-4.6-3.7-8.4-8.4-8.4h-87.8v-58.9h96.8c4.6 0 8.4-3.7 8.4-8.4v-26.8c0-4.6-3.7-8.4-8.4-8.4H608.2c-4.6 0-8.4 3.7-8.4 8.4v221.1c0 4.8 3.8 8.5 8.4 8.5z" p-id="13783" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileImageFilled, "File Image Filled"))]
 pub static AiFileImageFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileImageFilled",
+    label: "File Image Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4913,8 +6995,15 @@ pub static AiFileImageFilled: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M854.6 288.7L639.4 73.4c-6-6-14.2-9.4-22.7-9.4H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V311.3c0-8.5-3.4-16.6-9.4-22.6zM400 402c22.1 0 40 17.9 40 40s-17.9 40-40 40-40-17.9-40-40 17.9-40 40-40zm296 294H328c-6.7 0-10.4-7.7-6.3-12.9l99.8-127.2a8 8 0 0 1 12.6 0l41.1 52.4 77.8-99.2a8 8 0 0 1 12.6 0l136.5 174c4.3 5.2.5 12.9-6.1 12.9zm-94-370V137.8L790.2 326H602z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileImageOutlined, "File Image Outlined"))]
 pub static AiFileImageOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileImageOutlined",
+    label: "File Image Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4929,8 +7018,15 @@ pub static AiFileImageOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M553.1 509.1l-77.8 99.2-41.1-52.4a8 8 0 0 0-12.6 0l-99.8 127.2a7.98 7.98 0 0 0 6.3 12.9H696c6.7 0 10.4-7.7 6.3-12.9l-136.5-174a8.1 8.1 0 0 0-12.7 0zM360 442a40 40 0 1 0 80 0 40 40 0 1 0-80 0zm494.6-153.4L639.4 73.4c-6-6-14.1-9.4-22.6-9.4H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V311.3c0-8.5-3.4-16.7-9.4-22.7zM790.2 326H602V137.8L790.2 326zm1.8 562H232V136h302v216a42 42 0 0 0 42 42h216v494z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileImageTwotone, "File Image Twotone"))]
 pub static AiFileImageTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileImageTwotone",
+    label: "File Image Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4947,8 +7043,15 @@ pub static AiFileImageTwotone: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M553.1 509.1l-77.8 99.2-41.1-52.4a8 8 0 0 0-12.6 0l-99.8 127.2a7.98 7.98 0 0 0 6.3 12.9H696c6.7 0 10.4-7.7 6.3-12.9l-136.5-174a8.1 8.1 0 0 0-12.7 0zM360 442a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileJpgOutlined, "File JPG Outlined"))]
 pub static AiFileJpgOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileJpgOutlined",
+    label: "File JPG Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4963,8 +7066,15 @@ pub static AiFileJpgOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M874.6 301.8L596.8 21.3c-4.5-4.5-9.4-8.3-14.7-11.5-1.4-.8-2.8-1.6-4.3-2.3-.9-.5-1.9-.9-2.8-1.3-9-4-18.9-6.2-29-6.2H201c-39.8 0-73 32.2-73 72v880c0 39.8 33.2 72 73 72h623c39.8 0 71-32.2 71-72V352.5c0-19-7-37.2-20.4-50.7zM583 110.4L783.8 312H583V110.4zM823 952H200V72h311v240c0 39.8 33.2 72 73 72h239v568zM350 696.5c0 24.2-7.5 31.4-21.9 31.4-9 0-18.4-5.8-24.8-18.5L272.9 732c13.4 22.9 32.3 34.2 61.3 34.2 41.6 0 60.8-29.9 60.8-66.2V577h-45v119.5zM501.3 577H437v186h44v-62h21.6c39.1 0 73.1-19.6 73.1-63.6 0-45.8-33.5-60.4-74.4-60.4zm-.8 89H481v-53h18.2c21.5 0 33.4 6.2 33.4 24.9 0 18.1-10.5 28.1-32.1 28.1zm182.5-9v36h30v30.1c-4 2.9-11 4.7-17.7 4.7-34.3 0-50.7-21.4-50.7-58.2 0-36.1 19.7-57.4 47.1-57.4 15.3 0 25 6.2 34 14.4l23.7-28.3c-12.7-12.8-32.1-24.2-59.2-24.2-49.6 0-91.1 35.3-91.1 97 0 62.7 40 95.1 91.5 95.1 25.9 0 49.2-10.2 61.5-22.6V657H683z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileMarkdownFilled, "File Markdown Filled"))]
 pub static AiFileMarkdownFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileMarkdownFilled",
+    label: "File Markdown Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4979,8 +7089,15 @@ pub static AiFileMarkdownFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M854.6 288.7c6 6 9.4 14.1 9.4 22.6V928c0 17.7-14.3 32-32 32H192c-17.7 0-32-14.3-32-32V96c0-17.7 14.3-32 32-32h424.7c8.5 0 16.7 3.4 22.7 9.4l215.2 215.3zM790.2 326L602 137.8V326h188.2zM426.13 600.93l59.11 132.97a16 16 0 0 0 14.62 9.5h24.06a16 16 0 0 0 14.63-9.51l59.1-133.35V758a16 16 0 0 0 16.01 16H641a16 16 0 0 0 16-16V486a16 16 0 0 0-16-16h-34.75a16 16 0 0 0-14.67 9.62L512.1 662.2l-79.48-182.59a16 16 0 0 0-14.67-9.61H383a16 16 0 0 0-16 16v272a16 16 0 0 0 16 16h27.13a16 16 0 0 0 16-16V600.93z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileMarkdownOutlined, "File Markdown Outlined"))]
 pub static AiFileMarkdownOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileMarkdownOutlined",
+    label: "File Markdown Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4995,8 +7112,15 @@ pub static AiFileMarkdownOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M854.6 288.6L639.4 73.4c-6-6-14.1-9.4-22.6-9.4H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V311.3c0-8.5-3.4-16.7-9.4-22.7zM790.2 326H602V137.8L790.2 326zm1.8 562H232V136h302v216a42 42 0 0 0 42 42h216v494zM429 481.2c-1.9-4.4-6.2-7.2-11-7.2h-35c-6.6 0-12 5.4-12 12v272c0 6.6 5.4 12 12 12h27.1c6.6 0 12-5.4 12-12V582.1l66.8 150.2a12 12 0 0 0 11 7.1H524c4.7 0 9-2.8 11-7.1l66.8-150.6V758c0 6.6 5.4 12 12 12H641c6.6 0 12-5.4 12-12V486c0-6.6-5.4-12-12-12h-34.7c-4.8 0-9.1 2.8-11 7.2l-83.1 191-83.2-191z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileMarkdownTwotone, "File Markdown Twotone"))]
 pub static AiFileMarkdownTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileMarkdownTwotone",
+    label: "File Markdown Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5013,8 +7137,15 @@ pub static AiFileMarkdownTwotone: &icondata_core::IconData = &icondata_core::Ico
 <path d="M429 481.2c-1.9-4.4-6.2-7.2-11-7.2h-35c-6.6 0-12 5.4-12 12v272c0 6.6 5.4 12 12 12h27.1c6.6 0 12-5.4 12-12V582.1l66.8 150.2a12 12 0 0 0 11 7.1H524c4.7 0 9-2.8 11-7.1l66.8-150.6V758c0 6.6 5.4 12 12 12H641c6.6 0 12-5.4 12-12V486c0-6.6-5.4-12-12-12h-34.7c-4.8 0-9.1 2.8-11 7.2l-83.1 191-83.2-191z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileOutlined, "File Outlined"))]
 pub static AiFileOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileOutlined",
+    label: "File Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5029,8 +7160,15 @@ pub static AiFileOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M854.6 288.6L639.4 73.4c-6-6-14.1-9.4-22.6-9.4H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V311.3c0-8.5-3.4-16.7-9.4-22.7zM790.2 326H602V137.8L790.2 326zm1.8 562H232V136h302v216a42 42 0 0 0 42 42h216v494z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFilePdfFilled, "File PDF Filled"))]
 pub static AiFilePdfFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFilePdfFilled",
+    label: "File PDF Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5045,8 +7183,15 @@ pub static AiFilePdfFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M854.6 288.7c6 6 9.4 14.1 9.4 22.6V928c0 17.7-14.3 32-32 32H192c-17.7 0-32-14.3-32-32V96c0-17.7 14.3-32 32-32h424.7c8.5 0 16.7 3.4 22.7 9.4l215.2 215.3zM790.2 326L602 137.8V326h188.2zM633.22 637.26c-15.18-.5-31.32.67-49.65 2.96-24.3-14.99-40.66-35.58-52.28-65.83l1.07-4.38 1.24-5.18c4.3-18.13 6.61-31.36 7.3-44.7.52-10.07-.04-19.36-1.83-27.97-3.3-18.59-16.45-29.46-33.02-30.13-15.45-.63-29.65 8-33.28 21.37-5.91 21.62-2.45 50.07 10.08 98.59-15.96 38.05-37.05 82.66-51.2 107.54-18.89 9.74-33.6 18.6-45.96 28.42-16.3 12.97-26.48 26.3-29.28 40.3-1.36 6.49.69 14.97 5.36 21.92 5.3 7.88 13.28 13 22.85 13.74 24.15 1.87 53.83-23.03 86.6-79.26 3.29-1.1 6.77-2.26 11.02-3.7l11.9-4.02c7.53-2.54 12.99-4.36 18.39-6.11 23.4-7.62 41.1-12.43 57.2-15.17 27.98 14.98 60.32 24.8 82.1 24.8 17.98 0 30.13-9.32 34.52-23.99 3.85-12.88.8-27.82-7.48-36.08-8.56-8.41-24.3-12.43-45.65-13.12zM385.23 765.68v-.36l.13-.34a54.86 54.86 0 0 1 5.6-10.76c4.28-6.58 10.17-13.5 17.47-20.87 3.92-3.95 8-7.8 12.79-12.12 1.07-.96 7.91-7.05 9.19-8.25l11.17-10.4-8.12 12.93c-12.32 19.64-23.46 33.78-33 43-3.51 3.4-6.6 5.9-9.1 7.51a16.43 16.43 0 0 1-2.61 1.42c-.41.17-.77.27-1.13.3a2.2 2.2 0 0 1-1.12-.15 2.07 2.07 0 0 1-1.27-1.91zM511.17 547.4l-2.26 4-1.4-4.38c-3.1-9.83-5.38-24.64-6.01-38-.72-15.2.49-24.32 5.29-24.32 6.74 0 9.83 10.8 10.07 27.05.22 14.28-2.03 29.14-5.7 35.65zm-5.81 58.46l1.53-4.05 2.09 3.8c11.69 21.24 26.86 38.96 43.54 51.31l3.6 2.66-4.39.9c-16.33 3.38-31.54 8.46-52.34 16.85 2.17-.88-21.62 8.86-27.64 11.17l-5.25 2.01 2.8-4.88c12.35-21.5 23.76-47.32 36.05-79.77zm157.62 76.26c-7.86 3.1-24.78.33-54.57-12.39l-7.56-3.22 8.2-.6c23.3-1.73 39.8-.45 49.42 3.07 4.1 1.5 6.83 3.39 8.04 5.55a4.64 4.64 0 0 1-1.36 6.31 6.7 6.7 0 0 1-2.17 1.28z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFilePdfOutlined, "File PDF Outlined"))]
 pub static AiFilePdfOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFilePdfOutlined",
+    label: "File PDF Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5061,8 +7206,15 @@ pub static AiFilePdfOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M531.3 574.4l.3-1.4c5.8-23.9 13.1-53.7 7.4-80.7-3.8-21.3-19.5-29.6-32.9-30.2-15.8-.7-29.9 8.3-33.4 21.4-6.6 24-.7 56.8 10.1 98.6-13.6 32.4-35.3 79.5-51.2 107.5-29.6 15.3-69.3 38.9-75.2 68.7-1.2 5.5.2 12.5 3.5 18.8 3.7 7 9.6 12.4 16.5 15 3 1.1 6.6 2 10.8 2 17.6 0 46.1-14.2 84.1-79.4 5.8-1.9 11.8-3.9 17.6-5.9 27.2-9.2 55.4-18.8 80.9-23.1 28.2 15.1 60.3 24.8 82.1 24.8 21.6 0 30.1-12.8 33.3-20.5 5.6-13.5 2.9-30.5-6.2-39.6-13.2-13-45.3-16.4-95.3-10.2-24.6-15-40.7-35.4-52.4-65.8zM421.6 726.3c-13.9 20.2-24.4 30.3-30.1 34.7 6.7-12.3 19.8-25.3 30.1-34.7zm87.6-235.5c5.2 8.9 4.5 35.8.5 49.4-4.9-19.9-5.6-48.1-2.7-51.4.8.1 1.5.7 2.2 2zm-1.6 120.5c10.7 18.5 24.2 34.4 39.1 46.2-21.6 4.9-41.3 13-58.9 20.2-4.2 1.7-8.3 3.4-12.3 5 13.3-24.1 24.4-51.4 32.1-71.4zm155.6 65.5c.1.2.2.5-.4.9h-.2l-.2.3c-.8.5-9 5.3-44.3-8.6 40.6-1.9 45 7.3 45.1 7.4zm191.4-388.2L639.4 73.4c-6-6-14.1-9.4-22.6-9.4H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V311.3c0-8.5-3.4-16.7-9.4-22.7zM790.2 326H602V137.8L790.2 326zm1.8 562H232V136h302v216a42 42 0 0 0 42 42h216v494z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFilePdfTwotone, "File PDF Twotone"))]
 pub static AiFilePdfTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFilePdfTwotone",
+    label: "File PDF Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5081,8 +7233,15 @@ pub static AiFilePdfTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M535.9 585.3c-.8-1.7-1.5-3.3-2.2-4.9-.1-.3-.3-.7-.4-1l-1.8-4.5c-.1-.2-.1-.3-.2-.5l.1-.3.2-1.1c4-16.3 8.6-35.3 9.4-54.4v-.7c.3-8.6-.2-17.2-2-25.6-3.8-21.3-19.5-29.6-32.9-30.2-11.3-.5-21.8 4-28.1 11.4-.1.1-.1.2-.2.2-.2.2-.4.4-.5.6-2.1 2.7-3.7 5.8-4.6 9.1-6.6 24-.7 56.8 10.1 98.6-13.6 32.4-35.3 79.4-51.2 107.4v.1c-27.7 14.3-64.1 35.8-73.6 62.9 0 .1-.1.2-.1.3-.2.7-.5 1.4-.7 2.1-.1.2-.1.4-.2.6-.2.9-.5 1.8-.6 2.7-.9 4-.4 8.8 1.3 13.6.6 1.8 1.3 3.5 2.2 5.2 3.7 7 9.6 12.4 16.5 15 3 1.1 6.6 2 10.8 2 17.6 0 46.1-14.2 84.1-79.4 5.8-1.9 11.8-3.9 17.6-5.9 27.2-9.2 55.4-18.8 80.9-23.1 28.2 15.1 60.3 24.8 82.1 24.8 21.6 0 30.1-12.8 33.3-20.5 5.6-13.5 2.9-30.5-6.2-39.6-2.6-2.6-6-4.8-10.1-6.6-.1-.1-.3-.1-.4-.2-.5-.2-1.1-.4-1.6-.7-.4-.2-.8-.3-1.2-.5-.2-.1-.3-.1-.5-.2-16.2-5.8-41.7-6.7-76.3-2.8l-5.3.6c-5-3-9.6-6.3-13.9-9.8-14.2-11.3-25.1-25.8-33.8-44.7zM391.5 761c6.7-12.3 19.8-25.3 30.1-34.7-13.9 20.2-24.4 30.3-30.1 34.7zM507 488.8c.8.1 1.5.7 2.2 2 5.2 8.9 4.5 35.8.5 49.4-4.9-19.9-5.6-48.1-2.7-51.4zm-19.2 188.9c-4.2 1.7-8.3 3.4-12.3 5 13.3-24.1 24.4-51.4 32.1-71.4 10.7 18.5 24.2 34.4 39.1 46.2-21.6 4.9-41.3 13-58.9 20.2zm175.4-.9c.1.2.2.5-.4.9h-.2l-.2.3c-.8.5-9 5.3-44.3-8.6 40.6-1.9 45 7.3 45.1 7.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFilePptFilled, "File Ppt Filled"))]
 pub static AiFilePptFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFilePptFilled",
+    label: "File Ppt Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5097,8 +7256,15 @@ pub static AiFilePptFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M854.6 288.7c6 6 9.4 14.1 9.4 22.6V928c0 17.7-14.3 32-32 32H192c-17.7 0-32-14.3-32-32V96c0-17.7 14.3-32 32-32h424.7c8.5 0 16.7 3.4 22.7 9.4l215.2 215.3zM790.2 326L602 137.8V326h188.2zM468.53 760v-91.54h59.27c60.57 0 100.2-39.65 100.2-98.12 0-58.22-39.58-98.34-99.98-98.34H424a12 12 0 0 0-12 12v276a12 12 0 0 0 12 12h32.53a12 12 0 0 0 12-12zm0-139.33h34.9c47.82 0 67.19-12.93 67.19-50.33 0-32.05-18.12-50.12-49.87-50.12h-52.22v100.45z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFilePptOutlined, "File Ppt Outlined"))]
 pub static AiFilePptOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFilePptOutlined",
+    label: "File Ppt Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5113,8 +7279,15 @@ pub static AiFilePptOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M424 476c-4.4 0-8 3.6-8 8v276c0 4.4 3.6 8 8 8h32.5c4.4 0 8-3.6 8-8v-95.5h63.3c59.4 0 96.2-38.9 96.2-94.1 0-54.5-36.3-94.3-96-94.3H424zm150.6 94.3c0 43.4-26.5 54.3-71.2 54.3h-38.9V516.2h56.2c33.8 0 53.9 19.7 53.9 54.1zm280-281.7L639.4 73.4c-6-6-14.1-9.4-22.6-9.4H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V311.3c0-8.5-3.4-16.7-9.4-22.7zM790.2 326H602V137.8L790.2 326zm1.8 562H232V136h302v216a42 42 0 0 0 42 42h216v494z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFilePptTwotone, "File Ppt Twotone"))]
 pub static AiFilePptTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFilePptTwotone",
+    label: "File Ppt Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5132,8 +7305,15 @@ pub static AiFilePptTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M424 476.1c-4.4-.1-8 3.5-8 7.9v276c0 4.4 3.6 8 8 8h32.5c4.4 0 8-3.6 8-8v-95.5h63.3c59.4 0 96.2-38.9 96.2-94.1 0-54.5-36.3-94.3-96-94.3H424zm150.6 94.2c0 43.4-26.5 54.3-71.2 54.3h-38.9V516.2h56.2c33.8 0 53.9 19.7 53.9 54.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileProtectOutlined, "File Protect Outlined"))]
 pub static AiFileProtectOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileProtectOutlined",
+    label: "File Protect Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5148,8 +7328,15 @@ pub static AiFileProtectOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M644.7 669.2a7.92 7.92 0 0 0-6.5-3.3H594c-6.5 0-10.3 7.4-6.5 12.7l73.8 102.1c3.2 4.4 9.7 4.4 12.9 0l114.2-158c3.8-5.3 0-12.7-6.5-12.7h-44.3c-2.6 0-5 1.2-6.5 3.3l-63.5 87.8-22.9-31.9zM688 306v-48c0-4.4-3.6-8-8-8H296c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h384c4.4 0 8-3.6 8-8zm-392 88c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H296zm184 458H208V148h560v296c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V108c0-17.7-14.3-32-32-32H168c-17.7 0-32 14.3-32 32v784c0 17.7 14.3 32 32 32h312c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm402.6-320.8l-192-66.7c-.9-.3-1.7-.4-2.6-.4s-1.8.1-2.6.4l-192 66.7a7.96 7.96 0 0 0-5.4 7.5v251.1c0 2.5 1.1 4.8 3.1 6.3l192 150.2c1.4 1.1 3.2 1.7 4.9 1.7s3.5-.6 4.9-1.7l192-150.2c1.9-1.5 3.1-3.8 3.1-6.3V538.7c0-3.4-2.2-6.4-5.4-7.5zM826 763.7L688 871.6 550 763.7V577l138-48 138 48v186.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileSearchOutlined, "File Search Outlined"))]
 pub static AiFileSearchOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileSearchOutlined",
+    label: "File Search Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5164,8 +7351,15 @@ pub static AiFileSearchOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M688 312v-48c0-4.4-3.6-8-8-8H296c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h384c4.4 0 8-3.6 8-8zm-392 88c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H296zm144 452H208V148h560v344c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V108c0-17.7-14.3-32-32-32H168c-17.7 0-32 14.3-32 32v784c0 17.7 14.3 32 32 32h272c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm445.7 51.5l-93.3-93.3C814.7 780.7 828 743.9 828 704c0-97.2-78.8-176-176-176s-176 78.8-176 176 78.8 176 176 176c35.8 0 69-10.7 96.8-29l94.7 94.7c1.6 1.6 3.6 2.3 5.6 2.3s4.1-.8 5.6-2.3l31-31a7.9 7.9 0 0 0 0-11.2zM652 816c-61.9 0-112-50.1-112-112s50.1-112 112-112 112 50.1 112 112-50.1 112-112 112z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileSyncOutlined, "File Sync Outlined"))]
 pub static AiFileSyncOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileSyncOutlined",
+    label: "File Sync Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5180,8 +7374,15 @@ pub static AiFileSyncOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M296 256c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h384c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H296zm192 200v-48c0-4.4-3.6-8-8-8H296c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h184c4.4 0 8-3.6 8-8zm-48 396H208V148h560v344c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V108c0-17.7-14.3-32-32-32H168c-17.7 0-32 14.3-32 32v784c0 17.7 14.3 32 32 32h272c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm104.1-115.6c1.8-34.5 16.2-66.8 40.8-91.4 26.2-26.2 62-41 99.1-41 37.4 0 72.6 14.6 99.1 41 3.2 3.2 6.3 6.6 9.2 10.1L769.2 673a8 8 0 0 0 3 14.1l93.3 22.5c5 1.2 9.8-2.6 9.9-7.7l.6-95.4a8 8 0 0 0-12.9-6.4l-20.3 15.8C805.4 569.6 748.1 540 684 540c-109.9 0-199.6 86.9-204 195.7-.2 4.5 3.5 8.3 8 8.3h48.1c4.3 0 7.8-3.3 8-7.6zM880 744h-48.1c-4.3 0-7.8 3.3-8 7.6-1.8 34.5-16.2 66.8-40.8 91.4-26.2 26.2-62 41-99.1 41-37.4 0-72.6-14.6-99.1-41-3.2-3.2-6.3-6.6-9.2-10.1l23.1-17.9a8 8 0 0 0-3-14.1l-93.3-22.5c-5-1.2-9.8 2.6-9.9 7.7l-.6 95.4a8 8 0 0 0 12.9 6.4l20.3-15.8C562.6 918.4 619.9 948 684 948c109.9 0 199.6-86.9 204-195.7.2-4.5-3.5-8.3-8-8.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileTextFilled, "File Text Filled"))]
 pub static AiFileTextFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileTextFilled",
+    label: "File Text Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5196,8 +7397,15 @@ pub static AiFileTextFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M854.6 288.7c6 6 9.4 14.1 9.4 22.6V928c0 17.7-14.3 32-32 32H192c-17.7 0-32-14.3-32-32V96c0-17.7 14.3-32 32-32h424.7c8.5 0 16.7 3.4 22.7 9.4l215.2 215.3zM790.2 326L602 137.8V326h188.2zM320 482a8 8 0 0 0-8 8v48a8 8 0 0 0 8 8h384a8 8 0 0 0 8-8v-48a8 8 0 0 0-8-8H320zm0 136a8 8 0 0 0-8 8v48a8 8 0 0 0 8 8h184a8 8 0 0 0 8-8v-48a8 8 0 0 0-8-8H320z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileTextOutlined, "File Text Outlined"))]
 pub static AiFileTextOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileTextOutlined",
+    label: "File Text Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5212,8 +7420,15 @@ pub static AiFileTextOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M854.6 288.6L639.4 73.4c-6-6-14.1-9.4-22.6-9.4H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V311.3c0-8.5-3.4-16.7-9.4-22.7zM790.2 326H602V137.8L790.2 326zm1.8 562H232V136h302v216a42 42 0 0 0 42 42h216v494zM504 618H320c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zM312 490v48c0 4.4 3.6 8 8 8h384c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H320c-4.4 0-8 3.6-8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileTextTwotone, "File Text Twotone"))]
 pub static AiFileTextTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileTextTwotone",
+    label: "File Text Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5230,8 +7445,15 @@ pub static AiFileTextTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M312 490v48c0 4.4 3.6 8 8 8h384c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H320c-4.4 0-8 3.6-8 8zm192 128H320c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileTwotone, "File Twotone"))]
 pub static AiFileTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileTwotone",
+    label: "File Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5247,8 +7469,15 @@ pub static AiFileTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M854.6 288.6L639.4 73.4c-6-6-14.1-9.4-22.6-9.4H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V311.3c0-8.5-3.4-16.7-9.4-22.7zM602 137.8L790.2 326H602V137.8zM792 888H232V136h302v216a42 42 0 0 0 42 42h216v494z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileUnknownFilled, "File Unknown Filled"))]
 pub static AiFileUnknownFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileUnknownFilled",
+    label: "File Unknown Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5263,8 +7492,15 @@ pub static AiFileUnknownFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M854.6 288.7c6 6 9.4 14.1 9.4 22.6V928c0 17.7-14.3 32-32 32H192c-17.7 0-32-14.3-32-32V96c0-17.7 14.3-32 32-32h424.7c8.5 0 16.7 3.4 22.7 9.4l215.2 215.3zM790.2 326L602 137.8V326h188.2zM402 549c0 5.4 4.4 9.5 9.8 9.5h32.4c5.4 0 9.8-4.2 9.8-9.4 0-28.2 25.8-51.6 58-51.6s58 23.4 58 51.5c0 25.3-21 47.2-49.3 50.9-19.3 2.8-34.5 20.3-34.7 40.1v32c0 5.5 4.5 10 10 10h32c5.5 0 10-4.5 10-10v-12.2c0-6 4-11.5 9.7-13.3 44.6-14.4 75-54 74.3-98.9-.8-55.5-49.2-100.8-108.5-101.6-61.4-.7-111.5 45.6-111.5 103zm110 227a32 32 0 1 0 0-64 32 32 0 0 0 0 64z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileUnknownOutlined, "File Unknown Outlined"))]
 pub static AiFileUnknownOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileUnknownOutlined",
+    label: "File Unknown Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5279,8 +7515,15 @@ pub static AiFileUnknownOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M854.6 288.7L639.4 73.4c-6-6-14.2-9.4-22.7-9.4H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V311.3c0-8.5-3.4-16.6-9.4-22.6zM790.2 326H602V137.8L790.2 326zm1.8 562H232V136h302v216a42 42 0 0 0 42 42h216v494zM402 549c0 5.4 4.4 9.5 9.8 9.5h32.4c5.4 0 9.8-4.2 9.8-9.4 0-28.2 25.8-51.6 58-51.6s58 23.4 58 51.5c0 25.3-21 47.2-49.3 50.9-19.3 2.8-34.5 20.3-34.7 40.1v32c0 5.5 4.5 10 10 10h32c5.5 0 10-4.5 10-10v-12.2c0-6 4-11.5 9.7-13.3 44.6-14.4 75-54 74.3-98.9-.8-55.5-49.2-100.8-108.5-101.6-61.4-.7-111.5 45.6-111.5 103zm78 195a32 32 0 1 0 64 0 32 32 0 1 0-64 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileUnknownTwotone, "File Unknown Twotone"))]
 pub static AiFileUnknownTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileUnknownTwotone",
+    label: "File Unknown Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5297,8 +7540,15 @@ pub static AiFileUnknownTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M480 744a32 32 0 1 0 64 0 32 32 0 1 0-64 0zm-78-195c0 5.4 4.4 9.5 9.8 9.5h32.4c5.4 0 9.8-4.2 9.8-9.4 0-28.2 25.8-51.6 58-51.6s58 23.4 58 51.5c0 25.3-21 47.2-49.3 50.9-19.3 2.8-34.5 20.3-34.7 40.1v32c0 5.5 4.5 10 10 10h32c5.5 0 10-4.5 10-10v-12.2c0-6 4-11.5 9.7-13.3 44.6-14.4 75-54 74.3-98.9-.8-55.5-49.2-100.8-108.5-101.6-61.4-.7-111.5 45.6-111.5 103z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileWordFilled, "File Word Filled"))]
 pub static AiFileWordFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileWordFilled",
+    label: "File Word Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5313,8 +7563,15 @@ pub static AiFileWordFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M854.6 288.7c6 6 9.4 14.1 9.4 22.6V928c0 17.7-14.3 32-32 32H192c-17.7 0-32-14.3-32-32V96c0-17.7 14.3-32 32-32h424.7c8.5 0 16.7 3.4 22.7 9.4l215.2 215.3zM790.2 326L602 137.8V326h188.2zM512 566.1l52.81 197a12 12 0 0 0 11.6 8.9h31.77a12 12 0 0 0 11.6-8.88l74.37-276a12 12 0 0 0 .4-3.12 12 12 0 0 0-12-12h-35.57a12 12 0 0 0-11.7 9.31l-45.78 199.1-49.76-199.32A12 12 0 0 0 528.1 472h-32.2a12 12 0 0 0-11.64 9.1L434.6 680.01 388.5 481.3a12 12 0 0 0-11.68-9.29h-35.39a12 12 0 0 0-3.11.41 12 12 0 0 0-8.47 14.7l74.17 276A12 12 0 0 0 415.6 772h31.99a12 12 0 0 0 11.59-8.9l52.81-197z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileWordOutlined, "File Word Outlined"))]
 pub static AiFileWordOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileWordOutlined",
+    label: "File Word Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5329,8 +7586,15 @@ pub static AiFileWordOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M854.6 288.6L639.4 73.4c-6-6-14.1-9.4-22.6-9.4H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V311.3c0-8.5-3.4-16.7-9.4-22.7zM790.2 326H602V137.8L790.2 326zm1.8 562H232V136h302v216a42 42 0 0 0 42 42h216v494zM528.1 472h-32.2c-5.5 0-10.3 3.7-11.6 9.1L434.6 680l-46.1-198.7c-1.3-5.4-6.1-9.3-11.7-9.3h-35.4a12.02 12.02 0 0 0-11.6 15.1l74.2 276c1.4 5.2 6.2 8.9 11.6 8.9h32c5.4 0 10.2-3.6 11.6-8.9l52.8-197 52.8 197c1.4 5.2 6.2 8.9 11.6 8.9h31.8c5.4 0 10.2-3.6 11.6-8.9l74.4-276a12.04 12.04 0 0 0-11.6-15.1H647c-5.6 0-10.4 3.9-11.7 9.3l-45.8 199.1-49.8-199.3c-1.3-5.4-6.1-9.1-11.6-9.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileWordTwotone, "File Word Twotone"))]
 pub static AiFileWordTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileWordTwotone",
+    label: "File Word Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5347,8 +7611,15 @@ pub static AiFileWordTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M528.1 472h-32.2c-5.5 0-10.3 3.7-11.6 9.1L434.6 680l-46.1-198.7c-1.3-5.4-6.1-9.3-11.7-9.3h-35.4a12.02 12.02 0 0 0-11.6 15.1l74.2 276c1.4 5.2 6.2 8.9 11.6 8.9h32c5.4 0 10.2-3.6 11.6-8.9l52.8-197 52.8 197c1.4 5.2 6.2 8.9 11.6 8.9h31.8c5.4 0 10.2-3.6 11.6-8.9l74.4-276a12.04 12.04 0 0 0-11.6-15.1H647c-5.6 0-10.4 3.9-11.7 9.3l-45.8 199.1-49.8-199.3c-1.3-5.4-6.1-9.1-11.6-9.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileZipFilled, "File Zip Filled"))]
 pub static AiFileZipFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileZipFilled",
+    label: "File Zip Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5363,8 +7634,15 @@ pub static AiFileZipFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M854.6 288.7c6 6 9.4 14.1 9.4 22.6V928c0 17.7-14.3 32-32 32H192c-17.7 0-32-14.3-32-32V96c0-17.7 14.3-32 32-32h424.7c8.5 0 16.7 3.4 22.7 9.4l215.2 215.3zM790.2 326L602 137.8V326h188.2zM296 136v64h64v-64h-64zm64 64v64h64v-64h-64zm-64 64v64h64v-64h-64zm64 64v64h64v-64h-64zm-64 64v64h64v-64h-64zm64 64v64h64v-64h-64zm-64 64v64h64v-64h-64zm0 64v160h128V584H296zm48 48h32v64h-32v-64z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileZipOutlined, "File Zip Outlined"))]
 pub static AiFileZipOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileZipOutlined",
+    label: "File Zip Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5379,8 +7657,15 @@ pub static AiFileZipOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M296 392h64v64h-64zm0 190v160h128V582h-64v-62h-64v62zm80 48v64h-32v-64h32zm-16-302h64v64h-64zm-64-64h64v64h-64zm64 192h64v64h-64zm0-256h64v64h-64zm494.6 88.6L639.4 73.4c-6-6-14.1-9.4-22.6-9.4H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V311.3c0-8.5-3.4-16.7-9.4-22.7zM790.2 326H602V137.8L790.2 326zm1.8 562H232V136h64v64h64v-64h174v216a42 42 0 0 0 42 42h216v494z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFileZipTwotone, "File Zip Twotone"))]
 pub static AiFileZipTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFileZipTwotone",
+    label: "File Zip Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5398,8 +7683,15 @@ pub static AiFileZipTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M296 392h64v64h-64zm0-128h64v64h-64zm0 318v160h128V582h-64v-62h-64v62zm48 50v-2h32v64h-32v-62zm16-432h64v64h-64zm0 256h64v64h-64zm0-128h64v64h-64z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFilterFilled, "Filter Filled"))]
 pub static AiFilterFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFilterFilled",
+    label: "Filter Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5414,8 +7706,15 @@ pub static AiFilterFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M349 838c0 17.7 14.2 32 31.8 32h262.4c17.6 0 31.8-14.3 31.8-32V642H349v196zm531.1-684H143.9c-24.5 0-39.8 26.7-27.5 48l221.3 376h348.8l221.3-376c12.1-21.3-3.2-48-27.7-48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFilterOutlined, "Filter Outlined"))]
 pub static AiFilterOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFilterOutlined",
+    label: "Filter Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5430,8 +7729,15 @@ pub static AiFilterOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880.1 154H143.9c-24.5 0-39.8 26.7-27.5 48L349 597.4V838c0 17.7 14.2 32 31.8 32h262.4c17.6 0 31.8-14.3 31.8-32V597.4L907.7 202c12.2-21.3-3.1-48-27.6-48zM603.4 798H420.6V642h182.9v156zm9.6-236.6l-9.5 16.6h-183l-9.5-16.6L212.7 226h598.6L613 561.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFilterTwotone, "Filter Twotone"))]
 pub static AiFilterTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFilterTwotone",
+    label: "Filter Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5447,8 +7753,15 @@ pub static AiFilterTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M880.1 154H143.9c-24.5 0-39.8 26.7-27.5 48L349 597.4V838c0 17.7 14.2 32 31.8 32h262.4c17.6 0 31.8-14.3 31.8-32V597.4L907.7 202c12.2-21.3-3.1-48-27.6-48zM603.5 798H420.6V642h182.9v156zm9.5-236.6l-9.5 16.6h-183l-9.5-16.6L212.7 226h598.6L613 561.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFireFilled, "Fire Filled"))]
 pub static AiFireFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFireFilled",
+    label: "Fire Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5463,8 +7776,15 @@ pub static AiFireFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M834.1 469.2A347.49 347.49 0 0 0 751.2 354l-29.1-26.7a8.09 8.09 0 0 0-13 3.3l-13 37.3c-8.1 23.4-23 47.3-44.1 70.8-1.4 1.5-3 1.9-4.1 2-1.1.1-2.8-.1-4.3-1.5-1.4-1.2-2.1-3-2-4.8 3.7-60.2-14.3-128.1-53.7-202C555.3 171 510 123.1 453.4 89.7l-41.3-24.3c-5.4-3.2-12.3 1-12 7.3l2.2 48c1.5 32.8-2.3 61.8-11.3 85.9-11 29.5-26.8 56.9-47 81.5a295.64 295.64 0 0 1-47.5 46.1 352.6 352.6 0 0 0-100.3 121.5A347.75 347.75 0 0 0 160 610c0 47.2 9.3 92.9 27.7 136a349.4 349.4 0 0 0 75.5 110.9c32.4 32 70 57.2 111.9 74.7C418.5 949.8 464.5 959 512 959s93.5-9.2 136.9-27.3A348.6 348.6 0 0 0 760.8 857c32.4-32 57.8-69.4 75.5-110.9a344.2 344.2 0 0 0 27.7-136c0-48.8-10-96.2-29.9-140.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFireOutlined, "Fire Outlined"))]
 pub static AiFireOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFireOutlined",
+    label: "Fire Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5479,8 +7799,15 @@ pub static AiFireOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M834.1 469.2A347.49 347.49 0 0 0 751.2 354l-29.1-26.7a8.09 8.09 0 0 0-13 3.3l-13 37.3c-8.1 23.4-23 47.3-44.1 70.8-1.4 1.5-3 1.9-4.1 2-1.1.1-2.8-.1-4.3-1.5-1.4-1.2-2.1-3-2-4.8 3.7-60.2-14.3-128.1-53.7-202C555.3 171 510 123.1 453.4 89.7l-41.3-24.3c-5.4-3.2-12.3 1-12 7.3l2.2 48c1.5 32.8-2.3 61.8-11.3 85.9-11 29.5-26.8 56.9-47 81.5a295.64 295.64 0 0 1-47.5 46.1 352.6 352.6 0 0 0-100.3 121.5A347.75 347.75 0 0 0 160 610c0 47.2 9.3 92.9 27.7 136a349.4 349.4 0 0 0 75.5 110.9c32.4 32 70 57.2 111.9 74.7C418.5 949.8 464.5 959 512 959s93.5-9.2 136.9-27.3A348.6 348.6 0 0 0 760.8 857c32.4-32 57.8-69.4 75.5-110.9a344.2 344.2 0 0 0 27.7-136c0-48.8-10-96.2-29.9-140.9zM713 808.5c-53.7 53.2-125 82.4-201 82.4s-147.3-29.2-201-82.4c-53.5-53.1-83-123.5-83-198.4 0-43.5 9.8-85.2 29.1-124 18.8-37.9 46.8-71.8 80.8-97.9a349.6 349.6 0 0 0 58.6-56.8c25-30.5 44.6-64.5 58.2-101a240 240 0 0 0 12.1-46.5c24.1 22.2 44.3 49 61.2 80.4 33.4 62.6 48.8 118.3 45.8 165.7a74.01 74.01 0 0 0 24.4 59.8 73.36 73.36 0 0 0 53.4 18.8c19.7-1 37.8-9.7 51-24.4 13.3-14.9 24.8-30.1 34.4-45.6 14 17.9 25.7 37.4 35 58.4 15.9 35.8 24 73.9 24 113.1 0 74.9-29.5 145.4-83 198.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFireTwotone, "Fire Twotone"))]
 pub static AiFireTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFireTwotone",
+    label: "Fire Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5496,8 +7823,15 @@ pub static AiFireTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M834.1 469.2A347.49 347.49 0 0 0 751.2 354l-29.1-26.7a8.09 8.09 0 0 0-13 3.3l-13 37.3c-8.1 23.4-23 47.3-44.1 70.8-1.4 1.5-3 1.9-4.1 2-1.1.1-2.8-.1-4.3-1.5-1.4-1.2-2.1-3-2-4.8 3.7-60.2-14.3-128.1-53.7-202C555.3 171 510 123.1 453.4 89.7l-41.3-24.3c-5.4-3.2-12.3 1-12 7.3l2.2 48c1.5 32.8-2.3 61.8-11.3 85.9-11 29.5-26.8 56.9-47 81.5a295.64 295.64 0 0 1-47.5 46.1 352.6 352.6 0 0 0-100.3 121.5A347.75 347.75 0 0 0 160 610c0 47.2 9.3 92.9 27.7 136a349.4 349.4 0 0 0 75.5 110.9c32.4 32 70 57.2 111.9 74.7C418.5 949.8 464.5 959 512 959s93.5-9.2 136.9-27.3A348.6 348.6 0 0 0 760.8 857c32.4-32 57.8-69.4 75.5-110.9a344.2 344.2 0 0 0 27.7-136c0-48.8-10-96.2-29.9-140.9zM713 808.5c-53.7 53.2-125 82.4-201 82.4s-147.3-29.2-201-82.4c-53.5-53.1-83-123.5-83-198.4 0-43.5 9.8-85.2 29.1-124 18.8-37.9 46.8-71.8 80.8-97.9a349.6 349.6 0 0 0 58.6-56.8c25-30.5 44.6-64.5 58.2-101a240 240 0 0 0 12.1-46.5c24.1 22.2 44.3 49 61.2 80.4 33.4 62.6 48.8 118.3 45.8 165.7a74.01 74.01 0 0 0 24.4 59.8 73.36 73.36 0 0 0 53.4 18.8c19.7-1 37.8-9.7 51-24.4 13.3-14.9 24.8-30.1 34.4-45.6 14 17.9 25.7 37.4 35 58.4 15.9 35.8 24 73.9 24 113.1 0 74.9-29.5 145.4-83 198.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFlagFilled, "Flag Filled"))]
 pub static AiFlagFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFlagFilled",
+    label: "Flag Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5512,8 +7846,15 @@ pub static AiFlagFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M880 305H624V192c0-17.7-14.3-32-32-32H184v-40c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v784c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V640h248v113c0 17.7 14.3 32 32 32h416c17.7 0 32-14.3 32-32V337c0-17.7-14.3-32-32-32z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFlagOutlined, "Flag Outlined"))]
 pub static AiFlagOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFlagOutlined",
+    label: "Flag Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5528,8 +7869,15 @@ pub static AiFlagOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M880 305H624V192c0-17.7-14.3-32-32-32H184v-40c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v784c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V640h248v113c0 17.7 14.3 32 32 32h416c17.7 0 32-14.3 32-32V337c0-17.7-14.3-32-32-32zM184 568V232h368v336H184zm656 145H504v-73h112c4.4 0 8-3.6 8-8V377h216v336z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFlagTwotone, "Flag Twotone"))]
 pub static AiFlagTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFlagTwotone",
+    label: "Flag Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5546,8 +7894,15 @@ pub static AiFlagTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M880 305H624V192c0-17.7-14.3-32-32-32H184v-40c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v784c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V640h248v113c0 17.7 14.3 32 32 32h416c17.7 0 32-14.3 32-32V337c0-17.7-14.3-32-32-32zM184 568V232h368v336H184zm656 145H504v-73h112c4.4 0 8-3.6 8-8V377h216v336z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFolderAddFilled, "Folder Add Filled"))]
 pub static AiFolderAddFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFolderAddFilled",
+    label: "Folder Add Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5562,8 +7917,15 @@ pub static AiFolderAddFilled: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M880 298.4H521L403.7 186.2a8.15 8.15 0 0 0-5.5-2.2H144c-17.7 0-32 14.3-32 32v592c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V330.4c0-17.7-14.3-32-32-32zM632 577c0 3.8-3.4 7-7.5 7H540v84.9c0 3.9-3.2 7.1-7 7.1h-42c-3.8 0-7-3.2-7-7.1V584h-84.5c-4.1 0-7.5-3.2-7.5-7v-42c0-3.8 3.4-7 7.5-7H484v-84.9c0-3.9 3.2-7.1 7-7.1h42c3.8 0 7 3.2 7 7.1V528h84.5c4.1 0 7.5 3.2 7.5 7v42z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFolderAddOutlined, "Folder Add Outlined"))]
 pub static AiFolderAddOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFolderAddOutlined",
+    label: "Folder Add Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5578,8 +7940,15 @@ pub static AiFolderAddOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M484 443.1V528h-84.5c-4.1 0-7.5 3.1-7.5 7v42c0 3.8 3.4 7 7.5 7H484v84.9c0 3.9 3.2 7.1 7 7.1h42c3.9 0 7-3.2 7-7.1V584h84.5c4.1 0 7.5-3.2 7.5-7v-42c0-3.9-3.4-7-7.5-7H540v-84.9c0-3.9-3.1-7.1-7-7.1h-42c-3.8 0-7 3.2-7 7.1zm396-144.7H521L403.7 186.2a8.15 8.15 0 0 0-5.5-2.2H144c-17.7 0-32 14.3-32 32v592c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V330.4c0-17.7-14.3-32-32-32zM840 768H184V256h188.5l119.6 114.4H840V768z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFolderAddTwotone, "Folder Add Twotone"))]
 pub static AiFolderAddTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFolderAddTwotone",
+    label: "Folder Add Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5596,8 +7965,15 @@ pub static AiFolderAddTwotone: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M484 443.1V528h-84.5c-4.1 0-7.5 3.1-7.5 7v42c0 3.8 3.4 7 7.5 7H484v84.9c0 3.9 3.2 7.1 7 7.1h42c3.9 0 7-3.2 7-7.1V584h84.5c4.1 0 7.5-3.2 7.5-7v-42c0-3.9-3.4-7-7.5-7H540v-84.9c0-3.9-3.1-7.1-7-7.1h-42c-3.8 0-7 3.2-7 7.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFolderFilled, "Folder Filled"))]
 pub static AiFolderFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFolderFilled",
+    label: "Folder Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5612,8 +7988,15 @@ pub static AiFolderFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M880 298.4H521L403.7 186.2a8.15 8.15 0 0 0-5.5-2.2H144c-17.7 0-32 14.3-32 32v592c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V330.4c0-17.7-14.3-32-32-32z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFolderOpenFilled, "Folder Open Filled"))]
 pub static AiFolderOpenFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFolderOpenFilled",
+    label: "Folder Open Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5628,8 +8011,15 @@ pub static AiFolderOpenFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M928 444H820V330.4c0-17.7-14.3-32-32-32H473L355.7 186.2a8.15 8.15 0 0 0-5.5-2.2H96c-17.7 0-32 14.3-32 32v592c0 17.7 14.3 32 32 32h698c13 0 24.8-7.9 29.7-20l134-332c1.5-3.8 2.3-7.9 2.3-12 0-17.7-14.3-32-32-32zm-180 0H238c-13 0-24.8 7.9-29.7 20L136 643.2V256h188.5l119.6 114.4H748V444z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFolderOpenOutlined, "Folder Open Outlined"))]
 pub static AiFolderOpenOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFolderOpenOutlined",
+    label: "Folder Open Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5644,8 +8034,15 @@ pub static AiFolderOpenOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M928 444H820V330.4c0-17.7-14.3-32-32-32H473L355.7 186.2a8.15 8.15 0 0 0-5.5-2.2H96c-17.7 0-32 14.3-32 32v592c0 17.7 14.3 32 32 32h698c13 0 24.8-7.9 29.7-20l134-332c1.5-3.8 2.3-7.9 2.3-12 0-17.7-14.3-32-32-32zM136 256h188.5l119.6 114.4H748V444H238c-13 0-24.8 7.9-29.7 20L136 643.2V256zm635.3 512H159l103.3-256h612.4L771.3 768z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFolderOpenTwotone, "Folder Open Twotone"))]
 pub static AiFolderOpenTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFolderOpenTwotone",
+    label: "Folder Open Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5661,8 +8058,15 @@ pub static AiFolderOpenTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M928 444H820V330.4c0-17.7-14.3-32-32-32H473L355.7 186.2a8.15 8.15 0 0 0-5.5-2.2H96c-17.7 0-32 14.3-32 32v592c0 17.7 14.3 32 32 32h698c13 0 24.8-7.9 29.7-20l134-332c1.5-3.8 2.3-7.9 2.3-12 0-17.7-14.3-32-32-32zM136 256h188.5l119.6 114.4H748V444H238c-13 0-24.8 7.9-29.7 20L136 643.2V256zm635.3 512H159l103.3-256h612.4L771.3 768z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFolderOutlined, "Folder Outlined"))]
 pub static AiFolderOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFolderOutlined",
+    label: "Folder Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5677,8 +8081,15 @@ pub static AiFolderOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 298.4H521L403.7 186.2a8.15 8.15 0 0 0-5.5-2.2H144c-17.7 0-32 14.3-32 32v592c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V330.4c0-17.7-14.3-32-32-32zM840 768H184V256h188.5l119.6 114.4H840V768z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFolderTwotone, "Folder Twotone"))]
 pub static AiFolderTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFolderTwotone",
+    label: "Folder Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5694,8 +8105,15 @@ pub static AiFolderTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path fill="#E6E6E6" d="M372.5 256H184v512h656V370.4H492.1z" class="twotone-icon-secondary-color" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFolderViewOutlined, "Folder View Outlined"))]
 pub static AiFolderViewOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFolderViewOutlined",
+    label: "Folder View Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5715,8 +8133,15 @@ pub static AiFolderViewOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M880 298.4H521L403.7 186.2c-1.5-1.4-3.5-2.2-5.5-2.2H144c-17.7 0-32 14.3-32 32v592c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V330.4c0-17.7-14.3-32-32-32zM840 768H184V256h188.5l119.6 114.4H840V768z" p-id="13663" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFontColorsOutlined, "Font Colors Outlined"))]
 pub static AiFontColorsOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFontColorsOutlined",
+    label: "Font Colors Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5731,8 +8156,15 @@ pub static AiFontColorsOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M904 816H120c-4.4 0-8 3.6-8 8v80c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-80c0-4.4-3.6-8-8-8zm-650.3-80h85c4.2 0 8-2.7 9.3-6.8l53.7-166h219.2l53.2 166c1.3 4 5 6.8 9.3 6.8h89.1c1.1 0 2.2-.2 3.2-.5a9.7 9.7 0 0 0 6-12.4L573.6 118.6a9.9 9.9 0 0 0-9.2-6.6H462.1c-4.2 0-7.9 2.6-9.2 6.6L244.5 723.1c-.4 1-.5 2.1-.5 3.2-.1 5.3 4.3 9.7 9.7 9.7zm255.9-516.1h4.1l83.8 263.8H424.9l84.7-263.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFontSizeOutlined, "Font Size Outlined"))]
 pub static AiFontSizeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFontSizeOutlined",
+    label: "Font Size Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5747,8 +8179,15 @@ pub static AiFontSizeOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M920 416H616c-4.4 0-8 3.6-8 8v112c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8v-56h60v320h-46c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h164c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8h-46V480h60v56c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V424c0-4.4-3.6-8-8-8zM656 296V168c0-4.4-3.6-8-8-8H104c-4.4 0-8 3.6-8 8v128c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-64h168v560h-92c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h264c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-92V232h168v64c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiForkOutlined, "Fork Outlined"))]
 pub static AiForkOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiForkOutlined",
+    label: "Fork Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5763,8 +8202,15 @@ pub static AiForkOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M752 100c-61.8 0-112 50.2-112 112 0 47.7 29.9 88.5 72 104.6v27.6L512 601.4 312 344.2v-27.6c42.1-16.1 72-56.9 72-104.6 0-61.8-50.2-112-112-112s-112 50.2-112 112c0 50.6 33.8 93.5 80 107.3v34.4c0 9.7 3.3 19.3 9.3 27L476 672.3v33.6c-44.2 15-76 56.9-76 106.1 0 61.8 50.2 112 112 112s112-50.2 112-112c0-49.2-31.8-91-76-106.1v-33.6l226.7-291.6c6-7.7 9.3-17.3 9.3-27v-34.4c46.2-13.8 80-56.7 80-107.3 0-61.8-50.2-112-112-112zM224 212a48.01 48.01 0 0 1 96 0 48.01 48.01 0 0 1-96 0zm336 600a48.01 48.01 0 0 1-96 0 48.01 48.01 0 0 1 96 0zm192-552a48.01 48.01 0 0 1 0-96 48.01 48.01 0 0 1 0 96z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFormOutlined, "Form Outlined"))]
 pub static AiFormOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFormOutlined",
+    label: "Form Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5780,8 +8226,15 @@ pub static AiFormOutlined: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M355.9 534.9L354 653.8c-.1 8.9 7.1 16.2 16 16.2h.4l118-2.9c2-.1 4-.9 5.4-2.3l415.9-415c3.1-3.1 3.1-8.2 0-11.3L785.4 114.3c-1.6-1.6-3.6-2.3-5.7-2.3s-4.1.8-5.7 2.3l-415.8 415a8.3 8.3 0 0 0-2.3 5.6zm63.5 23.6L779.7 199l45.2 45.1-360.5 359.7-45.7 1.1.7-46.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFormatPainterFilled, "Format Painter Filled"))]
 pub static AiFormatPainterFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFormatPainterFilled",
+    label: "Format Painter Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5799,8 +8252,15 @@ pub static AiFormatPainterFilled: &icondata_core::IconData = &icondata_core::Ico
 <path d="M840 192h-56v-72c0-13.3-10.7-24-24-24H168c-13.3 0-24 10.7-24 24v272c0 13.3 10.7 24 24 24h592c13.3 0 24-10.7 24-24V256h32v200H465c-22.1 0-40 17.9-40 40v136h-44c-4.4 0-8 3.6-8 8v228c0 1.1 0.2 2.2 0.6 3.1-0.4 1.6-0.6 3.2-0.6 4.9 0 46.4 37.6 84 84 84s84-37.6 84-84c0-1.7-0.2-3.3-0.6-4.9 0.4-1 0.6-2 0.6-3.1V640c0-4.4-3.6-8-8-8h-44V520h351c22.1 0 40-17.9 40-40V232c0-22.1-17.9-40-40-40z" p-id="11410" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFormatPainterOutlined, "Format Painter Outlined"))]
 pub static AiFormatPainterOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFormatPainterOutlined",
+    label: "Format Painter Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5818,8 +8278,15 @@ pub static AiFormatPainterOutlined: &icondata_core::IconData = &icondata_core::I
 <path d="M840 192h-56v-72c0-13.3-10.7-24-24-24H168c-13.3 0-24 10.7-24 24v272c0 13.3 10.7 24 24 24h592c13.3 0 24-10.7 24-24V256h32v200H465c-22.1 0-40 17.9-40 40v136h-44c-4.4 0-8 3.6-8 8v228c0 0.6 0.1 1.3 0.2 1.9-0.1 2-0.2 4.1-0.2 6.1 0 46.4 37.6 84 84 84s84-37.6 84-84c0-2.1-0.1-4.1-0.2-6.1 0.1-0.6 0.2-1.2 0.2-1.9V640c0-4.4-3.6-8-8-8h-44V520h351c22.1 0 40-17.9 40-40V232c0-22.1-17.9-40-40-40zM720 352H208V160h512v192zM477 876c0 11-9 20-20 20s-20-9-20-20V696h40v180z" p-id="11174" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiForwardFilled, "Forward Filled"))]
 pub static AiForwardFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiForwardFilled",
+    label: "Forward Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5834,8 +8301,15 @@ pub static AiForwardFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M825.8 498L538.4 249.9c-10.7-9.2-26.4-.9-26.4 14v496.3c0 14.9 15.7 23.2 26.4 14L825.8 526c8.3-7.2 8.3-20.8 0-28zm-320 0L218.4 249.9c-10.7-9.2-26.4-.9-26.4 14v496.3c0 14.9 15.7 23.2 26.4 14L505.8 526c4.1-3.6 6.2-8.8 6.2-14 0-5.2-2.1-10.4-6.2-14z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiForwardOutlined, "Forward Outlined"))]
 pub static AiForwardOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiForwardOutlined",
+    label: "Forward Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5850,8 +8324,15 @@ pub static AiForwardOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M825.8 498L538.4 249.9c-10.7-9.2-26.4-.9-26.4 14v496.3c0 14.9 15.7 23.2 26.4 14L825.8 526c8.3-7.2 8.3-20.8 0-28zm-320 0L218.4 249.9c-10.7-9.2-26.4-.9-26.4 14v496.3c0 14.9 15.7 23.2 26.4 14L505.8 526c4.1-3.6 6.2-8.8 6.2-14 0-5.2-2.1-10.4-6.2-14z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFrownFilled, "Frown Filled"))]
 pub static AiFrownFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFrownFilled",
+    label: "Frown Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5866,8 +8347,15 @@ pub static AiFrownFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zM288 421a48.01 48.01 0 0 1 96 0 48.01 48.01 0 0 1-96 0zm376 272h-48.1c-4.2 0-7.8-3.2-8.1-7.4C604 636.1 562.5 597 512 597s-92.1 39.1-95.8 88.6c-.3 4.2-3.9 7.4-8.1 7.4H360a8 8 0 0 1-8-8.4c4.4-84.3 74.5-151.6 160-151.6s155.6 67.3 160 151.6a8 8 0 0 1-8 8.4zm24-224a48.01 48.01 0 0 1 0-96 48.01 48.01 0 0 1 0 96z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFrownOutlined, "Frown Outlined"))]
 pub static AiFrownOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFrownOutlined",
+    label: "Frown Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5882,8 +8370,15 @@ pub static AiFrownOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M288 421a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm352 0a48 48 0 1 0 96 0 48 48 0 1 0-96 0zM512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm263 711c-34.2 34.2-74 61-118.3 79.8C611 874.2 562.3 884 512 884c-50.3 0-99-9.8-144.8-29.2A370.4 370.4 0 0 1 248.9 775c-34.2-34.2-61-74-79.8-118.3C149.8 611 140 562.3 140 512s9.8-99 29.2-144.8A370.4 370.4 0 0 1 249 248.9c34.2-34.2 74-61 118.3-79.8C413 149.8 461.7 140 512 140c50.3 0 99 9.8 144.8 29.2A370.4 370.4 0 0 1 775.1 249c34.2 34.2 61 74 79.8 118.3C874.2 413 884 461.7 884 512s-9.8 99-29.2 144.8A368.89 368.89 0 0 1 775 775zM512 533c-85.5 0-155.6 67.3-160 151.6a8 8 0 0 0 8 8.4h48.1c4.2 0 7.8-3.2 8.1-7.4C420 636.1 461.5 597 512 597s92.1 39.1 95.8 88.6c.3 4.2 3.9 7.4 8.1 7.4H664a8 8 0 0 0 8-8.4C667.6 600.3 597.5 533 512 533z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFrownTwotone, "Frown Twotone"))]
 pub static AiFrownTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFrownTwotone",
+    label: "Frown Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5900,8 +8395,15 @@ pub static AiFrownTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M288 421a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm224 112c-85.5 0-155.6 67.3-160 151.6a8 8 0 0 0 8 8.4h48.1c4.2 0 7.8-3.2 8.1-7.4 3.7-49.5 45.3-88.6 95.8-88.6s92 39.1 95.8 88.6c.3 4.2 3.9 7.4 8.1 7.4H664a8 8 0 0 0 8-8.4C667.6 600.3 597.5 533 512 533zm128-112a48 48 0 1 0 96 0 48 48 0 1 0-96 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFullscreenExitOutlined, "Fullscreen Exit Outlined"))]
 pub static AiFullscreenExitOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFullscreenExitOutlined",
+    label: "Fullscreen Exit Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5916,8 +8418,15 @@ pub static AiFullscreenExitOutlined: &icondata_core::IconData = &icondata_core::
     data: r###"<path d="M391 240.9c-.8-6.6-8.9-9.4-13.6-4.7l-43.7 43.7L200 146.3a8.03 8.03 0 0 0-11.3 0l-42.4 42.3a8.03 8.03 0 0 0 0 11.3L280 333.6l-43.9 43.9a8.01 8.01 0 0 0 4.7 13.6L401 410c5.1.6 9.5-3.7 8.9-8.9L391 240.9zm10.1 373.2L240.8 633c-6.6.8-9.4 8.9-4.7 13.6l43.9 43.9L146.3 824a8.03 8.03 0 0 0 0 11.3l42.4 42.3c3.1 3.1 8.2 3.1 11.3 0L333.7 744l43.7 43.7A8.01 8.01 0 0 0 391 783l18.9-160.1c.6-5.1-3.7-9.4-8.8-8.8zm221.8-204.2L783.2 391c6.6-.8 9.4-8.9 4.7-13.6L744 333.6 877.7 200c3.1-3.1 3.1-8.2 0-11.3l-42.4-42.3a8.03 8.03 0 0 0-11.3 0L690.3 279.9l-43.7-43.7a8.01 8.01 0 0 0-13.6 4.7L614.1 401c-.6 5.2 3.7 9.5 8.8 8.9zM744 690.4l43.9-43.9a8.01 8.01 0 0 0-4.7-13.6L623 614c-5.1-.6-9.5 3.7-8.9 8.9L633 783.1c.8 6.6 8.9 9.4 13.6 4.7l43.7-43.7L824 877.7c3.1 3.1 8.2 3.1 11.3 0l42.4-42.3c3.1-3.1 3.1-8.2 0-11.3L744 690.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFullscreenOutlined, "Fullscreen Outlined"))]
 pub static AiFullscreenOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFullscreenOutlined",
+    label: "Fullscreen Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5932,8 +8441,15 @@ pub static AiFullscreenOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M290 236.4l43.9-43.9a8.01 8.01 0 0 0-4.7-13.6L169 160c-5.1-.6-9.5 3.7-8.9 8.9L179 329.1c.8 6.6 8.9 9.4 13.6 4.7l43.7-43.7L370 423.7c3.1 3.1 8.2 3.1 11.3 0l42.4-42.3c3.1-3.1 3.1-8.2 0-11.3L290 236.4zm352.7 187.3c3.1 3.1 8.2 3.1 11.3 0l133.7-133.6 43.7 43.7a8.01 8.01 0 0 0 13.6-4.7L863.9 169c.6-5.1-3.7-9.5-8.9-8.9L694.8 179c-6.6.8-9.4 8.9-4.7 13.6l43.9 43.9L600.3 370a8.03 8.03 0 0 0 0 11.3l42.4 42.4zM845 694.9c-.8-6.6-8.9-9.4-13.6-4.7l-43.7 43.7L654 600.3a8.03 8.03 0 0 0-11.3 0l-42.4 42.3a8.03 8.03 0 0 0 0 11.3L734 787.6l-43.9 43.9a8.01 8.01 0 0 0 4.7 13.6L855 864c5.1.6 9.5-3.7 8.9-8.9L845 694.9zm-463.7-94.6a8.03 8.03 0 0 0-11.3 0L236.3 733.9l-43.7-43.7a8.01 8.01 0 0 0-13.6 4.7L160.1 855c-.6 5.1 3.7 9.5 8.9 8.9L329.2 845c6.6-.8 9.4-8.9 4.7-13.6L290 787.6 423.7 654c3.1-3.1 3.1-8.2 0-11.3l-42.4-42.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFunctionOutlined, "Function Outlined"))]
 pub static AiFunctionOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFunctionOutlined",
+    label: "Function Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5951,8 +8467,15 @@ pub static AiFunctionOutlined: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M841 370c3-3.3 2.7-8.3-0.6-11.3-1.5-1.3-3.4-2.1-5.3-2.1h-72.6c-2.4 0-4.6 1-6.1 2.8L633.5 504.6c-2.9 3.4-7.9 3.8-11.3 0.9-0.9-0.8-1.6-1.7-2.1-2.8l-63.5-141.3c-1.3-2.9-4.1-4.7-7.3-4.7H380.7l0.9-4.7 8-42.3c10.5-55.4 38-81.4 85.8-81.4 18.6 0 35.5 1.7 48.8 4.7l14.1-66.8c-22.6-4.7-35.2-6.1-54.9-6.1-103.3 0-156.4 44.3-175.9 147.3l-9.4 49.4h-97.6c-3.8 0-7.1 2.7-7.8 6.4L181.9 415c-0.9 4.3 1.9 8.6 6.2 9.5 0.5 0.1 1.1 0.2 1.6 0.2H284l-89 429.9c-0.9 4.3 1.9 8.6 6.2 9.5 0.5 0.1 1.1 0.2 1.6 0.2H269c3.8 0 7.1-2.7 7.8-6.4l89.7-433.1h135.8l68.2 139.1c1.4 2.9 1 6.4-1.2 8.8l-180.6 203c-2.9 3.3-2.6 8.4 0.7 11.3 1.5 1.3 3.4 2 5.3 2h72.7c2.4 0 4.6-1 6.1-2.8l123.7-146.7c2.8-3.4 7.9-3.8 11.3-1 0.9 0.8 1.6 1.7 2.1 2.8L676.4 784c1.3 2.8 4.1 4.7 7.3 4.7h64.6c4.4 0 8-3.6 8-8 0-1.2-0.3-2.4-0.8-3.5l-95.2-198.9c-1.4-2.9-0.9-6.4 1.3-8.8L841 370z" p-id="12239" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFundFilled, "Fund Filled"))]
 pub static AiFundFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFundFilled",
+    label: "Fund Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5967,8 +8490,15 @@ pub static AiFundFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M926 164H94c-17.7 0-32 14.3-32 32v640c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V196c0-17.7-14.3-32-32-32zm-92.3 194.4l-297 297.2a8.03 8.03 0 0 1-11.3 0L410.9 541.1 238.4 713.7a8.03 8.03 0 0 1-11.3 0l-36.8-36.8a8.03 8.03 0 0 1 0-11.3l214.9-215c3.1-3.1 8.2-3.1 11.3 0L531 565l254.5-254.6c3.1-3.1 8.2-3.1 11.3 0l36.8 36.8c3.2 3 3.2 8.1.1 11.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFundOutlined, "Fund Outlined"))]
 pub static AiFundOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFundOutlined",
+    label: "Fund Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5983,8 +8513,15 @@ pub static AiFundOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M926 164H94c-17.7 0-32 14.3-32 32v640c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V196c0-17.7-14.3-32-32-32zm-40 632H134V236h752v560zm-658.9-82.3c3.1 3.1 8.2 3.1 11.3 0l172.5-172.5 114.4 114.5c3.1 3.1 8.2 3.1 11.3 0l297-297.2c3.1-3.1 3.1-8.2 0-11.3l-36.8-36.8a8.03 8.03 0 0 0-11.3 0L531 565 416.6 450.5a8.03 8.03 0 0 0-11.3 0l-214.9 215a8.03 8.03 0 0 0 0 11.3l36.7 36.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFundProjectionScreenOutlined, "Fund Projection Screen Outlined"))]
 pub static AiFundProjectionScreenOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFundProjectionScreenOutlined",
+    label: "Fund Projection Screen Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6003,8 +8540,15 @@ pub static AiFundProjectionScreenOutlined: &icondata_core::IconData = &icondata_
 <path d="M904 160H548V96c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v64H120c-17.7 0-32 14.3-32 32v520c0 17.7 14.3 32 32 32h356.4v32L311.6 884.1c-3.7 2.4-4.7 7.3-2.3 11l30.3 47.2v0.1c2.4 3.7 7.4 4.7 11.1 2.3L512 838.9l161.3 105.8c3.7 2.4 8.7 1.4 11.1-2.3v-0.1l30.3-47.2c2.4-3.7 1.3-8.6-2.3-11L548 776.3V744h356c17.7 0 32-14.3 32-32V192c0-17.7-14.3-32-32-32z m-40 512H160V232h704v440z" p-id="14139" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFundTwotone, "Fund Twotone"))]
 pub static AiFundTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFundTwotone",
+    label: "Fund Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6021,8 +8565,15 @@ pub static AiFundTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M229.1 709.7c3.1 3.1 8.2 3.1 11.3 0l172.5-172.5 114.4 114.5c3.1 3.1 8.2 3.1 11.3 0l297-297.2c3.1-3.1 3.1-8.2 0-11.3l-36.8-36.8a8.03 8.03 0 0 0-11.3 0L533 561 418.6 446.5a8.03 8.03 0 0 0-11.3 0l-214.9 215a8.03 8.03 0 0 0 0 11.3l36.7 36.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFundViewOutlined, "Fund View Outlined"))]
 pub static AiFundViewOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFundViewOutlined",
+    label: "Fund View Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6043,8 +8594,15 @@ pub static AiFundViewOutlined: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M724.9 338.1l-36.8-36.8c-3.1-3.1-8.2-3.1-11.3 0L493 485.3l-86.1-86.2c-3.1-3.1-8.2-3.1-11.3 0L251.3 543.4c-3.1 3.1-3.1 8.2 0 11.3l36.8 36.8c3.1 3.1 8.2 3.1 11.3 0l101.8-101.8 86.1 86.2c3.1 3.1 8.2 3.1 11.3 0l226.3-226.5c3.2-3.1 3.2-8.2 0-11.3z" p-id="14260" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFunnelPlotFilled, "Funnel Plot Filled"))]
 pub static AiFunnelPlotFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFunnelPlotFilled",
+    label: "Funnel Plot Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6059,8 +8617,15 @@ pub static AiFunnelPlotFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M336.7 586h350.6l84.9-148H251.8zm543.4-432H143.9c-24.5 0-39.8 26.7-27.5 48L215 374h594l98.7-172c12.2-21.3-3.1-48-27.6-48zM349 838c0 17.7 14.2 32 31.8 32h262.4c17.6 0 31.8-14.3 31.8-32V650H349v188z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFunnelPlotOutlined, "Funnel Plot Outlined"))]
 pub static AiFunnelPlotOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFunnelPlotOutlined",
+    label: "Funnel Plot Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6075,8 +8640,15 @@ pub static AiFunnelPlotOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M880.1 154H143.9c-24.5 0-39.8 26.7-27.5 48L349 607.4V838c0 17.7 14.2 32 31.8 32h262.4c17.6 0 31.8-14.3 31.8-32V607.4L907.7 202c12.2-21.3-3.1-48-27.6-48zM603.4 798H420.6V650h182.9v148zm9.6-226.6l-8.4 14.6H419.3l-8.4-14.6L334.4 438h355.2L613 571.4zM726.3 374H297.7l-85-148h598.6l-85 148z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiFunnelPlotTwotone, "Funnel Plot Twotone"))]
 pub static AiFunnelPlotTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiFunnelPlotTwotone",
+    label: "Funnel Plot Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6092,8 +8664,15 @@ pub static AiFunnelPlotTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M880.1 154H143.9c-24.5 0-39.8 26.7-27.5 48L349 607.4V838c0 17.7 14.2 32 31.8 32h262.4c17.6 0 31.8-14.3 31.8-32V607.4L907.7 202c12.2-21.3-3.1-48-27.6-48zM603.5 798H420.6V650h182.9v148zm9.5-226.6l-8.4 14.6H419.3l-8.4-14.6L334.4 438h355.2L613 571.4zM726.3 374H297.7l-85-148h598.6l-85 148z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGatewayOutlined, "Gateway Outlined"))]
 pub static AiGatewayOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGatewayOutlined",
+    label: "Gateway Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6108,8 +8687,15 @@ pub static AiGatewayOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M928 392c8.8 0 16-7.2 16-16V192c0-8.8-7.2-16-16-16H744c-8.8 0-16 7.2-16 16v56H296v-56c0-8.8-7.2-16-16-16H96c-8.8 0-16 7.2-16 16v184c0 8.8 7.2 16 16 16h56v240H96c-8.8 0-16 7.2-16 16v184c0 8.8 7.2 16 16 16h184c8.8 0 16-7.2 16-16v-56h432v56c0 8.8 7.2 16 16 16h184c8.8 0 16-7.2 16-16V648c0-8.8-7.2-16-16-16h-56V392h56zM792 240h88v88h-88v-88zm-648 88v-88h88v88h-88zm88 456h-88v-88h88v88zm648-88v88h-88v-88h88zm-80-64h-56c-8.8 0-16 7.2-16 16v56H296v-56c0-8.8-7.2-16-16-16h-56V392h56c8.8 0 16-7.2 16-16v-56h432v56c0 8.8 7.2 16 16 16h56v240z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGifOutlined, "GIF Outlined"))]
 pub static AiGifOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGifOutlined",
+    label: "GIF Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6127,8 +8713,15 @@ pub static AiGifOutlined: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M944 299H692c-4.4 0-8 3.6-8 8v406c0 4.4 3.6 8 8 8h59.2c4.4 0 8-3.6 8-8V549.9h168.2c4.4 0 8-3.6 8-8V495c0-4.4-3.6-8-8-8H759.2V364.2H944c4.4 0 8-3.6 8-8V307c0-4.4-3.6-8-8-8zM588 300h-56c-4.4 0-8 3.6-8 8v406c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V308c0-4.4-3.6-8-8-8zM452 500.9H290.5c-4.4 0-8 3.6-8 8v43.7c0 4.4 3.6 8 8 8h94.9l-0.3 8.9c-1.2 58.8-45.6 98.5-110.9 98.5-76.2 0-123.9-59.7-123.9-156.7 0-95.8 46.8-155.2 121.5-155.2 54.8 0 93.1 26.9 108.5 75.4h76.2c-13.6-87.2-86-143.4-184.7-143.4C150 288 72 375.2 72 511.9 72 650.2 149.1 736 273 736c114.1 0 187-70.7 187-181.6v-45.5c0-4.4-3.6-8-8-8z" p-id="12476" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGiftFilled, "Gift Filled"))]
 pub static AiGiftFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGiftFilled",
+    label: "Gift Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6143,8 +8736,15 @@ pub static AiGiftFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M160 894c0 17.7 14.3 32 32 32h286V550H160v344zm386 32h286c17.7 0 32-14.3 32-32V550H546v376zm334-616H732.4c13.6-21.4 21.6-46.8 21.6-74 0-76.1-61.9-138-138-138-41.4 0-78.7 18.4-104 47.4-25.3-29-62.6-47.4-104-47.4-76.1 0-138 61.9-138 138 0 27.2 7.9 52.6 21.6 74H144c-17.7 0-32 14.3-32 32v140h366V310h68v172h366V342c0-17.7-14.3-32-32-32zm-402-4h-70c-38.6 0-70-31.4-70-70s31.4-70 70-70 70 31.4 70 70v70zm138 0h-70v-70c0-38.6 31.4-70 70-70s70 31.4 70 70-31.4 70-70 70z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGiftOutlined, "Gift Outlined"))]
 pub static AiGiftOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGiftOutlined",
+    label: "Gift Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6159,8 +8759,15 @@ pub static AiGiftOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M880 310H732.4c13.6-21.4 21.6-46.8 21.6-74 0-76.1-61.9-138-138-138-41.4 0-78.7 18.4-104 47.4-25.3-29-62.6-47.4-104-47.4-76.1 0-138 61.9-138 138 0 27.2 7.9 52.6 21.6 74H144c-17.7 0-32 14.3-32 32v200c0 4.4 3.6 8 8 8h40v344c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V550h40c4.4 0 8-3.6 8-8V342c0-17.7-14.3-32-32-32zm-334-74c0-38.6 31.4-70 70-70s70 31.4 70 70-31.4 70-70 70h-70v-70zm-138-70c38.6 0 70 31.4 70 70v70h-70c-38.6 0-70-31.4-70-70s31.4-70 70-70zM180 482V378h298v104H180zm48 68h250v308H228V550zm568 308H546V550h250v308zm48-376H546V378h298v104z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGiftTwotone, "Gift Twotone"))]
 pub static AiGiftTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGiftTwotone",
+    label: "Gift Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6176,8 +8783,15 @@ pub static AiGiftTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M880 310H732.4c13.6-21.4 21.6-46.8 21.6-74 0-76.1-61.9-138-138-138-41.4 0-78.7 18.4-104 47.4-25.3-29-62.6-47.4-104-47.4-76.1 0-138 61.9-138 138 0 27.2 7.9 52.6 21.6 74H144c-17.7 0-32 14.3-32 32v200c0 4.4 3.6 8 8 8h40v344c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V550h40c4.4 0 8-3.6 8-8V342c0-17.7-14.3-32-32-32zM478 858H228V550h250v308zm0-376H180V378h298v104zm0-176h-70c-38.6 0-70-31.4-70-70s31.4-70 70-70 70 31.4 70 70v70zm68-70c0-38.6 31.4-70 70-70s70 31.4 70 70-31.4 70-70 70h-70v-70zm250 622H546V550h250v308zm48-376H546V378h298v104z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGithubFilled, "Github Filled"))]
 pub static AiGithubFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGithubFilled",
+    label: "Github Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6192,8 +8806,15 @@ pub static AiGithubFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M511.6 76.3C264.3 76.2 64 276.4 64 523.5 64 718.9 189.3 885 363.8 946c23.5 5.9 19.9-10.8 19.9-22.2v-77.5c-135.7 15.9-141.2-73.9-150.3-88.9C215 726 171.5 718 184.5 703c30.9-15.9 62.4 4 98.9 57.9 26.4 39.1 77.9 32.5 104 26 5.7-23.5 17.9-44.5 34.7-60.8-140.6-25.2-199.2-111-199.2-213 0-49.5 16.3-95 48.3-131.7-20.4-60.5 1.9-112.3 4.9-120 58.1-5.2 118.5 41.6 123.2 45.3 33-8.9 70.7-13.6 112.9-13.6 42.4 0 80.2 4.9 113.5 13.9 11.3-8.6 67.3-48.8 121.3-43.9 2.9 7.7 24.7 58.3 5.5 118 32.4 36.8 48.9 82.7 48.9 132.3 0 102.2-59 188.1-200 212.9a127.5 127.5 0 0 1 38.1 91v112.5c.8 9 0 17.9 15 17.9 177.1-59.7 304.6-227 304.6-424.1 0-247.2-200.4-447.3-447.5-447.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGithubOutlined, "Github Outlined"))]
 pub static AiGithubOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGithubOutlined",
+    label: "Github Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6208,8 +8829,15 @@ pub static AiGithubOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M511.6 76.3C264.3 76.2 64 276.4 64 523.5 64 718.9 189.3 885 363.8 946c23.5 5.9 19.9-10.8 19.9-22.2v-77.5c-135.7 15.9-141.2-73.9-150.3-88.9C215 726 171.5 718 184.5 703c30.9-15.9 62.4 4 98.9 57.9 26.4 39.1 77.9 32.5 104 26 5.7-23.5 17.9-44.5 34.7-60.8-140.6-25.2-199.2-111-199.2-213 0-49.5 16.3-95 48.3-131.7-20.4-60.5 1.9-112.3 4.9-120 58.1-5.2 118.5 41.6 123.2 45.3 33-8.9 70.7-13.6 112.9-13.6 42.4 0 80.2 4.9 113.5 13.9 11.3-8.6 67.3-48.8 121.3-43.9 2.9 7.7 24.7 58.3 5.5 118 32.4 36.8 48.9 82.7 48.9 132.3 0 102.2-59 188.1-200 212.9a127.5 127.5 0 0 1 38.1 91v112.5c.8 9 0 17.9 15 17.9 177.1-59.7 304.6-227 304.6-424.1 0-247.2-200.4-447.3-447.5-447.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGitlabFilled, "Gitlab Filled"))]
 pub static AiGitlabFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGitlabFilled",
+    label: "Gitlab Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6224,8 +8852,15 @@ pub static AiGitlabFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M910.5 553.2l-109-370.8c-6.8-20.4-23.1-34.1-44.9-34.1s-39.5 12.3-46.3 32.7l-72.2 215.4H386.2L314 181.1c-6.8-20.4-24.5-32.7-46.3-32.7s-39.5 13.6-44.9 34.1L113.9 553.2c-4.1 13.6 1.4 28.6 12.3 36.8l385.4 289 386.7-289c10.8-8.1 16.3-23.1 12.2-36.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGitlabOutlined, "Gitlab Outlined"))]
 pub static AiGitlabOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGitlabOutlined",
+    label: "Gitlab Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6240,8 +8875,15 @@ pub static AiGitlabOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M913.9 552.2L805 181.4v-.1c-7.6-22.9-25.7-36.5-48.3-36.5-23.4 0-42.5 13.5-49.7 35.2l-71.4 213H388.8l-71.4-213c-7.2-21.7-26.3-35.2-49.7-35.2-23.1 0-42.5 14.8-48.4 36.6L110.5 552.2c-4.4 14.7 1.2 31.4 13.5 40.7l368.5 276.4c2.6 3.6 6.2 6.3 10.4 7.8l8.6 6.4 8.5-6.4c4.9-1.7 9-4.7 11.9-8.9l368.4-275.4c12.4-9.2 18-25.9 13.6-40.6zM751.7 193.4c1-1.8 2.9-1.9 3.5-1.9 1.1 0 2.5.3 3.4 3L818 394.3H684.5l67.2-200.9zm-487.4 1c.9-2.6 2.3-2.9 3.4-2.9 2.7 0 2.9.1 3.4 1.7l67.3 201.2H206.5l57.8-200zM158.8 558.7l28.2-97.3 202.4 270.2-230.6-172.9zm73.9-116.4h122.1l90.8 284.3-212.9-284.3zM512.9 776L405.7 442.3H620L512.9 776zm157.9-333.7h119.5L580 723.1l90.8-280.8zm-40.7 293.9l207.3-276.7 29.5 99.2-236.8 177.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGlobalOutlined, "Global Outlined"))]
 pub static AiGlobalOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGlobalOutlined",
+    label: "Global Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6256,8 +8898,15 @@ pub static AiGlobalOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M854.4 800.9c.2-.3.5-.6.7-.9C920.6 722.1 960 621.7 960 512s-39.4-210.1-104.8-288c-.2-.3-.5-.5-.7-.8-1.1-1.3-2.1-2.5-3.2-3.7-.4-.5-.8-.9-1.2-1.4l-4.1-4.7-.1-.1c-1.5-1.7-3.1-3.4-4.6-5.1l-.1-.1c-3.2-3.4-6.4-6.8-9.7-10.1l-.1-.1-4.8-4.8-.3-.3c-1.5-1.5-3-2.9-4.5-4.3-.5-.5-1-1-1.6-1.5-1-1-2-1.9-3-2.8-.3-.3-.7-.6-1-1C736.4 109.2 629.5 64 512 64s-224.4 45.2-304.3 119.2c-.3.3-.7.6-1 1-1 .9-2 1.9-3 2.9-.5.5-1 1-1.6 1.5-1.5 1.4-3 2.9-4.5 4.3l-.3.3-4.8 4.8-.1.1c-3.3 3.3-6.5 6.7-9.7 10.1l-.1.1c-1.6 1.7-3.1 3.4-4.6 5.1l-.1.1c-1.4 1.5-2.8 3.1-4.1 4.7-.4.5-.8.9-1.2 1.4-1.1 1.2-2.1 2.5-3.2 3.7-.2.3-.5.5-.7.8C103.4 301.9 64 402.3 64 512s39.4 210.1 104.8 288c.2.3.5.6.7.9l3.1 3.7c.4.5.8.9 1.2 1.4l4.1 4.7c0 .1.1.1.1.2 1.5 1.7 3 3.4 4.6 5l.1.1c3.2 3.4 6.4 6.8 9.6 10.1l.1.1c1.6 1.6 3.1 3.2 4.7 4.7l.3.3c3.3 3.3 6.7 6.5 10.1 9.6 80.1 74 187 119.2 304.5 119.2s224.4-45.2 304.3-119.2a300 300 0 0 0 10-9.6l.3-.3c1.6-1.6 3.2-3.1 4.7-4.7l.1-.1c3.3-3.3 6.5-6.7 9.6-10.1l.1-.1c1.5-1.7 3.1-3.3 4.6-5 0-.1.1-.1.1-.2 1.4-1.5 2.8-3.1 4.1-4.7.4-.5.8-.9 1.2-1.4a99 99 0 0 0 3.3-3.7zm4.1-142.6c-13.8 32.6-32 62.8-54.2 90.2a444.07 444.07 0 0 0-81.5-55.9c11.6-46.9 18.8-98.4 20.7-152.6H887c-3 40.9-12.6 80.6-28.5 118.3zM887 484H743.5c-1.9-54.2-9.1-105.7-20.7-152.6 29.3-15.6 56.6-34.4 81.5-55.9A373.86 373.86 0 0 1 887 484zM658.3 165.5c39.7 16.8 75.8 40 107.6 69.2a394.72 394.72 0 0 1-59.4 41.8c-15.7-45-35.8-84.1-59.2-115.4 3.7 1.4 7.4 2.9 11 4.4zm-90.6 700.6c-9.2 7.2-18.4 12.7-27.7 16.4V697a389.1 389.1 0 0 1 115.7 26.2c-8.3 24.6-17.9 47.3-29 67.8-17.4 32.4-37.8 58.3-59 75.1zm59-633.1c11 20.6 20.7 43.3 29 67.8A389.1 389.1 0 0 1 540 327V141.6c9.2 3.7 18.5 9.1 27.7 16.4 21.2 16.7 41.6 42.6 59 75zM540 640.9V540h147.5c-1.6 44.2-7.1 87.1-16.3 127.8l-.3 1.2A445.02 445.02 0 0 0 540 640.9zm0-156.9V383.1c45.8-2.8 89.8-12.5 130.9-28.1l.3 1.2c9.2 40.7 14.7 83.5 16.3 127.8H540zm-56 56v100.9c-45.8 2.8-89.8 12.5-130.9 28.1l-.3-1.2c-9.2-40.7-14.7-83.5-16.3-127.8H484zm-147.5-56c1.6-44.2 7.1-87.1 16.3-127.8l.3-1.2c41.1 15.6 85 25.3 130.9 28.1V484H336.5zM484 697v185.4c-9.2-3.7-18.5-9.1-27.7-16.4-21.2-16.7-41.7-42.7-59.1-75.1-11-20.6-20.7-43.3-29-67.8 37.2-14.6 75.9-23.3 115.8-26.1zm0-370a389.1 389.1 0 0 1-115.7-26.2c8.3-24.6 17.9-47.3 29-67.8 17.4-32.4 37.8-58.4 59.1-75.1 9.2-7.2 18.4-12.7 27.7-16.4V327zM365.7 165.5c3.7-1.5 7.3-3 11-4.4-23.4 31.3-43.5 70.4-59.2 115.4-21-12-40.9-26-59.4-41.8 31.8-29.2 67.9-52.4 107.6-69.2zM165.5 365.7c13.8-32.6 32-62.8 54.2-90.2 24.9 21.5 52.2 40.3 81.5 55.9-11.6 46.9-18.8 98.4-20.7 152.6H137c3-40.9 12.6-80.6 28.5-118.3zM137 540h143.5c1.9 54.2 9.1 105.7 20.7 152.6a444.07 444.07 0 0 0-81.5 55.9A373.86 373.86 0 0 1 137 540zm228.7 318.5c-39.7-16.8-75.8-40-107.6-69.2 18.5-15.8 38.4-29.7 59.4-41.8 15.7 45 35.8 84.1 59.2 115.4-3.7-1.4-7.4-2.9-11-4.4zm292.6 0c-3.7 1.5-7.3 3-11 4.4 23.4-31.3 43.5-70.4 59.2-115.4 21 12 40.9 26 59.4 41.8a373.81 373.81 0 0 1-107.6 69.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGoldFilled, "Gold Filled"))]
 pub static AiGoldFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGoldFilled",
+    label: "Gold Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6272,8 +8921,15 @@ pub static AiGoldFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M905.9 806.7l-40.2-248c-.6-3.9-4-6.7-7.9-6.7H596.2c-3.9 0-7.3 2.8-7.9 6.7l-40.2 248c-.1.4-.1.9-.1 1.3 0 4.4 3.6 8 8 8h342c.4 0 .9 0 1.3-.1 4.3-.7 7.3-4.8 6.6-9.2zm-470.2-248c-.6-3.9-4-6.7-7.9-6.7H166.2c-3.9 0-7.3 2.8-7.9 6.7l-40.2 248c-.1.4-.1.9-.1 1.3 0 4.4 3.6 8 8 8h342c.4 0 .9 0 1.3-.1 4.4-.7 7.3-4.8 6.6-9.2l-40.2-248zM342 472h342c.4 0 .9 0 1.3-.1 4.4-.7 7.3-4.8 6.6-9.2l-40.2-248c-.6-3.9-4-6.7-7.9-6.7H382.2c-3.9 0-7.3 2.8-7.9 6.7l-40.2 248c-.1.4-.1.9-.1 1.3 0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGoldOutlined, "Gold Outlined"))]
 pub static AiGoldOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGoldOutlined",
+    label: "Gold Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6288,8 +8944,15 @@ pub static AiGoldOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M342 472h342c.4 0 .9 0 1.3-.1 4.4-.7 7.3-4.8 6.6-9.2l-40.2-248c-.6-3.9-4-6.7-7.9-6.7H382.2c-3.9 0-7.3 2.8-7.9 6.7l-40.2 248c-.1.4-.1.9-.1 1.3 0 4.4 3.6 8 8 8zm91.2-196h159.5l20.7 128h-201l20.8-128zm2.5 282.7c-.6-3.9-4-6.7-7.9-6.7H166.2c-3.9 0-7.3 2.8-7.9 6.7l-40.2 248c-.1.4-.1.9-.1 1.3 0 4.4 3.6 8 8 8h342c.4 0 .9 0 1.3-.1 4.4-.7 7.3-4.8 6.6-9.2l-40.2-248zM196.5 748l20.7-128h159.5l20.7 128H196.5zm709.4 58.7l-40.2-248c-.6-3.9-4-6.7-7.9-6.7H596.2c-3.9 0-7.3 2.8-7.9 6.7l-40.2 248c-.1.4-.1.9-.1 1.3 0 4.4 3.6 8 8 8h342c.4 0 .9 0 1.3-.1 4.3-.7 7.3-4.8 6.6-9.2zM626.5 748l20.7-128h159.5l20.7 128H626.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGoldTwotone, "Gold Twotone"))]
 pub static AiGoldTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGoldTwotone",
+    label: "Gold Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6305,8 +8968,15 @@ pub static AiGoldTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path fill="#E6E6E6" d="M592.7 276H433.2l-20.8 128h201zM217.2 620l-20.7 128h200.9l-20.7-128zm430 0l-20.7 128h200.9l-20.7-128z" class="twotone-icon-secondary-color" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGoldenFilled, "Golden Filled"))]
 pub static AiGoldenFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGoldenFilled",
+    label: "Golden Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6321,8 +8991,15 @@ pub static AiGoldenFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M905.9 806.7l-40.2-248c-.6-3.9-4-6.7-7.9-6.7H596.2c-3.9 0-7.3 2.8-7.9 6.7l-40.2 248c-.1.4-.1.9-.1 1.3 0 4.4 3.6 8 8 8h342c.4 0 .9 0 1.3-.1 4.3-.7 7.3-4.8 6.6-9.2zm-470.2-248c-.6-3.9-4-6.7-7.9-6.7H166.2c-3.9 0-7.3 2.8-7.9 6.7l-40.2 248c-.1.4-.1.9-.1 1.3 0 4.4 3.6 8 8 8h342c.4 0 .9 0 1.3-.1 4.4-.7 7.3-4.8 6.6-9.2l-40.2-248zM342 472h342c.4 0 .9 0 1.3-.1 4.4-.7 7.3-4.8 6.6-9.2l-40.2-248c-.6-3.9-4-6.7-7.9-6.7H382.2c-3.9 0-7.3 2.8-7.9 6.7l-40.2 248c-.1.4-.1.9-.1 1.3 0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGoogleCircleFilled, "Google Circle Filled"))]
 pub static AiGoogleCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGoogleCircleFilled",
+    label: "Google Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6337,8 +9014,15 @@ pub static AiGoogleCircleFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm167 633.6C638.4 735 583 757 516.9 757c-95.7 0-178.5-54.9-218.8-134.9C281.5 589 272 551.6 272 512s9.5-77 26.1-110.1c40.3-80.1 123.1-135 218.8-135 66 0 121.4 24.3 163.9 63.8L610.6 401c-25.4-24.3-57.7-36.6-93.6-36.6-63.8 0-117.8 43.1-137.1 101-4.9 14.7-7.7 30.4-7.7 46.6s2.8 31.9 7.7 46.6c19.3 57.9 73.3 101 137 101 33 0 61-8.7 82.9-23.4 26-17.4 43.2-43.3 48.9-74H516.9v-94.8h230.7c2.9 16.1 4.4 32.8 4.4 50.1 0 74.7-26.7 137.4-73 180.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGoogleOutlined, "Google Outlined"))]
 pub static AiGoogleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGoogleOutlined",
+    label: "Google Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6353,8 +9037,15 @@ pub static AiGoogleOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M881 442.4H519.7v148.5h206.4c-8.9 48-35.9 88.6-76.6 115.8-34.4 23-78.3 36.6-129.9 36.6-99.9 0-184.4-67.5-214.6-158.2-7.6-23-12-47.6-12-72.9s4.4-49.9 12-72.9c30.3-90.6 114.8-158.1 214.7-158.1 56.3 0 106.8 19.4 146.6 57.4l110-110.1c-66.5-62-153.2-100-256.6-100-149.9 0-279.6 86-342.7 211.4-26 51.8-40.8 110.4-40.8 172.4S151 632.8 177 684.6C240.1 810 369.8 896 519.7 896c103.6 0 190.4-34.4 253.8-93 72.5-66.8 114.4-165.2 114.4-282.1 0-27.2-2.4-53.3-6.9-78.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGooglePlusCircleFilled, "Google Plus Circle Filled"))]
 pub static AiGooglePlusCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGooglePlusCircleFilled",
+    label: "Google Plus Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6369,8 +9060,15 @@ pub static AiGooglePlusCircleFilled: &icondata_core::IconData = &icondata_core::
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm36.5 558.8c-43.9 61.8-132.1 79.8-200.9 53.3-69-26.3-118-99.2-112.1-173.5 1.5-90.9 85.2-170.6 176.1-167.5 43.6-2 84.6 16.9 118 43.6-14.3 16.2-29 31.8-44.8 46.3-40.1-27.7-97.2-35.6-137.3-3.6-57.4 39.7-60 133.4-4.8 176.1 53.7 48.7 155.2 24.5 170.1-50.1-33.6-.5-67.4 0-101-1.1-.1-20.1-.2-40.1-.1-60.2 56.2-.2 112.5-.3 168.8.2 3.3 47.3-3 97.5-32 136.5zM791 536.5c-16.8.2-33.6.3-50.4.4-.2 16.8-.3 33.6-.3 50.4H690c-.2-16.8-.2-33.5-.3-50.3-16.8-.2-33.6-.3-50.4-.5v-50.1c16.8-.2 33.6-.3 50.4-.3.1-16.8.3-33.6.4-50.4h50.2l.3 50.4c16.8.2 33.6.2 50.4.3v50.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGooglePlusOutlined, "Google Plus Outlined"))]
 pub static AiGooglePlusOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGooglePlusOutlined",
+    label: "Google Plus Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6385,8 +9083,15 @@ pub static AiGooglePlusOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M879.5 470.4c-.3-27-.4-54.2-.5-81.3h-80.8c-.3 27-.5 54.1-.7 81.3-27.2.1-54.2.3-81.2.6v80.9c27 .3 54.2.5 81.2.8.3 27 .3 54.1.5 81.1h80.9c.1-27 .3-54.1.5-81.3 27.2-.3 54.2-.4 81.2-.7v-80.9c-26.9-.2-54.1-.2-81.1-.5zm-530 .4c-.1 32.3 0 64.7.1 97 54.2 1.8 108.5 1 162.7 1.8-23.9 120.3-187.4 159.3-273.9 80.7-89-68.9-84.8-220 7.7-284 64.7-51.6 156.6-38.9 221.3 5.8 25.4-23.5 49.2-48.7 72.1-74.7-53.8-42.9-119.8-73.5-190-70.3-146.6-4.9-281.3 123.5-283.7 270.2-9.4 119.9 69.4 237.4 180.6 279.8 110.8 42.7 252.9 13.6 323.7-86 46.7-62.9 56.8-143.9 51.3-220-90.7-.7-181.3-.6-271.9-.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGooglePlusSquareFilled, "Google Plus Square Filled"))]
 pub static AiGooglePlusSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGooglePlusSquareFilled",
+    label: "Google Plus Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6401,8 +9106,15 @@ pub static AiGooglePlusSquareFilled: &icondata_core::IconData = &icondata_core::
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM548.5 622.8c-43.9 61.8-132.1 79.8-200.9 53.3-69-26.3-118-99.2-112.1-173.5 1.5-90.9 85.2-170.6 176.1-167.5 43.6-2 84.6 16.9 118 43.6-14.3 16.2-29 31.8-44.8 46.3-40.1-27.7-97.2-35.6-137.3-3.6-57.4 39.7-60 133.4-4.8 176.1 53.7 48.7 155.2 24.5 170.1-50.1-33.6-.5-67.4 0-101-1.1-.1-20.1-.2-40.1-.1-60.2 56.2-.2 112.5-.3 168.8.2 3.3 47.3-3 97.5-32 136.5zM791 536.5c-16.8.2-33.6.3-50.4.4-.2 16.8-.3 33.6-.3 50.4H690c-.2-16.8-.2-33.5-.3-50.3-16.8-.2-33.6-.3-50.4-.5v-50.1c16.8-.2 33.6-.3 50.4-.3.1-16.8.3-33.6.4-50.4h50.2l.3 50.4c16.8.2 33.6.2 50.4.3v50.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGoogleSquareFilled, "Google Square Filled"))]
 pub static AiGoogleSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGoogleSquareFilled",
+    label: "Google Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6417,8 +9129,15 @@ pub static AiGoogleSquareFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM679 697.6C638.4 735 583 757 516.9 757c-95.7 0-178.5-54.9-218.8-134.9A245.02 245.02 0 0 1 272 512c0-39.6 9.5-77 26.1-110.1 40.3-80.1 123.1-135 218.8-135 66 0 121.4 24.3 163.9 63.8L610.6 401c-25.4-24.3-57.7-36.6-93.6-36.6-63.8 0-117.8 43.1-137.1 101-4.9 14.7-7.7 30.4-7.7 46.6s2.8 31.9 7.7 46.6c19.3 57.9 73.3 101 137 101 33 0 61-8.7 82.9-23.4 26-17.4 43.2-43.3 48.9-74H516.9v-94.8h230.7c2.9 16.1 4.4 32.8 4.4 50.1 0 74.7-26.7 137.4-73 180.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiGroupOutlined, "Group Outlined"))]
 pub static AiGroupOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiGroupOutlined",
+    label: "Group Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6437,8 +9156,15 @@ pub static AiGroupOutlined: &icondata_core::IconData = &icondata_core::IconData
 <path d="M288 474h448c8.8 0 16-7.2 16-16V282c0-8.8-7.2-16-16-16H288c-8.8 0-16 7.2-16 16v176c0 8.8 7.2 16 16 16z m56-136h336v64H344v-64zM288 758h448c8.8 0 16-7.2 16-16V566c0-8.8-7.2-16-16-16H288c-8.8 0-16 7.2-16 16v176c0 8.8 7.2 16 16 16z m56-136h336v64H344v-64z" p-id="13902" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHddFilled, "Hdd Filled"))]
 pub static AiHddFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHddFilled",
+    label: "Hdd Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6453,8 +9179,15 @@ pub static AiHddFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M832 64H192c-17.7 0-32 14.3-32 32v224h704V96c0-17.7-14.3-32-32-32zM456 216c0 4.4-3.6 8-8 8H264c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h184c4.4 0 8 3.6 8 8v48zM160 928c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V704H160v224zm576-136c22.1 0 40 17.9 40 40s-17.9 40-40 40-40-17.9-40-40 17.9-40 40-40zM160 640h704V384H160v256zm96-152c0-4.4 3.6-8 8-8h184c4.4 0 8 3.6 8 8v48c0 4.4-3.6 8-8 8H264c-4.4 0-8-3.6-8-8v-48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHddOutlined, "Hdd Outlined"))]
 pub static AiHddOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHddOutlined",
+    label: "Hdd Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6469,8 +9202,15 @@ pub static AiHddOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M832 64H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V96c0-17.7-14.3-32-32-32zm-600 72h560v208H232V136zm560 480H232V408h560v208zm0 272H232V680h560v208zM496 208H312c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zM312 544h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H312c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8zm328 244a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHddTwotone, "Hdd Twotone"))]
 pub static AiHddTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHddTwotone",
+    label: "Hdd Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6487,8 +9227,15 @@ pub static AiHddTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M312 544h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H312c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8zm0-272h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H312c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8zm328 516a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHeartFilled, "Heart Filled"))]
 pub static AiHeartFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHeartFilled",
+    label: "Heart Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6503,8 +9250,15 @@ pub static AiHeartFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M923 283.6a260.04 260.04 0 0 0-56.9-82.8 264.4 264.4 0 0 0-84-55.5A265.34 265.34 0 0 0 679.7 125c-49.3 0-97.4 13.5-139.2 39-10 6.1-19.5 12.8-28.5 20.1-9-7.3-18.5-14-28.5-20.1-41.8-25.5-89.9-39-139.2-39-35.5 0-69.9 6.8-102.4 20.3-31.4 13-59.7 31.7-84 55.5a258.44 258.44 0 0 0-56.9 82.8c-13.9 32.3-21 66.6-21 101.9 0 33.3 6.8 68 20.3 103.3 11.3 29.5 27.5 60.1 48.2 91 32.8 48.9 77.9 99.9 133.9 151.6 92.8 85.7 184.7 144.9 188.6 147.3l23.7 15.2c10.5 6.7 24 6.7 34.5 0l23.7-15.2c3.9-2.5 95.7-61.6 188.6-147.3 56-51.7 101.1-102.7 133.9-151.6 20.7-30.9 37-61.5 48.2-91 13.5-35.3 20.3-70 20.3-103.3.1-35.3-7-69.6-20.9-101.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHeartOutlined, "Heart Outlined"))]
 pub static AiHeartOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHeartOutlined",
+    label: "Heart Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6519,8 +9273,15 @@ pub static AiHeartOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M923 283.6a260.04 260.04 0 0 0-56.9-82.8 264.4 264.4 0 0 0-84-55.5A265.34 265.34 0 0 0 679.7 125c-49.3 0-97.4 13.5-139.2 39-10 6.1-19.5 12.8-28.5 20.1-9-7.3-18.5-14-28.5-20.1-41.8-25.5-89.9-39-139.2-39-35.5 0-69.9 6.8-102.4 20.3-31.4 13-59.7 31.7-84 55.5a258.44 258.44 0 0 0-56.9 82.8c-13.9 32.3-21 66.6-21 101.9 0 33.3 6.8 68 20.3 103.3 11.3 29.5 27.5 60.1 48.2 91 32.8 48.9 77.9 99.9 133.9 151.6 92.8 85.7 184.7 144.9 188.6 147.3l23.7 15.2c10.5 6.7 24 6.7 34.5 0l23.7-15.2c3.9-2.5 95.7-61.6 188.6-147.3 56-51.7 101.1-102.7 133.9-151.6 20.7-30.9 37-61.5 48.2-91 13.5-35.3 20.3-70 20.3-103.3.1-35.3-7-69.6-20.9-101.9zM512 814.8S156 586.7 156 385.5C156 283.6 240.3 201 344.3 201c73.1 0 136.5 40.8 167.7 100.4C543.2 241.8 606.6 201 679.7 201c104 0 188.3 82.6 188.3 184.5 0 201.2-356 429.3-356 429.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHeartTwotone, "Heart Twotone"))]
 pub static AiHeartTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHeartTwotone",
+    label: "Heart Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6536,8 +9297,15 @@ pub static AiHeartTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path fill="#E6E6E6" d="M679.7 201c-73.1 0-136.5 40.8-167.7 100.4C480.8 241.8 417.4 201 344.3 201c-104 0-188.3 82.6-188.3 184.5 0 201.2 356 429.3 356 429.3s356-228.1 356-429.3C868 283.6 783.7 201 679.7 201z" class="twotone-icon-secondary-color" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHeatMapOutlined, "Heat Map Outlined"))]
 pub static AiHeatMapOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHeatMapOutlined",
+    label: "Heat Map Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6552,8 +9320,15 @@ pub static AiHeatMapOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M955.7 856l-416-720c-6.2-10.7-16.9-16-27.7-16s-21.6 5.3-27.7 16l-416 720C56 877.4 71.4 904 96 904h832c24.6 0 40-26.6 27.7-48zm-790.4-23.9L512 231.9 858.7 832H165.3zm319-474.1l-228 394c-12.3 21.3 3.1 48 27.7 48h455.8c24.7 0 40.1-26.7 27.7-48L539.7 358c-6.2-10.7-17-16-27.7-16-10.8 0-21.6 5.3-27.7 16zm214 386H325.7L512 422l186.3 322zm-214-194.1l-57 98.4C415 669.5 430.4 696 455 696h114c24.6 0 39.9-26.5 27.7-47.7l-57-98.4c-6.1-10.6-16.9-15.9-27.7-15.9s-21.5 5.3-27.7 15.9zm57.1 98.4h-58.7l29.4-50.7 29.3 50.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHighlightFilled, "Highlight Filled"))]
 pub static AiHighlightFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHighlightFilled",
+    label: "Highlight Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6568,8 +9343,15 @@ pub static AiHighlightFilled: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M957.6 507.4L603.2 158.2a7.9 7.9 0 0 0-11.2 0L353.3 393.4a8.03 8.03 0 0 0-.1 11.3l.1.1 40 39.4-117.2 115.3a8.03 8.03 0 0 0-.1 11.3l.1.1 39.5 38.9-189.1 187H72.1c-4.4 0-8.1 3.6-8.1 8V860c0 4.4 3.6 8 8 8h344.9c2.1 0 4.1-.8 5.6-2.3l76.1-75.6 40.4 39.8a7.9 7.9 0 0 0 11.2 0l117.1-115.6 40.1 39.5a7.9 7.9 0 0 0 11.2 0l238.7-235.2c3.4-3 3.4-8 .3-11.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHighlightOutlined, "Highlight Outlined"))]
 pub static AiHighlightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHighlightOutlined",
+    label: "Highlight Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6584,8 +9366,15 @@ pub static AiHighlightOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M957.6 507.4L603.2 158.2a7.9 7.9 0 0 0-11.2 0L353.3 393.4a8.03 8.03 0 0 0-.1 11.3l.1.1 40 39.4-117.2 115.3a8.03 8.03 0 0 0-.1 11.3l.1.1 39.5 38.9-189.1 187H72.1c-4.4 0-8.1 3.6-8.1 8V860c0 4.4 3.6 8 8 8h344.9c2.1 0 4.1-.8 5.6-2.3l76.1-75.6 40.4 39.8a7.9 7.9 0 0 0 11.2 0l117.1-115.6 40.1 39.5a7.9 7.9 0 0 0 11.2 0l238.7-235.2c3.4-3 3.4-8 .3-11.2zM389.8 796.2H229.6l134.4-133 80.1 78.9-54.3 54.1zm154.8-62.1L373.2 565.2l68.6-67.6 171.4 168.9-68.6 67.6zM713.1 658L450.3 399.1 597.6 254l262.8 259-147.3 145z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHighlightTwotone, "Highlight Twotone"))]
 pub static AiHighlightTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHighlightTwotone",
+    label: "Highlight Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6601,8 +9390,15 @@ pub static AiHighlightTwotone: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M957.6 507.5L603.2 158.3a7.9 7.9 0 0 0-11.2 0L353.3 393.5a8.03 8.03 0 0 0-.1 11.3l.1.1 40 39.4-117.2 115.3a8.03 8.03 0 0 0-.1 11.3l.1.1 39.5 38.9-189.1 187H72.1c-4.4 0-8.1 3.6-8.1 8v55.2c0 4.4 3.6 8 8 8h344.9c2.1 0 4.1-.8 5.6-2.3l76.1-75.6L539 830a7.9 7.9 0 0 0 11.2 0l117.1-115.6 40.1 39.5a7.9 7.9 0 0 0 11.2 0l238.7-235.2c3.4-3 3.4-8 .3-11.2zM389.8 796.3H229.6l134.4-133 80.1 78.9-54.3 54.1zm154.8-62.1L373.2 565.3l68.6-67.6 171.4 168.9-68.6 67.6zm168.5-76.1L450.3 399.2l147.3-145.1 262.8 259-147.3 145z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHistoryOutlined, "History Outlined"))]
 pub static AiHistoryOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHistoryOutlined",
+    label: "History Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6617,8 +9413,15 @@ pub static AiHistoryOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M536.1 273H488c-4.4 0-8 3.6-8 8v275.3c0 2.6 1.2 5 3.3 6.5l165.3 120.7c3.6 2.6 8.6 1.9 11.2-1.7l28.6-39c2.7-3.7 1.9-8.7-1.7-11.2L544.1 528.5V281c0-4.4-3.6-8-8-8zm219.8 75.2l156.8 38.3c5 1.2 9.9-2.6 9.9-7.7l.8-161.5c0-6.7-7.7-10.5-12.9-6.3L752.9 334.1a8 8 0 0 0 3 14.1zm167.7 301.1l-56.7-19.5a8 8 0 0 0-10.1 4.8c-1.9 5.1-3.9 10.1-6 15.1-17.8 42.1-43.3 80-75.9 112.5a353 353 0 0 1-112.5 75.9 352.18 352.18 0 0 1-137.7 27.8c-47.8 0-94.1-9.3-137.7-27.8a353 353 0 0 1-112.5-75.9c-32.5-32.5-58-70.4-75.9-112.5A353.44 353.44 0 0 1 171 512c0-47.8 9.3-94.2 27.8-137.8 17.8-42.1 43.3-80 75.9-112.5a353 353 0 0 1 112.5-75.9C430.6 167.3 477 158 524.8 158s94.1 9.3 137.7 27.8A353 353 0 0 1 775 261.7c10.2 10.3 19.8 21 28.6 32.3l59.8-46.8C784.7 146.6 662.2 81.9 524.6 82 285 82.1 92.6 276.7 95 516.4 97.4 751.9 288.9 942 524.8 942c185.5 0 343.5-117.6 403.7-282.3 1.5-4.2-.7-8.9-4.9-10.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHolderOutlined, "Holder Outlined"))]
 pub static AiHolderOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHolderOutlined",
+    label: "Holder Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6633,8 +9436,15 @@ pub static AiHolderOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M300,276.497424 C317.32646,286.500861 338.67354,286.500861 356,276.497424 C373.326461,266.493987 384,248.006874 384,228 C384,207.993128 373.326461,189.506014 356,179.502578 C338.67354,169.499141 317.32646,169.499141 300,179.502578 C282.673539,189.506014 272,207.993128 272,228 C272,248.006874 282.673539,266.493987 300,276.497424 Z M300,560.497426 C317.32646,570.500863 338.67354,570.500863 356,560.497426 C373.326461,550.493989 384,532.006876 384,512.000002 C384,491.993129 373.326461,473.506016 356,463.502579 C338.67354,453.499142 317.32646,453.499142 300,463.502579 C282.673539,473.506016 272,491.993129 272,512.000002 C272,532.006876 282.673539,550.493989 300,560.497426 Z M640,228 C640,248.006874 650.673539,266.493987 668,276.497424 C685.32646,286.500861 706.67354,286.500861 724,276.497424 C741.326461,266.493987 752,248.006874 752,228 C752,207.993128 741.326461,189.506014 724,179.502578 C706.67354,169.499141 685.32646,169.499141 668,179.502578 C650.673539,189.506014 640,207.993128 640,228 L640,228 Z M640,512.000002 C640,532.006876 650.673539,550.493989 668,560.497426 C685.32646,570.500863 706.67354,570.500863 724,560.497426 C741.326461,550.493989 752,532.006876 752,512.000002 C752,491.993129 741.326461,473.506016 724,463.502579 C706.67354,453.499142 685.32646,453.499142 668,463.502579 C650.673539,473.506016 640,491.993129 640,512.000002 L640,512.000002 Z M300,844.497427 C317.32646,854.500864 338.67354,854.500864 356,844.497427 C373.326461,834.493991 384,816.006877 384,796.000003 C384,775.993131 373.326461,757.506018 356,747.502581 C338.67354,737.499144 317.32646,737.499144 300,747.502581 C282.673539,757.506018 272,775.993131 272,796.000003 C272,816.006877 282.673539,834.493991 300,844.497427 Z M640,796.000003 C640,816.006877 650.673539,834.493991 668,844.497427 C685.32646,854.500864 706.67354,854.500864 724,844.497427 C741.326461,834.493991 752,816.006877 752,796.000003 C752,775.993131 741.326461,757.506018 724,747.502581 C706.67354,737.499144 685.32646,737.499144 668,747.502581 C650.673539,757.506018 640,775.993131 640,796.000003 L640,796.000003 Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHomeFilled, "Home Filled"))]
 pub static AiHomeFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHomeFilled",
+    label: "Home Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6649,8 +9459,15 @@ pub static AiHomeFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M946.5 505L534.6 93.4a31.93 31.93 0 0 0-45.2 0L77.5 505c-12 12-18.8 28.3-18.8 45.3 0 35.3 28.7 64 64 64h43.4V908c0 17.7 14.3 32 32 32H448V716h112v224h265.9c17.7 0 32-14.3 32-32V614.3h43.4c17 0 33.3-6.7 45.3-18.8 24.9-25 24.9-65.5-.1-90.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHomeOutlined, "Home Outlined"))]
 pub static AiHomeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHomeOutlined",
+    label: "Home Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6665,8 +9482,15 @@ pub static AiHomeOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M946.5 505L560.1 118.8l-25.9-25.9a31.5 31.5 0 0 0-44.4 0L77.5 505a63.9 63.9 0 0 0-18.8 46c.4 35.2 29.7 63.3 64.9 63.3h42.5V940h691.8V614.3h43.4c17.1 0 33.2-6.7 45.3-18.8a63.6 63.6 0 0 0 18.7-45.3c0-17-6.7-33.1-18.8-45.2zM568 868H456V664h112v204zm217.9-325.7V868H632V640c0-22.1-17.9-40-40-40H432c-22.1 0-40 17.9-40 40v228H238.1V542.3h-96l370-369.7 23.1 23.1L882 542.3h-96.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHomeTwotone, "Home Twotone"))]
 pub static AiHomeTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHomeTwotone",
+    label: "Home Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6682,8 +9506,15 @@ pub static AiHomeTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M951 510c0-.1-.1-.1-.1-.2l-1.8-2.1c-.1-.1-.2-.3-.4-.4-.7-.8-1.5-1.6-2.2-2.4L560.1 118.8l-25.9-25.9a31.5 31.5 0 0 0-44.4 0L77.5 505a63.6 63.6 0 0 0-16 26.6l-.6 2.1-.3 1.1-.3 1.2c-.2.7-.3 1.4-.4 2.1 0 .1 0 .3-.1.4-.6 3-.9 6-1 9.1v3.3c0 .5 0 1 .1 1.5 0 .5 0 .9.1 1.4 0 .5.1 1 .1 1.5 0 .6.1 1.2.2 1.8 0 .3.1.6.1.9l.3 2.5v.1c5.1 30.5 32.2 53.5 64 53.5h42.5V940h691.7V614.3h43.4c8.6 0 16.9-1.7 24.5-4.9s14.7-7.9 20.8-13.9a63.6 63.6 0 0 0 18.7-45.3c0-14.7-5-28.8-14.3-40.2zM568 868H456V664h112v204zm217.9-325.7V868H632V640c0-22.1-17.9-40-40-40H432c-22.1 0-40 17.9-40 40v228H238.1V542.3h-96l370-369.7 23.1 23.1L882 542.3h-96.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHourglassFilled, "Hourglass Filled"))]
 pub static AiHourglassFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHourglassFilled",
+    label: "Hourglass Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6698,8 +9529,15 @@ pub static AiHourglassFilled: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M742 318V184h86c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H196c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h86v134c0 81.5 42.4 153.2 106.4 194-64 40.8-106.4 112.5-106.4 194v134h-86c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h632c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-86V706c0-81.5-42.4-153.2-106.4-194 64-40.8 106.4-112.5 106.4-194z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHourglassOutlined, "Hourglass Outlined"))]
 pub static AiHourglassOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHourglassOutlined",
+    label: "Hourglass Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6714,8 +9552,15 @@ pub static AiHourglassOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M742 318V184h86c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H196c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h86v134c0 81.5 42.4 153.2 106.4 194-64 40.8-106.4 112.5-106.4 194v134h-86c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h632c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-86V706c0-81.5-42.4-153.2-106.4-194 64-40.8 106.4-112.5 106.4-194zm-72 388v134H354V706c0-42.2 16.4-81.9 46.3-111.7C430.1 564.4 469.8 548 512 548s81.9 16.4 111.7 46.3C653.6 624.1 670 663.8 670 706zm0-388c0 42.2-16.4 81.9-46.3 111.7C593.9 459.6 554.2 476 512 476s-81.9-16.4-111.7-46.3A156.63 156.63 0 0 1 354 318V184h316v134z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHourglassTwotone, "Hourglass Twotone"))]
 pub static AiHourglassTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHourglassTwotone",
+    label: "Hourglass Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6731,8 +9576,15 @@ pub static AiHourglassTwotone: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M742 318V184h86c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H196c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h86v134c0 81.5 42.4 153.2 106.4 194-64 40.8-106.4 112.5-106.4 194v134h-86c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h632c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-86V706c0-81.5-42.4-153.2-106.4-194 64-40.8 106.4-112.5 106.4-194zm-72 388v134H354V706c0-42.2 16.4-81.9 46.3-111.7C430.1 564.4 469.8 548 512 548s81.9 16.4 111.7 46.3C653.6 624.1 670 663.8 670 706zm0-388c0 42.2-16.4 81.9-46.3 111.7C593.9 459.6 554.2 476 512 476s-81.9-16.4-111.7-46.3A156.63 156.63 0 0 1 354 318V184h316v134z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHtml5Filled, "HTML 5 Filled"))]
 pub static AiHtml5Filled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHtml5Filled",
+    label: "HTML 5 Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6747,8 +9599,15 @@ pub static AiHtml5Filled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M145.2 96l66 746.6L512 928l299.6-85.4L878.9 96H145.2zm595 177.1l-4.8 47.2-1.7 19.5H382.3l8.2 94.2h335.1l-3.3 24.3-21.2 242.2-1.7 16.2-187 51.6v.3h-1.2l-.3.1v-.1h-.1l-188.6-52L310.8 572h91.1l6.5 73.2 102.4 27.7h.4l102-27.6 11.4-118.6H510.9v-.1H306l-22.8-253.5-1.7-24.3h460.3l-1.6 24.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHtml5Outlined, "HTML 5 Outlined"))]
 pub static AiHtml5Outlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHtml5Outlined",
+    label: "HTML 5 Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6763,8 +9622,15 @@ pub static AiHtml5Outlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M145 96l66 746.6L511.8 928l299.6-85.4L878.7 96H145zm610.9 700.6l-244.1 69.6-245.2-69.6-56.7-641.2h603.8l-57.8 641.2zM281 249l1.7 24.3 22.7 253.5h206.5v-.1h112.9l-11.4 118.5L511 672.9v.2h-.8l-102.4-27.7-6.5-73.2h-91l11.3 144.7 188.6 52h1.7v-.4l187.7-51.7 1.7-16.3 21.2-242.2 3.2-24.3H511v.2H389.9l-8.2-94.2h352.1l1.7-19.5 4.8-47.2L742 249H511z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiHtml5Twotone, "HTML 5 Twotone"))]
 pub static AiHtml5Twotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiHtml5Twotone",
+    label: "HTML 5 Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6781,8 +9647,15 @@ pub static AiHtml5Twotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M281 249l1.7 24.3 22.7 253.5h206.5v-.1h112.9l-11.4 118.5L511 672.9v.2h-.8l-102.4-27.7-6.5-73.2h-91l11.3 144.7 188.6 52h1.7v-.4l187.7-51.7 1.7-16.3 21.2-242.2 3.2-24.3H511v.2H389.9l-8.2-94.2h352.1l1.7-19.5 4.8-47.2L742 249H511z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiIdcardFilled, "Idcard Filled"))]
 pub static AiIdcardFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiIdcardFilled",
+    label: "Idcard Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6797,8 +9670,15 @@ pub static AiIdcardFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M373 411c-28.5 0-51.7 23.3-51.7 52s23.2 52 51.7 52 51.7-23.3 51.7-52-23.2-52-51.7-52zm555-251H96c-17.7 0-32 14.3-32 32v640c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V192c0-17.7-14.3-32-32-32zM608 420c0-4.4 1-8 2.3-8h123.4c1.3 0 2.3 3.6 2.3 8v48c0 4.4-1 8-2.3 8H610.3c-1.3 0-2.3-3.6-2.3-8v-48zm-86 253h-43.9c-4.2 0-7.6-3.3-7.9-7.5-3.8-50.5-46-90.5-97.2-90.5s-93.4 40-97.2 90.5c-.3 4.2-3.7 7.5-7.9 7.5H224a8 8 0 0 1-8-8.4c2.8-53.3 32-99.7 74.6-126.1a111.8 111.8 0 0 1-29.1-75.5c0-61.9 49.9-112 111.4-112s111.4 50.1 111.4 112c0 29.1-11 55.5-29.1 75.5 42.7 26.5 71.8 72.8 74.6 126.1.4 4.6-3.2 8.4-7.8 8.4zm278.9-53H615.1c-3.9 0-7.1-3.6-7.1-8v-48c0-4.4 3.2-8 7.1-8h185.7c3.9 0 7.1 3.6 7.1 8v48h.1c0 4.4-3.2 8-7.1 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiIdcardOutlined, "Idcard Outlined"))]
 pub static AiIdcardOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiIdcardOutlined",
+    label: "Idcard Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6813,8 +9693,15 @@ pub static AiIdcardOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M928 160H96c-17.7 0-32 14.3-32 32v640c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V192c0-17.7-14.3-32-32-32zm-40 632H136V232h752v560zM610.3 476h123.4c1.3 0 2.3-3.6 2.3-8v-48c0-4.4-1-8-2.3-8H610.3c-1.3 0-2.3 3.6-2.3 8v48c0 4.4 1 8 2.3 8zm4.8 144h185.7c3.9 0 7.1-3.6 7.1-8v-48c0-4.4-3.2-8-7.1-8H615.1c-3.9 0-7.1 3.6-7.1 8v48c0 4.4 3.2 8 7.1 8zM224 673h43.9c4.2 0 7.6-3.3 7.9-7.5 3.8-50.5 46-90.5 97.2-90.5s93.4 40 97.2 90.5c.3 4.2 3.7 7.5 7.9 7.5H522a8 8 0 0 0 8-8.4c-2.8-53.3-32-99.7-74.6-126.1a111.8 111.8 0 0 0 29.1-75.5c0-61.9-49.9-112-111.4-112s-111.4 50.1-111.4 112c0 29.1 11 55.5 29.1 75.5a158.09 158.09 0 0 0-74.6 126.1c-.4 4.6 3.2 8.4 7.8 8.4zm149-262c28.5 0 51.7 23.3 51.7 52s-23.2 52-51.7 52-51.7-23.3-51.7-52 23.2-52 51.7-52z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiIdcardTwotone, "Idcard Twotone"))]
 pub static AiIdcardTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiIdcardTwotone",
+    label: "Idcard Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6832,8 +9719,15 @@ pub static AiIdcardTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M610.3 476h123.4c1.3 0 2.3-3.6 2.3-8v-48c0-4.4-1-8-2.3-8H610.3c-1.3 0-2.3 3.6-2.3 8v48c0 4.4 1 8 2.3 8zm4.8 144h185.7c3.9 0 7.1-3.6 7.1-8v-48c0-4.4-3.2-8-7.1-8H615.1c-3.9 0-7.1 3.6-7.1 8v48c0 4.4 3.2 8 7.1 8zM224 673h43.9c4.2 0 7.6-3.3 7.9-7.5 3.8-50.5 46-90.5 97.2-90.5s93.4 40 97.2 90.5c.3 4.2 3.7 7.5 7.9 7.5H522a8 8 0 0 0 8-8.4c-2.8-53.3-32-99.7-74.6-126.1a111.8 111.8 0 0 0 29.1-75.5c0-61.9-49.9-112-111.4-112s-111.4 50.1-111.4 112c0 29.1 11 55.5 29.1 75.5a158.09 158.09 0 0 0-74.6 126.1c-.4 4.6 3.2 8.4 7.8 8.4zm149-262c28.5 0 51.7 23.3 51.7 52s-23.2 52-51.7 52-51.7-23.3-51.7-52 23.2-52 51.7-52z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiIeCircleFilled, "Ie Circle Filled"))]
 pub static AiIeCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiIeCircleFilled",
+    label: "Ie Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6848,8 +9742,15 @@ pub static AiIeCircleFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M693.6 284.4c-24 0-51.1 11.7-72.6 22 46.3 18 86 57.3 112.3 99.6 7.1-18.9 14.6-47.9 14.6-67.9 0-32-22.8-53.7-54.3-53.7zM512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm253.9 492.9H437.1c0 100.4 144.3 136 196.8 47.4h120.8c-32.6 91.7-119.7 146-216.8 146-35.1 0-70.3-.1-101.7-15.6-87.4 44.5-180.3 56.6-180.3-42 0-45.8 23.2-107.1 44-145C335 484 381.3 422.8 435.6 374.5c-43.7 18.9-91.1 66.3-122 101.2 25.9-112.8 129.5-193.6 237.1-186.5 130-59.8 209.7-34.1 209.7 38.6 0 27.4-10.6 63.3-21.4 87.9 25.2 45.5 33.3 97.6 26.9 141.2zM540.5 399.1c-53.7 0-102 39.7-104 94.9h208c-2-55.1-50.6-94.9-104-94.9zM320.6 602.9c-73 152.4 11.5 172.2 100.3 123.3-46.6-27.5-82.6-72.2-100.3-123.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiIeOutlined, "Ie Outlined"))]
 pub static AiIeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiIeOutlined",
+    label: "Ie Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6864,8 +9765,15 @@ pub static AiIeOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M852.6 367.6c16.3-36.9 32.1-90.7 32.1-131.8 0-109.1-119.5-147.6-314.5-57.9-161.4-10.8-316.8 110.5-355.6 279.7 46.3-52.3 117.4-123.4 183-151.7C316.1 378.3 246.7 470 194 565.6c-31.1 56.9-66 148.8-66 217.5 0 147.9 139.3 129.8 270.4 63 47.1 23.1 99.8 23.4 152.5 23.4 145.7 0 276.4-81.4 325.2-219H694.9c-78.8 132.9-295.2 79.5-295.2-71.2h493.2c9.6-65.4-2.5-143.6-40.3-211.7zM224.8 648.3c26.6 76.7 80.6 143.8 150.4 185-133.1 73.4-259.9 43.6-150.4-185zm174-163.3c3-82.7 75.4-142.3 156-142.3 80.1 0 153 59.6 156 142.3h-312zm276.8-281.4c32.1-15.4 72.8-33 108.8-33 47.1 0 81.4 32.6 81.4 80.6 0 30-11.1 73.5-21.9 101.8-39.3-63.5-98.9-122.4-168.3-149.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiIeSquareFilled, "Ie Square Filled"))]
 pub static AiIeSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiIeSquareFilled",
+    label: "Ie Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6880,8 +9788,15 @@ pub static AiIeSquareFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM765.9 556.9H437.1c0 100.4 144.3 136 196.8 47.4h120.8c-32.6 91.7-119.7 146-216.8 146-35.1 0-70.3-.1-101.7-15.6-87.4 44.5-180.3 56.6-180.3-42 0-45.8 23.2-107.1 44-145C335 484 381.3 422.8 435.6 374.5c-43.7 18.9-91.1 66.3-122 101.2 25.9-112.8 129.5-193.6 237.1-186.5 130-59.8 209.7-34.1 209.7 38.6 0 27.4-10.6 63.3-21.4 87.9 25.2 45.5 33.3 97.6 26.9 141.2zm-72.3-272.5c-24 0-51.1 11.7-72.6 22 46.3 18 86 57.3 112.3 99.6 7.1-18.9 14.6-47.9 14.6-67.9 0-32-22.8-53.7-54.3-53.7zM540.5 399.1c-53.7 0-102 39.7-104 94.9h208c-2-55.1-50.6-94.9-104-94.9zM320.6 602.9c-73 152.4 11.5 172.2 100.3 123.3-46.6-27.5-82.6-72.2-100.3-123.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiImportOutlined, "Import Outlined"))]
 pub static AiImportOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiImportOutlined",
+    label: "Import Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6896,8 +9811,15 @@ pub static AiImportOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h360c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H184V184h656v320c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V144c0-17.7-14.3-32-32-32ZM653.3 599.4l52.2-52.2c4.7-4.7 1.9-12.8-4.7-13.6l-179.4-21c-5.1-.6-9.5 3.7-8.9 8.9l21 179.4c.8 6.6 8.9 9.4 13.6 4.7l52.4-52.4 256.2 256.2c3.1 3.1 8.2 3.1 11.3 0l42.4-42.4c3.1-3.1 3.1-8.2 0-11.3L653.3 599.4Z" transform="matrix(1 0 0 -1 0 1024)" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInboxOutlined, "Inbox Outlined"))]
 pub static AiInboxOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInboxOutlined",
+    label: "Inbox Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6912,8 +9834,15 @@ pub static AiInboxOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M885.2 446.3l-.2-.8-112.2-285.1c-5-16.1-19.9-27.2-36.8-27.2H281.2c-17 0-32.1 11.3-36.9 27.6L139.4 443l-.3.7-.2.8c-1.3 4.9-1.7 9.9-1 14.8-.1 1.6-.2 3.2-.2 4.8V830a60.9 60.9 0 0 0 60.8 60.8h627.2c33.5 0 60.8-27.3 60.9-60.8V464.1c0-1.3 0-2.6-.1-3.7.4-4.9 0-9.6-1.3-14.1zm-295.8-43l-.3 15.7c-.8 44.9-31.8 75.1-77.1 75.1-22.1 0-41.1-7.1-54.8-20.6S436 441.2 435.6 419l-.3-15.7H229.5L309 210h399.2l81.7 193.3H589.4zm-375 76.8h157.3c24.3 57.1 76 90.8 140.4 90.8 33.7 0 65-9.4 90.3-27.2 22.2-15.6 39.5-37.4 50.7-63.6h156.5V814H214.4V480.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInfoCircleFilled, "Info Circle Filled"))]
 pub static AiInfoCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInfoCircleFilled",
+    label: "Info Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6928,8 +9857,15 @@ pub static AiInfoCircleFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm32 664c0 4.4-3.6 8-8 8h-48c-4.4 0-8-3.6-8-8V456c0-4.4 3.6-8 8-8h48c4.4 0 8 3.6 8 8v272zm-32-344a48.01 48.01 0 0 1 0-96 48.01 48.01 0 0 1 0 96z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInfoCircleOutlined, "Info Circle Outlined"))]
 pub static AiInfoCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInfoCircleOutlined",
+    label: "Info Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6945,8 +9881,15 @@ pub static AiInfoCircleOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M464 336a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm72 112h-48c-4.4 0-8 3.6-8 8v272c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V456c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInfoCircleTwotone, "Info Circle Twotone"))]
 pub static AiInfoCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInfoCircleTwotone",
+    label: "Info Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6963,8 +9906,15 @@ pub static AiInfoCircleTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M464 336a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm72 112h-48c-4.4 0-8 3.6-8 8v272c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V456c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInfoOutlined, "Info Outlined"))]
 pub static AiInfoOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInfoOutlined",
+    label: "Info Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6979,8 +9929,15 @@ pub static AiInfoOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M448 224a64 64 0 1 0 128 0 64 64 0 1 0-128 0zm96 168h-64c-4.4 0-8 3.6-8 8v464c0 4.4 3.6 8 8 8h64c4.4 0 8-3.6 8-8V400c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInsertRowAboveOutlined, "Insert Row Above Outlined"))]
 pub static AiInsertRowAboveOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInsertRowAboveOutlined",
+    label: "Insert Row Above Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6998,8 +9955,15 @@ pub static AiInsertRowAboveOutlined: &icondata_core::IconData = &icondata_core::
 <path d="M878.7 336H145.3c-18.4 0-33.3 14.3-33.3 32v464c0 17.7 14.9 32 33.3 32h733.3c18.4 0 33.3-14.3 33.3-32V368c0.1-17.7-14.8-32-33.2-32zM360 792H184V632h176v160z m0-224H184V408h176v160z m240 224H424V632h176v160z m0-224H424V408h176v160z m240 224H664V632h176v160z m0-224H664V408h176v160zM904 160H120c-4.4 0-8 3.6-8 8v80c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-80c0-4.4-3.6-8-8-8z" p-id="10820" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInsertRowBelowOutlined, "Insert Row Below Outlined"))]
 pub static AiInsertRowBelowOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInsertRowBelowOutlined",
+    label: "Insert Row Below Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7017,8 +9981,15 @@ pub static AiInsertRowBelowOutlined: &icondata_core::IconData = &icondata_core::
 <path d="M904 768H120c-4.4 0-8 3.6-8 8v80c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-80c0-4.4-3.6-8-8-8zM878.7 160H145.3c-18.4 0-33.3 14.3-33.3 32v464c0 17.7 14.9 32 33.3 32h733.3c18.4 0 33.3-14.3 33.3-32V192c0.1-17.7-14.8-32-33.2-32zM360 616H184V456h176v160z m0-224H184V232h176v160z m240 224H424V456h176v160z m0-224H424V232h176v160z m240 224H664V456h176v160z m0-224H664V232h176v160z" p-id="10702" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInsertRowLeftOutlined, "Insert Row Left Outlined"))]
 pub static AiInsertRowLeftOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInsertRowLeftOutlined",
+    label: "Insert Row Left Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7036,8 +10007,15 @@ pub static AiInsertRowLeftOutlined: &icondata_core::IconData = &icondata_core::I
 <path d="M248 112h-80c-4.4 0-8 3.6-8 8v784c0 4.4 3.6 8 8 8h80c4.4 0 8-3.6 8-8V120c0-4.4-3.6-8-8-8zM832 112H368c-17.7 0-32 14.9-32 33.3v733.3c0 18.4 14.3 33.3 32 33.3h464c17.7 0 32-14.9 32-33.3V145.3c0-18.4-14.3-33.3-32-33.3zM568 840H408V664h160v176z m0-240H408V424h160v176z m0-240H408V184h160v176z m224 480H632V664h160v176z m0-240H632V424h160v176z m0-240H632V184h160v176z" p-id="11528" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInsertRowRightOutlined, "Insert Row Right Outlined"))]
 pub static AiInsertRowRightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInsertRowRightOutlined",
+    label: "Insert Row Right Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7055,8 +10033,15 @@ pub static AiInsertRowRightOutlined: &icondata_core::IconData = &icondata_core::
 <path d="M856 112h-80c-4.4 0-8 3.6-8 8v784c0 4.4 3.6 8 8 8h80c4.4 0 8-3.6 8-8V120c0-4.4-3.6-8-8-8zM656 112H192c-17.7 0-32 14.9-32 33.3v733.3c0 18.4 14.3 33.3 32 33.3h464c17.7 0 32-14.9 32-33.3V145.3c0-18.4-14.3-33.3-32-33.3zM392 840H232V664h160v176z m0-240H232V424h160v176z m0-240H232V184h160v176z m224 480H456V664h160v176z m0-240H456V424h160v176z m0-240H456V184h160v176z" p-id="11292" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInstagramFilled, "Instagram Filled"))]
 pub static AiInstagramFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInstagramFilled",
+    label: "Instagram Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7071,8 +10056,15 @@ pub static AiInstagramFilled: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M512 378.7c-73.4 0-133.3 59.9-133.3 133.3S438.6 645.3 512 645.3 645.3 585.4 645.3 512 585.4 378.7 512 378.7zM911.8 512c0-55.2.5-109.9-2.6-165-3.1-64-17.7-120.8-64.5-167.6-46.9-46.9-103.6-61.4-167.6-64.5-55.2-3.1-109.9-2.6-165-2.6-55.2 0-109.9-.5-165 2.6-64 3.1-120.8 17.7-167.6 64.5C132.6 226.3 118.1 283 115 347c-3.1 55.2-2.6 109.9-2.6 165s-.5 109.9 2.6 165c3.1 64 17.7 120.8 64.5 167.6 46.9 46.9 103.6 61.4 167.6 64.5 55.2 3.1 109.9 2.6 165 2.6 55.2 0 109.9.5 165-2.6 64-3.1 120.8-17.7 167.6-64.5 46.9-46.9 61.4-103.6 64.5-167.6 3.2-55.1 2.6-109.8 2.6-165zM512 717.1c-113.5 0-205.1-91.6-205.1-205.1S398.5 306.9 512 306.9 717.1 398.5 717.1 512 625.5 717.1 512 717.1zm213.5-370.7c-26.5 0-47.9-21.4-47.9-47.9s21.4-47.9 47.9-47.9 47.9 21.4 47.9 47.9a47.84 47.84 0 0 1-47.9 47.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInstagramOutlined, "Instagram Outlined"))]
 pub static AiInstagramOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInstagramOutlined",
+    label: "Instagram Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7087,8 +10079,15 @@ pub static AiInstagramOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 306.9c-113.5 0-205.1 91.6-205.1 205.1S398.5 717.1 512 717.1 717.1 625.5 717.1 512 625.5 306.9 512 306.9zm0 338.4c-73.4 0-133.3-59.9-133.3-133.3S438.6 378.7 512 378.7 645.3 438.6 645.3 512 585.4 645.3 512 645.3zm213.5-394.6c-26.5 0-47.9 21.4-47.9 47.9s21.4 47.9 47.9 47.9 47.9-21.3 47.9-47.9a47.84 47.84 0 0 0-47.9-47.9zM911.8 512c0-55.2.5-109.9-2.6-165-3.1-64-17.7-120.8-64.5-167.6-46.9-46.9-103.6-61.4-167.6-64.5-55.2-3.1-109.9-2.6-165-2.6-55.2 0-109.9-.5-165 2.6-64 3.1-120.8 17.7-167.6 64.5C132.6 226.3 118.1 283 115 347c-3.1 55.2-2.6 109.9-2.6 165s-.5 109.9 2.6 165c3.1 64 17.7 120.8 64.5 167.6 46.9 46.9 103.6 61.4 167.6 64.5 55.2 3.1 109.9 2.6 165 2.6 55.2 0 109.9.5 165-2.6 64-3.1 120.8-17.7 167.6-64.5 46.9-46.9 61.4-103.6 64.5-167.6 3.2-55.1 2.6-109.8 2.6-165zm-88 235.8c-7.3 18.2-16.1 31.8-30.2 45.8-14.1 14.1-27.6 22.9-45.8 30.2C695.2 844.7 570.3 840 512 840c-58.3 0-183.3 4.7-235.9-16.1-18.2-7.3-31.8-16.1-45.8-30.2-14.1-14.1-22.9-27.6-30.2-45.8C179.3 695.2 184 570.3 184 512c0-58.3-4.7-183.3 16.1-235.9 7.3-18.2 16.1-31.8 30.2-45.8s27.6-22.9 45.8-30.2C328.7 179.3 453.7 184 512 184s183.3-4.7 235.9 16.1c18.2 7.3 31.8 16.1 45.8 30.2 14.1 14.1 22.9 27.6 30.2 45.8C844.7 328.7 840 453.7 840 512c0 58.3 4.7 183.2-16.2 235.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInsuranceFilled, "Insurance Filled"))]
 pub static AiInsuranceFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInsuranceFilled",
+    label: "Insurance Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7103,8 +10102,15 @@ pub static AiInsuranceFilled: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M519.9 358.8h97.9v41.6h-97.9zm347-188.9L527.1 54.1C523 52.7 517.5 52 512 52s-11 .7-15.1 2.1L157.1 169.9c-8.3 2.8-15.1 12.4-15.1 21.2v482.4c0 8.8 5.7 20.4 12.6 25.9L499.3 968c3.5 2.7 8 4.1 12.6 4.1s9.2-1.4 12.6-4.1l344.7-268.6c6.9-5.4 12.6-17 12.6-25.9V191.1c.2-8.8-6.6-18.3-14.9-21.2zM411.3 656h-.2c0 4.4-3.6 8-8 8h-37.3c-4.4 0-8-3.6-8-8V471.4c-7.7 9.2-15.4 17.9-23.1 26a6.04 6.04 0 0 1-10.2-2.4l-13.2-43.5c-.6-2-.2-4.1 1.2-5.6 37-43.4 64.7-95.1 82.2-153.6 1.1-3.5 5-5.3 8.4-3.7l38.6 18.3c2.7 1.3 4.1 4.4 3.2 7.2a429.2 429.2 0 0 1-33.6 79V656zm296.5-49.2l-26.3 35.3a5.92 5.92 0 0 1-8.9.7c-30.6-29.3-56.8-65.2-78.1-106.9V656c0 4.4-3.6 8-8 8h-36.2c-4.4 0-8-3.6-8-8V536c-22 44.7-49 80.8-80.6 107.6a5.9 5.9 0 0 1-8.9-1.4L430 605.7a6 6 0 0 1 1.6-8.1c28.6-20.3 51.9-45.2 71-76h-55.1c-4.4 0-8-3.6-8-8V478c0-4.4 3.6-8 8-8h94.9v-18.6h-65.9c-4.4 0-8-3.6-8-8V316c0-4.4 3.6-8 8-8h184.7c4.4 0 8 3.6 8 8v127.2c0 4.4-3.6 8-8 8h-66.7v18.6h98.8c4.4 0 8 3.6 8 8v35.6c0 4.4-3.6 8-8 8h-59c18.1 29.1 41.8 54.3 72.3 76.9 2.6 2.1 3.2 5.9 1.2 8.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInsuranceOutlined, "Insurance Outlined"))]
 pub static AiInsuranceOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInsuranceOutlined",
+    label: "Insurance Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7119,8 +10125,15 @@ pub static AiInsuranceOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M441.6 306.8L403 288.6a6.1 6.1 0 0 0-8.4 3.7c-17.5 58.5-45.2 110.1-82.2 153.6a6.05 6.05 0 0 0-1.2 5.6l13.2 43.5c1.3 4.4 7 5.7 10.2 2.4 7.7-8.1 15.4-16.9 23.1-26V656c0 4.4 3.6 8 8 8H403c4.4 0 8-3.6 8-8V393.1a429.2 429.2 0 0 0 33.6-79c1-2.9-.3-6-3-7.3zm26.8 9.2v127.2c0 4.4 3.6 8 8 8h65.9v18.6h-94.9c-4.4 0-8 3.6-8 8v35.6c0 4.4 3.6 8 8 8h55.1c-19.1 30.8-42.4 55.7-71 76a6 6 0 0 0-1.6 8.1l22.8 36.5c1.9 3.1 6.2 3.8 8.9 1.4 31.6-26.8 58.7-62.9 80.6-107.6v120c0 4.4 3.6 8 8 8h36.2c4.4 0 8-3.6 8-8V536c21.3 41.7 47.5 77.5 78.1 106.9 2.6 2.5 6.8 2.1 8.9-.7l26.3-35.3c2-2.7 1.4-6.5-1.2-8.4-30.5-22.6-54.2-47.8-72.3-76.9h59c4.4 0 8-3.6 8-8V478c0-4.4-3.6-8-8-8h-98.8v-18.6h66.7c4.4 0 8-3.6 8-8V316c0-4.4-3.6-8-8-8H476.4c-4.4 0-8 3.6-8 8zm51.5 42.8h97.9v41.6h-97.9v-41.6zm347-188.9L527.1 54.1C523 52.7 517.5 52 512 52s-11 .7-15.1 2.1L157.1 169.9c-8.3 2.8-15.1 12.4-15.1 21.2v482.4c0 8.8 5.7 20.4 12.6 25.9L499.3 968c3.5 2.7 8 4.1 12.6 4.1s9.2-1.4 12.6-4.1l344.7-268.6c6.9-5.4 12.6-17 12.6-25.9V191.1c.2-8.8-6.6-18.3-14.9-21.2zM810 654.3L512 886.5 214 654.3V226.7l298-101.6 298 101.6v427.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInsuranceTwotone, "Insurance Twotone"))]
 pub static AiInsuranceTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInsuranceTwotone",
+    label: "Insurance Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7138,8 +10151,15 @@ pub static AiInsuranceTwotone: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M443.7 306.9l-38.6-18.3c-3.4-1.6-7.3.2-8.4 3.7-17.5 58.5-45.2 110.2-82.2 153.6a5.7 5.7 0 0 0-1.2 5.6l13.2 43.5c1.4 4.5 7 5.8 10.2 2.4 7.7-8.1 15.4-16.8 23.1-26V656c0 4.4 3.6 8 8 8h37.3c4.4 0 8-3.6 8-8h.2V393.1a429.2 429.2 0 0 0 33.6-79c.9-2.8-.5-5.9-3.2-7.2zm26.8 9.1v127.4c0 4.4 3.6 8 8 8h65.9V470h-94.9c-4.4 0-8 3.6-8 8v35.6c0 4.4 3.6 8 8 8h55.1c-19.1 30.8-42.4 55.7-71 76a6 6 0 0 0-1.6 8.1l22.8 36.5c.9 1.5 2.4 2.5 4.1 2.8 1.7.3 3.5-.2 4.8-1.4 31.6-26.8 58.6-62.9 80.6-107.6v120c0 4.4 3.6 8 8 8h36.2c4.4 0 8-3.6 8-8V535.9c21.3 41.7 47.5 77.6 78.1 106.9 2.6 2.5 6.7 2.2 8.9-.7l26.3-35.3c2-2.6 1.4-6.4-1.2-8.5-30.5-22.6-54.2-47.8-72.3-76.9h59c4.4 0 8-3.6 8-8v-35.6c0-4.4-3.6-8-8-8h-98.8v-18.6h66.7c4.4 0 8-3.6 8-8V316c0-4.4-3.6-8-8-8H478.5c-4.4 0-8 3.6-8 8zm51.4 42.8h97.9v41.6h-97.9v-41.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInteractionFilled, "Interaction Filled"))]
 pub static AiInteractionFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInteractionFilled",
+    label: "Interaction Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7154,8 +10174,15 @@ pub static AiInteractionFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM726 585.7c0 55.3-44.7 100.1-99.7 100.1H420.6v53.4c0 5.7-6.5 8.8-10.9 5.3l-109.1-85.7c-3.5-2.7-3.5-8 0-10.7l109.1-85.7c4.4-3.5 10.9-.3 10.9 5.3v53.4h205.7c19.6 0 35.5-16 35.5-35.6v-78.9c0-3.7 3-6.8 6.8-6.8h50.7c3.7 0 6.8 3 6.8 6.8v79.1zm-2.6-209.9l-109.1 85.7c-4.4 3.5-10.9.3-10.9-5.3v-53.4H397.7c-19.6 0-35.5 16-35.5 35.6v78.9c0 3.7-3 6.8-6.8 6.8h-50.7c-3.7 0-6.8-3-6.8-6.8v-78.9c0-55.3 44.7-100.1 99.7-100.1h205.7v-53.4c0-5.7 6.5-8.8 10.9-5.3l109.1 85.7c3.6 2.5 3.6 7.8.1 10.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInteractionOutlined, "Interaction Outlined"))]
 pub static AiInteractionOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInteractionOutlined",
+    label: "Interaction Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7170,8 +10197,15 @@ pub static AiInteractionOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656zM304.8 524h50.7c3.7 0 6.8-3 6.8-6.8v-78.9c0-19.7 15.9-35.6 35.5-35.6h205.7v53.4c0 5.7 6.5 8.8 10.9 5.3l109.1-85.7c3.5-2.7 3.5-8 0-10.7l-109.1-85.7c-4.4-3.5-10.9-.3-10.9 5.3V338H397.7c-55.1 0-99.7 44.8-99.7 100.1V517c0 4 3 7 6.8 7zm-4.2 134.9l109.1 85.7c4.4 3.5 10.9.3 10.9-5.3v-53.4h205.7c55.1 0 99.7-44.8 99.7-100.1v-78.9c0-3.7-3-6.8-6.8-6.8h-50.7c-3.7 0-6.8 3-6.8 6.8v78.9c0 19.7-15.9 35.6-35.5 35.6H420.6V568c0-5.7-6.5-8.8-10.9-5.3l-109.1 85.7c-3.5 2.5-3.5 7.8 0 10.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiInteractionTwotone, "Interaction Twotone"))]
 pub static AiInteractionTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiInteractionTwotone",
+    label: "Interaction Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7188,8 +10222,15 @@ pub static AiInteractionTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M304.8 524h50.7c3.7 0 6.8-3 6.8-6.8v-78.9c0-19.7 15.9-35.6 35.5-35.6h205.7v53.4c0 5.7 6.5 8.8 10.9 5.3l109.1-85.7c3.5-2.7 3.5-8 0-10.7l-109.1-85.7c-4.4-3.5-10.9-.3-10.9 5.3V338H397.7c-55.1 0-99.7 44.8-99.7 100.1V517c0 4 3 7 6.8 7zm-4.2 134.9l109.1 85.7c4.4 3.5 10.9.3 10.9-5.3v-53.4h205.7c55.1 0 99.7-44.8 99.7-100.1v-78.9c0-3.7-3-6.8-6.8-6.8h-50.7c-3.7 0-6.8 3-6.8 6.8v78.9c0 19.7-15.9 35.6-35.5 35.6H420.6V568c0-5.7-6.5-8.8-10.9-5.3l-109.1 85.7c-3.5 2.5-3.5 7.8 0 10.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiIssuesCloseOutlined, "Issues Close Outlined"))]
 pub static AiIssuesCloseOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiIssuesCloseOutlined",
+    label: "Issues Close Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7204,8 +10245,15 @@ pub static AiIssuesCloseOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M464 688a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm72-112c4.4 0 8-3.6 8-8V296c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v272c0 4.4 3.6 8 8 8h48zm400-188h-59.3c-2.6 0-5 1.2-6.5 3.3L763.7 538.1l-49.9-68.8a7.92 7.92 0 0 0-6.5-3.3H648c-6.5 0-10.3 7.4-6.5 12.7l109.2 150.7a16.1 16.1 0 0 0 26 0l165.8-228.7c3.8-5.3 0-12.7-6.5-12.7zm-44 306h-64.2c-5.5 0-10.6 2.9-13.6 7.5a352.2 352.2 0 0 1-49.8 62.2A355.92 355.92 0 0 1 651.1 840a355 355 0 0 1-138.7 27.9c-48.1 0-94.8-9.4-138.7-27.9a355.92 355.92 0 0 1-113.3-76.3A353.06 353.06 0 0 1 184 650.5c-18.6-43.8-28-90.5-28-138.5s9.4-94.7 28-138.5c17.9-42.4 43.6-80.5 76.4-113.2 32.8-32.7 70.9-58.4 113.3-76.3a355 355 0 0 1 138.7-27.9c48.1 0 94.8 9.4 138.7 27.9 42.4 17.9 80.5 43.6 113.3 76.3 19 19 35.6 39.8 49.8 62.2 2.9 4.7 8.1 7.5 13.6 7.5H892c6 0 9.8-6.3 7.2-11.6C828.8 178.5 684.7 82 517.7 80 278.9 77.2 80.5 272.5 80 511.2 79.5 750.1 273.3 944 512.4 944c169.2 0 315.6-97 386.7-238.4A8 8 0 0 0 892 694z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiItalicOutlined, "Italic Outlined"))]
 pub static AiItalicOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiItalicOutlined",
+    label: "Italic Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7220,8 +10268,15 @@ pub static AiItalicOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M798 160H366c-4.4 0-8 3.6-8 8v64c0 4.4 3.6 8 8 8h181.2l-156 544H229c-4.4 0-8 3.6-8 8v64c0 4.4 3.6 8 8 8h432c4.4 0 8-3.6 8-8v-64c0-4.4-3.6-8-8-8H474.4l156-544H798c4.4 0 8-3.6 8-8v-64c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiKeyOutlined, "Key Outlined"))]
 pub static AiKeyOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiKeyOutlined",
+    label: "Key Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7236,8 +10291,15 @@ pub static AiKeyOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M608 112c-167.9 0-304 136.1-304 304 0 70.3 23.9 135 63.9 186.5l-41.1 41.1-62.3-62.3a8.15 8.15 0 0 0-11.4 0l-39.8 39.8a8.15 8.15 0 0 0 0 11.4l62.3 62.3-44.9 44.9-62.3-62.3a8.15 8.15 0 0 0-11.4 0l-39.8 39.8a8.15 8.15 0 0 0 0 11.4l62.3 62.3-65.3 65.3a8.03 8.03 0 0 0 0 11.3l42.3 42.3c3.1 3.1 8.2 3.1 11.3 0l253.6-253.6A304.06 304.06 0 0 0 608 720c167.9 0 304-136.1 304-304S775.9 112 608 112zm161.2 465.2C726.2 620.3 668.9 644 608 644c-60.9 0-118.2-23.7-161.2-66.8-43.1-43-66.8-100.3-66.8-161.2 0-60.9 23.7-118.2 66.8-161.2 43-43.1 100.3-66.8 161.2-66.8 60.9 0 118.2 23.7 161.2 66.8 43.1 43 66.8 100.3 66.8 161.2 0 60.9-23.7 118.2-66.8 161.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLaptopOutlined, "Laptop Outlined"))]
 pub static AiLaptopOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLaptopOutlined",
+    label: "Laptop Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7252,8 +10314,15 @@ pub static AiLaptopOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M956.9 845.1L896.4 632V168c0-17.7-14.3-32-32-32h-704c-17.7 0-32 14.3-32 32v464L67.9 845.1C60.4 866 75.8 888 98 888h828.8c22.2 0 37.6-22 30.1-42.9zM200.4 208h624v395h-624V208zm228.3 608l8.1-37h150.3l8.1 37H428.7zm224 0l-19.1-86.7c-.8-3.7-4.1-6.3-7.8-6.3H398.2c-3.8 0-7 2.6-7.8 6.3L371.3 816H151l42.3-149h638.2l42.3 149H652.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLayoutFilled, "Layout Filled"))]
 pub static AiLayoutFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLayoutFilled",
+    label: "Layout Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7268,8 +10337,15 @@ pub static AiLayoutFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M384 912h496c17.7 0 32-14.3 32-32V340H384v572zm496-800H384v164h528V144c0-17.7-14.3-32-32-32zm-768 32v736c0 17.7 14.3 32 32 32h176V112H144c-17.7 0-32 14.3-32 32z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLayoutOutlined, "Layout Outlined"))]
 pub static AiLayoutOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLayoutOutlined",
+    label: "Layout Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7284,8 +10360,15 @@ pub static AiLayoutOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-696 72h136v656H184V184zm656 656H384V384h456v456zM384 320V184h456v136H384z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLayoutTwotone, "Layout Twotone"))]
 pub static AiLayoutTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLayoutTwotone",
+    label: "Layout Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7301,8 +10384,15 @@ pub static AiLayoutTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M880 113H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V145c0-17.7-14.3-32-32-32zM320 841H184V185h136v656zm520 0H384V385h456v456zm0-520H384V185h456v136z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLeftCircleFilled, "Left Circle Filled"))]
 pub static AiLeftCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLeftCircleFilled",
+    label: "Left Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7317,8 +10407,15 @@ pub static AiLeftCircleFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm104 316.9c0 10.2-4.9 19.9-13.2 25.9L457.4 512l145.4 105.2c8.3 6 13.2 15.6 13.2 25.9V690c0 6.5-7.4 10.3-12.7 6.5l-246-178a7.95 7.95 0 0 1 0-12.9l246-178a8 8 0 0 1 12.7 6.5v46.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLeftCircleOutlined, "Left Circle Outlined"))]
 pub static AiLeftCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLeftCircleOutlined",
+    label: "Left Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7334,8 +10431,15 @@ pub static AiLeftCircleOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLeftCircleTwotone, "Left Circle Twotone"))]
 pub static AiLeftCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLeftCircleTwotone",
+    label: "Left Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7352,8 +10456,15 @@ pub static AiLeftCircleTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M603.3 327.5l-246 178a7.95 7.95 0 0 0 0 12.9l246 178c5.3 3.8 12.7 0 12.7-6.5V643c0-10.2-4.9-19.9-13.2-25.9L457.4 512l145.4-105.2c8.3-6 13.2-15.6 13.2-25.9V334c0-6.5-7.4-10.3-12.7-6.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLeftOutlined, "Left Outlined"))]
 pub static AiLeftOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLeftOutlined",
+    label: "Left Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7368,8 +10479,15 @@ pub static AiLeftOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M724 218.3V141c0-6.7-7.7-10.4-12.9-6.3L260.3 486.8a31.86 31.86 0 0 0 0 50.3l450.8 352.1c5.3 4.1 12.9.4 12.9-6.3v-77.3c0-4.9-2.3-9.6-6.1-12.6l-360-281 360-281.1c3.8-3 6.1-7.7 6.1-12.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLeftSquareFilled, "Left Square Filled"))]
 pub static AiLeftSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLeftSquareFilled",
+    label: "Left Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7384,8 +10502,15 @@ pub static AiLeftSquareFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM624 380.9c0 10.2-4.9 19.9-13.2 25.9L465.4 512l145.4 105.2c8.3 6 13.2 15.6 13.2 25.9V690c0 6.5-7.4 10.3-12.7 6.5l-246-178a7.95 7.95 0 0 1 0-12.9l246-178c5.3-3.8 12.7 0 12.7 6.5v46.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLeftSquareOutlined, "Left Square Outlined"))]
 pub static AiLeftSquareOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLeftSquareOutlined",
+    label: "Left Square Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7401,8 +10526,15 @@ pub static AiLeftSquareOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLeftSquareTwotone, "Left Square Twotone"))]
 pub static AiLeftSquareTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLeftSquareTwotone",
+    label: "Left Square Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7419,8 +10551,15 @@ pub static AiLeftSquareTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M365.3 518.4l246 178c5.3 3.9 12.7.1 12.7-6.4v-46.9c0-10.2-4.9-19.9-13.2-25.9L465.4 512l145.4-105.2c8.3-6 13.2-15.6 13.2-25.9V334c0-6.5-7.4-10.3-12.7-6.5l-246 178a7.95 7.95 0 0 0 0 12.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLikeFilled, "Like Filled"))]
 pub static AiLikeFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLikeFilled",
+    label: "Like Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7435,8 +10574,15 @@ pub static AiLikeFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M885.9 533.7c16.8-22.2 26.1-49.4 26.1-77.7 0-44.9-25.1-87.4-65.5-111.1a67.67 67.67 0 0 0-34.3-9.3H572.4l6-122.9c1.4-29.7-9.1-57.9-29.5-79.4A106.62 106.62 0 0 0 471 99.9c-52 0-98 35-111.8 85.1l-85.9 311h-.3v428h472.3c9.2 0 18.2-1.8 26.5-5.4 47.6-20.3 78.3-66.8 78.3-118.4 0-12.6-1.8-25-5.4-37 16.8-22.2 26.1-49.4 26.1-77.7 0-12.6-1.8-25-5.4-37 16.8-22.2 26.1-49.4 26.1-77.7-.2-12.6-2-25.1-5.6-37.1zM112 528v364c0 17.7 14.3 32 32 32h65V496h-65c-17.7 0-32 14.3-32 32z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLikeOutlined, "Like Outlined"))]
 pub static AiLikeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLikeOutlined",
+    label: "Like Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7451,8 +10597,15 @@ pub static AiLikeOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M885.9 533.7c16.8-22.2 26.1-49.4 26.1-77.7 0-44.9-25.1-87.4-65.5-111.1a67.67 67.67 0 0 0-34.3-9.3H572.4l6-122.9c1.4-29.7-9.1-57.9-29.5-79.4A106.62 106.62 0 0 0 471 99.9c-52 0-98 35-111.8 85.1l-85.9 311H144c-17.7 0-32 14.3-32 32v364c0 17.7 14.3 32 32 32h601.3c9.2 0 18.2-1.8 26.5-5.4 47.6-20.3 78.3-66.8 78.3-118.4 0-12.6-1.8-25-5.4-37 16.8-22.2 26.1-49.4 26.1-77.7 0-12.6-1.8-25-5.4-37 16.8-22.2 26.1-49.4 26.1-77.7-.2-12.6-2-25.1-5.6-37.1zM184 852V568h81v284h-81zm636.4-353l-21.9 19 13.9 25.4a56.2 56.2 0 0 1 6.9 27.3c0 16.5-7.2 32.2-19.6 43l-21.9 19 13.9 25.4a56.2 56.2 0 0 1 6.9 27.3c0 16.5-7.2 32.2-19.6 43l-21.9 19 13.9 25.4a56.2 56.2 0 0 1 6.9 27.3c0 22.4-13.2 42.6-33.6 51.8H329V564.8l99.5-360.5a44.1 44.1 0 0 1 42.2-32.3c7.6 0 15.1 2.2 21.1 6.7 9.9 7.4 15.2 18.6 14.6 30.5l-9.6 198.4h314.4C829 418.5 840 436.9 840 456c0 16.5-7.2 32.1-19.6 43z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLikeTwotone, "Like Twotone"))]
 pub static AiLikeTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLikeTwotone",
+    label: "Like Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7468,8 +10621,15 @@ pub static AiLikeTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M112 528v364c0 17.7 14.3 32 32 32h65V496h-65c-17.7 0-32 14.3-32 32zm773.9 5.7c16.8-22.2 26.1-49.4 26.1-77.7 0-44.9-25.1-87.5-65.5-111a67.67 67.67 0 0 0-34.3-9.3H572.3l6-122.9c1.5-29.7-9-57.9-29.5-79.4a106.4 106.4 0 0 0-77.9-33.4c-52 0-98 35-111.8 85.1l-85.8 310.8-.3 428h472.1c9.3 0 18.2-1.8 26.5-5.4 47.6-20.3 78.3-66.8 78.3-118.4 0-12.6-1.8-25-5.4-37 16.8-22.2 26.1-49.4 26.1-77.7 0-12.6-1.8-25-5.4-37 16.8-22.2 26.1-49.4 26.1-77.7 0-12.6-1.8-25-5.4-37zM820.4 499l-21.9 19 14 25.5a56.2 56.2 0 0 1 6.9 27.3c0 16.5-7.1 32.2-19.6 43l-21.9 19 13.9 25.4a56.2 56.2 0 0 1 6.9 27.3c0 16.5-7.1 32.2-19.6 43l-21.9 19 13.9 25.4a56.2 56.2 0 0 1 6.9 27.3c0 22.4-13.2 42.6-33.6 51.8H345V506.8c18.6-67.2 46.4-168 83.5-302.5a44.28 44.28 0 0 1 42.2-32.3c7.5-.1 15 2.2 21.1 6.7 9.9 7.4 15.2 18.6 14.6 30.5l-9.6 198.4h314.4C829 418.5 840 436.9 840 456c0 16.5-7.1 32.2-19.6 43z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLineChartOutlined, "Line Chart Outlined"))]
 pub static AiLineChartOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLineChartOutlined",
+    label: "Line Chart Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7484,8 +10644,15 @@ pub static AiLineChartOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M888 792H200V168c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v688c0 4.4 3.6 8 8 8h752c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM305.8 637.7c3.1 3.1 8.1 3.1 11.3 0l138.3-137.6L583 628.5c3.1 3.1 8.2 3.1 11.3 0l275.4-275.3c3.1-3.1 3.1-8.2 0-11.3l-39.6-39.6a8.03 8.03 0 0 0-11.3 0l-230 229.9L461.4 404a8.03 8.03 0 0 0-11.3 0L266.3 586.7a8.03 8.03 0 0 0 0 11.3l39.5 39.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLineHeightOutlined, "Line Height Outlined"))]
 pub static AiLineHeightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLineHeightOutlined",
+    label: "Line Height Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7500,8 +10667,15 @@ pub static AiLineHeightOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M648 160H104c-4.4 0-8 3.6-8 8v128c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-64h168v560h-92c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h264c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-92V232h168v64c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V168c0-4.4-3.6-8-8-8zm272.8 546H856V318h64.8c6 0 9.4-7 5.7-11.7L825.7 178.7a7.14 7.14 0 0 0-11.3 0L713.6 306.3a7.23 7.23 0 0 0 5.7 11.7H784v388h-64.8c-6 0-9.4 7-5.7 11.7l100.8 127.5c2.9 3.7 8.5 3.7 11.3 0l100.8-127.5a7.2 7.2 0 0 0-5.6-11.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLineOutlined, "Line Outlined"))]
 pub static AiLineOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLineOutlined",
+    label: "Line Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7516,8 +10690,15 @@ pub static AiLineOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M904 476H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLinkOutlined, "Link Outlined"))]
 pub static AiLinkOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLinkOutlined",
+    label: "Link Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7532,8 +10713,15 @@ pub static AiLinkOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M574 665.4a8.03 8.03 0 0 0-11.3 0L446.5 781.6c-53.8 53.8-144.6 59.5-204 0-59.5-59.5-53.8-150.2 0-204l116.2-116.2c3.1-3.1 3.1-8.2 0-11.3l-39.8-39.8a8.03 8.03 0 0 0-11.3 0L191.4 526.5c-84.6 84.6-84.6 221.5 0 306s221.5 84.6 306 0l116.2-116.2c3.1-3.1 3.1-8.2 0-11.3L574 665.4zm258.6-474c-84.6-84.6-221.5-84.6-306 0L410.3 307.6a8.03 8.03 0 0 0 0 11.3l39.7 39.7c3.1 3.1 8.2 3.1 11.3 0l116.2-116.2c53.8-53.8 144.6-59.5 204 0 59.5 59.5 53.8 150.2 0 204L665.3 562.6a8.03 8.03 0 0 0 0 11.3l39.8 39.8c3.1 3.1 8.2 3.1 11.3 0l116.2-116.2c84.5-84.6 84.5-221.5 0-306.1zM610.1 372.3a8.03 8.03 0 0 0-11.3 0L372.3 598.7a8.03 8.03 0 0 0 0 11.3l39.6 39.6c3.1 3.1 8.2 3.1 11.3 0l226.4-226.4c3.1-3.1 3.1-8.2 0-11.3l-39.5-39.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLinkedinFilled, "Linkedin Filled"))]
 pub static AiLinkedinFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLinkedinFilled",
+    label: "Linkedin Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7548,8 +10736,15 @@ pub static AiLinkedinFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM349.3 793.7H230.6V411.9h118.7v381.8zm-59.3-434a68.8 68.8 0 1 1 68.8-68.8c-.1 38-30.9 68.8-68.8 68.8zm503.7 434H675.1V608c0-44.3-.8-101.2-61.7-101.2-61.7 0-71.2 48.2-71.2 98v188.9H423.7V411.9h113.8v52.2h1.6c15.8-30 54.5-61.7 112.3-61.7 120.2 0 142.3 79.1 142.3 181.9v209.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLinkedinOutlined, "Linkedin Outlined"))]
 pub static AiLinkedinOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLinkedinOutlined",
+    label: "Linkedin Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7564,8 +10759,15 @@ pub static AiLinkedinOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M847.7 112H176.3c-35.5 0-64.3 28.8-64.3 64.3v671.4c0 35.5 28.8 64.3 64.3 64.3h671.4c35.5 0 64.3-28.8 64.3-64.3V176.3c0-35.5-28.8-64.3-64.3-64.3zm0 736c-447.8-.1-671.7-.2-671.7-.3.1-447.8.2-671.7.3-671.7 447.8.1 671.7.2 671.7.3-.1 447.8-.2 671.7-.3 671.7zM230.6 411.9h118.7v381.8H230.6zm59.4-52.2c37.9 0 68.8-30.8 68.8-68.8a68.8 68.8 0 1 0-137.6 0c-.1 38 30.7 68.8 68.8 68.8zm252.3 245.1c0-49.8 9.5-98 71.2-98 60.8 0 61.7 56.9 61.7 101.2v185.7h118.6V584.3c0-102.8-22.2-181.9-142.3-181.9-57.7 0-96.4 31.7-112.3 61.7h-1.6v-52.2H423.7v381.8h118.6V604.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLoading3QuartersOutlined, "Loading 3 Quarters Outlined"))]
 pub static AiLoading3QuartersOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLoading3QuartersOutlined",
+    label: "Loading 3 Quarters Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7580,8 +10782,15 @@ pub static AiLoading3QuartersOutlined: &icondata_core::IconData = &icondata_core
     data: r###"<path d="M512 1024c-69.1 0-136.2-13.5-199.3-40.2C251.7 958 197 921 150 874c-47-47-84-101.7-109.8-162.7C13.5 648.2 0 581.1 0 512c0-19.9 16.1-36 36-36s36 16.1 36 36c0 59.4 11.6 117 34.6 171.3 22.2 52.4 53.9 99.5 94.3 139.9 40.4 40.4 87.5 72.2 139.9 94.3C395 940.4 452.6 952 512 952c59.4 0 117-11.6 171.3-34.6 52.4-22.2 99.5-53.9 139.9-94.3 40.4-40.4 72.2-87.5 94.3-139.9C940.4 629 952 571.4 952 512c0-59.4-11.6-117-34.6-171.3a440.45 440.45 0 0 0-94.3-139.9 437.71 437.71 0 0 0-139.9-94.3C629 83.6 571.4 72 512 72c-19.9 0-36-16.1-36-36s16.1-36 36-36c69.1 0 136.2 13.5 199.3 40.2C772.3 66 827 103 874 150c47 47 83.9 101.8 109.7 162.7 26.7 63.1 40.2 130.2 40.2 199.3s-13.5 136.2-40.2 199.3C958 772.3 921 827 874 874c-47 47-101.8 83.9-162.7 109.7-63.1 26.8-130.2 40.3-199.3 40.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLoadingOutlined, "Loading Outlined"))]
 pub static AiLoadingOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLoadingOutlined",
+    label: "Loading Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7596,8 +10805,15 @@ pub static AiLoadingOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M988 548c-19.9 0-36-16.1-36-36 0-59.4-11.6-117-34.6-171.3a440.45 440.45 0 0 0-94.3-139.9 437.71 437.71 0 0 0-139.9-94.3C629 83.6 571.4 72 512 72c-19.9 0-36-16.1-36-36s16.1-36 36-36c69.1 0 136.2 13.5 199.3 40.3C772.3 66 827 103 874 150c47 47 83.9 101.8 109.7 162.7 26.7 63.1 40.2 130.2 40.2 199.3.1 19.9-16 36-35.9 36z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLockFilled, "Lock Filled"))]
 pub static AiLockFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLockFilled",
+    label: "Lock Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7612,8 +10828,15 @@ pub static AiLockFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M832 464h-68V240c0-70.7-57.3-128-128-128H388c-70.7 0-128 57.3-128 128v224h-68c-17.7 0-32 14.3-32 32v384c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V496c0-17.7-14.3-32-32-32zM540 701v53c0 4.4-3.6 8-8 8h-40c-4.4 0-8-3.6-8-8v-53a48.01 48.01 0 1 1 56 0zm152-237H332V240c0-30.9 25.1-56 56-56h248c30.9 0 56 25.1 56 56v224z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLockOutlined, "Lock Outlined"))]
 pub static AiLockOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLockOutlined",
+    label: "Lock Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7628,8 +10851,15 @@ pub static AiLockOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M832 464h-68V240c0-70.7-57.3-128-128-128H388c-70.7 0-128 57.3-128 128v224h-68c-17.7 0-32 14.3-32 32v384c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V496c0-17.7-14.3-32-32-32zM332 240c0-30.9 25.1-56 56-56h248c30.9 0 56 25.1 56 56v224H332V240zm460 600H232V536h560v304zM484 701v53c0 4.4 3.6 8 8 8h40c4.4 0 8-3.6 8-8v-53a48.01 48.01 0 1 0-56 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLockTwotone, "Lock Twotone"))]
 pub static AiLockTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLockTwotone",
+    label: "Lock Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7646,8 +10876,15 @@ pub static AiLockTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M484 701v53c0 4.4 3.6 8 8 8h40c4.4 0 8-3.6 8-8v-53a48.01 48.01 0 1 0-56 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLoginOutlined, "Login Outlined"))]
 pub static AiLoginOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLoginOutlined",
+    label: "Login Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7665,8 +10902,15 @@ pub static AiLoginOutlined: &icondata_core::IconData = &icondata_core::IconData
 <path d="M521.7 82c-152.5-.4-286.7 78.5-363.4 197.7-3.4 5.3.4 12.3 6.7 12.3h70.3c4.8 0 9.3-2.1 12.3-5.8 7-8.5 14.5-16.7 22.4-24.5 32.6-32.5 70.5-58.1 112.7-75.9 43.6-18.4 90-27.8 137.9-27.8 47.9 0 94.3 9.3 137.9 27.8 42.2 17.8 80.1 43.4 112.7 75.9 32.6 32.5 58.1 70.4 76 112.5C865.7 417.8 875 464.1 875 512c0 47.9-9.4 94.2-27.8 137.8-17.8 42.1-43.4 80-76 112.5s-70.5 58.1-112.7 75.9A352.8 352.8 0 0 1 520.6 866c-47.9 0-94.3-9.4-137.9-27.8A353.84 353.84 0 0 1 270 762.3c-7.9-7.9-15.3-16.1-22.4-24.5-3-3.7-7.6-5.8-12.3-5.8H165c-6.3 0-10.2 7-6.7 12.3C234.9 863.2 368.5 942 520.6 942c236.2 0 428-190.1 430.4-425.6C953.4 277.1 761.3 82.6 521.7 82zM395.02 624v-76h-314c-4.4 0-8-3.6-8-8v-56c0-4.4 3.6-8 8-8h314v-76c0-6.7 7.8-10.5 13-6.3l141.9 112a8 8 0 0 1 0 12.6l-141.9 112c-5.2 4.1-13 .4-13-6.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiLogoutOutlined, "Logout Outlined"))]
 pub static AiLogoutOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiLogoutOutlined",
+    label: "Logout Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7681,8 +10925,15 @@ pub static AiLogoutOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M868 732h-70.3c-4.8 0-9.3 2.1-12.3 5.8-7 8.5-14.5 16.7-22.4 24.5a353.84 353.84 0 0 1-112.7 75.9A352.8 352.8 0 0 1 512.4 866c-47.9 0-94.3-9.4-137.9-27.8a353.84 353.84 0 0 1-112.7-75.9 353.28 353.28 0 0 1-76-112.5C167.3 606.2 158 559.9 158 512s9.4-94.2 27.8-137.8c17.8-42.1 43.4-80 76-112.5s70.5-58.1 112.7-75.9c43.6-18.4 90-27.8 137.9-27.8 47.9 0 94.3 9.3 137.9 27.8 42.2 17.8 80.1 43.4 112.7 75.9 7.9 7.9 15.3 16.1 22.4 24.5 3 3.7 7.6 5.8 12.3 5.8H868c6.3 0 10.2-7 6.7-12.3C798 160.5 663.8 81.6 511.3 82 271.7 82.6 79.6 277.1 82 516.4 84.4 751.9 276.2 942 512.4 942c152.1 0 285.7-78.8 362.3-197.7 3.4-5.3-.4-12.3-6.7-12.3zm88.9-226.3L815 393.7c-5.3-4.2-13-.4-13 6.3v76H488c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h314v76c0 6.7 7.8 10.5 13 6.3l141.9-112a8 8 0 0 0 0-12.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMacCommandFilled, "Mac Command Filled"))]
 pub static AiMacCommandFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMacCommandFilled",
+    label: "Mac Command Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7702,8 +10953,15 @@ pub static AiMacCommandFilled: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M464 464h96v96h-96zM352 304c-26.5 0-48 21.5-48 48s21.5 48 48 48h48v-48c0-26.5-21.5-48-48-48zM304 672c0 26.5 21.5 48 48 48s48-21.5 48-48v-48h-48c-26.5 0-48 21.5-48 48z" p-id="7962" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMacCommandOutlined, "Mac Command Outlined"))]
 pub static AiMacCommandOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMacCommandOutlined",
+    label: "Mac Command Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7722,8 +10980,15 @@ pub static AiMacCommandOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M370.8 554.4c-54.6 0-98.8 44.2-98.8 98.8s44.2 98.8 98.8 98.8 98.8-44.2 98.8-98.8v-42.4h84.7v42.4c0 54.6 44.2 98.8 98.8 98.8s98.8-44.2 98.8-98.8-44.2-98.8-98.8-98.8h-42.4v-84.7h42.4c54.6 0 98.8-44.2 98.8-98.8 0-54.6-44.2-98.8-98.8-98.8s-98.8 44.2-98.8 98.8v42.4h-84.7v-42.4c0-54.6-44.2-98.8-98.8-98.8S272 316.2 272 370.8s44.2 98.8 98.8 98.8h42.4v84.7h-42.4z m42.4 98.8c0 23.4-19 42.4-42.4 42.4s-42.4-19-42.4-42.4 19-42.4 42.4-42.4h42.4v42.4z m197.6-282.4c0-23.4 19-42.4 42.4-42.4s42.4 19 42.4 42.4-19 42.4-42.4 42.4h-42.4v-42.4z m0 240h42.4c23.4 0 42.4 19 42.4 42.4s-19 42.4-42.4 42.4-42.4-19-42.4-42.4v-42.4zM469.6 469.6h84.7v84.7h-84.7v-84.7z m-98.8-56.4c-23.4 0-42.4-19-42.4-42.4s19-42.4 42.4-42.4 42.4 19 42.4 42.4v42.4h-42.4z" p-id="14379" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMailFilled, "Mail Filled"))]
 pub static AiMailFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMailFilled",
+    label: "Mail Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7738,8 +11003,15 @@ pub static AiMailFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M928 160H96c-17.7 0-32 14.3-32 32v640c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V192c0-17.7-14.3-32-32-32zm-80.8 108.9L531.7 514.4c-7.8 6.1-18.7 6.1-26.5 0L189.6 268.9A7.2 7.2 0 0 1 194 256h648.8a7.2 7.2 0 0 1 4.4 12.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMailOutlined, "Mail Outlined"))]
 pub static AiMailOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMailOutlined",
+    label: "Mail Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7754,8 +11026,15 @@ pub static AiMailOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M928 160H96c-17.7 0-32 14.3-32 32v640c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V192c0-17.7-14.3-32-32-32zm-40 110.8V792H136V270.8l-27.6-21.5 39.3-50.5 42.8 33.3h643.1l42.8-33.3 39.3 50.5-27.7 21.5zM833.6 232L512 482 190.4 232l-42.8-33.3-39.3 50.5 27.6 21.5 341.6 265.6a55.99 55.99 0 0 0 68.7 0L888 270.8l27.6-21.5-39.3-50.5-42.7 33.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMailTwotone, "Mail Twotone"))]
 pub static AiMailTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMailTwotone",
+    label: "Mail Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7772,8 +11051,15 @@ pub static AiMailTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M928 160H96c-17.7 0-32 14.3-32 32v640c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V192c0-17.7-14.3-32-32-32zm-94.5 72.1L512 482 190.5 232.1h643zm54.5 38.7V792H136V270.8l-27.6-21.5 27.5 21.4 341.6 265.6a55.99 55.99 0 0 0 68.7 0L888 270.8l27.6-21.5-39.3-50.5h.1l39.3 50.5-27.7 21.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiManOutlined, "Man Outlined"))]
 pub static AiManOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiManOutlined",
+    label: "Man Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7788,8 +11074,15 @@ pub static AiManOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M874 120H622c-3.3 0-6 2.7-6 6v56c0 3.3 2.7 6 6 6h160.4L583.1 387.3c-50-38.5-111-59.3-175.1-59.3-76.9 0-149.3 30-203.6 84.4S120 539.1 120 616s30 149.3 84.4 203.6C258.7 874 331.1 904 408 904s149.3-30 203.6-84.4C666 765.3 696 692.9 696 616c0-64.1-20.8-124.9-59.2-174.9L836 241.9V402c0 3.3 2.7 6 6 6h56c3.3 0 6-2.7 6-6V150c0-16.5-13.5-30-30-30zM408 828c-116.9 0-212-95.1-212-212s95.1-212 212-212 212 95.1 212 212-95.1 212-212 212z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMedicineBoxFilled, "Medicine Box Filled"))]
 pub static AiMedicineBoxFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMedicineBoxFilled",
+    label: "Medicine Box Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7804,8 +11097,15 @@ pub static AiMedicineBoxFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M839.2 278.1a32 32 0 0 0-30.4-22.1H736V144c0-17.7-14.3-32-32-32H320c-17.7 0-32 14.3-32 32v112h-72.8a31.9 31.9 0 0 0-30.4 22.1L112 502v378c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V502l-72.8-223.9zM660 628c0 4.4-3.6 8-8 8H544v108c0 4.4-3.6 8-8 8h-48c-4.4 0-8-3.6-8-8V636H372c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h108V464c0-4.4 3.6-8 8-8h48c4.4 0 8 3.6 8 8v108h108c4.4 0 8 3.6 8 8v48zm4-372H360v-72h304v72z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMedicineBoxOutlined, "Medicine Box Outlined"))]
 pub static AiMedicineBoxOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMedicineBoxOutlined",
+    label: "Medicine Box Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7820,8 +11120,15 @@ pub static AiMedicineBoxOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M839.2 278.1a32 32 0 0 0-30.4-22.1H736V144c0-17.7-14.3-32-32-32H320c-17.7 0-32 14.3-32 32v112h-72.8a31.9 31.9 0 0 0-30.4 22.1L112 502v378c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V502l-72.8-223.9zM360 184h304v72H360v-72zm480 656H184V513.4L244.3 328h535.4L840 513.4V840zM652 572H544V464c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v108H372c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h108v108c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V636h108c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMedicineBoxTwotone, "Medicine Box Twotone"))]
 pub static AiMedicineBoxTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMedicineBoxTwotone",
+    label: "Medicine Box Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7838,8 +11145,15 @@ pub static AiMedicineBoxTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M839.2 278.1a32 32 0 0 0-30.4-22.1H736V144c0-17.7-14.3-32-32-32H320c-17.7 0-32 14.3-32 32v112h-72.8a31.9 31.9 0 0 0-30.4 22.1L112 502v378c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V502l-72.8-223.9zM360 184h304v72H360v-72zm480 656H184V513.4L244.3 328h535.4L840 513.4V840z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMediumCircleFilled, "Medium Circle Filled"))]
 pub static AiMediumCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMediumCircleFilled",
+    label: "Medium Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7854,8 +11168,15 @@ pub static AiMediumCircleFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm256 253.7l-40.8 39.1c-3.6 2.7-5.3 7.1-4.6 11.4v287.7c-.7 4.4 1 8.8 4.6 11.4l40 39.1v8.7H566.4v-8.3l41.3-40.1c4.1-4.1 4.1-5.3 4.1-11.4V422.5l-115 291.6h-15.5L347.5 422.5V618c-1.2 8.2 1.7 16.5 7.5 22.4l53.8 65.1v8.7H256v-8.7l53.8-65.1a26.1 26.1 0 0 0 7-22.4V392c.7-6.3-1.7-12.4-6.5-16.7l-47.8-57.6V309H411l114.6 251.5 100.9-251.3H768v8.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMediumOutlined, "Medium Outlined"))]
 pub static AiMediumOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMediumOutlined",
+    label: "Medium Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7870,8 +11191,15 @@ pub static AiMediumOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M834.7 279.8l61.3-58.9V208H683.7L532.4 586.4 360.3 208H137.7v12.9l71.6 86.6c7 6.4 10.6 15.8 9.7 25.2V673c2.2 12.3-1.7 24.8-10.3 33.7L128 805v12.7h228.6v-12.9l-80.6-98a39.99 39.99 0 0 1-11.1-33.7V378.7l200.7 439.2h23.3l172.6-439.2v349.9c0 9.2 0 11.1-6 17.2l-62.1 60.3V819h301.2v-12.9l-59.9-58.9c-5.2-4-7.9-10.7-6.8-17.2V297a18.1 18.1 0 0 1 6.8-17.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMediumSquareFilled, "Medium Square Filled"))]
 pub static AiMediumSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMediumSquareFilled",
+    label: "Medium Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7886,8 +11214,15 @@ pub static AiMediumSquareFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM768 317.7l-40.8 39.1c-3.6 2.7-5.3 7.1-4.6 11.4v287.7c-.7 4.4 1 8.8 4.6 11.4l40 39.1v8.7H566.4v-8.3l41.3-40.1c4.1-4.1 4.1-5.3 4.1-11.4V422.5l-115 291.6h-15.5L347.5 422.5V618c-1.2 8.2 1.7 16.5 7.5 22.4l53.8 65.1v8.7H256v-8.7l53.8-65.1a26.1 26.1 0 0 0 7-22.4V392c.7-6.3-1.7-12.4-6.5-16.7l-47.8-57.6V309H411l114.6 251.5 100.9-251.3H768v8.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMediumWorkmarkOutlined, "Medium Workmark Outlined"))]
 pub static AiMediumWorkmarkOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMediumWorkmarkOutlined",
+    label: "Medium Workmark Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7902,8 +11237,15 @@ pub static AiMediumWorkmarkOutlined: &icondata_core::IconData = &icondata_core::
     data: r###"<path d="M517.2 590.55c0 3.55 0 4.36 2.4 6.55l13.43 13.25v.57h-59.57v-25.47a41.44 41.44 0 0 1-39.5 27.65c-30.61 0-52.84-24.25-52.84-68.87 0-41.8 23.99-69.69 57.65-69.69a35.15 35.15 0 0 1 34.61 21.67v-56.19a6.99 6.99 0 0 0-2.71-6.79l-12.8-12.45v-.56l59.33-7.04v177.37zm-43.74-8.09v-83.83a22.2 22.2 0 0 0-17.74-8.4c-14.48 0-28.47 13.25-28.47 52.62 0 36.86 12.07 49.88 27.1 49.88a23.91 23.91 0 0 0 19.11-10.27zm83.23 28.46V497.74a7.65 7.65 0 0 0-2.4-6.79l-13.19-13.74v-.57h59.56v114.8c0 3.55 0 4.36 2.4 6.54l13.12 12.45v.57l-59.49-.08zm-2.16-175.67c0-13.4 10.74-24.25 23.99-24.25 13.25 0 23.98 10.86 23.98 24.25 0 13.4-10.73 24.25-23.98 24.25s-23.99-10.85-23.99-24.25zm206.83 155.06c0 3.55 0 4.6 2.4 6.79l13.43 13.25v.57h-59.88V581.9a43.4 43.4 0 0 1-41.01 31.2c-26.55 0-40.78-19.56-40.78-56.59 0-17.86 0-37.43.56-59.41a6.91 6.91 0 0 0-2.4-6.55L620.5 477.2v-.57h59.09v73.81c0 24.25 3.51 40.42 18.54 40.42a23.96 23.96 0 0 0 19.35-12.2v-80.85a7.65 7.65 0 0 0-2.4-6.79l-13.27-13.82v-.57h59.56V590.3zm202.76 20.6c0-4.36.8-59.97.8-72.75 0-24.25-3.76-40.98-20.63-40.98a26.7 26.7 0 0 0-21.19 11.64 99.68 99.68 0 0 1 2.4 23.04c0 16.81-.56 38.23-.8 59.66a6.91 6.91 0 0 0 2.4 6.55l13.43 12.45v.56h-60.12c0-4.04.8-59.98.8-72.76 0-24.65-3.76-40.98-20.39-40.98-8.2.3-15.68 4.8-19.83 11.96v82.46c0 3.56 0 4.37 2.4 6.55l13.11 12.45v.56h-59.48V498.15a7.65 7.65 0 0 0-2.4-6.8l-13.19-14.14v-.57H841v28.78c5.53-19 23.13-31.76 42.7-30.96 19.82 0 33.26 11.16 38.93 32.34a46.41 46.41 0 0 1 44.77-32.34c26.55 0 41.58 19.8 41.58 57.23 0 17.87-.56 38.24-.8 59.66a6.5 6.5 0 0 0 2.72 6.55l13.11 12.45v.57h-59.88zM215.87 593.3l17.66 17.05v.57h-89.62v-.57l17.99-17.05a6.91 6.91 0 0 0 2.4-6.55V477.69c0-4.6 0-10.83.8-16.16L104.66 613.1h-.72l-62.6-139.45c-1.37-3.47-1.77-3.72-2.65-6.06v91.43a32.08 32.08 0 0 0 2.96 17.87l25.19 33.46v.57H0v-.57l25.18-33.55a32.16 32.16 0 0 0 2.96-17.78V457.97A19.71 19.71 0 0 0 24 444.15L6.16 420.78v-.56h63.96l53.56 118.1 47.17-118.1h62.6v.56l-17.58 19.8a6.99 6.99 0 0 0-2.72 6.8v139.37a6.5 6.5 0 0 0 2.72 6.55zm70.11-54.65v.56c0 34.6 17.67 48.5 38.38 48.5a43.5 43.5 0 0 0 40.77-24.97h.56c-7.2 34.2-28.14 50.36-59.48 50.36-33.82 0-65.72-20.61-65.72-68.39 0-50.2 31.98-70.25 67.32-70.25 28.46 0 58.76 13.58 58.76 57.24v6.95h-80.59zm0-6.95h39.42v-7.04c0-35.57-7.28-45.03-18.23-45.03-13.27 0-21.35 14.15-21.35 52.07h.16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMehFilled, "Meh Filled"))]
 pub static AiMehFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMehFilled",
+    label: "Meh Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7918,8 +11260,15 @@ pub static AiMehFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zM288 421a48.01 48.01 0 0 1 96 0 48.01 48.01 0 0 1-96 0zm384 200c0 4.4-3.6 8-8 8H360c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h304c4.4 0 8 3.6 8 8v48zm16-152a48.01 48.01 0 0 1 0-96 48.01 48.01 0 0 1 0 96z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMehOutlined, "Meh Outlined"))]
 pub static AiMehOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMehOutlined",
+    label: "Meh Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7934,8 +11283,15 @@ pub static AiMehOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M288 421a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm352 0a48 48 0 1 0 96 0 48 48 0 1 0-96 0zM512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm263 711c-34.2 34.2-74 61-118.3 79.8C611 874.2 562.3 884 512 884c-50.3 0-99-9.8-144.8-29.2A370.4 370.4 0 0 1 248.9 775c-34.2-34.2-61-74-79.8-118.3C149.8 611 140 562.3 140 512s9.8-99 29.2-144.8A370.4 370.4 0 0 1 249 248.9c34.2-34.2 74-61 118.3-79.8C413 149.8 461.7 140 512 140c50.3 0 99 9.8 144.8 29.2A370.4 370.4 0 0 1 775.1 249c34.2 34.2 61 74 79.8 118.3C874.2 413 884 461.7 884 512s-9.8 99-29.2 144.8A368.89 368.89 0 0 1 775 775zM664 565H360c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h304c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMehTwotone, "Meh Twotone"))]
 pub static AiMehTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMehTwotone",
+    label: "Meh Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7952,8 +11308,15 @@ pub static AiMehTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M288 421a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm376 144H360c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h304c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zm-24-144a48 48 0 1 0 96 0 48 48 0 1 0-96 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMenuFoldOutlined, "Menu Fold Outlined"))]
 pub static AiMenuFoldOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMenuFoldOutlined",
+    label: "Menu Fold Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7968,8 +11331,15 @@ pub static AiMenuFoldOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M408 442h480c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H408c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm-8 204c0 4.4 3.6 8 8 8h480c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H408c-4.4 0-8 3.6-8 8v56zm504-486H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 632H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM115.4 518.9L271.7 642c5.8 4.6 14.4.5 14.4-6.9V388.9c0-7.4-8.5-11.5-14.4-6.9L115.4 505.1a8.74 8.74 0 0 0 0 13.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMenuOutlined, "Menu Outlined"))]
 pub static AiMenuOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMenuOutlined",
+    label: "Menu Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7984,8 +11354,15 @@ pub static AiMenuOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M904 160H120c-4.4 0-8 3.6-8 8v64c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-64c0-4.4-3.6-8-8-8zm0 624H120c-4.4 0-8 3.6-8 8v64c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-64c0-4.4-3.6-8-8-8zm0-312H120c-4.4 0-8 3.6-8 8v64c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-64c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMenuUnfoldOutlined, "Menu Unfold Outlined"))]
 pub static AiMenuUnfoldOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMenuUnfoldOutlined",
+    label: "Menu Unfold Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8000,8 +11377,15 @@ pub static AiMenuUnfoldOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M408 442h480c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H408c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm-8 204c0 4.4 3.6 8 8 8h480c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H408c-4.4 0-8 3.6-8 8v56zm504-486H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 632H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM142.4 642.1L298.7 519a8.84 8.84 0 0 0 0-13.9L142.4 381.9c-5.8-4.6-14.4-.5-14.4 6.9v246.3a8.9 8.9 0 0 0 14.4 7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMergeCellsOutlined, "Merge Cells Outlined"))]
 pub static AiMergeCellsOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMergeCellsOutlined",
+    label: "Merge Cells Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8019,8 +11403,15 @@ pub static AiMergeCellsOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M482.2 508.4L331.3 389c-3-2.4-7.3-0.2-7.3 3.6V478H184V184h204v128c0 2.2 1.8 4 4 4h60c2.2 0 4-1.8 4-4V144c0-15.5-12.5-28-28-28H144c-15.5 0-28 12.5-28 28v736c0 15.5 12.5 28 28 28h284c15.5 0 28-12.5 28-28V712c0-2.2-1.8-4-4-4h-60c-2.2 0-4 1.8-4 4v128H184V546h140v85.4c0 3.8 4.4 6 7.3 3.6l150.9-119.4c2.4-1.8 2.4-5.4 0-7.2zM880 116H596c-15.5 0-28 12.5-28 28v168c0 2.2 1.8 4 4 4h60c2.2 0 4-1.8 4-4V184h204v294H700v-85.4c0-3.8-4.3-6-7.3-3.6l-151 119.4c-2.3 1.8-2.3 5.3 0 7.1l151 119.5c2.9 2.3 7.3 0.2 7.3-3.6V546h140v294H636V712c0-2.2-1.8-4-4-4h-60c-2.2 0-4 1.8-4 4v168c0 15.5 12.5 28 28 28h284c15.5 0 28-12.5 28-28V144c0-15.5-12.5-28-28-28z" p-id="10228" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMessageFilled, "Message Filled"))]
 pub static AiMessageFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMessageFilled",
+    label: "Message Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8035,8 +11426,15 @@ pub static AiMessageFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M924.3 338.4a447.57 447.57 0 0 0-96.1-143.3 443.09 443.09 0 0 0-143-96.3A443.91 443.91 0 0 0 512 64h-2c-60.5.3-119 12.3-174.1 35.9a444.08 444.08 0 0 0-141.7 96.5 445 445 0 0 0-95 142.8A449.89 449.89 0 0 0 65 514.1c.3 69.4 16.9 138.3 47.9 199.9v152c0 25.4 20.6 46 45.9 46h151.8a447.72 447.72 0 0 0 199.5 48h2.1c59.8 0 117.7-11.6 172.3-34.3A443.2 443.2 0 0 0 827 830.5c41.2-40.9 73.6-88.7 96.3-142 23.5-55.2 35.5-113.9 35.8-174.5.2-60.9-11.6-120-34.8-175.6zM312.4 560c-26.4 0-47.9-21.5-47.9-48s21.5-48 47.9-48 47.9 21.5 47.9 48-21.4 48-47.9 48zm199.6 0c-26.4 0-47.9-21.5-47.9-48s21.5-48 47.9-48 47.9 21.5 47.9 48-21.5 48-47.9 48zm199.6 0c-26.4 0-47.9-21.5-47.9-48s21.5-48 47.9-48 47.9 21.5 47.9 48-21.5 48-47.9 48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMessageOutlined, "Message Outlined"))]
 pub static AiMessageOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMessageOutlined",
+    label: "Message Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8051,8 +11449,15 @@ pub static AiMessageOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M464 512a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm200 0a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm-400 0a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm661.2-173.6c-22.6-53.7-55-101.9-96.3-143.3a444.35 444.35 0 0 0-143.3-96.3C630.6 75.7 572.2 64 512 64h-2c-60.6.3-119.3 12.3-174.5 35.9a445.35 445.35 0 0 0-142 96.5c-40.9 41.3-73 89.3-95.2 142.8-23 55.4-34.6 114.3-34.3 174.9A449.4 449.4 0 0 0 112 714v152a46 46 0 0 0 46 46h152.1A449.4 449.4 0 0 0 510 960h2.1c59.9 0 118-11.6 172.7-34.3a444.48 444.48 0 0 0 142.8-95.2c41.3-40.9 73.8-88.7 96.5-142 23.6-55.2 35.6-113.9 35.9-174.5.3-60.9-11.5-120-34.8-175.6zm-151.1 438C704 845.8 611 884 512 884h-1.7c-60.3-.3-120.2-15.3-173.1-43.5l-8.4-4.5H188V695.2l-4.5-8.4C155.3 633.9 140.3 574 140 513.7c-.4-99.7 37.7-193.3 107.6-263.8 69.8-70.5 163.1-109.5 262.8-109.9h1.7c50 0 98.5 9.7 144.2 28.9 44.6 18.7 84.6 45.6 119 80 34.3 34.3 61.3 74.4 80 119 19.4 46.2 29.1 95.2 28.9 145.8-.6 99.6-39.7 192.9-110.1 262.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMessageTwotone, "Message Twotone"))]
 pub static AiMessageTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMessageTwotone",
+    label: "Message Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8070,8 +11475,15 @@ pub static AiMessageTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M464 512a48 48 0 1 0 96 0 48 48 0 1 0-96 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMinusCircleFilled, "Minus Circle Filled"))]
 pub static AiMinusCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMinusCircleFilled",
+    label: "Minus Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8086,8 +11498,15 @@ pub static AiMinusCircleFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm192 472c0 4.4-3.6 8-8 8H328c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h368c4.4 0 8 3.6 8 8v48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMinusCircleOutlined, "Minus Circle Outlined"))]
 pub static AiMinusCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMinusCircleOutlined",
+    label: "Minus Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8103,8 +11522,15 @@ pub static AiMinusCircleOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMinusCircleTwotone, "Minus Circle Twotone"))]
 pub static AiMinusCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMinusCircleTwotone",
+    label: "Minus Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8121,8 +11547,15 @@ pub static AiMinusCircleTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M696 480H328c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h368c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMinusOutlined, "Minus Outlined"))]
 pub static AiMinusOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMinusOutlined",
+    label: "Minus Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8137,8 +11570,15 @@ pub static AiMinusOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M872 474H152c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h720c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMinusSquareFilled, "Minus Square Filled"))]
 pub static AiMinusSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMinusSquareFilled",
+    label: "Minus Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8153,8 +11593,15 @@ pub static AiMinusSquareFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM704 536c0 4.4-3.6 8-8 8H328c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h368c4.4 0 8 3.6 8 8v48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMinusSquareOutlined, "Minus Square Outlined"))]
 pub static AiMinusSquareOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMinusSquareOutlined",
+    label: "Minus Square Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8170,8 +11617,15 @@ pub static AiMinusSquareOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMinusSquareTwotone, "Minus Square Twotone"))]
 pub static AiMinusSquareTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMinusSquareTwotone",
+    label: "Minus Square Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8188,8 +11642,15 @@ pub static AiMinusSquareTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M328 544h368c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H328c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMobileFilled, "Mobile Filled"))]
 pub static AiMobileFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMobileFilled",
+    label: "Mobile Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8204,8 +11665,15 @@ pub static AiMobileFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M744 62H280c-35.3 0-64 28.7-64 64v768c0 35.3 28.7 64 64 64h464c35.3 0 64-28.7 64-64V126c0-35.3-28.7-64-64-64zM512 824c-22.1 0-40-17.9-40-40s17.9-40 40-40 40 17.9 40 40-17.9 40-40 40z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMobileOutlined, "Mobile Outlined"))]
 pub static AiMobileOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMobileOutlined",
+    label: "Mobile Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8220,8 +11688,15 @@ pub static AiMobileOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M744 62H280c-35.3 0-64 28.7-64 64v768c0 35.3 28.7 64 64 64h464c35.3 0 64-28.7 64-64V126c0-35.3-28.7-64-64-64zm-8 824H288V134h448v752zM472 784a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMobileTwotone, "Mobile Twotone"))]
 pub static AiMobileTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMobileTwotone",
+    label: "Mobile Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8238,8 +11713,15 @@ pub static AiMobileTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M472 786a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMoneyCollectFilled, "Money Collect Filled"))]
 pub static AiMoneyCollectFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMoneyCollectFilled",
+    label: "Money Collect Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8254,8 +11736,15 @@ pub static AiMoneyCollectFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M911.5 699.7a8 8 0 0 0-10.3-4.8L840 717.2V179c0-37.6-30.4-68-68-68H252c-37.6 0-68 30.4-68 68v538.2l-61.3-22.3c-.9-.3-1.8-.5-2.7-.5-4.4 0-8 3.6-8 8V762c0 3.3 2.1 6.3 5.3 7.5L501 909.1c7.1 2.6 14.8 2.6 21.9 0l383.8-139.5c3.2-1.2 5.3-4.2 5.3-7.5v-59.6c0-1-.2-1.9-.5-2.8zm-243.8-377L564 514.3h57.6c4.4 0 8 3.6 8 8v27.1c0 4.4-3.6 8-8 8h-76.3v39h76.3c4.4 0 8 3.6 8 8v27.1c0 4.4-3.6 8-8 8h-76.3V703c0 4.4-3.6 8-8 8h-49.9c-4.4 0-8-3.6-8-8v-63.4h-76c-4.4 0-8-3.6-8-8v-27.1c0-4.4 3.6-8 8-8h76v-39h-76c-4.4 0-8-3.6-8-8v-27.1c0-4.4 3.6-8 8-8h57L356.5 322.8c-2.1-3.8-.7-8.7 3.2-10.8 1.2-.7 2.5-1 3.8-1h55.7a8 8 0 0 1 7.1 4.4L511 484.2h3.3L599 315.4c1.3-2.7 4.1-4.4 7.1-4.4h54.5c4.4 0 8 3.6 8.1 7.9 0 1.3-.4 2.6-1 3.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMoneyCollectOutlined, "Money Collect Outlined"))]
 pub static AiMoneyCollectOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMoneyCollectOutlined",
+    label: "Money Collect Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8270,8 +11759,15 @@ pub static AiMoneyCollectOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M911.5 700.7a8 8 0 0 0-10.3-4.8L840 718.2V180c0-37.6-30.4-68-68-68H252c-37.6 0-68 30.4-68 68v538.2l-61.3-22.3c-.9-.3-1.8-.5-2.7-.5-4.4 0-8 3.6-8 8V763c0 3.3 2.1 6.3 5.3 7.5L501 910.1c7.1 2.6 14.8 2.6 21.9 0l383.8-139.5c3.2-1.2 5.3-4.2 5.3-7.5v-59.6c0-1-.2-1.9-.5-2.8zM512 837.5l-256-93.1V184h512v560.4l-256 93.1zM660.6 312h-54.5c-3 0-5.8 1.7-7.1 4.4l-84.7 168.8H511l-84.7-168.8a8 8 0 0 0-7.1-4.4h-55.7c-1.3 0-2.6.3-3.8 1-3.9 2.1-5.3 7-3.2 10.8l103.9 191.6h-57c-4.4 0-8 3.6-8 8v27.1c0 4.4 3.6 8 8 8h76v39h-76c-4.4 0-8 3.6-8 8v27.1c0 4.4 3.6 8 8 8h76V704c0 4.4 3.6 8 8 8h49.9c4.4 0 8-3.6 8-8v-63.5h76.3c4.4 0 8-3.6 8-8v-27.1c0-4.4-3.6-8-8-8h-76.3v-39h76.3c4.4 0 8-3.6 8-8v-27.1c0-4.4-3.6-8-8-8H564l103.7-191.6c.6-1.2 1-2.5 1-3.8-.1-4.3-3.7-7.9-8.1-7.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMoneyCollectTwotone, "Money Collect Twotone"))]
 pub static AiMoneyCollectTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMoneyCollectTwotone",
+    label: "Money Collect Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8288,8 +11784,15 @@ pub static AiMoneyCollectTwotone: &icondata_core::IconData = &icondata_core::Ico
 <path d="M460.4 515.4h-57c-4.4 0-8 3.6-8 8v27.1c0 4.4 3.6 8 8 8h76v39h-76c-4.4 0-8 3.6-8 8v27.1c0 4.4 3.6 8 8 8h76V704c0 4.4 3.6 8 8 8h49.9c4.4 0 8-3.6 8-8v-63.5h76.3c4.4 0 8-3.6 8-8v-27.1c0-4.4-3.6-8-8-8h-76.3v-39h76.3c4.4 0 8-3.6 8-8v-27.1c0-4.4-3.6-8-8-8H564l103.7-191.6c.6-1.2 1-2.5 1-3.8-.1-4.3-3.7-7.9-8.1-7.9h-54.5c-3 0-5.8 1.7-7.1 4.4l-84.7 168.8H511l-84.7-168.8a8 8 0 0 0-7.1-4.4h-55.7c-1.3 0-2.6.3-3.8 1-3.9 2.1-5.3 7-3.2 10.8l103.9 191.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMonitorOutlined, "Monitor Outlined"))]
 pub static AiMonitorOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMonitorOutlined",
+    label: "Monitor Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8304,8 +11807,15 @@ pub static AiMonitorOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M692.8 412.7l.2-.2-34.6-44.3a7.97 7.97 0 0 0-11.2-1.4l-50.4 39.3-70.5-90.1a7.97 7.97 0 0 0-11.2-1.4l-37.9 29.7a7.97 7.97 0 0 0-1.4 11.2l70.5 90.2-.2.1 34.6 44.3c2.7 3.5 7.7 4.1 11.2 1.4l50.4-39.3 64.1 82c2.7 3.5 7.7 4.1 11.2 1.4l37.9-29.6c3.5-2.7 4.1-7.7 1.4-11.2l-64.1-82.1zM608 112c-167.9 0-304 136.1-304 304 0 70.3 23.9 135 63.9 186.5L114.3 856.1a8.03 8.03 0 0 0 0 11.3l42.3 42.3c3.1 3.1 8.2 3.1 11.3 0l253.6-253.6C473 696.1 537.7 720 608 720c167.9 0 304-136.1 304-304S775.9 112 608 112zm161.2 465.2C726.2 620.3 668.9 644 608 644s-118.2-23.7-161.2-66.8C403.7 534.2 380 476.9 380 416s23.7-118.2 66.8-161.2c43-43.1 100.3-66.8 161.2-66.8s118.2 23.7 161.2 66.8c43.1 43 66.8 100.3 66.8 161.2s-23.7 118.2-66.8 161.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiMoreOutlined, "More Outlined"))]
 pub static AiMoreOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiMoreOutlined",
+    label: "More Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8320,8 +11830,15 @@ pub static AiMoreOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M456 231a56 56 0 1 0 112 0 56 56 0 1 0-112 0zm0 280a56 56 0 1 0 112 0 56 56 0 1 0-112 0zm0 280a56 56 0 1 0 112 0 56 56 0 1 0-112 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiNodeCollapseOutlined, "Node Collapse Outlined"))]
 pub static AiNodeCollapseOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiNodeCollapseOutlined",
+    label: "Node Collapse Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8339,8 +11856,15 @@ pub static AiNodeCollapseOutlined: &icondata_core::IconData = &icondata_core::Ic
 <path d="M952 612c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H298c-14.2-35.2-48.7-60-89-60-53 0-96 43-96 96s43 96 96 96c40.3 0 74.8-24.8 89-60h150.3v152c0 55.2 44.8 100 100 100H952c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H548.3c-15.5 0-28-12.5-28-28V612H952zM451.7 313.7l172.5 136.2c6.3 5.1 15.8 0.5 15.8-7.7V344h264c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8H640v-98.2c0-8.1-9.4-12.8-15.8-7.7L451.7 298.3c-4.9 3.9-4.9 11.5 0 15.4z" p-id="9842" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiNodeExpandOutlined, "Node Expand Outlined"))]
 pub static AiNodeExpandOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiNodeExpandOutlined",
+    label: "Node Expand Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8358,8 +11882,15 @@ pub static AiNodeExpandOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M952 612c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H298c-14.2-35.2-48.7-60-89-60-53 0-96 43-96 96s43 96 96 96c40.3 0 74.8-24.8 89-60h150.3v152c0 55.2 44.8 100 100 100H952c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H548.3c-15.5 0-28-12.5-28-28V612H952zM456 344h264v98.2c0 8.1 9.5 12.8 15.8 7.7l172.5-136.2c5-3.9 5-11.4 0-15.3L735.8 162.1c-6.4-5.1-15.8-0.5-15.8 7.7V268H456c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8z" p-id="9960" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiNodeIndexOutlined, "Node Index Outlined"))]
 pub static AiNodeIndexOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiNodeIndexOutlined",
+    label: "Node Index Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8377,8 +11908,15 @@ pub static AiNodeIndexOutlined: &icondata_core::IconData = &icondata_core::IconD
 <path d="M843.5 737.4c-12.4-75.2-79.2-129.1-155.3-125.4S550.9 676 546 752c-153.5-4.8-208-40.7-199.1-113.7 3.3-27.3 19.8-41.9 50.1-49 18.4-4.3 38.8-4.9 57.3-3.2 1.7 0.2 3.5 0.3 5.2 0.5 11.3 2.7 22.8 5 34.3 6.8 34.1 5.6 68.8 8.4 101.8 6.6 92.8-5 156-45.9 159.2-132.7 3.1-84.1-54.7-143.7-147.9-183.6-29.9-12.8-61.6-22.7-93.3-30.2-14.3-3.4-26.3-5.7-35.2-7.2-7.9-75.9-71.5-133.8-147.8-134.4-76.3-0.6-140.9 56.1-150.1 131.9s40 146.3 114.2 163.9c74.2 17.6 149.9-23.3 175.7-95.1 9.4 1.7 18.7 3.6 28 5.8 28.2 6.6 56.4 15.4 82.4 26.6 70.7 30.2 109.3 70.1 107.5 119.9-1.6 44.6-33.6 65.2-96.2 68.6-27.5 1.5-57.6-0.9-87.3-5.8-8.3-1.4-15.9-2.8-22.6-4.3-3.9-0.8-6.6-1.5-7.8-1.8l-3.1-0.6c-2.2-0.3-5.9-0.8-10.7-1.3-25-2.3-52.1-1.5-78.5 4.6-55.2 12.9-93.9 47.2-101.1 105.8-15.7 126.2 78.6 184.7 276 188.9 29.1 70.4 106.4 107.9 179.6 87 73.3-20.9 119.3-93.4 106.9-168.6zM329.1 345.2c-46 0-83.3-37.3-83.3-83.3s37.3-83.3 83.3-83.3 83.3 37.3 83.3 83.3-37.3 83.3-83.3 83.3zM695.6 845c-46 0-83.3-37.3-83.3-83.3s37.3-83.3 83.3-83.3 83.3 37.3 83.3 83.3-37.3 83.3-83.3 83.3z" p-id="12712" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiNotificationFilled, "Notification Filled"))]
 pub static AiNotificationFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiNotificationFilled",
+    label: "Notification Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8393,8 +11931,15 @@ pub static AiNotificationFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M880 112c-3.8 0-7.7.7-11.6 2.3L292 345.9H128c-8.8 0-16 7.4-16 16.6v299c0 9.2 7.2 16.6 16 16.6h101.6c-3.7 11.6-5.6 23.9-5.6 36.4 0 65.9 53.8 119.5 120 119.5 55.4 0 102.1-37.6 115.9-88.4l408.6 164.2c3.9 1.5 7.8 2.3 11.6 2.3 16.9 0 32-14.2 32-33.2V145.2C912 126.2 897 112 880 112zM344 762.3c-26.5 0-48-21.4-48-47.8 0-11.2 3.9-21.9 11-30.4l84.9 34.1c-2 24.6-22.7 44.1-47.9 44.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiNotificationOutlined, "Notification Outlined"))]
 pub static AiNotificationOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiNotificationOutlined",
+    label: "Notification Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8409,8 +11954,15 @@ pub static AiNotificationOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M880 112c-3.8 0-7.7.7-11.6 2.3L292 345.9H128c-8.8 0-16 7.4-16 16.6v299c0 9.2 7.2 16.6 16 16.6h101.7c-3.7 11.6-5.7 23.9-5.7 36.4 0 65.9 53.8 119.5 120 119.5 55.4 0 102.1-37.6 115.9-88.4l408.6 164.2c3.9 1.5 7.8 2.3 11.6 2.3 16.9 0 32-14.2 32-33.2V145.2C912 126.2 897 112 880 112zM344 762.3c-26.5 0-48-21.4-48-47.8 0-11.2 3.9-21.9 11-30.4l84.9 34.1c-2 24.6-22.7 44.1-47.9 44.1zm496 58.4L318.8 611.3l-12.9-5.2H184V417.9h121.9l12.9-5.2L840 203.3v617.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiNotificationTwotone, "Notification Twotone"))]
 pub static AiNotificationTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiNotificationTwotone",
+    label: "Notification Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8426,8 +11978,15 @@ pub static AiNotificationTwotone: &icondata_core::IconData = &icondata_core::Ico
 <path d="M880 112c-3.8 0-7.7.7-11.6 2.3L292 345.9H128c-8.8 0-16 7.4-16 16.6v299c0 9.2 7.2 16.6 16 16.6h101.7c-3.7 11.6-5.7 23.9-5.7 36.4 0 65.9 53.8 119.5 120 119.5 55.4 0 102.1-37.6 115.9-88.4l408.6 164.2c3.9 1.5 7.8 2.3 11.6 2.3 16.9 0 32-14.2 32-33.2V145.2C912 126.2 897 112 880 112zM344 762.3c-26.5 0-48-21.4-48-47.8 0-11.2 3.9-21.9 11-30.4l84.9 34.1c-2 24.6-22.7 44.1-47.9 44.1zm496 58.4L318.8 611.3l-12.9-5.2H184V417.9h121.9l12.9-5.2L840 203.3v617.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiNumberOutlined, "Number Outlined"))]
 pub static AiNumberOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiNumberOutlined",
+    label: "Number Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8442,8 +12001,15 @@ pub static AiNumberOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M872 394c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8H708V152c0-4.4-3.6-8-8-8h-64c-4.4 0-8 3.6-8 8v166H400V152c0-4.4-3.6-8-8-8h-64c-4.4 0-8 3.6-8 8v166H152c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h168v236H152c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h168v166c0 4.4 3.6 8 8 8h64c4.4 0 8-3.6 8-8V706h228v166c0 4.4 3.6 8 8 8h64c4.4 0 8-3.6 8-8V706h164c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8H708V394h164zM628 630H400V394h228v236z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiOneToOneOutlined, "One To One Outlined"))]
 pub static AiOneToOneOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiOneToOneOutlined",
+    label: "One To One Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8463,8 +12029,15 @@ pub static AiOneToOneOutlined: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M648 672h60c4.4 0 8-3.6 8-8V360c0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8v304c0 4.4 3.6 8 8 8z" p-id="13188" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiOrderedListOutlined, "Ordered List Outlined"))]
 pub static AiOrderedListOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiOrderedListOutlined",
+    label: "Ordered List Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8479,8 +12052,15 @@ pub static AiOrderedListOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M920 760H336c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h584c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-568H336c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h584c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 284H336c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h584c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM216 712H100c-2.2 0-4 1.8-4 4v34c0 2.2 1.8 4 4 4h72.4v20.5h-35.7c-2.2 0-4 1.8-4 4v34c0 2.2 1.8 4 4 4h35.7V838H100c-2.2 0-4 1.8-4 4v34c0 2.2 1.8 4 4 4h116c2.2 0 4-1.8 4-4V716c0-2.2-1.8-4-4-4zM100 188h38v120c0 2.2 1.8 4 4 4h40c2.2 0 4-1.8 4-4V152c0-4.4-3.6-8-8-8h-78c-2.2 0-4 1.8-4 4v36c0 2.2 1.8 4 4 4zm116 240H100c-2.2 0-4 1.8-4 4v36c0 2.2 1.8 4 4 4h68.4l-70.3 77.7a8.3 8.3 0 0 0-2.1 5.4V592c0 2.2 1.8 4 4 4h116c2.2 0 4-1.8 4-4v-36c0-2.2-1.8-4-4-4h-68.4l70.3-77.7a8.3 8.3 0 0 0 2.1-5.4V432c0-2.2-1.8-4-4-4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPaperClipOutlined, "Paper Clip Outlined"))]
 pub static AiPaperClipOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPaperClipOutlined",
+    label: "Paper Clip Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8495,8 +12075,15 @@ pub static AiPaperClipOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M779.3 196.6c-94.2-94.2-247.6-94.2-341.7 0l-261 260.8c-1.7 1.7-2.6 4-2.6 6.4s.9 4.7 2.6 6.4l36.9 36.9a9 9 0 0 0 12.7 0l261-260.8c32.4-32.4 75.5-50.2 121.3-50.2s88.9 17.8 121.2 50.2c32.4 32.4 50.2 75.5 50.2 121.2 0 45.8-17.8 88.8-50.2 121.2l-266 265.9-43.1 43.1c-40.3 40.3-105.8 40.3-146.1 0-19.5-19.5-30.2-45.4-30.2-73s10.7-53.5 30.2-73l263.9-263.8c6.7-6.6 15.5-10.3 24.9-10.3h.1c9.4 0 18.1 3.7 24.7 10.3 6.7 6.7 10.3 15.5 10.3 24.9 0 9.3-3.7 18.1-10.3 24.7L372.4 653c-1.7 1.7-2.6 4-2.6 6.4s.9 4.7 2.6 6.4l36.9 36.9a9 9 0 0 0 12.7 0l215.6-215.6c19.9-19.9 30.8-46.3 30.8-74.4s-11-54.6-30.8-74.4c-41.1-41.1-107.9-41-149 0L463 364 224.8 602.1A172.22 172.22 0 0 0 174 724.8c0 46.3 18.1 89.8 50.8 122.5 33.9 33.8 78.3 50.7 122.7 50.7 44.4 0 88.8-16.9 122.6-50.7l309.2-309C824.8 492.7 850 432 850 367.5c.1-64.6-25.1-125.3-70.7-170.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPartitionOutlined, "Partition Outlined"))]
 pub static AiPartitionOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPartitionOutlined",
+    label: "Partition Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8514,8 +12101,15 @@ pub static AiPartitionOutlined: &icondata_core::IconData = &icondata_core::IconD
 <path d="M640.6 429.8h257.1c7.9 0 14.3-6.4 14.3-14.3V158.3c0-7.9-6.4-14.3-14.3-14.3H640.6c-7.9 0-14.3 6.4-14.3 14.3v92.9H490.6c-3.9 0-7.1 3.2-7.1 7.1v221.5h-85.7v-96.5c0-7.9-6.4-14.3-14.3-14.3H126.3c-7.9 0-14.3 6.4-14.3 14.3v257.2c0 7.9 6.4 14.3 14.3 14.3h257.1c7.9 0 14.3-6.4 14.3-14.3V544h85.7v221.5c0 3.9 3.2 7.1 7.1 7.1h135.7v92.9c0 7.9 6.4 14.3 14.3 14.3h257.1c7.9 0 14.3-6.4 14.3-14.3v-257c0-7.9-6.4-14.3-14.3-14.3h-257c-7.9 0-14.3 6.4-14.3 14.3v100h-78.6v-393h78.6v100c0 7.9 6.4 14.3 14.3 14.3z m53.5-217.9h150V362h-150V211.9zM329.9 587h-150V437h150v150z m364.2 75.1h150v150.1h-150V662.1z" p-id="12594" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPauseCircleFilled, "Pause Circle Filled"))]
 pub static AiPauseCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPauseCircleFilled",
+    label: "Pause Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8530,8 +12124,15 @@ pub static AiPauseCircleFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm-80 600c0 4.4-3.6 8-8 8h-48c-4.4 0-8-3.6-8-8V360c0-4.4 3.6-8 8-8h48c4.4 0 8 3.6 8 8v304zm224 0c0 4.4-3.6 8-8 8h-48c-4.4 0-8-3.6-8-8V360c0-4.4 3.6-8 8-8h48c4.4 0 8 3.6 8 8v304z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPauseCircleOutlined, "Pause Circle Outlined"))]
 pub static AiPauseCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPauseCircleOutlined",
+    label: "Pause Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8546,8 +12147,15 @@ pub static AiPauseCircleOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm-88-532h-48c-4.4 0-8 3.6-8 8v304c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V360c0-4.4-3.6-8-8-8zm224 0h-48c-4.4 0-8 3.6-8 8v304c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V360c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPauseCircleTwotone, "Pause Circle Twotone"))]
 pub static AiPauseCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPauseCircleTwotone",
+    label: "Pause Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8564,8 +12172,15 @@ pub static AiPauseCircleTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M424 352h-48c-4.4 0-8 3.6-8 8v304c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V360c0-4.4-3.6-8-8-8zm224 0h-48c-4.4 0-8 3.6-8 8v304c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V360c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPauseOutlined, "Pause Outlined"))]
 pub static AiPauseOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPauseOutlined",
+    label: "Pause Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8580,8 +12195,15 @@ pub static AiPauseOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M304 176h80v672h-80zm408 0h-64c-4.4 0-8 3.6-8 8v656c0 4.4 3.6 8 8 8h64c4.4 0 8-3.6 8-8V184c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPayCircleFilled, "Pay Circle Filled"))]
 pub static AiPayCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPayCircleFilled",
+    label: "Pay Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8596,8 +12218,15 @@ pub static AiPayCircleFilled: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm166.6 246.8L567.5 515.6h62c4.4 0 8 3.6 8 8v29.9c0 4.4-3.6 8-8 8h-82V603h82c4.4 0 8 3.6 8 8v29.9c0 4.4-3.6 8-8 8h-82V717c0 4.4-3.6 8-8 8h-54.3c-4.4 0-8-3.6-8-8v-68.1h-81.7c-4.4 0-8-3.6-8-8V611c0-4.4 3.6-8 8-8h81.7v-41.5h-81.7c-4.4 0-8-3.6-8-8v-29.9c0-4.4 3.6-8 8-8h61.4L345.4 310.8a8.07 8.07 0 0 1 7-11.9h60.7c3 0 5.8 1.7 7.1 4.4l90.6 180h3.4l90.6-180a8 8 0 0 1 7.1-4.4h59.5c4.4 0 8 3.6 8 8 .2 1.4-.2 2.7-.8 3.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPayCircleOutlined, "Pay Circle Outlined"))]
 pub static AiPayCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPayCircleOutlined",
+    label: "Pay Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8612,8 +12241,15 @@ pub static AiPayCircleOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm159.6-585h-59.5c-3 0-5.8 1.7-7.1 4.4l-90.6 180H511l-90.6-180a8 8 0 0 0-7.1-4.4h-60.7c-1.3 0-2.6.3-3.8 1-3.9 2.1-5.3 7-3.2 10.9L457 515.7h-61.4c-4.4 0-8 3.6-8 8v29.9c0 4.4 3.6 8 8 8h81.7V603h-81.7c-4.4 0-8 3.6-8 8v29.9c0 4.4 3.6 8 8 8h81.7V717c0 4.4 3.6 8 8 8h54.3c4.4 0 8-3.6 8-8v-68.1h82c4.4 0 8-3.6 8-8V611c0-4.4-3.6-8-8-8h-82v-41.5h82c4.4 0 8-3.6 8-8v-29.9c0-4.4-3.6-8-8-8h-62l111.1-204.8c.6-1.2 1-2.5 1-3.8-.1-4.4-3.7-8-8.1-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPercentageOutlined, "Percentage Outlined"))]
 pub static AiPercentageOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPercentageOutlined",
+    label: "Percentage Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8628,8 +12264,15 @@ pub static AiPercentageOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M855.7 210.8l-42.4-42.4a8.03 8.03 0 0 0-11.3 0L168.3 801.9a8.03 8.03 0 0 0 0 11.3l42.4 42.4c3.1 3.1 8.2 3.1 11.3 0L855.6 222c3.2-3 3.2-8.1.1-11.2zM304 448c79.4 0 144-64.6 144-144s-64.6-144-144-144-144 64.6-144 144 64.6 144 144 144zm0-216c39.7 0 72 32.3 72 72s-32.3 72-72 72-72-32.3-72-72 32.3-72 72-72zm416 344c-79.4 0-144 64.6-144 144s64.6 144 144 144 144-64.6 144-144-64.6-144-144-144zm0 216c-39.7 0-72-32.3-72-72s32.3-72 72-72 72 32.3 72 72-32.3 72-72 72z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPhoneFilled, "Phone Filled"))]
 pub static AiPhoneFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPhoneFilled",
+    label: "Phone Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8644,8 +12287,15 @@ pub static AiPhoneFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M885.6 230.2L779.1 123.8a80.83 80.83 0 0 0-57.3-23.8c-21.7 0-42.1 8.5-57.4 23.8L549.8 238.4a80.83 80.83 0 0 0-23.8 57.3c0 21.7 8.5 42.1 23.8 57.4l83.8 83.8A393.82 393.82 0 0 1 553.1 553 395.34 395.34 0 0 1 437 633.8L353.2 550a80.83 80.83 0 0 0-57.3-23.8c-21.7 0-42.1 8.5-57.4 23.8L123.8 664.5a80.89 80.89 0 0 0-23.8 57.4c0 21.7 8.5 42.1 23.8 57.4l106.3 106.3c24.4 24.5 58.1 38.4 92.7 38.4 7.3 0 14.3-.6 21.2-1.8 134.8-22.2 268.5-93.9 376.4-201.7C828.2 612.8 899.8 479.2 922.3 344c6.8-41.3-6.9-83.8-36.7-113.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPhoneOutlined, "Phone Outlined"))]
 pub static AiPhoneOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPhoneOutlined",
+    label: "Phone Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8660,8 +12310,15 @@ pub static AiPhoneOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M877.1 238.7L770.6 132.3c-13-13-30.4-20.3-48.8-20.3s-35.8 7.2-48.8 20.3L558.3 246.8c-13 13-20.3 30.5-20.3 48.9 0 18.5 7.2 35.8 20.3 48.9l89.6 89.7a405.46 405.46 0 0 1-86.4 127.3c-36.7 36.9-79.6 66-127.2 86.6l-89.6-89.7c-13-13-30.4-20.3-48.8-20.3a68.2 68.2 0 0 0-48.8 20.3L132.3 673c-13 13-20.3 30.5-20.3 48.9 0 18.5 7.2 35.8 20.3 48.9l106.4 106.4c22.2 22.2 52.8 34.9 84.2 34.9 6.5 0 12.8-.5 19.2-1.6 132.4-21.8 263.8-92.3 369.9-198.3C818 606 888.4 474.6 910.4 342.1c6.3-37.6-6.3-76.3-33.3-103.4zm-37.6 91.5c-19.5 117.9-82.9 235.5-178.4 331s-213 158.9-330.9 178.4c-14.8 2.5-30-2.5-40.8-13.2L184.9 721.9 295.7 611l119.8 120 .9.9 21.6-8a481.29 481.29 0 0 0 285.7-285.8l8-21.6-120.8-120.7 110.8-110.9 104.5 104.5c10.8 10.8 15.8 26 13.3 40.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPhoneTwotone, "Phone Twotone"))]
 pub static AiPhoneTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPhoneTwotone",
+    label: "Phone Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8677,8 +12334,15 @@ pub static AiPhoneTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M877.1 238.7L770.6 132.3c-13-13-30.4-20.3-48.8-20.3s-35.8 7.2-48.8 20.3L558.3 246.8c-13 13-20.3 30.5-20.3 48.9 0 18.5 7.2 35.8 20.3 48.9l89.6 89.7a405.46 405.46 0 0 1-86.4 127.3c-36.7 36.9-79.6 66-127.2 86.6l-89.6-89.7c-13-13-30.4-20.3-48.8-20.3a68.2 68.2 0 0 0-48.8 20.3L132.3 673c-13 13-20.3 30.5-20.3 48.9 0 18.5 7.2 35.8 20.3 48.9l106.4 106.4c22.2 22.2 52.8 34.9 84.2 34.9 6.5 0 12.8-.5 19.2-1.6 132.4-21.8 263.8-92.3 369.9-198.3C818 606 888.4 474.6 910.4 342.1c6.3-37.6-6.3-76.3-33.3-103.4zm-37.6 91.5c-19.5 117.9-82.9 235.5-178.4 331s-213 158.9-330.9 178.4c-14.8 2.5-30-2.5-40.8-13.2L184.9 721.9 295.7 611l119.8 120 .9.9 21.6-8a481.29 481.29 0 0 0 285.7-285.8l8-21.6-120.8-120.7 110.8-110.9 104.5 104.5c10.8 10.8 15.8 26 13.3 40.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPicCenterOutlined, "Pic Center Outlined"))]
 pub static AiPicCenterOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPicCenterOutlined",
+    label: "Pic Center Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8693,8 +12357,15 @@ pub static AiPicCenterOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M952 792H72c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h880c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-632H72c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h880c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM848 660c8.8 0 16-7.2 16-16V380c0-8.8-7.2-16-16-16H176c-8.8 0-16 7.2-16 16v264c0 8.8 7.2 16 16 16h672zM232 436h560v152H232V436z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPicLeftOutlined, "Pic Left Outlined"))]
 pub static AiPicLeftOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPicLeftOutlined",
+    label: "Pic Left Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8709,8 +12380,15 @@ pub static AiPicLeftOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M952 792H72c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h880c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-632H72c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h880c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM608 660c8.8 0 16-7.2 16-16V380c0-8.8-7.2-16-16-16H96c-8.8 0-16 7.2-16 16v264c0 8.8 7.2 16 16 16h512zM152 436h400v152H152V436zm552 210c0 4.4 3.6 8 8 8h224c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H712c-4.4 0-8 3.6-8 8v56zm8-204h224c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H712c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPicRightOutlined, "Pic Right Outlined"))]
 pub static AiPicRightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPicRightOutlined",
+    label: "Pic Right Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8725,8 +12403,15 @@ pub static AiPicRightOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M952 792H72c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h880c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-632H72c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h880c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-24 500c8.8 0 16-7.2 16-16V380c0-8.8-7.2-16-16-16H416c-8.8 0-16 7.2-16 16v264c0 8.8 7.2 16 16 16h512zM472 436h400v152H472V436zM80 646c0 4.4 3.6 8 8 8h224c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H88c-4.4 0-8 3.6-8 8v56zm8-204h224c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H88c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPictureFilled, "Picture Filled"))]
 pub static AiPictureFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPictureFilled",
+    label: "Picture Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8741,8 +12426,15 @@ pub static AiPictureFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M928 160H96c-17.7 0-32 14.3-32 32v640c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V192c0-17.7-14.3-32-32-32zM338 304c35.3 0 64 28.7 64 64s-28.7 64-64 64-64-28.7-64-64 28.7-64 64-64zm513.9 437.1a8.11 8.11 0 0 1-5.2 1.9H177.2c-4.4 0-8-3.6-8-8 0-1.9.7-3.7 1.9-5.2l170.3-202c2.8-3.4 7.9-3.8 11.3-1 .3.3.7.6 1 1l99.4 118 158.1-187.5c2.8-3.4 7.9-3.8 11.3-1 .3.3.7.6 1 1l229.6 271.6c2.6 3.3 2.2 8.4-1.2 11.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPictureOutlined, "Picture Outlined"))]
 pub static AiPictureOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPictureOutlined",
+    label: "Picture Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8757,8 +12449,15 @@ pub static AiPictureOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M928 160H96c-17.7 0-32 14.3-32 32v640c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V192c0-17.7-14.3-32-32-32zm-40 632H136v-39.9l138.5-164.3 150.1 178L658.1 489 888 761.6V792zm0-129.8L664.2 396.8c-3.2-3.8-9-3.8-12.2 0L424.6 666.4l-144-170.7c-3.2-3.8-9-3.8-12.2 0L136 652.7V232h752v430.2zM304 456a88 88 0 1 0 0-176 88 88 0 0 0 0 176zm0-116c15.5 0 28 12.5 28 28s-12.5 28-28 28-28-12.5-28-28 12.5-28 28-28z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPictureTwotone, "Picture Twotone"))]
 pub static AiPictureTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPictureTwotone",
+    label: "Picture Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8777,8 +12476,15 @@ pub static AiPictureTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M304 456a88 88 0 1 0 0-176 88 88 0 0 0 0 176zm0-116c15.5 0 28 12.5 28 28s-12.5 28-28 28-28-12.5-28-28 12.5-28 28-28z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPieChartFilled, "Pie Chart Filled"))]
 pub static AiPieChartFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPieChartFilled",
+    label: "Pie Chart Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8793,8 +12499,15 @@ pub static AiPieChartFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M863.1 518.5H505.5V160.9c0-4.4-3.6-8-8-8h-26a398.57 398.57 0 0 0-282.5 117 397.47 397.47 0 0 0-85.6 127C82.6 446.2 72 498.5 72 552.5S82.6 658.7 103.4 708c20.1 47.5 48.9 90.3 85.6 127 36.7 36.7 79.4 65.5 127 85.6a396.64 396.64 0 0 0 155.6 31.5 398.57 398.57 0 0 0 282.5-117c36.7-36.7 65.5-79.4 85.6-127a396.64 396.64 0 0 0 31.5-155.6v-26c-.1-4.4-3.7-8-8.1-8zM951 463l-2.6-28.2c-8.5-92-49.3-178.8-115.1-244.3A398.5 398.5 0 0 0 588.4 75.6L560.1 73c-4.7-.4-8.7 3.2-8.7 7.9v383.7c0 4.4 3.6 8 8 8l383.6-1c4.7-.1 8.4-4 8-8.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPieChartOutlined, "Pie Chart Outlined"))]
 pub static AiPieChartOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPieChartOutlined",
+    label: "Pie Chart Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8809,8 +12522,15 @@ pub static AiPieChartOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M864 518H506V160c0-4.4-3.6-8-8-8h-26a398.46 398.46 0 0 0-282.8 117.1 398.19 398.19 0 0 0-85.7 127.1A397.61 397.61 0 0 0 72 552a398.46 398.46 0 0 0 117.1 282.8c36.7 36.7 79.5 65.6 127.1 85.7A397.61 397.61 0 0 0 472 952a398.46 398.46 0 0 0 282.8-117.1c36.7-36.7 65.6-79.5 85.7-127.1A397.61 397.61 0 0 0 872 552v-26c0-4.4-3.6-8-8-8zM705.7 787.8A331.59 331.59 0 0 1 470.4 884c-88.1-.4-170.9-34.9-233.2-97.2C174.5 724.1 140 640.7 140 552c0-88.7 34.5-172.1 97.2-234.8 54.6-54.6 124.9-87.9 200.8-95.5V586h364.3c-7.7 76.3-41.3 147-96.6 201.8zM952 462.4l-2.6-28.2c-8.5-92.1-49.4-179-115.2-244.6A399.4 399.4 0 0 0 589 74.6L560.7 72c-4.7-.4-8.7 3.2-8.7 7.9V464c0 4.4 3.6 8 8 8l384-1c4.7 0 8.4-4 8-8.6zm-332.2-58.2V147.6a332.24 332.24 0 0 1 166.4 89.8c45.7 45.6 77 103.6 90 166.1l-256.4.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPieChartTwotone, "Pie Chart Twotone"))]
 pub static AiPieChartTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPieChartTwotone",
+    label: "Pie Chart Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8829,8 +12549,15 @@ pub static AiPieChartTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M952 462.4l-2.6-28.2c-8.5-92.1-49.4-179-115.2-244.6A399.4 399.4 0 0 0 589 74.6L560.7 72c-3.4-.3-6.4 1.5-7.8 4.3a8.7 8.7 0 0 0-.9 3.6V464c0 4.4 3.6 8 8 8l384-1c1.2 0 2.3-.3 3.4-.7a8.1 8.1 0 0 0 4.6-7.9zm-332.2-58.2V147.6a332.24 332.24 0 0 1 166.4 89.8c45.7 45.6 77 103.6 90 166.1l-256.4.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPlayCircleFilled, "Play Circle Filled"))]
 pub static AiPlayCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPlayCircleFilled",
+    label: "Play Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8845,8 +12572,15 @@ pub static AiPlayCircleFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm144.1 454.9L437.7 677.8a8.02 8.02 0 0 1-12.7-6.5V353.7a8 8 0 0 1 12.7-6.5L656.1 506a7.9 7.9 0 0 1 0 12.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPlayCircleOutlined, "Play Circle Outlined"))]
 pub static AiPlayCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPlayCircleOutlined",
+    label: "Play Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8862,8 +12596,15 @@ pub static AiPlayCircleOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M719.4 499.1l-296.1-215A15.9 15.9 0 0 0 398 297v430c0 13.1 14.8 20.5 25.3 12.9l296.1-215a15.9 15.9 0 0 0 0-25.8zm-257.6 134V390.9L628.5 512 461.8 633.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPlayCircleTwotone, "Play Circle Twotone"))]
 pub static AiPlayCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPlayCircleTwotone",
+    label: "Play Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8880,8 +12621,15 @@ pub static AiPlayCircleTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M676.1 505.3L457.7 346.5A8 8 0 0 0 445 353v317.6a8.02 8.02 0 0 0 12.7 6.5l218.4-158.9a7.9 7.9 0 0 0 0-12.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPlaySquareFilled, "Play Square Filled"))]
 pub static AiPlaySquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPlaySquareFilled",
+    label: "Play Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8896,8 +12644,15 @@ pub static AiPlaySquareFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM641.7 520.8L442.3 677.6c-7.4 5.8-18.3.6-18.3-8.8V355.3c0-9.4 10.9-14.7 18.3-8.8l199.4 156.7a11.2 11.2 0 0 1 0 17.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPlaySquareOutlined, "Play Square Outlined"))]
 pub static AiPlaySquareOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPlaySquareOutlined",
+    label: "Play Square Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8913,8 +12668,15 @@ pub static AiPlaySquareOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPlaySquareTwotone, "Play Square Twotone"))]
 pub static AiPlaySquareTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPlaySquareTwotone",
+    label: "Play Square Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8931,8 +12693,15 @@ pub static AiPlaySquareTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M442.3 677.6l199.4-156.8a11.2 11.2 0 0 0 0-17.6L442.3 346.5c-7.4-5.9-18.3-.6-18.3 8.8v313.5c0 9.4 10.9 14.6 18.3 8.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPlusCircleFilled, "Plus Circle Filled"))]
 pub static AiPlusCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPlusCircleFilled",
+    label: "Plus Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8947,8 +12716,15 @@ pub static AiPlusCircleFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm192 472c0 4.4-3.6 8-8 8H544v152c0 4.4-3.6 8-8 8h-48c-4.4 0-8-3.6-8-8V544H328c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h152V328c0-4.4 3.6-8 8-8h48c4.4 0 8 3.6 8 8v152h152c4.4 0 8 3.6 8 8v48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPlusCircleOutlined, "Plus Circle Outlined"))]
 pub static AiPlusCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPlusCircleOutlined",
+    label: "Plus Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8964,8 +12740,15 @@ pub static AiPlusCircleOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPlusCircleTwotone, "Plus Circle Twotone"))]
 pub static AiPlusCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPlusCircleTwotone",
+    label: "Plus Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8982,8 +12765,15 @@ pub static AiPlusCircleTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M696 480H544V328c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v152H328c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h152v152c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V544h152c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPlusOutlined, "Plus Outlined"))]
 pub static AiPlusOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPlusOutlined",
+    label: "Plus Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8999,8 +12789,15 @@ pub static AiPlusOutlined: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M192 474h672q8 0 8 8v60q0 8-8 8H160q-8 0-8-8v-60q0-8 8-8Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPlusSquareFilled, "Plus Square Filled"))]
 pub static AiPlusSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPlusSquareFilled",
+    label: "Plus Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9015,8 +12812,15 @@ pub static AiPlusSquareFilled: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM704 536c0 4.4-3.6 8-8 8H544v152c0 4.4-3.6 8-8 8h-48c-4.4 0-8-3.6-8-8V544H328c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h152V328c0-4.4 3.6-8 8-8h48c4.4 0 8 3.6 8 8v152h152c4.4 0 8 3.6 8 8v48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPlusSquareOutlined, "Plus Square Outlined"))]
 pub static AiPlusSquareOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPlusSquareOutlined",
+    label: "Plus Square Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9032,8 +12836,15 @@ pub static AiPlusSquareOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPlusSquareTwotone, "Plus Square Twotone"))]
 pub static AiPlusSquareTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPlusSquareTwotone",
+    label: "Plus Square Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9050,8 +12861,15 @@ pub static AiPlusSquareTwotone: &icondata_core::IconData = &icondata_core::IconD
 <path d="M328 544h152v152c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V544h152c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H544V328c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v152H328c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPoundCircleFilled, "Pound Circle Filled"))]
 pub static AiPoundCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPoundCircleFilled",
+    label: "Pound Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9066,8 +12884,15 @@ pub static AiPoundCircleFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm146 658c0 4.4-3.6 8-8 8H376.2c-4.4 0-8-3.6-8-8v-38.5c0-3.7 2.5-6.9 6.1-7.8 44-10.9 72.8-49 72.8-94.2 0-14.7-2.5-29.4-5.9-44.2H374c-4.4 0-8-3.6-8-8v-30c0-4.4 3.6-8 8-8h53.7c-7.8-25.1-14.6-50.7-14.6-77.1 0-75.8 58.6-120.3 151.5-120.3 26.5 0 51.4 5.5 70.3 12.7 3.1 1.2 5.2 4.2 5.2 7.5v39.5a8 8 0 0 1-10.6 7.6c-17.9-6.4-39-10.5-60.4-10.5-53.3 0-87.3 26.6-87.3 70.2 0 24.7 6.2 47.9 13.4 70.5h112c4.4 0 8 3.6 8 8v30c0 4.4-3.6 8-8 8h-98.6c3.1 13.2 5.3 26.9 5.3 41 0 40.7-16.5 73.9-43.9 91.1v4.7h180c4.4 0 8 3.6 8 8V722z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPoundCircleOutlined, "Pound Circle Outlined"))]
 pub static AiPoundCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPoundCircleOutlined",
+    label: "Pound Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9082,8 +12907,15 @@ pub static AiPoundCircleOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm138-209.8H469.8v-4.7c27.4-17.2 43.9-50.4 43.9-91.1 0-14.1-2.2-27.9-5.3-41H607c4.4 0 8-3.6 8-8v-30c0-4.4-3.6-8-8-8H495c-7.2-22.6-13.4-45.7-13.4-70.5 0-43.5 34-70.2 87.3-70.2 21.5 0 42.5 4.1 60.4 10.5 5.2 1.9 10.6-2 10.6-7.6v-39.5c0-3.3-2.1-6.3-5.2-7.5-18.8-7.2-43.8-12.7-70.3-12.7-92.9 0-151.5 44.5-151.5 120.3 0 26.3 6.9 52 14.6 77.1H374c-4.4 0-8 3.6-8 8v30c0 4.4 3.6 8 8 8h67.1c3.4 14.7 5.9 29.4 5.9 44.2 0 45.2-28.8 83.3-72.8 94.2-3.6.9-6.1 4.1-6.1 7.8V722c0 4.4 3.6 8 8 8H650c4.4 0 8-3.6 8-8v-39.8c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPoundCircleTwotone, "Pound Circle Twotone"))]
 pub static AiPoundCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPoundCircleTwotone",
+    label: "Pound Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9100,8 +12932,15 @@ pub static AiPoundCircleTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M650 674.3H470v-4.7c27.4-17.2 43.9-50.4 43.9-91.1 0-14.1-2.2-27.8-5.3-41h98.6c4.4 0 8-3.6 8-8v-30c0-4.4-3.6-8-8-8h-112c-7.2-22.6-13.4-45.8-13.4-70.5 0-43.6 34-70.2 87.3-70.2 21.4 0 42.5 4.1 60.4 10.5a8 8 0 0 0 10.6-7.6v-39.5c0-3.3-2.1-6.3-5.2-7.5-18.9-7.2-43.8-12.7-70.3-12.7-92.9 0-151.5 44.5-151.5 120.3 0 26.4 6.8 52 14.6 77.1H374c-4.4 0-8 3.6-8 8v30c0 4.4 3.6 8 8 8h67.2c3.4 14.8 5.9 29.5 5.9 44.2 0 45.2-28.8 83.3-72.8 94.2-3.6.9-6.1 4.1-6.1 7.8v38.5c0 4.4 3.6 8 8 8H650c4.4 0 8-3.6 8-8v-39.8c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPoundOutlined, "Pound Outlined"))]
 pub static AiPoundOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPoundOutlined",
+    label: "Pound Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9116,8 +12955,15 @@ pub static AiPoundOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm138-209.8H469.8v-4.7c27.4-17.2 43.9-50.4 43.9-91.1 0-14.1-2.2-27.9-5.3-41H607c4.4 0 8-3.6 8-8v-30c0-4.4-3.6-8-8-8H495c-7.2-22.6-13.4-45.7-13.4-70.5 0-43.5 34-70.2 87.3-70.2 21.5 0 42.5 4.1 60.4 10.5 5.2 1.9 10.6-2 10.6-7.6v-39.5c0-3.3-2.1-6.3-5.2-7.5-18.8-7.2-43.8-12.7-70.3-12.7-92.9 0-151.5 44.5-151.5 120.3 0 26.3 6.9 52 14.6 77.1H374c-4.4 0-8 3.6-8 8v30c0 4.4 3.6 8 8 8h67.1c3.4 14.7 5.9 29.4 5.9 44.2 0 45.2-28.8 83.3-72.8 94.2-3.6.9-6.1 4.1-6.1 7.8V722c0 4.4 3.6 8 8 8H650c4.4 0 8-3.6 8-8v-39.8c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPoweroffOutlined, "Poweroff Outlined"))]
 pub static AiPoweroffOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPoweroffOutlined",
+    label: "Poweroff Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9132,8 +12978,15 @@ pub static AiPoweroffOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M705.6 124.9a8 8 0 0 0-11.6 7.2v64.2c0 5.5 2.9 10.6 7.5 13.6a352.2 352.2 0 0 1 62.2 49.8c32.7 32.8 58.4 70.9 76.3 113.3a355 355 0 0 1 27.9 138.7c0 48.1-9.4 94.8-27.9 138.7a355.92 355.92 0 0 1-76.3 113.3 353.06 353.06 0 0 1-113.2 76.4c-43.8 18.6-90.5 28-138.5 28s-94.7-9.4-138.5-28a353.06 353.06 0 0 1-113.2-76.4A355.92 355.92 0 0 1 184 650.4a355 355 0 0 1-27.9-138.7c0-48.1 9.4-94.8 27.9-138.7 17.9-42.4 43.6-80.5 76.3-113.3 19-19 39.8-35.6 62.2-49.8 4.7-2.9 7.5-8.1 7.5-13.6V132c0-6-6.3-9.8-11.6-7.2C178.5 195.2 82 339.3 80 506.3 77.2 745.1 272.5 943.5 511.2 944c239 .5 432.8-193.3 432.8-432.4 0-169.2-97-315.7-238.4-386.7zM480 560h64c4.4 0 8-3.6 8-8V88c0-4.4-3.6-8-8-8h-64c-4.4 0-8 3.6-8 8v464c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPrinterFilled, "Printer Filled"))]
 pub static AiPrinterFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPrinterFilled",
+    label: "Printer Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9148,8 +13001,15 @@ pub static AiPrinterFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M732 120c0-4.4-3.6-8-8-8H300c-4.4 0-8 3.6-8 8v148h440V120zm120 212H172c-44.2 0-80 35.8-80 80v328c0 17.7 14.3 32 32 32h168v132c0 4.4 3.6 8 8 8h424c4.4 0 8-3.6 8-8V772h168c17.7 0 32-14.3 32-32V412c0-44.2-35.8-80-80-80zM664 844H360V568h304v276zm164-360c0 4.4-3.6 8-8 8h-40c-4.4 0-8-3.6-8-8v-40c0-4.4 3.6-8 8-8h40c4.4 0 8 3.6 8 8v40z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPrinterOutlined, "Printer Outlined"))]
 pub static AiPrinterOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPrinterOutlined",
+    label: "Printer Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9164,8 +13024,15 @@ pub static AiPrinterOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M820 436h-40c-4.4 0-8 3.6-8 8v40c0 4.4 3.6 8 8 8h40c4.4 0 8-3.6 8-8v-40c0-4.4-3.6-8-8-8zm32-104H732V120c0-4.4-3.6-8-8-8H300c-4.4 0-8 3.6-8 8v212H172c-44.2 0-80 35.8-80 80v328c0 17.7 14.3 32 32 32h168v132c0 4.4 3.6 8 8 8h424c4.4 0 8-3.6 8-8V772h168c17.7 0 32-14.3 32-32V412c0-44.2-35.8-80-80-80zM360 180h304v152H360V180zm304 664H360V568h304v276zm200-140H732V500H292v204H160V412c0-6.6 5.4-12 12-12h680c6.6 0 12 5.4 12 12v292z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPrinterTwotone, "Printer Twotone"))]
 pub static AiPrinterTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPrinterTwotone",
+    label: "Printer Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9182,8 +13049,15 @@ pub static AiPrinterTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M820 436h-40c-4.4 0-8 3.6-8 8v40c0 4.4 3.6 8 8 8h40c4.4 0 8-3.6 8-8v-40c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiProfileFilled, "Profile Filled"))]
 pub static AiProfileFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiProfileFilled",
+    label: "Profile Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9198,8 +13072,15 @@ pub static AiProfileFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM380 696c-22.1 0-40-17.9-40-40s17.9-40 40-40 40 17.9 40 40-17.9 40-40 40zm0-144c-22.1 0-40-17.9-40-40s17.9-40 40-40 40 17.9 40 40-17.9 40-40 40zm0-144c-22.1 0-40-17.9-40-40s17.9-40 40-40 40 17.9 40 40-17.9 40-40 40zm304 272c0 4.4-3.6 8-8 8H492c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h184c4.4 0 8 3.6 8 8v48zm0-144c0 4.4-3.6 8-8 8H492c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h184c4.4 0 8 3.6 8 8v48zm0-144c0 4.4-3.6 8-8 8H492c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h184c4.4 0 8 3.6 8 8v48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiProfileOutlined, "Profile Outlined"))]
 pub static AiProfileOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiProfileOutlined",
+    label: "Profile Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9214,8 +13095,15 @@ pub static AiProfileOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656zM492 400h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H492c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8zm0 144h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H492c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8zm0 144h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H492c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8zM340 368a40 40 0 1 0 80 0 40 40 0 1 0-80 0zm0 144a40 40 0 1 0 80 0 40 40 0 1 0-80 0zm0 144a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiProfileTwotone, "Profile Twotone"))]
 pub static AiProfileTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiProfileTwotone",
+    label: "Profile Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9232,8 +13120,15 @@ pub static AiProfileTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M340 656a40 40 0 1 0 80 0 40 40 0 1 0-80 0zm0-144a40 40 0 1 0 80 0 40 40 0 1 0-80 0zm0-144a40 40 0 1 0 80 0 40 40 0 1 0-80 0zm152 320h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H492c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8zm0-144h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H492c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8zm0-144h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H492c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiProjectFilled, "Project Filled"))]
 pub static AiProjectFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiProjectFilled",
+    label: "Project Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9248,8 +13143,15 @@ pub static AiProjectFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM368 744c0 4.4-3.6 8-8 8h-80c-4.4 0-8-3.6-8-8V280c0-4.4 3.6-8 8-8h80c4.4 0 8 3.6 8 8v464zm192-280c0 4.4-3.6 8-8 8h-80c-4.4 0-8-3.6-8-8V280c0-4.4 3.6-8 8-8h80c4.4 0 8 3.6 8 8v184zm192 72c0 4.4-3.6 8-8 8h-80c-4.4 0-8-3.6-8-8V280c0-4.4 3.6-8 8-8h80c4.4 0 8 3.6 8 8v256z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiProjectOutlined, "Project Outlined"))]
 pub static AiProjectOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiProjectOutlined",
+    label: "Project Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9264,8 +13166,15 @@ pub static AiProjectOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M280 752h80c4.4 0 8-3.6 8-8V280c0-4.4-3.6-8-8-8h-80c-4.4 0-8 3.6-8 8v464c0 4.4 3.6 8 8 8zm192-280h80c4.4 0 8-3.6 8-8V280c0-4.4-3.6-8-8-8h-80c-4.4 0-8 3.6-8 8v184c0 4.4 3.6 8 8 8zm192 72h80c4.4 0 8-3.6 8-8V280c0-4.4-3.6-8-8-8h-80c-4.4 0-8 3.6-8 8v256c0 4.4 3.6 8 8 8zm216-432H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiProjectTwotone, "Project Twotone"))]
 pub static AiProjectTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiProjectTwotone",
+    label: "Project Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9282,8 +13191,15 @@ pub static AiProjectTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M280 752h80c4.4 0 8-3.6 8-8V280c0-4.4-3.6-8-8-8h-80c-4.4 0-8 3.6-8 8v464c0 4.4 3.6 8 8 8zm192-280h80c4.4 0 8-3.6 8-8V280c0-4.4-3.6-8-8-8h-80c-4.4 0-8 3.6-8 8v184c0 4.4 3.6 8 8 8zm192 72h80c4.4 0 8-3.6 8-8V280c0-4.4-3.6-8-8-8h-80c-4.4 0-8 3.6-8 8v256c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPropertySafetyFilled, "Property Safety Filled"))]
 pub static AiPropertySafetyFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPropertySafetyFilled",
+    label: "Property Safety Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9298,8 +13214,15 @@ pub static AiPropertySafetyFilled: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M866.9 169.9L527.1 54.1C523 52.7 517.5 52 512 52s-11 .7-15.1 2.1L157.1 169.9c-8.3 2.8-15.1 12.4-15.1 21.2v482.4c0 8.8 5.7 20.4 12.6 25.9L499.3 968c3.5 2.7 8 4.1 12.6 4.1s9.2-1.4 12.6-4.1l344.7-268.6c6.9-5.4 12.6-17 12.6-25.9V191.1c.2-8.8-6.6-18.3-14.9-21.2zM648.3 332.8l-87.7 161.1h45.7c5.5 0 10 4.5 10 10v21.3c0 5.5-4.5 10-10 10h-63.4v29.7h63.4c5.5 0 10 4.5 10 10v21.3c0 5.5-4.5 10-10 10h-63.4V658c0 5.5-4.5 10-10 10h-41.3c-5.5 0-10-4.5-10-10v-51.8h-63.1c-5.5 0-10-4.5-10-10v-21.3c0-5.5 4.5-10 10-10h63.1v-29.7h-63.1c-5.5 0-10-4.5-10-10v-21.3c0-5.5 4.5-10 10-10h45.2l-88-161.1c-2.6-4.8-.9-10.9 4-13.6 1.5-.8 3.1-1.2 4.8-1.2h46c3.8 0 7.2 2.1 8.9 5.5l72.9 144.3 73.2-144.3a10 10 0 0 1 8.9-5.5h45c5.5 0 10 4.5 10 10 .1 1.7-.3 3.3-1.1 4.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPropertySafetyOutlined, "Property Safety Outlined"))]
 pub static AiPropertySafetyOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPropertySafetyOutlined",
+    label: "Property Safety Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9314,8 +13237,15 @@ pub static AiPropertySafetyOutlined: &icondata_core::IconData = &icondata_core::
     data: r###"<path d="M866.9 169.9L527.1 54.1C523 52.7 517.5 52 512 52s-11 .7-15.1 2.1L157.1 169.9c-8.3 2.8-15.1 12.4-15.1 21.2v482.4c0 8.8 5.7 20.4 12.6 25.9L499.3 968c3.5 2.7 8 4.1 12.6 4.1s9.2-1.4 12.6-4.1l344.7-268.6c6.9-5.4 12.6-17 12.6-25.9V191.1c.2-8.8-6.6-18.3-14.9-21.2zM810 654.3L512 886.5 214 654.3V226.7l298-101.6 298 101.6v427.6zM430.5 318h-46c-1.7 0-3.3.4-4.8 1.2a10.1 10.1 0 0 0-4 13.6l88 161.1h-45.2c-5.5 0-10 4.5-10 10v21.3c0 5.5 4.5 10 10 10h63.1v29.7h-63.1c-5.5 0-10 4.5-10 10v21.3c0 5.5 4.5 10 10 10h63.1V658c0 5.5 4.5 10 10 10h41.3c5.5 0 10-4.5 10-10v-51.8h63.4c5.5 0 10-4.5 10-10v-21.3c0-5.5-4.5-10-10-10h-63.4v-29.7h63.4c5.5 0 10-4.5 10-10v-21.3c0-5.5-4.5-10-10-10h-45.7l87.7-161.1a10.05 10.05 0 0 0-8.8-14.8h-45c-3.8 0-7.2 2.1-8.9 5.5l-73.2 144.3-72.9-144.3c-1.7-3.4-5.2-5.5-9-5.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPropertySafetyTwotone, "Property Safety Twotone"))]
 pub static AiPropertySafetyTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPropertySafetyTwotone",
+    label: "Property Safety Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9332,8 +13262,15 @@ pub static AiPropertySafetyTwotone: &icondata_core::IconData = &icondata_core::I
 <path d="M438.9 323.5a9.88 9.88 0 0 0-8.9-5.5h-46c-1.7 0-3.3.4-4.8 1.2-4.9 2.7-6.6 8.8-4 13.6l88 161.1H418c-5.5 0-10 4.5-10 10v21.3c0 5.5 4.5 10 10 10h63.1v29.7H418c-5.5 0-10 4.5-10 10v21.3c0 5.5 4.5 10 10 10h63.1V658c0 5.5 4.5 10 10 10h41.3c5.5 0 10-4.5 10-10v-51.8h63.4c5.5 0 10-4.5 10-10v-21.3c0-5.5-4.5-10-10-10h-63.4v-29.7h63.4c5.5 0 10-4.5 10-10v-21.3c0-5.5-4.5-10-10-10h-45.7l87.7-161.1c.8-1.5 1.2-3.1 1.1-4.8 0-5.5-4.5-10-10-10h-45a10 10 0 0 0-8.9 5.5l-73.2 144.3-72.9-144.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPullRequestOutlined, "Pull Request Outlined"))]
 pub static AiPullRequestOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPullRequestOutlined",
+    label: "Pull Request Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9348,8 +13285,15 @@ pub static AiPullRequestOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M788 705.9V192c0-8.8-7.2-16-16-16H602v-68.8c0-6-7-9.4-11.7-5.7L462.7 202.3a7.14 7.14 0 0 0 0 11.3l127.5 100.8c4.7 3.7 11.7.4 11.7-5.7V240h114v465.9c-44.2 15-76 56.9-76 106.1 0 61.8 50.2 112 112 112s112-50.2 112-112c.1-49.2-31.7-91-75.9-106.1zM752 860a48.01 48.01 0 0 1 0-96 48.01 48.01 0 0 1 0 96zM384 212c0-61.8-50.2-112-112-112s-112 50.2-112 112c0 49.2 31.8 91 76 106.1V706c-44.2 15-76 56.9-76 106.1 0 61.8 50.2 112 112 112s112-50.2 112-112c0-49.2-31.8-91-76-106.1V318.1c44.2-15.1 76-56.9 76-106.1zm-160 0a48.01 48.01 0 0 1 96 0 48.01 48.01 0 0 1-96 0zm96 600a48.01 48.01 0 0 1-96 0 48.01 48.01 0 0 1 96 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPushpinFilled, "Pushpin Filled"))]
 pub static AiPushpinFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPushpinFilled",
+    label: "Pushpin Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9364,8 +13308,15 @@ pub static AiPushpinFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M878.3 392.1L631.9 145.7c-6.5-6.5-15-9.7-23.5-9.7s-17 3.2-23.5 9.7L423.8 306.9c-12.2-1.4-24.5-2-36.8-2-73.2 0-146.4 24.1-206.5 72.3-15.4 12.3-16.6 35.4-2.7 49.4l181.7 181.7-215.4 215.2a15.8 15.8 0 0 0-4.6 9.8l-3.4 37.2c-.9 9.4 6.6 17.4 15.9 17.4.5 0 1 0 1.5-.1l37.2-3.4c3.7-.3 7.2-2 9.8-4.6l215.4-215.4 181.7 181.7c6.5 6.5 15 9.7 23.5 9.7 9.7 0 19.3-4.2 25.9-12.4 56.3-70.3 79.7-158.3 70.2-243.4l161.1-161.1c12.9-12.8 12.9-33.8 0-46.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPushpinOutlined, "Pushpin Outlined"))]
 pub static AiPushpinOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPushpinOutlined",
+    label: "Pushpin Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9380,8 +13331,15 @@ pub static AiPushpinOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M878.3 392.1L631.9 145.7c-6.5-6.5-15-9.7-23.5-9.7s-17 3.2-23.5 9.7L423.8 306.9c-12.2-1.4-24.5-2-36.8-2-73.2 0-146.4 24.1-206.5 72.3a33.23 33.23 0 0 0-2.7 49.4l181.7 181.7-215.4 215.2a15.8 15.8 0 0 0-4.6 9.8l-3.4 37.2c-.9 9.4 6.6 17.4 15.9 17.4.5 0 1 0 1.5-.1l37.2-3.4c3.7-.3 7.2-2 9.8-4.6l215.4-215.4 181.7 181.7c6.5 6.5 15 9.7 23.5 9.7 9.7 0 19.3-4.2 25.9-12.4 56.3-70.3 79.7-158.3 70.2-243.4l161.1-161.1c12.9-12.8 12.9-33.8 0-46.8zM666.2 549.3l-24.5 24.5 3.8 34.4a259.92 259.92 0 0 1-30.4 153.9L262 408.8c12.9-7.1 26.3-13.1 40.3-17.9 27.2-9.4 55.7-14.1 84.7-14.1 9.6 0 19.3.5 28.9 1.6l34.4 3.8 24.5-24.5L608.5 224 800 415.5 666.2 549.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiPushpinTwotone, "Pushpin Twotone"))]
 pub static AiPushpinTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiPushpinTwotone",
+    label: "Pushpin Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9397,8 +13355,15 @@ pub static AiPushpinTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M878.3 392.1L631.9 145.7c-6.5-6.5-15-9.7-23.5-9.7s-17 3.2-23.5 9.7L423.8 306.9c-12.2-1.4-24.5-2-36.8-2-73.2 0-146.4 24.1-206.5 72.3a33.23 33.23 0 0 0-2.7 49.4l181.7 181.7-215.4 215.2a15.8 15.8 0 0 0-4.6 9.8l-3.4 37.2c-.9 9.4 6.6 17.4 15.9 17.4.5 0 1 0 1.5-.1l37.2-3.4c3.7-.3 7.2-2 9.8-4.6l215.4-215.4 181.7 181.7c6.5 6.5 15 9.7 23.5 9.7 9.7 0 19.3-4.2 25.9-12.4 56.3-70.3 79.7-158.3 70.2-243.4l161.1-161.1c12.9-12.8 12.9-33.8 0-46.8zM666.2 549.3l-24.5 24.5 3.8 34.4a259.92 259.92 0 0 1-30.4 153.9L262 408.8c12.9-7.1 26.3-13.1 40.3-17.9 27.2-9.4 55.7-14.1 84.7-14.1 9.6 0 19.3.5 28.9 1.6l34.4 3.8 24.5-24.5L608.5 224 800 415.5 666.2 549.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiQqCircleFilled, "Qq Circle Filled"))]
 pub static AiQqCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiQqCircleFilled",
+    label: "Qq Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9413,8 +13378,15 @@ pub static AiQqCircleFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm210.5 612.4c-11.5 1.4-44.9-52.7-44.9-52.7 0 31.3-16.2 72.2-51.1 101.8 16.9 5.2 54.9 19.2 45.9 34.4-7.3 12.3-125.6 7.9-159.8 4-34.2 3.8-152.5 8.3-159.8-4-9.1-15.2 28.9-29.2 45.8-34.4-35-29.5-51.1-70.4-51.1-101.8 0 0-33.4 54.1-44.9 52.7-5.4-.7-12.4-29.6 9.4-99.7 10.3-33 22-60.5 40.2-105.8-3.1-116.9 45.3-215 160.4-215 113.9 0 163.3 96.1 160.4 215 18.1 45.2 29.9 72.8 40.2 105.8 21.7 70.1 14.6 99.1 9.3 99.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiQqOutlined, "Qq Outlined"))]
 pub static AiQqOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiQqOutlined",
+    label: "Qq Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9429,8 +13401,15 @@ pub static AiQqOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M824.8 613.2c-16-51.4-34.4-94.6-62.7-165.3C766.5 262.2 689.3 112 511.5 112 331.7 112 256.2 265.2 261 447.9c-28.4 70.8-46.7 113.7-62.7 165.3-34 109.5-23 154.8-14.6 155.8 18 2.2 70.1-82.4 70.1-82.4 0 49 25.2 112.9 79.8 159-26.4 8.1-85.7 29.9-71.6 53.8 11.4 19.3 196.2 12.3 249.5 6.3 53.3 6 238.1 13 249.5-6.3 14.1-23.8-45.3-45.7-71.6-53.8 54.6-46.2 79.8-110.1 79.8-159 0 0 52.1 84.6 70.1 82.4 8.5-1.1 19.5-46.4-14.5-155.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiQqSquareFilled, "Qq Square Filled"))]
 pub static AiQqSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiQqSquareFilled",
+    label: "Qq Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9445,8 +13424,15 @@ pub static AiQqSquareFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM722.5 676.4c-11.5 1.4-44.9-52.7-44.9-52.7 0 31.3-16.2 72.2-51.1 101.8 16.9 5.2 54.9 19.2 45.9 34.4-7.3 12.3-125.6 7.9-159.8 4-34.2 3.8-152.5 8.3-159.8-4-9.1-15.2 28.9-29.2 45.8-34.4-35-29.5-51.1-70.4-51.1-101.8 0 0-33.4 54.1-44.9 52.7-5.4-.7-12.4-29.6 9.4-99.7 10.3-33 22-60.5 40.2-105.8-3.1-116.9 45.3-215 160.4-215 113.9 0 163.3 96.1 160.4 215 18.1 45.2 29.9 72.8 40.2 105.8 21.7 70.1 14.6 99.1 9.3 99.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiQrcodeOutlined, "Qrcode Outlined"))]
 pub static AiQrcodeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiQrcodeOutlined",
+    label: "Qrcode Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9461,8 +13447,15 @@ pub static AiQrcodeOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M468 128H160c-17.7 0-32 14.3-32 32v308c0 4.4 3.6 8 8 8h332c4.4 0 8-3.6 8-8V136c0-4.4-3.6-8-8-8zm-56 284H192V192h220v220zm-138-74h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm194 210H136c-4.4 0-8 3.6-8 8v308c0 17.7 14.3 32 32 32h308c4.4 0 8-3.6 8-8V556c0-4.4-3.6-8-8-8zm-56 284H192V612h220v220zm-138-74h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm590-630H556c-4.4 0-8 3.6-8 8v332c0 4.4 3.6 8 8 8h332c4.4 0 8-3.6 8-8V160c0-17.7-14.3-32-32-32zm-32 284H612V192h220v220zm-138-74h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm194 210h-48c-4.4 0-8 3.6-8 8v134h-78V556c0-4.4-3.6-8-8-8H556c-4.4 0-8 3.6-8 8v332c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V644h78v102c0 4.4 3.6 8 8 8h190c4.4 0 8-3.6 8-8V556c0-4.4-3.6-8-8-8zM746 832h-48c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zm142 0h-48c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiQuestionCircleFilled, "Question Circle Filled"))]
 pub static AiQuestionCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiQuestionCircleFilled",
+    label: "Question Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9477,8 +13470,15 @@ pub static AiQuestionCircleFilled: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 708c-22.1 0-40-17.9-40-40s17.9-40 40-40 40 17.9 40 40-17.9 40-40 40zm62.9-219.5a48.3 48.3 0 0 0-30.9 44.8V620c0 4.4-3.6 8-8 8h-48c-4.4 0-8-3.6-8-8v-21.5c0-23.1 6.7-45.9 19.9-64.9 12.9-18.6 30.9-32.8 52.1-40.9 34-13.1 56-41.6 56-72.7 0-44.1-43.1-80-96-80s-96 35.9-96 80v7.6c0 4.4-3.6 8-8 8h-48c-4.4 0-8-3.6-8-8V420c0-39.3 17.2-76 48.4-103.3C430.4 290.4 470 276 512 276s81.6 14.5 111.6 40.7C654.8 344 672 380.7 672 420c0 57.8-38.1 109.8-97.1 132.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiQuestionCircleOutlined, "Question Circle Outlined"))]
 pub static AiQuestionCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiQuestionCircleOutlined",
+    label: "Question Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9494,8 +13494,15 @@ pub static AiQuestionCircleOutlined: &icondata_core::IconData = &icondata_core::
 <path d="M623.6 316.7C593.6 290.4 554 276 512 276s-81.6 14.5-111.6 40.7C369.2 344 352 380.7 352 420v7.6c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V420c0-44.1 43.1-80 96-80s96 35.9 96 80c0 31.1-22 59.6-56.1 72.7-21.2 8.1-39.2 22.3-52.1 40.9-13.1 19-19.9 41.8-19.9 64.9V620c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8v-22.7a48.3 48.3 0 0 1 30.9-44.8c59-22.7 97.1-74.7 97.1-132.5.1-39.3-17.1-76-48.3-103.3zM472 732a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiQuestionCircleTwotone, "Question Circle Twotone"))]
 pub static AiQuestionCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiQuestionCircleTwotone",
+    label: "Question Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9512,8 +13519,15 @@ pub static AiQuestionCircleTwotone: &icondata_core::IconData = &icondata_core::I
 <path d="M472 732a40 40 0 1 0 80 0 40 40 0 1 0-80 0zm151.6-415.3C593.6 290.5 554 276 512 276s-81.6 14.4-111.6 40.7C369.2 344 352 380.7 352 420v7.6c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V420c0-44.1 43.1-80 96-80s96 35.9 96 80c0 31.1-22 59.6-56 72.7-21.2 8.1-39.2 22.3-52.1 40.9-13.2 19-19.9 41.8-19.9 64.9V620c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8v-22.7a48.3 48.3 0 0 1 30.9-44.8c59-22.7 97.1-74.7 97.1-132.5 0-39.3-17.2-76-48.4-103.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiQuestionOutlined, "Question Outlined"))]
 pub static AiQuestionOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiQuestionOutlined",
+    label: "Question Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9528,8 +13542,15 @@ pub static AiQuestionOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M764 280.9c-14-30.6-33.9-58.1-59.3-81.6C653.1 151.4 584.6 125 512 125s-141.1 26.4-192.7 74.2c-25.4 23.6-45.3 51-59.3 81.7-14.6 32-22 65.9-22 100.9v27c0 6.2 5 11.2 11.2 11.2h54c6.2 0 11.2-5 11.2-11.2v-27c0-99.5 88.6-180.4 197.6-180.4s197.6 80.9 197.6 180.4c0 40.8-14.5 79.2-42 111.2-27.2 31.7-65.6 54.4-108.1 64-24.3 5.5-46.2 19.2-61.7 38.8a110.85 110.85 0 0 0-23.9 68.6v31.4c0 6.2 5 11.2 11.2 11.2h54c6.2 0 11.2-5 11.2-11.2v-31.4c0-15.7 10.9-29.5 26-32.9 58.4-13.2 111.4-44.7 149.3-88.7 19.1-22.3 34-47.1 44.3-74 10.7-27.9 16.1-57.2 16.1-87 0-35-7.4-69-22-100.9zM512 787c-30.9 0-56 25.1-56 56s25.1 56 56 56 56-25.1 56-56-25.1-56-56-56z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRadarChartOutlined, "Radar Chart Outlined"))]
 pub static AiRadarChartOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRadarChartOutlined",
+    label: "Radar Chart Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9544,8 +13565,15 @@ pub static AiRadarChartOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M926.8 397.1l-396-288a31.81 31.81 0 0 0-37.6 0l-396 288a31.99 31.99 0 0 0-11.6 35.8l151.3 466a32 32 0 0 0 30.4 22.1h489.5c13.9 0 26.1-8.9 30.4-22.1l151.3-466c4.2-13.2-.5-27.6-11.7-35.8zM838.6 417l-98.5 32-200-144.7V199.9L838.6 417zM466 567.2l-89.1 122.3-55.2-169.2L466 567.2zm-116.3-96.8L484 373.3v140.8l-134.3-43.7zM512 599.2l93.9 128.9H418.1L512 599.2zm28.1-225.9l134.2 97.1L540.1 514V373.3zM558 567.2l144.3-46.9-55.2 169.2L558 567.2zm-74-367.3v104.4L283.9 449l-98.5-32L484 199.9zM169.3 470.8l86.5 28.1 80.4 246.4-53.8 73.9-113.1-348.4zM327.1 853l50.3-69h269.3l50.3 69H327.1zm414.5-33.8l-53.8-73.9 80.4-246.4 86.5-28.1-113.1 348.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRadiusBottomleftOutlined, "Radius Bottomleft Outlined"))]
 pub static AiRadiusBottomleftOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRadiusBottomleftOutlined",
+    label: "Radius Bottomleft Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9560,8 +13588,15 @@ pub static AiRadiusBottomleftOutlined: &icondata_core::IconData = &icondata_core
     data: r###"<path d="M712 824h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm2-696h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM136 374h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm0-174h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm752 624h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-348 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-230 72h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm230 624H358c-87.3 0-158-70.7-158-158V484c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v182c0 127 103 230 230 230h182c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRadiusBottomrightOutlined, "Radius Bottomright Outlined"))]
 pub static AiRadiusBottomrightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRadiusBottomrightOutlined",
+    label: "Radius Bottomright Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9576,8 +13611,15 @@ pub static AiRadiusBottomrightOutlined: &icondata_core::IconData = &icondata_cor
     data: r###"<path d="M368 824h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-58-624h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm578 102h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM192 824h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0-174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm292 72h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm174 0h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm230 276h-56c-4.4 0-8 3.6-8 8v182c0 87.3-70.7 158-158 158H484c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h182c127 0 230-103 230-230V484c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRadiusSettingOutlined, "Radius Setting Outlined"))]
 pub static AiRadiusSettingOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRadiusSettingOutlined",
+    label: "Radius Setting Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9592,8 +13634,15 @@ pub static AiRadiusSettingOutlined: &icondata_core::IconData = &icondata_core::I
     data: r###"<path d="M396 140h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-44 684h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm524-204h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM192 344h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 160h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 160h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 160h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm320 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm160 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm140-284c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V370c0-127-103-230-230-230H484c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h170c87.3 0 158 70.7 158 158v170zM236 96H92c-4.4 0-8 3.6-8 8v144c0 4.4 3.6 8 8 8h144c4.4 0 8-3.6 8-8V104c0-4.4-3.6-8-8-8zm-48 101.6c0 1.3-1.1 2.4-2.4 2.4h-43.2c-1.3 0-2.4-1.1-2.4-2.4v-43.2c0-1.3 1.1-2.4 2.4-2.4h43.2c1.3 0 2.4 1.1 2.4 2.4v43.2zM920 780H776c-4.4 0-8 3.6-8 8v144c0 4.4 3.6 8 8 8h144c4.4 0 8-3.6 8-8V788c0-4.4-3.6-8-8-8zm-48 101.6c0 1.3-1.1 2.4-2.4 2.4h-43.2c-1.3 0-2.4-1.1-2.4-2.4v-43.2c0-1.3 1.1-2.4 2.4-2.4h43.2c1.3 0 2.4 1.1 2.4 2.4v43.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRadiusUpleftOutlined, "Radius Upleft Outlined"))]
 pub static AiRadiusUpleftOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRadiusUpleftOutlined",
+    label: "Radius Upleft Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9608,8 +13657,15 @@ pub static AiRadiusUpleftOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M656 200h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zm58 624h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM192 650h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm696-696h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-348 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-174 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm174-696H358c-127 0-230 103-230 230v182c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V358c0-87.3 70.7-158 158-158h182c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRadiusUprightOutlined, "Radius Upright Outlined"))]
 pub static AiRadiusUprightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRadiusUprightOutlined",
+    label: "Radius Upright Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9624,8 +13680,15 @@ pub static AiRadiusUprightOutlined: &icondata_core::IconData = &icondata_core::I
     data: r###"<path d="M368 128h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-2 696h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm522-174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM192 128h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 174h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm348 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm174 0h-56c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm-48-696H484c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h182c87.3 0 158 70.7 158 158v182c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V358c0-127-103-230-230-230z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiReadFilled, "Read Filled"))]
 pub static AiReadFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiReadFilled",
+    label: "Read Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9640,8 +13703,15 @@ pub static AiReadFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M928 161H699.2c-49.1 0-97.1 14.1-138.4 40.7L512 233l-48.8-31.3A255.2 255.2 0 0 0 324.8 161H96c-17.7 0-32 14.3-32 32v568c0 17.7 14.3 32 32 32h228.8c49.1 0 97.1 14.1 138.4 40.7l44.4 28.6c1.3.8 2.8 1.3 4.3 1.3s3-.4 4.3-1.3l44.4-28.6C602 807.1 650.1 793 699.2 793H928c17.7 0 32-14.3 32-32V193c0-17.7-14.3-32-32-32zM404 553.5c0 4.1-3.2 7.5-7.1 7.5H211.1c-3.9 0-7.1-3.4-7.1-7.5v-45c0-4.1 3.2-7.5 7.1-7.5h185.7c3.9 0 7.1 3.4 7.1 7.5v45zm0-140c0 4.1-3.2 7.5-7.1 7.5H211.1c-3.9 0-7.1-3.4-7.1-7.5v-45c0-4.1 3.2-7.5 7.1-7.5h185.7c3.9 0 7.1 3.4 7.1 7.5v45zm416 140c0 4.1-3.2 7.5-7.1 7.5H627.1c-3.9 0-7.1-3.4-7.1-7.5v-45c0-4.1 3.2-7.5 7.1-7.5h185.7c3.9 0 7.1 3.4 7.1 7.5v45zm0-140c0 4.1-3.2 7.5-7.1 7.5H627.1c-3.9 0-7.1-3.4-7.1-7.5v-45c0-4.1 3.2-7.5 7.1-7.5h185.7c3.9 0 7.1 3.4 7.1 7.5v45z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiReadOutlined, "Read Outlined"))]
 pub static AiReadOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiReadOutlined",
+    label: "Read Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9656,8 +13726,15 @@ pub static AiReadOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M928 161H699.2c-49.1 0-97.1 14.1-138.4 40.7L512 233l-48.8-31.3A255.2 255.2 0 0 0 324.8 161H96c-17.7 0-32 14.3-32 32v568c0 17.7 14.3 32 32 32h228.8c49.1 0 97.1 14.1 138.4 40.7l44.4 28.6c1.3.8 2.8 1.3 4.3 1.3s3-.4 4.3-1.3l44.4-28.6C602 807.1 650.1 793 699.2 793H928c17.7 0 32-14.3 32-32V193c0-17.7-14.3-32-32-32zM324.8 721H136V233h188.8c35.4 0 69.8 10.1 99.5 29.2l48.8 31.3 6.9 4.5v462c-47.6-25.6-100.8-39-155.2-39zm563.2 0H699.2c-54.4 0-107.6 13.4-155.2 39V298l6.9-4.5 48.8-31.3c29.7-19.1 64.1-29.2 99.5-29.2H888v488zM396.9 361H211.1c-3.9 0-7.1 3.4-7.1 7.5v45c0 4.1 3.2 7.5 7.1 7.5h185.7c3.9 0 7.1-3.4 7.1-7.5v-45c.1-4.1-3.1-7.5-7-7.5zm223.1 7.5v45c0 4.1 3.2 7.5 7.1 7.5h185.7c3.9 0 7.1-3.4 7.1-7.5v-45c0-4.1-3.2-7.5-7.1-7.5H627.1c-3.9 0-7.1 3.4-7.1 7.5zM396.9 501H211.1c-3.9 0-7.1 3.4-7.1 7.5v45c0 4.1 3.2 7.5 7.1 7.5h185.7c3.9 0 7.1-3.4 7.1-7.5v-45c.1-4.1-3.1-7.5-7-7.5zm416 0H627.1c-3.9 0-7.1 3.4-7.1 7.5v45c0 4.1 3.2 7.5 7.1 7.5h185.7c3.9 0 7.1-3.4 7.1-7.5v-45c.1-4.1-3.1-7.5-7-7.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiReconciliationFilled, "Reconciliation Filled"))]
 pub static AiReconciliationFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiReconciliationFilled",
+    label: "Reconciliation Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9672,8 +13749,15 @@ pub static AiReconciliationFilled: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M676 623c-18.8 0-34 15.2-34 34s15.2 34 34 34 34-15.2 34-34-15.2-34-34-34zm204-455H668c0-30.9-25.1-56-56-56h-80c-30.9 0-56 25.1-56 56H264c-17.7 0-32 14.3-32 32v200h-88c-17.7 0-32 14.3-32 32v448c0 17.7 14.3 32 32 32h336c17.7 0 32-14.3 32-32v-16h368c17.7 0 32-14.3 32-32V200c0-17.7-14.3-32-32-32zM448 848H176V616h272v232zm0-296H176v-88h272v88zm20-272v-48h72v-56h64v56h72v48H468zm180 168v56c0 4.4-3.6 8-8 8h-48c-4.4 0-8-3.6-8-8v-56c0-4.4 3.6-8 8-8h48c4.4 0 8 3.6 8 8zm28 301c-50.8 0-92-41.2-92-92s41.2-92 92-92 92 41.2 92 92-41.2 92-92 92zm92-245c0 4.4-3.6 8-8 8h-48c-4.4 0-8-3.6-8-8v-96c0-4.4 3.6-8 8-8h48c4.4 0 8 3.6 8 8v96zm-92 61c-50.8 0-92 41.2-92 92s41.2 92 92 92 92-41.2 92-92-41.2-92-92-92zm0 126c-18.8 0-34-15.2-34-34s15.2-34 34-34 34 15.2 34 34-15.2 34-34 34z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiReconciliationOutlined, "Reconciliation Outlined"))]
 pub static AiReconciliationOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiReconciliationOutlined",
+    label: "Reconciliation Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9688,8 +13772,15 @@ pub static AiReconciliationOutlined: &icondata_core::IconData = &icondata_core::
     data: r###"<path d="M676 565c-50.8 0-92 41.2-92 92s41.2 92 92 92 92-41.2 92-92-41.2-92-92-92zm0 126c-18.8 0-34-15.2-34-34s15.2-34 34-34 34 15.2 34 34-15.2 34-34 34zm204-523H668c0-30.9-25.1-56-56-56h-80c-30.9 0-56 25.1-56 56H264c-17.7 0-32 14.3-32 32v200h-88c-17.7 0-32 14.3-32 32v448c0 17.7 14.3 32 32 32h336c17.7 0 32-14.3 32-32v-16h368c17.7 0 32-14.3 32-32V200c0-17.7-14.3-32-32-32zm-412 64h72v-56h64v56h72v48H468v-48zm-20 616H176V616h272v232zm0-296H176v-88h272v88zm392 240H512V432c0-17.7-14.3-32-32-32H304V240h100v104h336V240h100v552zM704 408v96c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8v-96c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8zM592 512h48c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiReconciliationTwotone, "Reconciliation Twotone"))]
 pub static AiReconciliationTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiReconciliationTwotone",
+    label: "Reconciliation Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9708,8 +13799,15 @@ pub static AiReconciliationTwotone: &icondata_core::IconData = &icondata_core::I
 <path d="M676 565c-50.8 0-92 41.2-92 92s41.2 92 92 92 92-41.2 92-92-41.2-92-92-92zm0 126c-18.8 0-34-15.2-34-34s15.2-34 34-34 34 15.2 34 34-15.2 34-34 34z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRedEnvelopeFilled, "Red Envelope Filled"))]
 pub static AiRedEnvelopeFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRedEnvelopeFilled",
+    label: "Red Envelope Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9724,8 +13822,15 @@ pub static AiRedEnvelopeFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M832 64H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V96c0-17.7-14.3-32-32-32zM647 470.4l-87.2 161h45.9c4.6 0 8.4 3.8 8.4 8.4v25.1c0 4.6-3.8 8.4-8.4 8.4h-63.3v28.6h63.3c4.6 0 8.4 3.8 8.4 8.4v25c.2 4.6-3.6 8.5-8.2 8.5h-63.3v49.9c0 4.6-3.8 8.4-8.4 8.4h-43.7c-4.6 0-8.4-3.8-8.4-8.4v-49.9h-63c-4.6 0-8.4-3.8-8.4-8.4v-25.1c0-4.6 3.8-8.4 8.4-8.4h63v-28.6h-63c-4.6 0-8.4-3.8-8.4-8.4v-25.1c0-4.6 3.8-8.4 8.4-8.4h45.4l-87.5-161c-2.2-4.1-.7-9.1 3.4-11.4 1.3-.6 2.6-1 3.9-1h48.8c3.2 0 6.1 1.8 7.5 4.6l71.9 141.8 71.9-141.9a8.5 8.5 0 0 1 7.5-4.6h47.8c4.6 0 8.4 3.8 8.4 8.4-.1 1.5-.5 2.9-1.1 4.1zM512.6 323L289 148h446L512.6 323z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRedEnvelopeOutlined, "Red Envelope Outlined"))]
 pub static AiRedEnvelopeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRedEnvelopeOutlined",
+    label: "Red Envelope Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9740,8 +13845,15 @@ pub static AiRedEnvelopeOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M440.6 462.6a8.38 8.38 0 0 0-7.5-4.6h-48.8c-1.3 0-2.6.4-3.9 1a8.4 8.4 0 0 0-3.4 11.4l87.4 161.1H419c-4.6 0-8.4 3.8-8.4 8.4V665c0 4.6 3.8 8.4 8.4 8.4h63V702h-63c-4.6 0-8.4 3.8-8.4 8.4v25.1c0 4.6 3.8 8.4 8.4 8.4h63v49.9c0 4.6 3.8 8.4 8.4 8.4h43.7c4.6 0 8.4-3.8 8.4-8.4v-49.9h63.3c4.7 0 8.4-3.8 8.2-8.5v-25c0-4.6-3.8-8.4-8.4-8.4h-63.3v-28.6h63.3c4.6 0 8.4-3.8 8.4-8.4v-25.1c0-4.6-3.8-8.4-8.4-8.4h-45.9l87.2-161a8.45 8.45 0 0 0-7.4-12.4h-47.8c-3.1 0-6 1.8-7.5 4.6l-71.9 141.9-71.7-142zM832 64H192c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V96c0-17.7-14.3-32-32-32zm-40 824H232V193.1l260.3 204.1c11.6 9.1 27.9 9.1 39.5 0L792 193.1V888zm0-751.3h-31.7L512 331.3 263.7 136.7H232v-.7h560v.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRedEnvelopeTwotone, "Red Envelope Twotone"))]
 pub static AiRedEnvelopeTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRedEnvelopeTwotone",
+    label: "Red Envelope Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9759,8 +13871,15 @@ pub static AiRedEnvelopeTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M440.6 462.6a8.38 8.38 0 0 0-7.5-4.6h-48.8c-1.3 0-2.6.4-3.9 1a8.4 8.4 0 0 0-3.4 11.4l87.4 161.1H419c-4.6 0-8.4 3.8-8.4 8.4V665c0 4.6 3.8 8.4 8.4 8.4h63V702h-63c-4.6 0-8.4 3.8-8.4 8.4v25.1c0 4.6 3.8 8.4 8.4 8.4h63v49.9c0 4.6 3.8 8.4 8.4 8.4h43.7c4.6 0 8.4-3.8 8.4-8.4v-49.9h63.3c4.7 0 8.4-3.8 8.2-8.5v-25c0-4.6-3.8-8.4-8.4-8.4h-63.3v-28.6h63.3c4.6 0 8.4-3.8 8.4-8.4v-25.1c0-4.6-3.8-8.4-8.4-8.4h-45.9l87.2-161a8.45 8.45 0 0 0-7.4-12.4h-47.8c-3.1 0-6 1.8-7.5 4.6l-71.9 141.9-71.7-142z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRedditCircleFilled, "Reddit Circle Filled"))]
 pub static AiRedditCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRedditCircleFilled",
+    label: "Reddit Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9775,8 +13894,15 @@ pub static AiRedditCircleFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M584 548a36 36 0 1 0 72 0 36 36 0 1 0-72 0zm144-108a35.9 35.9 0 0 0-32.5 20.6c18.8 14.3 34.4 30.7 45.9 48.8A35.98 35.98 0 0 0 728 440zM512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm245 477.9c4.6 13.5 7 27.6 7 42.1 0 99.4-112.8 180-252 180s-252-80.6-252-180c0-14.5 2.4-28.6 7-42.1A72.01 72.01 0 0 1 296 404c27.1 0 50.6 14.9 62.9 37 36.2-19.8 80.2-32.8 128.1-36.1l58.4-131.1c4.3-9.8 15.2-14.8 25.5-11.8l91.6 26.5a54.03 54.03 0 0 1 101.6 25.6c0 29.8-24.2 54-54 54-23.5 0-43.5-15.1-50.9-36.1L577 308.3l-43 96.5c49.1 3 94.2 16.1 131.2 36.3 12.3-22.1 35.8-37 62.9-37 39.8 0 72 32.2 72 72-.1 29.3-17.8 54.6-43.1 65.8zm-171.3 83c-14.9 11.7-44.3 24.3-73.7 24.3s-58.9-12.6-73.7-24.3c-9.3-7.3-22.7-5.7-30 3.6-7.3 9.3-5.7 22.7 3.6 30 25.7 20.4 65 33.5 100.1 33.5 35.1 0 74.4-13.1 100.2-33.5 9.3-7.3 10.9-20.8 3.6-30a21.46 21.46 0 0 0-30.1-3.6zM296 440a35.98 35.98 0 0 0-13.4 69.4c11.5-18.1 27.1-34.5 45.9-48.8A35.9 35.9 0 0 0 296 440zm72 108a36 36 0 1 0 72 0 36 36 0 1 0-72 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRedditOutlined, "Reddit Outlined"))]
 pub static AiRedditOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRedditOutlined",
+    label: "Reddit Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9791,8 +13917,15 @@ pub static AiRedditOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M288 568a56 56 0 1 0 112 0 56 56 0 1 0-112 0zm338.7 119.7c-23.1 18.2-68.9 37.8-114.7 37.8s-91.6-19.6-114.7-37.8c-14.4-11.3-35.3-8.9-46.7 5.5s-8.9 35.3 5.5 46.7C396.3 771.6 457.5 792 512 792s115.7-20.4 155.9-52.1a33.25 33.25 0 1 0-41.2-52.2zM960 456c0-61.9-50.1-112-112-112-42.1 0-78.7 23.2-97.9 57.6-57.6-31.5-127.7-51.8-204.1-56.5L612.9 195l127.9 36.9c11.5 32.6 42.6 56.1 79.2 56.1 46.4 0 84-37.6 84-84s-37.6-84-84-84c-32 0-59.8 17.9-74 44.2L603.5 123a33.2 33.2 0 0 0-39.6 18.4l-90.8 203.9c-74.5 5.2-142.9 25.4-199.2 56.2A111.94 111.94 0 0 0 176 344c-61.9 0-112 50.1-112 112 0 45.8 27.5 85.1 66.8 102.5-7.1 21-10.8 43-10.8 65.5 0 154.6 175.5 280 392 280s392-125.4 392-280c0-22.6-3.8-44.5-10.8-65.5C932.5 541.1 960 501.8 960 456zM820 172.5a31.5 31.5 0 1 1 0 63 31.5 31.5 0 0 1 0-63zM120 456c0-30.9 25.1-56 56-56a56 56 0 0 1 50.6 32.1c-29.3 22.2-53.5 47.8-71.5 75.9a56.23 56.23 0 0 1-35.1-52zm392 381.5c-179.8 0-325.5-95.6-325.5-213.5S332.2 410.5 512 410.5 837.5 506.1 837.5 624 691.8 837.5 512 837.5zM868.8 508c-17.9-28.1-42.2-53.7-71.5-75.9 9-18.9 28.3-32.1 50.6-32.1 30.9 0 56 25.1 56 56 .1 23.5-14.5 43.7-35.1 52zM624 568a56 56 0 1 0 112 0 56 56 0 1 0-112 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRedditSquareFilled, "Reddit Square Filled"))]
 pub static AiRedditSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRedditSquareFilled",
+    label: "Reddit Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9807,8 +13940,15 @@ pub static AiRedditSquareFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M296 440a35.98 35.98 0 0 0-13.4 69.4c11.5-18.1 27.1-34.5 45.9-48.8A35.9 35.9 0 0 0 296 440zm289.7 184.9c-14.9 11.7-44.3 24.3-73.7 24.3s-58.9-12.6-73.7-24.3c-9.3-7.3-22.7-5.7-30 3.6-7.3 9.3-5.7 22.7 3.6 30 25.7 20.4 65 33.5 100.1 33.5 35.1 0 74.4-13.1 100.2-33.5 9.3-7.3 10.9-20.8 3.6-30a21.46 21.46 0 0 0-30.1-3.6zM880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM757 541.9c4.6 13.5 7 27.6 7 42.1 0 99.4-112.8 180-252 180s-252-80.6-252-180c0-14.5 2.4-28.6 7-42.1A72.01 72.01 0 0 1 296 404c27.1 0 50.6 14.9 62.9 37 36.2-19.8 80.2-32.8 128.1-36.1l58.4-131.1c4.3-9.8 15.2-14.8 25.5-11.8l91.6 26.5a54.03 54.03 0 0 1 101.6 25.6c0 29.8-24.2 54-54 54-23.5 0-43.5-15.1-50.9-36.1L577 308.3l-43 96.5c49.1 3 94.2 16.1 131.2 36.3 12.3-22.1 35.8-37 62.9-37 39.8 0 72 32.2 72 72-.1 29.3-17.8 54.6-43.1 65.8zM584 548a36 36 0 1 0 72 0 36 36 0 1 0-72 0zm144-108a35.9 35.9 0 0 0-32.5 20.6c18.8 14.3 34.4 30.7 45.9 48.8A35.98 35.98 0 0 0 728 440zM368 548a36 36 0 1 0 72 0 36 36 0 1 0-72 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRedoOutlined, "Redo Outlined"))]
 pub static AiRedoOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRedoOutlined",
+    label: "Redo Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9823,8 +13963,15 @@ pub static AiRedoOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M758.2 839.1C851.8 765.9 912 651.9 912 523.9 912 303 733.5 124.3 512.6 124 291.4 123.7 112 302.8 112 523.9c0 125.2 57.5 236.9 147.6 310.2 3.5 2.8 8.6 2.2 11.4-1.3l39.4-50.5c2.7-3.4 2.1-8.3-1.2-11.1-8.1-6.6-15.9-13.7-23.4-21.2a318.64 318.64 0 0 1-68.6-101.7C200.4 609 192 567.1 192 523.9s8.4-85.1 25.1-124.5c16.1-38.1 39.2-72.3 68.6-101.7 29.4-29.4 63.6-52.5 101.7-68.6C426.9 212.4 468.8 204 512 204s85.1 8.4 124.5 25.1c38.1 16.1 72.3 39.2 101.7 68.6 29.4 29.4 52.5 63.6 68.6 101.7 16.7 39.4 25.1 81.3 25.1 124.5s-8.4 85.1-25.1 124.5a318.64 318.64 0 0 1-68.6 101.7c-9.3 9.3-19.1 18-29.3 26L668.2 724a8 8 0 0 0-14.1 3l-39.6 162.2c-1.2 5 2.6 9.9 7.7 9.9l167 .8c6.7 0 10.5-7.7 6.3-12.9l-37.3-47.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiReloadOutlined, "Reload Outlined"))]
 pub static AiReloadOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiReloadOutlined",
+    label: "Reload Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9839,8 +13986,15 @@ pub static AiReloadOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M909.1 209.3l-56.4 44.1C775.8 155.1 656.2 92 521.9 92 290 92 102.3 279.5 102 511.5 101.7 743.7 289.8 932 521.9 932c181.3 0 335.8-115 394.6-276.1 1.5-4.2-.7-8.9-4.9-10.3l-56.7-19.5a8 8 0 0 0-10.1 4.8c-1.8 5-3.8 10-5.9 14.9-17.3 41-42.1 77.8-73.7 109.4A344.77 344.77 0 0 1 655.9 829c-42.3 17.9-87.4 27-133.8 27-46.5 0-91.5-9.1-133.8-27A341.5 341.5 0 0 1 279 755.2a342.16 342.16 0 0 1-73.7-109.4c-17.9-42.4-27-87.4-27-133.9s9.1-91.5 27-133.9c17.3-41 42.1-77.8 73.7-109.4 31.6-31.6 68.4-56.4 109.3-73.8 42.3-17.9 87.4-27 133.8-27 46.5 0 91.5 9.1 133.8 27a341.5 341.5 0 0 1 109.3 73.8c9.9 9.9 19.2 20.4 27.8 31.4l-60.2 47a8 8 0 0 0 3 14.1l175.6 43c5 1.2 9.9-2.6 9.9-7.7l.8-180.9c-.1-6.6-7.8-10.3-13-6.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRestFilled, "Rest Filled"))]
 pub static AiRestFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRestFilled",
+    label: "Rest Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9855,8 +14009,15 @@ pub static AiRestFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M832 256h-28.1l-35.7-120.9c-4-13.7-16.5-23.1-30.7-23.1h-451c-14.3 0-26.8 9.4-30.7 23.1L220.1 256H192c-17.7 0-32 14.3-32 32v28c0 4.4 3.6 8 8 8h45.8l47.7 558.7a32 32 0 0 0 31.9 29.3h429.2a32 32 0 0 0 31.9-29.3L802.2 324H856c4.4 0 8-3.6 8-8v-28c0-17.7-14.3-32-32-32zM508 704c-79.5 0-144-64.5-144-144s64.5-144 144-144 144 64.5 144 144-64.5 144-144 144zM291 256l22.4-76h397.2l22.4 76H291zm137 304a80 80 0 1 0 160 0 80 80 0 1 0-160 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRestOutlined, "Rest Outlined"))]
 pub static AiRestOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRestOutlined",
+    label: "Rest Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9875,8 +14036,15 @@ pub static AiRestOutlined: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M832 256h-28.1l-35.7-120.9c-4-13.7-16.5-23.1-30.7-23.1h-451c-14.3 0-26.8 9.4-30.7 23.1L220.1 256H192c-17.7 0-32 14.3-32 32v28c0 4.4 3.6 8 8 8h45.8l47.7 558.7a32 32 0 0 0 31.9 29.3h429.2a32 32 0 0 0 31.9-29.3L802.2 324H856c4.4 0 8-3.6 8-8v-28c0-17.7-14.3-32-32-32zm-518.6-76h397.2l22.4 76H291l22.4-76zm376.2 664H326.4L282 324h451.9l-44.3 520z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRestTwotone, "Rest Twotone"))]
 pub static AiRestTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRestTwotone",
+    label: "Rest Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9893,8 +14061,15 @@ pub static AiRestTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M832 256h-28.1l-35.7-120.9c-4-13.7-16.5-23.1-30.7-23.1h-451c-14.3 0-26.8 9.4-30.7 23.1L220.1 256H192c-17.7 0-32 14.3-32 32v28c0 4.4 3.6 8 8 8h45.8l47.7 558.7a32 32 0 0 0 31.9 29.3h429.2a32 32 0 0 0 31.9-29.3L802.2 324H856c4.4 0 8-3.6 8-8v-28c0-17.7-14.3-32-32-32zm-518.6-76h397.2l22.4 76H291l22.4-76zm376.2 664H326.4L282 324h451.9l-44.3 520z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRetweetOutlined, "Retweet Outlined"))]
 pub static AiRetweetOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRetweetOutlined",
+    label: "Retweet Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9909,8 +14084,15 @@ pub static AiRetweetOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M136 552h63.6c4.4 0 8-3.6 8-8V288.7h528.6v72.6c0 1.9.6 3.7 1.8 5.2a8.3 8.3 0 0 0 11.7 1.4L893 255.4c4.3-5 3.6-10.3 0-13.2L749.7 129.8a8.22 8.22 0 0 0-5.2-1.8c-4.6 0-8.4 3.8-8.4 8.4V209H199.7c-39.5 0-71.7 32.2-71.7 71.8V544c0 4.4 3.6 8 8 8zm752-80h-63.6c-4.4 0-8 3.6-8 8v255.3H287.8v-72.6c0-1.9-.6-3.7-1.8-5.2a8.3 8.3 0 0 0-11.7-1.4L131 768.6c-4.3 5-3.6 10.3 0 13.2l143.3 112.4c1.5 1.2 3.3 1.8 5.2 1.8 4.6 0 8.4-3.8 8.4-8.4V815h536.6c39.5 0 71.7-32.2 71.7-71.8V480c-.2-4.4-3.8-8-8.2-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRightCircleFilled, "Right Circle Filled"))]
 pub static AiRightCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRightCircleFilled",
+    label: "Right Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9925,8 +14107,15 @@ pub static AiRightCircleFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm154.7 454.5l-246 178c-5.3 3.8-12.7 0-12.7-6.5v-46.9c0-10.2 4.9-19.9 13.2-25.9L566.6 512 421.2 406.8c-8.3-6-13.2-15.6-13.2-25.9V334c0-6.5 7.4-10.3 12.7-6.5l246 178c4.4 3.2 4.4 9.8 0 13z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRightCircleOutlined, "Right Circle Outlined"))]
 pub static AiRightCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRightCircleOutlined",
+    label: "Right Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9942,8 +14131,15 @@ pub static AiRightCircleOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRightCircleTwotone, "Right Circle Twotone"))]
 pub static AiRightCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRightCircleTwotone",
+    label: "Right Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9960,8 +14156,15 @@ pub static AiRightCircleTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M666.7 505.5l-246-178c-5.3-3.8-12.7 0-12.7 6.5v46.9c0 10.3 4.9 19.9 13.2 25.9L566.6 512 421.2 617.1c-8.3 6-13.2 15.7-13.2 25.9v46.9c0 6.5 7.4 10.3 12.7 6.5l246-178c4.4-3.2 4.4-9.7 0-12.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRightOutlined, "Right Outlined"))]
 pub static AiRightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRightOutlined",
+    label: "Right Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9976,8 +14179,15 @@ pub static AiRightOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M765.7 486.8L314.9 134.7A7.97 7.97 0 0 0 302 141v77.3c0 4.9 2.3 9.6 6.1 12.6l360 281.1-360 281.1c-3.9 3-6.1 7.7-6.1 12.6V883c0 6.7 7.7 10.4 12.9 6.3l450.8-352.1a31.96 31.96 0 0 0 0-50.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRightSquareFilled, "Right Square Filled"))]
 pub static AiRightSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRightSquareFilled",
+    label: "Right Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9992,8 +14202,15 @@ pub static AiRightSquareFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM658.7 518.5l-246 178c-5.3 3.8-12.7 0-12.7-6.5v-46.9c0-10.2 4.9-19.9 13.2-25.9L558.6 512 413.2 406.8c-8.3-6-13.2-15.6-13.2-25.9V334c0-6.5 7.4-10.3 12.7-6.5l246 178c4.4 3.2 4.4 9.8 0 13z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRightSquareOutlined, "Right Square Outlined"))]
 pub static AiRightSquareOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRightSquareOutlined",
+    label: "Right Square Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10009,8 +14226,15 @@ pub static AiRightSquareOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRightSquareTwotone, "Right Square Twotone"))]
 pub static AiRightSquareTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRightSquareTwotone",
+    label: "Right Square Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10027,8 +14251,15 @@ pub static AiRightSquareTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M412.7 696.4l246-178c4.4-3.2 4.4-9.7 0-12.9l-246-178c-5.3-3.8-12.7 0-12.7 6.5v46.9c0 10.3 4.9 19.9 13.2 25.9L558.6 512 413.2 617.2c-8.3 6-13.2 15.7-13.2 25.9V690c0 6.5 7.4 10.3 12.7 6.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRiseOutlined, "Rise Outlined"))]
 pub static AiRiseOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRiseOutlined",
+    label: "Rise Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10043,8 +14274,15 @@ pub static AiRiseOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M917 211.1l-199.2 24c-6.6.8-9.4 8.9-4.7 13.6l59.3 59.3-226 226-101.8-101.7c-6.3-6.3-16.4-6.2-22.6 0L100.3 754.1a8.03 8.03 0 0 0 0 11.3l45 45.2c3.1 3.1 8.2 3.1 11.3 0L433.3 534 535 635.7c6.3 6.2 16.4 6.2 22.6 0L829 364.5l59.3 59.3a8.01 8.01 0 0 0 13.6-4.7l24-199.2c.7-5.1-3.7-9.5-8.9-8.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRobotFilled, "Robot Filled"))]
 pub static AiRobotFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRobotFilled",
+    label: "Robot Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10062,8 +14300,15 @@ pub static AiRobotFilled: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M852 64H172c-17.7 0-32 14.3-32 32v660c0 17.7 14.3 32 32 32h680c17.7 0 32-14.3 32-32V96c0-17.7-14.3-32-32-32zM300 328c0-33.1 26.9-60 60-60s60 26.9 60 60-26.9 60-60 60-60-26.9-60-60z m372 248c0 4.4-3.6 8-8 8H360c-4.4 0-8-3.6-8-8v-60c0-4.4 3.6-8 8-8h304c4.4 0 8 3.6 8 8v60z m-8-188c-33.1 0-60-26.9-60-60s26.9-60 60-60 60 26.9 60 60-26.9 60-60 60zM799 864H225c-13.8 0-25 14.3-25 32v56c0 4.4 2.8 8 6.2 8h611.5c3.4 0 6.2-3.6 6.2-8v-56c0.1-17.7-11.1-32-24.9-32z" p-id="7949" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRobotOutlined, "Robot Outlined"))]
 pub static AiRobotOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRobotOutlined",
+    label: "Robot Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10078,8 +14323,15 @@ pub static AiRobotOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M300 328a60 60 0 1 0 120 0 60 60 0 1 0-120 0zM852 64H172c-17.7 0-32 14.3-32 32v660c0 17.7 14.3 32 32 32h680c17.7 0 32-14.3 32-32V96c0-17.7-14.3-32-32-32zm-32 660H204V128h616v596zM604 328a60 60 0 1 0 120 0 60 60 0 1 0-120 0zm250.2 556H169.8c-16.5 0-29.8 14.3-29.8 32v36c0 4.4 3.3 8 7.4 8h729.1c4.1 0 7.4-3.6 7.4-8v-36c.1-17.7-13.2-32-29.7-32zM664 508H360c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h304c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRocketFilled, "Rocket Filled"))]
 pub static AiRocketFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRocketFilled",
+    label: "Rocket Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10094,8 +14346,15 @@ pub static AiRocketFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M864 736c0-111.6-65.4-208-160-252.9V317.3c0-15.1-5.3-29.7-15.1-41.2L536.5 95.4C530.1 87.8 521 84 512 84s-18.1 3.8-24.5 11.4L335.1 276.1a63.97 63.97 0 0 0-15.1 41.2v165.8C225.4 528 160 624.4 160 736h156.5c-2.3 7.2-3.5 15-3.5 23.8 0 22.1 7.6 43.7 21.4 60.8a97.2 97.2 0 0 0 43.1 30.6c23.1 54 75.6 88.8 134.5 88.8 29.1 0 57.3-8.6 81.4-24.8 23.6-15.8 41.9-37.9 53-64a97 97 0 0 0 43.1-30.5 97.52 97.52 0 0 0 21.4-60.8c0-8.4-1.1-16.4-3.1-23.8L864 736zM512 352a48.01 48.01 0 0 1 0 96 48.01 48.01 0 0 1 0-96zm116.1 432.2c-5.2 3-11.2 4.2-17.1 3.4l-19.5-2.4-2.8 19.4c-5.4 37.9-38.4 66.5-76.7 66.5s-71.3-28.6-76.7-66.5l-2.8-19.5-19.5 2.5a27.7 27.7 0 0 1-17.1-3.5c-8.7-5-14.1-14.3-14.1-24.4 0-10.6 5.9-19.4 14.6-23.8h231.3c8.8 4.5 14.6 13.3 14.6 23.8-.1 10.2-5.5 19.6-14.2 24.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRocketOutlined, "Rocket Outlined"))]
 pub static AiRocketOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRocketOutlined",
+    label: "Rocket Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10110,8 +14369,15 @@ pub static AiRocketOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M864 736c0-111.6-65.4-208-160-252.9V317.3c0-15.1-5.3-29.7-15.1-41.2L536.5 95.4C530.1 87.8 521 84 512 84s-18.1 3.8-24.5 11.4L335.1 276.1a63.97 63.97 0 0 0-15.1 41.2v165.8C225.4 528 160 624.4 160 736h156.5c-2.3 7.2-3.5 15-3.5 23.8 0 22.1 7.6 43.7 21.4 60.8a97.2 97.2 0 0 0 43.1 30.6c23.1 54 75.6 88.8 134.5 88.8 29.1 0 57.3-8.6 81.4-24.8 23.6-15.8 41.9-37.9 53-64a97 97 0 0 0 43.1-30.5 97.52 97.52 0 0 0 21.4-60.8c0-8.4-1.1-16.4-3.1-23.8H864zM762.3 621.4c9.4 14.6 17 30.3 22.5 46.6H700V558.7a211.6 211.6 0 0 1 62.3 62.7zM388 483.1V318.8l124-147 124 147V668H388V483.1zM239.2 668c5.5-16.3 13.1-32 22.5-46.6 16.3-25.2 37.5-46.5 62.3-62.7V668h-84.8zm388.9 116.2c-5.2 3-11.2 4.2-17.1 3.4l-19.5-2.4-2.8 19.4c-5.4 37.9-38.4 66.5-76.7 66.5-38.3 0-71.3-28.6-76.7-66.5l-2.8-19.5-19.5 2.5a27.7 27.7 0 0 1-17.1-3.5c-8.7-5-14.1-14.3-14.1-24.4 0-10.6 5.9-19.4 14.6-23.8h231.3c8.8 4.5 14.6 13.3 14.6 23.8-.1 10.2-5.5 19.6-14.2 24.5zM464 400a48 48 0 1 0 96 0 48 48 0 1 0-96 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRocketTwotone, "Rocket Twotone"))]
 pub static AiRocketTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRocketTwotone",
+    label: "Rocket Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10128,8 +14394,15 @@ pub static AiRocketTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M464 400a48 48 0 1 0 96 0 48 48 0 1 0-96 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRollbackOutlined, "Rollback Outlined"))]
 pub static AiRollbackOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRollbackOutlined",
+    label: "Rollback Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10144,8 +14417,15 @@ pub static AiRollbackOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M793 242H366v-74c0-6.7-7.7-10.4-12.9-6.3l-142 112a8 8 0 0 0 0 12.6l142 112c5.2 4.1 12.9.4 12.9-6.3v-74h415v470H175c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h618c35.3 0 64-28.7 64-64V306c0-35.3-28.7-64-64-64z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRotateLeftOutlined, "Rotate Left Outlined"))]
 pub static AiRotateLeftOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRotateLeftOutlined",
+    label: "Rotate Left Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10164,8 +14444,15 @@ pub static AiRotateLeftOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M819.3 328.5c-78.8-100.7-196-153.6-314.6-154.2l-0.2-64c0-6.5-7.6-10.1-12.6-6.1l-128 101c-4 3.1-3.9 9.1 0 12.3L492 318.6c5.1 4 12.7 0.4 12.6-6.1v-63.9c12.9 0.1 25.9 0.9 38.8 2.5 42.1 5.2 82.1 18.2 119 38.7 38.1 21.2 71.2 49.7 98.4 84.3 27.1 34.7 46.7 73.7 58.1 115.8 11 40.7 14 82.7 8.9 124.8-0.7 5.4-1.4 10.8-2.4 16.1h74.9c14.8-103.6-11.3-213-81-302.3z" p-id="10465" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiRotateRightOutlined, "Rotate Right Outlined"))]
 pub static AiRotateRightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiRotateRightOutlined",
+    label: "Rotate Right Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10184,8 +14471,15 @@ pub static AiRotateRightOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M880 418H352c-17.7 0-32 14.3-32 32v414c0 17.7 14.3 32 32 32h528c17.7 0 32-14.3 32-32V450c0-17.7-14.3-32-32-32z m-44 402H396V494h440v326z" p-id="10584" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSafetyCertificateFilled, "Safety Certificate Filled"))]
 pub static AiSafetyCertificateFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSafetyCertificateFilled",
+    label: "Safety Certificate Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10200,8 +14494,15 @@ pub static AiSafetyCertificateFilled: &icondata_core::IconData = &icondata_core:
     data: r###"<path d="M866.9 169.9L527.1 54.1C523 52.7 517.5 52 512 52s-11 .7-15.1 2.1L157.1 169.9c-8.3 2.8-15.1 12.4-15.1 21.2v482.4c0 8.8 5.7 20.4 12.6 25.9L499.3 968c3.5 2.7 8 4.1 12.6 4.1s9.2-1.4 12.6-4.1l344.7-268.6c6.9-5.4 12.6-17 12.6-25.9V191.1c.2-8.8-6.6-18.3-14.9-21.2zM694.5 340.7L481.9 633.4a16.1 16.1 0 0 1-26 0l-126.4-174c-3.8-5.3 0-12.7 6.5-12.7h55.2c5.1 0 10 2.5 13 6.6l64.7 89 150.9-207.8c3-4.1 7.8-6.6 13-6.6H688c6.5.1 10.3 7.5 6.5 12.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSafetyCertificateOutlined, "Safety Certificate Outlined"))]
 pub static AiSafetyCertificateOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSafetyCertificateOutlined",
+    label: "Safety Certificate Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10216,8 +14517,15 @@ pub static AiSafetyCertificateOutlined: &icondata_core::IconData = &icondata_cor
     data: r###"<path d="M866.9 169.9L527.1 54.1C523 52.7 517.5 52 512 52s-11 .7-15.1 2.1L157.1 169.9c-8.3 2.8-15.1 12.4-15.1 21.2v482.4c0 8.8 5.7 20.4 12.6 25.9L499.3 968c3.5 2.7 8 4.1 12.6 4.1s9.2-1.4 12.6-4.1l344.7-268.6c6.9-5.4 12.6-17 12.6-25.9V191.1c.2-8.8-6.6-18.3-14.9-21.2zM810 654.3L512 886.5 214 654.3V226.7l298-101.6 298 101.6v427.6zm-405.8-201c-3-4.1-7.8-6.6-13-6.6H336c-6.5 0-10.3 7.4-6.5 12.7l126.4 174a16.1 16.1 0 0 0 26 0l212.6-292.7c3.8-5.3 0-12.7-6.5-12.7h-55.2c-5.1 0-10 2.5-13 6.6L468.9 542.4l-64.7-89.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSafetyCertificateTwotone, "Safety Certificate Twotone"))]
 pub static AiSafetyCertificateTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSafetyCertificateTwotone",
+    label: "Safety Certificate Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10234,8 +14542,15 @@ pub static AiSafetyCertificateTwotone: &icondata_core::IconData = &icondata_core
 <path d="M404.2 453.3c-3-4.1-7.8-6.6-13-6.6H336c-6.5 0-10.3 7.4-6.5 12.7l126.4 174a16.1 16.1 0 0 0 26 0l212.6-292.7c3.8-5.3 0-12.7-6.5-12.7h-55.2c-5.1 0-10 2.5-13 6.6L468.9 542.4l-64.7-89.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSafetyOutlined, "Safety Outlined"))]
 pub static AiSafetyOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSafetyOutlined",
+    label: "Safety Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10251,8 +14566,15 @@ pub static AiSafetyOutlined: &icondata_core::IconData = &icondata_core::IconData
 <path d="M378.4 475.1a35.91 35.91 0 0 0-50.9 0 35.91 35.91 0 0 0 0 50.9l129.4 129.4 2.1 2.1a33.98 33.98 0 0 0 48.1 0L730.6 434a33.98 33.98 0 0 0 0-48.1l-2.8-2.8a33.98 33.98 0 0 0-48.1 0L483 579.7 378.4 475.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSaveFilled, "Save Filled"))]
 pub static AiSaveFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSaveFilled",
+    label: "Save Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10267,8 +14589,15 @@ pub static AiSaveFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M893.3 293.3L730.7 130.7c-12-12-28.3-18.7-45.3-18.7H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V338.5c0-17-6.7-33.2-18.7-45.2zM384 176h256v112H384V176zm128 554c-79.5 0-144-64.5-144-144s64.5-144 144-144 144 64.5 144 144-64.5 144-144 144zm0-224c-44.2 0-80 35.8-80 80s35.8 80 80 80 80-35.8 80-80-35.8-80-80-80z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSaveOutlined, "Save Outlined"))]
 pub static AiSaveOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSaveOutlined",
+    label: "Save Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10283,8 +14612,15 @@ pub static AiSaveOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M893.3 293.3L730.7 130.7c-7.5-7.5-16.7-13-26.7-16V112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V338.5c0-17-6.7-33.2-18.7-45.2zM384 184h256v104H384V184zm456 656H184V184h136v136c0 17.7 14.3 32 32 32h320c17.7 0 32-14.3 32-32V205.8l136 136V840zM512 442c-79.5 0-144 64.5-144 144s64.5 144 144 144 144-64.5 144-144-64.5-144-144-144zm0 224c-44.2 0-80-35.8-80-80s35.8-80 80-80 80 35.8 80 80-35.8 80-80 80z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSaveTwotone, "Save Twotone"))]
 pub static AiSaveTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSaveTwotone",
+    label: "Save Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10301,8 +14637,15 @@ pub static AiSaveTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M893.3 293.3L730.7 130.7c-.7-.7-1.4-1.3-2.1-2-.1-.1-.3-.2-.4-.3-.7-.7-1.5-1.3-2.2-1.9a64 64 0 0 0-22-11.7V112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V338.5c0-17-6.7-33.2-18.7-45.2zM384 184h256v104H384V184zm456 656H184V184h136v136c0 17.7 14.3 32 32 32h320c17.7 0 32-14.3 32-32V205.8l136 136V840z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiScanOutlined, "Scan Outlined"))]
 pub static AiScanOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiScanOutlined",
+    label: "Scan Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10317,8 +14660,15 @@ pub static AiScanOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M136 384h56c4.4 0 8-3.6 8-8V200h176c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H196c-37.6 0-68 30.4-68 68v180c0 4.4 3.6 8 8 8zm512-184h176v176c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V196c0-37.6-30.4-68-68-68H648c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8zM376 824H200V648c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v180c0 37.6 30.4 68 68 68h180c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm512-184h-56c-4.4 0-8 3.6-8 8v176H648c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h180c37.6 0 68-30.4 68-68V648c0-4.4-3.6-8-8-8zm16-164H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiScheduleFilled, "Schedule Filled"))]
 pub static AiScheduleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiScheduleFilled",
+    label: "Schedule Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10333,8 +14683,15 @@ pub static AiScheduleFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M928 224H768v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H548v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H328v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H96c-17.7 0-32 14.3-32 32v576c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V256c0-17.7-14.3-32-32-32zM424 688c0 4.4-3.6 8-8 8H232c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h184c4.4 0 8 3.6 8 8v48zm0-136c0 4.4-3.6 8-8 8H232c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h184c4.4 0 8 3.6 8 8v48zm374.5-91.3l-165 228.7a15.9 15.9 0 0 1-25.8 0L493.5 531.2c-3.8-5.3 0-12.7 6.5-12.7h54.9c5.1 0 9.9 2.5 12.9 6.6l52.8 73.1 103.7-143.7c3-4.2 7.8-6.6 12.9-6.6H792c6.5.1 10.3 7.5 6.5 12.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiScheduleOutlined, "Schedule Outlined"))]
 pub static AiScheduleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiScheduleOutlined",
+    label: "Schedule Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10349,8 +14706,15 @@ pub static AiScheduleOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M928 224H768v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H548v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H328v-56c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v56H96c-17.7 0-32 14.3-32 32v576c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V256c0-17.7-14.3-32-32-32zm-40 568H136V296h120v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56h148v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56h148v56c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-56h120v496zM416 496H232c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zm0 136H232c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zm308.2-177.4L620.6 598.3l-52.8-73.1c-3-4.2-7.8-6.6-12.9-6.6H500c-6.5 0-10.3 7.4-6.5 12.7l114.1 158.2a15.9 15.9 0 0 0 25.8 0l165-228.7c3.8-5.3 0-12.7-6.5-12.7H737c-5-.1-9.8 2.4-12.8 6.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiScheduleTwotone, "Schedule Twotone"))]
 pub static AiScheduleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiScheduleTwotone",
+    label: "Schedule Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10368,8 +14732,15 @@ pub static AiScheduleTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M416 632H232c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiScissorOutlined, "Scissor Outlined"))]
 pub static AiScissorOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiScissorOutlined",
+    label: "Scissor Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10384,8 +14755,15 @@ pub static AiScissorOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M567.1 512l318.5-319.3c5-5 1.5-13.7-5.6-13.7h-90.5c-2.1 0-4.2.8-5.6 2.3l-273.3 274-90.2-90.5c12.5-22.1 19.7-47.6 19.7-74.8 0-83.9-68.1-152-152-152s-152 68.1-152 152 68.1 152 152 152c27.7 0 53.6-7.4 75.9-20.3l90 90.3-90.1 90.3A151.04 151.04 0 0 0 288 582c-83.9 0-152 68.1-152 152s68.1 152 152 152 152-68.1 152-152c0-27.2-7.2-52.7-19.7-74.8l90.2-90.5 273.3 274c1.5 1.5 3.5 2.3 5.6 2.3H880c7.1 0 10.7-8.6 5.6-13.7L567.1 512zM288 370c-44.1 0-80-35.9-80-80s35.9-80 80-80 80 35.9 80 80-35.9 80-80 80zm0 444c-44.1 0-80-35.9-80-80s35.9-80 80-80 80 35.9 80 80-35.9 80-80 80z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSearchOutlined, "Search Outlined"))]
 pub static AiSearchOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSearchOutlined",
+    label: "Search Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10400,8 +14778,15 @@ pub static AiSearchOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M909.6 854.5L649.9 594.8C690.2 542.7 712 479 712 412c0-80.2-31.3-155.4-87.9-212.1-56.6-56.7-132-87.9-212.1-87.9s-155.5 31.3-212.1 87.9C143.2 256.5 112 331.8 112 412c0 80.1 31.3 155.5 87.9 212.1C256.5 680.8 331.8 712 412 712c67 0 130.6-21.8 182.7-62l259.7 259.6a8.2 8.2 0 0 0 11.6 0l43.6-43.5a8.2 8.2 0 0 0 0-11.6zM570.4 570.4C528 612.7 471.8 636 412 636s-116-23.3-158.4-65.6C211.3 528 188 471.8 188 412s23.3-116.1 65.6-158.4C296 211.3 352.2 188 412 188s116.1 23.2 158.4 65.6S636 352.2 636 412s-23.3 116.1-65.6 158.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSecurityScanFilled, "Security Scan Filled"))]
 pub static AiSecurityScanFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSecurityScanFilled",
+    label: "Security Scan Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10416,8 +14801,15 @@ pub static AiSecurityScanFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M866.9 169.9L527.1 54.1C523 52.7 517.5 52 512 52s-11 .7-15.1 2.1L157.1 169.9c-8.3 2.8-15.1 12.4-15.1 21.2v482.4c0 8.8 5.7 20.4 12.6 25.9L499.3 968c3.5 2.7 8 4.1 12.6 4.1s9.2-1.4 12.6-4.1l344.7-268.6c6.9-5.4 12.6-17 12.6-25.9V191.1c.2-8.8-6.6-18.3-14.9-21.2zM626.8 554c-48.5 48.5-123 55.2-178.6 20.1l-77.5 77.5a8.03 8.03 0 0 1-11.3 0l-34-34a8.03 8.03 0 0 1 0-11.3l77.5-77.5c-35.1-55.7-28.4-130.1 20.1-178.6 56.3-56.3 147.5-56.3 203.8 0 56.3 56.3 56.3 147.5 0 203.8zm-158.54-45.27a80.1 80.1 0 1 0 113.27-113.28 80.1 80.1 0 1 0-113.27 113.28z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSecurityScanOutlined, "Security Scan Outlined"))]
 pub static AiSecurityScanOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSecurityScanOutlined",
+    label: "Security Scan Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10432,8 +14824,15 @@ pub static AiSecurityScanOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M866.9 169.9L527.1 54.1C523 52.7 517.5 52 512 52s-11 .7-15.1 2.1L157.1 169.9c-8.3 2.8-15.1 12.4-15.1 21.2v482.4c0 8.8 5.7 20.4 12.6 25.9L499.3 968c3.5 2.7 8 4.1 12.6 4.1s9.2-1.4 12.6-4.1l344.7-268.6c6.9-5.4 12.6-17 12.6-25.9V191.1c.2-8.8-6.6-18.3-14.9-21.2zM810 654.3L512 886.5 214 654.3V226.7l298-101.6 298 101.6v427.6zM402.9 528.8l-77.5 77.5a8.03 8.03 0 0 0 0 11.3l34 34c3.1 3.1 8.2 3.1 11.3 0l77.5-77.5c55.7 35.1 130.1 28.4 178.6-20.1 56.3-56.3 56.3-147.5 0-203.8-56.3-56.3-147.5-56.3-203.8 0-48.5 48.5-55.2 123-20.1 178.6zm65.4-133.3c31.3-31.3 82-31.3 113.2 0 31.3 31.3 31.3 82 0 113.2-31.3 31.3-82 31.3-113.2 0s-31.3-81.9 0-113.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSecurityScanTwotone, "Security Scan Twotone"))]
 pub static AiSecurityScanTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSecurityScanTwotone",
+    label: "Security Scan Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10451,8 +14850,15 @@ pub static AiSecurityScanTwotone: &icondata_core::IconData = &icondata_core::Ico
 <path d="M418.8 527.8l-77.5 77.5a8.03 8.03 0 0 0 0 11.3l34 34c3.1 3.1 8.2 3.1 11.3 0l77.5-77.5c55.6 35.1 130.1 28.4 178.6-20.1 56.3-56.3 56.3-147.5 0-203.8-56.3-56.3-147.5-56.3-203.8 0-48.5 48.5-55.2 122.9-20.1 178.6zm65.4-133.3a80.1 80.1 0 0 1 113.3 0 80.1 80.1 0 0 1 0 113.3c-31.3 31.3-82 31.3-113.3 0s-31.3-82 0-113.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSelectOutlined, "Select Outlined"))]
 pub static AiSelectOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSelectOutlined",
+    label: "Select Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10467,8 +14873,15 @@ pub static AiSelectOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h360c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H184V184h656v320c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V144c0-17.7-14.3-32-32-32zM653.3 599.4l52.2-52.2a8.01 8.01 0 0 0-4.7-13.6l-179.4-21c-5.1-.6-9.5 3.7-8.9 8.9l21 179.4c.8 6.6 8.9 9.4 13.6 4.7l52.4-52.4 256.2 256.2c3.1 3.1 8.2 3.1 11.3 0l42.4-42.4c3.1-3.1 3.1-8.2 0-11.3L653.3 599.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSendOutlined, "Send Outlined"))]
 pub static AiSendOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSendOutlined",
+    label: "Send Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10486,8 +14899,15 @@ pub static AiSendOutlined: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M931.4 498.9L94.9 79.5c-3.4-1.7-7.3-2.1-11-1.2-8.5 2.1-13.8 10.7-11.7 19.3l86.2 352.2c1.3 5.3 5.2 9.6 10.4 11.3l147.7 50.7-147.6 50.7c-5.2 1.8-9.1 6-10.3 11.3L72.2 926.5c-0.9 3.7-0.5 7.6 1.2 10.9 3.9 7.9 13.5 11.1 21.5 7.2l836.5-417c3.1-1.5 5.6-4.1 7.2-7.1 3.9-8 0.7-17.6-7.2-21.6zM170.8 826.3l50.3-205.6 295.2-101.3c2.3-0.8 4.2-2.6 5-5 1.4-4.2-0.8-8.7-5-10.2L221.1 403 171 198.2l628 314.9-628.2 313.2z" p-id="14020" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSettingFilled, "Setting Filled"))]
 pub static AiSettingFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSettingFilled",
+    label: "Setting Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10502,8 +14922,15 @@ pub static AiSettingFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M512.5 390.6c-29.9 0-57.9 11.6-79.1 32.8-21.1 21.2-32.8 49.2-32.8 79.1 0 29.9 11.7 57.9 32.8 79.1 21.2 21.1 49.2 32.8 79.1 32.8 29.9 0 57.9-11.7 79.1-32.8 21.1-21.2 32.8-49.2 32.8-79.1 0-29.9-11.7-57.9-32.8-79.1a110.96 110.96 0 0 0-79.1-32.8zm412.3 235.5l-65.4-55.9c3.1-19 4.7-38.4 4.7-57.7s-1.6-38.8-4.7-57.7l65.4-55.9a32.03 32.03 0 0 0 9.3-35.2l-.9-2.6a442.5 442.5 0 0 0-79.6-137.7l-1.8-2.1a32.12 32.12 0 0 0-35.1-9.5l-81.2 28.9c-30-24.6-63.4-44-99.6-57.5l-15.7-84.9a32.05 32.05 0 0 0-25.8-25.7l-2.7-.5c-52-9.4-106.8-9.4-158.8 0l-2.7.5a32.05 32.05 0 0 0-25.8 25.7l-15.8 85.3a353.44 353.44 0 0 0-98.9 57.3l-81.8-29.1a32 32 0 0 0-35.1 9.5l-1.8 2.1a445.93 445.93 0 0 0-79.6 137.7l-.9 2.6c-4.5 12.5-.8 26.5 9.3 35.2l66.2 56.5c-3.1 18.8-4.6 38-4.6 57 0 19.2 1.5 38.4 4.6 57l-66 56.5a32.03 32.03 0 0 0-9.3 35.2l.9 2.6c18.1 50.3 44.8 96.8 79.6 137.7l1.8 2.1a32.12 32.12 0 0 0 35.1 9.5l81.8-29.1c29.8 24.5 63 43.9 98.9 57.3l15.8 85.3a32.05 32.05 0 0 0 25.8 25.7l2.7.5a448.27 448.27 0 0 0 158.8 0l2.7-.5a32.05 32.05 0 0 0 25.8-25.7l15.7-84.9c36.2-13.6 69.6-32.9 99.6-57.5l81.2 28.9a32 32 0 0 0 35.1-9.5l1.8-2.1c34.8-41.1 61.5-87.4 79.6-137.7l.9-2.6c4.3-12.4.6-26.3-9.5-35zm-412.3 52.2c-97.1 0-175.8-78.7-175.8-175.8s78.7-175.8 175.8-175.8 175.8 78.7 175.8 175.8-78.7 175.8-175.8 175.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSettingOutlined, "Setting Outlined"))]
 pub static AiSettingOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSettingOutlined",
+    label: "Setting Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10518,8 +14945,15 @@ pub static AiSettingOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M924.8 625.7l-65.5-56c3.1-19 4.7-38.4 4.7-57.8s-1.6-38.8-4.7-57.8l65.5-56a32.03 32.03 0 0 0 9.3-35.2l-.9-2.6a443.74 443.74 0 0 0-79.7-137.9l-1.8-2.1a32.12 32.12 0 0 0-35.1-9.5l-81.3 28.9c-30-24.6-63.5-44-99.7-57.6l-15.7-85a32.05 32.05 0 0 0-25.8-25.7l-2.7-.5c-52.1-9.4-106.9-9.4-159 0l-2.7.5a32.05 32.05 0 0 0-25.8 25.7l-15.8 85.4a351.86 351.86 0 0 0-99 57.4l-81.9-29.1a32 32 0 0 0-35.1 9.5l-1.8 2.1a446.02 446.02 0 0 0-79.7 137.9l-.9 2.6c-4.5 12.5-.8 26.5 9.3 35.2l66.3 56.6c-3.1 18.8-4.6 38-4.6 57.1 0 19.2 1.5 38.4 4.6 57.1L99 625.5a32.03 32.03 0 0 0-9.3 35.2l.9 2.6c18.1 50.4 44.9 96.9 79.7 137.9l1.8 2.1a32.12 32.12 0 0 0 35.1 9.5l81.9-29.1c29.8 24.5 63.1 43.9 99 57.4l15.8 85.4a32.05 32.05 0 0 0 25.8 25.7l2.7.5a449.4 449.4 0 0 0 159 0l2.7-.5a32.05 32.05 0 0 0 25.8-25.7l15.7-85a350 350 0 0 0 99.7-57.6l81.3 28.9a32 32 0 0 0 35.1-9.5l1.8-2.1c34.8-41.1 61.6-87.5 79.7-137.9l.9-2.6c4.5-12.3.8-26.3-9.3-35zM788.3 465.9c2.5 15.1 3.8 30.6 3.8 46.1s-1.3 31-3.8 46.1l-6.6 40.1 74.7 63.9a370.03 370.03 0 0 1-42.6 73.6L721 702.8l-31.4 25.8c-23.9 19.6-50.5 35-79.3 45.8l-38.1 14.3-17.9 97a377.5 377.5 0 0 1-85 0l-17.9-97.2-37.8-14.5c-28.5-10.8-55-26.2-78.7-45.7l-31.4-25.9-93.4 33.2c-17-22.9-31.2-47.6-42.6-73.6l75.5-64.5-6.5-40c-2.4-14.9-3.7-30.3-3.7-45.5 0-15.3 1.2-30.6 3.7-45.5l6.5-40-75.5-64.5c11.3-26.1 25.6-50.7 42.6-73.6l93.4 33.2 31.4-25.9c23.7-19.5 50.2-34.9 78.7-45.7l37.9-14.3 17.9-97.2c28.1-3.2 56.8-3.2 85 0l17.9 97 38.1 14.3c28.7 10.8 55.4 26.2 79.3 45.8l31.4 25.8 92.8-32.9c17 22.9 31.2 47.6 42.6 73.6L781.8 426l6.5 39.9zM512 326c-97.2 0-176 78.8-176 176s78.8 176 176 176 176-78.8 176-176-78.8-176-176-176zm79.2 255.2A111.6 111.6 0 0 1 512 614c-29.9 0-58-11.7-79.2-32.8A111.6 111.6 0 0 1 400 502c0-29.9 11.7-58 32.8-79.2C454 401.6 482.1 390 512 390c29.9 0 58 11.6 79.2 32.8A111.6 111.6 0 0 1 624 502c0 29.9-11.7 58-32.8 79.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSettingTwotone, "Setting Twotone"))]
 pub static AiSettingTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSettingTwotone",
+    label: "Setting Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10537,8 +14971,15 @@ pub static AiSettingTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M594.1 952.2a32.05 32.05 0 0 0 25.8-25.7l15.7-85a350 350 0 0 0 99.7-57.6l81.3 28.9a32 32 0 0 0 35.1-9.5l1.8-2.1c34.8-41.1 61.6-87.5 79.7-137.9l.9-2.6c1.7-4.6 2.2-9.4 1.7-14.1-.9-7.9-4.7-15.4-11-20.9l-65.3-55.9-.2-.1c3.1-19 4.7-38.4 4.7-57.8 0-16.9-1.2-33.9-3.6-50.5-.3-2.2-.7-4.4-1-6.6 0-.2-.1-.5-.1-.7l65.5-56c6.9-5.9 10.9-14.4 11.2-23.2.1-4-.5-8.1-1.9-12l-.9-2.6a443.74 443.74 0 0 0-79.7-137.9l-1.8-2.1a32.12 32.12 0 0 0-35.1-9.5l-81.3 28.9c-30-24.6-63.4-44-99.6-57.6h-.1l-15.7-85c-.1-.5-.2-1.1-.4-1.6a32.08 32.08 0 0 0-25.4-24.1l-2.7-.5c-52.1-9.4-106.9-9.4-159 0l-2.7.5a32.05 32.05 0 0 0-25.8 25.7l-15.8 85.4a351.86 351.86 0 0 0-99 57.4l-81.9-29.1a32 32 0 0 0-35.1 9.5l-1.8 2.1a446.02 446.02 0 0 0-79.7 137.9l-.9 2.6a32.09 32.09 0 0 0 7.9 33.9c.5.4.9.9 1.4 1.3l66.3 56.6v.1c-3.1 18.8-4.6 37.9-4.6 57 0 19.2 1.5 38.4 4.6 57.1L99 625.5a32.03 32.03 0 0 0-9.3 35.2l.9 2.6c18.1 50.4 44.9 96.9 79.7 137.9l1.8 2.1c4.9 5.7 11.4 9.4 18.5 10.7 5.4 1 11.1.7 16.6-1.2l81.9-29.1c.1.1.3.2.4.3 29.7 24.3 62.8 43.6 98.6 57.1l15.8 85.4a32.05 32.05 0 0 0 25.8 25.7l2.7.5c26.1 4.7 52.8 7.1 79.5 7.1h.3c26.6 0 53.3-2.4 79.2-7.1l2.7-.5zm-39.8-66.5a377.5 377.5 0 0 1-85 0l-17.9-97.2-37.8-14.5c-28.5-10.8-55-26.2-78.7-45.7l-31.4-25.9-93.4 33.2c-17-22.9-31.2-47.6-42.6-73.6l75.5-64.5-6.5-40c-2.4-14.9-3.7-30.3-3.7-45.5 0-15.3 1.2-30.6 3.7-45.5l6.5-40-75.5-64.5c11.3-26.1 25.6-50.7 42.6-73.6l93.4 33.2 31.4-25.9c23.7-19.5 50.2-34.9 78.7-45.7l37.9-14.3 17.9-97.2c28.1-3.2 56.8-3.2 85 0l17.9 97 38.1 14.3c28.7 10.8 55.4 26.2 79.3 45.8l31.4 25.8 92.8-32.9c17 22.9 31.2 47.6 42.6 73.6L781.8 426l6.5 39.9c2.5 15.1 3.8 30.6 3.8 46.1s-1.3 31-3.8 46.1l-6.6 40.1 74.7 63.9a370.03 370.03 0 0 1-42.6 73.6L721 702.8l-31.4 25.8c-23.9 19.6-50.5 35-79.3 45.8l-38.1 14.3-17.9 97z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiShakeOutlined, "Shake Outlined"))]
 pub static AiShakeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiShakeOutlined",
+    label: "Shake Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10553,8 +14994,15 @@ pub static AiShakeOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M324 666a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm616.7-309.6L667.6 83.2C655.2 70.9 638.7 64 621.1 64s-34.1 6.8-46.5 19.2L83.3 574.5a65.85 65.85 0 0 0 0 93.1l273.2 273.2c12.3 12.3 28.9 19.2 46.5 19.2s34.1-6.8 46.5-19.2l491.3-491.3c25.6-25.7 25.6-67.5-.1-93.1zM403 880.1L143.9 621l477.2-477.2 259 259.2L403 880.1zM152.8 373.7a7.9 7.9 0 0 0 11.2 0L373.7 164a7.9 7.9 0 0 0 0-11.2l-38.4-38.4a7.9 7.9 0 0 0-11.2 0L114.3 323.9a7.9 7.9 0 0 0 0 11.2l38.5 38.6zm718.6 276.6a7.9 7.9 0 0 0-11.2 0L650.3 860.1a7.9 7.9 0 0 0 0 11.2l38.4 38.4a7.9 7.9 0 0 0 11.2 0L909.7 700a7.9 7.9 0 0 0 0-11.2l-38.3-38.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiShareAltOutlined, "Share Alt Outlined"))]
 pub static AiShareAltOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiShareAltOutlined",
+    label: "Share Alt Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10569,8 +15017,15 @@ pub static AiShareAltOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M752 664c-28.5 0-54.8 10-75.4 26.7L469.4 540.8a160.68 160.68 0 0 0 0-57.6l207.2-149.9C697.2 350 723.5 360 752 360c66.2 0 120-53.8 120-120s-53.8-120-120-120-120 53.8-120 120c0 11.6 1.6 22.7 4.7 33.3L439.9 415.8C410.7 377.1 364.3 352 312 352c-88.4 0-160 71.6-160 160s71.6 160 160 160c52.3 0 98.7-25.1 127.9-63.8l196.8 142.5c-3.1 10.6-4.7 21.8-4.7 33.3 0 66.2 53.8 120 120 120s120-53.8 120-120-53.8-120-120-120zm0-476c28.7 0 52 23.3 52 52s-23.3 52-52 52-52-23.3-52-52 23.3-52 52-52zM312 600c-48.5 0-88-39.5-88-88s39.5-88 88-88 88 39.5 88 88-39.5 88-88 88zm440 236c-28.7 0-52-23.3-52-52s23.3-52 52-52 52 23.3 52 52-23.3 52-52 52z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiShopFilled, "Shop Filled"))]
 pub static AiShopFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiShopFilled",
+    label: "Shop Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10585,8 +15040,15 @@ pub static AiShopFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M882 272.1V144c0-17.7-14.3-32-32-32H174c-17.7 0-32 14.3-32 32v128.1c-16.7 1-30 14.9-30 31.9v131.7a177 177 0 0 0 14.4 70.4c4.3 10.2 9.6 19.8 15.6 28.9v345c0 17.6 14.3 32 32 32h274V736h128v176h274c17.7 0 32-14.3 32-32V535a175 175 0 0 0 15.6-28.9c9.5-22.3 14.4-46 14.4-70.4V304c0-17-13.3-30.9-30-31.9zm-72 568H640V704c0-17.7-14.3-32-32-32H416c-17.7 0-32 14.3-32 32v136.1H214V597.9c2.9 1.4 5.9 2.8 9 4 22.3 9.4 46 14.1 70.4 14.1s48-4.7 70.4-14.1c13.8-5.8 26.8-13.2 38.7-22.1.2-.1.4-.1.6 0a180.4 180.4 0 0 0 38.7 22.1c22.3 9.4 46 14.1 70.4 14.1 24.4 0 48-4.7 70.4-14.1 13.8-5.8 26.8-13.2 38.7-22.1.2-.1.4-.1.6 0a180.4 180.4 0 0 0 38.7 22.1c22.3 9.4 46 14.1 70.4 14.1 24.4 0 48-4.7 70.4-14.1 3-1.3 6-2.6 9-4v242.2zm0-568.1H214v-88h596v88z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiShopOutlined, "Shop Outlined"))]
 pub static AiShopOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiShopOutlined",
+    label: "Shop Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10601,8 +15063,15 @@ pub static AiShopOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M882 272.1V144c0-17.7-14.3-32-32-32H174c-17.7 0-32 14.3-32 32v128.1c-16.7 1-30 14.9-30 31.9v131.7a177 177 0 0 0 14.4 70.4c4.3 10.2 9.6 19.8 15.6 28.9v345c0 17.6 14.3 32 32 32h676c17.7 0 32-14.3 32-32V535a175 175 0 0 0 15.6-28.9c9.5-22.3 14.4-46 14.4-70.4V304c0-17-13.3-30.9-30-31.9zM214 184h596v88H214v-88zm362 656.1H448V736h128v104.1zm234 0H640V704c0-17.7-14.3-32-32-32H416c-17.7 0-32 14.3-32 32v136.1H214V597.9c2.9 1.4 5.9 2.8 9 4 22.3 9.4 46 14.1 70.4 14.1s48-4.7 70.4-14.1c13.8-5.8 26.8-13.2 38.7-22.1.2-.1.4-.1.6 0a180.4 180.4 0 0 0 38.7 22.1c22.3 9.4 46 14.1 70.4 14.1 24.4 0 48-4.7 70.4-14.1 13.8-5.8 26.8-13.2 38.7-22.1.2-.1.4-.1.6 0a180.4 180.4 0 0 0 38.7 22.1c22.3 9.4 46 14.1 70.4 14.1 24.4 0 48-4.7 70.4-14.1 3-1.3 6-2.6 9-4v242.2zm30-404.4c0 59.8-49 108.3-109.3 108.3-40.8 0-76.4-22.1-95.2-54.9-2.9-5-8.1-8.1-13.9-8.1h-.6c-5.7 0-11 3.1-13.9 8.1A109.24 109.24 0 0 1 512 544c-40.7 0-76.2-22-95-54.7-3-5.1-8.4-8.3-14.3-8.3s-11.4 3.2-14.3 8.3a109.63 109.63 0 0 1-95.1 54.7C233 544 184 495.5 184 435.7v-91.2c0-.3.2-.5.5-.5h655c.3 0 .5.2.5.5v91.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiShopTwotone, "Shop Twotone"))]
 pub static AiShopTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiShopTwotone",
+    label: "Shop Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10618,8 +15087,15 @@ pub static AiShopTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M882 272.1V144c0-17.7-14.3-32-32-32H174c-17.7 0-32 14.3-32 32v128.1c-16.7 1-30 14.9-30 31.9v131.7a177 177 0 0 0 14.4 70.4c4.3 10.2 9.6 19.8 15.6 28.9v345c0 17.6 14.3 32 32 32h676c17.7 0 32-14.3 32-32V535a175 175 0 0 0 15.6-28.9c9.5-22.3 14.4-46 14.4-70.4V304c0-17-13.3-30.9-30-31.9zM214 184h596v88H214v-88zm362 656.1H448V736h128v104.1zm234.4 0H640V704c0-17.7-14.3-32-32-32H416c-17.7 0-32 14.3-32 32v136.1H214V597.9c2.9 1.4 5.9 2.8 9 4 22.3 9.4 46 14.1 70.4 14.1 24.4 0 48-4.7 70.4-14.1 13.8-5.8 26.8-13.2 38.7-22.1.2-.1.4-.1.6 0a180.4 180.4 0 0 0 38.7 22.1c22.3 9.4 46 14.1 70.4 14.1s48-4.7 70.4-14.1c13.8-5.8 26.8-13.2 38.7-22.1.2-.1.4-.1.6 0a180.4 180.4 0 0 0 38.7 22.1c22.3 9.4 46 14.1 70.4 14.1s48-4.7 70.4-14.1c3-1.3 6-2.6 9-4v242.2zM840 435.7c0 59.8-49 108.3-109.3 108.3-40.8 0-76.4-22.1-95.2-54.9-2.9-5-8.1-8.1-13.9-8.1h-.6c-5.7 0-11 3.1-13.9 8.1A109.24 109.24 0 0 1 512 544c-40.7 0-76.2-22-95-54.7-3-5.1-8.4-8.3-14.3-8.3s-11.4 3.2-14.3 8.3a109.63 109.63 0 0 1-95.1 54.7C233 544 184 495.5 184 435.7v-91.2c0-.3.2-.5.5-.5h655c.3 0 .5.2.5.5v91.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiShoppingCartOutlined, "Shopping Cart Outlined"))]
 pub static AiShoppingCartOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiShoppingCartOutlined",
+    label: "Shopping Cart Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10634,8 +15110,15 @@ pub static AiShoppingCartOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M922.9 701.9H327.4l29.9-60.9 496.8-.9c16.8 0 31.2-12 34.2-28.6l68.8-385.1c1.8-10.1-.9-20.5-7.5-28.4a34.99 34.99 0 0 0-26.6-12.5l-632-2.1-5.4-25.4c-3.4-16.2-18-28-34.6-28H96.5a35.3 35.3 0 1 0 0 70.6h125.9L246 312.8l58.1 281.3-74.8 122.1a34.96 34.96 0 0 0-3 36.8c6 11.9 18.1 19.4 31.5 19.4h62.8a102.43 102.43 0 0 0-20.6 61.7c0 56.6 46 102.6 102.6 102.6s102.6-46 102.6-102.6c0-22.3-7.4-44-20.6-61.7h161.1a102.43 102.43 0 0 0-20.6 61.7c0 56.6 46 102.6 102.6 102.6s102.6-46 102.6-102.6c0-22.3-7.4-44-20.6-61.7H923c19.4 0 35.3-15.8 35.3-35.3a35.42 35.42 0 0 0-35.4-35.2zM305.7 253l575.8 1.9-56.4 315.8-452.3.8L305.7 253zm96.9 612.7c-17.4 0-31.6-14.2-31.6-31.6 0-17.4 14.2-31.6 31.6-31.6s31.6 14.2 31.6 31.6a31.6 31.6 0 0 1-31.6 31.6zm325.1 0c-17.4 0-31.6-14.2-31.6-31.6 0-17.4 14.2-31.6 31.6-31.6s31.6 14.2 31.6 31.6a31.6 31.6 0 0 1-31.6 31.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiShoppingFilled, "Shopping Filled"))]
 pub static AiShoppingFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiShoppingFilled",
+    label: "Shopping Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10650,8 +15133,15 @@ pub static AiShoppingFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M832 312H696v-16c0-101.6-82.4-184-184-184s-184 82.4-184 184v16H192c-17.7 0-32 14.3-32 32v536c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V344c0-17.7-14.3-32-32-32zm-208 0H400v-16c0-61.9 50.1-112 112-112s112 50.1 112 112v16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiShoppingOutlined, "Shopping Outlined"))]
 pub static AiShoppingOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiShoppingOutlined",
+    label: "Shopping Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10666,8 +15156,15 @@ pub static AiShoppingOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M832 312H696v-16c0-101.6-82.4-184-184-184s-184 82.4-184 184v16H192c-17.7 0-32 14.3-32 32v536c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V344c0-17.7-14.3-32-32-32zm-432-16c0-61.9 50.1-112 112-112s112 50.1 112 112v16H400v-16zm392 544H232V384h96v88c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-88h224v88c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-88h96v456z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiShoppingTwotone, "Shopping Twotone"))]
 pub static AiShoppingTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiShoppingTwotone",
+    label: "Shopping Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10683,8 +15180,15 @@ pub static AiShoppingTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M832 312H696v-16c0-101.6-82.4-184-184-184s-184 82.4-184 184v16H192c-17.7 0-32 14.3-32 32v536c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V344c0-17.7-14.3-32-32-32zm-432-16c0-61.9 50.1-112 112-112s112 50.1 112 112v16H400v-16zm392 544H232V384h96v88c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-88h224v88c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-88h96v456z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiShrinkOutlined, "Shrink Outlined"))]
 pub static AiShrinkOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiShrinkOutlined",
+    label: "Shrink Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10699,8 +15203,15 @@ pub static AiShrinkOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M881.7 187.4l-45.1-45.1a8.03 8.03 0 0 0-11.3 0L667.8 299.9l-54.7-54.7a7.94 7.94 0 0 0-13.5 4.7L576.1 439c-.6 5.2 3.7 9.5 8.9 8.9l189.2-23.5c6.6-.8 9.3-8.8 4.7-13.5l-54.7-54.7 157.6-157.6c3-3 3-8.1-.1-11.2zM439 576.1l-189.2 23.5c-6.6.8-9.3 8.9-4.7 13.5l54.7 54.7-157.5 157.5a8.03 8.03 0 0 0 0 11.3l45.1 45.1c3.1 3.1 8.2 3.1 11.3 0l157.6-157.6 54.7 54.7a7.94 7.94 0 0 0 13.5-4.7L447.9 585a7.9 7.9 0 0 0-8.9-8.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSignalFilled, "Signal Filled"))]
 pub static AiSignalFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSignalFilled",
+    label: "Signal Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10718,8 +15229,15 @@ pub static AiSignalFilled: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M584 352H440c-17.7 0-32 14.3-32 32v544c0 17.7 14.3 32 32 32h144c17.7 0 32-14.3 32-32V384c0-17.7-14.3-32-32-32zM892 64H748c-17.7 0-32 14.3-32 32v832c0 17.7 14.3 32 32 32h144c17.7 0 32-14.3 32-32V96c0-17.7-14.3-32-32-32zM276 640H132c-17.7 0-32 14.3-32 32v256c0 17.7 14.3 32 32 32h144c17.7 0 32-14.3 32-32V672c0-17.7-14.3-32-32-32z" p-id="8306" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSisternodeOutlined, "Sisternode Outlined"))]
 pub static AiSisternodeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSisternodeOutlined",
+    label: "Sisternode Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10737,8 +15255,15 @@ pub static AiSisternodeOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M672 432c-120.3 0-219.9 88.5-237.3 204H320c-15.5 0-28-12.5-28-28V244h291c14.2 35.2 48.7 60 89 60 53 0 96-43 96-96s-43-96-96-96c-40.3 0-74.8 24.8-89 60H112v72h108v364c0 55.2 44.8 100 100 100h114.7c17.4 115.5 117 204 237.3 204 132.5 0 240-107.5 240-240S804.5 432 672 432z m128 266c0 4.4-3.6 8-8 8h-86v86c0 4.4-3.6 8-8 8h-52c-4.4 0-8-3.6-8-8v-86h-86c-4.4 0-8-3.6-8-8v-52c0-4.4 3.6-8 8-8h86v-86c0-4.4 3.6-8 8-8h52c4.4 0 8 3.6 8 8v86h86c4.4 0 8 3.6 8 8v52z" p-id="11883" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSketchCircleFilled, "Sketch Circle Filled"))]
 pub static AiSketchCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSketchCircleFilled",
+    label: "Sketch Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10753,8 +15278,15 @@ pub static AiSketchCircleFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M582.3 625.6l147.9-166.3h-63.4zm90-202.3h62.5l-92.1-115.1zm-274.7 36L512 684.5l114.4-225.2zM512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm286.7 380.2L515.8 762.3c-1 1.1-2.4 1.7-3.8 1.7s-2.8-.6-3.8-1.7L225.3 444.2a5.14 5.14 0 0 1-.2-6.6L365.6 262c1-1.2 2.4-1.9 4-1.9h284.6c1.6 0 3 .7 4 1.9l140.5 175.6a4.9 4.9 0 0 1 0 6.6zm-190.5-20.9L512 326.1l-96.2 97.2zM420.3 301.1l-23.1 89.8 88.8-89.8zm183.4 0H538l88.8 89.8zm-222.4 7.1l-92.1 115.1h62.5zm-87.5 151.1l147.9 166.3-84.5-166.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSketchOutlined, "Sketch Outlined"))]
 pub static AiSketchOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSketchOutlined",
+    label: "Sketch Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10769,8 +15301,15 @@ pub static AiSketchOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M925.6 405.1l-203-253.7a6.5 6.5 0 0 0-5-2.4H306.4c-1.9 0-3.8.9-5 2.4l-203 253.7a6.5 6.5 0 0 0 .2 8.3l408.6 459.5c1.2 1.4 3 2.1 4.8 2.1 1.8 0 3.5-.8 4.8-2.1l408.6-459.5a6.5 6.5 0 0 0 .2-8.3zM645.2 206.4l34.4 133.9-132.5-133.9h98.1zm8.2 178.5H370.6L512 242l141.4 142.9zM378.8 206.4h98.1L344.3 340.3l34.5-133.9zm-53.4 7l-44.1 171.5h-93.1l137.2-171.5zM194.6 434.9H289l125.8 247.7-220.2-247.7zM512 763.4L345.1 434.9h333.7L512 763.4zm97.1-80.8L735 434.9h94.4L609.1 682.6zm133.6-297.7l-44.1-171.5 137.2 171.5h-93.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSketchSquareFilled, "Sketch Square Filled"))]
 pub static AiSketchSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSketchSquareFilled",
+    label: "Sketch Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10785,8 +15324,15 @@ pub static AiSketchSquareFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M608.2 423.3L512 326.1l-96.2 97.2zm-25.9 202.3l147.9-166.3h-63.4zm90-202.3h62.5l-92.1-115.1zM880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-81.3 332.2L515.8 762.3c-1 1.1-2.4 1.7-3.8 1.7s-2.8-.6-3.8-1.7L225.3 444.2a5.14 5.14 0 0 1-.2-6.6L365.6 262c1-1.2 2.4-1.9 4-1.9h284.6c1.6 0 3 .7 4 1.9l140.5 175.6a4.9 4.9 0 0 1 0 6.6zm-401.1 15.1L512 684.5l114.4-225.2zm-16.3-151.1l-92.1 115.1h62.5zm-87.5 151.1l147.9 166.3-84.5-166.3zm126.5-158.2l-23.1 89.8 88.8-89.8zm183.4 0H538l88.8 89.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSkinFilled, "Skin Filled"))]
 pub static AiSkinFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSkinFilled",
+    label: "Skin Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10801,8 +15347,15 @@ pub static AiSkinFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M870 126H663.8c-17.4 0-32.9 11.9-37 29.3C614.3 208.1 567 246 512 246s-102.3-37.9-114.8-90.7a37.93 37.93 0 0 0-37-29.3H154a44 44 0 0 0-44 44v252a44 44 0 0 0 44 44h75v388a44 44 0 0 0 44 44h478a44 44 0 0 0 44-44V466h75a44 44 0 0 0 44-44V170a44 44 0 0 0-44-44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSkinOutlined, "Skin Outlined"))]
 pub static AiSkinOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSkinOutlined",
+    label: "Skin Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10817,8 +15370,15 @@ pub static AiSkinOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M870 126H663.8c-17.4 0-32.9 11.9-37 29.3C614.3 208.1 567 246 512 246s-102.3-37.9-114.8-90.7a37.93 37.93 0 0 0-37-29.3H154a44 44 0 0 0-44 44v252a44 44 0 0 0 44 44h75v388a44 44 0 0 0 44 44h478a44 44 0 0 0 44-44V466h75a44 44 0 0 0 44-44V170a44 44 0 0 0-44-44zm-28 268H723v432H301V394H182V198h153.3c28.2 71.2 97.5 120 176.7 120s148.5-48.8 176.7-120H842v196z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSkinTwotone, "Skin Twotone"))]
 pub static AiSkinTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSkinTwotone",
+    label: "Skin Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10834,8 +15394,15 @@ pub static AiSkinTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M870 126H663.8c-17.4 0-32.9 11.9-37 29.3C614.3 208.1 567 246 512 246s-102.3-37.9-114.8-90.7a37.93 37.93 0 0 0-37-29.3H154a44 44 0 0 0-44 44v252a44 44 0 0 0 44 44h75v388a44 44 0 0 0 44 44h478a44 44 0 0 0 44-44V466h75a44 44 0 0 0 44-44V170a44 44 0 0 0-44-44zm-28 268H723v432H301V394H182V198h153.3c28.2 71.2 97.5 120 176.7 120s148.5-48.8 176.7-120H842v196z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSkypeFilled, "Skype Filled"))]
 pub static AiSkypeFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSkypeFilled",
+    label: "Skype Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10850,8 +15417,15 @@ pub static AiSkypeFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M883.7 578.6c4.1-22.5 6.3-45.5 6.3-68.5 0-51-10-100.5-29.7-147-19-45-46.3-85.4-81-120.1a375.79 375.79 0 0 0-120.1-80.9c-46.6-19.7-96-29.7-147-29.7-24 0-48.1 2.3-71.5 6.8A225.1 225.1 0 0 0 335.6 113c-59.7 0-115.9 23.3-158.1 65.5A222.25 222.25 0 0 0 112 336.6c0 38 9.8 75.4 28.1 108.4-3.7 21.4-5.7 43.3-5.7 65.1 0 51 10 100.5 29.7 147 19 45 46.2 85.4 80.9 120.1 34.7 34.7 75.1 61.9 120.1 80.9 46.6 19.7 96 29.7 147 29.7 22.2 0 44.4-2 66.2-5.9 33.5 18.9 71.3 29 110 29 59.7 0 115.9-23.2 158.1-65.5 42.3-42.2 65.5-98.4 65.5-158.1.1-38-9.7-75.5-28.2-108.7zm-370 162.9c-134.2 0-194.2-66-194.2-115.4 0-25.4 18.7-43.1 44.5-43.1 57.4 0 42.6 82.5 149.7 82.5 54.9 0 85.2-29.8 85.2-60.3 0-18.3-9-38.7-45.2-47.6l-119.4-29.8c-96.1-24.1-113.6-76.1-113.6-124.9 0-101.4 95.5-139.5 185.2-139.5 82.6 0 180 45.7 180 106.5 0 26.1-22.6 41.2-48.4 41.2-49 0-40-67.8-138.7-67.8-49 0-76.1 22.2-76.1 53.9s38.7 41.8 72.3 49.5l88.4 19.6c96.8 21.6 121.3 78.1 121.3 131.3 0 82.3-63.3 143.9-191 143.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSkypeOutlined, "Skype Outlined"))]
 pub static AiSkypeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSkypeOutlined",
+    label: "Skype Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10866,8 +15440,15 @@ pub static AiSkypeOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M883.7 578.6c4.1-22.5 6.3-45.5 6.3-68.5 0-51-10-100.5-29.7-147-19-45-46.3-85.4-81-120.1a375.79 375.79 0 0 0-120.1-80.9c-46.6-19.7-96-29.7-147-29.7-24 0-48.1 2.3-71.5 6.8A225.1 225.1 0 0 0 335.6 113c-59.7 0-115.9 23.3-158.1 65.5A222.25 222.25 0 0 0 112 336.6c0 38 9.8 75.4 28.1 108.4-3.7 21.4-5.7 43.3-5.7 65.1 0 51 10 100.5 29.7 147 19 45 46.2 85.4 80.9 120.1 34.7 34.7 75.1 61.9 120.1 80.9 46.6 19.7 96 29.7 147 29.7 22.2 0 44.4-2 66.2-5.9 33.5 18.9 71.3 29 110 29 59.7 0 115.9-23.2 158.1-65.5 42.3-42.2 65.5-98.4 65.5-158.1.1-38-9.7-75.5-28.2-108.7zm-88.1 216C766.9 823.4 729 839 688.4 839c-26.1 0-51.8-6.8-74.6-19.7l-22.5-12.7-25.5 4.5c-17.8 3.2-35.8 4.8-53.6 4.8-41.4 0-81.3-8.1-119.1-24.1-36.3-15.3-69-37.3-97.2-65.5a304.29 304.29 0 0 1-65.5-97.1c-16-37.7-24-77.6-24-119 0-17.4 1.6-35.2 4.6-52.8l4.4-25.1L203 410a151.02 151.02 0 0 1-19.1-73.4c0-40.6 15.7-78.5 44.4-107.2C257.1 200.7 295 185 335.6 185a153 153 0 0 1 71.4 17.9l22.4 11.8 24.8-4.8c18.9-3.6 38.4-5.5 58-5.5 41.4 0 81.3 8.1 119 24 36.5 15.4 69.1 37.4 97.2 65.5 28.2 28.1 50.2 60.8 65.6 97.2 16 37.7 24 77.6 24 119 0 18.4-1.7 37-5.1 55.5l-4.7 25.5 12.6 22.6c12.6 22.5 19.2 48 19.2 73.7 0 40.7-15.7 78.5-44.4 107.2zM583.4 466.2L495 446.6c-33.6-7.7-72.3-17.8-72.3-49.5s27.1-53.9 76.1-53.9c98.7 0 89.7 67.8 138.7 67.8 25.8 0 48.4-15.2 48.4-41.2 0-60.8-97.4-106.5-180-106.5-89.7 0-185.2 38.1-185.2 139.5 0 48.8 17.4 100.8 113.6 124.9l119.4 29.8c36.1 8.9 45.2 29.2 45.2 47.6 0 30.5-30.3 60.3-85.2 60.3-107.2 0-92.3-82.5-149.7-82.5-25.8 0-44.5 17.8-44.5 43.1 0 49.4 60 115.4 194.2 115.4 127.7 0 191-61.5 191-144 0-53.1-24.5-109.6-121.3-131.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSlackCircleFilled, "Slack Circle Filled"))]
 pub static AiSlackCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSlackCircleFilled",
+    label: "Slack Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10882,8 +15463,15 @@ pub static AiSlackCircleFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zM361.5 580.2c0 27.8-22.5 50.4-50.3 50.4-13.3 0-26.1-5.3-35.6-14.8-9.4-9.5-14.7-22.3-14.7-35.6 0-27.8 22.5-50.4 50.3-50.4h50.3v50.4zm134 134.4c0 27.8-22.5 50.4-50.3 50.4-27.8 0-50.3-22.6-50.3-50.4V580.2c0-27.8 22.5-50.4 50.3-50.4 13.3 0 26.1 5.3 35.6 14.8s14.7 22.3 14.7 35.6v134.4zm-50.2-218.4h-134c-27.8 0-50.3-22.6-50.3-50.4 0-27.8 22.5-50.4 50.3-50.4h134c27.8 0 50.3 22.6 50.3 50.4-.1 27.9-22.6 50.4-50.3 50.4zm0-134.4c-13.3 0-26.1-5.3-35.6-14.8S395 324.8 395 311.4c0-27.8 22.5-50.4 50.3-50.4 27.8 0 50.3 22.6 50.3 50.4v50.4h-50.3zm83.7-50.4c0-27.8 22.5-50.4 50.3-50.4 27.8 0 50.3 22.6 50.3 50.4v134.4c0 27.8-22.5 50.4-50.3 50.4-27.8 0-50.3-22.6-50.3-50.4V311.4zM579.3 765c-27.8 0-50.3-22.6-50.3-50.4v-50.4h50.3c27.8 0 50.3 22.6 50.3 50.4 0 27.8-22.5 50.4-50.3 50.4zm134-134.4h-134c-13.3 0-26.1-5.3-35.6-14.8S529 593.6 529 580.2c0-27.8 22.5-50.4 50.3-50.4h134c27.8 0 50.3 22.6 50.3 50.4 0 27.8-22.5 50.4-50.3 50.4zm0-134.4H663v-50.4c0-27.8 22.5-50.4 50.3-50.4s50.3 22.6 50.3 50.4c0 27.8-22.5 50.4-50.3 50.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSlackOutlined, "Slack Outlined"))]
 pub static AiSlackOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSlackOutlined",
+    label: "Slack Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10898,8 +15486,15 @@ pub static AiSlackOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M409.4 128c-42.4 0-76.7 34.4-76.7 76.8 0 20.3 8.1 39.9 22.4 54.3 14.4 14.4 33.9 22.5 54.3 22.5h76.7v-76.8c0-42.3-34.3-76.7-76.7-76.8zm0 204.8H204.7c-42.4 0-76.7 34.4-76.7 76.8s34.4 76.8 76.7 76.8h204.6c42.4 0 76.7-34.4 76.7-76.8.1-42.4-34.3-76.8-76.6-76.8zM614 486.4c42.4 0 76.8-34.4 76.7-76.8V204.8c0-42.4-34.3-76.8-76.7-76.8-42.4 0-76.7 34.4-76.7 76.8v204.8c0 42.5 34.3 76.8 76.7 76.8zm281.4-76.8c0-42.4-34.4-76.8-76.7-76.8S742 367.2 742 409.6v76.8h76.7c42.3 0 76.7-34.4 76.7-76.8zm-76.8 128H614c-42.4 0-76.7 34.4-76.7 76.8 0 20.3 8.1 39.9 22.4 54.3 14.4 14.4 33.9 22.5 54.3 22.5h204.6c42.4 0 76.7-34.4 76.7-76.8.1-42.4-34.3-76.7-76.7-76.8zM614 742.4h-76.7v76.8c0 42.4 34.4 76.8 76.7 76.8 42.4 0 76.8-34.4 76.7-76.8.1-42.4-34.3-76.7-76.7-76.8zM409.4 537.6c-42.4 0-76.7 34.4-76.7 76.8v204.8c0 42.4 34.4 76.8 76.7 76.8 42.4 0 76.8-34.4 76.7-76.8V614.4c0-20.3-8.1-39.9-22.4-54.3-14.4-14.4-34-22.5-54.3-22.5zM128 614.4c0 20.3 8.1 39.9 22.4 54.3 14.4 14.4 33.9 22.5 54.3 22.5 42.4 0 76.8-34.4 76.7-76.8v-76.8h-76.7c-42.3 0-76.7 34.4-76.7 76.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSlackSquareFilled, "Slack Square Filled"))]
 pub static AiSlackSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSlackSquareFilled",
+    label: "Slack Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10914,8 +15509,15 @@ pub static AiSlackSquareFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM529 311.4c0-27.8 22.5-50.4 50.3-50.4 27.8 0 50.3 22.6 50.3 50.4v134.4c0 27.8-22.5 50.4-50.3 50.4-27.8 0-50.3-22.6-50.3-50.4V311.4zM361.5 580.2c0 27.8-22.5 50.4-50.3 50.4-13.3 0-26.1-5.3-35.6-14.8-9.4-9.5-14.7-22.3-14.7-35.6 0-27.8 22.5-50.4 50.3-50.4h50.3v50.4zm134 134.4c0 27.8-22.5 50.4-50.3 50.4-27.8 0-50.3-22.6-50.3-50.4V580.2c0-27.8 22.5-50.4 50.3-50.4 13.3 0 26.1 5.3 35.6 14.8s14.7 22.3 14.7 35.6v134.4zm-50.2-218.4h-134c-27.8 0-50.3-22.6-50.3-50.4 0-27.8 22.5-50.4 50.3-50.4h134c27.8 0 50.3 22.6 50.3 50.4-.1 27.9-22.6 50.4-50.3 50.4zm0-134.4c-13.3 0-26.1-5.3-35.6-14.8S395 324.8 395 311.4c0-27.8 22.5-50.4 50.3-50.4 27.8 0 50.3 22.6 50.3 50.4v50.4h-50.3zm134 403.2c-27.8 0-50.3-22.6-50.3-50.4v-50.4h50.3c27.8 0 50.3 22.6 50.3 50.4 0 27.8-22.5 50.4-50.3 50.4zm134-134.4h-134c-13.3 0-26.1-5.3-35.6-14.8-9.4-9.5-14.7-22.3-14.7-35.6 0-27.8 22.5-50.4 50.3-50.4h134c27.8 0 50.3 22.6 50.3 50.4 0 27.8-22.5 50.4-50.3 50.4zm0-134.4H663v-50.4c0-27.8 22.5-50.4 50.3-50.4s50.3 22.6 50.3 50.4c0 27.8-22.5 50.4-50.3 50.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSlackSquareOutlined, "Slack Square Outlined"))]
 pub static AiSlackSquareOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSlackSquareOutlined",
+    label: "Slack Square Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10930,8 +15532,15 @@ pub static AiSlackSquareOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM529 311.4c0-27.8 22.5-50.4 50.3-50.4 27.8 0 50.3 22.6 50.3 50.4v134.4c0 27.8-22.5 50.4-50.3 50.4-27.8 0-50.3-22.6-50.3-50.4V311.4zM361.5 580.2c0 27.8-22.5 50.4-50.3 50.4-13.3 0-26.1-5.3-35.6-14.8-9.4-9.5-14.7-22.3-14.7-35.6 0-27.8 22.5-50.4 50.3-50.4h50.3v50.4zm134 134.4c0 27.8-22.5 50.4-50.3 50.4-27.8 0-50.3-22.6-50.3-50.4V580.2c0-27.8 22.5-50.4 50.3-50.4 13.3 0 26.1 5.3 35.6 14.8s14.7 22.3 14.7 35.6v134.4zm-50.2-218.4h-134c-27.8 0-50.3-22.6-50.3-50.4 0-27.8 22.5-50.4 50.3-50.4h134c27.8 0 50.3 22.6 50.3 50.4-.1 27.9-22.6 50.4-50.3 50.4zm0-134.4c-13.3 0-26.1-5.3-35.6-14.8S395 324.8 395 311.4c0-27.8 22.5-50.4 50.3-50.4 27.8 0 50.3 22.6 50.3 50.4v50.4h-50.3zm134 403.2c-27.8 0-50.3-22.6-50.3-50.4v-50.4h50.3c27.8 0 50.3 22.6 50.3 50.4 0 27.8-22.5 50.4-50.3 50.4zm134-134.4h-134c-13.3 0-26.1-5.3-35.6-14.8-9.4-9.5-14.7-22.3-14.7-35.6 0-27.8 22.5-50.4 50.3-50.4h134c27.8 0 50.3 22.6 50.3 50.4 0 27.8-22.5 50.4-50.3 50.4zm0-134.4H663v-50.4c0-27.8 22.5-50.4 50.3-50.4s50.3 22.6 50.3 50.4c0 27.8-22.5 50.4-50.3 50.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSlidersFilled, "Sliders Filled"))]
 pub static AiSlidersFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSlidersFilled",
+    label: "Sliders Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10946,8 +15555,15 @@ pub static AiSlidersFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M904 296h-66v-96c0-4.4-3.6-8-8-8h-52c-4.4 0-8 3.6-8 8v96h-66c-4.4 0-8 3.6-8 8v416c0 4.4 3.6 8 8 8h66v96c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8v-96h66c4.4 0 8-3.6 8-8V304c0-4.4-3.6-8-8-8zm-584-72h-66v-56c0-4.4-3.6-8-8-8h-52c-4.4 0-8 3.6-8 8v56h-66c-4.4 0-8 3.6-8 8v560c0 4.4 3.6 8 8 8h66v56c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8v-56h66c4.4 0 8-3.6 8-8V232c0-4.4-3.6-8-8-8zm292 180h-66V232c0-4.4-3.6-8-8-8h-52c-4.4 0-8 3.6-8 8v172h-66c-4.4 0-8 3.6-8 8v200c0 4.4 3.6 8 8 8h66v172c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8V620h66c4.4 0 8-3.6 8-8V412c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSlidersOutlined, "Sliders Outlined"))]
 pub static AiSlidersOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSlidersOutlined",
+    label: "Sliders Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10962,8 +15578,15 @@ pub static AiSlidersOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M320 224h-66v-56c0-4.4-3.6-8-8-8h-52c-4.4 0-8 3.6-8 8v56h-66c-4.4 0-8 3.6-8 8v560c0 4.4 3.6 8 8 8h66v56c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8v-56h66c4.4 0 8-3.6 8-8V232c0-4.4-3.6-8-8-8zm-60 508h-80V292h80v440zm644-436h-66v-96c0-4.4-3.6-8-8-8h-52c-4.4 0-8 3.6-8 8v96h-66c-4.4 0-8 3.6-8 8v416c0 4.4 3.6 8 8 8h66v96c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8v-96h66c4.4 0 8-3.6 8-8V304c0-4.4-3.6-8-8-8zm-60 364h-80V364h80v296zM612 404h-66V232c0-4.4-3.6-8-8-8h-52c-4.4 0-8 3.6-8 8v172h-66c-4.4 0-8 3.6-8 8v200c0 4.4 3.6 8 8 8h66v172c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8V620h66c4.4 0 8-3.6 8-8V412c0-4.4-3.6-8-8-8zm-60 145a3 3 0 0 1-3 3h-74a3 3 0 0 1-3-3v-74a3 3 0 0 1 3-3h74a3 3 0 0 1 3 3v74z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSlidersTwotone, "Sliders Twotone"))]
 pub static AiSlidersTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSlidersTwotone",
+    label: "Sliders Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10979,8 +15602,15 @@ pub static AiSlidersTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M904 296h-66v-96c0-4.4-3.6-8-8-8h-52c-4.4 0-8 3.6-8 8v96h-66c-4.4 0-8 3.6-8 8v416c0 4.4 3.6 8 8 8h66v96c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8v-96h66c4.4 0 8-3.6 8-8V304c0-4.4-3.6-8-8-8zm-60 364h-80V364h80v296zM612 404h-66V232c0-4.4-3.6-8-8-8h-52c-4.4 0-8 3.6-8 8v172h-66c-4.4 0-8 3.6-8 8v200c0 4.4 3.6 8 8 8h66v172c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8V620h66c4.4 0 8-3.6 8-8V412c0-4.4-3.6-8-8-8zm-60 145a3 3 0 0 1-3 3h-74a3 3 0 0 1-3-3v-74a3 3 0 0 1 3-3h74a3 3 0 0 1 3 3v74zM320 224h-66v-56c0-4.4-3.6-8-8-8h-52c-4.4 0-8 3.6-8 8v56h-66c-4.4 0-8 3.6-8 8v560c0 4.4 3.6 8 8 8h66v56c0 4.4 3.6 8 8 8h52c4.4 0 8-3.6 8-8v-56h66c4.4 0 8-3.6 8-8V232c0-4.4-3.6-8-8-8zm-60 508h-80V292h80v440z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSmallDashOutlined, "Small Dash Outlined"))]
 pub static AiSmallDashOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSmallDashOutlined",
+    label: "Small Dash Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10995,8 +15625,15 @@ pub static AiSmallDashOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M112 476h72v72h-72zm182 0h72v72h-72zm364 0h72v72h-72zm182 0h72v72h-72zm-364 0h72v72h-72z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSmileFilled, "Smile Filled"))]
 pub static AiSmileFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSmileFilled",
+    label: "Smile Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11011,8 +15648,15 @@ pub static AiSmileFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zM288 421a48.01 48.01 0 0 1 96 0 48.01 48.01 0 0 1-96 0zm224 272c-85.5 0-155.6-67.3-160-151.6a8 8 0 0 1 8-8.4h48.1c4.2 0 7.8 3.2 8.1 7.4C420 589.9 461.5 629 512 629s92.1-39.1 95.8-88.6c.3-4.2 3.9-7.4 8.1-7.4H664a8 8 0 0 1 8 8.4C667.6 625.7 597.5 693 512 693zm176-224a48.01 48.01 0 0 1 0-96 48.01 48.01 0 0 1 0 96z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSmileOutlined, "Smile Outlined"))]
 pub static AiSmileOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSmileOutlined",
+    label: "Smile Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11027,8 +15671,15 @@ pub static AiSmileOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M288 421a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm352 0a48 48 0 1 0 96 0 48 48 0 1 0-96 0zM512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm263 711c-34.2 34.2-74 61-118.3 79.8C611 874.2 562.3 884 512 884c-50.3 0-99-9.8-144.8-29.2A370.4 370.4 0 0 1 248.9 775c-34.2-34.2-61-74-79.8-118.3C149.8 611 140 562.3 140 512s9.8-99 29.2-144.8A370.4 370.4 0 0 1 249 248.9c34.2-34.2 74-61 118.3-79.8C413 149.8 461.7 140 512 140c50.3 0 99 9.8 144.8 29.2A370.4 370.4 0 0 1 775.1 249c34.2 34.2 61 74 79.8 118.3C874.2 413 884 461.7 884 512s-9.8 99-29.2 144.8A368.89 368.89 0 0 1 775 775zM664 533h-48.1c-4.2 0-7.8 3.2-8.1 7.4C604 589.9 562.5 629 512 629s-92.1-39.1-95.8-88.6c-.3-4.2-3.9-7.4-8.1-7.4H360a8 8 0 0 0-8 8.4c4.4 84.3 74.5 151.6 160 151.6s155.6-67.3 160-151.6a8 8 0 0 0-8-8.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSmileTwotone, "Smile Twotone"))]
 pub static AiSmileTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSmileTwotone",
+    label: "Smile Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11045,8 +15696,15 @@ pub static AiSmileTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M288 421a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm376 112h-48.1c-4.2 0-7.8 3.2-8.1 7.4-3.7 49.5-45.3 88.6-95.8 88.6s-92-39.1-95.8-88.6c-.3-4.2-3.9-7.4-8.1-7.4H360a8 8 0 0 0-8 8.4c4.4 84.3 74.5 151.6 160 151.6s155.6-67.3 160-151.6a8 8 0 0 0-8-8.4zm-24-112a48 48 0 1 0 96 0 48 48 0 1 0-96 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSnippetsFilled, "Snippets Filled"))]
 pub static AiSnippetsFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSnippetsFilled",
+    label: "Snippets Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11061,8 +15719,15 @@ pub static AiSnippetsFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M832 112H724V72c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v40H500V72c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v40H320c-17.7 0-32 14.3-32 32v120h-96c-17.7 0-32 14.3-32 32v632c0 17.7 14.3 32 32 32h512c17.7 0 32-14.3 32-32v-96h96c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM664 486H514V336h.2L664 485.8v.2zm128 274h-56V456L544 264H360v-80h68v32c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-32h152v32c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-32h68v576z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSnippetsOutlined, "Snippets Outlined"))]
 pub static AiSnippetsOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSnippetsOutlined",
+    label: "Snippets Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11077,8 +15742,15 @@ pub static AiSnippetsOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M832 112H724V72c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v40H500V72c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v40H320c-17.7 0-32 14.3-32 32v120h-96c-17.7 0-32 14.3-32 32v632c0 17.7 14.3 32 32 32h512c17.7 0 32-14.3 32-32v-96h96c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM664 888H232V336h218v174c0 22.1 17.9 40 40 40h174v338zm0-402H514V336h.2L664 485.8v.2zm128 274h-56V456L544 264H360v-80h68v32c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-32h152v32c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-32h68v576z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSnippetsTwotone, "Snippets Twotone"))]
 pub static AiSnippetsTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSnippetsTwotone",
+    label: "Snippets Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11094,8 +15766,15 @@ pub static AiSnippetsTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M832 112H724V72c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v40H500V72c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v40H320c-17.7 0-32 14.3-32 32v120h-96c-17.7 0-32 14.3-32 32v632c0 17.7 14.3 32 32 32h512c17.7 0 32-14.3 32-32v-96h96c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM664 888H232V336h218v174c0 22.1 17.9 40 40 40h174v338zm0-402H514V336h.2L664 485.8v.2zm128 274h-56V456L544 264H360v-80h68v32c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-32h152v32c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-32h68v576z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSolutionOutlined, "Solution Outlined"))]
 pub static AiSolutionOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSolutionOutlined",
+    label: "Solution Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11110,8 +15789,15 @@ pub static AiSolutionOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M688 264c0-4.4-3.6-8-8-8H296c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h384c4.4 0 8-3.6 8-8v-48zm-8 136H296c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h384c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zM480 544H296c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h184c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zm-48 308H208V148h560v344c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V108c0-17.7-14.3-32-32-32H168c-17.7 0-32 14.3-32 32v784c0 17.7 14.3 32 32 32h264c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm356.8-74.4c29-26.3 47.2-64.3 47.2-106.6 0-79.5-64.5-144-144-144s-144 64.5-144 144c0 42.3 18.2 80.3 47.2 106.6-57 32.5-96.2 92.7-99.2 162.1-.2 4.5 3.5 8.3 8 8.3h48.1c4.2 0 7.7-3.3 8-7.6C564 871.2 621.7 816 692 816s128 55.2 131.9 124.4c.2 4.2 3.7 7.6 8 7.6H880c4.6 0 8.2-3.8 8-8.3-2.9-69.5-42.2-129.6-99.2-162.1zM692 591c44.2 0 80 35.8 80 80s-35.8 80-80 80-80-35.8-80-80 35.8-80 80-80z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSortAscendingOutlined, "Sort Ascending Outlined"))]
 pub static AiSortAscendingOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSortAscendingOutlined",
+    label: "Sort Ascending Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11126,8 +15812,15 @@ pub static AiSortAscendingOutlined: &icondata_core::IconData = &icondata_core::I
     data: r###"<path d="M839.6 433.8L749 150.5a9.24 9.24 0 0 0-8.9-6.5h-77.4c-4.1 0-7.6 2.6-8.9 6.5l-91.3 283.3c-.3.9-.5 1.9-.5 2.9 0 5.1 4.2 9.3 9.3 9.3h56.4c4.2 0 7.8-2.8 9-6.8l17.5-61.6h89l17.3 61.5c1.1 4 4.8 6.8 9 6.8h61.2c1 0 1.9-.1 2.8-.4 2.4-.8 4.3-2.4 5.5-4.6 1.1-2.2 1.3-4.7.6-7.1zM663.3 325.5l32.8-116.9h6.3l32.1 116.9h-71.2zm143.5 492.9H677.2v-.4l132.6-188.9c1.1-1.6 1.7-3.4 1.7-5.4v-36.4c0-5.1-4.2-9.3-9.3-9.3h-204c-5.1 0-9.3 4.2-9.3 9.3v43c0 5.1 4.2 9.3 9.3 9.3h122.6v.4L587.7 828.9a9.35 9.35 0 0 0-1.7 5.4v36.4c0 5.1 4.2 9.3 9.3 9.3h211.4c5.1 0 9.3-4.2 9.3-9.3v-43a9.2 9.2 0 0 0-9.2-9.3zM416 702h-76V172c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v530h-76c-6.7 0-10.5 7.8-6.3 13l112 141.9a8 8 0 0 0 12.6 0l112-141.9c4.1-5.2.4-13-6.3-13z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSortDescendingOutlined, "Sort Descending Outlined"))]
 pub static AiSortDescendingOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSortDescendingOutlined",
+    label: "Sort Descending Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11142,8 +15835,15 @@ pub static AiSortDescendingOutlined: &icondata_core::IconData = &icondata_core::
     data: r###"<path d="M839.6 433.8L749 150.5a9.24 9.24 0 0 0-8.9-6.5h-77.4c-4.1 0-7.6 2.6-8.9 6.5l-91.3 283.3c-.3.9-.5 1.9-.5 2.9 0 5.1 4.2 9.3 9.3 9.3h56.4c4.2 0 7.8-2.8 9-6.8l17.5-61.6h89l17.3 61.5c1.1 4 4.8 6.8 9 6.8h61.2c1 0 1.9-.1 2.8-.4 2.4-.8 4.3-2.4 5.5-4.6 1.1-2.2 1.3-4.7.6-7.1zM663.3 325.5l32.8-116.9h6.3l32.1 116.9h-71.2zm143.5 492.9H677.2v-.4l132.6-188.9c1.1-1.6 1.7-3.4 1.7-5.4v-36.4c0-5.1-4.2-9.3-9.3-9.3h-204c-5.1 0-9.3 4.2-9.3 9.3v43c0 5.1 4.2 9.3 9.3 9.3h122.6v.4L587.7 828.9a9.35 9.35 0 0 0-1.7 5.4v36.4c0 5.1 4.2 9.3 9.3 9.3h211.4c5.1 0 9.3-4.2 9.3-9.3v-43a9.2 9.2 0 0 0-9.2-9.3zM310.3 167.1a8 8 0 0 0-12.6 0L185.7 309c-4.2 5.3-.4 13 6.3 13h76v530c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V322h76c6.7 0 10.5-7.8 6.3-13l-112-141.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSoundFilled, "Sound Filled"))]
 pub static AiSoundFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSoundFilled",
+    label: "Sound Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11158,8 +15858,15 @@ pub static AiSoundFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M892.1 737.8l-110.3-63.7a15.9 15.9 0 0 0-21.7 5.9l-19.9 34.5c-4.4 7.6-1.8 17.4 5.8 21.8L856.3 800a15.9 15.9 0 0 0 21.7-5.9l19.9-34.5c4.4-7.6 1.7-17.4-5.8-21.8zM760 344a15.9 15.9 0 0 0 21.7 5.9L892 286.2c7.6-4.4 10.2-14.2 5.8-21.8L878 230a15.9 15.9 0 0 0-21.7-5.9L746 287.8a15.99 15.99 0 0 0-5.8 21.8L760 344zm174 132H806c-8.8 0-16 7.2-16 16v40c0 8.8 7.2 16 16 16h128c8.8 0 16-7.2 16-16v-40c0-8.8-7.2-16-16-16zM625.9 115c-5.9 0-11.9 1.6-17.4 5.3L254 352H90c-8.8 0-16 7.2-16 16v288c0 8.8 7.2 16 16 16h164l354.5 231.7c5.5 3.6 11.6 5.3 17.4 5.3 16.7 0 32.1-13.3 32.1-32.1V147.1c0-18.8-15.4-32.1-32.1-32.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSoundOutlined, "Sound Outlined"))]
 pub static AiSoundOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSoundOutlined",
+    label: "Sound Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11174,8 +15881,15 @@ pub static AiSoundOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M625.9 115c-5.9 0-11.9 1.6-17.4 5.3L254 352H90c-8.8 0-16 7.2-16 16v288c0 8.8 7.2 16 16 16h164l354.5 231.7c5.5 3.6 11.6 5.3 17.4 5.3 16.7 0 32.1-13.3 32.1-32.1V147.1c0-18.8-15.4-32.1-32.1-32.1zM586 803L293.4 611.7l-18-11.7H146V424h129.4l17.9-11.7L586 221v582zm348-327H806c-8.8 0-16 7.2-16 16v40c0 8.8 7.2 16 16 16h128c8.8 0 16-7.2 16-16v-40c0-8.8-7.2-16-16-16zm-41.9 261.8l-110.3-63.7a15.9 15.9 0 0 0-21.7 5.9l-19.9 34.5c-4.4 7.6-1.8 17.4 5.8 21.8L856.3 800a15.9 15.9 0 0 0 21.7-5.9l19.9-34.5c4.4-7.6 1.7-17.4-5.8-21.8zM760 344a15.9 15.9 0 0 0 21.7 5.9L892 286.2c7.6-4.4 10.2-14.2 5.8-21.8L878 230a15.9 15.9 0 0 0-21.7-5.9L746 287.8a15.99 15.99 0 0 0-5.8 21.8L760 344z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSoundTwotone, "Sound Twotone"))]
 pub static AiSoundTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSoundTwotone",
+    label: "Sound Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11191,8 +15905,15 @@ pub static AiSoundTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M892.1 737.8l-110.3-63.7a15.9 15.9 0 0 0-21.7 5.9l-19.9 34.5c-4.4 7.6-1.8 17.4 5.8 21.8L856.3 800a15.9 15.9 0 0 0 21.7-5.9l19.9-34.5c4.4-7.6 1.7-17.4-5.8-21.8zM934 476H806c-8.8 0-16 7.2-16 16v40c0 8.8 7.2 16 16 16h128c8.8 0 16-7.2 16-16v-40c0-8.8-7.2-16-16-16zM760 344a15.9 15.9 0 0 0 21.7 5.9L892 286.2c7.6-4.4 10.2-14.2 5.8-21.8L878 230a15.9 15.9 0 0 0-21.7-5.9L746 287.8a15.99 15.99 0 0 0-5.8 21.8L760 344zM625.9 115c-5.9 0-11.9 1.6-17.4 5.3L254 352H90c-8.8 0-16 7.2-16 16v288c0 8.8 7.2 16 16 16h164l354.5 231.7c5.5 3.6 11.6 5.3 17.4 5.3 16.7 0 32.1-13.3 32.1-32.1V147.1c0-18.8-15.4-32.1-32.1-32.1zM586 803L293.4 611.7l-18-11.7H146V424h129.4l17.9-11.7L586 221v582z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSplitCellsOutlined, "Split Cells Outlined"))]
 pub static AiSplitCellsOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSplitCellsOutlined",
+    label: "Split Cells Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11210,8 +15931,15 @@ pub static AiSplitCellsOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M938.2 508.4L787.3 389c-3-2.4-7.3-0.2-7.3 3.6V478H636V184h204v128c0 2.2 1.8 4 4 4h60c2.2 0 4-1.8 4-4V144c0-15.5-12.5-28-28-28H596c-15.5 0-28 12.5-28 28v736c0 15.5 12.5 28 28 28h284c15.5 0 28-12.5 28-28V712c0-2.2-1.8-4-4-4h-60c-2.2 0-4 1.8-4 4v128H636V546h144v85.4c0 3.8 4.4 6 7.3 3.6l150.9-119.4c2.4-1.8 2.4-5.4 0-7.2zM428 116H144c-15.5 0-28 12.5-28 28v168c0 2.2 1.8 4 4 4h60c2.2 0 4-1.8 4-4V184h204v294H244v-85.4c0-3.8-4.3-6-7.3-3.6l-151 119.4c-2.3 1.8-2.3 5.3 0 7.1l151 119.5c2.9 2.3 7.3 0.2 7.3-3.6V546h144v294H184V712c0-2.2-1.8-4-4-4h-60c-2.2 0-4 1.8-4 4v168c0 15.5 12.5 28 28 28h284c15.5 0 28-12.5 28-28V144c0-15.5-12.5-28-28-28z" p-id="11056" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiStarFilled, "Star Filled"))]
 pub static AiStarFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiStarFilled",
+    label: "Star Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11226,8 +15954,15 @@ pub static AiStarFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M908.1 353.1l-253.9-36.9L540.7 86.1c-3.1-6.3-8.2-11.4-14.5-14.5-15.8-7.8-35-1.3-42.9 14.5L369.8 316.2l-253.9 36.9c-7 1-13.4 4.3-18.3 9.3a32.05 32.05 0 0 0 .6 45.3l183.7 179.1-43.4 252.9a31.95 31.95 0 0 0 46.4 33.7L512 754l227.1 119.4c6.2 3.3 13.4 4.4 20.3 3.2 17.4-3 29.1-19.5 26.1-36.9l-43.4-252.9 183.7-179.1c5-4.9 8.3-11.3 9.3-18.3 2.7-17.5-9.5-33.7-27-36.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiStarOutlined, "Star Outlined"))]
 pub static AiStarOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiStarOutlined",
+    label: "Star Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11242,8 +15977,15 @@ pub static AiStarOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M908.1 353.1l-253.9-36.9L540.7 86.1c-3.1-6.3-8.2-11.4-14.5-14.5-15.8-7.8-35-1.3-42.9 14.5L369.8 316.2l-253.9 36.9c-7 1-13.4 4.3-18.3 9.3a32.05 32.05 0 0 0 .6 45.3l183.7 179.1-43.4 252.9a31.95 31.95 0 0 0 46.4 33.7L512 754l227.1 119.4c6.2 3.3 13.4 4.4 20.3 3.2 17.4-3 29.1-19.5 26.1-36.9l-43.4-252.9 183.7-179.1c5-4.9 8.3-11.3 9.3-18.3 2.7-17.5-9.5-33.7-27-36.3zM664.8 561.6l36.1 210.3L512 672.7 323.1 772l36.1-210.3-152.8-149L417.6 382 512 190.7 606.4 382l211.2 30.7-152.8 148.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiStarTwotone, "Star Twotone"))]
 pub static AiStarTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiStarTwotone",
+    label: "Star Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11259,8 +16001,15 @@ pub static AiStarTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M908.6 352.8l-253.9-36.9L541.2 85.8c-3.1-6.3-8.2-11.4-14.5-14.5-15.8-7.8-35-1.3-42.9 14.5L370.3 315.9l-253.9 36.9c-7 1-13.4 4.3-18.3 9.3a32.05 32.05 0 0 0 .6 45.3l183.7 179.1L239 839.4a31.95 31.95 0 0 0 46.4 33.7l227.1-119.4 227.1 119.4c6.2 3.3 13.4 4.4 20.3 3.2 17.4-3 29.1-19.5 26.1-36.9l-43.4-252.9 183.7-179.1c5-4.9 8.3-11.3 9.3-18.3 2.7-17.5-9.5-33.7-27-36.3zM665.3 561.3l36.1 210.3-188.9-99.2-188.9 99.3 36.1-210.3-152.8-149 211.2-30.7 94.4-191.3 94.4 191.3 211.2 30.7-152.8 148.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiStepBackwardFilled, "Step Backward Filled"))]
 pub static AiStepBackwardFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiStepBackwardFilled",
+    label: "Step Backward Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11275,8 +16024,15 @@ pub static AiStepBackwardFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M347.6 528.95l383.2 301.02c14.25 11.2 35.2 1.1 35.2-16.95V210.97c0-18.05-20.95-28.14-35.2-16.94L347.6 495.05a21.53 21.53 0 0 0 0 33.9M330 864h-64a8 8 0 0 1-8-8V168a8 8 0 0 1 8-8h64a8 8 0 0 1 8 8v688a8 8 0 0 1-8 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiStepBackwardOutlined, "Step Backward Outlined"))]
 pub static AiStepBackwardOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiStepBackwardOutlined",
+    label: "Step Backward Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11291,8 +16047,15 @@ pub static AiStepBackwardOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M347.6 528.95l383.2 301.02c14.25 11.2 35.2 1.1 35.2-16.95V210.97c0-18.05-20.95-28.14-35.2-16.94L347.6 495.05a21.53 21.53 0 0 0 0 33.9M330 864h-64a8 8 0 0 1-8-8V168a8 8 0 0 1 8-8h64a8 8 0 0 1 8 8v688a8 8 0 0 1-8 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiStepForwardFilled, "Step Forward Filled"))]
 pub static AiStepForwardFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiStepForwardFilled",
+    label: "Step Forward Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11307,8 +16070,15 @@ pub static AiStepForwardFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M676.4 528.95L293.2 829.97c-14.25 11.2-35.2 1.1-35.2-16.95V210.97c0-18.05 20.95-28.14 35.2-16.94l383.2 301.02a21.53 21.53 0 0 1 0 33.9M694 864h64a8 8 0 0 0 8-8V168a8 8 0 0 0-8-8h-64a8 8 0 0 0-8 8v688a8 8 0 0 0 8 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiStepForwardOutlined, "Step Forward Outlined"))]
 pub static AiStepForwardOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiStepForwardOutlined",
+    label: "Step Forward Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11323,8 +16093,15 @@ pub static AiStepForwardOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M676.4 528.95L293.2 829.97c-14.25 11.2-35.2 1.1-35.2-16.95V210.97c0-18.05 20.95-28.14 35.2-16.94l383.2 301.02a21.53 21.53 0 0 1 0 33.9M694 864h64a8 8 0 0 0 8-8V168a8 8 0 0 0-8-8h-64a8 8 0 0 0-8 8v688a8 8 0 0 0 8 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiStockOutlined, "Stock Outlined"))]
 pub static AiStockOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiStockOutlined",
+    label: "Stock Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11339,8 +16116,15 @@ pub static AiStockOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M904 747H120c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h784c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM165.7 621.8l39.7 39.5c3.1 3.1 8.2 3.1 11.3 0l234.7-233.9 97.6 97.3a32.11 32.11 0 0 0 45.2 0l264.2-263.2c3.1-3.1 3.1-8.2 0-11.3l-39.7-39.6a8.03 8.03 0 0 0-11.3 0l-235.7 235-97.7-97.3a32.11 32.11 0 0 0-45.2 0L165.7 610.5a7.94 7.94 0 0 0 0 11.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiStopFilled, "Stop Filled"))]
 pub static AiStopFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiStopFilled",
+    label: "Stop Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11355,8 +16139,15 @@ pub static AiStopFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm234.8 736.5L223.5 277.2c16-19.7 34-37.7 53.7-53.7l523.3 523.3c-16 19.6-34 37.7-53.7 53.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiStopOutlined, "Stop Outlined"))]
 pub static AiStopOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiStopOutlined",
+    label: "Stop Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11371,8 +16162,15 @@ pub static AiStopOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372 0-89 31.3-170.8 83.5-234.8l523.3 523.3C682.8 852.7 601 884 512 884zm288.5-137.2L277.2 223.5C341.2 171.3 423 140 512 140c205.4 0 372 166.6 372 372 0 89-31.3 170.8-83.5 234.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiStopTwotone, "Stop Twotone"))]
 pub static AiStopTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiStopTwotone",
+    label: "Stop Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11388,8 +16186,15 @@ pub static AiStopTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path fill="#E6E6E6" d="M512 140c-205.4 0-372 166.6-372 372s166.6 372 372 372c89 0 170.8-31.3 234.8-83.5L224 277.7c16-19.7 34-37.7 53.7-53.7l522.8 522.8C852.7 682.8 884 601 884 512c0-205.4-166.6-372-372-372z" class="twotone-icon-secondary-color" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiStrikethroughOutlined, "Strikethrough Outlined"))]
 pub static AiStrikethroughOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiStrikethroughOutlined",
+    label: "Strikethrough Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11404,8 +16209,15 @@ pub static AiStrikethroughOutlined: &icondata_core::IconData = &icondata_core::I
     data: r###"<path d="M952 474H569.9c-10-2-20.5-4-31.6-6-15.9-2.9-22.2-4.1-30.8-5.8-51.3-10-82.2-20-106.8-34.2-35.1-20.5-52.2-48.3-52.2-85.1 0-37 15.2-67.7 44-89 28.4-21 68.8-32.1 116.8-32.1 54.8 0 97.1 14.4 125.8 42.8 14.6 14.4 25.3 32.1 31.8 52.6 1.3 4.1 2.8 10 4.3 17.8.9 4.8 5.2 8.2 9.9 8.2h72.8c5.6 0 10.1-4.6 10.1-10.1v-1c-.7-6.8-1.3-12.1-2-16-7.3-43.5-28-81.7-59.7-110.3-44.4-40.5-109.7-61.8-188.7-61.8-72.3 0-137.4 18.1-183.3 50.9-25.6 18.4-45.4 41.2-58.6 67.7-13.5 27.1-20.3 58.4-20.3 92.9 0 29.5 5.7 54.5 17.3 76.5 8.3 15.7 19.6 29.5 34.1 42H72c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h433.2c2.1.4 3.9.8 5.9 1.2 30.9 6.2 49.5 10.4 66.6 15.2 23 6.5 40.6 13.3 55.2 21.5 35.8 20.2 53.3 49.2 53.3 89 0 35.3-15.5 66.8-43.6 88.8-30.5 23.9-75.6 36.4-130.5 36.4-43.7 0-80.7-8.5-110.2-25-29.1-16.3-49.1-39.8-59.7-69.5-.8-2.2-1.7-5.2-2.7-9-1.2-4.4-5.3-7.5-9.7-7.5h-79.7c-5.6 0-10.1 4.6-10.1 10.1v1c.2 2.3.4 4.2.6 5.7 6.5 48.8 30.3 88.8 70.7 118.8 47.1 34.8 113.4 53.2 191.8 53.2 84.2 0 154.8-19.8 204.2-57.3 25-18.9 44.2-42.2 57.1-69 13-27.1 19.7-57.9 19.7-91.5 0-31.8-5.8-58.4-17.8-81.4-5.8-11.2-13.1-21.5-21.8-30.8H952c4.4 0 8-3.6 8-8v-60a8 8 0 0 0-8-7.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSubnodeOutlined, "Subnode Outlined"))]
 pub static AiSubnodeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSubnodeOutlined",
+    label: "Subnode Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11423,8 +16235,15 @@ pub static AiSubnodeOutlined: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M688 240c-138 0-252 102.8-269.6 236H249c-14.2-35.2-48.7-60-89-60-53 0-96 43-96 96s43 96 96 96c40.3 0 74.8-24.8 89-60h169.3C436 681.2 550 784 688 784c150.2 0 272-121.8 272-272S838.2 240 688 240z m128 298c0 4.4-3.6 8-8 8h-86v86c0 4.4-3.6 8-8 8h-52c-4.4 0-8-3.6-8-8v-86h-86c-4.4 0-8-3.6-8-8v-52c0-4.4 3.6-8 8-8h86v-86c0-4.4 3.6-8 8-8h52c4.4 0 8 3.6 8 8v86h86c4.4 0 8 3.6 8 8v52z" p-id="10346" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSwapLeftOutlined, "Swap Left Outlined"))]
 pub static AiSwapLeftOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSwapLeftOutlined",
+    label: "Swap Left Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11439,8 +16258,15 @@ pub static AiSwapLeftOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M872 572H266.8l144.3-183c4.1-5.2.4-13-6.3-13H340c-9.8 0-19.1 4.5-25.1 12.2l-164 208c-16.5 21-1.6 51.8 25.1 51.8h696c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSwapOutlined, "Swap Outlined"))]
 pub static AiSwapOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSwapOutlined",
+    label: "Swap Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11455,8 +16281,15 @@ pub static AiSwapOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M847.9 592H152c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h605.2L612.9 851c-4.1 5.2-.4 13 6.3 13h72.5c4.9 0 9.5-2.2 12.6-6.1l168.8-214.1c16.5-21 1.6-51.8-25.2-51.8zM872 356H266.8l144.3-183c4.1-5.2.4-13-6.3-13h-72.5c-4.9 0-9.5 2.2-12.6 6.1L150.9 380.2c-16.5 21-1.6 51.8 25.1 51.8h696c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSwapRightOutlined, "Swap Right Outlined"))]
 pub static AiSwapRightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSwapRightOutlined",
+    label: "Swap Right Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11471,8 +16304,15 @@ pub static AiSwapRightOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M873.1 596.2l-164-208A32 32 0 0 0 684 376h-64.8c-6.7 0-10.4 7.7-6.3 13l144.3 183H152c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h695.9c26.8 0 41.7-30.8 25.2-51.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSwitcherFilled, "Switcher Filled"))]
 pub static AiSwitcherFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSwitcherFilled",
+    label: "Switcher Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11487,8 +16327,15 @@ pub static AiSwitcherFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M752 240H144c-17.7 0-32 14.3-32 32v608c0 17.7 14.3 32 32 32h608c17.7 0 32-14.3 32-32V272c0-17.7-14.3-32-32-32zM596 606c0 4.4-3.6 8-8 8H308c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h280c4.4 0 8 3.6 8 8v48zm284-494H264c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h576v576c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V144c0-17.7-14.3-32-32-32z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSwitcherOutlined, "Switcher Outlined"))]
 pub static AiSwitcherOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSwitcherOutlined",
+    label: "Switcher Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11503,8 +16350,15 @@ pub static AiSwitcherOutlined: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M752 240H144c-17.7 0-32 14.3-32 32v608c0 17.7 14.3 32 32 32h608c17.7 0 32-14.3 32-32V272c0-17.7-14.3-32-32-32zm-40 600H184V312h528v528zm168-728H264c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h576v576c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V144c0-17.7-14.3-32-32-32zM300 550h296v64H300z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSwitcherTwotone, "Switcher Twotone"))]
 pub static AiSwitcherTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSwitcherTwotone",
+    label: "Switcher Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11522,8 +16376,15 @@ pub static AiSwitcherTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M300 550h296v64H300z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiSyncOutlined, "Sync Outlined"))]
 pub static AiSyncOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiSyncOutlined",
+    label: "Sync Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11538,8 +16399,15 @@ pub static AiSyncOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M168 504.2c1-43.7 10-86.1 26.9-126 17.3-41 42.1-77.7 73.7-109.4S337 212.3 378 195c42.4-17.9 87.4-27 133.9-27s91.5 9.1 133.8 27A341.5 341.5 0 0 1 755 268.8c9.9 9.9 19.2 20.4 27.8 31.4l-60.2 47a8 8 0 0 0 3 14.1l175.7 43c5 1.2 9.9-2.6 9.9-7.7l.8-180.9c0-6.7-7.7-10.5-12.9-6.3l-56.4 44.1C765.8 155.1 646.2 92 511.8 92 282.7 92 96.3 275.6 92 503.8a8 8 0 0 0 8 8.2h60c4.4 0 7.9-3.5 8-7.8zm756 7.8h-60c-4.4 0-7.9 3.5-8 7.8-1 43.7-10 86.1-26.9 126-17.3 41-42.1 77.8-73.7 109.4A342.45 342.45 0 0 1 512.1 856a342.24 342.24 0 0 1-243.2-100.8c-9.9-9.9-19.2-20.4-27.8-31.4l60.2-47a8 8 0 0 0-3-14.1l-175.7-43c-5-1.2-9.9 2.6-9.9 7.7l-.7 181c0 6.7 7.7 10.5 12.9 6.3l56.4-44.1C258.2 868.9 377.8 932 512.2 932c229.2 0 415.5-183.7 419.8-411.8a8 8 0 0 0-8-8.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTableOutlined, "Table Outlined"))]
 pub static AiTableOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTableOutlined",
+    label: "Table Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11554,8 +16422,15 @@ pub static AiTableOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M928 160H96c-17.7 0-32 14.3-32 32v640c0 17.7 14.3 32 32 32h832c17.7 0 32-14.3 32-32V192c0-17.7-14.3-32-32-32zm-40 208H676V232h212v136zm0 224H676V432h212v160zM412 432h200v160H412V432zm200-64H412V232h200v136zm-476 64h212v160H136V432zm0-200h212v136H136V232zm0 424h212v136H136V656zm276 0h200v136H412V656zm476 136H676V656h212v136z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTabletFilled, "Tablet Filled"))]
 pub static AiTabletFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTabletFilled",
+    label: "Tablet Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11570,8 +16445,15 @@ pub static AiTabletFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M800 64H224c-35.3 0-64 28.7-64 64v768c0 35.3 28.7 64 64 64h576c35.3 0 64-28.7 64-64V128c0-35.3-28.7-64-64-64zM512 824c-22.1 0-40-17.9-40-40s17.9-40 40-40 40 17.9 40 40-17.9 40-40 40z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTabletOutlined, "Tablet Outlined"))]
 pub static AiTabletOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTabletOutlined",
+    label: "Tablet Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11586,8 +16468,15 @@ pub static AiTabletOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M800 64H224c-35.3 0-64 28.7-64 64v768c0 35.3 28.7 64 64 64h576c35.3 0 64-28.7 64-64V128c0-35.3-28.7-64-64-64zm-8 824H232V136h560v752zM472 784a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTabletTwotone, "Tablet Twotone"))]
 pub static AiTabletTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTabletTwotone",
+    label: "Tablet Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11604,8 +16493,15 @@ pub static AiTabletTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M472 784a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTagFilled, "Tag Filled"))]
 pub static AiTagFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTagFilled",
+    label: "Tag Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11620,8 +16516,15 @@ pub static AiTagFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M938 458.8l-29.6-312.6c-1.5-16.2-14.4-29-30.6-30.6L565.2 86h-.4c-3.2 0-5.7 1-7.6 2.9L88.9 557.2a9.96 9.96 0 0 0 0 14.1l363.8 363.8c1.9 1.9 4.4 2.9 7.1 2.9s5.2-1 7.1-2.9l468.3-468.3c2-2.1 3-5 2.8-8zM699 387c-35.3 0-64-28.7-64-64s28.7-64 64-64 64 28.7 64 64-28.7 64-64 64z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTagOutlined, "Tag Outlined"))]
 pub static AiTagOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTagOutlined",
+    label: "Tag Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11636,8 +16539,15 @@ pub static AiTagOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M938 458.8l-29.6-312.6c-1.5-16.2-14.4-29-30.6-30.6L565.2 86h-.4c-3.2 0-5.7 1-7.6 2.9L88.9 557.2a9.96 9.96 0 0 0 0 14.1l363.8 363.8c1.9 1.9 4.4 2.9 7.1 2.9s5.2-1 7.1-2.9l468.3-468.3c2-2.1 3-5 2.8-8zM459.7 834.7L189.3 564.3 589 164.6 836 188l23.4 247-399.7 399.7zM680 256c-48.5 0-88 39.5-88 88s39.5 88 88 88 88-39.5 88-88-39.5-88-88-88zm0 120c-17.7 0-32-14.3-32-32s14.3-32 32-32 32 14.3 32 32-14.3 32-32 32z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTagTwotone, "Tag Twotone"))]
 pub static AiTagTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTagTwotone",
+    label: "Tag Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11654,8 +16564,15 @@ pub static AiTagTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M938 458.8l-29.6-312.6c-1.5-16.2-14.4-29-30.6-30.6L565.2 86h-.4c-3.2 0-5.7 1-7.6 2.9L88.9 557.2a9.96 9.96 0 0 0 0 14.1l363.8 363.8a9.9 9.9 0 0 0 7.1 2.9c2.7 0 5.2-1 7.1-2.9l468.3-468.3c2-2.1 3-5 2.8-8zM459.7 834.7L189.3 564.3 589 164.6 836 188l23.4 247-399.7 399.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTagsFilled, "Tags Filled"))]
 pub static AiTagsFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTagsFilled",
+    label: "Tags Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11670,8 +16587,15 @@ pub static AiTagsFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M483.2 790.3L861.4 412c1.7-1.7 2.5-4 2.3-6.3l-25.5-301.4c-.7-7.8-6.8-13.9-14.6-14.6L522.2 64.3c-2.3-.2-4.7.6-6.3 2.3L137.7 444.8a8.03 8.03 0 0 0 0 11.3l334.2 334.2c3.1 3.2 8.2 3.2 11.3 0zm122.7-533.4c18.7-18.7 49.1-18.7 67.9 0 18.7 18.7 18.7 49.1 0 67.9-18.7 18.7-49.1 18.7-67.9 0-18.7-18.7-18.7-49.1 0-67.9zm283.8 282.9l-39.6-39.5a8.03 8.03 0 0 0-11.3 0l-362 361.3-237.6-237a8.03 8.03 0 0 0-11.3 0l-39.6 39.5a8.03 8.03 0 0 0 0 11.3l243.2 242.8 39.6 39.5c3.1 3.1 8.2 3.1 11.3 0l407.3-406.6c3.1-3.1 3.1-8.2 0-11.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTagsOutlined, "Tags Outlined"))]
 pub static AiTagsOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTagsOutlined",
+    label: "Tags Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11686,8 +16610,15 @@ pub static AiTagsOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M483.2 790.3L861.4 412c1.7-1.7 2.5-4 2.3-6.3l-25.5-301.4c-.7-7.8-6.8-13.9-14.6-14.6L522.2 64.3c-2.3-.2-4.7.6-6.3 2.3L137.7 444.8a8.03 8.03 0 0 0 0 11.3l334.2 334.2c3.1 3.2 8.2 3.2 11.3 0zm62.6-651.7l224.6 19 19 224.6L477.5 694 233.9 450.5l311.9-311.9zm60.16 186.23a48 48 0 1 0 67.88-67.89 48 48 0 1 0-67.88 67.89zM889.7 539.8l-39.6-39.5a8.03 8.03 0 0 0-11.3 0l-362 361.3-237.6-237a8.03 8.03 0 0 0-11.3 0l-39.6 39.5a8.03 8.03 0 0 0 0 11.3l243.2 242.8 39.6 39.5c3.1 3.1 8.2 3.1 11.3 0l407.3-406.6c3.1-3.1 3.1-8.2 0-11.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTagsTwotone, "Tags Twotone"))]
 pub static AiTagsTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTagsTwotone",
+    label: "Tags Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11705,8 +16636,15 @@ pub static AiTagsTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M137.7 444.8a8.03 8.03 0 0 0 0 11.3l334.2 334.2c1.3 1.3 2.9 2.1 4.7 2.3 2.4.3 4.8-.5 6.6-2.3L861.4 412c1.7-1.7 2.5-4 2.3-6.3l-25.5-301.4c-.7-7.8-6.8-13.9-14.6-14.6L522.2 64.3h-1.6c-1.8.2-3.4 1-4.7 2.3L137.7 444.8zm408.1-306.2l224.6 19 19 224.6L477.5 694 233.9 450.5l311.9-311.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTaobaoCircleFilled, "Taobao Circle Filled"))]
 pub static AiTaobaoCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTaobaoCircleFilled",
+    label: "Taobao Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11721,8 +16659,15 @@ pub static AiTaobaoCircleFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zM315.7 291.5c27.3 0 49.5 22.1 49.5 49.4s-22.1 49.4-49.5 49.4a49.4 49.4 0 1 1 0-98.8zM366.9 578c-13.6 42.3-10.2 26.7-64.4 144.5l-78.5-49s87.7-79.8 105.6-116.2c19.2-38.4-21.1-58.9-21.1-58.9l-60.2-37.5 32.7-50.2c45.4 33.7 48.7 36.6 79.2 67.2 23.8 23.9 20.7 56.8 6.7 100.1zm427.2 55c-15.3 143.8-202.4 90.3-202.4 90.3l10.2-41.1 43.3 9.3c80 5 72.3-64.9 72.3-64.9V423c.6-77.3-72.6-85.4-204.2-38.3l30.6 8.3c-2.5 9-12.5 23.2-25.2 38.6h176v35.6h-99.1v44.5h98.7v35.7h-98.7V622c14.9-4.8 28.6-11.5 40.5-20.5l-8.7-32.5 46.5-14.4 38.8 94.9-57.3 23.9-10.2-37.8c-25.6 19.5-78.8 48-171.8 45.4-99.2 2.6-73.7-112-73.7-112l2.5-1.3H472c-.5 14.7-6.6 38.7 1.7 51.8 6.8 10.8 24.2 12.6 35.3 13.1 1.3.1 2.6.1 3.9.1v-85.3h-101v-35.7h101v-44.5H487c-22.7 24.1-43.5 44.1-43.5 44.1l-30.6-26.7c21.7-22.9 43.3-59.1 56.8-83.2-10.9 4.4-22 9.2-33.6 14.2-11.2 14.3-24.2 29-38.7 43.5.5.8-50-28.4-50-28.4 52.2-44.4 81.4-139.9 81.4-139.9l72.5 20.4s-5.9 14-18.4 35.6c290.3-82.3 307.4 50.5 307.4 50.5s19.1 91.8 3.8 235.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTaobaoCircleOutlined, "Taobao Circle Outlined"))]
 pub static AiTaobaoCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTaobaoCircleOutlined",
+    label: "Taobao Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11737,8 +16682,15 @@ pub static AiTaobaoCircleOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zM315.7 291.5c27.3 0 49.5 22.1 49.5 49.4s-22.1 49.4-49.5 49.4a49.4 49.4 0 1 1 0-98.8zM366.9 578c-13.6 42.3-10.2 26.7-64.4 144.5l-78.5-49s87.7-79.8 105.6-116.2c19.2-38.4-21.1-58.9-21.1-58.9l-60.2-37.5 32.7-50.2c45.4 33.7 48.7 36.6 79.2 67.2 23.8 23.9 20.7 56.8 6.7 100.1zm427.2 55c-15.3 143.8-202.4 90.3-202.4 90.3l10.2-41.1 43.3 9.3c80 5 72.3-64.9 72.3-64.9V423c.6-77.3-72.6-85.4-204.2-38.3l30.6 8.3c-2.5 9-12.5 23.2-25.2 38.6h176v35.6h-99.1v44.5h98.7v35.7h-98.7V622c14.9-4.8 28.6-11.5 40.5-20.5l-8.7-32.5 46.5-14.4 38.8 94.9-57.3 23.9-10.2-37.8c-25.6 19.5-78.8 48-171.8 45.4-99.2 2.6-73.7-112-73.7-112l2.5-1.3H472c-.5 14.7-6.6 38.7 1.7 51.8 6.8 10.8 24.2 12.6 35.3 13.1 1.3.1 2.6.1 3.9.1v-85.3h-101v-35.7h101v-44.5H487c-22.7 24.1-43.5 44.1-43.5 44.1l-30.6-26.7c21.7-22.9 43.3-59.1 56.8-83.2-10.9 4.4-22 9.2-33.6 14.2-11.2 14.3-24.2 29-38.7 43.5.5.8-50-28.4-50-28.4 52.2-44.4 81.4-139.9 81.4-139.9l72.5 20.4s-5.9 14-18.4 35.6c290.3-82.3 307.4 50.5 307.4 50.5s19.1 91.8 3.8 235.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTaobaoOutlined, "Taobao Outlined"))]
 pub static AiTaobaoOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTaobaoOutlined",
+    label: "Taobao Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11753,8 +16705,15 @@ pub static AiTaobaoOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M168.5 273.7a68.7 68.7 0 1 0 137.4 0 68.7 68.7 0 1 0-137.4 0zm730 79.2s-23.7-184.4-426.9-70.1c17.3-30 25.6-49.5 25.6-49.5L396.4 205s-40.6 132.6-113 194.4c0 0 70.1 40.6 69.4 39.4 20.1-20.1 38.2-40.6 53.7-60.4 16.1-7 31.5-13.6 46.7-19.8-18.6 33.5-48.7 83.8-78.8 115.6l42.4 37s28.8-27.7 60.4-61.2h36v61.8H372.9v49.5h140.3v118.5c-1.7 0-3.6 0-5.4-.2-15.4-.7-39.5-3.3-49-18.2-11.5-18.1-3-51.5-2.4-71.9h-97l-3.4 1.8s-35.5 159.1 102.3 155.5c129.1 3.6 203-36 238.6-63.1l14.2 52.6 79.6-33.2-53.9-131.9-64.6 20.1 12.1 45.2c-16.6 12.4-35.6 21.7-56.2 28.4V561.3h137.1v-49.5H628.1V450h137.6v-49.5H521.3c17.6-21.4 31.5-41.1 35-53.6l-42.5-11.6c182.8-65.5 284.5-54.2 283.6 53.2v282.8s10.8 97.1-100.4 90.1l-60.2-12.9-14.2 57.1S882.5 880 903.7 680.2c21.3-200-5.2-327.3-5.2-327.3zm-707.4 18.3l-45.4 69.7 83.6 52.1s56 28.5 29.4 81.9C233.8 625.5 112 736.3 112 736.3l109 68.1c75.4-163.7 70.5-142 89.5-200.7 19.5-60.1 23.7-105.9-9.4-139.1-42.4-42.6-47-46.6-110-93.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTaobaoSquareFilled, "Taobao Square Filled"))]
 pub static AiTaobaoSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTaobaoSquareFilled",
+    label: "Taobao Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11769,8 +16728,15 @@ pub static AiTaobaoSquareFilled: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM315.7 291.5c27.3 0 49.5 22.1 49.5 49.4s-22.1 49.4-49.5 49.4a49.4 49.4 0 1 1 0-98.8zM366.9 578c-13.6 42.3-10.2 26.7-64.4 144.5l-78.5-49s87.7-79.8 105.6-116.2c19.2-38.4-21.1-58.9-21.1-58.9l-60.2-37.5 32.7-50.2c45.4 33.7 48.7 36.6 79.2 67.2 23.8 23.9 20.7 56.8 6.7 100.1zm427.2 55c-15.3 143.8-202.4 90.3-202.4 90.3l10.2-41.1 43.3 9.3c80 5 72.3-64.9 72.3-64.9V423c.6-77.3-72.6-85.4-204.2-38.3l30.6 8.3c-2.5 9-12.5 23.2-25.2 38.6h176v35.6h-99.1v44.5h98.7v35.7h-98.7V622c14.9-4.8 28.6-11.5 40.5-20.5l-8.7-32.5 46.5-14.4 38.8 94.9-57.3 23.9-10.2-37.8c-25.6 19.5-78.8 48-171.8 45.4-99.2 2.6-73.7-112-73.7-112l2.5-1.3H472c-.5 14.7-6.6 38.7 1.7 51.8 6.8 10.8 24.2 12.6 35.3 13.1 1.3.1 2.6.1 3.9.1v-85.3h-101v-35.7h101v-44.5H487c-22.7 24.1-43.5 44.1-43.5 44.1l-30.6-26.7c21.7-22.9 43.3-59.1 56.8-83.2-10.9 4.4-22 9.2-33.6 14.2-11.2 14.3-24.2 29-38.7 43.5.5.8-50-28.4-50-28.4 52.2-44.4 81.4-139.9 81.4-139.9l72.5 20.4s-5.9 14-18.4 35.6c290.3-82.3 307.4 50.5 307.4 50.5s19.1 91.8 3.8 235.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTeamOutlined, "Team Outlined"))]
 pub static AiTeamOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTeamOutlined",
+    label: "Team Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11785,8 +16751,15 @@ pub static AiTeamOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M824.2 699.9a301.55 301.55 0 0 0-86.4-60.4C783.1 602.8 812 546.8 812 484c0-110.8-92.4-201.7-203.2-200-109.1 1.7-197 90.6-197 200 0 62.8 29 118.8 74.2 155.5a300.95 300.95 0 0 0-86.4 60.4C345 754.6 314 826.8 312 903.8a8 8 0 0 0 8 8.2h56c4.3 0 7.9-3.4 8-7.7 1.9-58 25.4-112.3 66.7-153.5A226.62 226.62 0 0 1 612 684c60.9 0 118.2 23.7 161.3 66.8C814.5 792 838 846.3 840 904.3c.1 4.3 3.7 7.7 8 7.7h56a8 8 0 0 0 8-8.2c-2-77-33-149.2-87.8-203.9zM612 612c-34.2 0-66.4-13.3-90.5-37.5a126.86 126.86 0 0 1-37.5-91.8c.3-32.8 13.4-64.5 36.3-88 24-24.6 56.1-38.3 90.4-38.7 33.9-.3 66.8 12.9 91 36.6 24.8 24.3 38.4 56.8 38.4 91.4 0 34.2-13.3 66.3-37.5 90.5A127.3 127.3 0 0 1 612 612zM361.5 510.4c-.9-8.7-1.4-17.5-1.4-26.4 0-15.9 1.5-31.4 4.3-46.5.7-3.6-1.2-7.3-4.5-8.8-13.6-6.1-26.1-14.5-36.9-25.1a127.54 127.54 0 0 1-38.7-95.4c.9-32.1 13.8-62.6 36.3-85.6 24.7-25.3 57.9-39.1 93.2-38.7 31.9.3 62.7 12.6 86 34.4 7.9 7.4 14.7 15.6 20.4 24.4 2 3.1 5.9 4.4 9.3 3.2 17.6-6.1 36.2-10.4 55.3-12.4 5.6-.6 8.8-6.6 6.3-11.6-32.5-64.3-98.9-108.7-175.7-109.9-110.9-1.7-203.3 89.2-203.3 199.9 0 62.8 28.9 118.8 74.2 155.5-31.8 14.7-61.1 35-86.5 60.4-54.8 54.7-85.8 126.9-87.8 204a8 8 0 0 0 8 8.2h56.1c4.3 0 7.9-3.4 8-7.7 1.9-58 25.4-112.3 66.7-153.5 29.4-29.4 65.4-49.8 104.7-59.7 3.9-1 6.5-4.7 6-8.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiThunderboltFilled, "Thunderbolt Filled"))]
 pub static AiThunderboltFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiThunderboltFilled",
+    label: "Thunderbolt Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11801,8 +16774,15 @@ pub static AiThunderboltFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M848 359.3H627.7L825.8 109c4.1-5.3.4-13-6.3-13H436c-2.8 0-5.5 1.5-6.9 4L170 547.5c-3.1 5.3.7 12 6.9 12h174.4l-89.4 357.6c-1.9 7.8 7.5 13.3 13.3 7.7L853.5 373c5.2-4.9 1.7-13.7-5.5-13.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiThunderboltOutlined, "Thunderbolt Outlined"))]
 pub static AiThunderboltOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiThunderboltOutlined",
+    label: "Thunderbolt Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11817,8 +16797,15 @@ pub static AiThunderboltOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M848 359.3H627.7L825.8 109c4.1-5.3.4-13-6.3-13H436c-2.8 0-5.5 1.5-6.9 4L170 547.5c-3.1 5.3.7 12 6.9 12h174.4l-89.4 357.6c-1.9 7.8 7.5 13.3 13.3 7.7L853.5 373c5.2-4.9 1.7-13.7-5.5-13.7zM378.2 732.5l60.3-241H281.1l189.6-327.4h224.6L487 427.4h211L378.2 732.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiThunderboltTwotone, "Thunderbolt Twotone"))]
 pub static AiThunderboltTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiThunderboltTwotone",
+    label: "Thunderbolt Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11834,8 +16821,15 @@ pub static AiThunderboltTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M848.1 359.3H627.8L825.9 109c4.1-5.3.4-13-6.3-13H436.1c-2.8 0-5.5 1.5-6.9 4L170.1 547.5c-3.1 5.3.7 12 6.9 12h174.4L262 917.1c-1.9 7.8 7.5 13.3 13.3 7.7L853.6 373c5.2-4.9 1.7-13.7-5.5-13.7zM378.3 732.5l60.3-241H281.2l189.6-327.4h224.6L487.1 427.4h211L378.3 732.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiToTopOutlined, "To Top Outlined"))]
 pub static AiToTopOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiToTopOutlined",
+    label: "To Top Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11850,8 +16844,15 @@ pub static AiToTopOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M885 780H165c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h720c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8zM400 325.7h73.9V664c0 4.4 3.6 8 8 8h60c4.4 0 8-3.6 8-8V325.7H624c6.7 0 10.4-7.7 6.3-12.9L518.3 171a8 8 0 0 0-12.6 0l-112 141.7c-4.1 5.3-.4 13 6.3 13z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiToolFilled, "Tool Filled"))]
 pub static AiToolFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiToolFilled",
+    label: "Tool Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11866,8 +16867,15 @@ pub static AiToolFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M865.3 244.7c-.3-.3-61.1 59.8-182.1 180.6l-84.9-84.9 180.9-180.9c-95.2-57.3-217.5-42.6-296.8 36.7A244.42 244.42 0 0 0 419 432l1.8 6.7-283.5 283.4c-6.2 6.2-6.2 16.4 0 22.6l141.4 141.4c6.2 6.2 16.4 6.2 22.6 0l283.3-283.3 6.7 1.8c83.7 22.3 173.6-.9 236-63.3 79.4-79.3 94.1-201.6 38-296.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiToolOutlined, "Tool Outlined"))]
 pub static AiToolOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiToolOutlined",
+    label: "Tool Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11882,8 +16890,15 @@ pub static AiToolOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M876.6 239.5c-.5-.9-1.2-1.8-2-2.5-5-5-13.1-5-18.1 0L684.2 409.3l-67.9-67.9L788.7 169c.8-.8 1.4-1.6 2-2.5 3.6-6.1 1.6-13.9-4.5-17.5-98.2-58-226.8-44.7-311.3 39.7-67 67-89.2 162-66.5 247.4l-293 293c-3 3-2.8 7.9.3 11l169.7 169.7c3.1 3.1 8.1 3.3 11 .3l292.9-292.9c85.5 22.8 180.5.7 247.6-66.4 84.4-84.5 97.7-213.1 39.7-311.3zM786 499.8c-58.1 58.1-145.3 69.3-214.6 33.6l-8.8 8.8-.1-.1-274 274.1-79.2-79.2 230.1-230.1s0 .1.1.1l52.8-52.8c-35.7-69.3-24.5-156.5 33.6-214.6a184.2 184.2 0 0 1 144-53.5L537 318.9a32.05 32.05 0 0 0 0 45.3l124.5 124.5a32.05 32.05 0 0 0 45.3 0l132.8-132.8c3.7 51.8-14.4 104.8-53.6 143.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiToolTwotone, "Tool Twotone"))]
 pub static AiToolTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiToolTwotone",
+    label: "Tool Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11899,8 +16914,15 @@ pub static AiToolTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M876.6 239.5c-.5-.9-1.2-1.8-2-2.5-5-5-13.1-5-18.1 0L684.2 409.3l-67.9-67.9L788.7 169c.8-.8 1.4-1.6 2-2.5 3.6-6.1 1.6-13.9-4.5-17.5-98.2-58-226.8-44.7-311.3 39.7-67 67-89.2 162-66.5 247.4l-293 293c-3 3-2.8 7.9.3 11l169.7 169.7c3.1 3.1 8.1 3.3 11 .3l292.9-292.9c85.5 22.8 180.5.7 247.6-66.4 84.4-84.5 97.7-213.1 39.7-311.3zM786 499.8c-58.1 58.1-145.3 69.3-214.6 33.6l-8.8 8.8-.1-.1-274 274.1-79.2-79.2 230.1-230.1s0 .1.1.1l52.8-52.8c-35.7-69.3-24.5-156.5 33.6-214.6a184.2 184.2 0 0 1 144-53.5L537 318.9a32.05 32.05 0 0 0 0 45.3l124.5 124.5a32.05 32.05 0 0 0 45.3 0l132.8-132.8c3.7 51.8-14.4 104.8-53.6 143.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTrademarkCircleFilled, "Trademark Circle Filled"))]
 pub static AiTrademarkCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTrademarkCircleFilled",
+    label: "Trademark Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11915,8 +16937,15 @@ pub static AiTrademarkCircleFilled: &icondata_core::IconData = &icondata_core::I
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm164.7 660.2c-1.1.5-2.3.8-3.5.8h-62c-3.1 0-5.9-1.8-7.2-4.6l-74.6-159.2h-88.7V717c0 4.4-3.6 8-8 8H378c-4.4 0-8-3.6-8-8V307c0-4.4 3.6-8 8-8h155.6c98.8 0 144.2 59.9 144.2 131.1 0 70.2-43.6 106.4-78.4 119.2l80.8 164.2c2.1 3.9.4 8.7-3.5 10.7zM523.9 357h-83.4v148H522c53 0 82.8-25.6 82.8-72.4 0-50.3-32.9-75.6-80.9-75.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTrademarkCircleOutlined, "Trademark Circle Outlined"))]
 pub static AiTrademarkCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTrademarkCircleOutlined",
+    label: "Trademark Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11931,8 +16960,15 @@ pub static AiTrademarkCircleOutlined: &icondata_core::IconData = &icondata_core:
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm87.5-334.7c34.8-12.8 78.4-49 78.4-119.2 0-71.2-45.5-131.1-144.2-131.1H378c-4.4 0-8 3.6-8 8v410c0 4.4 3.6 8 8 8h54.5c4.4 0 8-3.6 8-8V561.2h88.7l74.6 159.2c1.3 2.8 4.1 4.6 7.2 4.6h62a7.9 7.9 0 0 0 7.1-11.5l-80.6-164.2zM522 505h-81.5V357h83.4c48 0 80.9 25.3 80.9 75.5 0 46.9-29.8 72.5-82.8 72.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTrademarkCircleTwotone, "Trademark Circle Twotone"))]
 pub static AiTrademarkCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTrademarkCircleTwotone",
+    label: "Trademark Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11950,8 +16986,15 @@ pub static AiTrademarkCircleTwotone: &icondata_core::IconData = &icondata_core::
 <path d="M605.4 549.3c34.8-12.8 78.4-49 78.4-119.2 0-71.2-45.4-131.1-144.2-131.1H384c-4.4 0-8 3.6-8 8v410c0 4.4 3.6 8 8 8h54.7c4.4 0 8-3.6 8-8V561.2h88.7L610 720.4c1.3 2.8 4.1 4.6 7.2 4.6h62c1.2 0 2.4-.3 3.5-.8 3.9-2 5.6-6.8 3.5-10.7l-80.8-164.2zM528 505h-81.5V357h83.4c48 0 80.9 25.3 80.9 75.6 0 46.8-29.8 72.4-82.8 72.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTrademarkOutlined, "Trademark Outlined"))]
 pub static AiTrademarkOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTrademarkOutlined",
+    label: "Trademark Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11966,8 +17009,15 @@ pub static AiTrademarkOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372zm87.5-334.7c34.8-12.8 78.4-49 78.4-119.2 0-71.2-45.5-131.1-144.2-131.1H378c-4.4 0-8 3.6-8 8v410c0 4.4 3.6 8 8 8h54.5c4.4 0 8-3.6 8-8V561.2h88.7l74.6 159.2c1.3 2.8 4.1 4.6 7.2 4.6h62a7.9 7.9 0 0 0 7.1-11.5l-80.6-164.2zM522 505h-81.5V357h83.4c48 0 80.9 25.3 80.9 75.5 0 46.9-29.8 72.5-82.8 72.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTransactionOutlined, "Transaction Outlined"))]
 pub static AiTransactionOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTransactionOutlined",
+    label: "Transaction Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11982,8 +17032,15 @@ pub static AiTransactionOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M668.6 320c0-4.4-3.6-8-8-8h-54.5c-3 0-5.8 1.7-7.1 4.4l-84.7 168.8H511l-84.7-168.8a8 8 0 0 0-7.1-4.4h-55.7c-1.3 0-2.6.3-3.8 1-3.9 2.1-5.3 7-3.2 10.8l103.9 191.6h-57c-4.4 0-8 3.6-8 8v27.1c0 4.4 3.6 8 8 8h76v39h-76c-4.4 0-8 3.6-8 8v27.1c0 4.4 3.6 8 8 8h76V704c0 4.4 3.6 8 8 8h49.9c4.4 0 8-3.6 8-8v-63.5h76.3c4.4 0 8-3.6 8-8v-27.1c0-4.4-3.6-8-8-8h-76.3v-39h76.3c4.4 0 8-3.6 8-8v-27.1c0-4.4-3.6-8-8-8H564l103.7-191.6c.5-1.1.9-2.4.9-3.7zM157.9 504.2a352.7 352.7 0 0 1 103.5-242.4c32.5-32.5 70.3-58.1 112.4-75.9 43.6-18.4 89.9-27.8 137.6-27.8 47.8 0 94.1 9.3 137.6 27.8 42.1 17.8 79.9 43.4 112.4 75.9 10 10 19.3 20.5 27.9 31.4l-50 39.1a8 8 0 0 0 3 14.1l156.8 38.3c5 1.2 9.9-2.6 9.9-7.7l.8-161.5c0-6.7-7.7-10.5-12.9-6.3l-47.8 37.4C770.7 146.3 648.6 82 511.5 82 277 82 86.3 270.1 82 503.8a8 8 0 0 0 8 8.2h60c4.3 0 7.8-3.5 7.9-7.8zM934 512h-60c-4.3 0-7.9 3.5-8 7.8a352.7 352.7 0 0 1-103.5 242.4 352.57 352.57 0 0 1-112.4 75.9c-43.6 18.4-89.9 27.8-137.6 27.8s-94.1-9.3-137.6-27.8a352.57 352.57 0 0 1-112.4-75.9c-10-10-19.3-20.5-27.9-31.4l49.9-39.1a8 8 0 0 0-3-14.1l-156.8-38.3c-5-1.2-9.9 2.6-9.9 7.7l-.8 161.7c0 6.7 7.7 10.5 12.9 6.3l47.8-37.4C253.3 877.7 375.4 942 512.5 942 747 942 937.7 753.9 942 520.2a8 8 0 0 0-8-8.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTranslationOutlined, "Translation Outlined"))]
 pub static AiTranslationOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTranslationOutlined",
+    label: "Translation Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12002,8 +17059,15 @@ pub static AiTranslationOutlined: &icondata_core::IconData = &icondata_core::Ico
 <path d="M414.3 256h-60.6c-3.4 0-6.4 2.2-7.6 5.4L219 629.4c-0.3 0.8-0.4 1.7-0.4 2.6 0 4.4 3.6 8 8 8h55.1c3.4 0 6.4-2.2 7.6-5.4L322 540h196.2L422 261.4c-1.3-3.2-4.3-5.4-7.7-5.4z m12.4 228h-85.5L384 360.2 426.7 484zM936 528H800v-93c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v93H592c-13.3 0-24 10.7-24 24v176c0 13.3 10.7 24 24 24h136v152c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V752h136c13.3 0 24-10.7 24-24V552c0-13.3-10.7-24-24-24zM728 680h-88v-80h88v80z m160 0h-88v-80h88v80z" p-id="11647" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTrophyFilled, "Trophy Filled"))]
 pub static AiTrophyFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTrophyFilled",
+    label: "Trophy Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12018,8 +17082,15 @@ pub static AiTrophyFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M868 160h-92v-40c0-4.4-3.6-8-8-8H256c-4.4 0-8 3.6-8 8v40h-92a44 44 0 0 0-44 44v148c0 81.7 60 149.6 138.2 162C265.6 630.2 359 721.8 476 734.5v105.2H280c-17.7 0-32 14.3-32 32V904c0 4.4 3.6 8 8 8h512c4.4 0 8-3.6 8-8v-32.3c0-17.7-14.3-32-32-32H548V734.5C665 721.8 758.4 630.2 773.8 514 852 501.6 912 433.7 912 352V204a44 44 0 0 0-44-44zM248 439.6c-37.1-11.9-64-46.7-64-87.6V232h64v207.6zM840 352c0 41-26.9 75.8-64 87.6V232h64v120z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTrophyOutlined, "Trophy Outlined"))]
 pub static AiTrophyOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTrophyOutlined",
+    label: "Trophy Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12034,8 +17105,15 @@ pub static AiTrophyOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M868 160h-92v-40c0-4.4-3.6-8-8-8H256c-4.4 0-8 3.6-8 8v40h-92a44 44 0 0 0-44 44v148c0 81.7 60 149.6 138.2 162C265.7 630.2 359 721.7 476 734.5v105.2H280c-17.7 0-32 14.3-32 32V904c0 4.4 3.6 8 8 8h512c4.4 0 8-3.6 8-8v-32.3c0-17.7-14.3-32-32-32H548V734.5C665 721.7 758.3 630.2 773.8 514 852 501.6 912 433.7 912 352V204a44 44 0 0 0-44-44zM184 352V232h64v207.6a91.99 91.99 0 0 1-64-87.6zm520 128c0 49.1-19.1 95.4-53.9 130.1-34.8 34.8-81 53.9-130.1 53.9h-16c-49.1 0-95.4-19.1-130.1-53.9-34.8-34.8-53.9-81-53.9-130.1V184h384v296zm136-128c0 41-26.9 75.8-64 87.6V232h64v120z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTrophyTwotone, "Trophy Twotone"))]
 pub static AiTrophyTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTrophyTwotone",
+    label: "Trophy Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12051,8 +17129,15 @@ pub static AiTrophyTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M868 160h-92v-40c0-4.4-3.6-8-8-8H256c-4.4 0-8 3.6-8 8v40h-92a44 44 0 0 0-44 44v148c0 81.7 60 149.6 138.2 162C265.7 630.2 359 721.7 476 734.5v105.2H280c-17.7 0-32 14.3-32 32V904c0 4.4 3.6 8 8 8h512c4.4 0 8-3.6 8-8v-32.3c0-17.7-14.3-32-32-32H548V734.5C665 721.7 758.3 630.2 773.8 514 852 501.6 912 433.7 912 352V204a44 44 0 0 0-44-44zM248 439.6a91.99 91.99 0 0 1-64-87.6V232h64v207.6zM704 480c0 49.1-19.1 95.4-53.9 130.1-34.8 34.8-81 53.9-130.1 53.9h-16c-49.1 0-95.4-19.1-130.1-53.9-34.8-34.8-53.9-81-53.9-130.1V184h384v296zm136-128c0 41-26.9 75.8-64 87.6V232h64v120z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTwitterCircleFilled, "Twitter Circle Filled"))]
 pub static AiTwitterCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTwitterCircleFilled",
+    label: "Twitter Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12067,8 +17152,15 @@ pub static AiTwitterCircleFilled: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm215.3 337.7c.3 4.7.3 9.6.3 14.4 0 146.8-111.8 315.9-316.1 315.9-63 0-121.4-18.3-170.6-49.8 9 1 17.6 1.4 26.8 1.4 52 0 99.8-17.6 137.9-47.4-48.8-1-89.8-33-103.8-77 17.1 2.5 32.5 2.5 50.1-2a111 111 0 0 1-88.9-109v-1.4c14.7 8.3 32 13.4 50.1 14.1a111.13 111.13 0 0 1-49.5-92.4c0-20.7 5.4-39.6 15.1-56a315.28 315.28 0 0 0 229 116.1C492 353.1 548.4 292 616.2 292c32 0 60.8 13.4 81.1 35 25.1-4.7 49.1-14.1 70.5-26.7-8.3 25.7-25.7 47.4-48.8 61.1 22.4-2.4 44-8.6 64-17.3-15.1 22.2-34 41.9-55.7 57.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTwitterOutlined, "Twitter Outlined"))]
 pub static AiTwitterOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTwitterOutlined",
+    label: "Twitter Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12083,8 +17175,15 @@ pub static AiTwitterOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M928 254.3c-30.6 13.2-63.9 22.7-98.2 26.4a170.1 170.1 0 0 0 75-94 336.64 336.64 0 0 1-108.2 41.2A170.1 170.1 0 0 0 672 174c-94.5 0-170.5 76.6-170.5 170.6 0 13.2 1.6 26.4 4.2 39.1-141.5-7.4-267.7-75-351.6-178.5a169.32 169.32 0 0 0-23.2 86.1c0 59.2 30.1 111.4 76 142.1a172 172 0 0 1-77.1-21.7v2.1c0 82.9 58.6 151.6 136.7 167.4a180.6 180.6 0 0 1-44.9 5.8c-11.1 0-21.6-1.1-32.2-2.6C211 652 273.9 701.1 348.8 702.7c-58.6 45.9-132 72.9-211.7 72.9-14.3 0-27.5-.5-41.2-2.1C171.5 822 261.2 850 357.8 850 671.4 850 843 590.2 843 364.7c0-7.4 0-14.8-.5-22.2 33.2-24.3 62.3-54.4 85.5-88.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiTwitterSquareFilled, "Twitter Square Filled"))]
 pub static AiTwitterSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiTwitterSquareFilled",
+    label: "Twitter Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12099,8 +17198,15 @@ pub static AiTwitterSquareFilled: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM727.3 401.7c.3 4.7.3 9.6.3 14.4 0 146.8-111.8 315.9-316.1 315.9-63 0-121.4-18.3-170.6-49.8 9 1 17.6 1.4 26.8 1.4 52 0 99.8-17.6 137.9-47.4-48.8-1-89.8-33-103.8-77 17.1 2.5 32.5 2.5 50.1-2a111 111 0 0 1-88.9-109v-1.4c14.7 8.3 32 13.4 50.1 14.1a111.13 111.13 0 0 1-49.5-92.4c0-20.7 5.4-39.6 15.1-56a315.28 315.28 0 0 0 229 116.1C492 353.1 548.4 292 616.2 292c32 0 60.8 13.4 81.1 35 25.1-4.7 49.1-14.1 70.5-26.7-8.3 25.7-25.7 47.4-48.8 61.1 22.4-2.4 44-8.6 64-17.3-15.1 22.2-34 41.9-55.7 57.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUnderlineOutlined, "Underline Outlined"))]
 pub static AiUnderlineOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUnderlineOutlined",
+    label: "Underline Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12115,8 +17221,15 @@ pub static AiUnderlineOutlined: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M824 804H200c-4.4 0-8 3.4-8 7.6v60.8c0 4.2 3.6 7.6 8 7.6h624c4.4 0 8-3.4 8-7.6v-60.8c0-4.2-3.6-7.6-8-7.6zm-312-76c69.4 0 134.6-27.1 183.8-76.2C745 602.7 772 537.4 772 468V156c0-6.6-5.4-12-12-12h-60c-6.6 0-12 5.4-12 12v312c0 97-79 176-176 176s-176-79-176-176V156c0-6.6-5.4-12-12-12h-60c-6.6 0-12 5.4-12 12v312c0 69.4 27.1 134.6 76.2 183.8C377.3 701 442.6 728 512 728z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUndoOutlined, "Undo Outlined"))]
 pub static AiUndoOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUndoOutlined",
+    label: "Undo Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12131,8 +17244,15 @@ pub static AiUndoOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M511.4 124C290.5 124.3 112 303 112 523.9c0 128 60.2 242 153.8 315.2l-37.5 48c-4.1 5.3-.3 13 6.3 12.9l167-.8c5.2 0 9-4.9 7.7-9.9L369.8 727a8 8 0 0 0-14.1-3L315 776.1c-10.2-8-20-16.7-29.3-26a318.64 318.64 0 0 1-68.6-101.7C200.4 609 192 567.1 192 523.9s8.4-85.1 25.1-124.5c16.1-38.1 39.2-72.3 68.6-101.7 29.4-29.4 63.6-52.5 101.7-68.6C426.9 212.4 468.8 204 512 204s85.1 8.4 124.5 25.1c38.1 16.1 72.3 39.2 101.7 68.6 29.4 29.4 52.5 63.6 68.6 101.7 16.7 39.4 25.1 81.3 25.1 124.5s-8.4 85.1-25.1 124.5a318.64 318.64 0 0 1-68.6 101.7c-7.5 7.5-15.3 14.5-23.4 21.2a7.93 7.93 0 0 0-1.2 11.1l39.4 50.5c2.8 3.5 7.9 4.1 11.4 1.3C854.5 760.8 912 649.1 912 523.9c0-221.1-179.4-400.2-400.6-399.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUngroupOutlined, "Ungroup Outlined"))]
 pub static AiUngroupOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUngroupOutlined",
+    label: "Ungroup Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12150,8 +17270,15 @@ pub static AiUngroupOutlined: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M736 550H288c-8.8 0-16 7.2-16 16v176c0 8.8 7.2 16 16 16h448c8.8 0 16-7.2 16-16V566c0-8.8-7.2-16-16-16z m-56 136H344v-64h336v64zM888 816c-39.8 0-72 32.2-72 72s32.2 72 72 72 72-32.2 72-72-32.2-72-72-72z m0 96c-13.3 0-24-10.7-24-24s10.7-24 24-24 24 10.7 24 24-10.7 24-24 24zM736 266H288c-8.8 0-16 7.2-16 16v176c0 8.8 7.2 16 16 16h448c8.8 0 16-7.2 16-16V282c0-8.8-7.2-16-16-16z m-56 136H344v-64h336v64zM888 208c39.8 0 72-32.2 72-72s-32.2-72-72-72-72 32.2-72 72 32.2 72 72 72z m0-96c13.3 0 24 10.7 24 24s-10.7 24-24 24-24-10.7-24-24 10.7-24 24-24zM136 64c-39.8 0-72 32.2-72 72s32.2 72 72 72 72-32.2 72-72-32.2-72-72-72z m0 96c-13.3 0-24-10.7-24-24s10.7-24 24-24 24 10.7 24 24-10.7 24-24 24zM136 816c-39.8 0-72 32.2-72 72s32.2 72 72 72 72-32.2 72-72-32.2-72-72-72z m0 96c-13.3 0-24-10.7-24-24s10.7-24 24-24 24 10.7 24 24-10.7 24-24 24z" p-id="14497" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUnlockFilled, "Unlock Filled"))]
 pub static AiUnlockFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUnlockFilled",
+    label: "Unlock Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12166,8 +17293,15 @@ pub static AiUnlockFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M832 464H332V240c0-30.9 25.1-56 56-56h248c30.9 0 56 25.1 56 56v68c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-68c0-70.7-57.3-128-128-128H388c-70.7 0-128 57.3-128 128v224h-68c-17.7 0-32 14.3-32 32v384c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V496c0-17.7-14.3-32-32-32zM540 701v53c0 4.4-3.6 8-8 8h-40c-4.4 0-8-3.6-8-8v-53a48.01 48.01 0 1 1 56 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUnlockOutlined, "Unlock Outlined"))]
 pub static AiUnlockOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUnlockOutlined",
+    label: "Unlock Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12182,8 +17316,15 @@ pub static AiUnlockOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M832 464H332V240c0-30.9 25.1-56 56-56h248c30.9 0 56 25.1 56 56v68c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-68c0-70.7-57.3-128-128-128H388c-70.7 0-128 57.3-128 128v224h-68c-17.7 0-32 14.3-32 32v384c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V496c0-17.7-14.3-32-32-32zm-40 376H232V536h560v304zM484 701v53c0 4.4 3.6 8 8 8h40c4.4 0 8-3.6 8-8v-53a48.01 48.01 0 1 0-56 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUnlockTwotone, "Unlock Twotone"))]
 pub static AiUnlockTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUnlockTwotone",
+    label: "Unlock Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12200,8 +17341,15 @@ pub static AiUnlockTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M832 464H332V240c0-30.9 25.1-56 56-56h248c30.9 0 56 25.1 56 56v68c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-68c0-70.7-57.3-128-128-128H388c-70.7 0-128 57.3-128 128v224h-68c-17.7 0-32 14.3-32 32v384c0 17.7 14.3 32 32 32h640c17.7 0 32-14.3 32-32V496c0-17.7-14.3-32-32-32zm-40 376H232V536h560v304z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUnorderedListOutlined, "Unordered List Outlined"))]
 pub static AiUnorderedListOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUnorderedListOutlined",
+    label: "Unordered List Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12216,8 +17364,15 @@ pub static AiUnorderedListOutlined: &icondata_core::IconData = &icondata_core::I
     data: r###"<path d="M912 192H328c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h584c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 284H328c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h584c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zm0 284H328c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h584c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8zM104 228a56 56 0 1 0 112 0 56 56 0 1 0-112 0zm0 284a56 56 0 1 0 112 0 56 56 0 1 0-112 0zm0 284a56 56 0 1 0 112 0 56 56 0 1 0-112 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUpCircleFilled, "Up Circle Filled"))]
 pub static AiUpCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUpCircleFilled",
+    label: "Up Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12232,8 +17387,15 @@ pub static AiUpCircleFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm178 555h-46.9c-10.2 0-19.9-4.9-25.9-13.2L512 460.4 406.8 605.8c-6 8.3-15.6 13.2-25.9 13.2H334c-6.5 0-10.3-7.4-6.5-12.7l178-246c3.2-4.4 9.7-4.4 12.9 0l178 246c3.9 5.3.1 12.7-6.4 12.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUpCircleOutlined, "Up Circle Outlined"))]
 pub static AiUpCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUpCircleOutlined",
+    label: "Up Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12249,8 +17411,15 @@ pub static AiUpCircleOutlined: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm0 820c-205.4 0-372-166.6-372-372s166.6-372 372-372 372 166.6 372 372-166.6 372-372 372z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUpCircleTwotone, "Up Circle Twotone"))]
 pub static AiUpCircleTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUpCircleTwotone",
+    label: "Up Circle Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12267,8 +17436,15 @@ pub static AiUpCircleTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M518.4 360.3a7.95 7.95 0 0 0-12.9 0l-178 246c-3.8 5.3 0 12.7 6.5 12.7h46.9c10.3 0 19.9-4.9 25.9-13.2L512 460.4l105.2 145.4c6 8.3 15.7 13.2 25.9 13.2H690c6.5 0 10.3-7.4 6.4-12.7l-178-246z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUpOutlined, "Up Outlined"))]
 pub static AiUpOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUpOutlined",
+    label: "Up Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12283,8 +17459,15 @@ pub static AiUpOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M890.5 755.3L537.9 269.2c-12.8-17.6-39-17.6-51.7 0L133.5 755.3A8 8 0 0 0 140 768h75c5.1 0 9.9-2.5 12.9-6.6L512 369.8l284.1 391.6c3 4.1 7.8 6.6 12.9 6.6h75c6.5 0 10.3-7.4 6.5-12.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUpSquareFilled, "Up Square Filled"))]
 pub static AiUpSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUpSquareFilled",
+    label: "Up Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12299,8 +17482,15 @@ pub static AiUpSquareFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM690 624h-46.9c-10.2 0-19.9-4.9-25.9-13.2L512 465.4 406.8 610.8c-6 8.3-15.6 13.2-25.9 13.2H334c-6.5 0-10.3-7.4-6.5-12.7l178-246c3.2-4.4 9.7-4.4 12.9 0l178 246c3.9 5.3.1 12.7-6.4 12.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUpSquareOutlined, "Up Square Outlined"))]
 pub static AiUpSquareOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUpSquareOutlined",
+    label: "Up Square Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12316,8 +17506,15 @@ pub static AiUpSquareOutlined: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 728H184V184h656v656z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUpSquareTwotone, "Up Square Twotone"))]
 pub static AiUpSquareTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUpSquareTwotone",
+    label: "Up Square Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12334,8 +17531,15 @@ pub static AiUpSquareTwotone: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M334 624h46.9c10.3 0 19.9-4.9 25.9-13.2L512 465.4l105.2 145.4c6 8.3 15.7 13.2 25.9 13.2H690c6.5 0 10.3-7.4 6.4-12.7l-178-246a7.95 7.95 0 0 0-12.9 0l-178 246c-3.8 5.3 0 12.7 6.5 12.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUploadOutlined, "Upload Outlined"))]
 pub static AiUploadOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUploadOutlined",
+    label: "Upload Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12350,8 +17554,15 @@ pub static AiUploadOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M400 317.7h73.9V656c0 4.4 3.6 8 8 8h60c4.4 0 8-3.6 8-8V317.7H624c6.7 0 10.4-7.7 6.3-12.9L518.3 163a8 8 0 0 0-12.6 0l-112 141.7c-4.1 5.3-.4 13 6.3 13zM878 626h-60c-4.4 0-8 3.6-8 8v154H214V634c0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8v198c0 17.7 14.3 32 32 32h684c17.7 0 32-14.3 32-32V634c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUsbFilled, "USB Filled"))]
 pub static AiUsbFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUsbFilled",
+    label: "USB Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12366,8 +17577,15 @@ pub static AiUsbFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M408 312h48c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8zm352 120V144c0-17.7-14.3-32-32-32H296c-17.7 0-32 14.3-32 32v288c-66.2 0-120 52.1-120 116v356c0 4.4 3.6 8 8 8h720c4.4 0 8-3.6 8-8V548c0-63.9-53.8-116-120-116zm-72 0H336V184h352v248zM568 312h48c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUsbOutlined, "USB Outlined"))]
 pub static AiUsbOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUsbOutlined",
+    label: "USB Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12382,8 +17600,15 @@ pub static AiUsbOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M760 432V144c0-17.7-14.3-32-32-32H296c-17.7 0-32 14.3-32 32v288c-66.2 0-120 52.1-120 116v356c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V548c0-24.3 21.6-44 48.1-44h495.8c26.5 0 48.1 19.7 48.1 44v356c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8V548c0-63.9-53.8-116-120-116zm-424 0V184h352v248H336zm120-184h-48c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zm160 0h-48c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUsbTwotone, "USB Twotone"))]
 pub static AiUsbTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUsbTwotone",
+    label: "USB Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12400,8 +17625,15 @@ pub static AiUsbTwotone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M760 432V144c0-17.7-14.3-32-32-32H296c-17.7 0-32 14.3-32 32v288c-66.2 0-120 52.1-120 116v356c0 4.4 3.6 8 8 8h720c4.4 0 8-3.6 8-8V548c0-63.9-53.8-116-120-116zM336 184h352v248H336V184zm472 656H216V548c0-24.3 21.6-44 48.1-44h495.8c26.5 0 48.1 19.7 48.1 44v292z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUserAddOutlined, "User Add Outlined"))]
 pub static AiUserAddOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUserAddOutlined",
+    label: "User Add Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12416,8 +17648,15 @@ pub static AiUserAddOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M678.3 642.4c24.2-13 51.9-20.4 81.4-20.4h.1c3 0 4.4-3.6 2.2-5.6a371.67 371.67 0 0 0-103.7-65.8c-.4-.2-.8-.3-1.2-.5C719.2 505 759.6 431.7 759.6 349c0-137-110.8-248-247.5-248S264.7 212 264.7 349c0 82.7 40.4 156 102.6 201.1-.4.2-.8.3-1.2.5-44.7 18.9-84.8 46-119.3 80.6a373.42 373.42 0 0 0-80.4 119.5A373.6 373.6 0 0 0 137 888.8a8 8 0 0 0 8 8.2h59.9c4.3 0 7.9-3.5 8-7.8 2-77.2 32.9-149.5 87.6-204.3C357 628.2 432.2 597 512.2 597c56.7 0 111.1 15.7 158 45.1a8.1 8.1 0 0 0 8.1.3zM512.2 521c-45.8 0-88.9-17.9-121.4-50.4A171.2 171.2 0 0 1 340.5 349c0-45.9 17.9-89.1 50.3-121.6S466.3 177 512.2 177s88.9 17.9 121.4 50.4A171.2 171.2 0 0 1 683.9 349c0 45.9-17.9 89.1-50.3 121.6C601.1 503.1 558 521 512.2 521zM880 759h-84v-84c0-4.4-3.6-8-8-8h-56c-4.4 0-8 3.6-8 8v84h-84c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h84v84c0 4.4 3.6 8 8 8h56c4.4 0 8-3.6 8-8v-84h84c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUserDeleteOutlined, "User Delete Outlined"))]
 pub static AiUserDeleteOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUserDeleteOutlined",
+    label: "User Delete Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12432,8 +17671,15 @@ pub static AiUserDeleteOutlined: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M678.3 655.4c24.2-13 51.9-20.4 81.4-20.4h.1c3 0 4.4-3.6 2.2-5.6a371.67 371.67 0 0 0-103.7-65.8c-.4-.2-.8-.3-1.2-.5C719.2 518 759.6 444.7 759.6 362c0-137-110.8-248-247.5-248S264.7 225 264.7 362c0 82.7 40.4 156 102.6 201.1-.4.2-.8.3-1.2.5-44.7 18.9-84.8 46-119.3 80.6a373.42 373.42 0 0 0-80.4 119.5A373.6 373.6 0 0 0 137 901.8a8 8 0 0 0 8 8.2h59.9c4.3 0 7.9-3.5 8-7.8 2-77.2 32.9-149.5 87.6-204.3C357 641.2 432.2 610 512.2 610c56.7 0 111.1 15.7 158 45.1a8.1 8.1 0 0 0 8.1.3zM512.2 534c-45.8 0-88.9-17.9-121.4-50.4A171.2 171.2 0 0 1 340.5 362c0-45.9 17.9-89.1 50.3-121.6S466.3 190 512.2 190s88.9 17.9 121.4 50.4A171.2 171.2 0 0 1 683.9 362c0 45.9-17.9 89.1-50.3 121.6C601.1 516.1 558 534 512.2 534zM880 772H640c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h240c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUserOutlined, "User Outlined"))]
 pub static AiUserOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUserOutlined",
+    label: "User Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12448,8 +17694,15 @@ pub static AiUserOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M858.5 763.6a374 374 0 0 0-80.6-119.5 375.63 375.63 0 0 0-119.5-80.6c-.4-.2-.8-.3-1.2-.5C719.5 518 760 444.7 760 362c0-137-111-248-248-248S264 225 264 362c0 82.7 40.5 156 102.8 201.1-.4.2-.8.3-1.2.5-44.8 18.9-85 46-119.5 80.6a375.63 375.63 0 0 0-80.6 119.5A371.7 371.7 0 0 0 136 901.8a8 8 0 0 0 8 8.2h60c4.4 0 7.9-3.5 8-7.8 2-77.2 33-149.5 87.8-204.3 56.7-56.7 132-87.9 212.2-87.9s155.5 31.2 212.2 87.9C779 752.7 810 825 812 902.2c.1 4.4 3.6 7.8 8 7.8h60a8 8 0 0 0 8-8.2c-1-47.8-10.9-94.3-29.5-138.2zM512 534c-45.9 0-89.1-17.9-121.6-50.4S340 407.9 340 362c0-45.9 17.9-89.1 50.4-121.6S466.1 190 512 190s89.1 17.9 121.6 50.4S684 316.1 684 362c0 45.9-17.9 89.1-50.4 121.6S557.9 534 512 534z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUserSwitchOutlined, "User Switch Outlined"))]
 pub static AiUserSwitchOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUserSwitchOutlined",
+    label: "User Switch Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12467,8 +17720,15 @@ pub static AiUserSwitchOutlined: &icondata_core::IconData = &icondata_core::Icon
 <path d="M759 335c0-137-111-248-248-248S263 198 263 335c0 82.8 40.6 156.2 103 201.2-0.4 0.2-0.7 0.3-0.9 0.4-44.7 18.9-84.8 46-119.3 80.6-34.5 34.5-61.5 74.7-80.4 119.5C146.9 780.5 137 827 136 874.8c-0.1 4.5 3.5 8.2 8 8.2h59.9c4.3 0 7.9-3.5 8-7.8 2-77.2 32.9-149.5 87.6-204.3C356 614.2 431 583 511 583c137 0 248-111 248-248zM511 507c-95 0-172-77-172-172s77-172 172-172 172 77 172 172-77 172-172 172zM616 728h264c4.4 0 8-3.6 8-8v-56c0-4.4-3.6-8-8-8H703.5l47.2-60.1c1.1-1.4 1.7-3.2 1.7-4.9 0-4.4-3.6-8-8-8h-72.6c-4.9 0-9.5 2.3-12.6 6.1l-68.5 87.1c-4.4 5.6-6.8 12.6-6.8 19.8 0.1 17.7 14.4 32 32.1 32zM856 792H592c-4.4 0-8 3.6-8 8v56c0 4.4 3.6 8 8 8h176.5l-47.2 60.1c-1.1 1.4-1.7 3.2-1.7 4.9 0 4.4 3.6 8 8 8h72.6c4.9 0 9.5-2.3 12.6-6.1l68.5-87.1c4.4-5.6 6.8-12.6 6.8-19.8-0.1-17.7-14.4-32-32.1-32z" p-id="14855" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUsergroupAddOutlined, "Usergroup Add Outlined"))]
 pub static AiUsergroupAddOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUsergroupAddOutlined",
+    label: "Usergroup Add Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12483,8 +17743,15 @@ pub static AiUsergroupAddOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M892 772h-80v-80c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8v80h-80c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h80v80c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8v-80h80c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zM373.5 498.4c-.9-8.7-1.4-17.5-1.4-26.4 0-15.9 1.5-31.4 4.3-46.5.7-3.6-1.2-7.3-4.5-8.8-13.6-6.1-26.1-14.5-36.9-25.1a127.54 127.54 0 0 1-38.7-95.4c.9-32.1 13.8-62.6 36.3-85.6 24.7-25.3 57.9-39.1 93.2-38.7 31.9.3 62.7 12.6 86 34.4 7.9 7.4 14.7 15.6 20.4 24.4 2 3.1 5.9 4.4 9.3 3.2 17.6-6.1 36.2-10.4 55.3-12.4 5.6-.6 8.8-6.6 6.3-11.6-32.5-64.3-98.9-108.7-175.7-109.9-110.8-1.7-203.2 89.2-203.2 200 0 62.8 28.9 118.8 74.2 155.5-31.8 14.7-61.1 35-86.5 60.4-54.8 54.7-85.8 126.9-87.8 204a8 8 0 0 0 8 8.2h56.1c4.3 0 7.9-3.4 8-7.7 1.9-58 25.4-112.3 66.7-153.5 29.4-29.4 65.4-49.8 104.7-59.7 3.8-1.1 6.4-4.8 5.9-8.8zM824 472c0-109.4-87.9-198.3-196.9-200C516.3 270.3 424 361.2 424 472c0 62.8 29 118.8 74.2 155.5a300.95 300.95 0 0 0-86.4 60.4C357 742.6 326 814.8 324 891.8a8 8 0 0 0 8 8.2h56c4.3 0 7.9-3.4 8-7.7 1.9-58 25.4-112.3 66.7-153.5C505.8 695.7 563 672 624 672c110.4 0 200-89.5 200-200zm-109.5 90.5C690.3 586.7 658.2 600 624 600s-66.3-13.3-90.5-37.5a127.26 127.26 0 0 1-37.5-91.8c.3-32.8 13.4-64.5 36.3-88 24-24.6 56.1-38.3 90.4-38.7 33.9-.3 66.8 12.9 91 36.6 24.8 24.3 38.4 56.8 38.4 91.4-.1 34.2-13.4 66.3-37.6 90.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiUsergroupDeleteOutlined, "Usergroup Delete Outlined"))]
 pub static AiUsergroupDeleteOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiUsergroupDeleteOutlined",
+    label: "Usergroup Delete Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12499,8 +17766,15 @@ pub static AiUsergroupDeleteOutlined: &icondata_core::IconData = &icondata_core:
     data: r###"<path d="M888 784H664c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h224c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8zM373.5 510.4c-.9-8.7-1.4-17.5-1.4-26.4 0-15.9 1.5-31.4 4.3-46.5.7-3.6-1.2-7.3-4.5-8.8-13.6-6.1-26.1-14.5-36.9-25.1a127.54 127.54 0 0 1-38.7-95.4c.9-32.1 13.8-62.6 36.3-85.6 24.7-25.3 57.9-39.1 93.2-38.7 31.9.3 62.7 12.6 86 34.4 7.9 7.4 14.7 15.6 20.4 24.4 2 3.1 5.9 4.4 9.3 3.2 17.6-6.1 36.2-10.4 55.3-12.4 5.6-.6 8.8-6.6 6.3-11.6-32.5-64.3-98.9-108.7-175.7-109.9-110.9-1.7-203.3 89.2-203.3 199.9 0 62.8 28.9 118.8 74.2 155.5-31.8 14.7-61.1 35-86.5 60.4-54.8 54.7-85.8 126.9-87.8 204a8 8 0 0 0 8 8.2h56.1c4.3 0 7.9-3.4 8-7.7 1.9-58 25.4-112.3 66.7-153.5 29.4-29.4 65.4-49.8 104.7-59.7 3.9-1 6.5-4.7 6-8.7zM824 484c0-109.4-87.9-198.3-196.9-200C516.3 282.3 424 373.2 424 484c0 62.8 29 118.8 74.2 155.5a300.95 300.95 0 0 0-86.4 60.4C357 754.6 326 826.8 324 903.8a8 8 0 0 0 8 8.2h56c4.3 0 7.9-3.4 8-7.7 1.9-58 25.4-112.3 66.7-153.5C505.8 707.7 563 684 624 684c110.4 0 200-89.5 200-200zm-109.5 90.5C690.3 598.7 658.2 612 624 612s-66.3-13.3-90.5-37.5a127.26 127.26 0 0 1-37.5-91.8c.3-32.8 13.4-64.5 36.3-88 24-24.6 56.1-38.3 90.4-38.7 33.9-.3 66.8 12.9 91 36.6 24.8 24.3 38.4 56.8 38.4 91.4-.1 34.2-13.4 66.3-37.6 90.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiVerifiedOutlined, "Verified Outlined"))]
 pub static AiVerifiedOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiVerifiedOutlined",
+    label: "Verified Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12520,8 +17794,15 @@ pub static AiVerifiedOutlined: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M452 297v36c0 4.4 3.6 8 8 8h108v274h-38V405c0-4.4-3.6-8-8-8h-35c-4.4 0-8 3.6-8 8v210h-31c-4.4 0-8 3.6-8 8v37c0 4.4 3.6 8 8 8h244c4.4 0 8-3.6 8-8v-37c0-4.4-3.6-8-8-8h-72V493h58c4.4 0 8-3.6 8-8v-35c0-4.4-3.6-8-8-8h-58V341h63c4.4 0 8-3.6 8-8v-36c0-4.4-3.6-8-8-8H460c-4.4 0-8 3.6-8 8z" p-id="14617" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiVerticalAlignBottomOutlined, "Vertical Align Bottom Outlined"))]
 pub static AiVerticalAlignBottomOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiVerticalAlignBottomOutlined",
+    label: "Vertical Align Bottom Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12536,8 +17817,15 @@ pub static AiVerticalAlignBottomOutlined: &icondata_core::IconData = &icondata_c
     data: r###"<path d="M859.9 780H164.1c-4.5 0-8.1 3.6-8.1 8v60c0 4.4 3.6 8 8.1 8h695.8c4.5 0 8.1-3.6 8.1-8v-60c0-4.4-3.6-8-8.1-8zM505.7 669a8 8 0 0 0 12.6 0l112-141.7c4.1-5.2.4-12.9-6.3-12.9h-74.1V176c0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8v338.3H400c-6.7 0-10.4 7.7-6.3 12.9l112 141.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiVerticalAlignMiddleOutlined, "Vertical Align Middle Outlined"))]
 pub static AiVerticalAlignMiddleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiVerticalAlignMiddleOutlined",
+    label: "Vertical Align Middle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12552,8 +17840,15 @@ pub static AiVerticalAlignMiddleOutlined: &icondata_core::IconData = &icondata_c
     data: r###"<path d="M859.9 474H164.1c-4.5 0-8.1 3.6-8.1 8v60c0 4.4 3.6 8 8.1 8h695.8c4.5 0 8.1-3.6 8.1-8v-60c0-4.4-3.6-8-8.1-8zm-353.6-74.7c2.9 3.7 8.5 3.7 11.3 0l100.8-127.5c3.7-4.7.4-11.7-5.7-11.7H550V104c0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8v156h-62.8c-6 0-9.4 7-5.7 11.7l100.8 127.6zm11.4 225.4a7.14 7.14 0 0 0-11.3 0L405.6 752.3a7.23 7.23 0 0 0 5.7 11.7H474v156c0 4.4 3.6 8 8 8h60c4.4 0 8-3.6 8-8V764h62.8c6 0 9.4-7 5.7-11.7L517.7 624.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiVerticalAlignTopOutlined, "Vertical Align Top Outlined"))]
 pub static AiVerticalAlignTopOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiVerticalAlignTopOutlined",
+    label: "Vertical Align Top Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12568,8 +17863,15 @@ pub static AiVerticalAlignTopOutlined: &icondata_core::IconData = &icondata_core
     data: r###"<path d="M859.9 168H164.1c-4.5 0-8.1 3.6-8.1 8v60c0 4.4 3.6 8 8.1 8h695.8c4.5 0 8.1-3.6 8.1-8v-60c0-4.4-3.6-8-8.1-8zM518.3 355a8 8 0 0 0-12.6 0l-112 141.7a7.98 7.98 0 0 0 6.3 12.9h73.9V848c0 4.4 3.6 8 8 8h60c4.4 0 8-3.6 8-8V509.7H624c6.7 0 10.4-7.7 6.3-12.9L518.3 355z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiVerticalLeftOutlined, "Vertical Left Outlined"))]
 pub static AiVerticalLeftOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiVerticalLeftOutlined",
+    label: "Vertical Left Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12584,8 +17886,15 @@ pub static AiVerticalLeftOutlined: &icondata_core::IconData = &icondata_core::Ic
     data: r###"<path d="M762 164h-64c-4.4 0-8 3.6-8 8v688c0 4.4 3.6 8 8 8h64c4.4 0 8-3.6 8-8V172c0-4.4-3.6-8-8-8zm-508 0v72.4c0 9.5 4.2 18.4 11.4 24.5L564.6 512 265.4 763.1c-7.2 6.1-11.4 15-11.4 24.5V860c0 6.8 7.9 10.5 13.1 6.1L689 512 267.1 157.9A7.95 7.95 0 0 0 254 164z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiVerticalRightOutlined, "Vertical Right Outlined"))]
 pub static AiVerticalRightOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiVerticalRightOutlined",
+    label: "Vertical Right Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12600,8 +17909,15 @@ pub static AiVerticalRightOutlined: &icondata_core::IconData = &icondata_core::I
     data: r###"<path d="M326 164h-64c-4.4 0-8 3.6-8 8v688c0 4.4 3.6 8 8 8h64c4.4 0 8-3.6 8-8V172c0-4.4-3.6-8-8-8zm444 72.4V164c0-6.8-7.9-10.5-13.1-6.1L335 512l421.9 354.1c5.2 4.4 13.1.7 13.1-6.1v-72.4c0-9.4-4.2-18.4-11.4-24.5L459.4 512l299.2-251.1c7.2-6.1 11.4-15.1 11.4-24.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiVideoCameraAddOutlined, "Video Camera Add Outlined"))]
 pub static AiVideoCameraAddOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiVideoCameraAddOutlined",
+    label: "Video Camera Add Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12621,8 +17937,15 @@ pub static AiVideoCameraAddOutlined: &icondata_core::IconData = &icondata_core::
 <path d="M320 360c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H208c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8h112z" p-id="14737" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiVideoCameraFilled, "Video Camera Filled"))]
 pub static AiVideoCameraFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiVideoCameraFilled",
+    label: "Video Camera Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12637,8 +17960,15 @@ pub static AiVideoCameraFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M912 302.3L784 376V224c0-35.3-28.7-64-64-64H128c-35.3 0-64 28.7-64 64v576c0 35.3 28.7 64 64 64h592c35.3 0 64-28.7 64-64V648l128 73.7c21.3 12.3 48-3.1 48-27.6V330c0-24.6-26.7-40-48-27.7zM328 352c0 4.4-3.6 8-8 8H208c-4.4 0-8-3.6-8-8v-48c0-4.4 3.6-8 8-8h112c4.4 0 8 3.6 8 8v48zm560 273l-104-59.8V458.9L888 399v226z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiVideoCameraOutlined, "Video Camera Outlined"))]
 pub static AiVideoCameraOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiVideoCameraOutlined",
+    label: "Video Camera Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12653,8 +17983,15 @@ pub static AiVideoCameraOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M912 302.3L784 376V224c0-35.3-28.7-64-64-64H128c-35.3 0-64 28.7-64 64v576c0 35.3 28.7 64 64 64h592c35.3 0 64-28.7 64-64V648l128 73.7c21.3 12.3 48-3.1 48-27.6V330c0-24.6-26.7-40-48-27.7zM712 792H136V232h576v560zm176-167l-104-59.8V458.9L888 399v226zM208 360h112c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H208c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiVideoCameraTwotone, "Video Camera Twotone"))]
 pub static AiVideoCameraTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiVideoCameraTwotone",
+    label: "Video Camera Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12671,8 +18008,15 @@ pub static AiVideoCameraTwotone: &icondata_core::IconData = &icondata_core::Icon
 <path d="M208 360h112c4.4 0 8-3.6 8-8v-48c0-4.4-3.6-8-8-8H208c-4.4 0-8 3.6-8 8v48c0 4.4 3.6 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWalletFilled, "Wallet Filled"))]
 pub static AiWalletFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWalletFilled",
+    label: "Wallet Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12687,8 +18031,15 @@ pub static AiWalletFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-32 464H528V448h320v128zm-268-64a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWalletOutlined, "Wallet Outlined"))]
 pub static AiWalletOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWalletOutlined",
+    label: "Wallet Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12703,8 +18054,15 @@ pub static AiWalletOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zm-40 464H528V448h312v128zm0 264H184V184h656v200H496c-17.7 0-32 14.3-32 32v192c0 17.7 14.3 32 32 32h344v200zM580 512a40 40 0 1 0 80 0 40 40 0 1 0-80 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWalletTwotone, "Wallet Twotone"))]
 pub static AiWalletTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWalletTwotone",
+    label: "Wallet Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12722,8 +18080,15 @@ pub static AiWalletTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path fill="#E6E6E6" d="M184 840h656V640H496c-17.7 0-32-14.3-32-32V416c0-17.7 14.3-32 32-32h344V184H184v656z" class="twotone-icon-secondary-color" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWarningFilled, "Warning Filled"))]
 pub static AiWarningFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWarningFilled",
+    label: "Warning Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12738,8 +18103,15 @@ pub static AiWarningFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M955.7 856l-416-720c-6.2-10.7-16.9-16-27.7-16s-21.6 5.3-27.7 16l-416 720C56 877.4 71.4 904 96 904h832c24.6 0 40-26.6 27.7-48zM480 416c0-4.4 3.6-8 8-8h48c4.4 0 8 3.6 8 8v184c0 4.4-3.6 8-8 8h-48c-4.4 0-8-3.6-8-8V416zm32 352a48.01 48.01 0 0 1 0-96 48.01 48.01 0 0 1 0 96z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWarningOutlined, "Warning Outlined"))]
 pub static AiWarningOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWarningOutlined",
+    label: "Warning Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12754,8 +18126,15 @@ pub static AiWarningOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M464 720a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm16-304v184c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V416c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8zm475.7 440l-416-720c-6.2-10.7-16.9-16-27.7-16s-21.6 5.3-27.7 16l-416 720C56 877.4 71.4 904 96 904h832c24.6 0 40-26.6 27.7-48zm-783.5-27.9L512 239.9l339.8 588.2H172.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "twotone")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWarningTwotone, "Warning Twotone"))]
 pub static AiWarningTwotone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWarningTwotone",
+    label: "Warning Twotone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12772,8 +18151,15 @@ pub static AiWarningTwotone: &icondata_core::IconData = &icondata_core::IconData
 <path d="M464 720a48 48 0 1 0 96 0 48 48 0 1 0-96 0zm16-304v184c0 4.4 3.6 8 8 8h48c4.4 0 8-3.6 8-8V416c0-4.4-3.6-8-8-8h-48c-4.4 0-8 3.6-8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWechatFilled, "Wechat Filled"))]
 pub static AiWechatFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWechatFilled",
+    label: "Wechat Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12788,8 +18174,15 @@ pub static AiWechatFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M690.1 377.4c5.9 0 11.8.2 17.6.5-24.4-128.7-158.3-227.1-319.9-227.1C209 150.8 64 271.4 64 420.2c0 81.1 43.6 154.2 111.9 203.6a21.5 21.5 0 0 1 9.1 17.6c0 2.4-.5 4.6-1.1 6.9-5.5 20.3-14.2 52.8-14.6 54.3-.7 2.6-1.7 5.2-1.7 7.9 0 5.9 4.8 10.8 10.8 10.8 2.3 0 4.2-.9 6.2-2l70.9-40.9c5.3-3.1 11-5 17.2-5 3.2 0 6.4.5 9.5 1.4 33.1 9.5 68.8 14.8 105.7 14.8 6 0 11.9-.1 17.8-.4-7.1-21-10.9-43.1-10.9-66 0-135.8 132.2-245.8 295.3-245.8zm-194.3-86.5c23.8 0 43.2 19.3 43.2 43.1s-19.3 43.1-43.2 43.1c-23.8 0-43.2-19.3-43.2-43.1s19.4-43.1 43.2-43.1zm-215.9 86.2c-23.8 0-43.2-19.3-43.2-43.1s19.3-43.1 43.2-43.1 43.2 19.3 43.2 43.1-19.4 43.1-43.2 43.1zm586.8 415.6c56.9-41.2 93.2-102 93.2-169.7 0-124-120.8-224.5-269.9-224.5-149 0-269.9 100.5-269.9 224.5S540.9 847.5 690 847.5c30.8 0 60.6-4.4 88.1-12.3 2.6-.8 5.2-1.2 7.9-1.2 5.2 0 9.9 1.6 14.3 4.1l59.1 34c1.7 1 3.3 1.7 5.2 1.7a9 9 0 0 0 6.4-2.6 9 9 0 0 0 2.6-6.4c0-2.2-.9-4.4-1.4-6.6-.3-1.2-7.6-28.3-12.2-45.3-.5-1.9-.9-3.8-.9-5.7.1-5.9 3.1-11.2 7.6-14.5zM600.2 587.2c-19.9 0-36-16.1-36-35.9 0-19.8 16.1-35.9 36-35.9s36 16.1 36 35.9c0 19.8-16.2 35.9-36 35.9zm179.9 0c-19.9 0-36-16.1-36-35.9 0-19.8 16.1-35.9 36-35.9s36 16.1 36 35.9a36.08 36.08 0 0 1-36 35.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWechatOutlined, "Wechat Outlined"))]
 pub static AiWechatOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWechatOutlined",
+    label: "Wechat Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12804,8 +18197,15 @@ pub static AiWechatOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M690.1 377.4c5.9 0 11.8.2 17.6.5-24.4-128.7-158.3-227.1-319.9-227.1C209 150.8 64 271.4 64 420.2c0 81.1 43.6 154.2 111.9 203.6a21.5 21.5 0 0 1 9.1 17.6c0 2.4-.5 4.6-1.1 6.9-5.5 20.3-14.2 52.8-14.6 54.3-.7 2.6-1.7 5.2-1.7 7.9 0 5.9 4.8 10.8 10.8 10.8 2.3 0 4.2-.9 6.2-2l70.9-40.9c5.3-3.1 11-5 17.2-5 3.2 0 6.4.5 9.5 1.4 33.1 9.5 68.8 14.8 105.7 14.8 6 0 11.9-.1 17.8-.4-7.1-21-10.9-43.1-10.9-66 0-135.8 132.2-245.8 295.3-245.8zm-194.3-86.5c23.8 0 43.2 19.3 43.2 43.1s-19.3 43.1-43.2 43.1c-23.8 0-43.2-19.3-43.2-43.1s19.4-43.1 43.2-43.1zm-215.9 86.2c-23.8 0-43.2-19.3-43.2-43.1s19.3-43.1 43.2-43.1 43.2 19.3 43.2 43.1-19.4 43.1-43.2 43.1zm586.8 415.6c56.9-41.2 93.2-102 93.2-169.7 0-124-120.8-224.5-269.9-224.5-149 0-269.9 100.5-269.9 224.5S540.9 847.5 690 847.5c30.8 0 60.6-4.4 88.1-12.3 2.6-.8 5.2-1.2 7.9-1.2 5.2 0 9.9 1.6 14.3 4.1l59.1 34c1.7 1 3.3 1.7 5.2 1.7a9 9 0 0 0 6.4-2.6 9 9 0 0 0 2.6-6.4c0-2.2-.9-4.4-1.4-6.6-.3-1.2-7.6-28.3-12.2-45.3-.5-1.9-.9-3.8-.9-5.7.1-5.9 3.1-11.2 7.6-14.5zM600.2 587.2c-19.9 0-36-16.1-36-35.9 0-19.8 16.1-35.9 36-35.9s36 16.1 36 35.9c0 19.8-16.2 35.9-36 35.9zm179.9 0c-19.9 0-36-16.1-36-35.9 0-19.8 16.1-35.9 36-35.9s36 16.1 36 35.9a36.08 36.08 0 0 1-36 35.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWeiboCircleFilled, "Weibo Circle Filled"))]
 pub static AiWeiboCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWeiboCircleFilled",
+    label: "Weibo Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12820,8 +18220,15 @@ pub static AiWeiboCircleFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm-44.4 672C353.1 736 236 680.4 236 588.9c0-47.8 30.2-103.1 82.3-155.3 69.5-69.6 150.6-101.4 181.1-70.8 13.5 13.5 14.8 36.8 6.1 64.6-4.5 14 13.1 6.3 13.1 6.3 56.2-23.6 105.2-25 123.1.7 9.6 13.7 8.6 32.8-.2 55.1-4.1 10.2 1.3 11.8 9 14.1 31.7 9.8 66.9 33.6 66.9 75.5.2 69.5-99.7 156.9-249.8 156.9zm207.3-290.8a34.9 34.9 0 0 0-7.2-34.1 34.68 34.68 0 0 0-33.1-10.7 18.24 18.24 0 0 1-7.6-35.7c24.1-5.1 50.1 2.3 67.7 21.9 17.7 19.6 22.4 46.3 14.9 69.8a18.13 18.13 0 0 1-22.9 11.7 18.18 18.18 0 0 1-11.8-22.9zm106 34.3s0 .1 0 0a21.1 21.1 0 0 1-26.6 13.7 21.19 21.19 0 0 1-13.6-26.7c11-34.2 4-73.2-21.7-101.8a104.04 104.04 0 0 0-98.9-32.1 21.14 21.14 0 0 1-25.1-16.3 21.07 21.07 0 0 1 16.2-25.1c49.4-10.5 102.8 4.8 139.1 45.1 36.3 40.2 46.1 95.1 30.6 143.2zm-334.5 6.1c-91.4 9-160.7 65.1-154.7 125.2 5.9 60.1 84.8 101.5 176.2 92.5 91.4-9.1 160.7-65.1 154.7-125.3-5.9-60.1-84.8-101.5-176.2-92.4zm80.2 141.7c-18.7 42.3-72.3 64.8-117.8 50.1-43.9-14.2-62.5-57.7-43.3-96.8 18.9-38.4 68-60.1 111.5-48.8 45 11.7 68 54.2 49.6 95.5zm-93-32.2c-14.2-5.9-32.4.2-41.2 13.9-8.8 13.8-4.7 30.2 9.3 36.6 14.3 6.5 33.2.3 42-13.8 8.8-14.3 4.2-30.6-10.1-36.7zm34.9-14.5c-5.4-2.2-12.2.5-15.4 5.8-3.1 5.4-1.4 11.5 4.1 13.8 5.5 2.3 12.6-.3 15.8-5.8 3-5.6 1-11.8-4.5-13.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWeiboCircleOutlined, "Weibo Circle Outlined"))]
 pub static AiWeiboCircleOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWeiboCircleOutlined",
+    label: "Weibo Circle Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12836,8 +18243,15 @@ pub static AiWeiboCircleOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm-44.4 672C353.1 736 236 680.4 236 588.9c0-47.8 30.2-103.1 82.3-155.3 69.5-69.6 150.6-101.4 181.1-70.8 13.5 13.5 14.8 36.8 6.1 64.6-4.5 14 13.1 6.3 13.1 6.3 56.2-23.6 105.2-25 123.1.7 9.6 13.7 8.6 32.8-.2 55.1-4.1 10.2 1.3 11.8 9 14.1 31.7 9.8 66.9 33.6 66.9 75.5.2 69.5-99.7 156.9-249.8 156.9zm207.3-290.8a34.9 34.9 0 0 0-7.2-34.1 34.68 34.68 0 0 0-33.1-10.7 18.24 18.24 0 0 1-7.6-35.7c24.1-5.1 50.1 2.3 67.7 21.9 17.7 19.6 22.4 46.3 14.9 69.8a18.13 18.13 0 0 1-22.9 11.7 18.18 18.18 0 0 1-11.8-22.9zm106 34.3s0 .1 0 0a21.1 21.1 0 0 1-26.6 13.7 21.19 21.19 0 0 1-13.6-26.7c11-34.2 4-73.2-21.7-101.8a104.04 104.04 0 0 0-98.9-32.1 21.14 21.14 0 0 1-25.1-16.3 21.07 21.07 0 0 1 16.2-25.1c49.4-10.5 102.8 4.8 139.1 45.1 36.3 40.2 46.1 95.1 30.6 143.2zm-334.5 6.1c-91.4 9-160.7 65.1-154.7 125.2 5.9 60.1 84.8 101.5 176.2 92.5 91.4-9.1 160.7-65.1 154.7-125.3-5.9-60.1-84.8-101.5-176.2-92.4zm80.2 141.7c-18.7 42.3-72.3 64.8-117.8 50.1-43.9-14.2-62.5-57.7-43.3-96.8 18.9-38.4 68-60.1 111.5-48.8 45 11.7 68 54.2 49.6 95.5zm-93-32.2c-14.2-5.9-32.4.2-41.2 13.9-8.8 13.8-4.7 30.2 9.3 36.6 14.3 6.5 33.2.3 42-13.8 8.8-14.3 4.2-30.6-10.1-36.7zm34.9-14.5c-5.4-2.2-12.2.5-15.4 5.8-3.1 5.4-1.4 11.5 4.1 13.8 5.5 2.3 12.6-.3 15.8-5.8 3-5.6 1-11.8-4.5-13.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWeiboOutlined, "Weibo Outlined"))]
 pub static AiWeiboOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWeiboOutlined",
+    label: "Weibo Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12852,8 +18266,15 @@ pub static AiWeiboOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M457.3 543c-68.1-17.7-145 16.2-174.6 76.2-30.1 61.2-1 129.1 67.8 151.3 71.2 23 155.2-12.2 184.4-78.3 28.7-64.6-7.2-131-77.6-149.2zm-52 156.2c-13.8 22.1-43.5 31.7-65.8 21.6-22-10-28.5-35.7-14.6-57.2 13.7-21.4 42.3-31 64.4-21.7 22.4 9.5 29.6 35 16 57.3zm45.5-58.5c-5 8.6-16.1 12.7-24.7 9.1-8.5-3.5-11.2-13.1-6.4-21.5 5-8.4 15.6-12.4 24.1-9.1 8.7 3.2 11.8 12.9 7 21.5zm334.5-197.2c15 4.8 31-3.4 35.9-18.3 11.8-36.6 4.4-78.4-23.2-109a111.39 111.39 0 0 0-106-34.3 28.45 28.45 0 0 0-21.9 33.8 28.39 28.39 0 0 0 33.8 21.8c18.4-3.9 38.3 1.8 51.9 16.7a54.2 54.2 0 0 1 11.3 53.3 28.45 28.45 0 0 0 18.2 36zm99.8-206c-56.7-62.9-140.4-86.9-217.7-70.5a32.98 32.98 0 0 0-25.4 39.3 33.12 33.12 0 0 0 39.3 25.5c55-11.7 114.4 5.4 154.8 50.1 40.3 44.7 51.2 105.7 34 159.1-5.6 17.4 3.9 36 21.3 41.7 17.4 5.6 36-3.9 41.6-21.2v-.1c24.1-75.4 8.9-161.1-47.9-223.9zM729 499c-12.2-3.6-20.5-6.1-14.1-22.1 13.8-34.7 15.2-64.7.3-86-28-40.1-104.8-37.9-192.8-1.1 0 0-27.6 12.1-20.6-9.8 13.5-43.5 11.5-79.9-9.6-101-47.7-47.8-174.6 1.8-283.5 110.6C127.3 471.1 80 557.5 80 632.2 80 775.1 263.2 862 442.5 862c235 0 391.3-136.5 391.3-245 0-65.5-55.2-102.6-104.8-118zM443 810.8c-143 14.1-266.5-50.5-275.8-144.5-9.3-93.9 99.2-181.5 242.2-195.6 143-14.2 266.5 50.5 275.8 144.4C694.4 709 586 796.6 443 810.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWeiboSquareFilled, "Weibo Square Filled"))]
 pub static AiWeiboSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWeiboSquareFilled",
+    label: "Weibo Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12868,8 +18289,15 @@ pub static AiWeiboSquareFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M433.6 595.1c-14.2-5.9-32.4.2-41.2 13.9-8.8 13.8-4.7 30.2 9.3 36.6 14.3 6.5 33.2.3 42-13.8 8.8-14.3 4.2-30.6-10.1-36.7zM880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM467.6 736C353.1 736 236 680.4 236 588.9c0-47.8 30.2-103.1 82.3-155.3 69.5-69.6 150.6-101.4 181.1-70.8 13.5 13.5 14.8 36.8 6.1 64.6-4.5 14 13.1 6.3 13.1 6.3 56.2-23.6 105.2-25 123.1.7 9.6 13.7 8.6 32.8-.2 55.1-4.1 10.2 1.3 11.8 9 14.1 31.7 9.8 66.9 33.6 66.9 75.5.2 69.5-99.7 156.9-249.8 156.9zm207.3-290.8a34.9 34.9 0 0 0-7.2-34.1 34.68 34.68 0 0 0-33.1-10.7 18.24 18.24 0 0 1-7.6-35.7c24.1-5.1 50.1 2.3 67.7 21.9 17.7 19.6 22.4 46.3 14.9 69.8a18.13 18.13 0 0 1-22.9 11.7 18.18 18.18 0 0 1-11.8-22.9zm106 34.3s0 .1 0 0a21.1 21.1 0 0 1-26.6 13.7 21.19 21.19 0 0 1-13.6-26.7c11-34.2 4-73.2-21.7-101.8a104.04 104.04 0 0 0-98.9-32.1 21.14 21.14 0 0 1-25.1-16.3 21.07 21.07 0 0 1 16.2-25.1c49.4-10.5 102.8 4.8 139.1 45.1 36.3 40.2 46.1 95.1 30.6 143.2zm-334.5 6.1c-91.4 9-160.7 65.1-154.7 125.2 5.9 60.1 84.8 101.5 176.2 92.5 91.4-9.1 160.7-65.1 154.7-125.3-5.9-60.1-84.8-101.5-176.2-92.4zm80.2 141.7c-18.7 42.3-72.3 64.8-117.8 50.1-43.9-14.2-62.5-57.7-43.3-96.8 18.9-38.4 68-60.1 111.5-48.8 45 11.7 68 54.2 49.6 95.5zm-58.1-46.7c-5.4-2.2-12.2.5-15.4 5.8-3.1 5.4-1.4 11.5 4.1 13.8 5.5 2.3 12.6-.3 15.8-5.8 3-5.6 1-11.8-4.5-13.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWeiboSquareOutlined, "Weibo Square Outlined"))]
 pub static AiWeiboSquareOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWeiboSquareOutlined",
+    label: "Weibo Square Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12884,8 +18312,15 @@ pub static AiWeiboSquareOutlined: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="M433.6 595.1c-14.2-5.9-32.4.2-41.2 13.9-8.8 13.8-4.7 30.2 9.3 36.6 14.3 6.5 33.2.3 42-13.8 8.8-14.3 4.2-30.6-10.1-36.7zM880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM467.6 736C353.1 736 236 680.4 236 588.9c0-47.8 30.2-103.1 82.3-155.3 69.5-69.6 150.6-101.4 181.1-70.8 13.5 13.5 14.8 36.8 6.1 64.6-4.5 14 13.1 6.3 13.1 6.3 56.2-23.6 105.2-25 123.1.7 9.6 13.7 8.6 32.8-.2 55.1-4.1 10.2 1.3 11.8 9 14.1 31.7 9.8 66.9 33.6 66.9 75.5.2 69.5-99.7 156.9-249.8 156.9zm207.3-290.8a34.9 34.9 0 0 0-7.2-34.1 34.68 34.68 0 0 0-33.1-10.7 18.24 18.24 0 0 1-7.6-35.7c24.1-5.1 50.1 2.3 67.7 21.9 17.7 19.6 22.4 46.3 14.9 69.8a18.13 18.13 0 0 1-22.9 11.7 18.18 18.18 0 0 1-11.8-22.9zm106 34.3s0 .1 0 0a21.1 21.1 0 0 1-26.6 13.7 21.19 21.19 0 0 1-13.6-26.7c11-34.2 4-73.2-21.7-101.8a104.04 104.04 0 0 0-98.9-32.1 21.14 21.14 0 0 1-25.1-16.3 21.07 21.07 0 0 1 16.2-25.1c49.4-10.5 102.8 4.8 139.1 45.1 36.3 40.2 46.1 95.1 30.6 143.2zm-334.5 6.1c-91.4 9-160.7 65.1-154.7 125.2 5.9 60.1 84.8 101.5 176.2 92.5 91.4-9.1 160.7-65.1 154.7-125.3-5.9-60.1-84.8-101.5-176.2-92.4zm80.2 141.7c-18.7 42.3-72.3 64.8-117.8 50.1-43.9-14.2-62.5-57.7-43.3-96.8 18.9-38.4 68-60.1 111.5-48.8 45 11.7 68 54.2 49.6 95.5zm-58.1-46.7c-5.4-2.2-12.2.5-15.4 5.8-3.1 5.4-1.4 11.5 4.1 13.8 5.5 2.3 12.6-.3 15.8-5.8 3-5.6 1-11.8-4.5-13.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWhatsAppOutlined, "Whats App Outlined"))]
 pub static AiWhatsAppOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWhatsAppOutlined",
+    label: "Whats App Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12904,8 +18339,15 @@ pub static AiWhatsAppOutlined: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M925.2 338.4c-22.6-53.7-55-101.9-96.3-143.3-41.3-41.3-89.5-73.8-143.3-96.3C630.6 75.7 572.2 64 512 64h-2c-60.6 0.3-119.3 12.3-174.5 35.9-53.3 22.8-101.1 55.2-142 96.5-40.9 41.3-73 89.3-95.2 142.8-23 55.4-34.6 114.3-34.3 174.9 0.3 69.4 16.9 138.3 48 199.9v152c0 25.4 20.6 46 46 46h152.1c61.6 31.1 130.5 47.7 199.9 48h2.1c59.9 0 118-11.6 172.7-34.3 53.5-22.3 101.6-54.3 142.8-95.2 41.3-40.9 73.8-88.7 96.5-142 23.6-55.2 35.6-113.9 35.9-174.5 0.3-60.9-11.5-120-34.8-175.6z m-151.1 438C704 845.8 611 884 512 884h-1.7c-60.3-0.3-120.2-15.3-173.1-43.5l-8.4-4.5H188V695.2l-4.5-8.4C155.3 633.9 140.3 574 140 513.7c-0.4-99.7 37.7-193.3 107.6-263.8 69.8-70.5 163.1-109.5 262.8-109.9h1.7c50 0 98.5 9.7 144.2 28.9 44.6 18.7 84.6 45.6 119 80 34.3 34.3 61.3 74.4 80 119 19.4 46.2 29.1 95.2 28.9 145.8-0.6 99.6-39.7 192.9-110.1 262.7z" p-id="14974" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWifiOutlined, "Wifi Outlined"))]
 pub static AiWifiOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWifiOutlined",
+    label: "Wifi Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12920,8 +18362,15 @@ pub static AiWifiOutlined: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M723 620.5C666.8 571.6 593.4 542 513 542s-153.8 29.6-210.1 78.6a8.1 8.1 0 0 0-.8 11.2l36 42.9c2.9 3.4 8 3.8 11.4.9C393.1 637.2 450.3 614 513 614s119.9 23.2 163.5 61.5c3.4 2.9 8.5 2.5 11.4-.9l36-42.9c2.8-3.3 2.4-8.3-.9-11.2zm117.4-140.1C751.7 406.5 637.6 362 513 362s-238.7 44.5-327.5 118.4a8.05 8.05 0 0 0-1 11.3l36 42.9c2.8 3.4 7.9 3.8 11.2 1C308 472.2 406.1 434 513 434s205 38.2 281.2 101.6c3.4 2.8 8.4 2.4 11.2-1l36-42.9c2.8-3.4 2.4-8.5-1-11.3zm116.7-139C835.7 241.8 680.3 182 511 182c-168.2 0-322.6 59-443.7 157.4a8 8 0 0 0-1.1 11.4l36 42.9c2.8 3.3 7.8 3.8 11.1 1.1C222 306.7 360.3 254 511 254c151.8 0 291 53.5 400 142.7 3.4 2.8 8.4 2.3 11.2-1.1l36-42.9c2.9-3.4 2.4-8.5-1.1-11.3zM448 778a64 64 0 1 0 128 0 64 64 0 1 0-128 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWindowsFilled, "Windows Filled"))]
 pub static AiWindowsFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWindowsFilled",
+    label: "Windows Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12936,8 +18385,15 @@ pub static AiWindowsFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M523.8 191.4v288.9h382V128.1zm0 642.2l382 62.2v-352h-382zM120.1 480.2H443V201.9l-322.9 53.5zm0 290.4L443 823.2V543.8H120.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWindowsOutlined, "Windows Outlined"))]
 pub static AiWindowsOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWindowsOutlined",
+    label: "Windows Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12952,8 +18408,15 @@ pub static AiWindowsOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M120.1 770.6L443 823.2V543.8H120.1v226.8zm63.4-163.5h196.2v141.6l-196.2-31.9V607.1zm340.3 226.5l382 62.2v-352h-382v289.8zm63.4-226.5h255.3v214.4l-255.3-41.6V607.1zm-63.4-415.7v288.8h382V128.1l-382 63.3zm318.7 225.5H587.3V245l255.3-42.3v214.2zm-722.4 63.3H443V201.9l-322.9 53.5v224.8zM183.5 309l196.2-32.5v140.4H183.5V309z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiWomanOutlined, "Woman Outlined"))]
 pub static AiWomanOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiWomanOutlined",
+    label: "Woman Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12968,8 +18431,15 @@ pub static AiWomanOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M712.8 548.8c53.6-53.6 83.2-125 83.2-200.8 0-75.9-29.5-147.2-83.2-200.8C659.2 93.6 587.8 64 512 64s-147.2 29.5-200.8 83.2C257.6 200.9 228 272.1 228 348c0 63.8 20.9 124.4 59.4 173.9 7.3 9.4 15.2 18.3 23.7 26.9 8.5 8.5 17.5 16.4 26.8 23.7 39.6 30.8 86.3 50.4 136.1 57V736H360c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h114v140c0 4.4 3.6 8 8 8h60c4.4 0 8-3.6 8-8V812h114c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8H550V629.5c61.5-8.2 118.2-36.1 162.8-80.7zM512 556c-55.6 0-107.7-21.6-147.1-60.9C325.6 455.8 304 403.6 304 348s21.6-107.7 60.9-147.1C404.2 161.5 456.4 140 512 140s107.7 21.6 147.1 60.9C698.4 240.2 720 292.4 720 348s-21.6 107.7-60.9 147.1C619.7 534.4 567.6 556 512 556z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiYahooFilled, "Yahoo Filled"))]
 pub static AiYahooFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiYahooFilled",
+    label: "Yahoo Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12984,8 +18454,15 @@ pub static AiYahooFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M937.3 231H824.7c-15.5 0-27.7 12.6-27.1 28.1l13.1 366h84.4l65.4-366.4c2.7-15.2-7.8-27.7-23.2-27.7zm-77.4 450.4h-14.1c-27.1 0-49.2 22.2-49.2 49.3v14.1c0 27.1 22.2 49.3 49.2 49.3h14.1c27.1 0 49.2-22.2 49.2-49.3v-14.1c0-27.1-22.2-49.3-49.2-49.3zM402.6 231C216.2 231 65 357 65 512.5S216.2 794 402.6 794s337.6-126 337.6-281.5S589.1 231 402.6 231zm225.2 225.2h-65.3L458.9 559.8v65.3h84.4v56.3H318.2v-56.3h84.4v-65.3L242.9 399.9h-37v-56.3h168.5v56.3h-37l93.4 93.5 28.1-28.1V400h168.8v56.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiYahooOutlined, "Yahoo Outlined"))]
 pub static AiYahooOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiYahooOutlined",
+    label: "Yahoo Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13000,8 +18477,15 @@ pub static AiYahooOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M859.9 681.4h-14.1c-27.1 0-49.2 22.2-49.2 49.3v14.1c0 27.1 22.2 49.3 49.2 49.3h14.1c27.1 0 49.2-22.2 49.2-49.3v-14.1c0-27.1-22.2-49.3-49.2-49.3zM402.6 231C216.2 231 65 357 65 512.5S216.2 794 402.6 794s337.6-126 337.6-281.5S589.1 231 402.6 231zm0 507C245.1 738 121 634.6 121 512.5c0-62.3 32.3-119.7 84.9-161v48.4h37l159.8 159.9v65.3h-84.4v56.3h225.1v-56.3H459v-65.3l103.5-103.6h65.3v-56.3H459v65.3l-28.1 28.1-93.4-93.5h37v-56.3H216.4c49.4-35 114.3-56.6 186.2-56.6 157.6 0 281.6 103.4 281.6 225.5S560.2 738 402.6 738zm534.7-507H824.7c-15.5 0-27.7 12.6-27.1 28.1l13.1 366h84.4l65.4-366.4c2.7-15.2-7.8-27.7-23.2-27.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiYoutubeFilled, "Youtube Filled"))]
 pub static AiYoutubeFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiYoutubeFilled",
+    label: "Youtube Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13016,8 +18500,15 @@ pub static AiYoutubeFilled: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M941.3 296.1a112.3 112.3 0 0 0-79.2-79.3C792.2 198 512 198 512 198s-280.2 0-350.1 18.7A112.12 112.12 0 0 0 82.7 296C64 366 64 512 64 512s0 146 18.7 215.9c10.3 38.6 40.7 69 79.2 79.3C231.8 826 512 826 512 826s280.2 0 350.1-18.8c38.6-10.3 68.9-40.7 79.2-79.3C960 658 960 512 960 512s0-146-18.7-215.9zM423 646V378l232 133-232 135z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiYoutubeOutlined, "Youtube Outlined"))]
 pub static AiYoutubeOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiYoutubeOutlined",
+    label: "Youtube Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13032,8 +18523,15 @@ pub static AiYoutubeOutlined: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M960 509.2c0-2.2 0-4.7-.1-7.6-.1-8.1-.3-17.2-.5-26.9-.8-27.9-2.2-55.7-4.4-81.9-3-36.1-7.4-66.2-13.4-88.8a139.52 139.52 0 0 0-98.3-98.5c-28.3-7.6-83.7-12.3-161.7-15.2-37.1-1.4-76.8-2.3-116.5-2.8-13.9-.2-26.8-.3-38.4-.4h-29.4c-11.6.1-24.5.2-38.4.4-39.7.5-79.4 1.4-116.5 2.8-78 3-133.5 7.7-161.7 15.2A139.35 139.35 0 0 0 82.4 304C76.3 326.6 72 356.7 69 392.8c-2.2 26.2-3.6 54-4.4 81.9-.3 9.7-.4 18.8-.5 26.9 0 2.9-.1 5.4-.1 7.6v5.6c0 2.2 0 4.7.1 7.6.1 8.1.3 17.2.5 26.9.8 27.9 2.2 55.7 4.4 81.9 3 36.1 7.4 66.2 13.4 88.8 12.8 47.9 50.4 85.7 98.3 98.5 28.2 7.6 83.7 12.3 161.7 15.2 37.1 1.4 76.8 2.3 116.5 2.8 13.9.2 26.8.3 38.4.4h29.4c11.6-.1 24.5-.2 38.4-.4 39.7-.5 79.4-1.4 116.5-2.8 78-3 133.5-7.7 161.7-15.2 47.9-12.8 85.5-50.5 98.3-98.5 6.1-22.6 10.4-52.7 13.4-88.8 2.2-26.2 3.6-54 4.4-81.9.3-9.7.4-18.8.5-26.9 0-2.9.1-5.4.1-7.6v-5.6zm-72 5.2c0 2.1 0 4.4-.1 7.1-.1 7.8-.3 16.4-.5 25.7-.7 26.6-2.1 53.2-4.2 77.9-2.7 32.2-6.5 58.6-11.2 76.3-6.2 23.1-24.4 41.4-47.4 47.5-21 5.6-73.9 10.1-145.8 12.8-36.4 1.4-75.6 2.3-114.7 2.8-13.7.2-26.4.3-37.8.3h-28.6l-37.8-.3c-39.1-.5-78.2-1.4-114.7-2.8-71.9-2.8-124.9-7.2-145.8-12.8-23-6.2-41.2-24.4-47.4-47.5-4.7-17.7-8.5-44.1-11.2-76.3-2.1-24.7-3.4-51.3-4.2-77.9-.3-9.3-.4-18-.5-25.7 0-2.7-.1-5.1-.1-7.1v-4.8c0-2.1 0-4.4.1-7.1.1-7.8.3-16.4.5-25.7.7-26.6 2.1-53.2 4.2-77.9 2.7-32.2 6.5-58.6 11.2-76.3 6.2-23.1 24.4-41.4 47.4-47.5 21-5.6 73.9-10.1 145.8-12.8 36.4-1.4 75.6-2.3 114.7-2.8 13.7-.2 26.4-.3 37.8-.3h28.6l37.8.3c39.1.5 78.2 1.4 114.7 2.8 71.9 2.8 124.9 7.2 145.8 12.8 23 6.2 41.2 24.4 47.4 47.5 4.7 17.7 8.5 44.1 11.2 76.3 2.1 24.7 3.4 51.3 4.2 77.9.3 9.3.4 18 .5 25.7 0 2.7.1 5.1.1 7.1v4.8zM423 646l232-135-232-133z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiYuqueFilled, "Yuque Filled"))]
 pub static AiYuqueFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiYuqueFilled",
+    label: "Yuque Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13048,8 +18546,15 @@ pub static AiYuqueFilled: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M854.6 370.6c-9.9-39.4 9.9-102.2 73.4-124.4l-67.9-3.6s-25.7-90-143.6-98c-117.9-8.1-195-3-195-3s87.4 55.6 52.4 154.7c-25.6 52.5-65.8 95.6-108.8 144.7-1.3 1.3-2.5 2.6-3.5 3.7C319.4 605 96 860 96 860c245.9 64.4 410.7-6.3 508.2-91.1 20.5-.2 35.9-.3 46.3-.3 135.8 0 250.6-117.6 245.9-248.4-3.2-89.9-31.9-110.2-41.8-149.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiYuqueOutlined, "Yuque Outlined"))]
 pub static AiYuqueOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiYuqueOutlined",
+    label: "Yuque Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13064,8 +18569,15 @@ pub static AiYuqueOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M854.6 370.6c-9.9-39.4 9.9-102.2 73.4-124.4l-67.9-3.6s-25.7-90-143.6-98c-117.8-8.1-194.9-3-195-3 .1 0 87.4 55.6 52.4 154.7-25.6 52.5-65.8 95.6-108.8 144.7-1.3 1.3-2.5 2.6-3.5 3.7C319.4 605 96 860 96 860c245.9 64.4 410.7-6.3 508.2-91.1 20.5-.2 35.9-.3 46.3-.3 135.8 0 250.6-117.6 245.9-248.4-3.2-89.9-31.9-110.2-41.8-149.6zm-204.1 334c-10.6 0-26.2.1-46.8.3l-23.6.2-17.8 15.5c-47.1 41-104.4 71.5-171.4 87.6-52.5 12.6-110 16.2-172.7 9.6 18-20.5 36.5-41.6 55.4-63.1 92-104.6 173.8-197.5 236.9-268.5l1.4-1.4 1.3-1.5c4.1-4.6 20.6-23.3 24.7-28.1 9.7-11.1 17.3-19.9 24.5-28.6 30.7-36.7 52.2-67.8 69-102.2l1.6-3.3 1.2-3.4c13.7-38.8 15.4-76.9 6.2-112.8 22.5.7 46.5 1.9 71.7 3.6 33.3 2.3 55.5 12.9 71.1 29.2 5.8 6 10.2 12.5 13.4 18.7 1 2 1.7 3.6 2.3 5l5 17.7c-15.7 34.5-19.9 73.3-11.4 107.2 3 11.8 6.9 22.4 12.3 34.4 2.1 4.7 9.5 20.1 11 23.3 10.3 22.7 15.4 43 16.7 78.7 3.3 94.6-82.7 181.9-182 181.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiZhihuCircleFilled, "Zhihu Circle Filled"))]
 pub static AiZhihuCircleFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiZhihuCircleFilled",
+    label: "Zhihu Circle Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13080,8 +18592,15 @@ pub static AiZhihuCircleFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M512 64C264.6 64 64 264.6 64 512s200.6 448 448 448 448-200.6 448-448S759.4 64 512 64zm-90.7 477.8l-.1 1.5c-1.5 20.4-6.3 43.9-12.9 67.6l24-18.1 71 80.7c9.2 33-3.3 63.1-3.3 63.1l-95.7-111.9v-.1c-8.9 29-20.1 57.3-33.3 84.7-22.6 45.7-55.2 54.7-89.5 57.7-34.4 3-23.3-5.3-23.3-5.3 68-55.5 78-87.8 96.8-123.1 11.9-22.3 20.4-64.3 25.3-96.8H264.1s4.8-31.2 19.2-41.7h101.6c.6-15.3-1.3-102.8-2-131.4h-49.4c-9.2 45-41 56.7-48.1 60.1-7 3.4-23.6 7.1-21.1 0 2.6-7.1 27-46.2 43.2-110.7 16.3-64.6 63.9-62 63.9-62-12.8 22.5-22.4 73.6-22.4 73.6h159.7c10.1 0 10.6 39 10.6 39h-90.8c-.7 22.7-2.8 83.8-5 131.4H519s12.2 15.4 12.2 41.7H421.3zm346.5 167h-87.6l-69.5 46.6-16.4-46.6h-40.1V321.5h213.6v387.3zM408.2 611s0-.1 0 0zm216 94.3l56.8-38.1h45.6-.1V364.7H596.7v302.5h14.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiZhihuOutlined, "Zhihu Outlined"))]
 pub static AiZhihuOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiZhihuOutlined",
+    label: "Zhihu Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13096,8 +18615,15 @@ pub static AiZhihuOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M564.7 230.1V803h60l25.2 71.4L756.3 803h131.5V230.1H564.7zm247.7 497h-59.9l-75.1 50.4-17.8-50.4h-18V308.3h170.7v418.8zM526.1 486.9H393.3c2.1-44.9 4.3-104.3 6.6-172.9h130.9l-.1-8.1c0-.6-.2-14.7-2.3-29.1-2.1-15-6.6-34.9-21-34.9H287.8c4.4-20.6 15.7-69.7 29.4-93.8l6.4-11.2-12.9-.7c-.8 0-19.6-.9-41.4 10.6-35.7 19-51.7 56.4-58.7 84.4-18.4 73.1-44.6 123.9-55.7 145.6-3.3 6.4-5.3 10.2-6.2 12.8-1.8 4.9-.8 9.8 2.8 13 10.5 9.5 38.2-2.9 38.5-3 .6-.3 1.3-.6 2.2-1 13.9-6.3 55.1-25 69.8-84.5h56.7c.7 32.2 3.1 138.4 2.9 172.9h-141l-2.1 1.5c-23.1 16.9-30.5 63.2-30.8 65.2l-1.4 9.2h167c-12.3 78.3-26.5 113.4-34 127.4-3.7 7-7.3 14-10.7 20.8-21.3 42.2-43.4 85.8-126.3 153.6-3.6 2.8-7 8-4.8 13.7 2.4 6.3 9.3 9.1 24.6 9.1 5.4 0 11.8-.3 19.4-1 49.9-4.4 100.8-18 135.1-87.6 17-35.1 31.7-71.7 43.9-108.9L497 850l5-12c.8-1.9 19-46.3 5.1-95.9l-.5-1.8-108.1-123-22 16.6c6.4-26.1 10.6-49.9 12.5-71.1h158.7v-8c0-40.1-18.5-63.9-19.2-64.9l-2.4-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "filled")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiZhihuSquareFilled, "Zhihu Square Filled"))]
 pub static AiZhihuSquareFilled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiZhihuSquareFilled",
+    label: "Zhihu Square Filled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13112,8 +18638,15 @@ pub static AiZhihuSquareFilled: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M880 112H144c-17.7 0-32 14.3-32 32v736c0 17.7 14.3 32 32 32h736c17.7 0 32-14.3 32-32V144c0-17.7-14.3-32-32-32zM432.3 592.8l71 80.7c9.2 33-3.3 63.1-3.3 63.1l-95.7-111.9v-.1c-8.9 29-20.1 57.3-33.3 84.7-22.6 45.7-55.2 54.7-89.5 57.7-34.4 3-23.3-5.3-23.3-5.3 68-55.5 78-87.8 96.8-123.1 11.9-22.3 20.4-64.3 25.3-96.8H264.1s4.8-31.2 19.2-41.7h101.6c.6-15.3-1.3-102.8-2-131.4h-49.4c-9.2 45-41 56.7-48.1 60.1-7 3.4-23.6 7.1-21.1 0 2.6-7.1 27-46.2 43.2-110.7 16.3-64.6 63.9-62 63.9-62-12.8 22.5-22.4 73.6-22.4 73.6h159.7c10.1 0 10.6 39 10.6 39h-90.8c-.7 22.7-2.8 83.8-5 131.4H519s12.2 15.4 12.2 41.7h-110l-.1 1.5c-1.5 20.4-6.3 43.9-12.9 67.6l24.1-18.1zm335.5 116h-87.6l-69.5 46.6-16.4-46.6h-40.1V321.5h213.6v387.3zM408.2 611s0-.1 0 0zm216 94.3l56.8-38.1h45.6-.1V364.7H596.7v302.5h14.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiZoomInOutlined, "Zoom In Outlined"))]
 pub static AiZoomInOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiZoomInOutlined",
+    label: "Zoom In Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13128,8 +18661,15 @@ pub static AiZoomInOutlined: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M637 443H519V309c0-4.4-3.6-8-8-8h-60c-4.4 0-8 3.6-8 8v134H325c-4.4 0-8 3.6-8 8v60c0 4.4 3.6 8 8 8h118v134c0 4.4 3.6 8 8 8h60c4.4 0 8-3.6 8-8V519h118c4.4 0 8-3.6 8-8v-60c0-4.4-3.6-8-8-8zm284 424L775 721c122.1-148.9 113.6-369.5-26-509-148-148.1-388.4-148.1-537 0-148.1 148.6-148.1 389 0 537 139.5 139.6 360.1 148.1 509 26l146 146c3.2 2.8 8.3 2.8 11 0l43-43c2.8-2.7 2.8-7.8 0-11zM696 696c-118.8 118.7-311.2 118.7-430 0-118.7-118.8-118.7-311.2 0-430 118.8-118.7 311.2-118.7 430 0 118.7 118.8 118.7 311.2 0 430z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg(feature = "outlined")]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(AiZoomOutOutlined, "Zoom Out Outlined"))]
 pub static AiZoomOutOutlined: &icondata_core::IconData = &icondata_core::IconData {
+    id: "AiZoomOutOutlined",
+    label: "Zoom Out Outlined",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1,9 +1,32 @@
 //! This crate provides a collection of icons in the form of SVG data
 //! from the [__Charm__](https://github.com/jaynewey/charm-icons) icon set.
 
+/// Documentation of every icon, showing its usage with different frameworks.
+#[cfg(feature = "docs")]
+macro_rules! examples {
+    ($icon:ident, $label:literal) => {
+        concat!(
+            $label,
+            "\n\n# Examples\n\nWith [Leptos](https://leptos.dev) and [`leptos_icons`](https://docs.rs/leptos_icons):\n```rust,ignore\nuse leptos::*;\nuse leptos_icons::Icon;\n\nview! { <Icon icon=icondata::",
+            stringify!($icon),
+            " /> }\n```\n\nWith [Yew](https://yew.rs):\n```rust,ignore\nuse yew::prelude::*;\n\nlet svg = icondata::",
+            stringify!($icon),
+            ".render(&Default::default());\nhtml! { { Html::from_html_unchecked(AttrValue::from(svg)) } }\n```\n\nWith [Dioxus](https://dioxuslabs.com):\n```rust,ignore\nuse dioxus::prelude::*;\n\nlet svg = icondata::",
+            stringify!($icon),
+            ".render(&Default::default());\nrsx! { span { dangerous_inner_html: \"{svg}\" } }\n```",
+        )
+    };
+}
+
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChAnchor, "Anchor"))]
 pub static ChAnchor: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChAnchor",
+    label: "Anchor",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19,8 +42,14 @@ pub static ChAnchor: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="3.5" r="1.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChApps, "Apps"))]
 pub static ChApps: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChApps",
+    label: "Apps",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -38,8 +67,14 @@ pub static ChApps: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="4.5" height="4.5" x="9.75" y="1.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChAppsMinus, "Apps Minus"))]
 pub static ChAppsMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChAppsMinus",
+    label: "Apps Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -57,8 +92,14 @@ pub static ChAppsMinus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14.8 3.75h-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChAppsPlus, "Apps Plus"))]
 pub static ChAppsPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChAppsPlus",
+    label: "Apps Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -76,8 +117,14 @@ pub static ChAppsPlus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14.8 3.75h-5m2.5-2.5v5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChArchive, "Archive"))]
 pub static ChArchive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChArchive",
+    label: "Archive",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -93,8 +140,14 @@ pub static ChArchive: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m6.75 9.25h2.5m-6.5-2.5v7.5h10.5v-7.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChArrowDown, "Arrow Down"))]
 pub static ChArrowDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChArrowDown",
+    label: "Arrow Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -109,8 +162,14 @@ pub static ChArrowDown: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m3.25 8.75 4.5 4.5 4.5-4.5m-4.5-6v10.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChArrowDownLeft, "Arrow Down Left"))]
 pub static ChArrowDownLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChArrowDownLeft",
+    label: "Arrow Down Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -125,8 +184,14 @@ pub static ChArrowDownLeft: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="m10.75 11.75h-6.5v-6.5m7.5-1-7.5 7.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChArrowDownRight, "Arrow Down Right"))]
 pub static ChArrowDownRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChArrowDownRight",
+    label: "Arrow Down Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -141,8 +206,14 @@ pub static ChArrowDownRight: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="m5.25 11.75h6.5v-6.5m-7.5-1 7.5 7.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChArrowLeft, "Arrow Left"))]
 pub static ChArrowLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChArrowLeft",
+    label: "Arrow Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -157,8 +228,14 @@ pub static ChArrowLeft: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m7.25 3.75-4.5 4.5 4.5 4.5m6-4.5h-10.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChArrowRight, "Arrow Right"))]
 pub static ChArrowRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChArrowRight",
+    label: "Arrow Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -173,8 +250,14 @@ pub static ChArrowRight: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m8.75 3.25 4.5 4.5-4.5 4.5m-6-4.5h10.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChArrowUp, "Arrow Up"))]
 pub static ChArrowUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChArrowUp",
+    label: "Arrow Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -189,8 +272,14 @@ pub static ChArrowUp: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m3.75 7.25 4.5-4.5 4.5 4.5m-4.5 6v-10.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChArrowUpLeft, "Arrow Up Left"))]
 pub static ChArrowUpLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChArrowUpLeft",
+    label: "Arrow Up Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -205,8 +294,14 @@ pub static ChArrowUpLeft: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m10.75 4.25h-6.5v6.5m7.5 1-7.5-7.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChArrowUpRight, "Arrow Up Right"))]
 pub static ChArrowUpRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChArrowUpRight",
+    label: "Arrow Up Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -221,8 +316,14 @@ pub static ChArrowUpRight: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m5.25 4.25h6.5v6.5m-7.5 1 7.5-7.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChAtSign, "At Sign"))]
 pub static ChAtSign: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChAtSign",
+    label: "At Sign",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -238,8 +339,14 @@ pub static ChAtSign: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="8" r="2.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChAtom, "Atom"))]
 pub static ChAtom: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChAtom",
+    label: "Atom",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -256,8 +363,14 @@ pub static ChAtom: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 8v0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChBell, "Bell"))]
 pub static ChBell: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChBell",
+    label: "Bell",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -273,8 +386,14 @@ pub static ChBell: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5.75 12.25c0 3 4.5 3 4.5 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChBellSlash, "Bell Slash"))]
 pub static ChBellSlash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChBellSlash",
+    label: "Bell Slash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -292,8 +411,14 @@ pub static ChBellSlash: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2.75 1.75 10.5 12.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChBin, "Bin"))]
 pub static ChBin: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChBin",
+    label: "Bin",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -308,8 +433,14 @@ pub static ChBin: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m5.75 4.25v-2.5h4.5v2.5m-6.5 1v9h8.5v-9m-9.5-.5h10.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChBinary, "Binary"))]
 pub static ChBinary: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChBinary",
+    label: "Binary",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -327,8 +458,14 @@ pub static ChBinary: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m3.25 14.25h3m-3-4.5h1.5v4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChBlock, "Block"))]
 pub static ChBlock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChBlock",
+    label: "Block",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -344,8 +481,14 @@ pub static ChBlock: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="4.25" x2="12.25" y1="11.75" y2="3.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChBluetooth, "Bluetooth"))]
 pub static ChBluetooth: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChBluetooth",
+    label: "Bluetooth",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -360,8 +503,14 @@ pub static ChBluetooth: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m3.75 11.25 8.5-6.25-4.5-3.25v12.5l4.5-3.25-8.5-6.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChBluetoothConnected, "Bluetooth Connected"))]
 pub static ChBluetoothConnected: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChBluetoothConnected",
+    label: "Bluetooth Connected",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -377,8 +526,14 @@ pub static ChBluetoothConnected: &icondata_core::IconData = &icondata_core::Icon
 <path d="m1.75 8h1.5m9.5 0h1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChBluetoothSearching, "Bluetooth Searching"))]
 pub static ChBluetoothSearching: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChBluetoothSearching",
+    label: "Bluetooth Searching",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -394,8 +549,14 @@ pub static ChBluetoothSearching: &icondata_core::IconData = &icondata_core::Icon
 <path d="m13.25 6.25s1 .5 1 1.75-1 1.75-1 1.75m-2-1.75v0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChBluetoothSlash, "Bluetooth Slash"))]
 pub static ChBluetoothSlash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChBluetoothSlash",
+    label: "Bluetooth Slash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -411,8 +572,14 @@ pub static ChBluetoothSlash: &icondata_core::IconData = &icondata_core::IconData
 <path d="m1.75 3.25 12.5 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChBook, "Book"))]
 pub static ChBook: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChBook",
+    label: "Book",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -427,8 +594,14 @@ pub static ChBook: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m11.75 11.75v2m1.5.5h-9c-.75 0-1.5-.5-1.5-1.5s.75-1.5 1.5-1.5h9v-9.5h-9c-.75 0-1.5.75-1.5 1.5v9.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChBookOpen, "Book Open"))]
 pub static ChBookOpen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChBookOpen",
+    label: "Book Open",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -444,8 +617,14 @@ pub static ChBookOpen: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 4.25v8.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChBookmark, "Bookmark"))]
 pub static ChBookmark: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChBookmark",
+    label: "Bookmark",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -460,8 +639,14 @@ pub static ChBookmark: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="3.75 1.75,12.25 1.75,12.25 14.25,8 9.75,3.75 14.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChBriefcase, "Briefcase"))]
 pub static ChBriefcase: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChBriefcase",
+    label: "Briefcase",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -477,8 +662,14 @@ pub static ChBriefcase: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m1.75 6.25s-.5 3.5 3 3.5h6.5c3.5 0 3-3.5 3-3.5m-8.5-2v-2.5h4.5v2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChBug, "Bug"))]
 pub static ChBug: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChBug",
+    label: "Bug",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -494,8 +685,14 @@ pub static ChBug: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14.25 10.25h-1.5m-1 2.5 1.5 1.5m0-8.5-1.5 1.5m-10 3h1.5m1 2.5-1.5 1.5m0-8.5 1.5 1.5m1.5-1.5s-.75-3 2.25-3 2.25 3 2.25 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCalendar, "Calendar"))]
 pub static ChCalendar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCalendar",
+    label: "Calendar",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -511,8 +708,14 @@ pub static ChCalendar: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m11.25 1.75v1.5m-6.5-1.5v1.5m-2.5 4h11.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCamera, "Camera"))]
 pub static ChCamera: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCamera",
+    label: "Camera",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -528,8 +731,14 @@ pub static ChCamera: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="8.5" r="2.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCameraVideo, "Camera Video"))]
 pub static ChCameraVideo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCameraVideo",
+    label: "Camera Video",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -545,8 +754,14 @@ pub static ChCameraVideo: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9.75 7.25 4.5-2.5v7.5l-4.5-2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCameraVideoSlash, "Camera Video Slash"))]
 pub static ChCameraVideoSlash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCameraVideoSlash",
+    label: "Camera Video Slash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -562,8 +777,14 @@ pub static ChCameraVideoSlash: &icondata_core::IconData = &icondata_core::IconDa
 <line x1="1.75" y1="2.25" x2="10.25" y2="14.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCandy, "Candy"))]
 pub static ChCandy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCandy",
+    label: "Candy",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -579,8 +800,14 @@ pub static ChCandy: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m7.25 11.25c0 1-.5 2.5-1.5 3-.75 0-1.5-1-2-2-1-.5-2-1.5-2-2 .5-1 2-1.5 3-1.5m4-4c0-1 .5-2.5 1.5-3 .75 0 1.5 1 2 2 1 .5 2 1.5 2 2-.5 1-2 1.5-3 1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCards, "Cards"))]
 pub static ChCards: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCards",
+    label: "Cards",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -596,8 +823,14 @@ pub static ChCards: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m10 3.75 4.25 2-4.25 7.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCast, "Cast"))]
 pub static ChCast: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCast",
+    label: "Cast",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -613,8 +846,14 @@ pub static ChCast: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m1.75 8.25c2.76142 0 5 2.23858 5 5m-5-2.5c1.38071 0 2.5 1.11929 2.5 2.5m-2.5 0v0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCertificate, "Certificate"))]
 pub static ChCertificate: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCertificate",
+    label: "Certificate",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -631,8 +870,14 @@ pub static ChCertificate: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="10.5" cy="7.5" r="2.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChChartBar, "Chart Bar"))]
 pub static ChChartBar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChChartBar",
+    label: "Chart Bar",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -647,8 +892,14 @@ pub static ChChartBar: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m1.75 1.75v12.5h12.5m-9-3v-2.5m4 2.5v-5.5m4 5.5v-8.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChChartLine, "Chart Line"))]
 pub static ChChartLine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChChartLine",
+    label: "Chart Line",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -663,8 +914,14 @@ pub static ChChartLine: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m4.75 11.25 2.5-4.5 2.5 2.5 3.5-6m-11.5-1.5v12.5h12.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChChevronDown, "Chevron Down"))]
 pub static ChChevronDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChChevronDown",
+    label: "Chevron Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -679,8 +936,14 @@ pub static ChChevronDown: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m3.75 5.75 4.25 4.5 4.25-4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChChevronLeft, "Chevron Left"))]
 pub static ChChevronLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChChevronLeft",
+    label: "Chevron Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -695,8 +958,14 @@ pub static ChChevronLeft: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m10.25 3.75-4.5 4.25l4.5 4.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChChevronRight, "Chevron Right"))]
 pub static ChChevronRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChChevronRight",
+    label: "Chevron Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -711,8 +980,14 @@ pub static ChChevronRight: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m5.75 12.25 4.5-4.25-4.5-4.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChChevronUp, "Chevron Up"))]
 pub static ChChevronUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChChevronUp",
+    label: "Chevron Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -727,8 +1002,14 @@ pub static ChChevronUp: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m12.25 10.25-4.25-4.5-4.25 4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChChevronsDown, "Chevrons Down"))]
 pub static ChChevronsDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChChevronsDown",
+    label: "Chevrons Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -743,8 +1024,14 @@ pub static ChChevronsDown: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m3.75 3.75 4.25 4.5 4.25-4.5m-8.5 5 4.25 4.5 4.25-4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChChevronsLeft, "Chevrons Left"))]
 pub static ChChevronsLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChChevronsLeft",
+    label: "Chevrons Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -759,8 +1046,14 @@ pub static ChChevronsLeft: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m12.25 3.75-4.5 4.25l4.5 4.25m-5-8.5-4.5 4.25 4.5 4.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChChevronsRight, "Chevrons Right"))]
 pub static ChChevronsRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChChevronsRight",
+    label: "Chevrons Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -775,8 +1068,14 @@ pub static ChChevronsRight: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="m3.75 12.25 4.5-4.25-4.5-4.25m5 8.5l4.5-4.25-4.5-4.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChChevronsUp, "Chevrons Up"))]
 pub static ChChevronsUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChChevronsUp",
+    label: "Chevrons Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -791,8 +1090,14 @@ pub static ChChevronsUp: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m12.25 12.25-4.25-4.5-4.25 4.5m8.5-5l-4.25-4.5-4.25 4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChChevronsUpDown, "Chevrons Up Down"))]
 pub static ChChevronsUpDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChChevronsUpDown",
+    label: "Chevrons Up Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -808,8 +1113,14 @@ pub static ChChevronsUpDown: &icondata_core::IconData = &icondata_core::IconData
 <path d="m11.25 5.25-3.25-3.5-3.25 3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChChip, "Chip"))]
 pub static ChChip: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChChip",
+    label: "Chip",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -826,8 +1137,14 @@ pub static ChChip: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2.25 10.25h-1m1-4.5h-1m13.5 4.5h-1m1-4.5h-1m-3.5 8v1m-4.5-1v1m4.5-13.5v1m-4.5-1v1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCircle, "Circle"))]
 pub static ChCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCircle",
+    label: "Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -842,8 +1159,14 @@ pub static ChCircle: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<circle cx="8" cy="8" r="6.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCircleCross, "Circle Cross"))]
 pub static ChCircleCross: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCircleCross",
+    label: "Circle Cross",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -859,8 +1182,14 @@ pub static ChCircleCross: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="8" r="6.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCircleMinus, "Circle Minus"))]
 pub static ChCircleMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCircleMinus",
+    label: "Circle Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -876,8 +1205,14 @@ pub static ChCircleMinus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="4.75" y1="8" x2="11.25" y2="8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCircleTick, "Circle Tick"))]
 pub static ChCircleTick: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCircleTick",
+    label: "Circle Tick",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -893,8 +1228,14 @@ pub static ChCircleTick: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="5.75 7.75,8.25 10.25,14.25 3.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCircleWarning, "Circle Warning"))]
 pub static ChCircleWarning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCircleWarning",
+    label: "Circle Warning",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -910,8 +1251,14 @@ pub static ChCircleWarning: &icondata_core::IconData = &icondata_core::IconData
 <path d="m8 10.75v0m0-6v3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChClipboard, "Clipboard"))]
 pub static ChClipboard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChClipboard",
+    label: "Clipboard",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -927,8 +1274,14 @@ pub static ChClipboard: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5.25 2.75h-2.5v11.5h10.5v-11.5h-2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChClipboardTick, "Clipboard Tick"))]
 pub static ChClipboardTick: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChClipboardTick",
+    label: "Clipboard Tick",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -944,8 +1297,14 @@ pub static ChClipboardTick: &icondata_core::IconData = &icondata_core::IconData
 <path d="m9.75 12.8 1.5 1.5 3-2.5m-9-9h-2.5v11.5h4.5m6-5v-6.5h-2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChClock, "Clock"))]
 pub static ChClock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChClock",
+    label: "Clock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -961,8 +1320,14 @@ pub static ChClock: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8.25 4.75v3.5l-2.5 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChClockAlarm, "Clock Alarm"))]
 pub static ChClockAlarm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChClockAlarm",
+    label: "Clock Alarm",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -978,8 +1343,14 @@ pub static ChClockAlarm: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="9" r="5.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCloud, "Cloud"))]
 pub static ChCloud: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCloud",
+    label: "Cloud",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -994,8 +1365,14 @@ pub static ChCloud: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m7 3.75c-1.79493 0-3.25 1.45507-3.25 3.25.00152.254757.032983.508452.09375.755859h-.00195c-1.17822.08305-2.09165 1.063-2.0918 2.24414 0 1.24264 1.00736 2.25 2.25 2.25h7.5c1.51878 0 2.75-1.23122 2.75-2.75s-1.2312-2.75-2.75-2.75c-.4352-.00022-.8643.10286-1.252.30078.0008-.01692.0015-.03385.0020-.05078 0-1.79493-1.45507-3.25-3.25-3.25z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChClover, "Clover"))]
 pub static ChClover: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChClover",
+    label: "Clover",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1010,8 +1387,14 @@ pub static ChClover: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m4.75 2.75c-.50 1.5 1.25 3.25 3.25 5.25 2-2 3.75-3.75 3.25-5.25s-2.5-1-3.25.50c-.75-1.5-2.75-2-3.25-.50zm3.25 5.25c2 2 3.75 3.75 5.25 3.25s1-2.5-.5-3.25c1.5-.75 2-2.75.5-3.25s-3.25 1.25-5.25 3.25zm0 0c-2 2-3.75 3.75-3.25 5.25s2.5 1 3.25-.5c.75 1.5 2.75 2 3.25.5s-1.25-3.25-3.25-5.25zm0 0c-2-2-3.75-3.75-5.25-3.25s-1 2.5.5 3.25c-1.5.75-2 2.75-.5 3.25s3.25-1.25 5.25-3.25z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCode, "Code"))]
 pub static ChCode: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCode",
+    label: "Code",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1026,8 +1409,14 @@ pub static ChCode: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m5.25 11.25-3.5-3.25 3.5-3.25m5.5 6.5 3.5-3.25-3.5-3.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCoffee, "Coffee"))]
 pub static ChCoffee: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCoffee",
+    label: "Coffee",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1043,8 +1432,14 @@ pub static ChCoffee: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8.75 1.75v1.5m-3-1.5v1.5m-3-1.5v1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCog, "Cog"))]
 pub static ChCog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCog",
+    label: "Cog",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1060,8 +1455,14 @@ pub static ChCog: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m6.75 1.75-.5 1.5-1.5 1-2-.5-1 2 1.5 1.5v1.5l-1.5 1.5 1 2 2-.5 1.5 1 .5 1.5h2.5l.5-1.5 1.5-1 2 .5 1-2-1.5-1.5v-1.5l1.5-1.5-1-2-2 .5-1.5-1-.5-1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCompass, "Compass"))]
 pub static ChCompass: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCompass",
+    label: "Compass",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1077,8 +1478,14 @@ pub static ChCompass: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="6.75 6.75,5.75 10.75,9.25 9.25,10.25 5.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChConicalFlask, "Conical Flask"))]
 pub static ChConicalFlask: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChConicalFlask",
+    label: "Conical Flask",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1093,8 +1500,14 @@ pub static ChConicalFlask: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m4.75 1.75h6.5m-6.5 8h6.5m-5.5-7.5v4.5l-4 7.5h12.5l-4-7.5v-4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChContainer, "Container"))]
 pub static ChContainer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChContainer",
+    label: "Container",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1110,8 +1523,14 @@ pub static ChContainer: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m10.8 6.25v5.5m-3.5-3.5v6m-5.5-8 5.5 2 7-4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCopy, "Copy"))]
 pub static ChCopy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCopy",
+    label: "Copy",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1126,8 +1545,14 @@ pub static ChCopy: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m11.25 4.25v-2.5h-9.5v9.5h2.5m.5-6.5v9.5h9.5v-9.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCopyleft, "Copyleft"))]
 pub static ChCopyleft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCopyleft",
+    label: "Copyleft",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1143,8 +1568,14 @@ pub static ChCopyleft: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m6 6.75s.75-1 2-1 2.25 1 2.25 2.25-1 2.25-2.25 2.25-2-1-2-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCopyright, "Copyright"))]
 pub static ChCopyright: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCopyright",
+    label: "Copyright",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1160,8 +1591,14 @@ pub static ChCopyright: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m10 6.75s-.75-1-2-1-2.25 1-2.25 2.25 1 2.25 2.25 2.25 2-1 2-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCreditCard, "Credit Card"))]
 pub static ChCreditCard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCreditCard",
+    label: "Credit Card",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1177,8 +1614,14 @@ pub static ChCreditCard: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9.75 10.25h1.5m-9-3h11.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCrop, "Crop"))]
 pub static ChCrop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCrop",
+    label: "Crop",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1194,8 +1637,14 @@ pub static ChCrop: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m11.8 14.2v-2.5m0-2.5v-5h-5m-2.5 0h-2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCross, "Cross"))]
 pub static ChCross: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCross",
+    label: "Cross",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1210,8 +1659,14 @@ pub static ChCross: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m11.25 4.75-6.5 6.5m0-6.5 6.5 6.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCrosshair, "Crosshair"))]
 pub static ChCrosshair: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCrosshair",
+    label: "Crosshair",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1227,8 +1682,14 @@ pub static ChCrosshair: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="8" r="6.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCube, "Cube"))]
 pub static ChCube: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCube",
+    label: "Cube",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1244,8 +1705,14 @@ pub static ChCube: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 14v-6m5.75-3-5.75 3m-6-3 6 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChCursor, "Cursor"))]
 pub static ChCursor: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChCursor",
+    label: "Cursor",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1261,8 +1728,14 @@ pub static ChCursor: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9.25" y1="9.25" x2="13.25" y2="13.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChDatabase, "Database"))]
 pub static ChDatabase: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChDatabase",
+    label: "Database",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1278,8 +1751,14 @@ pub static ChDatabase: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2.75 8.25s1.5 2 5.25 2 5.25-2 5.25-2m-10.5-4s1.5 2 5.25 2 5.25-2 5.25-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChDiamond, "Diamond"))]
 pub static ChDiamond: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChDiamond",
+    label: "Diamond",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1294,8 +1773,14 @@ pub static ChDiamond: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="1.25 8,8 14.75,14.75 8,8 1.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChDiff, "Diff"))]
 pub static ChDiff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChDiff",
+    label: "Diff",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1310,8 +1795,14 @@ pub static ChDiff: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m3.75 13.75h8m0-7.5h-8m4-4v8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChDisc, "Disc"))]
 pub static ChDisc: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChDisc",
+    label: "Disc",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1327,8 +1818,14 @@ pub static ChDisc: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="8" r="1.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChDownload, "Download"))]
 pub static ChDownload: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChDownload",
+    label: "Download",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1343,8 +1840,14 @@ pub static ChDownload: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m3.25 13.25h9m-8.5-6.5 4 3.5 4-3.5m-4-5v8.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChDroplet, "Droplet"))]
 pub static ChDroplet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChDroplet",
+    label: "Droplet",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1359,8 +1862,14 @@ pub static ChDroplet: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m2.75 9c0 2.9 2.35 5.25 5.25 5.25s5.25-2.35 5.25-5.25c0-3.25-5.25-7.25-5.25-7.25s-5.25 4-5.25 7.25z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChEraser, "Eraser"))]
 pub static ChEraser: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChEraser",
+    label: "Eraser",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1376,8 +1885,14 @@ pub static ChEraser: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="5.25" y1="6.75" x2="10.25" y2="11.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChEye, "Eye"))]
 pub static ChEye: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChEye",
+    label: "Eye",
+    source: None,
+    paint: icondata_core::IconPaint::FillAndStroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1393,8 +1908,14 @@ pub static ChEye: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="8" r="1.25" fill="currentColor" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChEyeSlash, "Eye Slash"))]
 pub static ChEyeSlash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChEyeSlash",
+    label: "Eye Slash",
+    source: None,
+    paint: icondata_core::IconPaint::FillAndStroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1411,8 +1932,14 @@ pub static ChEyeSlash: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m3.75 1.75 8.5 12.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFaceFrown, "Face Frown"))]
 pub static ChFaceFrown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFaceFrown",
+    label: "Face Frown",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1428,8 +1955,14 @@ pub static ChFaceFrown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9.75 6.25v-.5m-3.5.5v-.5m-.5 5s.5-1 2.25-1 2.25 1 2.25 1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFaceNeutral, "Face Neutral"))]
 pub static ChFaceNeutral: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFaceNeutral",
+    label: "Face Neutral",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1445,8 +1978,14 @@ pub static ChFaceNeutral: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9.75 6.25v-.5m-3.5.5v-.5m-.5 4.5h4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFaceSmile, "Face Smile"))]
 pub static ChFaceSmile: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFaceSmile",
+    label: "Face Smile",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1462,8 +2001,14 @@ pub static ChFaceSmile: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9.75 6.25v-.5m-3.5.5v-.5m-.5 4s.5 1.5 2.25 1.5 2.25-1.5 2.25-1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFile, "File"))]
 pub static ChFile: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFile",
+    label: "File",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1479,8 +2024,14 @@ pub static ChFile: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="7.75 2.25,7.75 7.25,12.75 7.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFileBinary, "File Binary"))]
 pub static ChFileBinary: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFileBinary",
+    label: "File Binary",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1498,8 +2049,14 @@ pub static ChFileBinary: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="7.75 2.25 7.75 7.25 12.8 7.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFileCode, "File Code"))]
 pub static ChFileCode: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFileCode",
+    label: "File Code",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1516,8 +2073,14 @@ pub static ChFileCode: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m6.75 10.8 2 1.75-2 1.75m-3-3.5-2 1.75 2 1.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFileSymlink, "File Symlink"))]
 pub static ChFileSymlink: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFileSymlink",
+    label: "File Symlink",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1534,8 +2097,14 @@ pub static ChFileSymlink: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2.75 14.25 3.5-3.5m0 3v-3h-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFiles, "Files"))]
 pub static ChFiles: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFiles",
+    label: "Files",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1552,8 +2121,14 @@ pub static ChFiles: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="10.25 11.75,10.25 14.25,2.75 14.25,2.75 4.75,5.25 4.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFilter, "Filter"))]
 pub static ChFilter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFilter",
+    label: "Filter",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1568,8 +2143,14 @@ pub static ChFilter: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="1.75 1.75,14.25 1.75,14.25 3.25,9.25 8.75,9.25 12.75,6.75 14.25,6.75 8.75,1.75 3.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFlag, "Flag"))]
 pub static ChFlag: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFlag",
+    label: "Flag",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1584,8 +2165,14 @@ pub static ChFlag: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m1.75 14.25v-11s2-1.5 4-1.5 2.5 1.5 4.5 1.5 4-1.5 4-1.5v7s-2 1.5-4 1.5-2.5-1.5-4.5-1.5-4 1.5-4 1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFlame, "Flame"))]
 pub static ChFlame: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFlame",
+    label: "Flame",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1600,8 +2187,14 @@ pub static ChFlame: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m8.25 7.75c2 2 2.5-2.5 3.5-2s1.5 2 1.5 3.25c0 3.25-2.35 5.25-5.25 5.25s-5.25-2.5-5.25-6 3.5-7 5.5-7c0 0-2 4.5 0 6.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFloppyDisk, "Floppy Disk"))]
 pub static ChFloppyDisk: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFloppyDisk",
+    label: "Floppy Disk",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1617,8 +2210,14 @@ pub static ChFloppyDisk: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="5.75 13.25,5.75 9.75,10.25 9.75,10.25 13.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFolder, "Folder"))]
 pub static ChFolder: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFolder",
+    label: "Folder",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1633,8 +2232,14 @@ pub static ChFolder: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="1.75 2.75,1.75 13.25,14.25 13.25,14.25 4.75,8.25 4.75,6.75 2.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFolderSymlink, "Folder Symlink"))]
 pub static ChFolderSymlink: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFolderSymlink",
+    label: "Folder Symlink",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1650,8 +2255,14 @@ pub static ChFolderSymlink: &icondata_core::IconData = &icondata_core::IconData
 <polyline points="8.25 13.25,14.25 13.25,14.25 4.75,8.25 4.75,6.75 2.75,1.75 2.75,1.75 6.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChFolders, "Folders"))]
 pub static ChFolders: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChFolders",
+    label: "Folders",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1667,8 +2278,14 @@ pub static ChFolders: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="4.75 5.25,1.75 5.25,1.75 13.25,11.25 13.25,11.25 10.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChForward, "Forward"))]
 pub static ChForward: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChForward",
+    label: "Forward",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1683,8 +2300,14 @@ pub static ChForward: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m1.75 13.25c.5-6 5.5-7.5 8-7v-3.5l4.5 5.25-4.5 5.25v-3.5c-2.5-0.5-6.5 0.5-8 3.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGamepad, "Gamepad"))]
 pub static ChGamepad: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGamepad",
+    label: "Gamepad",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1699,8 +2322,14 @@ pub static ChGamepad: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m3.25 3.75c-2 5-2 9 0 9.5s2.5-2 2.5-2h4.5s.5 2.5 2.5 2 2-4.5 0-9.5h-2l-1 1h-3.5l-1-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGem, "Gem"))]
 pub static ChGem: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGem",
+    label: "Gem",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1715,8 +2344,14 @@ pub static ChGem: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="4.75 2.75,11.25 2.75,14.25 6.25,8 13.25,1.75 6.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGift, "Gift"))]
 pub static ChGift: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGift",
+    label: "Gift",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1732,8 +2367,14 @@ pub static ChGift: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m10.25 4.75h-2.25c0-2 .5-3 2.25-3 2 0 2 3 0 3zm-4.5 0h2.25c0-2-.5-3-2.25-3-2 0-2 3 0 3zm2.25 9v-8.75m-5.25 3.75v5.5h10.5v-5.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGitBranch, "Git Branch"))]
 pub static ChGitBranch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGitBranch",
+    label: "Git Branch",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1751,8 +2392,14 @@ pub static ChGitBranch: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5.25 8.25c3 0 6 .5 6-2.5m-6.5 4.5v-4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGitCherryPick, "Git Cherry Pick"))]
 pub static ChGitCherryPick: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGitCherryPick",
+    label: "Git Cherry Pick",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1769,8 +2416,14 @@ pub static ChGitCherryPick: &icondata_core::IconData = &icondata_core::IconData
 <path d="m11.75 8h1.5m-4.5-3.25h1.5l1 3.25-1 3.25h-1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGitCommit, "Git Commit"))]
 pub static ChGitCommit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGitCommit",
+    label: "Git Commit",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1786,8 +2439,14 @@ pub static ChGitCommit: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 10.75v3.5m0-12.5v3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGitCompare, "Git Compare"))]
 pub static ChGitCompare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGitCompare",
+    label: "Git Compare",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1804,8 +2463,14 @@ pub static ChGitCompare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m3.75 5.75v5c0 1 .5 1.5 1.5 1.5h2m-.5 2 1.5-2-1.5-2m5.5 0v-5c0-1-.5-1.5-1.5-1.5h-2m.5-2-1.5 2 1.5 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGitFork, "Git Fork"))]
 pub static ChGitFork: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGitFork",
+    label: "Git Fork",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1823,8 +2488,14 @@ pub static ChGitFork: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 8.75v1.5m-3.25-4.5c0 3.5 6.5 3.5 6.5 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGitMerge, "Git Merge"))]
 pub static ChGitMerge: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGitMerge",
+    label: "Git Merge",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1842,8 +2513,14 @@ pub static ChGitMerge: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m4.75 10.25v-4.5c1 2 2 3 5.5 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGitRequest, "Git Request"))]
 pub static ChGitRequest: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGitRequest",
+    label: "Git Request",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1861,8 +2538,14 @@ pub static ChGitRequest: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9.25 1.75-1.5 2 1.5 2m3 4.5v-5c0-1-.5-1.5-1.5-1.5h-2m-5 2v4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGitRequestCross, "Git Request Cross"))]
 pub static ChGitRequestCross: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGitRequestCross",
+    label: "Git Request Cross",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1881,8 +2564,14 @@ pub static ChGitRequestCross: &icondata_core::IconData = &icondata_core::IconDat
 <path d="m14.25 1.75-3.5 3.5m0-3.5 3.5 3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGitRequestDraft, "Git Request Draft"))]
 pub static ChGitRequestDraft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGitRequestDraft",
+    label: "Git Request Draft",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1900,8 +2589,14 @@ pub static ChGitRequestDraft: &icondata_core::IconData = &icondata_core::IconDat
 <path d="m7.75 2.75h.5m2.5 0h.5m1.5 2.5v-.5m0 3v.5m-9-2.5v4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGithub, "Github"))]
 pub static ChGithub: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGithub",
+    label: "Github",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1917,8 +2612,14 @@ pub static ChGithub: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5.25 13.75c-1.5.5-3-.5-3.5-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGitlab, "Gitlab"))]
 pub static ChGitlab: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGitlab",
+    label: "Gitlab",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1933,8 +2634,14 @@ pub static ChGitlab: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m8 14.25-6.25-4.5 2-8 2 5.5h4.5l2-5.5 2 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGlasses, "Glasses"))]
 pub static ChGlasses: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGlasses",
+    label: "Glasses",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1951,8 +2658,14 @@ pub static ChGlasses: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14.25 10.75c-1.5-6-2-6.5-3.5-7m-9 7c1.5-6 2-6.5 3.5-7m1 7c1-1 2.5-1 3.5 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGlobe, "Globe"))]
 pub static ChGlobe: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGlobe",
+    label: "Globe",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1970,8 +2683,14 @@ pub static ChGlobe: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m7.75 14.2c-2.75-3.2-2.75-9.2 0-12.4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGrabHorizontal, "Grab Horizontal"))]
 pub static ChGrabHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGrabHorizontal",
+    label: "Grab Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1991,8 +2710,14 @@ pub static ChGrabHorizontal: &icondata_core::IconData = &icondata_core::IconData
 <circle cy="10.5" cx="13.5" r=".75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGrabVertical, "Grab Vertical"))]
 pub static ChGrabVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGrabVertical",
+    label: "Grab Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2012,8 +2737,14 @@ pub static ChGrabVertical: &icondata_core::IconData = &icondata_core::IconData {
 <circle cy="13.5" cx="10.4957" r=".75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChGraduateCap, "Graduate Cap"))]
 pub static ChGraduateCap: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChGraduateCap",
+    label: "Graduate Cap",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2028,8 +2759,14 @@ pub static ChGraduateCap: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m14.25 9.25v-3.25l-6.25-3.25-6.25 3.25 6.25 3.25 3.25-1.5v3.5c0 1-1.5 2-3.25 2s-3.25-1-3.25-2v-3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChHash, "Hash"))]
 pub static ChHash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChHash",
+    label: "Hash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2044,8 +2781,14 @@ pub static ChHash: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m2.75 10.25h9.5m-8.5-4.5h9.5m-2.5-4-1.5 12.5m-2.5-12.5-1.5 12.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChHeadphones, "Headphones"))]
 pub static ChHeadphones: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChHeadphones",
+    label: "Headphones",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2060,8 +2803,14 @@ pub static ChHeadphones: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m1.75 11.75c0-2.5 3.5-2 3.5-2v4.5s-3.5.5-3.5-2.5v-3.5c0-3 .5-6.5 6.25-6.5s6.25 3.5 6.25 6.5v3.5c0 3-3.5 2.5-3.5 2.5v-4.5s3.5-.5 3.5 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChHeart, "Heart"))]
 pub static ChHeart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChHeart",
+    label: "Heart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2076,8 +2825,14 @@ pub static ChHeart: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m3.25 9.75c3 3.5 4.75 4.5 4.75 4.5s1.75-1 4.75-4.5 1-7-1.5-7-3.25 3-3.25 3-.75-3-3.25-3-4.5 3.5-1.5 7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChHelp, "Help"))]
 pub static ChHelp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChHelp",
+    label: "Help",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2093,8 +2848,14 @@ pub static ChHelp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5.75 6.75c0-1 1-2 2.25-2s2.25 1.0335 2.25 2c0 1.5-1.5 1.5-2.25 2m0 2.5v0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChHexagon, "Hexagon"))]
 pub static ChHexagon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChHexagon",
+    label: "Hexagon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2109,8 +2870,14 @@ pub static ChHexagon: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="1.75 4.75,8 1.25,14.25 4.75,14.25 11.25,8 14.75,1.75 11.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChHome, "Home"))]
 pub static ChHome: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChHome",
+    label: "Home",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2125,8 +2892,14 @@ pub static ChHome: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m3.75 5.75v7.5h8.5v-7.5m-10.5 1.5 6.25-5.5l6.25 5.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChHourglass, "Hourglass"))]
 pub static ChHourglass: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChHourglass",
+    label: "Hourglass",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2141,8 +2914,14 @@ pub static ChHourglass: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m11.75 13.75c0-5-2-4-2-5.75s2-0.75 2-5.75m-7.5 11.5c0-5 2-4 2-5.75s-2-.75-2-5.75m-1.5-.5h10.5m-10.5 12.5h10.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChId, "ID"))]
 pub static ChId: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChId",
+    label: "ID",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2159,8 +2938,14 @@ pub static ChId: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m4.75 12.75c0-1 .75-3 3.25-3s3.25 2 3.25 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChImage, "Image"))]
 pub static ChImage: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChImage",
+    label: "Image",
+    source: None,
+    paint: icondata_core::IconPaint::FillAndStroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2177,8 +2962,14 @@ pub static ChImage: &icondata_core::IconData = &icondata_core::IconData {
 <circle fill="currentColor" cx="5.25" cy="6.25" r=".5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChInbox, "Inbox"))]
 pub static ChInbox: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChInbox",
+    label: "Inbox",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2194,8 +2985,14 @@ pub static ChInbox: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2.25 8.75h3l1 1.5h3.5l1-1.5h3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChInfinity, "Infinity"))]
 pub static ChInfinity: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChInfinity",
+    label: "Infinity",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2210,8 +3007,14 @@ pub static ChInfinity: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m5 5c2.5 1 3.5 5 6 6s3.25-1.25 3.25-3-.75-4-3.25-3-3.5 5-6 6-3.25-1.25-3.25-3 .75-4 3.25-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChInfo, "Info"))]
 pub static ChInfo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChInfo",
+    label: "Info",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2227,8 +3030,14 @@ pub static ChInfo: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 5.25v0m0 6v-3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChKey, "Key"))]
 pub static ChKey: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChKey",
+    label: "Key",
+    source: None,
+    paint: icondata_core::IconPaint::FillAndStroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2244,8 +3053,14 @@ pub static ChKey: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="10.75" cy="5.25" r="0.5" fill="currentColor" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChLaptop, "Laptop"))]
 pub static ChLaptop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChLaptop",
+    label: "Laptop",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2261,8 +3076,14 @@ pub static ChLaptop: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2.75 10.25-1 3h12.5l-1-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChLayoutColumns, "Layout Columns"))]
 pub static ChLayoutColumns: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChLayoutColumns",
+    label: "Layout Columns",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2278,8 +3099,14 @@ pub static ChLayoutColumns: &icondata_core::IconData = &icondata_core::IconData
 <line x1="8" y1="3.25" x2="8" y2="12.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChLayoutDashboard, "Layout Dashboard"))]
 pub static ChLayoutDashboard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChLayoutDashboard",
+    label: "Layout Dashboard",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2295,8 +3122,14 @@ pub static ChLayoutDashboard: &icondata_core::IconData = &icondata_core::IconDat
 <path d="m8.25 6.75h5.5m-11.5 2.5h5.5m.25-6v9.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChLayoutGrid, "Layout Grid"))]
 pub static ChLayoutGrid: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChLayoutGrid",
+    label: "Layout Grid",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2312,8 +3145,14 @@ pub static ChLayoutGrid: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2 8h12m-3.75-4.75v9.5m-4.5-9.5v9.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChLayoutList, "Layout List"))]
 pub static ChLayoutList: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChLayoutList",
+    label: "Layout List",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2329,8 +3168,14 @@ pub static ChLayoutList: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5.25 3.25v9.5m-3-6.5h11.5m-11.5 3.5h11.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChLayoutRows, "Layout Rows"))]
 pub static ChLayoutRows: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChLayoutRows",
+    label: "Layout Rows",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2346,8 +3191,14 @@ pub static ChLayoutRows: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" y1="8" x2="14" y2="8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChLayoutSidebar, "Layout Sidebar"))]
 pub static ChLayoutSidebar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChLayoutSidebar",
+    label: "Layout Sidebar",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2363,8 +3214,14 @@ pub static ChLayoutSidebar: &icondata_core::IconData = &icondata_core::IconData
 <path d="m6.25 3v9.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChLayoutStackH, "Layout Stack H"))]
 pub static ChLayoutStackH: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChLayoutStackH",
+    label: "Layout Stack H",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2381,8 +3238,14 @@ pub static ChLayoutStackH: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8" y1="8" x2="8" y2="12.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChLayoutStackV, "Layout Stack V"))]
 pub static ChLayoutStackV: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChLayoutStackV",
+    label: "Layout Stack V",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2399,8 +3262,14 @@ pub static ChLayoutStackV: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8" y1="8" x2="14" y2="8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChLightbulb, "Lightbulb"))]
 pub static ChLightbulb: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChLightbulb",
+    label: "Lightbulb",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2415,8 +3284,14 @@ pub static ChLightbulb: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m6.75 14.25h2.5m-1.25-12.5c-2.75 0-4.25 2-4.25 4s2 2.5 2 4.5v1h4.5v-1c0-2 2-2.5 2-4.5s-1.5-4-4.25-4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChLightningBolt, "Lightning Bolt"))]
 pub static ChLightningBolt: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChLightningBolt",
+    label: "Lightning Bolt",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2431,8 +3306,14 @@ pub static ChLightningBolt: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<polygon points="9.25 1.75,2.75 9.25,7.25 9.75,6.75 14.25,13.25 6.75,8.75 6.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChLink, "Link"))]
 pub static ChLink: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChLink",
+    label: "Link",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2447,8 +3328,14 @@ pub static ChLink: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m9.75 4.75c3 0 4.5 1.5 4.5 3.25s-1.5 3.25-4.5 3.25m-4-3.25h4.5m-4-3.25c-3 0-4.5 1.5-4.5 3.25s1.5 3.25 4.5 3.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChLinkExternal, "Link External"))]
 pub static ChLinkExternal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChLinkExternal",
+    label: "Link External",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2464,8 +3351,14 @@ pub static ChLinkExternal: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m13.25 2.75-5.5 5.5m3-6.5h3.5v3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChLinkSlash, "Link Slash"))]
 pub static ChLinkSlash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChLinkSlash",
+    label: "Link Slash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2480,8 +3373,14 @@ pub static ChLinkSlash: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m10.75 1.75-5.5 12.5m4.5-9.5c3 0 4.5 1.5 4.5 3.25s-1.5 3.25-4.5 3.25m-3.5-6.5c-3 0-4.5 1.5-4.5 3.25s1.5 3.25 4.5 3.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMail, "Mail"))]
 pub static ChMail: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMail",
+    label: "Mail",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2497,8 +3396,14 @@ pub static ChMail: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2.25 4.25 5.75 5 5.75-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMap, "Map"))]
 pub static ChMap: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMap",
+    label: "Map",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2513,8 +3418,14 @@ pub static ChMap: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m10.25 5.25v8.5m-4.5-10.5v8.5m-4 2.5v-9.5l4-2 4.5 2 4-2v9.5l-4 2-4.5-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMapPin, "Map Pin"))]
 pub static ChMapPin: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMapPin",
+    label: "Map Pin",
+    source: None,
+    paint: icondata_core::IconPaint::FillAndStroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2530,8 +3441,14 @@ pub static ChMapPin: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="7" r="1.25" fill="currentColor" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMediaBack, "Media Back"))]
 pub static ChMediaBack: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMediaBack",
+    label: "Media Back",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2547,8 +3464,14 @@ pub static ChMediaBack: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="1.75" y1="3.75" x2="1.75" y2="12.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMediaEject, "Media Eject"))]
 pub static ChMediaEject: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMediaEject",
+    label: "Media Eject",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2564,8 +3487,14 @@ pub static ChMediaEject: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="13.25" y1="14.25" x2="2.75" y2="14.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMediaFastForward, "Media Fast Forward"))]
 pub static ChMediaFastForward: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMediaFastForward",
+    label: "Media Fast Forward",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2581,8 +3510,14 @@ pub static ChMediaFastForward: &icondata_core::IconData = &icondata_core::IconDa
 <polygon points="1.75 3.75,1.75 12.25,7.75 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMediaPause, "Media Pause"))]
 pub static ChMediaPause: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMediaPause",
+    label: "Media Pause",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2598,8 +3533,14 @@ pub static ChMediaPause: &icondata_core::IconData = &icondata_core::IconData {
 <rect height="10.5" width="3.5" y="2.75" x="9.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMediaPlay, "Media Play"))]
 pub static ChMediaPlay: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMediaPlay",
+    label: "Media Play",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2614,8 +3555,14 @@ pub static ChMediaPlay: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="2.75 2.75,2.75 13.25,12.25 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMediaRewind, "Media Rewind"))]
 pub static ChMediaRewind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMediaRewind",
+    label: "Media Rewind",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2631,8 +3578,14 @@ pub static ChMediaRewind: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="14.25 3.75,14.25 12.25,8.25 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMediaSkip, "Media Skip"))]
 pub static ChMediaSkip: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMediaSkip",
+    label: "Media Skip",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2648,8 +3601,14 @@ pub static ChMediaSkip: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="14.25" y1="3.75" x2="14.25" y2="12.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMenuHamburger, "Menu Hamburger"))]
 pub static ChMenuHamburger: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMenuHamburger",
+    label: "Menu Hamburger",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2664,8 +3623,14 @@ pub static ChMenuHamburger: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="m2.75 12.25h10.5m-10.5-4h10.5m-10.5-4h10.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMenuKebab, "Menu Kebab"))]
 pub static ChMenuKebab: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMenuKebab",
+    label: "Menu Kebab",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2682,8 +3647,14 @@ pub static ChMenuKebab: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="13.5" r=".75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMenuMeatball, "Menu Meatball"))]
 pub static ChMenuMeatball: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMenuMeatball",
+    label: "Menu Meatball",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2700,8 +3671,14 @@ pub static ChMenuMeatball: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="13.5" cy="8" r=".75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMessage, "Message"))]
 pub static ChMessage: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMessage",
+    label: "Message",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2716,8 +3693,14 @@ pub static ChMessage: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="1.75 14.25,1.75 2.75,14.25 2.75,14.25 11.25,5.75 11.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMessages, "Messages"))]
 pub static ChMessages: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMessages",
+    label: "Messages",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2733,8 +3716,14 @@ pub static ChMessages: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m4.75 7.25-3 3v-8.5h10v3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMicrophone, "Microphone"))]
 pub static ChMicrophone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMicrophone",
+    label: "Microphone",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2750,8 +3739,14 @@ pub static ChMicrophone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 13v1.25m-5.25-6.5s0 4.5 5.25 4.50785c5.25.0079 5.25-4.5078 5.25-4.5078" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMinus, "Minus"))]
 pub static ChMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMinus",
+    label: "Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2766,8 +3761,14 @@ pub static ChMinus: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m13.25 7.75h-10.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMobile, "Mobile"))]
 pub static ChMobile: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMobile",
+    label: "Mobile",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2783,8 +3784,14 @@ pub static ChMobile: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8.25 11.75h-.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMonitor, "Monitor"))]
 pub static ChMonitor: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMonitor",
+    label: "Monitor",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2800,8 +3807,14 @@ pub static ChMonitor: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m4.75 14.25h6.5m-3.25-2.5v2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMonitorArrow, "Monitor Arrow"))]
 pub static ChMonitorArrow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMonitorArrow",
+    label: "Monitor Arrow",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2818,8 +3831,14 @@ pub static ChMonitorArrow: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9.75 6.25 4.5-4.5m-3.5-0.5h4v4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMonitorCross, "Monitor Cross"))]
 pub static ChMonitorCross: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMonitorCross",
+    label: "Monitor Cross",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2836,8 +3855,14 @@ pub static ChMonitorCross: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14.2 1.75-3.5 3.5m0-3.5 3.5 3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMoon, "Moon"))]
 pub static ChMoon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMoon",
+    label: "Moon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2852,8 +3877,14 @@ pub static ChMoon: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m1.75 8c0 3.45 2.8 6.25 6.25 6.25 3.41-.0027 6.25-3 6.25-6-1 .5-4 1.5-6-.5s-1-5-.5-6c-3 0-6 2.84-6 6.25z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMove, "Move"))]
 pub static ChMove: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMove",
+    label: "Move",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2868,8 +3899,14 @@ pub static ChMove: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m12.25 10.25 2-2.25-2-2.25m-2-2-2.25-2-2.25 2m-2 2-2 2.25 2 2.25m2 2 2.25 2 2.25-2m-2.25-10.5v12m5.75-5.75h-12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChMusic, "Music"))]
 pub static ChMusic: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChMusic",
+    label: "Music",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2886,8 +3923,14 @@ pub static ChMusic: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="6.25 12,6.25 2.75,14.25 1.75,14.25 11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChNewspaper, "Newspaper"))]
 pub static ChNewspaper: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChNewspaper",
+    label: "Newspaper",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2904,8 +3947,14 @@ pub static ChNewspaper: &icondata_core::IconData = &icondata_core::IconData {
 <rect x="4.75" y="4.75" width="3.5" height="3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChNorthStar, "North Star"))]
 pub static ChNorthStar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChNorthStar",
+    label: "North Star",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2923,8 +3972,14 @@ pub static ChNorthStar: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m11.25 11.25-7-7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChNotes, "Notes"))]
 pub static ChNotes: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChNotes",
+    label: "Notes",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2940,8 +3995,14 @@ pub static ChNotes: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5.75 7.75h4.5m-4.5 3h2.5m-2.5-6h4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChNotesCross, "Notes Cross"))]
 pub static ChNotesCross: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChNotesCross",
+    label: "Notes Cross",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2957,8 +4018,14 @@ pub static ChNotesCross: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14.25 10.75-3.5 3.5m-5-6.5h4.5m-4.5 3h1.5m-1.5-6h4.5m.5 6 3.5 3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChNotesTick, "Notes Tick"))]
 pub static ChNotesTick: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChNotesTick",
+    label: "Notes Tick",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2974,8 +4041,14 @@ pub static ChNotesTick: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9.75 12.75 1.5 1.5 3-2.5m-8.5-4h4.5m-4.5 3h1.5m-1.5-6h4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChNut, "Nut"))]
 pub static ChNut: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChNut",
+    label: "Nut",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2991,8 +4064,14 @@ pub static ChNut: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="8" r="2.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChOctagon, "Octagon"))]
 pub static ChOctagon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChOctagon",
+    label: "Octagon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3007,8 +4086,14 @@ pub static ChOctagon: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="5.25 1.75,10.75 1.75,14.25 5.25,14.25 10.75,10.75 14.25,5.25 14.25,1.75 10.75,1.75 5.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChOctagonWarning, "Octagon Warning"))]
 pub static ChOctagonWarning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChOctagonWarning",
+    label: "Octagon Warning",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3024,8 +4109,14 @@ pub static ChOctagonWarning: &icondata_core::IconData = &icondata_core::IconData
 <path d="m8 11.25v0m0-6.5v3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChOrganisation, "Organisation"))]
 pub static ChOrganisation: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChOrganisation",
+    label: "Organisation",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3043,8 +4134,14 @@ pub static ChOrganisation: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8.5 5.75v2m-3.75 2.5v-2h7.5v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPackage, "Package"))]
 pub static ChPackage: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPackage",
+    label: "Package",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3060,8 +4157,14 @@ pub static ChPackage: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 1.75v3.5m-5.75.5h11.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPadlock, "Padlock"))]
 pub static ChPadlock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPadlock",
+    label: "Padlock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3077,8 +4180,14 @@ pub static ChPadlock: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m4.75 6.25s-1-4.5 3.25-4.5 3.25 4.5 3.25 4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPaperPlane, "Paper Plane"))]
 pub static ChPaperPlane: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPaperPlane",
+    label: "Paper Plane",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3094,8 +4203,14 @@ pub static ChPaperPlane: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3.75" y1="7.75" x2="7.25" y2="7.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPaperclip, "Paperclip"))]
 pub static ChPaperclip: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPaperclip",
+    label: "Paperclip",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3110,8 +4225,14 @@ pub static ChPaperclip: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m8.25 10.25v-7s0-1.5-1.75-1.5-1.75 1.5-1.75 1.5v8s0 3 3.25 3 3.25-3 3.25-3v-4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPencil, "Pencil"))]
 pub static ChPencil: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPencil",
+    label: "Pencil",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3127,8 +4248,14 @@ pub static ChPencil: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8.75" y1="4.75" x2="11.25" y2="7.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPeople, "People"))]
 pub static ChPeople: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPeople",
+    label: "People",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3145,8 +4272,14 @@ pub static ChPeople: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m7.75 9.25c0-1 .75-3 3.25-3s3.25 2 3.25 3m-12.5 5c0-1 .75-3 3.25-3s3.25 2 3.25 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPerson, "Person"))]
 pub static ChPerson: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPerson",
+    label: "Person",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3162,8 +4295,14 @@ pub static ChPerson: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2.75 14.25c0-2.5 2-5 5.25-5s5.25 2.5 5.25 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPhone, "Phone"))]
 pub static ChPhone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPhone",
+    label: "Phone",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3178,8 +4317,14 @@ pub static ChPhone: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m1.75 1.75c0 8.5 4 12.5 12.5 12.5v-4l-3.5-1-1 1.5c-2 0-4.5-2.5-4.5-4.5l1.5-1-1-3.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPhoneCall, "Phone Call"))]
 pub static ChPhoneCall: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPhoneCall",
+    label: "Phone Call",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3195,8 +4340,14 @@ pub static ChPhoneCall: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9.75 1.75c2.5 0 4.5 2 4.5 4.5m-4.5-2c1 0 2 1 2 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPhoneCross, "Phone Cross"))]
 pub static ChPhoneCross: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPhoneCross",
+    label: "Phone Cross",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3212,8 +4363,14 @@ pub static ChPhoneCross: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m13.25 2.75-3.5 3.5m0-3.5 3.5 3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPhoneForward, "Phone Forward"))]
 pub static ChPhoneForward: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPhoneForward",
+    label: "Phone Forward",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3229,8 +4386,14 @@ pub static ChPhoneForward: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9.75 4.75h4.5m-2 2 2-2-2-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPhoneIncoming, "Phone Incoming"))]
 pub static ChPhoneIncoming: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPhoneIncoming",
+    label: "Phone Incoming",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3246,8 +4409,14 @@ pub static ChPhoneIncoming: &icondata_core::IconData = &icondata_core::IconData
 <path d="m13.25 2.75-3.5 3.5m0-3v3h3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPhoneOutgoing, "Phone Outgoing"))]
 pub static ChPhoneOutgoing: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPhoneOutgoing",
+    label: "Phone Outgoing",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3263,8 +4432,14 @@ pub static ChPhoneOutgoing: &icondata_core::IconData = &icondata_core::IconData
 <path d="m9.75 6.25 3.5-3.5m0 3v-3h-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPin, "Pin"))]
 pub static ChPin: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPin",
+    label: "Pin",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3279,8 +4454,14 @@ pub static ChPin: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m10.25 10.25 4 4m-12.5-7.5 5-5s1 2 2 3 4.5 2 4.5 2l-6.5 6.5s-1-3.5-2-4.5-3-2-3-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPlantPot, "Plant Pot"))]
 pub static ChPlantPot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPlantPot",
+    label: "Plant Pot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3296,8 +4477,14 @@ pub static ChPlantPot: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m4.75 9.75h6.5s.5 4.5-3.25 4.5-3.25-4.5-3.25-4.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPlus, "Plus"))]
 pub static ChPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPlus",
+    label: "Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3312,8 +4499,14 @@ pub static ChPlus: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m12.75 7.75h-10m5-5v10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPower, "Power"))]
 pub static ChPower: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPower",
+    label: "Power",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3328,8 +4521,14 @@ pub static ChPower: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m8 1.75v6.5m4.25-5s2 1.29822 2 4.75-2.79822 6.25-6.25 6.25-6.25-2.79822-6.25-6.25 2-4.75 2-4.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPrinter, "Printer"))]
 pub static ChPrinter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPrinter",
+    label: "Printer",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3345,8 +4544,14 @@ pub static ChPrinter: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m4.75 4.25v-2.5h6.5v2.5m-7 8h-2.5v-7.5h12.5v7.5h-2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChPulse, "Pulse"))]
 pub static ChPulse: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChPulse",
+    label: "Pulse",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3361,8 +4566,14 @@ pub static ChPulse: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polyline points="1.75 8.25, 4.25 8.25, 6.25 3.75, 9.75 12.25, 11.75 8.25, 14.25 8.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChQuote, "Quote"))]
 pub static ChQuote: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChQuote",
+    label: "Quote",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3378,8 +4589,14 @@ pub static ChQuote: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m13.25 3.75h-4.5v5.5c0 3.5 2.5 4.5 4.5 4-1.5-1.5-1.5-2.5-1.5-4h1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChRefresh, "Refresh"))]
 pub static ChRefresh: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChRefresh",
+    label: "Refresh",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3394,8 +4611,14 @@ pub static ChRefresh: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m4.75 10.75h-3m12.5-2c0 3-2.79822 5.5-6.25 5.5-3.75 0-6.25-3.5-6.25-3.5v3.5m9.5-9h3m-12.5 2c0-3 2.79822-5.5 6.25-5.5 3.75 0 6.25 3.5 6.25 3.5v-3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChReply, "Reply"))]
 pub static ChReply: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChReply",
+    label: "Reply",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3410,8 +4633,14 @@ pub static ChReply: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m14.25 13.25c-.5-6-5.5-7.5-8-7v-3.5l-4.5 5.25 4.5 5.25v-3.5c2.50001-0.5 6.5 0.5 8 3.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChRobot, "Robot"))]
 pub static ChRobot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChRobot",
+    label: "Robot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3427,8 +4656,14 @@ pub static ChRobot: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m10.75 8.75v1.5m-5.5-1.5v1.5m-.5-7.5 3.25 3 3.25-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChRocket, "Rocket"))]
 pub static ChRocket: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChRocket",
+    label: "Rocket",
+    source: None,
+    paint: icondata_core::IconPaint::FillAndStroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3445,8 +4680,14 @@ pub static ChRocket: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="10.25" cy="5.75" r=".5" fill="currentColor" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChRotateAntiClockwise, "Rotate Anti Clockwise"))]
 pub static ChRotateAntiClockwise: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChRotateAntiClockwise",
+    label: "Rotate Anti Clockwise",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3461,8 +4702,14 @@ pub static ChRotateAntiClockwise: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<path d="m4.75 5.25h-3m0 3.5c0 2.5 2.79822 5.5 6.25 5.5s6.25-2.79822 6.25-6.25-2.79822-6.25-6.25-6.25c-3.75 0-6.25 3.5-6.25 3.5v-3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChRotateClockwise, "Rotate Clockwise"))]
 pub static ChRotateClockwise: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChRotateClockwise",
+    label: "Rotate Clockwise",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3477,8 +4724,14 @@ pub static ChRotateClockwise: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="m11.25 5.25h3m0 3.5c0 2.5-2.79822 5.5-6.25 5.5s-6.25-2.7982-6.25-6.25c0-3.45178 2.79822-6.25 6.25-6.25 3.75 0 6.25 3.5 6.25 3.5v-3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChScales, "Scales"))]
 pub static ChScales: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChScales",
+    label: "Scales",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3494,8 +4747,14 @@ pub static ChScales: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m12.75 4.75-2 5c.5 1 3.5 1 4 0zm-9.5 0-2 5c.5 1 3.5 1 4 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChScreenMaximise, "Screen Maximise"))]
 pub static ChScreenMaximise: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChScreenMaximise",
+    label: "Screen Maximise",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3510,8 +4769,14 @@ pub static ChScreenMaximise: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="m5.25 14.25h-3.5v-3.5m12.5 0v3.5h-3.5m0-12.5h3.5v3.5m-12.5 0v-3.5h3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChScreenMinimise, "Screen Minimise"))]
 pub static ChScreenMinimise: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChScreenMinimise",
+    label: "Screen Minimise",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3526,8 +4791,14 @@ pub static ChScreenMinimise: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="m1.75 10.75h3.5v3.5m5.5 0v-3.5h3.5m0-5.5h-3.5v-3.5m-5.5 0v3.5h-3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSearch, "Search"))]
 pub static ChSearch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSearch",
+    label: "Search",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3543,8 +4814,14 @@ pub static ChSearch: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="7.5" cy="7.5" r="4.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChServer, "Server"))]
 pub static ChServer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChServer",
+    label: "Server",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3561,8 +4838,14 @@ pub static ChServer: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m4.75 10.75v0m0-5v0m6.5 0h-3m3 5h-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChShare, "Share"))]
 pub static ChShare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChShare",
+    label: "Share",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3580,8 +4863,14 @@ pub static ChShare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m6 9 4 2m-4-4 4-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChShield, "Shield"))]
 pub static ChShield: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChShield",
+    label: "Shield",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3596,8 +4885,14 @@ pub static ChShield: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m8 1.75 5.25 2v5c0 2.25-2 4.5-5.25 5.5-3.25-1-5.25-3-5.25-5.5v-5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChShieldCross, "Shield Cross"))]
 pub static ChShieldCross: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChShieldCross",
+    label: "Shield Cross",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3613,8 +4908,14 @@ pub static ChShieldCross: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9.75 5.75-3.5 3.5m0-3.5 3.5 3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChShieldKeyhole, "Shield Keyhole"))]
 pub static ChShieldKeyhole: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChShieldKeyhole",
+    label: "Shield Keyhole",
+    source: None,
+    paint: icondata_core::IconPaint::FillAndStroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3631,8 +4932,14 @@ pub static ChShieldKeyhole: &icondata_core::IconData = &icondata_core::IconData
 <circle cx="8" cy="6.5" r=".75" fill="currentColor" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChShieldTick, "Shield Tick"))]
 pub static ChShieldTick: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChShieldTick",
+    label: "Shield Tick",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3648,8 +4955,14 @@ pub static ChShieldTick: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="5.75 7.75,7.25 9.25,10.25 5.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChShieldWarning, "Shield Warning"))]
 pub static ChShieldWarning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChShieldWarning",
+    label: "Shield Warning",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3665,8 +4978,14 @@ pub static ChShieldWarning: &icondata_core::IconData = &icondata_core::IconData
 <path d="m8 10.75v0m0-5.5v3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChShoppingBag, "Shopping Bag"))]
 pub static ChShoppingBag: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChShoppingBag",
+    label: "Shopping Bag",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3682,8 +5001,14 @@ pub static ChShoppingBag: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5.75 7.75c0 1.5 1 2.5 2.25 2.5s2.25-1 2.25-2.5m-7.5-3 1.5-3h7.5l1.5 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSignIn, "Sign In"))]
 pub static ChSignIn: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSignIn",
+    label: "Sign In",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3698,8 +5023,14 @@ pub static ChSignIn: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m5.25 2.25h-3.5v12h3.5m4-9.5-3.5 3.5 3.5 3.5m5-3.5h-8.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSignOut, "Sign Out"))]
 pub static ChSignOut: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSignOut",
+    label: "Sign Out",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3714,8 +5045,14 @@ pub static ChSignOut: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m5.25 2.25h-3.5v12h3.5m5.5-9.5 3.5 3.5-3.5 3.5m-5-3.5h8.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSignpost, "Signpost"))]
 pub static ChSignpost: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSignpost",
+    label: "Signpost",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3731,8 +5068,14 @@ pub static ChSignpost: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m7.25 9.75v4.5m0-12.5v2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSkull, "Skull"))]
 pub static ChSkull: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSkull",
+    label: "Skull",
+    source: None,
+    paint: icondata_core::IconPaint::FillAndStroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3749,8 +5092,14 @@ pub static ChSkull: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="10.75" cy="7.75" r=".5" fill="currentColor" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSnowflake, "Snowflake"))]
 pub static ChSnowflake: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSnowflake",
+    label: "Snowflake",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3770,8 +5119,14 @@ pub static ChSnowflake: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m12.75 10.25-2.5-2.5 2.5-2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSoundDown, "Sound Down"))]
 pub static ChSoundDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSoundDown",
+    label: "Sound Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3787,8 +5142,14 @@ pub static ChSoundDown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m10.75 6.25s1 .5 1 1.75-1 1.75-1 1.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSoundMute, "Sound Mute"))]
 pub static ChSoundMute: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSoundMute",
+    label: "Sound Mute",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3804,8 +5165,14 @@ pub static ChSoundMute: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14.25 5.75-3.5 4.5m0-4.5 3.5 4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSoundUp, "Sound Up"))]
 pub static ChSoundUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSoundUp",
+    label: "Sound Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3821,8 +5188,14 @@ pub static ChSoundUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m10.75 6.25s1 .5 1 1.75-1 1.75-1 1.75m1-6.5c2 1 3 2.5 3 4.75s-1 3.75-3 4.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSpeaker, "Speaker"))]
 pub static ChSpeaker: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSpeaker",
+    label: "Speaker",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3839,8 +5212,14 @@ pub static ChSpeaker: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="9.5" r="2.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSquare, "Square"))]
 pub static ChSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSquare",
+    label: "Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3855,8 +5234,14 @@ pub static ChSquare: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<rect height="10.5" width="10.5" y="2.75" x="2.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSquareCross, "Square Cross"))]
 pub static ChSquareCross: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSquareCross",
+    label: "Square Cross",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3872,8 +5257,14 @@ pub static ChSquareCross: &icondata_core::IconData = &icondata_core::IconData {
 <rect height="10.5" width="10.5" y="2.75" x="2.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSquareTick, "Square Tick"))]
 pub static ChSquareTick: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSquareTick",
+    label: "Square Tick",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3889,8 +5280,14 @@ pub static ChSquareTick: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="5.75 7.75,8.25 10.25,14.25 3.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChStack, "Stack"))]
 pub static ChStack: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChStack",
+    label: "Stack",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3905,8 +5302,14 @@ pub static ChStack: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m1.75 11 6.25 3.25 6.25-3.25m-12.5-3 6.25 3.25 6.25-3.25m-6.25-6.25-6.25 3.25 6.25 3.25 6.25-3.25z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChStackPop, "Stack Pop"))]
 pub static ChStackPop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChStackPop",
+    label: "Stack Pop",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3922,8 +5325,14 @@ pub static ChStackPop: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 8.25v-6.5m-2.25 2 2.25-2 2.25 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChStackPush, "Stack Push"))]
 pub static ChStackPush: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChStackPush",
+    label: "Stack Push",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3939,8 +5348,14 @@ pub static ChStackPush: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 8.25v-6.5m-2.25 4.5 2.25 2 2.25-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChStar, "Star"))]
 pub static ChStar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChStar",
+    label: "Star",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3955,8 +5370,14 @@ pub static ChStar: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="8 1.75,5.75 5.75,1.75 6.25,4.75 9.75,3.75 14.25,8.00 12.25,12.25 14.25,11.25 9.75,14.25 6.25,10.25 5.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChStickyNote, "Sticky Note"))]
 pub static ChStickyNote: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChStickyNote",
+    label: "Sticky Note",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3972,8 +5393,14 @@ pub static ChStickyNote: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="8.75 13.25,8.75 8.75,13.25 8.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSun, "Sun"))]
 pub static ChSun: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSun",
+    label: "Sun",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3989,8 +5416,14 @@ pub static ChSun: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2.75 13.25.5-.5m9.5 0 .5.5m-.5-10 .5-.5m-10 .5-.5-.5m-.50 5.25h-1m13.5 0h-1m-5.75 5.75v1m0-13.5v1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSwapHorizontal, "Swap Horizontal"))]
 pub static ChSwapHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSwapHorizontal",
+    label: "Swap Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4005,8 +5438,14 @@ pub static ChSwapHorizontal: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="m5.75 8.25-3 3 3 3m7.5-3h-10.5m7.5-9.5l3 3-3 3m-7.5-3h10.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSwapVertical, "Swap Vertical"))]
 pub static ChSwapVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSwapVertical",
+    label: "Swap Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4021,8 +5460,14 @@ pub static ChSwapVertical: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m7.75 5.75-3-3-3 3m3 7.5v-10.5m9.5 7.5-3 3-3-3m3-7.5v10.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSword, "Sword"))]
 pub static ChSword: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSword",
+    label: "Sword",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4037,8 +5482,14 @@ pub static ChSword: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m2.75 9.25 1.5 2.5 2 1.5m-4.5 0 1 1m1.5-2.5-1.5 1.5m3-1 8.5-8.5v-2h-2l-8.5 8.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChSwords, "Swords"))]
 pub static ChSwords: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChSwords",
+    label: "Swords",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4054,8 +5505,14 @@ pub static ChSwords: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m10.25 12.25-2.25-2.25m2-2 2.25 2.25m1-1-1.5 2.5-2 1.5m4.5 0-1 1m-1.5-2.5 1.5 1.5m-7.25-5.25-4.25-4.25v-2h2l4.25 4.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChTablet, "Tablet"))]
 pub static ChTablet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChTablet",
+    label: "Tablet",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4071,8 +5528,14 @@ pub static ChTablet: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8.25 11.75h-.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChTag, "Tag"))]
 pub static ChTag: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChTag",
+    label: "Tag",
+    source: None,
+    paint: icondata_core::IconPaint::FillAndStroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4088,8 +5551,14 @@ pub static ChTag: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="11" cy="5" r=".5" fill="currentColor" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChTelescope, "Telescope"))]
 pub static ChTelescope: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChTelescope",
+    label: "Telescope",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4105,8 +5574,14 @@ pub static ChTelescope: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m7.75 11.2v3m-3-0.5 2.25-2.5 1.75-0.5 2.5 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChTent, "Tent"))]
 pub static ChTent: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChTent",
+    label: "Tent",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4122,8 +5597,14 @@ pub static ChTent: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9.75 1.75-8 12.5h12.5l-8-12.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChTerminal, "Terminal"))]
 pub static ChTerminal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChTerminal",
+    label: "Terminal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4139,8 +5620,14 @@ pub static ChTerminal: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8.75 10.25h2.5m-6.5-4.5 2.5 2.25-2.5 2.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChThumbDown, "Thumb Down"))]
 pub static ChThumbDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChThumbDown",
+    label: "Thumb Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4156,8 +5643,14 @@ pub static ChThumbDown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5.25 10.25h-3.5v-7.5h3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChThumbUp, "Thumb Up"))]
 pub static ChThumbUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChThumbUp",
+    label: "Thumb Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4173,8 +5666,14 @@ pub static ChThumbUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5.25 5.75h-3.5v7.5h3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChTick, "Tick"))]
 pub static ChTick: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChTick",
+    label: "Tick",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4189,8 +5688,14 @@ pub static ChTick: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polyline points="2.75 8.75,6.25 12.25,13.25 4.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChTickDouble, "Tick Double"))]
 pub static ChTickDouble: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChTickDouble",
+    label: "Tick Double",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4205,8 +5710,14 @@ pub static ChTickDouble: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m1.75 9.75 2.5 2.5m3.5-4 2.5-2.5m-4.5 4 2.5 2.5 6-6.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChTicket, "Ticket"))]
 pub static ChTicket: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChTicket",
+    label: "Ticket",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4222,8 +5733,14 @@ pub static ChTicket: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8.75 11.75v1.5m0-9.5v1.5m0 2.5v1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChTreeFir, "Tree Fir"))]
 pub static ChTreeFir: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChTreeFir",
+    label: "Tree Fir",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4238,8 +5755,14 @@ pub static ChTreeFir: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m8 1.75-4.25 5.5h2.5l-3.5 4h4v3h2.5v-3h4l-3.5-4h2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChTriangle, "Triangle"))]
 pub static ChTriangle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChTriangle",
+    label: "Triangle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4254,8 +5777,14 @@ pub static ChTriangle: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="8 2.75,1.75 14.25,14.25 14.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChTrophy, "Trophy"))]
 pub static ChTrophy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChTrophy",
+    label: "Trophy",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4271,8 +5800,14 @@ pub static ChTrophy: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 8.75v2m-3.25-9c-1.5 0-3 .5-3 2.25s1.5 2.25 3 2.25m6.5-4.5c1.5 0 3 .5 3 2.25s-1.5 2.25-3 2.25m-6.5-4.5h6.5v3.5c0 1.5-1 3-3.25 3s-3.25-1.5-3.25-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChUmbrella, "Umbrella"))]
 pub static ChUmbrella: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChUmbrella",
+    label: "Umbrella",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4288,8 +5823,14 @@ pub static ChUmbrella: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m7.75 8.75v4s0 1.5 1.5 1.5 1.5-1.5 1.5-1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChUpload, "Upload"))]
 pub static ChUpload: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChUpload",
+    label: "Upload",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4304,8 +5845,14 @@ pub static ChUpload: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m3.75 2.75h9m-8.5 6.5 4-3.5 4 3.5m-4 5v-8.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChWifi, "Wifi"))]
 pub static ChWifi: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChWifi",
+    label: "Wifi",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4320,8 +5867,14 @@ pub static ChWifi: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m1.75 4.75 6.25 8.5 6.25-8.5c-3.25-2.75-9.25-2.75-12.5 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChWifiFair, "Wifi Fair"))]
 pub static ChWifiFair: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChWifiFair",
+    label: "Wifi Fair",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4337,8 +5890,14 @@ pub static ChWifiFair: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m4.25 8c2-1.75 5.5-1.75 7.5 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChWifiPoor, "Wifi Poor"))]
 pub static ChWifiPoor: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChWifiPoor",
+    label: "Wifi Poor",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4354,8 +5913,14 @@ pub static ChWifiPoor: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5 9c.75-1.75 5.25-1.75 6 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChWifiSlash, "Wifi Slash"))]
 pub static ChWifiSlash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChWifiSlash",
+    label: "Wifi Slash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4371,8 +5936,14 @@ pub static ChWifiSlash: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="4.25" y1="1.75" x2="12.25" y2="12.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChWifiWarning, "Wifi Warning"))]
 pub static ChWifiWarning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChWifiWarning",
+    label: "Wifi Warning",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4388,8 +5959,14 @@ pub static ChWifiWarning: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m12.25 13.75v0m0-6v3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChZoomIn, "Zoom In"))]
 pub static ChZoomIn: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChZoomIn",
+    label: "Zoom In",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4405,8 +5982,14 @@ pub static ChZoomIn: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9.25 7.49992h-3.5m1.74992-1.74992v3.5m3.75008 2 3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ChZoomOut, "Zoom Out"))]
 pub static ChZoomOut: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ChZoomOut",
+    label: "Zoom Out",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -0,0 +1,118 @@
+//! WebAssembly component exposing the icons of the catalog exported by the builder through the
+//! `icondata:catalog` WIT interface of `wit/icondata.wit`, for hosts not written in Rust and
+//! plugin systems embedding it as a sandboxed component.
+//!
+//! The deflate compressed catalog is embedded into the component, read from the file named by the
+//! `ICONDATA_CATALOG` environment variable when building, see `cargo run -- export-component`.
+
+use std::{collections::HashMap, sync::OnceLock};
+
+use icondata_core::{Catalog, CatalogIcon, CatalogPackage};
+
+wit_bindgen::generate!({
+    path: "wit",
+    world: "icondata",
+});
+
+use exports::icondata::catalog::catalog::{Guest, Icon, IconPackage, RenderOptions};
+
+static COMPRESSED_CATALOG: &[u8] = include_bytes!(env!("ICONDATA_CATALOG"));
+
+struct Component;
+
+export!(Component);
+
+impl Guest for Component {
+    fn packages() -> Vec<IconPackage> {
+        catalog()
+            .packages
+            .iter()
+            .map(|package| IconPackage {
+                short_name: package.short_name.clone(),
+                name: package.name.clone(),
+                num_icons: package.icons.len() as u32,
+            })
+            .collect()
+    }
+
+    fn icons(package: Option<String>) -> Vec<Icon> {
+        catalog()
+            .packages
+            .iter()
+            .filter(|candidate| {
+                package
+                    .as_ref()
+                    .is_none_or(|short_name| &candidate.short_name == short_name)
+            })
+            .flat_map(|package| package.icons.iter().map(move |icon| to_icon(package, icon)))
+            .collect()
+    }
+
+    fn lookup(name: String) -> Option<Icon> {
+        find(&name).map(|(package, icon)| to_icon(package, icon))
+    }
+
+    fn svg(name: String, options: RenderOptions) -> Option<String> {
+        let (_, icon) = find(&name)?;
+        Some(icon.to_icon_data().render(&icondata_core::RenderOptions {
+            height: Some(options.size.as_deref().unwrap_or("1em")),
+            aria_label: options.label.as_deref(),
+            ..Default::default()
+        }))
+    }
+}
+
+/// The embedded catalog, decompressed on first use.
+fn catalog() -> &'static Catalog {
+    static CATALOG: OnceLock<Catalog> = OnceLock::new();
+    CATALOG.get_or_init(|| {
+        let bytes = miniz_oxide::inflate::decompress_to_vec(COMPRESSED_CATALOG)
+            .expect("a catalog compressed by the builder");
+        Catalog::from_bytes(&bytes).expect("a catalog of the version of icondata_core")
+    })
+}
+
+/// The icon with the given id or its kebab case form, with its package.
+fn find(name: &str) -> Option<(&'static CatalogPackage, &'static CatalogIcon)> {
+    static ICONS: OnceLock<HashMap<&str, (&CatalogPackage, &CatalogIcon)>> = OnceLock::new();
+    let icons = ICONS.get_or_init(|| {
+        catalog()
+            .packages
+            .iter()
+            .flat_map(|package| {
+                package
+                    .icons
+                    .iter()
+                    .map(move |icon| (icon.id.as_str(), (package, icon)))
+            })
+            .collect()
+    });
+    icons
+        .get(name)
+        .or_else(|| icons.get(icon_id(name).as_str()))
+        .copied()
+}
+
+fn to_icon(package: &CatalogPackage, icon: &CatalogIcon) -> Icon {
+    Icon {
+        id: icon.id.clone(),
+        label: icon.label.clone(),
+        package: package.short_name.clone(),
+        variant: icon.variant.clone(),
+        brand: icon.brand,
+    }
+}
+
+/// Icon id of a kebab case icon name, e.g. `BsHouse` for `bs-house`.
+fn icon_id(name: &str) -> String {
+    name.split('-')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
@@ -0,0 +1,59 @@
+use crate::{size, IconData, RenderOptions};
+
+/// Icons rendered smaller than this are hard to recognize and, when interactive, to hit.
+const MIN_SIZE_PX: f64 = 16.0;
+
+/// Font size assumed when converting `em` and `rem` sizes to pixels.
+const FONT_SIZE_PX: f64 = 16.0;
+
+/// Warns about accessibility problems of an icon about to be rendered with the given options.
+pub(crate) fn check(icon: &IconData, options: &RenderOptions) {
+    let (width, height) = size::dimensions(icon, options);
+    for (dimension, size) in [("width", width), ("height", height)] {
+        let Some(size) = size else {
+            continue;
+        };
+        if let Some(px) = size_px(&size).filter(|px| *px < MIN_SIZE_PX) {
+            log::warn!(
+                "icon {} is rendered with a {dimension} of {size} ({px}px), which is below the recommended minimum of {MIN_SIZE_PX}px",
+                icon.id,
+            );
+        }
+    }
+
+    if options.interactive && options.aria_label.is_none() {
+        log::warn!(
+            "icon {} is the content of an interactive element but has no aria_label, so screen readers cannot announce it",
+            icon.id,
+        );
+    }
+}
+
+/// Size in pixels of an absolute or font relative css length. `None` for anything else.
+fn size_px(size: &str) -> Option<f64> {
+    let size = size.trim();
+    let (number, factor) = if let Some(number) = size.strip_suffix("rem") {
+        (number, FONT_SIZE_PX)
+    } else if let Some(number) = size.strip_suffix("em") {
+        (number, FONT_SIZE_PX)
+    } else if let Some(number) = size.strip_suffix("px") {
+        (number, 1.0)
+    } else {
+        (size, 1.0)
+    };
+    number.parse::<f64>().ok().map(|number| number * factor)
+}
+
+#[cfg(test)]
+mod test {
+    use super::size_px;
+
+    #[test]
+    fn sizes() {
+        assert_eq!(size_px("1em"), Some(16.0));
+        assert_eq!(size_px("0.75rem"), Some(12.0));
+        assert_eq!(size_px("24px"), Some(24.0));
+        assert_eq!(size_px("24"), Some(24.0));
+        assert_eq!(size_px("100%"), None);
+    }
+}
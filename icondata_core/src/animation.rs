@@ -0,0 +1,88 @@
+/// Animation or transformation presets applied to a rendered icon, modeled after FontAwesome's
+/// `fa-spin`, `fa-pulse`, `fa-bounce` and `fa-flip-*` classes.
+///
+/// The icon receives an `icondata-<name>` class and an inline style, so that the preset works
+/// without any stylesheet while still allowing applications to override it using the class.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Animation {
+    /// Continuous rotation, e.g. for loading indicators.
+    Spin,
+    /// Rotation in eight discrete steps.
+    Pulse,
+    /// Jumping up and down.
+    Bounce,
+    /// Static mirroring along the vertical axis.
+    FlipHorizontal,
+    /// Static mirroring along the horizontal axis.
+    FlipVertical,
+}
+
+impl Animation {
+    /// All presets.
+    pub const ALL: [Animation; 5] = [
+        Animation::Spin,
+        Animation::Pulse,
+        Animation::Bounce,
+        Animation::FlipHorizontal,
+        Animation::FlipVertical,
+    ];
+
+    /// Stylesheet defining the classes and keyframes of all presets.
+    ///
+    /// With the `strict-csp` feature, no inline styles are rendered, so applications need to serve
+    /// this stylesheet themselves, e.g. as a static file allowed by their content security policy.
+    pub fn stylesheet() -> String {
+        let mut stylesheet = String::new();
+        let mut keyframes = Vec::new();
+        for animation in Animation::ALL {
+            stylesheet += &format!(".{} {{ {} }}\n", animation.class(), animation.style());
+            if let Some(frames) = animation
+                .keyframes()
+                .filter(|frames| !keyframes.contains(frames))
+            {
+                keyframes.push(frames);
+            }
+        }
+        for frames in keyframes {
+            stylesheet += frames;
+            stylesheet.push('\n');
+        }
+        stylesheet
+    }
+
+    /// Class added to the `<svg>` element.
+    pub const fn class(self) -> &'static str {
+        match self {
+            Animation::Spin => "icondata-spin",
+            Animation::Pulse => "icondata-pulse",
+            Animation::Bounce => "icondata-bounce",
+            Animation::FlipHorizontal => "icondata-flip-horizontal",
+            Animation::FlipVertical => "icondata-flip-vertical",
+        }
+    }
+
+    /// Inline style applying the preset.
+    pub const fn style(self) -> &'static str {
+        match self {
+            Animation::Spin => "animation: icondata-spin 2s linear infinite;",
+            Animation::Pulse => "animation: icondata-spin 1s steps(8) infinite;",
+            Animation::Bounce => "animation: icondata-bounce 1s ease-in-out infinite;",
+            Animation::FlipHorizontal => "transform: scale(-1, 1);",
+            Animation::FlipVertical => "transform: scale(1, -1);",
+        }
+    }
+
+    /// Keyframes referenced by the inline style, if any. Emitted once per rendered document.
+    pub const fn keyframes(self) -> Option<&'static str> {
+        match self {
+            Animation::Spin | Animation::Pulse => {
+                Some("@keyframes icondata-spin { to { transform: rotate(360deg); } }")
+            }
+            Animation::Bounce => Some(
+                "@keyframes icondata-bounce { 0%, 100% { transform: translateY(0); } 50% { transform: translateY(-25%); } }",
+            ),
+            Animation::FlipHorizontal | Animation::FlipVertical => None,
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::fmt::{self, Write};
+
+use crate::{render::write_attribute, rewrite::Rewrite, IconData, RenderOptions};
+
+/// Outline of the frame an avatar icon is cropped to.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AvatarShape {
+    #[default]
+    Circle,
+    /// A square with continuously rounded corners, as used by many mobile platforms.
+    Squircle,
+    /// The full square, without any rounding.
+    Square,
+}
+
+impl AvatarShape {
+    /// Path of the shape in a `0 0 100 100` coordinate system.
+    const fn path(self) -> &'static str {
+        match self {
+            AvatarShape::Circle => "M50 0a50 50 0 1 0 0 100a50 50 0 1 0 0-100z",
+            AvatarShape::Squircle => "M50 0C10 0 0 10 0 50s10 50 50 50s50-10 50-50S90 0 50 0z",
+            AvatarShape::Square => "M0 0h100v100H0z",
+        }
+    }
+
+    const fn clip_id(self) -> &'static str {
+        match self {
+            AvatarShape::Circle => "icondata-avatar-circle",
+            AvatarShape::Squircle => "icondata-avatar-squircle",
+            AvatarShape::Square => "icondata-avatar-square",
+        }
+    }
+}
+
+/// Options used when rendering an icon as an avatar placeholder.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AvatarOptions<'a> {
+    pub shape: AvatarShape,
+    /// Fill of the frame.
+    pub background: &'a str,
+    /// Color of the icon.
+    pub foreground: &'a str,
+    /// Width and height of the avatar. Defaults to "2em".
+    pub size: Option<&'a str>,
+    /// Space between the frame and the icon, as a fraction of the avatar size.
+    pub padding: f32,
+    pub class: Option<&'a str>,
+}
+
+impl Default for AvatarOptions<'_> {
+    fn default() -> Self {
+        Self {
+            shape: AvatarShape::default(),
+            background: "#e5e7eb",
+            foreground: "#4b5563",
+            size: None,
+            padding: 0.2,
+            class: None,
+        }
+    }
+}
+
+impl IconData {
+    /// Renders this icon centered in a filled frame, e.g. as a stand-in for a missing profile
+    /// picture. Everything outside of the frame is cropped.
+    pub fn render_avatar(&self, options: &AvatarOptions) -> String {
+        let mut buf = String::with_capacity(self.data.len() + 512);
+        self.write_avatar(&mut buf, options)
+            .expect("writing to a String does not fail");
+        buf
+    }
+
+    /// Streaming variant of [`IconData::render_avatar`].
+    pub fn write_avatar<W: Write>(&self, w: &mut W, options: &AvatarOptions) -> fmt::Result {
+        let size = options.size.unwrap_or("2em");
+        let shape = options.shape;
+        let padding = (options.padding.clamp(0.0, 0.5) * 100.0).round();
+        let inner = 100.0 - 2.0 * padding;
+
+        w.write_str(r#"<svg xmlns="http://www.w3.org/2000/svg""#)?;
+        if let Some(class) = options.class {
+            write_attribute(w, "class", class)?;
+        }
+        write_attribute(w, "width", size)?;
+        write_attribute(w, "height", size)?;
+        w.write_str(r#" viewBox="0 0 100 100">"#)?;
+        write!(
+            w,
+            r#"<defs><clipPath id="{}"><path d="{}" /></clipPath></defs>"#,
+            shape.clip_id(),
+            shape.path()
+        )?;
+        write!(w, r#"<g clip-path="url(#{})">"#, shape.clip_id())?;
+        write!(w, r#"<path d="{}""#, shape.path())?;
+        write_attribute(w, "fill", options.background)?;
+        w.write_str(" />")?;
+
+        write!(
+            w,
+            r#"<svg x="{padding}" y="{padding}" width="{inner}" height="{inner}""#
+        )?;
+        let rewrite = Rewrite::new(&RenderOptions::default());
+        let attributes = [
+            ("style", self.style.filter(|_| rewrite.inline_styles)),
+            ("viewBox", self.view_box),
+            ("stroke-linecap", self.stroke_linecap),
+            ("stroke-linejoin", self.stroke_linejoin),
+            ("stroke-width", self.stroke_width),
+            ("stroke", self.stroke),
+            ("fill", self.fill.or(Some("currentColor"))),
+            ("color", Some(options.foreground)),
+        ];
+        for (name, value) in attributes {
+            if let Some(value) = value {
+                write_attribute(w, name, value)?;
+            }
+        }
+        if let (false, Some(style)) = (rewrite.inline_styles, self.style) {
+            rewrite.write_style_attributes(w, style)?;
+        }
+        w.write_char('>')?;
+        rewrite.write(w, self.data)?;
+        w.write_str("</svg></g></svg>")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AvatarOptions, AvatarShape};
+    use crate::{IconData, IconPaint};
+
+    static USER: &IconData = &IconData {
+        id: "TestUser",
+        label: "User",
+        source: None,
+        paint: IconPaint::Fill,
+        brand_color: None,
+        style: None,
+        x: None,
+        y: None,
+        width: None,
+        height: None,
+        view_box: Some("0 0 24 24"),
+        stroke_linecap: None,
+        stroke_linejoin: None,
+        stroke_width: None,
+        stroke: None,
+        fill: None,
+        data: r#"<circle cx="12" cy="8" r="4" />"#,
+    };
+
+    #[test]
+    fn render_avatar() {
+        let out = USER.render_avatar(&AvatarOptions {
+            shape: AvatarShape::Squircle,
+            background: "navy",
+            foreground: "white",
+            ..Default::default()
+        });
+        assert!(out.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="2em" height="2em" viewBox="0 0 100 100"><defs><clipPath id="icondata-avatar-squircle">"#
+        ));
+        assert!(out.contains(r#" fill="navy" />"#));
+        assert!(out.contains(
+            r#"<svg x="20" y="20" width="60" height="60" viewBox="0 0 24 24" fill="currentColor" color="white"><circle"#
+        ));
+        assert!(out.ends_with("</svg></g></svg>"));
+    }
+}
@@ -0,0 +1,189 @@
+use std::sync::{Arc, Mutex};
+
+use crate::{IconData, RenderOptions};
+
+/// Number of entries kept by the caches of this crate unless changed with
+/// [`IconCache::set_capacity`].
+pub const DEFAULT_CAPACITY: usize = 256;
+
+/// Markup rendered by [`IconData::render_cached`], keyed by the rendering options.
+pub static RENDERED: IconCache<String, Arc<str>> = IconCache::new(DEFAULT_CAPACITY);
+
+/// Content decoded by `CompressedIcon::decoded_data`, keyed by the dictionary it was decoded with.
+pub static DECODED: IconCache<usize, Arc<str>> = IconCache::new(DEFAULT_CAPACITY);
+
+/// Thread-safe cache of data derived from icons, like rendered markup or decoded content, keyed by
+/// the id of the icon and the derivation, e.g. the options it was rendered with.
+///
+/// Once full, the least recently used entry is evicted, so that long-running servers deriving data
+/// from many icons neither grow without bounds nor redo the same derivations. Caches are meant to
+/// be small and are scanned linearly.
+#[derive(Debug)]
+pub struct IconCache<K, V> {
+    lru: Mutex<Lru<K, V>>,
+}
+
+#[derive(Debug)]
+struct Lru<K, V> {
+    capacity: usize,
+    /// Entries by icon id and derivation, least recently used first.
+    entries: Vec<(&'static str, K, V)>,
+}
+
+impl<K: PartialEq, V: Clone> IconCache<K, V> {
+    /// An empty cache keeping at most `capacity` entries.
+    pub const fn new(capacity: usize) -> Self {
+        IconCache {
+            lru: Mutex::new(Lru {
+                capacity,
+                entries: Vec::new(),
+            }),
+        }
+    }
+
+    /// The data derived from `icon` by `derivation`, computed with `derive` if it is not cached.
+    ///
+    /// The lock is not held while deriving, so concurrent calls for the same key may both derive
+    /// the data, of which the last one is kept.
+    pub fn get_or_insert_with(
+        &self,
+        icon: &IconData,
+        derivation: K,
+        derive: impl FnOnce() -> V,
+    ) -> V {
+        if let Some(value) = self.get(icon, &derivation) {
+            return value;
+        }
+        let value = derive();
+        let mut lru = self.lock();
+        lru.entries
+            .retain(|(id, cached, _)| !(*id == icon.id && *cached == derivation));
+        if lru.entries.len() >= lru.capacity {
+            let evicted = lru.entries.len() + 1 - lru.capacity.max(1);
+            lru.entries.drain(..evicted);
+        }
+        if lru.capacity > 0 {
+            lru.entries.push((icon.id, derivation, value.clone()));
+        }
+        value
+    }
+
+    /// The cached data derived from `icon` by `derivation`, marking it as recently used.
+    pub fn get(&self, icon: &IconData, derivation: &K) -> Option<V> {
+        let mut lru = self.lock();
+        let i = lru
+            .entries
+            .iter()
+            .position(|(id, cached, _)| *id == icon.id && cached == derivation)?;
+        let entry = lru.entries.remove(i);
+        let value = entry.2.clone();
+        lru.entries.push(entry);
+        Some(value)
+    }
+
+    /// Changes the number of entries kept, evicting the least recently used ones if there are
+    /// more. A capacity of zero disables caching.
+    pub fn set_capacity(&self, capacity: usize) {
+        let mut lru = self.lock();
+        lru.capacity = capacity;
+        let evicted = lru.entries.len().saturating_sub(capacity);
+        lru.entries.drain(..evicted);
+    }
+
+    /// Number of cached entries.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all entries.
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Lru<K, V>> {
+        self.lru
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl IconData {
+    /// Renders this icon like [`IconData::render`], reusing markup rendered with the same options
+    /// before, see [`RENDERED`].
+    pub fn render_cached(&self, options: &RenderOptions) -> Arc<str> {
+        let key = format!("{options:?}");
+        if let Some(markup) = RENDERED.get(self, &key) {
+            #[cfg(feature = "instrument")]
+            crate::instrument::rendered(self);
+            return markup;
+        }
+        RENDERED.get_or_insert_with(self, key, || self.render(options).into())
+    }
+}
+
+#[cfg(feature = "compressed")]
+impl crate::CompressedIcon {
+    /// The decoded svg content, like [`crate::CompressedIcon::get`] but kept in [`DECODED`] rather
+    /// than for the rest of the program, so that servers touching many icons once don't leak them.
+    pub fn decoded_data(&self) -> Arc<str> {
+        let dictionary = self.dictionary.as_ptr() as usize;
+        DECODED.get_or_insert_with(&self.icon, dictionary, || {
+            crate::decode(self.icon.data, self.dictionary).into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::IconCache;
+    use crate::{IconData, IconPaint};
+
+    fn icon(id: &'static str) -> IconData {
+        IconData {
+            id,
+            label: id,
+            source: None,
+            paint: IconPaint::Fill,
+            brand_color: None,
+            style: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            view_box: None,
+            stroke_linecap: None,
+            stroke_linejoin: None,
+            stroke_width: None,
+            stroke: None,
+            fill: None,
+            data: "",
+        }
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = IconCache::<&str, usize>::new(2);
+        let (a, b, c) = (icon("A"), icon("B"), icon("C"));
+
+        assert_eq!(cache.get_or_insert_with(&a, "size", || 1), 1);
+        assert_eq!(cache.get_or_insert_with(&b, "size", || 2), 2);
+        // Cached data is not derived again.
+        assert_eq!(cache.get_or_insert_with(&a, "size", || unreachable!()), 1);
+        // B is the least recently used entry.
+        assert_eq!(cache.get_or_insert_with(&c, "size", || 3), 3);
+        assert_eq!(cache.get(&b, &"size"), None);
+        assert_eq!(cache.get(&a, &"size"), Some(1));
+        assert_eq!(cache.get(&a, &"area"), None);
+
+        cache.set_capacity(1);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get(&a, &"size"), Some(1));
+        cache.set_capacity(0);
+        assert_eq!(cache.get_or_insert_with(&a, "size", || 4), 4);
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,455 @@
+use std::fmt;
+
+use crate::{IconData, IconPaint, IconSource};
+
+/// Version of the binary catalog layout. Catalogs of other versions are rejected when reading.
+pub const CATALOG_VERSION: u32 = 2;
+
+/// Leading bytes of every binary catalog.
+const MAGIC: &[u8; 4] = b"ICDC";
+
+/// All icons of all packages, in a compact binary form which tools can load without parsing the
+/// generated sources.
+///
+/// The layout is the magic `ICDC` followed by the little endian u32 [`CATALOG_VERSION`] and the
+/// packages. Strings are stored as their u32 byte length followed by their UTF-8 bytes, lists as
+/// their u32 length followed by their items.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Catalog {
+    pub packages: Vec<CatalogPackage>,
+}
+
+/// An icon package and its icons, as stored in a [`Catalog`].
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct CatalogPackage {
+    /// Identifier like "fa" for "Font Awesome", used as prefix of all icon ids.
+    pub short_name: String,
+    /// Full human readable name of the package.
+    pub name: String,
+    pub icons: Vec<CatalogIcon>,
+}
+
+/// Owned counterpart of [`IconData`](crate::IconData), with the icon's variant family and whether
+/// it is a brand icon.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct CatalogIcon {
+    pub id: String,
+    pub label: String,
+    /// Upstream origin of the icon, `None` if the package was not generated from a git commit.
+    pub source: Option<CatalogSource>,
+    pub paint: IconPaint,
+    pub brand_color: Option<String>,
+    /// Variant family of the icon, e.g. "outlined".
+    pub variant: Option<String>,
+    /// Whether the icon is a third-party brand or logo.
+    pub brand: bool,
+    pub style: Option<String>,
+    pub x: Option<String>,
+    pub y: Option<String>,
+    pub width: Option<String>,
+    pub height: Option<String>,
+    pub view_box: Option<String>,
+    pub stroke_linecap: Option<String>,
+    pub stroke_linejoin: Option<String>,
+    pub stroke_width: Option<String>,
+    pub stroke: Option<String>,
+    pub fill: Option<String>,
+    pub data: String,
+}
+
+/// Owned counterpart of [`IconSource`](crate::IconSource).
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct CatalogSource {
+    pub repository: String,
+    pub revision: String,
+    pub path: String,
+}
+
+/// Bit of an icon's flags marking it as brand icon. Lower bits mark present optional fields.
+const BRAND_FLAG: u16 = 1 << 15;
+
+/// Bit of an icon's flags marking that its source follows the optional fields.
+const SOURCE_FLAG: u16 = 1 << 14;
+
+impl CatalogIcon {
+    /// Renderable icon data borrowing from this icon, e.g. of a catalog leaked at startup.
+    pub fn to_icon_data(&'static self) -> IconData {
+        IconData {
+            id: &self.id,
+            label: &self.label,
+            source: self.source.as_ref().map(|source| IconSource {
+                repository: &source.repository,
+                revision: &source.revision,
+                path: &source.path,
+            }),
+            paint: self.paint,
+            brand_color: self.brand_color.as_deref(),
+            style: self.style.as_deref(),
+            x: self.x.as_deref(),
+            y: self.y.as_deref(),
+            width: self.width.as_deref(),
+            height: self.height.as_deref(),
+            view_box: self.view_box.as_deref(),
+            stroke_linecap: self.stroke_linecap.as_deref(),
+            stroke_linejoin: self.stroke_linejoin.as_deref(),
+            stroke_width: self.stroke_width.as_deref(),
+            stroke: self.stroke.as_deref(),
+            fill: self.fill.as_deref(),
+            data: &self.data,
+        }
+    }
+
+    fn optional_fields(&self) -> [&Option<String>; 13] {
+        [
+            &self.brand_color,
+            &self.variant,
+            &self.style,
+            &self.x,
+            &self.y,
+            &self.width,
+            &self.height,
+            &self.view_box,
+            &self.stroke_linecap,
+            &self.stroke_linejoin,
+            &self.stroke_width,
+            &self.stroke,
+            &self.fill,
+        ]
+    }
+
+    fn optional_fields_mut(&mut self) -> [&mut Option<String>; 13] {
+        [
+            &mut self.brand_color,
+            &mut self.variant,
+            &mut self.style,
+            &mut self.x,
+            &mut self.y,
+            &mut self.width,
+            &mut self.height,
+            &mut self.view_box,
+            &mut self.stroke_linecap,
+            &mut self.stroke_linejoin,
+            &mut self.stroke_width,
+            &mut self.stroke,
+            &mut self.fill,
+        ]
+    }
+}
+
+/// Reason a binary catalog could not be read.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CatalogError {
+    /// The bytes do not start with the catalog magic.
+    NotACatalog,
+    /// The catalog was written in another layout version.
+    UnsupportedVersion(u32),
+    /// The catalog ended in the middle of an item.
+    Truncated,
+    /// A string is not valid UTF-8.
+    InvalidUtf8,
+    /// The paint of an icon is none of the known [`IconPaint`] values.
+    InvalidPaint(u8),
+    /// The signature or public key is malformed, or the catalog was not signed by that key.
+    InvalidSignature,
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatalogError::NotACatalog => f.write_str("not an icondata catalog"),
+            CatalogError::UnsupportedVersion(version) => write!(
+                f,
+                "catalog version {version} is not supported, expected version {CATALOG_VERSION}"
+            ),
+            CatalogError::Truncated => f.write_str("catalog is truncated"),
+            CatalogError::InvalidUtf8 => f.write_str("catalog contains invalid UTF-8"),
+            CatalogError::InvalidPaint(paint) => {
+                write!(f, "catalog contains unknown paint {paint}")
+            }
+            CatalogError::InvalidSignature => f.write_str("catalog signature is invalid"),
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+impl Catalog {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(MAGIC);
+        write_u32(&mut buf, CATALOG_VERSION);
+        write_len(&mut buf, self.packages.len());
+        for package in &self.packages {
+            write_str(&mut buf, &package.short_name);
+            write_str(&mut buf, &package.name);
+            write_len(&mut buf, package.icons.len());
+            for icon in &package.icons {
+                write_icon(&mut buf, icon);
+            }
+        }
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Catalog, CatalogError> {
+        let mut reader = Reader { bytes };
+        if reader.take(MAGIC.len()).ok() != Some(MAGIC.as_slice()) {
+            return Err(CatalogError::NotACatalog);
+        }
+        let version = reader.u32()?;
+        if version != CATALOG_VERSION {
+            return Err(CatalogError::UnsupportedVersion(version));
+        }
+
+        let mut packages = Vec::new();
+        for _ in 0..reader.u32()? {
+            let short_name = reader.string()?;
+            let name = reader.string()?;
+            let mut icons = Vec::new();
+            for _ in 0..reader.u32()? {
+                icons.push(reader.icon()?);
+            }
+            packages.push(CatalogPackage {
+                short_name,
+                name,
+                icons,
+            });
+        }
+        Ok(Catalog { packages })
+    }
+
+    /// Read a catalog after checking its minisign `signature`, i.e. the contents of the `.minisig`
+    /// file written next to it by the builder, against the base64 encoded `public_key` of the
+    /// publisher.
+    #[cfg(feature = "catalog-signature")]
+    pub fn from_signed_bytes(
+        bytes: &[u8],
+        signature: &str,
+        public_key: &str,
+    ) -> Result<Catalog, CatalogError> {
+        use minisign_verify::{PublicKey, Signature};
+
+        let public_key =
+            PublicKey::from_base64(public_key).map_err(|_| CatalogError::InvalidSignature)?;
+        let signature = Signature::decode(signature).map_err(|_| CatalogError::InvalidSignature)?;
+        public_key
+            .verify(bytes, &signature, false)
+            .map_err(|_| CatalogError::InvalidSignature)?;
+        Catalog::from_bytes(bytes)
+    }
+}
+
+fn write_icon(buf: &mut Vec<u8>, icon: &CatalogIcon) {
+    let optional_fields = icon.optional_fields();
+    let mut flags = if icon.brand { BRAND_FLAG } else { 0 };
+    if icon.source.is_some() {
+        flags |= SOURCE_FLAG;
+    }
+    for (bit, field) in optional_fields.iter().enumerate() {
+        if field.is_some() {
+            flags |= 1 << bit;
+        }
+    }
+
+    write_str(buf, &icon.id);
+    write_str(buf, &icon.label);
+    buf.extend_from_slice(&flags.to_le_bytes());
+    buf.push(match icon.paint {
+        IconPaint::Fill => 0,
+        IconPaint::Stroke => 1,
+        IconPaint::FillAndStroke => 2,
+    });
+    for value in optional_fields.into_iter().flatten() {
+        write_str(buf, value);
+    }
+    if let Some(source) = &icon.source {
+        write_str(buf, &source.repository);
+        write_str(buf, &source.revision);
+        write_str(buf, &source.path);
+    }
+    write_str(buf, &icon.data);
+}
+
+fn write_u32(buf: &mut Vec<u8>, value: u32) {
+    buf.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_len(buf: &mut Vec<u8>, len: usize) {
+    write_u32(buf, u32::try_from(len).expect("catalog items fit into u32"));
+}
+
+fn write_str(buf: &mut Vec<u8>, value: &str) {
+    write_len(buf, value.len());
+    buf.extend_from_slice(value.as_bytes());
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CatalogError> {
+        if self.bytes.len() < len {
+            return Err(CatalogError::Truncated);
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8, CatalogError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, CatalogError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, CatalogError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn string(&mut self) -> Result<String, CatalogError> {
+        let len = self.u32()? as usize;
+        let bytes = self.take(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| CatalogError::InvalidUtf8)
+    }
+
+    fn icon(&mut self) -> Result<CatalogIcon, CatalogError> {
+        let mut icon = CatalogIcon {
+            id: self.string()?,
+            label: self.string()?,
+            ..Default::default()
+        };
+        let flags = self.u16()?;
+        icon.brand = flags & BRAND_FLAG != 0;
+        icon.paint = match self.u8()? {
+            0 => IconPaint::Fill,
+            1 => IconPaint::Stroke,
+            2 => IconPaint::FillAndStroke,
+            paint => return Err(CatalogError::InvalidPaint(paint)),
+        };
+        for (bit, field) in icon.optional_fields_mut().into_iter().enumerate() {
+            if flags & (1 << bit) != 0 {
+                *field = Some(self.string()?);
+            }
+        }
+        if flags & SOURCE_FLAG != 0 {
+            icon.source = Some(CatalogSource {
+                repository: self.string()?,
+                revision: self.string()?,
+                path: self.string()?,
+            });
+        }
+        icon.data = self.string()?;
+        Ok(icon)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Catalog, CatalogError, CatalogIcon, CatalogPackage, CatalogSource};
+    use crate::{IconPaint, IconSource};
+
+    #[test]
+    fn round_trip() {
+        let catalog = Catalog {
+            packages: vec![CatalogPackage {
+                short_name: "fa".to_owned(),
+                name: "Font Awesome".to_owned(),
+                icons: vec![CatalogIcon {
+                    id: "FaGithubBrands".to_owned(),
+                    label: "Github Brands".to_owned(),
+                    brand: true,
+                    view_box: Some("0 0 496 512".to_owned()),
+                    fill: Some("currentColor".to_owned()),
+                    data: r#"<path d="M0 0h496v512H0z" />"#.to_owned(),
+                    ..Default::default()
+                }],
+            }],
+        };
+        let bytes = catalog.to_bytes();
+        assert_eq!(Catalog::from_bytes(&bytes), Ok(catalog));
+        assert_eq!(
+            Catalog::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(CatalogError::Truncated)
+        );
+    }
+
+    #[test]
+    fn round_trip_stroke_icon() {
+        let icon = CatalogIcon {
+            id: "LuHouse".to_owned(),
+            label: "House".to_owned(),
+            source: Some(CatalogSource {
+                repository: "https://github.com/lucide-icons/lucide".to_owned(),
+                revision: "5e8ca0b3f9a1c4d2e7b6a9f0c3d1e2b4a5f6c7d8".to_owned(),
+                path: "icons/house.svg".to_owned(),
+            }),
+            paint: IconPaint::Stroke,
+            brand_color: Some("#F56565".to_owned()),
+            view_box: Some("0 0 24 24".to_owned()),
+            stroke: Some("currentColor".to_owned()),
+            stroke_width: Some("2".to_owned()),
+            fill: Some("none".to_owned()),
+            data: r#"<path d="M3 10l9-7 9 7v11H3z" />"#.to_owned(),
+            ..Default::default()
+        };
+        let catalog = Catalog {
+            packages: vec![CatalogPackage {
+                short_name: "lu".to_owned(),
+                name: "Lucide".to_owned(),
+                icons: vec![icon],
+            }],
+        };
+        let read: &'static Catalog =
+            Box::leak(Box::new(Catalog::from_bytes(&catalog.to_bytes()).unwrap()));
+        assert_eq!(*read, catalog);
+
+        let icon = read.packages[0].icons[0].to_icon_data();
+        assert_eq!(icon.paint, IconPaint::Stroke);
+        assert_eq!(icon.brand_color, Some("#F56565"));
+        assert_eq!(
+            icon.source,
+            Some(IconSource {
+                repository: "https://github.com/lucide-icons/lucide",
+                revision: "5e8ca0b3f9a1c4d2e7b6a9f0c3d1e2b4a5f6c7d8",
+                path: "icons/house.svg",
+            })
+        );
+        assert_eq!(icon.stroke_width, Some("2"));
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let mut bytes = Catalog::default().to_bytes();
+        bytes[4] = 0;
+        assert_eq!(
+            Catalog::from_bytes(&bytes),
+            Err(CatalogError::UnsupportedVersion(0))
+        );
+        assert_eq!(Catalog::from_bytes(b"{}"), Err(CatalogError::NotACatalog));
+    }
+
+    #[cfg(feature = "catalog-signature")]
+    #[test]
+    fn verifies_signature() {
+        const PUBLIC_KEY: &str = "RWRJQ0RDdGVzdOpKbGPinFIKvvVQexMuxfmVR3auvr57kkIe6mkURtIs";
+        const SIGNATURE: &str = "untrusted comment: signature of an empty catalog
+RURJQ0RDdGVzdO2FH5SdsOKG+ExPDLYKX2Hd8ABTLC/vfPJrtyET4InlIVeYDp81SrE9qibFCNOSFzmM1zbiVGIkxKf9ChRvrAI=
+trusted comment: icondata catalog version 2
+NXQUj1eMNmUpXyrzKxNzavENRQJGq9U5UMsM2Wu4+RZmSjHotRo2EKC+p7RFCP2SNJzyS79fFVysEeETrHL0BQ==
+";
+        let mut bytes = Catalog::default().to_bytes();
+        assert_eq!(
+            Catalog::from_signed_bytes(&bytes, SIGNATURE, PUBLIC_KEY),
+            Ok(Catalog::default())
+        );
+        bytes.push(0);
+        assert_eq!(
+            Catalog::from_signed_bytes(&bytes, SIGNATURE, PUBLIC_KEY),
+            Err(CatalogError::InvalidSignature)
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::{ops::Deref, sync::OnceLock};
+
+use crate::{Icon, IconData};
+
+/// Character referring to the first entry of a dictionary. The following characters of the
+/// private use area of Unicode refer to the following entries.
+pub const DICTIONARY_START: char = '\u{E000}';
+
+/// Maximum number of entries of a dictionary, the size of the private use area.
+pub const MAX_DICTIONARY_LEN: usize = 0xF8FF - 0xE000 + 1;
+
+/// An icon whose svg content is stored as a sequence of references into a dictionary of substrings
+/// shared by all icons of a crate, decoded on first use.
+///
+/// Icon crates generated in compressed mode hold their icons as `CompressedIcon`. They dereference
+/// to the decoded [`IconData`], and [`CompressedIcon::get`] returns it as [`Icon`]. Decoding happens
+/// once per icon; the decoded content is kept for the rest of the program. With the `cache`
+/// feature, [`CompressedIcon::decoded_data`] decodes into a bounded cache instead.
+#[derive(Debug)]
+pub struct CompressedIcon {
+    pub(crate) icon: IconData,
+    pub(crate) dictionary: &'static [&'static str],
+    decoded: OnceLock<&'static IconData>,
+}
+
+impl CompressedIcon {
+    /// An icon with the encoded content in `icon.data`, see [`decode`].
+    pub const fn new(icon: IconData, dictionary: &'static [&'static str]) -> Self {
+        CompressedIcon {
+            icon,
+            dictionary,
+            decoded: OnceLock::new(),
+        }
+    }
+
+    /// The icon with its decoded content.
+    pub fn get(&self) -> Icon {
+        self.decoded.get_or_init(|| {
+            let data = decode(self.icon.data, self.dictionary);
+            Box::leak(Box::new(IconData {
+                data: Box::leak(data.into_boxed_str()),
+                ..self.icon
+            }))
+        })
+    }
+
+    /// Number of bytes of the encoded content, as embedded into the binary.
+    pub const fn compressed_len(&self) -> usize {
+        self.icon.data.len()
+    }
+}
+
+impl Deref for CompressedIcon {
+    type Target = IconData;
+
+    fn deref(&self) -> &IconData {
+        self.get()
+    }
+}
+
+/// Decodes svg content encoded with the given dictionary: every character from
+/// [`DICTIONARY_START`] on is replaced by the dictionary entry it refers to. Characters referring
+/// past the end of the dictionary are kept as they are.
+pub fn decode(encoded: &str, dictionary: &[&str]) -> String {
+    let mut decoded = String::with_capacity(encoded.len() * 2);
+    for c in encoded.chars() {
+        let entry = (c as usize)
+            .checked_sub(DICTIONARY_START as usize)
+            .and_then(|i| dictionary.get(i));
+        match entry {
+            Some(entry) => decoded.push_str(entry),
+            None => decoded.push(c),
+        }
+    }
+    decoded
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode, CompressedIcon};
+    use crate::{IconData, IconPaint};
+
+    static DICTIONARY: &[&str] = &["<path d=\"", "\"/>"];
+
+    static ICON: CompressedIcon = CompressedIcon::new(
+        IconData {
+            id: "Square",
+            label: "Square",
+            source: None,
+            paint: IconPaint::Fill,
+            brand_color: None,
+            style: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            view_box: Some("0 0 24 24"),
+            stroke_linecap: None,
+            stroke_linejoin: None,
+            stroke_width: None,
+            stroke: None,
+            fill: None,
+            data: "\u{E000}M4 4h16v16H4z\u{E001}",
+        },
+        DICTIONARY,
+    );
+
+    #[test]
+    fn decodes_once() {
+        assert_eq!(ICON.data, r#"<path d="M4 4h16v16H4z"/>"#);
+        assert_eq!(ICON.view_box, Some("0 0 24 24"));
+        assert!(std::ptr::eq(ICON.get(), ICON.get()));
+        assert_eq!(ICON.compressed_len(), 19);
+        // References past the end of the dictionary are kept.
+        assert_eq!(decode("\u{E002}", DICTIONARY), "\u{E002}");
+    }
+}
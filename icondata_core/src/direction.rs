@@ -0,0 +1,95 @@
+use crate::Icon;
+
+/// Direction an icon like an arrow or chevron points to, given relative to the flow of text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// Where text starts, left in left-to-right locales.
+    Start,
+    /// Where text ends, right in left-to-right locales.
+    End,
+    Up,
+    Down,
+}
+
+/// Direction of the text of a locale.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextDirection {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+/// The variants of an icon pointing left, right, up and down, like the arrows of a package.
+///
+/// Icon crates provide one for every such set of icons, e.g. `TbArrowDirectional()`, so that
+/// navigation controls can ask for the icon pointing to the start or end of the text and get the
+/// right one in right-to-left locales.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Directional {
+    pub left: Icon,
+    pub right: Icon,
+    pub up: Icon,
+    pub down: Icon,
+}
+
+impl Directional {
+    /// The variant pointing to `direction` in a locale with the given text direction.
+    pub const fn get(&self, direction: Direction, text_direction: TextDirection) -> Icon {
+        match (direction, text_direction) {
+            (Direction::Start, TextDirection::Ltr) | (Direction::End, TextDirection::Rtl) => {
+                self.left
+            }
+            (Direction::End, TextDirection::Ltr) | (Direction::Start, TextDirection::Rtl) => {
+                self.right
+            }
+            (Direction::Up, _) => self.up,
+            (Direction::Down, _) => self.down,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Direction, Directional, TextDirection};
+    use crate::{IconData, IconPaint};
+
+    const fn arrow(id: &'static str) -> IconData {
+        IconData {
+            id,
+            label: id,
+            source: None,
+            paint: IconPaint::Stroke,
+            brand_color: None,
+            style: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            view_box: None,
+            stroke_linecap: None,
+            stroke_linejoin: None,
+            stroke_width: None,
+            stroke: None,
+            fill: None,
+            data: "",
+        }
+    }
+
+    static ARROWS: Directional = Directional {
+        left: &arrow("ArrowLeft"),
+        right: &arrow("ArrowRight"),
+        up: &arrow("ArrowUp"),
+        down: &arrow("ArrowDown"),
+    };
+
+    #[test]
+    fn flips_start_and_end_in_rtl() {
+        let id = |direction, text_direction| ARROWS.get(direction, text_direction).id;
+        assert_eq!(id(Direction::Start, TextDirection::Ltr), "ArrowLeft");
+        assert_eq!(id(Direction::Start, TextDirection::Rtl), "ArrowRight");
+        assert_eq!(id(Direction::End, TextDirection::Rtl), "ArrowLeft");
+        assert_eq!(id(Direction::Up, TextDirection::Rtl), "ArrowUp");
+    }
+}
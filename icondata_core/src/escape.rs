@@ -0,0 +1,152 @@
+//! Escaped forms of rendered icons for embedding them into other markup.
+//!
+//! Escaping svg markup by hand is error prone: a `#` in `url(#gradient)` ends a data URI early,
+//! commas split `srcset` candidates and quotes end the surrounding attribute or CSS string.
+
+use std::fmt::{self, Write};
+
+use crate::{IconData, RenderOptions};
+
+impl IconData {
+    /// Renders this icon as `data:` URI, usable in CSS `url()` with or without quotes, in `srcset`
+    /// and as `src` of an `<img>`. See [`svg_data_uri`].
+    pub fn render_data_uri(&self, options: &RenderOptions) -> String {
+        svg_data_uri(&self.render(options))
+    }
+
+    /// Renders this icon escaped for use as value of an HTML attribute, e.g. a `data-*` attribute
+    /// read by a script. See [`escape_attribute`].
+    pub fn render_attribute_escaped(&self, options: &RenderOptions) -> String {
+        escape_attribute(&self.render(options))
+    }
+}
+
+/// `data:` URI of the given svg markup, e.g. of a sprite from
+/// [`render_many_with_sprite`](crate::render_many_with_sprite).
+///
+/// Everything but letters, digits and `-_.~/:;=@!*` is percent-encoded, so the URI contains no
+/// quotes, parentheses, commas, whitespace or `#` and needs no further escaping in CSS, `srcset`
+/// or HTML attributes.
+pub fn svg_data_uri(svg: &str) -> String {
+    let mut buf = String::with_capacity(svg.len() * 3 / 2 + 20);
+    write_svg_data_uri(&mut buf, svg).expect("writing to a String does not fail");
+    buf
+}
+
+/// Streaming variant of [`svg_data_uri`].
+pub fn write_svg_data_uri<W: Write>(w: &mut W, svg: &str) -> fmt::Result {
+    w.write_str("data:image/svg+xml,")?;
+    for byte in svg.bytes() {
+        match byte {
+            b'a'..=b'z'
+            | b'A'..=b'Z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'~'
+            | b'/'
+            | b':'
+            | b';'
+            | b'='
+            | b'@'
+            | b'!'
+            | b'*' => w.write_char(byte as char)?,
+            byte => write!(w, "%{byte:02X}")?,
+        }
+    }
+    Ok(())
+}
+
+/// The given markup with `&`, `<`, `>` and both quotes replaced by character references, for use
+/// as value of an HTML attribute quoted either way.
+pub fn escape_attribute(markup: &str) -> String {
+    let mut buf = String::with_capacity(markup.len() + markup.len() / 4);
+    write_attribute_escaped(&mut buf, markup).expect("writing to a String does not fail");
+    buf
+}
+
+/// Streaming variant of [`escape_attribute`].
+pub fn write_attribute_escaped<W: Write>(w: &mut W, markup: &str) -> fmt::Result {
+    for c in markup.chars() {
+        match c {
+            '&' => w.write_str("&amp;")?,
+            '<' => w.write_str("&lt;")?,
+            '>' => w.write_str("&gt;")?,
+            '"' => w.write_str("&quot;")?,
+            '\'' => w.write_str("&#39;")?,
+            c => w.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{render_many_with_sprite, IconPaint};
+
+    static BADGE: &IconData = &IconData {
+        id: "TestBadge",
+        label: "Badge",
+        source: None,
+        paint: IconPaint::Fill,
+        brand_color: None,
+        style: None,
+        x: None,
+        y: None,
+        width: None,
+        height: None,
+        view_box: Some("0 0 24 24"),
+        stroke_linecap: None,
+        stroke_linejoin: None,
+        stroke_width: None,
+        stroke: None,
+        fill: None,
+        data: r##"<defs><linearGradient id="g"><stop stop-color="#E34F26" /></linearGradient></defs><path fill="url(#g)" d="M1,2 3,4" /><text font-family='Font, sans-serif'>A &amp; B — ü</text>"##,
+    };
+
+    fn percent_decode(encoded: &str) -> String {
+        let mut bytes = Vec::new();
+        let mut rest = encoded.as_bytes();
+        while let Some((&byte, tail)) = rest.split_first() {
+            if byte == b'%' {
+                let hex = std::str::from_utf8(&tail[..2]).unwrap();
+                bytes.push(u8::from_str_radix(hex, 16).unwrap());
+                rest = &tail[2..];
+            } else {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn data_uri_round_trips() {
+        let options = RenderOptions::default();
+        let svg = BADGE.render(&options);
+        let uri = BADGE.render_data_uri(&options);
+
+        let encoded = uri.strip_prefix("data:image/svg+xml,").unwrap();
+        assert!(!encoded.contains(['"', '\'', '(', ')', ',', '#', ' ', '<', '>', '&']));
+        assert!(encoded.is_ascii());
+        assert_eq!(percent_decode(encoded), svg);
+
+        let sprite = render_many_with_sprite(&[(BADGE, options), (BADGE, options)]);
+        let uri = svg_data_uri(&sprite);
+        let encoded = uri.strip_prefix("data:image/svg+xml,").unwrap();
+        assert_eq!(percent_decode(encoded), sprite);
+    }
+
+    #[test]
+    fn escapes_attribute() {
+        assert_eq!(
+            escape_attribute(r#"<path d="M1,2" font-family='a' /> &amp;"#),
+            "&lt;path d=&quot;M1,2&quot; font-family=&#39;a&#39; /&gt; &amp;amp;"
+        );
+        let escaped = BADGE.render_attribute_escaped(&RenderOptions::default());
+        assert!(!escaped.contains(['"', '\'', '<', '>']));
+        assert!(escaped.contains("A &amp;amp; B — ü"));
+    }
+}
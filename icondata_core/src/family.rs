@@ -0,0 +1,59 @@
+/// Hour shown on the 12 hour dial of a clock icon, taken by the `ClockAt` accessors the icon crates
+/// provide for packages with a clock icon per hour, like `LuClockAt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hour(u8);
+
+impl Hour {
+    /// The given hour of the day, from 0 to 23, on a 12 hour dial. Midnight and noon are 12, later
+    /// hours wrap around.
+    pub const fn new(hour: u8) -> Hour {
+        match hour % 12 {
+            0 => Hour(12),
+            hour => Hour(hour),
+        }
+    }
+
+    /// The hour on the dial, from 1 to 12.
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+}
+
+impl From<u8> for Hour {
+    fn from(hour: u8) -> Hour {
+        Hour::new(hour)
+    }
+}
+
+/// The variant whose value is closest to `value`, the lower one of two equally close variants.
+/// Used by the accessors generated for families of numbered icons.
+///
+/// # Panics
+///
+/// If there are no variants.
+#[doc(hidden)]
+pub fn nearest_variant<T: Copy>(value: u32, variants: &[(u32, T)]) -> T {
+    variants
+        .iter()
+        .min_by_key(|(variant, _)| variant.abs_diff(value))
+        .expect("families have variants")
+        .1
+}
+
+#[cfg(test)]
+mod test {
+    use super::{nearest_variant, Hour};
+
+    #[test]
+    fn picks_nearest_variant() {
+        let variants = [(25, "quarter"), (50, "half"), (100, "full")];
+        assert_eq!(nearest_variant(0, &variants), "quarter");
+        assert_eq!(nearest_variant(37, &variants), "quarter");
+        assert_eq!(nearest_variant(70, &variants), "half");
+        assert_eq!(nearest_variant(255, &variants), "full");
+
+        assert_eq!(Hour::new(0).get(), 12);
+        assert_eq!(Hour::new(15).get(), 3);
+        assert_eq!(Hour::from(12).get(), 12);
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt::{self, Write};
+
+use crate::{render, Icon, RenderOptions};
+
+/// An icon generated at runtime, like an [`identicon`](crate::identicon), owning its content.
+///
+/// Unlike an [`Icon`], which lives for the rest of the program, a generated icon is freed when it
+/// is dropped, so that generating icons for many users doesn't grow the memory of long-running
+/// servers. Generating the same icon again is cheap.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GeneratedIcon {
+    /// Attributes shared by all icons of the generator. Its id and label are reported to the
+    /// `a11y-debug` and `instrument` hooks.
+    pub(crate) template: Icon,
+    pub(crate) id: String,
+    pub(crate) label: String,
+    pub(crate) data: String,
+}
+
+impl GeneratedIcon {
+    /// Stable identifier of the icon, the same for the same generator input.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Human readable name of the icon.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// The svg content of the icon, without the enclosing `<svg>` element.
+    pub fn data(&self) -> &str {
+        &self.data
+    }
+
+    /// The `viewBox` the content of the icon is drawn in.
+    pub fn view_box(&self) -> Option<&'static str> {
+        self.template.view_box
+    }
+
+    /// Renders this icon as a standalone `<svg>` element, like [`IconData::render`].
+    ///
+    /// [`IconData::render`]: crate::IconData::render
+    pub fn render(&self, options: &RenderOptions) -> String {
+        let mut buf = String::with_capacity(self.data.len() + 256);
+        self.write_svg(&mut buf, options)
+            .expect("writing to a String does not fail");
+        buf
+    }
+
+    /// Writes this icon as a standalone `<svg>` element to `w`.
+    pub fn write_svg<W: Write>(&self, w: &mut W, options: &RenderOptions) -> fmt::Result {
+        render::write_svg_with_data(w, self.template, &self.data, &[], options)
+    }
+}
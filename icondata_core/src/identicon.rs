@@ -0,0 +1,99 @@
+use std::fmt::Write;
+
+use crate::{GeneratedIcon, IconData, IconPaint};
+
+/// Number of cells per row and column of an identicon.
+const GRID: u64 = 5;
+
+/// Attributes shared by all identicons.
+static TEMPLATE: &IconData = &IconData {
+    id: "Identicon",
+    label: "Identicon",
+    source: None,
+    paint: IconPaint::Fill,
+    brand_color: None,
+    style: None,
+    x: None,
+    y: None,
+    width: None,
+    height: None,
+    view_box: Some("-0.5 -0.5 6 6"),
+    stroke_linecap: None,
+    stroke_linejoin: None,
+    stroke_width: None,
+    stroke: None,
+    fill: None,
+    data: "",
+};
+
+/// Generates an abstract, symmetric placeholder icon which is always the same for the same seed,
+/// e.g. a user id.
+///
+/// The icon is rendered like any other icon with [`GeneratedIcon::render`], and is freed when
+/// dropped rather than kept for the rest of the program.
+pub fn identicon(seed: &str) -> GeneratedIcon {
+    let hash = fnv1a(seed.as_bytes());
+
+    let hue = (hash >> 16) % 360;
+    let mut data = format!("<path fill=\"hsl({hue}, 55%, 50%)\" d=\"");
+    // The left half including the middle column is taken from the hash and mirrored to the right.
+    for row in 0..GRID {
+        for col in 0..GRID {
+            let mirrored_col = col.min(GRID - 1 - col);
+            if hash >> (row * 3 + mirrored_col) & 1 == 1 {
+                write!(data, "M{col} {row}h1v1h-1z").expect("writing to a String does not fail");
+            }
+        }
+    }
+    data.push_str("\" />");
+
+    GeneratedIcon {
+        template: TEMPLATE,
+        id: format!("Identicon{hash:016x}"),
+        label: String::from(TEMPLATE.label),
+        data,
+    }
+}
+
+/// 64 bit FNV-1a hash, which unlike the std hashers is guaranteed to be stable across platforms
+/// and releases.
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::identicon;
+
+    #[test]
+    fn deterministic() {
+        let icon = identicon("user-42");
+        assert_eq!(icon, identicon("user-42"));
+        assert_eq!(icon.id(), "Identicon32c6d7a54d35dacb");
+        assert_ne!(icon.data(), identicon("user-43").data());
+        assert!(icon
+            .render(&Default::default())
+            .contains(r#" viewBox="-0.5 -0.5 6 6" fill="currentColor"><path fill="hsl("#));
+    }
+
+    #[test]
+    fn symmetric() {
+        let icon = identicon("symmetric");
+        let cells = icon
+            .data()
+            .split('M')
+            .skip(1)
+            .map(|cell| {
+                let mut coordinates = cell.split(['h', ' ']);
+                let col: u64 = coordinates.next().unwrap().parse().unwrap();
+                let row: u64 = coordinates.next().unwrap().parse().unwrap();
+                (col, row)
+            })
+            .collect::<Vec<_>>();
+        for (col, row) in &cells {
+            assert!(cells.contains(&(4 - col, *row)));
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, RwLock};
+
+use crate::IconData;
+
+/// Function called with every icon rendered, see [`register_render_hook`].
+pub type RenderHook = fn(&IconData);
+
+static HOOKS: RwLock<Vec<RenderHook>> = RwLock::new(Vec::new());
+
+/// Number of renders per icon id, recorded by [`count_render`].
+static COUNTS: Mutex<Option<HashMap<&'static str, usize>>> = Mutex::new(None);
+
+/// Calls `hook` with every icon rendered from now on, by [`IconData::render`] and the other
+/// rendering functions, so that applications can record which icons are used in production.
+///
+/// Hooks run on the rendering thread and should be cheap. They must not register hooks themselves.
+pub fn register_render_hook(hook: RenderHook) {
+    HOOKS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(hook);
+}
+
+/// Render hook counting the renders of every icon, read with [`render_counts`]. Register it with
+/// `register_render_hook(count_render)`.
+pub fn count_render(icon: &IconData) {
+    let mut counts = COUNTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *counts
+        .get_or_insert_with(HashMap::new)
+        .entry(icon.id)
+        .or_default() += 1;
+}
+
+/// Ids of all icons counted by [`count_render`] with their number of renders, most rendered first.
+///
+/// The ids are the names of the icons, e.g. "LuHome", as listed by the bundles of the icondata
+/// builder, so that a bundle of just the icons an application renders can be generated.
+pub fn render_counts() -> Vec<(&'static str, usize)> {
+    let counts = COUNTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut counts = counts
+        .iter()
+        .flatten()
+        .map(|(id, count)| (*id, *count))
+        .collect::<Vec<_>>();
+    counts.sort_by(|(a_id, a), (b_id, b)| b.cmp(a).then(a_id.cmp(b_id)));
+    counts
+}
+
+/// Calls all registered hooks with `icon`.
+pub(crate) fn rendered(icon: &IconData) {
+    for hook in HOOKS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+    {
+        hook(icon);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{count_render, register_render_hook, render_counts};
+    use crate::{render_many, Icon, IconData, IconPaint, RenderOptions};
+
+    fn icon(id: &'static str) -> Icon {
+        Box::leak(Box::new(IconData {
+            id,
+            label: id,
+            source: None,
+            paint: IconPaint::Fill,
+            brand_color: None,
+            style: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            view_box: None,
+            stroke_linecap: None,
+            stroke_linejoin: None,
+            stroke_width: None,
+            stroke: None,
+            fill: None,
+            data: "",
+        }))
+    }
+
+    #[test]
+    fn counts_renders() {
+        register_render_hook(count_render);
+        let (house, bell) = (icon("TestCountHouse"), icon("TestCountBell"));
+        let options = RenderOptions::default();
+
+        house.render(&options);
+        render_many(&[(house, options), (bell, options)]);
+
+        let counts = render_counts()
+            .into_iter()
+            .filter(|(id, _)| id.starts_with("TestCount"))
+            .collect::<Vec<_>>();
+        assert_eq!(counts, [("TestCountHouse", 2), ("TestCountBell", 1)]);
+    }
+}
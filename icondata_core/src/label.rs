@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Shortens `label` to at most `max_chars` user-perceived characters, appending an ellipsis if
+/// anything was cut.
+///
+/// Cuts at the last word boundary when possible and never splits a grapheme cluster, like a letter
+/// with a combining accent or an emoji sequence, so the result is always valid for display, e.g. in
+/// narrow icon pickers.
+pub fn truncate_label(label: &str, max_chars: usize) -> Cow<'_, str> {
+    if label.graphemes(true).count() <= max_chars {
+        return Cow::Borrowed(label);
+    }
+    if max_chars == 0 {
+        return Cow::Borrowed("");
+    }
+
+    // Leave room for the ellipsis.
+    let end = label
+        .grapheme_indices(true)
+        .nth(max_chars - 1)
+        .map_or(label.len(), |(i, _)| i);
+    let kept = &label[..end];
+    let kept = match kept.rfind(' ') {
+        Some(space) if space > 0 && !label[end..].starts_with(' ') => &kept[..space],
+        _ => kept,
+    };
+    Cow::Owned(format!("{}…", kept.trim_end()))
+}
+
+/// Abbreviates `label` to the initials of its words, e.g. "Arrow Right Circle" becomes "ARC".
+///
+/// Numbers are kept in full, so "Wifi 1" becomes "W1".
+pub fn abbreviate_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .filter_map(|word| {
+            if word.chars().all(|c| c.is_ascii_digit()) {
+                Some(Cow::Borrowed(word))
+            } else {
+                word.chars()
+                    .next()
+                    .map(|c| Cow::Owned(c.to_uppercase().collect()))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{abbreviate_label, truncate_label};
+
+    #[test]
+    fn truncate() {
+        assert_eq!(truncate_label("House Fill", 10), "House Fill");
+        assert_eq!(truncate_label("Arrow Right Circle", 12), "Arrow Right…");
+        assert_eq!(truncate_label("Arrow Right Circle", 4), "Arr…");
+        assert_eq!(truncate_label("Überraschung", 5), "Über…");
+        assert_eq!(truncate_label("House", 0), "");
+        // "Cafe" with a combining acute accent, which must stay on its "e".
+        assert_eq!(truncate_label("Cafe\u{301} Table", 5), "Cafe\u{301}…");
+        assert_eq!(truncate_label("Cafe\u{301}s", 5), "Cafe\u{301}s");
+    }
+
+    #[test]
+    fn abbreviate() {
+        assert_eq!(abbreviate_label("Arrow Right Circle"), "ARC");
+        assert_eq!(abbreviate_label("Wifi 1"), "W1");
+        assert_eq!(abbreviate_label("TV"), "T");
+        assert_eq!(abbreviate_label(""), "");
+    }
+}
@@ -0,0 +1,87 @@
+use crate::identicon::fnv1a;
+use crate::{write_attribute_escaped, GeneratedIcon, IconData, IconPaint};
+
+/// Attributes shared by all letter icons.
+static TEMPLATE: &IconData = &IconData {
+    id: "LetterIcon",
+    label: "Letter icon",
+    source: None,
+    paint: IconPaint::Fill,
+    brand_color: None,
+    style: None,
+    x: None,
+    y: None,
+    width: None,
+    height: None,
+    view_box: Some("0 0 24 24"),
+    stroke_linecap: None,
+    stroke_linejoin: None,
+    stroke_width: None,
+    stroke: None,
+    fill: None,
+    data: "",
+};
+
+/// Generates a placeholder icon showing the initials of `text` in white on a rounded square,
+/// whose color is always the same for the same seed, e.g. a user id.
+///
+/// A single word is shown by its first character, several words by the first characters of the
+/// first and the last word, e.g. "Ada Lovelace" becomes "AL". Like [`identicon`](crate::identicon),
+/// the icon is owned and freed when dropped.
+pub fn letter_icon(text: &str, seed: &str) -> GeneratedIcon {
+    let initials = initials(text);
+    let hash = fnv1a(format!("{initials}\0{seed}").as_bytes());
+    let hue = (fnv1a(seed.as_bytes()) >> 16) % 360;
+    let font_size = match initials.chars().count() {
+        1 => 14,
+        _ => 11,
+    };
+    let mut data = format!("<rect width=\"24\" height=\"24\" rx=\"5\" fill=\"hsl({hue}, 55%, 45%)\" /><text x=\"12\" y=\"12\" fill=\"#fff\" font-size=\"{font_size}\" font-family=\"sans-serif\" font-weight=\"600\" text-anchor=\"middle\" dominant-baseline=\"central\">");
+    write_attribute_escaped(&mut data, &initials).expect("writing to a String does not fail");
+    data.push_str("</text>");
+
+    GeneratedIcon {
+        template: TEMPLATE,
+        id: format!("LetterIcon{hash:016x}"),
+        label: initials,
+        data,
+    }
+}
+
+/// Up to two uppercase characters abbreviating `text`, "?" if it has none.
+fn initials(text: &str) -> String {
+    let mut words = text.split_whitespace();
+    let first = words.next().and_then(|word| word.chars().next());
+    let last = words.last().and_then(|word| word.chars().next());
+    match first {
+        Some(first) => first
+            .to_uppercase()
+            .chain(last.into_iter().flat_map(char::to_uppercase))
+            .collect(),
+        None => String::from("?"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{initials, letter_icon};
+
+    #[test]
+    fn abbreviates() {
+        assert_eq!(initials("Ada Lovelace"), "AL");
+        assert_eq!(initials("  grace  brewster hopper "), "GH");
+        assert_eq!(initials(""), "?");
+    }
+
+    #[test]
+    fn deterministic() {
+        let icon = letter_icon("Ada Lovelace", "user-42");
+        assert_eq!(icon, letter_icon("ada lovelace", "user-42"));
+        assert_eq!(icon.label(), "AL");
+        assert!(icon.data().contains(">AL</text>"));
+        assert_ne!(icon.data(), letter_icon("Ada Lovelace", "user-43").data());
+        assert!(letter_icon("<b>", "user-42")
+            .data()
+            .contains(">&lt;</text>"));
+    }
+}
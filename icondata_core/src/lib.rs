@@ -1,6 +1,88 @@
+#[cfg(feature = "a11y-debug")]
+mod a11y;
+mod animation;
+mod avatar;
+#[cfg(feature = "cache")]
+mod cache;
+#[cfg(feature = "catalog")]
+mod catalog;
+#[cfg(feature = "compressed")]
+mod compressed;
+mod direction;
+mod escape;
+mod family;
+mod generated;
+mod identicon;
+#[cfg(feature = "instrument")]
+mod instrument;
+mod label;
+mod letter;
+mod render;
+mod rewrite;
+mod size;
+mod source;
+mod spinner;
+mod state_pair;
+mod translations;
+
+pub use animation::Animation;
+pub use avatar::{AvatarOptions, AvatarShape};
+#[cfg(feature = "cache")]
+pub use cache::{IconCache, DECODED, DEFAULT_CAPACITY, RENDERED};
+#[cfg(feature = "catalog")]
+pub use catalog::{
+    Catalog, CatalogError, CatalogIcon, CatalogPackage, CatalogSource, CATALOG_VERSION,
+};
+#[cfg(feature = "compressed")]
+pub use compressed::{decode, CompressedIcon, DICTIONARY_START, MAX_DICTIONARY_LEN};
+pub use direction::{Direction, Directional, TextDirection};
+pub use escape::{escape_attribute, svg_data_uri, write_attribute_escaped, write_svg_data_uri};
+#[doc(hidden)]
+pub use family::nearest_variant;
+pub use family::Hour;
+pub use generated::GeneratedIcon;
+pub use identicon::identicon;
+#[cfg(feature = "instrument")]
+pub use instrument::{count_render, register_render_hook, render_counts, RenderHook};
+pub use label::{abbreviate_label, truncate_label};
+pub use letter::letter_icon;
+pub use render::{
+    render_many, render_many_with_sprite, write_many, write_many_with_sprite, RenderOptions,
+};
+pub use source::IconSource;
+pub use spinner::Spinner;
+pub use state_pair::StatePair;
+pub use translations::{register_labels, LabelTable};
+
+/// How the shapes of an icon are drawn, telling renderers whether to set its `fill` or its
+/// `stroke` to the desired color.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IconPaint {
+    /// All shapes are filled, like in most solid icon sets.
+    #[default]
+    Fill,
+    /// All shapes are outlines drawn with a stroke and are not filled.
+    Stroke,
+    /// Some shapes are filled and others are stroked.
+    FillAndStroke,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IconData {
+    /// Stable identifier of the icon, equal to the name of its constant, e.g. "AiHomeFilled".
+    pub id: &'static str,
+    /// Human readable name of the icon, e.g. "Arrow Right Circle".
+    pub label: &'static str,
+    /// Upstream origin of the icon. `None` for icons not taken from an icon package, like
+    /// identicons.
+    pub source: Option<IconSource>,
+    /// Whether the shapes of the icon are filled or stroked.
+    pub paint: IconPaint,
+    /// Official color of the brand shown by the icon as hex color, e.g. "#1DB954". Only known for
+    /// packages publishing the colors of their logos, like Simple Icons.
+    pub brand_color: Option<&'static str>,
     pub style: Option<&'static str>,
     pub x: Option<&'static str>,
     pub y: Option<&'static str>,
@@ -15,4 +97,72 @@ pub struct IconData {
     pub data: &'static str,
 }
 
+impl IconData {
+    /// Stable identifier of the icon, usable as a key for translations or persisted settings.
+    pub const fn id(&self) -> &'static str {
+        self.id
+    }
+
+    /// Human readable name of the icon, suitable for icon pickers and tooltips.
+    ///
+    /// Unlike the name of the icon constant, acronyms keep their case ("TV") and numbers are
+    /// separated from words ("Wifi 1").
+    pub const fn label(&self) -> &'static str {
+        self.label
+    }
+
+    /// Upstream repository, commit and file the icon was generated from, e.g. to link to the
+    /// original svg file.
+    pub const fn source(&self) -> Option<IconSource> {
+        self.source
+    }
+
+    /// Whether the icon is drawn by filling or by stroking its shapes, determined from its svg
+    /// content when generating the icon crates.
+    pub const fn paint(&self) -> IconPaint {
+        self.paint
+    }
+
+    /// Official color of the brand shown by the icon, e.g. "#1DB954". Render the icon with
+    /// [`RenderOptions::brand_color`] to fill it with this color.
+    pub const fn brand_color(&self) -> Option<&'static str> {
+        self.brand_color
+    }
+
+    /// Number of bytes of string data embedded for this icon, including all svg attributes and the
+    /// path of its source file. The repository and revision of the source are shared by all icons
+    /// of a package and not counted.
+    ///
+    /// This is a `const fn`, so that bundle size estimations can be made at compile time without
+    /// inspecting the path data.
+    pub const fn data_len(&self) -> usize {
+        const fn len(value: Option<&str>) -> usize {
+            match value {
+                Some(value) => value.len(),
+                None => 0,
+            }
+        }
+
+        self.id.len()
+            + self.label.len()
+            + match self.source {
+                Some(source) => source.path.len(),
+                None => 0,
+            }
+            + len(self.brand_color)
+            + len(self.style)
+            + len(self.x)
+            + len(self.y)
+            + len(self.width)
+            + len(self.height)
+            + len(self.view_box)
+            + len(self.stroke_linecap)
+            + len(self.stroke_linejoin)
+            + len(self.stroke_width)
+            + len(self.stroke)
+            + len(self.fill)
+            + self.data.len()
+    }
+}
+
 pub type Icon = &'static IconData;
@@ -0,0 +1,433 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::fmt::{self, Write};
+use std::hash::{Hash, Hasher};
+
+use crate::{rewrite::Rewrite, size, Animation, Icon, IconData};
+
+/// Per-icon options used when rendering an [`IconData`] to svg markup.
+///
+/// Unset fields fall back to the values stored in the icon data itself.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct RenderOptions<'a> {
+    /// Width of the rendered icon. If only the width is set, the height is derived from
+    /// [`IconData::intrinsic_aspect`].
+    pub width: Option<&'a str>,
+    /// Height of the rendered icon. If only the height is set, the width is derived from
+    /// [`IconData::intrinsic_aspect`].
+    pub height: Option<&'a str>,
+    pub class: Option<&'a str>,
+    /// Inline style of the icon. Ignored with the `strict-csp` feature, use `class` instead.
+    pub style: Option<&'a str>,
+    /// Accessible name of the icon. When set, the icon is exposed to assistive technology as an
+    /// image with this label.
+    pub aria_label: Option<&'a str>,
+    /// Whether the icon is the only content of an interactive element like a button or link, in
+    /// which case it needs an `aria_label`. Only used by the `a11y-debug` checks.
+    pub interactive: bool,
+    /// Animation or transformation preset applied to the icon. With the `strict-csp` feature, only
+    /// its class is set, see [`Animation::stylesheet`].
+    pub animation: Option<Animation>,
+    /// Replace every color of the icon by `currentColor`, so that multi-color icons like brand
+    /// logos are drawn in the color of the surrounding text.
+    pub monochrome: bool,
+    /// Fill the icon with its [`IconData::brand_color`] instead of `currentColor`, so that brand
+    /// logos are drawn in their official colors. Icons without brand color or with a fill of their
+    /// own are left as they are.
+    pub brand_color: bool,
+}
+
+impl IconData {
+    /// Renders this icon as a standalone `<svg>` element.
+    pub fn render(&self, options: &RenderOptions) -> String {
+        let mut buf = String::with_capacity(self.data.len() + 256);
+        self.write_svg(&mut buf, options)
+            .expect("writing to a String does not fail");
+        buf
+    }
+
+    /// Writes this icon as a standalone `<svg>` element to `w`.
+    ///
+    /// Use this over [`IconData::render`] when streaming markup into a template engine or response
+    /// body, as no intermediate `String` is allocated.
+    pub fn write_svg<W: Write>(&self, w: &mut W, options: &RenderOptions) -> fmt::Result {
+        write_svg_with_data(w, self, self.data, &[], options)
+    }
+}
+
+/// Writes `icon` as a standalone `<svg>` element with the svg content `data` instead of its own and
+/// the given additional attributes.
+pub(crate) fn write_svg_with_data<W: Write>(
+    w: &mut W,
+    icon: &IconData,
+    data: &str,
+    attributes: &[(&str, &str)],
+    options: &RenderOptions,
+) -> fmt::Result {
+    write_svg_open(w, icon, attributes, options)?;
+    write_keyframes(w, options, &mut HashSet::new())?;
+    write_data(w, data, options)?;
+    w.write_str("</svg>")
+}
+
+/// Renders a list of icons into a single buffer.
+///
+/// `<defs>` blocks which were already emitted for a previous icon are skipped, as their ids are
+/// document-global anyway.
+pub fn render_many(icons: &[(Icon, RenderOptions)]) -> String {
+    let mut buf = String::with_capacity(icons.iter().map(|(icon, _)| icon.data.len() + 256).sum());
+    write_many(&mut buf, icons).expect("writing to a String does not fail");
+    buf
+}
+
+/// Streaming variant of [`render_many`].
+pub fn write_many<W: Write>(w: &mut W, icons: &[(Icon, RenderOptions)]) -> fmt::Result {
+    let mut seen_defs = HashSet::new();
+    let mut seen_keyframes = HashSet::new();
+
+    for (icon, options) in icons {
+        write_svg_open(w, icon, &[], options)?;
+        write_keyframes(w, options, &mut seen_keyframes)?;
+        write_deduplicated(w, icon.data, options, &mut seen_defs)?;
+        w.write_str("</svg>")?;
+    }
+    Ok(())
+}
+
+/// Renders a list of icons as a hidden sprite sheet followed by one `<use>` reference per entry.
+///
+/// Every distinct icon is only serialized once, which keeps the output small when the same icons
+/// are repeated many times (tables, trees, ...).
+pub fn render_many_with_sprite(icons: &[(Icon, RenderOptions)]) -> String {
+    let mut buf = String::new();
+    write_many_with_sprite(&mut buf, icons).expect("writing to a String does not fail");
+    buf
+}
+
+/// Streaming variant of [`render_many_with_sprite`].
+pub fn write_many_with_sprite<W: Write>(w: &mut W, icons: &[(Icon, RenderOptions)]) -> fmt::Result {
+    let mut seen_icons = HashSet::new();
+    let mut seen_defs = HashSet::new();
+    let mut seen_keyframes = HashSet::new();
+
+    // The sprite sheet takes no space and is hidden from assistive technology. Without inline
+    // styles, it is hidden by its attributes alone.
+    w.write_str(match cfg!(feature = "strict-csp") {
+        true => {
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="0" aria-hidden="true">"#
+        }
+        false => r#"<svg xmlns="http://www.w3.org/2000/svg" style="display: none;">"#,
+    })?;
+    for (icon, options) in icons {
+        let id = symbol_id(icon, options);
+        if !seen_icons.insert(id.clone()) {
+            continue;
+        }
+        write!(w, r#"<symbol id="{id}""#)?;
+        if let Some(view_box) = icon.view_box {
+            write_attribute(w, "viewBox", view_box)?;
+        }
+        w.write_char('>')?;
+        write_deduplicated(w, icon.data, options, &mut seen_defs)?;
+        w.write_str("</symbol>")?;
+    }
+    w.write_str("</svg>")?;
+
+    for (icon, options) in icons {
+        write_svg_open(w, icon, &[], options)?;
+        write_keyframes(w, options, &mut seen_keyframes)?;
+        write!(w, r##"<use href="#{}" /></svg>"##, symbol_id(icon, options))?;
+    }
+    Ok(())
+}
+
+/// Id under which an icon is stored in a sprite sheet. Only stable within a single process.
+fn symbol_id(icon: &IconData, options: &RenderOptions) -> String {
+    let mut hasher = DefaultHasher::new();
+    (icon, options.monochrome).hash(&mut hasher);
+    format!("icondata-{:x}", hasher.finish())
+}
+
+fn write_svg_open<W: Write>(
+    w: &mut W,
+    icon: &IconData,
+    extra_attributes: &[(&str, &str)],
+    options: &RenderOptions,
+) -> fmt::Result {
+    #[cfg(feature = "a11y-debug")]
+    crate::a11y::check(icon, options);
+    #[cfg(feature = "instrument")]
+    crate::instrument::rendered(icon);
+
+    w.write_str(r#"<svg xmlns="http://www.w3.org/2000/svg""#)?;
+
+    let rewrite = Rewrite::new(options);
+    let animation = options.animation;
+    let class = join([options.class, animation.map(Animation::class)]);
+    // Without inline styles, animations rely on the stylesheet and the icon's own style is written
+    // as attributes.
+    let style = match rewrite.inline_styles {
+        true => join([icon.style, animation.map(Animation::style), options.style]),
+        false => None,
+    };
+    let (width, height) = size::dimensions(icon, options);
+    let fill = icon.fill.map(|fill| rewrite.color(fill));
+    let stroke = icon.stroke.map(|stroke| rewrite.color(stroke));
+    let brand_color = icon
+        .brand_color
+        .filter(|_| options.brand_color)
+        .map(|color| rewrite.color(color));
+    let attributes = [
+        ("class", class.as_deref()),
+        ("style", style.as_deref()),
+        ("x", icon.x),
+        ("y", icon.y),
+        ("width", width.as_deref()),
+        ("height", height.as_deref()),
+        ("viewBox", icon.view_box),
+        ("stroke-linecap", icon.stroke_linecap),
+        ("stroke-linejoin", icon.stroke_linejoin),
+        ("stroke-width", icon.stroke_width),
+        ("stroke", stroke),
+        ("fill", fill.or(brand_color).or(Some("currentColor"))),
+        ("role", options.aria_label.map(|_| "img")),
+        ("aria-label", options.aria_label),
+    ];
+    for (name, value) in attributes {
+        if let Some(value) = value {
+            write_attribute(w, name, value)?;
+        }
+    }
+    for (name, value) in extra_attributes {
+        write_attribute(w, name, value)?;
+    }
+    if let (false, Some(style)) = (rewrite.inline_styles, icon.style) {
+        rewrite.write_style_attributes(w, style)?;
+    }
+    w.write_char('>')
+}
+
+/// Joins all given values with spaces, or returns `None` if there are none.
+fn join<const N: usize>(values: [Option<&str>; N]) -> Option<String> {
+    let values = values.into_iter().flatten().collect::<Vec<_>>();
+    (!values.is_empty()).then(|| values.join(" "))
+}
+
+/// Writes the keyframes needed by the animation in `options`, unless they were already written.
+/// With the `strict-csp` feature, keyframes are expected to come from [`Animation::stylesheet`].
+fn write_keyframes<W: Write>(
+    w: &mut W,
+    options: &RenderOptions,
+    seen: &mut HashSet<&'static str>,
+) -> fmt::Result {
+    if cfg!(feature = "strict-csp") {
+        return Ok(());
+    }
+    match options.animation.and_then(Animation::keyframes) {
+        Some(keyframes) if seen.insert(keyframes) => write!(w, "<style>{keyframes}</style>"),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn write_attribute<W: Write>(w: &mut W, name: &str, value: &str) -> fmt::Result {
+    write!(w, " {name}=\"")?;
+    for c in value.chars() {
+        match c {
+            '"' => w.write_str("&quot;")?,
+            '&' => w.write_str("&amp;")?,
+            '<' => w.write_str("&lt;")?,
+            c => w.write_char(c)?,
+        }
+    }
+    w.write_char('"')
+}
+
+/// Writes the svg content of an icon, rewritten as requested by the options.
+fn write_data<W: Write>(w: &mut W, data: &str, options: &RenderOptions) -> fmt::Result {
+    Rewrite::new(options).write(w, data)
+}
+
+/// Writes `data`, skipping every `<defs>...</defs>` block which is already contained in `seen`.
+///
+/// Blocks are compared after being rewritten, as the same source block differs between e.g. a
+/// monochrome and a colored rendering.
+fn write_deduplicated<W: Write>(
+    w: &mut W,
+    mut data: &str,
+    options: &RenderOptions,
+    seen: &mut HashSet<String>,
+) -> fmt::Result {
+    while let Some(start) = data.find("<defs") {
+        let Some(len) = data[start..]
+            .find("</defs>")
+            .map(|end| end + "</defs>".len())
+        else {
+            break;
+        };
+        write_data(w, &data[..start], options)?;
+        let mut defs = String::new();
+        write_data(&mut defs, &data[start..start + len], options)?;
+        if !seen.contains(&defs) {
+            w.write_str(&defs)?;
+            seen.insert(defs);
+        }
+        data = &data[start + len..];
+    }
+    write_data(w, data, options)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::IconPaint;
+
+    static GRADIENT: &IconData = &IconData {
+        id: "TestGradient",
+        label: "Gradient",
+        source: None,
+        paint: IconPaint::Fill,
+        brand_color: None,
+        style: None,
+        x: None,
+        y: None,
+        width: None,
+        height: None,
+        view_box: Some("0 0 24 24"),
+        stroke_linecap: None,
+        stroke_linejoin: None,
+        stroke_width: None,
+        stroke: None,
+        fill: None,
+        data: r#"<defs><linearGradient id="g" /></defs><path d="M0 0h24v24H0z" />"#,
+    };
+
+    #[test]
+    fn render_applies_options() {
+        let out = GRADIENT.render(&RenderOptions {
+            width: Some("2em"),
+            class: Some("icon"),
+            ..Default::default()
+        });
+        assert_eq!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" class="icon" width="2em" height="2em" viewBox="0 0 24 24" fill="currentColor"><defs><linearGradient id="g" /></defs><path d="M0 0h24v24H0z" /></svg>"#
+        );
+    }
+
+    #[test]
+    fn render_labels_icon() {
+        let out = GRADIENT.render(&RenderOptions {
+            aria_label: Some("Gradient"),
+            ..Default::default()
+        });
+        assert!(out.contains(r#" role="img" aria-label="Gradient">"#));
+    }
+
+    #[test]
+    fn render_brand_color() {
+        let logo = IconData {
+            brand_color: Some("#1DB954"),
+            ..*GRADIENT
+        };
+        let options = RenderOptions {
+            brand_color: true,
+            ..Default::default()
+        };
+        assert!(logo.render(&options).contains(r##" fill="#1DB954">"##));
+        assert!(logo
+            .render(&Default::default())
+            .contains(r#" fill="currentColor">"#));
+        assert!(GRADIENT
+            .render(&options)
+            .contains(r#" fill="currentColor">"#));
+    }
+
+    #[cfg(not(feature = "strict-csp"))]
+    #[test]
+    fn render_animation() {
+        let options = RenderOptions {
+            class: Some("icon"),
+            animation: Some(Animation::Spin),
+            ..Default::default()
+        };
+        let out = GRADIENT.render(&options);
+        assert!(out.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" class="icon icondata-spin" style="animation: icondata-spin 2s linear infinite;""#
+        ));
+        assert!(out.contains("><style>@keyframes icondata-spin"));
+
+        let out = render_many(&[(GRADIENT, options), (GRADIENT, options)]);
+        assert_eq!(out.matches("@keyframes").count(), 1);
+    }
+
+    #[cfg(feature = "strict-csp")]
+    #[test]
+    fn render_without_inline_styles() {
+        let out = GRADIENT.render(&RenderOptions {
+            style: Some("color: red"),
+            animation: Some(Animation::Spin),
+            ..Default::default()
+        });
+        assert!(out
+            .starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" class="icondata-spin" width"#));
+        assert!(!out.contains("style"));
+    }
+
+    #[test]
+    fn write_svg_matches_render() {
+        let mut out = String::new();
+        GRADIENT
+            .write_svg(&mut out, &RenderOptions::default())
+            .unwrap();
+        assert_eq!(out, GRADIENT.render(&RenderOptions::default()));
+    }
+
+    #[test]
+    fn render_many_deduplicates_defs() {
+        let out = render_many(&[
+            (GRADIENT, RenderOptions::default()),
+            (GRADIENT, RenderOptions::default()),
+        ]);
+        assert_eq!(out.matches("<defs>").count(), 1);
+        assert_eq!(out.matches("<path").count(), 2);
+    }
+
+    #[test]
+    fn render_many_with_sprite_emits_symbol_once() {
+        let out = render_many_with_sprite(&[
+            (GRADIENT, RenderOptions::default()),
+            (GRADIENT, RenderOptions::default()),
+        ]);
+        assert_eq!(out.matches("<symbol").count(), 1);
+        assert_eq!(out.matches("<use").count(), 2);
+    }
+
+    #[cfg(feature = "strict-csp")]
+    #[test]
+    fn render_sprite_without_inline_styles() {
+        let out = render_many_with_sprite(&[(GRADIENT, RenderOptions::default())]);
+        assert!(out.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="0" aria-hidden="true"><symbol"#
+        ));
+        assert!(!out.contains("style"));
+    }
+
+    #[test]
+    fn render_many_keeps_defs_of_monochrome_variant() {
+        static COLORED: &IconData = &IconData {
+            data: r##"<defs><linearGradient id="g"><stop stop-color="#f00" /></linearGradient></defs><path fill="url(#g)" d="M0 0h24v24H0z" />"##,
+            ..*GRADIENT
+        };
+        let monochrome = RenderOptions {
+            monochrome: true,
+            ..Default::default()
+        };
+        let icons = [(COLORED, RenderOptions::default()), (COLORED, monochrome)];
+
+        for out in [render_many(&icons), render_many_with_sprite(&icons)] {
+            assert_eq!(out.matches("<defs>").count(), 2);
+            assert!(out.contains(r##"<stop stop-color="#f00" />"##));
+            assert!(out.contains(r#"<stop stop-color="currentColor" />"#));
+        }
+    }
+}
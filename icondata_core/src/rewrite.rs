@@ -0,0 +1,168 @@
+use std::fmt::{self, Write};
+
+use crate::RenderOptions;
+
+/// Attributes and style properties holding a color.
+const PAINT_PROPERTIES: &[&str] = &["fill", "stroke", "stop-color", "flood-color"];
+
+/// Changes applied to svg content while it is written.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Rewrite {
+    /// Replace every color by `currentColor`, see [`RenderOptions::monochrome`].
+    pub monochrome: bool,
+    /// Keep `style` attributes. Without, every style declaration is written as presentation
+    /// attribute instead, as required by a content security policy blocking inline styles.
+    pub inline_styles: bool,
+}
+
+impl Rewrite {
+    pub(crate) fn new(options: &RenderOptions) -> Rewrite {
+        Rewrite {
+            monochrome: options.monochrome,
+            inline_styles: !cfg!(feature = "strict-csp"),
+        }
+    }
+
+    /// The given color, recolored if requested.
+    pub(crate) fn color(self, value: &str) -> &str {
+        match self.monochrome {
+            true => recolor(value),
+            false => value,
+        }
+    }
+
+    /// Writes the svg content `data` with the requested changes.
+    pub(crate) fn write<W: Write>(self, w: &mut W, mut data: &str) -> fmt::Result {
+        if !self.monochrome && self.inline_styles {
+            return w.write_str(data);
+        }
+
+        while let Some(eq) = data.find('=') {
+            let before = data[..eq].trim_end();
+            let name_start = before
+                .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == ':'))
+                .map_or(0, |i| i + 1);
+            let name = &before[name_start..];
+
+            let after = &data[eq + 1..];
+            let value_start = eq + 1 + after.len() - after.trim_start().len();
+            let Some(quote) = data[value_start..]
+                .chars()
+                .next()
+                .filter(|c| *c == '"' || *c == '\'')
+            else {
+                w.write_str(&data[..=eq])?;
+                data = &data[eq + 1..];
+                continue;
+            };
+            let value_start = value_start + 1;
+            let Some(value_len) = data[value_start..].find(quote) else {
+                break;
+            };
+            let value = &data[value_start..value_start + value_len];
+            let value_end = value_start + value_len;
+
+            match name {
+                "style" if !self.inline_styles => {
+                    w.write_str(data[..name_start].trim_end())?;
+                    self.write_style_attributes(w, value)?;
+                    data = &data[value_end + 1..];
+                    continue;
+                }
+                "style" => {
+                    w.write_str(&data[..value_start])?;
+                    self.write_style(w, value)?;
+                }
+                name if PAINT_PROPERTIES.contains(&name) => {
+                    w.write_str(&data[..value_start])?;
+                    w.write_str(self.color(value))?;
+                }
+                _ => w.write_str(&data[..value_end])?,
+            }
+            data = &data[value_end..];
+        }
+        w.write_str(data)
+    }
+
+    fn write_style<W: Write>(self, w: &mut W, style: &str) -> fmt::Result {
+        for (i, declaration) in style.split(';').enumerate() {
+            if i > 0 {
+                w.write_char(';')?;
+            }
+            match declaration.split_once(':') {
+                Some((property, value)) if PAINT_PROPERTIES.contains(&property.trim()) => {
+                    write!(w, "{property}:{}", self.color(value))?;
+                }
+                _ => w.write_str(declaration)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes every declaration of the given inline style as presentation attribute, each preceded
+    /// by a space.
+    pub(crate) fn write_style_attributes<W: Write>(self, w: &mut W, style: &str) -> fmt::Result {
+        for (property, value) in style.split(';').filter_map(|decl| decl.split_once(':')) {
+            let (property, value) = (property.trim(), value.trim());
+            let value = match PAINT_PROPERTIES.contains(&property) {
+                true => self.color(value),
+                false => value,
+            };
+            crate::render::write_attribute(w, property, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// The given color, or `currentColor` unless it paints nothing or already is the current color.
+/// Gradients and patterns are replaced as well.
+fn recolor(value: &str) -> &str {
+    let keep = ["none", "transparent", "currentColor", "inherit"]
+        .iter()
+        .any(|keep| value.trim().eq_ignore_ascii_case(keep));
+    match keep {
+        true => value,
+        false => "currentColor",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Rewrite;
+
+    fn rewrite(rewrite: Rewrite, data: &str) -> String {
+        let mut out = String::new();
+        rewrite.write(&mut out, data).unwrap();
+        out
+    }
+
+    #[test]
+    fn replaces_colors() {
+        let monochrome = Rewrite {
+            monochrome: true,
+            inline_styles: true,
+        };
+        assert_eq!(
+            rewrite(
+                monochrome,
+                r##"<path fill="#E34F26" d="M0 0h24v24H0z"/><path fill='none' stroke="url(#g)" style="fill: red; opacity: .5"/><stop stop-color="#fff"/>"##
+            ),
+            r#"<path fill="currentColor" d="M0 0h24v24H0z"/><path fill='none' stroke="currentColor" style="fill:currentColor; opacity: .5"/><stop stop-color="currentColor"/>"#
+        );
+    }
+
+    #[test]
+    fn moves_inline_styles_to_attributes() {
+        let strict = Rewrite {
+            monochrome: false,
+            inline_styles: false,
+        };
+        assert_eq!(
+            rewrite(
+                strict,
+                r#"<path d="M0 0h24" style="fill:none;stroke:#000;stroke-width:32px"/>"#
+            ),
+            r##"<path d="M0 0h24" fill="none" stroke="#000" stroke-width="32px"/>"##
+        );
+    }
+}
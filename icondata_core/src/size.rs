@@ -0,0 +1,141 @@
+use std::borrow::Cow;
+
+use crate::{IconData, RenderOptions};
+
+impl IconData {
+    /// Ratio of width to height of the icon, taken from its view box or else from its width and
+    /// height.
+    ///
+    /// Most icons are square, but logos and weather icons often are not. `None` if the icon
+    /// declares neither a view box nor numeric width and height.
+    pub fn intrinsic_aspect(&self) -> Option<f64> {
+        let (width, height) = match self.view_box {
+            Some(view_box) => {
+                let mut numbers = view_box
+                    .split(|c: char| c.is_whitespace() || c == ',')
+                    .filter(|number| !number.is_empty())
+                    .skip(2)
+                    .map(str::parse::<f64>);
+                (numbers.next()?.ok()?, numbers.next()?.ok()?)
+            }
+            None => (split_length(self.width?)?.0, split_length(self.height?)?.0),
+        };
+        (width > 0.0 && height > 0.0).then_some(width / height)
+    }
+}
+
+/// Width and height an icon is rendered with.
+///
+/// When only one of them is given in the options, the other one is derived from the icon's
+/// [`IconData::intrinsic_aspect`], so that non-square icons are not squished. If the given length
+/// cannot be scaled, like `100%`, the other one is left out and derived by the browser. Without any
+/// size in the options, the icon's own size or `1em` is used.
+pub(crate) fn dimensions<'a>(
+    icon: &IconData,
+    options: &RenderOptions<'a>,
+) -> (Option<Cow<'a, str>>, Option<Cow<'a, str>>) {
+    let aspect = icon.intrinsic_aspect();
+    match (options.width, options.height) {
+        (Some(width), None) => {
+            let height = match aspect {
+                Some(aspect) => scale(width, 1.0 / aspect),
+                None => Some(Cow::Borrowed(icon.height.unwrap_or("1em"))),
+            };
+            (Some(Cow::Borrowed(width)), height)
+        }
+        (None, Some(height)) => {
+            let width = match aspect {
+                Some(aspect) => scale(height, aspect),
+                None => Some(Cow::Borrowed(icon.width.unwrap_or("1em"))),
+            };
+            (width, Some(Cow::Borrowed(height)))
+        }
+        (width, height) => (
+            Some(Cow::Borrowed(width.or(icon.width).unwrap_or("1em"))),
+            Some(Cow::Borrowed(height.or(icon.height).unwrap_or("1em"))),
+        ),
+    }
+}
+
+/// Multiplies a css length like `2em` or `24` by `factor`, keeping its unit.
+fn scale(length: &str, factor: f64) -> Option<Cow<'_, str>> {
+    if factor == 1.0 {
+        return Some(Cow::Borrowed(length));
+    }
+    let (number, unit) = split_length(length)?;
+    let scaled = format!("{:.4}", number * factor);
+    let scaled = scaled.trim_end_matches('0').trim_end_matches('.');
+    Some(Cow::Owned(format!("{scaled}{unit}")))
+}
+
+/// Splits a css length into its number and unit. `None` for percentages and anything which is not
+/// a plain length.
+fn split_length(length: &str) -> Option<(f64, &str)> {
+    let length = length.trim();
+    let unit_start = length
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(length.len());
+    let (number, unit) = length.split_at(unit_start);
+    if !unit.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    Some((number.parse().ok()?, unit))
+}
+
+#[cfg(test)]
+mod test {
+    use super::dimensions;
+    use crate::{IconData, IconPaint, RenderOptions};
+
+    static SUN: &IconData = &IconData {
+        id: "TestSun",
+        label: "Sun",
+        source: None,
+        paint: IconPaint::Fill,
+        brand_color: None,
+        style: None,
+        x: None,
+        y: None,
+        width: None,
+        height: None,
+        view_box: Some("0 0 30 20"),
+        stroke_linecap: None,
+        stroke_linejoin: None,
+        stroke_width: None,
+        stroke: None,
+        fill: None,
+        data: r#"<circle cx="15" cy="10" r="8" />"#,
+    };
+
+    fn dimensions_of(width: Option<&str>, height: Option<&str>) -> (String, String) {
+        let options = RenderOptions {
+            width,
+            height,
+            ..Default::default()
+        };
+        let (width, height) = dimensions(SUN, &options);
+        (
+            width.unwrap_or_default().into_owned(),
+            height.unwrap_or_default().into_owned(),
+        )
+    }
+
+    #[test]
+    fn preserves_aspect() {
+        assert_eq!(SUN.intrinsic_aspect(), Some(1.5));
+        assert_eq!(
+            dimensions_of(Some("3em"), None),
+            ("3em".into(), "2em".into())
+        );
+        assert_eq!(
+            dimensions_of(None, Some("24px")),
+            ("36px".into(), "24px".into())
+        );
+        assert_eq!(dimensions_of(None, Some("1")), ("1.5".into(), "1".into()));
+        assert_eq!(
+            dimensions_of(Some("100%"), None),
+            ("100%".into(), "".into())
+        );
+        assert_eq!(dimensions_of(None, None), ("1em".into(), "1em".into()));
+    }
+}
@@ -0,0 +1,39 @@
+/// Where an icon was taken from: the upstream repository, the commit it was generated from and the
+/// path of its svg file, e.g. to link to it from galleries or to report rendering bugs upstream.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IconSource {
+    /// Url of the upstream git repository, e.g. "https://github.com/FortAwesome/Font-Awesome".
+    pub repository: &'static str,
+    /// Commit of the repository the icon was generated from.
+    pub revision: &'static str,
+    /// Path of the svg file, relative to the root of the repository.
+    pub path: &'static str,
+}
+
+impl IconSource {
+    /// Link to the svg file as of [`IconSource::revision`], in the form used by GitHub and GitLab.
+    pub fn url(&self) -> String {
+        let repository = self.repository.trim_end_matches('/');
+        let repository = repository.strip_suffix(".git").unwrap_or(repository);
+        format!("{repository}/blob/{}/{}", self.revision, self.path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::IconSource;
+
+    #[test]
+    fn url() {
+        let source = IconSource {
+            repository: "https://github.com/FortAwesome/Font-Awesome.git",
+            revision: "0d1f27efb836eb2ab994ba37221849ed64a73e5c",
+            path: "svgs/brands/github.svg",
+        };
+        assert_eq!(
+            source.url(),
+            "https://github.com/FortAwesome/Font-Awesome/blob/0d1f27efb836eb2ab994ba37221849ed64a73e5c/svgs/brands/github.svg"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{render, Animation, Icon, RenderOptions};
+
+/// A loading indicator with the center it spins around.
+///
+/// The glyphs of many spinners are not centered in their view box, so rotating them around the
+/// center of the rendered icon makes them wobble. The main `icondata` crate lists the spinners of
+/// all packages in `icondata::spinners()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Spinner {
+    pub icon: Icon,
+    /// Center of the glyph as a CSS `transform-origin` relative to the rendered icon, e.g.
+    /// "50% 50%".
+    pub origin: &'static str,
+}
+
+impl Spinner {
+    /// Renders the icon spinning around its [`Spinner::origin`], using [`Animation::Spin`] unless
+    /// the options set another animation.
+    ///
+    /// With the `strict-csp` feature, no inline styles are rendered, so the origin is set as
+    /// `transform-origin` attribute instead, which stylesheets setting the origin take precedence
+    /// over.
+    pub fn render(&self, options: &RenderOptions) -> String {
+        let animation = options.animation.or(Some(Animation::Spin));
+        if cfg!(feature = "strict-csp") {
+            let mut buf = String::with_capacity(self.icon.data.len() + 256);
+            render::write_svg_with_data(
+                &mut buf,
+                self.icon,
+                self.icon.data,
+                &[("transform-origin", self.origin)],
+                &RenderOptions {
+                    animation,
+                    ..*options
+                },
+            )
+            .expect("writing to a String does not fail");
+            return buf;
+        }
+
+        let style = match options.style {
+            Some(style) => format!("transform-origin: {}; {style}", self.origin),
+            None => format!("transform-origin: {};", self.origin),
+        };
+        self.icon.render(&RenderOptions {
+            style: Some(&style),
+            animation,
+            ..*options
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Spinner;
+    use crate::{IconData, IconPaint, RenderOptions};
+
+    static SPINNER: Spinner = Spinner {
+        icon: &IconData {
+            id: "Loader",
+            label: "Loader",
+            source: None,
+            paint: IconPaint::Fill,
+            brand_color: None,
+            style: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            view_box: Some("0 0 24 24"),
+            stroke_linecap: None,
+            stroke_linejoin: None,
+            stroke_width: None,
+            stroke: None,
+            fill: None,
+            data: r#"<path d="M12 3a9 9 0 0 1 9 9"/>"#,
+        },
+        origin: "50% 37.5%",
+    };
+
+    #[test]
+    #[cfg(not(feature = "strict-csp"))]
+    fn spins_around_origin() {
+        let out = SPINNER.render(&RenderOptions {
+            style: Some("color: red;"),
+            ..Default::default()
+        });
+        assert!(out.contains("transform-origin: 50% 37.5%; color: red;"));
+        assert!(out.contains("animation: icondata-spin 2s linear infinite;"));
+    }
+
+    #[test]
+    #[cfg(feature = "strict-csp")]
+    fn spins_by_class() {
+        let out = SPINNER.render(&RenderOptions {
+            style: Some("color: red;"),
+            ..Default::default()
+        });
+        assert!(out.contains(r#"class="icondata-spin""#));
+        assert!(out.contains(r#" transform-origin="50% 37.5%""#));
+        assert!(!out.contains("style"));
+    }
+}
@@ -0,0 +1,32 @@
+use crate::Icon;
+
+/// The variants of an icon showing a state which is on and off, like an eye and a crossed out eye.
+///
+/// Icon crates provide one for every such pair of icons, e.g. `LuEyeToggle()`, so that toggle
+/// buttons don't have to pair the icons by hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StatePair {
+    /// The icon showing the state, e.g. an eye or a closed lock.
+    pub on: Icon,
+    /// The icon showing the opposite state, e.g. a crossed out eye or an open lock.
+    pub off: Icon,
+}
+
+impl StatePair {
+    /// The icon showing the state if `on`, else the one showing the opposite state.
+    pub const fn get(&self, on: bool) -> Icon {
+        match on {
+            true => self.on,
+            false => self.off,
+        }
+    }
+
+    /// The pair with its states swapped, for toggles whose icon shows the action rather than the
+    /// state, like a crossed out eye to hide something visible.
+    pub const fn swapped(&self) -> StatePair {
+        StatePair {
+            on: self.off,
+            off: self.on,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::sync::RwLock;
+
+use crate::IconData;
+
+/// Translated labels for a single locale, keyed by icon id.
+///
+/// Tables are usually generated from the translation template exported by the icondata builder
+/// and registered once at startup using [`register_labels`].
+#[derive(Debug, Clone, Copy)]
+pub struct LabelTable {
+    /// Locale of the translations, e.g. "de" or "pt-BR".
+    pub locale: &'static str,
+    /// Pairs of icon id and translated label, sorted by icon id.
+    pub labels: &'static [(&'static str, &'static str)],
+}
+
+impl LabelTable {
+    /// Translated label of the icon with the given id, if this table contains one.
+    pub fn get(&self, id: &str) -> Option<&'static str> {
+        self.labels
+            .binary_search_by(|(candidate, _)| (*candidate).cmp(id))
+            .ok()
+            .map(|i| self.labels[i].1)
+    }
+}
+
+static TABLES: RwLock<Vec<&'static LabelTable>> = RwLock::new(Vec::new());
+
+/// Makes the translations of `table` available through [`IconData::localized_label`].
+///
+/// Tables registered later take precedence over earlier ones for the same locale, so that an
+/// application can override individual labels of a table provided by a library.
+///
+/// # Panics
+///
+/// Panics if the labels of `table` are not sorted by icon id.
+pub fn register_labels(table: &'static LabelTable) {
+    assert!(
+        table.labels.windows(2).all(|pair| pair[0].0 < pair[1].0),
+        "labels of locale {} must be sorted by icon id without duplicates",
+        table.locale
+    );
+    TABLES
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(table);
+}
+
+impl IconData {
+    /// Label of this icon translated to `locale`, falling back to the english [`IconData::label`]
+    /// if no registered table contains a translation.
+    pub fn localized_label(&self, locale: &str) -> &'static str {
+        TABLES
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .rev()
+            .filter(|table| table.locale.eq_ignore_ascii_case(locale))
+            .find_map(|table| table.get(self.id))
+            .unwrap_or(self.label)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{register_labels, LabelTable};
+    use crate::{IconData, IconPaint};
+
+    static HOUSE: &IconData = &IconData {
+        id: "TestHouseFill",
+        label: "House Fill",
+        source: None,
+        paint: IconPaint::Fill,
+        brand_color: None,
+        style: None,
+        x: None,
+        y: None,
+        width: None,
+        height: None,
+        view_box: None,
+        stroke_linecap: None,
+        stroke_linejoin: None,
+        stroke_width: None,
+        stroke: None,
+        fill: None,
+        data: "",
+    };
+
+    #[test]
+    fn localized_label() {
+        static GERMAN: LabelTable = LabelTable {
+            locale: "de",
+            labels: &[("TestArrow", "Pfeil"), ("TestHouseFill", "Haus gefüllt")],
+        };
+        static GERMAN_OVERRIDE: LabelTable = LabelTable {
+            locale: "de",
+            labels: &[("TestHouseFill", "Haus")],
+        };
+
+        assert_eq!(HOUSE.localized_label("de"), "House Fill");
+        register_labels(&GERMAN);
+        assert_eq!(HOUSE.localized_label("de"), "Haus gefüllt");
+        assert_eq!(HOUSE.localized_label("DE"), "Haus gefüllt");
+        assert_eq!(HOUSE.localized_label("fr"), "House Fill");
+        register_labels(&GERMAN_OVERRIDE);
+        assert_eq!(HOUSE.localized_label("de"), "Haus");
+    }
+}
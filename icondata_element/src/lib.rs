@@ -0,0 +1,109 @@
+//! The `<icon-data>` custom element, rendering icons of the compressed catalog exported by the
+//! builder for frontends not written in Rust.
+//!
+//! ```html
+//! <icon-data name="bs-house" size="2em" label="Home"></icon-data>
+//! ```
+
+use std::{collections::HashMap, sync::OnceLock};
+
+use icondata_core::{Catalog, IconData, RenderOptions};
+use wasm_bindgen::prelude::*;
+
+/// Tag name of the custom element.
+const TAG: &str = "icon-data";
+
+/// Leading bytes of an uncompressed catalog.
+const CATALOG_MAGIC: &[u8] = b"ICDC";
+
+static ICONS: OnceLock<HashMap<&'static str, IconData>> = OnceLock::new();
+
+#[wasm_bindgen(inline_js = r#"
+export function define_element(tag, render) {
+    if (customElements.get(tag)) {
+        return;
+    }
+    customElements.define(tag, class extends HTMLElement {
+        static get observedAttributes() {
+            return ["name", "size", "label"];
+        }
+        connectedCallback() {
+            this.update();
+        }
+        attributeChangedCallback() {
+            this.update();
+        }
+        update() {
+            this.innerHTML = render(
+                this.getAttribute("name") ?? "",
+                this.getAttribute("size") ?? "",
+                this.getAttribute("label") ?? "",
+            );
+        }
+    });
+}
+"#)]
+extern "C" {
+    fn define_element(tag: &str, render: &Closure<dyn Fn(String, String, String) -> String>);
+}
+
+/// Loads the catalog, deflate compressed or not, and defines the `<icon-data>` element.
+///
+/// The element renders the icon given by its `name` attribute, either the icon id like `BsHouse`
+/// or its kebab case form `bs-house`. The optional `size` sets the height, the width following
+/// from the icon's aspect ratio, and `label` makes the icon accessible under this name.
+#[wasm_bindgen]
+pub fn define(catalog: &[u8]) -> Result<(), JsError> {
+    let bytes = match catalog.starts_with(CATALOG_MAGIC) {
+        true => catalog.to_vec(),
+        false => miniz_oxide::inflate::decompress_to_vec(catalog)
+            .map_err(|err| JsError::new(&format!("Could not decompress catalog: {err:?}")))?,
+    };
+    let catalog: &'static Catalog = Box::leak(Box::new(Catalog::from_bytes(&bytes)?));
+    ICONS.get_or_init(|| {
+        catalog
+            .packages
+            .iter()
+            .flat_map(|package| package.icons.iter())
+            .map(|icon| (icon.id.as_str(), icon.to_icon_data()))
+            .collect()
+    });
+
+    let render = Closure::<dyn Fn(String, String, String) -> String>::new(render);
+    define_element(TAG, &render);
+    // The element calls back into the closure for as long as the page lives.
+    render.forget();
+    Ok(())
+}
+
+fn render(name: String, size: String, label: String) -> String {
+    let Some(icon) = ICONS.get().and_then(|icons| {
+        icons
+            .get(name.as_str())
+            .or_else(|| icons.get(icon_id(&name).as_str()))
+    }) else {
+        return String::new();
+    };
+    icon.render(&RenderOptions {
+        height: Some(match size.is_empty() {
+            true => "1em",
+            false => size.as_str(),
+        }),
+        aria_label: (!label.is_empty()).then_some(label.as_str()),
+        ..Default::default()
+    })
+}
+
+/// Icon id of a kebab case icon name, e.g. `BsHouse` for `bs-house`.
+fn icon_id(name: &str) -> String {
+    name.split('-')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
@@ -0,0 +1,12 @@
+//! Generates the C header `include/icondata.h` from the exported functions and types.
+
+use std::{env, path::Path};
+
+fn main() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").expect("set by cargo");
+    cbindgen::generate(&crate_dir)
+        .expect("exported items cbindgen can translate")
+        .write_to_file(Path::new(&crate_dir).join("include/icondata.h"));
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+}
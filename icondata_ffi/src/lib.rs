@@ -0,0 +1,373 @@
+//! C library looking up icons of the catalog exported by the builder and rendering them as svg,
+//! for native apps not written in Rust. The header `include/icondata.h` is generated by cbindgen
+//! when building.
+//!
+//! ```c
+//! IcondataCatalog *catalog;
+//! if (icondata_catalog_load(bytes, len, &catalog) == ICONDATA_STATUS_OK) {
+//!     IcondataRenderOptions options = { .size = "2em", .label = "Home" };
+//!     char *svg = icondata_to_svg_string(catalog, "bs-house", &options);
+//!     /* ... */
+//!     icondata_string_free(svg);
+//!     icondata_catalog_free(catalog);
+//! }
+//! ```
+
+use std::{
+    collections::HashMap,
+    ffi::{c_char, CStr, CString},
+    ptr, slice,
+};
+
+use icondata_core::{Catalog, CatalogError, CatalogIcon, RenderOptions};
+
+/// Leading bytes of an uncompressed catalog.
+const CATALOG_MAGIC: &[u8] = b"ICDC";
+
+/// Outcome of loading a catalog.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IcondataStatus {
+    Ok,
+    /// A required pointer argument was null.
+    NullArgument,
+    /// The bytes are neither a catalog nor a deflate compressed one.
+    NotACatalog,
+    /// The catalog was written in a layout version this library cannot read.
+    UnsupportedVersion,
+    /// The catalog ended in the middle of an item.
+    Truncated,
+    /// A string of the catalog is not valid UTF-8.
+    InvalidUtf8,
+}
+
+impl From<CatalogError> for IcondataStatus {
+    fn from(error: CatalogError) -> IcondataStatus {
+        match error {
+            CatalogError::UnsupportedVersion(_) => IcondataStatus::UnsupportedVersion,
+            CatalogError::Truncated => IcondataStatus::Truncated,
+            CatalogError::InvalidUtf8 => IcondataStatus::InvalidUtf8,
+            CatalogError::NotACatalog
+            | CatalogError::InvalidPaint(_)
+            | CatalogError::InvalidSignature => IcondataStatus::NotACatalog,
+        }
+    }
+}
+
+/// An icon of a loaded catalog. All strings are owned by the catalog and valid until it is freed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct IcondataIcon {
+    /// Stable identifier of the icon, e.g. "BsHouse".
+    pub id: *const c_char,
+    pub label: *const c_char,
+    /// Short name of the package of the icon, e.g. "bs".
+    pub package: *const c_char,
+    /// Variant family of the icon, e.g. "outlined", or null.
+    pub variant: *const c_char,
+    /// Whether the icon is a third-party brand or logo.
+    pub brand: bool,
+}
+
+/// Options of [`icondata_to_svg_string`]. Null fields are left unset.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct IcondataRenderOptions {
+    /// Height of the icon, defaulting to "1em". The width follows from the icon's aspect ratio.
+    pub size: *const c_char,
+    /// Accessible name of the icon, exposing it to assistive technology.
+    pub label: *const c_char,
+}
+
+/// A loaded catalog, opaque to C.
+pub struct IcondataCatalog {
+    catalog: Catalog,
+    /// Position of each icon by package and index within the package, in catalog order.
+    positions: Vec<(usize, usize)>,
+    /// Icons in catalog order, pointing into `_strings`.
+    icons: Vec<IcondataIcon>,
+    /// Position in `icons` by icon id.
+    by_id: HashMap<String, usize>,
+    /// Owners of the strings of `icons`, only kept alive. Their heap buffers do not move with the
+    /// vector.
+    _strings: Vec<CString>,
+}
+
+impl IcondataCatalog {
+    fn new(catalog: Catalog) -> IcondataCatalog {
+        let mut strings = Vec::new();
+        let mut intern = |value: &str| {
+            // Strings read from the catalog contain no NUL bytes in practice, drop them otherwise.
+            let string = CString::new(value.replace('\0', "")).expect("NUL bytes were removed");
+            let ptr = string.as_ptr();
+            strings.push(string);
+            ptr
+        };
+
+        let mut positions = Vec::new();
+        let mut icons = Vec::new();
+        let mut by_id = HashMap::new();
+        for (package_index, package) in catalog.packages.iter().enumerate() {
+            let short_name = intern(&package.short_name);
+            for (icon_index, icon) in package.icons.iter().enumerate() {
+                by_id.insert(icon.id.clone(), icons.len());
+                positions.push((package_index, icon_index));
+                icons.push(IcondataIcon {
+                    id: intern(&icon.id),
+                    label: intern(&icon.label),
+                    package: short_name,
+                    variant: icon.variant.as_deref().map_or(ptr::null(), &mut intern),
+                    brand: icon.brand,
+                });
+            }
+        }
+        IcondataCatalog {
+            catalog,
+            positions,
+            icons,
+            by_id,
+            _strings: strings,
+        }
+    }
+
+    /// Position of the icon with the given id or its kebab case form.
+    fn find(&self, name: &str) -> Option<usize> {
+        self.by_id
+            .get(name)
+            .or_else(|| self.by_id.get(&icon_id(name)))
+            .copied()
+    }
+
+    fn catalog_icon(&self, index: usize) -> &CatalogIcon {
+        let (package, icon) = self.positions[index];
+        &self.catalog.packages[package].icons[icon]
+    }
+}
+
+/// Loads a catalog, deflate compressed or not, from `len` bytes at `bytes`, storing it in `out` if
+/// the returned status is `ICONDATA_STATUS_OK`. The bytes are copied and can be freed afterwards.
+///
+/// # Safety
+///
+/// `bytes` must point to `len` readable bytes and `out` to a writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn icondata_catalog_load(
+    bytes: *const u8,
+    len: usize,
+    out: *mut *mut IcondataCatalog,
+) -> IcondataStatus {
+    if bytes.is_null() || out.is_null() {
+        return IcondataStatus::NullArgument;
+    }
+    let bytes = slice::from_raw_parts(bytes, len);
+    let decompressed;
+    let bytes = match bytes.starts_with(CATALOG_MAGIC) {
+        true => bytes,
+        false => match miniz_oxide::inflate::decompress_to_vec(bytes) {
+            Ok(inflated) => {
+                decompressed = inflated;
+                &decompressed
+            }
+            Err(_) => return IcondataStatus::NotACatalog,
+        },
+    };
+    match Catalog::from_bytes(bytes) {
+        Ok(catalog) => {
+            *out = Box::into_raw(Box::new(IcondataCatalog::new(catalog)));
+            IcondataStatus::Ok
+        }
+        Err(error) => error.into(),
+    }
+}
+
+/// Frees a catalog and all strings of its icons. Does nothing for null.
+///
+/// # Safety
+///
+/// `catalog` must be null or returned by [`icondata_catalog_load`] and not freed before.
+#[no_mangle]
+pub unsafe extern "C" fn icondata_catalog_free(catalog: *mut IcondataCatalog) {
+    if !catalog.is_null() {
+        drop(Box::from_raw(catalog));
+    }
+}
+
+/// Number of icons of all packages of the catalog.
+///
+/// # Safety
+///
+/// `catalog` must be a catalog returned by [`icondata_catalog_load`] and not freed.
+#[no_mangle]
+pub unsafe extern "C" fn icondata_catalog_num_icons(catalog: *const IcondataCatalog) -> usize {
+    let catalog = &*catalog;
+    catalog.icons.len()
+}
+
+/// The icon at `index`, counting the icons of all packages in catalog order, or null if `index` is
+/// out of bounds.
+///
+/// # Safety
+///
+/// `catalog` must be a catalog returned by [`icondata_catalog_load`] and not freed.
+#[no_mangle]
+pub unsafe extern "C" fn icondata_catalog_icon(
+    catalog: *const IcondataCatalog,
+    index: usize,
+) -> *const IcondataIcon {
+    let catalog = &*catalog;
+    catalog
+        .icons
+        .get(index)
+        .map_or(ptr::null(), |icon| icon as *const IcondataIcon)
+}
+
+/// The icon with the given id like "BsHouse" or its kebab case form "bs-house", or null if the
+/// catalog has no such icon.
+///
+/// # Safety
+///
+/// `catalog` must be a catalog returned by [`icondata_catalog_load`] and not freed, `name` a NUL
+/// terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn icondata_lookup(
+    catalog: *const IcondataCatalog,
+    name: *const c_char,
+) -> *const IcondataIcon {
+    let catalog = &*catalog;
+    match to_str(name).and_then(|name| catalog.find(name)) {
+        Some(index) => &catalog.icons[index],
+        None => ptr::null(),
+    }
+}
+
+/// Renders the icon with the given id or its kebab case form as a standalone `<svg>` element, or
+/// returns null if the catalog has no such icon. `options` may be null. The returned string must
+/// be freed with [`icondata_string_free`].
+///
+/// # Safety
+///
+/// `catalog` must be a catalog returned by [`icondata_catalog_load`] and not freed, `name` and the
+/// non-null fields of `options` NUL terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn icondata_to_svg_string(
+    catalog: *const IcondataCatalog,
+    name: *const c_char,
+    options: *const IcondataRenderOptions,
+) -> *mut c_char {
+    let catalog = &*catalog;
+    let Some(index) = to_str(name).and_then(|name| catalog.find(name)) else {
+        return ptr::null_mut();
+    };
+    let (size, label) = match options.as_ref() {
+        Some(options) => (to_str(options.size), to_str(options.label)),
+        None => (None, None),
+    };
+    // SAFETY: The icon data borrowing from the catalog is dropped before returning, while the
+    // caller keeps the catalog alive.
+    let icon: &'static CatalogIcon = &*(catalog.catalog_icon(index) as *const CatalogIcon);
+    let svg = icon.to_icon_data().render(&RenderOptions {
+        height: Some(size.unwrap_or("1em")),
+        aria_label: label,
+        ..Default::default()
+    });
+    CString::new(svg).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Frees a string returned by [`icondata_to_svg_string`]. Does nothing for null.
+///
+/// # Safety
+///
+/// `string` must be null or returned by [`icondata_to_svg_string`] and not freed before.
+#[no_mangle]
+pub unsafe extern "C" fn icondata_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// The NUL terminated UTF-8 string at `string`, none for null or invalid UTF-8.
+unsafe fn to_str<'a>(string: *const c_char) -> Option<&'a str> {
+    match string.is_null() {
+        true => None,
+        false => CStr::from_ptr(string).to_str().ok(),
+    }
+}
+
+/// Icon id of a kebab case icon name, e.g. `BsHouse` for `bs-house`.
+fn icon_id(name: &str) -> String {
+    name.split('-')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use icondata_core::CatalogPackage;
+
+    use super::*;
+
+    fn load(bytes: &[u8]) -> Result<*mut IcondataCatalog, IcondataStatus> {
+        let mut catalog = ptr::null_mut();
+        match unsafe { icondata_catalog_load(bytes.as_ptr(), bytes.len(), &mut catalog) } {
+            IcondataStatus::Ok => Ok(catalog),
+            status => Err(status),
+        }
+    }
+
+    fn house() -> Vec<u8> {
+        Catalog {
+            packages: vec![CatalogPackage {
+                short_name: "bs".to_owned(),
+                name: "Bootstrap Icons".to_owned(),
+                icons: vec![CatalogIcon {
+                    id: "BsHouse".to_owned(),
+                    label: "House".to_owned(),
+                    view_box: Some("0 0 16 16".to_owned()),
+                    data: "<path d=\"M0 0h16v16H0z\"></path>".to_owned(),
+                    ..Default::default()
+                }],
+            }],
+        }
+        .to_bytes()
+    }
+
+    #[test]
+    fn looks_up_and_renders_icons() {
+        let catalog = load(&house()).unwrap();
+        unsafe {
+            assert_eq!(icondata_catalog_num_icons(catalog), 1);
+            let icon = &*icondata_lookup(catalog, c"bs-house".as_ptr());
+            assert_eq!(CStr::from_ptr(icon.id), c"BsHouse");
+            assert_eq!(CStr::from_ptr(icon.package), c"bs");
+            assert!(icon.variant.is_null());
+            assert!(icondata_lookup(catalog, c"BsHome".as_ptr()).is_null());
+            assert!(icondata_catalog_icon(catalog, 1).is_null());
+
+            let options = IcondataRenderOptions {
+                size: c"2em".as_ptr(),
+                label: ptr::null(),
+            };
+            let svg = icondata_to_svg_string(catalog, c"BsHouse".as_ptr(), &options);
+            let markup = CStr::from_ptr(svg).to_str().unwrap();
+            assert!(markup.starts_with("<svg"));
+            assert!(markup.contains("height=\"2em\""));
+            icondata_string_free(svg);
+            icondata_catalog_free(catalog);
+        }
+    }
+
+    #[test]
+    fn reads_compressed_catalogs() {
+        let compressed = miniz_oxide::deflate::compress_to_vec(&house(), 6);
+        let catalog = load(&compressed).unwrap();
+        unsafe { icondata_catalog_free(catalog) };
+        assert_eq!(load(b"not a catalog"), Err(IcondataStatus::NotACatalog));
+    }
+}
@@ -1,9 +1,32 @@
 //! This crate provides a collection of icons in the form of SVG data
 //! from the [__Feather__](https://github.com/feathericons/feather) icon set.
 
+/// Documentation of every icon, showing its usage with different frameworks.
+#[cfg(feature = "docs")]
+macro_rules! examples {
+    ($icon:ident, $label:literal) => {
+        concat!(
+            $label,
+            "\n\n# Examples\n\nWith [Leptos](https://leptos.dev) and [`leptos_icons`](https://docs.rs/leptos_icons):\n```rust,ignore\nuse leptos::*;\nuse leptos_icons::Icon;\n\nview! { <Icon icon=icondata::",
+            stringify!($icon),
+            " /> }\n```\n\nWith [Yew](https://yew.rs):\n```rust,ignore\nuse yew::prelude::*;\n\nlet svg = icondata::",
+            stringify!($icon),
+            ".render(&Default::default());\nhtml! { { Html::from_html_unchecked(AttrValue::from(svg)) } }\n```\n\nWith [Dioxus](https://dioxuslabs.com):\n```rust,ignore\nuse dioxus::prelude::*;\n\nlet svg = icondata::",
+            stringify!($icon),
+            ".render(&Default::default());\nrsx! { span { dangerous_inner_html: \"{svg}\" } }\n```",
+        )
+    };
+}
+
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiActivity, "Activity"))]
 pub static FiActivity: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiActivity",
+    label: "Activity",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18,8 +41,14 @@ pub static FiActivity: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polyline points="22 12 18 12 15 21 9 3 6 12 2 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiAirplay, "Airplay"))]
 pub static FiAirplay: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiAirplay",
+    label: "Airplay",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -35,8 +64,14 @@ pub static FiAirplay: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="12 15 17 21 7 21 12 15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiAlertCircle, "Alert Circle"))]
 pub static FiAlertCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiAlertCircle",
+    label: "Alert Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -53,8 +88,14 @@ pub static FiAlertCircle: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="16" x2="12.01" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiAlertOctagon, "Alert Octagon"))]
 pub static FiAlertOctagon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiAlertOctagon",
+    label: "Alert Octagon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -71,8 +112,14 @@ pub static FiAlertOctagon: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="16" x2="12.01" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiAlertTriangle, "Alert Triangle"))]
 pub static FiAlertTriangle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiAlertTriangle",
+    label: "Alert Triangle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -89,8 +136,14 @@ pub static FiAlertTriangle: &icondata_core::IconData = &icondata_core::IconData
 <line x1="12" y1="17" x2="12.01" y2="17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiAlignCenter, "Align Center"))]
 pub static FiAlignCenter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiAlignCenter",
+    label: "Align Center",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -108,8 +161,14 @@ pub static FiAlignCenter: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="18" y1="18" x2="6" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiAlignJustify, "Align Justify"))]
 pub static FiAlignJustify: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiAlignJustify",
+    label: "Align Justify",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -127,8 +186,14 @@ pub static FiAlignJustify: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="21" y1="18" x2="3" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiAlignLeft, "Align Left"))]
 pub static FiAlignLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiAlignLeft",
+    label: "Align Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -146,8 +211,14 @@ pub static FiAlignLeft: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="17" y1="18" x2="3" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiAlignRight, "Align Right"))]
 pub static FiAlignRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiAlignRight",
+    label: "Align Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -165,8 +236,14 @@ pub static FiAlignRight: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="21" y1="18" x2="7" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiAnchor, "Anchor"))]
 pub static FiAnchor: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiAnchor",
+    label: "Anchor",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -183,8 +260,14 @@ pub static FiAnchor: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 12H2a10 10 0 0 0 20 0h-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiAperture, "Aperture"))]
 pub static FiAperture: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiAperture",
+    label: "Aperture",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -205,8 +288,14 @@ pub static FiAperture: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="16.62" y1="12" x2="10.88" y2="21.94" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiArchive, "Archive"))]
 pub static FiArchive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiArchive",
+    label: "Archive",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -223,8 +312,14 @@ pub static FiArchive: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="10" y1="12" x2="14" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiArrowDown, "Arrow Down"))]
 pub static FiArrowDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiArrowDown",
+    label: "Arrow Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -240,8 +335,14 @@ pub static FiArrowDown: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="19 12 12 19 5 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiArrowDownCircle, "Arrow Down Circle"))]
 pub static FiArrowDownCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiArrowDownCircle",
+    label: "Arrow Down Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -258,8 +359,14 @@ pub static FiArrowDownCircle: &icondata_core::IconData = &icondata_core::IconDat
 <line x1="12" y1="8" x2="12" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiArrowDownLeft, "Arrow Down Left"))]
 pub static FiArrowDownLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiArrowDownLeft",
+    label: "Arrow Down Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -275,8 +382,14 @@ pub static FiArrowDownLeft: &icondata_core::IconData = &icondata_core::IconData
 <polyline points="17 17 7 17 7 7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiArrowDownRight, "Arrow Down Right"))]
 pub static FiArrowDownRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiArrowDownRight",
+    label: "Arrow Down Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -292,8 +405,14 @@ pub static FiArrowDownRight: &icondata_core::IconData = &icondata_core::IconData
 <polyline points="17 7 17 17 7 17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiArrowLeft, "Arrow Left"))]
 pub static FiArrowLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiArrowLeft",
+    label: "Arrow Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -309,8 +428,14 @@ pub static FiArrowLeft: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 19 5 12 12 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiArrowLeftCircle, "Arrow Left Circle"))]
 pub static FiArrowLeftCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiArrowLeftCircle",
+    label: "Arrow Left Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -327,8 +452,14 @@ pub static FiArrowLeftCircle: &icondata_core::IconData = &icondata_core::IconDat
 <line x1="16" y1="12" x2="8" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiArrowRight, "Arrow Right"))]
 pub static FiArrowRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiArrowRight",
+    label: "Arrow Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -344,8 +475,14 @@ pub static FiArrowRight: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 5 19 12 12 19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiArrowRightCircle, "Arrow Right Circle"))]
 pub static FiArrowRightCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiArrowRightCircle",
+    label: "Arrow Right Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -362,8 +499,14 @@ pub static FiArrowRightCircle: &icondata_core::IconData = &icondata_core::IconDa
 <line x1="8" y1="12" x2="16" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiArrowUp, "Arrow Up"))]
 pub static FiArrowUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiArrowUp",
+    label: "Arrow Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -379,8 +522,14 @@ pub static FiArrowUp: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="5 12 12 5 19 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiArrowUpCircle, "Arrow Up Circle"))]
 pub static FiArrowUpCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiArrowUpCircle",
+    label: "Arrow Up Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -397,8 +546,14 @@ pub static FiArrowUpCircle: &icondata_core::IconData = &icondata_core::IconData
 <line x1="12" y1="16" x2="12" y2="8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiArrowUpLeft, "Arrow Up Left"))]
 pub static FiArrowUpLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiArrowUpLeft",
+    label: "Arrow Up Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -414,8 +569,14 @@ pub static FiArrowUpLeft: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="7 17 7 7 17 7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiArrowUpRight, "Arrow Up Right"))]
 pub static FiArrowUpRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiArrowUpRight",
+    label: "Arrow Up Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -431,8 +592,14 @@ pub static FiArrowUpRight: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="7 7 17 7 17 17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiAtSign, "At Sign"))]
 pub static FiAtSign: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiAtSign",
+    label: "At Sign",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -448,8 +615,14 @@ pub static FiAtSign: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 8v5a3 3 0 0 0 6 0v-1a10 10 0 1 0-3.92 7.94" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiAward, "Award"))]
 pub static FiAward: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiAward",
+    label: "Award",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -465,8 +638,14 @@ pub static FiAward: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="8.21 13.89 7 23 12 20 17 23 15.79 13.88" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiBarChart, "Bar Chart"))]
 pub static FiBarChart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiBarChart",
+    label: "Bar Chart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -483,8 +662,14 @@ pub static FiBarChart: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="6" y1="20" x2="6" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiBarChart2, "Bar Chart 2"))]
 pub static FiBarChart2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiBarChart2",
+    label: "Bar Chart 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -501,8 +686,14 @@ pub static FiBarChart2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="6" y1="20" x2="6" y2="14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiBattery, "Battery"))]
 pub static FiBattery: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiBattery",
+    label: "Battery",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -518,8 +709,14 @@ pub static FiBattery: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="23" y1="13" x2="23" y2="11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiBatteryCharging, "Battery Charging"))]
 pub static FiBatteryCharging: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiBatteryCharging",
+    label: "Battery Charging",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -536,8 +733,14 @@ pub static FiBatteryCharging: &icondata_core::IconData = &icondata_core::IconDat
 <polyline points="11 6 7 12 13 12 9 18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiBell, "Bell"))]
 pub static FiBell: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiBell",
+    label: "Bell",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -553,8 +756,14 @@ pub static FiBell: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13.73 21a2 2 0 0 1-3.46 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiBellOff, "Bell Off"))]
 pub static FiBellOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiBellOff",
+    label: "Bell Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -573,8 +782,14 @@ pub static FiBellOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="1" y1="1" x2="23" y2="23" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiBluetooth, "Bluetooth"))]
 pub static FiBluetooth: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiBluetooth",
+    label: "Bluetooth",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -589,8 +804,14 @@ pub static FiBluetooth: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polyline points="6.5 6.5 17.5 17.5 12 23 12 1 17.5 6.5 6.5 17.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiBold, "Bold"))]
 pub static FiBold: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiBold",
+    label: "Bold",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -606,8 +827,14 @@ pub static FiBold: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6 12h9a4 4 0 0 1 4 4 4 4 0 0 1-4 4H6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiBook, "Book"))]
 pub static FiBook: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiBook",
+    label: "Book",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -623,8 +850,14 @@ pub static FiBook: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6.5 2H20v20H6.5A2.5 2.5 0 0 1 4 19.5v-15A2.5 2.5 0 0 1 6.5 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiBookOpen, "Book Open"))]
 pub static FiBookOpen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiBookOpen",
+    label: "Book Open",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -640,8 +873,14 @@ pub static FiBookOpen: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 3h-6a4 4 0 0 0-4 4v14a3 3 0 0 1 3-3h7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiBookmark, "Bookmark"))]
 pub static FiBookmark: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiBookmark",
+    label: "Bookmark",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -656,8 +895,14 @@ pub static FiBookmark: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M19 21l-7-5-7 5V5a2 2 0 0 1 2-2h10a2 2 0 0 1 2 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiBox, "Box"))]
 pub static FiBox: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiBox",
+    label: "Box",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -674,8 +919,14 @@ pub static FiBox: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="22.08" x2="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiBriefcase, "Briefcase"))]
 pub static FiBriefcase: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiBriefcase",
+    label: "Briefcase",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -691,8 +942,14 @@ pub static FiBriefcase: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 21V5a2 2 0 0 0-2-2h-4a2 2 0 0 0-2 2v16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCalendar, "Calendar"))]
 pub static FiCalendar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCalendar",
+    label: "Calendar",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -710,8 +967,14 @@ pub static FiCalendar: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" y1="10" x2="21" y2="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCamera, "Camera"))]
 pub static FiCamera: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCamera",
+    label: "Camera",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -727,8 +990,14 @@ pub static FiCamera: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="13" r="4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCameraOff, "Camera Off"))]
 pub static FiCameraOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCameraOff",
+    label: "Camera Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -744,8 +1013,14 @@ pub static FiCameraOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 21H3a2 2 0 0 1-2-2V8a2 2 0 0 1 2-2h3m3-3h6l2 3h4a2 2 0 0 1 2 2v9.34m-7.72-2.06a4 4 0 1 1-5.56-5.56" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCast, "Cast"))]
 pub static FiCast: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCast",
+    label: "Cast",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -761,8 +1036,14 @@ pub static FiCast: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" y1="20" x2="2.01" y2="20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCheck, "Check"))]
 pub static FiCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCheck",
+    label: "Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -777,8 +1058,14 @@ pub static FiCheck: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polyline points="20 6 9 17 4 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCheckCircle, "Check Circle"))]
 pub static FiCheckCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCheckCircle",
+    label: "Check Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -794,8 +1081,14 @@ pub static FiCheckCircle: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="22 4 12 14.01 9 11.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCheckSquare, "Check Square"))]
 pub static FiCheckSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCheckSquare",
+    label: "Check Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -811,8 +1104,14 @@ pub static FiCheckSquare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 12v7a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2V5a2 2 0 0 1 2-2h11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiChevronDown, "Chevron Down"))]
 pub static FiChevronDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiChevronDown",
+    label: "Chevron Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -827,8 +1126,14 @@ pub static FiChevronDown: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polyline points="6 9 12 15 18 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiChevronLeft, "Chevron Left"))]
 pub static FiChevronLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiChevronLeft",
+    label: "Chevron Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -843,8 +1148,14 @@ pub static FiChevronLeft: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polyline points="15 18 9 12 15 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiChevronRight, "Chevron Right"))]
 pub static FiChevronRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiChevronRight",
+    label: "Chevron Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -859,8 +1170,14 @@ pub static FiChevronRight: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polyline points="9 18 15 12 9 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiChevronUp, "Chevron Up"))]
 pub static FiChevronUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiChevronUp",
+    label: "Chevron Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -875,8 +1192,14 @@ pub static FiChevronUp: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polyline points="18 15 12 9 6 15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiChevronsDown, "Chevrons Down"))]
 pub static FiChevronsDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiChevronsDown",
+    label: "Chevrons Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -892,8 +1215,14 @@ pub static FiChevronsDown: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="7 6 12 11 17 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiChevronsLeft, "Chevrons Left"))]
 pub static FiChevronsLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiChevronsLeft",
+    label: "Chevrons Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -909,8 +1238,14 @@ pub static FiChevronsLeft: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="18 17 13 12 18 7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiChevronsRight, "Chevrons Right"))]
 pub static FiChevronsRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiChevronsRight",
+    label: "Chevrons Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -926,8 +1261,14 @@ pub static FiChevronsRight: &icondata_core::IconData = &icondata_core::IconData
 <polyline points="6 17 11 12 6 7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiChevronsUp, "Chevrons Up"))]
 pub static FiChevronsUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiChevronsUp",
+    label: "Chevrons Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -943,8 +1284,14 @@ pub static FiChevronsUp: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="17 18 12 13 7 18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiChrome, "Chrome"))]
 pub static FiChrome: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiChrome",
+    label: "Chrome",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -963,8 +1310,14 @@ pub static FiChrome: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="10.88" y1="21.94" x2="15.46" y2="14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCircle, "Circle"))]
 pub static FiCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCircle",
+    label: "Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -979,8 +1332,14 @@ pub static FiCircle: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<circle cx="12" cy="12" r="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiClipboard, "Clipboard"))]
 pub static FiClipboard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiClipboard",
+    label: "Clipboard",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -996,8 +1355,14 @@ pub static FiClipboard: &icondata_core::IconData = &icondata_core::IconData {
 <rect x="8" y="2" width="8" height="4" rx="1" ry="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiClock, "Clock"))]
 pub static FiClock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiClock",
+    label: "Clock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1013,8 +1378,14 @@ pub static FiClock: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 6 12 12 16 14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCloud, "Cloud"))]
 pub static FiCloud: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCloud",
+    label: "Cloud",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1029,8 +1400,14 @@ pub static FiCloud: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M18 10h-1.26A8 8 0 1 0 9 20h9a5 5 0 0 0 0-10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCloudDrizzle, "Cloud Drizzle"))]
 pub static FiCloudDrizzle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCloudDrizzle",
+    label: "Cloud Drizzle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1051,8 +1428,14 @@ pub static FiCloudDrizzle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 16.58A5 5 0 0 0 18 7h-1.26A8 8 0 1 0 4 15.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCloudLightning, "Cloud Lightning"))]
 pub static FiCloudLightning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCloudLightning",
+    label: "Cloud Lightning",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1068,8 +1451,14 @@ pub static FiCloudLightning: &icondata_core::IconData = &icondata_core::IconData
 <polyline points="13 11 9 17 15 17 11 23" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCloudOff, "Cloud Off"))]
 pub static FiCloudOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCloudOff",
+    label: "Cloud Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1085,8 +1474,14 @@ pub static FiCloudOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="1" y1="1" x2="23" y2="23" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCloudRain, "Cloud Rain"))]
 pub static FiCloudRain: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCloudRain",
+    label: "Cloud Rain",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1104,8 +1499,14 @@ pub static FiCloudRain: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 16.58A5 5 0 0 0 18 7h-1.26A8 8 0 1 0 4 15.25" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCloudSnow, "Cloud Snow"))]
 pub static FiCloudSnow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCloudSnow",
+    label: "Cloud Snow",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1126,8 +1527,14 @@ pub static FiCloudSnow: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="16" y1="20" x2="16.01" y2="20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCode, "Code"))]
 pub static FiCode: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCode",
+    label: "Code",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1143,8 +1550,14 @@ pub static FiCode: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="8 6 2 12 8 18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCodepen, "Codepen"))]
 pub static FiCodepen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCodepen",
+    label: "Codepen",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1163,8 +1576,14 @@ pub static FiCodepen: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="2" x2="12" y2="8.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCodesandbox, "Codesandbox"))]
 pub static FiCodesandbox: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCodesandbox",
+    label: "Codesandbox",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1184,8 +1603,14 @@ pub static FiCodesandbox: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="22.08" x2="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCoffee, "Coffee"))]
 pub static FiCoffee: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCoffee",
+    label: "Coffee",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1204,8 +1629,14 @@ pub static FiCoffee: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="14" y1="1" x2="14" y2="4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiColumns, "Columns"))]
 pub static FiColumns: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiColumns",
+    label: "Columns",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1220,8 +1651,14 @@ pub static FiColumns: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 3h7a2 2 0 0 1 2 2v14a2 2 0 0 1-2 2h-7m0-18H5a2 2 0 0 0-2 2v14a2 2 0 0 0 2 2h7m0-18v18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCommand, "Command"))]
 pub static FiCommand: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCommand",
+    label: "Command",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1236,8 +1673,14 @@ pub static FiCommand: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M18 3a3 3 0 0 0-3 3v12a3 3 0 0 0 3 3 3 3 0 0 0 3-3 3 3 0 0 0-3-3H6a3 3 0 0 0-3 3 3 3 0 0 0 3 3 3 3 0 0 0 3-3V6a3 3 0 0 0-3-3 3 3 0 0 0-3 3 3 3 0 0 0 3 3h12a3 3 0 0 0 3-3 3 3 0 0 0-3-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCompass, "Compass"))]
 pub static FiCompass: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCompass",
+    label: "Compass",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1253,8 +1696,14 @@ pub static FiCompass: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="16.24 7.76 14.12 14.12 7.76 16.24 9.88 9.88 16.24 7.76" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCopy, "Copy"))]
 pub static FiCopy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCopy",
+    label: "Copy",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1270,8 +1719,14 @@ pub static FiCopy: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 15H4a2 2 0 0 1-2-2V4a2 2 0 0 1 2-2h9a2 2 0 0 1 2 2v1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCornerDownLeft, "Corner Down Left"))]
 pub static FiCornerDownLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCornerDownLeft",
+    label: "Corner Down Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1287,8 +1742,14 @@ pub static FiCornerDownLeft: &icondata_core::IconData = &icondata_core::IconData
 <path d="M20 4v7a4 4 0 0 1-4 4H4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCornerDownRight, "Corner Down Right"))]
 pub static FiCornerDownRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCornerDownRight",
+    label: "Corner Down Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1304,8 +1765,14 @@ pub static FiCornerDownRight: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M4 4v7a4 4 0 0 0 4 4h12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCornerLeftDown, "Corner Left Down"))]
 pub static FiCornerLeftDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCornerLeftDown",
+    label: "Corner Left Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1321,8 +1788,14 @@ pub static FiCornerLeftDown: &icondata_core::IconData = &icondata_core::IconData
 <path d="M20 4h-7a4 4 0 0 0-4 4v12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCornerLeftUp, "Corner Left Up"))]
 pub static FiCornerLeftUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCornerLeftUp",
+    label: "Corner Left Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1338,8 +1811,14 @@ pub static FiCornerLeftUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 20h-7a4 4 0 0 1-4-4V4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCornerRightDown, "Corner Right Down"))]
 pub static FiCornerRightDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCornerRightDown",
+    label: "Corner Right Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1355,8 +1834,14 @@ pub static FiCornerRightDown: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M4 4h7a4 4 0 0 1 4 4v12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCornerRightUp, "Corner Right Up"))]
 pub static FiCornerRightUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCornerRightUp",
+    label: "Corner Right Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1372,8 +1857,14 @@ pub static FiCornerRightUp: &icondata_core::IconData = &icondata_core::IconData
 <path d="M4 20h7a4 4 0 0 0 4-4V4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCornerUpLeft, "Corner Up Left"))]
 pub static FiCornerUpLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCornerUpLeft",
+    label: "Corner Up Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1389,8 +1880,14 @@ pub static FiCornerUpLeft: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 20v-7a4 4 0 0 0-4-4H4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCornerUpRight, "Corner Up Right"))]
 pub static FiCornerUpRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCornerUpRight",
+    label: "Corner Up Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1406,8 +1903,14 @@ pub static FiCornerUpRight: &icondata_core::IconData = &icondata_core::IconData
 <path d="M4 20v-7a4 4 0 0 1 4-4h12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCpu, "CPU"))]
 pub static FiCpu: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCpu",
+    label: "CPU",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1431,8 +1934,14 @@ pub static FiCpu: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="1" y1="14" x2="4" y2="14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCreditCard, "Credit Card"))]
 pub static FiCreditCard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCreditCard",
+    label: "Credit Card",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1448,8 +1957,14 @@ pub static FiCreditCard: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="1" y1="10" x2="23" y2="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCrop, "Crop"))]
 pub static FiCrop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCrop",
+    label: "Crop",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1465,8 +1980,14 @@ pub static FiCrop: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M1 6.13L16 6a2 2 0 0 1 2 2v15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiCrosshair, "Crosshair"))]
 pub static FiCrosshair: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiCrosshair",
+    label: "Crosshair",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1485,8 +2006,14 @@ pub static FiCrosshair: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="22" x2="12" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiDatabase, "Database"))]
 pub static FiDatabase: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiDatabase",
+    label: "Database",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1503,8 +2030,14 @@ pub static FiDatabase: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 5v14c0 1.66 4 3 9 3s9-1.34 9-3V5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiDelete, "Delete"))]
 pub static FiDelete: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiDelete",
+    label: "Delete",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1521,8 +2054,14 @@ pub static FiDelete: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="9" x2="18" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiDisc, "Disc"))]
 pub static FiDisc: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiDisc",
+    label: "Disc",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1538,8 +2077,14 @@ pub static FiDisc: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiDivide, "Divide"))]
 pub static FiDivide: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiDivide",
+    label: "Divide",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1556,8 +2101,14 @@ pub static FiDivide: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="18" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiDivideCircle, "Divide Circle"))]
 pub static FiDivideCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiDivideCircle",
+    label: "Divide Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1575,8 +2126,14 @@ pub static FiDivideCircle: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiDivideSquare, "Divide Square"))]
 pub static FiDivideSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiDivideSquare",
+    label: "Divide Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1594,8 +2151,14 @@ pub static FiDivideSquare: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="8" x2="12" y2="8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiDollarSign, "Dollar Sign"))]
 pub static FiDollarSign: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiDollarSign",
+    label: "Dollar Sign",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1611,8 +2174,14 @@ pub static FiDollarSign: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17 5H9.5a3.5 3.5 0 0 0 0 7h5a3.5 3.5 0 0 1 0 7H6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiDownload, "Download"))]
 pub static FiDownload: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiDownload",
+    label: "Download",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1629,8 +2198,14 @@ pub static FiDownload: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="15" x2="12" y2="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiDownloadCloud, "Download Cloud"))]
 pub static FiDownloadCloud: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiDownloadCloud",
+    label: "Download Cloud",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1647,8 +2222,14 @@ pub static FiDownloadCloud: &icondata_core::IconData = &icondata_core::IconData
 <path d="M20.88 18.09A5 5 0 0 0 18 9h-1.26A8 8 0 1 0 3 16.29" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiDribbble, "Dribbble"))]
 pub static FiDribbble: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiDribbble",
+    label: "Dribbble",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1664,8 +2245,14 @@ pub static FiDribbble: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8.56 2.75c4.37 6.03 6.02 9.42 8.03 17.72m2.54-15.38c-3.72 4.35-8.94 5.66-16.88 5.85m19.5 1.9c-3.5-.93-6.63-.82-8.94 0-2.58.92-5.01 2.86-7.44 6.32" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiDroplet, "Droplet"))]
 pub static FiDroplet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiDroplet",
+    label: "Droplet",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1680,8 +2267,14 @@ pub static FiDroplet: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 2.69l5.66 5.66a8 8 0 1 1-11.31 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiEdit, "Edit"))]
 pub static FiEdit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiEdit",
+    label: "Edit",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1697,8 +2290,14 @@ pub static FiEdit: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18.5 2.5a2.121 2.121 0 0 1 3 3L12 15l-4 1 1-4 9.5-9.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiEdit2, "Edit 2"))]
 pub static FiEdit2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiEdit2",
+    label: "Edit 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1713,8 +2312,14 @@ pub static FiEdit2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M17 3a2.828 2.828 0 1 1 4 4L7.5 20.5 2 22l1.5-5.5L17 3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiEdit3, "Edit 3"))]
 pub static FiEdit3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiEdit3",
+    label: "Edit 3",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1730,8 +2335,14 @@ pub static FiEdit3: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16.5 3.5a2.121 2.121 0 0 1 3 3L7 19l-4 1 1-4L16.5 3.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiExternalLink, "External Link"))]
 pub static FiExternalLink: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiExternalLink",
+    label: "External Link",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1748,8 +2359,14 @@ pub static FiExternalLink: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="10" y1="14" x2="21" y2="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiEye, "Eye"))]
 pub static FiEye: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiEye",
+    label: "Eye",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1765,8 +2382,14 @@ pub static FiEye: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiEyeOff, "Eye Off"))]
 pub static FiEyeOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiEyeOff",
+    label: "Eye Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1782,8 +2405,14 @@ pub static FiEyeOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="1" y1="1" x2="23" y2="23" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFacebook, "Facebook"))]
 pub static FiFacebook: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFacebook",
+    label: "Facebook",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1798,8 +2427,14 @@ pub static FiFacebook: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M18 2h-3a5 5 0 0 0-5 5v3H7v4h3v8h4v-8h3l1-4h-4V7a1 1 0 0 1 1-1h3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFastForward, "Fast Forward"))]
 pub static FiFastForward: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFastForward",
+    label: "Fast Forward",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1815,8 +2450,14 @@ pub static FiFastForward: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="2 19 11 12 2 5 2 19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFeather, "Feather"))]
 pub static FiFeather: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFeather",
+    label: "Feather",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1833,8 +2474,14 @@ pub static FiFeather: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="17.5" y1="15" x2="9" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFigma, "Figma"))]
 pub static FiFigma: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFigma",
+    label: "Figma",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1853,8 +2500,14 @@ pub static FiFigma: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 12.5A3.5 3.5 0 0 1 8.5 9H12v7H8.5A3.5 3.5 0 0 1 5 12.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFile, "File"))]
 pub static FiFile: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFile",
+    label: "File",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1870,8 +2523,14 @@ pub static FiFile: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="13 2 13 9 20 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFileMinus, "File Minus"))]
 pub static FiFileMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFileMinus",
+    label: "File Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1888,8 +2547,14 @@ pub static FiFileMinus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" y1="15" x2="15" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFilePlus, "File Plus"))]
 pub static FiFilePlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFilePlus",
+    label: "File Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1907,8 +2572,14 @@ pub static FiFilePlus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" y1="15" x2="15" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFileText, "File Text"))]
 pub static FiFileText: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFileText",
+    label: "File Text",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1927,8 +2598,14 @@ pub static FiFileText: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="10 9 9 9 8 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFilm, "Film"))]
 pub static FiFilm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFilm",
+    label: "Film",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1950,8 +2627,14 @@ pub static FiFilm: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="17" y1="7" x2="22" y2="7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFilter, "Filter"))]
 pub static FiFilter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFilter",
+    label: "Filter",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1966,8 +2649,14 @@ pub static FiFilter: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="22 3 2 3 10 12.46 10 19 14 21 14 12.46 22 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFlag, "Flag"))]
 pub static FiFlag: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFlag",
+    label: "Flag",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1983,8 +2672,14 @@ pub static FiFlag: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="4" y1="22" x2="4" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFolder, "Folder"))]
 pub static FiFolder: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFolder",
+    label: "Folder",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1999,8 +2694,14 @@ pub static FiFolder: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M22 19a2 2 0 0 1-2 2H4a2 2 0 0 1-2-2V5a2 2 0 0 1 2-2h5l2 3h9a2 2 0 0 1 2 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFolderMinus, "Folder Minus"))]
 pub static FiFolderMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFolderMinus",
+    label: "Folder Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2016,8 +2717,14 @@ pub static FiFolderMinus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" y1="14" x2="15" y2="14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFolderPlus, "Folder Plus"))]
 pub static FiFolderPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFolderPlus",
+    label: "Folder Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2034,8 +2741,14 @@ pub static FiFolderPlus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" y1="14" x2="15" y2="14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFramer, "Framer"))]
 pub static FiFramer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFramer",
+    label: "Framer",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2050,8 +2763,14 @@ pub static FiFramer: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M5 16V9h14V2H5l14 14h-7m-7 0l7 7v-7m-7 0h7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiFrown, "Frown"))]
 pub static FiFrown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiFrown",
+    label: "Frown",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2069,8 +2788,14 @@ pub static FiFrown: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" y1="9" x2="15.01" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiGift, "Gift"))]
 pub static FiGift: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiGift",
+    label: "Gift",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2089,8 +2814,14 @@ pub static FiGift: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 7h4.5a2.5 2.5 0 0 0 0-5C13 2 12 7 12 7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiGitBranch, "Git Branch"))]
 pub static FiGitBranch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiGitBranch",
+    label: "Git Branch",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2108,8 +2839,14 @@ pub static FiGitBranch: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 9a9 9 0 0 1-9 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiGitCommit, "Git Commit"))]
 pub static FiGitCommit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiGitCommit",
+    label: "Git Commit",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2126,8 +2863,14 @@ pub static FiGitCommit: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="17.01" y1="12" x2="22.96" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiGitMerge, "Git Merge"))]
 pub static FiGitMerge: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiGitMerge",
+    label: "Git Merge",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2144,8 +2887,14 @@ pub static FiGitMerge: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6 21V9a9 9 0 0 0 9 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiGitPullRequest, "Git Pull Request"))]
 pub static FiGitPullRequest: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiGitPullRequest",
+    label: "Git Pull Request",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2163,8 +2912,14 @@ pub static FiGitPullRequest: &icondata_core::IconData = &icondata_core::IconData
 <line x1="6" y1="9" x2="6" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiGithub, "Github"))]
 pub static FiGithub: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiGithub",
+    label: "Github",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2179,8 +2934,14 @@ pub static FiGithub: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9 19c-5 1.5-5-2.5-7-3m14 6v-3.87a3.37 3.37 0 0 0-.94-2.61c3.14-.35 6.44-1.54 6.44-7A5.44 5.44 0 0 0 20 4.77 5.07 5.07 0 0 0 19.91 1S18.73.65 16 2.48a13.38 13.38 0 0 0-7 0C6.27.65 5.09 1 5.09 1A5.07 5.07 0 0 0 5 4.77a5.44 5.44 0 0 0-1.5 3.78c0 5.42 3.3 6.61 6.44 7A3.37 3.37 0 0 0 9 18.13V22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiGitlab, "Gitlab"))]
 pub static FiGitlab: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiGitlab",
+    label: "Gitlab",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2195,8 +2956,14 @@ pub static FiGitlab: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M22.65 14.39L12 22.13 1.35 14.39a.84.84 0 0 1-.3-.94l1.22-3.78 2.44-7.51A.42.42 0 0 1 4.82 2a.43.43 0 0 1 .58 0 .42.42 0 0 1 .11.18l2.44 7.49h8.1l2.44-7.51A.42.42 0 0 1 18.6 2a.43.43 0 0 1 .58 0 .42.42 0 0 1 .11.18l2.44 7.51L23 13.45a.84.84 0 0 1-.35.94z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiGlobe, "Globe"))]
 pub static FiGlobe: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiGlobe",
+    label: "Globe",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2213,8 +2980,14 @@ pub static FiGlobe: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 2a15.3 15.3 0 0 1 4 10 15.3 15.3 0 0 1-4 10 15.3 15.3 0 0 1-4-10 15.3 15.3 0 0 1 4-10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiGrid, "Grid"))]
 pub static FiGrid: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiGrid",
+    label: "Grid",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2232,8 +3005,14 @@ pub static FiGrid: &icondata_core::IconData = &icondata_core::IconData {
 <rect x="3" y="14" width="7" height="7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiHardDrive, "Hard Drive"))]
 pub static FiHardDrive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiHardDrive",
+    label: "Hard Drive",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2251,8 +3030,14 @@ pub static FiHardDrive: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="10" y1="16" x2="10.01" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiHash, "Hash"))]
 pub static FiHash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiHash",
+    label: "Hash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2270,8 +3055,14 @@ pub static FiHash: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="16" y1="3" x2="14" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiHeadphones, "Headphones"))]
 pub static FiHeadphones: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiHeadphones",
+    label: "Headphones",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2287,8 +3078,14 @@ pub static FiHeadphones: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 19a2 2 0 0 1-2 2h-1a2 2 0 0 1-2-2v-3a2 2 0 0 1 2-2h3zM3 19a2 2 0 0 0 2 2h1a2 2 0 0 0 2-2v-3a2 2 0 0 0-2-2H3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiHeart, "Heart"))]
 pub static FiHeart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiHeart",
+    label: "Heart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2303,8 +3100,14 @@ pub static FiHeart: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M20.84 4.61a5.5 5.5 0 0 0-7.78 0L12 5.67l-1.06-1.06a5.5 5.5 0 0 0-7.78 7.78l1.06 1.06L12 21.23l7.78-7.78 1.06-1.06a5.5 5.5 0 0 0 0-7.78z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiHelpCircle, "Help Circle"))]
 pub static FiHelpCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiHelpCircle",
+    label: "Help Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2321,8 +3124,14 @@ pub static FiHelpCircle: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="17" x2="12.01" y2="17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiHexagon, "Hexagon"))]
 pub static FiHexagon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiHexagon",
+    label: "Hexagon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2337,8 +3146,14 @@ pub static FiHexagon: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M21 16V8a2 2 0 0 0-1-1.73l-7-4a2 2 0 0 0-2 0l-7 4A2 2 0 0 0 3 8v8a2 2 0 0 0 1 1.73l7 4a2 2 0 0 0 2 0l7-4A2 2 0 0 0 21 16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiHome, "Home"))]
 pub static FiHome: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiHome",
+    label: "Home",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2354,8 +3169,14 @@ pub static FiHome: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="9 22 9 12 15 12 15 22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiImage, "Image"))]
 pub static FiImage: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiImage",
+    label: "Image",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2372,8 +3193,14 @@ pub static FiImage: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="21 15 16 10 5 21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiInbox, "Inbox"))]
 pub static FiInbox: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiInbox",
+    label: "Inbox",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2389,8 +3216,14 @@ pub static FiInbox: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5.45 5.11L2 12v6a2 2 0 0 0 2 2h16a2 2 0 0 0 2-2v-6l-3.45-6.89A2 2 0 0 0 16.76 4H7.24a2 2 0 0 0-1.79 1.11z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiInfo, "Info"))]
 pub static FiInfo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiInfo",
+    label: "Info",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2407,8 +3240,14 @@ pub static FiInfo: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="8" x2="12.01" y2="8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiInstagram, "Instagram"))]
 pub static FiInstagram: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiInstagram",
+    label: "Instagram",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2425,8 +3264,14 @@ pub static FiInstagram: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="17.5" y1="6.5" x2="17.51" y2="6.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiItalic, "Italic"))]
 pub static FiItalic: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiItalic",
+    label: "Italic",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2443,8 +3288,14 @@ pub static FiItalic: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" y1="4" x2="9" y2="20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiKey, "Key"))]
 pub static FiKey: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiKey",
+    label: "Key",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2459,8 +3310,14 @@ pub static FiKey: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M21 2l-2 2m-7.61 7.61a5.5 5.5 0 1 1-7.778 7.778 5.5 5.5 0 0 1 7.777-7.777zm0 0L15.5 7.5m0 0l3 3L22 7l-3-3m-3.5 3.5L19 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiLayers, "Layers"))]
 pub static FiLayers: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiLayers",
+    label: "Layers",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2477,8 +3334,14 @@ pub static FiLayers: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="2 12 12 17 22 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiLayout, "Layout"))]
 pub static FiLayout: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiLayout",
+    label: "Layout",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2495,8 +3358,14 @@ pub static FiLayout: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" y1="21" x2="9" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiLifeBuoy, "Life Buoy"))]
 pub static FiLifeBuoy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiLifeBuoy",
+    label: "Life Buoy",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2517,8 +3386,14 @@ pub static FiLifeBuoy: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="4.93" y1="19.07" x2="9.17" y2="14.83" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiLink, "Link"))]
 pub static FiLink: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiLink",
+    label: "Link",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2534,8 +3409,14 @@ pub static FiLink: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 11a5 5 0 0 0-7.54-.54l-3 3a5 5 0 0 0 7.07 7.07l1.71-1.71" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiLink2, "Link 2"))]
 pub static FiLink2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiLink2",
+    label: "Link 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2551,8 +3432,14 @@ pub static FiLink2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8" y1="12" x2="16" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiLinkedin, "Linkedin"))]
 pub static FiLinkedin: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiLinkedin",
+    label: "Linkedin",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2569,8 +3456,14 @@ pub static FiLinkedin: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="4" cy="4" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiList, "List"))]
 pub static FiList: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiList",
+    label: "List",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2590,8 +3483,14 @@ pub static FiList: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" y1="18" x2="3.01" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiLoader, "Loader"))]
 pub static FiLoader: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiLoader",
+    label: "Loader",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2613,8 +3512,14 @@ pub static FiLoader: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="16.24" y1="7.76" x2="19.07" y2="4.93" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiLock, "Lock"))]
 pub static FiLock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiLock",
+    label: "Lock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2630,8 +3535,14 @@ pub static FiLock: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 11V7a5 5 0 0 1 10 0v4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiLogIn, "Log In"))]
 pub static FiLogIn: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiLogIn",
+    label: "Log In",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2648,8 +3559,14 @@ pub static FiLogIn: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" y1="12" x2="3" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiLogOut, "Log Out"))]
 pub static FiLogOut: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiLogOut",
+    label: "Log Out",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2666,8 +3583,14 @@ pub static FiLogOut: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="21" y1="12" x2="9" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMail, "Mail"))]
 pub static FiMail: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMail",
+    label: "Mail",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2683,8 +3606,14 @@ pub static FiMail: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="22,6 12,13 2,6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMap, "Map"))]
 pub static FiMap: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMap",
+    label: "Map",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2701,8 +3630,14 @@ pub static FiMap: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="16" y1="6" x2="16" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMapPin, "Map Pin"))]
 pub static FiMapPin: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMapPin",
+    label: "Map Pin",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2718,8 +3653,14 @@ pub static FiMapPin: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="10" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMaximize, "Maximize"))]
 pub static FiMaximize: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMaximize",
+    label: "Maximize",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2734,8 +3675,14 @@ pub static FiMaximize: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 3H5a2 2 0 0 0-2 2v3m18 0V5a2 2 0 0 0-2-2h-3m0 18h3a2 2 0 0 0 2-2v-3M3 16v3a2 2 0 0 0 2 2h3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMaximize2, "Maximize 2"))]
 pub static FiMaximize2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMaximize2",
+    label: "Maximize 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2753,8 +3700,14 @@ pub static FiMaximize2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" y1="21" x2="10" y2="14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMeh, "Meh"))]
 pub static FiMeh: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMeh",
+    label: "Meh",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2772,8 +3725,14 @@ pub static FiMeh: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" y1="9" x2="15.01" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMenu, "Menu"))]
 pub static FiMenu: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMenu",
+    label: "Menu",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2790,8 +3749,14 @@ pub static FiMenu: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" y1="18" x2="21" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMessageCircle, "Message Circle"))]
 pub static FiMessageCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMessageCircle",
+    label: "Message Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2806,8 +3771,14 @@ pub static FiMessageCircle: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M21 11.5a8.38 8.38 0 0 1-.9 3.8 8.5 8.5 0 0 1-7.6 4.7 8.38 8.38 0 0 1-3.8-.9L3 21l1.9-5.7a8.38 8.38 0 0 1-.9-3.8 8.5 8.5 0 0 1 4.7-7.6 8.38 8.38 0 0 1 3.8-.9h.5a8.48 8.48 0 0 1 8 8v.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMessageSquare, "Message Square"))]
 pub static FiMessageSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMessageSquare",
+    label: "Message Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2822,8 +3793,14 @@ pub static FiMessageSquare: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M21 15a2 2 0 0 1-2 2H7l-4 4V5a2 2 0 0 1 2-2h14a2 2 0 0 1 2 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMic, "Mic"))]
 pub static FiMic: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMic",
+    label: "Mic",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2841,8 +3818,14 @@ pub static FiMic: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8" y1="23" x2="16" y2="23" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMicOff, "Mic Off"))]
 pub static FiMicOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMicOff",
+    label: "Mic Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2861,8 +3844,14 @@ pub static FiMicOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8" y1="23" x2="16" y2="23" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMinimize, "Minimize"))]
 pub static FiMinimize: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMinimize",
+    label: "Minimize",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2877,8 +3866,14 @@ pub static FiMinimize: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 3v3a2 2 0 0 1-2 2H3m18 0h-3a2 2 0 0 1-2-2V3m0 18v-3a2 2 0 0 1 2-2h3M3 16h3a2 2 0 0 1 2 2v3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMinimize2, "Minimize 2"))]
 pub static FiMinimize2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMinimize2",
+    label: "Minimize 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2896,8 +3891,14 @@ pub static FiMinimize2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" y1="21" x2="10" y2="14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMinus, "Minus"))]
 pub static FiMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMinus",
+    label: "Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2912,8 +3913,14 @@ pub static FiMinus: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<line x1="5" y1="12" x2="19" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMinusCircle, "Minus Circle"))]
 pub static FiMinusCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMinusCircle",
+    label: "Minus Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2929,8 +3936,14 @@ pub static FiMinusCircle: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8" y1="12" x2="16" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMinusSquare, "Minus Square"))]
 pub static FiMinusSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMinusSquare",
+    label: "Minus Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2946,8 +3959,14 @@ pub static FiMinusSquare: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8" y1="12" x2="16" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMonitor, "Monitor"))]
 pub static FiMonitor: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMonitor",
+    label: "Monitor",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2964,8 +3983,14 @@ pub static FiMonitor: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="17" x2="12" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMoon, "Moon"))]
 pub static FiMoon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMoon",
+    label: "Moon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2980,8 +4005,14 @@ pub static FiMoon: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M21 12.79A9 9 0 1 1 11.21 3 7 7 0 0 0 21 12.79z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMoreHorizontal, "More Horizontal"))]
 pub static FiMoreHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMoreHorizontal",
+    label: "More Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2998,8 +4029,14 @@ pub static FiMoreHorizontal: &icondata_core::IconData = &icondata_core::IconData
 <circle cx="5" cy="12" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMoreVertical, "More Vertical"))]
 pub static FiMoreVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMoreVertical",
+    label: "More Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3016,8 +4053,14 @@ pub static FiMoreVertical: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="19" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMousePointer, "Mouse Pointer"))]
 pub static FiMousePointer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMousePointer",
+    label: "Mouse Pointer",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3033,8 +4076,14 @@ pub static FiMousePointer: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13 13l6 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMove, "Move"))]
 pub static FiMove: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMove",
+    label: "Move",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3054,8 +4103,14 @@ pub static FiMove: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="2" x2="12" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiMusic, "Music"))]
 pub static FiMusic: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiMusic",
+    label: "Music",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3072,8 +4127,14 @@ pub static FiMusic: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="18" cy="16" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiNavigation, "Navigation"))]
 pub static FiNavigation: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiNavigation",
+    label: "Navigation",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3088,8 +4149,14 @@ pub static FiNavigation: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="3 11 22 2 13 21 11 13 3 11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiNavigation2, "Navigation 2"))]
 pub static FiNavigation2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiNavigation2",
+    label: "Navigation 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3104,8 +4171,14 @@ pub static FiNavigation2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="12 2 19 21 12 17 5 21 12 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiOctagon, "Octagon"))]
 pub static FiOctagon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiOctagon",
+    label: "Octagon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3120,8 +4193,14 @@ pub static FiOctagon: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="7.86 2 16.14 2 22 7.86 22 16.14 16.14 22 7.86 22 2 16.14 2 7.86 7.86 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPackage, "Package"))]
 pub static FiPackage: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPackage",
+    label: "Package",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3139,8 +4218,14 @@ pub static FiPackage: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="22.08" x2="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPaperclip, "Paperclip"))]
 pub static FiPaperclip: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPaperclip",
+    label: "Paperclip",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3155,8 +4240,14 @@ pub static FiPaperclip: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M21.44 11.05l-9.19 9.19a6 6 0 0 1-8.49-8.49l9.19-9.19a4 4 0 0 1 5.66 5.66l-9.2 9.19a2 2 0 0 1-2.83-2.83l8.49-8.48" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPause, "Pause"))]
 pub static FiPause: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPause",
+    label: "Pause",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3172,8 +4263,14 @@ pub static FiPause: &icondata_core::IconData = &icondata_core::IconData {
 <rect x="14" y="4" width="4" height="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPauseCircle, "Pause Circle"))]
 pub static FiPauseCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPauseCircle",
+    label: "Pause Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3190,8 +4287,14 @@ pub static FiPauseCircle: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="14" y1="15" x2="14" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPenTool, "Pen Tool"))]
 pub static FiPenTool: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPenTool",
+    label: "Pen Tool",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3209,8 +4312,14 @@ pub static FiPenTool: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="11" cy="11" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPercent, "Percent"))]
 pub static FiPercent: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPercent",
+    label: "Percent",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3227,8 +4336,14 @@ pub static FiPercent: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="17.5" cy="17.5" r="2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPhone, "Phone"))]
 pub static FiPhone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPhone",
+    label: "Phone",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3243,8 +4358,14 @@ pub static FiPhone: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M22 16.92v3a2 2 0 0 1-2.18 2 19.79 19.79 0 0 1-8.63-3.07 19.5 19.5 0 0 1-6-6 19.79 19.79 0 0 1-3.07-8.67A2 2 0 0 1 4.11 2h3a2 2 0 0 1 2 1.72 12.84 12.84 0 0 0 .7 2.81 2 2 0 0 1-.45 2.11L8.09 9.91a16 16 0 0 0 6 6l1.27-1.27a2 2 0 0 1 2.11-.45 12.84 12.84 0 0 0 2.81.7A2 2 0 0 1 22 16.92z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPhoneCall, "Phone Call"))]
 pub static FiPhoneCall: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPhoneCall",
+    label: "Phone Call",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3259,8 +4380,14 @@ pub static FiPhoneCall: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.05 5A5 5 0 0 1 19 8.95M15.05 1A9 9 0 0 1 23 8.94m-1 7.98v3a2 2 0 0 1-2.18 2 19.79 19.79 0 0 1-8.63-3.07 19.5 19.5 0 0 1-6-6 19.79 19.79 0 0 1-3.07-8.67A2 2 0 0 1 4.11 2h3a2 2 0 0 1 2 1.72 12.84 12.84 0 0 0 .7 2.81 2 2 0 0 1-.45 2.11L8.09 9.91a16 16 0 0 0 6 6l1.27-1.27a2 2 0 0 1 2.11-.45 12.84 12.84 0 0 0 2.81.7A2 2 0 0 1 22 16.92z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPhoneForwarded, "Phone Forwarded"))]
 pub static FiPhoneForwarded: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPhoneForwarded",
+    label: "Phone Forwarded",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3277,8 +4404,14 @@ pub static FiPhoneForwarded: &icondata_core::IconData = &icondata_core::IconData
 <path d="M22 16.92v3a2 2 0 0 1-2.18 2 19.79 19.79 0 0 1-8.63-3.07 19.5 19.5 0 0 1-6-6 19.79 19.79 0 0 1-3.07-8.67A2 2 0 0 1 4.11 2h3a2 2 0 0 1 2 1.72 12.84 12.84 0 0 0 .7 2.81 2 2 0 0 1-.45 2.11L8.09 9.91a16 16 0 0 0 6 6l1.27-1.27a2 2 0 0 1 2.11-.45 12.84 12.84 0 0 0 2.81.7A2 2 0 0 1 22 16.92z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPhoneIncoming, "Phone Incoming"))]
 pub static FiPhoneIncoming: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPhoneIncoming",
+    label: "Phone Incoming",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3295,8 +4428,14 @@ pub static FiPhoneIncoming: &icondata_core::IconData = &icondata_core::IconData
 <path d="M22 16.92v3a2 2 0 0 1-2.18 2 19.79 19.79 0 0 1-8.63-3.07 19.5 19.5 0 0 1-6-6 19.79 19.79 0 0 1-3.07-8.67A2 2 0 0 1 4.11 2h3a2 2 0 0 1 2 1.72 12.84 12.84 0 0 0 .7 2.81 2 2 0 0 1-.45 2.11L8.09 9.91a16 16 0 0 0 6 6l1.27-1.27a2 2 0 0 1 2.11-.45 12.84 12.84 0 0 0 2.81.7A2 2 0 0 1 22 16.92z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPhoneMissed, "Phone Missed"))]
 pub static FiPhoneMissed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPhoneMissed",
+    label: "Phone Missed",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3313,8 +4452,14 @@ pub static FiPhoneMissed: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 16.92v3a2 2 0 0 1-2.18 2 19.79 19.79 0 0 1-8.63-3.07 19.5 19.5 0 0 1-6-6 19.79 19.79 0 0 1-3.07-8.67A2 2 0 0 1 4.11 2h3a2 2 0 0 1 2 1.72 12.84 12.84 0 0 0 .7 2.81 2 2 0 0 1-.45 2.11L8.09 9.91a16 16 0 0 0 6 6l1.27-1.27a2 2 0 0 1 2.11-.45 12.84 12.84 0 0 0 2.81.7A2 2 0 0 1 22 16.92z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPhoneOff, "Phone Off"))]
 pub static FiPhoneOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPhoneOff",
+    label: "Phone Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3330,8 +4475,14 @@ pub static FiPhoneOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="23" y1="1" x2="1" y2="23" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPhoneOutgoing, "Phone Outgoing"))]
 pub static FiPhoneOutgoing: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPhoneOutgoing",
+    label: "Phone Outgoing",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3348,8 +4499,14 @@ pub static FiPhoneOutgoing: &icondata_core::IconData = &icondata_core::IconData
 <path d="M22 16.92v3a2 2 0 0 1-2.18 2 19.79 19.79 0 0 1-8.63-3.07 19.5 19.5 0 0 1-6-6 19.79 19.79 0 0 1-3.07-8.67A2 2 0 0 1 4.11 2h3a2 2 0 0 1 2 1.72 12.84 12.84 0 0 0 .7 2.81 2 2 0 0 1-.45 2.11L8.09 9.91a16 16 0 0 0 6 6l1.27-1.27a2 2 0 0 1 2.11-.45 12.84 12.84 0 0 0 2.81.7A2 2 0 0 1 22 16.92z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPieChart, "Pie Chart"))]
 pub static FiPieChart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPieChart",
+    label: "Pie Chart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3365,8 +4522,14 @@ pub static FiPieChart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 12A10 10 0 0 0 12 2v10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPlay, "Play"))]
 pub static FiPlay: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPlay",
+    label: "Play",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3381,8 +4544,14 @@ pub static FiPlay: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="5 3 19 12 5 21 5 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPlayCircle, "Play Circle"))]
 pub static FiPlayCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPlayCircle",
+    label: "Play Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3398,8 +4567,14 @@ pub static FiPlayCircle: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="10 8 16 12 10 16 10 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPlus, "Plus"))]
 pub static FiPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPlus",
+    label: "Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3415,8 +4590,14 @@ pub static FiPlus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="5" y1="12" x2="19" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPlusCircle, "Plus Circle"))]
 pub static FiPlusCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPlusCircle",
+    label: "Plus Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3433,8 +4614,14 @@ pub static FiPlusCircle: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8" y1="12" x2="16" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPlusSquare, "Plus Square"))]
 pub static FiPlusSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPlusSquare",
+    label: "Plus Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3451,8 +4638,14 @@ pub static FiPlusSquare: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8" y1="12" x2="16" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPocket, "Pocket"))]
 pub static FiPocket: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPocket",
+    label: "Pocket",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3468,8 +4661,14 @@ pub static FiPocket: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="8 10 12 14 16 10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPower, "Power"))]
 pub static FiPower: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPower",
+    label: "Power",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3485,8 +4684,14 @@ pub static FiPower: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="2" x2="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiPrinter, "Printer"))]
 pub static FiPrinter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiPrinter",
+    label: "Printer",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3503,8 +4708,14 @@ pub static FiPrinter: &icondata_core::IconData = &icondata_core::IconData {
 <rect x="6" y="14" width="12" height="8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiRadio, "Radio"))]
 pub static FiRadio: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiRadio",
+    label: "Radio",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3520,8 +4731,14 @@ pub static FiRadio: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16.24 7.76a6 6 0 0 1 0 8.49m-8.48-.01a6 6 0 0 1 0-8.49m11.31-2.82a10 10 0 0 1 0 14.14m-14.14 0a10 10 0 0 1 0-14.14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiRefreshCcw, "Refresh Ccw"))]
 pub static FiRefreshCcw: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiRefreshCcw",
+    label: "Refresh Ccw",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3538,8 +4755,14 @@ pub static FiRefreshCcw: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20.49 9A9 9 0 0 0 5.64 5.64L1 10m22 4l-4.64 4.36A9 9 0 0 1 3.51 15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiRefreshCw, "Refresh Cw"))]
 pub static FiRefreshCw: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiRefreshCw",
+    label: "Refresh Cw",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3556,8 +4779,14 @@ pub static FiRefreshCw: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3.51 9a9 9 0 0 1 14.85-3.36L23 10M1 14l4.64 4.36A9 9 0 0 0 20.49 15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiRepeat, "Repeat"))]
 pub static FiRepeat: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiRepeat",
+    label: "Repeat",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3575,8 +4804,14 @@ pub static FiRepeat: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 13v2a4 4 0 0 1-4 4H3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiRewind, "Rewind"))]
 pub static FiRewind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiRewind",
+    label: "Rewind",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3592,8 +4827,14 @@ pub static FiRewind: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="22 19 13 12 22 5 22 19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiRotateCcw, "Rotate Ccw"))]
 pub static FiRotateCcw: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiRotateCcw",
+    label: "Rotate Ccw",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3609,8 +4850,14 @@ pub static FiRotateCcw: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3.51 15a9 9 0 1 0 2.13-9.36L1 10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiRotateCw, "Rotate Cw"))]
 pub static FiRotateCw: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiRotateCw",
+    label: "Rotate Cw",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3626,8 +4873,14 @@ pub static FiRotateCw: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20.49 15a9 9 0 1 1-2.12-9.36L23 10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiRss, "RSS"))]
 pub static FiRss: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiRss",
+    label: "RSS",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3644,8 +4897,14 @@ pub static FiRss: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="5" cy="19" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSave, "Save"))]
 pub static FiSave: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSave",
+    label: "Save",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3662,8 +4921,14 @@ pub static FiSave: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="7 3 7 8 15 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiScissors, "Scissors"))]
 pub static FiScissors: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiScissors",
+    label: "Scissors",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3682,8 +4947,14 @@ pub static FiScissors: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8.12" y1="8.12" x2="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSearch, "Search"))]
 pub static FiSearch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSearch",
+    label: "Search",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3699,8 +4970,14 @@ pub static FiSearch: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="21" y1="21" x2="16.65" y2="16.65" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSend, "Send"))]
 pub static FiSend: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSend",
+    label: "Send",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3716,8 +4993,14 @@ pub static FiSend: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="22 2 15 22 11 13 2 9 22 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiServer, "Server"))]
 pub static FiServer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiServer",
+    label: "Server",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3735,8 +5018,14 @@ pub static FiServer: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="6" y1="18" x2="6.01" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSettings, "Settings"))]
 pub static FiSettings: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSettings",
+    label: "Settings",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3752,8 +5041,14 @@ pub static FiSettings: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19.4 15a1.65 1.65 0 0 0 .33 1.82l.06.06a2 2 0 0 1 0 2.83 2 2 0 0 1-2.83 0l-.06-.06a1.65 1.65 0 0 0-1.82-.33 1.65 1.65 0 0 0-1 1.51V21a2 2 0 0 1-2 2 2 2 0 0 1-2-2v-.09A1.65 1.65 0 0 0 9 19.4a1.65 1.65 0 0 0-1.82.33l-.06.06a2 2 0 0 1-2.83 0 2 2 0 0 1 0-2.83l.06-.06a1.65 1.65 0 0 0 .33-1.82 1.65 1.65 0 0 0-1.51-1H3a2 2 0 0 1-2-2 2 2 0 0 1 2-2h.09A1.65 1.65 0 0 0 4.6 9a1.65 1.65 0 0 0-.33-1.82l-.06-.06a2 2 0 0 1 0-2.83 2 2 0 0 1 2.83 0l.06.06a1.65 1.65 0 0 0 1.82.33H9a1.65 1.65 0 0 0 1-1.51V3a2 2 0 0 1 2-2 2 2 0 0 1 2 2v.09a1.65 1.65 0 0 0 1 1.51 1.65 1.65 0 0 0 1.82-.33l.06-.06a2 2 0 0 1 2.83 0 2 2 0 0 1 0 2.83l-.06.06a1.65 1.65 0 0 0-.33 1.82V9a1.65 1.65 0 0 0 1.51 1H21a2 2 0 0 1 2 2 2 2 0 0 1-2 2h-.09a1.65 1.65 0 0 0-1.51 1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiShare, "Share"))]
 pub static FiShare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiShare",
+    label: "Share",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3770,8 +5065,14 @@ pub static FiShare: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="2" x2="12" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiShare2, "Share 2"))]
 pub static FiShare2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiShare2",
+    label: "Share 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3790,8 +5091,14 @@ pub static FiShare2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15.41" y1="6.51" x2="8.59" y2="10.49" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiShield, "Shield"))]
 pub static FiShield: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiShield",
+    label: "Shield",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3806,8 +5113,14 @@ pub static FiShield: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 22s8-4 8-10V5l-8-3-8 3v7c0 6 8 10 8 10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiShieldOff, "Shield Off"))]
 pub static FiShieldOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiShieldOff",
+    label: "Shield Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3824,8 +5137,14 @@ pub static FiShieldOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="1" y1="1" x2="23" y2="23" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiShoppingBag, "Shopping Bag"))]
 pub static FiShoppingBag: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiShoppingBag",
+    label: "Shopping Bag",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3842,8 +5161,14 @@ pub static FiShoppingBag: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 10a4 4 0 0 1-8 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiShoppingCart, "Shopping Cart"))]
 pub static FiShoppingCart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiShoppingCart",
+    label: "Shopping Cart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3860,8 +5185,14 @@ pub static FiShoppingCart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M1 1h4l2.68 13.39a2 2 0 0 0 2 1.61h9.72a2 2 0 0 0 2-1.61L23 6H6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiShuffle, "Shuffle"))]
 pub static FiShuffle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiShuffle",
+    label: "Shuffle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3880,8 +5211,14 @@ pub static FiShuffle: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="4" y1="4" x2="9" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSidebar, "Sidebar"))]
 pub static FiSidebar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSidebar",
+    label: "Sidebar",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3897,8 +5234,14 @@ pub static FiSidebar: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" y1="3" x2="9" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSkipBack, "Skip Back"))]
 pub static FiSkipBack: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSkipBack",
+    label: "Skip Back",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3914,8 +5257,14 @@ pub static FiSkipBack: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="5" y1="19" x2="5" y2="5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSkipForward, "Skip Forward"))]
 pub static FiSkipForward: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSkipForward",
+    label: "Skip Forward",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3931,8 +5280,14 @@ pub static FiSkipForward: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="19" y1="5" x2="19" y2="19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSlack, "Slack"))]
 pub static FiSlack: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSlack",
+    label: "Slack",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3954,8 +5309,14 @@ pub static FiSlack: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8.5 5H10V3.5C10 2.67 9.33 2 8.5 2S7 2.67 7 3.5 7.67 5 8.5 5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSlash, "Slash"))]
 pub static FiSlash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSlash",
+    label: "Slash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3971,8 +5332,14 @@ pub static FiSlash: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="4.93" y1="4.93" x2="19.07" y2="19.07" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSliders, "Sliders"))]
 pub static FiSliders: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSliders",
+    label: "Sliders",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3995,8 +5362,14 @@ pub static FiSliders: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="17" y1="16" x2="23" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSmartphone, "Smartphone"))]
 pub static FiSmartphone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSmartphone",
+    label: "Smartphone",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4012,8 +5385,14 @@ pub static FiSmartphone: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="18" x2="12.01" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSmile, "Smile"))]
 pub static FiSmile: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSmile",
+    label: "Smile",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4031,8 +5410,14 @@ pub static FiSmile: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" y1="9" x2="15.01" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSpeaker, "Speaker"))]
 pub static FiSpeaker: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSpeaker",
+    label: "Speaker",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4049,8 +5434,14 @@ pub static FiSpeaker: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="6" x2="12.01" y2="6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSquare, "Square"))]
 pub static FiSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSquare",
+    label: "Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4065,8 +5456,14 @@ pub static FiSquare: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<rect x="3" y="3" width="18" height="18" rx="2" ry="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiStar, "Star"))]
 pub static FiStar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiStar",
+    label: "Star",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4081,8 +5478,14 @@ pub static FiStar: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="12 2 15.09 8.26 22 9.27 17 14.14 18.18 21.02 12 17.77 5.82 21.02 7 14.14 2 9.27 8.91 8.26 12 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiStopCircle, "Stop Circle"))]
 pub static FiStopCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiStopCircle",
+    label: "Stop Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4098,8 +5501,14 @@ pub static FiStopCircle: &icondata_core::IconData = &icondata_core::IconData {
 <rect x="9" y="9" width="6" height="6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSun, "Sun"))]
 pub static FiSun: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSun",
+    label: "Sun",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4122,8 +5531,14 @@ pub static FiSun: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="18.36" y1="5.64" x2="19.78" y2="4.22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSunrise, "Sunrise"))]
 pub static FiSunrise: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSunrise",
+    label: "Sunrise",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4145,8 +5560,14 @@ pub static FiSunrise: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="8 6 12 2 16 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiSunset, "Sunset"))]
 pub static FiSunset: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiSunset",
+    label: "Sunset",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4168,8 +5589,14 @@ pub static FiSunset: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="16 5 12 9 8 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTable, "Table"))]
 pub static FiTable: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTable",
+    label: "Table",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4184,8 +5611,14 @@ pub static FiTable: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9 3H5a2 2 0 0 0-2 2v4m6-6h10a2 2 0 0 1 2 2v4M9 3v18m0 0h10a2 2 0 0 0 2-2V9M9 21H5a2 2 0 0 1-2-2V9m0 0h18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTablet, "Tablet"))]
 pub static FiTablet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTablet",
+    label: "Tablet",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4201,8 +5634,14 @@ pub static FiTablet: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="18" x2="12.01" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTag, "Tag"))]
 pub static FiTag: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTag",
+    label: "Tag",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4218,8 +5657,14 @@ pub static FiTag: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="7" y1="7" x2="7.01" y2="7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTarget, "Target"))]
 pub static FiTarget: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTarget",
+    label: "Target",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4236,8 +5681,14 @@ pub static FiTarget: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTerminal, "Terminal"))]
 pub static FiTerminal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTerminal",
+    label: "Terminal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4253,8 +5704,14 @@ pub static FiTerminal: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="19" x2="20" y2="19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiThermometer, "Thermometer"))]
 pub static FiThermometer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiThermometer",
+    label: "Thermometer",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4269,8 +5726,14 @@ pub static FiThermometer: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14 14.76V3.5a2.5 2.5 0 0 0-5 0v11.26a4.5 4.5 0 1 0 5 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiThumbsDown, "Thumbs Down"))]
 pub static FiThumbsDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiThumbsDown",
+    label: "Thumbs Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4285,8 +5748,14 @@ pub static FiThumbsDown: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M10 15v4a3 3 0 0 0 3 3l4-9V2H5.72a2 2 0 0 0-2 1.7l-1.38 9a2 2 0 0 0 2 2.3zm7-13h2.67A2.31 2.31 0 0 1 22 4v7a2.31 2.31 0 0 1-2.33 2H17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiThumbsUp, "Thumbs Up"))]
 pub static FiThumbsUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiThumbsUp",
+    label: "Thumbs Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4301,8 +5770,14 @@ pub static FiThumbsUp: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14 9V5a3 3 0 0 0-3-3l-4 9v11h11.28a2 2 0 0 0 2-1.7l1.38-9a2 2 0 0 0-2-2.3zM7 22H4a2 2 0 0 1-2-2v-7a2 2 0 0 1 2-2h3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiToggleLeft, "Toggle Left"))]
 pub static FiToggleLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiToggleLeft",
+    label: "Toggle Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4318,8 +5793,14 @@ pub static FiToggleLeft: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="12" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiToggleRight, "Toggle Right"))]
 pub static FiToggleRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiToggleRight",
+    label: "Toggle Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4335,8 +5816,14 @@ pub static FiToggleRight: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="16" cy="12" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTool, "Tool"))]
 pub static FiTool: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTool",
+    label: "Tool",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4351,8 +5838,14 @@ pub static FiTool: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.7 6.3a1 1 0 0 0 0 1.4l1.6 1.6a1 1 0 0 0 1.4 0l3.77-3.77a6 6 0 0 1-7.94 7.94l-6.91 6.91a2.12 2.12 0 0 1-3-3l6.91-6.91a6 6 0 0 1 7.94-7.94l-3.76 3.76z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTrash, "Trash"))]
 pub static FiTrash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTrash",
+    label: "Trash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4368,8 +5861,14 @@ pub static FiTrash: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 6v14a2 2 0 0 1-2 2H7a2 2 0 0 1-2-2V6m3 0V4a2 2 0 0 1 2-2h4a2 2 0 0 1 2 2v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTrash2, "Trash 2"))]
 pub static FiTrash2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTrash2",
+    label: "Trash 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4387,8 +5886,14 @@ pub static FiTrash2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="14" y1="11" x2="14" y2="17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTrello, "Trello"))]
 pub static FiTrello: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTrello",
+    label: "Trello",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4405,8 +5910,14 @@ pub static FiTrello: &icondata_core::IconData = &icondata_core::IconData {
 <rect x="14" y="7" width="3" height="5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTrendingDown, "Trending Down"))]
 pub static FiTrendingDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTrendingDown",
+    label: "Trending Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4422,8 +5933,14 @@ pub static FiTrendingDown: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="17 18 23 18 23 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTrendingUp, "Trending Up"))]
 pub static FiTrendingUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTrendingUp",
+    label: "Trending Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4439,8 +5956,14 @@ pub static FiTrendingUp: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="17 6 23 6 23 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTriangle, "Triangle"))]
 pub static FiTriangle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTriangle",
+    label: "Triangle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4455,8 +5978,14 @@ pub static FiTriangle: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M10.29 3.86L1.82 18a2 2 0 0 0 1.71 3h16.94a2 2 0 0 0 1.71-3L13.71 3.86a2 2 0 0 0-3.42 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTruck, "Truck"))]
 pub static FiTruck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTruck",
+    label: "Truck",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4474,8 +6003,14 @@ pub static FiTruck: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="18.5" cy="18.5" r="2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTv, "TV"))]
 pub static FiTv: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTv",
+    label: "TV",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4491,8 +6026,14 @@ pub static FiTv: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="17 2 12 7 7 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTwitch, "Twitch"))]
 pub static FiTwitch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTwitch",
+    label: "Twitch",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4507,8 +6048,14 @@ pub static FiTwitch: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M21 2H3v16h5v4l4-4h5l4-4V2zM11 11V7M16 11V7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiTwitter, "Twitter"))]
 pub static FiTwitter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiTwitter",
+    label: "Twitter",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4523,8 +6070,14 @@ pub static FiTwitter: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M23 3a10.9 10.9 0 0 1-3.14 1.53 4.48 4.48 0 0 0-7.86 3v1A10.66 10.66 0 0 1 3 4s-4 9 5 13a11.64 11.64 0 0 1-7 2c9 5 20 0 20-11.5a4.5 4.5 0 0 0-.08-.83A7.72 7.72 0 0 0 23 3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiType, "Type"))]
 pub static FiType: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiType",
+    label: "Type",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4541,8 +6094,14 @@ pub static FiType: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="4" x2="12" y2="20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiUmbrella, "Umbrella"))]
 pub static FiUmbrella: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiUmbrella",
+    label: "Umbrella",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4557,8 +6116,14 @@ pub static FiUmbrella: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M23 12a11.05 11.05 0 0 0-22 0zm-5 7a3 3 0 0 1-6 0v-7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiUnderline, "Underline"))]
 pub static FiUnderline: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiUnderline",
+    label: "Underline",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4574,8 +6139,14 @@ pub static FiUnderline: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="4" y1="21" x2="20" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiUnlock, "Unlock"))]
 pub static FiUnlock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiUnlock",
+    label: "Unlock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4591,8 +6162,14 @@ pub static FiUnlock: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 11V7a5 5 0 0 1 9.9-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiUpload, "Upload"))]
 pub static FiUpload: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiUpload",
+    label: "Upload",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4609,8 +6186,14 @@ pub static FiUpload: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="3" x2="12" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiUploadCloud, "Upload Cloud"))]
 pub static FiUploadCloud: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiUploadCloud",
+    label: "Upload Cloud",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4628,8 +6211,14 @@ pub static FiUploadCloud: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="16 16 12 12 8 16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiUser, "User"))]
 pub static FiUser: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiUser",
+    label: "User",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4645,8 +6234,14 @@ pub static FiUser: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="7" r="4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiUserCheck, "User Check"))]
 pub static FiUserCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiUserCheck",
+    label: "User Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4663,8 +6258,14 @@ pub static FiUserCheck: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="17 11 19 13 23 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiUserMinus, "User Minus"))]
 pub static FiUserMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiUserMinus",
+    label: "User Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4681,8 +6282,14 @@ pub static FiUserMinus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="23" y1="11" x2="17" y2="11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiUserPlus, "User Plus"))]
 pub static FiUserPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiUserPlus",
+    label: "User Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4700,8 +6307,14 @@ pub static FiUserPlus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="23" y1="11" x2="17" y2="11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiUserX, "User X"))]
 pub static FiUserX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiUserX",
+    label: "User X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4719,8 +6332,14 @@ pub static FiUserX: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="23" y1="8" x2="18" y2="13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiUsers, "Users"))]
 pub static FiUsers: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiUsers",
+    label: "Users",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4738,8 +6357,14 @@ pub static FiUsers: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 3.13a4 4 0 0 1 0 7.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiVideo, "Video"))]
 pub static FiVideo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiVideo",
+    label: "Video",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4755,8 +6380,14 @@ pub static FiVideo: &icondata_core::IconData = &icondata_core::IconData {
 <rect x="1" y="5" width="15" height="14" rx="2" ry="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiVideoOff, "Video Off"))]
 pub static FiVideoOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiVideoOff",
+    label: "Video Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4772,8 +6403,14 @@ pub static FiVideoOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="1" y1="1" x2="23" y2="23" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiVoicemail, "Voicemail"))]
 pub static FiVoicemail: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiVoicemail",
+    label: "Voicemail",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4790,8 +6427,14 @@ pub static FiVoicemail: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="5.5" y1="16" x2="18.5" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiVolume, "Volume"))]
 pub static FiVolume: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiVolume",
+    label: "Volume",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4806,8 +6449,14 @@ pub static FiVolume: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="11 5 6 9 2 9 2 15 6 15 11 19 11 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiVolume1, "Volume 1"))]
 pub static FiVolume1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiVolume1",
+    label: "Volume 1",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4823,8 +6472,14 @@ pub static FiVolume1: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15.54 8.46a5 5 0 0 1 0 7.07" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiVolume2, "Volume 2"))]
 pub static FiVolume2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiVolume2",
+    label: "Volume 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4840,8 +6495,14 @@ pub static FiVolume2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19.07 4.93a10 10 0 0 1 0 14.14M15.54 8.46a5 5 0 0 1 0 7.07" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiVolumeX, "Volume X"))]
 pub static FiVolumeX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiVolumeX",
+    label: "Volume X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4858,8 +6519,14 @@ pub static FiVolumeX: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="17" y1="9" x2="23" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiWatch, "Watch"))]
 pub static FiWatch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiWatch",
+    label: "Watch",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4876,8 +6543,14 @@ pub static FiWatch: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16.51 17.35l-.35 3.83a2 2 0 0 1-2 1.82H9.83a2 2 0 0 1-2-1.82l-.35-3.83m.01-10.7l.35-3.83A2 2 0 0 1 9.83 1h4.35a2 2 0 0 1 2 1.82l.35 3.83" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiWifi, "Wifi"))]
 pub static FiWifi: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiWifi",
+    label: "Wifi",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4895,8 +6568,14 @@ pub static FiWifi: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="20" x2="12.01" y2="20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiWifiOff, "Wifi Off"))]
 pub static FiWifiOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiWifiOff",
+    label: "Wifi Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4917,8 +6596,14 @@ pub static FiWifiOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" y1="20" x2="12.01" y2="20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiWind, "Wind"))]
 pub static FiWind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiWind",
+    label: "Wind",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4933,8 +6618,14 @@ pub static FiWind: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9.59 4.59A2 2 0 1 1 11 8H2m10.59 11.41A2 2 0 1 0 14 16H2m15.73-8.27A2.5 2.5 0 1 1 19.5 12H2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiX, "X"))]
 pub static FiX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiX",
+    label: "X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4950,8 +6641,14 @@ pub static FiX: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="6" y1="6" x2="18" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiXCircle, "Xcircle"))]
 pub static FiXCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiXCircle",
+    label: "Xcircle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4968,8 +6665,14 @@ pub static FiXCircle: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" y1="9" x2="15" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiXOctagon, "Xoctagon"))]
 pub static FiXOctagon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiXOctagon",
+    label: "Xoctagon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4986,8 +6689,14 @@ pub static FiXOctagon: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" y1="9" x2="15" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiXSquare, "Xsquare"))]
 pub static FiXSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiXSquare",
+    label: "Xsquare",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5004,8 +6713,14 @@ pub static FiXSquare: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" y1="9" x2="9" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiYoutube, "Youtube"))]
 pub static FiYoutube: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiYoutube",
+    label: "Youtube",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5021,8 +6736,14 @@ pub static FiYoutube: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="9.75 15.02 15.5 11.75 9.75 8.48 9.75 15.02" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiZap, "Zap"))]
 pub static FiZap: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiZap",
+    label: "Zap",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5037,8 +6758,14 @@ pub static FiZap: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="13 2 3 14 12 14 11 22 21 10 12 10 13 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiZapOff, "Zap Off"))]
 pub static FiZapOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiZapOff",
+    label: "Zap Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5056,8 +6783,14 @@ pub static FiZapOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="1" y1="1" x2="23" y2="23" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiZoomIn, "Zoom In"))]
 pub static FiZoomIn: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiZoomIn",
+    label: "Zoom In",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5075,8 +6808,14 @@ pub static FiZoomIn: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8" y1="11" x2="14" y2="11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(FiZoomOut, "Zoom Out"))]
 pub static FiZoomOut: &icondata_core::IconData = &icondata_core::IconData {
+    id: "FiZoomOut",
+    label: "Zoom Out",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1,9 +1,32 @@
 //! This crate provides a collection of icons in the form of SVG data
 //! from the [__IcoMoon Free__](https://github.com/Keyamoon/IcoMoon-Free) icon set.
 
+/// Documentation of every icon, showing its usage with different frameworks.
+#[cfg(feature = "docs")]
+macro_rules! examples {
+    ($icon:ident, $label:literal) => {
+        concat!(
+            $label,
+            "\n\n# Examples\n\nWith [Leptos](https://leptos.dev) and [`leptos_icons`](https://docs.rs/leptos_icons):\n```rust,ignore\nuse leptos::*;\nuse leptos_icons::Icon;\n\nview! { <Icon icon=icondata::",
+            stringify!($icon),
+            " /> }\n```\n\nWith [Yew](https://yew.rs):\n```rust,ignore\nuse yew::prelude::*;\n\nlet svg = icondata::",
+            stringify!($icon),
+            ".render(&Default::default());\nhtml! { { Html::from_html_unchecked(AttrValue::from(svg)) } }\n```\n\nWith [Dioxus](https://dioxuslabs.com):\n```rust,ignore\nuse dioxus::prelude::*;\n\nlet svg = icondata::",
+            stringify!($icon),
+            ".render(&Default::default());\nrsx! { span { dangerous_inner_html: \"{svg}\" } }\n```",
+        )
+    };
+}
+
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(Im500px, "500 Px"))]
 pub static Im500px: &icondata_core::IconData = &icondata_core::IconData {
+    id: "Im500px",
+    label: "500 Px",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18,8 +41,14 @@ pub static Im500px: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M3.953 10.512c0.003 0.009 0.088 0.238 0.134 0.353 0.263 0.622 0.641 1.184 1.122 1.666s1.041 0.859 1.666 1.122c0.647 0.272 1.331 0.412 2.037 0.412s1.394-0.137 2.037-0.412c0.625-0.262 1.184-0.641 1.666-1.122s0.859-1.041 1.122-1.666c0.272-0.647 0.412-1.331 0.412-2.037s-0.137-1.394-0.412-2.037c-0.262-0.625-0.641-1.184-1.122-1.666s-1.041-0.859-1.666-1.122c-0.647-0.272-1.331-0.413-2.037-0.413-0.716 0-1.431 0.144-2.066 0.413-0.509 0.216-1.372 0.769-1.875 1.291l-0.003 0.003v-4.313h7.241c0.262-0.003 0.262-0.372 0.262-0.491 0-0.122 0-0.487-0.266-0.491h-7.828c-0.213 0-0.344 0.178-0.344 0.341v6.066c0 0.197 0.244 0.338 0.472 0.384 0.444 0.094 0.544-0.047 0.653-0.197l0.016-0.019c0.166-0.247 0.681-0.766 0.688-0.772 0.806-0.806 1.884-1.25 3.037-1.25 1.147 0 2.222 0.444 3.028 1.25 0.809 0.809 1.256 1.881 1.256 3.019 0 1.141-0.444 2.216-1.25 3.019-0.794 0.794-1.906 1.25-3.047 1.25-0.772 0-1.519-0.206-2.159-0.597l0.003-3.688c0-0.491 0.213-1.028 0.572-1.431 0.409-0.463 0.972-0.716 1.588-0.716 0.594 0 1.15 0.225 1.566 0.634 0.409 0.406 0.637 0.95 0.637 1.528 0 1.231-0.969 2.197-2.206 2.197-0.238 0-0.672-0.106-0.691-0.109-0.25-0.075-0.356 0.272-0.391 0.387-0.134 0.441 0.069 0.528 0.109 0.541 0.397 0.125 0.659 0.147 1.003 0.147 1.747 0 3.169-1.422 3.169-3.169 0-1.734-1.422-3.144-3.166-3.144-0.856 0-1.659 0.328-2.263 0.919-0.575 0.566-0.903 1.319-0.903 2.069v0.019c-0.003 0.094-0.003 2.306-0.006 3.031l-0.003-0.003c-0.328-0.363-0.653-0.919-0.869-1.488-0.084-0.222-0.275-0.184-0.534-0.103-0.125 0.034-0.469 0.141-0.391 0.394v0zM7.675 9.647c0 0.106 0.097 0.2 0.156 0.253l0.019 0.019c0.1 0.097 0.194 0.147 0.281 0.147 0.072 0 0.116-0.034 0.131-0.050 0.044-0.041 0.537-0.544 0.588-0.591l0.553 0.55c0.050 0.056 0.106 0.088 0.172 0.088 0.088 0 0.184-0.053 0.284-0.156 0.238-0.244 0.119-0.375 0.063-0.438l-0.559-0.559 0.584-0.588c0.128-0.137 0.016-0.284-0.097-0.397-0.162-0.162-0.322-0.206-0.422-0.112l-0.581 0.581-0.588-0.588c-0.031-0.031-0.072-0.047-0.113-0.047-0.078 0-0.172 0.053-0.275 0.156-0.181 0.181-0.219 0.306-0.125 0.406l0.588 0.584-0.584 0.584c-0.053 0.050-0.078 0.103-0.075 0.156zM8.953 1.716c-0.938 0-1.938 0.191-2.669 0.506-0.078 0.031-0.125 0.094-0.134 0.181-0.009 0.084 0.013 0.194 0.069 0.337 0.047 0.116 0.166 0.425 0.4 0.334 0.75-0.288 1.581-0.444 2.334-0.444 0.856 0 1.688 0.169 2.469 0.497 0.622 0.263 1.206 0.644 1.844 1.194 0.047 0.041 0.097 0.059 0.147 0.059 0.125 0 0.244-0.122 0.347-0.237 0.169-0.191 0.287-0.35 0.119-0.509-0.609-0.575-1.275-1.006-2.1-1.356-0.894-0.372-1.847-0.563-2.825-0.563zM14.006 13.3v0c-0.113-0.113-0.209-0.178-0.294-0.203s-0.162-0.006-0.222 0.053l-0.056 0.056c-0.581 0.581-1.259 1.037-2.012 1.356-0.781 0.331-1.609 0.497-2.463 0.497-0.856 0-1.684-0.169-2.463-0.497-0.753-0.319-1.431-0.775-2.013-1.356-0.606-0.606-1.063-1.284-1.356-2.012-0.288-0.713-0.381-1.247-0.413-1.422-0.003-0.016-0.006-0.028-0.006-0.037-0.041-0.206-0.231-0.222-0.503-0.178-0.112 0.019-0.459 0.072-0.428 0.319v0.006c0.091 0.578 0.253 1.144 0.481 1.681 0.366 0.866 0.891 1.644 1.559 2.313s1.447 1.191 2.313 1.559c0.897 0.378 1.85 0.572 2.831 0.572s1.934-0.194 2.831-0.572c0.866-0.366 1.644-0.891 2.313-1.559 0 0 0.037-0.037 0.059-0.059 0.069-0.084 0.134-0.225-0.159-0.516z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImAccessibility, "Accessibility"))]
 pub static ImAccessibility: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImAccessibility",
+    label: "Accessibility",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -35,8 +64,14 @@ pub static ImAccessibility: &icondata_core::IconData = &icondata_core::IconData
 <path d="M10 5l5.15-2.221-0.371-0.929-6.279 2.15h-1l-6.279-2.15-0.371 0.929 5.15 2.221v4l-2.051 6.634 0.935 0.355 2.902-6.489h0.429l2.902 6.489 0.935-0.355-2.051-6.634z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImAddressBook, "Address Book"))]
 pub static ImAddressBook: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImAddressBook",
+    label: "Address Book",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -55,8 +90,14 @@ pub static ImAddressBook: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M1 13h1.5v3h-1.5v-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImAidKit, "Aid Kit"))]
 pub static ImAidKit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImAidKit",
+    label: "Aid Kit",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -71,8 +112,14 @@ pub static ImAidKit: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14 4h-3v-2c0-0.55-0.45-1-1-1h-4c-0.55 0-1 0.45-1 1v2h-3c-1.1 0-2 0.9-2 2v8c0 1.1 0.9 2 2 2h12c1.1 0 2-0.9 2-2v-8c0-1.1-0.9-2-2-2zM6 2h4v2h-4v-2zM12 11h-3v3h-2v-3h-3v-2h3v-3h2v3h3v2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImAirplane, "Airplane"))]
 pub static ImAirplane: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImAirplane",
+    label: "Airplane",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -87,8 +134,14 @@ pub static ImAirplane: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 9.999l-2.857-2.857 6.857-5.143-2-2-8.571 3.429-2.698-2.699c-0.778-0.778-1.864-0.964-2.414-0.414s-0.364 1.636 0.414 2.414l2.698 2.698-3.429 8.572 2 2 5.144-6.857 2.857 2.857v4h2l1-3 3-1v-2l-4 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImAlarm, "Alarm"))]
 pub static ImAlarm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImAlarm",
+    label: "Alarm",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -104,8 +157,14 @@ pub static ImAlarm: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 9v-4h-1v5h4v-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImAmazon, "Amazon"))]
 pub static ImAmazon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImAmazon",
+    label: "Amazon",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -122,8 +181,14 @@ pub static ImAmazon: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11.053 11.838l0.003 0.003c0.387-0.341 1.084-0.95 1.478-1.278 0.156-0.125 0.128-0.334 0.006-0.509-0.353-0.488-0.728-0.884-0.728-1.784v-3c0-1.272 0.088-2.438-0.847-3.313-0.738-0.706-1.963-0.956-2.9-0.956-1.831 0-3.875 0.684-4.303 2.947-0.047 0.241 0.131 0.369 0.287 0.403l1.866 0.203c0.175-0.009 0.3-0.181 0.334-0.356 0.159-0.778 0.813-1.156 1.547-1.156 0.397 0 0.847 0.144 1.081 0.5 0.269 0.397 0.234 0.938 0.234 1.397v0.25c-1.116 0.125-2.575 0.206-3.619 0.666-1.206 0.522-2.053 1.584-2.053 3.147 0 2 1.259 3 2.881 3 1.369 0 2.116-0.322 3.172-1.403 0.35 0.506 0.463 0.753 1.103 1.284 0.147 0.078 0.328 0.072 0.456-0.044zM9.113 7.144c0 0.75 0.019 1.375-0.359 2.041-0.306 0.544-0.791 0.875-1.331 0.875-0.737 0-1.169-0.563-1.169-1.394 0-1.641 1.472-1.938 2.863-1.938v0.416z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImAndroid, "Android"))]
 pub static ImAndroid: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImAndroid",
+    label: "Android",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -139,8 +204,14 @@ pub static ImAndroid: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12.472 5c-0.152-1.373-0.922-2.559-2.025-3.276l0.5-1.001c0.123-0.247 0.023-0.547-0.224-0.671s-0.547-0.023-0.671 0.224l-0.502 1.004-0.13-0.052c-0.446-0.148-0.924-0.229-1.42-0.229s-0.974 0.081-1.42 0.229l-0.13 0.052-0.502-1.004c-0.123-0.247-0.424-0.347-0.671-0.224s-0.347 0.424-0.224 0.671l0.5 1.001c-1.103 0.716-1.873 1.903-2.025 3.276v0.5h8.972v-0.5h-0.028zM6.5 4c-0.276 0-0.5-0.224-0.5-0.5s0.223-0.499 0.499-0.5c0 0 0.001 0 0.001 0s0.001-0 0.001-0c0.276 0.001 0.499 0.224 0.499 0.5s-0.224 0.5-0.5 0.5zM9.5 4c-0.276 0-0.5-0.224-0.5-0.5s0.223-0.499 0.499-0.5c0 0 0.001 0 0.001 0s0.001-0 0.002-0c0.276 0.001 0.499 0.224 0.499 0.5s-0.224 0.5-0.5 0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImAngry, "Angry"))]
 pub static ImAngry: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImAngry",
+    label: "Angry",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -155,8 +226,14 @@ pub static ImAngry: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c4.418 0 8-3.582 8-8s-3.582-8-8-8-8 3.582-8 8 3.582 8 8 8zM8 1.5c3.59 0 6.5 2.91 6.5 6.5s-2.91 6.5-6.5 6.5-6.5-2.91-6.5-6.5 2.91-6.5 6.5-6.5zM11.002 12.199c-0.612-1.018-1.727-1.699-3.002-1.699s-2.389 0.681-3.002 1.699l-1.286-0.772c0.874-1.454 2.467-2.427 4.288-2.427s3.414 0.973 4.288 2.427l-1.286 0.772zM11.985 4.379c0.067 0.268-0.096 0.539-0.364 0.606-0.275 0.070-0.602 0.189-0.89 0.334 0.166 0.179 0.268 0.418 0.268 0.681 0 0.552-0.448 1-1 1s-1-0.448-1-1c0-0.018 0.001-0.036 0.002-0.054 0.032-0.741 0.706-1.234 1.275-1.518 0.543-0.271 1.080-0.407 1.102-0.413 0.268-0.067 0.539 0.096 0.606 0.364zM4.015 4.379c0.067-0.268 0.338-0.431 0.606-0.364 0.023 0.006 0.559 0.141 1.102 0.413 0.568 0.284 1.243 0.776 1.275 1.518 0.001 0.018 0.002 0.036 0.002 0.054 0 0.552-0.448 1-1 1s-1-0.448-1-1c0-0.263 0.102-0.503 0.268-0.681-0.288-0.144-0.614-0.264-0.89-0.334-0.268-0.067-0.431-0.338-0.364-0.606z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImAngry2, "Angry 2"))]
 pub static ImAngry2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImAngry2",
+    label: "Angry 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -171,8 +248,14 @@ pub static ImAngry2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM9.001 5.946c0.032-0.741 0.706-1.234 1.275-1.518 0.543-0.271 1.080-0.407 1.102-0.413 0.268-0.067 0.539 0.096 0.606 0.364s-0.096 0.539-0.364 0.606c-0.275 0.070-0.602 0.189-0.89 0.334 0.166 0.179 0.268 0.418 0.268 0.681 0 0.552-0.448 1-1 1s-1-0.448-1-1c0-0.018 0.001-0.036 0.002-0.054zM4.015 4.379c0.067-0.268 0.338-0.431 0.606-0.364 0.023 0.006 0.559 0.141 1.102 0.413 0.568 0.284 1.243 0.776 1.275 1.518 0.001 0.018 0.002 0.036 0.002 0.054 0 0.552-0.448 1-1 1s-1-0.448-1-1c0-0.263 0.102-0.503 0.268-0.681-0.288-0.144-0.614-0.264-0.89-0.334-0.268-0.067-0.431-0.338-0.364-0.606zM11.002 12.199c-0.612-1.018-1.727-1.699-3.002-1.699s-2.389 0.681-3.002 1.699l-1.286-0.772c0.874-1.454 2.467-2.427 4.288-2.427s3.414 0.973 4.288 2.427l-1.286 0.772z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImAppleinc, "Appleinc"))]
 pub static ImAppleinc: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImAppleinc",
+    label: "Appleinc",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -187,8 +270,14 @@ pub static ImAppleinc: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12.367 8.501c-0.020-2.026 1.652-2.998 1.727-3.046-0.94-1.375-2.404-1.564-2.926-1.585-1.246-0.126-2.431 0.734-3.064 0.734-0.631 0-1.607-0.715-2.64-0.696-1.358 0.020-2.61 0.79-3.31 2.006-1.411 2.448-0.361 6.076 1.014 8.061 0.672 0.972 1.473 2.064 2.525 2.025 1.013-0.040 1.396-0.656 2.621-0.656s1.569 0.656 2.641 0.635c1.090-0.020 1.781-0.991 2.448-1.966 0.772-1.128 1.089-2.219 1.108-2.275-0.024-0.011-2.126-0.816-2.147-3.236zM10.353 2.555c0.558-0.677 0.935-1.617 0.832-2.555-0.804 0.033-1.779 0.536-2.356 1.212-0.518 0.6-0.971 1.557-0.85 2.476 0.898 0.070 1.815-0.456 2.373-1.132z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowDown, "Arrow Down"))]
 pub static ImArrowDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowDown",
+    label: "Arrow Down",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -203,8 +292,14 @@ pub static ImArrowDown: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 15.5l7.5-7.5h-4.5v-8h-6v8h-4.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowDown2, "Arrow Down 2"))]
 pub static ImArrowDown2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowDown2",
+    label: "Arrow Down 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -219,8 +314,14 @@ pub static ImArrowDown2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.707 9.707l-5 5c-0.39 0.391-1.024 0.391-1.414 0l-5-5c-0.391-0.391-0.391-1.024 0-1.414s1.024-0.391 1.414 0l3.293 3.293v-9.586c0-0.552 0.448-1 1-1s1 0.448 1 1v9.586l3.293-3.293c0.195-0.195 0.451-0.293 0.707-0.293s0.512 0.098 0.707 0.293c0.391 0.391 0.391 1.024 0 1.414z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowDownLeft, "Arrow Down Left"))]
 pub static ImArrowDownLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowDownLeft",
+    label: "Arrow Down Left",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -235,8 +336,14 @@ pub static ImArrowDownLeft: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M11.5 16l-4-4 8.5-8.5-3.5-3.5-8.5 8.5-4-4v11.5h11.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowDownLeft2, "Arrow Down Left 2"))]
 pub static ImArrowDownLeft2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowDownLeft2",
+    label: "Arrow Down Left 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -251,8 +358,14 @@ pub static ImArrowDownLeft2: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M12.293 2.293l-8.293 8.293v-3.586c0-0.552-0.448-1-1-1s-1 0.448-1 1v6c0 0.404 0.244 0.769 0.617 0.924 0.124 0.051 0.254 0.076 0.383 0.076v0.001l6-0c0.552 0 1-0.448 1-1s-0.448-1-1-1h-3.586l8.293-8.293c0.195-0.195 0.293-0.451 0.293-0.707s-0.098-0.512-0.293-0.707c-0.39-0.391-1.024-0.391-1.414 0v0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowDownRight, "Arrow Down Right"))]
 pub static ImArrowDownRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowDownRight",
+    label: "Arrow Down Right",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -267,8 +380,14 @@ pub static ImArrowDownRight: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M16 4.5l-4 4-8.5-8.5-3.5 3.5 8.5 8.5-4 4h11.5v-11.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowDownRight2, "Arrow Down Right 2"))]
 pub static ImArrowDownRight2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowDownRight2",
+    label: "Arrow Down Right 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -283,8 +402,14 @@ pub static ImArrowDownRight2: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M2.293 3.707l8.293 8.293h-3.586c-0.552 0-1 0.448-1 1s0.448 1 1 1h6c0.404 0 0.769-0.244 0.924-0.617 0.051-0.124 0.076-0.254 0.076-0.383h0.001v-6c0-0.552-0.448-1-1-1s-1 0.448-1 1v3.586l-8.293-8.293c-0.195-0.195-0.451-0.293-0.707-0.293s-0.512 0.098-0.707 0.293c-0.391 0.39-0.391 1.024 0 1.414z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowLeft, "Arrow Left"))]
 pub static ImArrowLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowLeft",
+    label: "Arrow Left",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -299,8 +424,14 @@ pub static ImArrowLeft: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0.5 8l7.5 7.5v-4.5h8v-6h-8v-4.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowLeft2, "Arrow Left 2"))]
 pub static ImArrowLeft2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowLeft2",
+    label: "Arrow Left 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -315,8 +446,14 @@ pub static ImArrowLeft2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6.293 13.707l-5-5c-0.391-0.39-0.391-1.024 0-1.414l5-5c0.391-0.391 1.024-0.391 1.414 0s0.391 1.024 0 1.414l-3.293 3.293h9.586c0.552 0 1 0.448 1 1s-0.448 1-1 1h-9.586l3.293 3.293c0.195 0.195 0.293 0.451 0.293 0.707s-0.098 0.512-0.293 0.707c-0.391 0.391-1.024 0.391-1.414 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowRight, "Arrow Right"))]
 pub static ImArrowRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowRight",
+    label: "Arrow Right",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -331,8 +468,14 @@ pub static ImArrowRight: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.5 8l-7.5-7.5v4.5h-8v6h8v4.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowRight2, "Arrow Right 2"))]
 pub static ImArrowRight2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowRight2",
+    label: "Arrow Right 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -347,8 +490,14 @@ pub static ImArrowRight2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9.707 13.707l5-5c0.391-0.39 0.391-1.024 0-1.414l-5-5c-0.391-0.391-1.024-0.391-1.414 0s-0.391 1.024 0 1.414l3.293 3.293h-9.586c-0.552 0-1 0.448-1 1s0.448 1 1 1h9.586l-3.293 3.293c-0.195 0.195-0.293 0.451-0.293 0.707s0.098 0.512 0.293 0.707c0.391 0.391 1.024 0.391 1.414 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowUp, "Arrow Up"))]
 pub static ImArrowUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowUp",
+    label: "Arrow Up",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -363,8 +512,14 @@ pub static ImArrowUp: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0.5l-7.5 7.5h4.5v8h6v-8h4.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowUp2, "Arrow Up 2"))]
 pub static ImArrowUp2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowUp2",
+    label: "Arrow Up 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -379,8 +534,14 @@ pub static ImArrowUp2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.707 6.293l-5-5c-0.39-0.391-1.024-0.391-1.414 0l-5 5c-0.391 0.391-0.391 1.024 0 1.414s1.024 0.391 1.414 0l3.293-3.293v9.586c0 0.552 0.448 1 1 1s1-0.448 1-1v-9.586l3.293 3.293c0.195 0.195 0.451 0.293 0.707 0.293s0.512-0.098 0.707-0.293c0.391-0.391 0.391-1.024 0-1.414z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowUpLeft, "Arrow Up Left"))]
 pub static ImArrowUpLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowUpLeft",
+    label: "Arrow Up Left",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -395,8 +556,14 @@ pub static ImArrowUpLeft: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 11.5l4-4 8.5 8.5 3.5-3.5-8.5-8.5 4-4h-11.5v11.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowUpLeft2, "Arrow Up Left 2"))]
 pub static ImArrowUpLeft2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowUpLeft2",
+    label: "Arrow Up Left 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -411,8 +578,14 @@ pub static ImArrowUpLeft2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.707 12.293l-8.293-8.293h3.586c0.552 0 1-0.448 1-1s-0.448-1-1-1h-6c-0.404 0-0.769 0.244-0.924 0.617-0.051 0.124-0.076 0.254-0.076 0.383h-0.001v6c0 0.552 0.448 1 1 1s1-0.448 1-1v-3.586l8.293 8.293c0.195 0.195 0.451 0.293 0.707 0.293s0.512-0.098 0.707-0.293c0.391-0.39 0.391-1.024 0-1.414z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowUpRight, "Arrow Up Right"))]
 pub static ImArrowUpRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowUpRight",
+    label: "Arrow Up Right",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -427,8 +600,14 @@ pub static ImArrowUpRight: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4.5 0l4 4-8.5 8.5 3.5 3.5 8.5-8.5 4 4v-11.5h-11.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImArrowUpRight2, "Arrow Up Right 2"))]
 pub static ImArrowUpRight2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImArrowUpRight2",
+    label: "Arrow Up Right 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -443,8 +622,14 @@ pub static ImArrowUpRight2: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M3.707 13.707l8.293-8.293v3.586c0 0.552 0.448 1 1 1s1-0.448 1-1v-6c0-0.404-0.244-0.769-0.617-0.924-0.124-0.051-0.254-0.076-0.383-0.076v-0.001h-6c-0.552 0-1 0.448-1 1s0.448 1 1 1h3.586l-8.293 8.293c-0.195 0.195-0.293 0.451-0.293 0.707s0.098 0.512 0.293 0.707c0.39 0.391 1.024 0.391 1.414 0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImAttachment, "Attachment"))]
 pub static ImAttachment: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImAttachment",
+    label: "Attachment",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -459,8 +644,14 @@ pub static ImAttachment: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M10.404 5.11l-1.015-1.014-5.075 5.074c-0.841 0.841-0.841 2.204 0 3.044s2.204 0.841 3.045 0l6.090-6.089c1.402-1.401 1.402-3.673 0-5.074s-3.674-1.402-5.075 0l-6.394 6.393c-0.005 0.005-0.010 0.009-0.014 0.013-1.955 1.955-1.955 5.123 0 7.077s5.123 1.954 7.078 0c0.004-0.004 0.008-0.009 0.013-0.014l0.001 0.001 4.365-4.364-1.015-1.014-4.365 4.363c-0.005 0.004-0.009 0.009-0.013 0.013-1.392 1.392-3.656 1.392-5.048 0s-1.392-3.655 0-5.047c0.005-0.005 0.009-0.009 0.014-0.013l-0.001-0.001 6.395-6.393c0.839-0.84 2.205-0.84 3.045 0s0.839 2.205 0 3.044l-6.090 6.089c-0.28 0.28-0.735 0.28-1.015 0s-0.28-0.735 0-1.014l5.075-5.075z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBackward, "Backward"))]
 pub static ImBackward: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBackward",
+    label: "Backward",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -475,8 +666,14 @@ pub static ImBackward: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c4.418 0 8-3.582 8-8s-3.582-8-8-8-8 3.582-8 8 3.582 8 8 8zM8 1.5c3.59 0 6.5 2.91 6.5 6.5s-2.91 6.5-6.5 6.5-6.5-2.91-6.5-6.5 2.91-6.5 6.5-6.5zM11 10.5l-3.5-2.5 3.5-2.5zM7 10.5l-3.5-2.5 3.5-2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBackward2, "Backward 2"))]
 pub static ImBackward2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBackward2",
+    label: "Backward 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -491,8 +688,14 @@ pub static ImBackward2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9 2.5v5l5-5v11l-5-5v5l-5.5-5.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBaffled, "Baffled"))]
 pub static ImBaffled: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBaffled",
+    label: "Baffled",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -512,8 +715,14 @@ pub static ImBaffled: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6 11h4v1h-4v-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBaffled2, "Baffled 2"))]
 pub static ImBaffled2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBaffled2",
+    label: "Baffled 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -530,8 +739,14 @@ pub static ImBaffled2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM4 6.5c0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5-0.672 1.5-1.5 1.5-1.5-0.672-1.5-1.5zM10 12h-4v-1h4v1zM10.5 8c-0.828 0-1.5-0.672-1.5-1.5s0.672-1.5 1.5-1.5 1.5 0.672 1.5 1.5-0.672 1.5-1.5 1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBarcode, "Barcode"))]
 pub static ImBarcode: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBarcode",
+    label: "Barcode",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -546,8 +761,14 @@ pub static ImBarcode: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 2h2v10h-2zM3 2h1v10h-1zM5 2h1v10h-1zM8 2h1v10h-1zM12 2h1v10h-1zM15 2h1v10h-1zM10 2h0.5v10h-0.5zM7 2h0.5v10h-0.5zM13.5 2h0.5v10h-0.5zM0 13h1v1h-1zM3 13h1v1h-1zM5 13h1v1h-1zM10 13h1v1h-1zM15 13h1v1h-1zM12 13h2v1h-2zM7 13h2v1h-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBasecamp, "Basecamp"))]
 pub static ImBasecamp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBasecamp",
+    label: "Basecamp",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -562,8 +783,14 @@ pub static ImBasecamp: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 1.666c-2.919 0-5.169 2.444-6.444 4.838-0.719 1.347-1.222 2.822-1.453 4.331-0.025 0.172-0.050 0.344-0.069 0.519-0.009 0.094-0.019 0.188-0.025 0.281-0.009 0.119-0.003 0.156 0.059 0.256 0.187 0.303 0.409 0.584 0.659 0.838 0.512 0.525 1.134 0.928 1.794 1.241 1.503 0.709 3.2 0.966 4.85 1.022 1.703 0.056 3.453-0.084 5.081-0.616 1.391-0.453 2.731-1.244 3.503-2.522 0.084-0.137 0.025-0.341 0.009-0.5-0.019-0.191-0.044-0.378-0.075-0.566-0.056-0.369-0.131-0.731-0.222-1.094-0.181-0.738-0.428-1.463-0.728-2.159-1.088-2.525-3.1-5.219-5.963-5.775-0.322-0.063-0.65-0.094-0.978-0.094zM8.1 13.909c-1.784 0-3.728-0.159-5.334-1.019-0.625-0.334-1.262-0.819-1.563-1.484-0.087-0.194-0.056-0.269-0.016-0.497 0.028-0.147 0.041-0.291 0.106-0.428 0.091-0.191 0.184-0.378 0.281-0.566 0.328-0.634 0.681-1.262 1.091-1.853 0.203-0.291 0.419-0.578 0.669-0.828 0.175-0.175 0.388-0.362 0.634-0.422 0.756-0.181 1.334 0.694 1.794 1.134 0.222 0.213 0.519 0.453 0.85 0.412 0.228-0.028 0.431-0.206 0.594-0.353 0.553-0.497 0.997-1.112 1.456-1.691 0.228-0.284 0.453-0.572 0.7-0.844 0.166-0.184 0.347-0.394 0.569-0.513 0.397-0.216 0.903 0.228 1.178 0.456 0.469 0.391 0.884 0.847 1.281 1.309 0.378 0.441 0.744 0.888 1.066 1.372 0.497 0.75 0.928 1.55 1.322 2.359 0.084 0.175 0.113 0.294 0.144 0.488 0.019 0.106 0.059 0.228 0.044 0.338-0.022 0.153-0.128 0.319-0.206 0.444-0.188 0.297-0.441 0.553-0.719 0.769-1.166 0.903-2.744 1.203-4.178 1.338-0.588 0.056-1.175 0.078-1.762 0.078z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBehance, "Behance"))]
 pub static ImBehance: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBehance",
+    label: "Behance",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -580,8 +807,14 @@ pub static ImBehance: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10.444 4h3.991v0.972h-3.991v-0.972z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBehance2, "Behance 2"))]
 pub static ImBehance2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBehance2",
+    label: "Behance 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -599,8 +832,14 @@ pub static ImBehance2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12.134 7.247c-0.253 0-0.466 0.044-0.631 0.131s-0.3 0.194-0.403 0.319c-0.103 0.128-0.172 0.263-0.213 0.409-0.041 0.141-0.066 0.269-0.072 0.381h2.588c-0.037-0.406-0.178-0.706-0.366-0.916-0.194-0.213-0.512-0.325-0.903-0.325z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBell, "Bell"))]
 pub static ImBell: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBell",
+    label: "Bell",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -615,8 +854,14 @@ pub static ImBell: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16.023 12.5c0-4.5-4-3.5-4-7 0-0.29-0.028-0.538-0.079-0.749-0.263-1.766-1.44-3.183-2.965-3.615 0.014-0.062 0.021-0.125 0.021-0.191 0-0.52-0.45-0.945-1-0.945s-1 0.425-1 0.945c0 0.065 0.007 0.129 0.021 0.191-1.71 0.484-2.983 2.208-3.020 4.273-0.001 0.030-0.001 0.060-0.001 0.091 0 3.5-4 2.5-4 7 0 1.191 2.665 2.187 6.234 2.439 0.336 0.631 1.001 1.061 1.766 1.061s1.43-0.43 1.766-1.061c3.568-0.251 6.234-1.248 6.234-2.439 0-0.004-0-0.007-0-0.011l0.024 0.011zM12.91 13.345c-0.847 0.226-1.846 0.389-2.918 0.479-0.089-1.022-0.947-1.824-1.992-1.824s-1.903 0.802-1.992 1.824c-1.072-0.090-2.071-0.253-2.918-0.479-1.166-0.311-1.724-0.659-1.928-0.845 0.204-0.186 0.762-0.534 1.928-0.845 1.356-0.362 3.1-0.561 4.91-0.561s3.554 0.199 4.91 0.561c1.166 0.311 1.724 0.659 1.928 0.845-0.204 0.186-0.762 0.534-1.928 0.845z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBin, "Bin"))]
 pub static ImBin: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBin",
+    label: "Bin",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -632,8 +877,14 @@ pub static ImBin: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13.25 2h-3.25v-1.25c0-0.412-0.338-0.75-0.75-0.75h-3.5c-0.412 0-0.75 0.338-0.75 0.75v1.25h-3.25c-0.413 0-0.75 0.337-0.75 0.75v1.25h13v-1.25c0-0.413-0.338-0.75-0.75-0.75zM9 2h-3v-0.987h3v0.987z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBin2, "Bin 2"))]
 pub static ImBin2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBin2",
+    label: "Bin 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -648,8 +899,14 @@ pub static ImBin2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M3 16h10l1-11h-12zM10 2v-2h-4v2h-5v3l1-1h12l1 1v-3h-5zM9 2h-2v-1h2v1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBinoculars, "Binoculars"))]
 pub static ImBinoculars: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBinoculars",
+    label: "Binoculars",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -664,8 +921,14 @@ pub static ImBinoculars: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M1 0h6v1h-6zM9 0h6v1h-6zM14.875 5h-0.875v-4h-4v4h-4v-4h-4v4h-0.875c-0.619 0-1.125 0.506-1.125 1.125v8.75c0 0.619 0.506 1.125 1.125 1.125h4.75c0.619 0 1.125-0.506 1.125-1.125v-5.875h2v5.875c0 0.619 0.506 1.125 1.125 1.125h4.75c0.619 0 1.125-0.506 1.125-1.125v-8.75c0-0.619-0.506-1.125-1.125-1.125zM5.438 15h-3.875c-0.309 0-0.563-0.225-0.563-0.5s0.253-0.5 0.563-0.5h3.875c0.309 0 0.563 0.225 0.563 0.5s-0.253 0.5-0.563 0.5zM8.5 8h-1c-0.275 0-0.5-0.225-0.5-0.5s0.225-0.5 0.5-0.5h1c0.275 0 0.5 0.225 0.5 0.5s-0.225 0.5-0.5 0.5zM14.438 15h-3.875c-0.309 0-0.563-0.225-0.563-0.5s0.253-0.5 0.563-0.5h3.875c0.309 0 0.563 0.225 0.563 0.5s-0.253 0.5-0.563 0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBlocked, "Blocked"))]
 pub static ImBlocked: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBlocked",
+    label: "Blocked",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -680,8 +943,14 @@ pub static ImBlocked: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.657 2.343c-1.511-1.511-3.52-2.343-5.657-2.343s-4.146 0.832-5.657 2.343c-1.511 1.511-2.343 3.52-2.343 5.657s0.832 4.146 2.343 5.657c1.511 1.511 3.52 2.343 5.657 2.343s4.146-0.832 5.657-2.343c1.511-1.511 2.343-3.52 2.343-5.657s-0.832-4.146-2.343-5.657zM14 8c0 1.294-0.412 2.494-1.111 3.475l-8.364-8.364c0.981-0.699 2.181-1.111 3.475-1.111 3.308 0 6 2.692 6 6zM2 8c0-1.294 0.412-2.494 1.111-3.475l8.364 8.364c-0.981 0.699-2.181 1.111-3.475 1.111-3.308 0-6-2.692-6-6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBlog, "Blog"))]
 pub static ImBlog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBlog",
+    label: "Blog",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -698,8 +967,14 @@ pub static ImBlog: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7.5 6l-1 1-3.5 1-3 6.5 0.396 0.396 3.638-3.638c-0.022-0.083-0.034-0.169-0.034-0.259 0-0.552 0.448-1 1-1s1 0.448 1 1-0.448 1-1 1c-0.090 0-0.176-0.012-0.259-0.034l-3.638 3.638 0.396 0.396 6.5-3 1-3.5 1-1-2.5-2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBlogger, "Blogger"))]
 pub static ImBlogger: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBlogger",
+    label: "Blogger",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -714,8 +989,14 @@ pub static ImBlogger: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.966 6h-0.897c-0.549 0-1.031-0.465-1.069-1v0c0-2.854-2.301-5-5.175-5h-2.622c-2.872 0-5.201 2.313-5.203 5.167v5.669c0 2.854 2.331 5.165 5.203 5.165h5.6c2.874 0 5.197-2.311 5.197-5.165v-3.662c0-0.57-0.46-1.173-1.034-1.173zM5 4h3c0.55 0 1 0.45 1 1s-0.45 1-1 1h-3c-0.55 0-1-0.45-1-1s0.45-1 1-1zM11 12h-6c-0.55 0-1-0.45-1-1s0.45-1 1-1h6c0.55 0 1 0.45 1 1s-0.45 1-1 1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBlogger2, "Blogger 2"))]
 pub static ImBlogger2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBlogger2",
+    label: "Blogger 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -732,8 +1013,14 @@ pub static ImBlogger2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 6c0 0.55-0.45 1-1 1h-2c-0.55 0-1-0.45-1-1v0c0-0.55 0.45-1 1-1h2c0.55 0 1 0.45 1 1v0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBold, "Bold"))]
 pub static ImBold: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBold",
+    label: "Bold",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -748,8 +1035,14 @@ pub static ImBold: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11.061 7.573c0.586-0.696 0.939-1.594 0.939-2.573 0-2.206-1.794-4-4-4h-5v14h6c2.206 0 4-1.794 4-4 0-1.452-0.778-2.726-1.939-3.427zM6 3h1.586c0.874 0 1.586 0.897 1.586 2s-0.711 2-1.586 2h-1.586v-4zM8.484 13h-2.484v-4h2.484c0.913 0 1.656 0.897 1.656 2s-0.743 2-1.656 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBook, "Book"))]
 pub static ImBook: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBook",
+    label: "Book",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -765,8 +1058,14 @@ pub static ImBook: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3.501 13v0c-0 0-0.001 0-0.001 0-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5c0 0 0.001-0 0.001-0v0h9.498v-1h-9.498z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBookmark, "Bookmark"))]
 pub static ImBookmark: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBookmark",
+    label: "Bookmark",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -781,8 +1080,14 @@ pub static ImBookmark: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M3 0v16l5-5 5 5v-16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBookmarks, "Bookmarks"))]
 pub static ImBookmarks: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBookmarks",
+    label: "Bookmarks",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -797,8 +1102,14 @@ pub static ImBookmarks: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4 2v14l5-5 5 5v-14zM12 0h-10v14l1-1v-12h9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBooks, "Books"))]
 pub static ImBooks: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBooks",
+    label: "Books",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -816,8 +1127,14 @@ pub static ImBooks: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.5 13.5c0 0.276-0.224 0.5-0.5 0.5s-0.5-0.224-0.5-0.5c0-0.276 0.224-0.5 0.5-0.5s0.5 0.224 0.5 0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBoxAdd, "Box Add"))]
 pub static ImBoxAdd: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBoxAdd",
+    label: "Box Add",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -832,8 +1149,14 @@ pub static ImBoxAdd: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13 1h-10l-3 3v10.5c0 0.276 0.224 0.5 0.5 0.5h15c0.276 0 0.5-0.224 0.5-0.5v-10.5l-3-3zM8 13l-5-4h3v-3h4v3h3l-5 4zM2.414 3l1-1h9.172l1 1h-11.172z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBoxRemove, "Box Remove"))]
 pub static ImBoxRemove: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBoxRemove",
+    label: "Box Remove",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -848,8 +1171,14 @@ pub static ImBoxRemove: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13 1h-10l-3 3v10.5c0 0.276 0.224 0.5 0.5 0.5h15c0.276 0 0.5-0.224 0.5-0.5v-10.5l-3-3zM10 10v3h-4v-3h-3l5-4 5 4h-3zM2.414 3l1-1h9.171l1 1h-11.171z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBriefcase, "Briefcase"))]
 pub static ImBriefcase: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBriefcase",
+    label: "Briefcase",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -864,8 +1193,14 @@ pub static ImBriefcase: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15 4h-4v-1c0-0.55-0.45-1-1-1h-4c-0.55 0-1 0.45-1 1v1h-4c-0.55 0-1 0.45-1 1v9c0 0.55 0.45 1 1 1h14c0.55 0 1-0.45 1-1v-9c0-0.55-0.45-1-1-1zM6 3.002c0.001-0.001 0.001-0.001 0.002-0.002h3.996c0.001 0.001 0.001 0.001 0.002 0.002v0.998h-4v-0.998zM15 8h-2v1.5c0 0.275-0.225 0.5-0.5 0.5h-1c-0.275 0-0.5-0.225-0.5-0.5v-1.5h-6v1.5c0 0.275-0.225 0.5-0.5 0.5h-1c-0.275 0-0.5-0.225-0.5-0.5v-1.5h-2v-1h14v1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBrightnessContrast, "Brightness Contrast"))]
 pub static ImBrightnessContrast: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBrightnessContrast",
+    label: "Brightness Contrast",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -880,8 +1215,14 @@ pub static ImBrightnessContrast: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M8 4c-2.209 0-4 1.791-4 4s1.791 4 4 4 4-1.791 4-4-1.791-4-4-4zM8 10.5v-5c1.379 0 2.5 1.122 2.5 2.5s-1.121 2.5-2.5 2.5zM8 13c0.552 0 1 0.448 1 1v1c0 0.552-0.448 1-1 1s-1-0.448-1-1v-1c0-0.552 0.448-1 1-1zM8 3c-0.552 0-1-0.448-1-1v-1c0-0.552 0.448-1 1-1s1 0.448 1 1v1c0 0.552-0.448 1-1 1zM15 7c0.552 0 1 0.448 1 1s-0.448 1-1 1h-1c-0.552 0-1-0.448-1-1s0.448-1 1-1h1zM3 8c0 0.552-0.448 1-1 1h-1c-0.552 0-1-0.448-1-1s0.448-1 1-1h1c0.552 0 1 0.448 1 1zM12.95 11.536l0.707 0.707c0.39 0.39 0.39 1.024 0 1.414s-1.024 0.39-1.414 0l-0.707-0.707c-0.39-0.39-0.39-1.024 0-1.414s1.024-0.39 1.414 0zM3.050 4.464l-0.707-0.707c-0.391-0.391-0.391-1.024 0-1.414s1.024-0.391 1.414 0l0.707 0.707c0.391 0.391 0.391 1.024 0 1.414s-1.024 0.391-1.414 0zM12.95 4.464c-0.39 0.391-1.024 0.391-1.414 0s-0.39-1.024 0-1.414l0.707-0.707c0.39-0.391 1.024-0.391 1.414 0s0.39 1.024 0 1.414l-0.707 0.707zM3.050 11.536c0.39-0.39 1.024-0.39 1.414 0s0.391 1.024 0 1.414l-0.707 0.707c-0.391 0.39-1.024 0.39-1.414 0s-0.391-1.024 0-1.414l0.707-0.707z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBubble, "Bubble"))]
 pub static ImBubble: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBubble",
+    label: "Bubble",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -896,8 +1237,14 @@ pub static ImBubble: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 1c4.418 0 8 2.91 8 6.5s-3.582 6.5-8 6.5c-0.424 0-0.841-0.027-1.247-0.079-1.718 1.718-3.77 2.027-5.753 2.072v-0.421c1.071-0.525 2-1.48 2-2.572 0-0.152-0.012-0.302-0.034-0.448-1.809-1.192-2.966-3.012-2.966-5.052 0-3.59 3.582-6.5 8-6.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBubble2, "Bubble 2"))]
 pub static ImBubble2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBubble2",
+    label: "Bubble 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -912,8 +1259,14 @@ pub static ImBubble2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 3c-0.858 0-1.687 0.135-2.464 0.402-0.73 0.251-1.38 0.605-1.932 1.054-1.035 0.841-1.604 1.922-1.604 3.044 0 0.63 0.175 1.24 0.52 1.815 0.356 0.592 0.89 1.134 1.547 1.566 0.474 0.312 0.793 0.812 0.878 1.373 0.028 0.187 0.046 0.376 0.053 0.564 0.117-0.097 0.23-0.201 0.342-0.312 0.377-0.377 0.887-0.586 1.414-0.586 0.084 0 0.168 0.005 0.252 0.016 0.328 0.042 0.662 0.063 0.995 0.063 0.858 0 1.687-0.135 2.464-0.402 0.73-0.251 1.38-0.605 1.932-1.054 1.035-0.841 1.604-1.922 1.604-3.044s-0.57-2.203-1.604-3.044c-0.552-0.448-1.202-0.803-1.932-1.054-0.777-0.267-1.606-0.402-2.464-0.402zM8 1v0c4.418 0 8 2.91 8 6.5s-3.582 6.5-8 6.5c-0.424 0-0.841-0.027-1.247-0.079-1.718 1.718-3.77 2.027-5.753 2.072v-0.421c1.071-0.525 2-1.48 2-2.572 0-0.152-0.012-0.302-0.034-0.448-1.809-1.192-2.966-3.012-2.966-5.052 0-3.59 3.582-6.5 8-6.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBubbles, "Bubbles"))]
 pub static ImBubbles: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBubbles",
+    label: "Bubbles",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -928,8 +1281,14 @@ pub static ImBubbles: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M17 14.081c0 0.711 0.407 1.327 1 1.628v0.249c-0.166 0.023-0.335 0.035-0.508 0.035-1.063 0-2.021-0.446-2.699-1.16-0.41 0.109-0.844 0.168-1.293 0.168-2.485 0-4.5-1.791-4.5-4s2.015-4 4.5-4c2.485 0 4.5 1.791 4.5 4 0 0.865-0.309 1.665-0.834 2.32-0.107 0.232-0.166 0.489-0.166 0.761zM8 0c4.351 0 7.89 2.822 7.997 6.336-0.768-0.343-1.619-0.524-2.497-0.524-1.493 0-2.903 0.523-3.971 1.472-1.107 0.984-1.717 2.304-1.717 3.716 0 0.698 0.149 1.373 0.433 1.997-0.082 0.002-0.164 0.003-0.246 0.003-0.424 0-0.841-0.027-1.247-0.079-1.718 1.718-3.77 2.027-5.753 2.072v-0.421c1.071-0.525 2-1.48 2-2.572 0-0.152-0.012-0.302-0.034-0.448-1.809-1.192-2.966-3.012-2.966-5.052 0-3.59 3.582-6.5 8-6.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBubbles2, "Bubbles 2"))]
 pub static ImBubbles2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBubbles2",
+    label: "Bubbles 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -944,8 +1303,14 @@ pub static ImBubbles2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7.5 0v0c4.142 0 7.5 2.717 7.5 6.069s-3.358 6.069-7.5 6.069c-0.398 0-0.788-0.025-1.169-0.074-1.611 1.605-3.471 1.892-5.331 1.935v-0.393c1.004-0.49 1.813-1.382 1.813-2.402 0-0.142-0.011-0.282-0.032-0.419-1.696-1.113-2.781-2.812-2.781-4.717 0-3.352 3.358-6.069 7.5-6.069zM15.563 13.604c0 0.874 0.567 1.639 1.438 2.059v0.337c-1.611-0.036-3.090-0.283-4.487-1.658-0.33 0.041-0.669 0.063-1.013 0.063-1.492 0-2.866-0.402-3.963-1.079 2.261-0.008 4.395-0.732 6.013-2.042 0.816-0.66 1.459-1.435 1.913-2.302 0.481-0.92 0.724-1.9 0.724-2.913 0-0.163-0.007-0.326-0.020-0.487 1.134 0.936 1.832 2.213 1.832 3.62 0 1.633-0.94 3.089-2.41 4.043-0.018 0.117-0.027 0.237-0.027 0.359z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBubbles3, "Bubbles 3"))]
 pub static ImBubbles3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBubbles3",
+    label: "Bubbles 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -960,8 +1325,14 @@ pub static ImBubbles3: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M17 14.081c0 0.711 0.407 1.327 1 1.628v0.249c-0.166 0.023-0.335 0.035-0.508 0.035-1.063 0-2.021-0.446-2.699-1.16-0.41 0.109-0.844 0.168-1.293 0.168-2.485 0-4.5-1.791-4.5-4s2.015-4 4.5-4c2.485 0 4.5 1.791 4.5 4 0 0.865-0.309 1.665-0.834 2.32-0.107 0.232-0.166 0.489-0.166 0.761zM3.604 3.456c-1.035 0.841-1.604 1.922-1.604 3.044 0 0.63 0.175 1.24 0.52 1.815 0.356 0.592 0.89 1.134 1.547 1.566 0.474 0.312 0.793 0.812 0.878 1.373 0.028 0.187 0.046 0.376 0.053 0.564 0.117-0.097 0.23-0.201 0.342-0.312 0.377-0.377 0.887-0.586 1.414-0.586 0.084 0 0.168 0.005 0.252 0.016 0.327 0.042 0.662 0.063 0.994 0.063v2c-0.424-0-0.84-0.027-1.246-0.079-1.718 1.718-3.77 2.027-5.753 2.072v-0.421c1.071-0.525 2-1.48 2-2.572 0-0.152-0.012-0.302-0.034-0.448-1.809-1.192-2.966-3.012-2.966-5.052 0-3.59 3.582-6.5 8-6.5 4.351 0 7.89 2.822 7.997 6.336-0.642-0.286-1.341-0.46-2.067-0.509-0.18-0.876-0.709-1.7-1.535-2.371-0.552-0.448-1.202-0.803-1.932-1.054-0.777-0.267-1.606-0.402-2.464-0.402s-1.687 0.135-2.464 0.402c-0.73 0.251-1.38 0.605-1.932 1.054z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBubbles4, "Bubbles 4"))]
 pub static ImBubbles4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBubbles4",
+    label: "Bubbles 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -976,8 +1347,14 @@ pub static ImBubbles4: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7.5 2c-0.792 0-1.556 0.124-2.272 0.369-0.671 0.23-1.267 0.554-1.773 0.963-0.938 0.759-1.455 1.731-1.455 2.737 0 0.562 0.157 1.109 0.467 1.623 0.323 0.537 0.811 1.028 1.41 1.421 0.476 0.312 0.796 0.812 0.881 1.374 0.014 0.094 0.025 0.188 0.034 0.282 0.043-0.039 0.085-0.080 0.127-0.122 0.377-0.376 0.886-0.583 1.411-0.583 0.084 0 0.167 0.005 0.251 0.016 0.303 0.038 0.611 0.058 0.918 0.058 0.792 0 1.556-0.124 2.272-0.369 0.671-0.23 1.267-0.554 1.774-0.963 0.938-0.759 1.455-1.731 1.455-2.737s-0.517-1.978-1.455-2.737c-0.506-0.41-1.103-0.734-1.774-0.963-0.716-0.245-1.48-0.369-2.272-0.369zM7.5 0v0c4.142 0 7.5 2.717 7.5 6.069s-3.358 6.069-7.5 6.069c-0.398 0-0.788-0.025-1.169-0.074-1.611 1.605-3.471 1.892-5.331 1.935v-0.393c1.004-0.49 1.813-1.382 1.813-2.402 0-0.142-0.011-0.282-0.032-0.419-1.696-1.113-2.781-2.812-2.781-4.717 0-3.352 3.358-6.069 7.5-6.069zM15.563 13.604c0 0.874 0.567 1.639 1.438 2.059v0.337c-1.611-0.036-3.090-0.283-4.487-1.658-0.33 0.041-0.669 0.063-1.013 0.063-1.492 0-2.866-0.402-3.963-1.079 2.261-0.008 4.395-0.732 6.013-2.042 0.816-0.66 1.459-1.435 1.913-2.302 0.481-0.92 0.724-1.9 0.724-2.913 0-0.163-0.007-0.326-0.020-0.487 1.134 0.936 1.832 2.213 1.832 3.62 0 1.633-0.94 3.089-2.41 4.043-0.018 0.117-0.027 0.237-0.027 0.359z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBug, "Bug"))]
 pub static ImBug: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBug",
+    label: "Bug",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -992,8 +1369,14 @@ pub static ImBug: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 9v-1h-3.020c-0.092-1.136-0.497-2.172-1.12-3.004h2.53l1.095-4.379-0.97-0.243-0.905 3.621h-2.729c-0.014-0.011-0.028-0.021-0.042-0.032 0.105-0.305 0.162-0.632 0.162-0.972 0-1.653-1.343-2.992-3-2.992s-3 1.34-3 2.992c0 0.34 0.057 0.667 0.162 0.972-0.014 0.011-0.028 0.021-0.042 0.032h-2.729l-0.905-3.621-0.97 0.243 1.095 4.379h2.53c-0.623 0.832-1.028 1.868-1.12 3.004h-3.020v1h3.021c0.059 0.713 0.242 1.388 0.526 1.996h-1.937l-1.095 4.379 0.97 0.243 0.905-3.621h1.756c0.917 1.219 2.303 1.996 3.854 1.996s2.937-0.777 3.854-1.996h1.756l0.905 3.621 0.97-0.243-1.095-4.379h-1.937c0.283-0.608 0.466-1.283 0.526-1.996h3.021z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImBullhorn, "Bullhorn"))]
 pub static ImBullhorn: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImBullhorn",
+    label: "Bullhorn",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1008,8 +1391,14 @@ pub static ImBullhorn: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 6.707c0-3.139-0.919-5.687-2.054-5.707 0.005-0 0.009-0 0.014-0h-1.296c0 0-3.044 2.287-7.425 3.184-0.134 0.708-0.219 1.551-0.219 2.523s0.085 1.816 0.219 2.523c4.382 0.897 7.425 3.184 7.425 3.184h1.296c-0.005 0-0.009-0-0.014-0.001 1.136-0.020 2.054-2.567 2.054-5.707zM13.513 11.551c-0.147 0-0.305-0.152-0.387-0.243-0.197-0.22-0.387-0.562-0.55-0.989-0.363-0.957-0.564-2.239-0.564-3.611s0.2-2.655 0.564-3.611c0.162-0.428 0.353-0.77 0.55-0.99 0.081-0.091 0.24-0.243 0.387-0.243s0.305 0.152 0.387 0.243c0.197 0.22 0.387 0.562 0.55 0.99 0.363 0.957 0.564 2.239 0.564 3.611s-0.2 2.655-0.564 3.611c-0.162 0.428-0.353 0.77-0.55 0.989-0.081 0.091-0.24 0.243-0.387 0.243zM3.935 6.707c0-0.812 0.060-1.6 0.173-2.33-0.74 0.102-1.39 0.161-2.193 0.161-1.048 0-1.048 0-1.048 0l-0.867 1.479v1.378l0.867 1.479c0 0 0 0 1.048 0 0.803 0 1.453 0.059 2.193 0.161-0.113-0.729-0.173-1.518-0.173-2.33zM5.752 10.034l-2-0.383 1.279 5.024c0.066 0.26 0.324 0.391 0.573 0.291l1.852-0.741c0.249-0.1 0.349-0.374 0.222-0.611l-1.926-3.581zM13.513 8.574c-0.057 0-0.118-0.059-0.149-0.094-0.076-0.085-0.149-0.217-0.212-0.381-0.14-0.369-0.217-0.863-0.217-1.392s0.077-1.023 0.217-1.392c0.063-0.165 0.136-0.297 0.212-0.381 0.031-0.035 0.092-0.094 0.149-0.094s0.118 0.059 0.149 0.094c0.076 0.085 0.149 0.217 0.212 0.381 0.14 0.369 0.217 0.863 0.217 1.392s-0.077 1.023-0.217 1.392c-0.063 0.165-0.136 0.297-0.212 0.381-0.031 0.035-0.092 0.094-0.149 0.094z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCalculator, "Calculator"))]
 pub static ImCalculator: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCalculator",
+    label: "Calculator",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1024,8 +1413,14 @@ pub static ImCalculator: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6 1h-5c-0.55 0-1 0.45-1 1v5c0 0.55 0.45 1 1 1h5c0.55 0 1-0.45 1-1v-5c0-0.55-0.45-1-1-1zM6 5h-5v-1h5v1zM14 1h-5c-0.55 0-1 0.45-1 1v13c0 0.55 0.45 1 1 1h5c0.55 0 1-0.45 1-1v-13c0-0.55-0.45-1-1-1zM14 10h-5v-1h5v1zM14 7h-5v-1h5v1zM6 9h-5c-0.55 0-1 0.45-1 1v5c0 0.55 0.45 1 1 1h5c0.55 0 1-0.45 1-1v-5c0-0.55-0.45-1-1-1zM6 13h-2v2h-1v-2h-2v-1h2v-2h1v2h2v1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCalendar, "Calendar"))]
 pub static ImCalendar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCalendar",
+    label: "Calendar",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1040,8 +1435,14 @@ pub static ImCalendar: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M5 6h2v2h-2zM8 6h2v2h-2zM11 6h2v2h-2zM2 12h2v2h-2zM5 12h2v2h-2zM8 12h2v2h-2zM5 9h2v2h-2zM8 9h2v2h-2zM11 9h2v2h-2zM2 9h2v2h-2zM13 0v1h-2v-1h-7v1h-2v-1h-2v16h15v-16h-2zM14 15h-13v-11h13v11z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCamera, "Camera"))]
 pub static ImCamera: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCamera",
+    label: "Camera",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1056,8 +1457,14 @@ pub static ImCamera: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4.75 9.5c0 1.795 1.455 3.25 3.25 3.25s3.25-1.455 3.25-3.25-1.455-3.25-3.25-3.25-3.25 1.455-3.25 3.25zM15 4h-3.5c-0.25-1-0.5-2-1.5-2h-4c-1 0-1.25 1-1.5 2h-3.5c-0.55 0-1 0.45-1 1v9c0 0.55 0.45 1 1 1h14c0.55 0 1-0.45 1-1v-9c0-0.55-0.45-1-1-1zM8 13.938c-2.451 0-4.438-1.987-4.438-4.438s1.987-4.438 4.438-4.438c2.451 0 4.438 1.987 4.438 4.438s-1.987 4.438-4.438 4.438zM15 7h-2v-1h2v1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCancelCircle, "Cancel Circle"))]
 pub static ImCancelCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCancelCircle",
+    label: "Cancel Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1073,8 +1480,14 @@ pub static ImCancelCircle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10.5 4l-2.5 2.5-2.5-2.5-1.5 1.5 2.5 2.5-2.5 2.5 1.5 1.5 2.5-2.5 2.5 2.5 1.5-1.5-2.5-2.5 2.5-2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCart, "Cart"))]
 pub static ImCart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCart",
+    label: "Cart",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1091,8 +1504,14 @@ pub static ImCart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 8v-6h-12c0-0.552-0.448-1-1-1h-3v1h2l0.751 6.438c-0.458 0.367-0.751 0.93-0.751 1.562 0 1.105 0.895 2 2 2h12v-1h-12c-0.552 0-1-0.448-1-1 0-0.003 0-0.007 0-0.010l13-1.99z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCheckboxChecked, "Checkbox Checked"))]
 pub static ImCheckboxChecked: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCheckboxChecked",
+    label: "Checkbox Checked",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1107,8 +1526,14 @@ pub static ImCheckboxChecked: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M14 0h-12c-1.1 0-2 0.9-2 2v12c0 1.1 0.9 2 2 2h12c1.1 0 2-0.9 2-2v-12c0-1.1-0.9-2-2-2zM7 12.414l-3.707-3.707 1.414-1.414 2.293 2.293 4.793-4.793 1.414 1.414-6.207 6.207z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCheckboxUnchecked, "Checkbox Unchecked"))]
 pub static ImCheckboxUnchecked: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCheckboxUnchecked",
+    label: "Checkbox Unchecked",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1123,8 +1548,14 @@ pub static ImCheckboxUnchecked: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M14 0h-12c-1.1 0-2 0.9-2 2v12c0 1.1 0.9 2 2 2h12c1.1 0 2-0.9 2-2v-12c0-1.1-0.9-2-2-2zM14 14h-12v-12h12v12z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCheckmark, "Checkmark"))]
 pub static ImCheckmark: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCheckmark",
+    label: "Checkmark",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1139,8 +1570,14 @@ pub static ImCheckmark: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.5 2l-7.5 7.5-3.5-3.5-2.5 2.5 6 6 10-10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCheckmark2, "Checkmark 2"))]
 pub static ImCheckmark2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCheckmark2",
+    label: "Checkmark 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1155,8 +1592,14 @@ pub static ImCheckmark2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6.21 14.339l-6.217-6.119 3.084-3.035 3.133 3.083 6.713-6.607 3.084 3.035-9.797 9.643zM1.686 8.22l4.524 4.453 8.104-7.976-1.391-1.369-6.713 6.607-3.133-3.083-1.391 1.369z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImChrome, "Chrome"))]
 pub static ImChrome: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImChrome",
+    label: "Chrome",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1171,8 +1614,14 @@ pub static ImChrome: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4.036 6.977l-2.29-3.966c1.466-1.835 3.722-3.012 6.254-3.012 2.929 0 5.489 1.574 6.883 3.922h-6.528c-0.117-0.010-0.236-0.016-0.356-0.016-1.904 0-3.509 1.307-3.964 3.071zM10.864 5.078h4.585c0.355 0.905 0.551 1.891 0.551 2.922 0 4.388-3.533 7.95-7.909 7.999l3.272-5.667c0.461-0.662 0.731-1.466 0.731-2.332 0-1.143-0.471-2.178-1.23-2.922zM5.094 8c0-1.603 1.304-2.906 2.906-2.906s2.906 1.304 2.906 2.906c0 1.602-1.304 2.906-2.906 2.906s-2.906-1.304-2.906-2.906zM9.097 11.944l-2.29 3.967c-3.852-0.576-6.806-3.899-6.806-7.911 0-1.425 0.373-2.763 1.026-3.922l3.266 5.657c0.654 1.392 2.070 2.359 3.707 2.359 0.38 0 0.747-0.052 1.097-0.149z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCircleDown, "Circle Down"))]
 pub static ImCircleDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCircleDown",
+    label: "Circle Down",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1188,8 +1637,14 @@ pub static ImCircleDown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4.957 5.543l-1.414 1.414 4.457 4.457 4.457-4.457-1.414-1.414-3.043 3.043z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCircleLeft, "Circle Left"))]
 pub static ImCircleLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCircleLeft",
+    label: "Circle Left",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1205,8 +1660,14 @@ pub static ImCircleLeft: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10.457 4.957l-1.414-1.414-4.457 4.457 4.457 4.457 1.414-1.414-3.043-3.043z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCircleRight, "Circle Right"))]
 pub static ImCircleRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCircleRight",
+    label: "Circle Right",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1222,8 +1683,14 @@ pub static ImCircleRight: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5.543 11.043l1.414 1.414 4.457-4.457-4.457-4.457-1.414 1.414 3.043 3.043z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCircleUp, "Circle Up"))]
 pub static ImCircleUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCircleUp",
+    label: "Circle Up",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1239,8 +1706,14 @@ pub static ImCircleUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11.043 10.457l1.414-1.414-4.457-4.457-4.457 4.457 1.414 1.414 3.043-3.043z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImClearFormatting, "Clear Formatting"))]
 pub static ImClearFormatting: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImClearFormatting",
+    label: "Clear Formatting",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1255,8 +1728,14 @@ pub static ImClearFormatting: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M0 14h9v2h-9zM14 2h-4.727l-2.871 11h-2.067l2.871-11h-4.205v-2h11zM14.528 16l-2.028-2.028-2.028 2.028-0.972-0.972 2.028-2.028-2.028-2.028 0.972-0.972 2.028 2.028 2.028-2.028 0.972 0.972-2.028 2.028 2.028 2.028z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImClipboard, "Clipboard"))]
 pub static ImClipboard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImClipboard",
+    label: "Clipboard",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1272,8 +1751,14 @@ pub static ImClipboard: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 13.414l-3.207-3.707 0.914-0.914 2.293 1.793 4.293-3.793 0.914 0.914z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImClock, "Clock"))]
 pub static ImClock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImClock",
+    label: "Clock",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1288,8 +1773,14 @@ pub static ImClock: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M10.293 11.707l-3.293-3.293v-4.414h2v3.586l2.707 2.707zM8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM8 14c-3.314 0-6-2.686-6-6s2.686-6 6-6c3.314 0 6 2.686 6 6s-2.686 6-6 6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImClock2, "Clock 2"))]
 pub static ImClock2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImClock2",
+    label: "Clock 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1304,8 +1795,14 @@ pub static ImClock2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM10.293 11.707l-3.293-3.293v-4.414h2v3.586l2.707 2.707-1.414 1.414z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCloud, "Cloud"))]
 pub static ImCloud: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCloud",
+    label: "Cloud",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1320,8 +1817,14 @@ pub static ImCloud: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 10.274c0-1.283-0.886-2.358-2.078-2.648-0.051-2.011-1.695-3.626-3.717-3.626-1.184 0-2.239 0.555-2.92 1.418-0.382-0.494-0.98-0.812-1.652-0.812-1.153 0-2.088 0.936-2.088 2.089 0 0.101 0.007 0.199 0.021 0.296-0.175-0.032-0.356-0.049-0.54-0.049-1.672-0-3.027 1.356-3.027 3.029s1.355 3.029 3.027 3.029l10.254-0c1.502-0.003 2.719-1.222 2.719-2.726z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCloudCheck, "Cloud Check"))]
 pub static ImCloudCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCloudCheck",
+    label: "Cloud Check",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1336,8 +1839,14 @@ pub static ImCloudCheck: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.942 8.039c0.038-0.174 0.058-0.354 0.058-0.539 0-1.381-1.119-2.5-2.5-2.5-0.222 0-0.438 0.029-0.643 0.084-0.387-1.209-1.52-2.084-2.857-2.084-1.365 0-2.516 0.911-2.88 2.159-0.355-0.103-0.731-0.159-1.12-0.159-2.209 0-4 1.791-4 4s1.791 4 4 4h9.5c1.381 0 2.5-1.119 2.5-2.5 0-1.23-0.888-2.252-2.058-2.461zM6.5 12l-2.5-2.5 1-1 1.5 1.5 3.5-3.5 1 1-4.5 4.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCloudDownload, "Cloud Download"))]
 pub static ImCloudDownload: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCloudDownload",
+    label: "Cloud Download",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1352,8 +1861,14 @@ pub static ImCloudDownload: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M13.922 5.626c-0.051-2.011-1.695-3.626-3.717-3.626-1.184 0-2.239 0.555-2.92 1.418-0.382-0.494-0.98-0.812-1.652-0.812-1.153 0-2.088 0.936-2.088 2.089 0 0.101 0.007 0.199 0.021 0.296-0.175-0.032-0.356-0.049-0.54-0.049-1.672-0-3.027 1.356-3.027 3.029s1.355 3.029 3.027 3.029h1.434l3.539 3.664 3.539-3.664 1.742-0c1.502-0.003 2.719-1.222 2.719-2.726 0-1.283-0.886-2.358-2.078-2.648zM8 13l-3-3h2v-3h2v3h2l-3 3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCloudUpload, "Cloud Upload"))]
 pub static ImCloudUpload: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCloudUpload",
+    label: "Cloud Upload",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1368,8 +1883,14 @@ pub static ImCloudUpload: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.942 6.039c0.038-0.174 0.058-0.354 0.058-0.539 0-1.381-1.119-2.5-2.5-2.5-0.222 0-0.438 0.029-0.643 0.084-0.387-1.209-1.52-2.084-2.857-2.084-1.365 0-2.516 0.911-2.88 2.159-0.355-0.103-0.731-0.159-1.12-0.159-2.209 0-4 1.791-4 4s1.791 4 4 4h2v3h4v-3h3.5c1.381 0 2.5-1.119 2.5-2.5 0-1.23-0.888-2.253-2.058-2.461zM9 10v3h-2v-3h-2.5l3.5-3.5 3.5 3.5h-2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImClubs, "Clubs"))]
 pub static ImClubs: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImClubs",
+    label: "Clubs",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1384,8 +1905,14 @@ pub static ImClubs: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12.294 6.137c-0.922 0-1.751 0.384-2.341 1.011-0.25 0.265-0.684 0.58-1.153 0.856 0.22-0.842 0.917-1.902 1.4-2.367 0.619-0.596 1-1.435 1-2.367 0-1.795-1.429-3.252-3.2-3.271-1.771 0.019-3.2 1.475-3.2 3.271 0 0.932 0.38 1.771 1 2.367 0.484 0.465 1.18 1.525 1.4 2.367-0.469-0.277-0.903-0.591-1.153-0.856-0.59-0.627-1.419-1.011-2.341-1.011-1.787 0-3.236 1.463-3.236 3.271s1.448 3.271 3.236 3.271c0.923 0 1.751-0.396 2.341-1.023 0.263-0.279 0.726-0.627 1.223-0.916-0.047 2.308-1.149 4.003-2.271 4.67v0.59h6v-0.59c-1.122-0.668-2.224-2.363-2.271-4.67 0.498 0.289 0.961 0.637 1.223 0.916 0.59 0.626 1.419 1.023 2.341 1.023 1.787 0 3.236-1.464 3.236-3.271s-1.448-3.271-3.236-3.271z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCodepen, "Codepen"))]
 pub static ImCodepen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCodepen",
+    label: "Codepen",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1400,8 +1927,14 @@ pub static ImCodepen: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.777 5.751l-7-4.667c-0.168-0.112-0.387-0.112-0.555 0l-7 4.667c-0.139 0.093-0.223 0.249-0.223 0.416v4.667c0 0.167 0.084 0.323 0.223 0.416l7 4.667c0.084 0.056 0.181 0.084 0.277 0.084s0.193-0.028 0.277-0.084l7-4.667c0.139-0.093 0.223-0.249 0.223-0.416v-4.667c0-0.167-0.084-0.323-0.223-0.416zM7.5 10.232l-2.599-1.732 2.599-1.732 2.599 1.732-2.599 1.732zM8 5.899v-3.465l5.599 3.732-2.599 1.732-3-2zM7 5.899l-3 2-2.599-1.732 5.599-3.732v3.465zM3.099 8.5l-2.099 1.399v-2.798l2.099 1.399zM4 9.101l3 2v3.465l-5.599-3.732 2.599-1.732zM8 11.101l3-2 2.599 1.732-5.599 3.732v-3.465zM11.901 8.5l2.099-1.399v2.798l-2.099-1.399z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCog, "Cog"))]
 pub static ImCog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCog",
+    label: "Cog",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1416,8 +1949,14 @@ pub static ImCog: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.59 9.535c-0.839-1.454-0.335-3.317 1.127-4.164l-1.572-2.723c-0.449 0.263-0.972 0.414-1.529 0.414-1.68 0-3.042-1.371-3.042-3.062h-3.145c0.004 0.522-0.126 1.051-0.406 1.535-0.839 1.454-2.706 1.948-4.17 1.106l-1.572 2.723c0.453 0.257 0.845 0.634 1.123 1.117 0.838 1.452 0.336 3.311-1.12 4.16l1.572 2.723c0.448-0.261 0.967-0.41 1.522-0.41 1.675 0 3.033 1.362 3.042 3.046h3.145c-0.001-0.517 0.129-1.040 0.406-1.519 0.838-1.452 2.7-1.947 4.163-1.11l1.572-2.723c-0.45-0.257-0.839-0.633-1.116-1.113zM8 11.24c-1.789 0-3.24-1.45-3.24-3.24s1.45-3.24 3.24-3.24c1.789 0 3.24 1.45 3.24 3.24s-1.45 3.24-3.24 3.24z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCogs, "Cogs"))]
 pub static ImCogs: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCogs",
+    label: "Cogs",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1432,8 +1971,14 @@ pub static ImCogs: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M5.683 11.282l0.645-0.903-0.707-0.707-0.903 0.645c-0.168-0.094-0.347-0.168-0.535-0.222l-0.183-1.095h-1l-0.183 1.095c-0.188 0.053-0.368 0.128-0.535 0.222l-0.903-0.645-0.707 0.707 0.645 0.903c-0.094 0.168-0.168 0.347-0.222 0.535l-1.095 0.183v1l1.095 0.183c0.053 0.188 0.128 0.368 0.222 0.535l-0.645 0.903 0.707 0.707 0.903-0.645c0.168 0.094 0.347 0.168 0.535 0.222l0.183 1.095h1l0.183-1.095c0.188-0.053 0.368-0.128 0.535-0.222l0.903 0.645 0.707-0.707-0.645-0.903c0.094-0.168 0.168-0.347 0.222-0.535l1.095-0.182v-1l-1.095-0.183c-0.053-0.188-0.128-0.368-0.222-0.535zM3.5 13.5c-0.552 0-1-0.448-1-1s0.448-1 1-1 1 0.448 1 1-0.448 1-1 1zM16 6v-1l-1.053-0.191c-0.019-0.126-0.044-0.25-0.074-0.372l0.899-0.58-0.383-0.924-1.046 0.226c-0.066-0.108-0.136-0.213-0.211-0.315l0.609-0.88-0.707-0.707-0.88 0.609c-0.102-0.074-0.207-0.145-0.315-0.211l0.226-1.046-0.924-0.383-0.58 0.899c-0.122-0.030-0.246-0.054-0.372-0.074l-0.191-1.053h-1l-0.191 1.053c-0.126 0.019-0.25 0.044-0.372 0.074l-0.58-0.899-0.924 0.383 0.226 1.046c-0.108 0.066-0.213 0.136-0.315 0.211l-0.88-0.609-0.707 0.707 0.609 0.88c-0.074 0.102-0.145 0.207-0.211 0.315l-1.046-0.226-0.383 0.924 0.899 0.58c-0.030 0.122-0.054 0.246-0.074 0.372l-1.053 0.191v1l1.053 0.191c0.019 0.126 0.044 0.25 0.074 0.372l-0.899 0.58 0.383 0.924 1.046-0.226c0.066 0.108 0.136 0.213 0.211 0.315l-0.609 0.88 0.707 0.707 0.88-0.609c0.102 0.074 0.207 0.145 0.315 0.211l-0.226 1.046 0.924 0.383 0.58-0.899c0.122 0.030 0.246 0.054 0.372 0.074l0.191 1.053h1l0.191-1.053c0.126-0.019 0.25-0.044 0.372-0.074l0.58 0.899 0.924-0.383-0.226-1.046c0.108-0.066 0.213-0.136 0.315-0.211l0.88 0.609 0.707-0.707-0.609-0.88c0.074-0.102 0.145-0.207 0.211-0.315l1.046 0.226 0.383-0.924-0.899-0.58c0.030-0.122 0.054-0.246 0.074-0.372l1.053-0.191zM10.5 7.675c-1.201 0-2.175-0.974-2.175-2.175s0.974-2.175 2.175-2.175 2.175 0.974 2.175 2.175c0 1.201-0.974 2.175-2.175 2.175z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCoinDollar, "Coin Dollar"))]
 pub static ImCoinDollar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCoinDollar",
+    label: "Coin Dollar",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1448,8 +1993,14 @@ pub static ImCoinDollar: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7.5 1c-4.142 0-7.5 3.358-7.5 7.5s3.358 7.5 7.5 7.5c4.142 0 7.5-3.358 7.5-7.5s-3.358-7.5-7.5-7.5zM7.5 14.5c-3.314 0-6-2.686-6-6s2.686-6 6-6c3.314 0 6 2.686 6 6s-2.686 6-6 6zM8 8v-2h2v-1h-2v-1h-1v1h-2v4h2v2h-2v1h2v1h1v-1h2l-0-4h-2zM7 8h-1v-2h1v2zM9 11h-1v-2h1v2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCoinEuro, "Coin Euro"))]
 pub static ImCoinEuro: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCoinEuro",
+    label: "Coin Euro",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1465,8 +2016,14 @@ pub static ImCoinEuro: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10.482 10.068c-0.239-0.139-0.545-0.058-0.684 0.181-0.27 0.463-0.767 0.751-1.298 0.751h-2c-0.652 0-1.208-0.418-1.414-1h2.414c0.276 0 0.5-0.224 0.5-0.5s-0.224-0.5-0.5-0.5h-2.5v-1h2.5c0.276 0 0.5-0.224 0.5-0.5s-0.224-0.5-0.5-0.5h-2.414c0.206-0.582 0.762-1 1.414-1h2c0.531 0 1.028 0.288 1.298 0.751 0.139 0.239 0.445 0.32 0.684 0.181s0.32-0.445 0.181-0.684c-0.448-0.77-1.277-1.249-2.162-1.249h-2c-1.207 0-2.217 0.86-2.45 2h-0.55c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5h0.5v1h-0.5c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5h0.55c0.232 1.14 1.242 2 2.45 2h2c0.886 0 1.714-0.478 2.162-1.249 0.139-0.239 0.058-0.545-0.181-0.684z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCoinPound, "Coin Pound"))]
 pub static ImCoinPound: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCoinPound",
+    label: "Coin Pound",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1482,8 +2039,14 @@ pub static ImCoinPound: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9.5 11h-3.5v-2h1.5c0.276 0 0.5-0.224 0.5-0.5s-0.224-0.5-0.5-0.5h-1.5v-0.5c0-0.827 0.673-1.5 1.5-1.5 0.534 0 1.032 0.288 1.3 0.75 0.138 0.239 0.444 0.321 0.683 0.182s0.321-0.444 0.182-0.683c-0.446-0.771-1.276-1.25-2.165-1.25-1.378 0-2.5 1.122-2.5 2.5v0.5h-0.5c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5h0.5v3h4.5c0.276 0 0.5-0.224 0.5-0.5s-0.224-0.5-0.5-0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCoinYen, "Coin Yen"))]
 pub static ImCoinYen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCoinYen",
+    label: "Coin Yen",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1499,8 +2062,14 @@ pub static ImCoinYen: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9.5 9c0.276 0 0.5-0.224 0.5-0.5s-0.224-0.5-0.5-0.5h-1.066l1.482-2.223c0.153-0.23 0.091-0.54-0.139-0.693s-0.54-0.091-0.693 0.139l-1.584 2.376-1.584-2.376c-0.153-0.23-0.464-0.292-0.693-0.139s-0.292 0.464-0.139 0.693l1.482 2.223h-1.066c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5h1.5v1h-1.5c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5h1.5v1.5c0 0.276 0.224 0.5 0.5 0.5s0.5-0.224 0.5-0.5v-1.5h1.5c0.276 0 0.5-0.224 0.5-0.5s-0.224-0.5-0.5-0.5h-1.5v-1h1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCommand, "Command"))]
 pub static ImCommand: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCommand",
+    label: "Command",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1515,8 +2084,14 @@ pub static ImCommand: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11.5 14c-1.379 0-2.5-1.121-2.5-2.5v-1.5h-2v1.5c0 1.379-1.122 2.5-2.5 2.5s-2.5-1.121-2.5-2.5 1.122-2.5 2.5-2.5h1.5v-2h-1.5c-1.378 0-2.5-1.122-2.5-2.5s1.122-2.5 2.5-2.5 2.5 1.122 2.5 2.5v1.5h2v-1.5c0-1.378 1.121-2.5 2.5-2.5s2.5 1.122 2.5 2.5-1.121 2.5-2.5 2.5h-1.5v2h1.5c1.379 0 2.5 1.121 2.5 2.5s-1.121 2.5-2.5 2.5zM10 10v1.5c0 0.827 0.673 1.5 1.5 1.5s1.5-0.673 1.5-1.5-0.673-1.5-1.5-1.5h-1.5zM4.5 10c-0.827 0-1.5 0.673-1.5 1.5s0.673 1.5 1.5 1.5 1.5-0.673 1.5-1.5v-1.5h-1.5zM7 9h2v-2h-2v2zM10 6h1.5c0.827 0 1.5-0.673 1.5-1.5s-0.673-1.5-1.5-1.5-1.5 0.673-1.5 1.5v1.5zM4.5 3c-0.827 0-1.5 0.673-1.5 1.5s0.673 1.5 1.5 1.5h1.5v-1.5c0-0.827-0.673-1.5-1.5-1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCompass, "Compass"))]
 pub static ImCompass: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCompass",
+    label: "Compass",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1531,8 +2106,14 @@ pub static ImCompass: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8.5 16c-0.036 0-0.072-0.004-0.108-0.012-0.229-0.051-0.392-0.254-0.392-0.488v-7.5h-7.5c-0.234 0-0.437-0.163-0.488-0.392s0.064-0.462 0.277-0.561l15-7c0.191-0.089 0.416-0.049 0.565 0.1s0.188 0.374 0.1 0.565l-7 15c-0.083 0.179-0.262 0.289-0.453 0.289zM2.754 7h5.746c0.276 0 0.5 0.224 0.5 0.5v5.746l5.465-11.712-11.712 5.465z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCompass2, "Compass 2"))]
 pub static ImCompass2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCompass2",
+    label: "Compass 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1547,8 +2128,14 @@ pub static ImCompass2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM1.5 8c0-3.59 2.91-6.5 6.5-6.5 1.712 0 3.269 0.662 4.43 1.744l-6.43 2.756-2.756 6.43c-1.082-1.161-1.744-2.718-1.744-4.43zM9.143 9.143l-4.001 1.715 1.715-4.001 2.286 2.286zM8 14.5c-1.712 0-3.269-0.662-4.43-1.744l6.43-2.756 2.756-6.43c1.082 1.161 1.744 2.718 1.744 4.43 0 3.59-2.91 6.5-6.5 6.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImConfused, "Confused"))]
 pub static ImConfused: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImConfused",
+    label: "Confused",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1563,8 +2150,14 @@ pub static ImConfused: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c4.418 0 8-3.582 8-8s-3.582-8-8-8-8 3.582-8 8 3.582 8 8 8zM8 1.5c3.59 0 6.5 2.91 6.5 6.5s-2.91 6.5-6.5 6.5-6.5-2.91-6.5-6.5 2.91-6.5 6.5-6.5zM4 5c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1zM10 5c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1zM11.345 10h1.014c0.144 1.133-0.507 2.258-1.624 2.665-1.295 0.472-2.733-0.199-3.204-1.494-0.283-0.777-1.145-1.179-1.923-0.896-0.712 0.259-1.109 1.005-0.953 1.725h-1.013c-0.144-1.133 0.507-2.258 1.624-2.665 1.295-0.472 2.733 0.199 3.204 1.494 0.283 0.777 1.145 1.179 1.923 0.896 0.712-0.259 1.109-1.005 0.953-1.725z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImConfused2, "Confused 2"))]
 pub static ImConfused2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImConfused2",
+    label: "Confused 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1579,8 +2172,14 @@ pub static ImConfused2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8c4.418 0 8-3.582 8-8s-3.582-8-8-8zM11 4c0.552 0 1 0.448 1 1s-0.448 1-1 1-1-0.448-1-1c0-0.552 0.448-1 1-1zM5 4c0.552 0 1 0.448 1 1s-0.448 1-1 1-1-0.448-1-1c0-0.552 0.448-1 1-1zM10.735 12.665c-1.295 0.472-2.733-0.199-3.204-1.494-0.283-0.777-1.145-1.179-1.923-0.896-0.712 0.259-1.109 1.005-0.953 1.725h-1.013c-0.144-1.133 0.507-2.258 1.624-2.665 1.295-0.472 2.733 0.199 3.204 1.494 0.283 0.777 1.145 1.179 1.923 0.896 0.712-0.259 1.109-1.005 0.953-1.725h1.014c0.144 1.133-0.507 2.258-1.624 2.665z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImConnection, "Connection"))]
 pub static ImConnection: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImConnection",
+    label: "Connection",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1595,8 +2194,14 @@ pub static ImConnection: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M10 9c1.654 0 3.154 0.673 4.241 1.759l-1.414 1.414c-0.724-0.724-1.724-1.173-2.827-1.173s-2.103 0.449-2.827 1.173l-1.414-1.414c1.086-1.086 2.586-1.759 4.241-1.759zM2.929 7.929c1.889-1.889 4.4-2.929 7.071-2.929s5.182 1.040 7.071 2.929l-1.414 1.414c-1.511-1.511-3.52-2.343-5.657-2.343s-4.146 0.832-5.657 2.343l-1.414-1.414zM15.45 2.101c1.667 0.705 3.164 1.715 4.45 3v0l-1.414 1.414c-2.267-2.266-5.28-3.515-8.485-3.515s-6.219 1.248-8.485 3.515l-1.414-1.414c1.285-1.285 2.783-2.295 4.45-3 1.727-0.73 3.56-1.101 5.45-1.101s3.723 0.37 5.45 1.101zM9 14c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImContrast, "Contrast"))]
 pub static ImContrast: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImContrast",
+    label: "Contrast",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1611,8 +2216,14 @@ pub static ImContrast: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM2 8c0-3.314 2.686-6 6-6v12c-3.314 0-6-2.686-6-6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCool, "Cool"))]
 pub static ImCool: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCool",
+    label: "Cool",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1627,8 +2238,14 @@ pub static ImCool: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c4.418 0 8-3.582 8-8s-3.582-8-8-8-8 3.582-8 8 3.582 8 8 8zM8 1.5c3.59 0 6.5 2.91 6.5 6.5s-2.91 6.5-6.5 6.5-6.5-2.91-6.5-6.5 2.91-6.5 6.5-6.5zM12.5 4c0.275 0 0.5 0.225 0.5 0.5v1.5c0 0.55-0.45 1-1 1h-2c-0.55 0-1-0.45-1-1h-2c0 0.55-0.45 1-1 1h-2c-0.55 0-1-0.45-1-1v-1.5c0-0.275 0.225-0.5 0.5-0.5h3c0.275 0 0.5 0.225 0.5 0.5v0.5h2v-0.5c0-0.275 0.225-0.5 0.5-0.5h3zM8 12c1.456 0 2.731-0.778 3.43-1.942l0.857 0.515c-0.874 1.454-2.467 2.427-4.288 2.427-0.757 0-1.475-0.169-2.118-0.47l0.518-0.864c0.49 0.214 1.031 0.334 1.6 0.334z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCool2, "Cool 2"))]
 pub static ImCool2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCool2",
+    label: "Cool 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1643,8 +2260,14 @@ pub static ImCool2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8c4.418 0 8-3.582 8-8s-3.582-8-8-8zM8 13c-0.757 0-1.475-0.169-2.118-0.47l0.518-0.864c0.49 0.214 1.031 0.334 1.6 0.334 1.456 0 2.731-0.778 3.43-1.942l0.858 0.515c-0.874 1.454-2.467 2.427-4.288 2.427zM13 6c0 0.55-0.45 1-1 1h-2c-0.55 0-1-0.45-1-1h-2c0 0.55-0.45 1-1 1h-2c-0.55 0-1-0.45-1-1v-1.5c0-0.275 0.225-0.5 0.5-0.5h3c0.275 0 0.5 0.225 0.5 0.5v0.5h2v-0.5c0-0.275 0.225-0.5 0.5-0.5h3c0.275 0 0.5 0.225 0.5 0.5v1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCopy, "Copy"))]
 pub static ImCopy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCopy",
+    label: "Copy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1659,8 +2282,14 @@ pub static ImCopy: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M10 4v-4h-7l-3 3v9h6v4h10v-12h-6zM3 1.414v1.586h-1.586l1.586-1.586zM1 11v-7h3v-3h5v3l-3 3v4h-5zM9 5.414v1.586h-1.586l1.586-1.586zM15 15h-8v-7h3v-3h5v10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCreditCard, "Credit Card"))]
 pub static ImCreditCard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCreditCard",
+    label: "Credit Card",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1675,8 +2304,14 @@ pub static ImCreditCard: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 2h-13c-0.825 0-1.5 0.675-1.5 1.5v9c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-9c0-0.825-0.675-1.5-1.5-1.5zM1.5 3h13c0.271 0 0.5 0.229 0.5 0.5v1.5h-14v-1.5c0-0.271 0.229-0.5 0.5-0.5zM14.5 13h-13c-0.271 0-0.5-0.229-0.5-0.5v-4.5h14v4.5c0 0.271-0.229 0.5-0.5 0.5zM2 10h1v2h-1zM4 10h1v2h-1zM6 10h1v2h-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCrop, "Crop"))]
 pub static ImCrop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCrop",
+    label: "Crop",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1691,8 +2326,14 @@ pub static ImCrop: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13 4l3-3-1-1-3 3h-7v-3h-2v3h-3v2h3v8h8v3h2v-3h3v-2h-3v-7zM5 5h5l-5 5v-5zM6 11l5-5v5h-5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCross, "Cross"))]
 pub static ImCross: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCross",
+    label: "Cross",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1707,8 +2348,14 @@ pub static ImCross: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.854 12.854c-0-0-0-0-0-0l-4.854-4.854 4.854-4.854c0-0 0-0 0-0 0.052-0.052 0.090-0.113 0.114-0.178 0.066-0.178 0.028-0.386-0.114-0.529l-2.293-2.293c-0.143-0.143-0.351-0.181-0.529-0.114-0.065 0.024-0.126 0.062-0.178 0.114 0 0-0 0-0 0l-4.854 4.854-4.854-4.854c-0-0-0-0-0-0-0.052-0.052-0.113-0.090-0.178-0.114-0.178-0.066-0.386-0.029-0.529 0.114l-2.293 2.293c-0.143 0.143-0.181 0.351-0.114 0.529 0.024 0.065 0.062 0.126 0.114 0.178 0 0 0 0 0 0l4.854 4.854-4.854 4.854c-0 0-0 0-0 0-0.052 0.052-0.090 0.113-0.114 0.178-0.066 0.178-0.029 0.386 0.114 0.529l2.293 2.293c0.143 0.143 0.351 0.181 0.529 0.114 0.065-0.024 0.126-0.062 0.178-0.114 0-0 0-0 0-0l4.854-4.854 4.854 4.854c0 0 0 0 0 0 0.052 0.052 0.113 0.090 0.178 0.114 0.178 0.066 0.386 0.029 0.529-0.114l2.293-2.293c0.143-0.143 0.181-0.351 0.114-0.529-0.024-0.065-0.062-0.126-0.114-0.178z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCrying, "Crying"))]
 pub static ImCrying: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCrying",
+    label: "Crying",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1730,8 +2377,14 @@ pub static ImCrying: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4.5 12c-0.276 0-0.5-0.224-0.5-0.5v-1c0-0.276 0.224-0.5 0.5-0.5s0.5 0.224 0.5 0.5v1c0 0.276-0.224 0.5-0.5 0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCrying2, "Crying 2"))]
 pub static ImCrying2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCrying2",
+    label: "Crying 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1746,8 +2399,14 @@ pub static ImCrying2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM5 11.5c0 0.276-0.224 0.5-0.5 0.5s-0.5-0.224-0.5-0.5v-1c0-0.276 0.224-0.5 0.5-0.5s0.5 0.224 0.5 0.5v1zM5 8.5c0 0.276-0.224 0.5-0.5 0.5s-0.5-0.224-0.5-0.5v-1c0-0.276 0.224-0.5 0.5-0.5s0.5 0.224 0.5 0.5v1zM5.5 6h-2c-0.276 0-0.5-0.224-0.5-0.5s0.224-0.5 0.5-0.5h2c0.276 0 0.5 0.224 0.5 0.5s-0.224 0.5-0.5 0.5zM9.854 13.229c-0.098 0.098-0.226 0.146-0.354 0.146s-0.256-0.049-0.354-0.146c-0.072-0.072-0.46-0.229-1.146-0.229s-1.075 0.157-1.146 0.229c-0.195 0.195-0.512 0.195-0.707 0s-0.195-0.512 0-0.707c0.471-0.471 1.453-0.521 1.854-0.521s1.383 0.051 1.854 0.521c0.195 0.195 0.195 0.512 0 0.707zM12 11.5c0 0.276-0.224 0.5-0.5 0.5s-0.5-0.224-0.5-0.5v-1c0-0.276 0.224-0.5 0.5-0.5s0.5 0.224 0.5 0.5v1zM12 8.5c0 0.276-0.224 0.5-0.5 0.5s-0.5-0.224-0.5-0.5v-1c0-0.276 0.224-0.5 0.5-0.5s0.5 0.224 0.5 0.5v1zM12.5 6h-2c-0.276 0-0.5-0.224-0.5-0.5s0.224-0.5 0.5-0.5h2c0.276 0 0.5 0.224 0.5 0.5s-0.224 0.5-0.5 0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCss3, "CSS 3"))]
 pub static ImCss3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCss3",
+    label: "CSS 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1762,8 +2421,14 @@ pub static ImCss3: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M2.381 0.758l-0.537 2.686h10.934l-0.342 1.735h-10.94l-0.53 2.686h10.933l-0.61 3.063-4.406 1.46-3.819-1.46 0.261-1.329h-2.686l-0.639 3.224 6.316 2.417 7.281-2.417 2.403-12.066z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImCtrl, "Ctrl"))]
 pub static ImCtrl: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImCtrl",
+    label: "Ctrl",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1778,8 +2443,14 @@ pub static ImCtrl: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11.5 7c-0.139 0-0.278-0.058-0.377-0.171l-3.124-3.57-3.124 3.57c-0.182 0.208-0.498 0.229-0.706 0.047s-0.229-0.498-0.047-0.706l3.5-4c0.095-0.108 0.232-0.171 0.376-0.171s0.281 0.062 0.376 0.171l3.5 4c0.182 0.208 0.161 0.524-0.047 0.706-0.095 0.083-0.212 0.124-0.329 0.124z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDatabase, "Database"))]
 pub static ImDatabase: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDatabase",
+    label: "Database",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1796,8 +2467,14 @@ pub static ImDatabase: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 13c-4.418 0-8-1.119-8-2.5v3c0 1.381 3.582 2.5 8 2.5s8-1.119 8-2.5v-3c0 1.381-3.582 2.5-8 2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDelicious, "Delicious"))]
 pub static ImDelicious: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDelicious",
+    label: "Delicious",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1812,8 +2489,14 @@ pub static ImDelicious: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 0v16h16v-16h-16zM8 15v-7h-7v-7h7v7h7v7h-7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDeviantart, "Deviantart"))]
 pub static ImDeviantart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDeviantart",
+    label: "Deviantart",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1828,8 +2511,14 @@ pub static ImDeviantart: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12.953 2.909v-2.909h-2.909l-0.291 0.294-1.375 2.616-0.431 0.291h-4.9v3.994h2.694l0.241 0.291-2.934 5.606v2.909h2.909l0.291-0.294 1.375-2.616 0.431-0.291h4.9v-3.994h-2.694l-0.241-0.294z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDiamonds, "Diamonds"))]
 pub static ImDiamonds: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDiamonds",
+    label: "Diamonds",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1844,8 +2533,14 @@ pub static ImDiamonds: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0l-5 8 5 8 5-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDice, "Dice"))]
 pub static ImDice: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDice",
+    label: "Dice",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1860,8 +2555,14 @@ pub static ImDice: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.5 3h-8c-1.375 0-2.5 1.125-2.5 2.5v8c0 1.375 1.125 2.5 2.5 2.5h8c1.375 0 2.5-1.125 2.5-2.5v-8c0-1.375-1.125-2.5-2.5-2.5zM6.5 14c-0.828 0-1.5-0.672-1.5-1.5s0.672-1.5 1.5-1.5 1.5 0.672 1.5 1.5-0.672 1.5-1.5 1.5zM6.5 8c-0.828 0-1.5-0.672-1.5-1.5s0.672-1.5 1.5-1.5 1.5 0.672 1.5 1.5-0.672 1.5-1.5 1.5zM9.5 11c-0.828 0-1.5-0.672-1.5-1.5s0.672-1.5 1.5-1.5 1.5 0.672 1.5 1.5-0.672 1.5-1.5 1.5zM12.5 14c-0.828 0-1.5-0.672-1.5-1.5s0.672-1.5 1.5-1.5 1.5 0.672 1.5 1.5-0.672 1.5-1.5 1.5zM12.5 8c-0.828 0-1.5-0.672-1.5-1.5s0.672-1.5 1.5-1.5 1.5 0.672 1.5 1.5-0.672 1.5-1.5 1.5zM12.949 2c-0.233-1.138-1.245-2-2.449-2h-8c-1.375 0-2.5 1.125-2.5 2.5v8c0 1.204 0.862 2.216 2 2.449v-9.949c0-0.55 0.45-1 1-1h9.949z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDisplay, "Display"))]
 pub static ImDisplay: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDisplay",
+    label: "Display",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1876,8 +2577,14 @@ pub static ImDisplay: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 1v10h16v-10h-16zM15 10h-14v-8h14v8zM10.5 12h-5l-0.5 2-1 1h8l-1-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDownload, "Download"))]
 pub static ImDownload: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDownload",
+    label: "Download",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1892,8 +2599,14 @@ pub static ImDownload: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 9l4-4h-3v-4h-2v4h-3zM11.636 7.364l-1.121 1.121 4.064 1.515-6.579 2.453-6.579-2.453 4.064-1.515-1.121-1.121-4.364 1.636v4l8 3 8-3v-4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDownload2, "Download 2"))]
 pub static ImDownload2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDownload2",
+    label: "Download 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1908,8 +2621,14 @@ pub static ImDownload2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14 8h-2.5l-3.5 3.5-3.5-3.5h-2.5l-2 4v1h16v-1l-2-4zM0 14h16v1h-16v-1zM9 5v-4h-2v4h-3.5l4.5 4.5 4.5-4.5h-3.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDownload3, "Download 3"))]
 pub static ImDownload3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDownload3",
+    label: "Download 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1924,8 +2643,14 @@ pub static ImDownload3: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11.5 7l-4 4-4-4h2.5v-6h3v6zM7.5 11h-7.5v4h15v-4h-7.5zM14 13h-2v-1h2v1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDrawer, "Drawer"))]
 pub static ImDrawer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDrawer",
+    label: "Drawer",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1942,8 +2667,14 @@ pub static ImDrawer: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12.5 10h-9c-0.276 0-0.5-0.224-0.5-0.5s0.224-0.5 0.5-0.5h9c0.276 0 0.5 0.224 0.5 0.5s-0.224 0.5-0.5 0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDrawer2, "Drawer 2"))]
 pub static ImDrawer2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDrawer2",
+    label: "Drawer 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1958,8 +2689,14 @@ pub static ImDrawer2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.89 10.188l-4-5c-0.095-0.119-0.239-0.188-0.39-0.188h-7c-0.152 0-0.296 0.069-0.39 0.188l-4 5c-0.071 0.089-0.11 0.199-0.11 0.312v4.5c0 0.552 0.448 1 1 1h14c0.552 0 1-0.448 1-1v-4.5c0-0.114-0.039-0.224-0.11-0.312zM15 11h-3.5l-2 2h-3l-2-2h-3.5v-0.325l3.74-4.675h6.519l3.74 4.675v0.325z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDribbble, "Dribbble"))]
 pub static ImDribbble: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDribbble",
+    label: "Dribbble",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1974,8 +2711,14 @@ pub static ImDribbble: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c-4.412 0-8-3.588-8-8s3.587-8 8-8c4.412 0 8 3.587 8 8s-3.588 8-8 8v0zM14.747 9.094c-0.234-0.075-2.116-0.634-4.256-0.291 0.894 2.456 1.256 4.456 1.328 4.872 1.531-1.037 2.625-2.678 2.928-4.581v0zM10.669 14.3c-0.103-0.6-0.497-2.688-1.456-5.181-0.016 0.006-0.031 0.009-0.044 0.016-3.856 1.344-5.241 4.016-5.362 4.266 1.159 0.903 2.616 1.444 4.194 1.444 0.947 0 1.85-0.194 2.669-0.544v0zM2.922 12.578c0.156-0.266 2.031-3.369 5.553-4.509 0.088-0.028 0.178-0.056 0.269-0.081-0.172-0.388-0.359-0.778-0.553-1.159-3.409 1.022-6.722 0.978-7.022 0.975-0.003 0.069-0.003 0.138-0.003 0.209 0 1.753 0.666 3.356 1.756 4.566v0zM1.313 6.609c0.306 0.003 3.122 0.016 6.319-0.831-1.131-2.013-2.353-3.706-2.534-3.953-1.913 0.903-3.344 2.666-3.784 4.784v0zM6.4 1.366c0.188 0.253 1.431 1.944 2.55 4 2.431-0.909 3.459-2.294 3.581-2.469-1.206-1.072-2.794-1.722-4.531-1.722-0.55 0.003-1.088 0.069-1.6 0.191v0zM13.291 3.691c-0.144 0.194-1.291 1.663-3.816 2.694 0.159 0.325 0.313 0.656 0.453 0.991 0.050 0.119 0.1 0.234 0.147 0.353 2.275-0.284 4.534 0.172 4.759 0.219-0.016-1.612-0.594-3.094-1.544-4.256v0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDrive, "Drive"))]
 pub static ImDrive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDrive",
+    label: "Drive",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1990,8 +2733,14 @@ pub static ImDrive: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M3 14h10c1.657 0 3-1.343 3-3h-16c0 1.657 1.343 3 3 3zM13 12h1v1h-1v-1zM15 2h-14l-1 8h16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDropbox, "Dropbox"))]
 pub static ImDropbox: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDropbox",
+    label: "Dropbox",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2010,8 +2759,14 @@ pub static ImDropbox: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11.377 13.212l-3.377-2.895-3.377 2.895-2.123-1.179v1.467l5.5 2.5 5.5-2.5v-1.467z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImDroplet, "Droplet"))]
 pub static ImDroplet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImDroplet",
+    label: "Droplet",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2026,8 +2781,14 @@ pub static ImDroplet: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.51 7.393c-1.027-2.866-3.205-5.44-5.51-7.393-2.305 1.953-4.482 4.527-5.51 7.393-0.635 1.772-0.698 3.696 0.197 5.397 1.029 1.955 3.104 3.21 5.313 3.21s4.284-1.255 5.313-3.21c0.895-1.701 0.832-3.624 0.197-5.397zM11.543 11.859c-0.684 1.301-2.075 2.141-3.543 2.141-0.861 0-1.696-0.29-2.377-0.791 0.207 0.027 0.416 0.041 0.627 0.041 1.835 0 3.573-1.050 4.428-2.676 0.701-1.333 0.64-2.716 0.373-3.818 0.227 0.44 0.42 0.878 0.576 1.311 0.353 0.985 0.625 2.443-0.084 3.791z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEarth, "Earth"))]
 pub static ImEarth: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEarth",
+    label: "Earth",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2042,8 +2803,14 @@ pub static ImEarth: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM8 15c-0.984 0-1.92-0.203-2.769-0.57l3.643-4.098c0.081-0.092 0.126-0.21 0.126-0.332v-1.5c0-0.276-0.224-0.5-0.5-0.5-1.765 0-3.628-1.835-3.646-1.854-0.094-0.094-0.221-0.146-0.354-0.146h-2c-0.276 0-0.5 0.224-0.5 0.5v3c0 0.189 0.107 0.363 0.276 0.447l1.724 0.862v2.936c-1.813-1.265-3-3.366-3-5.745 0-1.074 0.242-2.091 0.674-3h1.826c0.133 0 0.26-0.053 0.354-0.146l2-2c0.094-0.094 0.146-0.221 0.146-0.354v-1.21c0.634-0.189 1.305-0.29 2-0.29 1.1 0 2.141 0.254 3.067 0.706-0.065 0.055-0.128 0.112-0.188 0.172-0.567 0.567-0.879 1.32-0.879 2.121s0.312 1.555 0.879 2.121c0.569 0.569 1.332 0.879 2.119 0.879 0.049 0 0.099-0.001 0.149-0.004 0.216 0.809 0.605 2.917-0.131 5.818-0.007 0.027-0.011 0.055-0.013 0.082-1.271 1.298-3.042 2.104-5.002 2.104z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEdge, "Edge"))]
 pub static ImEdge: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEdge",
+    label: "Edge",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2058,8 +2825,14 @@ pub static ImEdge: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0.241 7.103c0.469-3.7 2.994-7.056 7.519-7.103 2.731 0.053 4.978 1.291 6.316 3.65 0.672 1.231 0.881 2.525 0.925 3.953v1.678h-10.041c0.047 4.141 6.094 4 8.697 2.175v3.372c-1.525 0.916-4.984 1.734-7.662 0.681-2.281-0.856-3.906-3.244-3.897-5.541-0.075-2.978 1.481-4.95 3.897-6.072-0.513 0.634-0.903 1.334-1.106 2.547h5.669c0 0 0.331-3.388-3.209-3.388-3.338 0.116-5.744 2.056-7.106 4.047v0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEject, "Eject"))]
 pub static ImEject: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEject",
+    label: "Eject",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2074,8 +2847,14 @@ pub static ImEject: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 12h16v2h-16zM8 2l8 8h-16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEllo, "Ello"))]
 pub static ImEllo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEllo",
+    label: "Ello",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2090,8 +2869,14 @@ pub static ImEllo: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM12.885 9.212c-0.575 2.23-2.584 3.788-4.885 3.788s-4.31-1.558-4.885-3.788c-0.097-0.377 0.131-0.764 0.508-0.861 0.058-0.015 0.118-0.023 0.177-0.023 0.322 0 0.604 0.218 0.684 0.531 0.414 1.605 1.86 2.727 3.516 2.727s3.102-1.121 3.516-2.727c0.081-0.313 0.362-0.531 0.684-0.531 0.060 0 0.12 0.008 0.178 0.023 0.183 0.047 0.336 0.163 0.432 0.326s0.123 0.353 0.075 0.536z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEmbed, "Embed"))]
 pub static ImEmbed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEmbed",
+    label: "Embed",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2107,8 +2892,14 @@ pub static ImEmbed: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 4.5l-1.5-1.5-5 5 5 5 1.5-1.5-3.5-3.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEmbed2, "Embed 2"))]
 pub static ImEmbed2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEmbed2",
+    label: "Embed 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2125,8 +2916,14 @@ pub static ImEmbed2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10.958 2.352l1.085 0.296-3 11-1.085-0.296 3-11z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEnlarge, "Enlarge"))]
 pub static ImEnlarge: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEnlarge",
+    label: "Enlarge",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2144,8 +2941,14 @@ pub static ImEnlarge: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M0 0v6.5l2.5-2.5 3 3 1.5-1.5-3-3 2.5-2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEnlarge2, "Enlarge 2"))]
 pub static ImEnlarge2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEnlarge2",
+    label: "Enlarge 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2160,8 +2963,14 @@ pub static ImEnlarge2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 0v6.5l-2.5-2.5-3 3-1.5-1.5 3-3-2.5-2.5zM7 10.5l-3 3 2.5 2.5h-6.5v-6.5l2.5 2.5 3-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEnter, "Enter"))]
 pub static ImEnter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEnter",
+    label: "Enter",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2176,8 +2985,14 @@ pub static ImEnter: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6 8h-5v-2h5v-2l3 3-3 3zM16 0v13l-6 3v-3h-6v-4h1v3h5v-9l4-2h-9v4h-1v-5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEnvelop, "Envelop"))]
 pub static ImEnvelop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEnvelop",
+    label: "Envelop",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2192,8 +3007,14 @@ pub static ImEnvelop: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 2h-13c-0.825 0-1.5 0.675-1.5 1.5v10c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-10c0-0.825-0.675-1.5-1.5-1.5zM6.23 8.6l-4.23 3.295v-7.838l4.23 4.543zM2.756 4h10.488l-5.244 3.938-5.244-3.938zM6.395 8.777l1.605 1.723 1.605-1.723 3.29 4.223h-9.79l3.29-4.223zM9.77 8.6l4.23-4.543v7.838l-4.23-3.295z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEqualizer, "Equalizer"))]
 pub static ImEqualizer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEqualizer",
+    label: "Equalizer",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2208,8 +3029,14 @@ pub static ImEqualizer: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7 2v-0.25c0-0.413-0.338-0.75-0.75-0.75h-2.5c-0.413 0-0.75 0.337-0.75 0.75v0.25h-3v2h3v0.25c0 0.412 0.337 0.75 0.75 0.75h2.5c0.412 0 0.75-0.338 0.75-0.75v-0.25h9v-2h-9zM4 4v-2h2v2h-2zM13 6.75c0-0.412-0.338-0.75-0.75-0.75h-2.5c-0.412 0-0.75 0.338-0.75 0.75v0.25h-9v2h9v0.25c0 0.412 0.338 0.75 0.75 0.75h2.5c0.412 0 0.75-0.338 0.75-0.75v-0.25h3v-2h-3v-0.25zM10 9v-2h2v2h-2zM7 11.75c0-0.412-0.338-0.75-0.75-0.75h-2.5c-0.413 0-0.75 0.338-0.75 0.75v0.25h-3v2h3v0.25c0 0.412 0.337 0.75 0.75 0.75h2.5c0.412 0 0.75-0.338 0.75-0.75v-0.25h9v-2h-9v-0.25zM4 14v-2h2v2h-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEqualizer2, "Equalizer 2"))]
 pub static ImEqualizer2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEqualizer2",
+    label: "Equalizer 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2224,8 +3051,14 @@ pub static ImEqualizer2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14 7h0.25c0.412 0 0.75-0.338 0.75-0.75v-2.5c0-0.413-0.338-0.75-0.75-0.75h-0.25v-3h-2v3h-0.25c-0.412 0-0.75 0.337-0.75 0.75v2.5c0 0.412 0.338 0.75 0.75 0.75h0.25v9h2v-9zM12 4h2v2h-2v-2zM9.25 13c0.412 0 0.75-0.338 0.75-0.75v-2.5c0-0.412-0.338-0.75-0.75-0.75h-0.25v-9h-2v9h-0.25c-0.412 0-0.75 0.338-0.75 0.75v2.5c0 0.412 0.338 0.75 0.75 0.75h0.25v3h2v-3h0.25zM7 10h2v2h-2v-2zM4.25 7c0.412 0 0.75-0.338 0.75-0.75v-2.5c0-0.413-0.338-0.75-0.75-0.75h-0.25v-3h-2v3h-0.25c-0.413 0-0.75 0.337-0.75 0.75v2.5c0 0.412 0.337 0.75 0.75 0.75h0.25v9h2v-9h0.25zM2 4h2v2h-2v-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEvil, "Evil"))]
 pub static ImEvil: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEvil",
+    label: "Evil",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2240,8 +3073,14 @@ pub static ImEvil: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M10 7c-0.552 0-1-0.448-1-1 0-0.018 0.001-0.036 0.002-0.054 0.032-0.741 0.706-1.234 1.275-1.518 0.543-0.271 1.080-0.407 1.102-0.413 0.268-0.067 0.539 0.096 0.606 0.364s-0.096 0.539-0.364 0.606c-0.275 0.070-0.602 0.189-0.89 0.334 0.166 0.179 0.268 0.418 0.268 0.681 0 0.552-0.448 1-1 1zM4.379 4.985c-0.268-0.067-0.431-0.338-0.364-0.606s0.338-0.431 0.606-0.364c0.023 0.006 0.559 0.141 1.102 0.413 0.568 0.284 1.243 0.776 1.275 1.518 0.001 0.018 0.002 0.036 0.002 0.054 0 0.552-0.448 1-1 1s-1-0.448-1-1c0-0.263 0.102-0.503 0.268-0.681-0.288-0.144-0.614-0.264-0.89-0.334zM8 11.5c1.274 0 2.389-0.681 3.002-1.699l1.286 0.772c-0.874 1.454-2.467 2.427-4.288 2.427s-3.413-0.973-4.288-2.427l1.286-0.772c0.612 1.018 1.727 1.699 3.002 1.699zM16 1c0-0.711-0.149-1.387-0.416-2-0.525 1.201-1.507 2.155-2.726 2.643-1.347-1.031-3.030-1.643-4.857-1.643s-3.51 0.613-4.857 1.643c-1.22-0.488-2.202-1.443-2.726-2.643-0.268 0.613-0.416 1.289-0.416 2 0 1.15 0.388 2.208 1.040 3.053-0.662 1.165-1.040 2.512-1.040 3.947 0 4.418 3.582 8 8 8s8-3.582 8-8c0-1.436-0.378-2.783-1.040-3.947 0.652-0.845 1.040-1.903 1.040-3.053zM8 14.5c-3.59 0-6.5-2.91-6.5-6.5s2.91-6.5 6.5-6.5 6.5 2.91 6.5 6.5-2.91 6.5-6.5 6.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEvil2, "Evil 2"))]
 pub static ImEvil2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEvil2",
+    label: "Evil 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2256,8 +3095,14 @@ pub static ImEvil2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 1c0-0.711-0.149-1.387-0.416-2-0.525 1.201-1.507 2.155-2.726 2.643-1.347-1.031-3.030-1.643-4.857-1.643s-3.51 0.613-4.857 1.643c-1.22-0.488-2.202-1.443-2.726-2.643-0.268 0.613-0.416 1.289-0.416 2 0 1.15 0.388 2.208 1.040 3.053-0.662 1.165-1.040 2.512-1.040 3.947 0 4.418 3.582 8 8 8s8-3.582 8-8c0-1.436-0.378-2.783-1.040-3.947 0.652-0.845 1.040-1.903 1.040-3.053zM9.001 5.946c0.032-0.741 0.706-1.234 1.275-1.518 0.543-0.271 1.080-0.407 1.102-0.413 0.268-0.067 0.539 0.096 0.606 0.364s-0.096 0.539-0.364 0.606c-0.275 0.070-0.602 0.189-0.89 0.334 0.166 0.179 0.268 0.418 0.268 0.681 0 0.552-0.448 1-1 1s-1-0.448-1-1c0-0.018 0.001-0.036 0.002-0.054zM4.015 4.379c0.067-0.268 0.338-0.431 0.606-0.364 0.023 0.006 0.559 0.141 1.102 0.413 0.568 0.284 1.243 0.776 1.275 1.518 0.001 0.018 0.002 0.036 0.002 0.054 0 0.552-0.448 1-1 1s-1-0.448-1-1c0-0.263 0.102-0.503 0.268-0.681-0.288-0.144-0.614-0.264-0.89-0.334-0.268-0.067-0.431-0.338-0.364-0.606zM8 13c-1.82 0-3.413-0.973-4.288-2.427l1.286-0.772c0.612 1.018 1.727 1.699 3.002 1.699s2.389-0.681 3.002-1.699l1.286 0.772c-0.874 1.454-2.467 2.427-4.288 2.427z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImExit, "Exit"))]
 pub static ImExit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImExit",
+    label: "Exit",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2272,8 +3117,14 @@ pub static ImExit: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 10v-2h-5v-2h5v-2l3 3zM11 9v4h-5v3l-6-3v-13h11v5h-1v-4h-8l4 2v9h4v-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEye, "Eye"))]
 pub static ImEye: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEye",
+    label: "Eye",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2288,8 +3139,14 @@ pub static ImEye: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 3c-3.489 0-6.514 2.032-8 5 1.486 2.968 4.511 5 8 5s6.514-2.032 8-5c-1.486-2.968-4.511-5-8-5zM11.945 5.652c0.94 0.6 1.737 1.403 2.335 2.348-0.598 0.946-1.395 1.749-2.335 2.348-1.181 0.753-2.545 1.152-3.944 1.152s-2.763-0.398-3.945-1.152c-0.94-0.6-1.737-1.403-2.335-2.348 0.598-0.946 1.395-1.749 2.335-2.348 0.061-0.039 0.123-0.077 0.185-0.114-0.156 0.427-0.241 0.888-0.241 1.369 0 2.209 1.791 4 4 4s4-1.791 4-4c0-0.481-0.085-0.942-0.241-1.369 0.062 0.037 0.124 0.075 0.185 0.114v0zM8 6.5c0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5 0.672-1.5 1.5-1.5 1.5 0.672 1.5 1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEyeBlocked, "Eye Blocked"))]
 pub static ImEyeBlocked: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEyeBlocked",
+    label: "Eye Blocked",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2306,8 +3163,14 @@ pub static ImEyeBlocked: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12.969 4.531l-1.084 1.084c0.020 0.012 0.040 0.024 0.059 0.037 0.94 0.6 1.737 1.403 2.335 2.348-0.598 0.946-1.395 1.749-2.335 2.348-1.181 0.753-2.545 1.152-3.944 1.152-0.604 0-1.202-0.074-1.781-0.219l-1.201 1.201c0.933 0.335 1.937 0.518 2.982 0.518 3.489 0 6.514-2.032 8-5-0.703-1.405-1.752-2.6-3.031-3.469z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEyeMinus, "Eye Minus"))]
 pub static ImEyeMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEyeMinus",
+    label: "Eye Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2323,8 +3186,14 @@ pub static ImEyeMinus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13.599 5h-4.599v-1.944c-0.328-0.037-0.662-0.056-1-0.056-3.489 0-6.514 2.032-8 5 1.486 2.968 4.511 5 8 5s6.514-2.032 8-5c-0.584-1.167-1.407-2.189-2.401-3zM6.5 5c0.828 0 1.5 0.672 1.5 1.5s-0.672 1.5-1.5 1.5-1.5-0.672-1.5-1.5 0.672-1.5 1.5-1.5zM11.944 10.348c-1.181 0.753-2.545 1.152-3.944 1.152s-2.763-0.398-3.945-1.152c-0.94-0.6-1.736-1.403-2.335-2.348 0.598-0.946 1.395-1.749 2.335-2.348 0.061-0.039 0.123-0.077 0.185-0.114-0.156 0.427-0.241 0.888-0.241 1.369 0 2.209 1.791 4 4 4s4-1.791 4-4c0-0.481-0.085-0.942-0.241-1.369 0.062 0.037 0.124 0.075 0.185 0.114 0.94 0.6 1.737 1.403 2.335 2.348-0.598 0.946-1.395 1.749-2.335 2.348z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEyePlus, "Eye Plus"))]
 pub static ImEyePlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEyePlus",
+    label: "Eye Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2340,8 +3209,14 @@ pub static ImEyePlus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13.498 6.969c0.288 0.32 0.55 0.665 0.782 1.031-0.598 0.946-1.395 1.749-2.335 2.348-1.181 0.753-2.545 1.152-3.944 1.152s-2.763-0.398-3.945-1.152c-0.94-0.6-1.736-1.403-2.335-2.348 0.598-0.946 1.395-1.749 2.335-2.348 0.061-0.039 0.123-0.077 0.185-0.114-0.156 0.427-0.241 0.888-0.241 1.369 0 2.209 1.791 4 4 4s4-1.791 4-4c0-0.011-0-0.022-0-0.032-1.708-0.44-2.973-1.979-2.999-3.817-0.329-0.037-0.662-0.057-1.001-0.057-3.489 0-6.514 2.032-8 5 1.486 2.968 4.511 5 8 5s6.514-2.032 8-5c-0.276-0.55-0.604-1.069-0.979-1.548-0.457 0.268-0.973 0.449-1.523 0.517zM6.5 5c0.828 0 1.5 0.672 1.5 1.5s-0.672 1.5-1.5 1.5-1.5-0.672-1.5-1.5 0.672-1.5 1.5-1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImEyedropper, "Eyedropper"))]
 pub static ImEyedropper: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImEyedropper",
+    label: "Eyedropper",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2356,8 +3231,14 @@ pub static ImEyedropper: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.414 0.586c-0.781-0.781-2.047-0.781-2.828 0l-2.689 2.689-1.896-1.896-2.121 2.121 1.663 1.663-7.377 7.377c-0.126 0.126-0.179 0.296-0.161 0.46h-0.004v2.5c0 0.276 0.224 0.5 0.5 0.5h2.5c0 0 0.042 0 0.063 0 0.144 0 0.288-0.055 0.398-0.165l7.377-7.377 1.663 1.663 2.121-2.121-1.896-1.896 2.689-2.689c0.781-0.781 0.781-2.047 0-2.828zM2.705 15h-1.705v-1.705l7.337-7.337 1.704 1.704-7.337 7.337z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFacebook, "Facebook"))]
 pub static ImFacebook: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFacebook",
+    label: "Facebook",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2372,8 +3253,14 @@ pub static ImFacebook: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9.5 3h2.5v-3h-2.5c-1.93 0-3.5 1.57-3.5 3.5v1.5h-2v3h2v8h3v-8h2.5l0.5-3h-3v-1.5c0-0.271 0.229-0.5 0.5-0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFacebook2, "Facebook 2"))]
 pub static ImFacebook2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFacebook2",
+    label: "Facebook 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2388,8 +3275,14 @@ pub static ImFacebook2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h6.5v-7h-2v-2h2v-1c0-1.653 1.347-3 3-3h2v2h-2c-0.55 0-1 0.45-1 1v1h3l-0.5 2h-2.5v7h4.5c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFeed, "Feed"))]
 pub static ImFeed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFeed",
+    label: "Feed",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2404,8 +3297,14 @@ pub static ImFeed: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6 8c0-1.105 0.895-2 2-2s2 0.895 2 2c0 1.105-0.895 2-2 2s-2-0.895-2-2zM10.38 3.602c1.56 0.846 2.62 2.498 2.62 4.398s-1.059 3.552-2.62 4.398c0.689-1.096 1.12-2.66 1.12-4.398s-0.431-3.302-1.12-4.398zM4.5 8c0 1.738 0.431 3.302 1.12 4.398-1.56-0.846-2.62-2.498-2.62-4.398s1.059-3.552 2.62-4.398c-0.689 1.096-1.12 2.66-1.12 4.398zM1.5 8c0 2.686 0.85 5.097 2.198 6.746-2.223-1.421-3.698-3.911-3.698-6.746s1.474-5.325 3.698-6.746c-1.348 1.649-2.198 4.060-2.198 6.746zM12.302 1.254c2.223 1.421 3.698 3.911 3.698 6.746s-1.474 5.325-3.698 6.746c1.348-1.649 2.198-4.060 2.198-6.746s-0.85-5.097-2.198-6.746z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFileEmpty, "File Empty"))]
 pub static ImFileEmpty: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFileEmpty",
+    label: "File Empty",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2420,8 +3319,14 @@ pub static ImFileEmpty: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.341 3.579c-0.347-0.473-0.831-1.027-1.362-1.558s-1.085-1.015-1.558-1.362c-0.806-0.591-1.197-0.659-1.421-0.659h-7.75c-0.689 0-1.25 0.561-1.25 1.25v13.5c0 0.689 0.561 1.25 1.25 1.25h11.5c0.689 0 1.25-0.561 1.25-1.25v-9.75c0-0.224-0.068-0.615-0.659-1.421zM12.271 2.729c0.48 0.48 0.856 0.912 1.134 1.271h-2.406v-2.405c0.359 0.278 0.792 0.654 1.271 1.134zM14 14.75c0 0.136-0.114 0.25-0.25 0.25h-11.5c-0.135 0-0.25-0.114-0.25-0.25v-13.5c0-0.135 0.115-0.25 0.25-0.25 0 0 7.749-0 7.75 0v3.5c0 0.276 0.224 0.5 0.5 0.5h3.5v9.75z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFileExcel, "File Excel"))]
 pub static ImFileExcel: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFileExcel",
+    label: "File Excel",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2437,8 +3342,14 @@ pub static ImFileExcel: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.341 3.579c-0.347-0.473-0.831-1.027-1.362-1.558s-1.085-1.015-1.558-1.362c-0.806-0.591-1.197-0.659-1.421-0.659h-7.75c-0.689 0-1.25 0.561-1.25 1.25v13.5c0 0.689 0.561 1.25 1.25 1.25h11.5c0.689 0 1.25-0.561 1.25-1.25v-9.75c0-0.224-0.068-0.615-0.659-1.421v0zM12.271 2.729c0.48 0.48 0.856 0.912 1.134 1.271h-2.406v-2.405c0.359 0.278 0.792 0.654 1.271 1.134v0zM14 14.75c0 0.136-0.114 0.25-0.25 0.25h-11.5c-0.135 0-0.25-0.114-0.25-0.25v-13.5c0-0.135 0.115-0.25 0.25-0.25 0 0 7.749-0 7.75 0v3.5c0 0.276 0.224 0.5 0.5 0.5h3.5v9.75z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFileMusic, "File Music"))]
 pub static ImFileMusic: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFileMusic",
+    label: "File Music",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2454,8 +3365,14 @@ pub static ImFileMusic: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11.817 6.113c-0.116-0.095-0.268-0.133-0.415-0.104l-5 1c-0.234 0.047-0.402 0.252-0.402 0.49v3.701c-0.294-0.128-0.636-0.201-1-0.201-1.105 0-2 0.672-2 1.5s0.895 1.5 2 1.5 2-0.672 2-1.5v-3.59l4-0.8v2.091c-0.294-0.128-0.636-0.201-1-0.201-1.105 0-2 0.672-2 1.5s0.895 1.5 2 1.5 2-0.672 2-1.5v-5c0-0.15-0.067-0.292-0.183-0.387z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFileOpenoffice, "File Openoffice"))]
 pub static ImFileOpenoffice: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFileOpenoffice",
+    label: "File Openoffice",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2471,8 +3388,14 @@ pub static ImFileOpenoffice: &icondata_core::IconData = &icondata_core::IconData
 <path d="M14.341 3.579c-0.347-0.473-0.831-1.027-1.362-1.558s-1.085-1.015-1.558-1.362c-0.806-0.591-1.197-0.659-1.421-0.659h-7.75c-0.689 0-1.25 0.561-1.25 1.25v13.5c0 0.689 0.561 1.25 1.25 1.25h11.5c0.689 0 1.25-0.561 1.25-1.25v-9.75c0-0.224-0.068-0.615-0.659-1.421v0zM12.271 2.729c0.48 0.48 0.856 0.912 1.134 1.271h-2.406v-2.405c0.359 0.278 0.792 0.654 1.271 1.134v0zM14 14.75c0 0.136-0.114 0.25-0.25 0.25h-11.5c-0.135 0-0.25-0.114-0.25-0.25v-13.5c0-0.135 0.115-0.25 0.25-0.25 0 0 7.749-0 7.75 0v3.5c0 0.276 0.224 0.5 0.5 0.5h3.5v9.75z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFilePdf, "File PDF"))]
 pub static ImFilePdf: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFilePdf",
+    label: "File PDF",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2488,8 +3411,14 @@ pub static ImFilePdf: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.341 3.579c-0.347-0.473-0.831-1.027-1.362-1.558s-1.085-1.015-1.558-1.362c-0.806-0.591-1.197-0.659-1.421-0.659h-7.75c-0.689 0-1.25 0.561-1.25 1.25v13.5c0 0.689 0.561 1.25 1.25 1.25h11.5c0.689 0 1.25-0.561 1.25-1.25v-9.75c0-0.224-0.068-0.615-0.659-1.421v0zM12.271 2.729c0.48 0.48 0.856 0.912 1.134 1.271h-2.406v-2.405c0.359 0.278 0.792 0.654 1.271 1.134v0zM14 14.75c0 0.136-0.114 0.25-0.25 0.25h-11.5c-0.135 0-0.25-0.114-0.25-0.25v-13.5c0-0.135 0.115-0.25 0.25-0.25 0 0 7.749-0 7.75 0v3.5c0 0.276 0.224 0.5 0.5 0.5h3.5v9.75z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFilePicture, "File Picture"))]
 pub static ImFilePicture: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFilePicture",
+    label: "File Picture",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2506,8 +3435,14 @@ pub static ImFilePicture: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.341 3.579c-0.347-0.473-0.831-1.027-1.362-1.558s-1.085-1.015-1.558-1.362c-0.806-0.591-1.197-0.659-1.421-0.659h-7.75c-0.689 0-1.25 0.561-1.25 1.25v13.5c0 0.689 0.561 1.25 1.25 1.25h11.5c0.689 0 1.25-0.561 1.25-1.25v-9.75c0-0.224-0.068-0.615-0.659-1.421zM12.271 2.729c0.48 0.48 0.856 0.912 1.134 1.271h-2.406v-2.405c0.359 0.278 0.792 0.654 1.271 1.134zM14 14.75c0 0.136-0.114 0.25-0.25 0.25h-11.5c-0.135 0-0.25-0.114-0.25-0.25v-13.5c0-0.135 0.115-0.25 0.25-0.25 0 0 7.749-0 7.75 0v3.5c0 0.276 0.224 0.5 0.5 0.5h3.5v9.75z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFilePlay, "File Play"))]
 pub static ImFilePlay: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFilePlay",
+    label: "File Play",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2523,8 +3458,14 @@ pub static ImFilePlay: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.341 3.579c-0.347-0.473-0.831-1.027-1.362-1.558s-1.085-1.015-1.558-1.362c-0.806-0.591-1.197-0.659-1.421-0.659h-7.75c-0.689 0-1.25 0.561-1.25 1.25v13.5c0 0.689 0.561 1.25 1.25 1.25h11.5c0.689 0 1.25-0.561 1.25-1.25v-9.75c0-0.224-0.068-0.615-0.659-1.421zM12.271 2.729c0.48 0.48 0.856 0.912 1.134 1.271h-2.406v-2.405c0.359 0.278 0.792 0.654 1.271 1.134zM14 14.75c0 0.136-0.114 0.25-0.25 0.25h-11.5c-0.135 0-0.25-0.114-0.25-0.25v-13.5c0-0.135 0.115-0.25 0.25-0.25 0 0 7.749-0 7.75 0v3.5c0 0.276 0.224 0.5 0.5 0.5h3.5v9.75z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFileText, "File Text"))]
 pub static ImFileText: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFileText",
+    label: "File Text",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2539,8 +3480,14 @@ pub static ImFileText: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.5 0h-12c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h12c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM13 14h-11v-12h11v12zM4 7h7v1h-7zM4 9h7v1h-7zM4 11h7v1h-7zM4 5h7v1h-7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFileText2, "File Text 2"))]
 pub static ImFileText2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFileText2",
+    label: "File Text 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2558,8 +3505,14 @@ pub static ImFileText2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11.5 9h-7c-0.276 0-0.5-0.224-0.5-0.5s0.224-0.5 0.5-0.5h7c0.276 0 0.5 0.224 0.5 0.5s-0.224 0.5-0.5 0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFileVideo, "File Video"))]
 pub static ImFileVideo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFileVideo",
+    label: "File Video",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2576,8 +3529,14 @@ pub static ImFileVideo: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 10l3-2v5l-3-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFileWord, "File Word"))]
 pub static ImFileWord: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFileWord",
+    label: "File Word",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2593,8 +3552,14 @@ pub static ImFileWord: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.341 3.579c-0.347-0.473-0.831-1.027-1.362-1.558s-1.085-1.015-1.558-1.362c-0.806-0.591-1.197-0.659-1.421-0.659h-7.75c-0.689 0-1.25 0.561-1.25 1.25v13.5c0 0.689 0.561 1.25 1.25 1.25h11.5c0.689 0 1.25-0.561 1.25-1.25v-9.75c0-0.224-0.068-0.615-0.659-1.421v0zM12.271 2.729c0.48 0.48 0.856 0.912 1.134 1.271h-2.406v-2.405c0.359 0.278 0.792 0.654 1.271 1.134v0zM14 14.75c0 0.136-0.114 0.25-0.25 0.25h-11.5c-0.135 0-0.25-0.114-0.25-0.25v-13.5c0-0.135 0.115-0.25 0.25-0.25 0 0 7.749-0 7.75 0v3.5c0 0.276 0.224 0.5 0.5 0.5h3.5v9.75z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFileZip, "File Zip"))]
 pub static ImFileZip: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFileZip",
+    label: "File Zip",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2618,8 +3583,14 @@ pub static ImFileZip: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 13.25c0 0.412 0.338 0.75 0.75 0.75h2.5c0.412 0 0.75-0.338 0.75-0.75v-2.5c0-0.412-0.338-0.75-0.75-0.75h-1.25v-1h-2v4.25zM7 12v1h-2v-1h2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFilesEmpty, "Files Empty"))]
 pub static ImFilesEmpty: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFilesEmpty",
+    label: "Files Empty",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2635,8 +3606,14 @@ pub static ImFilesEmpty: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9.421 0.659c-0.806-0.591-1.197-0.659-1.421-0.659h-5.75c-0.689 0-1.25 0.561-1.25 1.25v11.5c0 0.604 0.43 1.109 1 1.225v-12.725c0-0.135 0.115-0.25 0.25-0.25h7.607c-0.151-0.124-0.297-0.238-0.437-0.341z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFilm, "Film"))]
 pub static ImFilm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFilm",
+    label: "Film",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2651,8 +3628,14 @@ pub static ImFilm: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 2v12h16v-12h-16zM3 13h-2v-2h2v2zM3 9h-2v-2h2v2zM3 5h-2v-2h2v2zM12 13h-8v-10h8v10zM15 13h-2v-2h2v2zM15 9h-2v-2h2v2zM15 5h-2v-2h2v2zM6 5v6l4-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFilter, "Filter"))]
 pub static ImFilter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFilter",
+    label: "Filter",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2667,8 +3650,14 @@ pub static ImFilter: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 1.119-8 2.5v1.5l6 6v5c0 0.552 0.895 1 2 1s2-0.448 2-1v-5l6-6v-1.5c0-1.381-3.582-2.5-8-2.5zM1.475 2.169c0.374-0.213 0.9-0.416 1.52-0.586 1.374-0.376 3.152-0.583 5.005-0.583s3.631 0.207 5.005 0.583c0.62 0.17 1.146 0.372 1.52 0.586 0.247 0.141 0.38 0.26 0.442 0.331-0.062 0.071-0.195 0.19-0.442 0.331-0.374 0.213-0.9 0.416-1.52 0.586-1.374 0.376-3.152 0.583-5.005 0.583s-3.631-0.207-5.005-0.583c-0.62-0.17-1.146-0.372-1.52-0.586-0.247-0.141-0.38-0.26-0.442-0.331 0.062-0.071 0.195-0.19 0.442-0.331z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFinder, "Finder"))]
 pub static ImFinder: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFinder",
+    label: "Finder",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2694,8 +3683,14 @@ pub static ImFinder: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8.68 13.035c-0.061 0.005-0.122 0.009-0.183 0.013 0.061-0.004 0.122-0.008 0.183-0.013z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFire, "Fire"))]
 pub static ImFire: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFire",
+    label: "Fire",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2710,8 +3705,14 @@ pub static ImFire: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M5.016 16c-1.066-2.219-0.498-3.49 0.321-4.688 0.897-1.312 1.129-2.61 1.129-2.61s0.706 0.917 0.423 2.352c1.246-1.387 1.482-3.598 1.293-4.445 2.817 1.969 4.021 6.232 2.399 9.392 8.631-4.883 2.147-12.19 1.018-13.013 0.376 0.823 0.448 2.216-0.313 2.893-1.287-4.879-4.468-5.879-4.468-5.879 0.376 2.516-1.364 5.268-3.042 7.324-0.059-1.003-0.122-1.696-0.649-2.656-0.118 1.823-1.511 3.309-1.889 5.135-0.511 2.473 0.383 4.284 3.777 6.197z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFirefox, "Firefox"))]
 pub static ImFirefox: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFirefox",
+    label: "Firefox",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2726,8 +3727,14 @@ pub static ImFirefox: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.977 5.221l-0.185 1.189c0 0-0.265-2.201-0.59-3.024-0.498-1.261-0.719-1.251-0.72-1.249 0.333 0.847 0.273 1.302 0.273 1.302s-0.591-1.609-2.152-2.121c-1.729-0.567-2.665-0.412-2.773-0.383-0.016-0-0.032-0-0.047-0 0.013 0.001 0.025 0.002 0.038 0.003-0.001 0-0.001 0.001-0.001 0.001 0.007 0.009 1.911 0.333 2.249 0.797 0 0-0.809 0-1.614 0.232-0.036 0.010 2.961 0.374 3.574 3.37 0 0-0.329-0.686-0.735-0.802 0.267 0.813 0.199 2.356-0.056 3.123-0.033 0.099-0.066-0.426-0.568-0.652 0.161 1.151-0.010 2.976-0.808 3.479-0.062 0.039 0.5-1.802 0.113-1.090-2.23 3.419-4.866 1.578-6.051 0.767 0.607 0.132 1.76-0.021 2.271-0.4 0.001-0 0.001-0.001 0.002-0.001 0.554-0.379 0.882-0.656 1.177-0.59s0.491-0.23 0.262-0.493c-0.229-0.263-0.786-0.625-1.539-0.428-0.531 0.139-1.19 0.727-2.194 0.132-0.771-0.457-0.844-0.837-0.851-1.1 0.019-0.093 0.043-0.18 0.072-0.26 0.089-0.248 0.358-0.323 0.508-0.382 0.254 0.044 0.473 0.123 0.703 0.241 0.003-0.076 0.004-0.178-0-0.293 0.022-0.044 0.008-0.176-0.027-0.337-0.020-0.161-0.053-0.328-0.106-0.48 0-0 0-0 0-0s0.002-0.001 0.002-0.001c0.001-0.001 0.002-0.002 0.003-0.003s0-0.001 0.001-0.001c0.001-0.002 0.002-0.004 0.003-0.007 0.016-0.072 0.188-0.211 0.402-0.361 0.192-0.134 0.417-0.277 0.595-0.387 0.157-0.098 0.277-0.17 0.302-0.189 0.010-0.007 0.021-0.016 0.034-0.026 0.002-0.002 0.005-0.004 0.007-0.006s0.003-0.002 0.004-0.004c0.085-0.067 0.211-0.194 0.237-0.462 0-0.001 0-0.001 0-0.002 0.001-0.008 0.001-0.016 0.002-0.024 0-0.006 0.001-0.011 0.001-0.017 0-0.004 0-0.009 0.001-0.013 0-0.011 0.001-0.021 0.001-0.032 0-0.001 0-0.001 0-0.002 0-0.026-0-0.053-0.002-0.081-0.001-0.016-0.002-0.030-0.005-0.043-0-0.001-0-0.001-0-0.002s-0.001-0.003-0.001-0.004-0.001-0.005-0.002-0.007c-0-0-0-0-0-0.001-0.001-0.003-0.002-0.005-0.003-0.007-0-0-0-0-0-0-0.027-0.064-0.13-0.088-0.554-0.096-0-0-0.001-0-0.001-0v0c-0.173-0.003-0.399-0.003-0.695-0.002-0.52 0.002-0.807-0.508-0.898-0.705 0.126-0.695 0.489-1.19 1.085-1.525 0.011-0.006 0.009-0.012-0.004-0.015 0.117-0.071-1.41-0.002-2.112 0.891-0.623-0.155-1.166-0.144-1.635-0.035-0.090-0.003-0.202-0.014-0.335-0.041-0.311-0.282-0.757-0.803-0.781-1.425 0 0-0.001 0.001-0.004 0.003-0-0.006-0.001-0.012-0.001-0.018 0 0-0.949 0.729-0.807 2.717-0 0.032-0.001 0.062-0.002 0.092-0.257 0.348-0.384 0.641-0.394 0.706-0.228 0.463-0.458 1.16-0.646 2.218 0 0 0.131-0.417 0.395-0.889-0.194 0.594-0.346 1.518-0.257 2.904 0 0 0.024-0.307 0.107-0.75 0.065 0.86 0.352 1.921 1.076 3.169 1.39 2.396 3.526 3.605 5.887 3.791 0.419 0.035 0.845 0.035 1.272 0.003 0.039-0.003 0.079-0.006 0.118-0.009 0.484-0.034 0.971-0.107 1.457-0.224 6.643-1.606 5.921-9.628 5.921-9.628z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFirst, "First"))]
 pub static ImFirst: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFirst",
+    label: "First",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2742,8 +3749,14 @@ pub static ImFirst: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M2 14v-12h2v5.5l5-5v5l5-5v11l-5-5v5l-5-5v5.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFlag, "Flag"))]
 pub static ImFlag: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFlag",
+    label: "Flag",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2760,8 +3773,14 @@ pub static ImFlag: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9.5 0.508c-0.733-0.312-1.805-0.508-3-0.508-1.506 0-2.818 0.312-3.5 0.773v8c0.682-0.461 1.994-0.773 3.5-0.773 1.195 0 2.267 0.197 3 0.508v-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFlattr, "Flattr"))]
 pub static ImFlattr: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFlattr",
+    label: "Flattr",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2776,8 +3795,14 @@ pub static ImFlattr: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M5.743 0c-3.802 0-5.743 2.19-5.743 6.279v0 8.579l3.725-3.729v-4.358c0-1.694 0.449-2.772 1.955-3.014v0c0.526-0.103 1.621-0.067 2.317-0.067v0 2.587c0 0.024 0.003 0.066 0.009 0.087v0c0.029 0.105 0.124 0.181 0.236 0.182v0c0.063 0 0.123-0.033 0.184-0.093v0l6.455-6.453-9.139-0.001zM12.275 4.871v4.358c0 1.694-0.449 2.772-1.955 3.014v0c-0.526 0.103-1.621 0.067-2.317 0.067v0-2.587c0-0.023-0.003-0.066-0.009-0.087v0c-0.029-0.105-0.124-0.182-0.236-0.182v0c-0.064-0-0.123 0.033-0.184 0.093v0l-6.455 6.453 9.139 0.001c3.802 0 5.743-2.19 5.743-6.279v0-8.579l-3.725 3.729z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFlickr, "Flickr"))]
 pub static ImFlickr: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFlickr",
+    label: "Flickr",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2792,8 +3817,14 @@ pub static ImFlickr: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 8.5c0-1.933 1.567-3.5 3.5-3.5s3.5 1.567 3.5 3.5c0 1.933-1.567 3.5-3.5 3.5s-3.5-1.567-3.5-3.5zM9 8.5c0-1.933 1.567-3.5 3.5-3.5s3.5 1.567 3.5 3.5c0 1.933-1.567 3.5-3.5 3.5s-3.5-1.567-3.5-3.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFlickr2, "Flickr 2"))]
 pub static ImFlickr2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFlickr2",
+    label: "Flickr 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2808,8 +3839,14 @@ pub static ImFlickr2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12.5 6.5c-1.103 0-2 0.897-2 2s0.897 2 2 2c1.103 0 2-0.897 2-2s-0.897-2-2-2zM12.5 5v0c1.933 0 3.5 1.567 3.5 3.5s-1.567 3.5-3.5 3.5-3.5-1.567-3.5-3.5c0-1.933 1.567-3.5 3.5-3.5zM0 8.5c0-1.933 1.567-3.5 3.5-3.5s3.5 1.567 3.5 3.5c0 1.933-1.567 3.5-3.5 3.5s-3.5-1.567-3.5-3.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFlickr3, "Flickr 3"))]
 pub static ImFlickr3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFlickr3",
+    label: "Flickr 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2824,8 +3861,14 @@ pub static ImFlickr3: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM4.5 10.5c-1.381 0-2.5-1.119-2.5-2.5s1.119-2.5 2.5-2.5 2.5 1.119 2.5 2.5-1.119 2.5-2.5 2.5zM11.5 10.5c-1.381 0-2.5-1.119-2.5-2.5s1.119-2.5 2.5-2.5c1.381 0 2.5 1.119 2.5 2.5s-1.119 2.5-2.5 2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFlickr4, "Flickr 4"))]
 pub static ImFlickr4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFlickr4",
+    label: "Flickr 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2840,8 +3883,14 @@ pub static ImFlickr4: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.606-8 8.055s3.582 8.055 8 8.055 8-3.606 8-8.055-3.582-8.055-8-8.055zM4.5 10.5c-1.381 0-2.5-1.119-2.5-2.5s1.119-2.5 2.5-2.5 2.5 1.119 2.5 2.5c0 1.381-1.119 2.5-2.5 2.5zM11.5 10.5c-1.381 0-2.5-1.119-2.5-2.5s1.119-2.5 2.5-2.5 2.5 1.119 2.5 2.5c0 1.381-1.119 2.5-2.5 2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFloppyDisk, "Floppy Disk"))]
 pub static ImFloppyDisk: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFloppyDisk",
+    label: "Floppy Disk",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2856,8 +3905,14 @@ pub static ImFloppyDisk: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14 0h-14v16h16v-14l-2-2zM8 2h2v4h-2v-4zM14 14h-12v-12h1v5h9v-5h1.172l0.828 0.828v11.172z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFolder, "Folder"))]
 pub static ImFolder: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFolder",
+    label: "Folder",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2872,8 +3927,14 @@ pub static ImFolder: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7 2l2 2h7v11h-16v-13z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFolderDownload, "Folder Download"))]
 pub static ImFolderDownload: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFolderDownload",
+    label: "Folder Download",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2888,8 +3949,14 @@ pub static ImFolderDownload: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M9 4l-2-2h-7v13h16v-11h-7zM8 13.5l-3.5-3.5h2.5v-4h2v4h2.5l-3.5 3.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFolderMinus, "Folder Minus"))]
 pub static ImFolderMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFolderMinus",
+    label: "Folder Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2904,8 +3971,14 @@ pub static ImFolderMinus: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9 4l-2-2h-7v13h16v-11h-7zM11 11h-6v-2h6v2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFolderOpen, "Folder Open"))]
 pub static ImFolderOpen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFolderOpen",
+    label: "Folder Open",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2920,8 +3993,14 @@ pub static ImFolderOpen: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13 15l3-8h-13l-3 8zM2 6l-2 9v-13h4.5l2 2h6.5v2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFolderPlus, "Folder Plus"))]
 pub static ImFolderPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFolderPlus",
+    label: "Folder Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2936,8 +4015,14 @@ pub static ImFolderPlus: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9 4l-2-2h-7v13h16v-11h-7zM11 11h-2v2h-2v-2h-2v-2h2v-2h2v2h2v2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFolderUpload, "Folder Upload"))]
 pub static ImFolderUpload: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFolderUpload",
+    label: "Folder Upload",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2952,8 +4037,14 @@ pub static ImFolderUpload: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9 4l-2-2h-7v13h16v-11h-7zM8 7.5l3.5 3.5h-2.5v4h-2v-4h-2.5l3.5-3.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFont, "Font"))]
 pub static ImFont: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFont",
+    label: "Font",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2968,8 +4059,14 @@ pub static ImFont: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12.494 0.253c-1.414 0-2.322-0.253-3.779-0.253-4.708 0-6.903 2.681-6.903 5.404 0 1.604 0.76 2.132 2.259 2.132-0.106-0.232-0.296-0.486-0.296-1.626 0-3.188 1.203-4.117 2.744-4.18 0 0-1.264 12.396-4.934 13.883v0.385h4.947l1.688-8h3.091l0.689-2h-3.358l0.812-3.847c0.929 0.19 1.837 0.38 2.618 0.38 0.971 0 1.858-0.296 2.343-2.533-0.591 0.19-1.224 0.253-1.921 0.253z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFontSize, "Font Size"))]
 pub static ImFontSize: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFontSize",
+    label: "Font Size",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2984,8 +4081,14 @@ pub static ImFontSize: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M1 8h6v2h-2v6h-2v-6h-2zM15 4h-3.934v12h-2.133v-12h-3.934v-2h10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImForward, "Forward"))]
 pub static ImForward: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImForward",
+    label: "Forward",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3000,8 +4103,14 @@ pub static ImForward: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4.096 0c-1.777 3.219-2.076 8.13 4.904 7.966v-3.966l6 6-6 6v-3.881c-8.359 0.218-9.29-7.378-4.904-12.119z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImForward2, "Forward 2"))]
 pub static ImForward2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImForward2",
+    label: "Forward 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3016,8 +4125,14 @@ pub static ImForward2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM8 14.5c-3.59 0-6.5-2.91-6.5-6.5s2.91-6.5 6.5-6.5 6.5 2.91 6.5 6.5-2.91 6.5-6.5 6.5zM5 5.5l3.5 2.5-3.5 2.5zM9 5.5l3.5 2.5-3.5 2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImForward3, "Forward 3"))]
 pub static ImForward3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImForward3",
+    label: "Forward 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3032,8 +4147,14 @@ pub static ImForward3: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 13.5v-5l-5 5v-11l5 5v-5l5.5 5.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFoursquare, "Foursquare"))]
 pub static ImFoursquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFoursquare",
+    label: "Foursquare",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3048,8 +4169,14 @@ pub static ImFoursquare: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.306 1.408c-0.188-0.256-0.488-0.408-0.806-0.408h-9.5c-0.552 0-1 0.448-1 1v12c0 0.404 0.244 0.769 0.617 0.924 0.124 0.051 0.254 0.076 0.382 0.076 0.26 0 0.516-0.102 0.707-0.293l3.707-3.707h2.586c0.437 0 0.824-0.284 0.954-0.702l2.5-8c0.095-0.304 0.040-0.634-0.149-0.891zM10.515 5h-3.515c-0.552 0-1 0.448-1 1s0.448 1 1 1h2.89l-0.625 2h-2.265c-0.265 0-0.52 0.105-0.707 0.293l-2.293 2.293v-8.586h7.14l-0.625 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFrustrated, "Frustrated"))]
 pub static ImFrustrated: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFrustrated",
+    label: "Frustrated",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3066,8 +4193,14 @@ pub static ImFrustrated: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11.379 4.015c-0.023 0.006-0.559 0.141-1.102 0.413-0.568 0.284-1.243 0.776-1.275 1.518-0.001 0.018-0.002 0.036-0.002 0.054 0 0.552 0.448 1 1 1s1-0.448 1-1c0-0.263-0.102-0.503-0.268-0.681 0.288-0.144 0.614-0.264 0.89-0.334 0.268-0.067 0.431-0.338 0.364-0.606s-0.338-0.431-0.606-0.364z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImFrustrated2, "Frustrated 2"))]
 pub static ImFrustrated2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImFrustrated2",
+    label: "Frustrated 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3085,8 +4218,14 @@ pub static ImFrustrated2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM9.002 5.946c0.032-0.741 0.706-1.234 1.275-1.518 0.543-0.271 1.080-0.407 1.102-0.413 0.268-0.067 0.539 0.096 0.606 0.364s-0.096 0.539-0.364 0.606c-0.275 0.070-0.602 0.189-0.89 0.334 0.166 0.179 0.268 0.418 0.268 0.681 0 0.552-0.448 1-1 1s-1-0.448-1-1c0-0.018 0.001-0.036 0.002-0.054zM4.015 4.379c0.067-0.268 0.338-0.431 0.606-0.364 0.023 0.006 0.559 0.141 1.102 0.413 0.568 0.284 1.243 0.776 1.275 1.518 0.001 0.018 0.002 0.036 0.002 0.054 0 0.552-0.448 1-1 1s-1-0.448-1-1c0-0.263 0.102-0.503 0.268-0.681-0.288-0.144-0.614-0.264-0.89-0.334-0.268-0.067-0.431-0.338-0.364-0.606zM13 11.75c0 0.689-0.561 1.25-1.25 1.25h-7.5c-0.689 0-1.25-0.561-1.25-1.25v-1.5c0-0.689 0.561-1.25 1.25-1.25h7.5c0.689 0 1.25 0.561 1.25 1.25v1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImGift, "Gift"))]
 pub static ImGift: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImGift",
+    label: "Gift",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3101,8 +4240,14 @@ pub static ImGift: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12.055 5c0.283-0.201 0.555-0.425 0.804-0.674 0.522-0.522 0.871-1.164 0.983-1.808 0.123-0.706-0.057-1.362-0.494-1.798-0.348-0.348-0.82-0.533-1.365-0.533-0.775 0-1.593 0.372-2.242 1.021-1.039 1.039-1.644 2.472-1.97 3.496-0.241-1.028-0.722-2.416-1.657-3.351-0.501-0.501-1.142-0.759-1.748-0.759-0.495 0-0.965 0.172-1.317 0.523-0.781 0.781-0.675 2.153 0.236 3.064 0.325 0.325 0.705 0.595 1.105 0.819h-3.391v4h1v7h12v-7h1v-4h-2.945zM10.536 2.003c0.433-0.433 0.974-0.692 1.446-0.692 0.167 0 0.402 0.035 0.57 0.203 0.407 0.407 0.178 1.349-0.489 2.016-0.687 0.687-1.61 1.159-2.413 1.47h-0.792c0.29-0.899 0.813-2.132 1.678-2.997zM3.655 2.514c-0.011-0.143-0.001-0.41 0.191-0.601 0.16-0.16 0.372-0.194 0.521-0.194v0c0.332 0 0.679 0.157 0.952 0.429 0.529 0.529 0.965 1.371 1.26 2.436 0.008 0.029 0.016 0.057 0.023 0.086-0.028-0.008-0.057-0.015-0.086-0.023-1.064-0.295-1.906-0.731-2.436-1.26-0.247-0.247-0.403-0.565-0.426-0.872zM7 15h-4v-6.5h4v6.5zM7 8h-5v-2h5v2zM13 15h-4v-6.5h4v6.5zM14 8h-5v-2h5v2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImGit, "Git"))]
 pub static ImGit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImGit",
+    label: "Git",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3117,8 +4262,14 @@ pub static ImGit: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.698 7.287l-6.986-6.986c-0.402-0.402-1.055-0.402-1.457 0l-1.623 1.623 1.221 1.221c0.196-0.094 0.415-0.146 0.647-0.146 0.828 0 1.5 0.672 1.5 1.5 0 0.232-0.053 0.451-0.146 0.647l2 2c0.196-0.094 0.415-0.146 0.647-0.146 0.828 0 1.5 0.672 1.5 1.5s-0.672 1.5-1.5 1.5-1.5-0.672-1.5-1.5c0-0.232 0.053-0.451 0.146-0.647l-2-2c-0.048 0.023-0.097 0.043-0.147 0.061v4.171c0.583 0.206 1 0.761 1 1.414 0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5c0-0.653 0.417-1.208 1-1.414v-4.171c-0.583-0.206-1-0.761-1-1.414 0-0.232 0.053-0.451 0.146-0.647l-1.221-1.221-4.623 4.623c-0.402 0.403-0.402 1.055 0 1.458l6.986 6.986c0.402 0.402 1.054 0.402 1.457 0l6.953-6.953c0.402-0.403 0.402-1.055-0-1.458z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImGithub, "Github"))]
 pub static ImGithub: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImGithub",
+    label: "Github",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3133,8 +4284,14 @@ pub static ImGithub: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0.198c-4.418 0-8 3.582-8 8 0 3.535 2.292 6.533 5.471 7.591 0.4 0.074 0.547-0.174 0.547-0.385 0-0.191-0.008-0.821-0.011-1.489-2.226 0.484-2.695-0.944-2.695-0.944-0.364-0.925-0.888-1.171-0.888-1.171-0.726-0.497 0.055-0.486 0.055-0.486 0.803 0.056 1.226 0.824 1.226 0.824 0.714 1.223 1.872 0.869 2.328 0.665 0.072-0.517 0.279-0.87 0.508-1.070-1.777-0.202-3.645-0.888-3.645-3.954 0-0.873 0.313-1.587 0.824-2.147-0.083-0.202-0.357-1.015 0.077-2.117 0 0 0.672-0.215 2.201 0.82 0.638-0.177 1.322-0.266 2.002-0.269 0.68 0.003 1.365 0.092 2.004 0.269 1.527-1.035 2.198-0.82 2.198-0.82 0.435 1.102 0.162 1.916 0.079 2.117 0.513 0.56 0.823 1.274 0.823 2.147 0 3.073-1.872 3.749-3.653 3.947 0.287 0.248 0.543 0.735 0.543 1.481 0 1.070-0.009 1.932-0.009 2.195 0 0.213 0.144 0.462 0.55 0.384 3.177-1.059 5.466-4.057 5.466-7.59 0-4.418-3.582-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImGlass, "Glass"))]
 pub static ImGlass: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImGlass",
+    label: "Glass",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3149,8 +4306,14 @@ pub static ImGlass: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12.153 0.263c-0.087-0.162-0.256-0.263-0.44-0.263h-7.425c-0.184 0-0.353 0.101-0.44 0.263-0.554 1.032-0.847 2.237-0.847 3.487 0 1.647 0.506 3.2 1.424 4.374 0.71 0.907 1.601 1.508 2.576 1.753v5.123h-1.5c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5h5c0.276 0 0.5-0.224 0.5-0.5s-0.224-0.5-0.5-0.5h-1.5v-5.123c0.975-0.244 1.866-0.846 2.576-1.753 0.918-1.174 1.424-2.727 1.424-4.374 0-1.249-0.293-2.455-0.847-3.487zM4.595 1h6.809c0.39 0.827 0.595 1.771 0.595 2.75 0 0.084-0.002 0.167-0.005 0.25h-7.991c-0.003-0.083-0.005-0.166-0.005-0.25-0-0.979 0.205-1.923 0.595-2.75z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImGlass2, "Glass 2"))]
 pub static ImGlass2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImGlass2",
+    label: "Glass 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3165,8 +4328,14 @@ pub static ImGlass2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.893 2.809c0.118-0.151 0.14-0.355 0.057-0.527s-0.258-0.281-0.45-0.281h-11c-0.191 0-0.366 0.109-0.45 0.281s-0.062 0.377 0.057 0.527l4.893 6.228v5.963h-1.5c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5h5c0.276 0 0.5-0.224 0.5-0.5s-0.224-0.5-0.5-0.5h-1.5v-5.963l4.893-6.228zM12.471 3l-1.571 2h-5.8l-1.571-2h8.943z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImGoogle, "Google"))]
 pub static ImGoogle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImGoogle",
+    label: "Google",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3181,8 +4350,14 @@ pub static ImGoogle: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8.159 6.856v2.744h4.537c-0.184 1.178-1.372 3.45-4.537 3.45-2.731 0-4.959-2.262-4.959-5.050s2.228-5.050 4.959-5.050c1.553 0 2.594 0.663 3.188 1.234l2.172-2.091c-1.394-1.306-3.2-2.094-5.359-2.094-4.422 0-8 3.578-8 8s3.578 8 8 8c4.616 0 7.681-3.247 7.681-7.816 0-0.525-0.056-0.925-0.125-1.325l-7.556-0.003z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImGoogle2, "Google 2"))]
 pub static ImGoogle2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImGoogle2",
+    label: "Google 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3197,8 +4372,14 @@ pub static ImGoogle2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM8.119 14c-3.316 0-6-2.684-6-6s2.684-6 6-6c1.619 0 2.975 0.591 4.019 1.569l-1.628 1.569c-0.447-0.428-1.225-0.925-2.391-0.925-2.050 0-3.719 1.697-3.719 3.787s1.672 3.787 3.719 3.787c2.375 0 3.266-1.706 3.403-2.588h-3.403v-2.056h5.666c0.050 0.3 0.094 0.6 0.094 0.994 0.003 3.428-2.294 5.863-5.759 5.863z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImGoogle3, "Google 3"))]
 pub static ImGoogle3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImGoogle3",
+    label: "Google 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3213,8 +4394,14 @@ pub static ImGoogle3: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.419 0-8 3.581-8 8s3.581 8 8 8 8-3.581 8-8-3.581-8-8-8zM8.119 14c-3.316 0-6-2.684-6-6s2.684-6 6-6c1.619 0 2.975 0.591 4.019 1.569l-1.628 1.569c-0.447-0.428-1.225-0.925-2.391-0.925-2.050 0-3.719 1.697-3.719 3.787s1.672 3.787 3.719 3.787c2.375 0 3.266-1.706 3.403-2.588h-3.403v-2.056h5.666c0.050 0.3 0.094 0.6 0.094 0.994 0.003 3.428-2.294 5.863-5.759 5.863z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImGoogleDrive, "Google Drive"))]
 pub static ImGoogleDrive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImGoogleDrive",
+    label: "Google Drive",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3231,8 +4418,14 @@ pub static ImGoogleDrive: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4.534 2l-4.534 7.856 2.888 5 4.534-7.856z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImGooglePlus, "Google Plus"))]
 pub static ImGooglePlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImGooglePlus",
+    label: "Google Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3248,8 +4441,14 @@ pub static ImGooglePlus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 7h-1.5v-1.5h-1.5v1.5h-1.5v1.5h1.5v1.5h1.5v-1.5h1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImGooglePlus2, "Google Plus 2"))]
 pub static ImGooglePlus2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImGooglePlus2",
+    label: "Google Plus 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3264,8 +4463,14 @@ pub static ImGooglePlus2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM6 12c-2.212 0-4-1.787-4-4s1.788-4 4-4c1.081 0 1.984 0.394 2.681 1.047l-1.088 1.044c-0.297-0.284-0.816-0.616-1.594-0.616-1.366 0-2.481 1.131-2.481 2.525s1.116 2.525 2.481 2.525c1.584 0 2.178-1.137 2.269-1.725h-2.269v-1.372h3.778c0.034 0.2 0.063 0.4 0.063 0.663 0 2.287-1.531 3.909-3.841 3.909zM14 8h-1v1h-1v-1h-1v-1h1v-1h1v1h1v1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImGooglePlus3, "Google Plus 3"))]
 pub static ImGooglePlus3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImGooglePlus3",
+    label: "Google Plus 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3280,8 +4485,14 @@ pub static ImGooglePlus3: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.419 0-8 3.581-8 8s3.581 8 8 8 8-3.581 8-8-3.581-8-8-8zM6 12c-2.212 0-4-1.787-4-4s1.788-4 4-4c1.081 0 1.984 0.394 2.681 1.047l-1.088 1.044c-0.297-0.284-0.816-0.616-1.594-0.616-1.366 0-2.481 1.131-2.481 2.525s1.116 2.525 2.481 2.525c1.584 0 2.178-1.137 2.269-1.725h-2.269v-1.372h3.778c0.034 0.2 0.063 0.4 0.063 0.663 0 2.287-1.531 3.909-3.841 3.909zM13 8v1h-1v-1h-1v-1h1v-1h1v1h1v1h-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImGrin, "Grin"))]
 pub static ImGrin: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImGrin",
+    label: "Grin",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3296,8 +4507,14 @@ pub static ImGrin: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c4.418 0 8-3.582 8-8s-3.582-8-8-8-8 3.582-8 8 3.582 8 8 8zM8 1.5c3.59 0 6.5 2.91 6.5 6.5s-2.91 6.5-6.5 6.5-6.5-2.91-6.5-6.5 2.91-6.5 6.5-6.5zM3 8v1c0 2.2 1.8 4 4 4h2c2.2 0 4-1.8 4-4v-1h-10zM6 11.828c-0.415-0.148-0.796-0.388-1.118-0.71-0.569-0.569-0.882-1.321-0.882-2.118h2v2.828zM9 12h-2v-3h2v3zM11.118 11.118c-0.322 0.322-0.703 0.562-1.118 0.71v-2.828h2c0 0.797-0.313 1.549-0.882 2.118zM3.521 6c0 0 0 0 0 0 0.153 0 0.283-0.11 0.308-0.261 0.096-0.573 0.589-0.989 1.171-0.989s1.074 0.416 1.171 0.989c0.025 0.151 0.156 0.261 0.308 0.261s0.283-0.11 0.308-0.261c0.017-0.101 0.025-0.202 0.025-0.302 0-0.999-0.813-1.813-1.813-1.813s-1.813 0.813-1.813 1.813c0 0.1 0.009 0.201 0.025 0.302 0.025 0.151 0.156 0.261 0.308 0.261zM9.521 6c0 0 0 0 0 0 0.153 0 0.283-0.11 0.308-0.261 0.096-0.573 0.589-0.989 1.171-0.989s1.074 0.416 1.171 0.989c0.025 0.151 0.156 0.261 0.308 0.261s0.283-0.11 0.308-0.261c0.017-0.101 0.025-0.202 0.025-0.302 0-0.999-0.813-1.813-1.813-1.813s-1.813 0.813-1.813 1.813c0 0.1 0.008 0.201 0.025 0.302 0.025 0.151 0.156 0.261 0.308 0.261z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImGrin2, "Grin 2"))]
 pub static ImGrin2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImGrin2",
+    label: "Grin 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3312,8 +4529,14 @@ pub static ImGrin2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8c4.418 0 8-3.582 8-8s-3.582-8-8-8zM11 3.688c0.999 0 1.813 0.813 1.813 1.813 0 0.1-0.009 0.201-0.025 0.302-0.025 0.151-0.156 0.261-0.308 0.261s-0.283-0.11-0.308-0.261c-0.096-0.573-0.589-0.833-1.171-0.833s-1.074 0.26-1.171 0.833c-0.025 0.151-0.156 0.261-0.308 0.261-0 0 0 0-0 0-0.153 0-0.283-0.11-0.308-0.261-0.017-0.101-0.025-0.202-0.025-0.302 0-0.999 0.813-1.813 1.813-1.813zM5 3.688c0.999 0 1.813 0.813 1.813 1.813 0 0.1-0.009 0.201-0.025 0.302-0.025 0.151-0.156 0.261-0.308 0.261s-0.283-0.11-0.308-0.261c-0.096-0.573-0.589-0.833-1.171-0.833s-1.074 0.26-1.171 0.833c-0.025 0.151-0.156 0.261-0.308 0.261 0 0 0 0 0 0-0.153 0-0.283-0.11-0.308-0.261-0.017-0.101-0.025-0.202-0.025-0.302 0-0.999 0.813-1.813 1.813-1.813zM3 9h3v3.873c-1.72-0.447-3-2.018-3-3.873zM7 13v-4h2v4h-2zM10 12.873v-3.873h3c0 1.855-1.28 3.426-3 3.873z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHackernews, "Hackernews"))]
 pub static ImHackernews: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHackernews",
+    label: "Hackernews",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3328,8 +4551,14 @@ pub static ImHackernews: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 0v16h16v-16h-16zM8.5 9.125v3.375h-1v-3.375l-2.734-5.125h1.134l2.1 3.938 2.1-3.938h1.134l-2.734 5.125z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHammer, "Hammer"))]
 pub static ImHammer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHammer",
+    label: "Hammer",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3344,8 +4573,14 @@ pub static ImHammer: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.781 12.953l-4.712-4.712c-0.292-0.292-0.769-0.292-1.061 0l-0.354 0.354-2.875-2.875 4.72-4.72h-5l-2.22 2.22-0.22-0.22h-1.061v1.061l0.22 0.22-3.22 3.22 2.5 2.5 3.22-3.22 2.875 2.875-0.354 0.354c-0.292 0.292-0.292 0.769 0 1.061l4.712 4.712c0.292 0.292 0.769 0.292 1.061 0l1.768-1.768c0.292-0.292 0.292-0.769 0-1.061z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHammer2, "Hammer 2"))]
 pub static ImHammer2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHammer2",
+    label: "Hammer 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3360,8 +4595,14 @@ pub static ImHammer2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.784 14.309l-8.572-7.804 0.399-0.4c0.326-0.327 0.503-0.75 0.53-1.181 0.016-0.007 0.031-0.014 0.046-0.023l1.609-1.006c0.218-0.256 0.202-0.66-0.036-0.898l-2.799-2.806c-0.237-0.238-0.641-0.254-0.896-0.036l-1.004 1.614c-0.008 0.015-0.015 0.031-0.022 0.046-0.43 0.027-0.852 0.204-1.178 0.531l-1.522 1.527c-0.327 0.327-0.503 0.75-0.53 1.181-0.016 0.007-0.031 0.014-0.046 0.023l-1.609 1.006c-0.218 0.256-0.202 0.66 0.036 0.898l2.799 2.806c0.237 0.238 0.641 0.254 0.896 0.036l1.004-1.614c0.008-0.015 0.015-0.031 0.023-0.046 0.43-0.027 0.852-0.204 1.178-0.531l0.442-0.443 7.783 8.596c0.226 0.249 0.573 0.289 0.773 0.089l0.787-0.789c0.199-0.2 0.159-0.549-0.089-0.775z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHangouts, "Hangouts"))]
 pub static ImHangouts: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHangouts",
+    label: "Hangouts",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3376,8 +4617,14 @@ pub static ImHangouts: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7.997 0c-3.816 0-6.909 3.094-6.909 6.909 0 3.616 3.294 6.547 6.909 6.547v2.544c4.197-2.128 6.916-5.556 6.916-9.091 0-3.816-3.1-6.909-6.916-6.909zM7 8c0 0.828-0.447 1.5-1 1.5v-1.5h-2v-3h3v3zM12 8c0 0.828-0.447 1.5-1 1.5v-1.5h-2v-3h3v3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHappy, "Happy"))]
 pub static ImHappy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHappy",
+    label: "Happy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3392,8 +4639,14 @@ pub static ImHappy: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c4.418 0 8-3.582 8-8s-3.582-8-8-8-8 3.582-8 8 3.582 8 8 8zM8 1.5c3.59 0 6.5 2.91 6.5 6.5s-2.91 6.5-6.5 6.5-6.5-2.91-6.5-6.5 2.91-6.5 6.5-6.5zM8 9.356c1.812 0 3.535-0.481 5-1.327-0.228 2.788-2.393 4.971-5 4.971s-4.772-2.186-5-4.973c1.465 0.845 3.188 1.329 5 1.329zM4 5.5c0-0.828 0.448-1.5 1-1.5s1 0.672 1 1.5c0 0.828-0.448 1.5-1 1.5s-1-0.672-1-1.5zM10 5.5c0-0.828 0.448-1.5 1-1.5s1 0.672 1 1.5c0 0.828-0.448 1.5-1 1.5s-1-0.672-1-1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHappy2, "Happy 2"))]
 pub static ImHappy2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHappy2",
+    label: "Happy 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3408,8 +4661,14 @@ pub static ImHappy2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM11 4c0.552 0 1 0.672 1 1.5s-0.448 1.5-1 1.5-1-0.672-1-1.5 0.448-1.5 1-1.5zM5 4c0.552 0 1 0.672 1 1.5s-0.448 1.5-1 1.5-1-0.672-1-1.5 0.448-1.5 1-1.5zM8 14c-2.607 0-4.772-2.186-5-4.973 1.465 0.846 3.188 1.329 5 1.329s3.535-0.481 5-1.327c-0.228 2.788-2.393 4.971-5 4.971z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHeadphones, "Headphones"))]
 pub static ImHeadphones: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHeadphones",
+    label: "Headphones",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3426,8 +4685,14 @@ pub static ImHeadphones: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 8c0-4.418-3.582-8-8-8s-8 3.582-8 8c0 0.96 0.169 1.88 0.479 2.732-0.304 0.519-0.479 1.123-0.479 1.768 0 1.763 1.304 3.222 3 3.464v-6.928c-0.499 0.071-0.963 0.248-1.371 0.506-0.084-0.417-0.129-0.849-0.129-1.292 0-3.59 2.91-6.5 6.5-6.5s6.5 2.91 6.5 6.5c0 0.442-0.044 0.874-0.128 1.292-0.408-0.259-0.873-0.435-1.372-0.507v6.929c1.696-0.243 3-1.701 3-3.464 0-0.645-0.175-1.249-0.479-1.768 0.31-0.853 0.479-1.773 0.479-2.732z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHeart, "Heart"))]
 pub static ImHeart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHeart",
+    label: "Heart",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3442,8 +4707,14 @@ pub static ImHeart: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11.8 1c-1.682 0-3.129 1.368-3.799 2.797-0.671-1.429-2.118-2.797-3.8-2.797-2.318 0-4.2 1.882-4.2 4.2 0 4.716 4.758 5.953 8 10.616 3.065-4.634 8-6.050 8-10.616 0-2.319-1.882-4.2-4.2-4.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHeartBroken, "Heart Broken"))]
 pub static ImHeartBroken: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHeartBroken",
+    label: "Heart Broken",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3458,8 +4729,14 @@ pub static ImHeartBroken: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11.8 1c2.318 0 4.2 1.882 4.2 4.2 0 4.566-4.935 5.982-8 10.616-3.243-4.663-8-5.9-8-10.616 0-2.319 1.882-4.2 4.2-4.2 0.943 0 1.812 0.43 2.512 1.060l-1.213 1.94 3.5 2-2 5 5.5-6-3.5-2 0.967-1.451c0.553-0.34 1.175-0.549 1.833-0.549z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHipster, "Hipster"))]
 pub static ImHipster: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHipster",
+    label: "Hipster",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3476,8 +4753,14 @@ pub static ImHipster: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5.439 8.439c0.586-0.586 1.536-0.586 2.121 0s0.586 1.536 0 2.121c-0.019 0.019-0.038 0.037-0.058 0.055-1.352 1.227-4.503-0.029-4.503-1.615 0.969 0.625 1.726 0.153 2.439-0.561z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHipster2, "Hipster 2"))]
 pub static ImHipster2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHipster2",
+    label: "Hipster 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3492,8 +4775,14 @@ pub static ImHipster2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM11 4c0.552 0 1 0.448 1 1s-0.448 1-1 1-1-0.448-1-1 0.448-1 1-1zM5 4c0.552 0 1 0.448 1 1s-0.448 1-1 1-1-0.448-1-1 0.448-1 1-1zM8.497 10.615c-0.020-0.018-0.039-0.036-0.058-0.055-0.293-0.293-0.439-0.677-0.439-1.060-0 0.384-0.146 0.768-0.439 1.060-0.019 0.019-0.038 0.037-0.058 0.055-1.352 1.227-4.503-0.029-4.503-1.615 0.969 0.625 1.726 0.153 2.439-0.561 0.586-0.586 1.536-0.586 2.121 0 0.293 0.293 0.439 0.677 0.439 1.060 0-0.384 0.146-0.768 0.439-1.060 0.586-0.586 1.536-0.586 2.121 0 0.713 0.714 1.471 1.186 2.439 0.561 0 1.586-3.151 2.842-4.503 1.615z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHistory, "History"))]
 pub static ImHistory: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHistory",
+    label: "History",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3508,8 +4797,14 @@ pub static ImHistory: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M10 1c3.866 0 7 3.134 7 7s-3.134 7-7 7v-1.5c1.469 0 2.85-0.572 3.889-1.611s1.611-2.42 1.611-3.889c0-1.469-0.572-2.85-1.611-3.889s-2.42-1.611-3.889-1.611c-1.469 0-2.85 0.572-3.889 1.611-0.799 0.799-1.322 1.801-1.52 2.889h2.909l-3.5 4-3.5-4h2.571c0.485-3.392 3.402-6 6.929-6zM13 7v2h-4v-5h2v3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHome, "Home"))]
 pub static ImHome: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHome",
+    label: "Home",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3524,8 +4819,14 @@ pub static ImHome: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 9.226l-8-6.21-8 6.21v-2.532l8-6.21 8 6.21zM14 9v6h-4v-4h-4v4h-4v-6l6-4.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHome2, "Home 2"))]
 pub static ImHome2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHome2",
+    label: "Home 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3540,8 +4841,14 @@ pub static ImHome2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0.5l-8 8 1.5 1.5 1.5-1.5v6.5h4v-3h2v3h4v-6.5l1.5 1.5 1.5-1.5-8-8zM8 7c-0.552 0-1-0.448-1-1s0.448-1 1-1c0.552 0 1 0.448 1 1s-0.448 1-1 1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHome3, "Home 3"))]
 pub static ImHome3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHome3",
+    label: "Home 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3556,8 +4863,14 @@ pub static ImHome3: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 9.5l-3-3v-4.5h-2v2.5l-3-3-8 8v0.5h2v5h5v-3h2v3h5v-5h2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHourGlass, "Hour Glass"))]
 pub static ImHourGlass: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHourGlass",
+    label: "Hour Glass",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3572,8 +4885,14 @@ pub static ImHourGlass: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11.39 8c2.152-1.365 3.61-3.988 3.61-7 0-0.339-0.019-0.672-0.054-1h-13.891c-0.036 0.328-0.054 0.661-0.054 1 0 3.012 1.457 5.635 3.609 7-2.152 1.365-3.609 3.988-3.609 7 0 0.339 0.019 0.672 0.054 1h13.891c0.036-0.328 0.054-0.661 0.054-1 0-3.012-1.457-5.635-3.609-7zM2.5 15c0-2.921 1.253-5.397 3.5-6.214v-1.572c-2.247-0.817-3.5-3.294-3.5-6.214v0h11c0 2.921-1.253 5.397-3.5 6.214v1.572c2.247 0.817 3.5 3.294 3.5 6.214h-11zM9.682 10.462c-1.12-0.635-1.181-1.459-1.182-1.959v-1.004c0-0.5 0.059-1.327 1.184-1.963 0.602-0.349 1.122-0.88 1.516-1.537h-6.4c0.395 0.657 0.916 1.188 1.518 1.538 1.12 0.635 1.181 1.459 1.182 1.959v1.004c0 0.5-0.059 1.327-1.184 1.963-1.135 0.659-1.98 1.964-2.236 3.537h7.839c-0.256-1.574-1.102-2.879-2.238-3.538z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHtmlFive, "HTML Five"))]
 pub static ImHtmlFive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHtmlFive",
+    label: "HTML Five",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3588,8 +4907,14 @@ pub static ImHtmlFive: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0.946 0l1.284 14.4 5.762 1.6 5.777-1.602 1.286-14.398h-14.108zM12.26 4.71h-6.758l0.161 1.809h6.437l-0.485 5.422-3.623 1.004-3.618-1.004-0.247-2.774h1.773l0.126 1.41 1.967 0.53 0.004-0.001 1.968-0.531 0.204-2.29h-6.121l-0.476-5.341h8.847l-0.158 1.766z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImHtmlFive2, "HTML Five 2"))]
 pub static ImHtmlFive2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImHtmlFive2",
+    label: "HTML Five 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3604,8 +4929,14 @@ pub static ImHtmlFive2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0.946 0l1.284 14.4 5.762 1.6 5.777-1.602 1.286-14.398h-14.108zM12.668 13.482l-4.644 1.287v0.007l-0.012-0.004-0.012 0.004v-0.007l-4.644-1.287-1.098-12.304h11.508l-1.098 12.304zM10.168 8.284l-0.204 2.29-1.972 0.532-1.967-0.53-0.126-1.41h-1.773l0.247 2.774 3.626 1.003 3.615-1.003 0.485-5.422h-6.437l-0.161-1.809h6.758l0.158-1.766h-8.847l0.477 5.341z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImIcoMoon, "Ico Moon"))]
 pub static ImIcoMoon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImIcoMoon",
+    label: "Ico Moon",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3620,8 +4951,14 @@ pub static ImIcoMoon: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4.055 8c0-1.022 0.829-1.851 1.851-1.851s1.851 0.829 1.851 1.851c0 1.022-0.829 1.851-1.851 1.851s-1.851-0.829-1.851-1.851zM8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8c4.418 0 8-3.582 8-8s-3.582-8-8-8zM5.928 14.989c-2.406-1.4-4.023-4.005-4.023-6.989s1.617-5.589 4.023-6.989c2.406 1.399 4.025 4.005 4.025 6.989s-1.618 5.589-4.025 6.989z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImIe, "Ie"))]
 pub static ImIe: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImIe",
+    label: "Ie",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3636,8 +4973,14 @@ pub static ImIe: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11.472 9.825h3.688c0.028-0.256 0.040-0.517 0.040-0.784 0-1.253-0.336-2.429-0.924-3.442 0.607-1.614 0.586-2.984-0.227-3.803-0.773-0.77-2.848-0.645-5.194 0.394-0.174-0.013-0.349-0.020-0.526-0.020-3.22 0-5.921 2.216-6.667 5.201 1.010-1.293 2.072-2.231 3.492-2.913-0.129 0.121-0.882 0.87-1.009 0.996-3.743 3.742-4.923 8.63-3.653 9.9 0.965 0.965 2.715 0.802 4.725-0.182 0.934 0.476 1.992 0.744 3.113 0.744 3.018 0 5.575-1.942 6.501-4.648h-3.717c-0.511 0.943-1.512 1.586-2.66 1.586s-2.148-0.642-2.66-1.586c-0.227-0.426-0.358-0.915-0.358-1.432v-0.011h6.035zM5.442 8.013c0.085-1.517 1.347-2.728 2.887-2.728s2.802 1.21 2.887 2.728h-5.774zM14.015 2.559c0.524 0.529 0.511 1.503 0.063 2.719-0.768-1.17-1.883-2.093-3.2-2.619 1.408-0.604 2.553-0.684 3.137-0.1zM1.461 15.113c-0.668-0.669-0.467-2.072 0.394-3.763 0.536 1.504 1.581 2.767 2.927 3.581-1.491 0.677-2.712 0.792-3.321 0.182z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImImage, "Image"))]
 pub static ImImage: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImImage",
+    label: "Image",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3654,8 +4997,14 @@ pub static ImImage: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 13h-12v-2l3.5-6 4 5h1l3.5-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImImages, "Images"))]
 pub static ImImages: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImImages",
+    label: "Images",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3672,8 +5021,14 @@ pub static ImImages: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 14h-12v-2l3.5-6 4 5h1l3.5-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImIndentDecrease, "Indent Decrease"))]
 pub static ImIndentDecrease: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImIndentDecrease",
+    label: "Indent Decrease",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3688,8 +5043,14 @@ pub static ImIndentDecrease: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M0 1h16v2h-16zM6 4h10v2h-10zM6 7h10v2h-10zM6 10h10v2h-10zM0 13h16v2h-16zM4 5v6l-4-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImIndentIncrease, "Indent Increase"))]
 pub static ImIndentIncrease: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImIndentIncrease",
+    label: "Indent Increase",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3704,8 +5065,14 @@ pub static ImIndentIncrease: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M0 1h16v2h-16zM6 4h10v2h-10zM6 7h10v2h-10zM6 10h10v2h-10zM0 13h16v2h-16zM0 11v-6l4 3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImInfinite, "Infinite"))]
 pub static ImInfinite: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImInfinite",
+    label: "Infinite",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3720,8 +5087,14 @@ pub static ImInfinite: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12.25 11.75c-1.002 0-1.943-0.39-2.652-1.098l-1.598-1.598-1.598 1.598c-0.708 0.708-1.65 1.098-2.652 1.098s-1.944-0.39-2.652-1.098c-0.708-0.708-1.098-1.65-1.098-2.652s0.39-1.943 1.098-2.652c0.708-0.708 1.65-1.098 2.652-1.098s1.943 0.39 2.652 1.098l1.598 1.598 1.598-1.598c0.708-0.708 1.65-1.098 2.652-1.098s1.944 0.39 2.652 1.098c0.708 0.708 1.098 1.65 1.098 2.652s-0.39 1.943-1.098 2.652c-0.708 0.708-1.65 1.098-2.652 1.098zM10.652 9.598c0.427 0.427 0.994 0.662 1.598 0.662s1.171-0.235 1.598-0.662c0.427-0.427 0.662-0.994 0.662-1.598s-0.235-1.171-0.662-1.598c-0.427-0.427-0.994-0.662-1.598-0.662s-1.171 0.235-1.598 0.662l-1.598 1.598 1.598 1.598zM3.75 5.74c-0.604 0-1.171 0.235-1.598 0.662s-0.662 0.994-0.662 1.598c0 0.604 0.235 1.171 0.662 1.598s0.994 0.662 1.598 0.662c0.604 0 1.171-0.235 1.598-0.662l1.598-1.598-1.598-1.598c-0.427-0.427-0.994-0.662-1.598-0.662v0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImInfo, "Info"))]
 pub static ImInfo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImInfo",
+    label: "Info",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3738,8 +5111,14 @@ pub static ImInfo: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM8 14.5c-3.59 0-6.5-2.91-6.5-6.5s2.91-6.5 6.5-6.5 6.5 2.91 6.5 6.5-2.91 6.5-6.5 6.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImInsertTemplate, "Insert Template"))]
 pub static ImInsertTemplate: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImInsertTemplate",
+    label: "Insert Template",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3754,8 +5133,14 @@ pub static ImInsertTemplate: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M6 3h2v1h-2zM9 3h2v1h-2zM14 3v4h-3v-1h2v-2h-1v-1zM5 6h2v1h-2zM8 6h2v1h-2zM3 4v2h1v1h-2v-4h3v1zM6 9h2v1h-2zM9 9h2v1h-2zM14 9v4h-3v-1h2v-2h-1v-1zM5 12h2v1h-2zM8 12h2v1h-2zM3 10v2h1v1h-2v-4h3v1zM15 1h-14v14h14v-14zM16 0v0 16h-16v-16h16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImInstagram, "Instagram"))]
 pub static ImInstagram: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImInstagram",
+    label: "Instagram",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3770,8 +5155,14 @@ pub static ImInstagram: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM11 2.5c0-0.275 0.225-0.5 0.5-0.5h2c0.275 0 0.5 0.225 0.5 0.5v2c0 0.275-0.225 0.5-0.5 0.5h-2c-0.275 0-0.5-0.225-0.5-0.5v-2zM8 5c1.656 0 3 1.344 3 3s-1.344 3-3 3c-1.656 0-3-1.344-3-3s1.344-3 3-3zM14 13.5v0c0 0.275-0.225 0.5-0.5 0.5h-11c-0.275 0-0.5-0.225-0.5-0.5v0-6.5h1.1c-0.066 0.322-0.1 0.656-0.1 1 0 2.762 2.237 5 5 5s5-2.238 5-5c0-0.344-0.034-0.678-0.1-1h1.1v6.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImItalic, "Italic"))]
 pub static ImItalic: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImItalic",
+    label: "Italic",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3786,8 +5177,14 @@ pub static ImItalic: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14 1v1h-2l-5 12h2v1h-7v-1h2l5-12h-2v-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImJoomla, "Joomla"))]
 pub static ImJoomla: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImJoomla",
+    label: "Joomla",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3802,8 +5199,14 @@ pub static ImJoomla: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4.156 4.323c0.513-0.513 1.344-0.513 1.856-0l0.122 0.123 1.58-1.581-0.123-0.123c-0.9-0.902-2.164-1.217-3.319-0.946-0.166-1.018-1.048-1.796-2.112-1.796-1.182 0-2.14 0.96-2.14 2.143 0 1.021 0.712 1.875 1.667 2.091-0.362 1.21-0.066 2.576 0.888 3.531l3.56 3.561 1.578-1.581-3.56-3.561c-0.511-0.511-0.512-1.346 0.003-1.861zM15.98 2.143c0-1.184-0.958-2.143-2.14-2.143-1.082 0-1.976 0.804-2.12 1.847-1.204-0.354-2.559-0.055-3.51 0.897l-3.56 3.561 1.58 1.581 3.559-3.56c0.515-0.515 1.344-0.514 1.854-0.003 0.512 0.513 0.512 1.346-0.001 1.859l-0.122 0.122 1.578 1.582 0.123-0.124c0.945-0.946 1.245-2.293 0.9-3.494 1.049-0.138 1.858-1.037 1.858-2.125zM14.16 11.735c0.283-1.163-0.031-2.443-0.939-3.352l-3.555-3.562-1.58 1.58 3.555 3.563c0.515 0.516 0.514 1.345 0.003 1.857-0.513 0.513-1.344 0.513-1.857-0l-0.121-0.122-1.578 1.582 0.121 0.121c0.961 0.962 2.338 1.257 3.553 0.883 0.197 0.979 1.061 1.716 2.098 1.716 1.181 0 2.14-0.959 2.14-2.143 0-1.081-0.8-1.976-1.84-2.122zM9.568 8.261l-3.555 3.562c-0.511 0.512-1.344 0.513-1.859-0.002-0.513-0.514-0.513-1.345-0.001-1.859l0.122-0.121-1.579-1.58-0.121 0.12c-0.918 0.919-1.228 2.216-0.929 3.39-0.944 0.223-1.646 1.072-1.646 2.086-0 1.184 0.958 2.143 2.14 2.143 1.017-0.001 1.869-0.71 2.087-1.662 1.167 0.29 2.453-0.020 3.365-0.934l3.555-3.562-1.578-1.582z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImKey, "Key"))]
 pub static ImKey: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImKey",
+    label: "Key",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3818,8 +5221,14 @@ pub static ImKey: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11 0c-2.761 0-5 2.239-5 5 0 0.313 0.029 0.619 0.084 0.916l-6.084 6.084v3c0 0.552 0.448 1 1 1h1v-1h2v-2h2v-2h2l1.298-1.298c0.531 0.192 1.105 0.298 1.702 0.298 2.761 0 5-2.239 5-5s-2.239-5-5-5zM12.498 5.002c-0.828 0-1.5-0.672-1.5-1.5s0.672-1.5 1.5-1.5 1.5 0.672 1.5 1.5-0.672 1.5-1.5 1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImKey2, "Key 2"))]
 pub static ImKey2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImKey2",
+    label: "Key 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3834,8 +5243,14 @@ pub static ImKey2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.658 4.91l-1.58-1.58c-0.387-0.387-1.021-1.021-1.409-1.409l-1.58-1.58c-0.387-0.387-1.077-0.456-1.533-0.152l-4.319 2.88c-0.456 0.304-0.628 0.954-0.383 1.444l1.101 2.203c0.034 0.067 0.073 0.139 0.115 0.213l-5.571 5.571-0.5 3.5h3v-1h2v-2h2v-2h2v-1.112c0.1 0.060 0.196 0.113 0.284 0.157l2.203 1.101c0.49 0.245 1.14 0.072 1.444-0.383l2.88-4.319c0.304-0.456 0.236-1.146-0.152-1.533zM2.354 13.354l-0.707-0.707 4.868-4.868 0.707 0.707-4.868 4.868zM14.328 6.621l-0.707 0.707c-0.194 0.194-0.513 0.194-0.707 0l-4.243-4.243c-0.194-0.194-0.194-0.513 0-0.707l0.707-0.707c0.194-0.194 0.513-0.194 0.707 0l4.243 4.243c0.194 0.194 0.194 0.513 0 0.707z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImKeyboard, "Keyboard"))]
 pub static ImKeyboard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImKeyboard",
+    label: "Keyboard",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3850,8 +5265,14 @@ pub static ImKeyboard: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M17 2h-16c-0.55 0-1 0.45-1 1v10c0 0.55 0.45 1 1 1h16c0.55 0 1-0.45 1-1v-10c0-0.55-0.45-1-1-1zM10 4h2v2h-2v-2zM13 7v2h-2v-2h2zM7 4h2v2h-2v-2zM10 7v2h-2v-2h2zM4 4h2v2h-2v-2zM7 7v2h-2v-2h2zM2 4h1v2h-1v-2zM2 7h2v2h-2v-2zM3 12h-1v-2h1v2zM12 12h-8v-2h8v2zM16 12h-3v-2h3v2zM16 9h-2v-2h2v2zM16 6h-3v-2h3v2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLab, "Lab"))]
 pub static ImLab: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLab",
+    label: "Lab",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3866,8 +5287,14 @@ pub static ImLab: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.942 12.57l-4.942-8.235v-3.335h0.5c0.275 0 0.5-0.225 0.5-0.5s-0.225-0.5-0.5-0.5h-5c-0.275 0-0.5 0.225-0.5 0.5s0.225 0.5 0.5 0.5h0.5v3.335l-4.942 8.235c-1.132 1.886-0.258 3.43 1.942 3.43h10c2.2 0 3.074-1.543 1.942-3.43zM3.766 10l3.234-5.39v-3.61h2v3.61l3.234 5.39h-8.468z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLanyrd, "Lanyrd"))]
 pub static ImLanyrd: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLanyrd",
+    label: "Lanyrd",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3882,8 +5309,14 @@ pub static ImLanyrd: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM12.85 12.012l-5.444 1.781c-1.244 0.406-1.369 0.341-1.931-1.4l-1.375-4.259c-0.328-1.009-1.328-3.728-1.497-4.25-0.313-0.969-0.313-1.022 1.516-1.616 1.431-0.469 1.491-0.453 2.009 1.163 0.419 1.3 0.688 2.35 1.119 3.678l1.172 3.625 3.744-1.225c0.738-0.244 0.984-0.231 1.194 0.678l0.15 0.688c0.175 0.797-0.228 1-0.656 1.137z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLaptop, "Laptop"))]
 pub static ImLaptop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLaptop",
+    label: "Laptop",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3898,8 +5331,14 @@ pub static ImLaptop: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14 11v-8c0-0.55-0.45-1-1-1h-10c-0.55 0-1 0.45-1 1v8h-2v3h16v-3h-2zM10 13h-4v-1h4v1zM13 11h-10v-7.998c0.001-0.001 0.001-0.001 0.002-0.002h9.996c0.001 0.001 0.001 0.001 0.002 0.002v7.998z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLast, "Last"))]
 pub static ImLast: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLast",
+    label: "Last",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3914,8 +5353,14 @@ pub static ImLast: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14 2v12h-2v-5.5l-5 5v-5l-5 5v-11l5 5v-5l5 5v-5.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLastfm, "Lastfm"))]
 pub static ImLastfm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLastfm",
+    label: "Lastfm",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3930,8 +5375,14 @@ pub static ImLastfm: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7.056 11.972l-0.588-1.594c0 0-0.953 1.063-2.381 1.063-1.266 0-2.163-1.1-2.163-2.859 0-2.253 1.137-3.059 2.253-3.059 1.612 0 2.125 1.044 2.566 2.381l0.588 1.831c0.588 1.778 1.688 3.206 4.856 3.206 2.272 0 3.813-0.697 3.813-2.528 0-1.484-0.844-2.253-2.419-2.622l-1.172-0.256c-0.806-0.184-1.044-0.513-1.044-1.063 0-0.622 0.494-0.991 1.3-0.991 0.881 0 1.356 0.331 1.428 1.119l1.831-0.219c-0.147-1.65-1.284-2.328-3.153-2.328-1.65 0-3.262 0.622-3.262 2.622 0 1.247 0.606 2.034 2.125 2.4l1.247 0.294c0.934 0.219 1.247 0.606 1.247 1.137 0 0.678-0.659 0.953-1.906 0.953-1.85 0-2.622-0.972-3.059-2.309l-0.606-1.831c-0.766-2.384-1.994-3.263-4.431-3.263-2.694 0-4.125 1.703-4.125 4.6 0 2.784 1.428 4.287 3.997 4.287 2.069 0 3.059-0.972 3.059-0.972v0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLastfm2, "Lastfm 2"))]
 pub static ImLastfm2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLastfm2",
+    label: "Lastfm 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3946,8 +5397,14 @@ pub static ImLastfm2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM11.666 11.887c-2.775 0-3.737-1.25-4.25-2.806l-0.513-1.603c-0.384-1.172-0.834-2.084-2.244-2.084-0.978 0-1.972 0.706-1.972 2.678 0 1.541 0.784 2.503 1.894 2.503 1.25 0 2.084-0.931 2.084-0.931l0.513 1.394c0 0-0.866 0.85-2.678 0.85-2.25 0-3.5-1.313-3.5-3.75 0-2.534 1.25-4.025 3.609-4.025 2.134 0 3.206 0.769 3.881 2.853l0.528 1.603c0.384 1.172 1.059 2.022 2.678 2.022 1.091 0 1.669-0.241 1.669-0.834 0-0.466-0.272-0.803-1.091-0.994l-1.091-0.256c-1.331-0.322-1.859-1.009-1.859-2.1 0-1.747 1.412-2.294 2.853-2.294 1.634 0 2.631 0.594 2.759 2.038l-1.603 0.194c-0.066-0.691-0.481-0.978-1.25-0.978-0.706 0-1.137 0.322-1.137 0.866 0 0.481 0.209 0.769 0.912 0.931l1.025 0.225c1.378 0.322 2.116 0.994 2.116 2.294 0 1.597-1.347 2.206-3.334 2.206z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLeaf, "Leaf"))]
 pub static ImLeaf: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLeaf",
+    label: "Leaf",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3962,8 +5419,14 @@ pub static ImLeaf: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.802 2.102c-1.73-1.311-4.393-2.094-7.124-2.094-3.377 0-6.129 1.179-7.549 3.235-0.667 0.965-1.036 2.109-1.097 3.398-0.054 1.148 0.139 2.418 0.573 3.784 1.482-4.444 5.622-7.923 10.395-7.923 0 0-4.466 1.175-7.274 4.816-0.002 0.002-0.039 0.048-0.103 0.136-0.564 0.754-1.055 1.612-1.423 2.583-0.623 1.482-1.2 3.515-1.2 5.965h2c0 0-0.304-1.91 0.224-4.106 0.873 0.118 1.654 0.177 2.357 0.177 1.839 0 3.146-0.398 4.115-1.252 0.868-0.765 1.347-1.794 1.854-2.882 0.774-1.663 1.651-3.547 4.198-5.002 0.146-0.083 0.24-0.234 0.251-0.402s-0.063-0.329-0.197-0.431z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLibrary, "Library"))]
 pub static ImLibrary: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLibrary",
+    label: "Library",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3979,8 +5442,14 @@ pub static ImLibrary: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 0h1l8 5v1h-17v-1l8-5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLibreoffice, "Libreoffice"))]
 pub static ImLibreoffice: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLibreoffice",
+    label: "Libreoffice",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3995,8 +5464,14 @@ pub static ImLibreoffice: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8.354 0.354c-0.194-0.194-0.579-0.354-0.854-0.354h-6c-0.275 0-0.5 0.225-0.5 0.5v15c0 0.275 0.225 0.5 0.5 0.5h12c0.275 0 0.5-0.225 0.5-0.5v-9c0-0.275-0.159-0.659-0.354-0.854l-5.293-5.293zM13 15h-11v-14h5.487c0.046 0.008 0.131 0.043 0.169 0.070l5.274 5.274c0.027 0.038 0.062 0.123 0.070 0.169v8.487zM13.5 0h-3c-0.275 0-0.341 0.159-0.146 0.354l3.293 3.293c0.194 0.194 0.354 0.129 0.354-0.146v-3c0-0.275-0.225-0.5-0.5-0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLifebuoy, "Lifebuoy"))]
 pub static ImLifebuoy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLifebuoy",
+    label: "Lifebuoy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4011,8 +5486,14 @@ pub static ImLifebuoy: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM5 8c0-1.657 1.343-3 3-3s3 1.343 3 3-1.343 3-3 3-3-1.343-3-3zM14.468 10.679v0l-2.772-1.148c0.196-0.472 0.304-0.989 0.304-1.531s-0.108-1.059-0.304-1.531l2.772-1.148c0.342 0.825 0.532 1.73 0.532 2.679s-0.189 1.854-0.532 2.679v0zM10.679 1.532v0 0l-1.148 2.772c-0.472-0.196-0.989-0.304-1.531-0.304s-1.059 0.108-1.531 0.304l-1.148-2.772c0.825-0.342 1.73-0.532 2.679-0.532s1.854 0.189 2.679 0.532zM1.532 5.321l2.772 1.148c-0.196 0.472-0.304 0.989-0.304 1.531s0.108 1.059 0.304 1.531l-2.772 1.148c-0.342-0.825-0.532-1.73-0.532-2.679s0.189-1.854 0.532-2.679zM5.321 14.468l1.148-2.772c0.472 0.196 0.989 0.304 1.531 0.304s1.059-0.108 1.531-0.304l1.148 2.772c-0.825 0.342-1.73 0.532-2.679 0.532s-1.854-0.189-2.679-0.532z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLigature, "Ligature"))]
 pub static ImLigature: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLigature",
+    label: "Ligature",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4027,8 +5508,14 @@ pub static ImLigature: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 13.622c0-0.001 0-0.001 0-0.002l-0.005-6.821-1.992 0.097h-3.936v-0.336c0-1.274 0.091-2.546 0.269-3.042 0.123-0.343 0.353-0.652 0.683-0.919 0.322-0.261 0.643-0.393 0.955-0.393 0.262 0 0.48 0.045 0.647 0.134 0.235 0.134 0.464 0.359 0.682 0.669 0.577 0.82 0.812 1.038 0.939 1.131 0.216 0.158 0.477 0.238 0.776 0.238 0.292 0 0.546-0.109 0.757-0.324 0.209-0.213 0.315-0.479 0.315-0.792 0-0.335-0.139-0.691-0.414-1.057-0.268-0.358-0.683-0.652-1.232-0.875-0.536-0.218-1.14-0.329-1.793-0.329-0.949 0-1.813 0.228-2.568 0.678-0.757 0.451-1.337 1.077-1.725 1.863-0.359 0.728-0.333 2.105-0.355 3.355h-1.965v1.116h1.962v5.073c0 1.12-0.342 1.422-0.472 1.583-0.179 0.222-0.509 0.455-0.944 0.455h-0.604v0.878h6.021v-0.878h-0.105c-1.424 0-1.828-0.154-1.828-1.888 0-0 0-0.001 0-0.001l-0.001-5.222h2.191c1.163 0 1.43 0.054 1.491 0.077 0.074 0.028 0.169 0.075 0.204 0.143 0.014 0.026 0.081 0.391 0.081 1.296v3.917c0 0.913-0.111 1.217-0.179 1.319-0.145 0.222-0.319 0.345-0.854 0.358v0.879h4.588v-0.873c-1.431 0-1.588-0.153-1.588-1.505z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLigature2, "Ligature 2"))]
 pub static ImLigature2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLigature2",
+    label: "Ligature 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4043,8 +5530,14 @@ pub static ImLigature2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.364 14.335c-0.183 0-1.307-0.206-1.375-0.458-0.161-0.619-0.183-1.284-0.183-2.040v-8.453c0-1.261 0.252-1.994 0.252-1.994-0.023-0.115-0.138-0.367-0.275-0.367h-0.069c-0.069 0-0.871 0.504-1.605 0.504-0.596-0-0.967-0.527-1.655-0.527-2.892 0-4.249 2.349-4.249 5.672v0.173c0 0.069-0.046 0.138-0.115 0.138h-0.94c-0.115 0-0.344 0.642-0.344 0.94 0 0.092 0.023 0.137 0.069 0.137h1.215c0.069 0 0.115 0.092 0.115 0.16 0 2.040-0.023 4.052-0.023 4.052 0 0.321-0.023 1.031-0.16 1.605-0.069 0.252-1.123 0.458-1.398 0.458-0.115 0-0.115 0.55 0 0.665 0.94-0.046 1.559-0.115 2.499-0.115 0.871 0 1.536 0.069 2.453 0.115 0.046-0.138 0.046-0.665-0.069-0.665-0.183 0-1.307-0.206-1.375-0.458-0.16-0.619-0.16-1.284-0.183-2.040v-3.639c0-0.069 0.069-0.138 0.138-0.138h2.361c0.16-0.321 0.275-0.711 0.275-0.917 0-0.138 0-0.16-0.115-0.16h-2.544c-0.046 0-0.115-0.069-0.115-0.115v-0.825c0-2.040 0.836-3.837 2.234-3.837 0.99 0 1.854 0.642 1.854 3.093 0 0 0 0 0 0 0.003 0.063 0.005 0.114 0.005 0.148v6.825c0 0.321-0.023 1.031-0.16 1.605-0.069 0.252-1.123 0.458-1.398 0.458-0.115 0-0.115 0.55 0 0.665 0.94-0.046 1.559-0.115 2.499-0.115 0.871 0 1.536 0.069 2.453 0.115 0.046-0.137 0.046-0.665-0.069-0.665z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLink, "Link"))]
 pub static ImLink: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLink",
+    label: "Link",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4060,8 +5553,14 @@ pub static ImLink: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 15.813c-1.018 0-1.976-0.397-2.696-1.117-1.486-1.486-1.486-3.905 0-5.392l1.371-1.371c0.317-0.317 0.832-0.317 1.149 0s0.317 0.832 0 1.149l-1.371 1.371c-0.853 0.853-0.853 2.241 0 3.094 0.413 0.413 0.962 0.641 1.547 0.641s1.134-0.228 1.547-0.641l3-3c0.853-0.853 0.853-2.241 0-3.094-0.317-0.317-0.317-0.832 0-1.149s0.832-0.317 1.149 0c1.486 1.486 1.486 3.905 0 5.392l-3 3c-0.72 0.72-1.678 1.117-2.696 1.117z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLinkedin, "Linkedin"))]
 pub static ImLinkedin: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLinkedin",
+    label: "Linkedin",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4076,8 +5575,14 @@ pub static ImLinkedin: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM6 13h-2v-7h2v7zM5 5c-0.553 0-1-0.447-1-1s0.447-1 1-1c0.553 0 1 0.447 1 1s-0.447 1-1 1zM13 13h-2v-4c0-0.553-0.447-1-1-1s-1 0.447-1 1v4h-2v-7h2v1.241c0.412-0.566 1.044-1.241 1.75-1.241 1.244 0 2.25 1.119 2.25 2.5v4.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLinkedin2, "Linkedin 2"))]
 pub static ImLinkedin2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLinkedin2",
+    label: "Linkedin 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4094,8 +5599,14 @@ pub static ImLinkedin2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 3.5c0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5c0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImList, "List"))]
 pub static ImList: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImList",
+    label: "List",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4110,8 +5621,14 @@ pub static ImList: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 0h4v4h-4zM6 1h10v2h-10zM0 6h4v4h-4zM6 7h10v2h-10zM0 12h4v4h-4zM6 13h10v2h-10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImList2, "List 2"))]
 pub static ImList2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImList2",
+    label: "List 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4126,8 +5643,14 @@ pub static ImList2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6 1h10v2h-10v-2zM6 7h10v2h-10v-2zM6 13h10v2h-10v-2zM0 2c0-1.105 0.895-2 2-2s2 0.895 2 2c0 1.105-0.895 2-2 2s-2-0.895-2-2zM0 8c0-1.105 0.895-2 2-2s2 0.895 2 2c0 1.105-0.895 2-2 2s-2-0.895-2-2zM0 14c0-1.105 0.895-2 2-2s2 0.895 2 2c0 1.105-0.895 2-2 2s-2-0.895-2-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImListNumbered, "List Numbered"))]
 pub static ImListNumbered: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImListNumbered",
+    label: "List Numbered",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4142,8 +5665,14 @@ pub static ImListNumbered: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6 13h10v2h-10zM6 7h10v2h-10zM6 1h10v2h-10zM3 0v4h-1v-3h-1v-1zM2 8.219v0.781h2v1h-3v-2.281l2-0.938v-0.781h-2v-1h3v2.281zM4 11v5h-3v-1h2v-1h-2v-1h2v-1h-2v-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLocation, "Location"))]
 pub static ImLocation: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLocation",
+    label: "Location",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4158,8 +5687,14 @@ pub static ImLocation: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-2.761 0-5 2.239-5 5 0 5 5 11 5 11s5-6 5-11c0-2.761-2.239-5-5-5zM8 8c-1.657 0-3-1.343-3-3s1.343-3 3-3 3 1.343 3 3-1.343 3-3 3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLocation2, "Location 2"))]
 pub static ImLocation2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLocation2",
+    label: "Location 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4174,8 +5709,14 @@ pub static ImLocation2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-2.761 0-5 2.239-5 5 0 5 5 11 5 11s5-6 5-11c0-2.761-2.239-5-5-5zM8 8.063c-1.691 0-3.063-1.371-3.063-3.063s1.371-3.063 3.063-3.063 3.063 1.371 3.063 3.063-1.371 3.063-3.063 3.063zM6.063 5c0-1.070 0.867-1.938 1.938-1.938s1.938 0.867 1.938 1.938c0 1.070-0.867 1.938-1.938 1.938s-1.938-0.867-1.938-1.938z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLock, "Lock"))]
 pub static ImLock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLock",
+    label: "Lock",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4190,8 +5731,14 @@ pub static ImLock: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9.25 7h-0.25v-3c0-1.654-1.346-3-3-3h-2c-1.654 0-3 1.346-3 3v3h-0.25c-0.412 0-0.75 0.338-0.75 0.75v7.5c0 0.412 0.338 0.75 0.75 0.75h8.5c0.412 0 0.75-0.338 0.75-0.75v-7.5c0-0.412-0.338-0.75-0.75-0.75zM3 4c0-0.551 0.449-1 1-1h2c0.551 0 1 0.449 1 1v3h-4v-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLoop, "Loop"))]
 pub static ImLoop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLoop",
+    label: "Loop",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4206,8 +5753,14 @@ pub static ImLoop: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M2 5h10v3l4-4-4-4v3h-12v6h2zM14 11h-10v-3l-4 4 4 4v-3h12v-6h-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLoop2, "Loop 2"))]
 pub static ImLoop2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLoop2",
+    label: "Loop 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4223,8 +5776,14 @@ pub static ImLoop2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.5 8c0 3.59-2.91 6.5-6.5 6.5-1.922 0-3.649-0.835-4.839-2.161l2.339-2.339h-5.5v5.5l2.099-2.099c1.463 1.597 3.565 2.599 5.901 2.599 4.418 0 8-3.582 8-8h-1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImLtr, "Ltr"))]
 pub static ImLtr: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImLtr",
+    label: "Ltr",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4239,8 +5798,14 @@ pub static ImLtr: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-2.209 0-4 1.791-4 4s1.791 4 4 4v8h2v-14h2v14h2v-14h2v-2h-8zM0 11l4-4-4-4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMagicWand, "Magic Wand"))]
 pub static ImMagicWand: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMagicWand",
+    label: "Magic Wand",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4255,8 +5820,14 @@ pub static ImMagicWand: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4 3l-2-2h-1v1l2 2zM5 0h1v2h-1zM9 5h2v1h-2zM10 2v-1h-1l-2 2 1 1zM0 5h2v1h-2zM5 9h1v2h-1zM1 9v1h1l2-2-1-1zM15.781 13.781l-9.939-9.939c-0.292-0.292-0.769-0.292-1.061 0l-0.939 0.939c-0.292 0.292-0.292 0.769 0 1.061l9.939 9.939c0.292 0.292 0.769 0.292 1.061 0l0.939-0.939c0.292-0.292 0.292-0.769 0-1.061zM7.5 8.5l-3-3 1-1 3 3-1 1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMagnet, "Magnet"))]
 pub static ImMagnet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMagnet",
+    label: "Magnet",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4271,8 +5842,14 @@ pub static ImMagnet: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14 0h-4l1 9c0 1.657-1.343 3-3 3s-3-1.343-3-3l1-9h-4l-1 9c0 3.866 3.134 7 7 7s7-3.134 7-7l-1-9zM12.154 13.154c-1.11 1.11-2.585 1.721-4.154 1.721s-3.045-0.611-4.154-1.721c-1.096-1.096-1.705-2.548-1.72-4.095l0.564-5.075h1.736l-0.55 4.953v0.062c0 1.102 0.429 2.138 1.208 2.917s1.815 1.208 2.917 1.208 2.138-0.429 2.917-1.208c0.779-0.779 1.208-1.815 1.208-2.917v-0.062l-0.007-0.062-0.543-4.891h1.736l0.564 5.075c-0.015 1.547-0.625 2.999-1.72 4.095z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMail, "Mail"))]
 pub static ImMail: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMail",
+    label: "Mail",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4287,8 +5864,14 @@ pub static ImMail: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.333 0h-10.666c-1.467 0-2.667 1.2-2.667 2.667v10.666c0 1.467 1.2 2.667 2.667 2.667h10.666c1.468 0 2.667-1.2 2.667-2.667v-10.666c0-1.467-1.199-2.667-2.667-2.667zM4 4h8c0.143 0 0.281 0.031 0.409 0.088l-4.409 5.143-4.409-5.143c0.127-0.058 0.266-0.088 0.409-0.088zM3 11v-6c0-0.021 0.001-0.042 0.002-0.063l2.932 3.421-2.9 2.9c-0.023-0.083-0.034-0.17-0.034-0.258zM12 12h-8c-0.088 0-0.175-0.012-0.258-0.034l2.846-2.846 1.413 1.648 1.413-1.648 2.846 2.846c-0.083 0.023-0.17 0.034-0.258 0.034zM13 11c0 0.088-0.012 0.175-0.034 0.258l-2.9-2.9 2.932-3.421c0.001 0.021 0.002 0.042 0.002 0.063v6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMail2, "Mail 2"))]
 pub static ImMail2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMail2",
+    label: "Mail 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4303,8 +5886,14 @@ pub static ImMail2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.333 0h-10.666c-1.467 0-2.667 1.2-2.667 2.667v10.666c0 1.468 1.2 2.667 2.667 2.667h10.666c1.467 0 2.667-1.199 2.667-2.667v-10.666c0-1.467-1.2-2.667-2.667-2.667zM13.333 2c0.125 0 0.243 0.036 0.344 0.099l-5.678 4.694-5.677-4.694c0.101-0.063 0.219-0.099 0.344-0.099h10.666zM2.667 14c-0.030 0-0.060-0.002-0.089-0.006l3.525-4.89-0.457-0.457-3.646 3.646v-9.549l6 7.256 6-7.256v9.549l-3.646-3.646-0.457 0.457 3.525 4.89c-0.029 0.004-0.059 0.006-0.088 0.006h-10.666z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMail3, "Mail 3"))]
 pub static ImMail3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMail3",
+    label: "Mail 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4319,8 +5908,14 @@ pub static ImMail3: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.333 0h-10.666c-1.467 0-2.667 1.2-2.667 2.667v10.666c0 1.468 1.2 2.667 2.667 2.667h10.666c1.467 0 2.667-1.199 2.667-2.667v-10.666c0-1.467-1.2-2.667-2.667-2.667zM2.854 13.854l-1.207-1.207 4-4 0.457 0.457-3.25 4.75zM2.396 3.104l0.457-0.457 5.146 4.146 5.146-4.146 0.457 0.457-5.604 6.604-5.604-6.604zM13.146 13.854l-3.25-4.75 0.457-0.457 4 4-1.207 1.207z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMail4, "Mail 4"))]
 pub static ImMail4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMail4",
+    label: "Mail 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4335,8 +5930,14 @@ pub static ImMail4: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM4 4h8c0.143 0 0.281 0.031 0.409 0.088l-4.409 5.143-4.409-5.143c0.127-0.058 0.266-0.088 0.409-0.088zM3 11v-6c0-0.021 0.001-0.042 0.002-0.063l2.932 3.421-2.9 2.9c-0.023-0.083-0.034-0.17-0.034-0.258zM12 12h-8c-0.088 0-0.175-0.012-0.258-0.034l2.846-2.846 1.413 1.648 1.413-1.648 2.846 2.846c-0.083 0.023-0.17 0.034-0.258 0.034zM13 11c0 0.088-0.012 0.175-0.034 0.258l-2.9-2.9 2.932-3.421c0.001 0.021 0.002 0.042 0.002 0.063v6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMakeGroup, "Make Group"))]
 pub static ImMakeGroup: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMakeGroup",
+    label: "Make Group",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4355,8 +5956,14 @@ pub static ImMakeGroup: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 8h-1c-1.336 0-2.591-0.52-3.536-1.464s-1.464-2.2-1.464-3.536v-1c0-1.1-0.9-2-2-2h-4c-1.1 0-2 0.9-2 2v4c0 1.1 0.9 2 2 2h1c1.336 0 2.591 0.52 3.536 1.464s1.464 2.2 1.464 3.536v1c0 1.1 0.9 2 2 2h4c1.1 0 2-0.9 2-2v-4c0-1.1-0.9-2-2-2zM15 14c0 0.265-0.105 0.515-0.295 0.705s-0.44 0.295-0.705 0.295h-4c-0.265 0-0.515-0.105-0.705-0.295s-0.295-0.44-0.295-0.705v-1c0-3.314-2.686-6-6-6h-1c-0.265 0-0.515-0.105-0.705-0.295s-0.295-0.441-0.295-0.705v-4c0-0.265 0.105-0.515 0.295-0.705s0.44-0.295 0.705-0.295h4c0.265 0 0.515 0.105 0.705 0.295s0.295 0.44 0.295 0.705v1c0 3.314 2.686 6 6 6h1c0.265 0 0.515 0.105 0.705 0.295s0.295 0.44 0.295 0.705v4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMan, "Man"))]
 pub static ImMan: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMan",
+    label: "Man",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4372,8 +5979,14 @@ pub static ImMan: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 4h-3c-0.552 0-1 0.448-1 1v5h1v6h1.25v-6h0.5v6h1.25v-6h1v-5c0-0.552-0.448-1-1-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImManWoman, "Man Woman"))]
 pub static ImManWoman: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImManWoman",
+    label: "Man Woman",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4391,8 +6004,14 @@ pub static ImManWoman: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15.234 8l0.766-0.555-2.083-3.221c-0.092-0.14-0.249-0.225-0.417-0.225h-4c-0.168 0-0.325 0.084-0.417 0.225l-2.083 3.221 0.766 0.555 1.729-2.244 0.601 1.402-2.095 3.841h1.917l0.333 5h1v-5h0.5v5h1l0.333-5h1.917l-2.095-3.842 0.601-1.402 1.729 2.244z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMap, "Map"))]
 pub static ImMap: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMap",
+    label: "Map",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4409,8 +6028,14 @@ pub static ImMap: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 3.5l4-3v12l-4 3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMap2, "Map 2"))]
 pub static ImMap2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMap2",
+    label: "Map 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4425,8 +6050,14 @@ pub static ImMap2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M10.5 3l-5-2-5.5 2v12l5.5-2 5 2 5.5-2v-12l-5.5 2zM6 2.277l4 1.6v9.846l-4-1.6v-9.846zM1 3.7l4-1.455v9.872l-4 1.454v-9.872zM15 12.3l-4 1.455v-9.872l4-1.455v9.872z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMenu, "Menu"))]
 pub static ImMenu: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMenu",
+    label: "Menu",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4441,8 +6072,14 @@ pub static ImMenu: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M1 3h14v3h-14zM1 7h14v3h-14zM1 11h14v3h-14z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMenu2, "Menu 2"))]
 pub static ImMenu2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMenu2",
+    label: "Menu 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4459,8 +6096,14 @@ pub static ImMenu2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21.5 8l-3-3-3 3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMenu3, "Menu 3"))]
 pub static ImMenu3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMenu3",
+    label: "Menu 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4476,8 +6119,14 @@ pub static ImMenu3: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15.5 7l3 3 3-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMenu4, "Menu 4"))]
 pub static ImMenu4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMenu4",
+    label: "Menu 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4493,8 +6142,14 @@ pub static ImMenu4: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15.5 10l3-3 3 3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMeter, "Meter"))]
 pub static ImMeter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMeter",
+    label: "Meter",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4509,8 +6164,14 @@ pub static ImMeter: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 1c4.418 0 8 3.582 8 8 0 3.012-1.665 5.635-4.125 7h-7.75c-2.46-1.365-4.125-3.988-4.125-7 0-4.418 3.582-8 8-8zM12.53 13.53c1.21-1.21 1.876-2.819 1.876-4.53h-1.406v-1h1.329c-0.11-0.703-0.334-1.377-0.665-2h-1.664v-1h1.004c-0.147-0.184-0.306-0.361-0.475-0.53-0.722-0.722-1.587-1.251-2.53-1.559v1.089h-1v-1.329c-0.328-0.051-0.662-0.078-1-0.078s-0.672 0.026-1 0.078v1.329h-1v-1.089c-0.943 0.309-1.808 0.837-2.53 1.559-0.169 0.169-0.327 0.346-0.475 0.53h1.004v1h-1.664c-0.331 0.623-0.555 1.297-0.665 2h1.329v1h-1.406c0 1.711 0.666 3.32 1.876 4.53 0.167 0.167 0.343 0.324 0.524 0.47h3.006l0.571-8h0.857l0.571 8h3.006c0.182-0.146 0.357-0.303 0.524-0.47z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMeter2, "Meter 2"))]
 pub static ImMeter2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMeter2",
+    label: "Meter 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4525,8 +6186,14 @@ pub static ImMeter2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM4.732 13.034c0.174-0.479 0.268-0.995 0.268-1.534 0-1.943-1.231-3.598-2.956-4.228 0.16-1.327 0.754-2.555 1.714-3.514 1.133-1.133 2.64-1.757 4.243-1.757s3.109 0.624 4.243 1.757c0.96 0.96 1.554 2.188 1.714 3.514-1.725 0.63-2.956 2.285-2.956 4.228 0 0.539 0.095 1.055 0.268 1.534-0.964 0.629-2.090 0.966-3.268 0.966s-2.304-0.338-3.268-0.966zM8.621 10.016c0.217 0.055 0.379 0.251 0.379 0.484v1c0 0.275-0.225 0.5-0.5 0.5h-1c-0.275 0-0.5-0.225-0.5-0.5v-1c0-0.233 0.162-0.43 0.379-0.484l0.371-7.016h0.5l0.371 7.016z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMic, "Mic"))]
 pub static ImMic: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMic",
+    label: "Mic",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4541,8 +6208,14 @@ pub static ImMic: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7.5 11c1.381 0 2.5-1.119 2.5-2.5v-6c0-1.381-1.119-2.5-2.5-2.5s-2.5 1.119-2.5 2.5v6c0 1.381 1.119 2.5 2.5 2.5zM11 7v1.5c0 1.933-1.567 3.5-3.5 3.5s-3.5-1.567-3.5-3.5v-1.5h-1v1.5c0 2.316 1.75 4.223 4 4.472v2.028h-2v1h5v-1h-2v-2.028c2.25-0.249 4-2.156 4-4.472v-1.5h-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMinus, "Minus"))]
 pub static ImMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMinus",
+    label: "Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4557,8 +6230,14 @@ pub static ImMinus: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 6.5v3c0 0.276 0.224 0.5 0.5 0.5h15c0.276 0 0.5-0.224 0.5-0.5v-3c0-0.276-0.224-0.5-0.5-0.5h-15c-0.276 0-0.5 0.224-0.5 0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMobile, "Mobile"))]
 pub static ImMobile: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMobile",
+    label: "Mobile",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4573,8 +6252,14 @@ pub static ImMobile: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11.5 0h-7c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h7c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM6 0.75h4v0.5h-4v-0.5zM8 15c-0.552 0-1-0.448-1-1s0.448-1 1-1 1 0.448 1 1-0.448 1-1 1zM12 12h-8v-10h8v10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMobile2, "Mobile 2"))]
 pub static ImMobile2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMobile2",
+    label: "Mobile 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4589,8 +6274,14 @@ pub static ImMobile2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 0h-9c-0.55 0-1 0.45-1 1v14c0 0.55 0.45 1 1 1h9c0.55 0 1-0.45 1-1v-14c0-0.55-0.45-1-1-1zM7.5 15.278c-0.43 0-0.778-0.348-0.778-0.778s0.348-0.778 0.778-0.778 0.778 0.348 0.778 0.778-0.348 0.778-0.778 0.778zM12 13h-9v-11h9v11z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMoveDown, "Move Down"))]
 pub static ImMoveDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMoveDown",
+    label: "Move Down",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4614,8 +6305,14 @@ pub static ImMoveDown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 12h1v1.5h-1v-1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMoveUp, "Move Up"))]
 pub static ImMoveUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMoveUp",
+    label: "Move Up",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4639,8 +6336,14 @@ pub static ImMoveUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 11v3h-3v-3h3zM6 10h-5v5h5v-5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMug, "Mug"))]
 pub static ImMug: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMug",
+    label: "Mug",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4655,8 +6358,14 @@ pub static ImMug: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15 5h-3v-1.5c0-1.381-2.686-2.5-6-2.5s-6 1.119-6 2.5v10c0 1.381 2.686 2.5 6 2.5s6-1.119 6-2.5v-1.5h3c0.552 0 1-0.448 1-1v-5c0-0.552-0.448-1-1-1zM2.751 4.037c-0.578-0.19-0.928-0.394-1.116-0.537 0.188-0.143 0.538-0.347 1.116-0.537 0.905-0.298 2.059-0.463 3.249-0.463s2.344 0.164 3.249 0.463c0.578 0.19 0.928 0.394 1.116 0.537-0.188 0.143-0.538 0.347-1.116 0.537-0.905 0.298-2.059 0.463-3.249 0.463s-2.344-0.164-3.249-0.463zM14 10h-2v-3h2v3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImMusic, "Music"))]
 pub static ImMusic: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImMusic",
+    label: "Music",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4671,8 +6380,14 @@ pub static ImMusic: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15 0h1v11.5c0 1.381-1.567 2.5-3.5 2.5s-3.5-1.119-3.5-2.5c0-1.381 1.567-2.5 3.5-2.5 0.979 0 1.865 0.287 2.5 0.751v-5.751l-8 1.778v7.722c0 1.381-1.567 2.5-3.5 2.5s-3.5-1.119-3.5-2.5c0-1.381 1.567-2.5 3.5-2.5 0.979 0 1.865 0.287 2.5 0.751v-9.751l9-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImNeutral, "Neutral"))]
 pub static ImNeutral: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImNeutral",
+    label: "Neutral",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4687,8 +6402,14 @@ pub static ImNeutral: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c4.418 0 8-3.582 8-8s-3.582-8-8-8-8 3.582-8 8 3.582 8 8 8zM8 1.5c3.59 0 6.5 2.91 6.5 6.5s-2.91 6.5-6.5 6.5-6.5-2.91-6.5-6.5 2.91-6.5 6.5-6.5zM4 5c0 0.552 0.448 1 1 1s1-0.448 1-1-0.448-1-1-1-1 0.448-1 1zM10 5c0 0.552 0.448 1 1 1s1-0.448 1-1-0.448-1-1-1-1 0.448-1 1zM6 11h4v1h-4v-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImNeutral2, "Neutral 2"))]
 pub static ImNeutral2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImNeutral2",
+    label: "Neutral 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4703,8 +6424,14 @@ pub static ImNeutral2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8c4.418 0 8-3.582 8-8s-3.582-8-8-8zM10 12h-4v-1h4v1zM11 4c0.552 0 1 0.448 1 1s-0.448 1-1 1-1-0.448-1-1c0-0.552 0.448-1 1-1zM5 4c0.552 0 1 0.448 1 1s-0.448 1-1 1-1-0.448-1-1c0-0.552 0.448-1 1-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImNewTab, "New Tab"))]
 pub static ImNewTab: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImNewTab",
+    label: "New Tab",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4720,8 +6447,14 @@ pub static ImNewTab: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5.5 4l2.5 2.5-3 3 1.5 1.5 3-3 2.5 2.5v-6.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImNewspaper, "Newspaper"))]
 pub static ImNewspaper: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImNewspaper",
+    label: "Newspaper",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4736,8 +6469,14 @@ pub static ImNewspaper: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14 4v-2h-14v11c0 0.552 0.448 1 1 1h13.5c0.828 0 1.5-0.672 1.5-1.5v-8.5h-2zM13 13h-12v-10h12v10zM2 5h10v1h-10zM8 7h4v1h-4zM8 9h4v1h-4zM8 11h3v1h-3zM2 7h5v5h-5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImNext, "Next"))]
 pub static ImNext: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImNext",
+    label: "Next",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4754,8 +6493,14 @@ pub static ImNext: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11 5h-2v6h2v-6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImNext2, "Next 2"))]
 pub static ImNext2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImNext2",
+    label: "Next 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4770,8 +6515,14 @@ pub static ImNext2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 2v12h-2v-5.5l-5 5v-11l5 5v-5.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImNotification, "Notification"))]
 pub static ImNotification: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImNotification",
+    label: "Notification",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4786,8 +6537,14 @@ pub static ImNotification: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 1.5c-1.736 0-3.369 0.676-4.596 1.904s-1.904 2.86-1.904 4.596c0 1.736 0.676 3.369 1.904 4.596s2.86 1.904 4.596 1.904c1.736 0 3.369-0.676 4.596-1.904s1.904-2.86 1.904-4.596c0-1.736-0.676-3.369-1.904-4.596s-2.86-1.904-4.596-1.904zM8 0v0c4.418 0 8 3.582 8 8s-3.582 8-8 8c-4.418 0-8-3.582-8-8s3.582-8 8-8zM7 11h2v2h-2zM7 3h2v6h-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImNpm, "Npm"))]
 pub static ImNpm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImNpm",
+    label: "Npm",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4802,8 +6559,14 @@ pub static ImNpm: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 0v16h16v-16h-16zM13 13h-2v-8h-3v8h-5v-10h10v10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImOffice, "Office"))]
 pub static ImOffice: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImOffice",
+    label: "Office",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4818,8 +6581,14 @@ pub static ImOffice: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 16h8v-16h-8v16zM5 2h2v2h-2v-2zM5 6h2v2h-2v-2zM5 10h2v2h-2v-2zM1 2h2v2h-2v-2zM1 6h2v2h-2v-2zM1 10h2v2h-2v-2zM9 5h7v1h-7zM9 16h2v-4h3v4h2v-9h-7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImOmega, "Omega"))]
 pub static ImOmega: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImOmega",
+    label: "Omega",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4834,8 +6603,14 @@ pub static ImOmega: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11 14h4l1-2v4h-6v-3.347c2.049-0.883 3.5-3.081 3.5-5.653 0-3.35-2.462-5.973-5.5-5.973s-5.5 2.622-5.5 5.973c0 2.572 1.451 4.77 3.5 5.653v3.347h-6v-4l1 2h4v-0.509c-2.932-1.038-5-3.553-5-6.491 0-3.866 3.582-7 8-7s8 3.134 8 7c0 2.938-2.068 5.452-5 6.491v0.509z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImOnedrive, "Onedrive"))]
 pub static ImOnedrive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImOnedrive",
+    label: "Onedrive",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4850,8 +6625,14 @@ pub static ImOnedrive: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M5.482 12.944c-0.942-0.235-1.466-0.984-1.468-2.095-0-0.355 0.025-0.525 0.114-0.754 0.217-0.56 0.793-0.982 1.55-1.138 0.377-0.077 0.493-0.16 0.493-0.353 0-0.060 0.045-0.24 0.1-0.399 0.249-0.724 0.71-1.327 1.202-1.573 0.515-0.258 0.776-0.316 1.399-0.313 0.886 0.005 1.327 0.197 1.945 0.846l0.34 0.357 0.304-0.105c1.473-0.51 2.942 0.358 3.061 1.809l0.032 0.397 0.29 0.104c0.829 0.297 1.218 0.92 1.148 1.837-0.046 0.599-0.326 1.078-0.77 1.315l-0.209 0.112-4.638 0.009c-3.564 0.007-4.697-0.006-4.893-0.055v0zM1.613 12.281c-0.565-0.142-1.164-0.67-1.445-1.273-0.159-0.342-0.168-0.393-0.168-0.998 0-0.576 0.014-0.668 0.14-0.954 0.267-0.603 0.78-1.038 1.422-1.21 0.136-0.036 0.263-0.094 0.283-0.128s0.043-0.221 0.050-0.415c0.045-1.206 0.794-2.269 1.839-2.61 0.565-0.184 1.306-0.202 1.92 0.058 0.195 0.082 0.173 0.1 0.585-0.471 0.244-0.338 0.705-0.695 1.108-0.909 0.435-0.231 0.887-0.337 1.428-0.336 1.512 0.004 2.815 1.003 3.297 2.529 0.154 0.487 0.146 0.624-0.035 0.628-0.079 0.002-0.306 0.048-0.505 0.102l-0.361 0.099-0.329-0.348c-0.928-0.98-2.441-1.192-3.728-0.522-0.514 0.268-0.927 0.652-1.239 1.153-0.222 0.357-0.506 1.024-0.506 1.189 0 0.117-0.090 0.176-0.474 0.309-1.189 0.412-1.883 1.364-1.882 2.582 0 0.443 0.108 0.986 0.258 1.296 0.057 0.117 0.088 0.228 0.070 0.247-0.046 0.049-1.525 0.032-1.73-0.019v0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImOpera, "Opera"))]
 pub static ImOpera: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImOpera",
+    label: "Opera",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4867,8 +6648,14 @@ pub static ImOpera: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5.366 3.491c-0.884 1.044-1.456 2.587-1.494 4.322 0 0.003 0 0.372 0 0.378 0.038 1.731 0.613 3.275 1.497 4.319 1.147 1.491 2.853 2.434 4.759 2.434 1.172 0 2.269-0.356 3.206-0.978-1.419 1.266-3.287 2.034-5.334 2.034-0.128 0-0.256-0.003-0.381-0.009-4.241-0.2-7.619-3.7-7.619-7.991 0-4.419 3.581-8 8-8 0.009 0 0.019 0 0.031 0 2.037 0.006 3.894 0.775 5.303 2.038-0.938-0.622-2.034-0.981-3.206-0.981-1.906 0-3.612 0.944-4.763 2.434z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImOpt, "Opt"))]
 pub static ImOpt: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImOpt",
+    label: "Opt",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4884,8 +6671,14 @@ pub static ImOpt: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.5 4h-5c-0.276 0-0.5-0.224-0.5-0.5s0.224-0.5 0.5-0.5h5c0.276 0 0.5 0.224 0.5 0.5s-0.224 0.5-0.5 0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPacman, "Pacman"))]
 pub static ImPacman: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPacman",
+    label: "Pacman",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4900,8 +6693,14 @@ pub static ImPacman: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.074 2.794c-1.467-1.71-3.644-2.794-6.074-2.794-4.418 0-8 3.582-8 8s3.582 8 8 8c2.43 0 4.607-1.084 6.074-2.794l-5.074-5.206 5.074-5.206zM11 1.884c0.616 0 1.116 0.499 1.116 1.116s-0.499 1.116-1.116 1.116-1.116-0.499-1.116-1.116c0-0.616 0.499-1.116 1.116-1.116z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPageBreak, "Page Break"))]
 pub static ImPageBreak: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPageBreak",
+    label: "Page Break",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4916,8 +6715,14 @@ pub static ImPageBreak: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 8h2v1h-2zM3 8h3v1h-3zM7 8h2v1h-2zM10 8h3v1h-3zM14 8h2v1h-2zM13.75 0l0.25 7h-12l0.25-7h0.5l0.25 6h10l0.25-6zM2.25 16l-0.25-6h12l-0.25 6h-0.5l-0.25-5h-10l-0.25 5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPagebreak, "Pagebreak"))]
 pub static ImPagebreak: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPagebreak",
+    label: "Pagebreak",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4932,8 +6737,14 @@ pub static ImPagebreak: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4 6v-6h12v6h-1v-5h-10v5zM16 9v7h-12v-7h1v6h10v-6zM8 7h2v1h-2zM5 7h2v1h-2zM11 7h2v1h-2zM14 7h2v1h-2zM0 4.5l3 3-3 3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPaintFormat, "Paint Format"))]
 pub static ImPaintFormat: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPaintFormat",
+    label: "Paint Format",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4948,8 +6759,14 @@ pub static ImPaintFormat: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 9v-6h-3v-1c0-0.55-0.45-1-1-1h-11c-0.55 0-1 0.45-1 1v3c0 0.55 0.45 1 1 1h11c0.55 0 1-0.45 1-1v-1h2v4h-9v2h-0.5c-0.276 0-0.5 0.224-0.5 0.5v5c0 0.276 0.224 0.5 0.5 0.5h2c0.276 0 0.5-0.224 0.5-0.5v-5c0-0.276-0.224-0.5-0.5-0.5h-0.5v-1h9zM12 3h-11v-1h11v1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImParagraphCenter, "Paragraph Center"))]
 pub static ImParagraphCenter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImParagraphCenter",
+    label: "Paragraph Center",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4964,8 +6781,14 @@ pub static ImParagraphCenter: &icondata_core::IconData = &icondata_core::IconDat
     data: r###"<path d="M0 1h16v2h-16zM3 4h10v2h-10zM3 10h10v2h-10zM0 7h16v2h-16zM0 13h16v2h-16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImParagraphJustify, "Paragraph Justify"))]
 pub static ImParagraphJustify: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImParagraphJustify",
+    label: "Paragraph Justify",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4980,8 +6803,14 @@ pub static ImParagraphJustify: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M0 1h16v2h-16zM0 4h16v2h-16zM0 7h16v2h-16zM0 10h16v2h-16zM0 13h16v2h-16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImParagraphLeft, "Paragraph Left"))]
 pub static ImParagraphLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImParagraphLeft",
+    label: "Paragraph Left",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4996,8 +6825,14 @@ pub static ImParagraphLeft: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M0 1h16v2h-16zM0 4h10v2h-10zM0 10h10v2h-10zM0 7h16v2h-16zM0 13h16v2h-16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImParagraphRight, "Paragraph Right"))]
 pub static ImParagraphRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImParagraphRight",
+    label: "Paragraph Right",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5012,8 +6847,14 @@ pub static ImParagraphRight: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M0 1h16v2h-16zM6 4h10v2h-10zM6 10h10v2h-10zM0 7h16v2h-16zM0 13h16v2h-16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPaste, "Paste"))]
 pub static ImPaste: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPaste",
+    label: "Paste",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5028,8 +6869,14 @@ pub static ImPaste: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11 2h-2v-1c0-0.55-0.45-1-1-1h-2c-0.55 0-1 0.45-1 1v1h-2v2h8v-2zM8 2h-2v-0.998c0.001-0.001 0.001-0.001 0.002-0.002h1.996c0.001 0.001 0.001 0.001 0.002 0.002v0.998zM13 5v-2.5c0-0.275-0.225-0.5-0.5-0.5h-1v1h0.5v2h-3l-3 3v4h-4v-9h0.5v-1h-1c-0.275 0-0.5 0.225-0.5 0.5v10c0 0.275 0.225 0.5 0.5 0.5h4.5v3h10v-11h-3zM9 6.414v1.586h-1.586l1.586-1.586zM15 15h-8v-6h3v-3h5v9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPause, "Pause"))]
 pub static ImPause: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPause",
+    label: "Pause",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5044,8 +6891,14 @@ pub static ImPause: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM8 14.5c-3.59 0-6.5-2.91-6.5-6.5s2.91-6.5 6.5-6.5 6.5 2.91 6.5 6.5-2.91 6.5-6.5 6.5zM5 5h2v6h-2zM9 5h2v6h-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPause2, "Pause 2"))]
 pub static ImPause2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPause2",
+    label: "Pause 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5060,8 +6913,14 @@ pub static ImPause2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M2 2h5v12h-5zM9 2h5v12h-5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPaypal, "Paypal"))]
 pub static ImPaypal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPaypal",
+    label: "Paypal",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5077,8 +6936,14 @@ pub static ImPaypal: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12.984 1.206c-0.741-0.844-2.081-1.206-3.794-1.206h-4.972c-0.35 0-0.65 0.253-0.703 0.6l-2.072 13.131c-0.041 0.259 0.159 0.494 0.422 0.494h3.072l0.772-4.891-0.025 0.153c0.053-0.347 0.35-0.6 0.7-0.6h1.459c2.866 0 5.109-1.162 5.766-4.531 0.019-0.1 0.037-0.197 0.050-0.291 0.194-1.244 0-2.094-0.675-2.859z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPen, "Pen"))]
 pub static ImPen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPen",
+    label: "Pen",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5094,8 +6959,14 @@ pub static ImPen: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9.615 2.115l-4.115 0.343c-0.273 0.034-0.501 0.092-0.58 0.449-0 0-0 0.001-0 0.001-1.116 5.36-4.92 10.591-4.92 10.591l0.896 0.896 4.25-4.25c-0.094-0.196-0.146-0.415-0.146-0.647 0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5-0.672 1.5-1.5 1.5c-0.232 0-0.451-0.053-0.647-0.146l-4.25 4.25 0.896 0.896c0 0 5.231-3.804 10.591-4.92 0-0 0.001-0 0.001-0 0.357-0.078 0.415-0.306 0.449-0.58l0.343-4.115-4.269-4.269z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPencil, "Pencil"))]
 pub static ImPencil: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPencil",
+    label: "Pencil",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5110,8 +6981,14 @@ pub static ImPencil: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.5 0c1.381 0 2.5 1.119 2.5 2.5 0 0.563-0.186 1.082-0.5 1.5l-1 1-3.5-3.5 1-1c0.418-0.314 0.937-0.5 1.5-0.5zM1 11.5l-1 4.5 4.5-1 9.25-9.25-3.5-3.5-9.25 9.25zM11.181 5.681l-7 7-0.862-0.862 7-7 0.862 0.862z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPencil2, "Pencil 2"))]
 pub static ImPencil2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPencil2",
+    label: "Pencil 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5126,8 +7003,14 @@ pub static ImPencil2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6 10l2-1 7-7-1-1-7 7-1 2zM4.52 13.548c-0.494-1.043-1.026-1.574-2.069-2.069l1.548-4.262 2-1.217 6-6h-3l-6 6-3 10 10-3 6-6v-3l-6 6-1.217 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPhone, "Phone"))]
 pub static ImPhone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPhone",
+    label: "Phone",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5142,8 +7025,14 @@ pub static ImPhone: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11 10c-1 1-1 2-2 2s-2-1-3-2-2-2-2-3 1-1 2-2-2-4-3-4-3 3-3 3c0 2 2.055 6.055 4 8s6 4 8 4c0 0 3-2 3-3s-3-4-4-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPhoneHangUp, "Phone Hang Up"))]
 pub static ImPhoneHangUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPhoneHangUp",
+    label: "Phone Hang Up",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5158,8 +7047,14 @@ pub static ImPhoneHangUp: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.897 9c0.125 0.867 0.207 2.053-0.182 2.507-0.643 0.751-4.714 0.751-4.714-0.751 0-0.756 0.67-1.252 0.027-2.003-0.632-0.738-1.766-0.75-3.027-0.751s-2.394 0.012-3.027 0.751c-0.643 0.751 0.027 1.247 0.027 2.003 0 1.501-4.071 1.501-4.714 0.751-0.389-0.454-0.307-1.64-0.182-2.507 0.096-0.579 0.339-1.203 1.118-2 0-0 0-0 0-0 1.168-1.090 2.935-1.98 6.716-2v-0c0.021 0 0.042 0 0.063 0s0.041-0 0.063-0v0c3.781 0.019 5.548 0.91 6.716 2 0 0 0 0 0 0 0.778 0.797 1.022 1.421 1.118 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPieChart, "Pie Chart"))]
 pub static ImPieChart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPieChart",
+    label: "Pie Chart",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5174,8 +7069,14 @@ pub static ImPieChart: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7 9v-7c-3.866 0-7 3.134-7 7s3.134 7 7 7 7-3.134 7-7c0-1.126-0.266-2.189-0.738-3.131l-6.262 3.131zM14.262 3.869c-1.149-2.294-3.521-3.869-6.262-3.869v7l6.262-3.131z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPilcrow, "Pilcrow"))]
 pub static ImPilcrow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPilcrow",
+    label: "Pilcrow",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5190,8 +7091,14 @@ pub static ImPilcrow: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6 0h8v2h-2v14h-2v-14h-2v14h-2v-8c-2.209 0-4-1.791-4-4s1.791-4 4-4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPinterest, "Pinterest"))]
 pub static ImPinterest: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPinterest",
+    label: "Pinterest",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5206,8 +7113,14 @@ pub static ImPinterest: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 1.069c-3.828 0-6.931 3.103-6.931 6.931 0 2.938 1.828 5.444 4.406 6.453-0.059-0.547-0.116-1.391 0.025-1.988 0.125-0.541 0.813-3.444 0.813-3.444s-0.206-0.416-0.206-1.028c0-0.963 0.559-1.684 1.253-1.684 0.591 0 0.878 0.444 0.878 0.975 0 0.594-0.378 1.484-0.575 2.306-0.166 0.691 0.344 1.253 1.025 1.253 1.231 0 2.178-1.3 2.178-3.175 0-1.659-1.194-2.819-2.894-2.819-1.972 0-3.128 1.478-3.128 3.009 0 0.597 0.228 1.234 0.516 1.581 0.056 0.069 0.066 0.128 0.047 0.2-0.053 0.219-0.169 0.691-0.194 0.787-0.031 0.128-0.1 0.153-0.231 0.094-0.866-0.403-1.406-1.669-1.406-2.684 0-2.188 1.587-4.194 4.578-4.194 2.403 0 4.272 1.712 4.272 4.003 0 2.388-1.506 4.313-3.597 4.313-0.703 0-1.362-0.366-1.588-0.797 0 0-0.347 1.322-0.431 1.647-0.156 0.603-0.578 1.356-0.862 1.816 0.65 0.2 1.337 0.309 2.053 0.309 3.828 0 6.931-3.103 6.931-6.931 0-3.831-3.103-6.934-6.931-6.934z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPinterest2, "Pinterest 2"))]
 pub static ImPinterest2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPinterest2",
+    label: "Pinterest 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5222,8 +7135,14 @@ pub static ImPinterest2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.412 0-8 3.587-8 8s3.587 8 8 8 8-3.588 8-8-3.588-8-8-8zM8 14.931c-0.716 0-1.403-0.109-2.053-0.309 0.281-0.459 0.706-1.216 0.862-1.816 0.084-0.325 0.431-1.647 0.431-1.647 0.225 0.431 0.888 0.797 1.587 0.797 2.091 0 3.597-1.922 3.597-4.313 0-2.291-1.869-4.003-4.272-4.003-2.991 0-4.578 2.009-4.578 4.194 0 1.016 0.541 2.281 1.406 2.684 0.131 0.063 0.2 0.034 0.231-0.094 0.022-0.097 0.141-0.566 0.194-0.787 0.016-0.069 0.009-0.131-0.047-0.2-0.287-0.347-0.516-0.988-0.516-1.581 0-1.528 1.156-3.009 3.128-3.009 1.703 0 2.894 1.159 2.894 2.819 0 1.875-0.947 3.175-2.178 3.175-0.681 0-1.191-0.563-1.025-1.253 0.197-0.825 0.575-1.713 0.575-2.306 0-0.531-0.284-0.975-0.878-0.975-0.697 0-1.253 0.719-1.253 1.684 0 0.612 0.206 1.028 0.206 1.028s-0.688 2.903-0.813 3.444c-0.141 0.6-0.084 1.441-0.025 1.988-2.578-1.006-4.406-3.512-4.406-6.45 0-3.828 3.103-6.931 6.931-6.931s6.931 3.103 6.931 6.931c0 3.828-3.103 6.931-6.931 6.931z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPlay, "Play"))]
 pub static ImPlay: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPlay",
+    label: "Play",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5238,8 +7157,14 @@ pub static ImPlay: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.331 2.502c-2.244-0.323-4.724-0.502-7.331-0.502s-5.087 0.179-7.331 0.502c-0.43 1.683-0.669 3.543-0.669 5.498s0.239 3.815 0.669 5.498c2.244 0.323 4.724 0.502 7.331 0.502s5.087-0.179 7.331-0.502c0.43-1.683 0.669-3.543 0.669-5.498s-0.239-3.815-0.669-5.498zM6 11v-6l5 3-5 3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPlay2, "Play 2"))]
 pub static ImPlay2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPlay2",
+    label: "Play 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5254,8 +7179,14 @@ pub static ImPlay2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM8 14.5c-3.59 0-6.5-2.91-6.5-6.5s2.91-6.5 6.5-6.5 6.5 2.91 6.5 6.5-2.91 6.5-6.5 6.5zM6 4.5l6 3.5-6 3.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPlay3, "Play 3"))]
 pub static ImPlay3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPlay3",
+    label: "Play 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5270,8 +7201,14 @@ pub static ImPlay3: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M3 2l10 6-10 6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPlus, "Plus"))]
 pub static ImPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPlus",
+    label: "Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5286,8 +7223,14 @@ pub static ImPlus: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.5 6h-5.5v-5.5c0-0.276-0.224-0.5-0.5-0.5h-3c-0.276 0-0.5 0.224-0.5 0.5v5.5h-5.5c-0.276 0-0.5 0.224-0.5 0.5v3c0 0.276 0.224 0.5 0.5 0.5h5.5v5.5c0 0.276 0.224 0.5 0.5 0.5h3c0.276 0 0.5-0.224 0.5-0.5v-5.5h5.5c0.276 0 0.5-0.224 0.5-0.5v-3c0-0.276-0.224-0.5-0.5-0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPodcast, "Podcast"))]
 pub static ImPodcast: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPodcast",
+    label: "Podcast",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5302,8 +7245,14 @@ pub static ImPodcast: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 8c0-4.418-3.582-8-8-8s-8 3.582-8 8c0 3.438 2.169 6.37 5.214 7.501l-0.214 0.499h6l-0.214-0.499c3.045-1.131 5.214-4.063 5.214-7.501zM7.606 9.919c-0.356-0.153-0.606-0.507-0.606-0.919 0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.412-0.25 0.766-0.606 0.919l-0.394-0.919-0.394 0.919zM8.41 9.958c0.908-0.189 1.59-0.994 1.59-1.958 0-1.105-0.895-2-2-2s-2 0.895-2 2c0 0.964 0.682 1.768 1.59 1.957l-1.166 2.721c-1.425-0.612-2.424-2.028-2.424-3.677 0-2.209 1.791-4.188 4-4.188s4 1.978 4 4.188c0 1.649-0.999 3.066-2.424 3.677l-1.166-2.72zM10.757 15.433l-1.155-2.695c1.976-0.668 3.398-2.537 3.398-4.738 0-2.761-2.239-5-5-5s-5 2.239-5 5c0 2.201 1.422 4.070 3.398 4.738l-1.155 2.695c-2.494-1.070-4.24-3.547-4.24-6.433 0-3.865 3.133-7.185 6.997-7.185s6.997 3.32 6.997 7.185c0 2.886-1.747 5.363-4.24 6.433z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPointDown, "Point Down"))]
 pub static ImPointDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPointDown",
+    label: "Point Down",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5318,8 +7267,14 @@ pub static ImPointDown: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15 6.5v2.5c0 0.827-0.673 1.5-1.5 1.5-0.267 0-0.518-0.070-0.736-0.193-0.267 0.417-0.734 0.693-1.264 0.693-0.384 0-0.734-0.145-1-0.383-0.266 0.238-0.616 0.383-1 0.383-0.175 0-0.344-0.030-0.5-0.086v3.586c0 0.827-0.673 1.5-1.5 1.5s-1.5-0.673-1.5-1.5v-6.167l-2.75 1.466c-0.227 0.131-0.486 0.201-0.75 0.201-0.827 0-1.5-0.673-1.5-1.5 0-0.412 0.164-0.796 0.461-1.082 0.004-0.004 0.008-0.007 0.012-0.011l3.737-3.407h-0.71c-0.276 0-0.5-0.224-0.5-0.5v-3c0-0.276 0.224-0.5 0.5-0.5h10c0.276 0 0.5 0.224 0.5 0.5v3c0 0.276-0.224 0.5-0.5 0.5h-0.691l1.138 2.276c0.035 0.069 0.053 0.146 0.053 0.224zM14 2.5c0-0.276-0.224-0.5-0.5-0.5s-0.5 0.224-0.5 0.5 0.224 0.5 0.5 0.5 0.5-0.224 0.5-0.5zM14 6.618l-1.309-2.618h-5.997l-4.544 4.143c-0.097 0.095-0.15 0.221-0.15 0.357 0 0.276 0.224 0.5 0.5 0.5 0.085 0 0.166-0.020 0.239-0.061 0.008-0.005 0.017-0.010 0.025-0.014l3.5-1.866c0.155-0.083 0.342-0.078 0.492 0.013s0.243 0.253 0.243 0.429v7c0 0.276 0.224 0.5 0.5 0.5s0.5-0.224 0.5-0.5v-5c0-0.276 0.224-0.5 0.5-0.5s0.5 0.224 0.5 0.5c0 0.276 0.224 0.5 0.5 0.5s0.5-0.224 0.5-0.5c0-0.276 0.224-0.5 0.5-0.5s0.5 0.224 0.5 0.5c0 0.276 0.224 0.5 0.5 0.5s0.5-0.224 0.5-0.5v-0.5c0-0.276 0.224-0.5 0.5-0.5s0.5 0.224 0.5 0.5c0 0.276 0.224 0.5 0.5 0.5s0.5-0.224 0.5-0.5v-2.382z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPointLeft, "Point Left"))]
 pub static ImPointLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPointLeft",
+    label: "Point Left",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5334,8 +7289,14 @@ pub static ImPointLeft: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9.5 15h-2.5c-0.827 0-1.5-0.673-1.5-1.5 0-0.267 0.070-0.518 0.193-0.736-0.417-0.267-0.693-0.734-0.693-1.264 0-0.384 0.145-0.734 0.383-1-0.238-0.266-0.383-0.616-0.383-1 0-0.175 0.030-0.344 0.086-0.5h-3.586c-0.827 0-1.5-0.673-1.5-1.5s0.673-1.5 1.5-1.5h6.167l-1.466-2.75c-0.131-0.227-0.201-0.486-0.201-0.75 0-0.827 0.673-1.5 1.5-1.5 0.412 0 0.796 0.164 1.082 0.461 0.004 0.004 0.007 0.008 0.011 0.012l3.407 3.737v-0.71c0-0.276 0.224-0.5 0.5-0.5h3c0.276 0 0.5 0.224 0.5 0.5v10c0 0.276-0.224 0.5-0.5 0.5h-3c-0.276 0-0.5-0.224-0.5-0.5v-0.691l-2.276 1.138c-0.069 0.035-0.146 0.053-0.224 0.053zM13.5 14c0.276 0 0.5-0.224 0.5-0.5s-0.224-0.5-0.5-0.5-0.5 0.224-0.5 0.5 0.224 0.5 0.5 0.5zM9.382 14l2.618-1.309v-5.997l-4.143-4.544c-0.095-0.097-0.221-0.15-0.357-0.15-0.276 0-0.5 0.224-0.5 0.5 0 0.085 0.020 0.166 0.061 0.239 0.005 0.008 0.010 0.017 0.014 0.025l1.866 3.5c0.083 0.155 0.078 0.342-0.012 0.492s-0.253 0.243-0.429 0.243h-7c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5h5c0.276 0 0.5 0.224 0.5 0.5s-0.224 0.5-0.5 0.5c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5c0.276 0 0.5 0.224 0.5 0.5s-0.224 0.5-0.5 0.5c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5h0.5c0.276 0 0.5 0.224 0.5 0.5s-0.224 0.5-0.5 0.5c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5h2.382z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPointRight, "Point Right"))]
 pub static ImPointRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPointRight",
+    label: "Point Right",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5350,8 +7311,14 @@ pub static ImPointRight: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6.5 15h2.5c0.827 0 1.5-0.673 1.5-1.5 0-0.267-0.070-0.518-0.193-0.736 0.417-0.267 0.693-0.734 0.693-1.264 0-0.384-0.145-0.734-0.383-1 0.238-0.266 0.383-0.616 0.383-1 0-0.175-0.030-0.344-0.086-0.5h3.586c0.827 0 1.5-0.673 1.5-1.5s-0.673-1.5-1.5-1.5h-6.167l1.466-2.75c0.131-0.227 0.201-0.486 0.201-0.75 0-0.827-0.673-1.5-1.5-1.5-0.412 0-0.796 0.164-1.082 0.461-0.004 0.004-0.007 0.008-0.011 0.012l-3.407 3.737v-0.71c0-0.276-0.224-0.5-0.5-0.5h-3c-0.276 0-0.5 0.224-0.5 0.5v10c0 0.276 0.224 0.5 0.5 0.5h3c0.276 0 0.5-0.224 0.5-0.5v-0.691l2.276 1.138c0.069 0.035 0.146 0.053 0.224 0.053zM2.5 14c-0.276 0-0.5-0.224-0.5-0.5s0.224-0.5 0.5-0.5 0.5 0.224 0.5 0.5-0.224 0.5-0.5 0.5zM6.618 14l-2.618-1.309v-5.997l4.143-4.544c0.095-0.097 0.221-0.15 0.357-0.15 0.276 0 0.5 0.224 0.5 0.5 0 0.085-0.020 0.166-0.061 0.239-0.005 0.008-0.010 0.017-0.014 0.025l-1.866 3.5c-0.083 0.155-0.078 0.342 0.013 0.492s0.253 0.243 0.429 0.243h7c0.276 0 0.5 0.224 0.5 0.5s-0.224 0.5-0.5 0.5h-5c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5c0.276 0 0.5 0.224 0.5 0.5s-0.224 0.5-0.5 0.5c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5c0.276 0 0.5 0.224 0.5 0.5s-0.224 0.5-0.5 0.5h-0.5c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5c0.276 0 0.5 0.224 0.5 0.5s-0.224 0.5-0.5 0.5h-2.382z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPointUp, "Point Up"))]
 pub static ImPointUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPointUp",
+    label: "Point Up",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5366,8 +7333,14 @@ pub static ImPointUp: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15 9.5v-2.5c0-0.827-0.673-1.5-1.5-1.5-0.267 0-0.518 0.070-0.736 0.193-0.267-0.417-0.734-0.693-1.264-0.693-0.384 0-0.734 0.145-1 0.383-0.266-0.238-0.616-0.383-1-0.383-0.175 0-0.344 0.030-0.5 0.086v-3.586c0-0.827-0.673-1.5-1.5-1.5s-1.5 0.673-1.5 1.5v6.167l-2.75-1.466c-0.227-0.131-0.486-0.201-0.75-0.201-0.827 0-1.5 0.673-1.5 1.5 0 0.412 0.164 0.796 0.461 1.082 0.004 0.004 0.008 0.007 0.012 0.011l3.737 3.407h-0.71c-0.276 0-0.5 0.224-0.5 0.5v3c0 0.276 0.224 0.5 0.5 0.5h10c0.276 0 0.5-0.224 0.5-0.5v-3c0-0.276-0.224-0.5-0.5-0.5h-0.691l1.138-2.276c0.035-0.069 0.053-0.146 0.053-0.224zM14 13.5c0 0.276-0.224 0.5-0.5 0.5s-0.5-0.224-0.5-0.5 0.224-0.5 0.5-0.5 0.5 0.224 0.5 0.5zM14 9.382l-1.309 2.618h-5.997l-4.544-4.143c-0.097-0.095-0.15-0.221-0.15-0.357 0-0.276 0.224-0.5 0.5-0.5 0.085 0 0.166 0.020 0.239 0.061 0.008 0.005 0.017 0.010 0.025 0.014l3.5 1.866c0.155 0.083 0.342 0.078 0.492-0.012s0.243-0.253 0.243-0.429v-7c0-0.276 0.224-0.5 0.5-0.5s0.5 0.224 0.5 0.5v5c0 0.276 0.224 0.5 0.5 0.5s0.5-0.224 0.5-0.5c0-0.276 0.224-0.5 0.5-0.5s0.5 0.224 0.5 0.5c0 0.276 0.224 0.5 0.5 0.5s0.5-0.224 0.5-0.5c0-0.276 0.224-0.5 0.5-0.5s0.5 0.224 0.5 0.5v0.5c0 0.276 0.224 0.5 0.5 0.5s0.5-0.224 0.5-0.5c0-0.276 0.224-0.5 0.5-0.5s0.5 0.224 0.5 0.5v2.382z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPower, "Power"))]
 pub static ImPower: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPower",
+    label: "Power",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5382,8 +7355,14 @@ pub static ImPower: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6 0l-6 8h6l-4 8 14-10h-8l6-6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPowerCord, "Power Cord"))]
 pub static ImPowerCord: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPowerCord",
+    label: "Power Cord",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5399,8 +7378,14 @@ pub static ImPowerCord: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12.407 10.528l-6.935-6.935c-1.497 1.795-3.196 4.57-2.022 6.957l-2.066 2.066c-0.486 0.486-0.486 1.282 0 1.768l0.232 0.232c0.486 0.486 1.282 0.486 1.768 0l2.066-2.066c2.387 1.174 5.161-0.524 6.957-2.022z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPrevious, "Previous"))]
 pub static ImPrevious: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPrevious",
+    label: "Previous",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5417,8 +7402,14 @@ pub static ImPrevious: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 5h2v6h-2v-6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPrevious2, "Previous 2"))]
 pub static ImPrevious2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPrevious2",
+    label: "Previous 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5433,8 +7424,14 @@ pub static ImPrevious2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4 14v-12h2v5.5l5-5v11l-5-5v5.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPriceTag, "Price Tag"))]
 pub static ImPriceTag: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPriceTag",
+    label: "Price Tag",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5449,8 +7446,14 @@ pub static ImPriceTag: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.25 0h-6c-0.412 0-0.989 0.239-1.28 0.53l-7.439 7.439c-0.292 0.292-0.292 0.769 0 1.061l6.439 6.439c0.292 0.292 0.769 0.292 1.061 0l7.439-7.439c0.292-0.292 0.53-0.868 0.53-1.28v-6c0-0.412-0.338-0.75-0.75-0.75zM11.5 6c-0.828 0-1.5-0.672-1.5-1.5s0.672-1.5 1.5-1.5 1.5 0.672 1.5 1.5-0.672 1.5-1.5 1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPriceTags, "Price Tags"))]
 pub static ImPriceTags: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPriceTags",
+    label: "Price Tags",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5466,8 +7469,14 @@ pub static ImPriceTags: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2 8.5l8.5-8.5h-1.25c-0.412 0-0.989 0.239-1.28 0.53l-7.439 7.439c-0.292 0.292-0.292 0.769 0 1.061l6.439 6.439c0.292 0.292 0.769 0.292 1.061 0l0.47-0.47-6.5-6.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPrinter, "Printer"))]
 pub static ImPrinter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPrinter",
+    label: "Printer",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5483,8 +7492,14 @@ pub static ImPrinter: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 4h-14c-0.55 0-1 0.45-1 1v5c0 0.55 0.45 1 1 1h3v4h8v-4h3c0.55 0 1-0.45 1-1v-5c0-0.55-0.45-1-1-1zM2 7c-0.552 0-1-0.448-1-1s0.448-1 1-1 1 0.448 1 1-0.448 1-1 1zM11 14h-6v-5h6v5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImProfile, "Profile"))]
 pub static ImProfile: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImProfile",
+    label: "Profile",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5499,8 +7514,14 @@ pub static ImProfile: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.5 0h-12c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h12c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM13 14h-11v-12h11v12zM4 9h7v1h-7zM4 11h7v1h-7zM5 4.5c0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5c0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5zM7.5 6h-2c-0.825 0-1.5 0.45-1.5 1v1h5v-1c0-0.55-0.675-1-1.5-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImPushpin, "Pushpin"))]
 pub static ImPushpin: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImPushpin",
+    label: "Pushpin",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5515,8 +7536,14 @@ pub static ImPushpin: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8.5 0l-1.5 1.5 1.5 1.5-3.5 4h-3.5l2.75 2.75-4.25 5.635v0.615h0.615l5.635-4.25 2.75 2.75v-3.5l4-3.5 1.5 1.5 1.5-1.5-7.5-7.5zM7 8.5l-1-1 3.5-3.5 1 1-3.5 3.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImQrcode, "Qrcode"))]
 pub static ImQrcode: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImQrcode",
+    label: "Qrcode",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5531,8 +7558,14 @@ pub static ImQrcode: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M5 1h-4v4h4v-4zM6 0v0 6h-6v-6h6zM2 2h2v2h-2zM15 1h-4v4h4v-4zM16 0v0 6h-6v-6h6zM12 2h2v2h-2zM5 11h-4v4h4v-4zM6 10v0 6h-6v-6h6zM2 12h2v2h-2zM7 0h1v1h-1zM8 1h1v1h-1zM7 2h1v1h-1zM8 3h1v1h-1zM7 4h1v1h-1zM8 5h1v1h-1zM7 6h1v1h-1zM7 8h1v1h-1zM8 9h1v1h-1zM7 10h1v1h-1zM8 11h1v1h-1zM7 12h1v1h-1zM8 13h1v1h-1zM7 14h1v1h-1zM8 15h1v1h-1zM15 8h1v1h-1zM1 8h1v1h-1zM2 7h1v1h-1zM0 7h1v1h-1zM4 7h1v1h-1zM5 8h1v1h-1zM6 7h1v1h-1zM9 8h1v1h-1zM10 7h1v1h-1zM11 8h1v1h-1zM12 7h1v1h-1zM13 8h1v1h-1zM14 7h1v1h-1zM15 10h1v1h-1zM9 10h1v1h-1zM10 9h1v1h-1zM11 10h1v1h-1zM13 10h1v1h-1zM14 9h1v1h-1zM15 12h1v1h-1zM9 12h1v1h-1zM10 11h1v1h-1zM12 11h1v1h-1zM13 12h1v1h-1zM14 11h1v1h-1zM15 14h1v1h-1zM10 13h1v1h-1zM11 14h1v1h-1zM12 13h1v1h-1zM13 14h1v1h-1zM10 15h1v1h-1zM12 15h1v1h-1zM14 15h1v1h-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImQuestion, "Question"))]
 pub static ImQuestion: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImQuestion",
+    label: "Question",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5547,8 +7580,14 @@ pub static ImQuestion: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7 11h2v2h-2zM11 4c0.552 0 1 0.448 1 1v3l-3 2h-2v-1l3-2v-1h-5v-2h6zM8 1.5c-1.736 0-3.369 0.676-4.596 1.904s-1.904 2.86-1.904 4.596c0 1.736 0.676 3.369 1.904 4.596s2.86 1.904 4.596 1.904c1.736 0 3.369-0.676 4.596-1.904s1.904-2.86 1.904-4.596c0-1.736-0.676-3.369-1.904-4.596s-2.86-1.904-4.596-1.904zM8 0v0c4.418 0 8 3.582 8 8s-3.582 8-8 8c-4.418 0-8-3.582-8-8s3.582-8 8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImQuill, "Quill"))]
 pub static ImQuill: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImQuill",
+    label: "Quill",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5563,8 +7602,14 @@ pub static ImQuill: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 16c2-6 7.234-16 16-16-4.109 3.297-6 11-9 11s-3 0-3 0l-3 5h-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImQuotesLeft, "Quotes Left"))]
 pub static ImQuotesLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImQuotesLeft",
+    label: "Quotes Left",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5579,8 +7624,14 @@ pub static ImQuotesLeft: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M3.516 7c1.933 0 3.5 1.567 3.5 3.5s-1.567 3.5-3.5 3.5-3.5-1.567-3.5-3.5l-0.016-0.5c0-3.866 3.134-7 7-7v2c-1.336 0-2.591 0.52-3.536 1.464-0.182 0.182-0.348 0.375-0.497 0.578 0.179-0.028 0.362-0.043 0.548-0.043zM12.516 7c1.933 0 3.5 1.567 3.5 3.5s-1.567 3.5-3.5 3.5-3.5-1.567-3.5-3.5l-0.016-0.5c0-3.866 3.134-7 7-7v2c-1.336 0-2.591 0.52-3.536 1.464-0.182 0.182-0.348 0.375-0.497 0.578 0.179-0.028 0.362-0.043 0.549-0.043z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImQuotesRight, "Quotes Right"))]
 pub static ImQuotesRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImQuotesRight",
+    label: "Quotes Right",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5595,8 +7646,14 @@ pub static ImQuotesRight: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12.5 10c-1.933 0-3.5-1.567-3.5-3.5s1.567-3.5 3.5-3.5 3.5 1.567 3.5 3.5l0.016 0.5c0 3.866-3.134 7-7 7v-2c1.336 0 2.591-0.52 3.536-1.464 0.182-0.182 0.348-0.375 0.497-0.578-0.179 0.028-0.362 0.043-0.549 0.043zM3.5 10c-1.933 0-3.5-1.567-3.5-3.5s1.567-3.5 3.5-3.5 3.5 1.567 3.5 3.5l0.016 0.5c0 3.866-3.134 7-7 7v-2c1.336 0 2.591-0.52 3.536-1.464 0.182-0.182 0.348-0.375 0.497-0.578-0.179 0.028-0.362 0.043-0.549 0.043z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImRadioChecked, "Radio Checked"))]
 pub static ImRadioChecked: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImRadioChecked",
+    label: "Radio Checked",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5611,8 +7668,14 @@ pub static ImRadioChecked: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM8 14c-3.314 0-6-2.686-6-6s2.686-6 6-6c3.314 0 6 2.686 6 6s-2.686 6-6 6zM5 8c0-1.657 1.343-3 3-3s3 1.343 3 3c0 1.657-1.343 3-3 3s-3-1.343-3-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImRadioChecked2, "Radio Checked 2"))]
 pub static ImRadioChecked2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImRadioChecked2",
+    label: "Radio Checked 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5627,8 +7690,14 @@ pub static ImRadioChecked2: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM8 10c-1.105 0-2-0.895-2-2s0.895-2 2-2c1.105 0 2 0.895 2 2s-0.895 2-2 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImRadioUnchecked, "Radio Unchecked"))]
 pub static ImRadioUnchecked: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImRadioUnchecked",
+    label: "Radio Unchecked",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5643,8 +7712,14 @@ pub static ImRadioUnchecked: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM8 14c-3.314 0-6-2.686-6-6s2.686-6 6-6c3.314 0 6 2.686 6 6s-2.686 6-6 6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImReddit, "Reddit"))]
 pub static ImReddit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImReddit",
+    label: "Reddit",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5659,8 +7734,14 @@ pub static ImReddit: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4 10c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1zM10 10c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1zM10.049 12.137c0.258-0.203 0.631-0.159 0.834 0.099s0.159 0.631-0.099 0.834c-0.717 0.565-1.81 0.93-2.783 0.93s-2.066-0.365-2.784-0.93c-0.258-0.203-0.302-0.576-0.099-0.834s0.576-0.302 0.834-0.099c0.413 0.325 1.23 0.675 2.049 0.675s1.636-0.35 2.049-0.675zM16 8c0-1.105-0.895-2-2-2-0.752 0-1.406 0.415-1.748 1.028-1.028-0.562-2.28-0.926-3.645-1.010l1.193-2.68 2.284 0.659c0.206 0.583 0.761 1.002 1.415 1.002 0.828 0 1.5-0.672 1.5-1.5s-0.672-1.5-1.5-1.5c-0.571 0-1.068 0.319-1.321 0.789l-2.545-0.735c-0.285-0.082-0.587 0.058-0.707 0.329l-1.621 3.641c-1.33 0.094-2.551 0.453-3.557 1.004-0.342-0.613-0.996-1.028-1.748-1.028-1.105 0-2 0.895-2 2 0 0.817 0.491 1.52 1.193 1.83-0.126 0.375-0.193 0.767-0.193 1.17 0 2.761 3.134 5 7 5s7-2.239 7-5c0-0.403-0.067-0.795-0.193-1.17 0.703-0.31 1.193-1.013 1.193-1.83zM13.5 2.938c0.311 0 0.563 0.252 0.563 0.563s-0.252 0.563-0.563 0.563-0.563-0.252-0.563-0.563 0.252-0.563 0.563-0.563zM1 8c0-0.551 0.449-1 1-1 0.399 0 0.743 0.234 0.904 0.573-0.523 0.396-0.956 0.854-1.276 1.355-0.368-0.148-0.628-0.508-0.628-0.928zM8 14.813c-3.21 0-5.813-1.707-5.813-3.813s2.602-3.813 5.813-3.813c3.21 0 5.813 1.707 5.813 3.813s-2.602 3.813-5.813 3.813zM14.372 8.928c-0.32-0.502-0.753-0.959-1.276-1.355 0.161-0.338 0.505-0.573 0.904-0.573 0.551 0 1 0.449 1 1 0 0.42-0.26 0.78-0.628 0.928z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImRedo, "Redo"))]
 pub static ImRedo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImRedo",
+    label: "Redo",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5675,8 +7756,14 @@ pub static ImRedo: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 9c0 2.389 1.048 4.534 2.709 6l1.323-1.5c-1.246-1.099-2.031-2.708-2.031-4.5 0-3.314 2.686-6 6-6 1.657 0 3.157 0.672 4.243 1.757l-2.243 2.243h6v-6l-2.343 2.343c-1.448-1.448-3.448-2.343-5.657-2.343-4.418 0-8 3.582-8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImRedo2, "Redo 2"))]
 pub static ImRedo2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImRedo2",
+    label: "Redo 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5691,8 +7778,14 @@ pub static ImRedo2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9 3.881v-3.881l6 6-6 6v-3.966c-6.98-0.164-6.681 4.747-4.904 7.966-4.386-4.741-3.455-12.337 4.904-12.119z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImRenren, "Renren"))]
 pub static ImRenren: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImRenren",
+    label: "Renren",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5709,8 +7802,14 @@ pub static ImRenren: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7.972 10.041c-0.497 2.056-1.981 3.813-3.828 4.981 1.138 0.622 2.441 0.978 3.828 0.978s2.691-0.356 3.828-0.978c-1.847-1.169-3.331-2.925-3.828-4.981z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImReply, "Reply"))]
 pub static ImReply: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImReply",
+    label: "Reply",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5725,8 +7824,14 @@ pub static ImReply: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7 12.119v3.881l-6-6 6-6v3.966c6.98 0.164 6.681-4.747 4.904-7.966 4.386 4.741 3.455 12.337-4.904 12.119z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImRoad, "Road"))]
 pub static ImRoad: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImRoad",
+    label: "Road",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5741,8 +7846,14 @@ pub static ImRoad: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11 16h5l-4-16h-3l0.5 4h-3l0.5-4h-3l-4 16h5l0.5-4h5l0.5 4zM5.75 10l0.5-4h3.5l0.5 4h-4.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImRocket, "Rocket"))]
 pub static ImRocket: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImRocket",
+    label: "Rocket",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5757,8 +7868,14 @@ pub static ImRocket: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11 1l-5 5h-3l-3 4c0 0 3.178-0.885 5.032-0.47l-5.032 6.47 6.592-5.127c0.919 2.104-0.592 5.127-0.592 5.127l4-3v-3l5-5 1-5-5 1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImRss, "RSS"))]
 pub static ImRss: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImRss",
+    label: "RSS",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5773,8 +7890,14 @@ pub static ImRss: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M2.13 11.733c-1.175 0-2.13 0.958-2.13 2.126 0 1.174 0.955 2.122 2.13 2.122 1.179 0 2.133-0.948 2.133-2.122-0-1.168-0.954-2.126-2.133-2.126zM0.002 5.436v3.067c1.997 0 3.874 0.781 5.288 2.196 1.412 1.411 2.192 3.297 2.192 5.302h3.080c-0-5.825-4.739-10.564-10.56-10.564zM0.006 0v3.068c7.122 0 12.918 5.802 12.918 12.932h3.076c0-8.82-7.176-16-15.994-16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImRss2, "RSS 2"))]
 pub static ImRss2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImRss2",
+    label: "RSS 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5789,8 +7912,14 @@ pub static ImRss2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM4.359 12.988c-0.75 0-1.359-0.603-1.359-1.353 0-0.744 0.609-1.356 1.359-1.356 0.753 0 1.359 0.613 1.359 1.356 0 0.75-0.609 1.353-1.359 1.353zM7.772 13c0-1.278-0.497-2.481-1.397-3.381-0.903-0.903-2.1-1.4-3.375-1.4v-1.956c3.713 0 6.738 3.022 6.738 6.737h-1.966zM11.244 13c0-4.547-3.697-8.25-8.241-8.25v-1.956c5.625 0 10.203 4.581 10.203 10.206h-1.963z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImRtl, "Rtl"))]
 pub static ImRtl: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImRtl",
+    label: "Rtl",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5805,8 +7934,14 @@ pub static ImRtl: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4 0c-2.209 0-4 1.791-4 4s1.791 4 4 4v8h2v-14h2v14h2v-14h2v-2h-8zM16 3l-4 4 4 4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSad, "Sad"))]
 pub static ImSad: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSad",
+    label: "Sad",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5821,8 +7956,14 @@ pub static ImSad: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c4.418 0 8-3.582 8-8s-3.582-8-8-8-8 3.582-8 8 3.582 8 8 8zM8 1.5c3.59 0 6.5 2.91 6.5 6.5s-2.91 6.5-6.5 6.5-6.5-2.91-6.5-6.5 2.91-6.5 6.5-6.5zM4 5c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1zM10 5c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1zM4.998 12.199l-1.286-0.772c0.874-1.454 2.467-2.427 4.288-2.427s3.413 0.973 4.288 2.427l-1.286 0.772c-0.612-1.018-1.727-1.699-3.002-1.699s-2.389 0.681-3.002 1.699z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSad2, "Sad 2"))]
 pub static ImSad2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSad2",
+    label: "Sad 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5837,8 +7978,14 @@ pub static ImSad2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM11 4c0.552 0 1 0.448 1 1s-0.448 1-1 1-1-0.448-1-1 0.448-1 1-1zM5 4c0.552 0 1 0.448 1 1s-0.448 1-1 1-1-0.448-1-1 0.448-1 1-1zM11.002 12.199c-0.612-1.018-1.727-1.699-3.002-1.699s-2.389 0.681-3.002 1.699l-1.286-0.772c0.874-1.454 2.467-2.427 4.288-2.427s3.414 0.973 4.288 2.427l-1.286 0.772z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSafari, "Safari"))]
 pub static ImSafari: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSafari",
+    label: "Safari",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5885,8 +8032,14 @@ pub static ImSafari: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9.243 1.109l0.245 0.049-0.293 1.471-0.245-0.049 0.293-1.471z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImScissors, "Scissors"))]
 pub static ImScissors: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImScissors",
+    label: "Scissors",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5901,8 +8054,14 @@ pub static ImScissors: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.279 10.62c-1.042-1.628-2.829-2.345-3.992-1.601-0.1 0.064-0.193 0.138-0.277 0.218l-1.241-1.942 2.867-4.5c0.235-0.433 0.321-0.949 0.207-1.468-0.109-0.496-0.383-0.913-0.752-1.207l-0.192-0.122-3.398 5.314-3.398-5.314-0.192 0.122c-0.369 0.294-0.643 0.711-0.752 1.207-0.114 0.519-0.027 1.035 0.207 1.468l2.867 4.5-1.241 1.942c-0.085-0.081-0.177-0.154-0.277-0.218-1.163-0.744-2.95-0.028-3.992 1.601s-0.944 3.551 0.219 4.296c1.163 0.744 2.95 0.028 3.992-1.601l2.567-4.029 2.567 4.029c1.042 1.628 2.829 2.345 3.992 1.601s1.261-2.667 0.219-4.296zM3.67 12.507c-0.469 0.733-1.071 1.089-1.478 1.179-0 0-0 0-0 0-0.133 0.029-0.317 0.047-0.443-0.033-0.139-0.089-0.231-0.324-0.247-0.629-0.025-0.494 0.151-1.076 0.483-1.594 0.469-0.733 1.071-1.089 1.478-1.179 0.133-0.029 0.317-0.047 0.443 0.033 0.139 0.089 0.231 0.324 0.247 0.629 0.025 0.495-0.151 1.076-0.483 1.594zM7.5 8c-0.276 0-0.5-0.224-0.5-0.5s0.224-0.5 0.5-0.5 0.5 0.224 0.5 0.5-0.224 0.5-0.5 0.5zM13.498 13.023c-0.016 0.305-0.108 0.54-0.247 0.629-0.125 0.080-0.31 0.062-0.443 0.033 0 0 0 0-0 0-0.407-0.089-1.009-0.446-1.478-1.179-0.332-0.519-0.508-1.1-0.483-1.594 0.016-0.305 0.108-0.54 0.247-0.629 0.125-0.080 0.31-0.062 0.443-0.033 0.407 0.089 1.009 0.446 1.478 1.179 0.332 0.519 0.508 1.1 0.483 1.594z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSearch, "Search"))]
 pub static ImSearch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSearch",
+    label: "Search",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5917,8 +8076,14 @@ pub static ImSearch: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.504 13.616l-3.79-3.223c-0.392-0.353-0.811-0.514-1.149-0.499 0.895-1.048 1.435-2.407 1.435-3.893 0-3.314-2.686-6-6-6s-6 2.686-6 6 2.686 6 6 6c1.486 0 2.845-0.54 3.893-1.435-0.016 0.338 0.146 0.757 0.499 1.149l3.223 3.79c0.552 0.613 1.453 0.665 2.003 0.115s0.498-1.452-0.115-2.003zM6 10c-2.209 0-4-1.791-4-4s1.791-4 4-4 4 1.791 4 4-1.791 4-4 4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSection, "Section"))]
 pub static ImSection: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSection",
+    label: "Section",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5933,8 +8098,14 @@ pub static ImSection: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7.749 16c-0.771 0-1.424-0.225-1.939-0.669-0.519-0.447-0.782-0.969-0.782-1.552 0-0.283 0.103-0.527 0.307-0.726 0.207-0.202 0.465-0.309 0.748-0.309 0.281 0 0.534 0.1 0.732 0.29 0.195 0.187 0.294 0.435 0.294 0.736 0 0.177-0.029 0.372-0.086 0.58-0.056 0.206-0.068 0.312-0.068 0.364 0 0.058 0.014 0.126 0.121 0.199 0.199 0.138 0.439 0.204 0.732 0.204 0.353 0 0.667-0.123 0.962-0.375 0.29-0.249 0.431-0.505 0.431-0.782 0-0.308-0.082-0.575-0.252-0.816-0.287-0.402-0.826-0.874-1.603-1.401-1.248-0.835-2.079-1.559-2.54-2.211-0.358-0.511-0.539-1.061-0.539-1.636 0-0.579 0.19-1.155 0.564-1.713 0.32-0.477 0.794-0.908 1.41-1.283-0.33-0.355-0.577-0.689-0.736-0.995-0.201-0.387-0.303-0.787-0.303-1.189 0-0.747 0.295-1.393 0.878-1.92s1.31-0.795 2.161-0.795c0.783 0 1.441 0.22 1.956 0.654 0.521 0.439 0.785 0.952 0.785 1.524 0 0.292-0.109 0.553-0.324 0.776l-0.004 0.004c-0.125 0.124-0.353 0.271-0.735 0.271-0.299 0-0.561-0.098-0.758-0.283-0.196-0.184-0.296-0.405-0.296-0.656 0-0.108 0.027-0.272 0.084-0.515 0.028-0.115 0.042-0.221 0.042-0.316 0-0.162-0.058-0.285-0.183-0.39-0.129-0.108-0.314-0.161-0.565-0.161-0.389 0-0.708 0.118-0.975 0.361s-0.399 0.533-0.399 0.883c0 0.315 0.071 0.574 0.212 0.771 0.267 0.374 0.731 0.778 1.378 1.201 1.315 0.853 2.233 1.636 2.727 2.325 0.365 0.518 0.549 1.068 0.549 1.637 0 0.572-0.186 1.148-0.552 1.714-0.316 0.487-0.793 0.926-1.42 1.308 0.347 0.367 0.591 0.688 0.743 0.977 0.189 0.359 0.284 0.751 0.284 1.165 0 0.776-0.296 1.435-0.879 1.96s-1.31 0.79-2.161 0.79zM6.975 5.568c-0.753 0.452-1.12 0.972-1.12 1.583 0 0.356 0.102 0.674 0.31 0.973 0.311 0.436 0.926 0.97 1.825 1.583 0.381 0.259 0.724 0.511 1.025 0.751 0.767-0.461 1.14-0.974 1.14-1.565 0-0.322-0.127-0.668-0.378-1.030-0.263-0.378-0.826-0.872-1.674-1.467-0.443-0.306-0.821-0.583-1.128-0.827z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImShare, "Share"))]
 pub static ImShare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImShare",
+    label: "Share",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5949,8 +8120,14 @@ pub static ImShare: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4 10c0 0 0.919-3 6-3v3l6-4-6-4v3c-4 0-6 2.495-6 5zM11 12h-9v-6h1.967c0.158-0.186 0.327-0.365 0.508-0.534 0.687-0.644 1.509-1.135 2.439-1.466h-6.914v10h13v-4.197l-2 1.333v0.864z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImShare2, "Share 2"))]
 pub static ImShare2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImShare2",
+    label: "Share 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5965,8 +8142,14 @@ pub static ImShare2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.5 11c-0.706 0-1.342 0.293-1.797 0.763l-6.734-3.367c0.021-0.129 0.032-0.261 0.032-0.396s-0.011-0.267-0.032-0.396l6.734-3.367c0.455 0.47 1.091 0.763 1.797 0.763 1.381 0 2.5-1.119 2.5-2.5s-1.119-2.5-2.5-2.5-2.5 1.119-2.5 2.5c0 0.135 0.011 0.267 0.031 0.396l-6.734 3.367c-0.455-0.47-1.091-0.763-1.797-0.763-1.381 0-2.5 1.119-2.5 2.5s1.119 2.5 2.5 2.5c0.706 0 1.343-0.293 1.797-0.763l6.734 3.367c-0.021 0.129-0.031 0.261-0.031 0.396 0 1.381 1.119 2.5 2.5 2.5s2.5-1.119 2.5-2.5c0-1.381-1.119-2.5-2.5-2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImShield, "Shield"))]
 pub static ImShield: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImShield",
+    label: "Shield",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5981,8 +8164,14 @@ pub static ImShield: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15 0l-7 2-7-2c0 0-0.070 0.808 0 2l7 2.189 7-2.189c0.070-1.192 0-2 0-2zM1.128 3.049c0.375 3.917 1.773 10.504 6.872 12.951 5.099-2.448 6.497-9.034 6.872-12.951l-6.872 2.584-6.872-2.584z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImShift, "Shift"))]
 pub static ImShift: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImShift",
+    label: "Shift",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5997,8 +8186,14 @@ pub static ImShift: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M10.5 14h-5c-0.276 0-0.5-0.224-0.5-0.5v-5.5h-2c-0.202 0-0.385-0.122-0.462-0.309s-0.035-0.402 0.108-0.545l5-5c0.195-0.195 0.512-0.195 0.707 0l5 5c0.143 0.143 0.186 0.358 0.108 0.545s-0.26 0.309-0.462 0.309h-2v5.5c0 0.276-0.224 0.5-0.5 0.5zM6 13h4v-5.5c0-0.276 0.224-0.5 0.5-0.5h1.293l-3.793-3.793-3.793 3.793h1.293c0.276 0 0.5 0.224 0.5 0.5v5.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImShocked, "Shocked"))]
 pub static ImShocked: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImShocked",
+    label: "Shocked",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6013,8 +8208,14 @@ pub static ImShocked: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c4.418 0 8-3.582 8-8s-3.582-8-8-8-8 3.582-8 8 3.582 8 8 8zM8 1.5c3.59 0 6.5 2.91 6.5 6.5s-2.91 6.5-6.5 6.5-6.5-2.91-6.5-6.5 2.91-6.5 6.5-6.5zM6 11c0-1.105 0.895-2 2-2s2 0.895 2 2c0 1.105-0.895 2-2 2s-2-0.895-2-2zM10 5.5c0-0.828 0.448-1.5 1-1.5s1 0.672 1 1.5c0 0.828-0.448 1.5-1 1.5s-1-0.672-1-1.5zM4 5.5c0-0.828 0.448-1.5 1-1.5s1 0.672 1 1.5c0 0.828-0.448 1.5-1 1.5s-1-0.672-1-1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImShocked2, "Shocked 2"))]
 pub static ImShocked2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImShocked2",
+    label: "Shocked 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6029,8 +8230,14 @@ pub static ImShocked2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM5 7c-0.552 0-1-0.672-1-1.5s0.448-1.5 1-1.5 1 0.672 1 1.5-0.448 1.5-1 1.5zM8 13c-1.105 0-2-0.895-2-2s0.895-2 2-2c1.105 0 2 0.895 2 2s-0.895 2-2 2zM11 7c-0.552 0-1-0.672-1-1.5s0.448-1.5 1-1.5 1 0.672 1 1.5-0.448 1.5-1 1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImShrink, "Shrink"))]
 pub static ImShrink: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImShrink",
+    label: "Shrink",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6048,8 +8255,14 @@ pub static ImShrink: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 7v-6.5l-2.5 2.5-3-3-1.5 1.5 3 3-2.5 2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImShrink2, "Shrink 2"))]
 pub static ImShrink2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImShrink2",
+    label: "Shrink 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6064,8 +8277,14 @@ pub static ImShrink2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7 9v6.5l-2.5-2.5-3 3-1.5-1.5 3-3-2.5-2.5zM16 1.5l-3 3 2.5 2.5h-6.5v-6.5l2.5 2.5 3-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImShuffle, "Shuffle"))]
 pub static ImShuffle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImShuffle",
+    label: "Shuffle",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6080,8 +8299,14 @@ pub static ImShuffle: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 11h-1.586l-2.5-2.5 2.5-2.5h1.586v2.5l3.5-3.5-3.5-3.5v2.5h-2c-0.265 0-0.52 0.105-0.707 0.293l-2.793 2.793-2.793-2.793c-0.188-0.188-0.442-0.293-0.707-0.293h-3v2h2.586l2.5 2.5-2.5 2.5h-2.586v2h3c0.265 0 0.52-0.105 0.707-0.293l2.793-2.793 2.793 2.793c0.188 0.188 0.442 0.293 0.707 0.293h2v2.5l3.5-3.5-3.5-3.5v2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSigma, "Sigma"))]
 pub static ImSigma: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSigma",
+    label: "Sigma",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6096,8 +8321,14 @@ pub static ImSigma: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.713 11.48l0.694-1.48h0.594l-1 6h-15v-1.16l5.18-6.113-5.18-5.18v-3.547h15.313l0.688 4h-0.537l-0.293-0.607c-0.552-1.146-0.967-1.393-2.17-1.393h-10.344l5.517 5.516-4.647 5.483h8.474c1.813 0 2.291-0.65 2.713-1.52z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSinaWeibo, "Sina Weibo"))]
 pub static ImSinaWeibo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSinaWeibo",
+    label: "Sina Weibo",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6115,8 +8346,14 @@ pub static ImSinaWeibo: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6.869 10.884c-0.094 0.159-0.297 0.234-0.456 0.169-0.159-0.063-0.206-0.244-0.116-0.397 0.094-0.153 0.291-0.228 0.447-0.169 0.156 0.056 0.213 0.234 0.125 0.397zM6.022 11.966c-0.256 0.409-0.806 0.588-1.219 0.4-0.406-0.184-0.528-0.659-0.272-1.059 0.253-0.397 0.784-0.575 1.194-0.403 0.416 0.178 0.55 0.65 0.297 1.063zM6.984 9.072c-1.259-0.328-2.684 0.3-3.231 1.409-0.559 1.131-0.019 2.391 1.253 2.803 1.319 0.425 2.875-0.228 3.416-1.447 0.534-1.197-0.131-2.425-1.438-2.766z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSkype, "Skype"))]
 pub static ImSkype: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSkype",
+    label: "Skype",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6135,8 +8372,14 @@ pub static ImSkype: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15.434 9.331c-0.006 0.028-0.009 0.056-0.016 0.084l-0.028-0.162c0.016 0.028 0.028 0.053 0.044 0.078 0.081-0.45 0.125-0.909 0.125-1.369 0-1.019-0.2-2.009-0.594-2.941-0.381-0.9-0.925-1.709-1.619-2.403s-1.503-1.238-2.4-1.619c-0.931-0.394-1.922-0.594-2.941-0.594-0.481 0-0.963 0.044-1.431 0.134 0 0-0.003 0-0.003 0 0.025 0.012 0.053 0.025 0.078 0.041l-0.159-0.025c0.028-0.006 0.053-0.009 0.081-0.016-0.644-0.341-1.366-0.525-2.097-0.525-1.194 0-2.319 0.466-3.163 1.309s-1.309 1.969-1.309 3.163c0 0.759 0.197 1.509 0.563 2.169 0.006-0.028 0.009-0.056 0.012-0.081l0.028 0.159c-0.016-0.025-0.028-0.050-0.041-0.075-0.075 0.428-0.112 0.866-0.112 1.303 0 1.019 0.2 2.009 0.594 2.941 0.381 0.9 0.925 1.706 1.619 2.4s1.503 1.238 2.403 1.619c0.931 0.394 1.922 0.594 2.941 0.594 0.444 0 0.887-0.041 1.322-0.119-0.025-0.016-0.050-0.028-0.078-0.041l0.162 0.028c-0.028 0.006-0.056 0.009-0.084 0.012 0.669 0.378 1.428 0.581 2.2 0.581 1.194 0 2.319-0.466 3.162-1.309s1.309-1.969 1.309-3.162c-0.003-0.759-0.2-1.509-0.569-2.175zM8.034 12.591c-2.684 0-3.884-1.319-3.884-2.309 0-0.506 0.375-0.863 0.891-0.863 1.15 0 0.85 1.65 2.994 1.65 1.097 0 1.703-0.597 1.703-1.206 0-0.366-0.181-0.772-0.903-0.95l-2.388-0.597c-1.922-0.481-2.272-1.522-2.272-2.5 0-2.028 1.909-2.791 3.703-2.791 1.653 0 3.6 0.913 3.6 2.131 0 0.522-0.453 0.825-0.969 0.825-0.981 0-0.8-1.356-2.775-1.356-0.981 0-1.522 0.444-1.522 1.078s0.775 0.838 1.447 0.991l1.769 0.394c1.934 0.431 2.425 1.563 2.425 2.625 0 1.647-1.266 2.878-3.819 2.878z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSleepy, "Sleepy"))]
 pub static ImSleepy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSleepy",
+    label: "Sleepy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6154,8 +8397,14 @@ pub static ImSleepy: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11.5 5.313c-0.128 0-0.256-0.049-0.354-0.146-0.302-0.302-0.991-0.302-1.293 0-0.195 0.195-0.512 0.195-0.707 0s-0.195-0.512 0-0.707c0.696-0.696 2.011-0.696 2.707 0 0.195 0.195 0.195 0.512 0 0.707-0.098 0.098-0.226 0.146-0.354 0.146z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSleepy2, "Sleepy 2"))]
 pub static ImSleepy2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSleepy2",
+    label: "Sleepy 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6170,8 +8419,14 @@ pub static ImSleepy2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM4.854 5.166c-0.195 0.195-0.512 0.195-0.707 0s-0.195-0.512 0-0.707c0.696-0.696 2.011-0.696 2.707 0 0.195 0.195 0.195 0.512 0 0.707-0.098 0.098-0.226 0.146-0.354 0.146s-0.256-0.049-0.354-0.146c-0.302-0.302-0.991-0.302-1.293 0zM8 13c-1.105 0-2-1.119-2-2.5s0.895-2.5 2-2.5 2 1.119 2 2.5-0.895 2.5-2 2.5zM11.854 5.166c-0.098 0.098-0.226 0.146-0.354 0.146s-0.256-0.049-0.354-0.146c-0.302-0.302-0.991-0.302-1.293 0-0.195 0.195-0.512 0.195-0.707 0s-0.195-0.512 0-0.707c0.696-0.696 2.011-0.696 2.707 0 0.195 0.195 0.195 0.512 0 0.707z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSmile, "Smile"))]
 pub static ImSmile: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSmile",
+    label: "Smile",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6186,8 +8441,14 @@ pub static ImSmile: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c4.418 0 8-3.582 8-8s-3.582-8-8-8-8 3.582-8 8 3.582 8 8 8zM8 1.5c3.59 0 6.5 2.91 6.5 6.5s-2.91 6.5-6.5 6.5-6.5-2.91-6.5-6.5 2.91-6.5 6.5-6.5zM4 5c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1zM10 5c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1zM11.002 9.801l1.286 0.772c-0.874 1.454-2.467 2.427-4.288 2.427s-3.413-0.973-4.288-2.427l1.286-0.772c0.612 1.018 1.727 1.699 3.002 1.699s2.389-0.681 3.002-1.699z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSmile2, "Smile 2"))]
 pub static ImSmile2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSmile2",
+    label: "Smile 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6202,8 +8463,14 @@ pub static ImSmile2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM11 4c0.552 0 1 0.448 1 1s-0.448 1-1 1-1-0.448-1-1 0.448-1 1-1zM5 4c0.552 0 1 0.448 1 1s-0.448 1-1 1-1-0.448-1-1 0.448-1 1-1zM8 13c-1.82 0-3.413-0.973-4.288-2.427l1.286-0.772c0.612 1.018 1.727 1.699 3.002 1.699s2.389-0.681 3.002-1.699l1.286 0.772c-0.874 1.454-2.467 2.427-4.288 2.427z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSortAlphaAsc, "Sort Alpha Asc"))]
 pub static ImSortAlphaAsc: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSortAlphaAsc",
+    label: "Sort Alpha Asc",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6220,8 +8487,14 @@ pub static ImSortAlphaAsc: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15.947 6.276l-3-6c-0.085-0.169-0.258-0.276-0.447-0.276s-0.363 0.107-0.447 0.276l-3 6c-0.123 0.247-0.023 0.547 0.224 0.671 0.072 0.036 0.148 0.053 0.223 0.053 0.183 0 0.36-0.101 0.448-0.277l0.862-1.724h3.382l0.862 1.724c0.123 0.247 0.424 0.347 0.671 0.224s0.347-0.424 0.224-0.671zM11.309 4l1.191-2.382 1.191 2.382h-2.382z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSortAlphaDesc, "Sort Alpha Desc"))]
 pub static ImSortAlphaDesc: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSortAlphaDesc",
+    label: "Sort Alpha Desc",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6238,8 +8511,14 @@ pub static ImSortAlphaDesc: &icondata_core::IconData = &icondata_core::IconData
 <path d="M15.947 15.276l-3-6c-0.085-0.169-0.258-0.276-0.447-0.276s-0.363 0.107-0.447 0.276l-3 6c-0.123 0.247-0.023 0.547 0.224 0.671 0.072 0.036 0.148 0.053 0.223 0.053 0.183 0 0.36-0.101 0.448-0.277l0.862-1.724h3.382l0.862 1.724c0.123 0.247 0.424 0.347 0.671 0.224s0.347-0.424 0.224-0.671zM11.309 13l1.191-2.382 1.191 2.382h-2.382z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSortAmountAsc, "Sort Amount Asc"))]
 pub static ImSortAmountAsc: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSortAmountAsc",
+    label: "Sort Amount Asc",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6258,8 +8537,14 @@ pub static ImSortAmountAsc: &icondata_core::IconData = &icondata_core::IconData
 <path d="M7 0h3v2h-3v-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSortAmountDesc, "Sort Amount Desc"))]
 pub static ImSortAmountDesc: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSortAmountDesc",
+    label: "Sort Amount Desc",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6278,8 +8563,14 @@ pub static ImSortAmountDesc: &icondata_core::IconData = &icondata_core::IconData
 <path d="M7 9h3v2h-3v-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSortNumbericDesc, "Sort Numberic Desc"))]
 pub static ImSortNumbericDesc: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSortNumbericDesc",
+    label: "Sort Numberic Desc",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6296,8 +8587,14 @@ pub static ImSortNumbericDesc: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M14.5 0h-3c-0.276 0-0.5 0.224-0.5 0.5v3c0 0.276 0.224 0.5 0.5 0.5h2.5v2h-2.5c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5h3c0.276 0 0.5-0.224 0.5-0.5v-6c0-0.276-0.224-0.5-0.5-0.5zM12 1h2v2h-2v-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSortNumericAsc, "Sort Numeric Asc"))]
 pub static ImSortNumericAsc: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSortNumericAsc",
+    label: "Sort Numeric Asc",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6314,8 +8611,14 @@ pub static ImSortNumericAsc: &icondata_core::IconData = &icondata_core::IconData
 <path d="M14.5 9h-3c-0.276 0-0.5 0.224-0.5 0.5v3c0 0.276 0.224 0.5 0.5 0.5h2.5v2h-2.5c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5h3c0.276 0 0.5-0.224 0.5-0.5v-6c0-0.276-0.224-0.5-0.5-0.5zM12 10h2v2h-2v-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSoundcloud, "Soundcloud"))]
 pub static ImSoundcloud: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSoundcloud",
+    label: "Soundcloud",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6330,8 +8633,14 @@ pub static ImSoundcloud: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.937 8.034c-0.283 0-0.552 0.055-0.798 0.154-0.164-1.787-1.723-3.188-3.625-3.188-0.465 0-0.917 0.088-1.317 0.237-0.156 0.058-0.197 0.117-0.197 0.233v6.292c0 0.121 0.098 0.222 0.221 0.234 0.005 0.001 5.68 0.003 5.717 0.003 1.139 0 2.062-0.888 2.062-1.983s-0.924-1.983-2.063-1.983zM6.25 12h0.5l0.25-3.503-0.25-3.497h-0.5l-0.25 3.497zM4.75 12h-0.5l-0.25-2.543 0.25-2.457h0.5l0.25 2.5zM2.25 12h0.5l0.25-2-0.25-2h-0.5l-0.25 2zM0.25 11h0.5l0.25-1-0.25-1h-0.5l-0.25 1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSoundcloud2, "Soundcloud 2"))]
 pub static ImSoundcloud2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSoundcloud2",
+    label: "Soundcloud 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6346,8 +8655,14 @@ pub static ImSoundcloud2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM2.75 11h-0.5l-0.25-1.5 0.25-1.5h0.5l0.25 1.5-0.25 1.5zM4.75 11h-0.5l-0.25-2 0.25-2h0.5l0.25 2-0.25 2zM6.75 11h-0.5l-0.25-3 0.25-3h0.5l0.25 3-0.25 3zM12.894 11c-0.031 0-4.706-0.003-4.709-0.003-0.1-0.009-0.181-0.097-0.184-0.2v-5.394c0-0.1 0.034-0.15 0.162-0.2 0.331-0.128 0.703-0.203 1.088-0.203 1.566 0 2.85 1.2 2.987 2.734 0.203-0.084 0.425-0.131 0.656-0.131 0.938 0 1.7 0.762 1.7 1.7s-0.762 1.697-1.7 1.697z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpades, "Spades"))]
 pub static ImSpades: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpades",
+    label: "Spades",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6362,8 +8677,14 @@ pub static ImSpades: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12.775 5.44c-3.024-2.248-4.067-4.047-4.774-5.44v0c-0 0-0-0-0-0v0c-0.708 1.393-1.75 3.192-4.774 5.44-5.157 3.833-0.303 9.182 3.965 6.238-0.278 1.827-1.227 3.159-2.191 3.733v0.59h6v-0.59c-0.964-0.574-1.913-1.906-2.191-3.733 4.268 2.944 9.122-2.405 3.965-6.238z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpellCheck, "Spell Check"))]
 pub static ImSpellCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpellCheck",
+    label: "Spell Check",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6378,8 +8699,14 @@ pub static ImSpellCheck: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M2 4h2v3h1v-6c0-0.55-0.45-1-1-1h-2c-0.55 0-1 0.45-1 1v6h1v-3zM2 1h2v2h-2v-2zM15 1v-1h-3c-0.55 0-1 0.45-1 1v5c0 0.55 0.45 1 1 1h3v-1h-3v-5h3zM10 2.5v-1.5c0-0.55-0.45-1-1-1h-3v7h3c0.55 0 1-0.45 1-1v-1.5c0-0.55-0.137-1-0.688-1 0.55 0 0.688-0.45 0.688-1zM9 6h-2v-2h2v2zM9 3h-2v-2h2v2zM13 9l-6.5 7-3.5-4.5 1.281-1.094 2.219 2.313 5.5-4.719z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSphere, "Sphere"))]
 pub static ImSphere: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSphere",
+    label: "Sphere",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6394,8 +8721,14 @@ pub static ImSphere: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7.5 1c-4.142 0-7.5 3.358-7.5 7.5s3.358 7.5 7.5 7.5c4.142 0 7.5-3.358 7.5-7.5s-3.358-7.5-7.5-7.5zM11.744 11c0.134-0.632 0.219-1.303 0.246-2h1.991c-0.052 0.691-0.213 1.361-0.479 2h-1.758zM3.256 6c-0.134 0.632-0.219 1.303-0.246 2h-1.991c0.052-0.691 0.213-1.361 0.479-2h1.758zM10.719 6c0.15 0.64 0.241 1.31 0.27 2h-2.989v-2h2.719zM8 5v-2.927c0.228 0.066 0.454 0.178 0.675 0.334 0.415 0.293 0.813 0.744 1.149 1.304 0.233 0.388 0.434 0.819 0.601 1.289h-2.426zM5.176 3.711c0.336-0.561 0.734-1.012 1.149-1.304 0.222-0.156 0.447-0.268 0.675-0.334v2.927h-2.426c0.168-0.47 0.369-0.901 0.601-1.289zM7 6v2h-2.989c0.029-0.69 0.12-1.36 0.27-2h2.719zM1.498 11c-0.266-0.639-0.427-1.309-0.479-2h1.991c0.028 0.697 0.112 1.368 0.246 2h-1.758zM4.011 9h2.989v2h-2.719c-0.15-0.64-0.241-1.31-0.27-2zM7 12v2.927c-0.228-0.066-0.454-0.178-0.675-0.334-0.415-0.293-0.813-0.744-1.149-1.304-0.233-0.388-0.434-0.819-0.602-1.289h2.426zM9.825 13.289c-0.336 0.561-0.734 1.012-1.149 1.304-0.222 0.156-0.447 0.268-0.675 0.334v-2.927h2.426c-0.168 0.47-0.369 0.901-0.602 1.289zM8 11v-2h2.989c-0.029 0.69-0.12 1.36-0.27 2h-2.719zM11.99 8c-0.028-0.697-0.112-1.368-0.246-2h1.758c0.267 0.639 0.427 1.309 0.479 2h-1.991zM12.979 5h-1.498c-0.291-0.918-0.693-1.723-1.177-2.366 0.665 0.318 1.267 0.744 1.792 1.27 0.336 0.336 0.631 0.702 0.883 1.096zM2.904 3.904c0.526-0.526 1.128-0.952 1.792-1.27-0.483 0.643-0.886 1.448-1.177 2.366h-1.498c0.252-0.394 0.547-0.761 0.883-1.096zM2.021 12h1.498c0.291 0.918 0.693 1.723 1.177 2.366-0.665-0.318-1.267-0.744-1.792-1.27-0.336-0.336-0.631-0.702-0.883-1.096zM12.096 13.096c-0.526 0.526-1.128 0.952-1.792 1.27 0.483-0.643 0.886-1.448 1.177-2.366h1.498c-0.252 0.394-0.547 0.761-0.883 1.096z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpinner, "Spinner"))]
 pub static ImSpinner: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpinner",
+    label: "Spinner",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6410,8 +8743,14 @@ pub static ImSpinner: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6 2c0-1.105 0.895-2 2-2s2 0.895 2 2c0 1.105-0.895 2-2 2s-2-0.895-2-2zM10.243 3.757c0-1.105 0.895-2 2-2s2 0.895 2 2c0 1.105-0.895 2-2 2s-2-0.895-2-2zM13 8c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1zM11.243 12.243c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1zM7 14c0 0 0 0 0 0 0-0.552 0.448-1 1-1s1 0.448 1 1c0 0 0 0 0 0 0 0.552-0.448 1-1 1s-1-0.448-1-1zM2.757 12.243c0 0 0 0 0 0 0-0.552 0.448-1 1-1s1 0.448 1 1c0 0 0 0 0 0 0 0.552-0.448 1-1 1s-1-0.448-1-1zM2.257 3.757c0 0 0 0 0 0 0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5c0 0 0 0 0 0 0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5zM0.875 8c0-0.621 0.504-1.125 1.125-1.125s1.125 0.504 1.125 1.125c0 0.621-0.504 1.125-1.125 1.125s-1.125-0.504-1.125-1.125z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpinner10, "Spinner 10"))]
 pub static ImSpinner10: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpinner10",
+    label: "Spinner 10",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6426,8 +8765,14 @@ pub static ImSpinner10: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0.001 8.025l-0 0c0 0 0 0.001 0 0.003 0.002 0.061 0.009 0.12 0.021 0.177 0.003 0.027 0.007 0.057 0.011 0.090 0.003 0.029 0.007 0.061 0.011 0.095 0.006 0.040 0.012 0.083 0.019 0.128 0.013 0.090 0.028 0.189 0.045 0.296 0.021 0.101 0.044 0.21 0.068 0.326 0.011 0.058 0.028 0.117 0.044 0.178s0.032 0.123 0.049 0.188c0.009 0.032 0.016 0.065 0.027 0.097s0.021 0.065 0.031 0.098 0.043 0.134 0.065 0.203c0.006 0.017 0.011 0.035 0.017 0.052s0.013 0.034 0.019 0.052 0.026 0.070 0.039 0.105c0.027 0.070 0.053 0.142 0.081 0.215 0.031 0.071 0.062 0.144 0.094 0.218 0.016 0.037 0.032 0.074 0.048 0.111s0.035 0.073 0.053 0.111 0.073 0.148 0.11 0.224c0.039 0.075 0.081 0.149 0.123 0.224 0.021 0.037 0.042 0.075 0.063 0.113s0.045 0.074 0.068 0.112 0.093 0.149 0.14 0.224c0.198 0.295 0.417 0.587 0.66 0.864 0.245 0.275 0.511 0.535 0.792 0.775 0.284 0.236 0.582 0.452 0.886 0.642 0.306 0.188 0.619 0.349 0.928 0.487 0.078 0.032 0.156 0.063 0.232 0.095 0.038 0.015 0.076 0.032 0.115 0.046s0.077 0.027 0.115 0.041 0.151 0.054 0.226 0.078c0.075 0.022 0.15 0.044 0.224 0.066 0.037 0.011 0.073 0.022 0.109 0.031s0.073 0.018 0.109 0.027 0.143 0.035 0.213 0.052c0.070 0.014 0.139 0.027 0.207 0.040 0.034 0.006 0.067 0.013 0.101 0.019 0.017 0.003 0.033 0.006 0.049 0.009s0.033 0.005 0.049 0.007c0.066 0.009 0.13 0.018 0.192 0.027 0.031 0.004 0.062 0.009 0.093 0.013s0.061 0.006 0.091 0.009 0.118 0.010 0.174 0.015c0.056 0.005 0.111 0.011 0.164 0.012 0.004 0 0.007 0 0.011 0 0.010 0.544 0.453 0.982 1 0.982 0.008 0 0.017-0 0.025-0.001v0c0 0 0.001-0 0.004-0 0.061-0.002 0.12-0.009 0.177-0.021 0.027-0.003 0.057-0.007 0.090-0.011 0.029-0.003 0.061-0.007 0.095-0.011 0.040-0.006 0.083-0.012 0.128-0.019 0.090-0.013 0.189-0.028 0.296-0.045 0.101-0.021 0.21-0.044 0.326-0.068 0.058-0.011 0.117-0.028 0.178-0.044s0.123-0.033 0.188-0.049c0.032-0.009 0.065-0.016 0.097-0.027s0.065-0.021 0.098-0.031 0.134-0.043 0.203-0.065c0.017-0.006 0.035-0.011 0.052-0.017s0.034-0.013 0.052-0.019 0.070-0.026 0.105-0.039c0.070-0.027 0.142-0.053 0.215-0.081 0.071-0.031 0.144-0.062 0.218-0.094 0.037-0.016 0.074-0.032 0.111-0.048s0.073-0.035 0.111-0.053 0.148-0.073 0.224-0.11c0.075-0.039 0.149-0.081 0.224-0.123 0.037-0.021 0.075-0.042 0.113-0.063s0.074-0.045 0.112-0.068 0.149-0.093 0.224-0.14c0.295-0.197 0.587-0.417 0.864-0.66 0.275-0.245 0.535-0.511 0.775-0.792 0.236-0.284 0.452-0.582 0.642-0.886 0.188-0.306 0.349-0.619 0.487-0.928 0.032-0.078 0.063-0.156 0.095-0.232 0.015-0.038 0.032-0.076 0.046-0.115s0.027-0.077 0.040-0.115 0.054-0.151 0.078-0.226c0.022-0.075 0.044-0.15 0.066-0.224 0.011-0.037 0.022-0.073 0.031-0.109s0.018-0.073 0.027-0.109 0.035-0.143 0.052-0.213c0.014-0.070 0.027-0.139 0.040-0.207 0.006-0.034 0.013-0.067 0.019-0.101 0.003-0.017 0.006-0.033 0.009-0.049s0.005-0.033 0.007-0.050c0.009-0.065 0.018-0.13 0.027-0.192 0.004-0.031 0.009-0.062 0.013-0.093s0.006-0.061 0.009-0.091 0.010-0.118 0.015-0.174c0.005-0.056 0.011-0.111 0.012-0.165 0-0.008 0.001-0.016 0.001-0.025 0.55-0.002 0.996-0.449 0.996-1 0-0.008-0-0.017-0.001-0.025h0c0 0-0-0.001-0-0.003-0.002-0.061-0.009-0.12-0.021-0.177-0.003-0.027-0.007-0.057-0.011-0.090-0.003-0.029-0.007-0.061-0.011-0.095-0.006-0.040-0.012-0.083-0.019-0.128-0.013-0.090-0.028-0.189-0.045-0.296-0.021-0.101-0.044-0.21-0.068-0.326-0.011-0.058-0.028-0.117-0.044-0.178s-0.032-0.123-0.049-0.188c-0.009-0.032-0.016-0.065-0.027-0.097s-0.021-0.065-0.031-0.098-0.043-0.134-0.065-0.203c-0.005-0.017-0.011-0.035-0.017-0.052s-0.013-0.034-0.019-0.052-0.026-0.070-0.039-0.105c-0.027-0.070-0.053-0.142-0.081-0.215-0.031-0.071-0.062-0.144-0.094-0.218-0.016-0.037-0.032-0.074-0.048-0.111s-0.035-0.073-0.053-0.111-0.073-0.148-0.11-0.224c-0.039-0.075-0.081-0.149-0.123-0.224-0.021-0.037-0.042-0.075-0.063-0.113s-0.045-0.074-0.068-0.112-0.093-0.149-0.14-0.224c-0.197-0.295-0.417-0.587-0.66-0.864-0.245-0.275-0.511-0.535-0.792-0.775-0.284-0.236-0.582-0.452-0.886-0.642-0.306-0.188-0.619-0.349-0.928-0.487-0.078-0.032-0.156-0.063-0.232-0.095-0.038-0.015-0.076-0.032-0.115-0.046s-0.077-0.027-0.115-0.040-0.151-0.054-0.226-0.078c-0.075-0.022-0.15-0.044-0.224-0.066-0.037-0.010-0.073-0.022-0.109-0.031s-0.073-0.018-0.109-0.027-0.143-0.035-0.213-0.052c-0.070-0.014-0.139-0.027-0.207-0.040-0.034-0.006-0.067-0.013-0.101-0.019-0.017-0.003-0.033-0.006-0.049-0.009s-0.033-0.005-0.049-0.007c-0.066-0.009-0.13-0.018-0.192-0.027-0.031-0.004-0.062-0.009-0.093-0.013s-0.061-0.006-0.091-0.009-0.118-0.010-0.174-0.015c-0.056-0.005-0.111-0.011-0.164-0.012-0.013-0-0.026-0.001-0.039-0.001-0.010-0.543-0.454-0.981-0.999-0.981-0.008 0-0.017 0-0.025 0.001l-0-0c0 0-0.001 0-0.003 0-0.061 0.002-0.12 0.009-0.177 0.021-0.027 0.003-0.057 0.007-0.090 0.011-0.029 0.003-0.061 0.007-0.095 0.011-0.040 0.006-0.083 0.012-0.128 0.019-0.090 0.013-0.189 0.028-0.296 0.045-0.101 0.021-0.21 0.044-0.326 0.068-0.058 0.011-0.117 0.028-0.178 0.044s-0.123 0.033-0.188 0.049c-0.032 0.009-0.065 0.016-0.097 0.027s-0.065 0.021-0.098 0.031-0.134 0.043-0.203 0.065c-0.017 0.006-0.035 0.011-0.052 0.017s-0.034 0.013-0.052 0.019-0.070 0.026-0.105 0.039c-0.070 0.027-0.142 0.053-0.215 0.081-0.071 0.031-0.144 0.062-0.218 0.094-0.037 0.016-0.074 0.032-0.111 0.048s-0.073 0.035-0.111 0.053-0.148 0.073-0.224 0.11c-0.075 0.039-0.149 0.081-0.224 0.123-0.037 0.021-0.075 0.042-0.113 0.063s-0.074 0.045-0.112 0.068-0.149 0.093-0.224 0.14c-0.295 0.198-0.587 0.417-0.864 0.66-0.275 0.245-0.535 0.511-0.775 0.792-0.236 0.284-0.452 0.582-0.642 0.886-0.188 0.306-0.349 0.619-0.487 0.928-0.032 0.078-0.063 0.156-0.095 0.232-0.015 0.038-0.032 0.076-0.046 0.115s-0.027 0.077-0.040 0.115-0.054 0.151-0.078 0.226c-0.022 0.075-0.044 0.15-0.066 0.224-0.011 0.037-0.022 0.073-0.032 0.109s-0.018 0.073-0.027 0.109-0.035 0.143-0.052 0.213c-0.014 0.070-0.027 0.139-0.040 0.207-0.006 0.034-0.013 0.067-0.019 0.101-0.003 0.017-0.006 0.033-0.009 0.049s-0.005 0.033-0.007 0.050c-0.009 0.065-0.018 0.13-0.027 0.192-0.004 0.031-0.009 0.062-0.013 0.093s-0.006 0.061-0.009 0.091-0.010 0.118-0.015 0.174c-0.005 0.056-0.011 0.111-0.012 0.165-0 0.009-0.001 0.017-0.001 0.025-0.537 0.017-0.967 0.458-0.967 0.999 0 0.008 0 0.017 0.001 0.025zM1.149 7.011c0.001-0.003 0.001-0.006 0.002-0.009 0.010-0.051 0.026-0.102 0.040-0.155s0.030-0.107 0.045-0.163c0.008-0.028 0.015-0.056 0.024-0.084s0.019-0.057 0.028-0.086 0.038-0.116 0.058-0.176c0.005-0.015 0.010-0.030 0.015-0.045s0.012-0.030 0.017-0.045 0.023-0.060 0.035-0.091 0.048-0.123 0.073-0.186c0.028-0.062 0.056-0.125 0.084-0.189 0.014-0.032 0.028-0.064 0.043-0.096s0.032-0.064 0.048-0.096 0.065-0.128 0.098-0.194c0.034-0.065 0.073-0.128 0.109-0.194 0.018-0.032 0.037-0.065 0.056-0.098s0.040-0.064 0.061-0.096c0.041-0.064 0.082-0.129 0.124-0.194 0.176-0.255 0.369-0.506 0.583-0.744 0.217-0.236 0.451-0.459 0.697-0.665 0.25-0.202 0.511-0.385 0.776-0.547 0.268-0.159 0.541-0.294 0.808-0.41 0.068-0.027 0.135-0.053 0.202-0.079 0.033-0.013 0.066-0.027 0.099-0.038s0.067-0.022 0.1-0.033 0.131-0.045 0.196-0.065c0.065-0.018 0.13-0.036 0.194-0.054 0.032-0.009 0.063-0.019 0.095-0.026s0.063-0.014 0.094-0.021 0.123-0.028 0.184-0.042c0.061-0.011 0.12-0.021 0.179-0.032 0.029-0.005 0.058-0.010 0.087-0.015 0.014-0.003 0.029-0.005 0.043-0.008s0.029-0.003 0.043-0.005c0.056-0.007 0.112-0.014 0.166-0.020 0.027-0.003 0.053-0.007 0.080-0.010s0.053-0.004 0.078-0.006 0.102-0.007 0.15-0.011c0.049-0.003 0.095-0.008 0.142-0.008 0.091-0.002 0.177-0.004 0.256-0.006 0.073 0.003 0.14 0.005 0.2 0.007 0.030 0.001 0.058 0.002 0.085 0.002 0.033 0.002 0.064 0.004 0.093 0.006 0.033 0.002 0.063 0.004 0.091 0.006 0.051 0.008 0.103 0.012 0.156 0.012 0.007 0 0.015-0 0.022-0.001 0.002 0 0.004 0 0.004 0v-0c0.487-0.012 0.887-0.372 0.962-0.84 0.008 0.002 0.017 0.004 0.025 0.006 0.051 0.010 0.102 0.026 0.155 0.040s0.107 0.030 0.163 0.045c0.028 0.008 0.056 0.015 0.084 0.024s0.057 0.019 0.086 0.028 0.116 0.038 0.176 0.058c0.015 0.005 0.030 0.010 0.045 0.015s0.030 0.012 0.045 0.017 0.060 0.023 0.091 0.035 0.123 0.048 0.186 0.073c0.062 0.028 0.125 0.056 0.189 0.084 0.032 0.014 0.064 0.028 0.096 0.043s0.064 0.032 0.096 0.048 0.128 0.065 0.194 0.098c0.065 0.034 0.129 0.073 0.194 0.109 0.032 0.018 0.065 0.037 0.098 0.056s0.064 0.040 0.096 0.061 0.129 0.082 0.194 0.124c0.255 0.176 0.506 0.369 0.744 0.583 0.236 0.217 0.459 0.451 0.665 0.697 0.202 0.25 0.385 0.511 0.547 0.776 0.159 0.268 0.294 0.541 0.41 0.808 0.027 0.068 0.053 0.135 0.079 0.202 0.013 0.033 0.027 0.066 0.038 0.099s0.022 0.067 0.033 0.1 0.045 0.131 0.065 0.196c0.018 0.065 0.036 0.13 0.054 0.194 0.009 0.032 0.019 0.063 0.026 0.095s0.014 0.063 0.021 0.094 0.028 0.123 0.042 0.184c0.011 0.061 0.021 0.12 0.032 0.179 0.005 0.029 0.010 0.058 0.015 0.087 0.003 0.014 0.005 0.029 0.008 0.043s0.003 0.029 0.005 0.043c0.007 0.056 0.014 0.112 0.020 0.166 0.003 0.027 0.007 0.053 0.010 0.080s0.004 0.053 0.006 0.078 0.007 0.102 0.011 0.15c0.003 0.049 0.008 0.095 0.008 0.142 0.002 0.091 0.004 0.177 0.006 0.256-0.003 0.073-0.005 0.14-0.007 0.2-0.001 0.030-0.002 0.058-0.002 0.085-0.002 0.033-0.004 0.064-0.006 0.093-0.002 0.033-0.004 0.063-0.006 0.091-0.008 0.051-0.012 0.103-0.012 0.156 0 0.007 0 0.015 0.001 0.022-0 0.002-0 0.004-0 0.004h0c0.012 0.481 0.363 0.877 0.823 0.959-0.001 0.005-0.002 0.009-0.003 0.014-0.010 0.051-0.025 0.102-0.040 0.155s-0.030 0.107-0.045 0.163c-0.008 0.028-0.015 0.056-0.024 0.084s-0.019 0.057-0.028 0.086-0.039 0.116-0.058 0.176c-0.005 0.015-0.010 0.030-0.015 0.045s-0.012 0.030-0.017 0.045-0.023 0.060-0.035 0.091-0.048 0.123-0.073 0.186c-0.028 0.062-0.056 0.125-0.084 0.189-0.014 0.032-0.028 0.064-0.043 0.096s-0.032 0.064-0.048 0.096-0.065 0.128-0.098 0.194c-0.034 0.065-0.073 0.129-0.109 0.194-0.018 0.032-0.037 0.065-0.056 0.098s-0.040 0.064-0.061 0.096-0.082 0.129-0.124 0.194c-0.176 0.255-0.369 0.506-0.583 0.744-0.217 0.236-0.451 0.459-0.697 0.665-0.25 0.202-0.511 0.385-0.776 0.547-0.268 0.159-0.541 0.294-0.808 0.41-0.068 0.027-0.135 0.053-0.202 0.079-0.033 0.013-0.066 0.027-0.099 0.038s-0.067 0.022-0.1 0.033-0.131 0.045-0.196 0.065c-0.065 0.018-0.13 0.036-0.194 0.054-0.032 0.009-0.063 0.019-0.095 0.026s-0.063 0.014-0.094 0.021-0.123 0.028-0.184 0.042c-0.061 0.011-0.12 0.021-0.179 0.032-0.029 0.005-0.058 0.010-0.087 0.015-0.014 0.003-0.028 0.005-0.043 0.008s-0.029 0.003-0.043 0.005c-0.056 0.007-0.112 0.014-0.166 0.020-0.027 0.003-0.053 0.007-0.080 0.010s-0.053 0.004-0.078 0.006-0.102 0.007-0.15 0.011c-0.049 0.003-0.095 0.008-0.142 0.008-0.091 0.002-0.177 0.004-0.256 0.006-0.073-0.003-0.14-0.005-0.2-0.007-0.030-0.001-0.058-0.002-0.085-0.002-0.033-0.002-0.064-0.004-0.093-0.006-0.033-0.002-0.063-0.004-0.091-0.006-0.051-0.008-0.103-0.012-0.156-0.012-0.007 0-0.015 0-0.022 0.001-0.002-0-0.003-0-0.003-0v0c-0.484 0.012-0.883 0.369-0.961 0.834-0.050-0.010-0.101-0.025-0.153-0.039s-0.107-0.030-0.163-0.045c-0.028-0.008-0.056-0.015-0.084-0.024s-0.057-0.019-0.086-0.028-0.116-0.039-0.176-0.058c-0.015-0.005-0.030-0.010-0.045-0.015s-0.030-0.012-0.045-0.017-0.060-0.023-0.091-0.035-0.123-0.048-0.186-0.073c-0.062-0.028-0.125-0.056-0.189-0.084-0.032-0.014-0.064-0.028-0.096-0.043s-0.064-0.032-0.096-0.048-0.128-0.065-0.194-0.098c-0.065-0.034-0.129-0.073-0.194-0.109-0.032-0.018-0.065-0.037-0.098-0.056s-0.064-0.040-0.096-0.061c-0.064-0.041-0.129-0.082-0.194-0.124-0.255-0.175-0.506-0.369-0.744-0.583-0.236-0.217-0.459-0.451-0.665-0.697-0.202-0.25-0.385-0.511-0.547-0.776-0.159-0.268-0.294-0.541-0.41-0.808-0.027-0.068-0.053-0.135-0.079-0.202-0.013-0.033-0.027-0.066-0.038-0.099s-0.022-0.067-0.033-0.1-0.045-0.131-0.065-0.196c-0.018-0.065-0.036-0.13-0.054-0.194-0.009-0.032-0.019-0.063-0.026-0.095s-0.014-0.063-0.021-0.094-0.028-0.123-0.042-0.184c-0.011-0.061-0.021-0.12-0.032-0.179-0.005-0.029-0.010-0.058-0.015-0.087-0.003-0.014-0.005-0.028-0.008-0.043s-0.003-0.029-0.005-0.043c-0.007-0.056-0.014-0.112-0.020-0.166-0.003-0.027-0.007-0.053-0.010-0.080s-0.004-0.053-0.006-0.078-0.007-0.101-0.011-0.15c-0.003-0.049-0.008-0.095-0.008-0.142-0.002-0.091-0.004-0.177-0.006-0.256 0.003-0.073 0.005-0.14 0.007-0.2 0.001-0.030 0.002-0.058 0.002-0.085 0.002-0.033 0.004-0.064 0.006-0.093 0.002-0.033 0.004-0.063 0.006-0.091 0.008-0.051 0.012-0.103 0.012-0.156 0-0.007-0-0.015-0.001-0.022 0-0.002 0-0.003 0-0.003h-0c-0.012-0.49-0.377-0.893-0.851-0.964z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpinner11, "Spinner 11"))]
 pub static ImSpinner11: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpinner11",
+    label: "Spinner 11",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6442,8 +8787,14 @@ pub static ImSpinner11: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 6h-6l2.243-2.243c-1.133-1.133-2.64-1.757-4.243-1.757s-3.109 0.624-4.243 1.757c-1.133 1.133-1.757 2.64-1.757 4.243s0.624 3.109 1.757 4.243c1.133 1.133 2.64 1.757 4.243 1.757s3.109-0.624 4.243-1.757c0.095-0.095 0.185-0.192 0.273-0.292l1.505 1.317c-1.466 1.674-3.62 2.732-6.020 2.732-4.418 0-8-3.582-8-8s3.582-8 8-8c2.209 0 4.209 0.896 5.656 2.344l2.343-2.344v6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpinner2, "Spinner 2"))]
 pub static ImSpinner2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpinner2",
+    label: "Spinner 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6458,8 +8809,14 @@ pub static ImSpinner2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 8c-0.020-1.045-0.247-2.086-0.665-3.038-0.417-0.953-1.023-1.817-1.766-2.53s-1.624-1.278-2.578-1.651c-0.953-0.374-1.978-0.552-2.991-0.531-1.013 0.020-2.021 0.24-2.943 0.646-0.923 0.405-1.758 0.992-2.449 1.712s-1.237 1.574-1.597 2.497c-0.361 0.923-0.533 1.914-0.512 2.895 0.020 0.981 0.234 1.955 0.627 2.847 0.392 0.892 0.961 1.7 1.658 2.368s1.523 1.195 2.416 1.543c0.892 0.348 1.851 0.514 2.799 0.493 0.949-0.020 1.89-0.227 2.751-0.608 0.862-0.379 1.642-0.929 2.287-1.604s1.154-1.472 1.488-2.335c0.204-0.523 0.342-1.069 0.415-1.622 0.019 0.001 0.039 0.002 0.059 0.002 0.552 0 1-0.448 1-1 0-0.028-0.001-0.056-0.004-0.083h0.004zM14.411 10.655c-0.367 0.831-0.898 1.584-1.55 2.206s-1.422 1.112-2.254 1.434c-0.832 0.323-1.723 0.476-2.608 0.454-0.884-0.020-1.759-0.215-2.56-0.57-0.801-0.354-1.526-0.867-2.125-1.495s-1.071-1.371-1.38-2.173c-0.31-0.801-0.457-1.66-0.435-2.512s0.208-1.694 0.551-2.464c0.342-0.77 0.836-1.468 1.441-2.044s1.321-1.029 2.092-1.326c0.771-0.298 1.596-0.438 2.416-0.416s1.629 0.202 2.368 0.532c0.74 0.329 1.41 0.805 1.963 1.387s0.988 1.27 1.272 2.011c0.285 0.74 0.418 1.532 0.397 2.32h0.004c-0.002 0.027-0.004 0.055-0.004 0.083 0 0.516 0.39 0.94 0.892 0.994-0.097 0.544-0.258 1.075-0.481 1.578z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpinner3, "Spinner 3"))]
 pub static ImSpinner3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpinner3",
+    label: "Spinner 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6485,8 +8842,14 @@ pub static ImSpinner3: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10.098 4.832c-0.079 0-0.159-0.020-0.233-0.063-0.223-0.129-0.299-0.414-0.171-0.637l1.399-2.423c0.129-0.223 0.414-0.299 0.637-0.171s0.299 0.414 0.171 0.637l-1.399 2.423c-0.086 0.15-0.243 0.233-0.404 0.233z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpinner4, "Spinner 4"))]
 pub static ImSpinner4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpinner4",
+    label: "Spinner 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6501,8 +8864,14 @@ pub static ImSpinner4: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M3 8c0-0.19 0.011-0.378 0.032-0.563l-2.89-0.939c-0.092 0.487-0.141 0.989-0.141 1.502 0 2.3 0.971 4.374 2.526 5.833l1.786-2.458c-0.814-0.889-1.312-2.074-1.312-3.375zM13 8c0 1.301-0.497 2.486-1.312 3.375l1.786 2.458c1.555-1.459 2.526-3.533 2.526-5.833 0-0.513-0.049-1.015-0.141-1.502l-2.89 0.939c0.021 0.185 0.032 0.373 0.032 0.563zM9 3.1c1.436 0.292 2.649 1.199 3.351 2.435l2.89-0.939c-1.144-2.428-3.473-4.188-6.241-4.534v3.038zM3.649 5.535c0.702-1.236 1.914-2.143 3.351-2.435v-3.038c-2.769 0.345-5.097 2.105-6.241 4.534l2.89 0.939zM10.071 12.552c-0.631 0.288-1.332 0.448-2.071 0.448s-1.44-0.16-2.071-0.448l-1.786 2.458c1.144 0.631 2.458 0.99 3.857 0.99s2.713-0.359 3.857-0.99l-1.786-2.458z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpinner5, "Spinner 5"))]
 pub static ImSpinner5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpinner5",
+    label: "Spinner 5",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6517,8 +8886,14 @@ pub static ImSpinner5: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM8 4c2.209 0 4 1.791 4 4s-1.791 4-4 4-4-1.791-4-4 1.791-4 4-4zM12.773 12.773c-1.275 1.275-2.97 1.977-4.773 1.977s-3.498-0.702-4.773-1.977-1.977-2.97-1.977-4.773c0-1.803 0.702-3.498 1.977-4.773l1.061 1.061c0 0 0 0 0 0-2.047 2.047-2.047 5.378 0 7.425 0.992 0.992 2.31 1.538 3.712 1.538s2.721-0.546 3.712-1.538c2.047-2.047 2.047-5.378 0-7.425l1.061-1.061c1.275 1.275 1.977 2.97 1.977 4.773s-0.702 3.498-1.977 4.773z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpinner6, "Spinner 6"))]
 pub static ImSpinner6: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpinner6",
+    label: "Spinner 6",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6533,8 +8908,14 @@ pub static ImSpinner6: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6 2c0-1.105 0.895-2 2-2s2 0.895 2 2c0 1.105-0.895 2-2 2s-2-0.895-2-2zM12.359 8c0 0 0 0 0 0 0-0.906 0.735-1.641 1.641-1.641s1.641 0.735 1.641 1.641c0 0 0 0 0 0 0 0.906-0.735 1.641-1.641 1.641s-1.641-0.735-1.641-1.641zM10.757 12.243c0-0.821 0.665-1.486 1.486-1.486s1.486 0.665 1.486 1.486c0 0.821-0.665 1.486-1.486 1.486s-1.486-0.665-1.486-1.486zM6.654 14c0-0.743 0.603-1.346 1.346-1.346s1.346 0.603 1.346 1.346c0 0.743-0.603 1.346-1.346 1.346s-1.346-0.603-1.346-1.346zM2.538 12.243c0-0.673 0.546-1.219 1.219-1.219s1.219 0.546 1.219 1.219c0 0.673-0.546 1.219-1.219 1.219s-1.219-0.546-1.219-1.219zM0.896 8c0-0.61 0.494-1.104 1.104-1.104s1.104 0.494 1.104 1.104c0 0.61-0.494 1.104-1.104 1.104s-1.104-0.494-1.104-1.104zM2.757 3.757c0 0 0 0 0 0 0-0.552 0.448-1 1-1s1 0.448 1 1c0 0 0 0 0 0 0 0.552-0.448 1-1 1s-1-0.448-1-1zM14.054 3.757c0 1-0.811 1.811-1.812 1.811s-1.812-0.811-1.812-1.811c0-1.001 0.811-1.811 1.812-1.811s1.812 0.811 1.812 1.811z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpinner7, "Spinner 7"))]
 pub static ImSpinner7: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpinner7",
+    label: "Spinner 7",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6549,8 +8930,14 @@ pub static ImSpinner7: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6.5 14.5c0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5c0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5zM0 8c0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5c0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5zM13 8c0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5c0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5zM1.904 3.404c0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5c0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5zM11.096 12.596c0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5c0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5zM1.904 12.596c0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5c0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5zM11.096 3.404c0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5c0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpinner8, "Spinner 8"))]
 pub static ImSpinner8: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpinner8",
+    label: "Spinner 8",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6565,8 +8952,14 @@ pub static ImSpinner8: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c-2.137 0-4.146-0.832-5.657-2.343s-2.343-3.52-2.343-5.657c0-1.513 0.425-2.986 1.228-4.261 0.781-1.239 1.885-2.24 3.193-2.895l0.672 1.341c-1.063 0.533-1.961 1.347-2.596 2.354-0.652 1.034-0.997 2.231-0.997 3.461 0 3.584 2.916 6.5 6.5 6.5s6.5-2.916 6.5-6.5c0-1.23-0.345-2.426-0.997-3.461-0.635-1.008-1.533-1.822-2.596-2.354l0.672-1.341c1.308 0.655 2.412 1.656 3.193 2.895 0.803 1.274 1.228 2.748 1.228 4.261 0 2.137-0.832 4.146-2.343 5.657s-3.52 2.343-5.657 2.343z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpinner9, "Spinner 9"))]
 pub static ImSpinner9: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpinner9",
+    label: "Spinner 9",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6581,8 +8974,14 @@ pub static ImSpinner9: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.355 0-7.898 3.481-7.998 7.812 0.092-3.779 2.966-6.812 6.498-6.812 3.59 0 6.5 3.134 6.5 7 0 0.828 0.672 1.5 1.5 1.5s1.5-0.672 1.5-1.5c0-4.418-3.582-8-8-8zM8 16c4.355 0 7.898-3.481 7.998-7.812-0.092 3.779-2.966 6.812-6.498 6.812-3.59 0-6.5-3.134-6.5-7 0-0.828-0.672-1.5-1.5-1.5s-1.5 0.672-1.5 1.5c0 4.418 3.582 8 8 8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpoonKnife, "Spoon Knife"))]
 pub static ImSpoonKnife: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpoonKnife",
+    label: "Spoon Knife",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6597,8 +8996,14 @@ pub static ImSpoonKnife: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M3.5 0c-1.657 0-3 1.567-3 3.5 0 1.655 0.985 3.042 2.308 3.406l-0.497 8.096c-0.034 0.549 0.389 0.998 0.939 0.998h0.5c0.55 0 0.972-0.449 0.939-0.998l-0.497-8.096c1.323-0.365 2.308-1.751 2.308-3.406 0-1.933-1.343-3.5-3-3.5zM13.583 0l-0.833 5h-0.625l-0.417-5h-0.417l-0.417 5h-0.625l-0.833-5h-0.417v6.5c0 0.276 0.224 0.5 0.5 0.5h1.302l-0.491 8.002c-0.034 0.549 0.389 0.998 0.939 0.998h0.5c0.55 0 0.972-0.449 0.939-0.998l-0.491-8.002h1.302c0.276 0 0.5-0.224 0.5-0.5v-6.5h-0.417z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSpotify, "Spotify"))]
 pub static ImSpotify: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSpotify",
+    label: "Spotify",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6613,8 +9018,14 @@ pub static ImSpotify: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.4 0-8 3.6-8 8s3.6 8 8 8 8-3.6 8-8-3.559-8-8-8zM11.681 11.559c-0.159 0.241-0.441 0.319-0.681 0.159-1.881-1.159-4.241-1.4-7.041-0.759-0.281 0.081-0.519-0.119-0.6-0.359-0.081-0.281 0.119-0.519 0.359-0.6 3.041-0.681 5.681-0.4 7.759 0.881 0.281 0.119 0.322 0.438 0.203 0.678zM12.641 9.359c-0.2 0.281-0.559 0.4-0.841 0.2-2.159-1.319-5.441-1.719-7.959-0.919-0.319 0.081-0.681-0.081-0.759-0.4-0.081-0.319 0.081-0.681 0.4-0.759 2.919-0.881 6.519-0.441 9 1.081 0.238 0.119 0.359 0.519 0.159 0.797zM12.719 7.119c-2.559-1.519-6.841-1.681-9.281-0.919-0.4 0.119-0.8-0.119-0.919-0.481-0.119-0.4 0.119-0.8 0.481-0.919 2.841-0.841 7.519-0.681 10.481 1.081 0.359 0.2 0.481 0.681 0.281 1.041-0.203 0.278-0.681 0.397-1.044 0.197z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImStack, "Stack"))]
 pub static ImStack: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImStack",
+    label: "Stack",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6629,8 +9040,14 @@ pub static ImStack: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 5l-8-4-8 4 8 4 8-4zM8 2.328l5.345 2.672-5.345 2.672-5.345-2.672 5.345-2.672zM14.398 7.199l1.602 0.801-8 4-8-4 1.602-0.801 6.398 3.199zM14.398 10.199l1.602 0.801-8 4-8-4 1.602-0.801 6.398 3.199z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImStackoverflow, "Stackoverflow"))]
 pub static ImStackoverflow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImStackoverflow",
+    label: "Stackoverflow",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6645,8 +9062,14 @@ pub static ImStackoverflow: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M16 10v6h-16v-6h2v4h12v-4zM3 11h10v2h-10zM3.237 8.835l0.433-1.953 9.763 2.164-0.433 1.953zM4.37 4.821l0.845-1.813 9.063 4.226-0.845 1.813zM15.496 5.648l-1.218 1.587-7.934-6.088 0.88-1.147h0.91z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImStarEmpty, "Star Empty"))]
 pub static ImStarEmpty: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImStarEmpty",
+    label: "Star Empty",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6661,8 +9084,14 @@ pub static ImStarEmpty: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 6.204l-5.528-0.803-2.472-5.009-2.472 5.009-5.528 0.803 4 3.899-0.944 5.505 4.944-2.599 4.944 2.599-0.944-5.505 4-3.899zM8 11.773l-3.492 1.836 0.667-3.888-2.825-2.753 3.904-0.567 1.746-3.537 1.746 3.537 3.904 0.567-2.825 2.753 0.667 3.888-3.492-1.836z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImStarFull, "Star Full"))]
 pub static ImStarFull: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImStarFull",
+    label: "Star Full",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6677,8 +9106,14 @@ pub static ImStarFull: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 6.204l-5.528-0.803-2.472-5.009-2.472 5.009-5.528 0.803 4 3.899-0.944 5.505 4.944-2.599 4.944 2.599-0.944-5.505 4-3.899z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImStarHalf, "Star Half"))]
 pub static ImStarHalf: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImStarHalf",
+    label: "Star Half",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6693,8 +9128,14 @@ pub static ImStarHalf: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 6.204l-5.528-0.803-2.472-5.009-2.472 5.009-5.528 0.803 4 3.899-0.944 5.505 4.944-2.599 4.944 2.599-0.944-5.505 4-3.899zM8 11.773l-0.015 0.008 0.015-8.918 1.746 3.537 3.904 0.567-2.825 2.753 0.667 3.888-3.492-1.836z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImStatsBars, "Stats Bars"))]
 pub static ImStatsBars: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImStatsBars",
+    label: "Stats Bars",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6709,8 +9150,14 @@ pub static ImStatsBars: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 13h16v2h-16zM2 9h2v3h-2zM5 5h2v7h-2zM8 8h2v4h-2zM11 2h2v10h-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImStatsBars2, "Stats Bars 2"))]
 pub static ImStatsBars2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImStatsBars2",
+    label: "Stats Bars 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6725,8 +9172,14 @@ pub static ImStatsBars2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4.5 6h-3c-0.275 0-0.5 0.225-0.5 0.5v9c0 0.275 0.225 0.5 0.5 0.5h3c0.275 0 0.5-0.225 0.5-0.5v-9c0-0.275-0.225-0.5-0.5-0.5zM4.5 15h-3v-4h3v4zM9.5 4h-3c-0.275 0-0.5 0.225-0.5 0.5v11c0 0.275 0.225 0.5 0.5 0.5h3c0.275 0 0.5-0.225 0.5-0.5v-11c0-0.275-0.225-0.5-0.5-0.5zM9.5 15h-3v-5h3v5zM14.5 2h-3c-0.275 0-0.5 0.225-0.5 0.5v13c0 0.275 0.225 0.5 0.5 0.5h3c0.275 0 0.5-0.225 0.5-0.5v-13c0-0.275-0.225-0.5-0.5-0.5zM14.5 15h-3v-6h3v6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImStatsDots, "Stats Dots"))]
 pub static ImStatsDots: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImStatsDots",
+    label: "Stats Dots",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6741,8 +9194,14 @@ pub static ImStatsDots: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M2 14h14v2h-16v-16h2zM4.5 13c-0.828 0-1.5-0.672-1.5-1.5s0.672-1.5 1.5-1.5c0.044 0 0.088 0.002 0.131 0.006l1.612-2.687c-0.154-0.235-0.243-0.517-0.243-0.819 0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5c0 0.302-0.090 0.583-0.243 0.819l1.612 2.687c0.043-0.004 0.087-0.006 0.131-0.006 0.033 0 0.066 0.001 0.099 0.004l2.662-4.658c-0.165-0.241-0.261-0.532-0.261-0.845 0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5c0 0.828-0.672 1.5-1.5 1.5-0.033 0-0.066-0.001-0.099-0.004l-2.662 4.658c0.165 0.241 0.261 0.532 0.261 0.845 0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5c0-0.302 0.090-0.583 0.243-0.819l-1.612-2.687c-0.043 0.004-0.087 0.006-0.131 0.006s-0.088-0.002-0.131-0.006l-1.612 2.687c0.154 0.235 0.243 0.517 0.243 0.819 0 0.828-0.672 1.5-1.5 1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSteam, "Steam"))]
 pub static ImSteam: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSteam",
+    label: "Steam",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6757,8 +9216,14 @@ pub static ImSteam: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11 4.5c0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5c0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5zM14.975 2.025c-1.367-1.367-3.583-1.367-4.95 0-0.556 0.556-0.886 1.252-0.989 1.975v0l-3.198 4.847c-0.43 0.022-0.856 0.132-1.249 0.328l-2.467-1.928c-0.571-0.446-1.396-0.345-1.842 0.226s-0.345 1.396 0.226 1.842l2.436 1.905c-0.265 1.043 0.010 2.196 0.827 3.012 1.233 1.233 3.232 1.233 4.465 0 0.757-0.757 1.049-1.804 0.876-2.784l3.891-3.484c0.723-0.104 1.419-0.434 1.975-0.989 1.367-1.367 1.367-3.583 0-4.95zM6 14.105c-1.162 0-2.105-0.942-2.105-2.105 0-0.011 0.001-0.022 0.001-0.033l1.046 0.817c0.24 0.188 0.525 0.278 0.807 0.278 0.39 0 0.776-0.173 1.035-0.504 0.446-0.571 0.345-1.396-0.226-1.842l-0.992-0.776c0.14-0.029 0.285-0.045 0.434-0.045 1.162 0 2.105 0.942 2.105 2.105s-0.942 2.105-2.105 2.105zM12.5 7c-1.381 0-2.5-1.119-2.5-2.5s1.119-2.5 2.5-2.5 2.5 1.119 2.5 2.5-1.119 2.5-2.5 2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSteam2, "Steam 2"))]
 pub static ImSteam2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSteam2",
+    label: "Steam 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6773,8 +9238,14 @@ pub static ImSteam2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4.749 13.063c0.424 0 0.84-0.205 1.093-0.585 0.402-0.603 0.239-1.418-0.364-1.82l-1.032-0.688c0.177-0.048 0.362-0.074 0.554-0.074 1.162 0 2.105 0.942 2.105 2.105s-0.942 2.105-2.105 2.105c-1.131 0-2.054-0.893-2.102-2.012l1.124 0.749c0.224 0.149 0.477 0.221 0.727 0.221zM13.333 0c1.467 0 2.667 1.2 2.667 2.667v10.666c0 1.468-1.2 2.667-2.667 2.667h-10.666c-1.467 0-2.667-1.199-2.667-2.667v-3.172l1.896 1.264c-0.182 0.987 0.108 2.044 0.872 2.808 1.233 1.233 3.232 1.233 4.465 0 0.757-0.757 1.049-1.804 0.876-2.784l3.892-3.484c0.723-0.104 1.419-0.433 1.975-0.989 1.367-1.367 1.367-3.583 0-4.95s-3.583-1.367-4.95 0c-0.556 0.556-0.886 1.252-0.989 1.975v0l-3.198 4.847c-0.498 0.025-0.99 0.168-1.433 0.428l-3.404-2.269v-4.339c0-1.467 1.2-2.667 2.667-2.667h10.666zM14 4.5c0-1.381-1.119-2.5-2.5-2.5s-2.5 1.119-2.5 2.5 1.119 2.5 2.5 2.5 2.5-1.119 2.5-2.5zM10 4.5c0-0.828 0.672-1.5 1.5-1.5s1.5 0.672 1.5 1.5-0.672 1.5-1.5 1.5-1.5-0.672-1.5-1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImStop, "Stop"))]
 pub static ImStop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImStop",
+    label: "Stop",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6789,8 +9260,14 @@ pub static ImStop: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM8 14.5c-3.59 0-6.5-2.91-6.5-6.5s2.91-6.5 6.5-6.5 6.5 2.91 6.5 6.5-2.91 6.5-6.5 6.5zM5 5h6v6h-6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImStop2, "Stop 2"))]
 pub static ImStop2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImStop2",
+    label: "Stop 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6805,8 +9282,14 @@ pub static ImStop2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M2 2h12v12h-12z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImStopwatch, "Stopwatch"))]
 pub static ImStopwatch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImStopwatch",
+    label: "Stopwatch",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6821,8 +9304,14 @@ pub static ImStopwatch: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 3.019v-1.019h2v-1c0-0.552-0.448-1-1-1h-3c-0.552 0-1 0.448-1 1v1h2v1.019c-3.356 0.255-6 3.059-6 6.481 0 3.59 2.91 6.5 6.5 6.5s6.5-2.91 6.5-6.5c0-3.422-2.644-6.226-6-6.481zM11.036 13.036c-0.944 0.944-2.2 1.464-3.536 1.464s-2.591-0.52-3.536-1.464c-0.944-0.944-1.464-2.2-1.464-3.536s0.52-2.591 1.464-3.536c0.907-0.907 2.101-1.422 3.377-1.462l-0.339 4.907c-0.029 0.411 0.195 0.591 0.497 0.591s0.527-0.18 0.497-0.591l-0.339-4.907c1.276 0.040 2.47 0.555 3.377 1.462 0.944 0.944 1.464 2.2 1.464 3.536s-0.52 2.591-1.464 3.536z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImStrikethrough, "Strikethrough"))]
 pub static ImStrikethrough: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImStrikethrough",
+    label: "Strikethrough",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6837,8 +9326,14 @@ pub static ImStrikethrough: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M16 8v1h-3.664c0.43 0.602 0.664 1.292 0.664 2 0 1.107-0.573 2.172-1.572 2.921-0.927 0.696-2.145 1.079-3.428 1.079s-2.501-0.383-3.428-1.079c-0.999-0.749-1.572-1.814-1.572-2.921h2c0 1.084 1.374 2 3 2s3-0.916 3-2c0-1.084-1.374-2-3-2h-8v-1h4.68c-0.037-0.026-0.073-0.052-0.108-0.079-0.999-0.749-1.572-1.814-1.572-2.921s0.573-2.172 1.572-2.921c0.927-0.696 2.145-1.079 3.428-1.079s2.501 0.383 3.428 1.079c0.999 0.749 1.572 1.814 1.572 2.921h-2c0-1.084-1.374-2-3-2s-3 0.916-3 2c0 1.084 1.374 2 3 2 1.234 0 2.407 0.354 3.32 1h4.68z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImStumbleupon, "Stumbleupon"))]
 pub static ImStumbleupon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImStumbleupon",
+    label: "Stumbleupon",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6854,8 +9349,14 @@ pub static ImStumbleupon: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 10c0 1.653-1.347 3-3 3s-3-1.347-3-3.219v-1.938l0.719 0.375 1.281-0.375v1.938c0 0.769 0.45 1.219 1 1.219s1-0.45 1-1v-2h2v2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImStumbleupon2, "Stumbleupon 2"))]
 pub static ImStumbleupon2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImStumbleupon2",
+    label: "Stumbleupon 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6870,8 +9371,14 @@ pub static ImStumbleupon2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.313 0h-10.625c-1.478 0-2.688 1.209-2.688 2.688v10.625c0 1.478 1.209 2.688 2.688 2.688h10.625c1.478 0 2.688-1.209 2.688-2.688v-10.625c0-1.478-1.209-2.688-2.688-2.688zM8 5c-0.551 0-1 0.449-1 1v4c0 1.654-1.346 3-3 3s-3-1.346-3-3v-2h2v2c0 0.551 0.449 1 1 1s1-0.449 1-1v-4c0-1.654 1.346-3 3-3s3 1.346 3 2.781v0.969l-1.281 0.375-0.719-0.375v-0.969c0-0.333-0.449-0.781-1-0.781zM15 10c0 1.654-1.346 3-3 3s-3-1.346-3-3.219v-1.938l0.719 0.375 1.281-0.375v1.938c0 0.77 0.449 1.219 1 1.219s1-0.449 1-1v-2h2v2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSubscript, "Subscript"))]
 pub static ImSubscript: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSubscript",
+    label: "Subscript",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6886,8 +9393,14 @@ pub static ImSubscript: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 14.219v0.781h2v1h-3v-2.281l2-0.938v-0.781h-2v-1h3v2.281zM10.563 4h-2.125l-2.938 2.938-2.938-2.938h-2.125l4 4-4 4h2.125l2.938-2.938 2.938 2.938h2.125l-4-4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSubscript2, "Subscript 2"))]
 pub static ImSubscript2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSubscript2",
+    label: "Subscript 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6903,8 +9416,14 @@ pub static ImSubscript2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13.5 13h-1c-0.276 0-0.5-0.224-0.5-0.5s0.224-0.5 0.5-0.5h2c0.276 0 0.5-0.224 0.5-0.5s-0.224-0.5-0.5-0.5h-2c-0.827 0-1.5 0.673-1.5 1.5 0 0.384 0.145 0.734 0.383 1 0.275 0.307 0.674 0.5 1.117 0.5h1c0.276 0 0.5 0.224 0.5 0.5s-0.224 0.5-0.5 0.5h-2c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5h2c0.827 0 1.5-0.673 1.5-1.5 0-0.384-0.145-0.734-0.383-1-0.275-0.307-0.674-0.5-1.117-0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSun, "Sun"))]
 pub static ImSun: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSun",
+    label: "Sun",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6920,8 +9439,14 @@ pub static ImSun: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 4c-2.209 0-4 1.791-4 4s1.791 4 4 4c2.209 0 4-1.791 4-4s-1.791-4-4-4zM8 10.5c-1.381 0-2.5-1.119-2.5-2.5s1.119-2.5 2.5-2.5 2.5 1.119 2.5 2.5-1.119 2.5-2.5 2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSuperscript, "Superscript"))]
 pub static ImSuperscript: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSuperscript",
+    label: "Superscript",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6936,8 +9461,14 @@ pub static ImSuperscript: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 3.219v0.781h2v1h-3v-2.281l2-0.938v-0.781h-2v-1h3v2.281zM10.563 4h-2.125l-2.938 2.938-2.938-2.938h-2.125l4 4-4 4h2.125l2.938-2.938 2.938 2.938h2.125l-4-4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSuperscript2, "Superscript 2"))]
 pub static ImSuperscript2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSuperscript2",
+    label: "Superscript 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6953,8 +9484,14 @@ pub static ImSuperscript2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13.5 2h-1c-0.276 0-0.5-0.224-0.5-0.5s0.224-0.5 0.5-0.5h2c0.276 0 0.5-0.224 0.5-0.5s-0.224-0.5-0.5-0.5h-2c-0.827 0-1.5 0.673-1.5 1.5 0 0.384 0.145 0.734 0.383 1 0.275 0.307 0.674 0.5 1.117 0.5h1c0.276 0 0.5 0.224 0.5 0.5s-0.224 0.5-0.5 0.5h-2c-0.276 0-0.5 0.224-0.5 0.5s0.224 0.5 0.5 0.5h2c0.827 0 1.5-0.673 1.5-1.5 0-0.384-0.145-0.734-0.383-1-0.275-0.307-0.674-0.5-1.117-0.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSvg, "SVG"))]
 pub static ImSvg: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSvg",
+    label: "SVG",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6969,8 +9506,14 @@ pub static ImSvg: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 6.5c-0.444 0-0.843 0.193-1.118 0.5h-2.968l2.099-2.099c0.411 0.023 0.83-0.123 1.144-0.437 0.586-0.586 0.586-1.536 0-2.121s-1.536-0.586-2.121 0c-0.314 0.314-0.46 0.733-0.437 1.144l-2.099 2.099v-2.968c0.307-0.275 0.5-0.674 0.5-1.118 0-0.828-0.672-1.5-1.5-1.5s-1.5 0.672-1.5 1.5c0 0.444 0.193 0.843 0.5 1.118v2.968l-2.099-2.099c0.023-0.411-0.123-0.83-0.437-1.144-0.586-0.586-1.536-0.586-2.121 0s-0.586 1.536 0 2.121c0.314 0.314 0.733 0.46 1.144 0.437l2.099 2.099h-2.968c-0.275-0.307-0.674-0.5-1.118-0.5-0.828 0-1.5 0.672-1.5 1.5s0.672 1.5 1.5 1.5c0.444 0 0.843-0.193 1.118-0.5h2.968l-2.099 2.099c-0.411-0.023-0.83 0.123-1.144 0.437-0.586 0.586-0.586 1.536 0 2.121s1.536 0.586 2.121 0c0.314-0.314 0.46-0.733 0.437-1.144l2.099-2.099v2.968c-0.307 0.275-0.5 0.674-0.5 1.118 0 0.828 0.672 1.5 1.5 1.5s1.5-0.672 1.5-1.5c0-0.444-0.193-0.843-0.5-1.118v-2.968l2.099 2.099c-0.023 0.411 0.123 0.83 0.437 1.144 0.586 0.586 1.536 0.586 2.121 0s0.586-1.536 0-2.121c-0.314-0.314-0.733-0.46-1.144-0.437l-2.099-2.099h2.968c0.275 0.307 0.674 0.5 1.118 0.5 0.828 0 1.5-0.672 1.5-1.5s-0.672-1.5-1.5-1.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImSwitch, "Switch"))]
 pub static ImSwitch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImSwitch",
+    label: "Switch",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6985,8 +9528,14 @@ pub static ImSwitch: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M10 2.29v2.124c0.566 0.247 1.086 0.6 1.536 1.050 0.944 0.944 1.464 2.2 1.464 3.536s-0.52 2.591-1.464 3.536c-0.944 0.944-2.2 1.464-3.536 1.464s-2.591-0.52-3.536-1.464c-0.944-0.944-1.464-2.2-1.464-3.536s0.52-2.591 1.464-3.536c0.45-0.45 0.97-0.803 1.536-1.050v-2.124c-2.891 0.861-5 3.539-5 6.71 0 3.866 3.134 7 7 7s7-3.134 7-7c0-3.171-2.109-5.849-5-6.71zM7 0h2v8h-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTab, "Tab"))]
 pub static ImTab: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTab",
+    label: "Tab",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7004,8 +9553,14 @@ pub static ImTab: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11 5h-11v-2h11v-2.5l3.5 3.5-3.5 3.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTable, "Table"))]
 pub static ImTable: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTable",
+    label: "Table",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7020,8 +9575,14 @@ pub static ImTable: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 3v11h16v-11h-16zM6 10v-2h4v2h-4zM10 11v2h-4v-2h4zM10 5v2h-4v-2h4zM5 5v2h-4v-2h4zM1 8h4v2h-4v-2zM11 8h4v2h-4v-2zM11 7v-2h4v2h-4zM1 11h4v2h-4v-2zM11 13v-2h4v2h-4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTable2, "Table 2"))]
 pub static ImTable2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTable2",
+    label: "Table 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7036,8 +9597,14 @@ pub static ImTable2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 1v14h16v-14h-16zM6 10v-3h4v3h-4zM10 11v3h-4v-3h4zM10 3v3h-4v-3h4zM5 3v3h-4v-3h4zM1 7h4v3h-4v-3zM11 7h4v3h-4v-3zM11 6v-3h4v3h-4zM1 11h4v3h-4v-3zM11 14v-3h4v3h-4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTablet, "Tablet"))]
 pub static ImTablet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTablet",
+    label: "Tablet",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7052,8 +9619,14 @@ pub static ImTablet: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12.5 0h-10c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h10c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM7.5 15.5c-0.276 0-0.5-0.224-0.5-0.5s0.224-0.5 0.5-0.5 0.5 0.224 0.5 0.5-0.224 0.5-0.5 0.5zM12 14h-9v-12h9v12z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTarget, "Target"))]
 pub static ImTarget: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTarget",
+    label: "Target",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7068,8 +9641,14 @@ pub static ImTarget: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 7h-1.577c-0.432-2.785-2.638-4.991-5.423-5.423v-1.577h-2v1.577c-2.785 0.432-4.991 2.638-5.423 5.423h-1.577v2h1.577c0.432 2.785 2.638 4.991 5.423 5.423v1.577h2v-1.577c2.785-0.432 4.991-2.638 5.423-5.423h1.577v-2zM12.388 7h-1.559c-0.301-0.852-0.977-1.528-1.829-1.829v-1.559c1.68 0.383 3.005 1.708 3.388 3.388zM8 9c-0.552 0-1-0.448-1-1s0.448-1 1-1c0.552 0 1 0.448 1 1s-0.448 1-1 1zM7 3.612v1.559c-0.852 0.301-1.528 0.977-1.829 1.829h-1.559c0.383-1.68 1.708-3.005 3.388-3.388zM3.612 9h1.559c0.301 0.852 0.977 1.528 1.829 1.829v1.559c-1.68-0.383-3.005-1.708-3.388-3.388zM9 12.388v-1.559c0.852-0.301 1.528-0.977 1.829-1.829h1.559c-0.383 1.68-1.708 3.005-3.388 3.388z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTelegram, "Telegram"))]
 pub static ImTelegram: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTelegram",
+    label: "Telegram",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7084,8 +9663,14 @@ pub static ImTelegram: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.419 0-8 3.581-8 8s3.581 8 8 8 8-3.581 8-8-3.581-8-8-8zM11.931 5.484l-1.313 6.184c-0.091 0.441-0.356 0.544-0.725 0.341l-2-1.478-0.959 0.934c-0.112 0.109-0.2 0.2-0.4 0.2-0.259 0-0.216-0.097-0.303-0.344l-0.681-2.237-1.978-0.616c-0.428-0.131-0.431-0.425 0.097-0.634l7.706-2.975c0.35-0.159 0.691 0.084 0.556 0.625z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTerminal, "Terminal"))]
 pub static ImTerminal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTerminal",
+    label: "Terminal",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7100,8 +9685,14 @@ pub static ImTerminal: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 1v14h16v-14h-16zM15 14h-14v-12h14v12zM14 3h-12v10h12v-10zM7 8h-1v1h-1v1h-1v-1h1v-1h1v-1h-1v-1h-1v-1h1v1h1v1h1v1zM11 10h-3v-1h3v1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTextColor, "Text Color"))]
 pub static ImTextColor: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTextColor",
+    label: "Text Color",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7116,8 +9707,14 @@ pub static ImTextColor: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M5.032 13l0.9-3h4.137l0.9 3h1.775l-3-10h-3.488l-3 10h1.776zM7.432 5h1.137l0.9 3h-2.937l0.9-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTextHeight, "Text Height"))]
 pub static ImTextHeight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTextHeight",
+    label: "Text Height",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7132,8 +9729,14 @@ pub static ImTextHeight: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14 12h2l-2.5 3-2.5-3h2v-8h-2l2.5-3 2.5 3h-2zM10 1v4l-1-2h-3v11h2v1h-6v-1h2v-11h-3l-1 2v-4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTextWidth, "Text Width"))]
 pub static ImTextWidth: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTextWidth",
+    label: "Text Width",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7148,8 +9751,14 @@ pub static ImTextWidth: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4 14v2l-3-2.5 3-2.5v2h8v-2l3 2.5-3 2.5v-2zM13 1v4l-1-2h-3v7h2v1h-6v-1h2v-7h-3l-1 2v-4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTicket, "Ticket"))]
 pub static ImTicket: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTicket",
+    label: "Ticket",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7164,8 +9773,14 @@ pub static ImTicket: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9 5l2 2-4 4-2-2zM15.649 4.649l-1.149-1.149-0.5 0.5c-0.256 0.256-0.61 0.414-1 0.414-0.781 0-1.414-0.633-1.414-1.414 0-0.391 0.158-0.744 0.415-1l0.5-0.5-1.149-1.149c-0.468-0.468-1.235-0.468-1.703 0l-9.297 9.297c-0.468 0.468-0.468 1.235 0 1.703l1.149 1.149 0.499-0.499c0.256-0.256 0.61-0.415 1.001-0.415 0.781 0 1.414 0.633 1.414 1.414 0 0.391-0.158 0.744-0.415 1l-0.5 0.5 1.149 1.149c0.468 0.468 1.234 0.468 1.703 0l9.297-9.297c0.468-0.468 0.468-1.235 0-1.703zM7 13l-4-4 6-6 4 4-6 6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTongue, "Tongue"))]
 pub static ImTongue: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTongue",
+    label: "Tongue",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7180,8 +9795,14 @@ pub static ImTongue: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c4.418 0 8-3.582 8-8s-3.582-8-8-8-8 3.582-8 8 3.582 8 8 8zM8 1.5c3.59 0 6.5 2.91 6.5 6.5s-2.91 6.5-6.5 6.5-6.5-2.91-6.5-6.5 2.91-6.5 6.5-6.5zM4 5c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1zM10 5c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1zM12 9v1h-1v1.5c0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5v-1.5h-4v-1h8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTongue2, "Tongue 2"))]
 pub static ImTongue2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTongue2",
+    label: "Tongue 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7196,8 +9817,14 @@ pub static ImTongue2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM5 4c0.552 0 1 0.448 1 1s-0.448 1-1 1-1-0.448-1-1 0.448-1 1-1zM12 10h-1v1.5c0 0.828-0.672 1.5-1.5 1.5s-1.5-0.672-1.5-1.5v-1.5h-4v-1h8v1zM11 6c-0.552 0-1-0.448-1-1s0.448-1 1-1 1 0.448 1 1-0.448 1-1 1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTree, "Tree"))]
 pub static ImTree: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTree",
+    label: "Tree",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7212,8 +9839,14 @@ pub static ImTree: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.25 12h-0.25v-3.25c0-0.965-0.785-1.75-1.75-1.75h-4.25v-2h0.25c0.412 0 0.75-0.338 0.75-0.75v-2.5c0-0.413-0.338-0.75-0.75-0.75h-2.5c-0.412 0-0.75 0.337-0.75 0.75v2.5c0 0.412 0.338 0.75 0.75 0.75h0.25v2h-4.25c-0.965 0-1.75 0.785-1.75 1.75v3.25h-0.25c-0.412 0-0.75 0.338-0.75 0.75v2.5c0 0.412 0.338 0.75 0.75 0.75h2.5c0.413 0 0.75-0.338 0.75-0.75v-2.5c0-0.412-0.337-0.75-0.75-0.75h-0.25v-3h4v3h-0.25c-0.412 0-0.75 0.338-0.75 0.75v2.5c0 0.412 0.338 0.75 0.75 0.75h2.5c0.412 0 0.75-0.338 0.75-0.75v-2.5c0-0.412-0.338-0.75-0.75-0.75h-0.25v-3h4v3h-0.25c-0.412 0-0.75 0.338-0.75 0.75v2.5c0 0.412 0.338 0.75 0.75 0.75h2.5c0.412 0 0.75-0.338 0.75-0.75v-2.5c0-0.412-0.338-0.75-0.75-0.75zM3 15h-2v-2h2v2zM9 15h-2v-2h2v2zM7 4v-2h2v2h-2zM15 15h-2v-2h2v2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTrello, "Trello"))]
 pub static ImTrello: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTrello",
+    label: "Trello",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7228,8 +9861,14 @@ pub static ImTrello: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM7 12c0 0.55-0.45 1-1 1h-2c-0.55 0-1-0.45-1-1v-8c0-0.55 0.45-1 1-1h2c0.55 0 1 0.45 1 1v8zM13 9c0 0.55-0.45 1-1 1h-2c-0.55 0-1-0.45-1-1v-5c0-0.55 0.45-1 1-1h2c0.55 0 1 0.45 1 1v5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTrophy, "Trophy"))]
 pub static ImTrophy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTrophy",
+    label: "Trophy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7244,8 +9883,14 @@ pub static ImTrophy: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13 3v-2h-10v2h-3v2c0 1.657 1.343 3 3 3 0.314 0 0.616-0.048 0.9-0.138 0.721 1.031 1.822 1.778 3.1 2.037v3.1h-1c-1.105 0-2 0.895-2 2h8c0-1.105-0.895-2-2-2h-1v-3.1c1.278-0.259 2.378-1.006 3.1-2.037 0.284 0.089 0.587 0.138 0.9 0.138 1.657 0 3-1.343 3-3v-2h-3zM3 6.813c-0.999 0-1.813-0.813-1.813-1.813v-1h1.813v1c0 0.628 0.116 1.229 0.327 1.782-0.106 0.019-0.216 0.030-0.327 0.030zM14.813 5c0 0.999-0.813 1.813-1.813 1.813-0.112 0-0.221-0.011-0.327-0.030 0.211-0.554 0.327-1.154 0.327-1.782v-1h1.813v1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTruck, "Truck"))]
 pub static ImTruck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTruck",
+    label: "Truck",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7260,8 +9905,14 @@ pub static ImTruck: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 9l-2-4h-3v-2c0-0.55-0.45-1-1-1h-9c-0.55 0-1 0.45-1 1v8l1 1h1.268c-0.17 0.294-0.268 0.636-0.268 1 0 1.105 0.895 2 2 2s2-0.895 2-2c0-0.364-0.098-0.706-0.268-1h5.536c-0.17 0.294-0.268 0.636-0.268 1 0 1.105 0.895 2 2 2s2-0.895 2-2c0-0.364-0.098-0.706-0.268-1h1.268v-3zM11 9v-3h2.073l1.5 3h-3.573z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTumblr, "Tumblr"))]
 pub static ImTumblr: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTumblr",
+    label: "Tumblr",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7276,8 +9927,14 @@ pub static ImTumblr: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9.001 7l-0 3.659c0 0.928-0.012 1.463 0.086 1.727 0.098 0.262 0.342 0.534 0.609 0.691 0.354 0.212 0.758 0.318 1.214 0.318 0.81 0 1.289-0.107 2.090-0.633v2.405c-0.683 0.321-1.279 0.509-1.833 0.639-0.555 0.129-1.154 0.194-1.798 0.194-0.732 0-1.163-0.092-1.725-0.276-0.562-0.185-1.042-0.45-1.438-0.79-0.398-0.343-0.672-0.706-0.826-1.091s-0.23-0.944-0.23-1.676v-5.611h-2.147v-2.266c0.628-0.204 1.331-0.497 1.778-0.877 0.449-0.382 0.809-0.839 1.080-1.374 0.272-0.534 0.459-1.214 0.561-2.039h2.579l-0 4h3.999v3h-3.999z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTumblr2, "Tumblr 2"))]
 pub static ImTumblr2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTumblr2",
+    label: "Tumblr 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7292,8 +9949,14 @@ pub static ImTumblr2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM11.434 12.884c-0.472 0.222-0.9 0.378-1.281 0.469-0.381 0.088-0.797 0.134-1.241 0.134-0.506 0-0.803-0.063-1.191-0.191s-0.719-0.309-0.994-0.544c-0.275-0.238-0.463-0.488-0.569-0.753s-0.159-0.65-0.159-1.156v-3.872h-1.5v-1.563c0.434-0.141 0.938-0.344 1.244-0.606 0.309-0.263 0.559-0.578 0.744-0.947 0.188-0.369 0.316-0.837 0.388-1.406h1.569v2.55h2.556v1.972h-2.553v2.831c0 0.641-0.009 1.009 0.059 1.191s0.238 0.369 0.422 0.475c0.244 0.147 0.525 0.219 0.838 0.219 0.559 0 1.116-0.181 1.669-0.544v1.741z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTux, "Tux"))]
 pub static ImTux: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTux",
+    label: "Tux",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7308,8 +9971,14 @@ pub static ImTux: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8.87 11.514c-1.28 0.596-2.471 0.589-3.271 0.532-0.954-0.069-1.721-0.33-2.058-0.558-0.208-0.141-0.49-0.086-0.631 0.122s-0.086 0.49 0.122 0.631c0.542 0.367 1.501 0.64 2.503 0.712 0.17 0.012 0.356 0.020 0.557 0.020 0.872 0 1.979-0.083 3.163-0.634 0.228-0.106 0.326-0.376 0.22-0.604s-0.376-0.326-0.604-0.22zM13.921 10.841c0.044-3.948 0.449-11.409-7.109-10.806-7.463 0.601-5.484 8.484-5.595 11.124-0.099 1.397-0.562 3.104-1.217 4.841h2.017c0.207-0.736 0.36-1.464 0.425-2.159 0.122 0.085 0.252 0.167 0.391 0.245 0.226 0.133 0.42 0.31 0.626 0.497 0.48 0.438 1.025 0.934 2.089 0.996 0.071 0.004 0.143 0.006 0.214 0.006 1.077 0 1.813-0.471 2.404-0.85 0.283-0.181 0.528-0.338 0.759-0.413 0.655-0.205 1.227-0.536 1.655-0.957 0.067-0.066 0.129-0.133 0.187-0.202 0.238 0.873 0.564 1.856 0.926 2.836h4.307c-1.034-1.597-2.101-3.162-2.079-5.159zM1.939 8.693c0-0 0-0-0-0.001-0.074-1.288 0.542-2.372 1.377-2.421s1.571 0.957 1.645 2.245c0 0 0 0 0 0.001 0.004 0.069 0.006 0.138 0.006 0.206-0.264 0.066-0.503 0.163-0.717 0.275-0.001-0.010-0.001-0.019-0.002-0.029 0-0 0-0 0-0-0.071-0.731-0.462-1.284-0.873-1.234s-0.686 0.684-0.614 1.415c0 0 0 0 0 0 0.031 0.319 0.123 0.604 0.251 0.819-0.032 0.025-0.122 0.091-0.225 0.166-0.078 0.057-0.172 0.126-0.286 0.21-0.311-0.408-0.524-0.993-0.562-1.655zM10.395 11.878c-0.030 0.681-0.92 1.322-1.743 1.579l-0.005 0.002c-0.342 0.111-0.647 0.306-0.97 0.513-0.543 0.347-1.104 0.706-1.914 0.706-0.053 0-0.108-0.002-0.161-0.005-0.742-0.043-1.090-0.36-1.529-0.761-0.232-0.211-0.472-0.43-0.781-0.611l-0.007-0.004c-0.667-0.377-1.081-0.845-1.108-1.253-0.013-0.203 0.077-0.378 0.268-0.522 0.416-0.312 0.695-0.516 0.879-0.651 0.205-0.15 0.267-0.195 0.313-0.239 0.033-0.031 0.068-0.065 0.106-0.103 0.382-0.371 1.021-0.993 2.002-0.993 0.6 0 1.264 0.231 1.971 0.686 0.333 0.217 0.623 0.317 0.99 0.444 0.252 0.087 0.539 0.186 0.922 0.35l0.006 0.003c0.357 0.147 0.78 0.415 0.76 0.858zM10.198 10.278c-0.069-0.035-0.14-0.068-0.215-0.098-0.345-0.148-0.622-0.248-0.852-0.328 0.127-0.248 0.206-0.558 0.213-0.894 0-0 0-0 0-0 0.018-0.818-0.395-1.483-0.922-1.484s-0.968 0.661-0.986 1.479c0 0 0 0 0 0-0.001 0.027-0.001 0.053-0 0.080-0.324-0.149-0.643-0.258-0.956-0.324-0.001-0.031-0.003-0.061-0.004-0.092 0-0 0-0.001 0-0.001-0.030-1.491 0.884-2.725 2.043-2.756s2.122 1.152 2.153 2.642c0 0 0 0.001 0 0.001 0.014 0.674-0.167 1.295-0.475 1.776z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTv, "TV"))]
 pub static ImTv: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTv",
+    label: "TV",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7324,8 +9993,14 @@ pub static ImTv: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.331 4.502c-1.388-0.199-2.865-0.344-4.407-0.425l2.576-2.576-1-1-3.509 3.509c-0.328-0.006-0.659-0.009-0.991-0.009v0l-4-4-1 1 3.034 3.034c-1.889 0.066-3.693 0.227-5.365 0.467-0.43 1.683-0.669 3.543-0.669 5.498s0.239 3.815 0.669 5.498c2.244 0.323 4.724 0.502 7.331 0.502s5.087-0.179 7.331-0.502c0.43-1.683 0.669-3.543 0.669-5.498s-0.239-3.815-0.669-5.498zM13.498 13.666c-1.683 0.215-3.543 0.334-5.498 0.334s-3.815-0.119-5.498-0.334c-0.323-1.122-0.502-2.362-0.502-3.666s0.179-2.543 0.502-3.666c1.683-0.215 3.543-0.334 5.498-0.334s3.815 0.119 5.498 0.334c0.323 1.122 0.502 2.362 0.502 3.666s-0.179 2.543-0.502 3.666z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTwitch, "Twitch"))]
 pub static ImTwitch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTwitch",
+    label: "Twitch",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7342,8 +10017,14 @@ pub static ImTwitch: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6.5 4h1.5v4h-1.5v-4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImTwitter, "Twitter"))]
 pub static ImTwitter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImTwitter",
+    label: "Twitter",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7358,8 +10039,14 @@ pub static ImTwitter: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M16 3.538c-0.588 0.263-1.222 0.438-1.884 0.516 0.678-0.406 1.197-1.050 1.444-1.816-0.634 0.375-1.338 0.65-2.084 0.797-0.6-0.638-1.453-1.034-2.397-1.034-1.813 0-3.281 1.469-3.281 3.281 0 0.256 0.028 0.506 0.084 0.747-2.728-0.138-5.147-1.444-6.766-3.431-0.281 0.484-0.444 1.050-0.444 1.65 0 1.138 0.578 2.144 1.459 2.731-0.538-0.016-1.044-0.166-1.488-0.409 0 0.013 0 0.028 0 0.041 0 1.591 1.131 2.919 2.634 3.219-0.275 0.075-0.566 0.116-0.866 0.116-0.212 0-0.416-0.022-0.619-0.059 0.419 1.303 1.631 2.253 3.066 2.281-1.125 0.881-2.538 1.406-4.078 1.406-0.266 0-0.525-0.016-0.784-0.047 1.456 0.934 3.181 1.475 5.034 1.475 6.037 0 9.341-5.003 9.341-9.341 0-0.144-0.003-0.284-0.009-0.425 0.641-0.459 1.197-1.038 1.637-1.697z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImUnderline, "Underline"))]
 pub static ImUnderline: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImUnderline",
+    label: "Underline",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7374,8 +10061,14 @@ pub static ImUnderline: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11 1h2v6.5c0 2.485-2.239 4.5-5 4.5s-5-2.015-5-4.5v-6.5h2v6.5c0 0.628 0.285 1.23 0.802 1.695 0.577 0.519 1.357 0.805 2.198 0.805s1.621-0.286 2.198-0.805c0.517-0.466 0.802-1.068 0.802-1.695v-6.5zM3 13h10v2h-10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImUndo, "Undo"))]
 pub static ImUndo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImUndo",
+    label: "Undo",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7390,8 +10083,14 @@ pub static ImUndo: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 1c-2.209 0-4.209 0.896-5.657 2.343l-2.343-2.343v6h6l-2.243-2.243c1.086-1.086 2.586-1.757 4.243-1.757 3.314 0 6 2.686 6 6 0 1.792-0.786 3.401-2.032 4.5l1.323 1.5c1.661-1.466 2.709-3.611 2.709-6 0-4.418-3.582-8-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImUndo2, "Undo 2"))]
 pub static ImUndo2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImUndo2",
+    label: "Undo 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7406,8 +10105,14 @@ pub static ImUndo2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11.904 16c1.777-3.219 2.076-8.13-4.904-7.966v3.966l-6-6 6-6v3.881c8.359-0.218 9.29 7.378 4.904 12.119z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImUngroup, "Ungroup"))]
 pub static ImUngroup: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImUngroup",
+    label: "Ungroup",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7440,8 +10145,14 @@ pub static ImUngroup: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M1 15h2v1h-2v-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImUnlocked, "Unlocked"))]
 pub static ImUnlocked: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImUnlocked",
+    label: "Unlocked",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7456,8 +10167,14 @@ pub static ImUnlocked: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 1c1.654 0 3 1.346 3 3v3h-2v-3c0-0.551-0.449-1-1-1h-2c-0.551 0-1 0.449-1 1v3h0.25c0.412 0 0.75 0.338 0.75 0.75v7.5c0 0.412-0.338 0.75-0.75 0.75h-8.5c-0.412 0-0.75-0.338-0.75-0.75v-7.5c0-0.412 0.338-0.75 0.75-0.75h6.25v-3c0-1.654 1.346-3 3-3h2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImUpload, "Upload"))]
 pub static ImUpload: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImUpload",
+    label: "Upload",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7472,8 +10189,14 @@ pub static ImUpload: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7 9h2v-4h3l-4-4-4 4h3zM10 6.75v1.542l4.579 1.708-6.579 2.453-6.579-2.453 4.579-1.708v-1.542l-6 2.25v4l8 3 8-3v-4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImUpload2, "Upload 2"))]
 pub static ImUpload2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImUpload2",
+    label: "Upload 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7488,8 +10211,14 @@ pub static ImUpload2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0 14h16v1h-16zM16 12v1h-16v-1l2-4h4v2h4v-2h4zM3.5 5l4.5-4.5 4.5 4.5h-3.5v4h-2v-4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImUpload3, "Upload 3"))]
 pub static ImUpload3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImUpload3",
+    label: "Upload 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7504,8 +10233,14 @@ pub static ImUpload3: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M7.5 11h-7.5v4h15v-4h-7.5zM14 13h-2v-1h2v1zM3.5 5l4-4 4 4h-2.5v5h-3v-5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImUser, "User"))]
 pub static ImUser: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImUser",
+    label: "User",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7520,8 +10255,14 @@ pub static ImUser: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9 11.041v-0.825c1.102-0.621 2-2.168 2-3.716 0-2.485 0-4.5-3-4.5s-3 2.015-3 4.5c0 1.548 0.898 3.095 2 3.716v0.825c-3.392 0.277-6 1.944-6 3.959h14c0-2.015-2.608-3.682-6-3.959z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImUserCheck, "User Check"))]
 pub static ImUserCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImUserCheck",
+    label: "User Check",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7537,8 +10278,14 @@ pub static ImUserCheck: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 12h5v-1.799c-1.050-0.613-2.442-1.033-4-1.16v-0.825c1.102-0.621 2-2.168 2-3.716 0-2.485 0-4.5-3-4.5s-3 2.015-3 4.5c0 1.548 0.898 3.095 2 3.716v0.825c-3.392 0.277-6 1.944-6 3.959h7v-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImUserMinus, "User Minus"))]
 pub static ImUserMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImUserMinus",
+    label: "User Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7554,8 +10301,14 @@ pub static ImUserMinus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11.5 7c-2.485 0-4.5 2.015-4.5 4.5s2.015 4.5 4.5 4.5c2.485 0 4.5-2.015 4.5-4.5s-2.015-4.5-4.5-4.5zM14 12h-5v-1h5v1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImUserPlus, "User Plus"))]
 pub static ImUserPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImUserPlus",
+    label: "User Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7571,8 +10324,14 @@ pub static ImUserPlus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11.5 7c-2.485 0-4.5 2.015-4.5 4.5s2.015 4.5 4.5 4.5c2.485 0 4.5-2.015 4.5-4.5s-2.015-4.5-4.5-4.5zM14 12h-2v2h-1v-2h-2v-1h2v-2h1v2h2v1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImUserTie, "User Tie"))]
 pub static ImUserTie: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImUserTie",
+    label: "User Tie",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7587,8 +10346,14 @@ pub static ImUserTie: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M5 3c0-1.657 1.343-3 3-3s3 1.343 3 3c0 1.657-1.343 3-3 3s-3-1.343-3-3zM12.001 7h-0.553l-3.111 6.316 1.163-5.816-1.5-1.5-1.5 1.5 1.163 5.816-3.111-6.316h-0.554c-1.999 0-1.999 1.344-1.999 3v5h12v-5c0-1.656 0-3-1.999-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImUsers, "Users"))]
 pub static ImUsers: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImUsers",
+    label: "Users",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7604,8 +10369,14 @@ pub static ImUsers: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5.112 12.427c0.864-0.565 1.939-0.994 3.122-1.256-0.235-0.278-0.449-0.588-0.633-0.922-0.475-0.863-0.726-1.813-0.726-2.748 0-1.344 0-2.614 0.478-3.653 0.464-1.008 1.299-1.633 2.488-1.867-0.264-1.195-0.968-1.98-2.841-1.98-3 0-3 2.015-3 4.5 0 1.548 0.898 3.095 2 3.716v0.825c-3.392 0.277-6 1.944-6 3.959h4.359c0.227-0.202 0.478-0.393 0.753-0.573z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImVideoCamera, "Video Camera"))]
 pub static ImVideoCamera: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImVideoCamera",
+    label: "Video Camera",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7620,8 +10391,14 @@ pub static ImVideoCamera: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6 4.5c0-1.381 1.119-2.5 2.5-2.5s2.5 1.119 2.5 2.5c0 1.381-1.119 2.5-2.5 2.5s-2.5-1.119-2.5-2.5zM0 4.5c0-1.381 1.119-2.5 2.5-2.5s2.5 1.119 2.5 2.5c0 1.381-1.119 2.5-2.5 2.5s-2.5-1.119-2.5-2.5zM12 9.5v-1.5c0-0.55-0.45-1-1-1h-10c-0.55 0-1 0.45-1 1v5c0 0.55 0.45 1 1 1h10c0.55 0 1-0.45 1-1v-1.5l4 2.5v-7l-4 2.5zM10 12h-8v-3h8v3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImVimeo, "Vimeo"))]
 pub static ImVimeo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImVimeo",
+    label: "Vimeo",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7636,8 +10413,14 @@ pub static ImVimeo: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.994 4.281c-0.072 1.556-1.159 3.691-3.263 6.397-2.175 2.825-4.016 4.241-5.522 4.241-0.931 0-1.722-0.859-2.366-2.581-0.431-1.578-0.859-3.156-1.291-4.734-0.478-1.722-0.991-2.581-1.541-2.581-0.119 0-0.538 0.253-1.256 0.753l-0.753-0.969c0.791-0.694 1.569-1.388 2.334-2.081 1.053-0.909 1.844-1.387 2.372-1.438 1.244-0.119 2.013 0.731 2.3 2.553 0.309 1.966 0.525 3.188 0.647 3.666 0.359 1.631 0.753 2.447 1.184 2.447 0.334 0 0.838-0.528 1.509-1.588 0.669-1.056 1.028-1.862 1.078-2.416 0.097-0.912-0.262-1.372-1.078-1.372-0.384 0-0.778 0.088-1.184 0.263 0.787-2.575 2.287-3.825 4.506-3.753 1.641 0.044 2.416 1.109 2.322 3.194z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImVimeo2, "Vimeo 2"))]
 pub static ImVimeo2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImVimeo2",
+    label: "Vimeo 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7652,8 +10435,14 @@ pub static ImVimeo2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM13.463 5.313c-0.050 1.125-0.838 2.666-2.359 4.622-1.572 2.044-2.903 3.066-3.991 3.066-0.675 0-1.244-0.622-1.709-1.866-0.313-1.141-0.622-2.281-0.934-3.422-0.344-1.244-0.716-1.866-1.112-1.866-0.087 0-0.391 0.181-0.906 0.544l-0.544-0.7c0.572-0.5 1.134-1.003 1.687-1.503 0.763-0.656 1.331-1.003 1.712-1.038 0.9-0.087 1.453 0.528 1.662 1.844 0.225 1.422 0.381 2.303 0.469 2.65 0.259 1.178 0.544 1.766 0.856 1.766 0.241 0 0.606-0.381 1.091-1.147s0.744-1.347 0.778-1.747c0.069-0.659-0.191-0.991-0.778-0.991-0.278 0-0.563 0.063-0.856 0.191 0.569-1.859 1.653-2.766 3.256-2.712 1.188 0.034 1.747 0.803 1.678 2.309z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImVine, "Vine"))]
 pub static ImVine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImVine",
+    label: "Vine",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7668,8 +10457,14 @@ pub static ImVine: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.012 7.953c-0.412 0.094-0.809 0.137-1.169 0.137-2.019 0-3.572-1.409-3.572-3.862 0-1.203 0.466-1.825 1.122-1.825 0.625 0 1.041 0.559 1.041 1.697 0 0.647-0.172 1.356-0.3 1.775 0 0 0.622 1.084 2.322 0.753 0.363-0.803 0.556-1.841 0.556-2.75 0-2.45-1.25-3.878-3.541-3.878-2.356 0-3.734 1.809-3.734 4.197 0 2.366 1.106 4.394 2.928 5.319-0.766 1.534-1.741 2.884-2.759 3.903-1.844-2.231-3.513-5.206-4.197-11.016h-2.722c1.259 9.675 5.006 12.756 6 13.347 0.559 0.337 1.044 0.322 1.556 0.031 0.806-0.456 3.222-2.875 4.563-5.703 0.563 0 1.238-0.066 1.909-0.219v-1.906z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImVk, "Vk"))]
 pub static ImVk: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImVk",
+    label: "Vk",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7684,8 +10479,14 @@ pub static ImVk: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM12.959 11.2l-1.463 0.022c0 0-0.316 0.063-0.728-0.222-0.547-0.375-1.063-1.353-1.466-1.225-0.406 0.128-0.394 1.006-0.394 1.006s0.003 0.188-0.091 0.287c-0.1 0.109-0.3 0.131-0.3 0.131h-0.653c0 0-1.444 0.088-2.716-1.238-1.388-1.444-2.612-4.309-2.612-4.309s-0.072-0.188 0.006-0.278c0.087-0.103 0.322-0.109 0.322-0.109l1.566-0.009c0 0 0.147 0.025 0.253 0.103 0.088 0.063 0.134 0.184 0.134 0.184s0.253 0.641 0.588 1.219c0.653 1.128 0.959 1.375 1.181 1.256 0.322-0.175 0.225-1.597 0.225-1.597s0.006-0.516-0.162-0.744c-0.131-0.178-0.378-0.231-0.484-0.244-0.088-0.013 0.056-0.216 0.244-0.309 0.281-0.138 0.778-0.147 1.366-0.141 0.456 0.003 0.591 0.034 0.769 0.075 0.541 0.131 0.356 0.634 0.356 1.841 0 0.388-0.069 0.931 0.209 1.109 0.119 0.078 0.412 0.012 1.147-1.234 0.347-0.591 0.609-1.284 0.609-1.284s0.056-0.125 0.144-0.178c0.091-0.053 0.213-0.037 0.213-0.037l1.647-0.009c0 0 0.494-0.059 0.575 0.166 0.084 0.234-0.184 0.781-0.856 1.678-1.103 1.472-1.228 1.334-0.309 2.184 0.875 0.813 1.056 1.209 1.088 1.259 0.356 0.6-0.406 0.647-0.406 0.647z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImVolumeDecrease, "Volume Decrease"))]
 pub static ImVolumeDecrease: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImVolumeDecrease",
+    label: "Volume Decrease",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7701,8 +10502,14 @@ pub static ImVolumeDecrease: &icondata_core::IconData = &icondata_core::IconData
 <path d="M6.5 15c-0.13 0-0.258-0.051-0.354-0.146l-3.854-3.854h-1.793c-0.276 0-0.5-0.224-0.5-0.5v-5c0-0.276 0.224-0.5 0.5-0.5h1.793l3.854-3.854c0.143-0.143 0.358-0.186 0.545-0.108s0.309 0.26 0.309 0.462v13c0 0.202-0.122 0.385-0.309 0.462-0.062 0.026-0.127 0.038-0.191 0.038z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImVolumeHigh, "Volume High"))]
 pub static ImVolumeHigh: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImVolumeHigh",
+    label: "Volume High",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7718,8 +10525,14 @@ pub static ImVolumeHigh: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6.5 15c-0.13 0-0.258-0.051-0.354-0.146l-3.854-3.854h-1.793c-0.276 0-0.5-0.224-0.5-0.5v-5c0-0.276 0.224-0.5 0.5-0.5h1.793l3.854-3.854c0.143-0.143 0.358-0.186 0.545-0.108s0.309 0.26 0.309 0.462v13c0 0.202-0.122 0.385-0.309 0.462-0.062 0.026-0.127 0.038-0.191 0.038z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImVolumeIncrease, "Volume Increase"))]
 pub static ImVolumeIncrease: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImVolumeIncrease",
+    label: "Volume Increase",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7735,8 +10548,14 @@ pub static ImVolumeIncrease: &icondata_core::IconData = &icondata_core::IconData
 <path d="M6.5 15c-0.13 0-0.258-0.051-0.354-0.146l-3.854-3.854h-1.793c-0.276 0-0.5-0.224-0.5-0.5v-5c0-0.276 0.224-0.5 0.5-0.5h1.793l3.854-3.854c0.143-0.143 0.358-0.186 0.545-0.108s0.309 0.26 0.309 0.462v13c0 0.202-0.122 0.385-0.309 0.462-0.062 0.026-0.127 0.038-0.191 0.038z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImVolumeLow, "Volume Low"))]
 pub static ImVolumeLow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImVolumeLow",
+    label: "Volume Low",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7752,8 +10571,14 @@ pub static ImVolumeLow: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6.5 15c-0.13 0-0.258-0.051-0.354-0.146l-3.854-3.854h-1.793c-0.276 0-0.5-0.224-0.5-0.5v-5c0-0.276 0.224-0.5 0.5-0.5h1.793l3.854-3.854c0.143-0.143 0.358-0.186 0.545-0.108s0.309 0.26 0.309 0.462v13c0 0.202-0.122 0.385-0.309 0.462-0.062 0.026-0.127 0.038-0.191 0.038z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImVolumeMedium, "Volume Medium"))]
 pub static ImVolumeMedium: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImVolumeMedium",
+    label: "Volume Medium",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7769,8 +10594,14 @@ pub static ImVolumeMedium: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6.5 15c-0.13 0-0.258-0.051-0.354-0.146l-3.854-3.854h-1.793c-0.276 0-0.5-0.224-0.5-0.5v-5c0-0.276 0.224-0.5 0.5-0.5h1.793l3.854-3.854c0.143-0.143 0.358-0.186 0.545-0.108s0.309 0.26 0.309 0.462v13c0 0.202-0.122 0.385-0.309 0.462-0.062 0.026-0.127 0.038-0.191 0.038z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImVolumeMute, "Volume Mute"))]
 pub static ImVolumeMute: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImVolumeMute",
+    label: "Volume Mute",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7785,8 +10616,14 @@ pub static ImVolumeMute: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6.5 15c-0.13 0-0.258-0.051-0.354-0.146l-3.854-3.854h-1.793c-0.276 0-0.5-0.224-0.5-0.5v-5c0-0.276 0.224-0.5 0.5-0.5h1.793l3.854-3.854c0.143-0.143 0.358-0.186 0.545-0.108s0.309 0.26 0.309 0.462v13c0 0.202-0.122 0.385-0.309 0.462-0.062 0.026-0.127 0.038-0.191 0.038z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImVolumeMute2, "Volume Mute 2"))]
 pub static ImVolumeMute2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImVolumeMute2",
+    label: "Volume Mute 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7802,8 +10639,14 @@ pub static ImVolumeMute2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6.5 15c-0.13 0-0.258-0.051-0.354-0.146l-3.854-3.854h-1.793c-0.276 0-0.5-0.224-0.5-0.5v-5c0-0.276 0.224-0.5 0.5-0.5h1.793l3.854-3.854c0.143-0.143 0.358-0.186 0.545-0.108s0.309 0.26 0.309 0.462v13c0 0.202-0.122 0.385-0.309 0.462-0.062 0.026-0.127 0.038-0.191 0.038z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImWarning, "Warning"))]
 pub static ImWarning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImWarning",
+    label: "Warning",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7820,8 +10663,14 @@ pub static ImWarning: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 11c-0.552 0-1-0.448-1-1v-3c0-0.552 0.448-1 1-1s1 0.448 1 1v3c0 0.552-0.448 1-1 1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImWhatsapp, "Whatsapp"))]
 pub static ImWhatsapp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImWhatsapp",
+    label: "Whatsapp",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7836,8 +10685,14 @@ pub static ImWhatsapp: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M13.641 2.325c-1.497-1.5-3.488-2.325-5.609-2.325-4.369 0-7.925 3.556-7.925 7.928 0 1.397 0.366 2.763 1.059 3.963l-1.125 4.109 4.203-1.103c1.159 0.631 2.463 0.966 3.787 0.966h0.003c0 0 0 0 0 0 4.369 0 7.928-3.556 7.928-7.928 0-2.119-0.825-4.109-2.322-5.609zM8.034 14.525v0c-1.184 0-2.344-0.319-3.356-0.919l-0.241-0.144-2.494 0.653 0.666-2.431-0.156-0.25c-0.663-1.047-1.009-2.259-1.009-3.506 0-3.634 2.956-6.591 6.594-6.591 1.759 0 3.416 0.688 4.659 1.931 1.244 1.247 1.928 2.9 1.928 4.662-0.003 3.637-2.959 6.594-6.591 6.594zM11.647 9.588c-0.197-0.1-1.172-0.578-1.353-0.644s-0.313-0.1-0.447 0.1c-0.131 0.197-0.512 0.644-0.628 0.778-0.116 0.131-0.231 0.15-0.428 0.050s-0.838-0.309-1.594-0.984c-0.588-0.525-0.987-1.175-1.103-1.372s-0.013-0.306 0.088-0.403c0.091-0.088 0.197-0.231 0.297-0.347s0.131-0.197 0.197-0.331c0.066-0.131 0.034-0.247-0.016-0.347s-0.447-1.075-0.609-1.472c-0.159-0.388-0.325-0.334-0.447-0.341-0.116-0.006-0.247-0.006-0.378-0.006s-0.347 0.050-0.528 0.247c-0.181 0.197-0.694 0.678-0.694 1.653s0.709 1.916 0.809 2.050c0.1 0.131 1.397 2.134 3.384 2.991 0.472 0.203 0.841 0.325 1.128 0.419 0.475 0.15 0.906 0.128 1.247 0.078 0.381-0.056 1.172-0.478 1.338-0.941s0.166-0.859 0.116-0.941c-0.047-0.088-0.178-0.137-0.378-0.238z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImWikipedia, "Wikipedia"))]
 pub static ImWikipedia: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImWikipedia",
+    label: "Wikipedia",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7852,8 +10707,14 @@ pub static ImWikipedia: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.106 3.65c0 0.050-0.016 0.097-0.047 0.141-0.031 0.041-0.066 0.063-0.106 0.063-0.313 0.031-0.569 0.131-0.766 0.3-0.2 0.169-0.403 0.497-0.613 0.975l-3.225 7.272c-0.022 0.069-0.081 0.1-0.178 0.1-0.075 0-0.134-0.034-0.178-0.1l-1.809-3.781-2.081 3.781c-0.044 0.069-0.1 0.1-0.178 0.1-0.094 0-0.153-0.034-0.184-0.1l-3.166-7.269c-0.197-0.45-0.406-0.766-0.625-0.944s-0.525-0.291-0.916-0.331c-0.034 0-0.066-0.019-0.094-0.053-0.031-0.034-0.044-0.075-0.044-0.122 0-0.119 0.034-0.178 0.1-0.178 0.281 0 0.578 0.013 0.888 0.038 0.288 0.025 0.556 0.038 0.809 0.038 0.256 0 0.563-0.013 0.913-0.038 0.366-0.025 0.691-0.038 0.975-0.038 0.069 0 0.1 0.059 0.1 0.178s-0.022 0.175-0.063 0.175c-0.281 0.022-0.506 0.094-0.669 0.216s-0.244 0.281-0.244 0.481c0 0.1 0.034 0.228 0.1 0.378l2.616 5.912 1.487-2.806-1.384-2.903c-0.25-0.519-0.453-0.853-0.612-1.003s-0.403-0.241-0.728-0.275c-0.031 0-0.056-0.019-0.084-0.053s-0.041-0.075-0.041-0.122c0-0.119 0.028-0.178 0.088-0.178 0.281 0 0.541 0.013 0.778 0.038 0.228 0.025 0.469 0.038 0.728 0.038 0.253 0 0.519-0.013 0.803-0.038 0.291-0.025 0.578-0.038 0.859-0.038 0.069 0 0.1 0.059 0.1 0.178s-0.019 0.175-0.063 0.175c-0.566 0.038-0.847 0.2-0.847 0.481 0 0.125 0.066 0.322 0.197 0.588l0.916 1.859 0.912-1.7c0.125-0.241 0.191-0.444 0.191-0.606 0-0.388-0.281-0.594-0.847-0.619-0.050 0-0.075-0.059-0.075-0.175 0-0.044 0.012-0.081 0.037-0.119s0.050-0.056 0.075-0.056c0.203 0 0.45 0.013 0.747 0.038 0.281 0.025 0.516 0.038 0.697 0.038 0.131 0 0.322-0.013 0.575-0.031 0.319-0.028 0.588-0.044 0.803-0.044 0.050 0 0.075 0.050 0.075 0.15 0 0.134-0.047 0.203-0.137 0.203-0.328 0.034-0.594 0.125-0.794 0.272s-0.45 0.481-0.75 1.006l-1.222 2.237 1.644 3.35 2.428-5.647c0.084-0.206 0.125-0.397 0.125-0.569 0-0.412-0.281-0.631-0.847-0.659-0.050 0-0.075-0.059-0.075-0.175 0-0.119 0.037-0.178 0.113-0.178 0.206 0 0.45 0.013 0.734 0.038 0.262 0.025 0.481 0.038 0.656 0.038 0.188 0 0.4-0.013 0.644-0.038 0.253-0.025 0.481-0.038 0.684-0.038 0.063 0 0.094 0.050 0.094 0.15z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImWindows, "Windows"))]
 pub static ImWindows: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImWindows",
+    label: "Windows",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7868,8 +10729,14 @@ pub static ImWindows: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M6.441 7.999c-0.745-0.383-1.47-0.577-2.154-0.577-0.093 0-0.187 0.003-0.28 0.011-0.873 0.072-1.671 0.303-2.184 0.482-0.136 0.050-0.276 0.103-0.419 0.161l-1.403 4.866c0.964-0.357 1.817-0.53 2.598-0.53 1.263 0 2.18 0.472 2.937 0.958 0.359-1.217 1.219-4.158 1.476-5.036-0.187-0.114-0.376-0.228-0.571-0.333zM8.255 9.235l-1.413 4.909c0.419 0.24 1.83 1.001 2.91 1.001 0.872 0 1.848-0.223 2.982-0.684l1.349-4.718c-0.916 0.296-1.795 0.446-2.617 0.446-1.499 0-2.549-0.486-3.211-0.952zM4.575 5.762c1.205 0.012 2.096 0.472 2.835 0.945l1.449-4.958c-0.305-0.175-1.106-0.611-1.685-0.759-0.381-0.089-0.782-0.135-1.206-0.135-0.809 0.015-1.694 0.218-2.701 0.622l-1.382 4.853c1.013-0.382 1.885-0.568 2.689-0.568 0.001 0 0.002 0 0.002 0zM16 3.096c-0.919 0.357-1.816 0.539-2.672 0.539-1.433 0-2.489-0.497-3.173-0.974l-1.437 4.972c0.965 0.62 2.005 0.936 3.096 0.936 0.89 0 1.812-0.214 2.742-0.636l-0.003-0.035 0.058-0.014 1.39-4.788z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImWindows8, "Windows 8"))]
 pub static ImWindows8: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImWindows8",
+    label: "Windows 8",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7884,8 +10751,14 @@ pub static ImWindows8: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M0.005 8l-0.005-4.876 6-0.815v5.691zM7 2.164l7.998-1.164v7h-7.998zM15 9l-0.002 7-7.998-1.125v-5.875zM6 14.747l-5.995-0.822-0-4.926h5.995z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImWink, "Wink"))]
 pub static ImWink: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImWink",
+    label: "Wink",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7900,8 +10773,14 @@ pub static ImWink: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c4.418 0 8-3.582 8-8s-3.582-8-8-8-8 3.582-8 8 3.582 8 8 8zM8 1.5c3.59 0 6.5 2.91 6.5 6.5s-2.91 6.5-6.5 6.5-6.5-2.91-6.5-6.5 2.91-6.5 6.5-6.5zM8.48 11.11c2.191-0.433 3.892-1.43 4.507-2.759-0.338 2.624-2.524 4.649-5.17 4.649-1.863 0-3.498-1.004-4.42-2.515 1.1 0.86 3.040 1.028 5.083 0.625zM10 5.5c0-0.828 0.448-1.5 1-1.5s1 0.672 1 1.5c0 0.828-0.448 1.5-1 1.5s-1-0.672-1-1.5zM5.5 5.805c-0.653 0-1.208 0.245-1.414 0.586-0.055-0.092-0.086-0.503-0.086-0.605 0-0.485 0.672-0.879 1.5-0.879s1.5 0.394 1.5 0.879c0 0.103-0.030 0.514-0.086 0.605-0.206-0.341-0.761-0.586-1.414-0.586z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImWink2, "Wink 2"))]
 pub static ImWink2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImWink2",
+    label: "Wink 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7916,8 +10795,14 @@ pub static ImWink2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8c4.418 0 8-3.582 8-8s-3.582-8-8-8zM11 4c0.552 0 1 0.672 1 1.5s-0.448 1.5-1 1.5-1-0.672-1-1.5 0.448-1.5 1-1.5zM5.5 4.876c0.932 0 1.594 0.349 1.594 0.895 0 0.116 0.060 0.672-0.003 0.775-0.232-0.384-0.856-0.659-1.591-0.659s-1.359 0.275-1.591 0.659c-0.062-0.103-0.003-0.659-0.003-0.775 0-0.546 0.662-0.895 1.594-0.895zM7.818 13c-1.863 0-3.498-1.004-4.42-2.515 1.1 0.86 3.040 1.028 5.083 0.625 2.191-0.433 3.892-1.43 4.507-2.759-0.338 2.624-2.524 4.649-5.17 4.649z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImWoman, "Woman"))]
 pub static ImWoman: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImWoman",
+    label: "Woman",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7933,8 +10818,14 @@ pub static ImWoman: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11.234 8l0.766-0.555-2.083-3.221c-0.092-0.14-0.249-0.225-0.417-0.225h-4c-0.168 0-0.325 0.084-0.417 0.225l-2.083 3.221 0.766 0.555 1.729-2.244 0.601 1.402-2.095 3.841h1.917l0.333 5h1v-5h0.5v5h1l0.333-5h1.917l-2.095-3.842 0.601-1.402 1.729 2.244z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImWondering, "Wondering"))]
 pub static ImWondering: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImWondering",
+    label: "Wondering",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7949,8 +10840,14 @@ pub static ImWondering: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 16c4.418 0 8-3.582 8-8s-3.582-8-8-8-8 3.582-8 8 3.582 8 8 8zM8 1.5c3.59 0 6.5 2.91 6.5 6.5s-2.91 6.5-6.5 6.5-6.5-2.91-6.5-6.5 2.91-6.5 6.5-6.5zM11.652 9.4l0.351 1.2-6.828 2-0.351-1.2zM4 5c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1zM10 5c0-0.552 0.448-1 1-1s1 0.448 1 1c0 0.552-0.448 1-1 1s-1-0.448-1-1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImWondering2, "Wondering 2"))]
 pub static ImWondering2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImWondering2",
+    label: "Wondering 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7965,8 +10862,14 @@ pub static ImWondering2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM11 4c0.552 0 1 0.448 1 1s-0.448 1-1 1-1-0.448-1-1 0.448-1 1-1zM4 5c0-0.552 0.448-1 1-1s1 0.448 1 1-0.448 1-1 1-1-0.448-1-1zM5.176 12.6l-0.351-1.2 6.828-2 0.351 1.2-6.828 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImWordpress, "Wordpress"))]
 pub static ImWordpress: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImWordpress",
+    label: "Wordpress",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7981,8 +10884,14 @@ pub static ImWordpress: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M2 8c0 2.313 1.38 4.312 3.382 5.259l-2.862-7.637c-0.333 0.727-0.52 1.531-0.52 2.378zM12.050 7.705c0-0.722-0.266-1.222-0.495-1.612-0.304-0.482-0.589-0.889-0.589-1.371 0-0.537 0.418-1.037 1.008-1.037 0.027 0 0.052 0.003 0.078 0.005-1.067-0.953-2.49-1.534-4.052-1.534-2.096 0-3.94 1.048-5.013 2.634 0.141 0.004 0.274 0.007 0.386 0.007 0.627 0 1.599-0.074 1.599-0.074 0.323-0.018 0.361 0.444 0.038 0.482 0 0-0.325 0.037-0.687 0.055l2.185 6.33 1.313-3.835-0.935-2.495c-0.323-0.019-0.629-0.055-0.629-0.055-0.323-0.019-0.285-0.5 0.038-0.482 0 0 0.991 0.074 1.58 0.074 0.627 0 1.599-0.074 1.599-0.074 0.323-0.018 0.362 0.444 0.038 0.482 0 0-0.326 0.037-0.687 0.055l2.168 6.282 0.599-1.947c0.259-0.809 0.457-1.389 0.457-1.889zM8.105 8.511l-1.8 5.095c0.538 0.154 1.106 0.238 1.695 0.238 0.699 0 1.369-0.117 1.992-0.331-0.016-0.025-0.031-0.052-0.043-0.081l-1.844-4.921zM13.265 5.196c0.026 0.186 0.040 0.386 0.040 0.601 0 0.593-0.114 1.259-0.456 2.093l-1.833 5.16c1.784-1.013 2.983-2.895 2.983-5.051 0-1.016-0.267-1.971-0.735-2.803zM8 0c-4.418 0-8 3.582-8 8s3.582 8 8 8 8-3.582 8-8-3.582-8-8-8zM8 15c-3.866 0-7-3.134-7-7s3.134-7 7-7 7 3.134 7 7-3.134 7-7 7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImWrench, "Wrench"))]
 pub static ImWrench: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImWrench",
+    label: "Wrench",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7997,8 +10906,14 @@ pub static ImWrench: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.671 12.779l-7.196-6.168c0.335-0.63 0.525-1.348 0.525-2.111 0-2.485-2.015-4.5-4.5-4.5-0.455 0-0.893 0.068-1.307 0.193l2.6 2.6c0.389 0.389 0.389 1.025 0 1.414l-1.586 1.586c-0.389 0.389-1.025 0.389-1.414 0l-2.6-2.6c-0.125 0.414-0.193 0.852-0.193 1.307 0 2.485 2.015 4.5 4.5 4.5 0.763 0 1.482-0.19 2.111-0.525l6.168 7.196c0.358 0.418 0.969 0.441 1.358 0.052l1.586-1.586c0.389-0.389 0.365-1-0.052-1.358z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImXing, "Xing"))]
 pub static ImXing: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImXing",
+    label: "Xing",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8013,8 +10928,14 @@ pub static ImXing: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.5 0h-13c-0.825 0-1.5 0.675-1.5 1.5v13c0 0.825 0.675 1.5 1.5 1.5h13c0.825 0 1.5-0.675 1.5-1.5v-13c0-0.825-0.675-1.5-1.5-1.5zM4.884 10.406h-1.728c-0.103 0-0.181-0.047-0.225-0.119-0.047-0.075-0.047-0.169 0-0.266l1.838-3.244c0.003-0.003 0.003-0.006 0-0.009l-1.169-2.025c-0.047-0.097-0.056-0.191-0.009-0.266 0.044-0.072 0.131-0.109 0.237-0.109h1.731c0.266 0 0.397 0.172 0.481 0.325 0 0 1.181 2.063 1.191 2.075-0.069 0.125-1.869 3.303-1.869 3.303-0.094 0.162-0.219 0.334-0.478 0.334zM13.069 2.378l-3.831 6.775c-0.003 0.003-0.003 0.009 0 0.012l2.441 4.456c0.047 0.097 0.050 0.194 0.003 0.269-0.044 0.072-0.125 0.109-0.231 0.109h-1.728c-0.266 0-0.397-0.175-0.484-0.328 0 0-2.453-4.5-2.459-4.512 0.122-0.216 3.85-6.828 3.85-6.828 0.094-0.166 0.206-0.328 0.463-0.328h1.753c0.103 0 0.188 0.041 0.231 0.109 0.044 0.072 0.044 0.169-0.006 0.266z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImXing2, "Xing 2"))]
 pub static ImXing2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImXing2",
+    label: "Xing 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8030,8 +10951,14 @@ pub static ImXing2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12.125 0c-0.344 0-0.494 0.216-0.619 0.441 0 0-4.972 8.816-5.134 9.106 0.009 0.016 3.278 6.016 3.278 6.016 0.116 0.203 0.291 0.441 0.644 0.441h2.306c0.137 0 0.247-0.053 0.306-0.147 0.063-0.1 0.059-0.228-0.006-0.356l-3.25-5.947c-0.003-0.006-0.003-0.009 0-0.016l5.109-9.034c0.063-0.128 0.066-0.256 0.006-0.356-0.059-0.094-0.169-0.147-0.306-0.147h-2.334z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImYahoo, "Yahoo"))]
 pub static ImYahoo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImYahoo",
+    label: "Yahoo",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8046,8 +10973,14 @@ pub static ImYahoo: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8.878 9.203v0c1.759-3.088 4.666-8.125 5.463-9.203-0.35 0.234-0.887 0.353-1.381 0.466l-0.747-0.466c-0.6 1.119-2.813 4.734-4.222 7.050-1.428-2.366-3.119-5.097-4.222-7.050-0.875 0.188-1.237 0.197-2.109 0v0 0c0 0 0 0 0 0v0c1.731 2.606 4.503 7.572 5.447 9.203v0l-0.128 6.797 1.013-0.466v-0.012l1.012 0.478-0.125-6.797z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImYahoo2, "Yahoo 2"))]
 pub static ImYahoo2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImYahoo2",
+    label: "Yahoo 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8062,8 +10995,14 @@ pub static ImYahoo2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8.019 1.087c-2.828 0-5.5-0.372-8.019-1.087 0 5.653 0 14.581 0 16 2.522-0.716 5.194-1.088 8.019-1.088 2.794 0 5.459 0.363 7.981 1.088 0-5.444 0-10.153 0-16-2.522 0.725-5.184 1.087-7.981 1.087zM12.45 2.453l-0.097 0.153c-0.091 0.144-0.172 0.266-0.284 0.438-0.15 0.225-0.431 0.672-0.769 1.247-0.094 0.159-0.209 0.35-0.328 0.556-0.228 0.384-0.484 0.819-0.688 1.162-0.084 0.147-0.169 0.297-0.256 0.447-0.225 0.391-0.456 0.794-0.678 1.181-0.228 0.403-0.453 0.8-0.678 1.194v0.397c0 0.55 0.012 1.15 0.031 1.684 0.009 0.244 0.019 0.678 0.031 1.137 0.012 0.547 0.025 1.113 0.041 1.4l0.003 0.088v0.009l-0.094-0.025c-0.037-0.009-0.072-0.019-0.109-0.028-0.113-0.025-0.234-0.044-0.353-0.056-0.072-0.006-0.147-0.009-0.222-0.009 0 0 0 0 0 0s0 0 0 0c-0.075 0-0.15 0.003-0.222 0.009-0.119 0.012-0.241 0.031-0.353 0.056-0.037 0.009-0.075 0.019-0.109 0.028l-0.094 0.025v-0.009l0.003-0.088c0.013-0.284 0.028-0.853 0.041-1.4 0.009-0.459 0.022-0.894 0.031-1.137 0.022-0.537 0.031-1.134 0.031-1.684v-0.397c-0.225-0.397-0.45-0.791-0.678-1.194-0.222-0.391-0.453-0.791-0.675-1.181-0.088-0.15-0.172-0.3-0.256-0.447-0.2-0.347-0.459-0.781-0.688-1.162-0.122-0.203-0.237-0.397-0.328-0.556-0.338-0.575-0.619-1.019-0.769-1.247-0.112-0.172-0.194-0.294-0.284-0.438l-0.097-0.153 0.175 0.050c0.222 0.063 0.45 0.094 0.694 0.094s0.478-0.031 0.697-0.094l0.053-0.016 0.028 0.047c0.431 0.778 1.591 2.684 2.284 3.825 0.237 0.394 0.428 0.703 0.522 0.862 0 0 0 0 0-0.003 0 0 0 0 0 0.003 0.094-0.156 0.284-0.469 0.522-0.862 0.694-1.138 1.853-3.044 2.284-3.825l0.028-0.047 0.053 0.016c0.219 0.063 0.453 0.094 0.697 0.094s0.472-0.031 0.694-0.094l0.166-0.050z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImYelp, "Yelp"))]
 pub static ImYelp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImYelp",
+    label: "Yelp",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8078,8 +11017,14 @@ pub static ImYelp: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9.514 10.21c-0.27 0.272-0.042 0.768-0.042 0.768l2.033 3.394c0 0 0.334 0.447 0.623 0.447 0.29 0 0.577-0.239 0.577-0.239l1.607-2.297c0 0 0.162-0.29 0.166-0.544 0.006-0.361-0.538-0.46-0.538-0.46l-3.805-1.222c-0 0-0.373-0.099-0.621 0.152zM9.321 8.5c0.195 0.33 0.732 0.234 0.732 0.234l3.796-1.109c0 0 0.517-0.21 0.591-0.491 0.072-0.281-0.085-0.619-0.085-0.619l-1.814-2.137c0 0-0.157-0.27-0.483-0.297-0.36-0.031-0.581 0.405-0.581 0.405l-2.145 3.375c0 0-0.19 0.336-0.010 0.64zM7.527 7.184c0.447-0.11 0.518-0.759 0.518-0.759l-0.030-5.404c0 0-0.067-0.667-0.367-0.847-0.47-0.285-0.609-0.136-0.744-0.116l-3.151 1.171c0 0-0.309 0.102-0.469 0.36-0.23 0.365 0.233 0.899 0.233 0.899l3.276 4.465c0 0 0.323 0.334 0.735 0.233zM6.749 9.371c0.011-0.417-0.5-0.667-0.5-0.667l-3.387-1.711c0 0-0.502-0.207-0.746-0.063-0.187 0.11-0.352 0.31-0.368 0.486l-0.221 2.716c0 0-0.033 0.471 0.089 0.685 0.173 0.304 0.741 0.092 0.741 0.092l3.955-0.874c0.154-0.103 0.423-0.113 0.438-0.664zM7.732 10.837c-0.339-0.174-0.746 0.187-0.746 0.187l-2.648 2.915c0 0-0.33 0.446-0.246 0.72 0.079 0.257 0.21 0.384 0.396 0.474l2.659 0.839c0 0 0.322 0.067 0.567-0.004 0.347-0.1 0.283-0.643 0.283-0.643l0.060-3.947c-0 0-0.014-0.38-0.324-0.541z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImYoutube, "Youtube"))]
 pub static ImYoutube: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImYoutube",
+    label: "Youtube",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8094,8 +11039,14 @@ pub static ImYoutube: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.841 4.8c0 0-0.156-1.103-0.637-1.587-0.609-0.637-1.291-0.641-1.603-0.678-2.237-0.163-5.597-0.163-5.597-0.163h-0.006c0 0-3.359 0-5.597 0.163-0.313 0.038-0.994 0.041-1.603 0.678-0.481 0.484-0.634 1.587-0.634 1.587s-0.159 1.294-0.159 2.591v1.213c0 1.294 0.159 2.591 0.159 2.591s0.156 1.103 0.634 1.588c0.609 0.637 1.409 0.616 1.766 0.684 1.281 0.122 5.441 0.159 5.441 0.159s3.363-0.006 5.6-0.166c0.313-0.037 0.994-0.041 1.603-0.678 0.481-0.484 0.637-1.588 0.637-1.588s0.159-1.294 0.159-2.591v-1.213c-0.003-1.294-0.162-2.591-0.162-2.591zM6.347 10.075v-4.497l4.322 2.256-4.322 2.241z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImYoutube2, "Youtube 2"))]
 pub static ImYoutube2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImYoutube2",
+    label: "Youtube 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8115,8 +11066,14 @@ pub static ImYoutube2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M40.014 4.791c-0.142-1.701-0.255-2.253-0.605-2.962-0.465-0.939-1.136-1.434-2.092-1.543-0.739-0.084-3.521-0.203-6.094-0.26-4.456-0.099-11.782 0.092-12.718 0.331-0.432 0.111-0.757 0.299-1.094 0.634-0.591 0.588-0.944 1.432-1.085 2.6-0.323 2.666-0.33 5.886-0.019 8.649 0.134 1.188 0.41 1.96 0.928 2.596 0.323 0.397 0.881 0.734 1.379 0.835 0.35 0.071 2.1 0.169 4.65 0.26 0.38 0.014 1.385 0.037 2.235 0.052 1.77 0.031 5.025 0.013 6.886-0.039 1.252-0.035 3.534-0.128 3.961-0.161 0.12-0.009 0.398-0.027 0.618-0.039 0.739-0.042 1.209-0.196 1.65-0.543 0.571-0.449 1.013-1.278 1.2-2.251 0.177-0.92 0.295-2.559 0.319-4.42 0.020-1.555-0.007-2.393-0.119-3.741zM22.27 4.175l-0.828 0.010-0.036 8.83-0.718 0.009c-0.555 0.008-0.724-0.001-0.737-0.036-0.010-0.025-0.021-2.016-0.026-4.424l-0.009-4.379-1.617-0.020v-1.38l4.779 0.019 0.020 1.36-0.828 0.010zM27.347 9.236v3.797h-1.308v-0.4c0-0.301-0.011-0.4-0.047-0.4-0.026 0-0.144 0.099-0.263 0.22-0.259 0.263-0.565 0.474-0.827 0.572-0.542 0.203-1.056 0.084-1.275-0.293-0.201-0.345-0.204-0.423-0.204-4.005v-3.29h1.307l0.010 3.098c0.010 3.044 0.011 3.1 0.084 3.224 0.097 0.164 0.244 0.209 0.478 0.144 0.138-0.038 0.232-0.105 0.455-0.327l0.282-0.28v-5.859h1.308v3.797zM32.449 12.491c-0.115 0.257-0.372 0.508-0.583 0.57-0.549 0.162-0.99 0.030-1.499-0.449-0.158-0.149-0.305-0.269-0.327-0.269-0.027 0-0.041 0.116-0.041 0.345v0.345h-1.308v-10.248h1.308v1.672c0 0.919 0.012 1.672 0.027 1.672s0.153-0.122 0.307-0.27c0.354-0.341 0.649-0.491 1.024-0.519 0.669-0.051 1.068 0.294 1.25 1.080 0.057 0.245 0.062 0.525 0.062 2.798-0 2.768-0 2.78-0.221 3.273zM37.984 10.971c-0.012 0.285-0.046 0.612-0.077 0.727-0.182 0.674-0.666 1.152-1.366 1.348-0.942 0.264-1.98-0.168-2.394-0.997-0.232-0.465-0.241-0.558-0.241-2.831 0-1.853 0.007-2.081 0.066-2.334 0.168-0.715 0.584-1.178 1.289-1.435 0.204-0.074 0.417-0.113 0.63-0.117 0.761-0.016 1.515 0.393 1.832 1.059 0.213 0.449 0.24 0.642 0.261 1.908l0.019 1.136-2.789 0.019-0.010 0.763c-0.015 1.077 0.058 1.408 0.349 1.603 0.244 0.165 0.62 0.152 0.824-0.027 0.192-0.168 0.246-0.349 0.265-0.877l0.017-0.463h1.347l-0.022 0.518z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImZoomIn, "Zoom In"))]
 pub static ImZoomIn: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImZoomIn",
+    label: "Zoom In",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8131,8 +11088,14 @@ pub static ImZoomIn: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15.504 13.616l-3.79-3.223c-0.392-0.353-0.811-0.514-1.149-0.499 0.895-1.048 1.435-2.407 1.435-3.893 0-3.314-2.686-6-6-6s-6 2.686-6 6 2.686 6 6 6c1.486 0 2.845-0.54 3.893-1.435-0.016 0.338 0.146 0.757 0.499 1.149l3.223 3.79c0.552 0.613 1.453 0.665 2.003 0.115s0.498-1.452-0.115-2.003zM6 10c-2.209 0-4-1.791-4-4s1.791-4 4-4 4 1.791 4 4-1.791 4-4 4zM7 3h-2v2h-2v2h2v2h2v-2h2v-2h-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(ImZoomOut, "Zoom Out"))]
 pub static ImZoomOut: &icondata_core::IconData = &icondata_core::IconData {
+    id: "ImZoomOut",
+    label: "Zoom Out",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
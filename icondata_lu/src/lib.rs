@@ -1,9 +1,32 @@
 //! This crate provides a collection of icons in the form of SVG data
 //! from the [__Lucide__](https://github.com/lucide-icons/lucide) icon set.
 
+/// Documentation of every icon, showing its usage with different frameworks.
+#[cfg(feature = "docs")]
+macro_rules! examples {
+    ($icon:ident, $label:literal) => {
+        concat!(
+            $label,
+            "\n\n# Examples\n\nWith [Leptos](https://leptos.dev) and [`leptos_icons`](https://docs.rs/leptos_icons):\n```rust,ignore\nuse leptos::*;\nuse leptos_icons::Icon;\n\nview! { <Icon icon=icondata::",
+            stringify!($icon),
+            " /> }\n```\n\nWith [Yew](https://yew.rs):\n```rust,ignore\nuse yew::prelude::*;\n\nlet svg = icondata::",
+            stringify!($icon),
+            ".render(&Default::default());\nhtml! { { Html::from_html_unchecked(AttrValue::from(svg)) } }\n```\n\nWith [Dioxus](https://dioxuslabs.com):\n```rust,ignore\nuse dioxus::prelude::*;\n\nlet svg = icondata::",
+            stringify!($icon),
+            ".render(&Default::default());\nrsx! { span { dangerous_inner_html: \"{svg}\" } }\n```",
+        )
+    };
+}
+
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAccessibility, "Accessibility"))]
 pub static LuAccessibility: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAccessibility",
+    label: "Accessibility",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22,8 +45,14 @@ pub static LuAccessibility: &icondata_core::IconData = &icondata_core::IconData
 <path d="M13.76 17.5a5 5 0 0 0-6.88-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuActivity, "Activity"))]
 pub static LuActivity: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuActivity",
+    label: "Activity",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -38,8 +67,14 @@ pub static LuActivity: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M22 12h-4l-3 9L9 3l-3 9H2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuActivitySquare, "Activity Square"))]
 pub static LuActivitySquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuActivitySquare",
+    label: "Activity Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -55,8 +90,14 @@ pub static LuActivitySquare: &icondata_core::IconData = &icondata_core::IconData
 <path d="M17 12h-2l-2 5-2-10-2 5H7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAirVent, "Air Vent"))]
 pub static LuAirVent: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAirVent",
+    label: "Air Vent",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -74,8 +115,14 @@ pub static LuAirVent: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6.6 15.6A2 2 0 1 0 10 17v-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAirplay, "Airplay"))]
 pub static LuAirplay: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAirplay",
+    label: "Airplay",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -91,8 +138,14 @@ pub static LuAirplay: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="12 15 17 21 7 21 12 15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlarmCheck, "Alarm Check"))]
 pub static LuAlarmCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlarmCheck",
+    label: "Alarm Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -112,8 +165,14 @@ pub static LuAlarmCheck: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 13 2 2 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlarmClock, "Alarm Clock"))]
 pub static LuAlarmClock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlarmClock",
+    label: "Alarm Clock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -133,8 +192,14 @@ pub static LuAlarmClock: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17.64 18.67 20 21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlarmClockOff, "Alarm Clock Off"))]
 pub static LuAlarmClockOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlarmClockOff",
+    label: "Alarm Clock Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -154,8 +219,14 @@ pub static LuAlarmClockOff: &icondata_core::IconData = &icondata_core::IconData
 <path d="M4 4 2 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlarmMinus, "Alarm Minus"))]
 pub static LuAlarmMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlarmMinus",
+    label: "Alarm Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -175,8 +246,14 @@ pub static LuAlarmMinus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 13h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlarmPlus, "Alarm Plus"))]
 pub static LuAlarmPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlarmPlus",
+    label: "Alarm Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -197,8 +274,14 @@ pub static LuAlarmPlus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 13h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlbum, "Album"))]
 pub static LuAlbum: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlbum",
+    label: "Album",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -214,8 +297,14 @@ pub static LuAlbum: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="11 3 11 11 14 8 17 11 17 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlertCircle, "Alert Circle"))]
 pub static LuAlertCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlertCircle",
+    label: "Alert Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -232,8 +321,14 @@ pub static LuAlertCircle: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12.01" y1="16" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlertOctagon, "Alert Octagon"))]
 pub static LuAlertOctagon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlertOctagon",
+    label: "Alert Octagon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -250,8 +345,14 @@ pub static LuAlertOctagon: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12.01" y1="16" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlertTriangle, "Alert Triangle"))]
 pub static LuAlertTriangle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlertTriangle",
+    label: "Alert Triangle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -268,8 +369,14 @@ pub static LuAlertTriangle: &icondata_core::IconData = &icondata_core::IconData
 <path d="M12 17h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignCenter, "Align Center"))]
 pub static LuAlignCenter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignCenter",
+    label: "Align Center",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -286,8 +393,14 @@ pub static LuAlignCenter: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="19" x2="5" y1="18" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignCenterHorizontal, "Align Center Horizontal"))]
 pub static LuAlignCenterHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignCenterHorizontal",
+    label: "Align Center Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -306,8 +419,14 @@ pub static LuAlignCenterHorizontal: &icondata_core::IconData = &icondata_core::I
 <path d="M14 8V7c0-1.1.9-2 2-2h2a2 2 0 0 1 2 2v1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignCenterVertical, "Align Center Vertical"))]
 pub static LuAlignCenterVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignCenterVertical",
+    label: "Align Center Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -326,8 +445,14 @@ pub static LuAlignCenterVertical: &icondata_core::IconData = &icondata_core::Ico
 <path d="M16 14h1a2 2 0 0 1 2 2v2a2 2 0 0 1-2 2h-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignEndHorizontal, "Align End Horizontal"))]
 pub static LuAlignEndHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignEndHorizontal",
+    label: "Align End Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -344,8 +469,14 @@ pub static LuAlignEndHorizontal: &icondata_core::IconData = &icondata_core::Icon
 <path d="M22 22H2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignEndVertical, "Align End Vertical"))]
 pub static LuAlignEndVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignEndVertical",
+    label: "Align End Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -362,8 +493,14 @@ pub static LuAlignEndVertical: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M22 22V2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignHorizontalDistributeCenter, "Align Horizontal Distribute Center"))]
 pub static LuAlignHorizontalDistributeCenter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignHorizontalDistributeCenter",
+    label: "Align Horizontal Distribute Center",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -383,8 +520,14 @@ pub static LuAlignHorizontalDistributeCenter: &icondata_core::IconData = &iconda
 <path d="M7 5V2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignHorizontalDistributeEnd, "Align Horizontal Distribute End"))]
 pub static LuAlignHorizontalDistributeEnd: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignHorizontalDistributeEnd",
+    label: "Align Horizontal Distribute End",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -402,8 +545,14 @@ pub static LuAlignHorizontalDistributeEnd: &icondata_core::IconData = &icondata_
 <path d="M20 2v20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignHorizontalDistributeStart, "Align Horizontal Distribute Start"))]
 pub static LuAlignHorizontalDistributeStart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignHorizontalDistributeStart",
+    label: "Align Horizontal Distribute Start",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -421,8 +570,14 @@ pub static LuAlignHorizontalDistributeStart: &icondata_core::IconData = &icondat
 <path d="M14 2v20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignHorizontalJustifyCenter, "Align Horizontal Justify Center"))]
 pub static LuAlignHorizontalJustifyCenter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignHorizontalJustifyCenter",
+    label: "Align Horizontal Justify Center",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -439,8 +594,14 @@ pub static LuAlignHorizontalJustifyCenter: &icondata_core::IconData = &icondata_
 <path d="M12 2v20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignHorizontalJustifyEnd, "Align Horizontal Justify End"))]
 pub static LuAlignHorizontalJustifyEnd: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignHorizontalJustifyEnd",
+    label: "Align Horizontal Justify End",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -457,8 +618,14 @@ pub static LuAlignHorizontalJustifyEnd: &icondata_core::IconData = &icondata_cor
 <path d="M22 2v20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignHorizontalJustifyStart, "Align Horizontal Justify Start"))]
 pub static LuAlignHorizontalJustifyStart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignHorizontalJustifyStart",
+    label: "Align Horizontal Justify Start",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -475,8 +642,14 @@ pub static LuAlignHorizontalJustifyStart: &icondata_core::IconData = &icondata_c
 <path d="M2 2v20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignHorizontalSpaceAround, "Align Horizontal Space Around"))]
 pub static LuAlignHorizontalSpaceAround: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignHorizontalSpaceAround",
+    label: "Align Horizontal Space Around",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -493,8 +666,14 @@ pub static LuAlignHorizontalSpaceAround: &icondata_core::IconData = &icondata_co
 <path d="M20 22V2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignHorizontalSpaceBetween, "Align Horizontal Space Between"))]
 pub static LuAlignHorizontalSpaceBetween: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignHorizontalSpaceBetween",
+    label: "Align Horizontal Space Between",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -512,8 +691,14 @@ pub static LuAlignHorizontalSpaceBetween: &icondata_core::IconData = &icondata_c
 <path d="M21 2v20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignJustify, "Align Justify"))]
 pub static LuAlignJustify: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignJustify",
+    label: "Align Justify",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -530,8 +715,14 @@ pub static LuAlignJustify: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" x2="21" y1="18" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignLeft, "Align Left"))]
 pub static LuAlignLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignLeft",
+    label: "Align Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -548,8 +739,14 @@ pub static LuAlignLeft: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="17" x2="3" y1="18" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignRight, "Align Right"))]
 pub static LuAlignRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignRight",
+    label: "Align Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -566,8 +763,14 @@ pub static LuAlignRight: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="21" x2="7" y1="18" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignStartHorizontal, "Align Start Horizontal"))]
 pub static LuAlignStartHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignStartHorizontal",
+    label: "Align Start Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -584,8 +787,14 @@ pub static LuAlignStartHorizontal: &icondata_core::IconData = &icondata_core::Ic
 <path d="M22 2H2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignStartVertical, "Align Start Vertical"))]
 pub static LuAlignStartVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignStartVertical",
+    label: "Align Start Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -602,8 +811,14 @@ pub static LuAlignStartVertical: &icondata_core::IconData = &icondata_core::Icon
 <path d="M2 2v20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignVerticalDistributeCenter, "Align Vertical Distribute Center"))]
 pub static LuAlignVerticalDistributeCenter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignVerticalDistributeCenter",
+    label: "Align Vertical Distribute Center",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -623,8 +838,14 @@ pub static LuAlignVerticalDistributeCenter: &icondata_core::IconData = &icondata
 <path d="M5 17H2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignVerticalDistributeEnd, "Align Vertical Distribute End"))]
 pub static LuAlignVerticalDistributeEnd: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignVerticalDistributeEnd",
+    label: "Align Vertical Distribute End",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -642,8 +863,14 @@ pub static LuAlignVerticalDistributeEnd: &icondata_core::IconData = &icondata_co
 <path d="M2 10h20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignVerticalDistributeStart, "Align Vertical Distribute Start"))]
 pub static LuAlignVerticalDistributeStart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignVerticalDistributeStart",
+    label: "Align Vertical Distribute Start",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -661,8 +888,14 @@ pub static LuAlignVerticalDistributeStart: &icondata_core::IconData = &icondata_
 <path d="M2 4h20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignVerticalJustifyCenter, "Align Vertical Justify Center"))]
 pub static LuAlignVerticalJustifyCenter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignVerticalJustifyCenter",
+    label: "Align Vertical Justify Center",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -679,8 +912,14 @@ pub static LuAlignVerticalJustifyCenter: &icondata_core::IconData = &icondata_co
 <path d="M2 12h20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignVerticalJustifyEnd, "Align Vertical Justify End"))]
 pub static LuAlignVerticalJustifyEnd: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignVerticalJustifyEnd",
+    label: "Align Vertical Justify End",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -697,8 +936,14 @@ pub static LuAlignVerticalJustifyEnd: &icondata_core::IconData = &icondata_core:
 <path d="M2 22h20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignVerticalJustifyStart, "Align Vertical Justify Start"))]
 pub static LuAlignVerticalJustifyStart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignVerticalJustifyStart",
+    label: "Align Vertical Justify Start",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -715,8 +960,14 @@ pub static LuAlignVerticalJustifyStart: &icondata_core::IconData = &icondata_cor
 <path d="M2 2h20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignVerticalSpaceAround, "Align Vertical Space Around"))]
 pub static LuAlignVerticalSpaceAround: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignVerticalSpaceAround",
+    label: "Align Vertical Space Around",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -733,8 +984,14 @@ pub static LuAlignVerticalSpaceAround: &icondata_core::IconData = &icondata_core
 <path d="M22 4H2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAlignVerticalSpaceBetween, "Align Vertical Space Between"))]
 pub static LuAlignVerticalSpaceBetween: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAlignVerticalSpaceBetween",
+    label: "Align Vertical Space Between",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -752,8 +1009,14 @@ pub static LuAlignVerticalSpaceBetween: &icondata_core::IconData = &icondata_cor
 <path d="M2 3h20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAmpersand, "Ampersand"))]
 pub static LuAmpersand: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAmpersand",
+    label: "Ampersand",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -769,8 +1032,14 @@ pub static LuAmpersand: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 12h3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAmpersands, "Ampersands"))]
 pub static LuAmpersands: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAmpersands",
+    label: "Ampersands",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -786,8 +1055,14 @@ pub static LuAmpersands: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 17c-5-3-7-7-7-9a2 2 0 0 1 4 0c0 2.5-5 2.5-5 6 0 1.7 1.3 3 3 3 2.8 0 5-2.2 5-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAnchor, "Anchor"))]
 pub static LuAnchor: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAnchor",
+    label: "Anchor",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -804,8 +1079,14 @@ pub static LuAnchor: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 12H2a10 10 0 0 0 20 0h-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAngry, "Angry"))]
 pub static LuAngry: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAngry",
+    label: "Angry",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -825,8 +1106,14 @@ pub static LuAngry: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 10h0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAnnoyed, "Annoyed"))]
 pub static LuAnnoyed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAnnoyed",
+    label: "Annoyed",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -844,8 +1131,14 @@ pub static LuAnnoyed: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 9h2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAntenna, "Antenna"))]
 pub static LuAntenna: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAntenna",
+    label: "Antenna",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -865,8 +1158,14 @@ pub static LuAntenna: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 16v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAperture, "Aperture"))]
 pub static LuAperture: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAperture",
+    label: "Aperture",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -887,8 +1186,14 @@ pub static LuAperture: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="16.62" x2="10.88" y1="12" y2="21.94" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAppWindow, "App Window"))]
 pub static LuAppWindow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAppWindow",
+    label: "App Window",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -906,8 +1211,14 @@ pub static LuAppWindow: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6 4v4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuApple, "Apple"))]
 pub static LuApple: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuApple",
+    label: "Apple",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -923,8 +1234,14 @@ pub static LuApple: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 2c1 .5 2 2 2 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArchive, "Archive"))]
 pub static LuArchive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArchive",
+    label: "Archive",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -941,8 +1258,14 @@ pub static LuArchive: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 13h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArchiveRestore, "Archive Restore"))]
 pub static LuArchiveRestore: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArchiveRestore",
+    label: "Archive Restore",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -961,8 +1284,14 @@ pub static LuArchiveRestore: &icondata_core::IconData = &icondata_core::IconData
 <path d="M20 9v9a2 2 0 0 1-2 2h-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAreaChart, "Area Chart"))]
 pub static LuAreaChart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAreaChart",
+    label: "Area Chart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -978,8 +1307,14 @@ pub static LuAreaChart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 12v5h12V8l-5 5-4-4Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArmchair, "Armchair"))]
 pub static LuArmchair: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArmchair",
+    label: "Armchair",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -997,8 +1332,14 @@ pub static LuArmchair: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 18v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowBigDown, "Arrow Big Down"))]
 pub static LuArrowBigDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowBigDown",
+    label: "Arrow Big Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1013,8 +1354,14 @@ pub static LuArrowBigDown: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15 6v6h4l-7 7-7-7h4V6h6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowBigDownDash, "Arrow Big Down Dash"))]
 pub static LuArrowBigDownDash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowBigDownDash",
+    label: "Arrow Big Down Dash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1030,8 +1377,14 @@ pub static LuArrowBigDownDash: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M15 9v3h4l-7 7-7-7h4V9h6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowBigLeft, "Arrow Big Left"))]
 pub static LuArrowBigLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowBigLeft",
+    label: "Arrow Big Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1046,8 +1399,14 @@ pub static LuArrowBigLeft: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M18 15h-6v4l-7-7 7-7v4h6v6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowBigLeftDash, "Arrow Big Left Dash"))]
 pub static LuArrowBigLeftDash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowBigLeftDash",
+    label: "Arrow Big Left Dash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1063,8 +1422,14 @@ pub static LuArrowBigLeftDash: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M15 15h-3v4l-7-7 7-7v4h3v6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowBigRight, "Arrow Big Right"))]
 pub static LuArrowBigRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowBigRight",
+    label: "Arrow Big Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1079,8 +1444,14 @@ pub static LuArrowBigRight: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M6 9h6V5l7 7-7 7v-4H6V9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowBigRightDash, "Arrow Big Right Dash"))]
 pub static LuArrowBigRightDash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowBigRightDash",
+    label: "Arrow Big Right Dash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1096,8 +1467,14 @@ pub static LuArrowBigRightDash: &icondata_core::IconData = &icondata_core::IconD
 <path d="M9 9h3V5l7 7-7 7v-4H9V9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowBigUp, "Arrow Big Up"))]
 pub static LuArrowBigUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowBigUp",
+    label: "Arrow Big Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1112,8 +1489,14 @@ pub static LuArrowBigUp: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9 18v-6H5l7-7 7 7h-4v6H9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowBigUpDash, "Arrow Big Up Dash"))]
 pub static LuArrowBigUpDash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowBigUpDash",
+    label: "Arrow Big Up Dash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1129,8 +1512,14 @@ pub static LuArrowBigUpDash: &icondata_core::IconData = &icondata_core::IconData
 <path d="M9 15v-3H5l7-7 7 7h-4v3H9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDown, "Arrow Down"))]
 pub static LuArrowDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDown",
+    label: "Arrow Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1146,8 +1535,14 @@ pub static LuArrowDown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m19 12-7 7-7-7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDown01, "Arrow Down 01"))]
 pub static LuArrowDown01: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDown01",
+    label: "Arrow Down 01",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1166,8 +1561,14 @@ pub static LuArrowDown01: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 20h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDown10, "Arrow Down 10"))]
 pub static LuArrowDown10: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDown10",
+    label: "Arrow Down 10",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1186,8 +1587,14 @@ pub static LuArrowDown10: &icondata_core::IconData = &icondata_core::IconData {
 <rect x="15" y="14" width="4" height="6" ry="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownAZ, "Arrow Down Az"))]
 pub static LuArrowDownAZ: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownAZ",
+    label: "Arrow Down Az",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1206,8 +1613,14 @@ pub static LuArrowDownAZ: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 14h5l-5 6h5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownCircle, "Arrow Down Circle"))]
 pub static LuArrowDownCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownCircle",
+    label: "Arrow Down Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1224,8 +1637,14 @@ pub static LuArrowDownCircle: &icondata_core::IconData = &icondata_core::IconDat
 <path d="m8 12 4 4 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownFromLine, "Arrow Down From Line"))]
 pub static LuArrowDownFromLine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownFromLine",
+    label: "Arrow Down From Line",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1242,8 +1661,14 @@ pub static LuArrowDownFromLine: &icondata_core::IconData = &icondata_core::IconD
 <path d="m6 15 6 6 6-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownLeft, "Arrow Down Left"))]
 pub static LuArrowDownLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownLeft",
+    label: "Arrow Down Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1259,8 +1684,14 @@ pub static LuArrowDownLeft: &icondata_core::IconData = &icondata_core::IconData
 <path d="M17 17H7V7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownLeftFromCircle, "Arrow Down Left From Circle"))]
 pub static LuArrowDownLeftFromCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownLeftFromCircle",
+    label: "Arrow Down Left From Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1277,8 +1708,14 @@ pub static LuArrowDownLeftFromCircle: &icondata_core::IconData = &icondata_core:
 <path d="M8 22H2v-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownLeftSquare, "Arrow Down Left Square"))]
 pub static LuArrowDownLeftSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownLeftSquare",
+    label: "Arrow Down Left Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1295,8 +1732,14 @@ pub static LuArrowDownLeftSquare: &icondata_core::IconData = &icondata_core::Ico
 <path d="M16 16H8V8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownNarrowWide, "Arrow Down Narrow Wide"))]
 pub static LuArrowDownNarrowWide: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownNarrowWide",
+    label: "Arrow Down Narrow Wide",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1315,8 +1758,14 @@ pub static LuArrowDownNarrowWide: &icondata_core::IconData = &icondata_core::Ico
 <path d="M11 12h10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownRight, "Arrow Down Right"))]
 pub static LuArrowDownRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownRight",
+    label: "Arrow Down Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1332,8 +1781,14 @@ pub static LuArrowDownRight: &icondata_core::IconData = &icondata_core::IconData
 <path d="M17 7v10H7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownRightFromCircle, "Arrow Down Right From Circle"))]
 pub static LuArrowDownRightFromCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownRightFromCircle",
+    label: "Arrow Down Right From Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1350,8 +1805,14 @@ pub static LuArrowDownRightFromCircle: &icondata_core::IconData = &icondata_core
 <path d="M22 16v6h-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownRightSquare, "Arrow Down Right Square"))]
 pub static LuArrowDownRightSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownRightSquare",
+    label: "Arrow Down Right Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1368,8 +1829,14 @@ pub static LuArrowDownRightSquare: &icondata_core::IconData = &icondata_core::Ic
 <path d="M16 8v8H8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownSquare, "Arrow Down Square"))]
 pub static LuArrowDownSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownSquare",
+    label: "Arrow Down Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1386,8 +1853,14 @@ pub static LuArrowDownSquare: &icondata_core::IconData = &icondata_core::IconDat
 <path d="m8 12 4 4 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownToDot, "Arrow Down To Dot"))]
 pub static LuArrowDownToDot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownToDot",
+    label: "Arrow Down To Dot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1404,8 +1877,14 @@ pub static LuArrowDownToDot: &icondata_core::IconData = &icondata_core::IconData
 <circle cx="12" cy="21" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownToLine, "Arrow Down To Line"))]
 pub static LuArrowDownToLine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownToLine",
+    label: "Arrow Down To Line",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1422,8 +1901,14 @@ pub static LuArrowDownToLine: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M19 21H5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownUp, "Arrow Down Up"))]
 pub static LuArrowDownUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownUp",
+    label: "Arrow Down Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1441,8 +1926,14 @@ pub static LuArrowDownUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17 4v16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownWideNarrow, "Arrow Down Wide Narrow"))]
 pub static LuArrowDownWideNarrow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownWideNarrow",
+    label: "Arrow Down Wide Narrow",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1461,8 +1952,14 @@ pub static LuArrowDownWideNarrow: &icondata_core::IconData = &icondata_core::Ico
 <path d="M11 12h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowDownZA, "Arrow Down Za"))]
 pub static LuArrowDownZA: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowDownZA",
+    label: "Arrow Down Za",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1481,8 +1978,14 @@ pub static LuArrowDownZA: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 18h-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowLeft, "Arrow Left"))]
 pub static LuArrowLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowLeft",
+    label: "Arrow Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1498,8 +2001,14 @@ pub static LuArrowLeft: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 12H5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowLeftCircle, "Arrow Left Circle"))]
 pub static LuArrowLeftCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowLeftCircle",
+    label: "Arrow Left Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1516,8 +2025,14 @@ pub static LuArrowLeftCircle: &icondata_core::IconData = &icondata_core::IconDat
 <path d="m12 8-4 4 4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowLeftFromLine, "Arrow Left From Line"))]
 pub static LuArrowLeftFromLine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowLeftFromLine",
+    label: "Arrow Left From Line",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1534,8 +2049,14 @@ pub static LuArrowLeftFromLine: &icondata_core::IconData = &icondata_core::IconD
 <path d="M21 19V5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowLeftRight, "Arrow Left Right"))]
 pub static LuArrowLeftRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowLeftRight",
+    label: "Arrow Left Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1553,8 +2074,14 @@ pub static LuArrowLeftRight: &icondata_core::IconData = &icondata_core::IconData
 <path d="M20 17H4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowLeftSquare, "Arrow Left Square"))]
 pub static LuArrowLeftSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowLeftSquare",
+    label: "Arrow Left Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1571,8 +2098,14 @@ pub static LuArrowLeftSquare: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M16 12H8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowLeftToLine, "Arrow Left To Line"))]
 pub static LuArrowLeftToLine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowLeftToLine",
+    label: "Arrow Left To Line",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1589,8 +2122,14 @@ pub static LuArrowLeftToLine: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M7 12h14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowRight, "Arrow Right"))]
 pub static LuArrowRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowRight",
+    label: "Arrow Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1606,8 +2145,14 @@ pub static LuArrowRight: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m12 5 7 7-7 7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowRightCircle, "Arrow Right Circle"))]
 pub static LuArrowRightCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowRightCircle",
+    label: "Arrow Right Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1624,8 +2169,14 @@ pub static LuArrowRightCircle: &icondata_core::IconData = &icondata_core::IconDa
 <path d="m12 16 4-4-4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowRightFromLine, "Arrow Right From Line"))]
 pub static LuArrowRightFromLine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowRightFromLine",
+    label: "Arrow Right From Line",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1642,8 +2193,14 @@ pub static LuArrowRightFromLine: &icondata_core::IconData = &icondata_core::Icon
 <path d="m15 18 6-6-6-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowRightLeft, "Arrow Right Left"))]
 pub static LuArrowRightLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowRightLeft",
+    label: "Arrow Right Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1661,8 +2218,14 @@ pub static LuArrowRightLeft: &icondata_core::IconData = &icondata_core::IconData
 <path d="M4 17h16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowRightSquare, "Arrow Right Square"))]
 pub static LuArrowRightSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowRightSquare",
+    label: "Arrow Right Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1679,8 +2242,14 @@ pub static LuArrowRightSquare: &icondata_core::IconData = &icondata_core::IconDa
 <path d="m12 16 4-4-4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowRightToLine, "Arrow Right To Line"))]
 pub static LuArrowRightToLine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowRightToLine",
+    label: "Arrow Right To Line",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1697,8 +2266,14 @@ pub static LuArrowRightToLine: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M21 5v14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUp, "Arrow Up"))]
 pub static LuArrowUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUp",
+    label: "Arrow Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1714,8 +2289,14 @@ pub static LuArrowUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 19V5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUp01, "Arrow Up 01"))]
 pub static LuArrowUp01: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUp01",
+    label: "Arrow Up 01",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1734,8 +2315,14 @@ pub static LuArrowUp01: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 20h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUp10, "Arrow Up 10"))]
 pub static LuArrowUp10: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUp10",
+    label: "Arrow Up 10",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1754,8 +2341,14 @@ pub static LuArrowUp10: &icondata_core::IconData = &icondata_core::IconData {
 <rect x="15" y="14" width="4" height="6" ry="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpAZ, "Arrow Up Az"))]
 pub static LuArrowUpAZ: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpAZ",
+    label: "Arrow Up Az",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1774,8 +2367,14 @@ pub static LuArrowUpAZ: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 14h5l-5 6h5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpCircle, "Arrow Up Circle"))]
 pub static LuArrowUpCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpCircle",
+    label: "Arrow Up Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1792,8 +2391,14 @@ pub static LuArrowUpCircle: &icondata_core::IconData = &icondata_core::IconData
 <path d="M12 16V8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpDown, "Arrow Up Down"))]
 pub static LuArrowUpDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpDown",
+    label: "Arrow Up Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1811,8 +2416,14 @@ pub static LuArrowUpDown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 4v16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpFromDot, "Arrow Up From Dot"))]
 pub static LuArrowUpFromDot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpFromDot",
+    label: "Arrow Up From Dot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1829,8 +2440,14 @@ pub static LuArrowUpFromDot: &icondata_core::IconData = &icondata_core::IconData
 <circle cx="12" cy="21" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpFromLine, "Arrow Up From Line"))]
 pub static LuArrowUpFromLine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpFromLine",
+    label: "Arrow Up From Line",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1847,8 +2464,14 @@ pub static LuArrowUpFromLine: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M5 21h14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpLeft, "Arrow Up Left"))]
 pub static LuArrowUpLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpLeft",
+    label: "Arrow Up Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1864,8 +2487,14 @@ pub static LuArrowUpLeft: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17 17 7 7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpLeftFromCircle, "Arrow Up Left From Circle"))]
 pub static LuArrowUpLeftFromCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpLeftFromCircle",
+    label: "Arrow Up Left From Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1882,8 +2511,14 @@ pub static LuArrowUpLeftFromCircle: &icondata_core::IconData = &icondata_core::I
 <path d="M12 2A10 10 0 1 1 2 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpLeftSquare, "Arrow Up Left Square"))]
 pub static LuArrowUpLeftSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpLeftSquare",
+    label: "Arrow Up Left Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1900,8 +2535,14 @@ pub static LuArrowUpLeftSquare: &icondata_core::IconData = &icondata_core::IconD
 <path d="M16 16 8 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpNarrowWide, "Arrow Up Narrow Wide"))]
 pub static LuArrowUpNarrowWide: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpNarrowWide",
+    label: "Arrow Up Narrow Wide",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1920,8 +2561,14 @@ pub static LuArrowUpNarrowWide: &icondata_core::IconData = &icondata_core::IconD
 <path d="M11 20h10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpRight, "Arrow Up Right"))]
 pub static LuArrowUpRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpRight",
+    label: "Arrow Up Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1937,8 +2584,14 @@ pub static LuArrowUpRight: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 17 17 7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpRightFromCircle, "Arrow Up Right From Circle"))]
 pub static LuArrowUpRightFromCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpRightFromCircle",
+    label: "Arrow Up Right From Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1955,8 +2608,14 @@ pub static LuArrowUpRightFromCircle: &icondata_core::IconData = &icondata_core::
 <path d="M16 2h6v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpRightSquare, "Arrow Up Right Square"))]
 pub static LuArrowUpRightSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpRightSquare",
+    label: "Arrow Up Right Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1973,8 +2632,14 @@ pub static LuArrowUpRightSquare: &icondata_core::IconData = &icondata_core::Icon
 <path d="m8 16 8-8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpSquare, "Arrow Up Square"))]
 pub static LuArrowUpSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpSquare",
+    label: "Arrow Up Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1991,8 +2656,14 @@ pub static LuArrowUpSquare: &icondata_core::IconData = &icondata_core::IconData
 <path d="M12 16V8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpToLine, "Arrow Up To Line"))]
 pub static LuArrowUpToLine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpToLine",
+    label: "Arrow Up To Line",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2009,8 +2680,14 @@ pub static LuArrowUpToLine: &icondata_core::IconData = &icondata_core::IconData
 <path d="M12 7v14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpWideNarrow, "Arrow Up Wide Narrow"))]
 pub static LuArrowUpWideNarrow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpWideNarrow",
+    label: "Arrow Up Wide Narrow",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2029,8 +2706,14 @@ pub static LuArrowUpWideNarrow: &icondata_core::IconData = &icondata_core::IconD
 <path d="M11 20h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowUpZA, "Arrow Up Za"))]
 pub static LuArrowUpZA: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowUpZA",
+    label: "Arrow Up Za",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2049,8 +2732,14 @@ pub static LuArrowUpZA: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 18h-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuArrowsUpFromLine, "Arrows Up From Line"))]
 pub static LuArrowsUpFromLine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuArrowsUpFromLine",
+    label: "Arrows Up From Line",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2069,8 +2758,14 @@ pub static LuArrowsUpFromLine: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M4 21h16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAsterisk, "Asterisk"))]
 pub static LuAsterisk: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAsterisk",
+    label: "Asterisk",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2087,8 +2782,14 @@ pub static LuAsterisk: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m6.804 9 10.392 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAtSign, "At Sign"))]
 pub static LuAtSign: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAtSign",
+    label: "At Sign",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2104,8 +2805,14 @@ pub static LuAtSign: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 8v5a3 3 0 0 0 6 0v-1a10 10 0 1 0-4 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAtom, "Atom"))]
 pub static LuAtom: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAtom",
+    label: "Atom",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2122,8 +2829,14 @@ pub static LuAtom: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15.7 15.7c4.52-4.54 6.54-9.87 4.5-11.9-2.03-2.04-7.36-.02-11.9 4.5-4.52 4.54-6.54 9.87-4.5 11.9 2.03 2.04 7.36.02 11.9-4.5Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAward, "Award"))]
 pub static LuAward: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAward",
+    label: "Award",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2139,8 +2852,14 @@ pub static LuAward: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15.477 12.89 17 22l-5-3-5 3 1.523-9.11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAxe, "Axe"))]
 pub static LuAxe: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAxe",
+    label: "Axe",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2156,8 +2875,14 @@ pub static LuAxe: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 13 9 7l4-4 6 6h3a8 8 0 0 1-7 7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuAxis3d, "Axis 3 D"))]
 pub static LuAxis3d: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuAxis3d",
+    label: "Axis 3 D",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2173,8 +2898,14 @@ pub static LuAxis3d: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m4 20 7-7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBaby, "Baby"))]
 pub static LuBaby: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBaby",
+    label: "Baby",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2192,8 +2923,14 @@ pub static LuBaby: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 6.3a9 9 0 0 1 1.8 3.9 2 2 0 0 1 0 3.6 9 9 0 0 1-17.6 0 2 2 0 0 1 0-3.6A9 9 0 0 1 12 3c2 0 3.5 1.1 3.5 2.5s-.9 2.5-2 2.5c-.8 0-1.5-.4-1.5-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBackpack, "Backpack"))]
 pub static LuBackpack: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBackpack",
+    label: "Backpack",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2212,8 +2949,14 @@ pub static LuBackpack: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 18h8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBadge, "Badge"))]
 pub static LuBadge: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBadge",
+    label: "Badge",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2228,8 +2971,14 @@ pub static LuBadge: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M3.85 8.62a4 4 0 0 1 4.78-4.77 4 4 0 0 1 6.74 0 4 4 0 0 1 4.78 4.78 4 4 0 0 1 0 6.74 4 4 0 0 1-4.77 4.78 4 4 0 0 1-6.75 0 4 4 0 0 1-4.78-4.77 4 4 0 0 1 0-6.76Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBadgeAlert, "Badge Alert"))]
 pub static LuBadgeAlert: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBadgeAlert",
+    label: "Badge Alert",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2246,8 +2995,14 @@ pub static LuBadgeAlert: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12.01" y1="16" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBadgeCheck, "Badge Check"))]
 pub static LuBadgeCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBadgeCheck",
+    label: "Badge Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2263,8 +3018,14 @@ pub static LuBadgeCheck: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 12 2 2 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBadgeDollarSign, "Badge Dollar Sign"))]
 pub static LuBadgeDollarSign: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBadgeDollarSign",
+    label: "Badge Dollar Sign",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2281,8 +3042,14 @@ pub static LuBadgeDollarSign: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M12 18V6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBadgeHelp, "Badge Help"))]
 pub static LuBadgeHelp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBadgeHelp",
+    label: "Badge Help",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2299,8 +3066,14 @@ pub static LuBadgeHelp: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12.01" y1="17" y2="17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBadgeInfo, "Badge Info"))]
 pub static LuBadgeInfo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBadgeInfo",
+    label: "Badge Info",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2317,8 +3090,14 @@ pub static LuBadgeInfo: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12.01" y1="8" y2="8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBadgeMinus, "Badge Minus"))]
 pub static LuBadgeMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBadgeMinus",
+    label: "Badge Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2334,8 +3113,14 @@ pub static LuBadgeMinus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8" x2="16" y1="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBadgePercent, "Badge Percent"))]
 pub static LuBadgePercent: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBadgePercent",
+    label: "Badge Percent",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2353,8 +3138,14 @@ pub static LuBadgePercent: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 15h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBadgePlus, "Badge Plus"))]
 pub static LuBadgePlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBadgePlus",
+    label: "Badge Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2371,8 +3162,14 @@ pub static LuBadgePlus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8" x2="16" y1="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBadgeX, "Badge X"))]
 pub static LuBadgeX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBadgeX",
+    label: "Badge X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2389,8 +3186,14 @@ pub static LuBadgeX: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" x2="15" y1="9" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBaggageClaim, "Baggage Claim"))]
 pub static LuBaggageClaim: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBaggageClaim",
+    label: "Baggage Claim",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2409,8 +3212,14 @@ pub static LuBaggageClaim: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="9" cy="20" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBan, "Ban"))]
 pub static LuBan: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBan",
+    label: "Ban",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2426,8 +3235,14 @@ pub static LuBan: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m4.9 4.9 14.2 14.2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBanana, "Banana"))]
 pub static LuBanana: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBanana",
+    label: "Banana",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2443,8 +3258,14 @@ pub static LuBanana: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5.15 17.89c5.52-1.52 8.65-6.89 7-12C11.55 4 11.5 2 13 2c3.22 0 5 5.5 5 8 0 6.5-4.2 12-10.49 12C5.11 22 2 22 2 20c0-1.5 1.14-1.55 3.15-2.11Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBanknote, "Banknote"))]
 pub static LuBanknote: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBanknote",
+    label: "Banknote",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2461,8 +3282,14 @@ pub static LuBanknote: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6 12h.01M18 12h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBarChart, "Bar Chart"))]
 pub static LuBarChart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBarChart",
+    label: "Bar Chart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2479,8 +3306,14 @@ pub static LuBarChart: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="6" x2="6" y1="20" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBarChart2, "Bar Chart 2"))]
 pub static LuBarChart2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBarChart2",
+    label: "Bar Chart 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2497,8 +3330,14 @@ pub static LuBarChart2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="6" x2="6" y1="20" y2="14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBarChart3, "Bar Chart 3"))]
 pub static LuBarChart3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBarChart3",
+    label: "Bar Chart 3",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2516,8 +3355,14 @@ pub static LuBarChart3: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 17v-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBarChart4, "Bar Chart 4"))]
 pub static LuBarChart4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBarChart4",
+    label: "Bar Chart 4",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2535,8 +3380,14 @@ pub static LuBarChart4: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 17v-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBarChartBig, "Bar Chart Big"))]
 pub static LuBarChartBig: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBarChartBig",
+    label: "Bar Chart Big",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2553,8 +3404,14 @@ pub static LuBarChartBig: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="4" height="12" x="15" y="5" rx="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBarChartHorizontal, "Bar Chart Horizontal"))]
 pub static LuBarChartHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBarChartHorizontal",
+    label: "Bar Chart Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2572,8 +3429,14 @@ pub static LuBarChartHorizontal: &icondata_core::IconData = &icondata_core::Icon
 <path d="M7 6h3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBarChartHorizontalBig, "Bar Chart Horizontal Big"))]
 pub static LuBarChartHorizontalBig: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBarChartHorizontalBig",
+    label: "Bar Chart Horizontal Big",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2590,8 +3453,14 @@ pub static LuBarChartHorizontalBig: &icondata_core::IconData = &icondata_core::I
 <rect width="7" height="4" x="7" y="13" rx="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBaseline, "Baseline"))]
 pub static LuBaseline: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBaseline",
+    label: "Baseline",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2608,8 +3477,14 @@ pub static LuBaseline: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 12h8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBath, "Bath"))]
 pub static LuBath: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBath",
+    label: "Bath",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2628,8 +3503,14 @@ pub static LuBath: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="17" x2="17" y1="19" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBattery, "Battery"))]
 pub static LuBattery: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBattery",
+    label: "Battery",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2645,8 +3526,14 @@ pub static LuBattery: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="22" x2="22" y1="11" y2="13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBatteryCharging, "Battery Charging"))]
 pub static LuBatteryCharging: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBatteryCharging",
+    label: "Battery Charging",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2664,8 +3551,14 @@ pub static LuBatteryCharging: &icondata_core::IconData = &icondata_core::IconDat
 <line x1="22" x2="22" y1="11" y2="13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBatteryFull, "Battery Full"))]
 pub static LuBatteryFull: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBatteryFull",
+    label: "Battery Full",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2684,8 +3577,14 @@ pub static LuBatteryFull: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="14" x2="14" y1="11" y2="13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBatteryLow, "Battery Low"))]
 pub static LuBatteryLow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBatteryLow",
+    label: "Battery Low",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2702,8 +3601,14 @@ pub static LuBatteryLow: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="6" x2="6" y1="11" y2="13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBatteryMedium, "Battery Medium"))]
 pub static LuBatteryMedium: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBatteryMedium",
+    label: "Battery Medium",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2721,8 +3626,14 @@ pub static LuBatteryMedium: &icondata_core::IconData = &icondata_core::IconData
 <line x1="10" x2="10" y1="11" y2="13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBatteryWarning, "Battery Warning"))]
 pub static LuBatteryWarning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBatteryWarning",
+    label: "Battery Warning",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2741,8 +3652,14 @@ pub static LuBatteryWarning: &icondata_core::IconData = &icondata_core::IconData
 <line x1="10" x2="10" y1="17" y2="17.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBeaker, "Beaker"))]
 pub static LuBeaker: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBeaker",
+    label: "Beaker",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2759,8 +3676,14 @@ pub static LuBeaker: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6 14h12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBean, "Bean"))]
 pub static LuBean: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBean",
+    label: "Bean",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2776,8 +3699,14 @@ pub static LuBean: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5.341 10.62a4 4 0 1 0 5.279-5.28" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBeanOff, "Bean Off"))]
 pub static LuBeanOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBeanOff",
+    label: "Bean Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2795,8 +3724,14 @@ pub static LuBeanOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBed, "Bed"))]
 pub static LuBed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBed",
+    label: "Bed",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2814,8 +3749,14 @@ pub static LuBed: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6 8v9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBedDouble, "Bed Double"))]
 pub static LuBedDouble: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBedDouble",
+    label: "Bed Double",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2833,8 +3774,14 @@ pub static LuBedDouble: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2 18h20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBedSingle, "Bed Single"))]
 pub static LuBedSingle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBedSingle",
+    label: "Bed Single",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2851,8 +3798,14 @@ pub static LuBedSingle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 18h18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBeef, "Beef"))]
 pub static LuBeef: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBeef",
+    label: "Beef",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2869,8 +3822,14 @@ pub static LuBeef: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m18.5 6 2.19 4.5a6.48 6.48 0 0 1 .31 2 6.49 6.49 0 0 1-2.6 5.2C15.4 20.2 11 22 7 22a3 3 0 0 1-2.68-1.66L2.4 16.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBeer, "Beer"))]
 pub static LuBeer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBeer",
+    label: "Beer",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2889,8 +3848,14 @@ pub static LuBeer: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 8v12a2 2 0 0 0 2 2h8a2 2 0 0 0 2-2V8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBell, "Bell"))]
 pub static LuBell: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBell",
+    label: "Bell",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2906,8 +3871,14 @@ pub static LuBell: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10.3 21a1.94 1.94 0 0 0 3.4 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBellDot, "Bell Dot"))]
 pub static LuBellDot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBellDot",
+    label: "Bell Dot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2924,8 +3895,14 @@ pub static LuBellDot: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="18" cy="8" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBellMinus, "Bell Minus"))]
 pub static LuBellMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBellMinus",
+    label: "Bell Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2942,8 +3919,14 @@ pub static LuBellMinus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 8h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBellOff, "Bell Off"))]
 pub static LuBellOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBellOff",
+    label: "Bell Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2961,8 +3944,14 @@ pub static LuBellOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2 2 20 20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBellPlus, "Bell Plus"))]
 pub static LuBellPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBellPlus",
+    label: "Bell Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2980,8 +3969,14 @@ pub static LuBellPlus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 5v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBellRing, "Bell Ring"))]
 pub static LuBellRing: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBellRing",
+    label: "Bell Ring",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -2999,8 +3994,14 @@ pub static LuBellRing: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 8c0-2.3-.8-4.3-2-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBike, "Bike"))]
 pub static LuBike: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBike",
+    label: "Bike",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3018,8 +4019,14 @@ pub static LuBike: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 17.5V14l-3-3 4-3 2 3h2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBinary, "Binary"))]
 pub static LuBinary: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBinary",
+    label: "Binary",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3039,8 +4046,14 @@ pub static LuBinary: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 4h2v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBiohazard, "Biohazard"))]
 pub static LuBiohazard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBiohazard",
+    label: "Biohazard",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3064,8 +4077,14 @@ pub static LuBiohazard: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5.5 13.9c.3.9.8 1.8 1.5 2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBird, "Bird"))]
 pub static LuBird: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBird",
+    label: "Bird",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3085,8 +4104,14 @@ pub static LuBird: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 18a6 6 0 0 0 3.84-10.61" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBitcoin, "Bitcoin"))]
 pub static LuBitcoin: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBitcoin",
+    label: "Bitcoin",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3101,8 +4126,14 @@ pub static LuBitcoin: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11.767 19.089c4.924.868 6.14-6.025 1.216-6.894m-1.216 6.894L5.86 18.047m5.908 1.042-.347 1.97m1.563-8.864c4.924.869 6.14-6.025 1.215-6.893m-1.215 6.893-3.94-.694m5.155-6.2L8.29 4.26m5.908 1.042.348-1.97M7.48 20.364l3.126-17.727" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBlinds, "Blinds"))]
 pub static LuBlinds: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBlinds",
+    label: "Blinds",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3123,8 +4154,14 @@ pub static LuBlinds: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="4" cy="19" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBlocks, "Blocks"))]
 pub static LuBlocks: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBlocks",
+    label: "Blocks",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3140,8 +4177,14 @@ pub static LuBlocks: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 21V8a1 1 0 0 0-1-1H4a1 1 0 0 0-1 1v12a1 1 0 0 0 1 1h12a1 1 0 0 0 1-1v-5a1 1 0 0 0-1-1H3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBluetooth, "Bluetooth"))]
 pub static LuBluetooth: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBluetooth",
+    label: "Bluetooth",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3156,8 +4199,14 @@ pub static LuBluetooth: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m7 7 10 10-5 5V2l5 5L7 17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBluetoothConnected, "Bluetooth Connected"))]
 pub static LuBluetoothConnected: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBluetoothConnected",
+    label: "Bluetooth Connected",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3174,8 +4223,14 @@ pub static LuBluetoothConnected: &icondata_core::IconData = &icondata_core::Icon
 <line x1="3" x2="6" y1="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBluetoothOff, "Bluetooth Off"))]
 pub static LuBluetoothOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBluetoothOff",
+    label: "Bluetooth Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3192,8 +4247,14 @@ pub static LuBluetoothOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.5 9.5 17 7l-5-5v4.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBluetoothSearching, "Bluetooth Searching"))]
 pub static LuBluetoothSearching: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBluetoothSearching",
+    label: "Bluetooth Searching",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3210,8 +4271,14 @@ pub static LuBluetoothSearching: &icondata_core::IconData = &icondata_core::Icon
 <path d="M18 12h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBold, "Bold"))]
 pub static LuBold: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBold",
+    label: "Bold",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3227,8 +4294,14 @@ pub static LuBold: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 20a4 4 0 0 0 0-8H6v8Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBomb, "Bomb"))]
 pub static LuBomb: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBomb",
+    label: "Bomb",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3245,8 +4318,14 @@ pub static LuBomb: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m22 2-1.5 1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBone, "Bone"))]
 pub static LuBone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBone",
+    label: "Bone",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3261,8 +4340,14 @@ pub static LuBone: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M17 10c.7-.7 1.69 0 2.5 0a2.5 2.5 0 1 0 0-5 .5.5 0 0 1-.5-.5 2.5 2.5 0 1 0-5 0c0 .81.7 1.8 0 2.5l-7 7c-.7.7-1.69 0-2.5 0a2.5 2.5 0 0 0 0 5c.28 0 .5.22.5.5a2.5 2.5 0 1 0 5 0c0-.81-.7-1.8 0-2.5Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBook, "Book"))]
 pub static LuBook: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBook",
+    label: "Book",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3277,8 +4362,14 @@ pub static LuBook: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4 19.5v-15A2.5 2.5 0 0 1 6.5 2H20v20H6.5a2.5 2.5 0 0 1 0-5H20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookCopy, "Book Copy"))]
 pub static LuBookCopy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookCopy",
+    label: "Book Copy",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3295,8 +4386,14 @@ pub static LuBookCopy: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 18H11a2 2 0 1 0 0 4h11V6H11a2 2 0 0 0-2 2v12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookDown, "Book Down"))]
 pub static LuBookDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookDown",
+    label: "Book Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3313,8 +4410,14 @@ pub static LuBookDown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 10 3 3 3-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookKey, "Book Key"))]
 pub static LuBookKey: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookKey",
+    label: "Book Key",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3333,8 +4436,14 @@ pub static LuBookKey: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m19 3 1 1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookLock, "Book Lock"))]
 pub static LuBookLock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookLock",
+    label: "Book Lock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3352,8 +4461,14 @@ pub static LuBookLock: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 6V4a2 2 0 1 0-4 0v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookMarked, "Book Marked"))]
 pub static LuBookMarked: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookMarked",
+    label: "Book Marked",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3369,8 +4484,14 @@ pub static LuBookMarked: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="10 2 10 10 13 7 16 10 16 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookMinus, "Book Minus"))]
 pub static LuBookMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookMinus",
+    label: "Book Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3386,8 +4507,14 @@ pub static LuBookMinus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 10h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookOpen, "Book Open"))]
 pub static LuBookOpen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookOpen",
+    label: "Book Open",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3403,8 +4530,14 @@ pub static LuBookOpen: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 3h-6a4 4 0 0 0-4 4v14a3 3 0 0 1 3-3h7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookOpenCheck, "Book Open Check"))]
 pub static LuBookOpenCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookOpenCheck",
+    label: "Book Open Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3421,8 +4554,14 @@ pub static LuBookOpenCheck: &icondata_core::IconData = &icondata_core::IconData
 <path d="M22 6V3h-6c-2.2 0-4 1.8-4 4v14c0-1.7 1.3-3 3-3h7v-2.3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookPlus, "Book Plus"))]
 pub static LuBookPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookPlus",
+    label: "Book Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3439,8 +4578,14 @@ pub static LuBookPlus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 7v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookTemplate, "Book Template"))]
 pub static LuBookTemplate: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookTemplate",
+    label: "Book Template",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3465,8 +4610,14 @@ pub static LuBookTemplate: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 5v-.5A2.5 2.5 0 0 1 6.5 2H8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookUp, "Book Up"))]
 pub static LuBookUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookUp",
+    label: "Book Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3483,8 +4634,14 @@ pub static LuBookUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 10 3-3 3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookUp2, "Book Up 2"))]
 pub static LuBookUp2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookUp2",
+    label: "Book Up 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3503,8 +4660,14 @@ pub static LuBookUp2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 5 3-3 3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookX, "Book X"))]
 pub static LuBookX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookX",
+    label: "Book X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3521,8 +4684,14 @@ pub static LuBookX: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9.5 7 5 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookmark, "Bookmark"))]
 pub static LuBookmark: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookmark",
+    label: "Bookmark",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3537,8 +4706,14 @@ pub static LuBookmark: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m19 21-7-4-7 4V5a2 2 0 0 1 2-2h10a2 2 0 0 1 2 2v16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookmarkMinus, "Bookmark Minus"))]
 pub static LuBookmarkMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookmarkMinus",
+    label: "Bookmark Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3554,8 +4729,14 @@ pub static LuBookmarkMinus: &icondata_core::IconData = &icondata_core::IconData
 <line x1="15" x2="9" y1="10" y2="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBookmarkPlus, "Bookmark Plus"))]
 pub static LuBookmarkPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBookmarkPlus",
+    label: "Bookmark Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3572,8 +4753,14 @@ pub static LuBookmarkPlus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" x2="9" y1="10" y2="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBoomBox, "Boom Box"))]
 pub static LuBoomBox: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBoomBox",
+    label: "Boom Box",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3594,8 +4781,14 @@ pub static LuBoomBox: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="16" cy="15" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBot, "Bot"))]
 pub static LuBot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBot",
+    label: "Bot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3614,8 +4807,14 @@ pub static LuBot: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="16" x2="16" y1="16" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBox, "Box"))]
 pub static LuBox: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBox",
+    label: "Box",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3632,8 +4831,14 @@ pub static LuBox: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="22" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBoxSelect, "Box Select"))]
 pub static LuBoxSelect: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBoxSelect",
+    label: "Box Select",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3659,8 +4864,14 @@ pub static LuBoxSelect: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 14v1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBoxes, "Boxes"))]
 pub static LuBoxes: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBoxes",
+    label: "Boxes",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3686,8 +4897,14 @@ pub static LuBoxes: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 13.5V8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBraces, "Braces"))]
 pub static LuBraces: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBraces",
+    label: "Braces",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3703,8 +4920,14 @@ pub static LuBraces: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 21h1a2 2 0 0 0 2-2v-5c0-1.1.9-2 2-2a2 2 0 0 1-2-2V5a2 2 0 0 0-2-2h-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBrackets, "Brackets"))]
 pub static LuBrackets: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBrackets",
+    label: "Brackets",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3720,8 +4943,14 @@ pub static LuBrackets: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 21H5V3h3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBrain, "Brain"))]
 pub static LuBrain: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBrain",
+    label: "Brain",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3737,8 +4966,14 @@ pub static LuBrain: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.5 2A2.5 2.5 0 0 0 12 4.5v15a2.5 2.5 0 0 0 4.96.44 2.5 2.5 0 0 0 2.96-3.08 3 3 0 0 0 .34-5.58 2.5 2.5 0 0 0-1.32-4.24 2.5 2.5 0 0 0-1.98-3A2.5 2.5 0 0 0 14.5 2Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBrainCircuit, "Brain Circuit"))]
 pub static LuBrainCircuit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBrainCircuit",
+    label: "Brain Circuit",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3761,8 +4996,14 @@ pub static LuBrainCircuit: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18.5 3a.5.5 0 1 1-1 0 .5.5 0 0 1 1 0Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBrainCog, "Brain Cog"))]
 pub static LuBrainCog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBrainCog",
+    label: "Brain Cog",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3784,8 +5025,14 @@ pub static LuBrainCog: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m10.27 11-.87-.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBriefcase, "Briefcase"))]
 pub static LuBriefcase: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBriefcase",
+    label: "Briefcase",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3801,8 +5048,14 @@ pub static LuBriefcase: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 21V5a2 2 0 0 0-2-2h-4a2 2 0 0 0-2 2v16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBringToFront, "Bring To Front"))]
 pub static LuBringToFront: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBringToFront",
+    label: "Bring To Front",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3819,8 +5072,14 @@ pub static LuBringToFront: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 20a2 2 0 0 0 2 2h4a2 2 0 0 0 2-2v-4a2 2 0 0 0-2-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBrush, "Brush"))]
 pub static LuBrush: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBrush",
+    label: "Brush",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3836,8 +5095,14 @@ pub static LuBrush: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7.07 14.94c-1.66 0-3 1.35-3 3.02 0 1.33-2.5 1.52-2 2.02 1.08 1.1 2.49 2.02 4 2.02 2.2 0 4-1.8 4-4.04a3.01 3.01 0 0 0-3-3.02z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBug, "Bug"))]
 pub static LuBug: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBug",
+    label: "Bug",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3860,8 +5125,14 @@ pub static LuBug: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14 4-1 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBuilding, "Building"))]
 pub static LuBuilding: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBuilding",
+    label: "Building",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3886,8 +5157,14 @@ pub static LuBuilding: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 14h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBuilding2, "Building 2"))]
 pub static LuBuilding2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBuilding2",
+    label: "Building 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3908,8 +5185,14 @@ pub static LuBuilding2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 18h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBus, "Bus"))]
 pub static LuBus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBus",
+    label: "Bus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3930,8 +5213,14 @@ pub static LuBus: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="16" cy="18" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuBusFront, "Bus Front"))]
 pub static LuBusFront: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuBusFront",
+    label: "Bus Front",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3954,8 +5243,14 @@ pub static LuBusFront: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 21v-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCable, "Cable"))]
 pub static LuCable: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCable",
+    label: "Cable",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3976,8 +5271,14 @@ pub static LuCable: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 19h-6v-2a2 2 0 0 1 2-2h2a2 2 0 0 1 2 2Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCableCar, "Cable Car"))]
 pub static LuCableCar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCableCar",
+    label: "Cable Car",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -3999,8 +5300,14 @@ pub static LuCableCar: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 17h16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCake, "Cake"))]
 pub static LuCake: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCake",
+    label: "Cake",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4023,8 +5330,14 @@ pub static LuCake: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17 4h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCakeSlice, "Cake Slice"))]
 pub static LuCakeSlice: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCakeSlice",
+    label: "Cake Slice",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4042,8 +5355,14 @@ pub static LuCakeSlice: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 17H3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCalculator, "Calculator"))]
 pub static LuCalculator: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCalculator",
+    label: "Calculator",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4067,8 +5386,14 @@ pub static LuCalculator: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 18h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCalendar, "Calendar"))]
 pub static LuCalendar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCalendar",
+    label: "Calendar",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4086,8 +5411,14 @@ pub static LuCalendar: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" x2="21" y1="10" y2="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCalendarCheck, "Calendar Check"))]
 pub static LuCalendarCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCalendarCheck",
+    label: "Calendar Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4106,8 +5437,14 @@ pub static LuCalendarCheck: &icondata_core::IconData = &icondata_core::IconData
 <path d="m9 16 2 2 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCalendarCheck2, "Calendar Check 2"))]
 pub static LuCalendarCheck2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCalendarCheck2",
+    label: "Calendar Check 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4126,8 +5463,14 @@ pub static LuCalendarCheck2: &icondata_core::IconData = &icondata_core::IconData
 <path d="m16 20 2 2 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCalendarClock, "Calendar Clock"))]
 pub static LuCalendarClock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCalendarClock",
+    label: "Calendar Clock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4147,8 +5490,14 @@ pub static LuCalendarClock: &icondata_core::IconData = &icondata_core::IconData
 <path d="M22 16a6 6 0 1 1-12 0 6 6 0 0 1 12 0Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCalendarDays, "Calendar Days"))]
 pub static LuCalendarDays: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCalendarDays",
+    label: "Calendar Days",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4172,8 +5521,14 @@ pub static LuCalendarDays: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 18h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCalendarHeart, "Calendar Heart"))]
 pub static LuCalendarHeart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCalendarHeart",
+    label: "Calendar Heart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4192,8 +5547,14 @@ pub static LuCalendarHeart: &icondata_core::IconData = &icondata_core::IconData
 <path d="M21.29 14.7a2.43 2.43 0 0 0-2.65-.52c-.3.12-.57.3-.8.53l-.34.34-.35-.34a2.43 2.43 0 0 0-2.65-.53c-.3.12-.56.3-.79.53-.95.94-1 2.53.2 3.74L17.5 22l3.6-3.55c1.2-1.21 1.14-2.8.19-3.74Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCalendarMinus, "Calendar Minus"))]
 pub static LuCalendarMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCalendarMinus",
+    label: "Calendar Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4212,8 +5573,14 @@ pub static LuCalendarMinus: &icondata_core::IconData = &icondata_core::IconData
 <line x1="16" x2="22" y1="19" y2="19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCalendarOff, "Calendar Off"))]
 pub static LuCalendarOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCalendarOff",
+    label: "Calendar Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4233,8 +5600,14 @@ pub static LuCalendarOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCalendarPlus, "Calendar Plus"))]
 pub static LuCalendarPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCalendarPlus",
+    label: "Calendar Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4254,8 +5627,14 @@ pub static LuCalendarPlus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="16" x2="22" y1="19" y2="19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCalendarRange, "Calendar Range"))]
 pub static LuCalendarRange: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCalendarRange",
+    label: "Calendar Range",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4277,8 +5656,14 @@ pub static LuCalendarRange: &icondata_core::IconData = &icondata_core::IconData
 <path d="M17 18h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCalendarSearch, "Calendar Search"))]
 pub static LuCalendarSearch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCalendarSearch",
+    label: "Calendar Search",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4298,8 +5683,14 @@ pub static LuCalendarSearch: &icondata_core::IconData = &icondata_core::IconData
 <path d="m22 22-1.5-1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCalendarX, "Calendar X"))]
 pub static LuCalendarX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCalendarX",
+    label: "Calendar X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4319,8 +5710,14 @@ pub static LuCalendarX: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="14" x2="10" y1="14" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCalendarX2, "Calendar X 2"))]
 pub static LuCalendarX2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCalendarX2",
+    label: "Calendar X 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4340,8 +5737,14 @@ pub static LuCalendarX2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="17" x2="22" y1="22" y2="17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCamera, "Camera"))]
 pub static LuCamera: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCamera",
+    label: "Camera",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4357,8 +5760,14 @@ pub static LuCamera: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="13" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCameraOff, "Camera Off"))]
 pub static LuCameraOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCameraOff",
+    label: "Camera Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4376,8 +5785,14 @@ pub static LuCameraOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.121 15.121A3 3 0 1 1 9.88 10.88" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCandlestickChart, "Candlestick Chart"))]
 pub static LuCandlestickChart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCandlestickChart",
+    label: "Candlestick Chart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4398,8 +5813,14 @@ pub static LuCandlestickChart: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M3 3v18h18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCandy, "Candy"))]
 pub static LuCandy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCandy",
+    label: "Candy",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4418,8 +5839,14 @@ pub static LuCandy: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 17-1 5-1.37-.68A3 3 0 0 0 4.3 21H3v-1.3a3 3 0 0 0-.32-1.33L2 17l5-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCandyCane, "Candy Cane"))]
 pub static LuCandyCane: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCandyCane",
+    label: "Candy Cane",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4438,8 +5865,14 @@ pub static LuCandyCane: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4.9 14.7 7 18.9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCandyOff, "Candy Off"))]
 pub static LuCandyOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCandyOff",
+    label: "Candy Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4461,8 +5894,14 @@ pub static LuCandyOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCar, "Car"))]
 pub static LuCar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCar",
+    label: "Car",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4480,8 +5919,14 @@ pub static LuCar: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="17" cy="17" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCarFront, "Car Front"))]
 pub static LuCarFront: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCarFront",
+    label: "Car Front",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4501,8 +5946,14 @@ pub static LuCarFront: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 18v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCarTaxiFront, "Car Taxi Front"))]
 pub static LuCarTaxiFront: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCarTaxiFront",
+    label: "Car Taxi Front",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4523,8 +5974,14 @@ pub static LuCarTaxiFront: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 18v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCarrot, "Carrot"))]
 pub static LuCarrot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCarrot",
+    label: "Carrot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4541,8 +5998,14 @@ pub static LuCarrot: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 2s-2 1.33-2 3.5S15 9 15 9s2-1.84 2-3.5C17 3.33 15 2 15 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCaseLower, "Case Lower"))]
 pub static LuCaseLower: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCaseLower",
+    label: "Case Lower",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4560,8 +6023,14 @@ pub static LuCaseLower: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 7v8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCaseSensitive, "Case Sensitive"))]
 pub static LuCaseSensitive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCaseSensitive",
+    label: "Case Sensitive",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4579,8 +6048,14 @@ pub static LuCaseSensitive: &icondata_core::IconData = &icondata_core::IconData
 <path d="M21 9v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCaseUpper, "Case Upper"))]
 pub static LuCaseUpper: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCaseUpper",
+    label: "Case Upper",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4597,8 +6072,14 @@ pub static LuCaseUpper: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 11h4.5a2 2 0 0 1 0 4H15V7h4a2 2 0 0 1 0 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCassetteTape, "Cassette Tape"))]
 pub static LuCassetteTape: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCassetteTape",
+    label: "Cassette Tape",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4617,8 +6098,14 @@ pub static LuCassetteTape: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m6 20 .7-2.9A1.4 1.4 0 0 1 8.1 16h7.8a1.4 1.4 0 0 1 1.4 1l.7 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCast, "Cast"))]
 pub static LuCast: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCast",
+    label: "Cast",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4636,8 +6123,14 @@ pub static LuCast: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="2.01" y1="20" y2="20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCastle, "Castle"))]
 pub static LuCastle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCastle",
+    label: "Castle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4660,8 +6153,14 @@ pub static LuCastle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 4V2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCat, "Cat"))]
 pub static LuCat: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCat",
+    label: "Cat",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4679,8 +6178,14 @@ pub static LuCat: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11.25 16.25h1.5L12 17l-.75-.75Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCheck, "Check"))]
 pub static LuCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCheck",
+    label: "Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4695,8 +6200,14 @@ pub static LuCheck: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polyline points="20 6 9 17 4 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCheckCheck, "Check Check"))]
 pub static LuCheckCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCheckCheck",
+    label: "Check Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4712,8 +6223,14 @@ pub static LuCheckCheck: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m22 10-7.5 7.5L13 16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCheckCircle, "Check Circle"))]
 pub static LuCheckCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCheckCircle",
+    label: "Check Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4729,8 +6246,14 @@ pub static LuCheckCircle: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="22 4 12 14.01 9 11.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCheckCircle2, "Check Circle 2"))]
 pub static LuCheckCircle2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCheckCircle2",
+    label: "Check Circle 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4746,8 +6269,14 @@ pub static LuCheckCircle2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 12 2 2 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCheckSquare, "Check Square"))]
 pub static LuCheckSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCheckSquare",
+    label: "Check Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4763,8 +6292,14 @@ pub static LuCheckSquare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 12v7a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2V5a2 2 0 0 1 2-2h11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChefHat, "Chef Hat"))]
 pub static LuChefHat: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChefHat",
+    label: "Chef Hat",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4780,8 +6315,14 @@ pub static LuChefHat: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="6" x2="18" y1="17" y2="17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCherry, "Cherry"))]
 pub static LuCherry: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCherry",
+    label: "Cherry",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4799,8 +6340,14 @@ pub static LuCherry: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 9c-4.29 0-7.14-2.33-10-7 5.71 0 10 4.67 10 7Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronDown, "Chevron Down"))]
 pub static LuChevronDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronDown",
+    label: "Chevron Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4815,8 +6362,14 @@ pub static LuChevronDown: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m6 9 6 6 6-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronDownCircle, "Chevron Down Circle"))]
 pub static LuChevronDownCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronDownCircle",
+    label: "Chevron Down Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4832,8 +6385,14 @@ pub static LuChevronDownCircle: &icondata_core::IconData = &icondata_core::IconD
 <path d="m16 10-4 4-4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronDownSquare, "Chevron Down Square"))]
 pub static LuChevronDownSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronDownSquare",
+    label: "Chevron Down Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4849,8 +6408,14 @@ pub static LuChevronDownSquare: &icondata_core::IconData = &icondata_core::IconD
 <path d="m16 10-4 4-4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronFirst, "Chevron First"))]
 pub static LuChevronFirst: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronFirst",
+    label: "Chevron First",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4866,8 +6431,14 @@ pub static LuChevronFirst: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 6v12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronLast, "Chevron Last"))]
 pub static LuChevronLast: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronLast",
+    label: "Chevron Last",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4883,8 +6454,14 @@ pub static LuChevronLast: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17 6v12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronLeft, "Chevron Left"))]
 pub static LuChevronLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronLeft",
+    label: "Chevron Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4899,8 +6476,14 @@ pub static LuChevronLeft: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m15 18-6-6 6-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronLeftCircle, "Chevron Left Circle"))]
 pub static LuChevronLeftCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronLeftCircle",
+    label: "Chevron Left Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4916,8 +6499,14 @@ pub static LuChevronLeftCircle: &icondata_core::IconData = &icondata_core::IconD
 <path d="m14 16-4-4 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronLeftSquare, "Chevron Left Square"))]
 pub static LuChevronLeftSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronLeftSquare",
+    label: "Chevron Left Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4933,8 +6522,14 @@ pub static LuChevronLeftSquare: &icondata_core::IconData = &icondata_core::IconD
 <path d="m14 16-4-4 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronRight, "Chevron Right"))]
 pub static LuChevronRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronRight",
+    label: "Chevron Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4949,8 +6544,14 @@ pub static LuChevronRight: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m9 18 6-6-6-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronRightCircle, "Chevron Right Circle"))]
 pub static LuChevronRightCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronRightCircle",
+    label: "Chevron Right Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4966,8 +6567,14 @@ pub static LuChevronRightCircle: &icondata_core::IconData = &icondata_core::Icon
 <path d="m10 8 4 4-4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronRightSquare, "Chevron Right Square"))]
 pub static LuChevronRightSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronRightSquare",
+    label: "Chevron Right Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4983,8 +6590,14 @@ pub static LuChevronRightSquare: &icondata_core::IconData = &icondata_core::Icon
 <path d="m10 8 4 4-4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronUp, "Chevron Up"))]
 pub static LuChevronUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronUp",
+    label: "Chevron Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -4999,8 +6612,14 @@ pub static LuChevronUp: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m18 15-6-6-6 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronUpCircle, "Chevron Up Circle"))]
 pub static LuChevronUpCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronUpCircle",
+    label: "Chevron Up Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5016,8 +6635,14 @@ pub static LuChevronUpCircle: &icondata_core::IconData = &icondata_core::IconDat
 <path d="m8 14 4-4 4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronUpSquare, "Chevron Up Square"))]
 pub static LuChevronUpSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronUpSquare",
+    label: "Chevron Up Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5033,8 +6658,14 @@ pub static LuChevronUpSquare: &icondata_core::IconData = &icondata_core::IconDat
 <path d="m8 14 4-4 4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronsDown, "Chevrons Down"))]
 pub static LuChevronsDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronsDown",
+    label: "Chevrons Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5050,8 +6681,14 @@ pub static LuChevronsDown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m7 13 5 5 5-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronsDownUp, "Chevrons Down Up"))]
 pub static LuChevronsDownUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronsDownUp",
+    label: "Chevrons Down Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5067,8 +6704,14 @@ pub static LuChevronsDownUp: &icondata_core::IconData = &icondata_core::IconData
 <path d="m7 4 5 5 5-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronsLeft, "Chevrons Left"))]
 pub static LuChevronsLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronsLeft",
+    label: "Chevrons Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5084,8 +6727,14 @@ pub static LuChevronsLeft: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m18 17-5-5 5-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronsLeftRight, "Chevrons Left Right"))]
 pub static LuChevronsLeftRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronsLeftRight",
+    label: "Chevrons Left Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5101,8 +6750,14 @@ pub static LuChevronsLeftRight: &icondata_core::IconData = &icondata_core::IconD
 <path d="m15 7 5 5-5 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronsRight, "Chevrons Right"))]
 pub static LuChevronsRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronsRight",
+    label: "Chevrons Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5118,8 +6773,14 @@ pub static LuChevronsRight: &icondata_core::IconData = &icondata_core::IconData
 <path d="m13 17 5-5-5-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronsRightLeft, "Chevrons Right Left"))]
 pub static LuChevronsRightLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronsRightLeft",
+    label: "Chevrons Right Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5135,8 +6796,14 @@ pub static LuChevronsRightLeft: &icondata_core::IconData = &icondata_core::IconD
 <path d="m4 17 5-5-5-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronsUp, "Chevrons Up"))]
 pub static LuChevronsUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronsUp",
+    label: "Chevrons Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5152,8 +6819,14 @@ pub static LuChevronsUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m17 18-5-5-5 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChevronsUpDown, "Chevrons Up Down"))]
 pub static LuChevronsUpDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChevronsUpDown",
+    label: "Chevrons Up Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5169,8 +6842,14 @@ pub static LuChevronsUpDown: &icondata_core::IconData = &icondata_core::IconData
 <path d="m7 9 5-5 5 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChrome, "Chrome"))]
 pub static LuChrome: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChrome",
+    label: "Chrome",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5189,8 +6868,14 @@ pub static LuChrome: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="10.88" x2="15.46" y1="21.94" y2="14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuChurch, "Church"))]
 pub static LuChurch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuChurch",
+    label: "Church",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5209,8 +6894,14 @@ pub static LuChurch: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 9h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCigarette, "Cigarette"))]
 pub static LuCigarette: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCigarette",
+    label: "Cigarette",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5229,8 +6920,14 @@ pub static LuCigarette: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 8c0-2.5-2-2.5-2-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCigaretteOff, "Cigarette Off"))]
 pub static LuCigaretteOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCigaretteOff",
+    label: "Cigarette Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5251,8 +6948,14 @@ pub static LuCigaretteOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 8c0-2.5-2-2.5-2-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCircle, "Circle"))]
 pub static LuCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCircle",
+    label: "Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5267,8 +6970,14 @@ pub static LuCircle: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<circle cx="12" cy="12" r="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCircleDashed, "Circle Dashed"))]
 pub static LuCircleDashed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCircleDashed",
+    label: "Circle Dashed",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5290,8 +6999,14 @@ pub static LuCircleDashed: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3.71 6.4a9.95 9.95 0 0 1 2.7-2.69" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCircleDollarSign, "Circle Dollar Sign"))]
 pub static LuCircleDollarSign: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCircleDollarSign",
+    label: "Circle Dollar Sign",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5308,8 +7023,14 @@ pub static LuCircleDollarSign: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M12 18V6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCircleDot, "Circle Dot"))]
 pub static LuCircleDot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCircleDot",
+    label: "Circle Dot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5325,8 +7046,14 @@ pub static LuCircleDot: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCircleDotDashed, "Circle Dot Dashed"))]
 pub static LuCircleDotDashed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCircleDotDashed",
+    label: "Circle Dot Dashed",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5349,8 +7076,14 @@ pub static LuCircleDotDashed: &icondata_core::IconData = &icondata_core::IconDat
 <circle cx="12" cy="12" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCircleEllipsis, "Circle Ellipsis"))]
 pub static LuCircleEllipsis: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCircleEllipsis",
+    label: "Circle Ellipsis",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5368,8 +7101,14 @@ pub static LuCircleEllipsis: &icondata_core::IconData = &icondata_core::IconData
 <path d="M7 12h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCircleEqual, "Circle Equal"))]
 pub static LuCircleEqual: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCircleEqual",
+    label: "Circle Equal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5386,8 +7125,14 @@ pub static LuCircleEqual: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCircleOff, "Circle Off"))]
 pub static LuCircleOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCircleOff",
+    label: "Circle Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5404,8 +7149,14 @@ pub static LuCircleOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19.08 19.08A10 10 0 1 1 4.92 4.92" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCircleSlash, "Circle Slash"))]
 pub static LuCircleSlash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCircleSlash",
+    label: "Circle Slash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5421,8 +7172,14 @@ pub static LuCircleSlash: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCircleSlash2, "Circle Slash 2"))]
 pub static LuCircleSlash2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCircleSlash2",
+    label: "Circle Slash 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5438,8 +7195,14 @@ pub static LuCircleSlash2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 2 2 22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCircuitBoard, "Circuit Board"))]
 pub static LuCircuitBoard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCircuitBoard",
+    label: "Circuit Board",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5458,8 +7221,14 @@ pub static LuCircuitBoard: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="15" cy="15" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCitrus, "Citrus"))]
 pub static LuCitrus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCitrus",
+    label: "Citrus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5477,8 +7246,14 @@ pub static LuCitrus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 17.85V10H6.15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClapperboard, "Clapperboard"))]
 pub static LuClapperboard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClapperboard",
+    label: "Clapperboard",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5496,8 +7271,14 @@ pub static LuClapperboard: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m11.86 3.38 3.38 4.2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClipboard, "Clipboard"))]
 pub static LuClipboard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClipboard",
+    label: "Clipboard",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5513,8 +7294,14 @@ pub static LuClipboard: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 4h2a2 2 0 0 1 2 2v14a2 2 0 0 1-2 2H6a2 2 0 0 1-2-2V6a2 2 0 0 1 2-2h2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClipboardCheck, "Clipboard Check"))]
 pub static LuClipboardCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClipboardCheck",
+    label: "Clipboard Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5531,8 +7318,14 @@ pub static LuClipboardCheck: &icondata_core::IconData = &icondata_core::IconData
 <path d="m9 14 2 2 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClipboardCopy, "Clipboard Copy"))]
 pub static LuClipboardCopy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClipboardCopy",
+    label: "Clipboard Copy",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5551,8 +7344,14 @@ pub static LuClipboardCopy: &icondata_core::IconData = &icondata_core::IconData
 <path d="m15 10-4 4 4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClipboardEdit, "Clipboard Edit"))]
 pub static LuClipboardEdit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClipboardEdit",
+    label: "Clipboard Edit",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5570,8 +7369,14 @@ pub static LuClipboardEdit: &icondata_core::IconData = &icondata_core::IconData
 <path d="M4 13.5V6a2 2 0 0 1 2-2h2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClipboardList, "Clipboard List"))]
 pub static LuClipboardList: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClipboardList",
+    label: "Clipboard List",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5591,8 +7396,14 @@ pub static LuClipboardList: &icondata_core::IconData = &icondata_core::IconData
 <path d="M8 16h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClipboardPaste, "Clipboard Paste"))]
 pub static LuClipboardPaste: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClipboardPaste",
+    label: "Clipboard Paste",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5609,8 +7420,14 @@ pub static LuClipboardPaste: &icondata_core::IconData = &icondata_core::IconData
 <path d="m17 10 4 4-4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClipboardSignature, "Clipboard Signature"))]
 pub static LuClipboardSignature: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClipboardSignature",
+    label: "Clipboard Signature",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5629,8 +7446,14 @@ pub static LuClipboardSignature: &icondata_core::IconData = &icondata_core::Icon
 <path d="M8 18h1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClipboardType, "Clipboard Type"))]
 pub static LuClipboardType: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClipboardType",
+    label: "Clipboard Type",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5649,8 +7472,14 @@ pub static LuClipboardType: &icondata_core::IconData = &icondata_core::IconData
 <path d="M12 11v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClipboardX, "Clipboard X"))]
 pub static LuClipboardX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClipboardX",
+    label: "Clipboard X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5668,8 +7497,14 @@ pub static LuClipboardX: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 11 6 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClock, "Clock"))]
 pub static LuClock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClock",
+    label: "Clock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5685,8 +7520,14 @@ pub static LuClock: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 6 12 12 16 14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClock1, "Clock 1"))]
 pub static LuClock1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClock1",
+    label: "Clock 1",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5702,8 +7543,14 @@ pub static LuClock1: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 6 12 12 14.5 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClock10, "Clock 10"))]
 pub static LuClock10: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClock10",
+    label: "Clock 10",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5719,8 +7566,14 @@ pub static LuClock10: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 6 12 12 8 10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClock11, "Clock 11"))]
 pub static LuClock11: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClock11",
+    label: "Clock 11",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5736,8 +7589,14 @@ pub static LuClock11: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 6 12 12 9.5 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClock12, "Clock 12"))]
 pub static LuClock12: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClock12",
+    label: "Clock 12",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5753,8 +7612,14 @@ pub static LuClock12: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 6 12 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClock2, "Clock 2"))]
 pub static LuClock2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClock2",
+    label: "Clock 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5770,8 +7635,14 @@ pub static LuClock2: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 6 12 12 16 10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClock3, "Clock 3"))]
 pub static LuClock3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClock3",
+    label: "Clock 3",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5787,8 +7658,14 @@ pub static LuClock3: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 6 12 12 16.5 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClock4, "Clock 4"))]
 pub static LuClock4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClock4",
+    label: "Clock 4",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5804,8 +7681,14 @@ pub static LuClock4: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 6 12 12 16 14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClock5, "Clock 5"))]
 pub static LuClock5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClock5",
+    label: "Clock 5",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5821,8 +7704,14 @@ pub static LuClock5: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 6 12 12 14.5 16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClock6, "Clock 6"))]
 pub static LuClock6: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClock6",
+    label: "Clock 6",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5838,8 +7727,14 @@ pub static LuClock6: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 6 12 12 12 16.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClock7, "Clock 7"))]
 pub static LuClock7: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClock7",
+    label: "Clock 7",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5855,8 +7750,14 @@ pub static LuClock7: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 6 12 12 9.5 16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClock8, "Clock 8"))]
 pub static LuClock8: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClock8",
+    label: "Clock 8",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5872,8 +7773,14 @@ pub static LuClock8: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 6 12 12 8 14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClock9, "Clock 9"))]
 pub static LuClock9: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClock9",
+    label: "Clock 9",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5889,8 +7796,14 @@ pub static LuClock9: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="12 6 12 12 7.5 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloud, "Cloud"))]
 pub static LuCloud: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloud",
+    label: "Cloud",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5905,8 +7818,14 @@ pub static LuCloud: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M17.5 19H9a7 7 0 1 1 6.71-9h1.79a4.5 4.5 0 1 1 0 9Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloudCog, "Cloud Cog"))]
 pub static LuCloudCog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloudCog",
+    label: "Cloud Cog",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5930,8 +7849,14 @@ pub static LuCloudCog: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9.88 14.88 9 14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloudDrizzle, "Cloud Drizzle"))]
 pub static LuCloudDrizzle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloudDrizzle",
+    label: "Cloud Drizzle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5952,8 +7877,14 @@ pub static LuCloudDrizzle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 16v1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloudFog, "Cloud Fog"))]
 pub static LuCloudFog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloudFog",
+    label: "Cloud Fog",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5970,8 +7901,14 @@ pub static LuCloudFog: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17 21H9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloudHail, "Cloud Hail"))]
 pub static LuCloudHail: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloudHail",
+    label: "Cloud Hail",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -5992,8 +7929,14 @@ pub static LuCloudHail: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 22h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloudLightning, "Cloud Lightning"))]
 pub static LuCloudLightning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloudLightning",
+    label: "Cloud Lightning",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6009,8 +7952,14 @@ pub static LuCloudLightning: &icondata_core::IconData = &icondata_core::IconData
 <path d="m13 12-3 5h4l-3 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloudMoon, "Cloud Moon"))]
 pub static LuCloudMoon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloudMoon",
+    label: "Cloud Moon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6026,8 +7975,14 @@ pub static LuCloudMoon: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10.1 9A6 6 0 0 1 16 4a4.24 4.24 0 0 0 6 6 6 6 0 0 1-3 5.197" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloudMoonRain, "Cloud Moon Rain"))]
 pub static LuCloudMoonRain: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloudMoonRain",
+    label: "Cloud Moon Rain",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6045,8 +8000,14 @@ pub static LuCloudMoonRain: &icondata_core::IconData = &icondata_core::IconData
 <path d="M7 19v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloudOff, "Cloud Off"))]
 pub static LuCloudOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloudOff",
+    label: "Cloud Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6063,8 +8024,14 @@ pub static LuCloudOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21.532 16.5A4.5 4.5 0 0 0 17.5 10h-1.79A7.008 7.008 0 0 0 10 5.07" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloudRain, "Cloud Rain"))]
 pub static LuCloudRain: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloudRain",
+    label: "Cloud Rain",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6082,8 +8049,14 @@ pub static LuCloudRain: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 16v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloudRainWind, "Cloud Rain Wind"))]
 pub static LuCloudRainWind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloudRainWind",
+    label: "Cloud Rain Wind",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6101,8 +8074,14 @@ pub static LuCloudRainWind: &icondata_core::IconData = &icondata_core::IconData
 <path d="m17 13-3 7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloudSnow, "Cloud Snow"))]
 pub static LuCloudSnow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloudSnow",
+    label: "Cloud Snow",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6123,8 +8102,14 @@ pub static LuCloudSnow: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 19h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloudSun, "Cloud Sun"))]
 pub static LuCloudSun: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloudSun",
+    label: "Cloud Sun",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6144,8 +8129,14 @@ pub static LuCloudSun: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13 22H7a5 5 0 1 1 4.9-6H13a3 3 0 0 1 0 6Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloudSunRain, "Cloud Sun Rain"))]
 pub static LuCloudSunRain: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloudSunRain",
+    label: "Cloud Sun Rain",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6167,8 +8158,14 @@ pub static LuCloudSunRain: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 19v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCloudy, "Cloudy"))]
 pub static LuCloudy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCloudy",
+    label: "Cloudy",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6184,8 +8181,14 @@ pub static LuCloudy: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 10a3 3 0 0 0-3-3h-2.207a5.502 5.502 0 0 0-10.702.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClover, "Clover"))]
 pub static LuClover: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClover",
+    label: "Clover",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6204,8 +8207,14 @@ pub static LuClover: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m7 17-5 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuClub, "Club"))]
 pub static LuClub: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuClub",
+    label: "Club",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6221,8 +8230,14 @@ pub static LuClub: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 17.66L12 22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCode, "Code"))]
 pub static LuCode: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCode",
+    label: "Code",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6238,8 +8253,14 @@ pub static LuCode: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="8 6 2 12 8 18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCode2, "Code 2"))]
 pub static LuCode2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCode2",
+    label: "Code 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6256,8 +8277,14 @@ pub static LuCode2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14.5 4-5 16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCodepen, "Codepen"))]
 pub static LuCodepen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCodepen",
+    label: "Codepen",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6276,8 +8303,14 @@ pub static LuCodepen: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="2" y2="8.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCodesandbox, "Codesandbox"))]
 pub static LuCodesandbox: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCodesandbox",
+    label: "Codesandbox",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6297,8 +8330,14 @@ pub static LuCodesandbox: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="22.08" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCoffee, "Coffee"))]
 pub static LuCoffee: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCoffee",
+    label: "Coffee",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6317,8 +8356,14 @@ pub static LuCoffee: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="14" x2="14" y1="2" y2="4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCog, "Cog"))]
 pub static LuCog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCog",
+    label: "Cog",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6346,8 +8391,14 @@ pub static LuCog: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m11 13.73-4 6.93" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCoins, "Coins"))]
 pub static LuCoins: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCoins",
+    label: "Coins",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6365,8 +8416,14 @@ pub static LuCoins: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m16.71 13.88.7.71-2.82 2.82" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuColumns, "Columns"))]
 pub static LuColumns: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuColumns",
+    label: "Columns",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6382,8 +8439,14 @@ pub static LuColumns: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="3" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCombine, "Combine"))]
 pub static LuCombine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCombine",
+    label: "Combine",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6403,8 +8466,14 @@ pub static LuCombine: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="8" height="8" x="14" y="14" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCommand, "Command"))]
 pub static LuCommand: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCommand",
+    label: "Command",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6419,8 +8488,14 @@ pub static LuCommand: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15 6v12a3 3 0 1 0 3-3H6a3 3 0 1 0 3 3V6a3 3 0 1 0-3 3h12a3 3 0 1 0-3-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCompass, "Compass"))]
 pub static LuCompass: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCompass",
+    label: "Compass",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6436,8 +8511,14 @@ pub static LuCompass: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="16.24 7.76 14.12 14.12 7.76 16.24 9.88 9.88 16.24 7.76" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuComponent, "Component"))]
 pub static LuComponent: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuComponent",
+    label: "Component",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6455,8 +8536,14 @@ pub static LuComponent: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m12 15 3.5 3.5L12 22l-3.5-3.5L12 15Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuComputer, "Computer"))]
 pub static LuComputer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuComputer",
+    label: "Computer",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6474,8 +8561,14 @@ pub static LuComputer: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 18h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuConciergeBell, "Concierge Bell"))]
 pub static LuConciergeBell: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuConciergeBell",
+    label: "Concierge Bell",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6493,8 +8586,14 @@ pub static LuConciergeBell: &icondata_core::IconData = &icondata_core::IconData
 <path d="M10 4h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuConstruction, "Construction"))]
 pub static LuConstruction: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuConstruction",
+    label: "Construction",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6516,8 +8615,14 @@ pub static LuConstruction: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 6 8 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuContact, "Contact"))]
 pub static LuContact: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuContact",
+    label: "Contact",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6536,8 +8641,14 @@ pub static LuContact: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="16" x2="16" y1="2" y2="4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuContact2, "Contact 2"))]
 pub static LuContact2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuContact2",
+    label: "Contact 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6556,8 +8667,14 @@ pub static LuContact2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="16" x2="16" y1="2" y2="4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuContainer, "Container"))]
 pub static LuContainer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuContainer",
+    label: "Container",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6576,8 +8693,14 @@ pub static LuContainer: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 17.5V9.4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuContrast, "Contrast"))]
 pub static LuContrast: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuContrast",
+    label: "Contrast",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6593,8 +8716,14 @@ pub static LuContrast: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 18a6 6 0 0 0 0-12v12z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCookie, "Cookie"))]
 pub static LuCookie: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCookie",
+    label: "Cookie",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6614,8 +8743,14 @@ pub static LuCookie: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 14v.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCopy, "Copy"))]
 pub static LuCopy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCopy",
+    label: "Copy",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6631,8 +8766,14 @@ pub static LuCopy: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 16c-1.1 0-2-.9-2-2V4c0-1.1.9-2 2-2h10c1.1 0 2 .9 2 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCopyCheck, "Copy Check"))]
 pub static LuCopyCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCopyCheck",
+    label: "Copy Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6649,8 +8790,14 @@ pub static LuCopyCheck: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 16c-1.1 0-2-.9-2-2V4c0-1.1.9-2 2-2h10c1.1 0 2 .9 2 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCopyMinus, "Copy Minus"))]
 pub static LuCopyMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCopyMinus",
+    label: "Copy Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6667,8 +8814,14 @@ pub static LuCopyMinus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 16c-1.1 0-2-.9-2-2V4c0-1.1.9-2 2-2h10c1.1 0 2 .9 2 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCopyPlus, "Copy Plus"))]
 pub static LuCopyPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCopyPlus",
+    label: "Copy Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6686,8 +8839,14 @@ pub static LuCopyPlus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 16c-1.1 0-2-.9-2-2V4c0-1.1.9-2 2-2h10c1.1 0 2 .9 2 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCopySlash, "Copy Slash"))]
 pub static LuCopySlash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCopySlash",
+    label: "Copy Slash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6704,8 +8863,14 @@ pub static LuCopySlash: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 16c-1.1 0-2-.9-2-2V4c0-1.1.9-2 2-2h10c1.1 0 2 .9 2 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCopyX, "Copy X"))]
 pub static LuCopyX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCopyX",
+    label: "Copy X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6723,8 +8888,14 @@ pub static LuCopyX: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 16c-1.1 0-2-.9-2-2V4c0-1.1.9-2 2-2h10c1.1 0 2 .9 2 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCopyleft, "Copyleft"))]
 pub static LuCopyleft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCopyleft",
+    label: "Copyleft",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6740,8 +8911,14 @@ pub static LuCopyleft: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 9.35a4 4 0 1 1 0 5.3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCopyright, "Copyright"))]
 pub static LuCopyright: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCopyright",
+    label: "Copyright",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6757,8 +8934,14 @@ pub static LuCopyright: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 9.354a4 4 0 1 0 0 5.292" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCornerDownLeft, "Corner Down Left"))]
 pub static LuCornerDownLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCornerDownLeft",
+    label: "Corner Down Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6774,8 +8957,14 @@ pub static LuCornerDownLeft: &icondata_core::IconData = &icondata_core::IconData
 <path d="M20 4v7a4 4 0 0 1-4 4H4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCornerDownRight, "Corner Down Right"))]
 pub static LuCornerDownRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCornerDownRight",
+    label: "Corner Down Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6791,8 +8980,14 @@ pub static LuCornerDownRight: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M4 4v7a4 4 0 0 0 4 4h12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCornerLeftDown, "Corner Left Down"))]
 pub static LuCornerLeftDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCornerLeftDown",
+    label: "Corner Left Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6808,8 +9003,14 @@ pub static LuCornerLeftDown: &icondata_core::IconData = &icondata_core::IconData
 <path d="M20 4h-7a4 4 0 0 0-4 4v12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCornerLeftUp, "Corner Left Up"))]
 pub static LuCornerLeftUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCornerLeftUp",
+    label: "Corner Left Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6825,8 +9026,14 @@ pub static LuCornerLeftUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 20h-7a4 4 0 0 1-4-4V4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCornerRightDown, "Corner Right Down"))]
 pub static LuCornerRightDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCornerRightDown",
+    label: "Corner Right Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6842,8 +9049,14 @@ pub static LuCornerRightDown: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M4 4h7a4 4 0 0 1 4 4v12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCornerRightUp, "Corner Right Up"))]
 pub static LuCornerRightUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCornerRightUp",
+    label: "Corner Right Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6859,8 +9072,14 @@ pub static LuCornerRightUp: &icondata_core::IconData = &icondata_core::IconData
 <path d="M4 20h7a4 4 0 0 0 4-4V4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCornerUpLeft, "Corner Up Left"))]
 pub static LuCornerUpLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCornerUpLeft",
+    label: "Corner Up Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6876,8 +9095,14 @@ pub static LuCornerUpLeft: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 20v-7a4 4 0 0 0-4-4H4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCornerUpRight, "Corner Up Right"))]
 pub static LuCornerUpRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCornerUpRight",
+    label: "Corner Up Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6893,8 +9118,14 @@ pub static LuCornerUpRight: &icondata_core::IconData = &icondata_core::IconData
 <path d="M4 20v-7a4 4 0 0 1 4-4h12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCpu, "CPU"))]
 pub static LuCpu: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCpu",
+    label: "CPU",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6918,8 +9149,14 @@ pub static LuCpu: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 20v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCreativeCommons, "Creative Commons"))]
 pub static LuCreativeCommons: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCreativeCommons",
+    label: "Creative Commons",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6936,8 +9173,14 @@ pub static LuCreativeCommons: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M17 9.3a2.8 2.8 0 0 0-3.5 1 3.1 3.1 0 0 0 0 3.4 2.7 2.7 0 0 0 3.5 1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCreditCard, "Credit Card"))]
 pub static LuCreditCard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCreditCard",
+    label: "Credit Card",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6953,8 +9196,14 @@ pub static LuCreditCard: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="10" y2="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCroissant, "Croissant"))]
 pub static LuCroissant: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCroissant",
+    label: "Croissant",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6973,8 +9222,14 @@ pub static LuCroissant: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 16c1.55 0 4-.24 4 2 0 2-2.17 2.5-4 2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCrop, "Crop"))]
 pub static LuCrop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCrop",
+    label: "Crop",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -6990,8 +9245,14 @@ pub static LuCrop: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 22V8a2 2 0 0 0-2-2H2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCross, "Cross"))]
 pub static LuCross: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCross",
+    label: "Cross",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7006,8 +9267,14 @@ pub static LuCross: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M11 2a2 2 0 0 0-2 2v5H4a2 2 0 0 0-2 2v2c0 1.1.9 2 2 2h5v5c0 1.1.9 2 2 2h2a2 2 0 0 0 2-2v-5h5a2 2 0 0 0 2-2v-2a2 2 0 0 0-2-2h-5V4a2 2 0 0 0-2-2h-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCrosshair, "Crosshair"))]
 pub static LuCrosshair: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCrosshair",
+    label: "Crosshair",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7026,8 +9293,14 @@ pub static LuCrosshair: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="22" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCrown, "Crown"))]
 pub static LuCrown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCrown",
+    label: "Crown",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7042,8 +9315,14 @@ pub static LuCrown: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m2 4 3 12h14l3-12-6 7-4-7-4 7-6-7zm3 16h14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCupSoda, "Cup Soda"))]
 pub static LuCupSoda: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCupSoda",
+    label: "Cup Soda",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7061,8 +9340,14 @@ pub static LuCupSoda: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m12 8 1-6h2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuCurrency, "Currency"))]
 pub static LuCurrency: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuCurrency",
+    label: "Currency",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7081,8 +9366,14 @@ pub static LuCurrency: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="21" x2="18" y1="21" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDatabase, "Database"))]
 pub static LuDatabase: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDatabase",
+    label: "Database",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7099,8 +9390,14 @@ pub static LuDatabase: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 12A9 3 0 0 0 21 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDatabaseBackup, "Database Backup"))]
 pub static LuDatabaseBackup: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDatabaseBackup",
+    label: "Database Backup",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7120,8 +9417,14 @@ pub static LuDatabaseBackup: &icondata_core::IconData = &icondata_core::IconData
 <path d="M12 12v4h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDelete, "Delete"))]
 pub static LuDelete: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDelete",
+    label: "Delete",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7138,8 +9441,14 @@ pub static LuDelete: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="18" y1="9" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDessert, "Dessert"))]
 pub static LuDessert: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDessert",
+    label: "Dessert",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7156,8 +9465,14 @@ pub static LuDessert: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3.2 14.8a9 9 0 0 0 17.6 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDiamond, "Diamond"))]
 pub static LuDiamond: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDiamond",
+    label: "Diamond",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7172,8 +9487,14 @@ pub static LuDiamond: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M2.7 10.3a2.41 2.41 0 0 0 0 3.41l7.59 7.59a2.41 2.41 0 0 0 3.41 0l7.59-7.59a2.41 2.41 0 0 0 0-3.41l-7.59-7.59a2.41 2.41 0 0 0-3.41 0Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDice1, "Dice 1"))]
 pub static LuDice1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDice1",
+    label: "Dice 1",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7189,8 +9510,14 @@ pub static LuDice1: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 12h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDice2, "Dice 2"))]
 pub static LuDice2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDice2",
+    label: "Dice 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7207,8 +9534,14 @@ pub static LuDice2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 15h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDice3, "Dice 3"))]
 pub static LuDice3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDice3",
+    label: "Dice 3",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7226,8 +9559,14 @@ pub static LuDice3: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 16h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDice4, "Dice 4"))]
 pub static LuDice4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDice4",
+    label: "Dice 4",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7246,8 +9585,14 @@ pub static LuDice4: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 16h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDice5, "Dice 5"))]
 pub static LuDice5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDice5",
+    label: "Dice 5",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7267,8 +9612,14 @@ pub static LuDice5: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 12h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDice6, "Dice 6"))]
 pub static LuDice6: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDice6",
+    label: "Dice 6",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7289,8 +9640,14 @@ pub static LuDice6: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 16h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDices, "Dices"))]
 pub static LuDices: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDices",
+    label: "Dices",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7310,8 +9667,14 @@ pub static LuDices: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 9h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDiff, "Diff"))]
 pub static LuDiff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDiff",
+    label: "Diff",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7328,8 +9691,14 @@ pub static LuDiff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 21h14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDisc, "Disc"))]
 pub static LuDisc: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDisc",
+    label: "Disc",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7345,8 +9714,14 @@ pub static LuDisc: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDisc2, "Disc 2"))]
 pub static LuDisc2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDisc2",
+    label: "Disc 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7363,8 +9738,14 @@ pub static LuDisc2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 12h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDisc3, "Disc 3"))]
 pub static LuDisc3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDisc3",
+    label: "Disc 3",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7382,8 +9763,14 @@ pub static LuDisc3: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 12c0 1.7-.7 3.2-1.8 4.2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDivide, "Divide"))]
 pub static LuDivide: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDivide",
+    label: "Divide",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7400,8 +9787,14 @@ pub static LuDivide: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="18" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDivideCircle, "Divide Circle"))]
 pub static LuDivideCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDivideCircle",
+    label: "Divide Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7419,8 +9812,14 @@ pub static LuDivideCircle: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDivideSquare, "Divide Square"))]
 pub static LuDivideSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDivideSquare",
+    label: "Divide Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7438,8 +9837,14 @@ pub static LuDivideSquare: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="8" y2="8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDna, "Dna"))]
 pub static LuDna: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDna",
+    label: "Dna",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7464,8 +9869,14 @@ pub static LuDna: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m10 16 1.5 1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDnaOff, "Dna Off"))]
 pub static LuDnaOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDnaOff",
+    label: "Dna Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7490,8 +9901,14 @@ pub static LuDnaOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDog, "Dog"))]
 pub static LuDog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDog",
+    label: "Dog",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7511,8 +9928,14 @@ pub static LuDog: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4.42 11.247A13.152 13.152 0 0 0 4 14.556C4 18.728 7.582 21 12 21s8-2.272 8-6.444c0-1.061-.162-2.2-.493-3.309m-9.243-6.082A8.801 8.801 0 0 1 12 5c.78 0 1.5.108 2.161.306" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDollarSign, "Dollar Sign"))]
 pub static LuDollarSign: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDollarSign",
+    label: "Dollar Sign",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7528,8 +9951,14 @@ pub static LuDollarSign: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17 5H9.5a3.5 3.5 0 0 0 0 7h5a3.5 3.5 0 0 1 0 7H6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDonut, "Donut"))]
 pub static LuDonut: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDonut",
+    label: "Donut",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7545,8 +9974,14 @@ pub static LuDonut: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDoorClosed, "Door Closed"))]
 pub static LuDoorClosed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDoorClosed",
+    label: "Door Closed",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7563,8 +9998,14 @@ pub static LuDoorClosed: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 12v.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDoorOpen, "Door Open"))]
 pub static LuDoorOpen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDoorOpen",
+    label: "Door Open",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7583,8 +10024,14 @@ pub static LuDoorOpen: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13 4.562v16.157a1 1 0 0 1-1.242.97L5 20V5.562a2 2 0 0 1 1.515-1.94l4-1A2 2 0 0 1 13 4.561Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDot, "Dot"))]
 pub static LuDot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDot",
+    label: "Dot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7599,8 +10046,14 @@ pub static LuDot: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<circle cx="12.1" cy="12.1" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDownload, "Download"))]
 pub static LuDownload: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDownload",
+    label: "Download",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7617,8 +10070,14 @@ pub static LuDownload: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="15" y2="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDownloadCloud, "Download Cloud"))]
 pub static LuDownloadCloud: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDownloadCloud",
+    label: "Download Cloud",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7635,8 +10094,14 @@ pub static LuDownloadCloud: &icondata_core::IconData = &icondata_core::IconData
 <path d="m8 17 4 4 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDribbble, "Dribbble"))]
 pub static LuDribbble: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDribbble",
+    label: "Dribbble",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7654,8 +10119,14 @@ pub static LuDribbble: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8.56 2.75c4.37 6 6 9.42 8 17.72" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDroplet, "Droplet"))]
 pub static LuDroplet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDroplet",
+    label: "Droplet",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7670,8 +10141,14 @@ pub static LuDroplet: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 22a7 7 0 0 0 7-7c0-2-1-3.9-3-5.5s-3.5-4-4-6.5c-.5 2.5-2 4.9-4 6.5C6 11.1 5 13 5 15a7 7 0 0 0 7 7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDroplets, "Droplets"))]
 pub static LuDroplets: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDroplets",
+    label: "Droplets",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7687,8 +10164,14 @@ pub static LuDroplets: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12.56 6.6A10.97 10.97 0 0 0 14 3.02c.5 2.5 2 4.9 4 6.5s3 3.5 3 5.5a6.98 6.98 0 0 1-11.91 4.97" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDrumstick, "Drumstick"))]
 pub static LuDrumstick: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDrumstick",
+    label: "Drumstick",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7704,8 +10187,14 @@ pub static LuDrumstick: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m11.25 15.6-2.16 2.16a2.5 2.5 0 1 1-4.56 1.73 2.49 2.49 0 0 1-1.41-4.24 2.5 2.5 0 0 1 3.14-.32l2.16-2.16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuDumbbell, "Dumbbell"))]
 pub static LuDumbbell: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuDumbbell",
+    label: "Dumbbell",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7726,8 +10215,14 @@ pub static LuDumbbell: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14 21 7-7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuEar, "Ear"))]
 pub static LuEar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuEar",
+    label: "Ear",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7743,8 +10238,14 @@ pub static LuEar: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 8.5a2.5 2.5 0 0 0-5 0v1a2 2 0 1 1 0 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuEarOff, "Ear Off"))]
 pub static LuEarOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuEarOff",
+    label: "Ear Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7763,8 +10264,14 @@ pub static LuEarOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuEgg, "Egg"))]
 pub static LuEgg: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuEgg",
+    label: "Egg",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7779,8 +10286,14 @@ pub static LuEgg: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 22c6.23-.05 7.87-5.57 7.5-10-.36-4.34-3.95-9.96-7.5-10-3.55.04-7.14 5.66-7.5 10-.37 4.43 1.27 9.95 7.5 10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuEggFried, "Egg Fried"))]
 pub static LuEggFried: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuEggFried",
+    label: "Egg Fried",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7796,8 +10309,14 @@ pub static LuEggFried: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 8c0-3.5 2.5-6 6.5-6 5 0 4.83 3 7.5 5s5 2 5 6c0 4.5-2.5 6.5-7 6.5-2.5 0-2.5 2.5-6 2.5s-7-2-7-5.5c0-3 1.5-3 1.5-5C3.5 10 3 9 3 8Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuEggOff, "Egg Off"))]
 pub static LuEggOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuEggOff",
+    label: "Egg Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7814,8 +10333,14 @@ pub static LuEggOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuEqual, "Equal"))]
 pub static LuEqual: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuEqual",
+    label: "Equal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7831,8 +10356,14 @@ pub static LuEqual: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="5" x2="19" y1="15" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuEqualNot, "Equal Not"))]
 pub static LuEqualNot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuEqualNot",
+    label: "Equal Not",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7849,8 +10380,14 @@ pub static LuEqualNot: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="19" x2="5" y1="5" y2="19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuEraser, "Eraser"))]
 pub static LuEraser: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuEraser",
+    label: "Eraser",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7867,8 +10404,14 @@ pub static LuEraser: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5 11 9 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuEuro, "Euro"))]
 pub static LuEuro: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuEuro",
+    label: "Euro",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7885,8 +10428,14 @@ pub static LuEuro: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 6a7.7 7.7 0 0 0-5.2-2A7.9 7.9 0 0 0 6 12c0 4.4 3.5 8 7.8 8 2 0 3.8-.8 5.2-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuExpand, "Expand"))]
 pub static LuExpand: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuExpand",
+    label: "Expand",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7904,8 +10453,14 @@ pub static LuExpand: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 7.8V3m0 0h4.8M3 3l6 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuExternalLink, "External Link"))]
 pub static LuExternalLink: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuExternalLink",
+    label: "External Link",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7922,8 +10477,14 @@ pub static LuExternalLink: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="10" x2="21" y1="14" y2="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuEye, "Eye"))]
 pub static LuEye: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuEye",
+    label: "Eye",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7939,8 +10500,14 @@ pub static LuEye: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuEyeOff, "Eye Off"))]
 pub static LuEyeOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuEyeOff",
+    label: "Eye Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7958,8 +10525,14 @@ pub static LuEyeOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFacebook, "Facebook"))]
 pub static LuFacebook: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFacebook",
+    label: "Facebook",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7974,8 +10547,14 @@ pub static LuFacebook: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M18 2h-3a5 5 0 0 0-5 5v3H7v4h3v8h4v-8h3l1-4h-4V7a1 1 0 0 1 1-1h3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFactory, "Factory"))]
 pub static LuFactory: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFactory",
+    label: "Factory",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -7993,8 +10572,14 @@ pub static LuFactory: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 18h1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFan, "Fan"))]
 pub static LuFan: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFan",
+    label: "Fan",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8010,8 +10595,14 @@ pub static LuFan: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 12v.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFastForward, "Fast Forward"))]
 pub static LuFastForward: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFastForward",
+    label: "Fast Forward",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8027,8 +10618,14 @@ pub static LuFastForward: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="2 19 11 12 2 5 2 19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFeather, "Feather"))]
 pub static LuFeather: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFeather",
+    label: "Feather",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8045,8 +10642,14 @@ pub static LuFeather: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="17.5" x2="9" y1="15" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFerrisWheel, "Ferris Wheel"))]
 pub static LuFerrisWheel: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFerrisWheel",
+    label: "Ferris Wheel",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8069,8 +10672,14 @@ pub static LuFerrisWheel: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 18.7a9 9 0 1 0-12 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFigma, "Figma"))]
 pub static LuFigma: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFigma",
+    label: "Figma",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8089,8 +10698,14 @@ pub static LuFigma: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 12.5A3.5 3.5 0 0 1 8.5 9H12v7H8.5A3.5 3.5 0 0 1 5 12.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFile, "File"))]
 pub static LuFile: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFile",
+    label: "File",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8106,8 +10721,14 @@ pub static LuFile: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="14 2 14 8 20 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileArchive, "File Archive"))]
 pub static LuFileArchive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileArchive",
+    label: "File Archive",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8127,8 +10748,14 @@ pub static LuFileArchive: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 18v-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileAudio, "File Audio"))]
 pub static LuFileAudio: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileAudio",
+    label: "File Audio",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8147,8 +10774,14 @@ pub static LuFileAudio: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2 19v-3a6 6 0 0 1 12 0v3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileAudio2, "File Audio 2"))]
 pub static LuFileAudio2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileAudio2",
+    label: "File Audio 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8167,8 +10800,14 @@ pub static LuFileAudio2: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="3" cy="17" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileAxis3d, "File Axis 3 D"))]
 pub static LuFileAxis3d: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileAxis3d",
+    label: "File Axis 3 D",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8186,8 +10825,14 @@ pub static LuFileAxis3d: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 18 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileBadge, "File Badge"))]
 pub static LuFileBadge: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileBadge",
+    label: "File Badge",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8205,8 +10850,14 @@ pub static LuFileBadge: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 16.5 8 22l-3-1-3 1 1-5.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileBadge2, "File Badge 2"))]
 pub static LuFileBadge2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileBadge2",
+    label: "File Badge 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8223,8 +10874,14 @@ pub static LuFileBadge2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14 12.5 1 5.5-3-1-3 1 1-5.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileBarChart, "File Bar Chart"))]
 pub static LuFileBarChart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileBarChart",
+    label: "File Bar Chart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8243,8 +10900,14 @@ pub static LuFileBarChart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 18v-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileBarChart2, "File Bar Chart 2"))]
 pub static LuFileBarChart2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileBarChart2",
+    label: "File Bar Chart 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8263,8 +10926,14 @@ pub static LuFileBarChart2: &icondata_core::IconData = &icondata_core::IconData
 <path d="M16 18v-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileBox, "File Box"))]
 pub static LuFileBox: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileBox",
+    label: "File Box",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8284,8 +10953,14 @@ pub static LuFileBox: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 17v5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileCheck, "File Check"))]
 pub static LuFileCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileCheck",
+    label: "File Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8302,8 +10977,14 @@ pub static LuFileCheck: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 15 2 2 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileCheck2, "File Check 2"))]
 pub static LuFileCheck2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileCheck2",
+    label: "File Check 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8320,8 +11001,14 @@ pub static LuFileCheck2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m3 15 2 2 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileClock, "File Clock"))]
 pub static LuFileClock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileClock",
+    label: "File Clock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8339,8 +11026,14 @@ pub static LuFileClock: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9.5 17.5 8 16.25V14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileCode, "File Code"))]
 pub static LuFileCode: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileCode",
+    label: "File Code",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8358,8 +11051,14 @@ pub static LuFileCode: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14 17 2-2-2-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileCode2, "File Code 2"))]
 pub static LuFileCode2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileCode2",
+    label: "File Code 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8377,8 +11076,14 @@ pub static LuFileCode2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5 12-3 3 3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileCog, "File Cog"))]
 pub static LuFileCog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileCog",
+    label: "File Cog",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8403,8 +11108,14 @@ pub static LuFileCog: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3.88 11.88 3 11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileCog2, "File Cog 2"))]
 pub static LuFileCog2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileCog2",
+    label: "File Cog 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8427,8 +11138,14 @@ pub static LuFileCog2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m10.27 14-.87-.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileDiff, "File Diff"))]
 pub static LuFileDiff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileDiff",
+    label: "File Diff",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8446,8 +11163,14 @@ pub static LuFileDiff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 17h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileDigit, "File Digit"))]
 pub static LuFileDigit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileDigit",
+    label: "File Digit",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8466,8 +11189,14 @@ pub static LuFileDigit: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 18h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileDown, "File Down"))]
 pub static LuFileDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileDown",
+    label: "File Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8485,8 +11214,14 @@ pub static LuFileDown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 15 3 3 3-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileEdit, "File Edit"))]
 pub static LuFileEdit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileEdit",
+    label: "File Edit",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8503,8 +11238,14 @@ pub static LuFileEdit: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10.42 12.61a2.1 2.1 0 1 1 2.97 2.97L7.95 21 4 22l.99-3.95 5.43-5.44Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileHeart, "File Heart"))]
 pub static LuFileHeart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileHeart",
+    label: "File Heart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8521,8 +11262,14 @@ pub static LuFileHeart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10.29 10.7a2.43 2.43 0 0 0-2.66-.52c-.29.12-.56.3-.78.53l-.35.34-.35-.34a2.43 2.43 0 0 0-2.65-.53c-.3.12-.56.3-.79.53-.95.94-1 2.53.2 3.74L6.5 18l3.6-3.55c1.2-1.21 1.14-2.8.19-3.74Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileImage, "File Image"))]
 pub static LuFileImage: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileImage",
+    label: "File Image",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8540,8 +11287,14 @@ pub static LuFileImage: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m20 17-1.09-1.09a2 2 0 0 0-2.82 0L10 22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileInput, "File Input"))]
 pub static LuFileInput: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileInput",
+    label: "File Input",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8559,8 +11312,14 @@ pub static LuFileInput: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 18 3-3-3-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileJson, "File JSON"))]
 pub static LuFileJson: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileJson",
+    label: "File JSON",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8578,8 +11337,14 @@ pub static LuFileJson: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 18a1 1 0 0 0 1-1v-1a1 1 0 0 1 1-1 1 1 0 0 1-1-1v-1a1 1 0 0 0-1-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileJson2, "File JSON 2"))]
 pub static LuFileJson2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileJson2",
+    label: "File JSON 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8597,8 +11362,14 @@ pub static LuFileJson2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 18a1 1 0 0 0 1-1v-1a1 1 0 0 1 1-1 1 1 0 0 1-1-1v-1a1 1 0 0 0-1-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileKey, "File Key"))]
 pub static LuFileKey: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileKey",
+    label: "File Key",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8616,8 +11387,14 @@ pub static LuFileKey: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m15 11 1 1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileKey2, "File Key 2"))]
 pub static LuFileKey2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileKey2",
+    label: "File Key 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8636,8 +11413,14 @@ pub static LuFileKey2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 11 1 1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileLineChart, "File Line Chart"))]
 pub static LuFileLineChart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileLineChart",
+    label: "File Line Chart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8654,8 +11437,14 @@ pub static LuFileLineChart: &icondata_core::IconData = &icondata_core::IconData
 <path d="m16 13-3.5 3.5-2-2L8 17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileLock, "File Lock"))]
 pub static LuFileLock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileLock",
+    label: "File Lock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8672,8 +11461,14 @@ pub static LuFileLock: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 12v-2a3 3 0 1 0-6 0v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileLock2, "File Lock 2"))]
 pub static LuFileLock2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileLock2",
+    label: "File Lock 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8691,8 +11486,14 @@ pub static LuFileLock2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 13v-2a2 2 0 1 0-4 0v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileMinus, "File Minus"))]
 pub static LuFileMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileMinus",
+    label: "File Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8709,8 +11510,14 @@ pub static LuFileMinus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" x2="15" y1="15" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileMinus2, "File Minus 2"))]
 pub static LuFileMinus2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileMinus2",
+    label: "File Minus 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8727,8 +11534,14 @@ pub static LuFileMinus2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 15h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileOutput, "File Output"))]
 pub static LuFileOutput: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileOutput",
+    label: "File Output",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8746,8 +11559,14 @@ pub static LuFileOutput: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5 12-3 3 3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFilePieChart, "File Pie Chart"))]
 pub static LuFilePieChart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFilePieChart",
+    label: "File Pie Chart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8765,8 +11584,14 @@ pub static LuFilePieChart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13.83 16A5.83 5.83 0 0 0 8 10.17V16h5.83Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFilePlus, "File Plus"))]
 pub static LuFilePlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFilePlus",
+    label: "File Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8784,8 +11609,14 @@ pub static LuFilePlus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" x2="15" y1="15" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFilePlus2, "File Plus 2"))]
 pub static LuFilePlus2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFilePlus2",
+    label: "File Plus 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8803,8 +11634,14 @@ pub static LuFilePlus2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6 12v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileQuestion, "File Question"))]
 pub static LuFileQuestion: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileQuestion",
+    label: "File Question",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8821,8 +11658,14 @@ pub static LuFileQuestion: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 17h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileScan, "File Scan"))]
 pub static LuFileScan: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileScan",
+    label: "File Scan",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8842,8 +11685,14 @@ pub static LuFileScan: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 14a2 2 0 0 0-2 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileSearch, "File Search"))]
 pub static LuFileSearch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileSearch",
+    label: "File Search",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8861,8 +11710,14 @@ pub static LuFileSearch: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 18-1.5-1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileSearch2, "File Search 2"))]
 pub static LuFileSearch2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileSearch2",
+    label: "File Search 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8880,8 +11735,14 @@ pub static LuFileSearch2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13.25 16.25 15 18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileSignature, "File Signature"))]
 pub static LuFileSignature: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileSignature",
+    label: "File Signature",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8898,8 +11759,14 @@ pub static LuFileSignature: &icondata_core::IconData = &icondata_core::IconData
 <path d="M18.42 9.61a2.1 2.1 0 1 1 2.97 2.97L16.95 17 13 18l.99-3.95 4.43-4.44Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileSpreadsheet, "File Spreadsheet"))]
 pub static LuFileSpreadsheet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileSpreadsheet",
+    label: "File Spreadsheet",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8919,8 +11786,14 @@ pub static LuFileSpreadsheet: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M14 17h2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileStack, "File Stack"))]
 pub static LuFileStack: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileStack",
+    label: "File Stack",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8938,8 +11811,14 @@ pub static LuFileStack: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 12v8.8c0 .3.2.6.4.8.2.2.5.4.8.4H11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileSymlink, "File Symlink"))]
 pub static LuFileSymlink: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileSymlink",
+    label: "File Symlink",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8957,8 +11836,14 @@ pub static LuFileSymlink: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 18v-1a2 2 0 0 1 2-2h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileTerminal, "File Terminal"))]
 pub static LuFileTerminal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileTerminal",
+    label: "File Terminal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8976,8 +11861,14 @@ pub static LuFileTerminal: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 18h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileText, "File Text"))]
 pub static LuFileText: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileText",
+    label: "File Text",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -8996,8 +11887,14 @@ pub static LuFileText: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="10" x2="8" y1="9" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileType, "File Type"))]
 pub static LuFileType: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileType",
+    label: "File Type",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9016,8 +11913,14 @@ pub static LuFileType: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 12v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileType2, "File Type 2"))]
 pub static LuFileType2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileType2",
+    label: "File Type 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9036,8 +11939,14 @@ pub static LuFileType2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 12v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileUp, "File Up"))]
 pub static LuFileUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileUp",
+    label: "File Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9055,8 +11964,14 @@ pub static LuFileUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m15 15-3-3-3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileVideo, "File Video"))]
 pub static LuFileVideo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileVideo",
+    label: "File Video",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9073,8 +11988,14 @@ pub static LuFileVideo: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m10 11 5 3-5 3v-6Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileVideo2, "File Video 2"))]
 pub static LuFileVideo2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileVideo2",
+    label: "File Video 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9092,8 +12013,14 @@ pub static LuFileVideo2: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="8" height="6" x="2" y="12" rx="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileVolume, "File Volume"))]
 pub static LuFileVolume: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileVolume",
+    label: "File Volume",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9111,8 +12038,14 @@ pub static LuFileVolume: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11 11c.64.8 1 1.87 1 3s-.36 2.2-1 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileVolume2, "File Volume 2"))]
 pub static LuFileVolume2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileVolume2",
+    label: "File Volume 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9131,8 +12064,14 @@ pub static LuFileVolume2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 15h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileWarning, "File Warning"))]
 pub static LuFileWarning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileWarning",
+    label: "File Warning",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9149,8 +12088,14 @@ pub static LuFileWarning: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 17h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileX, "File X"))]
 pub static LuFileX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileX",
+    label: "File X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9168,8 +12113,14 @@ pub static LuFileX: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="14.5" x2="9.5" y1="12.5" y2="17.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFileX2, "File X 2"))]
 pub static LuFileX2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFileX2",
+    label: "File X 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9187,8 +12138,14 @@ pub static LuFileX2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 12.5-5 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFiles, "Files"))]
 pub static LuFiles: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFiles",
+    label: "Files",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9205,8 +12162,14 @@ pub static LuFiles: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 2v5h5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFilm, "Film"))]
 pub static LuFilm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFilm",
+    label: "Film",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9228,8 +12191,14 @@ pub static LuFilm: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="17" x2="22" y1="7" y2="7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFilter, "Filter"))]
 pub static LuFilter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFilter",
+    label: "Filter",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9244,8 +12213,14 @@ pub static LuFilter: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="22 3 2 3 10 12.46 10 19 14 21 14 12.46 22 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFilterX, "Filter X"))]
 pub static LuFilterX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFilterX",
+    label: "Filter X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9262,8 +12237,14 @@ pub static LuFilterX: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m17 3 5 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFingerprint, "Fingerprint"))]
 pub static LuFingerprint: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFingerprint",
+    label: "Fingerprint",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9286,8 +12267,14 @@ pub static LuFingerprint: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 6.8a6 6 0 0 1 9 5.2c0 .47 0 1.17-.02 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFish, "Fish"))]
 pub static LuFish: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFish",
+    label: "Fish",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9307,8 +12294,14 @@ pub static LuFish: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m16.01 17.93-.23 1.4A2 2 0 0 1 13.8 21H9.5a5.96 5.96 0 0 0 1.49-3.98" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFishOff, "Fish Off"))]
 pub static LuFishOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFishOff",
+    label: "Fish Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9325,8 +12318,14 @@ pub static LuFishOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m16.01 17.93-.23 1.4A2 2 0 0 1 13.8 21H9.5a5.96 5.96 0 0 0 1.49-3.98M8.53 3h5.27a2 2 0 0 1 1.98 1.67l.23 1.4M2 2l20 20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFishSymbol, "Fish Symbol"))]
 pub static LuFishSymbol: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFishSymbol",
+    label: "Fish Symbol",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9341,8 +12340,14 @@ pub static LuFishSymbol: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M2 16s9-15 20-4C11 23 2 8 2 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlag, "Flag"))]
 pub static LuFlag: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlag",
+    label: "Flag",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9358,8 +12363,14 @@ pub static LuFlag: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="4" x2="4" y1="22" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlagOff, "Flag Off"))]
 pub static LuFlagOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlagOff",
+    label: "Flag Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9377,8 +12388,14 @@ pub static LuFlagOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlagTriangleLeft, "Flag Triangle Left"))]
 pub static LuFlagTriangleLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlagTriangleLeft",
+    label: "Flag Triangle Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9393,8 +12410,14 @@ pub static LuFlagTriangleLeft: &icondata_core::IconData = &icondata_core::IconDa
     data: r###"<path d="M17 22V2L7 7l10 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlagTriangleRight, "Flag Triangle Right"))]
 pub static LuFlagTriangleRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlagTriangleRight",
+    label: "Flag Triangle Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9409,8 +12432,14 @@ pub static LuFlagTriangleRight: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<path d="M7 22V2l10 5-10 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlame, "Flame"))]
 pub static LuFlame: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlame",
+    label: "Flame",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9425,8 +12454,14 @@ pub static LuFlame: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M8.5 14.5A2.5 2.5 0 0 0 11 12c0-1.38-.5-2-1-3-1.072-2.143-.224-4.054 2-6 .5 2.5 2 4.9 4 6.5 2 1.6 3 3.5 3 5.5a7 7 0 1 1-14 0c0-1.153.433-2.294 1-3a2.5 2.5 0 0 0 2.5 2.5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlashlight, "Flashlight"))]
 pub static LuFlashlight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlashlight",
+    label: "Flashlight",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9443,8 +12478,14 @@ pub static LuFlashlight: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlashlightOff, "Flashlight Off"))]
 pub static LuFlashlightOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlashlightOff",
+    label: "Flashlight Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9462,8 +12503,14 @@ pub static LuFlashlightOff: &icondata_core::IconData = &icondata_core::IconData
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlaskConical, "Flask Conical"))]
 pub static LuFlaskConical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlaskConical",
+    label: "Flask Conical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9480,8 +12527,14 @@ pub static LuFlaskConical: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 16h10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlaskConicalOff, "Flask Conical Off"))]
 pub static LuFlaskConicalOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlaskConicalOff",
+    label: "Flask Conical Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9501,8 +12554,14 @@ pub static LuFlaskConicalOff: &icondata_core::IconData = &icondata_core::IconDat
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlaskRound, "Flask Round"))]
 pub static LuFlaskRound: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlaskRound",
+    label: "Flask Round",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9521,8 +12580,14 @@ pub static LuFlaskRound: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5.52 16h12.96" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlipHorizontal, "Flip Horizontal"))]
 pub static LuFlipHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlipHorizontal",
+    label: "Flip Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9542,8 +12607,14 @@ pub static LuFlipHorizontal: &icondata_core::IconData = &icondata_core::IconData
 <path d="M12 2v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlipHorizontal2, "Flip Horizontal 2"))]
 pub static LuFlipHorizontal2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlipHorizontal2",
+    label: "Flip Horizontal 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9563,8 +12634,14 @@ pub static LuFlipHorizontal2: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M12 2v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlipVertical, "Flip Vertical"))]
 pub static LuFlipVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlipVertical",
+    label: "Flip Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9584,8 +12661,14 @@ pub static LuFlipVertical: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 12h-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlipVertical2, "Flip Vertical 2"))]
 pub static LuFlipVertical2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlipVertical2",
+    label: "Flip Vertical 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9605,8 +12688,14 @@ pub static LuFlipVertical2: &icondata_core::IconData = &icondata_core::IconData
 <path d="M22 12h-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlower, "Flower"))]
 pub static LuFlower: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlower",
+    label: "Flower",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9626,8 +12715,14 @@ pub static LuFlower: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.5 14.5 16 16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFlower2, "Flower 2"))]
 pub static LuFlower2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFlower2",
+    label: "Flower 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9646,8 +12741,14 @@ pub static LuFlower2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 22c-4.2 0-7-1.667-7-5 4.2 0 7 1.667 7 5Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFocus, "Focus"))]
 pub static LuFocus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFocus",
+    label: "Focus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9666,8 +12767,14 @@ pub static LuFocus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 21H5a2 2 0 0 1-2-2v-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFoldHorizontal, "Fold Horizontal"))]
 pub static LuFoldHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFoldHorizontal",
+    label: "Fold Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9689,8 +12796,14 @@ pub static LuFoldHorizontal: &icondata_core::IconData = &icondata_core::IconData
 <path d="m5 15 3-3-3-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFoldVertical, "Fold Vertical"))]
 pub static LuFoldVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFoldVertical",
+    label: "Fold Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9712,8 +12825,14 @@ pub static LuFoldVertical: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m15 5-3 3-3-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolder, "Folder"))]
 pub static LuFolder: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolder",
+    label: "Folder",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9728,8 +12847,14 @@ pub static LuFolder: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4 20h16a2 2 0 0 0 2-2V8a2 2 0 0 0-2-2h-7.93a2 2 0 0 1-1.66-.9l-.82-1.2A2 2 0 0 0 7.93 3H4a2 2 0 0 0-2 2v13c0 1.1.9 2 2 2Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderArchive, "Folder Archive"))]
 pub static LuFolderArchive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderArchive",
+    label: "Folder Archive",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9747,8 +12872,14 @@ pub static LuFolderArchive: &icondata_core::IconData = &icondata_core::IconData
 <path d="M16 17v-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderCheck, "Folder Check"))]
 pub static LuFolderCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderCheck",
+    label: "Folder Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9764,8 +12895,14 @@ pub static LuFolderCheck: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 13 2 2 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderClock, "Folder Clock"))]
 pub static LuFolderClock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderClock",
+    label: "Folder Clock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9782,8 +12919,14 @@ pub static LuFolderClock: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 14v2l1 1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderClosed, "Folder Closed"))]
 pub static LuFolderClosed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderClosed",
+    label: "Folder Closed",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9799,8 +12942,14 @@ pub static LuFolderClosed: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2 10h20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderCog, "Folder Cog"))]
 pub static LuFolderCog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderCog",
+    label: "Folder Cog",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9824,8 +12973,14 @@ pub static LuFolderCog: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15.88 15.88 15 15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderCog2, "Folder Cog 2"))]
 pub static LuFolderCog2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderCog2",
+    label: "Folder Cog 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9847,8 +13002,14 @@ pub static LuFolderCog2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m10.27 12-.87-.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderDot, "Folder Dot"))]
 pub static LuFolderDot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderDot",
+    label: "Folder Dot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9864,8 +13025,14 @@ pub static LuFolderDot: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="13" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderDown, "Folder Down"))]
 pub static LuFolderDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderDown",
+    label: "Folder Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9882,8 +13049,14 @@ pub static LuFolderDown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m15 13-3 3-3-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderEdit, "Folder Edit"))]
 pub static LuFolderEdit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderEdit",
+    label: "Folder Edit",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9899,8 +13072,14 @@ pub static LuFolderEdit: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2 11.5V5c0-1.1.9-2 2-2h3.93a2 2 0 0 1 1.66.9l.82 1.2a2 2 0 0 0 1.66.9H20a2 2 0 0 1 2 2v10a2 2 0 0 1-2 2h-9.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderGit, "Folder Git"))]
 pub static LuFolderGit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderGit",
+    label: "Folder Git",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9918,8 +13097,14 @@ pub static LuFolderGit: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 13h3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderGit2, "Folder Git 2"))]
 pub static LuFolderGit2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderGit2",
+    label: "Folder Git 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9937,8 +13122,14 @@ pub static LuFolderGit2: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="20" cy="19" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderHeart, "Folder Heart"))]
 pub static LuFolderHeart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderHeart",
+    label: "Folder Heart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9954,8 +13145,14 @@ pub static LuFolderHeart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21.29 13.7a2.43 2.43 0 0 0-2.65-.52c-.3.12-.57.3-.8.53l-.34.34-.35-.34a2.43 2.43 0 0 0-2.65-.53c-.3.12-.56.3-.79.53-.95.94-1 2.53.2 3.74L17.5 21l3.6-3.55c1.2-1.21 1.14-2.8.19-3.74Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderInput, "Folder Input"))]
 pub static LuFolderInput: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderInput",
+    label: "Folder Input",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9972,8 +13169,14 @@ pub static LuFolderInput: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 16 3-3-3-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderKanban, "Folder Kanban"))]
 pub static LuFolderKanban: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderKanban",
+    label: "Folder Kanban",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -9991,8 +13194,14 @@ pub static LuFolderKanban: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 10v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderKey, "Folder Key"))]
 pub static LuFolderKey: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderKey",
+    label: "Folder Key",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10010,8 +13219,14 @@ pub static LuFolderKey: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m21 15 1 1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderLock, "Folder Lock"))]
 pub static LuFolderLock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderLock",
+    label: "Folder Lock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10028,8 +13243,14 @@ pub static LuFolderLock: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 17v-2a2 2 0 1 0-4 0v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderMinus, "Folder Minus"))]
 pub static LuFolderMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderMinus",
+    label: "Folder Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10045,8 +13266,14 @@ pub static LuFolderMinus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" x2="15" y1="13" y2="13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderOpen, "Folder Open"))]
 pub static LuFolderOpen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderOpen",
+    label: "Folder Open",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10061,8 +13288,14 @@ pub static LuFolderOpen: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m6 14 1.45-2.9A2 2 0 0 1 9.24 10H20a2 2 0 0 1 1.94 2.5l-1.55 6a2 2 0 0 1-1.94 1.5H4a2 2 0 0 1-2-2V5c0-1.1.9-2 2-2h3.93a2 2 0 0 1 1.66.9l.82 1.2a2 2 0 0 0 1.66.9H18a2 2 0 0 1 2 2v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderOpenDot, "Folder Open Dot"))]
 pub static LuFolderOpenDot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderOpenDot",
+    label: "Folder Open Dot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10078,8 +13311,14 @@ pub static LuFolderOpenDot: &icondata_core::IconData = &icondata_core::IconData
 <circle cx="14" cy="15" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderOutput, "Folder Output"))]
 pub static LuFolderOutput: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderOutput",
+    label: "Folder Output",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10096,8 +13335,14 @@ pub static LuFolderOutput: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5 10-3 3 3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderPlus, "Folder Plus"))]
 pub static LuFolderPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderPlus",
+    label: "Folder Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10114,8 +13359,14 @@ pub static LuFolderPlus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" x2="15" y1="13" y2="13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderRoot, "Folder Root"))]
 pub static LuFolderRoot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderRoot",
+    label: "Folder Root",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10132,8 +13383,14 @@ pub static LuFolderRoot: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 15v5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderSearch, "Folder Search"))]
 pub static LuFolderSearch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderSearch",
+    label: "Folder Search",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10150,8 +13407,14 @@ pub static LuFolderSearch: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m21 21-1.5-1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderSearch2, "Folder Search 2"))]
 pub static LuFolderSearch2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderSearch2",
+    label: "Folder Search 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10168,8 +13431,14 @@ pub static LuFolderSearch2: &icondata_core::IconData = &icondata_core::IconData
 <path d="M13.27 14.27 15 16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderSymlink, "Folder Symlink"))]
 pub static LuFolderSymlink: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderSymlink",
+    label: "Folder Symlink",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10186,8 +13455,14 @@ pub static LuFolderSymlink: &icondata_core::IconData = &icondata_core::IconData
 <path d="M2 16v-1a2 2 0 0 1 2-2h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderSync, "Folder Sync"))]
 pub static LuFolderSync: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderSync",
+    label: "Folder Sync",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10206,8 +13481,14 @@ pub static LuFolderSync: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m22 18-1.5 1.5c-.9.9-2.1 1.5-3.5 1.5s-2.6-.6-3.5-1.5c-.4-.4-.8-1-1-1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderTree, "Folder Tree"))]
 pub static LuFolderTree: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderTree",
+    label: "Folder Tree",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10225,8 +13506,14 @@ pub static LuFolderTree: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 3v13c0 1.1.9 2 2 2h3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderUp, "Folder Up"))]
 pub static LuFolderUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderUp",
+    label: "Folder Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10243,8 +13530,14 @@ pub static LuFolderUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 13 3-3 3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolderX, "Folder X"))]
 pub static LuFolderX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolderX",
+    label: "Folder X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10261,8 +13554,14 @@ pub static LuFolderX: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14.5 10.5-5 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFolders, "Folders"))]
 pub static LuFolders: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFolders",
+    label: "Folders",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10278,8 +13577,14 @@ pub static LuFolders: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2 8v11c0 1.1.9 2 2 2h14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFootprints, "Footprints"))]
 pub static LuFootprints: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFootprints",
+    label: "Footprints",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10297,8 +13602,14 @@ pub static LuFootprints: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 13h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuForklift, "Forklift"))]
 pub static LuForklift: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuForklift",
+    label: "Forklift",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10316,8 +13627,14 @@ pub static LuForklift: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 19h3m5-17v17h6M6 12V7c0-1.1.9-2 2-2h3l5 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFormInput, "Form Input"))]
 pub static LuFormInput: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFormInput",
+    label: "Form Input",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10335,8 +13652,14 @@ pub static LuFormInput: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 12h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuForward, "Forward"))]
 pub static LuForward: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuForward",
+    label: "Forward",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10352,8 +13675,14 @@ pub static LuForward: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 18v-2a4 4 0 0 1 4-4h12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFrame, "Frame"))]
 pub static LuFrame: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFrame",
+    label: "Frame",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10371,8 +13700,14 @@ pub static LuFrame: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="18" x2="18" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFramer, "Framer"))]
 pub static LuFramer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFramer",
+    label: "Framer",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10387,8 +13722,14 @@ pub static LuFramer: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M5 16V9h14V2H5l14 14h-7m-7 0 7 7v-7m-7 0h7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFrown, "Frown"))]
 pub static LuFrown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFrown",
+    label: "Frown",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10406,8 +13747,14 @@ pub static LuFrown: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" x2="15.01" y1="9" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFuel, "Fuel"))]
 pub static LuFuel: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFuel",
+    label: "Fuel",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10425,8 +13772,14 @@ pub static LuFuel: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 13h2a2 2 0 0 1 2 2v2a2 2 0 0 0 2 2h0a2 2 0 0 0 2-2V9.83a2 2 0 0 0-.59-1.42L18 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuFunctionSquare, "Function Square"))]
 pub static LuFunctionSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuFunctionSquare",
+    label: "Function Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10443,8 +13796,14 @@ pub static LuFunctionSquare: &icondata_core::IconData = &icondata_core::IconData
 <path d="M9 11.2h5.7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGalleryHorizontal, "Gallery Horizontal"))]
 pub static LuGalleryHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGalleryHorizontal",
+    label: "Gallery Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10461,8 +13820,14 @@ pub static LuGalleryHorizontal: &icondata_core::IconData = &icondata_core::IconD
 <path d="M22 3v18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGalleryHorizontalEnd, "Gallery Horizontal End"))]
 pub static LuGalleryHorizontalEnd: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGalleryHorizontalEnd",
+    label: "Gallery Horizontal End",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10479,8 +13844,14 @@ pub static LuGalleryHorizontalEnd: &icondata_core::IconData = &icondata_core::Ic
 <rect width="12" height="18" x="10" y="3" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGalleryThumbnails, "Gallery Thumbnails"))]
 pub static LuGalleryThumbnails: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGalleryThumbnails",
+    label: "Gallery Thumbnails",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10499,8 +13870,14 @@ pub static LuGalleryThumbnails: &icondata_core::IconData = &icondata_core::IconD
 <path d="M19 21h1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGalleryVertical, "Gallery Vertical"))]
 pub static LuGalleryVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGalleryVertical",
+    label: "Gallery Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10517,8 +13894,14 @@ pub static LuGalleryVertical: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M3 22h18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGalleryVerticalEnd, "Gallery Vertical End"))]
 pub static LuGalleryVerticalEnd: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGalleryVerticalEnd",
+    label: "Gallery Vertical End",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10535,8 +13918,14 @@ pub static LuGalleryVerticalEnd: &icondata_core::IconData = &icondata_core::Icon
 <rect width="18" height="12" x="3" y="10" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGamepad, "Gamepad"))]
 pub static LuGamepad: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGamepad",
+    label: "Gamepad",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10555,8 +13944,14 @@ pub static LuGamepad: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="20" height="12" x="2" y="6" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGamepad2, "Gamepad 2"))]
 pub static LuGamepad2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGamepad2",
+    label: "Gamepad 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10575,8 +13970,14 @@ pub static LuGamepad2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17.32 5H6.68a4 4 0 0 0-3.978 3.59c-.006.052-.01.101-.017.152C2.604 9.416 2 14.456 2 16a3 3 0 0 0 3 3c1 0 1.5-.5 2-1l1.414-1.414A2 2 0 0 1 9.828 16h4.344a2 2 0 0 1 1.414.586L17 18c.5.5 1 1 2 1a3 3 0 0 0 3-3c0-1.545-.604-6.584-.685-7.258-.007-.05-.011-.1-.017-.151A4 4 0 0 0 17.32 5z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGanttChart, "Gantt Chart"))]
 pub static LuGanttChart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGanttChart",
+    label: "Gantt Chart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10593,8 +13994,14 @@ pub static LuGanttChart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11 18h7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGanttChartSquare, "Gantt Chart Square"))]
 pub static LuGanttChartSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGanttChartSquare",
+    label: "Gantt Chart Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10612,8 +14019,14 @@ pub static LuGanttChartSquare: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M11 16h5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGauge, "Gauge"))]
 pub static LuGauge: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGauge",
+    label: "Gauge",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10629,8 +14042,14 @@ pub static LuGauge: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3.34 19a10 10 0 1 1 17.32 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGaugeCircle, "Gauge Circle"))]
 pub static LuGaugeCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGaugeCircle",
+    label: "Gauge Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10647,8 +14066,14 @@ pub static LuGaugeCircle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13.4 10.6 19 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGavel, "Gavel"))]
 pub static LuGavel: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGavel",
+    label: "Gavel",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10667,8 +14092,14 @@ pub static LuGavel: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m21 11-8-8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGem, "Gem"))]
 pub static LuGem: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGem",
+    label: "Gem",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10685,8 +14116,14 @@ pub static LuGem: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2 9h20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGhost, "Ghost"))]
 pub static LuGhost: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGhost",
+    label: "Ghost",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10703,8 +14140,14 @@ pub static LuGhost: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 2a8 8 0 0 0-8 8v12l3-3 2.5 2.5L12 19l2.5 2.5L17 19l3 3V10a8 8 0 0 0-8-8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGift, "Gift"))]
 pub static LuGift: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGift",
+    label: "Gift",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10723,8 +14166,14 @@ pub static LuGift: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 7h4.5a2.5 2.5 0 0 0 0-5C13 2 12 7 12 7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGitBranch, "Git Branch"))]
 pub static LuGitBranch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGitBranch",
+    label: "Git Branch",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10742,8 +14191,14 @@ pub static LuGitBranch: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 9a9 9 0 0 1-9 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGitBranchPlus, "Git Branch Plus"))]
 pub static LuGitBranchPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGitBranchPlus",
+    label: "Git Branch Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10763,8 +14218,14 @@ pub static LuGitBranchPlus: &icondata_core::IconData = &icondata_core::IconData
 <path d="M21 18h-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGitCommit, "Git Commit"))]
 pub static LuGitCommit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGitCommit",
+    label: "Git Commit",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10781,8 +14242,14 @@ pub static LuGitCommit: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" x2="21" y1="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGitCompare, "Git Compare"))]
 pub static LuGitCompare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGitCompare",
+    label: "Git Compare",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10800,8 +14267,14 @@ pub static LuGitCompare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11 18H8a2 2 0 0 1-2-2V9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGitFork, "Git Fork"))]
 pub static LuGitFork: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGitFork",
+    label: "Git Fork",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10820,8 +14293,14 @@ pub static LuGitFork: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 12v3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGitMerge, "Git Merge"))]
 pub static LuGitMerge: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGitMerge",
+    label: "Git Merge",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10838,8 +14317,14 @@ pub static LuGitMerge: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6 21V9a9 9 0 0 0 9 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGitPullRequest, "Git Pull Request"))]
 pub static LuGitPullRequest: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGitPullRequest",
+    label: "Git Pull Request",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10857,8 +14342,14 @@ pub static LuGitPullRequest: &icondata_core::IconData = &icondata_core::IconData
 <line x1="6" x2="6" y1="9" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGitPullRequestClosed, "Git Pull Request Closed"))]
 pub static LuGitPullRequestClosed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGitPullRequestClosed",
+    label: "Git Pull Request Closed",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10878,8 +14369,14 @@ pub static LuGitPullRequestClosed: &icondata_core::IconData = &icondata_core::Ic
 <line x1="6" x2="6" y1="9" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGitPullRequestDraft, "Git Pull Request Draft"))]
 pub static LuGitPullRequestDraft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGitPullRequestDraft",
+    label: "Git Pull Request Draft",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10898,8 +14395,14 @@ pub static LuGitPullRequestDraft: &icondata_core::IconData = &icondata_core::Ico
 <line x1="6" x2="6" y1="9" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGithub, "Github"))]
 pub static LuGithub: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGithub",
+    label: "Github",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10915,8 +14418,14 @@ pub static LuGithub: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 18c-4.51 2-5-2-7-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGitlab, "Gitlab"))]
 pub static LuGitlab: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGitlab",
+    label: "Gitlab",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10931,8 +14440,14 @@ pub static LuGitlab: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m22 13.29-3.33-10a.42.42 0 0 0-.14-.18.38.38 0 0 0-.22-.11.39.39 0 0 0-.23.07.42.42 0 0 0-.14.18l-2.26 6.67H8.32L6.1 3.26a.42.42 0 0 0-.1-.18.38.38 0 0 0-.26-.08.39.39 0 0 0-.23.07.42.42 0 0 0-.14.18L2 13.29a.74.74 0 0 0 .27.83L12 21l9.69-6.88a.71.71 0 0 0 .31-.83Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGlassWater, "Glass Water"))]
 pub static LuGlassWater: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGlassWater",
+    label: "Glass Water",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10948,8 +14463,14 @@ pub static LuGlassWater: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6 12a5 5 0 0 1 6 0 5 5 0 0 0 6 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGlasses, "Glasses"))]
 pub static LuGlasses: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGlasses",
+    label: "Glasses",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10968,8 +14489,14 @@ pub static LuGlasses: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21.5 13 19 7c-.7-1.3-1.5-2-3-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGlobe, "Globe"))]
 pub static LuGlobe: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGlobe",
+    label: "Globe",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -10986,8 +14513,14 @@ pub static LuGlobe: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 2a15.3 15.3 0 0 1 4 10 15.3 15.3 0 0 1-4 10 15.3 15.3 0 0 1-4-10 15.3 15.3 0 0 1 4-10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGlobe2, "Globe 2"))]
 pub static LuGlobe2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGlobe2",
+    label: "Globe 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11005,8 +14538,14 @@ pub static LuGlobe2: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGoal, "Goal"))]
 pub static LuGoal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGoal",
+    label: "Goal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11023,8 +14562,14 @@ pub static LuGoal: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 10a5 5 0 1 0 8.9 2.02" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGrab, "Grab"))]
 pub static LuGrab: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGrab",
+    label: "Grab",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11043,8 +14588,14 @@ pub static LuGrab: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 11v0a2 2 0 1 1 4 0v3a8 8 0 0 1-8 8h-4a8 8 0 0 1-8-8 2 2 0 1 1 4 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGraduationCap, "Graduation Cap"))]
 pub static LuGraduationCap: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGraduationCap",
+    label: "Graduation Cap",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11060,8 +14611,14 @@ pub static LuGraduationCap: &icondata_core::IconData = &icondata_core::IconData
 <path d="M6 12v5c3 3 9 3 12 0v-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGrape, "Grape"))]
 pub static LuGrape: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGrape",
+    label: "Grape",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11084,8 +14641,14 @@ pub static LuGrape: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="5" cy="19" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGrid2x2, "Grid 2 X 2"))]
 pub static LuGrid2x2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGrid2x2",
+    label: "Grid 2 X 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11102,8 +14665,14 @@ pub static LuGrid2x2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 3v18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGrid3x3, "Grid 3 X 3"))]
 pub static LuGrid3x3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGrid3x3",
+    label: "Grid 3 X 3",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11122,8 +14691,14 @@ pub static LuGrid3x3: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 3v18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGrip, "Grip"))]
 pub static LuGrip: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGrip",
+    label: "Grip",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11146,8 +14721,14 @@ pub static LuGrip: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="5" cy="19" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGripHorizontal, "Grip Horizontal"))]
 pub static LuGripHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGripHorizontal",
+    label: "Grip Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11167,8 +14748,14 @@ pub static LuGripHorizontal: &icondata_core::IconData = &icondata_core::IconData
 <circle cx="5" cy="15" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGripVertical, "Grip Vertical"))]
 pub static LuGripVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGripVertical",
+    label: "Grip Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11188,8 +14775,14 @@ pub static LuGripVertical: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="15" cy="19" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuGroup, "Group"))]
 pub static LuGroup: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuGroup",
+    label: "Group",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11209,8 +14802,14 @@ pub static LuGroup: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="7" height="5" x="10" y="12" rx="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHammer, "Hammer"))]
 pub static LuHammer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHammer",
+    label: "Hammer",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11227,8 +14826,14 @@ pub static LuHammer: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m20.91 11.7-1.25-1.25c-.6-.6-.93-1.4-.93-2.25v-.86L16.01 4.6a5.56 5.56 0 0 0-3.94-1.64H9l.92.82A6.18 6.18 0 0 1 12 8.4v1.56l2 2h2.47l2.26 1.91" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHand, "Hand"))]
 pub static LuHand: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHand",
+    label: "Hand",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11246,8 +14851,14 @@ pub static LuHand: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 8a2 2 0 1 1 4 0v6a8 8 0 0 1-8 8h-2c-2.8 0-4.5-.86-5.99-2.34l-3.6-3.6a2 2 0 0 1 2.83-2.82L7 15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHandMetal, "Hand Metal"))]
 pub static LuHandMetal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHandMetal",
+    label: "Hand Metal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11265,8 +14876,14 @@ pub static LuHandMetal: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m7 15-1.76-1.76a2 2 0 0 0-2.83 2.82l3.6 3.6C7.5 21.14 9.2 22 12 22h2a8 8 0 0 0 8-8V7a2 2 0 1 0-4 0v5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHardDrive, "Hard Drive"))]
 pub static LuHardDrive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHardDrive",
+    label: "Hard Drive",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11284,8 +14901,14 @@ pub static LuHardDrive: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="10" x2="10.01" y1="16" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHardDriveDownload, "Hard Drive Download"))]
 pub static LuHardDriveDownload: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHardDriveDownload",
+    label: "Hard Drive Download",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11304,8 +14927,14 @@ pub static LuHardDriveDownload: &icondata_core::IconData = &icondata_core::IconD
 <path d="M10 18h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHardDriveUpload, "Hard Drive Upload"))]
 pub static LuHardDriveUpload: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHardDriveUpload",
+    label: "Hard Drive Upload",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11324,8 +14953,14 @@ pub static LuHardDriveUpload: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M10 18h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHardHat, "Hard Hat"))]
 pub static LuHardHat: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHardHat",
+    label: "Hard Hat",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11343,8 +14978,14 @@ pub static LuHardHat: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 6h0a6 6 0 0 1 6 6v3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHash, "Hash"))]
 pub static LuHash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHash",
+    label: "Hash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11362,8 +15003,14 @@ pub static LuHash: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="16" x2="14" y1="3" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHaze, "Haze"))]
 pub static LuHaze: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHaze",
+    label: "Haze",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11385,8 +15032,14 @@ pub static LuHaze: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 5V2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHdmiPort, "Hdmi Port"))]
 pub static LuHdmiPort: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHdmiPort",
+    label: "Hdmi Port",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11402,8 +15055,14 @@ pub static LuHdmiPort: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7.5 12h9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHeading, "Heading"))]
 pub static LuHeading: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHeading",
+    label: "Heading",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11420,8 +15079,14 @@ pub static LuHeading: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 20V4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHeading1, "Heading 1"))]
 pub static LuHeading1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHeading1",
+    label: "Heading 1",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11439,8 +15104,14 @@ pub static LuHeading1: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m17 12 3-2v8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHeading2, "Heading 2"))]
 pub static LuHeading2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHeading2",
+    label: "Heading 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11458,8 +15129,14 @@ pub static LuHeading2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 18h-4c0-4 4-3 4-6 0-1.5-2-2.5-4-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHeading3, "Heading 3"))]
 pub static LuHeading3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHeading3",
+    label: "Heading 3",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11478,8 +15155,14 @@ pub static LuHeading3: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17 17.5c2 1.5 4 .3 4-1.5a2 2 0 0 0-2-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHeading4, "Heading 4"))]
 pub static LuHeading4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHeading4",
+    label: "Heading 4",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11498,8 +15181,14 @@ pub static LuHeading4: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 10v8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHeading5, "Heading 5"))]
 pub static LuHeading5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHeading5",
+    label: "Heading 5",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11518,8 +15207,14 @@ pub static LuHeading5: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17 17.7c.4.2.8.3 1.3.3 1.5 0 2.7-1.1 2.7-2.5S19.8 13 18.3 13H17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHeading6, "Heading 6"))]
 pub static LuHeading6: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHeading6",
+    label: "Heading 6",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11538,8 +15233,14 @@ pub static LuHeading6: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 10c-2 2-3 3.5-3 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHeadphones, "Headphones"))]
 pub static LuHeadphones: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHeadphones",
+    label: "Headphones",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11554,8 +15255,14 @@ pub static LuHeadphones: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M3 14h3a2 2 0 0 1 2 2v3a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v-7a9 9 0 0 1 18 0v7a2 2 0 0 1-2 2h-1a2 2 0 0 1-2-2v-3a2 2 0 0 1 2-2h3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHeart, "Heart"))]
 pub static LuHeart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHeart",
+    label: "Heart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11570,8 +15277,14 @@ pub static LuHeart: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M19 14c1.49-1.46 3-3.21 3-5.5A5.5 5.5 0 0 0 16.5 3c-1.76 0-3 .5-4.5 2-1.5-1.5-2.74-2-4.5-2A5.5 5.5 0 0 0 2 8.5c0 2.3 1.5 4.05 3 5.5l7 7Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHeartCrack, "Heart Crack"))]
 pub static LuHeartCrack: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHeartCrack",
+    label: "Heart Crack",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11587,8 +15300,14 @@ pub static LuHeartCrack: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m12 13-1-1 2-2-3-3 2-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHeartHandshake, "Heart Handshake"))]
 pub static LuHeartHandshake: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHeartHandshake",
+    label: "Heart Handshake",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11606,8 +15325,14 @@ pub static LuHeartHandshake: &icondata_core::IconData = &icondata_core::IconData
 <path d="m15 18-2-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHeartOff, "Heart Off"))]
 pub static LuHeartOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHeartOff",
+    label: "Heart Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11624,8 +15349,14 @@ pub static LuHeartOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8.76 3.1c1.15.22 2.13.78 3.24 1.9 1.5-1.5 2.74-2 4.5-2A5.5 5.5 0 0 1 22 8.5c0 2.12-1.3 3.78-2.67 5.17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHeartPulse, "Heart Pulse"))]
 pub static LuHeartPulse: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHeartPulse",
+    label: "Heart Pulse",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11641,8 +15372,14 @@ pub static LuHeartPulse: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3.22 12H9.5l.5-1 2 4.5 2-7 1.5 3.5h5.27" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHelpCircle, "Help Circle"))]
 pub static LuHelpCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHelpCircle",
+    label: "Help Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11659,8 +15396,14 @@ pub static LuHelpCircle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 17h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHelpingHand, "Helping Hand"))]
 pub static LuHelpingHand: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHelpingHand",
+    label: "Helping Hand",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11676,8 +15419,14 @@ pub static LuHelpingHand: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2 14 6 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHexagon, "Hexagon"))]
 pub static LuHexagon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHexagon",
+    label: "Hexagon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11692,8 +15441,14 @@ pub static LuHexagon: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M21 16V8a2 2 0 0 0-1-1.73l-7-4a2 2 0 0 0-2 0l-7 4A2 2 0 0 0 3 8v8a2 2 0 0 0 1 1.73l7 4a2 2 0 0 0 2 0l7-4A2 2 0 0 0 21 16z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHighlighter, "Highlighter"))]
 pub static LuHighlighter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHighlighter",
+    label: "Highlighter",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11709,8 +15464,14 @@ pub static LuHighlighter: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m22 12-4.6 4.6a2 2 0 0 1-2.8 0l-5.2-5.2a2 2 0 0 1 0-2.8L14 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHistory, "History"))]
 pub static LuHistory: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHistory",
+    label: "History",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11727,8 +15488,14 @@ pub static LuHistory: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 7v5l4 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHome, "Home"))]
 pub static LuHome: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHome",
+    label: "Home",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11744,8 +15511,14 @@ pub static LuHome: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="9 22 9 12 15 12 15 22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHop, "Hop"))]
 pub static LuHop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHop",
+    label: "Hop",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11767,8 +15540,14 @@ pub static LuHop: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4.5 4.5 3 3c-.184-.185-.184-.816 0-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHopOff, "Hop Off"))]
 pub static LuHopOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHopOff",
+    label: "Hop Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11790,8 +15569,14 @@ pub static LuHopOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHotel, "Hotel"))]
 pub static LuHotel: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHotel",
+    label: "Hotel",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11814,8 +15599,14 @@ pub static LuHotel: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 22v-6.5m4 0V22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuHourglass, "Hourglass"))]
 pub static LuHourglass: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuHourglass",
+    label: "Hourglass",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11833,8 +15624,14 @@ pub static LuHourglass: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 2v4.172a2 2 0 0 0 .586 1.414L12 12l4.414-4.414A2 2 0 0 0 17 6.172V2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuIceCream, "Ice Cream"))]
 pub static LuIceCream: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuIceCream",
+    label: "Ice Cream",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11851,8 +15648,14 @@ pub static LuIceCream: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17 7a2 2 0 0 1 0 4H7a2 2 0 0 1 0-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuIceCream2, "Ice Cream 2"))]
 pub static LuIceCream2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuIceCream2",
+    label: "Ice Cream 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11869,8 +15672,14 @@ pub static LuIceCream2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15.5 6.5a3.5 3.5 0 1 0-7 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuImage, "Image"))]
 pub static LuImage: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuImage",
+    label: "Image",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11887,8 +15696,14 @@ pub static LuImage: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m21 15-3.086-3.086a2 2 0 0 0-2.828 0L6 21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuImageMinus, "Image Minus"))]
 pub static LuImageMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuImageMinus",
+    label: "Image Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11906,8 +15721,14 @@ pub static LuImageMinus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m21 15-3.086-3.086a2 2 0 0 0-2.828 0L6 21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuImageOff, "Image Off"))]
 pub static LuImageOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuImageOff",
+    label: "Image Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11927,8 +15748,14 @@ pub static LuImageOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 15V5a2 2 0 0 0-2-2H9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuImagePlus, "Image Plus"))]
 pub static LuImagePlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuImagePlus",
+    label: "Image Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11947,8 +15774,14 @@ pub static LuImagePlus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m21 15-3.086-3.086a2 2 0 0 0-2.828 0L6 21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuImport, "Import"))]
 pub static LuImport: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuImport",
+    label: "Import",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11965,8 +15798,14 @@ pub static LuImport: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 5H4a2 2 0 0 0-2 2v10a2 2 0 0 0 2 2h16a2 2 0 0 0 2-2V7a2 2 0 0 0-2-2h-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuInbox, "Inbox"))]
 pub static LuInbox: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuInbox",
+    label: "Inbox",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -11982,8 +15821,14 @@ pub static LuInbox: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5.45 5.11 2 12v6a2 2 0 0 0 2 2h16a2 2 0 0 0 2-2v-6l-3.45-6.89A2 2 0 0 0 16.76 4H7.24a2 2 0 0 0-1.79 1.11z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuIndent, "Indent"))]
 pub static LuIndent: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuIndent",
+    label: "Indent",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12001,8 +15846,14 @@ pub static LuIndent: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="21" x2="11" y1="18" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuIndianRupee, "Indian Rupee"))]
 pub static LuIndianRupee: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuIndianRupee",
+    label: "Indian Rupee",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12021,8 +15872,14 @@ pub static LuIndianRupee: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 13c6.667 0 6.667-10 0-10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuInfinity, "Infinity"))]
 pub static LuInfinity: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuInfinity",
+    label: "Infinity",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12037,8 +15894,14 @@ pub static LuInfinity: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 12c-2-2.67-4-4-6-4a4 4 0 1 0 0 8c2 0 4-1.33 6-4Zm0 0c2 2.67 4 4 6 4a4 4 0 0 0 0-8c-2 0-4 1.33-6 4Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuInfo, "Info"))]
 pub static LuInfo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuInfo",
+    label: "Info",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12055,8 +15918,14 @@ pub static LuInfo: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 8h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuInspect, "Inspect"))]
 pub static LuInspect: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuInspect",
+    label: "Inspect",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12072,8 +15941,14 @@ pub static LuInspect: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m12 12 4 10 1.7-4.3L22 16Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuInstagram, "Instagram"))]
 pub static LuInstagram: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuInstagram",
+    label: "Instagram",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12090,8 +15965,14 @@ pub static LuInstagram: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="17.5" x2="17.51" y1="6.5" y2="6.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuItalic, "Italic"))]
 pub static LuItalic: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuItalic",
+    label: "Italic",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12108,8 +15989,14 @@ pub static LuItalic: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" x2="9" y1="4" y2="20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuIterationCcw, "Iteration Ccw"))]
 pub static LuIterationCcw: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuIterationCcw",
+    label: "Iteration Ccw",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12125,8 +16012,14 @@ pub static LuIterationCcw: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="16 14 20 18 16 22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuIterationCw, "Iteration Cw"))]
 pub static LuIterationCw: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuIterationCw",
+    label: "Iteration Cw",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12142,8 +16035,14 @@ pub static LuIterationCw: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="8 22 4 18 8 14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuJapaneseYen, "Japanese Yen"))]
 pub static LuJapaneseYen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuJapaneseYen",
+    label: "Japanese Yen",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12160,8 +16059,14 @@ pub static LuJapaneseYen: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6 11h12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuJoystick, "Joystick"))]
 pub static LuJoystick: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuJoystick",
+    label: "Joystick",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12179,8 +16084,14 @@ pub static LuJoystick: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="6" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuKanban, "Kanban"))]
 pub static LuKanban: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuKanban",
+    label: "Kanban",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12197,8 +16108,14 @@ pub static LuKanban: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 5v14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuKanbanSquare, "Kanban Square"))]
 pub static LuKanbanSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuKanbanSquare",
+    label: "Kanban Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12216,8 +16133,14 @@ pub static LuKanbanSquare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 7v9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuKanbanSquareDashed, "Kanban Square Dashed"))]
 pub static LuKanbanSquareDashed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuKanbanSquareDashed",
+    label: "Kanban Square Dashed",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12246,8 +16169,14 @@ pub static LuKanbanSquareDashed: &icondata_core::IconData = &icondata_core::Icon
 <path d="M3 9v1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuKey, "Key"))]
 pub static LuKey: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuKey",
+    label: "Key",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12264,8 +16193,14 @@ pub static LuKey: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m15.5 7.5 3 3L22 7l-3-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuKeyRound, "Key Round"))]
 pub static LuKeyRound: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuKeyRound",
+    label: "Key Round",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12281,8 +16216,14 @@ pub static LuKeyRound: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="16.5" cy="7.5" r=".5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuKeySquare, "Key Square"))]
 pub static LuKeySquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuKeySquare",
+    label: "Key Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12299,8 +16240,14 @@ pub static LuKeySquare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9.4 10.6 2 18v3c0 .6.4 1 1 1h4v-3h3v-3h2l1.4-1.4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuKeyboard, "Keyboard"))]
 pub static LuKeyboard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuKeyboard",
+    label: "Keyboard",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12323,8 +16270,14 @@ pub static LuKeyboard: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 16h10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLamp, "Lamp"))]
 pub static LuLamp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLamp",
+    label: "Lamp",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12341,8 +16294,14 @@ pub static LuLamp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 22v-2c0-1.1.9-2 2-2h4a2 2 0 0 1 2 2v2H8Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLampCeiling, "Lamp Ceiling"))]
 pub static LuLampCeiling: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLampCeiling",
+    label: "Lamp Ceiling",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12359,8 +16318,14 @@ pub static LuLampCeiling: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9.17 16a3 3 0 1 0 5.66 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLampDesk, "Lamp Desk"))]
 pub static LuLampDesk: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLampDesk",
+    label: "Lamp Desk",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12378,8 +16343,14 @@ pub static LuLampDesk: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 22v-2c0-1.1.9-2 2-2h4a2 2 0 0 1 2 2v2H3Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLampFloor, "Lamp Floor"))]
 pub static LuLampFloor: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLampFloor",
+    label: "Lamp Floor",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12396,8 +16367,14 @@ pub static LuLampFloor: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 22h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLampWallDown, "Lamp Wall Down"))]
 pub static LuLampWallDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLampWallDown",
+    label: "Lamp Wall Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12414,8 +16391,14 @@ pub static LuLampWallDown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 9h2a2 2 0 0 0 2-2V5a2 2 0 0 0-2-2H4v6Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLampWallUp, "Lamp Wall Up"))]
 pub static LuLampWallUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLampWallUp",
+    label: "Lamp Wall Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12432,8 +16415,14 @@ pub static LuLampWallUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 15h2a2 2 0 0 1 2 2v2a2 2 0 0 1-2 2H4v-6Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLandmark, "Landmark"))]
 pub static LuLandmark: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLandmark",
+    label: "Landmark",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12453,8 +16442,14 @@ pub static LuLandmark: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="12 2 20 7 4 7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLanguages, "Languages"))]
 pub static LuLanguages: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLanguages",
+    label: "Languages",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12474,8 +16469,14 @@ pub static LuLanguages: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 18h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLaptop, "Laptop"))]
 pub static LuLaptop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLaptop",
+    label: "Laptop",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12490,8 +16491,14 @@ pub static LuLaptop: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M20 16V7a2 2 0 0 0-2-2H6a2 2 0 0 0-2 2v9m16 0H4m16 0 1.28 2.55a1 1 0 0 1-.9 1.45H3.62a1 1 0 0 1-.9-1.45L4 16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLaptop2, "Laptop 2"))]
 pub static LuLaptop2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLaptop2",
+    label: "Laptop 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12507,8 +16514,14 @@ pub static LuLaptop2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="20" y2="20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLasso, "Lasso"))]
 pub static LuLasso: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLasso",
+    label: "Lasso",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12525,8 +16538,14 @@ pub static LuLasso: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 18a2 2 0 1 0 0-4 2 2 0 0 0 0 4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLassoSelect, "Lasso Select"))]
 pub static LuLassoSelect: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLassoSelect",
+    label: "Lasso Select",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12545,8 +16564,14 @@ pub static LuLassoSelect: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.33 22h-.09a.35.35 0 0 1-.24-.32v-10a.34.34 0 0 1 .33-.34c.08 0 .15.03.21.08l7.34 6a.33.33 0 0 1-.21.59h-4.49l-2.57 3.85a.35.35 0 0 1-.28.14v0z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLaugh, "Laugh"))]
 pub static LuLaugh: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLaugh",
+    label: "Laugh",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12564,8 +16589,14 @@ pub static LuLaugh: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" x2="15.01" y1="9" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLayers, "Layers"))]
 pub static LuLayers: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLayers",
+    label: "Layers",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12582,8 +16613,14 @@ pub static LuLayers: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="2 12 12 17 22 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLayout, "Layout"))]
 pub static LuLayout: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLayout",
+    label: "Layout",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12600,8 +16637,14 @@ pub static LuLayout: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" x2="9" y1="21" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLayoutDashboard, "Layout Dashboard"))]
 pub static LuLayoutDashboard: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLayoutDashboard",
+    label: "Layout Dashboard",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12619,8 +16662,14 @@ pub static LuLayoutDashboard: &icondata_core::IconData = &icondata_core::IconDat
 <rect width="7" height="5" x="3" y="16" rx="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLayoutGrid, "Layout Grid"))]
 pub static LuLayoutGrid: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLayoutGrid",
+    label: "Layout Grid",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12638,8 +16687,14 @@ pub static LuLayoutGrid: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="7" height="7" x="3" y="14" rx="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLayoutList, "Layout List"))]
 pub static LuLayoutList: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLayoutList",
+    label: "Layout List",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12659,8 +16714,14 @@ pub static LuLayoutList: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 20h7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLayoutPanelLeft, "Layout Panel Left"))]
 pub static LuLayoutPanelLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLayoutPanelLeft",
+    label: "Layout Panel Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12677,8 +16738,14 @@ pub static LuLayoutPanelLeft: &icondata_core::IconData = &icondata_core::IconDat
 <rect width="7" height="7" x="14" y="14" rx="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLayoutPanelTop, "Layout Panel Top"))]
 pub static LuLayoutPanelTop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLayoutPanelTop",
+    label: "Layout Panel Top",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12695,8 +16762,14 @@ pub static LuLayoutPanelTop: &icondata_core::IconData = &icondata_core::IconData
 <rect width="7" height="7" x="14" y="14" rx="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLayoutTemplate, "Layout Template"))]
 pub static LuLayoutTemplate: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLayoutTemplate",
+    label: "Layout Template",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12713,8 +16786,14 @@ pub static LuLayoutTemplate: &icondata_core::IconData = &icondata_core::IconData
 <rect width="5" height="7" x="16" y="14" rx="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLeaf, "Leaf"))]
 pub static LuLeaf: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLeaf",
+    label: "Leaf",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12730,8 +16809,14 @@ pub static LuLeaf: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2 21c0-3 1.85-5.36 5.08-6C9.5 14.52 12 13 13 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLeafyGreen, "Leafy Green"))]
 pub static LuLeafyGreen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLeafyGreen",
+    label: "Leafy Green",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12747,8 +16832,14 @@ pub static LuLeafyGreen: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2 22 17 7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLibrary, "Library"))]
 pub static LuLibrary: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLibrary",
+    label: "Library",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12766,8 +16857,14 @@ pub static LuLibrary: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 4v16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLifeBuoy, "Life Buoy"))]
 pub static LuLifeBuoy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLifeBuoy",
+    label: "Life Buoy",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12787,8 +16884,14 @@ pub static LuLifeBuoy: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLigature, "Ligature"))]
 pub static LuLigature: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLigature",
+    label: "Ligature",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12807,8 +16910,14 @@ pub static LuLigature: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 20h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLightbulb, "Lightbulb"))]
 pub static LuLightbulb: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLightbulb",
+    label: "Lightbulb",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12825,8 +16934,14 @@ pub static LuLightbulb: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 22h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLightbulbOff, "Lightbulb Off"))]
 pub static LuLightbulbOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLightbulbOff",
+    label: "Lightbulb Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12845,8 +16960,14 @@ pub static LuLightbulbOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 22h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLineChart, "Line Chart"))]
 pub static LuLineChart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLineChart",
+    label: "Line Chart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12862,8 +16983,14 @@ pub static LuLineChart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m19 9-5 5-4-4-3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLink, "Link"))]
 pub static LuLink: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLink",
+    label: "Link",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12879,8 +17006,14 @@ pub static LuLink: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 11a5 5 0 0 0-7.54-.54l-3 3a5 5 0 0 0 7.07 7.07l1.71-1.71" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLink2, "Link 2"))]
 pub static LuLink2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLink2",
+    label: "Link 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12897,8 +17030,14 @@ pub static LuLink2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8" x2="16" y1="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLink2Off, "Link 2 Off"))]
 pub static LuLink2Off: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLink2Off",
+    label: "Link 2 Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12916,8 +17055,14 @@ pub static LuLink2Off: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLinkedin, "Linkedin"))]
 pub static LuLinkedin: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLinkedin",
+    label: "Linkedin",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12934,8 +17079,14 @@ pub static LuLinkedin: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="4" cy="4" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuList, "List"))]
 pub static LuList: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuList",
+    label: "List",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12955,8 +17106,14 @@ pub static LuList: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" x2="3.01" y1="18" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuListChecks, "List Checks"))]
 pub static LuListChecks: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuListChecks",
+    label: "List Checks",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12975,8 +17132,14 @@ pub static LuListChecks: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13 18h8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuListEnd, "List End"))]
 pub static LuListEnd: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuListEnd",
+    label: "List End",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -12995,8 +17158,14 @@ pub static LuListEnd: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m16 16-2 2 2 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuListFilter, "List Filter"))]
 pub static LuListFilter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuListFilter",
+    label: "List Filter",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13013,8 +17182,14 @@ pub static LuListFilter: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 18h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuListMinus, "List Minus"))]
 pub static LuListMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuListMinus",
+    label: "List Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13032,8 +17207,14 @@ pub static LuListMinus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 12h-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuListMusic, "List Music"))]
 pub static LuListMusic: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuListMusic",
+    label: "List Music",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13052,8 +17233,14 @@ pub static LuListMusic: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 18H3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuListOrdered, "List Ordered"))]
 pub static LuListOrdered: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuListOrdered",
+    label: "List Ordered",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13073,8 +17260,14 @@ pub static LuListOrdered: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6 18H4c0-1 2-2 2-3s-1-1.5-2-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuListPlus, "List Plus"))]
 pub static LuListPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuListPlus",
+    label: "List Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13093,8 +17286,14 @@ pub static LuListPlus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 12h-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuListRestart, "List Restart"))]
 pub static LuListRestart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuListRestart",
+    label: "List Restart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13113,8 +17312,14 @@ pub static LuListRestart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11 10v4h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuListStart, "List Start"))]
 pub static LuListStart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuListStart",
+    label: "List Start",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13133,8 +17338,14 @@ pub static LuListStart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m16 8-2-2 2-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuListTodo, "List Todo"))]
 pub static LuListTodo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuListTodo",
+    label: "List Todo",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13153,8 +17364,14 @@ pub static LuListTodo: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13 18h8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuListTree, "List Tree"))]
 pub static LuListTree: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuListTree",
+    label: "List Tree",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13173,8 +17390,14 @@ pub static LuListTree: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 10v6c0 1.1.9 2 2 2h3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuListVideo, "List Video"))]
 pub static LuListVideo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuListVideo",
+    label: "List Video",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13192,8 +17415,14 @@ pub static LuListVideo: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m16 12 5 3-5 3v-6Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuListX, "List X"))]
 pub static LuListX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuListX",
+    label: "List X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13212,8 +17441,14 @@ pub static LuListX: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m15 10 4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLoader, "Loader"))]
 pub static LuLoader: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLoader",
+    label: "Loader",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13235,8 +17470,14 @@ pub static LuLoader: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="16.24" x2="19.07" y1="7.76" y2="4.93" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLoader2, "Loader 2"))]
 pub static LuLoader2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLoader2",
+    label: "Loader 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13251,8 +17492,14 @@ pub static LuLoader2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M21 12a9 9 0 1 1-6.219-8.56" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLocate, "Locate"))]
 pub static LuLocate: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLocate",
+    label: "Locate",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13271,8 +17518,14 @@ pub static LuLocate: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLocateFixed, "Locate Fixed"))]
 pub static LuLocateFixed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLocateFixed",
+    label: "Locate Fixed",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13292,8 +17545,14 @@ pub static LuLocateFixed: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLocateOff, "Locate Off"))]
 pub static LuLocateOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLocateOff",
+    label: "Locate Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13314,8 +17573,14 @@ pub static LuLocateOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLock, "Lock"))]
 pub static LuLock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLock",
+    label: "Lock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13331,8 +17596,14 @@ pub static LuLock: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 11V7a5 5 0 0 1 10 0v4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLogIn, "Log In"))]
 pub static LuLogIn: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLogIn",
+    label: "Log In",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13349,8 +17620,14 @@ pub static LuLogIn: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" x2="3" y1="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLogOut, "Log Out"))]
 pub static LuLogOut: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLogOut",
+    label: "Log Out",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13367,8 +17644,14 @@ pub static LuLogOut: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="21" x2="9" y1="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLollipop, "Lollipop"))]
 pub static LuLollipop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLollipop",
+    label: "Lollipop",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13385,8 +17668,14 @@ pub static LuLollipop: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11 11a2 2 0 0 0 4 0 4 4 0 0 0-8 0 6 6 0 0 0 12 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuLuggage, "Luggage"))]
 pub static LuLuggage: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuLuggage",
+    label: "Luggage",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13405,8 +17694,14 @@ pub static LuLuggage: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="20" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMSquare, "Msquare"))]
 pub static LuMSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMSquare",
+    label: "Msquare",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13422,8 +17717,14 @@ pub static LuMSquare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 16V8l4 4 4-4v8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMagnet, "Magnet"))]
 pub static LuMagnet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMagnet",
+    label: "Magnet",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13440,8 +17741,14 @@ pub static LuMagnet: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m12 15 4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMail, "Mail"))]
 pub static LuMail: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMail",
+    label: "Mail",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13457,8 +17764,14 @@ pub static LuMail: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m22 7-8.97 5.7a1.94 1.94 0 0 1-2.06 0L2 7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMailCheck, "Mail Check"))]
 pub static LuMailCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMailCheck",
+    label: "Mail Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13475,8 +17788,14 @@ pub static LuMailCheck: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m16 19 2 2 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMailMinus, "Mail Minus"))]
 pub static LuMailMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMailMinus",
+    label: "Mail Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13493,8 +17812,14 @@ pub static LuMailMinus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 19h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMailOpen, "Mail Open"))]
 pub static LuMailOpen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMailOpen",
+    label: "Mail Open",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13510,8 +17835,14 @@ pub static LuMailOpen: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m22 10-8.97 5.7a1.94 1.94 0 0 1-2.06 0L2 10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMailPlus, "Mail Plus"))]
 pub static LuMailPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMailPlus",
+    label: "Mail Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13529,8 +17860,14 @@ pub static LuMailPlus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 19h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMailQuestion, "Mail Question"))]
 pub static LuMailQuestion: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMailQuestion",
+    label: "Mail Question",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13548,8 +17885,14 @@ pub static LuMailQuestion: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 22v.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMailSearch, "Mail Search"))]
 pub static LuMailSearch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMailSearch",
+    label: "Mail Search",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13568,8 +17911,14 @@ pub static LuMailSearch: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m22 22-1.5-1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMailWarning, "Mail Warning"))]
 pub static LuMailWarning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMailWarning",
+    label: "Mail Warning",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13587,8 +17936,14 @@ pub static LuMailWarning: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 22v.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMailX, "Mail X"))]
 pub static LuMailX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMailX",
+    label: "Mail X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13606,8 +17961,14 @@ pub static LuMailX: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m21 17-4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMailbox, "Mailbox"))]
 pub static LuMailbox: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMailbox",
+    label: "Mailbox",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13625,8 +17986,14 @@ pub static LuMailbox: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="6" x2="7" y1="10" y2="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMails, "Mails"))]
 pub static LuMails: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMails",
+    label: "Mails",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13643,8 +18010,14 @@ pub static LuMails: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2 8v11c0 1.1.9 2 2 2h14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMap, "Map"))]
 pub static LuMap: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMap",
+    label: "Map",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13661,8 +18034,14 @@ pub static LuMap: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" x2="15" y1="6" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMapPin, "Map Pin"))]
 pub static LuMapPin: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMapPin",
+    label: "Map Pin",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13678,8 +18057,14 @@ pub static LuMapPin: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="10" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMapPinOff, "Map Pin Off"))]
 pub static LuMapPinOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMapPinOff",
+    label: "Map Pin Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13698,8 +18083,14 @@ pub static LuMapPinOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMartini, "Martini"))]
 pub static LuMartini: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMartini",
+    label: "Martini",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13716,8 +18107,14 @@ pub static LuMartini: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m19 3-7 8-7-8Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMaximize, "Maximize"))]
 pub static LuMaximize: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMaximize",
+    label: "Maximize",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13735,8 +18132,14 @@ pub static LuMaximize: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 21h3a2 2 0 0 0 2-2v-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMaximize2, "Maximize 2"))]
 pub static LuMaximize2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMaximize2",
+    label: "Maximize 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13754,8 +18157,14 @@ pub static LuMaximize2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" x2="10" y1="21" y2="14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMedal, "Medal"))]
 pub static LuMedal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMedal",
+    label: "Medal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13775,8 +18184,14 @@ pub static LuMedal: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 18v-2h-.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMegaphone, "Megaphone"))]
 pub static LuMegaphone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMegaphone",
+    label: "Megaphone",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13792,8 +18207,14 @@ pub static LuMegaphone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11.6 16.8a3 3 0 1 1-5.8-1.6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMegaphoneOff, "Megaphone Off"))]
 pub static LuMegaphoneOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMegaphoneOff",
+    label: "Megaphone Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13811,8 +18232,14 @@ pub static LuMegaphoneOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMeh, "Meh"))]
 pub static LuMeh: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMeh",
+    label: "Meh",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13830,8 +18257,14 @@ pub static LuMeh: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" x2="15.01" y1="9" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMemoryStick, "Memory Stick"))]
 pub static LuMemoryStick: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMemoryStick",
+    label: "Memory Stick",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13854,8 +18287,14 @@ pub static LuMemoryStick: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2 7a2 2 0 0 1 2-2h16a2 2 0 0 1 2 2v1.1a2 2 0 0 0 0 3.837V17a2 2 0 0 1-2 2H4a2 2 0 0 1-2-2v-5.1a2 2 0 0 0 0-3.837Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMenu, "Menu"))]
 pub static LuMenu: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMenu",
+    label: "Menu",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13872,8 +18311,14 @@ pub static LuMenu: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="4" x2="20" y1="18" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMenuSquare, "Menu Square"))]
 pub static LuMenuSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMenuSquare",
+    label: "Menu Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13891,8 +18336,14 @@ pub static LuMenuSquare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 16h10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMerge, "Merge"))]
 pub static LuMerge: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMerge",
+    label: "Merge",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13909,8 +18360,14 @@ pub static LuMerge: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m20 22-5-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMessageCircle, "Message Circle"))]
 pub static LuMessageCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMessageCircle",
+    label: "Message Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13925,8 +18382,14 @@ pub static LuMessageCircle: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="m3 21 1.9-5.7a8.5 8.5 0 1 1 3.8 3.8z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMessageSquare, "Message Square"))]
 pub static LuMessageSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMessageSquare",
+    label: "Message Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13941,8 +18404,14 @@ pub static LuMessageSquare: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M21 15a2 2 0 0 1-2 2H7l-4 4V5a2 2 0 0 1 2-2h14a2 2 0 0 1 2 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMessageSquareDashed, "Message Square Dashed"))]
 pub static LuMessageSquareDashed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMessageSquareDashed",
+    label: "Message Square Dashed",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13964,8 +18433,14 @@ pub static LuMessageSquareDashed: &icondata_core::IconData = &icondata_core::Ico
 <path d="M3 12v-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMessageSquarePlus, "Message Square Plus"))]
 pub static LuMessageSquarePlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMessageSquarePlus",
+    label: "Message Square Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13982,8 +18457,14 @@ pub static LuMessageSquarePlus: &icondata_core::IconData = &icondata_core::IconD
 <line x1="12" x2="12" y1="7" y2="13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMessagesSquare, "Messages Square"))]
 pub static LuMessagesSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMessagesSquare",
+    label: "Messages Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -13999,8 +18480,14 @@ pub static LuMessagesSquare: &icondata_core::IconData = &icondata_core::IconData
 <path d="M18 9h2a2 2 0 0 1 2 2v11l-4-4h-6a2 2 0 0 1-2-2v-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMic, "Mic"))]
 pub static LuMic: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMic",
+    label: "Mic",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14017,8 +18504,14 @@ pub static LuMic: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="19" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMic2, "Mic 2"))]
 pub static LuMic2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMic2",
+    label: "Mic 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14034,8 +18527,14 @@ pub static LuMic2: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="17" cy="7" r="5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMicOff, "Mic Off"))]
 pub static LuMicOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMicOff",
+    label: "Mic Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14055,8 +18554,14 @@ pub static LuMicOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="19" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMicroscope, "Microscope"))]
 pub static LuMicroscope: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMicroscope",
+    label: "Microscope",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14076,8 +18581,14 @@ pub static LuMicroscope: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 6V3a1 1 0 0 0-1-1H9a1 1 0 0 0-1 1v3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMicrowave, "Microwave"))]
 pub static LuMicrowave: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMicrowave",
+    label: "Microwave",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14096,8 +18607,14 @@ pub static LuMicrowave: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 19v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMilestone, "Milestone"))]
 pub static LuMilestone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMilestone",
+    label: "Milestone",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14114,8 +18631,14 @@ pub static LuMilestone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 3v3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMilk, "Milk"))]
 pub static LuMilk: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMilk",
+    label: "Milk",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14132,8 +18655,14 @@ pub static LuMilk: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 15a6.472 6.472 0 0 1 5 0 6.47 6.47 0 0 0 5 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMilkOff, "Milk Off"))]
 pub static LuMilkOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMilkOff",
+    label: "Milk Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14151,8 +18680,14 @@ pub static LuMilkOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMinimize, "Minimize"))]
 pub static LuMinimize: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMinimize",
+    label: "Minimize",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14170,8 +18705,14 @@ pub static LuMinimize: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 21v-3a2 2 0 0 1 2-2h3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMinimize2, "Minimize 2"))]
 pub static LuMinimize2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMinimize2",
+    label: "Minimize 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14189,8 +18730,14 @@ pub static LuMinimize2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" x2="10" y1="21" y2="14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMinus, "Minus"))]
 pub static LuMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMinus",
+    label: "Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14205,8 +18752,14 @@ pub static LuMinus: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M5 12h14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMinusCircle, "Minus Circle"))]
 pub static LuMinusCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMinusCircle",
+    label: "Minus Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14222,8 +18775,14 @@ pub static LuMinusCircle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 12h8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMinusSquare, "Minus Square"))]
 pub static LuMinusSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMinusSquare",
+    label: "Minus Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14239,8 +18798,14 @@ pub static LuMinusSquare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 12h8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMonitor, "Monitor"))]
 pub static LuMonitor: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMonitor",
+    label: "Monitor",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14257,8 +18822,14 @@ pub static LuMonitor: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="17" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMonitorCheck, "Monitor Check"))]
 pub static LuMonitorCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMonitorCheck",
+    label: "Monitor Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14276,8 +18847,14 @@ pub static LuMonitorCheck: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 21h8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMonitorDot, "Monitor Dot"))]
 pub static LuMonitorDot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMonitorDot",
+    label: "Monitor Dot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14295,8 +18872,14 @@ pub static LuMonitorDot: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 21h8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMonitorDown, "Monitor Down"))]
 pub static LuMonitorDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMonitorDown",
+    label: "Monitor Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14315,8 +18898,14 @@ pub static LuMonitorDown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 21h8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMonitorOff, "Monitor Off"))]
 pub static LuMonitorOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMonitorOff",
+    label: "Monitor Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14335,8 +18924,14 @@ pub static LuMonitorOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2 2 20 20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMonitorPause, "Monitor Pause"))]
 pub static LuMonitorPause: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMonitorPause",
+    label: "Monitor Pause",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14355,8 +18950,14 @@ pub static LuMonitorPause: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 21h8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMonitorPlay, "Monitor Play"))]
 pub static LuMonitorPlay: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMonitorPlay",
+    label: "Monitor Play",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14374,8 +18975,14 @@ pub static LuMonitorPlay: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 21h8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMonitorSmartphone, "Monitor Smartphone"))]
 pub static LuMonitorSmartphone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMonitorSmartphone",
+    label: "Monitor Smartphone",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14393,8 +19000,14 @@ pub static LuMonitorSmartphone: &icondata_core::IconData = &icondata_core::IconD
 <rect width="6" height="10" x="16" y="12" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMonitorSpeaker, "Monitor Speaker"))]
 pub static LuMonitorSpeaker: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMonitorSpeaker",
+    label: "Monitor Speaker",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14413,8 +19026,14 @@ pub static LuMonitorSpeaker: &icondata_core::IconData = &icondata_core::IconData
 <circle cx="17" cy="15" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMonitorStop, "Monitor Stop"))]
 pub static LuMonitorStop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMonitorStop",
+    label: "Monitor Stop",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14432,8 +19051,14 @@ pub static LuMonitorStop: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 21h8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMonitorUp, "Monitor Up"))]
 pub static LuMonitorUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMonitorUp",
+    label: "Monitor Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14452,8 +19077,14 @@ pub static LuMonitorUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 21h8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMonitorX, "Monitor X"))]
 pub static LuMonitorX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMonitorX",
+    label: "Monitor X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14472,8 +19103,14 @@ pub static LuMonitorX: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 21h8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoon, "Moon"))]
 pub static LuMoon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoon",
+    label: "Moon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14488,8 +19125,14 @@ pub static LuMoon: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 3a6 6 0 0 0 9 9 9 9 0 1 1-9-9Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoonStar, "Moon Star"))]
 pub static LuMoonStar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoonStar",
+    label: "Moon Star",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14506,8 +19149,14 @@ pub static LuMoonStar: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 5h-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoreHorizontal, "More Horizontal"))]
 pub static LuMoreHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoreHorizontal",
+    label: "More Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14524,8 +19173,14 @@ pub static LuMoreHorizontal: &icondata_core::IconData = &icondata_core::IconData
 <circle cx="5" cy="12" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoreVertical, "More Vertical"))]
 pub static LuMoreVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoreVertical",
+    label: "More Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14542,8 +19197,14 @@ pub static LuMoreVertical: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="19" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMountain, "Mountain"))]
 pub static LuMountain: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMountain",
+    label: "Mountain",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14558,8 +19219,14 @@ pub static LuMountain: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m8 3 4 8 5-5 5 15H2L8 3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMountainSnow, "Mountain Snow"))]
 pub static LuMountainSnow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMountainSnow",
+    label: "Mountain Snow",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14575,8 +19242,14 @@ pub static LuMountainSnow: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4.14 15.08c2.62-1.57 5.24-1.43 7.86.42 2.74 1.94 5.49 2 8.23.19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMouse, "Mouse"))]
 pub static LuMouse: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMouse",
+    label: "Mouse",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14592,8 +19265,14 @@ pub static LuMouse: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 6v4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMousePointer, "Mouse Pointer"))]
 pub static LuMousePointer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMousePointer",
+    label: "Mouse Pointer",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14609,8 +19288,14 @@ pub static LuMousePointer: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m13 13 6 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMousePointer2, "Mouse Pointer 2"))]
 pub static LuMousePointer2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMousePointer2",
+    label: "Mouse Pointer 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14625,8 +19310,14 @@ pub static LuMousePointer2: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="m4 4 7.07 17 2.51-7.39L21 11.07z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMousePointerClick, "Mouse Pointer Click"))]
 pub static LuMousePointerClick: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMousePointerClick",
+    label: "Mouse Pointer Click",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14643,8 +19334,14 @@ pub static LuMousePointerClick: &icondata_core::IconData = &icondata_core::IconD
 <path d="m7.188 2.239.777 2.897M5.136 7.965l-2.898-.777M13.95 4.05l-2.122 2.122m-5.657 5.656-2.12 2.122" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMove, "Move"))]
 pub static LuMove: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMove",
+    label: "Move",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14664,8 +19361,14 @@ pub static LuMove: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMove3d, "Move 3 D"))]
 pub static LuMove3d: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMove3d",
+    label: "Move 3 D",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14683,8 +19386,14 @@ pub static LuMove3d: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m18 16 3 3-3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoveDiagonal, "Move Diagonal"))]
 pub static LuMoveDiagonal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoveDiagonal",
+    label: "Move Diagonal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14701,8 +19410,14 @@ pub static LuMoveDiagonal: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="19" x2="5" y1="5" y2="19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoveDiagonal2, "Move Diagonal 2"))]
 pub static LuMoveDiagonal2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoveDiagonal2",
+    label: "Move Diagonal 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14719,8 +19434,14 @@ pub static LuMoveDiagonal2: &icondata_core::IconData = &icondata_core::IconData
 <line x1="5" x2="19" y1="5" y2="19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoveDown, "Move Down"))]
 pub static LuMoveDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoveDown",
+    label: "Move Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14736,8 +19457,14 @@ pub static LuMoveDown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 2V22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoveDownLeft, "Move Down Left"))]
 pub static LuMoveDownLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoveDownLeft",
+    label: "Move Down Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14753,8 +19480,14 @@ pub static LuMoveDownLeft: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 5L5 19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoveDownRight, "Move Down Right"))]
 pub static LuMoveDownRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoveDownRight",
+    label: "Move Down Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14770,8 +19503,14 @@ pub static LuMoveDownRight: &icondata_core::IconData = &icondata_core::IconData
 <path d="M5 5L19 19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoveHorizontal, "Move Horizontal"))]
 pub static LuMoveHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoveHorizontal",
+    label: "Move Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14788,8 +19527,14 @@ pub static LuMoveHorizontal: &icondata_core::IconData = &icondata_core::IconData
 <line x1="2" x2="22" y1="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoveLeft, "Move Left"))]
 pub static LuMoveLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoveLeft",
+    label: "Move Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14805,8 +19550,14 @@ pub static LuMoveLeft: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2 12H22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoveRight, "Move Right"))]
 pub static LuMoveRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoveRight",
+    label: "Move Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14822,8 +19573,14 @@ pub static LuMoveRight: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2 12H22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoveUp, "Move Up"))]
 pub static LuMoveUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoveUp",
+    label: "Move Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14839,8 +19596,14 @@ pub static LuMoveUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 2V22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoveUpLeft, "Move Up Left"))]
 pub static LuMoveUpLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoveUpLeft",
+    label: "Move Up Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14856,8 +19619,14 @@ pub static LuMoveUpLeft: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 5L19 19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoveUpRight, "Move Up Right"))]
 pub static LuMoveUpRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoveUpRight",
+    label: "Move Up Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14873,8 +19642,14 @@ pub static LuMoveUpRight: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 5L5 19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMoveVertical, "Move Vertical"))]
 pub static LuMoveVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMoveVertical",
+    label: "Move Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14891,8 +19666,14 @@ pub static LuMoveVertical: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMusic, "Music"))]
 pub static LuMusic: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMusic",
+    label: "Music",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14909,8 +19690,14 @@ pub static LuMusic: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="18" cy="16" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMusic2, "Music 2"))]
 pub static LuMusic2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMusic2",
+    label: "Music 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14926,8 +19713,14 @@ pub static LuMusic2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 18V2l7 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMusic3, "Music 3"))]
 pub static LuMusic3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMusic3",
+    label: "Music 3",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14943,8 +19736,14 @@ pub static LuMusic3: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 18V2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuMusic4, "Music 4"))]
 pub static LuMusic4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuMusic4",
+    label: "Music 4",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14962,8 +19761,14 @@ pub static LuMusic4: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="18" cy="16" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuNavigation, "Navigation"))]
 pub static LuNavigation: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuNavigation",
+    label: "Navigation",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14978,8 +19783,14 @@ pub static LuNavigation: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="3 11 22 2 13 21 11 13 3 11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuNavigation2, "Navigation 2"))]
 pub static LuNavigation2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuNavigation2",
+    label: "Navigation 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -14994,8 +19805,14 @@ pub static LuNavigation2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="12 2 19 21 12 17 5 21 12 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuNavigation2Off, "Navigation 2 Off"))]
 pub static LuNavigation2Off: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuNavigation2Off",
+    label: "Navigation 2 Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15012,8 +19829,14 @@ pub static LuNavigation2Off: &icondata_core::IconData = &icondata_core::IconData
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuNavigationOff, "Navigation Off"))]
 pub static LuNavigationOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuNavigationOff",
+    label: "Navigation Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15030,8 +19853,14 @@ pub static LuNavigationOff: &icondata_core::IconData = &icondata_core::IconData
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuNetwork, "Network"))]
 pub static LuNetwork: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuNetwork",
+    label: "Network",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15050,8 +19879,14 @@ pub static LuNetwork: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 12V8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuNewspaper, "Newspaper"))]
 pub static LuNewspaper: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuNewspaper",
+    label: "Newspaper",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15069,8 +19904,14 @@ pub static LuNewspaper: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 6h8v4h-8V6Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuNfc, "NFC"))]
 pub static LuNfc: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuNfc",
+    label: "NFC",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15088,8 +19929,14 @@ pub static LuNfc: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16.37 2a20.16 20.16 0 0 1 0 20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuNut, "Nut"))]
 pub static LuNut: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuNut",
+    label: "Nut",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15106,8 +19953,14 @@ pub static LuNut: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 4C8 4 4.5 6 4 8c-.243.97-.919 1.952-2 3 1.31-.082 1.972-.29 3-1 .54.92.982 1.356 2 2 1.452-.647 1.954-1.098 2.5-2 .595.995 1.151 1.427 2.5 2 1.31-.621 1.862-1.058 2.5-2 .629.977 1.162 1.423 2.5 2 1.209-.548 1.68-.967 2-2 1.032.916 1.683 1.157 3 1-1.297-1.036-1.758-2.03-2-3-.5-2-4-4-8-4Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuNutOff, "Nut Off"))]
 pub static LuNutOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuNutOff",
+    label: "Nut Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15126,8 +19979,14 @@ pub static LuNutOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuOctagon, "Octagon"))]
 pub static LuOctagon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuOctagon",
+    label: "Octagon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15142,8 +20001,14 @@ pub static LuOctagon: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="7.86 2 16.14 2 22 7.86 22 16.14 16.14 22 7.86 22 2 16.14 2 7.86 7.86 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuOption, "Option"))]
 pub static LuOption: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuOption",
+    label: "Option",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15159,8 +20024,14 @@ pub static LuOption: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 3h7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuOrbit, "Orbit"))]
 pub static LuOrbit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuOrbit",
+    label: "Orbit",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15179,8 +20050,14 @@ pub static LuOrbit: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13.5 2.1a10 10 0 0 0-9.841 15.416" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuOutdent, "Outdent"))]
 pub static LuOutdent: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuOutdent",
+    label: "Outdent",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15198,8 +20075,14 @@ pub static LuOutdent: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="21" x2="11" y1="18" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPackage, "Package"))]
 pub static LuPackage: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPackage",
+    label: "Package",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15217,8 +20100,14 @@ pub static LuPackage: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="22" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPackage2, "Package 2"))]
 pub static LuPackage2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPackage2",
+    label: "Package 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15235,8 +20124,14 @@ pub static LuPackage2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 3v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPackageCheck, "Package Check"))]
 pub static LuPackageCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPackageCheck",
+    label: "Package Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15255,8 +20150,14 @@ pub static LuPackageCheck: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="22" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPackageMinus, "Package Minus"))]
 pub static LuPackageMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPackageMinus",
+    label: "Package Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15275,8 +20176,14 @@ pub static LuPackageMinus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="22" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPackageOpen, "Package Open"))]
 pub static LuPackageOpen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPackageOpen",
+    label: "Package Open",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15294,8 +20201,14 @@ pub static LuPackageOpen: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 13.5v3.37a2.06 2.06 0 0 1-1.11 1.83l-6 3.08a1.93 1.93 0 0 1-1.78 0l-6-3.08A2.06 2.06 0 0 1 4 16.87V13.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPackagePlus, "Package Plus"))]
 pub static LuPackagePlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPackagePlus",
+    label: "Package Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15315,8 +20228,14 @@ pub static LuPackagePlus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="22" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPackageSearch, "Package Search"))]
 pub static LuPackageSearch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPackageSearch",
+    label: "Package Search",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15336,8 +20255,14 @@ pub static LuPackageSearch: &icondata_core::IconData = &icondata_core::IconData
 <path d="M20.27 17.27 22 19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPackageX, "Package X"))]
 pub static LuPackageX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPackageX",
+    label: "Package X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15356,8 +20281,14 @@ pub static LuPackageX: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m17 13 5 5m-5 0 5-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPaintBucket, "Paint Bucket"))]
 pub static LuPaintBucket: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPaintBucket",
+    label: "Paint Bucket",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15375,8 +20306,14 @@ pub static LuPaintBucket: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 20a2 2 0 1 1-4 0c0-1.6 1.7-2.4 2-4 .3 1.6 2 2.4 2 4Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPaintbrush, "Paintbrush"))]
 pub static LuPaintbrush: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPaintbrush",
+    label: "Paintbrush",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15393,8 +20330,14 @@ pub static LuPaintbrush: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.5 17.5 4.5 15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPaintbrush2, "Paintbrush 2"))]
 pub static LuPaintbrush2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPaintbrush2",
+    label: "Paintbrush 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15412,8 +20355,14 @@ pub static LuPaintbrush2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 2v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPalette, "Palette"))]
 pub static LuPalette: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPalette",
+    label: "Palette",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15432,8 +20381,14 @@ pub static LuPalette: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 2C6.5 2 2 6.5 2 12s4.5 10 10 10c.926 0 1.648-.746 1.648-1.688 0-.437-.18-.835-.437-1.125-.29-.289-.438-.652-.438-1.125a1.64 1.64 0 0 1 1.668-1.668h1.996c3.051 0 5.555-2.503 5.555-5.554C21.965 6.012 17.461 2 12 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPalmtree, "Palmtree"))]
 pub static LuPalmtree: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPalmtree",
+    label: "Palmtree",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15451,8 +20406,14 @@ pub static LuPalmtree: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11 15.5c.5 2.5-.17 4.5-1 6.5h4c2-5.5-.5-12-1-14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelBottom, "Panel Bottom"))]
 pub static LuPanelBottom: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelBottom",
+    label: "Panel Bottom",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15468,8 +20429,14 @@ pub static LuPanelBottom: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" x2="21" y1="15" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelBottomClose, "Panel Bottom Close"))]
 pub static LuPanelBottomClose: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelBottomClose",
+    label: "Panel Bottom Close",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15486,8 +20453,14 @@ pub static LuPanelBottomClose: &icondata_core::IconData = &icondata_core::IconDa
 <path d="m15 8-3 3-3-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelBottomInactive, "Panel Bottom Inactive"))]
 pub static LuPanelBottomInactive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelBottomInactive",
+    label: "Panel Bottom Inactive",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15506,8 +20479,14 @@ pub static LuPanelBottomInactive: &icondata_core::IconData = &icondata_core::Ico
 <path d="M9 15h1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelBottomOpen, "Panel Bottom Open"))]
 pub static LuPanelBottomOpen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelBottomOpen",
+    label: "Panel Bottom Open",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15524,8 +20503,14 @@ pub static LuPanelBottomOpen: &icondata_core::IconData = &icondata_core::IconDat
 <path d="m9 10 3-3 3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelLeft, "Panel Left"))]
 pub static LuPanelLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelLeft",
+    label: "Panel Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15541,8 +20526,14 @@ pub static LuPanelLeft: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" x2="9" y1="3" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelLeftClose, "Panel Left Close"))]
 pub static LuPanelLeftClose: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelLeftClose",
+    label: "Panel Left Close",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15559,8 +20550,14 @@ pub static LuPanelLeftClose: &icondata_core::IconData = &icondata_core::IconData
 <path d="m16 15-3-3 3-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelLeftInactive, "Panel Left Inactive"))]
 pub static LuPanelLeftInactive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelLeftInactive",
+    label: "Panel Left Inactive",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15579,8 +20576,14 @@ pub static LuPanelLeftInactive: &icondata_core::IconData = &icondata_core::IconD
 <path d="M9 9v1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelLeftOpen, "Panel Left Open"))]
 pub static LuPanelLeftOpen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelLeftOpen",
+    label: "Panel Left Open",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15597,8 +20600,14 @@ pub static LuPanelLeftOpen: &icondata_core::IconData = &icondata_core::IconData
 <path d="m14 9 3 3-3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelRight, "Panel Right"))]
 pub static LuPanelRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelRight",
+    label: "Panel Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15614,8 +20623,14 @@ pub static LuPanelRight: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" x2="15" y1="3" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelRightClose, "Panel Right Close"))]
 pub static LuPanelRightClose: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelRightClose",
+    label: "Panel Right Close",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15632,8 +20647,14 @@ pub static LuPanelRightClose: &icondata_core::IconData = &icondata_core::IconDat
 <path d="m8 9 3 3-3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelRightInactive, "Panel Right Inactive"))]
 pub static LuPanelRightInactive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelRightInactive",
+    label: "Panel Right Inactive",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15652,8 +20673,14 @@ pub static LuPanelRightInactive: &icondata_core::IconData = &icondata_core::Icon
 <path d="M15 9v1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelRightOpen, "Panel Right Open"))]
 pub static LuPanelRightOpen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelRightOpen",
+    label: "Panel Right Open",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15670,8 +20697,14 @@ pub static LuPanelRightOpen: &icondata_core::IconData = &icondata_core::IconData
 <path d="m10 15-3-3 3-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelTop, "Panel Top"))]
 pub static LuPanelTop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelTop",
+    label: "Panel Top",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15687,8 +20720,14 @@ pub static LuPanelTop: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" x2="21" y1="9" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelTopClose, "Panel Top Close"))]
 pub static LuPanelTopClose: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelTopClose",
+    label: "Panel Top Close",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15705,8 +20744,14 @@ pub static LuPanelTopClose: &icondata_core::IconData = &icondata_core::IconData
 <path d="m9 16 3-3 3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelTopInactive, "Panel Top Inactive"))]
 pub static LuPanelTopInactive: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelTopInactive",
+    label: "Panel Top Inactive",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15725,8 +20770,14 @@ pub static LuPanelTopInactive: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M9 9h1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPanelTopOpen, "Panel Top Open"))]
 pub static LuPanelTopOpen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPanelTopOpen",
+    label: "Panel Top Open",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15743,8 +20794,14 @@ pub static LuPanelTopOpen: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m15 14-3 3-3-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPaperclip, "Paperclip"))]
 pub static LuPaperclip: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPaperclip",
+    label: "Paperclip",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15759,8 +20816,14 @@ pub static LuPaperclip: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m21.44 11.05-9.19 9.19a6 6 0 0 1-8.49-8.49l8.57-8.57A4 4 0 1 1 18 8.84l-8.59 8.57a2 2 0 0 1-2.83-2.83l8.49-8.48" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuParentheses, "Parentheses"))]
 pub static LuParentheses: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuParentheses",
+    label: "Parentheses",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15776,8 +20839,14 @@ pub static LuParentheses: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 3s4 3 4 9-4 9-4 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuParkingCircle, "Parking Circle"))]
 pub static LuParkingCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuParkingCircle",
+    label: "Parking Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15793,8 +20862,14 @@ pub static LuParkingCircle: &icondata_core::IconData = &icondata_core::IconData
 <path d="M9 17V7h4a3 3 0 0 1 0 6H9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuParkingCircleOff, "Parking Circle Off"))]
 pub static LuParkingCircleOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuParkingCircleOff",
+    label: "Parking Circle Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15812,8 +20887,14 @@ pub static LuParkingCircleOff: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M9 17v-2.34" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuParkingMeter, "Parking Meter"))]
 pub static LuParkingMeter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuParkingMeter",
+    label: "Parking Meter",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15832,8 +20913,14 @@ pub static LuParkingMeter: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 19v3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuParkingSquare, "Parking Square"))]
 pub static LuParkingSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuParkingSquare",
+    label: "Parking Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15849,8 +20936,14 @@ pub static LuParkingSquare: &icondata_core::IconData = &icondata_core::IconData
 <path d="M9 17V7h4a3 3 0 0 1 0 6H9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuParkingSquareOff, "Parking Square Off"))]
 pub static LuParkingSquareOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuParkingSquareOff",
+    label: "Parking Square Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15869,8 +20962,14 @@ pub static LuParkingSquareOff: &icondata_core::IconData = &icondata_core::IconDa
 <path d="M9 17v-2.3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPartyPopper, "Party Popper"))]
 pub static LuPartyPopper: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPartyPopper",
+    label: "Party Popper",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15893,8 +20992,14 @@ pub static LuPartyPopper: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11 13c1.93 1.93 2.83 4.17 2 5-.83.83-3.07-.07-5-2-1.93-1.93-2.83-4.17-2-5 .83-.83 3.07.07 5 2Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPause, "Pause"))]
 pub static LuPause: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPause",
+    label: "Pause",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15910,8 +21015,14 @@ pub static LuPause: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="4" height="16" x="14" y="4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPauseCircle, "Pause Circle"))]
 pub static LuPauseCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPauseCircle",
+    label: "Pause Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15928,8 +21039,14 @@ pub static LuPauseCircle: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="14" x2="14" y1="15" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPauseOctagon, "Pause Octagon"))]
 pub static LuPauseOctagon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPauseOctagon",
+    label: "Pause Octagon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15946,8 +21063,14 @@ pub static LuPauseOctagon: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7.714 2h8.572L22 7.714v8.572L16.286 22H7.714L2 16.286V7.714L7.714 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPawPrint, "Paw Print"))]
 pub static LuPawPrint: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPawPrint",
+    label: "Paw Print",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15965,8 +21088,14 @@ pub static LuPawPrint: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 10a5 5 0 0 1 5 5v3.5a3.5 3.5 0 0 1-6.84 1.045Q6.52 17.48 4.46 16.84A3.5 3.5 0 0 1 5.5 10Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPcCase, "Pc Case"))]
 pub static LuPcCase: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPcCase",
+    label: "Pc Case",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -15984,8 +21113,14 @@ pub static LuPcCase: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 10h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPen, "Pen"))]
 pub static LuPen: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPen",
+    label: "Pen",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16000,8 +21135,14 @@ pub static LuPen: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M17 3a2.85 2.83 0 1 1 4 4L7.5 20.5 2 22l1.5-5.5Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPenLine, "Pen Line"))]
 pub static LuPenLine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPenLine",
+    label: "Pen Line",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16017,8 +21158,14 @@ pub static LuPenLine: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16.5 3.5a2.12 2.12 0 0 1 3 3L7 19l-4 1 1-4Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPenSquare, "Pen Square"))]
 pub static LuPenSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPenSquare",
+    label: "Pen Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16034,8 +21181,14 @@ pub static LuPenSquare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18.5 2.5a2.12 2.12 0 0 1 3 3L12 15l-4 1 1-4Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPenTool, "Pen Tool"))]
 pub static LuPenTool: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPenTool",
+    label: "Pen Tool",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16053,8 +21206,14 @@ pub static LuPenTool: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="11" cy="11" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPencil, "Pencil"))]
 pub static LuPencil: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPencil",
+    label: "Pencil",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16070,8 +21229,14 @@ pub static LuPencil: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m15 5 4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPencilLine, "Pencil Line"))]
 pub static LuPencilLine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPencilLine",
+    label: "Pencil Line",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16088,8 +21253,14 @@ pub static LuPencilLine: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m15 5 3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPencilRuler, "Pencil Ruler"))]
 pub static LuPencilRuler: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPencilRuler",
+    label: "Pencil Ruler",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16109,8 +21280,14 @@ pub static LuPencilRuler: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m17 11 4.3 4.3c.94.94.94 2.46 0 3.4l-2.6 2.6c-.94.94-2.46.94-3.4 0L11 17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPercent, "Percent"))]
 pub static LuPercent: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPercent",
+    label: "Percent",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16127,8 +21304,14 @@ pub static LuPercent: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="17.5" cy="17.5" r="2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPersonStanding, "Person Standing"))]
 pub static LuPersonStanding: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPersonStanding",
+    label: "Person Standing",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16146,8 +21329,14 @@ pub static LuPersonStanding: &icondata_core::IconData = &icondata_core::IconData
 <path d="M12 10v4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPhone, "Phone"))]
 pub static LuPhone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPhone",
+    label: "Phone",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16162,8 +21351,14 @@ pub static LuPhone: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M22 16.92v3a2 2 0 0 1-2.18 2 19.79 19.79 0 0 1-8.63-3.07 19.5 19.5 0 0 1-6-6 19.79 19.79 0 0 1-3.07-8.67A2 2 0 0 1 4.11 2h3a2 2 0 0 1 2 1.72 12.84 12.84 0 0 0 .7 2.81 2 2 0 0 1-.45 2.11L8.09 9.91a16 16 0 0 0 6 6l1.27-1.27a2 2 0 0 1 2.11-.45 12.84 12.84 0 0 0 2.81.7A2 2 0 0 1 22 16.92z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPhoneCall, "Phone Call"))]
 pub static LuPhoneCall: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPhoneCall",
+    label: "Phone Call",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16180,8 +21375,14 @@ pub static LuPhoneCall: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.05 6A5 5 0 0 1 18 10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPhoneForwarded, "Phone Forwarded"))]
 pub static LuPhoneForwarded: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPhoneForwarded",
+    label: "Phone Forwarded",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16198,8 +21399,14 @@ pub static LuPhoneForwarded: &icondata_core::IconData = &icondata_core::IconData
 <path d="M22 16.92v3a2 2 0 0 1-2.18 2 19.79 19.79 0 0 1-8.63-3.07 19.5 19.5 0 0 1-6-6 19.79 19.79 0 0 1-3.07-8.67A2 2 0 0 1 4.11 2h3a2 2 0 0 1 2 1.72 12.84 12.84 0 0 0 .7 2.81 2 2 0 0 1-.45 2.11L8.09 9.91a16 16 0 0 0 6 6l1.27-1.27a2 2 0 0 1 2.11-.45 12.84 12.84 0 0 0 2.81.7A2 2 0 0 1 22 16.92z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPhoneIncoming, "Phone Incoming"))]
 pub static LuPhoneIncoming: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPhoneIncoming",
+    label: "Phone Incoming",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16216,8 +21423,14 @@ pub static LuPhoneIncoming: &icondata_core::IconData = &icondata_core::IconData
 <path d="M22 16.92v3a2 2 0 0 1-2.18 2 19.79 19.79 0 0 1-8.63-3.07 19.5 19.5 0 0 1-6-6 19.79 19.79 0 0 1-3.07-8.67A2 2 0 0 1 4.11 2h3a2 2 0 0 1 2 1.72 12.84 12.84 0 0 0 .7 2.81 2 2 0 0 1-.45 2.11L8.09 9.91a16 16 0 0 0 6 6l1.27-1.27a2 2 0 0 1 2.11-.45 12.84 12.84 0 0 0 2.81.7A2 2 0 0 1 22 16.92z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPhoneMissed, "Phone Missed"))]
 pub static LuPhoneMissed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPhoneMissed",
+    label: "Phone Missed",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16234,8 +21447,14 @@ pub static LuPhoneMissed: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 16.92v3a2 2 0 0 1-2.18 2 19.79 19.79 0 0 1-8.63-3.07 19.5 19.5 0 0 1-6-6 19.79 19.79 0 0 1-3.07-8.67A2 2 0 0 1 4.11 2h3a2 2 0 0 1 2 1.72 12.84 12.84 0 0 0 .7 2.81 2 2 0 0 1-.45 2.11L8.09 9.91a16 16 0 0 0 6 6l1.27-1.27a2 2 0 0 1 2.11-.45 12.84 12.84 0 0 0 2.81.7A2 2 0 0 1 22 16.92z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPhoneOff, "Phone Off"))]
 pub static LuPhoneOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPhoneOff",
+    label: "Phone Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16251,8 +21470,14 @@ pub static LuPhoneOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="22" x2="2" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPhoneOutgoing, "Phone Outgoing"))]
 pub static LuPhoneOutgoing: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPhoneOutgoing",
+    label: "Phone Outgoing",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16269,8 +21494,14 @@ pub static LuPhoneOutgoing: &icondata_core::IconData = &icondata_core::IconData
 <path d="M22 16.92v3a2 2 0 0 1-2.18 2 19.79 19.79 0 0 1-8.63-3.07 19.5 19.5 0 0 1-6-6 19.79 19.79 0 0 1-3.07-8.67A2 2 0 0 1 4.11 2h3a2 2 0 0 1 2 1.72 12.84 12.84 0 0 0 .7 2.81 2 2 0 0 1-.45 2.11L8.09 9.91a16 16 0 0 0 6 6l1.27-1.27a2 2 0 0 1 2.11-.45 12.84 12.84 0 0 0 2.81.7A2 2 0 0 1 22 16.92z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPi, "Pi"))]
 pub static LuPi: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPi",
+    label: "Pi",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16287,8 +21518,14 @@ pub static LuPi: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 20c-1.7 0-3-1.3-3-3V4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPiSquare, "Pi Square"))]
 pub static LuPiSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPiSquare",
+    label: "Pi Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16306,8 +21543,14 @@ pub static LuPiSquare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 17a2 2 0 0 1-2-2V7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPictureInPicture, "Picture In Picture"))]
 pub static LuPictureInPicture: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPictureInPicture",
+    label: "Picture In Picture",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16323,8 +21566,14 @@ pub static LuPictureInPicture: &icondata_core::IconData = &icondata_core::IconDa
 <rect width="10" height="7" x="12" y="13.5" ry="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPictureInPicture2, "Picture In Picture 2"))]
 pub static LuPictureInPicture2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPictureInPicture2",
+    label: "Picture In Picture 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16340,8 +21589,14 @@ pub static LuPictureInPicture2: &icondata_core::IconData = &icondata_core::IconD
 <rect width="10" height="7" x="12" y="13" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPieChart, "Pie Chart"))]
 pub static LuPieChart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPieChart",
+    label: "Pie Chart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16357,8 +21612,14 @@ pub static LuPieChart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 12A10 10 0 0 0 12 2v10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPiggyBank, "Piggy Bank"))]
 pub static LuPiggyBank: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPiggyBank",
+    label: "Piggy Bank",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16375,8 +21636,14 @@ pub static LuPiggyBank: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 11h0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPilcrow, "Pilcrow"))]
 pub static LuPilcrow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPilcrow",
+    label: "Pilcrow",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16393,8 +21660,14 @@ pub static LuPilcrow: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 4H9.5a4.5 4.5 0 0 0 0 9H13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPilcrowSquare, "Pilcrow Square"))]
 pub static LuPilcrowSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPilcrowSquare",
+    label: "Pilcrow Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16412,8 +21685,14 @@ pub static LuPilcrowSquare: &icondata_core::IconData = &icondata_core::IconData
 <path d="M16 7v10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPill, "Pill"))]
 pub static LuPill: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPill",
+    label: "Pill",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16429,8 +21708,14 @@ pub static LuPill: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8.5 8.5 7 7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPin, "Pin"))]
 pub static LuPin: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPin",
+    label: "Pin",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16446,8 +21731,14 @@ pub static LuPin: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 17h14v-1.76a2 2 0 0 0-1.11-1.79l-1.78-.9A2 2 0 0 1 15 10.76V6h1a2 2 0 0 0 0-4H8a2 2 0 0 0 0 4h1v4.76a2 2 0 0 1-1.11 1.79l-1.78.9A2 2 0 0 0 5 15.24Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPinOff, "Pin Off"))]
 pub static LuPinOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPinOff",
+    label: "Pin Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16465,8 +21756,14 @@ pub static LuPinOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 9.34V6h1a2 2 0 0 0 0-4H7.89" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPipette, "Pipette"))]
 pub static LuPipette: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPipette",
+    label: "Pipette",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16483,8 +21780,14 @@ pub static LuPipette: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m15 6 3.4-3.4a2.1 2.1 0 1 1 3 3L18 9l.4.4a2.1 2.1 0 1 1-3 3l-3.8-3.8a2.1 2.1 0 1 1 3-3l.4.4Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPizza, "Pizza"))]
 pub static LuPizza: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPizza",
+    label: "Pizza",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16503,8 +21806,14 @@ pub static LuPizza: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5.71 17.11a17.04 17.04 0 0 1 11.4-11.4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPlane, "Plane"))]
 pub static LuPlane: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPlane",
+    label: "Plane",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16519,8 +21828,14 @@ pub static LuPlane: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M17.8 19.2 16 11l3.5-3.5C21 6 21.5 4 21 3c-1-.5-3 0-4.5 1.5L13 8 4.8 6.2c-.5-.1-.9.1-1.1.5l-.3.5c-.2.5-.1 1 .3 1.3L9 12l-2 3H4l-1 1 3 2 2 3 1-1v-3l3-2 3.5 5.3c.3.4.8.5 1.3.3l.5-.2c.4-.3.6-.7.5-1.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPlaneLanding, "Plane Landing"))]
 pub static LuPlaneLanding: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPlaneLanding",
+    label: "Plane Landing",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16536,8 +21851,14 @@ pub static LuPlaneLanding: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3.77 10.77 2 9l2-4.5 1.1.55c.55.28.9.84.9 1.45s.35 1.17.9 1.45L8 8.5l3-6 1.05.53a2 2 0 0 1 1.09 1.52l.72 5.4a2 2 0 0 0 1.09 1.52l4.4 2.2c.42.22.78.55 1.01.96l.6 1.03c.49.88-.06 1.98-1.06 2.1l-1.18.15c-.47.06-.95-.02-1.37-.24L4.29 11.15a2 2 0 0 1-.52-.38Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPlaneTakeoff, "Plane Takeoff"))]
 pub static LuPlaneTakeoff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPlaneTakeoff",
+    label: "Plane Takeoff",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16553,8 +21874,14 @@ pub static LuPlaneTakeoff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6.36 17.4 4 17l-2-4 1.1-.55a2 2 0 0 1 1.8 0l.17.1a2 2 0 0 0 1.8 0L8 12 5 6l.9-.45a2 2 0 0 1 2.09.2l4.02 3a2 2 0 0 0 2.1.2l4.19-2.06a2.41 2.41 0 0 1 1.73-.17L21 7a1.4 1.4 0 0 1 .87 1.99l-.38.76c-.23.46-.6.84-1.07 1.08L7.58 17.2a2 2 0 0 1-1.22.18Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPlay, "Play"))]
 pub static LuPlay: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPlay",
+    label: "Play",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16569,8 +21896,14 @@ pub static LuPlay: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="5 3 19 12 5 21 5 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPlayCircle, "Play Circle"))]
 pub static LuPlayCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPlayCircle",
+    label: "Play Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16586,8 +21919,14 @@ pub static LuPlayCircle: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="10 8 16 12 10 16 10 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPlaySquare, "Play Square"))]
 pub static LuPlaySquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPlaySquare",
+    label: "Play Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16603,8 +21942,14 @@ pub static LuPlaySquare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 8 6 4-6 4Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPlug, "Plug"))]
 pub static LuPlug: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPlug",
+    label: "Plug",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16622,8 +21967,14 @@ pub static LuPlug: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 8v5a4 4 0 0 1-4 4h-4a4 4 0 0 1-4-4V8Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPlug2, "Plug 2"))]
 pub static LuPlug2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPlug2",
+    label: "Plug 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16642,8 +21993,14 @@ pub static LuPlug2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6 11V8h12v3a6 6 0 1 1-12 0v0Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPlugZap, "Plug Zap"))]
 pub static LuPlugZap: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPlugZap",
+    label: "Plug Zap",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16662,8 +22019,14 @@ pub static LuPlugZap: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m18 3-4 4h6l-4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPlugZap2, "Plug Zap 2"))]
 pub static LuPlugZap2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPlugZap2",
+    label: "Plug Zap 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16682,8 +22045,14 @@ pub static LuPlugZap2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 22v-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPlus, "Plus"))]
 pub static LuPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPlus",
+    label: "Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16699,8 +22068,14 @@ pub static LuPlus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 5v14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPlusCircle, "Plus Circle"))]
 pub static LuPlusCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPlusCircle",
+    label: "Plus Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16717,8 +22092,14 @@ pub static LuPlusCircle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 8v8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPlusSquare, "Plus Square"))]
 pub static LuPlusSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPlusSquare",
+    label: "Plus Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16735,8 +22116,14 @@ pub static LuPlusSquare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 8v8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPocket, "Pocket"))]
 pub static LuPocket: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPocket",
+    label: "Pocket",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16752,8 +22139,14 @@ pub static LuPocket: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="8 10 12 14 16 10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPocketKnife, "Pocket Knife"))]
 pub static LuPocketKnife: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPocketKnife",
+    label: "Pocket Knife",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16772,8 +22165,14 @@ pub static LuPocketKnife: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 11.66V22a4 4 0 0 0 4-4V6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPodcast, "Podcast"))]
 pub static LuPodcast: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPodcast",
+    label: "Podcast",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16791,8 +22190,14 @@ pub static LuPodcast: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17 18.5a9 9 0 1 0-10 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPointer, "Pointer"))]
 pub static LuPointer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPointer",
+    label: "Pointer",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16811,8 +22216,14 @@ pub static LuPointer: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 11a2 2 0 1 1 4 0v3a8 8 0 0 1-8 8h-2c-2.8 0-4.5-.86-5.99-2.34l-3.6-3.6a2 2 0 0 1 2.83-2.82L7 15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPopcorn, "Popcorn"))]
 pub static LuPopcorn: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPopcorn",
+    label: "Popcorn",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16830,8 +22241,14 @@ pub static LuPopcorn: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 8c.5 0 .9.4.8 1l-2.6 12c-.1.5-.7 1-1.2 1H7c-.6 0-1.1-.4-1.2-1L3.2 9c-.1-.6.3-1 .8-1Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPopsicle, "Popsicle"))]
 pub static LuPopsicle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPopsicle",
+    label: "Popsicle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16847,8 +22264,14 @@ pub static LuPopsicle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m22 22-5.5-5.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPoundSterling, "Pound Sterling"))]
 pub static LuPoundSterling: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPoundSterling",
+    label: "Pound Sterling",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16866,8 +22289,14 @@ pub static LuPoundSterling: &icondata_core::IconData = &icondata_core::IconData
 <path d="M6 13h10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPower, "Power"))]
 pub static LuPower: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPower",
+    label: "Power",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16883,8 +22312,14 @@ pub static LuPower: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="2" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPowerOff, "Power Off"))]
 pub static LuPowerOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPowerOff",
+    label: "Power Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16902,8 +22337,14 @@ pub static LuPowerOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2 2 20 20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPresentation, "Presentation"))]
 pub static LuPresentation: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPresentation",
+    label: "Presentation",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16920,8 +22361,14 @@ pub static LuPresentation: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m7 21 5-5 5 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPrinter, "Printer"))]
 pub static LuPrinter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPrinter",
+    label: "Printer",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16938,8 +22385,14 @@ pub static LuPrinter: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="12" height="8" x="6" y="14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuProjector, "Projector"))]
 pub static LuProjector: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuProjector",
+    label: "Projector",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16959,8 +22412,14 @@ pub static LuProjector: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 16h2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuPuzzle, "Puzzle"))]
 pub static LuPuzzle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuPuzzle",
+    label: "Puzzle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -16975,8 +22434,14 @@ pub static LuPuzzle: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M19.439 7.85c-.049.322.059.648.289.878l1.568 1.568c.47.47.706 1.087.706 1.704s-.235 1.233-.706 1.704l-1.611 1.611a.98.98 0 0 1-.837.276c-.47-.07-.802-.48-.968-.925a2.501 2.501 0 1 0-3.214 3.214c.446.166.855.497.925.968a.979.979 0 0 1-.276.837l-1.61 1.61a2.404 2.404 0 0 1-1.705.707 2.402 2.402 0 0 1-1.704-.706l-1.568-1.568a1.026 1.026 0 0 0-.877-.29c-.493.074-.84.504-1.02.968a2.5 2.5 0 1 1-3.237-3.237c.464-.18.894-.527.967-1.02a1.026 1.026 0 0 0-.289-.877l-1.568-1.568A2.402 2.402 0 0 1 1.998 12c0-.617.236-1.234.706-1.704L4.23 8.77c.24-.24.581-.353.917-.303.515.077.877.528 1.073 1.01a2.5 2.5 0 1 0 3.259-3.259c-.482-.196-.933-.558-1.01-1.073-.05-.336.062-.676.303-.917l1.525-1.525A2.402 2.402 0 0 1 12 1.998c.617 0 1.234.236 1.704.706l1.568 1.568c.23.23.556.338.877.29.493-.074.84-.504 1.02-.968a2.5 2.5 0 1 1 3.237 3.237c-.464.18-.894.527-.967 1.02Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuQrCode, "QR Code"))]
 pub static LuQrCode: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuQrCode",
+    label: "QR Code",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17002,8 +22467,14 @@ pub static LuQrCode: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 21v-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuQuote, "Quote"))]
 pub static LuQuote: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuQuote",
+    label: "Quote",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17019,8 +22490,14 @@ pub static LuQuote: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 21c3 0 7-1 7-8V5c0-1.25-.757-2.017-2-2h-4c-1.25 0-2 .75-2 1.972V11c0 1.25.75 2 2 2h.75c0 2.25.25 4-2.75 4v3c0 1 0 1 1 1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRabbit, "Rabbit"))]
 pub static LuRabbit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRabbit",
+    label: "Rabbit",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17039,8 +22516,14 @@ pub static LuRabbit: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 12h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRadar, "Radar"))]
 pub static LuRadar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRadar",
+    label: "Radar",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17062,8 +22545,14 @@ pub static LuRadar: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m13.41 10.59 5.66-5.66" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRadiation, "Radiation"))]
 pub static LuRadiation: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRadiation",
+    label: "Radiation",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17081,8 +22570,14 @@ pub static LuRadiation: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7.5 19.8c-.3.5-.1 1.1.4 1.3 2.6 1.2 5.6 1.2 8.2 0 .5-.2.7-.8.4-1.3-.5-.9-1.4-2.5-2.5-4.3-1.2.7-2.8.7-4 0-1.1 1.8-2 3.4-2.5 4.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRadio, "Radio"))]
 pub static LuRadio: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRadio",
+    label: "Radio",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17101,8 +22596,14 @@ pub static LuRadio: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19.1 4.9C23 8.8 23 15.1 19.1 19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRadioReceiver, "Radio Receiver"))]
 pub static LuRadioReceiver: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRadioReceiver",
+    label: "Radio Receiver",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17120,8 +22621,14 @@ pub static LuRadioReceiver: &icondata_core::IconData = &icondata_core::IconData
 <path d="M18 12h0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRadioTower, "Radio Tower"))]
 pub static LuRadioTower: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRadioTower",
+    label: "Radio Tower",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17142,8 +22649,14 @@ pub static LuRadioTower: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 22 4-11 4 11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRailSymbol, "Rail Symbol"))]
 pub static LuRailSymbol: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRailSymbol",
+    label: "Rail Symbol",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17160,8 +22673,14 @@ pub static LuRailSymbol: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14 20-5-5 6-6-5-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRainbow, "Rainbow"))]
 pub static LuRainbow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRainbow",
+    label: "Rainbow",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17178,8 +22697,14 @@ pub static LuRainbow: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 17a2 2 0 0 1 4 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRat, "Rat"))]
 pub static LuRat: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRat",
+    label: "Rat",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17198,8 +22723,14 @@ pub static LuRat: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 9h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRatio, "Ratio"))]
 pub static LuRatio: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRatio",
+    label: "Ratio",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17215,8 +22746,14 @@ pub static LuRatio: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="20" height="12" x="2" y="6" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuReceipt, "Receipt"))]
 pub static LuReceipt: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuReceipt",
+    label: "Receipt",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17233,8 +22770,14 @@ pub static LuReceipt: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 17V7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRectangleHorizontal, "Rectangle Horizontal"))]
 pub static LuRectangleHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRectangleHorizontal",
+    label: "Rectangle Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17249,8 +22792,14 @@ pub static LuRectangleHorizontal: &icondata_core::IconData = &icondata_core::Ico
     data: r###"<rect width="20" height="12" x="2" y="6" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRectangleVertical, "Rectangle Vertical"))]
 pub static LuRectangleVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRectangleVertical",
+    label: "Rectangle Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17265,8 +22814,14 @@ pub static LuRectangleVertical: &icondata_core::IconData = &icondata_core::IconD
     data: r###"<rect width="12" height="20" x="6" y="2" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRecycle, "Recycle"))]
 pub static LuRecycle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRecycle",
+    label: "Recycle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17286,8 +22841,14 @@ pub static LuRecycle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m13.378 9.633 4.096 1.098 1.097-4.096" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRedo, "Redo"))]
 pub static LuRedo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRedo",
+    label: "Redo",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17303,8 +22864,14 @@ pub static LuRedo: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 17a9 9 0 0 1 9-9 9 9 0 0 1 6 2.3l3 2.7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRedo2, "Redo 2"))]
 pub static LuRedo2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRedo2",
+    label: "Redo 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17320,8 +22887,14 @@ pub static LuRedo2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 9H9.5A5.5 5.5 0 0 0 4 14.5v0A5.5 5.5 0 0 0 9.5 20H13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRedoDot, "Redo Dot"))]
 pub static LuRedoDot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRedoDot",
+    label: "Redo Dot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17338,8 +22911,14 @@ pub static LuRedoDot: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 17a9 9 0 0 1 9-9 9 9 0 0 1 6 2.3l3 2.7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRefreshCcw, "Refresh Ccw"))]
 pub static LuRefreshCcw: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRefreshCcw",
+    label: "Refresh Ccw",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17357,8 +22936,14 @@ pub static LuRefreshCcw: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 16h5v5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRefreshCcwDot, "Refresh Ccw Dot"))]
 pub static LuRefreshCcwDot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRefreshCcwDot",
+    label: "Refresh Ccw Dot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17377,8 +22962,14 @@ pub static LuRefreshCcwDot: &icondata_core::IconData = &icondata_core::IconData
 <circle cx="12" cy="12" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRefreshCw, "Refresh Cw"))]
 pub static LuRefreshCw: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRefreshCw",
+    label: "Refresh Cw",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17396,8 +22987,14 @@ pub static LuRefreshCw: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 16H3v5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRefreshCwOff, "Refresh Cw Off"))]
 pub static LuRefreshCwOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRefreshCwOff",
+    label: "Refresh Cw Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17418,8 +23015,14 @@ pub static LuRefreshCwOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 22 2 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRefrigerator, "Refrigerator"))]
 pub static LuRefrigerator: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRefrigerator",
+    label: "Refrigerator",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17436,8 +23039,14 @@ pub static LuRefrigerator: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 7v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRegex, "Regex"))]
 pub static LuRegex: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRegex",
+    label: "Regex",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17455,8 +23064,14 @@ pub static LuRegex: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 17a2 2 0 0 0-2-2H5a2 2 0 0 0-2 2v2a2 2 0 0 0 2 2h2a2 2 0 0 0 2-2v-2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRemoveFormatting, "Remove Formatting"))]
 pub static LuRemoveFormatting: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRemoveFormatting",
+    label: "Remove Formatting",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17475,8 +23090,14 @@ pub static LuRemoveFormatting: &icondata_core::IconData = &icondata_core::IconDa
 <path d="m20 15-5 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRepeat, "Repeat"))]
 pub static LuRepeat: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRepeat",
+    label: "Repeat",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17494,8 +23115,14 @@ pub static LuRepeat: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 13v1a4 4 0 0 1-4 4H3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRepeat1, "Repeat 1"))]
 pub static LuRepeat1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRepeat1",
+    label: "Repeat 1",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17514,8 +23141,14 @@ pub static LuRepeat1: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11 10h1v4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRepeat2, "Repeat 2"))]
 pub static LuRepeat2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRepeat2",
+    label: "Repeat 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17533,8 +23166,14 @@ pub static LuRepeat2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11 6h6a2 2 0 0 1 2 2v10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuReplace, "Replace"))]
 pub static LuReplace: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuReplace",
+    label: "Replace",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17555,8 +23194,14 @@ pub static LuReplace: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="8" height="8" x="2" y="14" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuReplaceAll, "Replace All"))]
 pub static LuReplaceAll: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuReplaceAll",
+    label: "Replace All",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17579,8 +23224,14 @@ pub static LuReplaceAll: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 14c1.1 0 2 .9 2 2v4c0 1.1-.9 2-2 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuReply, "Reply"))]
 pub static LuReply: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuReply",
+    label: "Reply",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17596,8 +23247,14 @@ pub static LuReply: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 18v-2a4 4 0 0 0-4-4H4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuReplyAll, "Reply All"))]
 pub static LuReplyAll: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuReplyAll",
+    label: "Reply All",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17614,8 +23271,14 @@ pub static LuReplyAll: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 18v-2a4 4 0 0 0-4-4H7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRewind, "Rewind"))]
 pub static LuRewind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRewind",
+    label: "Rewind",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17631,8 +23294,14 @@ pub static LuRewind: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="22 19 13 12 22 5 22 19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRocket, "Rocket"))]
 pub static LuRocket: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRocket",
+    label: "Rocket",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17650,8 +23319,14 @@ pub static LuRocket: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 15v5s3.03-.55 4-2c1.08-1.62 0-5 0-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRockingChair, "Rocking Chair"))]
 pub static LuRockingChair: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRockingChair",
+    label: "Rocking Chair",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17669,8 +23344,14 @@ pub static LuRockingChair: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2.75 18a13 13 0 0 0 18.5 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRollerCoaster, "Roller Coaster"))]
 pub static LuRollerCoaster: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRollerCoaster",
+    label: "Roller Coaster",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17691,8 +23372,14 @@ pub static LuRollerCoaster: &icondata_core::IconData = &icondata_core::IconData
 <path d="M2 19V9a4 4 0 0 1 4-4c2 0 4 1.33 6 4s4 4 6 4a4 4 0 1 0-3-6.65" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRotate3d, "Rotate 3 D"))]
 pub static LuRotate3d: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRotate3d",
+    label: "Rotate 3 D",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17709,8 +23396,14 @@ pub static LuRotate3d: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 15.57c-1.804.885-4.274 1.43-7 1.43-5.523 0-10-2.239-10-5s4.477-5 10-5c4.838 0 8.873 1.718 9.8 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRotateCcw, "Rotate Ccw"))]
 pub static LuRotateCcw: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRotateCcw",
+    label: "Rotate Ccw",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17726,8 +23419,14 @@ pub static LuRotateCcw: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 3v5h5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRotateCw, "Rotate Cw"))]
 pub static LuRotateCw: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRotateCw",
+    label: "Rotate Cw",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17743,8 +23442,14 @@ pub static LuRotateCw: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 3v5h-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRouter, "Router"))]
 pub static LuRouter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRouter",
+    label: "Router",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17764,8 +23469,14 @@ pub static LuRouter: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20.66 4.34a8 8 0 0 0-11.31 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRows, "Rows"))]
 pub static LuRows: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRows",
+    label: "Rows",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17781,8 +23492,14 @@ pub static LuRows: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" x2="21" y1="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRss, "RSS"))]
 pub static LuRss: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRss",
+    label: "RSS",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17799,8 +23516,14 @@ pub static LuRss: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="5" cy="19" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRuler, "Ruler"))]
 pub static LuRuler: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRuler",
+    label: "Ruler",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17819,8 +23542,14 @@ pub static LuRuler: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m17.5 15.5 2-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuRussianRuble, "Russian Ruble"))]
 pub static LuRussianRuble: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuRussianRuble",
+    label: "Russian Ruble",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17839,8 +23568,14 @@ pub static LuRussianRuble: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 3h5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSailboat, "Sailboat"))]
 pub static LuSailboat: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSailboat",
+    label: "Sailboat",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17857,8 +23592,14 @@ pub static LuSailboat: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 2v16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSalad, "Salad"))]
 pub static LuSalad: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSalad",
+    label: "Salad",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17877,8 +23618,14 @@ pub static LuSalad: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10.9 7.25A3.99 3.99 0 0 0 4 10c0 .73.2 1.41.54 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSandwich, "Sandwich"))]
 pub static LuSandwich: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSandwich",
+    label: "Sandwich",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17896,8 +23643,14 @@ pub static LuSandwich: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12.97 19.77 7 15h12.5l-3.75 4.5a2 2 0 0 1-2.78.27Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSatellite, "Satellite"))]
 pub static LuSatellite: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSatellite",
+    label: "Satellite",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17916,8 +23669,14 @@ pub static LuSatellite: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 21a6 6 0 0 0-6-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSatelliteDish, "Satellite Dish"))]
 pub static LuSatelliteDish: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSatelliteDish",
+    label: "Satellite Dish",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17935,8 +23694,14 @@ pub static LuSatelliteDish: &icondata_core::IconData = &icondata_core::IconData
 <path d="M21 13A10 10 0 0 0 11 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSave, "Save"))]
 pub static LuSave: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSave",
+    label: "Save",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17953,8 +23718,14 @@ pub static LuSave: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="7 3 7 8 15 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSaveAll, "Save All"))]
 pub static LuSaveAll: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSaveAll",
+    label: "Save All",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17972,8 +23743,14 @@ pub static LuSaveAll: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 22H4a2 2 0 0 1-2-2V6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScale, "Scale"))]
 pub static LuScale: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScale",
+    label: "Scale",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -17992,8 +23769,14 @@ pub static LuScale: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 7h2c2 0 5-1 7-2 2 1 5 2 7 2h2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScale3d, "Scale 3 D"))]
 pub static LuScale3d: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScale3d",
+    label: "Scale 3 D",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18011,8 +23794,14 @@ pub static LuScale3d: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m5 19 6-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScaling, "Scaling"))]
 pub static LuScaling: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScaling",
+    label: "Scaling",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18030,8 +23819,14 @@ pub static LuScaling: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 15H9v-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScan, "Scan"))]
 pub static LuScan: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScan",
+    label: "Scan",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18049,8 +23844,14 @@ pub static LuScan: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 21H5a2 2 0 0 1-2-2v-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScanFace, "Scan Face"))]
 pub static LuScanFace: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScanFace",
+    label: "Scan Face",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18071,8 +23872,14 @@ pub static LuScanFace: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 9h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScanLine, "Scan Line"))]
 pub static LuScanLine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScanLine",
+    label: "Scan Line",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18091,8 +23898,14 @@ pub static LuScanLine: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="7" x2="17" y1="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScatterChart, "Scatter Chart"))]
 pub static LuScatterChart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScatterChart",
+    label: "Scatter Chart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18112,8 +23925,14 @@ pub static LuScatterChart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 3v18h18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSchool, "School"))]
 pub static LuSchool: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSchool",
+    label: "School",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18133,8 +23952,14 @@ pub static LuSchool: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="9" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSchool2, "School 2"))]
 pub static LuSchool2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSchool2",
+    label: "School 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18155,8 +23980,14 @@ pub static LuSchool2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 22v-5a2 2 0 0 0-2-2v0a2 2 0 0 0-2 2v5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScissors, "Scissors"))]
 pub static LuScissors: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScissors",
+    label: "Scissors",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18175,8 +24006,14 @@ pub static LuScissors: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.8 14.8 20 20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScissorsLineDashed, "Scissors Line Dashed"))]
 pub static LuScissorsLineDashed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScissorsLineDashed",
+    label: "Scissors Line Dashed",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18197,8 +24034,14 @@ pub static LuScissorsLineDashed: &icondata_core::IconData = &icondata_core::Icon
 <path d="M22 12h-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScissorsSquare, "Scissors Square"))]
 pub static LuScissorsSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScissorsSquare",
+    label: "Scissors Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18218,8 +24061,14 @@ pub static LuScissorsSquare: &icondata_core::IconData = &icondata_core::IconData
 <path d="m18 6-8.586 8.586" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScissorsSquareDashedBottom, "Scissors Square Dashed Bottom"))]
 pub static LuScissorsSquareDashedBottom: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScissorsSquareDashedBottom",
+    label: "Scissors Square Dashed Bottom",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18241,8 +24090,14 @@ pub static LuScissorsSquareDashedBottom: &icondata_core::IconData = &icondata_co
 <path d="m18 6-8.586 8.586" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScreenShare, "Screen Share"))]
 pub static LuScreenShare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScreenShare",
+    label: "Screen Share",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18261,8 +24116,14 @@ pub static LuScreenShare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17 3h5v5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScreenShareOff, "Screen Share Off"))]
 pub static LuScreenShareOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScreenShareOff",
+    label: "Screen Share Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18281,8 +24142,14 @@ pub static LuScreenShareOff: &icondata_core::IconData = &icondata_core::IconData
 <path d="m17 3 5 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScroll, "Scroll"))]
 pub static LuScroll: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScroll",
+    label: "Scroll",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18298,8 +24165,14 @@ pub static LuScroll: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 17V5a2 2 0 0 0-2-2H4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuScrollText, "Scroll Text"))]
 pub static LuScrollText: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuScrollText",
+    label: "Scroll Text",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18317,8 +24190,14 @@ pub static LuScrollText: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 12h-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSearch, "Search"))]
 pub static LuSearch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSearch",
+    label: "Search",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18334,8 +24213,14 @@ pub static LuSearch: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m21 21-4.3-4.3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSearchCheck, "Search Check"))]
 pub static LuSearchCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSearchCheck",
+    label: "Search Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18352,8 +24237,14 @@ pub static LuSearchCheck: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m21 21-4.3-4.3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSearchCode, "Search Code"))]
 pub static LuSearchCode: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSearchCode",
+    label: "Search Code",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18371,8 +24262,14 @@ pub static LuSearchCode: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m21 21-4.3-4.3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSearchSlash, "Search Slash"))]
 pub static LuSearchSlash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSearchSlash",
+    label: "Search Slash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18389,8 +24286,14 @@ pub static LuSearchSlash: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m21 21-4.3-4.3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSearchX, "Search X"))]
 pub static LuSearchX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSearchX",
+    label: "Search X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18408,8 +24311,14 @@ pub static LuSearchX: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m21 21-4.3-4.3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSend, "Send"))]
 pub static LuSend: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSend",
+    label: "Send",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18425,8 +24334,14 @@ pub static LuSend: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 2 11 13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSendHorizonal, "Send Horizonal"))]
 pub static LuSendHorizonal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSendHorizonal",
+    label: "Send Horizonal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18442,8 +24357,14 @@ pub static LuSendHorizonal: &icondata_core::IconData = &icondata_core::IconData
 <path d="M6 12h16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSendToBack, "Send To Back"))]
 pub static LuSendToBack: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSendToBack",
+    label: "Send To Back",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18461,8 +24382,14 @@ pub static LuSendToBack: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 7h1a2 2 0 0 1 2 2v1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSeparatorHorizontal, "Separator Horizontal"))]
 pub static LuSeparatorHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSeparatorHorizontal",
+    label: "Separator Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18479,8 +24406,14 @@ pub static LuSeparatorHorizontal: &icondata_core::IconData = &icondata_core::Ico
 <polyline points="16 16 12 20 8 16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSeparatorVertical, "Separator Vertical"))]
 pub static LuSeparatorVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSeparatorVertical",
+    label: "Separator Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18497,8 +24430,14 @@ pub static LuSeparatorVertical: &icondata_core::IconData = &icondata_core::IconD
 <polyline points="16 16 20 12 16 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuServer, "Server"))]
 pub static LuServer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuServer",
+    label: "Server",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18516,8 +24455,14 @@ pub static LuServer: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="6" x2="6.01" y1="18" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuServerCog, "Server Cog"))]
 pub static LuServerCog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuServerCog",
+    label: "Server Cog",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18544,8 +24489,14 @@ pub static LuServerCog: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9.88 9.88 9 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuServerCrash, "Server Crash"))]
 pub static LuServerCrash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuServerCrash",
+    label: "Server Crash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18564,8 +24515,14 @@ pub static LuServerCrash: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m13 6-4 6h6l-4 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuServerOff, "Server Off"))]
 pub static LuServerOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuServerOff",
+    label: "Server Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18585,8 +24542,14 @@ pub static LuServerOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m2 2 20 20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSettings, "Settings"))]
 pub static LuSettings: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSettings",
+    label: "Settings",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18602,8 +24565,14 @@ pub static LuSettings: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSettings2, "Settings 2"))]
 pub static LuSettings2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSettings2",
+    label: "Settings 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18621,8 +24590,14 @@ pub static LuSettings2: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="7" cy="7" r="3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShapes, "Shapes"))]
 pub static LuShapes: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShapes",
+    label: "Shapes",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18639,8 +24614,14 @@ pub static LuShapes: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="17.5" cy="17.5" r="3.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShare, "Share"))]
 pub static LuShare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShare",
+    label: "Share",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18657,8 +24638,14 @@ pub static LuShare: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="2" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShare2, "Share 2"))]
 pub static LuShare2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShare2",
+    label: "Share 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18677,8 +24664,14 @@ pub static LuShare2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15.41" x2="8.59" y1="6.51" y2="10.49" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSheet, "Sheet"))]
 pub static LuSheet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSheet",
+    label: "Sheet",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18697,8 +24690,14 @@ pub static LuSheet: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" x2="15" y1="9" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShell, "Shell"))]
 pub static LuShell: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShell",
+    label: "Shell",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18713,8 +24712,14 @@ pub static LuShell: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14 11a2 2 0 1 1-4 0 4 4 0 0 1 8 0 6 6 0 0 1-12 0 8 8 0 0 1 16 0 10 10 0 1 1-20 0 11.93 11.93 0 0 1 2.42-7.22 2 2 0 1 1 3.16 2.44" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShield, "Shield"))]
 pub static LuShield: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShield",
+    label: "Shield",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18729,8 +24734,14 @@ pub static LuShield: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 22s8-4 8-10V5l-8-3-8 3v7c0 6 8 10 8 10z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShieldAlert, "Shield Alert"))]
 pub static LuShieldAlert: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShieldAlert",
+    label: "Shield Alert",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18747,8 +24758,14 @@ pub static LuShieldAlert: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 16h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShieldCheck, "Shield Check"))]
 pub static LuShieldCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShieldCheck",
+    label: "Shield Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18764,8 +24781,14 @@ pub static LuShieldCheck: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 12 2 2 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShieldClose, "Shield Close"))]
 pub static LuShieldClose: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShieldClose",
+    label: "Shield Close",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18782,8 +24805,14 @@ pub static LuShieldClose: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="14.5" x2="9.5" y1="9" y2="14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShieldOff, "Shield Off"))]
 pub static LuShieldOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShieldOff",
+    label: "Shield Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18800,8 +24829,14 @@ pub static LuShieldOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShieldQuestion, "Shield Question"))]
 pub static LuShieldQuestion: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShieldQuestion",
+    label: "Shield Question",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18818,8 +24853,14 @@ pub static LuShieldQuestion: &icondata_core::IconData = &icondata_core::IconData
 <path d="M9.1 9a3 3 0 0 1 5.82 1c0 2-3 3-3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShip, "Ship"))]
 pub static LuShip: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShip",
+    label: "Ship",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18838,8 +24879,14 @@ pub static LuShip: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 2v3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShipWheel, "Ship Wheel"))]
 pub static LuShipWheel: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShipWheel",
+    label: "Ship Wheel",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18863,8 +24910,14 @@ pub static LuShipWheel: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="2.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShirt, "Shirt"))]
 pub static LuShirt: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShirt",
+    label: "Shirt",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18879,8 +24932,14 @@ pub static LuShirt: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M20.38 3.46 16 2a4 4 0 0 1-8 0L3.62 3.46a2 2 0 0 0-1.34 2.23l.58 3.47a1 1 0 0 0 .99.84H6v10c0 1.1.9 2 2 2h8a2 2 0 0 0 2-2V10h2.15a1 1 0 0 0 .99-.84l.58-3.47a2 2 0 0 0-1.34-2.23z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShoppingBag, "Shopping Bag"))]
 pub static LuShoppingBag: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShoppingBag",
+    label: "Shopping Bag",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18897,8 +24956,14 @@ pub static LuShoppingBag: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 10a4 4 0 0 1-8 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShoppingBasket, "Shopping Basket"))]
 pub static LuShoppingBasket: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShoppingBasket",
+    label: "Shopping Basket",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18919,8 +24984,14 @@ pub static LuShoppingBasket: &icondata_core::IconData = &icondata_core::IconData
 <path d="m15 11-1 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShoppingCart, "Shopping Cart"))]
 pub static LuShoppingCart: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShoppingCart",
+    label: "Shopping Cart",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18937,8 +25008,14 @@ pub static LuShoppingCart: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2.05 2.05h2l2.66 12.42a2 2 0 0 0 2 1.58h9.78a2 2 0 0 0 1.95-1.57l1.65-7.43H5.12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShovel, "Shovel"))]
 pub static LuShovel: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShovel",
+    label: "Shovel",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18955,8 +25032,14 @@ pub static LuShovel: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m17 2 5 5-.5.5a3.53 3.53 0 0 1-5 0s0 0 0 0a3.53 3.53 0 0 1 0-5L17 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShowerHead, "Shower Head"))]
 pub static LuShowerHead: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShowerHead",
+    label: "Shower Head",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18980,8 +25063,14 @@ pub static LuShowerHead: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 11v.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShrink, "Shrink"))]
 pub static LuShrink: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShrink",
+    label: "Shrink",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -18999,8 +25088,14 @@ pub static LuShrink: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 4.2V9m0 0H4.2M9 9 3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShrub, "Shrub"))]
 pub static LuShrub: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShrub",
+    label: "Shrub",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19017,8 +25112,14 @@ pub static LuShrub: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14 14-2 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuShuffle, "Shuffle"))]
 pub static LuShuffle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuShuffle",
+    label: "Shuffle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19037,8 +25138,14 @@ pub static LuShuffle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m18 14 4 4-4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSigma, "Sigma"))]
 pub static LuSigma: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSigma",
+    label: "Sigma",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19053,8 +25160,14 @@ pub static LuSigma: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M18 7V4H6l6 8-6 8h12v-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSigmaSquare, "Sigma Square"))]
 pub static LuSigmaSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSigmaSquare",
+    label: "Sigma Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19070,8 +25183,14 @@ pub static LuSigmaSquare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 8.9V7H8l4 5-4 5h8v-1.9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSignal, "Signal"))]
 pub static LuSignal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSignal",
+    label: "Signal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19090,8 +25209,14 @@ pub static LuSignal: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 4v16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSignalHigh, "Signal High"))]
 pub static LuSignalHigh: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSignalHigh",
+    label: "Signal High",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19109,8 +25234,14 @@ pub static LuSignalHigh: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17 20V8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSignalLow, "Signal Low"))]
 pub static LuSignalLow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSignalLow",
+    label: "Signal Low",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19126,8 +25257,14 @@ pub static LuSignalLow: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 20v-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSignalMedium, "Signal Medium"))]
 pub static LuSignalMedium: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSignalMedium",
+    label: "Signal Medium",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19144,8 +25281,14 @@ pub static LuSignalMedium: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 20v-8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSignalZero, "Signal Zero"))]
 pub static LuSignalZero: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSignalZero",
+    label: "Signal Zero",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19160,8 +25303,14 @@ pub static LuSignalZero: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M2 20h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSiren, "Siren"))]
 pub static LuSiren: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSiren",
+    label: "Siren",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19183,8 +25332,14 @@ pub static LuSiren: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 12v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSkipBack, "Skip Back"))]
 pub static LuSkipBack: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSkipBack",
+    label: "Skip Back",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19200,8 +25355,14 @@ pub static LuSkipBack: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="5" x2="5" y1="19" y2="5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSkipForward, "Skip Forward"))]
 pub static LuSkipForward: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSkipForward",
+    label: "Skip Forward",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19217,8 +25378,14 @@ pub static LuSkipForward: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="19" x2="19" y1="5" y2="19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSkull, "Skull"))]
 pub static LuSkull: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSkull",
+    label: "Skull",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19237,8 +25404,14 @@ pub static LuSkull: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 20a2 2 0 0 0 1.56-3.25 8 8 0 1 0-11.12 0A2 2 0 0 0 8 20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSlack, "Slack"))]
 pub static LuSlack: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSlack",
+    label: "Slack",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19260,8 +25433,14 @@ pub static LuSlack: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8.5 5H10V3.5A1.5 1.5 0 1 0 8.5 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSlice, "Slice"))]
 pub static LuSlice: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSlice",
+    label: "Slice",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19277,8 +25456,14 @@ pub static LuSlice: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18.37 3.63 8 14l3 3L21.37 6.63a2.12 2.12 0 1 0-3-3Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSliders, "Sliders"))]
 pub static LuSliders: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSliders",
+    label: "Sliders",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19301,8 +25486,14 @@ pub static LuSliders: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="18" x2="22" y1="16" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSlidersHorizontal, "Sliders Horizontal"))]
 pub static LuSlidersHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSlidersHorizontal",
+    label: "Sliders Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19325,8 +25516,14 @@ pub static LuSlidersHorizontal: &icondata_core::IconData = &icondata_core::IconD
 <line x1="16" x2="16" y1="18" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSmartphone, "Smartphone"))]
 pub static LuSmartphone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSmartphone",
+    label: "Smartphone",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19342,8 +25539,14 @@ pub static LuSmartphone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 18h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSmartphoneCharging, "Smartphone Charging"))]
 pub static LuSmartphoneCharging: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSmartphoneCharging",
+    label: "Smartphone Charging",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19359,8 +25562,14 @@ pub static LuSmartphoneCharging: &icondata_core::IconData = &icondata_core::Icon
 <path d="M12.667 8 10 12h4l-2.667 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSmartphoneNfc, "Smartphone NFC"))]
 pub static LuSmartphoneNfc: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSmartphoneNfc",
+    label: "Smartphone NFC",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19378,8 +25587,14 @@ pub static LuSmartphoneNfc: &icondata_core::IconData = &icondata_core::IconData
 <path d="M19.91 4.1a15.91 15.91 0 0 1 .01 15.8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSmile, "Smile"))]
 pub static LuSmile: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSmile",
+    label: "Smile",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19397,8 +25612,14 @@ pub static LuSmile: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="15" x2="15.01" y1="9" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSmilePlus, "Smile Plus"))]
 pub static LuSmilePlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSmilePlus",
+    label: "Smile Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19418,8 +25639,14 @@ pub static LuSmilePlus: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 2v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSnail, "Snail"))]
 pub static LuSnail: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSnail",
+    label: "Snail",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19438,8 +25665,14 @@ pub static LuSnail: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 3 20.9 5.2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSnowflake, "Snowflake"))]
 pub static LuSnowflake: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSnowflake",
+    label: "Snowflake",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19459,8 +25692,14 @@ pub static LuSnowflake: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m8 20 4-4 4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSofa, "Sofa"))]
 pub static LuSofa: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSofa",
+    label: "Sofa",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19479,8 +25718,14 @@ pub static LuSofa: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 4v9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSoup, "Soup"))]
 pub static LuSoup: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSoup",
+    label: "Soup",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19500,8 +25745,14 @@ pub static LuSoup: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6.25 3c.27.1.8.53.75 1.36-.06.83-.93 1.2-1 2.02-.05.78.34 1.24.74 1.62" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSpace, "Space"))]
 pub static LuSpace: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSpace",
+    label: "Space",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19516,8 +25767,14 @@ pub static LuSpace: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M22 17v1c0 .5-.5 1-1 1H3c-.5 0-1-.5-1-1v-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSpade, "Spade"))]
 pub static LuSpade: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSpade",
+    label: "Spade",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19533,8 +25790,14 @@ pub static LuSpade: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 18v4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSparkle, "Sparkle"))]
 pub static LuSparkle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSparkle",
+    label: "Sparkle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19549,8 +25812,14 @@ pub static LuSparkle: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m12 3-1.9 5.8a2 2 0 0 1-1.287 1.288L3 12l5.8 1.9a2 2 0 0 1 1.288 1.287L12 21l1.9-5.8a2 2 0 0 1 1.287-1.288L21 12l-5.8-1.9a2 2 0 0 1-1.288-1.287Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSparkles, "Sparkles"))]
 pub static LuSparkles: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSparkles",
+    label: "Sparkles",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19569,8 +25838,14 @@ pub static LuSparkles: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17 19h4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSpeaker, "Speaker"))]
 pub static LuSpeaker: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSpeaker",
+    label: "Speaker",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19587,8 +25862,14 @@ pub static LuSpeaker: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12.01" y1="6" y2="6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSpellCheck, "Spell Check"))]
 pub static LuSpellCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSpellCheck",
+    label: "Spell Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19605,8 +25886,14 @@ pub static LuSpellCheck: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m16 20 2 2 4-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSpellCheck2, "Spell Check 2"))]
 pub static LuSpellCheck2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSpellCheck2",
+    label: "Spell Check 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19623,8 +25910,14 @@ pub static LuSpellCheck2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 21c1.1 0 1.1-1 2.3-1s1.1 1 2.3 1c1.1 0 1.1-1 2.3-1 1.1 0 1.1 1 2.3 1 1.1 0 1.1-1 2.3-1 1.1 0 1.1 1 2.3 1 1.1 0 1.1-1 2.3-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSpline, "Spline"))]
 pub static LuSpline: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSpline",
+    label: "Spline",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19641,8 +25934,14 @@ pub static LuSpline: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M5 17A12 12 0 0 1 17 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSplit, "Split"))]
 pub static LuSplit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSplit",
+    label: "Split",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19660,8 +25959,14 @@ pub static LuSplit: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m15 9 6-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSplitSquareHorizontal, "Split Square Horizontal"))]
 pub static LuSplitSquareHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSplitSquareHorizontal",
+    label: "Split Square Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19678,8 +25983,14 @@ pub static LuSplitSquareHorizontal: &icondata_core::IconData = &icondata_core::I
 <line x1="12" x2="12" y1="4" y2="20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSplitSquareVertical, "Split Square Vertical"))]
 pub static LuSplitSquareVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSplitSquareVertical",
+    label: "Split Square Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19696,8 +26007,14 @@ pub static LuSplitSquareVertical: &icondata_core::IconData = &icondata_core::Ico
 <line x1="4" x2="20" y1="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSprayCan, "Spray Can"))]
 pub static LuSprayCan: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSprayCan",
+    label: "Spray Can",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19721,8 +26038,14 @@ pub static LuSprayCan: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m13 19 8-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSprout, "Sprout"))]
 pub static LuSprout: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSprout",
+    label: "Sprout",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19740,8 +26063,14 @@ pub static LuSprout: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.1 6a7 7 0 0 0-1.1 4c1.9-.1 3.3-.6 4.3-1.4 1-1 1.6-2.3 1.7-4.6-2.7.1-4 1-4.9 2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSquare, "Square"))]
 pub static LuSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSquare",
+    label: "Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19756,8 +26085,14 @@ pub static LuSquare: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<rect width="18" height="18" x="3" y="3" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSquareAsterisk, "Square Asterisk"))]
 pub static LuSquareAsterisk: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSquareAsterisk",
+    label: "Square Asterisk",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19775,8 +26110,14 @@ pub static LuSquareAsterisk: &icondata_core::IconData = &icondata_core::IconData
 <path d="m8.5 10 7 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSquareCode, "Square Code"))]
 pub static LuSquareCode: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSquareCode",
+    label: "Square Code",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19793,8 +26134,14 @@ pub static LuSquareCode: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14 14 2-2-2-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSquareDashedBottom, "Square Dashed Bottom"))]
 pub static LuSquareDashedBottom: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSquareDashedBottom",
+    label: "Square Dashed Bottom",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19811,8 +26158,14 @@ pub static LuSquareDashedBottom: &icondata_core::IconData = &icondata_core::Icon
 <path d="M14 21h1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSquareDashedBottomCode, "Square Dashed Bottom Code"))]
 pub static LuSquareDashedBottomCode: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSquareDashedBottomCode",
+    label: "Square Dashed Bottom Code",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19831,8 +26184,14 @@ pub static LuSquareDashedBottomCode: &icondata_core::IconData = &icondata_core::
 <path d="M14 21h1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSquareDot, "Square Dot"))]
 pub static LuSquareDot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSquareDot",
+    label: "Square Dot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19848,8 +26207,14 @@ pub static LuSquareDot: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSquareEqual, "Square Equal"))]
 pub static LuSquareEqual: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSquareEqual",
+    label: "Square Equal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19866,8 +26231,14 @@ pub static LuSquareEqual: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 14h10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSquareSlash, "Square Slash"))]
 pub static LuSquareSlash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSquareSlash",
+    label: "Square Slash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19883,8 +26254,14 @@ pub static LuSquareSlash: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" x2="15" y1="15" y2="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSquareStack, "Square Stack"))]
 pub static LuSquareStack: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSquareStack",
+    label: "Square Stack",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19901,8 +26278,14 @@ pub static LuSquareStack: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="8" height="8" x="14" y="14" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSquirrel, "Squirrel"))]
 pub static LuSquirrel: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSquirrel",
+    label: "Squirrel",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19920,8 +26303,14 @@ pub static LuSquirrel: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 13h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuStamp, "Stamp"))]
 pub static LuStamp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuStamp",
+    label: "Stamp",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19938,8 +26327,14 @@ pub static LuStamp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 13V8.5C14 7 15 7 15 5a3 3 0 0 0-3-3c-1.66 0-3 1-3 3s1 2 1 3.5V13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuStar, "Star"))]
 pub static LuStar: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuStar",
+    label: "Star",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19954,8 +26349,14 @@ pub static LuStar: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="12 2 15.09 8.26 22 9.27 17 14.14 18.18 21.02 12 17.77 5.82 21.02 7 14.14 2 9.27 8.91 8.26 12 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuStarHalf, "Star Half"))]
 pub static LuStarHalf: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuStarHalf",
+    label: "Star Half",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19970,8 +26371,14 @@ pub static LuStarHalf: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M12 17.8 5.8 21 7 14.1 2 9.3l7-1L12 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuStarOff, "Star Off"))]
 pub static LuStarOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuStarOff",
+    label: "Star Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -19988,8 +26395,14 @@ pub static LuStarOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuStepBack, "Step Back"))]
 pub static LuStepBack: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuStepBack",
+    label: "Step Back",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20005,8 +26418,14 @@ pub static LuStepBack: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="14,20 4,12 14,4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuStepForward, "Step Forward"))]
 pub static LuStepForward: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuStepForward",
+    label: "Step Forward",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20022,8 +26441,14 @@ pub static LuStepForward: &icondata_core::IconData = &icondata_core::IconData {
 <polygon points="10,4 20,12 10,20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuStethoscope, "Stethoscope"))]
 pub static LuStethoscope: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuStethoscope",
+    label: "Stethoscope",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20040,8 +26465,14 @@ pub static LuStethoscope: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="20" cy="10" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSticker, "Sticker"))]
 pub static LuSticker: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSticker",
+    label: "Sticker",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20060,8 +26491,14 @@ pub static LuSticker: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 13h0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuStickyNote, "Sticky Note"))]
 pub static LuStickyNote: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuStickyNote",
+    label: "Sticky Note",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20077,8 +26514,14 @@ pub static LuStickyNote: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 3v6h6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuStopCircle, "Stop Circle"))]
 pub static LuStopCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuStopCircle",
+    label: "Stop Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20094,8 +26537,14 @@ pub static LuStopCircle: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="6" height="6" x="9" y="9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuStore, "Store"))]
 pub static LuStore: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuStore",
+    label: "Store",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20114,8 +26563,14 @@ pub static LuStore: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 7v3a2 2 0 0 1-2 2v0a2.7 2.7 0 0 1-1.59-.63.7.7 0 0 0-.82 0A2.7 2.7 0 0 1 16 12a2.7 2.7 0 0 1-1.59-.63.7.7 0 0 0-.82 0A2.7 2.7 0 0 1 12 12a2.7 2.7 0 0 1-1.59-.63.7.7 0 0 0-.82 0A2.7 2.7 0 0 1 8 12a2.7 2.7 0 0 1-1.59-.63.7.7 0 0 0-.82 0A2.7 2.7 0 0 1 4 12v0a2 2 0 0 1-2-2V7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuStretchHorizontal, "Stretch Horizontal"))]
 pub static LuStretchHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuStretchHorizontal",
+    label: "Stretch Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20131,8 +26586,14 @@ pub static LuStretchHorizontal: &icondata_core::IconData = &icondata_core::IconD
 <rect width="20" height="6" x="2" y="14" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuStretchVertical, "Stretch Vertical"))]
 pub static LuStretchVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuStretchVertical",
+    label: "Stretch Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20148,8 +26609,14 @@ pub static LuStretchVertical: &icondata_core::IconData = &icondata_core::IconDat
 <rect width="6" height="20" x="14" y="2" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuStrikethrough, "Strikethrough"))]
 pub static LuStrikethrough: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuStrikethrough",
+    label: "Strikethrough",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20166,8 +26633,14 @@ pub static LuStrikethrough: &icondata_core::IconData = &icondata_core::IconData
 <line x1="4" x2="20" y1="12" y2="12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSubscript, "Subscript"))]
 pub static LuSubscript: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSubscript",
+    label: "Subscript",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20184,8 +26657,14 @@ pub static LuSubscript: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 19h-4c0-1.5.44-2 1.5-2.5S20 15.33 20 14c0-.47-.17-.93-.48-1.29a2.11 2.11 0 0 0-2.62-.44c-.42.24-.74.62-.9 1.07" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSubtitles, "Subtitles"))]
 pub static LuSubtitles: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSubtitles",
+    label: "Subtitles",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20204,8 +26683,14 @@ pub static LuSubtitles: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 15a2 2 0 0 1-2 2H7l-4 4V5a2 2 0 0 1 2-2h14a2 2 0 0 1 2 2v10Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSun, "Sun"))]
 pub static LuSun: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSun",
+    label: "Sun",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20228,8 +26713,14 @@ pub static LuSun: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m19.07 4.93-1.41 1.41" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSunDim, "Sun Dim"))]
 pub static LuSunDim: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSunDim",
+    label: "Sun Dim",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20252,8 +26743,14 @@ pub static LuSunDim: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M6.343 6.343h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSunMedium, "Sun Medium"))]
 pub static LuSunMedium: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSunMedium",
+    label: "Sun Medium",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20276,8 +26773,14 @@ pub static LuSunMedium: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m17.657 17.657.707.707" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSunMoon, "Sun Moon"))]
 pub static LuSunMoon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSunMoon",
+    label: "Sun Moon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20301,8 +26804,14 @@ pub static LuSunMoon: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m19.07 4.93-1.41 1.41" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSunSnow, "Sun Snow"))]
 pub static LuSunSnow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSunSnow",
+    label: "Sun Snow",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20327,8 +26836,14 @@ pub static LuSunSnow: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m21 15-3-3 3-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSunrise, "Sunrise"))]
 pub static LuSunrise: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSunrise",
+    label: "Sunrise",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20350,8 +26865,14 @@ pub static LuSunrise: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 18a4 4 0 0 0-8 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSunset, "Sunset"))]
 pub static LuSunset: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSunset",
+    label: "Sunset",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20373,8 +26894,14 @@ pub static LuSunset: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 18a4 4 0 0 0-8 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSuperscript, "Superscript"))]
 pub static LuSuperscript: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSuperscript",
+    label: "Superscript",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20391,8 +26918,14 @@ pub static LuSuperscript: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 12h-4c0-1.5.442-2 1.5-2.5S20 8.334 20 7.002c0-.472-.17-.93-.484-1.29a2.105 2.105 0 0 0-2.617-.436c-.42.239-.738.614-.899 1.06" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSwissFranc, "Swiss Franc"))]
 pub static LuSwissFranc: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSwissFranc",
+    label: "Swiss Franc",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20409,8 +26942,14 @@ pub static LuSwissFranc: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M10 9.5h7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSwitchCamera, "Switch Camera"))]
 pub static LuSwitchCamera: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSwitchCamera",
+    label: "Switch Camera",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20429,8 +26968,14 @@ pub static LuSwitchCamera: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m6 2 3 3-3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSword, "Sword"))]
 pub static LuSword: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSword",
+    label: "Sword",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20448,8 +26993,14 @@ pub static LuSword: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="19" x2="21" y1="21" y2="19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSwords, "Swords"))]
 pub static LuSwords: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSwords",
+    label: "Swords",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20471,8 +27022,14 @@ pub static LuSwords: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" x2="5" y1="19" y2="21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuSyringe, "Syringe"))]
 pub static LuSyringe: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuSyringe",
+    label: "Syringe",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20492,8 +27049,14 @@ pub static LuSyringe: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m14 4 6 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTable, "Table"))]
 pub static LuTable: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTable",
+    label: "Table",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20511,8 +27074,14 @@ pub static LuTable: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 15h18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTable2, "Table 2"))]
 pub static LuTable2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTable2",
+    label: "Table 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20527,8 +27096,14 @@ pub static LuTable2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9 3H5a2 2 0 0 0-2 2v4m6-6h10a2 2 0 0 1 2 2v4M9 3v18m0 0h10a2 2 0 0 0 2-2V9M9 21H5a2 2 0 0 1-2-2V9m0 0h18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTableProperties, "Table Properties"))]
 pub static LuTableProperties: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTableProperties",
+    label: "Table Properties",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20546,8 +27121,14 @@ pub static LuTableProperties: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M21 15H3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTablet, "Tablet"))]
 pub static LuTablet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTablet",
+    label: "Tablet",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20563,8 +27144,14 @@ pub static LuTablet: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12.01" y1="18" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTablets, "Tablets"))]
 pub static LuTablets: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTablets",
+    label: "Tablets",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20582,8 +27169,14 @@ pub static LuTablets: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m3.46 10.54 7.08-7.08" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTag, "Tag"))]
 pub static LuTag: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTag",
+    label: "Tag",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20599,8 +27192,14 @@ pub static LuTag: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 7h.01" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTags, "Tags"))]
 pub static LuTags: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTags",
+    label: "Tags",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20617,8 +27216,14 @@ pub static LuTags: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m15 5 6.3 6.3a2.4 2.4 0 0 1 0 3.4L17 19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTally1, "Tally 1"))]
 pub static LuTally1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTally1",
+    label: "Tally 1",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20633,8 +27238,14 @@ pub static LuTally1: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M4 4v16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTally2, "Tally 2"))]
 pub static LuTally2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTally2",
+    label: "Tally 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20650,8 +27261,14 @@ pub static LuTally2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 4v16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTally3, "Tally 3"))]
 pub static LuTally3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTally3",
+    label: "Tally 3",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20668,8 +27285,14 @@ pub static LuTally3: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14 4v16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTally4, "Tally 4"))]
 pub static LuTally4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTally4",
+    label: "Tally 4",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20687,8 +27310,14 @@ pub static LuTally4: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 4v16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTally5, "Tally 5"))]
 pub static LuTally5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTally5",
+    label: "Tally 5",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20707,8 +27336,14 @@ pub static LuTally5: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 6 2 18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTarget, "Target"))]
 pub static LuTarget: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTarget",
+    label: "Target",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20725,8 +27360,14 @@ pub static LuTarget: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTent, "Tent"))]
 pub static LuTent: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTent",
+    label: "Tent",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20745,8 +27386,14 @@ pub static LuTent: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m12 15 3 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTerminal, "Terminal"))]
 pub static LuTerminal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTerminal",
+    label: "Terminal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20762,8 +27409,14 @@ pub static LuTerminal: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="20" y1="19" y2="19" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTerminalSquare, "Terminal Square"))]
 pub static LuTerminalSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTerminalSquare",
+    label: "Terminal Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20780,8 +27433,14 @@ pub static LuTerminalSquare: &icondata_core::IconData = &icondata_core::IconData
 <rect width="18" height="18" x="3" y="3" rx="2" ry="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTestTube, "Test Tube"))]
 pub static LuTestTube: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTestTube",
+    label: "Test Tube",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20798,8 +27457,14 @@ pub static LuTestTube: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M14.5 16h-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTestTube2, "Test Tube 2"))]
 pub static LuTestTube2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTestTube2",
+    label: "Test Tube 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20816,8 +27481,14 @@ pub static LuTestTube2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 16H4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTestTubes, "Test Tubes"))]
 pub static LuTestTubes: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTestTubes",
+    label: "Test Tubes",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20837,8 +27508,14 @@ pub static LuTestTubes: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 16h-5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuText, "Text"))]
 pub static LuText: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuText",
+    label: "Text",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20855,8 +27532,14 @@ pub static LuText: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15.1 18H3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTextCursor, "Text Cursor"))]
 pub static LuTextCursor: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTextCursor",
+    label: "Text Cursor",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20873,8 +27556,14 @@ pub static LuTextCursor: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 2h1a4 4 0 0 1 4 4v1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTextCursorInput, "Text Cursor Input"))]
 pub static LuTextCursorInput: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTextCursorInput",
+    label: "Text Cursor Input",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20893,8 +27582,14 @@ pub static LuTextCursorInput: &icondata_core::IconData = &icondata_core::IconDat
 <path d="M9 7v10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTextQuote, "Text Quote"))]
 pub static LuTextQuote: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTextQuote",
+    label: "Text Quote",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20912,8 +27607,14 @@ pub static LuTextQuote: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 12v6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTextSelect, "Text Select"))]
 pub static LuTextSelect: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTextSelect",
+    label: "Text Select",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20942,8 +27643,14 @@ pub static LuTextSelect: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="7" x2="13" y1="16" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuThermometer, "Thermometer"))]
 pub static LuThermometer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuThermometer",
+    label: "Thermometer",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20958,8 +27665,14 @@ pub static LuThermometer: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14 4v10.54a4 4 0 1 1-4 0V4a2 2 0 0 1 4 0Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuThermometerSnowflake, "Thermometer Snowflake"))]
 pub static LuThermometerSnowflake: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuThermometerSnowflake",
+    label: "Thermometer Snowflake",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -20979,8 +27692,14 @@ pub static LuThermometerSnowflake: &icondata_core::IconData = &icondata_core::Ic
 <path d="M20 4v10.54a4 4 0 1 1-4 0V4a2 2 0 0 1 4 0Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuThermometerSun, "Thermometer Sun"))]
 pub static LuThermometerSun: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuThermometerSun",
+    label: "Thermometer Sun",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21000,8 +27719,14 @@ pub static LuThermometerSun: &icondata_core::IconData = &icondata_core::IconData
 <path d="M6.34 7.34 4.93 5.93" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuThumbsDown, "Thumbs Down"))]
 pub static LuThumbsDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuThumbsDown",
+    label: "Thumbs Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21017,8 +27742,14 @@ pub static LuThumbsDown: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 18.12 10 14H4.17a2 2 0 0 1-1.92-2.56l2.33-8A2 2 0 0 1 6.5 2H20a2 2 0 0 1 2 2v8a2 2 0 0 1-2 2h-2.76a2 2 0 0 0-1.79 1.11L12 22h0a3.13 3.13 0 0 1-3-3.88Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuThumbsUp, "Thumbs Up"))]
 pub static LuThumbsUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuThumbsUp",
+    label: "Thumbs Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21034,8 +27765,14 @@ pub static LuThumbsUp: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15 5.88 14 10h5.83a2 2 0 0 1 1.92 2.56l-2.33 8A2 2 0 0 1 17.5 22H4a2 2 0 0 1-2-2v-8a2 2 0 0 1 2-2h2.76a2 2 0 0 0 1.79-1.11L12 2h0a3.13 3.13 0 0 1 3 3.88Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTicket, "Ticket"))]
 pub static LuTicket: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTicket",
+    label: "Ticket",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21053,8 +27790,14 @@ pub static LuTicket: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13 11v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTimer, "Timer"))]
 pub static LuTimer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTimer",
+    label: "Timer",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21071,8 +27814,14 @@ pub static LuTimer: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="14" r="8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTimerOff, "Timer Off"))]
 pub static LuTimerOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTimerOff",
+    label: "Timer Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21091,8 +27840,14 @@ pub static LuTimerOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 12v-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTimerReset, "Timer Reset"))]
 pub static LuTimerReset: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTimerReset",
+    label: "Timer Reset",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21110,8 +27865,14 @@ pub static LuTimerReset: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M9 17H4v5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuToggleLeft, "Toggle Left"))]
 pub static LuToggleLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuToggleLeft",
+    label: "Toggle Left",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21127,8 +27888,14 @@ pub static LuToggleLeft: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="8" cy="12" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuToggleRight, "Toggle Right"))]
 pub static LuToggleRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuToggleRight",
+    label: "Toggle Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21144,8 +27911,14 @@ pub static LuToggleRight: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="16" cy="12" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTornado, "Tornado"))]
 pub static LuTornado: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTornado",
+    label: "Tornado",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21164,8 +27937,14 @@ pub static LuTornado: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11 20H9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTouchpad, "Touchpad"))]
 pub static LuTouchpad: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTouchpad",
+    label: "Touchpad",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21182,8 +27961,14 @@ pub static LuTouchpad: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 20v-6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTouchpadOff, "Touchpad Off"))]
 pub static LuTouchpadOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTouchpadOff",
+    label: "Touchpad Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21203,8 +27988,14 @@ pub static LuTouchpadOff: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 16V6a2 2 0 0 0-2-2H10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTowerControl, "Tower Control"))]
 pub static LuTowerControl: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTowerControl",
+    label: "Tower Control",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21225,8 +28016,14 @@ pub static LuTowerControl: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M13 2h-2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuToyBrick, "Toy Brick"))]
 pub static LuToyBrick: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuToyBrick",
+    label: "Toy Brick",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21243,8 +28040,14 @@ pub static LuToyBrick: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19 8V5c0-.6-.4-1-1-1h-3a1 1 0 0 0-1 1v3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTractor, "Tractor"))]
 pub static LuTractor: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTractor",
+    label: "Tractor",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21267,8 +28070,14 @@ pub static LuTractor: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="18" cy="18" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTrafficCone, "Traffic Cone"))]
 pub static LuTrafficCone: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTrafficCone",
+    label: "Traffic Cone",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21286,8 +28095,14 @@ pub static LuTrafficCone: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m7.5 12.2-4.7 2.7c-.5.3-.8.7-.8 1.1s.3.8.8 1.1l7.6 4.5c.9.5 2.1.5 3 0l7.6-4.5c.7-.3 1-.7 1-1.1s-.3-.8-.8-1.1l-4.7-2.8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTrainFront, "Train Front"))]
 pub static LuTrainFront: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTrainFront",
+    label: "Train Front",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21307,8 +28122,14 @@ pub static LuTrainFront: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m16 19 2 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTrainFrontTunnel, "Train Front Tunnel"))]
 pub static LuTrainFrontTunnel: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTrainFrontTunnel",
+    label: "Train Front Tunnel",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21329,8 +28150,14 @@ pub static LuTrainFrontTunnel: &icondata_core::IconData = &icondata_core::IconDa
 <path d="m15 19 2 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTrainTrack, "Train Track"))]
 pub static LuTrainTrack: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTrainTrack",
+    label: "Train Track",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21351,8 +28178,14 @@ pub static LuTrainTrack: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 22 22 7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTramFront, "Tram Front"))]
 pub static LuTramFront: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTramFront",
+    label: "Tram Front",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21373,8 +28206,14 @@ pub static LuTramFront: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 15h0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTrash, "Trash"))]
 pub static LuTrash: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTrash",
+    label: "Trash",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21391,8 +28230,14 @@ pub static LuTrash: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M8 6V4c0-1 1-2 2-2h4c1 0 2 1 2 2v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTrash2, "Trash 2"))]
 pub static LuTrash2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTrash2",
+    label: "Trash 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21411,8 +28256,14 @@ pub static LuTrash2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="14" x2="14" y1="11" y2="17" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTreeDeciduous, "Tree Deciduous"))]
 pub static LuTreeDeciduous: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTreeDeciduous",
+    label: "Tree Deciduous",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21428,8 +28279,14 @@ pub static LuTreeDeciduous: &icondata_core::IconData = &icondata_core::IconData
 <path d="M12 19v3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTreePine, "Tree Pine"))]
 pub static LuTreePine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTreePine",
+    label: "Tree Pine",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21445,8 +28302,14 @@ pub static LuTreePine: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 22v-3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTrees, "Trees"))]
 pub static LuTrees: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTrees",
+    label: "Trees",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21464,8 +28327,14 @@ pub static LuTrees: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 19h8.3a1 1 0 0 0 .7-1.7L18 14h.3a1 1 0 0 0 .7-1.7L16 9h.2a1 1 0 0 0 .8-1.7L13 3l-1.4 1.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTrello, "Trello"))]
 pub static LuTrello: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTrello",
+    label: "Trello",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21482,8 +28351,14 @@ pub static LuTrello: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="3" height="5" x="14" y="7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTrendingDown, "Trending Down"))]
 pub static LuTrendingDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTrendingDown",
+    label: "Trending Down",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21499,8 +28374,14 @@ pub static LuTrendingDown: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="16 17 22 17 22 11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTrendingUp, "Trending Up"))]
 pub static LuTrendingUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTrendingUp",
+    label: "Trending Up",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21516,8 +28397,14 @@ pub static LuTrendingUp: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="16 7 22 7 22 13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTriangle, "Triangle"))]
 pub static LuTriangle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTriangle",
+    label: "Triangle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21532,8 +28419,14 @@ pub static LuTriangle: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="m21.73 18-8-14a2 2 0 0 0-3.48 0l-8 14A2 2 0 0 0 4 21h16a2 2 0 0 0 1.73-3Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTriangleRight, "Triangle Right"))]
 pub static LuTriangleRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTriangleRight",
+    label: "Triangle Right",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21548,8 +28441,14 @@ pub static LuTriangleRight: &icondata_core::IconData = &icondata_core::IconData
     data: r###"<path d="M22 18a2 2 0 0 1-2 2H3c-1.1 0-1.3-.6-.4-1.3L20.4 4.3c.9-.7 1.6-.4 1.6.7Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTrophy, "Trophy"))]
 pub static LuTrophy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTrophy",
+    label: "Trophy",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21569,8 +28468,14 @@ pub static LuTrophy: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 2H6v7a6 6 0 0 0 12 0V2Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTruck, "Truck"))]
 pub static LuTruck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTruck",
+    label: "Truck",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21589,8 +28494,14 @@ pub static LuTruck: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="17" cy="18" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTurtle, "Turtle"))]
 pub static LuTurtle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTurtle",
+    label: "Turtle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21608,8 +28519,14 @@ pub static LuTurtle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16.93 10H20a2 2 0 0 1 0 4H2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTv, "TV"))]
 pub static LuTv: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTv",
+    label: "TV",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21625,8 +28542,14 @@ pub static LuTv: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="17 2 12 7 7 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTv2, "TV 2"))]
 pub static LuTv2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTv2",
+    label: "TV 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21642,8 +28565,14 @@ pub static LuTv2: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="20" height="14" x="2" y="3" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTwitch, "Twitch"))]
 pub static LuTwitch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTwitch",
+    label: "Twitch",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21658,8 +28587,14 @@ pub static LuTwitch: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M21 2H3v16h5v4l4-4h5l4-4V2zm-10 9V7m5 4V7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuTwitter, "Twitter"))]
 pub static LuTwitter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuTwitter",
+    label: "Twitter",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21674,8 +28609,14 @@ pub static LuTwitter: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M22 4s-.7 2.1-2 3.4c1.6 10-9.4 17.3-18 11.6 2.2.1 4.4-.6 6-2C3 15.5.5 9.6 3 5c2.2 2.6 5.6 4.1 9 4-.9-4.2 4-6.6 7-3.8 1.1 0 3-1.2 3-1.2z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuType, "Type"))]
 pub static LuType: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuType",
+    label: "Type",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21692,8 +28633,14 @@ pub static LuType: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="4" y2="20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUmbrella, "Umbrella"))]
 pub static LuUmbrella: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUmbrella",
+    label: "Umbrella",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21710,8 +28657,14 @@ pub static LuUmbrella: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 2v1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUnderline, "Underline"))]
 pub static LuUnderline: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUnderline",
+    label: "Underline",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21727,8 +28680,14 @@ pub static LuUnderline: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="4" x2="20" y1="20" y2="20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUndo, "Undo"))]
 pub static LuUndo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUndo",
+    label: "Undo",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21744,8 +28703,14 @@ pub static LuUndo: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 17a9 9 0 0 0-9-9 9 9 0 0 0-6 2.3L3 13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUndo2, "Undo 2"))]
 pub static LuUndo2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUndo2",
+    label: "Undo 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21761,8 +28726,14 @@ pub static LuUndo2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M4 9h10.5a5.5 5.5 0 0 1 5.5 5.5v0a5.5 5.5 0 0 1-5.5 5.5H11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUndoDot, "Undo Dot"))]
 pub static LuUndoDot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUndoDot",
+    label: "Undo Dot",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21779,8 +28750,14 @@ pub static LuUndoDot: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 17a9 9 0 0 0-9-9 9 9 0 0 0-6 2.3L3 13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUnfoldHorizontal, "Unfold Horizontal"))]
 pub static LuUnfoldHorizontal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUnfoldHorizontal",
+    label: "Unfold Horizontal",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21802,8 +28779,14 @@ pub static LuUnfoldHorizontal: &icondata_core::IconData = &icondata_core::IconDa
 <path d="m5 9-3 3 3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUnfoldVertical, "Unfold Vertical"))]
 pub static LuUnfoldVertical: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUnfoldVertical",
+    label: "Unfold Vertical",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21825,8 +28808,14 @@ pub static LuUnfoldVertical: &icondata_core::IconData = &icondata_core::IconData
 <path d="m15 5-3-3-3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUngroup, "Ungroup"))]
 pub static LuUngroup: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUngroup",
+    label: "Ungroup",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21842,8 +28831,14 @@ pub static LuUngroup: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="8" height="6" x="11" y="14" rx="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUnlink, "Unlink"))]
 pub static LuUnlink: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUnlink",
+    label: "Unlink",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21863,8 +28858,14 @@ pub static LuUnlink: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="19" x2="22" y1="16" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUnlink2, "Unlink 2"))]
 pub static LuUnlink2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUnlink2",
+    label: "Unlink 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21879,8 +28880,14 @@ pub static LuUnlink2: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M15 7h2a5 5 0 0 1 0 10h-2m-6 0H7A5 5 0 0 1 7 7h2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUnlock, "Unlock"))]
 pub static LuUnlock: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUnlock",
+    label: "Unlock",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21896,8 +28903,14 @@ pub static LuUnlock: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 11V7a5 5 0 0 1 9.9-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUnplug, "Unplug"))]
 pub static LuUnplug: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUnplug",
+    label: "Unplug",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21917,8 +28930,14 @@ pub static LuUnplug: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m12 6 6 6 2.3-2.3a2.4 2.4 0 0 0 0-3.4l-2.6-2.6a2.4 2.4 0 0 0-3.4 0Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUpload, "Upload"))]
 pub static LuUpload: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUpload",
+    label: "Upload",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21935,8 +28954,14 @@ pub static LuUpload: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12" y1="3" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUploadCloud, "Upload Cloud"))]
 pub static LuUploadCloud: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUploadCloud",
+    label: "Upload Cloud",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21953,8 +28978,14 @@ pub static LuUploadCloud: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m16 16-4-4-4 4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUsb, "USB"))]
 pub static LuUsb: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUsb",
+    label: "USB",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21975,8 +29006,14 @@ pub static LuUsb: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m18 12 1-1 1 1-1 1Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUser, "User"))]
 pub static LuUser: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUser",
+    label: "User",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -21992,8 +29029,14 @@ pub static LuUser: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="7" r="4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUser2, "User 2"))]
 pub static LuUser2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUser2",
+    label: "User 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22009,8 +29052,14 @@ pub static LuUser2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M20 21a8 8 0 1 0-16 0" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUserCheck, "User Check"))]
 pub static LuUserCheck: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUserCheck",
+    label: "User Check",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22027,8 +29076,14 @@ pub static LuUserCheck: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="16 11 18 13 22 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUserCheck2, "User Check 2"))]
 pub static LuUserCheck2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUserCheck2",
+    label: "User Check 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22045,8 +29100,14 @@ pub static LuUserCheck2: &icondata_core::IconData = &icondata_core::IconData {
 <polyline points="16 11 18 13 22 9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUserCircle, "User Circle"))]
 pub static LuUserCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUserCircle",
+    label: "User Circle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22063,8 +29124,14 @@ pub static LuUserCircle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 20.662V19a2 2 0 0 1 2-2h6a2 2 0 0 1 2 2v1.662" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUserCircle2, "User Circle 2"))]
 pub static LuUserCircle2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUserCircle2",
+    label: "User Circle 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22081,8 +29148,14 @@ pub static LuUserCircle2: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="12" cy="12" r="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUserCog, "User Cog"))]
 pub static LuUserCog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUserCog",
+    label: "User Cog",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22105,8 +29178,14 @@ pub static LuUserCog: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m17.27 10-.87-.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUserCog2, "User Cog 2"))]
 pub static LuUserCog2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUserCog2",
+    label: "User Cog 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22129,8 +29208,14 @@ pub static LuUserCog2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m17.27 10-.87-.5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUserMinus, "User Minus"))]
 pub static LuUserMinus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUserMinus",
+    label: "User Minus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22147,8 +29232,14 @@ pub static LuUserMinus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="22" x2="16" y1="11" y2="11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUserMinus2, "User Minus 2"))]
 pub static LuUserMinus2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUserMinus2",
+    label: "User Minus 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22165,8 +29256,14 @@ pub static LuUserMinus2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="22" x2="16" y1="11" y2="11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUserPlus, "User Plus"))]
 pub static LuUserPlus: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUserPlus",
+    label: "User Plus",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22184,8 +29281,14 @@ pub static LuUserPlus: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="22" x2="16" y1="11" y2="11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUserPlus2, "User Plus 2"))]
 pub static LuUserPlus2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUserPlus2",
+    label: "User Plus 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22203,8 +29306,14 @@ pub static LuUserPlus2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="22" x2="16" y1="11" y2="11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUserSquare, "User Square"))]
 pub static LuUserSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUserSquare",
+    label: "User Square",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22221,8 +29330,14 @@ pub static LuUserSquare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 21v-2a2 2 0 0 1 2-2h6a2 2 0 0 1 2 2v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUserSquare2, "User Square 2"))]
 pub static LuUserSquare2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUserSquare2",
+    label: "User Square 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22239,8 +29354,14 @@ pub static LuUserSquare2: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="18" height="18" x="3" y="3" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUserX, "User X"))]
 pub static LuUserX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUserX",
+    label: "User X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22258,8 +29379,14 @@ pub static LuUserX: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="22" x2="17" y1="8" y2="13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUserX2, "User X 2"))]
 pub static LuUserX2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUserX2",
+    label: "User X 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22277,8 +29404,14 @@ pub static LuUserX2: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="22" x2="17" y1="8" y2="13" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUsers, "Users"))]
 pub static LuUsers: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUsers",
+    label: "Users",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22296,8 +29429,14 @@ pub static LuUsers: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M16 3.13a4 4 0 0 1 0 7.75" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUsers2, "Users 2"))]
 pub static LuUsers2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUsers2",
+    label: "Users 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22314,8 +29453,14 @@ pub static LuUsers2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 19a6 6 0 0 0-6-6 4 4 0 1 0 0-8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUtensils, "Utensils"))]
 pub static LuUtensils: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUtensils",
+    label: "Utensils",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22332,8 +29477,14 @@ pub static LuUtensils: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 15V2v0a5 5 0 0 0-5 5v6c0 1.1.9 2 2 2h3Zm0 0v7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUtensilsCrossed, "Utensils Crossed"))]
 pub static LuUtensilsCrossed: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUtensilsCrossed",
+    label: "Utensils Crossed",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22351,8 +29502,14 @@ pub static LuUtensilsCrossed: &icondata_core::IconData = &icondata_core::IconDat
 <path d="m19 5-7 7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuUtilityPole, "Utility Pole"))]
 pub static LuUtilityPole: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuUtilityPole",
+    label: "Utility Pole",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22373,8 +29530,14 @@ pub static LuUtilityPole: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m19 5-7 7-7-7" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuVariable, "Variable"))]
 pub static LuVariable: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuVariable",
+    label: "Variable",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22392,8 +29555,14 @@ pub static LuVariable: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="9" x2="15" y1="9" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuVegan, "Vegan"))]
 pub static LuVegan: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuVegan",
+    label: "Vegan",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22410,8 +29579,14 @@ pub static LuVegan: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M17.41 3.6a10 10 0 1 0 3 3" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuVenetianMask, "Venetian Mask"))]
 pub static LuVenetianMask: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuVenetianMask",
+    label: "Venetian Mask",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22428,8 +29603,14 @@ pub static LuVenetianMask: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 11c-1.5 0-3 .5-3 2 2 0 3 0 3-2Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuVibrate, "Vibrate"))]
 pub static LuVibrate: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuVibrate",
+    label: "Vibrate",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22446,8 +29627,14 @@ pub static LuVibrate: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="8" height="14" x="8" y="5" rx="1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuVibrateOff, "Vibrate Off"))]
 pub static LuVibrateOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuVibrateOff",
+    label: "Vibrate Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22466,8 +29653,14 @@ pub static LuVibrateOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuVideo, "Video"))]
 pub static LuVideo: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuVideo",
+    label: "Video",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22483,8 +29676,14 @@ pub static LuVideo: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="14" height="12" x="2" y="6" rx="2" ry="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuVideoOff, "Video Off"))]
 pub static LuVideoOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuVideoOff",
+    label: "Video Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22501,8 +29700,14 @@ pub static LuVideoOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuVideotape, "Videotape"))]
 pub static LuVideotape: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuVideotape",
+    label: "Videotape",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22521,8 +29726,14 @@ pub static LuVideotape: &icondata_core::IconData = &icondata_core::IconData {
 <circle cx="16" cy="14" r="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuView, "View"))]
 pub static LuView: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuView",
+    label: "View",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22540,8 +29751,14 @@ pub static LuView: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M21 7V5a2 2 0 0 0-2-2H5a2 2 0 0 0-2 2v2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuVoicemail, "Voicemail"))]
 pub static LuVoicemail: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuVoicemail",
+    label: "Voicemail",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22558,8 +29775,14 @@ pub static LuVoicemail: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="6" x2="18" y1="16" y2="16" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuVolume, "Volume"))]
 pub static LuVolume: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuVolume",
+    label: "Volume",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22574,8 +29797,14 @@ pub static LuVolume: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="11 5 6 9 2 9 2 15 6 15 11 19 11 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuVolume1, "Volume 1"))]
 pub static LuVolume1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuVolume1",
+    label: "Volume 1",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22591,8 +29820,14 @@ pub static LuVolume1: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M15.54 8.46a5 5 0 0 1 0 7.07" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuVolume2, "Volume 2"))]
 pub static LuVolume2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuVolume2",
+    label: "Volume 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22609,8 +29844,14 @@ pub static LuVolume2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19.07 4.93a10 10 0 0 1 0 14.14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuVolumeX, "Volume X"))]
 pub static LuVolumeX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuVolumeX",
+    label: "Volume X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22627,8 +29868,14 @@ pub static LuVolumeX: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="16" x2="22" y1="9" y2="15" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuVote, "Vote"))]
 pub static LuVote: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuVote",
+    label: "Vote",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22645,8 +29892,14 @@ pub static LuVote: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 19H2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWallet, "Wallet"))]
 pub static LuWallet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWallet",
+    label: "Wallet",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22663,8 +29916,14 @@ pub static LuWallet: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M18 12a2 2 0 0 0 0 4h4v-4Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWallet2, "Wallet 2"))]
 pub static LuWallet2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWallet2",
+    label: "Wallet 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22680,8 +29939,14 @@ pub static LuWallet2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M7 7h12a2 2 0 0 1 2 2v10a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2V5a2 2 0 0 1 2-2h14" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWalletCards, "Wallet Cards"))]
 pub static LuWalletCards: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWalletCards",
+    label: "Wallet Cards",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22698,8 +29963,14 @@ pub static LuWalletCards: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M3 11h3c.8 0 1.6.3 2.1.9l1.1.9c1.6 1.6 4.1 1.6 5.7 0l1.1-.9c.5-.5 1.3-.9 2.1-.9H21" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWallpaper, "Wallpaper"))]
 pub static LuWallpaper: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWallpaper",
+    label: "Wallpaper",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22717,8 +29988,14 @@ pub static LuWallpaper: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 17v4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWand, "Wand"))]
 pub static LuWand: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWand",
+    label: "Wand",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22741,8 +30018,14 @@ pub static LuWand: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12.2 6.2 11 5" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWand2, "Wand 2"))]
 pub static LuWand2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWand2",
+    label: "Wand 2",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22764,8 +30047,14 @@ pub static LuWand2: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M11 3H9" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWarehouse, "Warehouse"))]
 pub static LuWarehouse: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWarehouse",
+    label: "Warehouse",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22783,8 +30072,14 @@ pub static LuWarehouse: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="12" height="12" x="6" y="10" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWatch, "Watch"))]
 pub static LuWatch: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWatch",
+    label: "Watch",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22802,8 +30097,14 @@ pub static LuWatch: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m7.88 16.36.8 4a2 2 0 0 0 2 1.61h2.72a2 2 0 0 0 2-1.61l.81-4.05" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWaves, "Waves"))]
 pub static LuWaves: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWaves",
+    label: "Waves",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22820,8 +30121,14 @@ pub static LuWaves: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M2 18c.6.5 1.2 1 2.5 1 2.5 0 2.5-2 5-2 2.6 0 2.4 2 5 2 2.5 0 2.5-2 5-2 1.3 0 1.9.5 2.5 1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWebcam, "Webcam"))]
 pub static LuWebcam: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWebcam",
+    label: "Webcam",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22839,8 +30146,14 @@ pub static LuWebcam: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 22v-4" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWebhook, "Webhook"))]
 pub static LuWebhook: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWebhook",
+    label: "Webhook",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22857,8 +30170,14 @@ pub static LuWebhook: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m12 6 3.13 5.73C15.66 12.7 16.9 13 18 13a4 4 0 0 1 0 8" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWheat, "Wheat"))]
 pub static LuWheat: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWheat",
+    label: "Wheat",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22880,8 +30199,14 @@ pub static LuWheat: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M19.47 9.47 21 11l-1.53 1.53a3.5 3.5 0 0 1-4.94 0L13 11l1.53-1.53a3.5 3.5 0 0 1 4.94 0Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWheatOff, "Wheat Off"))]
 pub static LuWheatOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWheatOff",
+    label: "Wheat Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22905,8 +30230,14 @@ pub static LuWheatOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWholeWord, "Whole Word"))]
 pub static LuWholeWord: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWholeWord",
+    label: "Whole Word",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22925,8 +30256,14 @@ pub static LuWholeWord: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M22 17v1c0 .5-.5 1-1 1H3c-.5 0-1-.5-1-1v-1" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWifi, "Wifi"))]
 pub static LuWifi: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWifi",
+    label: "Wifi",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22944,8 +30281,14 @@ pub static LuWifi: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12.01" y1="20" y2="20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWifiOff, "Wifi Off"))]
 pub static LuWifiOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWifiOff",
+    label: "Wifi Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22966,8 +30309,14 @@ pub static LuWifiOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="12" x2="12.01" y1="20" y2="20" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWind, "Wind"))]
 pub static LuWind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWind",
+    label: "Wind",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -22984,8 +30333,14 @@ pub static LuWind: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12.6 19.4A2 2 0 1 0 14 16H2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWine, "Wine"))]
 pub static LuWine: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWine",
+    label: "Wine",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -23003,8 +30358,14 @@ pub static LuWine: &icondata_core::IconData = &icondata_core::IconData {
 <path d="M12 15a5 5 0 0 0 5-5c0-2-.5-4-2-8H9c-1.5 4-2 6-2 8a5 5 0 0 0 5 5Z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWineOff, "Wine Off"))]
 pub static LuWineOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWineOff",
+    label: "Wine Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -23023,8 +30384,14 @@ pub static LuWineOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWorkflow, "Workflow"))]
 pub static LuWorkflow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWorkflow",
+    label: "Workflow",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -23041,8 +30408,14 @@ pub static LuWorkflow: &icondata_core::IconData = &icondata_core::IconData {
 <rect width="8" height="8" x="13" y="13" rx="2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWrapText, "Wrap Text"))]
 pub static LuWrapText: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWrapText",
+    label: "Wrap Text",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -23060,8 +30433,14 @@ pub static LuWrapText: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="3" x2="10" y1="18" y2="18" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuWrench, "Wrench"))]
 pub static LuWrench: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuWrench",
+    label: "Wrench",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -23076,8 +30455,14 @@ pub static LuWrench: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M14.7 6.3a1 1 0 0 0 0 1.4l1.6 1.6a1 1 0 0 0 1.4 0l3.77-3.77a6 6 0 0 1-7.94 7.94l-6.91 6.91a2.12 2.12 0 0 1-3-3l6.91-6.91a6 6 0 0 1 7.94-7.94l-3.76 3.76z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuX, "X"))]
 pub static LuX: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuX",
+    label: "X",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -23093,8 +30478,14 @@ pub static LuX: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m6 6 12 12" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuXCircle, "Xcircle"))]
 pub static LuXCircle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuXCircle",
+    label: "Xcircle",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -23111,8 +30502,14 @@ pub static LuXCircle: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 9 6 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuXOctagon, "Xoctagon"))]
 pub static LuXOctagon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuXOctagon",
+    label: "Xoctagon",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -23129,8 +30526,14 @@ pub static LuXOctagon: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 9 6 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuXSquare, "Xsquare"))]
 pub static LuXSquare: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuXSquare",
+    label: "Xsquare",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -23147,8 +30550,14 @@ pub static LuXSquare: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m9 9 6 6" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuYoutube, "Youtube"))]
 pub static LuYoutube: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuYoutube",
+    label: "Youtube",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -23164,8 +30573,14 @@ pub static LuYoutube: &icondata_core::IconData = &icondata_core::IconData {
 <path d="m10 15 5-3-5-3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuZap, "Zap"))]
 pub static LuZap: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuZap",
+    label: "Zap",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -23180,8 +30595,14 @@ pub static LuZap: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<polygon points="13 2 3 14 12 14 11 22 21 10 12 10 13 2" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuZapOff, "Zap Off"))]
 pub static LuZapOff: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuZapOff",
+    label: "Zap Off",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -23199,8 +30620,14 @@ pub static LuZapOff: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="2" x2="22" y1="2" y2="22" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuZoomIn, "Zoom In"))]
 pub static LuZoomIn: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuZoomIn",
+    label: "Zoom In",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -23218,8 +30645,14 @@ pub static LuZoomIn: &icondata_core::IconData = &icondata_core::IconData {
 <line x1="8" x2="14" y1="11" y2="11" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(LuZoomOut, "Zoom Out"))]
 pub static LuZoomOut: &icondata_core::IconData = &icondata_core::IconData {
+    id: "LuZoomOut",
+    label: "Zoom Out",
+    source: None,
+    paint: icondata_core::IconPaint::Stroke,
+    brand_color: None,
     style: None,
     x: None,
     y: None,
@@ -1,9 +1,32 @@
 //! This crate provides a collection of icons in the form of SVG data
 //! from the [__Weather Icons__](https://github.com/erikflowers/weather-icons) icon set.
 
+/// Documentation of every icon, showing its usage with different frameworks.
+#[cfg(feature = "docs")]
+macro_rules! examples {
+    ($icon:ident, $label:literal) => {
+        concat!(
+            $label,
+            "\n\n# Examples\n\nWith [Leptos](https://leptos.dev) and [`leptos_icons`](https://docs.rs/leptos_icons):\n```rust,ignore\nuse leptos::*;\nuse leptos_icons::Icon;\n\nview! { <Icon icon=icondata::",
+            stringify!($icon),
+            " /> }\n```\n\nWith [Yew](https://yew.rs):\n```rust,ignore\nuse yew::prelude::*;\n\nlet svg = icondata::",
+            stringify!($icon),
+            ".render(&Default::default());\nhtml! { { Html::from_html_unchecked(AttrValue::from(svg)) } }\n```\n\nWith [Dioxus](https://dioxuslabs.com):\n```rust,ignore\nuse dioxus::prelude::*;\n\nlet svg = icondata::",
+            stringify!($icon),
+            ".render(&Default::default());\nrsx! { span { dangerous_inner_html: \"{svg}\" } }\n```",
+        )
+    };
+}
+
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiAlien, "Alien"))]
 pub static WiAlien: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiAlien",
+    label: "Alien",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -28,8 +51,14 @@ pub static WiAlien: &icondata_core::IconData = &icondata_core::IconData {
 	" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiBarometer, "Barometer"))]
 pub static WiBarometer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiBarometer",
+    label: "Barometer",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -53,8 +82,14 @@ pub static WiBarometer: &icondata_core::IconData = &icondata_core::IconData {
 	C13.65,15.76,13.5,15.39,13.5,14.95z M14.81,10.28V8.12h0.69v2.17H14.81z M17.75,13.55v-0.74h2.17v0.74H17.75z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiCelsius, "Celsius"))]
 pub static WiCelsius: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiCelsius",
+    label: "Celsius",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -81,8 +116,14 @@ pub static WiCelsius: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.51,0-0.95,0.09-1.35,0.27c-0.39,0.18-0.7,0.42-0.91,0.73c-0.43,0.59-0.65,1.28-0.65,2.07V15.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiCloud, "Cloud"))]
 pub static WiCloud: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiCloud",
+    label: "Cloud",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -103,8 +144,14 @@ pub static WiCloud: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.81,0.73-1.27,1.64-1.37,2.72l-0.08,0.57c0,0.12-0.07,0.18-0.2,0.18H9.27c-0.84,0.1-1.54,0.46-2.1,1.07S6.32,16.05,6.32,16.88z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiCloudDown, "Cloud Down"))]
 pub static WiCloudDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiCloudDown",
+    label: "Cloud Down",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -131,8 +178,14 @@ pub static WiCloudDown: &icondata_core::IconData = &icondata_core::IconData {
 	C11.66,17.97,11.58,18.17,11.58,18.4z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiCloudRefresh, "Cloud Refresh"))]
 pub static WiCloudRefresh: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiCloudRefresh",
+    label: "Cloud Refresh",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -162,8 +215,14 @@ pub static WiCloudRefresh: &icondata_core::IconData = &icondata_core::IconData {
 	c0,0.24,0.07,0.43,0.23,0.58l0.35,0.36c-1,0.17-1.83,0.63-2.49,1.4C11.19,16.24,10.86,17.14,10.86,18.18z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiCloudUp, "Cloud Up"))]
 pub static WiCloudUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiCloudUp",
+    label: "Cloud Up",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -190,8 +249,14 @@ pub static WiCloudUp: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.24,0-0.44,0.08-0.59,0.24l-2.58,2.57C11.66,17.08,11.58,17.27,11.58,17.51z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiCloudy, "Cloudy"))]
 pub static WiCloudy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiCloudy",
+    label: "Cloudy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -218,8 +283,14 @@ pub static WiCloudy: &icondata_core::IconData = &icondata_core::IconData {
 	z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiCloudyGusts, "Cloudy Gusts"))]
 pub static WiCloudyGusts: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiCloudyGusts",
+    label: "Cloudy Gusts",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -250,8 +321,14 @@ pub static WiCloudyGusts: &icondata_core::IconData = &icondata_core::IconData {
 	s-1.12,1.36-1.38,2.21v0.04C5.77,15.56,5.77,15.58,5.77,15.61z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiCloudyWindy, "Cloudy Windy"))]
 pub static WiCloudyWindy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiCloudyWindy",
+    label: "Cloudy Windy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -279,8 +356,14 @@ pub static WiCloudyWindy: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.23,0-0.43-0.08-0.6-0.25C7.58,24.57,7.5,24.37,7.5,24.13z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayCloudy, "Day Cloudy"))]
 pub static WiDayCloudy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayCloudy",
+    label: "Day Cloudy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -315,8 +398,14 @@ pub static WiDayCloudy: &icondata_core::IconData = &icondata_core::IconData {
 	s-0.35-0.25-0.58-0.25h-2.04c-0.24,0-0.44,0.08-0.61,0.25C24.8,11.17,24.72,11.37,24.72,11.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayCloudyGusts, "Day Cloudy Gusts"))]
 pub static WiDayCloudyGusts: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayCloudyGusts",
+    label: "Day Cloudy Gusts",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -360,8 +449,14 @@ pub static WiDayCloudyGusts: &icondata_core::IconData = &icondata_core::IconData
 	c-0.17-0.17-0.37-0.26-0.6-0.26h-2.02c-0.24,0-0.43,0.08-0.59,0.25C26.03,11.13,25.95,11.33,25.95,11.57z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayCloudyHigh, "Day Cloudy High"))]
 pub static WiDayCloudyHigh: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayCloudyHigh",
+    label: "Day Cloudy High",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -396,8 +491,14 @@ pub static WiDayCloudyHigh: &icondata_core::IconData = &icondata_core::IconData
 	c0,0.19-0.07,0.36-0.21,0.49c-0.14,0.13-0.3,0.2-0.5,0.2h-1.62c-0.19,0-0.34-0.07-0.47-0.2C23.14,16.35,23.08,16.19,23.08,15.99z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayCloudyWindy, "Day Cloudy Windy"))]
 pub static WiDayCloudyWindy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayCloudyWindy",
+    label: "Day Cloudy Windy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -439,8 +540,14 @@ pub static WiDayCloudyWindy: &icondata_core::IconData = &icondata_core::IconData
 	z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayFog, "Day Fog"))]
 pub static WiDayFog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayFog",
+    label: "Day Fog",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -480,8 +587,14 @@ pub static WiDayFog: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.16-0.16-0.35-0.24-0.59-0.24h-2.05c-0.24,0-0.44,0.08-0.6,0.25C26.04,11.14,25.96,11.34,25.96,11.57z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayHail, "Day Hail"))]
 pub static WiDayHail: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayHail",
+    label: "Day Hail",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -532,8 +645,14 @@ pub static WiDayHail: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.25,0-0.46,0.08-0.63,0.24C24.83,11.12,24.74,11.32,24.74,11.55z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayHaze, "Day Haze"))]
 pub static WiDayHaze: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayHaze",
+    label: "Day Haze",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -575,8 +694,14 @@ pub static WiDayHaze: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.17,0.17-0.37,0.25-0.6,0.25h-2.02c-0.23,0-0.43-0.08-0.58-0.25S21.92,15.86,21.92,15.62z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayLightWind, "Day Light Wind"))]
 pub static WiDayLightWind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayLightWind",
+    label: "Day Light Wind",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -617,8 +742,14 @@ pub static WiDayLightWind: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.18,0.17-0.38,0.25-0.61,0.25h-2.03c-0.23,0-0.42-0.08-0.58-0.25C24.08,11.99,24,11.79,24,11.55z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayLightning, "Day Lightning"))]
 pub static WiDayLightning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayLightning",
+    label: "Day Lightning",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -656,8 +787,14 @@ pub static WiDayLightning: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.23,0-0.43,0.08-0.6,0.25C24.77,11.12,24.68,11.32,24.68,11.56z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayRain, "Day Rain"))]
 pub static WiDayRain: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayRain",
+    label: "Day Rain",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -701,8 +838,14 @@ pub static WiDayRain: &icondata_core::IconData = &icondata_core::IconData {
 	C24.8,11.14,24.72,11.34,24.72,11.58z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayRainMix, "Day Rain Mix"))]
 pub static WiDayRainMix: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayRainMix",
+    label: "Day Rain Mix",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -760,8 +903,14 @@ pub static WiDayRainMix: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.24,0-0.44,0.08-0.62,0.25S24.66,11.37,24.66,11.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayRainWind, "Day Rain Wind"))]
 pub static WiDayRainWind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayRainWind",
+    label: "Day Rain Wind",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -810,8 +959,14 @@ pub static WiDayRainWind: &icondata_core::IconData = &icondata_core::IconData {
 	z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayShowers, "Day Showers"))]
 pub static WiDayShowers: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayShowers",
+    label: "Day Showers",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -863,8 +1018,14 @@ pub static WiDayShowers: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.17-0.16-0.37-0.24-0.62-0.24h-2.04c-0.23,0-0.43,0.08-0.59,0.25C24.79,11.2,24.71,11.41,24.71,11.64z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDaySleet, "Day Sleet"))]
 pub static WiDaySleet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDaySleet",
+    label: "Day Sleet",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -919,8 +1080,14 @@ pub static WiDaySleet: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.17,0.17-0.37,0.25-0.61,0.25h-2.06c-0.24,0-0.44-0.09-0.6-0.27C24.81,12.05,24.73,11.85,24.73,11.61z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDaySleetStorm, "Day Sleet Storm"))]
 pub static WiDaySleetStorm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDaySleetStorm",
+    label: "Day Sleet Storm",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -969,8 +1136,14 @@ pub static WiDaySleetStorm: &icondata_core::IconData = &icondata_core::IconData
 	s-0.08-0.44-0.25-0.6c-0.17-0.16-0.37-0.24-0.61-0.24h-2.06c-0.24,0-0.45,0.08-0.61,0.24C24.81,11.14,24.73,11.34,24.73,11.58z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDaySnow, "Day Snow"))]
 pub static WiDaySnow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDaySnow",
+    label: "Day Snow",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1019,8 +1192,14 @@ pub static WiDaySnow: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.24,0-0.44,0.08-0.62,0.25S24.67,11.37,24.67,11.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDaySnowThunderstorm, "Day Snow Thunderstorm"))]
 pub static WiDaySnowThunderstorm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDaySnowThunderstorm",
+    label: "Day Snow Thunderstorm",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1066,8 +1245,14 @@ pub static WiDaySnowThunderstorm: &icondata_core::IconData = &icondata_core::Ico
 	c-0.24,0-0.45,0.08-0.61,0.24C24.81,11.14,24.73,11.34,24.73,11.58z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDaySnowWind, "Day Snow Wind"))]
 pub static WiDaySnowWind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDaySnowWind",
+    label: "Day Snow Wind",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1116,8 +1301,14 @@ pub static WiDaySnowWind: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.24,0-0.44,0.08-0.62,0.25S24.67,11.37,24.67,11.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDaySprinkle, "Day Sprinkle"))]
 pub static WiDaySprinkle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDaySprinkle",
+    label: "Day Sprinkle",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1159,8 +1350,14 @@ pub static WiDaySprinkle: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.24,0-0.44,0.08-0.62,0.25S24.67,11.37,24.67,11.6z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayStormShowers, "Day Storm Showers"))]
 pub static WiDayStormShowers: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayStormShowers",
+    label: "Day Storm Showers",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1207,8 +1404,14 @@ pub static WiDayStormShowers: &icondata_core::IconData = &icondata_core::IconDat
 	c-0.24,0-0.45,0.08-0.61,0.24C24.81,11.14,24.73,11.34,24.73,11.58z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDaySunny, "Day Sunny"))]
 pub static WiDaySunny: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDaySunny",
+    label: "Day Sunny",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1244,8 +1447,14 @@ pub static WiDaySunny: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.23,0-0.43-0.08-0.58-0.25S21.92,14.86,21.92,14.62z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDaySunnyOvercast, "Day Sunny Overcast"))]
 pub static WiDaySunnyOvercast: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDaySunnyOvercast",
+    label: "Day Sunny Overcast",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1283,8 +1492,14 @@ pub static WiDaySunnyOvercast: &icondata_core::IconData = &icondata_core::IconDa
 	C23.35,12.89,23.25,13.13,23.25,13.43z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayThunderstorm, "Day Thunderstorm"))]
 pub static WiDayThunderstorm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayThunderstorm",
+    label: "Day Thunderstorm",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1327,8 +1542,14 @@ pub static WiDayThunderstorm: &icondata_core::IconData = &icondata_core::IconDat
 	" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDayWindy, "Day Windy"))]
 pub static WiDayWindy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDayWindy",
+    label: "Day Windy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1373,8 +1594,14 @@ pub static WiDayWindy: &icondata_core::IconData = &icondata_core::IconData {
 	z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDegrees, "Degrees"))]
 pub static WiDegrees: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDegrees",
+    label: "Degrees",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1393,8 +1620,14 @@ pub static WiDegrees: &icondata_core::IconData = &icondata_core::IconData {
 	C14.16,8.72,14.07,8.94,14.07,9.21z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDirectionDown, "Direction Down"))]
 pub static WiDirectionDown: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDirectionDown",
+    label: "Direction Down",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1412,8 +1645,14 @@ pub static WiDirectionDown: &icondata_core::IconData = &icondata_core::IconData
 	c-0.16-0.16-0.34-0.23-0.55-0.23c-0.22,0-0.42,0.08-0.57,0.23S11.77,16.25,11.77,16.47z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDirectionDownLeft, "Direction Down Left"))]
 pub static WiDirectionDownLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDirectionDownLeft",
+    label: "Direction Down Left",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1432,8 +1671,14 @@ pub static WiDirectionDownLeft: &icondata_core::IconData = &icondata_core::IconD
 	V16.77z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDirectionDownRight, "Direction Down Right"))]
 pub static WiDirectionDownRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDirectionDownRight",
+    label: "Direction Down Right",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1451,8 +1696,14 @@ pub static WiDirectionDownRight: &icondata_core::IconData = &icondata_core::Icon
 	l-6.08-6.06C10.13,10.63,10.04,10.38,10.04,10.08z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDirectionLeft, "Direction Left"))]
 pub static WiDirectionLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDirectionLeft",
+    label: "Direction Left",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1470,8 +1721,14 @@ pub static WiDirectionLeft: &icondata_core::IconData = &icondata_core::IconData
 	c-0.32,0-0.61,0.14-0.85,0.41l-3.84,3.75C7.21,14.31,7.09,14.6,7.09,14.96z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDirectionRight, "Direction Right"))]
 pub static WiDirectionRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDirectionRight",
+    label: "Direction Right",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1490,8 +1747,14 @@ pub static WiDirectionRight: &icondata_core::IconData = &icondata_core::IconData
 	C10.01,13.95,9.94,14.14,9.94,14.36z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDirectionUp, "Direction Up"))]
 pub static WiDirectionUp: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDirectionUp",
+    label: "Direction Up",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1509,8 +1772,14 @@ pub static WiDirectionUp: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.24-0.23-0.55-0.37-0.92-0.4c-0.37,0.02-0.68,0.16-0.92,0.41l-3.75,3.81C10.08,10.25,9.95,10.53,9.95,10.87z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDirectionUpLeft, "Direction Up Left"))]
 pub static WiDirectionUpLeft: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDirectionUpLeft",
+    label: "Direction Up Left",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1528,8 +1797,14 @@ pub static WiDirectionUpLeft: &icondata_core::IconData = &icondata_core::IconDat
 	c-0.29,0-0.54-0.1-0.74-0.31S10.03,14.6,10.03,14.31z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDirectionUpRight, "Direction Up Right"))]
 pub static WiDirectionUpRight: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDirectionUpRight",
+    label: "Direction Up Right",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1547,8 +1822,14 @@ pub static WiDirectionUpRight: &icondata_core::IconData = &icondata_core::IconDa
 	l-6.06,6.06C10.14,16.99,10.05,17.25,10.05,17.55z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiDust, "Dust"))]
 pub static WiDust: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiDust",
+    label: "Dust",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1588,8 +1869,14 @@ pub static WiDust: &icondata_core::IconData = &icondata_core::IconData {
 	c0,0.27-0.08,0.48-0.24,0.64c-0.16,0.16-0.37,0.24-0.64,0.24c-0.23,0-0.41-0.08-0.55-0.24C21.1,11.15,21.03,10.94,21.03,10.67z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiEarthquake, "Earthquake"))]
 pub static WiEarthquake: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiEarthquake",
+    label: "Earthquake",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1614,8 +1901,14 @@ pub static WiEarthquake: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.11,0.09-0.18,0.2-0.2,0.35l-1.48,8.61H5.82c-0.16,0-0.29,0.06-0.4,0.17C5.31,15.01,5.25,15.14,5.25,15.3z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiFahrenheit, "Fahrenheit"))]
 pub static WiFahrenheit: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiFahrenheit",
+    label: "Fahrenheit",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1637,8 +1930,14 @@ pub static WiFahrenheit: &icondata_core::IconData = &icondata_core::IconData {
 	" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiFire, "Fire"))]
 pub static WiFire: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiFire",
+    label: "Fire",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1669,8 +1968,14 @@ pub static WiFire: &icondata_core::IconData = &icondata_core::IconData {
 	C8.29,17.1,8.22,16.75,8.2,16.37z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiFlood, "Flood"))]
 pub static WiFlood: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiFlood",
+    label: "Flood",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1701,8 +2006,14 @@ pub static WiFlood: &icondata_core::IconData = &icondata_core::IconData {
 	C12.29,9.97,12.23,9.83,12.23,9.67z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiFog, "Fog"))]
 pub static WiFog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiFog",
+    label: "Fog",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1729,8 +2040,14 @@ pub static WiFog: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.23,0-0.43-0.09-0.6-0.26C7.07,24.55,6.98,24.34,6.98,24.11z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiGaleWarning, "Gale Warning"))]
 pub static WiGaleWarning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiGaleWarning",
+    label: "Gale Warning",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1745,8 +2062,14 @@ pub static WiGaleWarning: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M10.67,24.6V7.45h1.03V24.6H10.67z M12.4,22.44v-7.41l8.65,3.69L12.4,22.44z M12.4,14.86V7.45l8.65,3.69L12.4,14.86z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiHail, "Hail"))]
 pub static WiHail: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiHail",
+    label: "Hail",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1785,8 +2108,14 @@ pub static WiHail: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.16,0-0.32,0.05-0.47,0.15s-0.25,0.25-0.31,0.45l-0.9,3.36L17.47,21.23z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiHorizon, "Horizon"))]
 pub static WiHorizon: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiHorizon",
+    label: "Horizon",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1812,8 +2141,14 @@ pub static WiHorizon: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.17,0.17-0.36,0.26-0.56,0.28c-0.23,0.02-0.44-0.06-0.65-0.24S19.86,14.43,19.86,14.18z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiHorizonAlt, "Horizon Alt"))]
 pub static WiHorizonAlt: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiHorizonAlt",
+    label: "Horizon Alt",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1846,8 +2181,14 @@ pub static WiHorizonAlt: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.18,0.17-0.38,0.25-0.62,0.25h-2.09c-0.23,0-0.43-0.08-0.59-0.25C22.18,16.34,22.1,16.14,22.1,15.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiHot, "Hot"))]
 pub static WiHot: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiHot",
+    label: "Hot",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1912,8 +2253,14 @@ pub static WiHot: &icondata_core::IconData = &icondata_core::IconData {
 	z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiHumidity, "Humidity"))]
 pub static WiHumidity: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiHumidity",
+    label: "Humidity",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1945,8 +2292,14 @@ pub static WiHumidity: &icondata_core::IconData = &icondata_core::IconData {
 	" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiHurricane, "Hurricane"))]
 pub static WiHurricane: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiHurricane",
+    label: "Hurricane",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1972,8 +2325,14 @@ pub static WiHurricane: &icondata_core::IconData = &icondata_core::IconData {
 	z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiHurricaneWarning, "Hurricane Warning"))]
 pub static WiHurricaneWarning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiHurricaneWarning",
+    label: "Hurricane Warning",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -1989,8 +2348,14 @@ pub static WiHurricaneWarning: &icondata_core::IconData = &icondata_core::IconDa
 	h-3.81V19.52z M12.09,12.05h3.81v-2.5h-3.81V12.05z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiLightning, "Lightning"))]
 pub static WiLightning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiLightning",
+    label: "Lightning",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2008,8 +2373,14 @@ pub static WiLightning: &icondata_core::IconData = &icondata_core::IconData {
 	l-2.08,5.48c-0.03,0.09-0.03,0.16,0.01,0.21c0.04,0.05,0.1,0.07,0.19,0.07h2.04L16.46,18.18z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiLunarEclipse, "Lunar Eclipse"))]
 pub static WiLunarEclipse: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiLunarEclipse",
+    label: "Lunar Eclipse",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2029,8 +2400,14 @@ pub static WiLunarEclipse: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.69-0.68-1.53-1.02-2.5-1.02C14.74,11.14,14.49,11.17,14.25,11.22z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMeteor, "Meteor"))]
 pub static WiMeteor: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMeteor",
+    label: "Meteor",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2056,8 +2433,14 @@ pub static WiMeteor: &icondata_core::IconData = &icondata_core::IconData {
 	c-1,0-1.87,0.33-2.6,1c-0.41,0.36-0.72,0.78-0.95,1.28C8.4,18.3,8.29,18.83,8.29,19.39z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltFirstQuarter, "Moon Alt First Quarter"))]
 pub static WiMoonAltFirstQuarter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltFirstQuarter",
+    label: "Moon Alt First Quarter",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2076,8 +2459,14 @@ pub static WiMoonAltFirstQuarter: &icondata_core::IconData = &icondata_core::Ico
 	s-1.25-2.31-2.15-3.21s-1.97-1.61-3.21-2.15s-2.54-0.8-3.91-0.8H14.8V24.51z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltFull, "Moon Alt Full"))]
 pub static WiMoonAltFull: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltFull",
+    label: "Moon Alt Full",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2097,8 +2486,14 @@ pub static WiMoonAltFull: &icondata_core::IconData = &icondata_core::IconData {
 	c-1.36,0-2.66,0.27-3.9,0.8S8.79,6.41,7.89,7.32s-1.61,1.98-2.15,3.22S4.94,13.08,4.94,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltNew, "Moon Alt New"))]
 pub static WiMoonAltNew: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltNew",
+    label: "Moon Alt New",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2115,8 +2510,14 @@ pub static WiMoonAltNew: &icondata_core::IconData = &icondata_core::IconData {
 	S3.74,12.4,3.74,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltThirdQuarter, "Moon Alt Third Quarter"))]
 pub static WiMoonAltThirdQuarter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltThirdQuarter",
+    label: "Moon Alt Third Quarter",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2135,8 +2536,14 @@ pub static WiMoonAltThirdQuarter: &icondata_core::IconData = &icondata_core::Ico
 	S8.79,6.44,7.89,7.34s-1.61,1.97-2.15,3.21S4.94,13.09,4.94,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaningCrescent1, "Moon Alt Waning Crescent 1"))]
 pub static WiMoonAltWaningCrescent1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaningCrescent1",
+    label: "Moon Alt Waning Crescent 1",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2155,8 +2562,14 @@ pub static WiMoonAltWaningCrescent1: &icondata_core::IconData = &icondata_core::
 	c0-3.39,0.3-6.74,0.91-10.05h-0.1c-1.36,0-2.66,0.27-3.9,0.8S8.79,6.44,7.89,7.34s-1.61,1.97-2.15,3.21S4.94,13.09,4.94,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaningCrescent2, "Moon Alt Waning Crescent 2"))]
 pub static WiMoonAltWaningCrescent2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaningCrescent2",
+    label: "Moon Alt Waning Crescent 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2175,8 +2588,14 @@ pub static WiMoonAltWaningCrescent2: &icondata_core::IconData = &icondata_core::
 	c0-3.61,0.6-6.96,1.8-10.05c-1.36,0-2.65,0.27-3.89,0.81s-2.3,1.25-3.19,2.15s-1.61,1.97-2.14,3.2S4.94,13.09,4.94,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaningCrescent3, "Moon Alt Waning Crescent 3"))]
 pub static WiMoonAltWaningCrescent3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaningCrescent3",
+    label: "Moon Alt Waning Crescent 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2195,8 +2614,14 @@ pub static WiMoonAltWaningCrescent3: &icondata_core::IconData = &icondata_core::
 	c0-1.87,0.23-3.67,0.69-5.41s1.11-3.29,1.95-4.64c-1.8,0.03-3.45,0.5-4.96,1.41s-2.7,2.13-3.58,3.65S4.94,12.65,4.94,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaningCrescent4, "Moon Alt Waning Crescent 4"))]
 pub static WiMoonAltWaningCrescent4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaningCrescent4",
+    label: "Moon Alt Waning Crescent 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2216,8 +2641,14 @@ pub static WiMoonAltWaningCrescent4: &icondata_core::IconData = &icondata_core::
 	S4.94,12.68,4.94,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaningCrescent5, "Moon Alt Waning Crescent 5"))]
 pub static WiMoonAltWaningCrescent5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaningCrescent5",
+    label: "Moon Alt Waning Crescent 5",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2237,8 +2668,14 @@ pub static WiMoonAltWaningCrescent5: &icondata_core::IconData = &icondata_core::
 	s-1.46,1.92-1.93,3.11S4.94,13.15,4.94,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaningCrescent6, "Moon Alt Waning Crescent 6"))]
 pub static WiMoonAltWaningCrescent6: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaningCrescent6",
+    label: "Moon Alt Waning Crescent 6",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2258,8 +2695,14 @@ pub static WiMoonAltWaningCrescent6: &icondata_core::IconData = &icondata_core::
 	S4.94,12.77,4.94,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaningGibbous1, "Moon Alt Waning Gibbous 1"))]
 pub static WiMoonAltWaningGibbous1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaningGibbous1",
+    label: "Moon Alt Waning Gibbous 1",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2279,8 +2722,14 @@ pub static WiMoonAltWaningGibbous1: &icondata_core::IconData = &icondata_core::I
 	c-0.4-0.09-1.01-0.13-1.82-0.13c-1.36,0-2.66,0.26-3.9,0.79S8.8,6.43,7.9,7.32s-1.62,1.97-2.15,3.2S4.94,13.06,4.94,14.43z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaningGibbous2, "Moon Alt Waning Gibbous 2"))]
 pub static WiMoonAltWaningGibbous2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaningGibbous2",
+    label: "Moon Alt Waning Gibbous 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2301,8 +2750,14 @@ pub static WiMoonAltWaningGibbous2: &icondata_core::IconData = &icondata_core::I
 	s-1.61,1.97-2.15,3.21S4.94,13.09,4.94,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaningGibbous3, "Moon Alt Waning Gibbous 3"))]
 pub static WiMoonAltWaningGibbous3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaningGibbous3",
+    label: "Moon Alt Waning Gibbous 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2323,8 +2778,14 @@ pub static WiMoonAltWaningGibbous3: &icondata_core::IconData = &icondata_core::I
 	S4.94,13.09,4.94,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaningGibbous4, "Moon Alt Waning Gibbous 4"))]
 pub static WiMoonAltWaningGibbous4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaningGibbous4",
+    label: "Moon Alt Waning Gibbous 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2344,8 +2805,14 @@ pub static WiMoonAltWaningGibbous4: &icondata_core::IconData = &icondata_core::I
 	c-1.36,0-2.66,0.27-3.9,0.8S8.79,6.44,7.89,7.34s-1.61,1.97-2.15,3.21S4.94,13.09,4.94,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaningGibbous5, "Moon Alt Waning Gibbous 5"))]
 pub static WiMoonAltWaningGibbous5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaningGibbous5",
+    label: "Moon Alt Waning Gibbous 5",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2365,8 +2832,14 @@ pub static WiMoonAltWaningGibbous5: &icondata_core::IconData = &icondata_core::I
 	c-1.36,0-2.66,0.27-3.9,0.8S8.79,6.44,7.89,7.34s-1.61,1.97-2.15,3.21S4.94,13.09,4.94,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaningGibbous6, "Moon Alt Waning Gibbous 6"))]
 pub static WiMoonAltWaningGibbous6: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaningGibbous6",
+    label: "Moon Alt Waning Gibbous 6",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2385,8 +2858,14 @@ pub static WiMoonAltWaningGibbous6: &icondata_core::IconData = &icondata_core::I
 	c0-3.82-0.34-7.17-1.03-10.05h-0.37c-1.36,0-2.66,0.27-3.9,0.8S8.79,6.44,7.89,7.34s-1.61,1.97-2.15,3.21S4.94,13.09,4.94,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaxingCrescent1, "Moon Alt Waxing Crescent 1"))]
 pub static WiMoonAltWaxingCrescent1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaxingCrescent1",
+    label: "Moon Alt Waxing Crescent 1",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2405,8 +2884,14 @@ pub static WiMoonAltWaxingCrescent1: &icondata_core::IconData = &icondata_core::
 	c0-1.66-0.38-3.21-1.14-4.66s-1.8-2.63-3.13-3.57S18,4.71,16.38,4.48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaxingCrescent2, "Moon Alt Waxing Crescent 2"))]
 pub static WiMoonAltWaxingCrescent2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaxingCrescent2",
+    label: "Moon Alt Waxing Crescent 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2425,8 +2910,14 @@ pub static WiMoonAltWaxingCrescent2: &icondata_core::IconData = &icondata_core::
 	s1.17-3.03,1.17-4.72c0-1.72-0.41-3.32-1.22-4.8s-1.91-2.69-3.31-3.61S17.59,4.57,15.9,4.42z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaxingCrescent3, "Moon Alt Waxing Crescent 3"))]
 pub static WiMoonAltWaxingCrescent3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaxingCrescent3",
+    label: "Moon Alt Waxing Crescent 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2445,8 +2936,14 @@ pub static WiMoonAltWaxingCrescent3: &icondata_core::IconData = &icondata_core::
 	s1.49-1.93,1.97-3.13s0.73-2.44,0.73-3.74c0-1.75-0.42-3.38-1.26-4.89s-1.99-2.72-3.44-3.64S17.31,4.5,15.58,4.41z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaxingCrescent4, "Moon Alt Waxing Crescent 4"))]
 pub static WiMoonAltWaxingCrescent4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaxingCrescent4",
+    label: "Moon Alt Waxing Crescent 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2465,8 +2962,14 @@ pub static WiMoonAltWaxingCrescent4: &icondata_core::IconData = &icondata_core::
 	s1.53-1.95,2.04-3.16s0.75-2.48,0.75-3.81c0-1.78-0.43-3.43-1.3-4.94s-2.04-2.73-3.53-3.65S17.12,4.45,15.35,4.39z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaxingCrescent5, "Moon Alt Waxing Crescent 5"))]
 pub static WiMoonAltWaxingCrescent5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaxingCrescent5",
+    label: "Moon Alt Waxing Crescent 5",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2485,8 +2988,14 @@ pub static WiMoonAltWaxingCrescent5: &icondata_core::IconData = &icondata_core::
 	s1.57-1.95,2.09-3.18s0.78-2.51,0.78-3.86c0-1.8-0.44-3.46-1.33-5s-2.08-2.75-3.6-3.65S16.95,4.42,15.15,4.39z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaxingCrescent6, "Moon Alt Waxing Crescent 6"))]
 pub static WiMoonAltWaxingCrescent6: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaxingCrescent6",
+    label: "Moon Alt Waxing Crescent 6",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2505,8 +3014,14 @@ pub static WiMoonAltWaxingCrescent6: &icondata_core::IconData = &icondata_core::
 	s0.79-2.54,0.79-3.9s-0.27-2.66-0.8-3.9s-1.25-2.31-2.15-3.21s-1.97-1.61-3.21-2.15S16.36,4.39,15.01,4.39z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaxingGibbous1, "Moon Alt Waxing Gibbous 1"))]
 pub static WiMoonAltWaxingGibbous1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaxingGibbous1",
+    label: "Moon Alt Waxing Gibbous 1",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2526,8 +3041,14 @@ pub static WiMoonAltWaxingGibbous1: &icondata_core::IconData = &icondata_core::I
 	z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaxingGibbous2, "Moon Alt Waxing Gibbous 2"))]
 pub static WiMoonAltWaxingGibbous2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaxingGibbous2",
+    label: "Moon Alt Waxing Gibbous 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2547,8 +3068,14 @@ pub static WiMoonAltWaxingGibbous2: &icondata_core::IconData = &icondata_core::I
 	c-0.23,0-0.42,0-0.54,0.01C12.79,7.55,11.96,10.9,11.96,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaxingGibbous3, "Moon Alt Waxing Gibbous 3"))]
 pub static WiMoonAltWaxingGibbous3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaxingGibbous3",
+    label: "Moon Alt Waxing Gibbous 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2568,8 +3095,14 @@ pub static WiMoonAltWaxingGibbous3: &icondata_core::IconData = &icondata_core::I
 	c-0.36,0-0.63,0.01-0.81,0.03c-1.08,1.22-1.96,2.69-2.64,4.42S10.54,12.43,10.54,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaxingGibbous4, "Moon Alt Waxing Gibbous 4"))]
 pub static WiMoonAltWaxingGibbous4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaxingGibbous4",
+    label: "Moon Alt Waxing Gibbous 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2589,8 +3122,14 @@ pub static WiMoonAltWaxingGibbous4: &icondata_core::IconData = &icondata_core::I
 	c-1.4,1.06-2.53,2.46-3.39,4.2S9.13,12.29,9.13,14.43z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaxingGibbous5, "Moon Alt Waxing Gibbous 5"))]
 pub static WiMoonAltWaxingGibbous5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaxingGibbous5",
+    label: "Moon Alt Waxing Gibbous 5",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2610,8 +3149,14 @@ pub static WiMoonAltWaxingGibbous5: &icondata_core::IconData = &icondata_core::I
 	c-1.63,0.94-2.95,2.27-3.95,3.99S7.71,12.22,7.71,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonAltWaxingGibbous6, "Moon Alt Waxing Gibbous 6"))]
 pub static WiMoonAltWaxingGibbous6: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonAltWaxingGibbous6",
+    label: "Moon Alt Waxing Gibbous 6",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2632,8 +3177,14 @@ pub static WiMoonAltWaxingGibbous6: &icondata_core::IconData = &icondata_core::I
 	S9.1,7.01,8.66,7.54S7.84,8.62,7.53,9.16s-0.54,1.12-0.69,1.74s-0.26,1.2-0.32,1.75S6.42,13.8,6.42,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonFirstQuarter, "Moon First Quarter"))]
 pub static WiMoonFirstQuarter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonFirstQuarter",
+    label: "Moon First Quarter",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2649,8 +3200,14 @@ pub static WiMoonFirstQuarter: &icondata_core::IconData = &icondata_core::IconDa
 	s-3.61-1.51-5.65-1.51V25.71z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonFull, "Moon Full"))]
 pub static WiMoonFull: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonFull",
+    label: "Moon Full",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2667,8 +3224,14 @@ pub static WiMoonFull: &icondata_core::IconData = &icondata_core::IconData {
 	S3.74,12.4,3.74,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonNew, "Moon New"))]
 pub static WiMoonNew: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonNew",
+    label: "Moon New",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2688,8 +3251,14 @@ pub static WiMoonNew: &icondata_core::IconData = &icondata_core::IconData {
 	c-1.36,0-2.66,0.27-3.9,0.8S8.79,6.41,7.89,7.32s-1.61,1.98-2.15,3.22S4.94,13.08,4.94,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonThirdQuarter, "Moon Third Quarter"))]
 pub static WiMoonThirdQuarter: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonThirdQuarter",
+    label: "Moon Third Quarter",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2705,8 +3274,14 @@ pub static WiMoonThirdQuarter: &icondata_core::IconData = &icondata_core::IconDa
 	S6.26,7.07,5.25,8.8S3.74,12.4,3.74,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaningCrescent1, "Moon Waning Crescent 1"))]
 pub static WiMoonWaningCrescent1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaningCrescent1",
+    label: "Moon Waning Crescent 1",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2722,8 +3297,14 @@ pub static WiMoonWaningCrescent1: &icondata_core::IconData = &icondata_core::Ico
 	c0-3.43,0.5-7.18,1.49-11.25c-2.04,0-3.92,0.5-5.65,1.51S6.26,7.07,5.25,8.8S3.74,12.4,3.74,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaningCrescent2, "Moon Waning Crescent 2"))]
 pub static WiMoonWaningCrescent2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaningCrescent2",
+    label: "Moon Waning Crescent 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2739,8 +3320,14 @@ pub static WiMoonWaningCrescent2: &icondata_core::IconData = &icondata_core::Ico
 	c0-3.98,1.01-7.73,3.02-11.25c-2.04,0-3.93,0.5-5.65,1.51s-3.1,2.37-4.1,4.09S3.74,12.4,3.74,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaningCrescent3, "Moon Waning Crescent 3"))]
 pub static WiMoonWaningCrescent3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaningCrescent3",
+    label: "Moon Waning Crescent 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2756,8 +3343,14 @@ pub static WiMoonWaningCrescent3: &icondata_core::IconData = &icondata_core::Ico
 	c0-2.32,0.42-4.46,1.25-6.4s1.91-3.56,3.23-4.85c-2.04,0-3.92,0.5-5.65,1.51S6.26,7.07,5.25,8.8S3.74,12.4,3.74,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaningCrescent4, "Moon Waning Crescent 4"))]
 pub static WiMoonWaningCrescent4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaningCrescent4",
+    label: "Moon Waning Crescent 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2774,8 +3367,14 @@ pub static WiMoonWaningCrescent4: &icondata_core::IconData = &icondata_core::Ico
 	S3.74,12.4,3.74,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaningCrescent5, "Moon Waning Crescent 5"))]
 pub static WiMoonWaningCrescent5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaningCrescent5",
+    label: "Moon Waning Crescent 5",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2792,8 +3391,14 @@ pub static WiMoonWaningCrescent5: &icondata_core::IconData = &icondata_core::Ico
 	c-2.04,0-3.92,0.5-5.65,1.51S6.26,7.07,5.25,8.8S3.74,12.4,3.74,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaningCrescent6, "Moon Waning Crescent 6"))]
 pub static WiMoonWaningCrescent6: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaningCrescent6",
+    label: "Moon Waning Crescent 6",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2811,8 +3416,14 @@ pub static WiMoonWaningCrescent6: &icondata_core::IconData = &icondata_core::Ico
 	S6.26,7.07,5.25,8.8S3.74,12.4,3.74,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaningGibbous1, "Moon Waning Gibbous 1"))]
 pub static WiMoonWaningGibbous1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaningGibbous1",
+    label: "Moon Waning Gibbous 1",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2829,8 +3440,14 @@ pub static WiMoonWaningGibbous1: &icondata_core::IconData = &icondata_core::Icon
 	s-1.47-0.81-2.26-1.07c-1.52,0-2.98,0.3-4.37,0.89S8.02,5.57,7.02,6.57s-1.8,2.19-2.39,3.57S3.74,12.97,3.74,14.49z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaningGibbous2, "Moon Waning Gibbous 2"))]
 pub static WiMoonWaningGibbous2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaningGibbous2",
+    label: "Moon Waning Gibbous 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2847,8 +3464,14 @@ pub static WiMoonWaningGibbous2: &icondata_core::IconData = &icondata_core::Icon
 	c-2.03,0-3.91,0.5-5.63,1.5S6.25,7.14,5.24,8.86S3.74,12.46,3.74,14.49z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaningGibbous3, "Moon Waning Gibbous 3"))]
 pub static WiMoonWaningGibbous3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaningGibbous3",
+    label: "Moon Waning Gibbous 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2865,8 +3488,14 @@ pub static WiMoonWaningGibbous3: &icondata_core::IconData = &icondata_core::Icon
 	c-1.52,0-2.98,0.3-4.37,0.89s-2.58,1.39-3.58,2.4s-1.8,2.2-2.39,3.59S3.74,12.96,3.74,14.49z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaningGibbous4, "Moon Waning Gibbous 4"))]
 pub static WiMoonWaningGibbous4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaningGibbous4",
+    label: "Moon Waning Gibbous 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2883,8 +3512,14 @@ pub static WiMoonWaningGibbous4: &icondata_core::IconData = &icondata_core::Icon
 	S3.74,12.44,3.74,14.47z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaningGibbous5, "Moon Waning Gibbous 5"))]
 pub static WiMoonWaningGibbous5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaningGibbous5",
+    label: "Moon Waning Gibbous 5",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2900,8 +3535,14 @@ pub static WiMoonWaningGibbous5: &icondata_core::IconData = &icondata_core::Icon
 	c0-3.96-1.08-7.71-3.25-11.24c-2.03,0-3.91,0.5-5.63,1.5S6.26,7.1,5.25,8.83S3.74,12.44,3.74,14.47z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaningGibbous6, "Moon Waning Gibbous 6"))]
 pub static WiMoonWaningGibbous6: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaningGibbous6",
+    label: "Moon Waning Gibbous 6",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2917,8 +3558,14 @@ pub static WiMoonWaningGibbous6: &icondata_core::IconData = &icondata_core::Icon
 	c0-3.46-0.72-7.2-2.15-11.24c-1.52,0-2.98,0.3-4.37,0.89S8.03,5.5,7.03,6.5s-1.8,2.2-2.4,3.59S3.74,12.93,3.74,14.46z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaxingCrescent1, "Moon Waxing Crescent 1"))]
 pub static WiMoonWaxingCrescent1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaxingCrescent1",
+    label: "Moon Waxing Crescent 1",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2936,8 +3583,14 @@ pub static WiMoonWaxingCrescent1: &icondata_core::IconData = &icondata_core::Ico
 	s-1.14,0.92-1.87,1.33S15.9,25.42,15.01,25.71z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaxingCrescent2, "Moon Waxing Crescent 2"))]
 pub static WiMoonWaxingCrescent2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaxingCrescent2",
+    label: "Moon Waxing Crescent 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2954,8 +3607,14 @@ pub static WiMoonWaxingCrescent2: &icondata_core::IconData = &icondata_core::Ico
 	c0,0.91-0.04,1.76-0.13,2.54s-0.27,1.63-0.53,2.53s-0.62,1.71-1.06,2.43s-1.04,1.42-1.82,2.09S16.03,25.26,15.01,25.71z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaxingCrescent3, "Moon Waxing Crescent 3"))]
 pub static WiMoonWaxingCrescent3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaxingCrescent3",
+    label: "Moon Waxing Crescent 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2972,8 +3631,14 @@ pub static WiMoonWaxingCrescent3: &icondata_core::IconData = &icondata_core::Ico
 	s-0.51,1.68-0.86,2.4s-0.83,1.42-1.45,2.12S15.83,25.21,15.01,25.71z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaxingCrescent4, "Moon Waxing Crescent 4"))]
 pub static WiMoonWaxingCrescent4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaxingCrescent4",
+    label: "Moon Waxing Crescent 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -2990,8 +3655,14 @@ pub static WiMoonWaxingCrescent4: &icondata_core::IconData = &icondata_core::Ico
 	s-0.38,1.65-0.64,2.37s-0.63,1.43-1.09,2.15S15.62,25.15,15.01,25.71z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaxingCrescent5, "Moon Waxing Crescent 5"))]
 pub static WiMoonWaxingCrescent5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaxingCrescent5",
+    label: "Moon Waxing Crescent 5",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3007,8 +3678,14 @@ pub static WiMoonWaxingCrescent5: &icondata_core::IconData = &icondata_core::Ico
 	s-3.61-1.51-5.65-1.51c1.67,2.9,2.5,6.65,2.5,11.25c0,2.33-0.17,4.43-0.52,6.3S15.97,24.26,14.99,25.71z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaxingCrescent6, "Moon Waxing Crescent 6"))]
 pub static WiMoonWaxingCrescent6: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaxingCrescent6",
+    label: "Moon Waxing Crescent 6",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3024,8 +3701,14 @@ pub static WiMoonWaxingCrescent6: &icondata_core::IconData = &icondata_core::Ico
 	s-3.61-1.51-5.65-1.51c1.67,2.9,2.5,6.65,2.5,11.25c0,2.33-0.17,4.43-0.52,6.3S15.97,24.26,14.99,25.71z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaxingGibbous1, "Moon Waxing Gibbous 1"))]
 pub static WiMoonWaxingGibbous1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaxingGibbous1",
+    label: "Moon Waxing Gibbous 1",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3041,8 +3724,14 @@ pub static WiMoonWaxingGibbous1: &icondata_core::IconData = &icondata_core::Icon
 	c0.01-2.04-0.49-3.93-1.49-5.65c-1-1.73-2.36-3.1-4.08-4.11s-3.6-1.52-5.64-1.53C14.32,6.91,13.94,10.66,13.93,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaxingGibbous2, "Moon Waxing Gibbous 2"))]
 pub static WiMoonWaxingGibbous2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaxingGibbous2",
+    label: "Moon Waxing Gibbous 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3058,8 +3747,14 @@ pub static WiMoonWaxingGibbous2: &icondata_core::IconData = &icondata_core::Icon
 	s-0.5-3.92-1.51-5.65s-2.37-3.09-4.1-4.09s-3.61-1.51-5.65-1.51C13.57,6.61,12.85,10.36,12.85,14.44z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaxingGibbous3, "Moon Waxing Gibbous 3"))]
 pub static WiMoonWaxingGibbous3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaxingGibbous3",
+    label: "Moon Waxing Gibbous 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3076,8 +3771,14 @@ pub static WiMoonWaxingGibbous3: &icondata_core::IconData = &icondata_core::Icon
 	c-1.02,1.46-1.81,3.16-2.37,5.13S11.8,12.3,11.8,14.43z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaxingGibbous4, "Moon Waxing Gibbous 4"))]
 pub static WiMoonWaxingGibbous4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaxingGibbous4",
+    label: "Moon Waxing Gibbous 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3094,8 +3795,14 @@ pub static WiMoonWaxingGibbous4: &icondata_core::IconData = &icondata_core::Icon
 	c-1.35,1.3-2.4,2.94-3.16,4.93S10.73,12.19,10.73,14.43z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaxingGibbous5, "Moon Waxing Gibbous 5"))]
 pub static WiMoonWaxingGibbous5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaxingGibbous5",
+    label: "Moon Waxing Gibbous 5",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3112,8 +3819,14 @@ pub static WiMoonWaxingGibbous5: &icondata_core::IconData = &icondata_core::Icon
 	s-2.2-1.81-3.6-2.4s-2.85-0.89-4.37-0.89c-1.67,1.14-2.98,2.72-3.94,4.74S9.65,12.09,9.65,14.43z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonWaxingGibbous6, "Moon Waxing Gibbous 6"))]
 pub static WiMoonWaxingGibbous6: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonWaxingGibbous6",
+    label: "Moon Waxing Gibbous 6",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3130,8 +3843,14 @@ pub static WiMoonWaxingGibbous6: &icondata_core::IconData = &icondata_core::Icon
 	s-3.61-1.51-5.65-1.51c-1.99,1-3.56,2.51-4.72,4.55S8.58,11.99,8.58,14.43z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonrise, "Moonrise"))]
 pub static WiMoonrise: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonrise",
+    label: "Moonrise",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3153,8 +3872,14 @@ pub static WiMoonrise: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.27,0-0.5-0.1-0.69-0.29S8.09,21.14,8.09,20.87z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiMoonset, "Moonset"))]
 pub static WiMoonset: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiMoonset",
+    label: "Moonset",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3177,8 +3902,14 @@ pub static WiMoonset: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.12,0.09-0.24,0.09-0.34,0l-3.08-2.84h-2.6c-0.27,0-0.5-0.1-0.69-0.29C8.09,21.39,7.99,21.16,7.99,20.89z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNa, "Na"))]
 pub static WiNa: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNa",
+    label: "Na",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3195,8 +3926,14 @@ pub static WiNa: &icondata_core::IconData = &icondata_core::IconData {
 	l0.6,2.04H19.07z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltCloudy, "Night Alt Cloudy"))]
 pub static WiNightAltCloudy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltCloudy",
+    label: "Night Alt Cloudy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3224,8 +3961,14 @@ pub static WiNightAltCloudy: &icondata_core::IconData = &icondata_core::IconData
 	c-0.58-0.01-1.15,0.13-1.7,0.44C18.38,7.82,17.93,8.24,17.6,8.79z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltCloudyGusts, "Night Alt Cloudy Gusts"))]
 pub static WiNightAltCloudyGusts: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltCloudyGusts",
+    label: "Night Alt Cloudy Gusts",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3262,8 +4005,14 @@ pub static WiNightAltCloudyGusts: &icondata_core::IconData = &icondata_core::Ico
 	C20.48,10.71,19.81,9.64,18.73,8.76z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltCloudyHigh, "Night Alt Cloudy High"))]
 pub static WiNightAltCloudyHigh: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltCloudyHigh",
+    label: "Night Alt Cloudy High",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3288,8 +4037,14 @@ pub static WiNightAltCloudyHigh: &icondata_core::IconData = &icondata_core::Icon
 	s0.75-1.31,0.86-2.1c-1.08-0.22-1.98-0.65-2.72-1.3c-0.84,0.65-1.78,0.99-2.82,1.01H16.71z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltCloudyWindy, "Night Alt Cloudy Windy"))]
 pub static WiNightAltCloudyWindy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltCloudyWindy",
+    label: "Night Alt Cloudy Windy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3323,8 +4078,14 @@ pub static WiNightAltCloudyWindy: &icondata_core::IconData = &icondata_core::Ico
 	C21.06,10.77,20.4,9.71,19.33,8.78z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltHail, "Night Alt Hail"))]
 pub static WiNightAltHail: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltHail",
+    label: "Night Alt Hail",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3367,8 +4128,14 @@ pub static WiNightAltHail: &icondata_core::IconData = &icondata_core::IconData {
 	C22.25,12.39,21.17,12,19.88,12h-0.32C19.3,10.75,18.66,9.69,17.62,8.83z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltLightning, "Night Alt Lightning"))]
 pub static WiNightAltLightning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltLightning",
+    label: "Night Alt Lightning",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3398,8 +4165,14 @@ pub static WiNightAltLightning: &icondata_core::IconData = &icondata_core::IconD
 	C19.29,10.71,18.63,9.66,17.6,8.81z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltPartlyCloudy, "Night Alt Partly Cloudy"))]
 pub static WiNightAltPartlyCloudy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltPartlyCloudy",
+    label: "Night Alt Partly Cloudy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3427,8 +4200,14 @@ pub static WiNightAltPartlyCloudy: &icondata_core::IconData = &icondata_core::Ic
 	c-0.57,0.26-1.05,0.64-1.44,1.12l-0.03-0.02C13.55,11.56,13.25,12.35,13.18,13.25z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltRain, "Night Alt Rain"))]
 pub static WiNightAltRain: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltRain",
+    label: "Night Alt Rain",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3464,8 +4243,14 @@ pub static WiNightAltRain: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.92-0.76-1.99-1.13-3.23-1.13h-0.31C19.3,10.7,18.64,9.64,17.58,8.77z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltRainMix, "Night Alt Rain Mix"))]
 pub static WiNightAltRainMix: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltRainMix",
+    label: "Night Alt Rain Mix",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3516,8 +4301,14 @@ pub static WiNightAltRainMix: &icondata_core::IconData = &icondata_core::IconDat
 	C19.27,10.66,18.61,9.59,17.59,8.76z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltRainWind, "Night Alt Rain Wind"))]
 pub static WiNightAltRainWind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltRainWind",
+    label: "Night Alt Rain Wind",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3558,8 +4349,14 @@ pub static WiNightAltRainWind: &icondata_core::IconData = &icondata_core::IconDa
 	C19.26,10.74,18.6,9.67,17.57,8.81z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltShowers, "Night Alt Showers"))]
 pub static WiNightAltShowers: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltShowers",
+    label: "Night Alt Showers",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3602,8 +4399,14 @@ pub static WiNightAltShowers: &icondata_core::IconData = &icondata_core::IconDat
 	C19.27,10.78,18.63,9.73,17.58,8.81z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltSleet, "Night Alt Sleet"))]
 pub static WiNightAltSleet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltSleet",
+    label: "Night Alt Sleet",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3650,8 +4453,14 @@ pub static WiNightAltSleet: &icondata_core::IconData = &icondata_core::IconData
 	C18.36,7.84,17.92,8.27,17.59,8.82z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltSleetStorm, "Night Alt Sleet Storm"))]
 pub static WiNightAltSleetStorm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltSleetStorm",
+    label: "Night Alt Sleet Storm",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3692,8 +4501,14 @@ pub static WiNightAltSleetStorm: &icondata_core::IconData = &icondata_core::Icon
 	c-0.91-0.78-1.98-1.17-3.22-1.17h-0.33C19.28,10.68,18.62,9.62,17.59,8.77z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltSnow, "Night Alt Snow"))]
 pub static WiNightAltSnow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltSnow",
+    label: "Night Alt Snow",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3734,8 +4549,14 @@ pub static WiNightAltSnow: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.16,0.36-0.41,0.72-0.76,1.07c-0.89-0.79-1.96-1.18-3.23-1.18h-0.31C19.3,10.74,18.64,9.68,17.58,8.77z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltSnowThunderstorm, "Night Alt Snow Thunderstorm"))]
 pub static WiNightAltSnowThunderstorm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltSnowThunderstorm",
+    label: "Night Alt Snow Thunderstorm",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3774,8 +4595,14 @@ pub static WiNightAltSnowThunderstorm: &icondata_core::IconData = &icondata_core
 	C19.28,10.68,18.62,9.62,17.59,8.77z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltSnowWind, "Night Alt Snow Wind"))]
 pub static WiNightAltSnowWind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltSnowWind",
+    label: "Night Alt Snow Wind",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3817,8 +4644,14 @@ pub static WiNightAltSnowWind: &icondata_core::IconData = &icondata_core::IconDa
 	c-0.89-0.79-1.96-1.18-3.23-1.18h-0.31C19.3,10.74,18.64,9.68,17.58,8.77z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltSprinkle, "Night Alt Sprinkle"))]
 pub static WiNightAltSprinkle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltSprinkle",
+    label: "Night Alt Sprinkle",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3853,8 +4686,14 @@ pub static WiNightAltSprinkle: &icondata_core::IconData = &icondata_core::IconDa
 	c-0.93-0.76-1.99-1.14-3.21-1.14h-0.33C19.27,10.65,18.6,9.59,17.56,8.77z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltStormShowers, "Night Alt Storm Showers"))]
 pub static WiNightAltStormShowers: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltStormShowers",
+    label: "Night Alt Storm Showers",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3894,8 +4733,14 @@ pub static WiNightAltStormShowers: &icondata_core::IconData = &icondata_core::Ic
 	s-0.27,0.26-0.33,0.47l-0.29,1.02c0,0.01,0,0.04-0.01,0.1C17.79,18.79,17.78,18.84,17.78,18.87z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightAltThunderstorm, "Night Alt Thunderstorm"))]
 pub static WiNightAltThunderstorm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightAltThunderstorm",
+    label: "Night Alt Thunderstorm",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3929,8 +4774,14 @@ pub static WiNightAltThunderstorm: &icondata_core::IconData = &icondata_core::Ic
 	s1.25,1.51,2.17,1.97c-0.18,0.42-0.44,0.77-0.79,1.07c-0.92-0.79-1.99-1.18-3.22-1.18h-0.32C19.29,10.66,18.63,9.61,17.59,8.75z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightClear, "Night Clear"))]
 pub static WiNightClear: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightClear",
+    label: "Night Clear",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3952,8 +4803,14 @@ pub static WiNightClear: &icondata_core::IconData = &icondata_core::IconData {
 	C9.96,12.74,9.74,13.59,9.74,14.48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightCloudy, "Night Cloudy"))]
 pub static WiNightCloudy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightCloudy",
+    label: "Night Cloudy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -3977,8 +4834,14 @@ pub static WiNightCloudy: &icondata_core::IconData = &icondata_core::IconData {
 	" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightCloudyGusts, "Night Cloudy Gusts"))]
 pub static WiNightCloudyGusts: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightCloudyGusts",
+    label: "Night Cloudy Gusts",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4012,8 +4875,14 @@ pub static WiNightCloudyGusts: &icondata_core::IconData = &icondata_core::IconDa
 	c-0.96-0.96-2.11-1.43-3.47-1.43h-0.33C20.73,10.88,20.16,9.89,19.24,9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightCloudyHigh, "Night Cloudy High"))]
 pub static WiNightCloudyHigh: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightCloudyHigh",
+    label: "Night Cloudy High",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4039,8 +4908,14 @@ pub static WiNightCloudyHigh: &icondata_core::IconData = &icondata_core::IconDat
 	c-0.39,0.82-0.97,1.5-1.74,2.02c-0.77,0.52-1.63,0.79-2.57,0.83h-3.03C16.54,18.44,16.54,18.47,16.55,18.56z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightCloudyWindy, "Night Cloudy Windy"))]
 pub static WiNightCloudyWindy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightCloudyWindy",
+    label: "Night Cloudy Windy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4071,8 +4946,14 @@ pub static WiNightCloudyWindy: &icondata_core::IconData = &icondata_core::IconDa
 	c-0.98-0.96-2.15-1.43-3.52-1.43h-0.32C21.3,10.84,20.73,9.87,19.83,9.02z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightFog, "Night Fog"))]
 pub static WiNightFog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightFog",
+    label: "Night Fog",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4103,8 +4984,14 @@ pub static WiNightFog: &icondata_core::IconData = &icondata_core::IconData {
 	C20.18,10.57,19.52,9.51,18.51,8.7z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightHail, "Night Hail"))]
 pub static WiNightHail: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightHail",
+    label: "Night Hail",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4145,8 +5032,14 @@ pub static WiNightHail: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.98-0.98-2.14-1.47-3.49-1.47h-0.33C19.41,10.78,18.85,9.81,18.04,9.02z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightLightning, "Night Lightning"))]
 pub static WiNightLightning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightLightning",
+    label: "Night Lightning",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4173,8 +5066,14 @@ pub static WiNightLightning: &icondata_core::IconData = &icondata_core::IconData
 	C19.44,10.81,18.86,9.83,18,9.05z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightPartlyCloudy, "Night Partly Cloudy"))]
 pub static WiNightPartlyCloudy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightPartlyCloudy",
+    label: "Night Partly Cloudy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4199,8 +5098,14 @@ pub static WiNightPartlyCloudy: &icondata_core::IconData = &icondata_core::IconD
 	c-0.59-0.49-1.27-0.73-2.03-0.73c-0.29-0.88-0.81-1.57-1.54-2.09c-0.73-0.52-1.56-0.78-2.49-0.78H13.18z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightRain, "Night Rain"))]
 pub static WiNightRain: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightRain",
+    label: "Night Rain",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4233,8 +5138,14 @@ pub static WiNightRain: &icondata_core::IconData = &icondata_core::IconData {
 	" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightRainMix, "Night Rain Mix"))]
 pub static WiNightRainMix: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightRainMix",
+    label: "Night Rain Mix",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4284,8 +5195,14 @@ pub static WiNightRainMix: &icondata_core::IconData = &icondata_core::IconData {
 	z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightRainWind, "Night Rain Wind"))]
 pub static WiNightRainWind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightRainWind",
+    label: "Night Rain Wind",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4323,8 +5240,14 @@ pub static WiNightRainWind: &icondata_core::IconData = &icondata_core::IconData
 	c-0.97-0.98-2.16-1.46-3.55-1.46H19.8C19.55,10.84,18.98,9.86,18.09,9.03z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightShowers, "Night Showers"))]
 pub static WiNightShowers: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightShowers",
+    label: "Night Showers",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4364,8 +5287,14 @@ pub static WiNightShowers: &icondata_core::IconData = &icondata_core::IconData {
 	c0.69,0.7,1.03,1.53,1.03,2.51c0,0.64-0.16,1.23-0.48,1.77c-0.96-0.96-2.12-1.44-3.49-1.44h-0.32C19.5,10.87,18.94,9.9,18.06,9.05z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightSleet, "Night Sleet"))]
 pub static WiNightSleet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightSleet",
+    label: "Night Sleet",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4408,8 +5337,14 @@ pub static WiNightSleet: &icondata_core::IconData = &icondata_core::IconData {
 	c0.31-0.55,0.47-1.15,0.47-1.81c0-0.98-0.35-1.81-1.04-2.5c-0.69-0.68-1.53-1.03-2.51-1.03C19.54,8.14,18.73,8.46,18.06,9.1z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightSleetStorm, "Night Sleet Storm"))]
 pub static WiNightSleetStorm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightSleetStorm",
+    label: "Night Sleet Storm",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4447,8 +5382,14 @@ pub static WiNightSleetStorm: &icondata_core::IconData = &icondata_core::IconDat
 	c-0.96-0.96-2.13-1.44-3.52-1.44h-0.31C19.42,10.76,18.85,9.78,18.02,9.02z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightSnow, "Night Snow"))]
 pub static WiNightSnow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightSnow",
+    label: "Night Snow",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4486,8 +5427,14 @@ pub static WiNightSnow: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.96-0.96-2.13-1.44-3.5-1.44h-0.31C19.44,10.8,18.87,9.82,18.03,9.02z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightSnowThunderstorm, "Night Snow Thunderstorm"))]
 pub static WiNightSnowThunderstorm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightSnowThunderstorm",
+    label: "Night Snow Thunderstorm",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4523,8 +5470,14 @@ pub static WiNightSnowThunderstorm: &icondata_core::IconData = &icondata_core::I
 	z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightSnowWind, "Night Snow Wind"))]
 pub static WiNightSnowWind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightSnowWind",
+    label: "Night Snow Wind",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4562,8 +5515,14 @@ pub static WiNightSnowWind: &icondata_core::IconData = &icondata_core::IconData
 	c-0.96-0.96-2.13-1.44-3.5-1.44h-0.31C19.44,10.8,18.87,9.82,18.03,9.02z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightSprinkle, "Night Sprinkle"))]
 pub static WiNightSprinkle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightSprinkle",
+    label: "Night Sprinkle",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4595,8 +5554,14 @@ pub static WiNightSprinkle: &icondata_core::IconData = &icondata_core::IconData
 	C19.42,10.76,18.85,9.79,18.01,9.02z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightStormShowers, "Night Storm Showers"))]
 pub static WiNightStormShowers: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightStormShowers",
+    label: "Night Storm Showers",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4633,8 +5598,14 @@ pub static WiNightStormShowers: &icondata_core::IconData = &icondata_core::IconD
 	c-0.99-0.96-2.15-1.44-3.49-1.44h-0.32C19.44,10.75,18.88,9.77,18.06,8.98z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiNightThunderstorm, "Night Thunderstorm"))]
 pub static WiNightThunderstorm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiNightThunderstorm",
+    label: "Night Thunderstorm",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4665,8 +5636,14 @@ pub static WiNightThunderstorm: &icondata_core::IconData = &icondata_core::IconD
 	c-0.96-0.96-2.12-1.44-3.48-1.44h-0.32C19.42,10.84,18.86,9.86,18.02,9.04z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiRain, "Rain"))]
 pub static WiRain: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiRain",
+    label: "Rain",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4698,8 +5675,14 @@ pub static WiRain: &icondata_core::IconData = &icondata_core::IconData {
 	C16.79,23.94,16.74,23.78,16.74,23.62z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiRainMix, "Rain Mix"))]
 pub static WiRainMix: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiRainMix",
+    label: "Rain Mix",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4745,8 +5728,14 @@ pub static WiRainMix: &icondata_core::IconData = &icondata_core::IconData {
 	C18.1,18.74,18.1,18.78,18.1,18.81z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiRainWind, "Rain Wind"))]
 pub static WiRainWind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiRainWind",
+    label: "Rain Wind",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4783,8 +5772,14 @@ pub static WiRainWind: &icondata_core::IconData = &icondata_core::IconData {
 	C15.84,25.8,15.81,25.92,15.81,26.03z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiRaindrop, "Raindrop"))]
 pub static WiRaindrop: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiRaindrop",
+    label: "Raindrop",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4802,8 +5797,14 @@ pub static WiRaindrop: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.57,0.79-1.07,1.64-1.49,2.55C10.01,13.79,9.81,14.58,9.81,15.25z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiRaindrops, "Raindrops"))]
 pub static WiRaindrops: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiRaindrops",
+    label: "Raindrops",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4825,8 +5826,14 @@ pub static WiRaindrops: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.29,0-0.53-0.09-0.72-0.28C15.75,9.98,15.65,9.75,15.65,9.48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiRefresh, "Refresh"))]
 pub static WiRefresh: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiRefresh",
+    label: "Refresh",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4847,8 +5854,14 @@ pub static WiRefresh: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.17,0.17-0.25,0.37-0.25,0.6c0,0.24,0.08,0.45,0.24,0.61l0.38,0.36c-1.25,0.22-2.29,0.82-3.12,1.8S9.77,14.27,9.77,15.53z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiRefreshAlt, "Refresh Alt"))]
 pub static WiRefreshAlt: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiRefreshAlt",
+    label: "Refresh Alt",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4868,8 +5881,14 @@ pub static WiRefreshAlt: &icondata_core::IconData = &icondata_core::IconData {
 	s-0.21,0.3-0.21,0.5c0,0.21,0.07,0.38,0.22,0.51l0.3,0.28c-1.15,0.08-2.11,0.53-2.89,1.35C12.17,12.77,11.78,13.76,11.78,14.91z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiSandstorm, "Sandstorm"))]
 pub static WiSandstorm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiSandstorm",
+    label: "Sandstorm",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4909,8 +5928,14 @@ pub static WiSandstorm: &icondata_core::IconData = &icondata_core::IconData {
 	c0-0.23-0.08-0.43-0.25-0.6c-0.17-0.17-0.37-0.25-0.61-0.25c-0.23,0-0.42,0.08-0.58,0.25C22.9,16.61,22.81,16.81,22.81,17.04z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiShowers, "Showers"))]
 pub static WiShowers: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiShowers",
+    label: "Showers",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4950,8 +5975,14 @@ pub static WiShowers: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.21-0.06-0.36-0.17-0.46-0.31C18.16,19.29,18.11,19.14,18.11,18.98z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiSleet, "Sleet"))]
 pub static WiSleet: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiSleet",
+    label: "Sleet",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -4995,8 +6026,14 @@ pub static WiSleet: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.24,0-0.43-0.08-0.58-0.24C16.97,21.61,16.88,21.42,16.88,21.19z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiSmallCraftAdvisory, "Small Craft Advisory"))]
 pub static WiSmallCraftAdvisory: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiSmallCraftAdvisory",
+    label: "Small Craft Advisory",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5011,8 +6048,14 @@ pub static WiSmallCraftAdvisory: &icondata_core::IconData = &icondata_core::Icon
     data: r###"<path d="M9.81,24.6V7.45h1.03V24.6H9.81z M11.54,14.86V7.45l8.65,3.69L11.54,14.86z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiSmog, "Smog"))]
 pub static WiSmog: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiSmog",
+    label: "Smog",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5034,8 +6077,14 @@ pub static WiSmog: &icondata_core::IconData = &icondata_core::IconData {
 	z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiSmoke, "Smoke"))]
 pub static WiSmoke: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiSmoke",
+    label: "Smoke",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5061,8 +6110,14 @@ pub static WiSmoke: &icondata_core::IconData = &icondata_core::IconData {
 	s-1.04,1.44-1.07,2.37c-0.02,0-0.05,0-0.08,0c-0.04,0-0.07,0-0.09,0c-0.79,0-1.46,0.28-2.03,0.84S7.74,11.45,7.74,12.23z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiSnow, "Snow"))]
 pub static WiSnow: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiSnow",
+    label: "Snow",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5098,8 +6153,14 @@ pub static WiSnow: &icondata_core::IconData = &icondata_core::IconData {
 	c0,0.24-0.08,0.43-0.23,0.59c-0.16,0.16-0.35,0.23-0.59,0.23c-0.24,0-0.44-0.08-0.6-0.24C17.5,25.07,17.41,24.88,17.41,24.65z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiSnowWind, "Snow Wind"))]
 pub static WiSnowWind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiSnowWind",
+    label: "Snow Wind",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5135,8 +6196,14 @@ pub static WiSnowWind: &icondata_core::IconData = &icondata_core::IconData {
 	c0,0.24-0.08,0.44-0.24,0.6c-0.16,0.17-0.35,0.25-0.59,0.25c-0.24,0-0.44-0.08-0.6-0.25C17.5,21.45,17.41,21.25,17.41,21.02z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiSnowflakeCold, "Snowflake Cold"))]
 pub static WiSnowflakeCold: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiSnowflakeCold",
+    label: "Snowflake Cold",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5175,8 +6242,14 @@ pub static WiSnowflakeCold: &icondata_core::IconData = &icondata_core::IconData
 	c0,0.18-0.07,0.34-0.2,0.47c-0.13,0.13-0.29,0.2-0.47,0.2c-0.19,0-0.36-0.07-0.49-0.2C21.24,14.75,21.17,14.59,21.17,14.41z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiSolarEclipse, "Solar Eclipse"))]
 pub static WiSolarEclipse: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiSolarEclipse",
+    label: "Solar Eclipse",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5213,8 +6286,14 @@ pub static WiSolarEclipse: &icondata_core::IconData = &icondata_core::IconData {
 	S21.92,14.86,21.92,14.62z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiSprinkle, "Sprinkle"))]
 pub static WiSprinkle: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiSprinkle",
+    label: "Sprinkle",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5243,8 +6322,14 @@ pub static WiSprinkle: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.18,0.18-0.4,0.28-0.66,0.28c-0.27,0-0.5-0.09-0.69-0.28C15.11,15.6,15.02,15.38,15.02,15.12z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiStars, "Stars"))]
 pub static WiStars: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiStars",
+    label: "Stars",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5267,8 +6352,14 @@ pub static WiStars: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.95,0.04-1.76,0.41-2.42,1.1c-0.66,0.69-0.99,1.51-0.99,2.47c0-0.96-0.33-1.78-0.98-2.47C19.58,15.42,18.78,15.05,17.83,15.01z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiStormShowers, "Storm Showers"))]
 pub static WiStormShowers: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiStormShowers",
+    label: "Storm Showers",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5303,8 +6394,14 @@ pub static WiStormShowers: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.17,0-0.33,0.05-0.49,0.15c-0.16,0.1-0.27,0.26-0.33,0.48l-0.27,1.01C18.32,18.72,18.31,18.79,18.31,18.86z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiStormWarning, "Storm Warning"))]
 pub static WiStormWarning: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiStormWarning",
+    label: "Storm Warning",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5319,8 +6416,14 @@ pub static WiStormWarning: &icondata_core::IconData = &icondata_core::IconData {
     data: r###"<path d="M9.76,24.6V7.45h1.13V24.6H9.76z M11.7,14.05v-6.6h8.55v6.6H11.7z M14.06,12.05h3.81v-2.5h-3.81V12.05z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiStrongWind, "Strong Wind"))]
 pub static WiStrongWind: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiStrongWind",
+    label: "Strong Wind",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5344,8 +6447,14 @@ pub static WiStrongWind: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.25,0-0.46,0.09-0.64,0.26C3.19,13.23,3.1,13.44,3.1,13.69z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiSunrise, "Sunrise"))]
 pub static WiSunrise: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiSunrise",
+    label: "Sunrise",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5379,8 +6488,14 @@ pub static WiSunrise: &icondata_core::IconData = &icondata_core::IconData {
 	C23.08,15.88,22.99,15.64,22.99,15.36z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiSunset, "Sunset"))]
 pub static WiSunset: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiSunset",
+    label: "Sunset",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5414,8 +6529,14 @@ pub static WiSunset: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.19,0.2-0.42,0.3-0.69,0.3h-2.32c-0.26,0-0.48-0.1-0.66-0.29C22.99,15.99,22.9,15.75,22.9,15.47z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiThermometer, "Thermometer"))]
 pub static WiThermometer: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiThermometer",
+    label: "Thermometer",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5439,8 +6560,14 @@ pub static WiThermometer: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.05,0.06-0.08,0.12-0.08,0.21v7.34c-0.61,0.09-1.13,0.37-1.56,0.85C12.66,18.37,12.45,18.92,12.45,19.56z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiThermometerExterior, "Thermometer Exterior"))]
 pub static WiThermometerExterior: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiThermometerExterior",
+    label: "Thermometer Exterior",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5461,8 +6588,14 @@ pub static WiThermometerExterior: &icondata_core::IconData = &icondata_core::Ico
 	c0,0.15-0.05,0.25-0.14,0.29l-0.27,0.14c-0.55,0.29-0.98,0.7-1.29,1.23C11.82,18.35,11.67,18.92,11.67,19.56z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiThermometerInternal, "Thermometer Internal"))]
 pub static WiThermometerInternal: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiThermometerInternal",
+    label: "Thermometer Internal",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5480,8 +6613,14 @@ pub static WiThermometerInternal: &icondata_core::IconData = &icondata_core::Ico
 	C12.69,18.37,12.48,18.92,12.48,19.56z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiThunderstorm, "Thunderstorm"))]
 pub static WiThunderstorm: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiThunderstorm",
+    label: "Thunderstorm",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5511,8 +6650,14 @@ pub static WiThunderstorm: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.14,0.1-0.24,0.25-0.3,0.45l-1.46,5.7c0,0.02,0,0.05-0.01,0.11C16.93,23.5,16.93,23.53,16.93,23.56z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTime1, "Time 1"))]
 pub static WiTime1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTime1",
+    label: "Time 1",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5534,8 +6679,14 @@ pub static WiTime1: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.13,0.35-0.38,0.53-0.76,0.53c-0.23,0-0.43-0.08-0.59-0.24S14.14,14.71,14.14,14.47z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTime10, "Time 10"))]
 pub static WiTime10: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTime10",
+    label: "Time 10",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5557,8 +6708,14 @@ pub static WiTime10: &icondata_core::IconData = &icondata_core::IconData {
 	s-0.36,0.24-0.59,0.24c-0.21,0-0.39-0.07-0.56-0.22l-3.88-2.17C10.34,12.8,10.21,12.63,10.14,12.41z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTime11, "Time 11"))]
 pub static WiTime11: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTime11",
+    label: "Time 11",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5580,8 +6737,14 @@ pub static WiTime11: &icondata_core::IconData = &icondata_core::IconData {
 	s-0.36,0.24-0.59,0.24c-0.4,0-0.66-0.18-0.79-0.53l-2.26-3.91C11.81,10.67,11.78,10.46,11.84,10.24z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTime12, "Time 12"))]
 pub static WiTime12: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTime12",
+    label: "Time 12",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5602,8 +6765,14 @@ pub static WiTime12: &icondata_core::IconData = &icondata_core::IconData {
 	V7.81c0-0.23-0.08-0.43-0.25-0.59s-0.36-0.24-0.59-0.24c-0.23,0-0.43,0.08-0.59,0.24s-0.24,0.36-0.24,0.59V14.47z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTime2, "Time 2"))]
 pub static WiTime2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTime2",
+    label: "Time 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5625,8 +6794,14 @@ pub static WiTime2: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.17,0.15-0.35,0.22-0.56,0.22c-0.23,0-0.43-0.08-0.59-0.24S14.14,14.71,14.14,14.47z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTime3, "Time 3"))]
 pub static WiTime3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTime3",
+    label: "Time 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5648,8 +6823,14 @@ pub static WiTime3: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.03,0.01-0.09,0.01-0.18,0.01c-0.23,0-0.43-0.08-0.59-0.24S14.14,14.71,14.14,14.47z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTime4, "Time 4"))]
 pub static WiTime4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTime4",
+    label: "Time 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5671,8 +6852,14 @@ pub static WiTime4: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.17-0.05-0.31-0.15-0.42-0.29S14.14,14.66,14.14,14.47z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTime5, "Time 5"))]
 pub static WiTime5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTime5",
+    label: "Time 5",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5694,8 +6881,14 @@ pub static WiTime5: &icondata_core::IconData = &icondata_core::IconData {
 	C14.2,14.85,14.14,14.68,14.14,14.47z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTime6, "Time 6"))]
 pub static WiTime6: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTime6",
+    label: "Time 6",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5716,8 +6909,14 @@ pub static WiTime6: &icondata_core::IconData = &icondata_core::IconData {
 	c0,0.23-0.08,0.43-0.24,0.6s-0.36,0.25-0.59,0.25s-0.43-0.08-0.59-0.25S14.14,19.31,14.14,19.07z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTime7, "Time 7"))]
 pub static WiTime7: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTime7",
+    label: "Time 7",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5739,8 +6938,14 @@ pub static WiTime7: &icondata_core::IconData = &icondata_core::IconData {
 	C12.09,19.1,11.96,18.93,11.89,18.71z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTime8, "Time 8"))]
 pub static WiTime8: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTime8",
+    label: "Time 8",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5762,8 +6967,14 @@ pub static WiTime8: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.32,0-0.56-0.14-0.72-0.42C10.14,16.99,10.11,16.78,10.17,16.56z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTime9, "Time 9"))]
 pub static WiTime9: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTime9",
+    label: "Time 9",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5785,8 +6996,14 @@ pub static WiTime9: &icondata_core::IconData = &icondata_core::IconData {
 	c-0.23,0-0.43-0.08-0.59-0.25C9.59,14.88,9.51,14.68,9.51,14.46z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTornado, "Tornado"))]
 pub static WiTornado: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTornado",
+    label: "Tornado",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5820,8 +7037,14 @@ pub static WiTornado: &icondata_core::IconData = &icondata_core::IconData {
 	c-2,0-3.72-0.21-5.14-0.63C9.32,8.71,8.42,8.3,7.98,7.87C7.75,7.66,7.63,7.48,7.63,7.31z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTrain, "Train"))]
 pub static WiTrain: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTrain",
+    label: "Train",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5856,8 +7079,14 @@ pub static WiTrain: &icondata_core::IconData = &icondata_core::IconData {
 	C15.93,4.75,15.78,5.07,15.78,5.43z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiTsunami, "Tsunami"))]
 pub static WiTsunami: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiTsunami",
+    label: "Tsunami",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5892,8 +7121,14 @@ pub static WiTsunami: &icondata_core::IconData = &icondata_core::IconData {
 	C6.89,12.65,6.62,14.07,6.62,15.58z M18.05,20.64h3.88v-3.52l-1.98-1.21l-1.9,1.19V20.64z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiUmbrella, "Umbrella"))]
 pub static WiUmbrella: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiUmbrella",
+    label: "Umbrella",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5925,8 +7160,14 @@ pub static WiUmbrella: &icondata_core::IconData = &icondata_core::IconData {
 	c-1.79-2.34-4.27-3.51-7.43-3.51c-1.58,0-2.99,0.3-4.24,0.9c-1.24,0.6-2.26,1.47-3.05,2.61C7.44,11.82,7.12,12.41,6.73,13.23z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiVolcano, "Volcano"))]
 pub static WiVolcano: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiVolcano",
+    label: "Volcano",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5958,8 +7199,14 @@ pub static WiVolcano: &icondata_core::IconData = &icondata_core::IconData {
 	C14.79,15.84,14.76,15.64,14.76,15.48z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindBeaufort0, "Wind Beaufort 0"))]
 pub static WiWindBeaufort0: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindBeaufort0",
+    label: "Wind Beaufort 0",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -5989,8 +7236,14 @@ pub static WiWindBeaufort0: &icondata_core::IconData = &icondata_core::IconData
 	c0.16,0.17,0.23,0.44,0.23,0.79c0,0.96-0.17,1.78-0.5,2.45s-0.74,1.01-1.23,1.01C20.63,20.19,20.32,19.78,20.32,18.96z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindBeaufort1, "Wind Beaufort 1"))]
 pub static WiWindBeaufort1: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindBeaufort1",
+    label: "Wind Beaufort 1",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -6014,8 +7267,14 @@ pub static WiWindBeaufort1: &icondata_core::IconData = &icondata_core::IconData
 	C5.81,11.18,5.76,11.32,5.76,11.48z M18.65,21.85h2.47l1.65-7.98H20.3L18.65,21.85z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindBeaufort10, "Wind Beaufort 10"))]
 pub static WiWindBeaufort10: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindBeaufort10",
+    label: "Wind Beaufort 10",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -6045,8 +7304,14 @@ pub static WiWindBeaufort10: &icondata_core::IconData = &icondata_core::IconData
 	C22.52,20.19,22.21,19.79,22.21,18.98z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindBeaufort11, "Wind Beaufort 11"))]
 pub static WiWindBeaufort11: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindBeaufort11",
+    label: "Wind Beaufort 11",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -6071,8 +7336,14 @@ pub static WiWindBeaufort11: &icondata_core::IconData = &icondata_core::IconData
 	l1.65-7.99h-2.45L21.3,21.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindBeaufort12, "Wind Beaufort 12"))]
 pub static WiWindBeaufort12: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindBeaufort12",
+    label: "Wind Beaufort 12",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -6102,8 +7373,14 @@ pub static WiWindBeaufort12: &icondata_core::IconData = &icondata_core::IconData
 	C19.68,21.03,19.57,21.44,19.51,21.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindBeaufort2, "Wind Beaufort 2"))]
 pub static WiWindBeaufort2: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindBeaufort2",
+    label: "Wind Beaufort 2",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -6133,8 +7410,14 @@ pub static WiWindBeaufort2: &icondata_core::IconData = &icondata_core::IconData
 	C17.82,21.01,17.71,21.41,17.66,21.85z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindBeaufort3, "Wind Beaufort 3"))]
 pub static WiWindBeaufort3: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindBeaufort3",
+    label: "Wind Beaufort 3",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -6165,8 +7448,14 @@ pub static WiWindBeaufort3: &icondata_core::IconData = &icondata_core::IconData
 	c-0.17-0.21-0.24-0.51-0.21-0.89h-2.07C18.13,19.06,18.12,19.27,18.12,19.52z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindBeaufort4, "Wind Beaufort 4"))]
 pub static WiWindBeaufort4: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindBeaufort4",
+    label: "Wind Beaufort 4",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -6191,8 +7480,14 @@ pub static WiWindBeaufort4: &icondata_core::IconData = &icondata_core::IconData
 	L17.98,20.35z M20.23,18.45l2.24-2.21h0.03l-0.49,2.21H20.23z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindBeaufort5, "Wind Beaufort 5"))]
 pub static WiWindBeaufort5: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindBeaufort5",
+    label: "Wind Beaufort 5",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -6221,8 +7516,14 @@ pub static WiWindBeaufort5: &icondata_core::IconData = &icondata_core::IconData
 	c-0.23,0.21-0.57,0.31-1,0.31c-0.31,0-0.56-0.06-0.73-0.17c-0.21-0.11-0.33-0.31-0.36-0.6H18.04z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindBeaufort6, "Wind Beaufort 6"))]
 pub static WiWindBeaufort6: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindBeaufort6",
+    label: "Wind Beaufort 6",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -6254,8 +7555,14 @@ pub static WiWindBeaufort6: &icondata_core::IconData = &icondata_core::IconData
 	c-0.31,0-0.55-0.09-0.75-0.27C20.48,19.69,20.38,19.46,20.38,19.17z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindBeaufort7, "Wind Beaufort 7"))]
 pub static WiWindBeaufort7: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindBeaufort7",
+    label: "Wind Beaufort 7",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -6280,8 +7587,14 @@ pub static WiWindBeaufort7: &icondata_core::IconData = &icondata_core::IconData
 	l-0.41,1.89h3.49C20.39,18,19.22,19.96,18.85,21.9z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindBeaufort8, "Wind Beaufort 8"))]
 pub static WiWindBeaufort8: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindBeaufort8",
+    label: "Wind Beaufort 8",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -6316,8 +7629,14 @@ pub static WiWindBeaufort8: &icondata_core::IconData = &icondata_core::IconData
 	c-0.08,0.1-0.21,0.18-0.39,0.24c-0.18,0.06-0.4,0.09-0.66,0.09c-0.4,0-0.68-0.08-0.84-0.23C20.94,16.75,20.86,16.57,20.86,16.37z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindBeaufort9, "Wind Beaufort 9"))]
 pub static WiWindBeaufort9: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindBeaufort9",
+    label: "Wind Beaufort 9",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -6349,8 +7668,14 @@ pub static WiWindBeaufort9: &icondata_core::IconData = &icondata_core::IconData
 	c-0.07-0.12-0.11-0.22-0.14-0.31S20.75,16.96,20.75,16.88z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindDeg, "Wind Deg"))]
 pub static WiWindDeg: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindDeg",
+    label: "Wind Deg",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),
@@ -6371,8 +7696,14 @@ pub static WiWindDeg: &icondata_core::IconData = &icondata_core::IconData {
 	s-0.13,0.06-0.24,0l-3.47-1.3c-0.1-0.04-0.2-0.04-0.29,0l-3.5,1.3c-0.1,0.06-0.17,0.06-0.21,0S11.09,20.45,11.11,20.35z" />"###
 };
 #[allow(non_upper_case_globals)]
-#[doc(hidden)]
+#[cfg_attr(not(feature = "docs"), doc(hidden))]
+#[cfg_attr(feature = "docs", doc = examples!(WiWindy, "Windy"))]
 pub static WiWindy: &icondata_core::IconData = &icondata_core::IconData {
+    id: "WiWindy",
+    label: "Windy",
+    source: None,
+    paint: icondata_core::IconPaint::Fill,
+    brand_color: None,
     style: Some("enable-background:new 0 0 30 30;"),
     x: Some("0px"),
     y: Some("0px"),